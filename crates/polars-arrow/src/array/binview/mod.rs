@@ -372,6 +372,77 @@ impl<T: ViewType + ?Sized> BinaryViewArrayGeneric<T> {
         mutable.freeze().with_validity(self.validity)
     }
 
+    /// Drop the buffers that no view refers to, without copying any values.
+    ///
+    /// Large values have a buffer of their own, so after a gather or filter of large values
+    /// this frees the memory of the values that were removed, which [`Self::gc`] would copy.
+    pub fn drop_unused_buffers(self) -> Self {
+        let mut used = vec![false; self.buffers.len()];
+        for view in self.views.iter().filter(|v| !v.is_inline()) {
+            used[view.buffer_idx as usize] = true;
+        }
+        if used.iter().all(|used| *used) {
+            return self;
+        }
+
+        let mut new_idx = vec![0u32; self.buffers.len()];
+        let mut buffers = Vec::with_capacity(used.iter().filter(|used| **used).count());
+        for (i, buffer) in self.buffers.iter().enumerate() {
+            if used[i] {
+                new_idx[i] = buffers.len() as u32;
+                buffers.push(buffer.clone());
+            }
+        }
+        let views = self
+            .views
+            .iter()
+            .map(|view| {
+                let mut view = *view;
+                if !view.is_inline() {
+                    view.buffer_idx = new_idx[view.buffer_idx as usize];
+                }
+                view
+            })
+            .collect::<Vec<_>>();
+        let total_buffer_len = buffers.iter().map(|b| b.len()).sum();
+        let total_bytes_len = self.total_bytes_len.load(Ordering::Relaxed) as usize;
+        // SAFETY: only the buffer indices changed, to those of the same buffers.
+        unsafe {
+            Self::new_unchecked(
+                self.data_type,
+                views.into(),
+                buffers.into(),
+                self.validity,
+                total_bytes_len,
+                total_buffer_len,
+            )
+        }
+    }
+
+    /// Garbage collect if the buffers hold bytes that no view refers to, such as the values
+    /// removed by a filter or outside of a slice.
+    pub fn shrink_views(self) -> Self {
+        if self.total_buffer_len == 0 {
+            return self;
+        }
+        let referenced = self
+            .views
+            .iter()
+            .filter(|v| !v.is_inline())
+            .map(|v| v.length as usize)
+            .sum::<usize>();
+        if referenced < self.total_buffer_len {
+            let arr = self.drop_unused_buffers();
+            if referenced < arr.total_buffer_len {
+                arr.gc()
+            } else {
+                arr
+            }
+        } else {
+            self
+        }
+    }
+
     pub fn is_sliced(&self) -> bool {
         self.views.as_ptr() != self.views.storage_ptr()
     }
@@ -395,7 +466,14 @@ impl<T: ViewType + ?Sized> BinaryViewArrayGeneric<T> {
         if savings_upper_bound >= GC_MINIMUM_SAVINGS
             && cur_mem_usage >= 4 * lower_bound_mem_usage_post_gc
         {
-            self.gc()
+            // Try to free the buffers of large values before copying all values.
+            let arr = self.drop_unused_buffers();
+            let cur_mem_usage = arr.len() * 16 + arr.total_buffer_len();
+            if cur_mem_usage >= 4 * lower_bound_mem_usage_post_gc {
+                arr.gc()
+            } else {
+                arr
+            }
         } else {
             self
         }
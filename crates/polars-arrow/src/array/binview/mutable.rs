@@ -17,6 +17,11 @@ use crate::trusted_len::TrustedLen;
 use crate::types::NativeType;
 
 const DEFAULT_BLOCK_SIZE: usize = 8 * 1024;
+const MAX_BLOCK_SIZE: usize = 16 * 1024 * 1024;
+/// Values of at least this size get a buffer of their own. They don't inflate the buffers of the
+/// small values that way, and the buffer can be dropped as a whole once the value isn't used
+/// anymore, see [`BinaryViewArrayGeneric::drop_unused_buffers`].
+const LARGE_VALUE_SIZE: usize = 256 * 1024;
 
 pub struct MutableBinaryViewArray<T: ViewType + ?Sized> {
     pub(super) views: Vec<View>,
@@ -163,12 +168,21 @@ impl<T: ViewType + ?Sized> MutableBinaryViewArray<T> {
 
         if len <= 12 {
             payload[4..4 + bytes.len()].copy_from_slice(bytes);
+        } else if bytes.len() >= LARGE_VALUE_SIZE {
+            self.total_buffer_len += bytes.len();
+            // The views into the in-progress buffer refer to it by the index it gets once it is
+            // completed, so it has to be completed before the large value's buffer.
+            self.finish_in_progress();
+            unsafe { payload[4..8].copy_from_slice(bytes.get_unchecked_release(0..4)) };
+            let buffer_idx: u32 = self.completed_buffers.len().try_into().unwrap();
+            payload[8..12].copy_from_slice(&buffer_idx.to_le_bytes());
+            self.completed_buffers.push(bytes.to_vec().into());
         } else {
             self.total_buffer_len += bytes.len();
             let required_cap = self.in_progress_buffer.len() + bytes.len();
             if self.in_progress_buffer.capacity() < required_cap {
                 let new_capacity = (self.in_progress_buffer.capacity() * 2)
-                    .clamp(DEFAULT_BLOCK_SIZE, 16 * 1024 * 1024)
+                    .clamp(DEFAULT_BLOCK_SIZE, MAX_BLOCK_SIZE)
                     .max(bytes.len());
                 let in_progress = Vec::with_capacity(new_capacity);
                 let flushed = std::mem::replace(&mut self.in_progress_buffer, in_progress);
@@ -318,8 +332,12 @@ impl<T: ViewType + ?Sized> MutableBinaryViewArray<T> {
 
     fn finish_in_progress(&mut self) -> bool {
         if !self.in_progress_buffer.is_empty() {
-            self.completed_buffers
-                .push(std::mem::take(&mut self.in_progress_buffer).into());
+            let mut buffer = std::mem::take(&mut self.in_progress_buffer);
+            // Don't hold on to the unused capacity of a buffer that is completed early.
+            if buffer.capacity() > DEFAULT_BLOCK_SIZE && buffer.len() < buffer.capacity() / 2 {
+                buffer.shrink_to_fit();
+            }
+            self.completed_buffers.push(buffer.into());
             true
         } else {
             false
@@ -74,6 +74,59 @@ impl View {
             }
         }
     }
+
+    /// Whether the bytes are stored in the view itself instead of in a buffer.
+    #[inline(always)]
+    pub fn is_inline(&self) -> bool {
+        self.length <= INLINE_VIEW_SIZE
+    }
+
+    /// Compare the bytes of two views for equality. The buffers are only read if the lengths
+    /// and prefixes are equal and the bytes are not inlined.
+    ///
+    /// # Safety
+    /// Assumes that the views are valid for their buffers.
+    #[inline]
+    pub unsafe fn eq_unchecked(
+        &self,
+        other: &Self,
+        buffers: &[Buffer<u8>],
+        other_buffers: &[Buffer<u8>],
+    ) -> bool {
+        // The first 64 bits contain the length and the prefix.
+        let (a, b) = (self.as_u128(), other.as_u128());
+        if a as u64 != b as u64 {
+            return false;
+        }
+        if self.is_inline() {
+            // The lengths are equal and the padding is zeroed.
+            a == b
+        } else {
+            self.get_slice_unchecked(buffers) == other.get_slice_unchecked(other_buffers)
+        }
+    }
+
+    /// Compare the bytes of two views lexicographically. The buffers are only read if the first
+    /// four bytes are equal.
+    ///
+    /// # Safety
+    /// Assumes that the views are valid for their buffers.
+    #[inline]
+    pub unsafe fn cmp_unchecked(
+        &self,
+        other: &Self,
+        buffers: &[Buffer<u8>],
+        other_buffers: &[Buffer<u8>],
+    ) -> Ordering {
+        if self.prefix != other.prefix {
+            // The prefix holds the first bytes in little-endian order and is zero-padded, so the
+            // byte swapped prefixes order like the bytes.
+            return u32::from_be_bytes(self.prefix.to_le_bytes())
+                .cmp(&u32::from_be_bytes(other.prefix.to_le_bytes()));
+        }
+        self.get_slice_unchecked(buffers)
+            .cmp(other.get_slice_unchecked(other_buffers))
+    }
 }
 
 impl IsNull for View {
@@ -401,6 +401,125 @@ pub fn new_null_array(data_type: ArrowDataType, length: usize) -> Box<dyn Array>
     }
 }
 
+/// Returns `array` with its [`ArrowDataType`] replaced by `data_type`, e.g. to add or remove an
+/// extension type. No data is copied.
+///
+/// # Panics
+/// Panics if the physical types of both data types differ, or for union arrays.
+pub fn with_data_type(array: &dyn Array, data_type: ArrowDataType) -> Box<dyn Array> {
+    use crate::datatypes::PhysicalType::*;
+
+    macro_rules! downcast {
+        ($ty:ty) => {
+            array.as_any().downcast_ref::<$ty>().unwrap()
+        };
+    }
+
+    let physical_type = array.data_type().to_physical_type();
+    assert_eq!(
+        physical_type,
+        data_type.to_physical_type(),
+        "the physical types of both data types must be equal"
+    );
+    let validity = array.validity().cloned();
+    match physical_type {
+        Null => Box::new(NullArray::new(data_type, array.len())),
+        Boolean => {
+            let arr = downcast!(BooleanArray);
+            Box::new(BooleanArray::new(data_type, arr.values().clone(), validity))
+        },
+        Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+            Box::new(downcast!(PrimitiveArray<$T>).clone().to(data_type))
+        }),
+        Binary => {
+            let arr = downcast!(BinaryArray<i32>);
+            let (offsets, values) = (arr.offsets().clone(), arr.values().clone());
+            // SAFETY: the offsets and values are those of a valid array.
+            Box::new(unsafe { BinaryArray::new_unchecked(data_type, offsets, values, validity) })
+        },
+        LargeBinary => {
+            let arr = downcast!(BinaryArray<i64>);
+            let (offsets, values) = (arr.offsets().clone(), arr.values().clone());
+            // SAFETY: the offsets and values are those of a valid array.
+            Box::new(unsafe { BinaryArray::new_unchecked(data_type, offsets, values, validity) })
+        },
+        FixedSizeBinary => Box::new(downcast!(FixedSizeBinaryArray).clone().to(data_type)),
+        Utf8 => {
+            let arr = downcast!(Utf8Array<i32>);
+            let (offsets, values) = (arr.offsets().clone(), arr.values().clone());
+            // SAFETY: the values are valid utf8, as they are those of a valid array.
+            Box::new(unsafe { Utf8Array::new_unchecked(data_type, offsets, values, validity) })
+        },
+        LargeUtf8 => {
+            let arr = downcast!(Utf8Array<i64>);
+            let (offsets, values) = (arr.offsets().clone(), arr.values().clone());
+            // SAFETY: the values are valid utf8, as they are those of a valid array.
+            Box::new(unsafe { Utf8Array::new_unchecked(data_type, offsets, values, validity) })
+        },
+        List => {
+            let arr = downcast!(ListArray<i32>);
+            let (offsets, values) = (arr.offsets().clone(), arr.values().clone());
+            Box::new(ListArray::new(data_type, offsets, values, validity))
+        },
+        LargeList => {
+            let arr = downcast!(ListArray<i64>);
+            let (offsets, values) = (arr.offsets().clone(), arr.values().clone());
+            Box::new(ListArray::new(data_type, offsets, values, validity))
+        },
+        FixedSizeList => {
+            let values = downcast!(FixedSizeListArray).values().clone();
+            Box::new(FixedSizeListArray::new(data_type, values, validity))
+        },
+        Struct => {
+            let values = downcast!(StructArray).values().to_vec();
+            Box::new(StructArray::new(data_type, values, validity))
+        },
+        Map => {
+            let arr = downcast!(MapArray);
+            let (offsets, field) = (arr.offsets().clone(), arr.field().clone());
+            Box::new(MapArray::new(data_type, offsets, field, validity))
+        },
+        BinaryView => {
+            let arr = downcast!(BinaryViewArray);
+            // SAFETY: the views and buffers are those of a valid array.
+            Box::new(unsafe {
+                BinaryViewArray::new_unchecked(
+                    data_type,
+                    arr.views().clone(),
+                    arr.data_buffers().clone(),
+                    validity,
+                    arr.total_bytes_len(),
+                    arr.total_buffer_len(),
+                )
+            })
+        },
+        Utf8View => {
+            let arr = downcast!(Utf8ViewArray);
+            // SAFETY: the views and buffers are those of a valid array.
+            Box::new(unsafe {
+                Utf8ViewArray::new_unchecked(
+                    data_type,
+                    arr.views().clone(),
+                    arr.data_buffers().clone(),
+                    validity,
+                    arr.total_bytes_len(),
+                    arr.total_buffer_len(),
+                )
+            })
+        },
+        Dictionary(key_type) => {
+            match_integer_type!(key_type, |$T| {
+                let arr = downcast!(DictionaryArray<$T>);
+                Box::new(
+                    DictionaryArray::<$T>::try_new(data_type, arr.keys().clone(), arr.values().clone())
+                        .unwrap(),
+                )
+            })
+        },
+        Union => panic!("cannot replace the data type of a union array"),
+    }
+}
+
 /// Trait providing bi-directional conversion between polars_arrow [`Array`] and arrow-rs [`ArrayData`]
 ///
 /// [`ArrayData`]: arrow_data::ArrayData
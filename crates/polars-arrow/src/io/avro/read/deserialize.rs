@@ -74,6 +74,51 @@ fn is_union_null_first(avro_field: &AvroSchema) -> bool {
     }
 }
 
+/// Returns the schema of the items of an avro array or the values of an avro map, and whether
+/// it is a map.
+fn list_item_schema(avro_field: &AvroSchema) -> (&AvroSchema, bool) {
+    match avro_field {
+        AvroSchema::Array(inner) => (inner.as_ref(), false),
+        AvroSchema::Map(inner) => (inner.as_ref(), true),
+        AvroSchema::Union(u) => match &u.as_slice() {
+            &[AvroSchema::Array(inner), _] | &[_, AvroSchema::Array(inner)] => {
+                (inner.as_ref(), false)
+            },
+            &[AvroSchema::Map(inner), _] | &[_, AvroSchema::Map(inner)] => (inner.as_ref(), true),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+/// Deserializes a map entry, a string key followed by its value, into a key/value struct.
+fn deserialize_map_entry<'a>(
+    array: &mut dyn MutableArray,
+    avro_value: &AvroSchema,
+    mut block: &'a [u8],
+) -> PolarsResult<&'a [u8]> {
+    let ArrowDataType::Struct(fields) = array.data_type() else {
+        unreachable!()
+    };
+    let value_is_nullable = fields[1].is_nullable;
+    let array = array
+        .as_mut_any()
+        .downcast_mut::<DynMutableStructArray>()
+        .unwrap();
+
+    block = deserialize_value(array.mut_values(0), &AvroSchema::String(None), block)?;
+    block = deserialize_item(array.mut_values(1), value_is_nullable, avro_value, block)?;
+    array.try_push_valid()?;
+    Ok(block)
+}
+
+fn skip_bytes(mut block: &[u8]) -> PolarsResult<&[u8]> {
+    let len: usize = util::zigzag_i64(&mut block)?
+        .try_into()
+        .map_err(|_| polars_err!(oos = "Avro format contains a non-usize number of bytes"))?;
+    Ok(&block[len..])
+}
+
 fn deserialize_item<'a>(
     array: &mut dyn MutableArray,
     is_nullable: bool,
@@ -100,16 +145,7 @@ fn deserialize_value<'a>(
     match data_type {
         ArrowDataType::List(inner) => {
             let is_nullable = inner.is_nullable;
-            let avro_inner = match avro_field {
-                AvroSchema::Array(inner) => inner.as_ref(),
-                AvroSchema::Union(u) => match &u.as_slice() {
-                    &[AvroSchema::Array(inner), _] | &[_, AvroSchema::Array(inner)] => {
-                        inner.as_ref()
-                    },
-                    _ => unreachable!(),
-                },
-                _ => unreachable!(),
-            };
+            let (avro_inner, is_map) = list_item_schema(avro_field);
 
             let array = array
                 .as_mut_any()
@@ -137,7 +173,11 @@ fn deserialize_value<'a>(
                 // Each item is encoded per the array’s item schema.
                 let values = array.mut_values();
                 for _ in 0..len {
-                    block = deserialize_item(values, is_nullable, avro_inner, block)?;
+                    block = if is_map {
+                        deserialize_map_entry(values, avro_inner, block)?
+                    } else {
+                        deserialize_item(values, is_nullable, avro_inner, block)?
+                    };
                 }
             }
             array.try_push_valid()?;
@@ -333,16 +373,7 @@ fn skip_item<'a>(
     }
     match &field.data_type {
         ArrowDataType::List(inner) => {
-            let avro_inner = match avro_field {
-                AvroSchema::Array(inner) => inner.as_ref(),
-                AvroSchema::Union(u) => match &u.as_slice() {
-                    &[AvroSchema::Array(inner), _] | &[_, AvroSchema::Array(inner)] => {
-                        inner.as_ref()
-                    },
-                    _ => unreachable!(),
-                },
-                _ => unreachable!(),
-            };
+            let (avro_inner, is_map) = list_item_schema(avro_field);
 
             loop {
                 let len = util::zigzag_i64(&mut block)?;
@@ -370,6 +401,14 @@ fn skip_item<'a>(
 
                 if let Some(bytes) = bytes {
                     block = &block[bytes..];
+                } else if is_map {
+                    let ArrowDataType::Struct(entry_fields) = inner.data_type() else {
+                        unreachable!()
+                    };
+                    for _ in 0..len {
+                        block = skip_bytes(block)?;
+                        block = skip_item(&entry_fields[1], avro_inner, block)?;
+                    }
                 } else {
                     for _ in 0..len {
                         block = skip_item(inner, avro_inner, block)?;
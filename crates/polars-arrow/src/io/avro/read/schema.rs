@@ -90,7 +90,13 @@ fn schema_to_field(
             Some("item"), // default name for list items
             Metadata::default(),
         )?)),
-        AvroSchema::Map(_) => todo!("Avro maps are mapped to MapArrays"),
+        // Maps are read as a list of key/value structs; avro map keys are always strings.
+        AvroSchema::Map(value_schema) => {
+            let value = schema_to_field(value_schema, Some("value"), Metadata::default())?;
+            let entries =
+                ArrowDataType::Struct(vec![Field::new("key", ArrowDataType::Utf8, false), value]);
+            ArrowDataType::List(Box::new(Field::new("item", entries, false)))
+        },
         AvroSchema::Union(schemas) => {
             // If there are only two variants and one of them is null, set the other type as the field data type
             let has_nullable = schemas.iter().any(|x| x == &AvroSchema::Null);
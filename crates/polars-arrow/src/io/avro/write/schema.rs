@@ -83,6 +83,12 @@ fn _type_to_schema(data_type: &ArrowDataType, name_counter: &mut i32) -> PolarsR
         ArrowDataType::Timestamp(TimeUnit::Microsecond, None) => {
             AvroSchema::Long(Some(LongLogical::LocalTimestampMicros))
         },
+        ArrowDataType::Timestamp(TimeUnit::Millisecond, Some(_)) => {
+            AvroSchema::Long(Some(LongLogical::TimestampMillis))
+        },
+        ArrowDataType::Timestamp(TimeUnit::Microsecond, Some(_)) => {
+            AvroSchema::Long(Some(LongLogical::TimestampMicros))
+        },
         ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
             let mut fixed = Fixed::new("", 12);
             fixed.logical = Some(FixedLogical::Duration);
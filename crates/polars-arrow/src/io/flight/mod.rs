@@ -31,6 +31,7 @@ pub fn serialize_batch(
     let mut dictionary_tracker = DictionaryTracker {
         dictionaries: Default::default(),
         cannot_replace: false,
+        emit_deltas: false,
     };
 
     let (encoded_dictionaries, encoded_batch) =
@@ -110,6 +111,32 @@ pub fn deserialize_schemas(bytes: &[u8]) -> PolarsResult<(ArrowSchema, IpcSchema
     read::deserialize_schema(bytes)
 }
 
+/// Deserialize the schema of a [`arrow_format::flight::data::FlightInfo`], i.e. the inverse of
+/// [`serialize_schema_to_info`].
+pub fn deserialize_schemas_from_info(bytes: &[u8]) -> PolarsResult<(ArrowSchema, IpcSchema)> {
+    const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
+
+    let read_u32 = |bytes: &[u8]| -> PolarsResult<usize> {
+        let len: [u8; 4] = bytes
+            .get(..4)
+            .and_then(|b| b.try_into().ok())
+            .ok_or_else(|| polars_err!(oos = "flight schema is too short"))?;
+        Ok(u32::from_le_bytes(len) as usize)
+    };
+
+    // Legacy messages omit the continuation marker.
+    let bytes = if bytes.starts_with(&CONTINUATION_MARKER) {
+        &bytes[4..]
+    } else {
+        bytes
+    };
+    let length = read_u32(bytes)?;
+    let message = bytes
+        .get(4..4 + length)
+        .ok_or_else(|| polars_err!(oos = "flight schema is too short"))?;
+    deserialize_schemas(message)
+}
+
 /// Deserializes [`FlightData`] representing a record batch message to [`RecordBatchT`].
 pub fn deserialize_batch(
     data: &FlightData,
@@ -185,6 +212,18 @@ pub fn deserialize_message(
     fields: &[Field],
     ipc_schema: &IpcSchema,
     dictionaries: &mut Dictionaries,
+) -> PolarsResult<Option<RecordBatchT<Box<dyn Array>>>> {
+    deserialize_message_with_projection(data, fields, ipc_schema, None, dictionaries)
+}
+
+/// Like [`deserialize_message`], but only deserializes the columns in `projection`. Schema
+/// messages, as sent at the start of a `DoGet` stream, are skipped.
+pub fn deserialize_message_with_projection(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    projection: Option<&[usize]>,
+    dictionaries: &mut Dictionaries,
 ) -> PolarsResult<Option<RecordBatchT<Box<dyn Array>>>> {
     let FlightData {
         data_header,
@@ -206,7 +245,7 @@ pub fn deserialize_message(
                 batch,
                 fields,
                 ipc_schema,
-                None,
+                projection,
                 None,
                 dictionaries,
                 arrow_format::ipc::MetadataVersion::V5,
@@ -234,6 +273,7 @@ pub fn deserialize_message(
             )?;
             Ok(None)
         },
+        ipc::MessageHeaderRef::Schema(_) => Ok(None),
         t => polars_bail!(ComputeError:
             "Reading types other than record batches not yet supported, unable to read {t:?}"
         ),
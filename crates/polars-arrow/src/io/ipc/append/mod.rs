@@ -16,7 +16,8 @@ use super::write::*;
 impl<R: Read + Seek + Write> FileWriter<R> {
     /// Creates a new [`FileWriter`] from an existing file, seeking to the last message
     /// and appending new messages afterwards. Users call `finish` to write the footer (with both)
-    /// the existing and appended messages on it.
+    /// the existing and appended messages on it. The custom metadata of the existing footer is
+    /// kept.
     /// # Error
     /// This function errors iff:
     /// * the file's endianness is not the native endianness (not yet supported)
@@ -64,8 +65,10 @@ impl<R: Read + Seek + Write> FileWriter<R> {
             dictionary_tracker: DictionaryTracker {
                 dictionaries,
                 cannot_replace: true,
+                emit_deltas: false,
             },
             encoded_message: Default::default(),
+            custom_metadata: metadata.custom_metadata,
         })
     }
 }
@@ -30,10 +30,15 @@ pub fn decompress_zstd(_input_buf: &[u8], _output_buf: &mut [u8]) -> PolarsResul
 
 #[cfg(feature = "io_ipc_compression")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_compression")))]
-pub fn compress_lz4(input_buf: &[u8], output_buf: &mut Vec<u8>) -> PolarsResult<()> {
+pub fn compress_lz4(
+    input_buf: &[u8],
+    output_buf: &mut Vec<u8>,
+    level: Option<u32>,
+) -> PolarsResult<()> {
     use std::io::Write;
 
     let mut encoder = lz4::EncoderBuilder::new()
+        .level(level.unwrap_or(0))
         .build(output_buf)
         .map_err(to_compute_err)?;
     encoder.write_all(input_buf)?;
@@ -42,17 +47,29 @@ pub fn compress_lz4(input_buf: &[u8], output_buf: &mut Vec<u8>) -> PolarsResult<
 
 #[cfg(feature = "io_ipc_compression")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_compression")))]
-pub fn compress_zstd(input_buf: &[u8], output_buf: &mut Vec<u8>) -> PolarsResult<()> {
-    zstd::stream::copy_encode(input_buf, output_buf, 0).map_err(|e| e.into())
+pub fn compress_zstd(
+    input_buf: &[u8],
+    output_buf: &mut Vec<u8>,
+    level: Option<i32>,
+) -> PolarsResult<()> {
+    zstd::stream::copy_encode(input_buf, output_buf, level.unwrap_or(0)).map_err(|e| e.into())
 }
 
 #[cfg(not(feature = "io_ipc_compression"))]
-pub fn compress_lz4(_input_buf: &[u8], _output_buf: &[u8]) -> PolarsResult<()> {
+pub fn compress_lz4(
+    _input_buf: &[u8],
+    _output_buf: &[u8],
+    _level: Option<u32>,
+) -> PolarsResult<()> {
     panic!("The crate was compiled without IPC compression. Use `io_ipc_compression` to write compressed IPC.")
 }
 
 #[cfg(not(feature = "io_ipc_compression"))]
-pub fn compress_zstd(_input_buf: &[u8], _output_buf: &[u8]) -> PolarsResult<()> {
+pub fn compress_zstd(
+    _input_buf: &[u8],
+    _output_buf: &[u8],
+    _level: Option<i32>,
+) -> PolarsResult<()> {
     panic!("The crate was compiled without IPC compression. Use `io_ipc_compression` to write compressed IPC.")
 }
 
@@ -66,7 +83,7 @@ mod tests {
     fn round_trip_zstd() {
         let data: Vec<u8> = (0..200u8).map(|x| x % 10).collect();
         let mut buffer = vec![];
-        compress_zstd(&data, &mut buffer).unwrap();
+        compress_zstd(&data, &mut buffer, None).unwrap();
 
         let mut result = vec![0; 200];
         decompress_zstd(&buffer, &mut result).unwrap();
@@ -79,7 +96,7 @@ mod tests {
     fn round_trip_lz4() {
         let data: Vec<u8> = (0..200u8).map(|x| x % 10).collect();
         let mut buffer = vec![];
-        compress_lz4(&data, &mut buffer).unwrap();
+        compress_lz4(&data, &mut buffer, None).unwrap();
 
         let mut result = vec![0; 200];
         decompress_lz4(&buffer, &mut result).unwrap();
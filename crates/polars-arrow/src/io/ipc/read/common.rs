@@ -7,6 +7,7 @@ use polars_error::{polars_bail, polars_err, PolarsResult};
 use super::deserialize::{read, skip};
 use super::Dictionaries;
 use crate::array::*;
+use crate::compute::concatenate::concatenate;
 use crate::datatypes::{ArrowDataType, Field};
 use crate::io::ipc::read::OutOfSpecKind;
 use crate::io::ipc::{IpcField, IpcSchema};
@@ -253,12 +254,9 @@ pub fn read_dictionary<R: Read + Seek>(
     file_size: u64,
     scratch: &mut Vec<u8>,
 ) -> PolarsResult<()> {
-    if batch
+    let is_delta = batch
         .is_delta()
-        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferIsDelta(err)))?
-    {
-        polars_bail!(ComputeError: "delta dictionary batches not supported")
-    }
+        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferIsDelta(err)))?;
 
     let id = batch
         .id()
@@ -298,7 +296,15 @@ pub fn read_dictionary<R: Read + Seek>(
         scratch,
     )?;
 
-    dictionaries.insert(id, chunk.into_arrays().pop().unwrap());
+    let mut values = chunk.into_arrays().pop().unwrap();
+    if is_delta {
+        // A delta adds values to the end of the dictionary that was read before.
+        let Some(previous) = dictionaries.get(&id) else {
+            polars_bail!(ComputeError: "delta dictionary batch for dictionary {} that was not read yet", id)
+        };
+        values = concatenate(&[previous.as_ref(), values.as_ref()])?;
+    }
+    dictionaries.insert(id, values);
 
     Ok(())
 }
@@ -11,7 +11,7 @@ use super::common::*;
 use super::schema::fb_to_schema;
 use super::{Dictionaries, OutOfSpecKind};
 use crate::array::Array;
-use crate::datatypes::ArrowSchemaRef;
+use crate::datatypes::{ArrowSchemaRef, Metadata};
 use crate::io::ipc::IpcSchema;
 use crate::record_batch::RecordBatchT;
 
@@ -34,6 +34,9 @@ pub struct FileMetadata {
 
     /// The total size of the file in bytes
     pub size: u64,
+
+    /// The custom metadata of the footer
+    pub custom_metadata: Metadata,
 }
 
 /// Read the row count by summing the length of the of the record batches
@@ -211,12 +214,26 @@ pub fn deserialize_footer(footer_data: &[u8], size: u64) -> PolarsResult<FileMet
         })
         .transpose()?;
 
+    let mut custom_metadata = Metadata::new();
+    if let Some(list) = footer
+        .custom_metadata()
+        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferFooter(err)))?
+    {
+        for kv in list {
+            let kv = kv?;
+            if let (Some(k), Some(v)) = (kv.key()?, kv.value()?) {
+                custom_metadata.insert(k.to_string(), v.to_string());
+            }
+        }
+    }
+
     Ok(FileMetadata {
         schema: Arc::new(schema),
         ipc_schema,
         blocks,
         dictionaries,
         size,
+        custom_metadata,
     })
 }
 
@@ -93,6 +93,55 @@ fn read_uncompressed_buffer<T: NativeType, R: Read + Seek>(
     Ok(buffer)
 }
 
+/// The uncompressed length that is written in front of a compressed buffer when the buffer is
+/// stored without compression.
+const UNCOMPRESSED_MARKER: i64 = -1;
+
+/// Decompress `data`, a buffer that starts with its uncompressed length, into `out`.
+fn decompress_buffer(
+    data: &[u8],
+    out: &mut [u8],
+    type_name: &'static str,
+    compression: Compression,
+) -> PolarsResult<()> {
+    if data.len() < 8 {
+        polars_bail!(
+            oos = OutOfSpecKind::InvalidBuffer {
+                length: out.len(),
+                type_name,
+                required_number_of_bytes: 8,
+                buffer_length: data.len(),
+            }
+        )
+    }
+    if i64::from_le_bytes(data[..8].try_into().unwrap()) == UNCOMPRESSED_MARKER {
+        let raw = &data[8..];
+        if raw.len() < out.len() {
+            polars_bail!(
+                oos = OutOfSpecKind::InvalidBuffer {
+                    length: out.len(),
+                    type_name,
+                    required_number_of_bytes: out.len(),
+                    buffer_length: raw.len(),
+                }
+            )
+        }
+        out.copy_from_slice(&raw[..out.len()]);
+        return Ok(());
+    }
+
+    let compression = compression
+        .codec()
+        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferCompression(err)))?;
+
+    match compression {
+        arrow_format::ipc::CompressionType::Lz4Frame => {
+            compression::decompress_lz4(&data[8..], out)
+        },
+        arrow_format::ipc::CompressionType::Zstd => compression::decompress_zstd(&data[8..], out),
+    }
+}
+
 fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
     reader: &mut R,
     buffer_length: usize,
@@ -101,7 +150,8 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
     compression: Compression,
     scratch: &mut Vec<u8>,
 ) -> PolarsResult<Vec<T>> {
-    if output_length == Some(0) {
+    // Empty buffers are not compressed.
+    if output_length.unwrap_or(buffer_length) == 0 {
         return Ok(vec![]);
     }
 
@@ -119,27 +169,19 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
         .take(buffer_length as u64)
         .read_to_end(scratch)?;
 
-    let length = output_length
-        .unwrap_or_else(|| i64::from_le_bytes(scratch[..8].try_into().unwrap()) as usize);
+    let length = output_length.unwrap_or_else(|| {
+        match i64::from_le_bytes(scratch[..8].try_into().unwrap()) {
+            UNCOMPRESSED_MARKER => (scratch.len() - 8) / std::mem::size_of::<T>(),
+            length => length as usize,
+        }
+    });
 
     // It is undefined behavior to call read_exact on un-initialized, https://doc.rust-lang.org/std/io/trait.Read.html#tymethod.read
     // see also https://github.com/MaikKlein/ash/issues/354#issue-781730580
     let mut buffer = vec![T::default(); length];
 
     let out_slice = bytemuck::cast_slice_mut(&mut buffer);
-
-    let compression = compression
-        .codec()
-        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferCompression(err)))?;
-
-    match compression {
-        arrow_format::ipc::CompressionType::Lz4Frame => {
-            compression::decompress_lz4(&scratch[8..], out_slice)?;
-        },
-        arrow_format::ipc::CompressionType::Zstd => {
-            compression::decompress_zstd(&scratch[8..], out_slice)?;
-        },
-    }
+    decompress_buffer(scratch, out_slice, std::any::type_name::<T>(), compression)?;
     Ok(buffer)
 }
 
@@ -275,18 +317,7 @@ fn read_compressed_bitmap<R: Read + Seek>(
     scratch.try_reserve(bytes)?;
     reader.by_ref().take(bytes as u64).read_to_end(scratch)?;
 
-    let compression = compression
-        .codec()
-        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferCompression(err)))?;
-
-    match compression {
-        arrow_format::ipc::CompressionType::Lz4Frame => {
-            compression::decompress_lz4(&scratch[8..], &mut buffer)?;
-        },
-        arrow_format::ipc::CompressionType::Zstd => {
-            compression::decompress_zstd(&scratch[8..], &mut buffer)?;
-        },
-    }
+    decompress_buffer(scratch, &mut buffer, "bitmap", compression)?;
     Ok(buffer)
 }
 
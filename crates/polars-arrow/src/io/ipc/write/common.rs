@@ -3,8 +3,8 @@ use std::borrow::{Borrow, Cow};
 use arrow_format::ipc::planus::Builder;
 use polars_error::{polars_bail, polars_err, PolarsResult};
 
-use super::super::IpcField;
-use super::{write, write_dictionary};
+use super::super::{compression, IpcField};
+use super::{write, write_uncompressed};
 use crate::array::*;
 use crate::datatypes::*;
 use crate::io::ipc::endianness::is_native_little_endian;
@@ -13,21 +13,34 @@ use crate::legacy::prelude::LargeListArray;
 use crate::match_integer_type;
 use crate::record_batch::RecordBatchT;
 
-/// Compression codec
+/// Compression codec, with the compression level or `None` for the default level.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Compression {
     /// LZ4 (framed)
-    LZ4,
+    LZ4(Option<u32>),
     /// ZSTD
-    ZSTD,
+    ZSTD(Option<i32>),
+}
+
+impl Compression {
+    #[cfg_attr(not(feature = "io_ipc_compression"), allow(clippy::ptr_arg))]
+    pub(super) fn compress(self, input_buf: &[u8], output_buf: &mut Vec<u8>) -> PolarsResult<()> {
+        match self {
+            Self::LZ4(level) => compression::compress_lz4(input_buf, output_buf, level),
+            Self::ZSTD(level) => compression::compress_zstd(input_buf, output_buf, level),
+        }
+    }
 }
 
 /// Options declaring the behaviour of writing to IPC
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub struct WriteOptions {
     /// Whether the buffers should be compressed and which codec to use.
     /// Note: to use compression the crate must be compiled with feature `io_ipc_compression`.
     pub compression: Option<Compression>,
+    /// The indices of the columns whose buffers are not compressed, e.g. because they don't
+    /// compress well. Only applies if `compression` is set.
+    pub uncompressed_columns: Vec<usize>,
 }
 
 fn encode_dictionary(
@@ -56,10 +69,16 @@ fn encode_dictionary(
                 encoded_dictionaries
             )?;
 
-            if emit {
-                encoded_dictionaries.push(dictionary_batch_to_bytes::<$T>(
+            let delta = match emit {
+                DictionaryEmit::Skip => None,
+                DictionaryEmit::Full => Some(values.clone()),
+                DictionaryEmit::Delta(offset) => Some(values.sliced(offset, values.len() - offset)),
+            };
+            if let Some(values) = delta {
+                encoded_dictionaries.push(dictionary_batch_to_bytes(
                     dict_id,
-                    array,
+                    values.as_ref(),
+                    matches!(emit, DictionaryEmit::Delta(_)),
                     options,
                     is_native_little_endian(),
                 ));
@@ -218,8 +237,8 @@ fn serialize_compression(
 ) -> Option<Box<arrow_format::ipc::BodyCompression>> {
     if let Some(compression) = compression {
         let codec = match compression {
-            Compression::LZ4 => arrow_format::ipc::CompressionType::Lz4Frame,
-            Compression::ZSTD => arrow_format::ipc::CompressionType::Zstd,
+            Compression::LZ4(_) => arrow_format::ipc::CompressionType::Lz4Frame,
+            Compression::ZSTD(_) => arrow_format::ipc::CompressionType::Zstd,
         };
         Some(Box::new(arrow_format::ipc::BodyCompression {
             codec,
@@ -279,7 +298,7 @@ fn chunk_to_bytes_amortized(
 
     let mut offset = 0;
     let mut variadic_buffer_counts = vec![];
-    for array in chunk.arrays() {
+    for (i, array) in chunk.arrays().iter().enumerate() {
         // We don't want to write all buffers in sliced arrays.
         let array = match array.data_type() {
             ArrowDataType::BinaryView => {
@@ -304,15 +323,26 @@ fn chunk_to_bytes_amortized(
 
         set_variadic_buffer_counts(&mut variadic_buffer_counts, array);
 
-        write(
-            array,
-            &mut buffers,
-            &mut arrow_data,
-            &mut nodes,
-            &mut offset,
-            is_native_little_endian(),
-            options.compression,
-        )
+        if options.compression.is_some() && options.uncompressed_columns.contains(&i) {
+            write_uncompressed(
+                array,
+                &mut buffers,
+                &mut arrow_data,
+                &mut nodes,
+                &mut offset,
+                is_native_little_endian(),
+            )
+        } else {
+            write(
+                array,
+                &mut buffers,
+                &mut arrow_data,
+                &mut nodes,
+                &mut offset,
+                is_native_little_endian(),
+                options.compression,
+            )
+        }
     }
 
     let variadic_buffer_counts = if variadic_buffer_counts.is_empty() {
@@ -346,9 +376,10 @@ fn chunk_to_bytes_amortized(
 
 /// Write dictionary values into two sets of bytes, one for the header (ipc::Schema::Message) and the
 /// other for the data
-fn dictionary_batch_to_bytes<K: DictionaryKey>(
+fn dictionary_batch_to_bytes(
     dict_id: i64,
-    array: &DictionaryArray<K>,
+    values: &dyn Array,
+    is_delta: bool,
     options: &WriteOptions,
     is_little_endian: bool,
 ) -> EncodedData {
@@ -356,7 +387,7 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
     let mut buffers: Vec<arrow_format::ipc::Buffer> = vec![];
     let mut arrow_data: Vec<u8> = vec![];
     let mut variadic_buffer_counts = vec![];
-    set_variadic_buffer_counts(&mut variadic_buffer_counts, array.values().as_ref());
+    set_variadic_buffer_counts(&mut variadic_buffer_counts, values);
 
     let variadic_buffer_counts = if variadic_buffer_counts.is_empty() {
         None
@@ -364,16 +395,16 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
         Some(variadic_buffer_counts)
     };
 
-    let length = write_dictionary(
-        array,
+    write(
+        values,
         &mut buffers,
         &mut arrow_data,
         &mut nodes,
         &mut 0,
         is_little_endian,
         options.compression,
-        false,
     );
+    let length = values.len();
 
     let compression = serialize_compression(options.compression);
 
@@ -389,7 +420,7 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
                     compression,
                     variadic_buffer_counts,
                 })),
-                is_delta: false,
+                is_delta,
             },
         ))),
         body_length: arrow_data.len() as i64,
@@ -405,25 +436,42 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
     }
 }
 
+/// What has to be written for a dictionary, see [`DictionaryTracker::insert`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DictionaryEmit {
+    /// The dictionary was written already.
+    Skip,
+    /// Write all values of the dictionary.
+    Full,
+    /// Write the values from this offset on, as a delta to the written dictionary.
+    Delta(usize),
+}
+
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
 /// multiple times. Can optionally error if an update to an existing dictionary is attempted, which
 /// isn't allowed in the `FileWriter`.
 pub struct DictionaryTracker {
     pub dictionaries: Dictionaries,
     pub cannot_replace: bool,
+    /// Whether a dictionary that extends the written one is emitted as a delta that only holds
+    /// the new values.
+    pub emit_deltas: bool,
 }
 
 impl DictionaryTracker {
     /// Keep track of the dictionary with the given ID and values. Behavior:
     ///
-    /// * If this ID has been written already and has the same data, return `Ok(false)` to indicate
-    ///   that the dictionary was not actually inserted (because it's already been seen).
+    /// * If this ID has been written already and has the same data, return
+    ///   [`DictionaryEmit::Skip`] to indicate that the dictionary was not actually inserted
+    ///   (because it's already been seen).
+    /// * If this tracker emits deltas and the written dictionary is a prefix of the new one,
+    ///   return [`DictionaryEmit::Delta`] with the number of written values.
     /// * If this ID has been written already but with different data, and this tracker is
     ///   configured to return an error, return an error.
     /// * If the tracker has not been configured to error on replacement or this dictionary
-    ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
-    ///   inserted.
-    pub fn insert(&mut self, dict_id: i64, array: &dyn Array) -> PolarsResult<bool> {
+    ///   has never been seen before, return [`DictionaryEmit::Full`] to indicate that the
+    ///   dictionary was just inserted.
+    pub fn insert(&mut self, dict_id: i64, array: &dyn Array) -> PolarsResult<DictionaryEmit> {
         let values = match array.data_type() {
             ArrowDataType::Dictionary(key_type, _, _) => {
                 match_integer_type!(key_type, |$T| {
@@ -438,10 +486,16 @@ impl DictionaryTracker {
         };
 
         // If a dictionary with this id was already emitted, check if it was the same.
+        let mut emit = DictionaryEmit::Full;
         if let Some(last) = self.dictionaries.get(&dict_id) {
             if last.as_ref() == values.as_ref() {
                 // Same dictionary values => no need to emit it again
-                return Ok(false);
+                return Ok(DictionaryEmit::Skip);
+            } else if self.emit_deltas
+                && values.len() > last.len()
+                && values.sliced(0, last.len()).as_ref() == last.as_ref()
+            {
+                emit = DictionaryEmit::Delta(last.len());
             } else if self.cannot_replace {
                 polars_bail!(InvalidOperation:
                     "Dictionary replacement detected when writing IPC file format. \
@@ -452,7 +506,7 @@ impl DictionaryTracker {
         };
 
         self.dictionaries.insert(dict_id, values.clone());
-        Ok(true)
+        Ok(emit)
     }
 }
 
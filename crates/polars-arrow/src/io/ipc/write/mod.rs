@@ -7,8 +7,7 @@ pub(crate) mod writer;
 
 pub use common::{Compression, Record, WriteOptions};
 pub use schema::schema_to_bytes;
-pub use serialize::write;
-use serialize::write_dictionary;
+pub use serialize::{write, write_uncompressed};
 pub use stream::StreamWriter;
 pub use writer::FileWriter;
 
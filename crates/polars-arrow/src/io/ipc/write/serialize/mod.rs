@@ -1,7 +1,6 @@
 #![allow(clippy::ptr_arg)] // false positive in clippy, see https://github.com/rust-lang/rust-clippy/issues/8463
 use arrow_format::ipc;
 
-use super::super::compression;
 use super::super::endianness::is_native_little_endian;
 use super::common::{pad_to_64, Compression};
 use crate::array::*;
@@ -193,6 +192,48 @@ pub fn write(
     }
 }
 
+/// Writes an [`Array`] to `arrow_data` without compression, as a column of a record batch whose
+/// other columns are compressed.
+///
+/// Each buffer starts with an uncompressed length of -1 instead of its length, which the IPC
+/// format defines as a buffer that is stored without compression.
+pub fn write_uncompressed(
+    array: &dyn Array,
+    buffers: &mut Vec<ipc::Buffer>,
+    arrow_data: &mut Vec<u8>,
+    nodes: &mut Vec<ipc::FieldNode>,
+    offset: &mut i64,
+    is_little_endian: bool,
+) {
+    let mut raw_buffers = vec![];
+    let mut raw_data = vec![];
+    write(
+        array,
+        &mut raw_buffers,
+        &mut raw_data,
+        nodes,
+        &mut 0,
+        is_little_endian,
+        None,
+    );
+
+    for buffer in raw_buffers {
+        // Empty buffers have no compression and thus no length in front of them.
+        if buffer.length == 0 {
+            buffers.push(ipc::Buffer {
+                offset: *offset,
+                length: 0,
+            });
+            continue;
+        }
+        let bytes = &raw_data[buffer.offset as usize..(buffer.offset + buffer.length) as usize];
+        let start = arrow_data.len();
+        arrow_data.extend_from_slice(&(-1i64).to_le_bytes());
+        arrow_data.extend_from_slice(bytes);
+        buffers.push(finish_buffer(arrow_data, start, offset));
+    }
+}
+
 #[inline]
 fn pad_buffer_to_64(buffer: &mut Vec<u8>, length: usize) {
     let pad_len = pad_to_64(length);
@@ -212,14 +253,7 @@ fn write_bytes(
     let start = arrow_data.len();
     if let Some(compression) = compression {
         arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
-            Compression::LZ4 => {
-                compression::compress_lz4(bytes, arrow_data).unwrap();
-            },
-            Compression::ZSTD => {
-                compression::compress_zstd(bytes, arrow_data).unwrap();
-            },
-        }
+        compression.compress(bytes, arrow_data).unwrap();
     } else {
         arrow_data.extend_from_slice(bytes);
     };
@@ -314,14 +348,7 @@ fn _write_compressed_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
             .for_each(|x| swapped.extend_from_slice(x.as_ref()))
     };
     arrow_data.extend_from_slice(&(swapped.len() as i64).to_le_bytes());
-    match compression {
-        Compression::LZ4 => {
-            compression::compress_lz4(&swapped, arrow_data).unwrap();
-        },
-        Compression::ZSTD => {
-            compression::compress_zstd(&swapped, arrow_data).unwrap();
-        },
-    }
+    compression.compress(&swapped, arrow_data).unwrap();
 }
 
 fn _write_buffer<T: NativeType>(buffer: &[T], arrow_data: &mut Vec<u8>, is_little_endian: bool) {
@@ -343,14 +370,7 @@ fn _write_compressed_buffer<T: NativeType>(
     if is_little_endian == is_native_little_endian() {
         let bytes = bytemuck::cast_slice(buffer);
         arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
-            Compression::LZ4 => {
-                compression::compress_lz4(bytes, arrow_data).unwrap();
-            },
-            Compression::ZSTD => {
-                compression::compress_zstd(bytes, arrow_data).unwrap();
-            },
-        }
+        compression.compress(bytes, arrow_data).unwrap();
     } else {
         todo!()
     }
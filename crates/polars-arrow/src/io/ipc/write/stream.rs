@@ -44,11 +44,19 @@ impl<W: Write> StreamWriter<W> {
             dictionary_tracker: DictionaryTracker {
                 dictionaries: Default::default(),
                 cannot_replace: false,
+                emit_deltas: false,
             },
             ipc_fields: None,
         }
     }
 
+    /// Emit a dictionary that extends the previously written dictionary of its field as a delta
+    /// batch holding only the new values, instead of writing the whole dictionary again.
+    pub fn with_dictionary_deltas(mut self, emit_deltas: bool) -> Self {
+        self.dictionary_tracker.emit_deltas = emit_deltas;
+        self
+    }
+
     /// Starts the stream by writing a Schema message to it.
     /// Use `ipc_fields` to declare dictionary ids in the schema, for dictionary-reuse
     pub fn start(
@@ -45,6 +45,7 @@ where
             dictionary_tracker: DictionaryTracker {
                 dictionaries: Default::default(),
                 cannot_replace: false,
+                emit_deltas: false,
             },
             options: write_options,
         }
@@ -40,6 +40,8 @@ pub struct FileWriter<W: Write> {
     pub(crate) dictionary_tracker: DictionaryTracker,
     /// Buffer/scratch that is reused between writes
     pub(crate) encoded_message: EncodedData,
+    /// Custom metadata that will be written as part of the IPC footer
+    pub(crate) custom_metadata: Metadata,
 }
 
 impl<W: Write> FileWriter<W> {
@@ -81,8 +83,10 @@ impl<W: Write> FileWriter<W> {
             dictionary_tracker: DictionaryTracker {
                 dictionaries: Default::default(),
                 cannot_replace: true,
+                emit_deltas: false,
             },
             encoded_message: Default::default(),
+            custom_metadata: Default::default(),
         }
     }
 
@@ -102,6 +106,11 @@ impl<W: Write> FileWriter<W> {
         self.encoded_message = scratches;
     }
 
+    /// Set the custom metadata of the footer, which is written when the writer is finished.
+    pub fn set_custom_metadata(&mut self, custom_metadata: Metadata) {
+        self.custom_metadata = custom_metadata;
+    }
+
     /// Writes the header and first (schema) message to the file.
     /// # Errors
     /// Errors if the file has been started or has finished.
@@ -188,13 +197,18 @@ impl<W: Write> FileWriter<W> {
         write_continuation(&mut self.writer, 0)?;
 
         let schema = schema::serialize_schema(&self.schema, &self.ipc_fields);
+        let custom_metadata = self
+            .custom_metadata
+            .iter()
+            .map(|(k, v)| schema::key_value(k, v))
+            .collect::<Vec<_>>();
 
         let root = arrow_format::ipc::Footer {
             version: arrow_format::ipc::MetadataVersion::V5,
             schema: Some(Box::new(schema)),
             dictionaries: Some(std::mem::take(&mut self.dictionary_blocks)),
             record_batches: Some(std::mem::take(&mut self.record_blocks)),
-            custom_metadata: None,
+            custom_metadata: (!custom_metadata.is_empty()).then_some(custom_metadata),
         };
         let mut builder = Builder::new();
         let footer_data = builder.finish(&root, None);
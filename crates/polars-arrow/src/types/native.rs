@@ -602,18 +602,20 @@ impl TotalEq for f16 {
 
 impl TotalOrd for f16 {
     #[inline]
-    fn tot_cmp(&self, _other: &Self) -> std::cmp::Ordering {
-        unimplemented!()
+    fn tot_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.to_f32().tot_cmp(&other.to_f32())
     }
 }
 
 impl MinMax for f16 {
-    fn nan_min_lt(&self, _other: &Self) -> bool {
-        unimplemented!()
+    #[inline]
+    fn nan_min_lt(&self, other: &Self) -> bool {
+        self.to_f32().nan_min_lt(&other.to_f32())
     }
 
-    fn nan_max_lt(&self, _other: &Self) -> bool {
-        unimplemented!()
+    #[inline]
+    fn nan_max_lt(&self, other: &Self) -> bool {
+        self.to_f32().nan_max_lt(&other.to_f32())
     }
 }
 
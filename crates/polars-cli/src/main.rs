@@ -0,0 +1,141 @@
+//! `polars`: run SQL queries and serialized plans on files from the command line.
+//!
+//! ```text
+//! polars sql "SELECT city, avg(temp) FROM read_parquet('weather/*.parquet') GROUP BY city"
+//! polars sql -t trips=trips.csv "SELECT count(*) FROM trips" -o counts.parquet
+//! polars plan query.json --explain
+//! ```
+mod output;
+mod source;
+
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+use polars::error::to_compute_err;
+use polars::prelude::*;
+use polars::sql::SQLContext;
+
+use crate::output::OutputFormat;
+
+#[derive(Parser)]
+#[command(name = "polars", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a SQL query. Files can be queried with table functions such as
+    /// `read_parquet('path')` or registered as tables with `--table`.
+    Sql {
+        /// The query, read from stdin if it is `-`.
+        query: String,
+        /// Register a file as a table, as `name=path` or `path` to name it after the file.
+        /// The format follows from the extension of the file.
+        #[arg(short, long = "table", value_name = "NAME=PATH", value_parser = source::parse_table)]
+        tables: Vec<(String, String)>,
+    },
+    /// Run a plan serialized with `LazyFrame::serialize_plan`, as JSON if the file ends with
+    /// `.json` and binary otherwise.
+    Plan {
+        /// The serialized plan, read from stdin if it is `-`.
+        path: PathBuf,
+    },
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Write the result to this file instead of printing it.
+    #[arg(short, long, global = true)]
+    output: Option<PathBuf>,
+    /// The format of the result. Defaults to the extension of `--output`, or a table.
+    #[arg(short, long, global = true, value_enum)]
+    format: Option<OutputFormat>,
+    /// Print the optimized plan instead of running the query.
+    #[arg(long, global = true)]
+    explain: bool,
+    /// Print the time spent in every node of the plan after running the query.
+    #[arg(long, global = true, conflicts_with = "explain")]
+    profile: bool,
+    /// Run the query with the streaming engine.
+    #[arg(long, global = true)]
+    streaming: bool,
+}
+
+fn read_input(path: &str) -> PolarsResult<Vec<u8>> {
+    let mut buf = vec![];
+    if path == "-" {
+        std::io::stdin().read_to_end(&mut buf)?;
+    } else {
+        File::open(path)?.read_to_end(&mut buf)?;
+    }
+    Ok(buf)
+}
+
+fn query(command: &Command) -> PolarsResult<LazyFrame> {
+    match command {
+        Command::Sql { query, tables } => {
+            let mut ctx = SQLContext::new();
+            for (name, path) in tables {
+                ctx.register(name, source::scan(path)?);
+            }
+            let query = if query == "-" {
+                String::from_utf8(read_input(query)?).map_err(to_compute_err)?
+            } else {
+                query.clone()
+            };
+            ctx.execute(&query)
+        },
+        Command::Plan { path } => {
+            let format = match path.extension().and_then(|ext| ext.to_str()) {
+                Some("json") => PlanSerializationFormat::Json,
+                _ => PlanSerializationFormat::Binary,
+            };
+            let plan = read_input(&path.to_string_lossy())?;
+            LazyFrame::from_serialized_plan(plan.as_slice(), format)
+        },
+    }
+}
+
+fn run(cli: Cli) -> PolarsResult<()> {
+    let args = cli.run;
+    let lf = query(&cli.command)?.with_streaming(args.streaming);
+    if args.explain {
+        println!("{}", lf.explain(true)?);
+        return Ok(());
+    }
+
+    let format = match (args.format, &args.output) {
+        (Some(format), _) => format,
+        (None, Some(path)) => OutputFormat::from_path(path)?,
+        (None, None) => OutputFormat::Table,
+    };
+    let (mut df, profile) = if args.profile {
+        let (df, profile) = lf.profile()?;
+        (df, Some(profile))
+    } else {
+        (lf.collect()?, None)
+    };
+    output::write(&mut df, format, args.output.as_deref())?;
+    if let Some(profile) = profile {
+        // Keep stdout for the result.
+        eprintln!("{profile}");
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        },
+    }
+}
@@ -0,0 +1,94 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use clap::ValueEnum;
+use polars::prelude::*;
+use polars_core::fmt::RenderOptions;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum OutputFormat {
+    /// The table of the `Display` of a `DataFrame`.
+    Table,
+    Markdown,
+    Html,
+    Csv,
+    /// A JSON array of rows.
+    Json,
+    /// A JSON object per row.
+    Ndjson,
+    Parquet,
+    Ipc,
+}
+
+impl OutputFormat {
+    pub(crate) fn from_path(path: &Path) -> PolarsResult<Self> {
+        let ext = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        Ok(match ext.as_str() {
+            "txt" => Self::Table,
+            "md" => Self::Markdown,
+            "html" => Self::Html,
+            "csv" => Self::Csv,
+            "json" => Self::Json,
+            "ndjson" | "jsonl" => Self::Ndjson,
+            "parquet" | "pq" => Self::Parquet,
+            "ipc" | "arrow" | "feather" => Self::Ipc,
+            _ => polars_bail!(
+                InvalidOperation: "cannot infer the output format of '{}', set it with `--format`",
+                path.display()
+            ),
+        })
+    }
+}
+
+/// Write `df` in `format` to the file at `path`, or to stdout.
+pub(crate) fn write(
+    df: &mut DataFrame,
+    format: OutputFormat,
+    path: Option<&Path>,
+) -> PolarsResult<()> {
+    let mut out: Box<dyn Write> = match path {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(std::io::stdout().lock()),
+    };
+    // Tables are rendered in full, only the terminal output is truncated.
+    let options = RenderOptions {
+        max_rows: Some(usize::MAX),
+        max_cols: Some(usize::MAX),
+        ..Default::default()
+    };
+    match format {
+        OutputFormat::Table => writeln!(out, "{df}")?,
+        OutputFormat::Markdown => write!(out, "{}", df.to_markdown(&options))?,
+        OutputFormat::Html => writeln!(out, "{}", df.to_html(&options))?,
+        OutputFormat::Csv => CsvWriter::new(&mut out).finish(df)?,
+        OutputFormat::Json => JsonWriter::new(&mut out)
+            .with_json_format(JsonFormat::Json)
+            .finish(df)?,
+        OutputFormat::Ndjson => JsonWriter::new(&mut out).finish(df)?,
+        OutputFormat::Parquet => {
+            ParquetWriter::new(&mut out).finish(df)?;
+        },
+        OutputFormat::Ipc => IpcWriter::new(&mut out).finish(df)?,
+    }
+    out.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_output_format_from_path() {
+        let format = |path: &str| OutputFormat::from_path(Path::new(path)).ok();
+        assert_eq!(format("out/result.PARQUET"), Some(OutputFormat::Parquet));
+        assert_eq!(format("result.jsonl"), Some(OutputFormat::Ndjson));
+        assert_eq!(format("README.md"), Some(OutputFormat::Markdown));
+        assert_eq!(format("result"), None);
+    }
+}
@@ -0,0 +1,70 @@
+use std::path::Path;
+
+use polars::prelude::*;
+
+/// Parse a `--table` argument, `name=path` or a `path` whose file stem is the name.
+pub(crate) fn parse_table(arg: &str) -> Result<(String, String), String> {
+    if let Some((name, path)) = arg.split_once('=') {
+        if name.is_empty() || path.is_empty() {
+            return Err(format!("expected `name=path`, got '{arg}'"));
+        }
+        return Ok((name.to_string(), path.to_string()));
+    }
+    // The stem of `data/*.parquet` would be `*`.
+    let stem = Path::new(arg)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .filter(|stem| !stem.contains(['*', '?', '[']));
+    match stem {
+        Some(stem) => Ok((stem.to_string(), arg.to_string())),
+        None => Err(format!(
+            "cannot name a table after '{arg}', use `name=path`"
+        )),
+    }
+}
+
+/// Scan a local or cloud file, or a glob of files, in the format of its extension.
+pub(crate) fn scan(path: &str) -> PolarsResult<LazyFrame> {
+    let ext = Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    match ext.as_str() {
+        "parquet" | "pq" => LazyFrame::scan_parquet(path, ScanArgsParquet::default()),
+        "csv" => LazyCsvReader::new(path).finish(),
+        "tsv" => LazyCsvReader::new(path).with_separator(b'\t').finish(),
+        "ipc" | "arrow" | "feather" => LazyFrame::scan_ipc(path, ScanArgsIpc::default()),
+        "ndjson" | "jsonl" => LazyJsonLineReader::new(path).finish(),
+        _ => polars_bail!(
+            InvalidOperation: "cannot infer the format of '{}' from its extension, expected one \
+            of parquet, csv, tsv, ipc, arrow, feather, ndjson or jsonl", path
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_table() {
+        let table = |name: &str, path: &str| -> Result<_, String> {
+            Ok((name.to_string(), path.to_string()))
+        };
+        assert_eq!(
+            parse_table("trips=data/trips.csv"),
+            table("trips", "data/trips.csv")
+        );
+        assert_eq!(
+            parse_table("data/trips.csv"),
+            table("trips", "data/trips.csv")
+        );
+        assert_eq!(
+            parse_table("s3://bucket/trips.parquet"),
+            table("trips", "s3://bucket/trips.parquet")
+        );
+        assert!(parse_table("data/*.csv").is_err());
+        assert!(parse_table("=data/trips.csv").is_err());
+    }
+}
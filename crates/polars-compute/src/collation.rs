@@ -0,0 +1,93 @@
+//! Locale-aware ordering of strings, following the Unicode Collation Algorithm with the tailorings
+//! of the CLDR.
+use std::cmp::Ordering;
+
+use arrow::array::{Array, PrimitiveArray, Utf8ViewArray};
+use icu_collator::{CaseLevel, Collator, CollatorOptions, Numeric, Strength};
+use icu_locid::extensions::unicode::key;
+use icu_locid::Locale;
+use polars_error::{polars_bail, polars_err, PolarsResult};
+use polars_utils::aliases::PlHashMap;
+use polars_utils::IdxSize;
+
+/// Create a collator for a BCP 47 language tag, e.g. `de-AT`.
+///
+/// Like the ICU collations of databases, the options are set with the Unicode extension keywords
+/// of the tag:
+/// - `ks`: the strength, `level1` ignores accents and case, `level2` ignores case and `level3`
+///   (the default) distinguishes both.
+/// - `kc`: distinguish case, even if the strength ignores accents.
+/// - `kn`: order sequences of digits by their numeric value.
+///
+/// For example `de-AT-u-ks-level2` is a case-insensitive Austrian German collation.
+pub fn collator(tag: &str) -> PolarsResult<Collator> {
+    let locale: Locale = tag
+        .parse()
+        .map_err(|_| polars_err!(InvalidOperation: "invalid collation locale: '{}'", tag))?;
+
+    let keywords = &locale.extensions.unicode.keywords;
+    // A keyword with the value `true` is stored without a value.
+    let is_on = |value: Option<String>| matches!(value.as_deref(), Some("" | "true"));
+
+    let mut options = CollatorOptions::new();
+    if let Some(strength) = keywords.get(&key!("ks")).map(|v| v.to_string()) {
+        options.strength = Some(match strength.as_str() {
+            "level1" => Strength::Primary,
+            "level2" => Strength::Secondary,
+            "level3" => Strength::Tertiary,
+            "level4" => Strength::Quaternary,
+            "identic" => Strength::Identical,
+            _ => polars_bail!(
+                InvalidOperation: "invalid collation strength '{}' in '{}'", strength, tag
+            ),
+        });
+    }
+    if is_on(keywords.get(&key!("kc")).map(|v| v.to_string())) {
+        options.case_level = Some(CaseLevel::On);
+    }
+    if is_on(keywords.get(&key!("kn")).map(|v| v.to_string())) {
+        options.numeric = Some(Numeric::On);
+    }
+
+    Collator::try_new(&(&locale).into(), options)
+        .map_err(|e| polars_err!(InvalidOperation: "cannot create collation '{}': {}", tag, e))
+}
+
+/// Compute collation keys for the strings of all `arrays`.
+///
+/// The keys are dense integers with the order of the collation: strings that are equal under the
+/// collation get the same key. The keys are only comparable between the arrays of one call.
+pub fn collation_keys(
+    arrays: &[&Utf8ViewArray],
+    collator: &Collator,
+) -> Vec<PrimitiveArray<IdxSize>> {
+    // Comparing strings under a collation is expensive, so only the distinct strings are sorted.
+    let mut keys = PlHashMap::<&str, IdxSize>::default();
+    for arr in arrays {
+        for s in arr.iter().flatten() {
+            keys.entry(s).or_insert(0);
+        }
+    }
+    let mut distinct = keys.keys().copied().collect::<Vec<_>>();
+    distinct.sort_unstable_by(|a, b| collator.compare(a, b));
+
+    let mut key = 0;
+    for (i, s) in distinct.iter().enumerate() {
+        if i > 0 && collator.compare(distinct[i - 1], s) != Ordering::Equal {
+            key += 1;
+        }
+        *keys.get_mut(s).unwrap() = key;
+    }
+
+    arrays
+        .iter()
+        .map(|arr| {
+            // The value of a null slot does not matter.
+            let values = arr
+                .values_iter()
+                .map(|s| keys.get(s).copied().unwrap_or_default())
+                .collect::<Vec<_>>();
+            PrimitiveArray::from_vec(values).with_validity(arr.validity().cloned())
+        })
+        .collect()
+}
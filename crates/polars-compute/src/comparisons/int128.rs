@@ -0,0 +1,53 @@
+use arrow::array::PrimitiveArray;
+use arrow::bitmap::Bitmap;
+
+use super::{TotalEqKernel, TotalOrdKernel};
+use crate::decimal;
+
+impl TotalEqKernel for PrimitiveArray<i128> {
+    type Scalar = i128;
+
+    fn tot_eq_kernel(&self, other: &Self) -> Bitmap {
+        decimal::eq_kernel(self.values(), other.values())
+    }
+
+    fn tot_ne_kernel(&self, other: &Self) -> Bitmap {
+        decimal::ne_kernel(self.values(), other.values())
+    }
+
+    fn tot_eq_kernel_broadcast(&self, other: &Self::Scalar) -> Bitmap {
+        decimal::eq_broadcast(self.values(), *other)
+    }
+
+    fn tot_ne_kernel_broadcast(&self, other: &Self::Scalar) -> Bitmap {
+        decimal::ne_broadcast(self.values(), *other)
+    }
+}
+
+impl TotalOrdKernel for PrimitiveArray<i128> {
+    type Scalar = i128;
+
+    fn tot_lt_kernel(&self, other: &Self) -> Bitmap {
+        decimal::lt_kernel(self.values(), other.values())
+    }
+
+    fn tot_le_kernel(&self, other: &Self) -> Bitmap {
+        decimal::le_kernel(self.values(), other.values())
+    }
+
+    fn tot_lt_kernel_broadcast(&self, other: &Self::Scalar) -> Bitmap {
+        decimal::lt_broadcast(self.values(), *other)
+    }
+
+    fn tot_le_kernel_broadcast(&self, other: &Self::Scalar) -> Bitmap {
+        decimal::le_broadcast(self.values(), *other)
+    }
+
+    fn tot_gt_kernel_broadcast(&self, other: &Self::Scalar) -> Bitmap {
+        decimal::gt_broadcast(self.values(), *other)
+    }
+
+    fn tot_ge_kernel_broadcast(&self, other: &Self::Scalar) -> Bitmap {
+        decimal::ge_broadcast(self.values(), *other)
+    }
+}
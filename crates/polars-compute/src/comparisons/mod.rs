@@ -76,10 +76,31 @@ pub trait TotalOrdKernel: Sized + Array {
     fn tot_ge_kernel_broadcast(&self, other: &Self::Scalar) -> Bitmap;
 }
 
+// Trait to enable the scalar blanket implementation. Unlike `crate::NotSimdPrimitive` it
+// excludes `i128`, which has its own kernels in `int128`.
+trait NotSimdPrimitive: arrow::types::NativeType {}
+
+macro_rules! impl_not_simd_primitive {
+    ($($T:ty),*) => {
+        $(impl NotSimdPrimitive for $T {})*
+    };
+}
+
+#[cfg(not(feature = "simd"))]
+impl_not_simd_primitive!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+impl_not_simd_primitive!(
+    u128,
+    arrow::types::f16,
+    arrow::types::i256,
+    arrow::types::days_ms,
+    arrow::types::months_days_ns
+);
+
 mod binary;
 mod boolean;
 mod dictionary;
 mod dyn_array;
+mod int128;
 mod list;
 mod null;
 mod scalar;
@@ -2,8 +2,7 @@ use arrow::array::PrimitiveArray;
 use arrow::bitmap::Bitmap;
 use polars_utils::total_ord::TotalOrd;
 
-use super::{TotalEqKernel, TotalOrdKernel};
-use crate::NotSimdPrimitive;
+use super::{NotSimdPrimitive, TotalEqKernel, TotalOrdKernel};
 
 impl<T: NotSimdPrimitive + TotalOrd> TotalEqKernel for PrimitiveArray<T> {
     type Scalar = T;
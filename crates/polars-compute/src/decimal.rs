@@ -0,0 +1,291 @@
+//! Kernels on the `i128` values of decimals.
+//!
+//! The loops are branchless so the compiler can vectorize them, overflow is accumulated in a
+//! flag and checked once at the end. Callers fall back to a slower path that finds the value
+//! that overflowed if they need to report it.
+use arrow::bitmap::Bitmap;
+use polars_utils::IdxSize;
+
+const LANES: usize = 8;
+
+/// `10^precision`, the smallest value that doesn't fit a decimal of that precision.
+fn bound(precision: usize) -> u128 {
+    10_u128.pow(precision as u32)
+}
+
+fn checked_binary(
+    lhs: impl Iterator<Item = i128>,
+    rhs: impl Iterator<Item = i128>,
+    len: usize,
+    precision: usize,
+    op: fn(i128, i128) -> (i128, bool),
+) -> Option<Vec<i128>> {
+    let bound = bound(precision);
+    let mut overflow = false;
+    let mut out = Vec::with_capacity(len);
+    out.extend(lhs.zip(rhs).map(|(a, b)| {
+        let (v, o) = op(a, b);
+        overflow |= o | (v.unsigned_abs() >= bound);
+        v
+    }));
+    (!overflow).then_some(out)
+}
+
+/// `lhs + rhs`, or `None` if a sum doesn't fit `precision` digits.
+pub fn add(lhs: &[i128], rhs: &[i128], precision: usize) -> Option<Vec<i128>> {
+    assert_eq!(lhs.len(), rhs.len());
+    let (a, b) = (lhs.iter().copied(), rhs.iter().copied());
+    checked_binary(a, b, lhs.len(), precision, i128::overflowing_add)
+}
+
+/// `lhs - rhs`, or `None` if a difference doesn't fit `precision` digits.
+pub fn sub(lhs: &[i128], rhs: &[i128], precision: usize) -> Option<Vec<i128>> {
+    assert_eq!(lhs.len(), rhs.len());
+    let (a, b) = (lhs.iter().copied(), rhs.iter().copied());
+    checked_binary(a, b, lhs.len(), precision, i128::overflowing_sub)
+}
+
+/// `lhs + rhs` for every value of `lhs`, or `None` if a sum doesn't fit `precision` digits.
+pub fn add_scalar(lhs: &[i128], rhs: i128, precision: usize) -> Option<Vec<i128>> {
+    let (a, b) = (lhs.iter().copied(), std::iter::repeat(rhs));
+    checked_binary(a, b, lhs.len(), precision, i128::overflowing_add)
+}
+
+/// `lhs - rhs` for every value of `lhs`, or `None` if a difference doesn't fit `precision`
+/// digits.
+pub fn sub_scalar(lhs: &[i128], rhs: i128, precision: usize) -> Option<Vec<i128>> {
+    let (a, b) = (lhs.iter().copied(), std::iter::repeat(rhs));
+    checked_binary(a, b, lhs.len(), precision, i128::overflowing_sub)
+}
+
+/// `lhs - rhs` for every value of `rhs`, or `None` if a difference doesn't fit `precision`
+/// digits.
+pub fn scalar_sub(lhs: i128, rhs: &[i128], precision: usize) -> Option<Vec<i128>> {
+    let (a, b) = (std::iter::repeat(lhs), rhs.iter().copied());
+    checked_binary(a, b, rhs.len(), precision, i128::overflowing_sub)
+}
+
+/// A wrapping sum that counts how often it wrapped, so the exact sum is
+/// `value + carry * 2^128`.
+#[derive(Copy, Clone, Default)]
+struct CarrySum {
+    value: i128,
+    carry: i64,
+}
+
+impl CarrySum {
+    #[inline(always)]
+    fn add(&mut self, v: i128) {
+        let (value, overflow) = self.value.overflowing_add(v);
+        // Adding a positive number wraps downwards past `i128::MAX`, a negative one upwards.
+        self.carry += overflow as i64 * (1 - 2 * (v < 0) as i64);
+        self.value = value;
+    }
+
+    fn combine(mut self, other: Self) -> Self {
+        self.add(other.value);
+        self.carry += other.carry;
+        self
+    }
+
+    fn get(self) -> Option<i128> {
+        (self.carry == 0).then_some(self.value)
+    }
+}
+
+fn sum_lanes(values: impl Iterator<Item = i128>) -> CarrySum {
+    let mut lanes = [CarrySum::default(); LANES];
+    for (i, v) in values.enumerate() {
+        lanes[i % LANES].add(v);
+    }
+    lanes
+        .into_iter()
+        .fold(CarrySum::default(), CarrySum::combine)
+}
+
+/// The exact sum of the valid `values`, or `None` if it doesn't fit an `i128`.
+///
+/// Values that are null according to `validity` are ignored, whatever their value.
+pub fn sum(values: &[i128], validity: Option<&Bitmap>) -> Option<i128> {
+    let sum = match validity.filter(|v| v.unset_bits() > 0) {
+        None => {
+            let mut lanes = [CarrySum::default(); LANES];
+            let chunks = values.chunks_exact(LANES);
+            let mut sum = CarrySum::default();
+            chunks.remainder().iter().for_each(|v| sum.add(*v));
+            for chunk in chunks {
+                for (lane, v) in lanes.iter_mut().zip(chunk) {
+                    lane.add(*v);
+                }
+            }
+            lanes.into_iter().fold(sum, CarrySum::combine)
+        },
+        Some(validity) => {
+            assert_eq!(values.len(), validity.len());
+            // Null values are masked to zero instead of skipped to keep the loop branchless.
+            let masked = values
+                .iter()
+                .zip(validity.iter())
+                .map(|(v, valid)| *v & -(valid as i128));
+            sum_lanes(masked)
+        },
+    };
+    sum.get()
+}
+
+/// The exact sum of the valid values at the indices `idx`, or `None` if it doesn't fit an
+/// `i128`.
+///
+/// # Safety
+/// The indices must be in bounds of `values` and `validity`.
+pub unsafe fn sum_gather(
+    values: &[i128],
+    validity: Option<&Bitmap>,
+    idx: &[IdxSize],
+) -> Option<i128> {
+    let sum = match validity.filter(|v| v.unset_bits() > 0) {
+        None => sum_lanes(idx.iter().map(|i| *values.get_unchecked(*i as usize))),
+        Some(validity) => sum_lanes(idx.iter().map(|i| {
+            let i = *i as usize;
+            *values.get_unchecked(i) & -(validity.get_bit_unchecked(i) as i128)
+        })),
+    };
+    sum.get()
+}
+
+/// Collect a bit per value of `f` into a bitmap, 64 bits at a time.
+fn collect_bits(len: usize, f: impl Fn(usize) -> bool) -> Bitmap {
+    let mut bytes = Vec::with_capacity(len.div_ceil(64) * 8);
+    for start in (0..len).step_by(64) {
+        let end = (start + 64).min(len);
+        let mut word = 0u64;
+        for i in start..end {
+            word |= (f(i) as u64) << (i - start);
+        }
+        bytes.extend_from_slice(&word.to_le_bytes());
+    }
+    bytes.truncate(len.div_ceil(8));
+    Bitmap::from_u8_vec(bytes, len)
+}
+
+/// Split an `i128` into its signed high and unsigned low 64 bits, which compare like the whole
+/// number when compared in that order.
+#[inline(always)]
+fn split(v: i128) -> (i64, u64) {
+    ((v >> 64) as i64, v as u64)
+}
+
+#[inline(always)]
+fn lt(a: i128, b: i128) -> bool {
+    let ((ah, al), (bh, bl)) = (split(a), split(b));
+    (ah < bh) | ((ah == bh) & (al < bl))
+}
+
+#[inline(always)]
+fn le(a: i128, b: i128) -> bool {
+    let ((ah, al), (bh, bl)) = (split(a), split(b));
+    (ah < bh) | ((ah == bh) & (al <= bl))
+}
+
+#[inline(always)]
+fn eq(a: i128, b: i128) -> bool {
+    let ((ah, al), (bh, bl)) = (split(a), split(b));
+    (ah == bh) & (al == bl)
+}
+
+macro_rules! impl_cmp {
+    ($name:ident, $broadcast:ident, $op:expr, $doc:literal) => {
+        #[doc = concat!("A bitmap of `lhs ", $doc, " rhs`.")]
+        pub fn $name(lhs: &[i128], rhs: &[i128]) -> Bitmap {
+            assert_eq!(lhs.len(), rhs.len());
+            collect_bits(lhs.len(), |i| $op(lhs[i], rhs[i]))
+        }
+
+        #[doc = concat!("A bitmap of `lhs ", $doc, " rhs` for every value of `lhs`.")]
+        pub fn $broadcast(lhs: &[i128], rhs: i128) -> Bitmap {
+            collect_bits(lhs.len(), |i| $op(lhs[i], rhs))
+        }
+    };
+}
+
+impl_cmp!(eq_kernel, eq_broadcast, eq, "==");
+impl_cmp!(ne_kernel, ne_broadcast, |a, b| !eq(a, b), "!=");
+impl_cmp!(lt_kernel, lt_broadcast, lt, "<");
+impl_cmp!(le_kernel, le_broadcast, le, "<=");
+impl_cmp!(gt_kernel, gt_broadcast, |a, b| lt(b, a), ">");
+impl_cmp!(ge_kernel, ge_broadcast, |a, b| le(b, a), ">=");
+
+fn reduce_lanes(values: &[i128], f: fn(i128, i128) -> i128) -> Option<i128> {
+    let first = *values.first()?;
+    let mut lanes = [first; LANES];
+    let chunks = values.chunks_exact(LANES);
+    let rest = chunks.remainder().iter().copied().fold(first, f);
+    for chunk in chunks {
+        for (lane, v) in lanes.iter_mut().zip(chunk) {
+            *lane = f(*lane, *v);
+        }
+    }
+    Some(lanes.into_iter().fold(rest, f))
+}
+
+/// The smallest of `values`.
+pub fn min(values: &[i128]) -> Option<i128> {
+    reduce_lanes(values, |a, b| if lt(b, a) { b } else { a })
+}
+
+/// The largest of `values`.
+pub fn max(values: &[i128]) -> Option<i128> {
+    reduce_lanes(values, |a, b| if lt(a, b) { b } else { a })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decimal_add_sub() {
+        let a = [1, -5, 99];
+        assert_eq!(add(&a, &[1, 1, -99], 2), Some(vec![2, -4, 0]));
+        assert_eq!(add(&a, &[1, 1, 1], 2), None);
+        assert_eq!(sub_scalar(&a, 1, 2), Some(vec![0, -6, 98]));
+        assert_eq!(scalar_sub(-1, &a, 2), None);
+        assert_eq!(add_scalar(&[i128::MAX], 1, 38), None);
+    }
+
+    #[test]
+    fn test_decimal_sum() {
+        let values = (-20..25).map(|v| v * 1_000_000).collect::<Vec<i128>>();
+        assert_eq!(sum(&values, None), Some(values.iter().sum()));
+
+        // Wrapping partial sums that come back in range are exact.
+        let values = [i128::MAX, i128::MAX, -i128::MAX, -i128::MAX, 7];
+        assert_eq!(sum(&values, None), Some(7));
+        assert_eq!(sum(&[i128::MAX, 1], None), None);
+        assert_eq!(sum(&[i128::MIN, -1], None), None);
+
+        let validity = Bitmap::from([true, false, true, false, true]);
+        assert_eq!(sum(&values, Some(&validity)), Some(7));
+        let idx = [0, 2, 4, 4];
+        assert_eq!(unsafe { sum_gather(&values, None, &idx) }, Some(14));
+        assert_eq!(unsafe { sum_gather(&values, None, &[0, 1]) }, None);
+    }
+
+    #[test]
+    fn test_decimal_cmp_min_max() {
+        let a = (0..100)
+            .map(|v: i128| (v - 50) << (v % 70))
+            .collect::<Vec<_>>();
+        let b = a.iter().rev().copied().collect::<Vec<_>>();
+        let expected = a.iter().zip(&b).map(|(a, b)| a < b).collect::<Bitmap>();
+        assert_eq!(lt_kernel(&a, &b), expected);
+        assert_eq!(ge_kernel(&a, &b), !&expected);
+        let expected = a.iter().map(|a| *a > 3).collect::<Bitmap>();
+        assert_eq!(gt_broadcast(&a, 3), expected);
+        assert_eq!(le_broadcast(&a, 3), !&expected);
+        assert_eq!(eq_kernel(&a, &a).unset_bits(), 0);
+
+        assert_eq!(min(&a), a.iter().min().copied());
+        assert_eq!(max(&a), a.iter().max().copied());
+        assert_eq!(min(&[]), None);
+    }
+}
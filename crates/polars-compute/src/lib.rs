@@ -8,11 +8,16 @@
 use arrow::types::NativeType;
 
 pub mod arithmetic;
+#[cfg(feature = "collation")]
+pub mod collation;
 pub mod comparisons;
+pub mod decimal;
 pub mod filter;
 pub mod float_sum;
 pub mod if_then_else;
+pub mod linalg;
 pub mod min_max;
+pub mod segmented;
 pub mod unique;
 
 pub mod arity;
@@ -0,0 +1,117 @@
+//! Kernels on dense vectors and row-major matrices of floats, e.g. the rows of an array column
+//! of embeddings.
+//!
+//! The sums are accumulated in [`LANES`] independent accumulators. Floating point addition is
+//! not associative, so with a single accumulator the compiler can't vectorize the loops.
+use num_traits::Float;
+
+const LANES: usize = 16;
+
+fn horizontal_sum<T: Float>(acc: [T; LANES]) -> T {
+    let mut width = LANES;
+    let mut acc = acc;
+    while width > 1 {
+        width /= 2;
+        for i in 0..width {
+            acc[i] = acc[i] + acc[i + width];
+        }
+    }
+    acc[0]
+}
+
+/// The dot product of two vectors of equal length.
+pub fn dot<T: Float>(a: &[T], b: &[T]) -> T {
+    assert_eq!(a.len(), b.len());
+    let mut acc = [T::zero(); LANES];
+    let a_chunks = a.chunks_exact(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let tail = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .fold(T::zero(), |sum, (x, y)| sum + *x * *y);
+    for (a, b) in a_chunks.zip(b_chunks) {
+        for i in 0..LANES {
+            acc[i] = acc[i] + a[i] * b[i];
+        }
+    }
+    horizontal_sum(acc) + tail
+}
+
+/// The euclidean norm of a vector.
+pub fn l2_norm<T: Float>(a: &[T]) -> T {
+    dot(a, a).sqrt()
+}
+
+/// The cosine of the angle between two vectors of equal length, which is NaN if either of them
+/// is zero.
+pub fn cosine_similarity<T: Float>(a: &[T], b: &[T]) -> T {
+    assert_eq!(a.len(), b.len());
+    let mut acc_ab = [T::zero(); LANES];
+    let mut acc_aa = [T::zero(); LANES];
+    let mut acc_bb = [T::zero(); LANES];
+    let a_chunks = a.chunks_exact(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let (mut ab, mut aa, mut bb) = (T::zero(), T::zero(), T::zero());
+    for (x, y) in a_chunks.remainder().iter().zip(b_chunks.remainder()) {
+        ab = ab + *x * *y;
+        aa = aa + *x * *x;
+        bb = bb + *y * *y;
+    }
+    for (a, b) in a_chunks.zip(b_chunks) {
+        for i in 0..LANES {
+            acc_ab[i] = acc_ab[i] + a[i] * b[i];
+            acc_aa[i] = acc_aa[i] + a[i] * a[i];
+            acc_bb[i] = acc_bb[i] + b[i] * b[i];
+        }
+    }
+    let ab = horizontal_sum(acc_ab) + ab;
+    let aa = horizontal_sum(acc_aa) + aa;
+    let bb = horizontal_sum(acc_bb) + bb;
+    ab / (aa.sqrt() * bb.sqrt())
+}
+
+/// Multiply the `n x k` matrix `a` with the `k x m` matrix `b` into the `n x m` matrix `out`.
+/// All matrices are row-major.
+pub fn matmul<T: Float>(a: &[T], b: &[T], out: &mut [T], n: usize, k: usize, m: usize) {
+    assert_eq!(a.len(), n * k);
+    assert_eq!(b.len(), k * m);
+    assert_eq!(out.len(), n * m);
+    out.fill(T::zero());
+    // With this loop order the innermost loop runs over contiguous rows of `b` and `out`.
+    for (a_row, out_row) in a.chunks_exact(k.max(1)).zip(out.chunks_exact_mut(m.max(1))) {
+        for (a_ij, b_row) in a_row.iter().zip(b.chunks_exact(m.max(1))) {
+            for (o, b_jl) in out_row.iter_mut().zip(b_row) {
+                *o = *o + *a_ij * *b_jl;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_linalg() {
+        let a = (0..37).map(|v| v as f64).collect::<Vec<_>>();
+        let b = (0..37).map(|v| (v % 3) as f64).collect::<Vec<_>>();
+        let expected = a.iter().zip(&b).map(|(x, y)| x * y).sum::<f64>();
+        assert_eq!(dot(&a, &b), expected);
+        assert_eq!(l2_norm(&[3.0f32, 4.0]), 5.0);
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[2.0, 0.0]), 1.0);
+        assert!(cosine_similarity(&[1.0, 0.0], &[0.0, 0.0]).is_nan());
+
+        // [[1, 2, 3], [4, 5, 6]] x [[1, 0], [0, 1], [1, 1]]
+        let mut out = [0.0; 4];
+        matmul(
+            &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            &[1.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            &mut out,
+            2,
+            3,
+            2,
+        );
+        assert_eq!(out, [4.0, 5.0, 10.0, 11.0]);
+    }
+}
@@ -0,0 +1,57 @@
+use arrow::array::{Array, PrimitiveArray};
+use polars_utils::min_max::MinMax;
+
+use super::MinMaxKernel;
+use crate::decimal;
+
+fn reduce_vals(
+    v: &PrimitiveArray<i128>,
+    kernel: fn(&[i128]) -> Option<i128>,
+    f: fn(i128, i128) -> i128,
+) -> Option<i128> {
+    if v.null_count() == 0 {
+        kernel(v.values())
+    } else {
+        v.non_null_values_iter().reduce(f)
+    }
+}
+
+impl MinMaxKernel for PrimitiveArray<i128> {
+    type Scalar<'a> = i128;
+
+    fn min_ignore_nan_kernel(&self) -> Option<Self::Scalar<'_>> {
+        reduce_vals(self, decimal::min, MinMax::min_ignore_nan)
+    }
+
+    fn max_ignore_nan_kernel(&self) -> Option<Self::Scalar<'_>> {
+        reduce_vals(self, decimal::max, MinMax::max_ignore_nan)
+    }
+
+    fn min_propagate_nan_kernel(&self) -> Option<Self::Scalar<'_>> {
+        self.min_ignore_nan_kernel()
+    }
+
+    fn max_propagate_nan_kernel(&self) -> Option<Self::Scalar<'_>> {
+        self.max_ignore_nan_kernel()
+    }
+}
+
+impl MinMaxKernel for [i128] {
+    type Scalar<'a> = i128;
+
+    fn min_ignore_nan_kernel(&self) -> Option<Self::Scalar<'_>> {
+        decimal::min(self)
+    }
+
+    fn max_ignore_nan_kernel(&self) -> Option<Self::Scalar<'_>> {
+        decimal::max(self)
+    }
+
+    fn min_propagate_nan_kernel(&self) -> Option<Self::Scalar<'_>> {
+        self.min_ignore_nan_kernel()
+    }
+
+    fn max_propagate_nan_kernel(&self) -> Option<Self::Scalar<'_>> {
+        self.max_ignore_nan_kernel()
+    }
+}
@@ -27,18 +27,21 @@ pub trait MinMaxKernel {
     }
 }
 
-// Trait to enable the scalar blanket implementation.
+// Trait to enable the scalar blanket implementation, `i128` has its own kernels in `int128`.
 trait NotSimdPrimitive {}
 
-#[cfg(not(feature = "simd"))]
-impl<T> NotSimdPrimitive for T {}
+macro_rules! impl_not_simd_primitive {
+    ($($T:ty),*) => {
+        $(impl NotSimdPrimitive for $T {})*
+    };
+}
 
-#[cfg(feature = "simd")]
-impl NotSimdPrimitive for u128 {}
-#[cfg(feature = "simd")]
-impl NotSimdPrimitive for i128 {}
+#[cfg(not(feature = "simd"))]
+impl_not_simd_primitive!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+impl_not_simd_primitive!(u128);
 
 mod dyn_array;
+mod int128;
 mod scalar;
 
 #[cfg(feature = "simd")]
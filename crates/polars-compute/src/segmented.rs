@@ -0,0 +1,162 @@
+//! Reductions over segments of a values buffer: the lists of a list array, the rows of a fixed
+//! size list array, or the groups of a group-by on sorted keys.
+//!
+//! The segments are given as ranges of the values, see [`offsets_to_segments`] and
+//! [`slices_to_segments`]. Every segment is reduced with the kernels for whole slices, which
+//! are vectorized, rather than value by value. The caller accounts for the validity of the
+//! values and of the segments.
+use std::ops::Range;
+
+use arrow::array::PrimitiveArray;
+use arrow::types::NativeType;
+use num_traits::{Float, NumCast};
+use polars_utils::IdxSize;
+
+use crate::float_sum::FloatSum;
+use crate::min_max::MinMaxKernel;
+
+/// The segments between consecutive `offsets`, e.g. of a list array.
+pub fn offsets_to_segments(offsets: &[i64]) -> impl Iterator<Item = Range<usize>> + '_ {
+    offsets.windows(2).map(|w| w[0] as usize..w[1] as usize)
+}
+
+/// The segments of `[first, len]` slices, e.g. of the groups of sorted keys.
+pub fn slices_to_segments(slices: &[[IdxSize; 2]]) -> impl Iterator<Item = Range<usize>> + '_ {
+    slices
+        .iter()
+        .map(|[first, len]| *first as usize..(*first + *len) as usize)
+}
+
+/// The segments of `width` values, e.g. of a fixed size list array.
+pub fn fixed_size_segments(len: usize, width: usize) -> impl Iterator<Item = Range<usize>> {
+    (0..len)
+        .step_by(width.max(1))
+        .map(move |start| start..start + width)
+}
+
+/// The sum of a segment, as `S`.
+///
+/// Integers wrap on overflow, floats are summed pairwise.
+pub trait SegmentSum<S>: Sized {
+    fn segment_sum(values: &[Self]) -> S;
+}
+
+macro_rules! impl_int_segment_sum {
+    ($T:ty => $($S:ty),+) => {
+        $(
+            impl SegmentSum<$S> for $T {
+                #[inline]
+                fn segment_sum(values: &[Self]) -> $S {
+                    values
+                        .iter()
+                        .fold(0 as $S, |acc, v| acc.wrapping_add(*v as $S))
+                }
+            }
+        )+
+    };
+}
+
+impl_int_segment_sum!(i8 => i8, i64);
+impl_int_segment_sum!(i16 => i16, i64);
+impl_int_segment_sum!(i32 => i32, i64);
+impl_int_segment_sum!(i64 => i64);
+impl_int_segment_sum!(u8 => u8, i64);
+impl_int_segment_sum!(u16 => u16, i64);
+impl_int_segment_sum!(u32 => u32, i64);
+impl_int_segment_sum!(u64 => u64);
+impl_int_segment_sum!(i128 => i128);
+
+macro_rules! impl_float_segment_sum {
+    ($T:ty => $($S:ty),+) => {
+        $(
+            impl SegmentSum<$S> for $T {
+                #[inline]
+                fn segment_sum(values: &[Self]) -> $S {
+                    <$T as FloatSum<$S>>::sum(values)
+                }
+            }
+        )+
+    };
+}
+
+impl_float_segment_sum!(f32 => f32, f64);
+impl_float_segment_sum!(f64 => f64);
+
+/// The sum of every segment of `values`. Empty segments sum to zero.
+pub fn segmented_sum<T, S>(values: &[T], segments: impl Iterator<Item = Range<usize>>) -> Vec<S>
+where
+    T: SegmentSum<S>,
+{
+    segments.map(|r| T::segment_sum(&values[r])).collect()
+}
+
+/// The mean of every segment of `values`, null for empty segments.
+pub fn segmented_mean<T, F>(
+    values: &[T],
+    segments: impl Iterator<Item = Range<usize>>,
+) -> PrimitiveArray<F>
+where
+    T: FloatSum<F>,
+    F: NativeType + Float,
+{
+    segments
+        .map(|r| {
+            let len = r.len();
+            (len > 0).then(|| T::sum(&values[r]) / <F as NumCast>::from(len).unwrap())
+        })
+        .collect()
+}
+
+/// The minimum of every segment of `values`, ignoring NaN. Null for empty segments.
+pub fn segmented_min<T>(
+    values: &[T],
+    segments: impl Iterator<Item = Range<usize>>,
+) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    [T]: for<'a> MinMaxKernel<Scalar<'a> = T>,
+{
+    segments
+        .map(|r| values[r].min_ignore_nan_kernel())
+        .collect()
+}
+
+/// The maximum of every segment of `values`, ignoring NaN. Null for empty segments.
+pub fn segmented_max<T>(
+    values: &[T],
+    segments: impl Iterator<Item = Range<usize>>,
+) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    [T]: for<'a> MinMaxKernel<Scalar<'a> = T>,
+{
+    segments
+        .map(|r| values[r].max_ignore_nan_kernel())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_segmented_reductions() {
+        let values = [1i32, 2, 3, 4, 5, 6];
+        let offsets = [0i64, 3, 3, 6];
+
+        let sums: Vec<i64> = segmented_sum(&values, offsets_to_segments(&offsets));
+        assert_eq!(sums, [6, 0, 15]);
+        let means = segmented_mean::<_, f64>(&values, offsets_to_segments(&offsets));
+        assert_eq!(means, PrimitiveArray::from([Some(2.0), None, Some(5.0)]));
+
+        let slices = [[1, 2], [4, 2]];
+        let mins = segmented_min(&values, slices_to_segments(&slices));
+        assert_eq!(mins, PrimitiveArray::from([Some(2), Some(5)]));
+        let maxs = segmented_max(&values, fixed_size_segments(values.len(), 3));
+        assert_eq!(maxs, PrimitiveArray::from([Some(3), Some(6)]));
+
+        let floats = [1.0f64, f64::NAN, 3.0];
+        let maxs = segmented_max(&floats, offsets_to_segments(&[0, 3]));
+        assert_eq!(maxs.value(0), 3.0);
+    }
+}
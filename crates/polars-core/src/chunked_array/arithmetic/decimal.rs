@@ -1,13 +1,315 @@
+use std::cmp::Ordering;
+
+use arrow::array::PrimitiveArray;
+use polars_compute::decimal;
+
 use super::*;
+use crate::chunked_array::ops::arity::{broadcast_try_binary_elementwise, try_unary_elementwise};
+use crate::config;
+use crate::series::ops::RoundMode;
+
+/// The largest precision of a decimal, every number with this many digits fits an `i128`.
+pub const DECIMAL_MAX_PRECISION: usize = 38;
+
+/// The number of fractional digits a division keeps at least.
+const MIN_DIV_SCALE: usize = 6;
+
+/// What happens to a decimal result that needs more than [`DECIMAL_MAX_PRECISION`] digits.
+///
+/// Either way, values that don't fit the resulting precision raise an error.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum DecimalOverflow {
+    /// Keep all fractional digits and cap the precision, leaving fewer digits for the integer
+    /// part of the values.
+    Raise,
+    /// Give up fractional digits, keeping at least 6, to make room for the integer part.
+    #[default]
+    Promote,
+}
+
+/// An arithmetic operation on two decimals.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DecimalArithmetic {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+/// The precision and scale of `lhs <op> rhs`, given as `(precision, scale)`, following the rules
+/// of the SQL standard.
+///
+/// If the precision of either side is unknown, so is the precision of the result. A division
+/// then keeps 4 more fractional digits than its dividend, at least 6.
+pub fn decimal_arithmetic_dtype(
+    op: DecimalArithmetic,
+    lhs: (Option<usize>, usize),
+    rhs: (Option<usize>, usize),
+    overflow: DecimalOverflow,
+) -> (Option<usize>, usize) {
+    use DecimalArithmetic::*;
+    let ((p1, s1), (p2, s2)) = (lhs, rhs);
+
+    let (Some(p1), Some(p2)) = (p1, p2) else {
+        let scale = match op {
+            Add | Sub | Rem => s1.max(s2),
+            Mul => s1 + s2,
+            Div => MIN_DIV_SCALE.max(s1 + 4),
+        };
+        return (None, scale.min(DECIMAL_MAX_PRECISION));
+    };
+
+    let (i1, i2) = (p1.saturating_sub(s1), p2.saturating_sub(s2));
+    let (int_digits, scale) = match op {
+        Add | Sub => (i1.max(i2) + 1, s1.max(s2)),
+        Mul => (i1 + i2 + 1, s1 + s2),
+        Div => (i1 + s2, MIN_DIV_SCALE.max(s1 + p2 + 1)),
+        Rem => (i1.min(i2), s1.max(s2)),
+    };
+    if int_digits + scale <= DECIMAL_MAX_PRECISION {
+        return (Some(int_digits + scale), scale);
+    }
+    let scale = match overflow {
+        DecimalOverflow::Raise => scale.min(DECIMAL_MAX_PRECISION),
+        DecimalOverflow::Promote => DECIMAL_MAX_PRECISION
+            .saturating_sub(int_digits)
+            .max(scale.min(MIN_DIV_SCALE)),
+    };
+    (Some(DECIMAL_MAX_PRECISION), scale)
+}
+
+/// The precision of a decimal that is rescaled from `from` to `to` fractional digits, which
+/// keeps the number of integer digits.
+pub fn decimal_rescale_precision(
+    precision: Option<usize>,
+    from: usize,
+    to: usize,
+) -> Option<usize> {
+    precision.map(|p| (p.saturating_sub(from) + to).min(DECIMAL_MAX_PRECISION))
+}
+
+/// The decimal type that holds every value of the integer type `dtype`, if it is one.
+///
+/// An integer literal gets the precision of its value.
+pub fn integer_decimal_dtype(dtype: &DataType) -> Option<DataType> {
+    use DataType::*;
+    let precision = match dtype {
+        Int8 | UInt8 => 3,
+        Int16 | UInt16 => 5,
+        Int32 | UInt32 => 10,
+        Int64 => 19,
+        UInt64 => 20,
+        Unknown(UnknownKind::Int(v)) => n_digits(*v),
+        _ => return None,
+    };
+    Some(Decimal(Some(precision), Some(0)))
+}
+
+fn n_digits(value: i128) -> usize {
+    value
+        .unsigned_abs()
+        .checked_ilog10()
+        .map_or(1, |log| log as usize + 1)
+}
+
+fn pow10(exp: usize) -> Option<i128> {
+    10_i128.checked_pow(exp as u32)
+}
+
+/// Divide `a` by `b`, rounding the quotient to an integer with `mode`.
+///
+/// Returns `None` if `b` is zero or the quotient overflows.
+fn div_round(a: i128, b: i128, mode: RoundMode) -> Option<i128> {
+    let quotient = a.checked_div(b)?;
+    let remainder = (a % b).unsigned_abs();
+    if remainder == 0 {
+        return Some(quotient);
+    }
+    let away = match remainder.cmp(&(b.unsigned_abs() - remainder)) {
+        Ordering::Less => false,
+        Ordering::Greater => true,
+        Ordering::Equal => match mode {
+            RoundMode::HalfAway => true,
+            RoundMode::HalfEven => quotient % 2 != 0,
+        },
+    };
+    if !away {
+        Some(quotient)
+    } else if (a < 0) == (b < 0) {
+        quotient.checked_add(1)
+    } else {
+        quotient.checked_sub(1)
+    }
+}
+
+/// Bring `value` from scale `from` to scale `to`, rounding dropped digits with `mode`.
+fn rescale_value(value: i128, from: usize, to: usize, mode: RoundMode) -> Option<i128> {
+    match to.cmp(&from) {
+        Ordering::Equal => Some(value),
+        Ordering::Greater => value.checked_mul(pow10(to - from)?),
+        // Every `i128` has fewer than 40 digits, so it rounds to zero beyond that.
+        Ordering::Less => match pow10(from - to) {
+            Some(divisor) => div_round(value, divisor, mode),
+            None => Some(0),
+        },
+    }
+}
+
+/// Return `value` if it has at most `precision` digits.
+fn fit_precision(value: i128, precision: Option<usize>) -> Option<i128> {
+    let bound = pow10(precision.unwrap_or(DECIMAL_MAX_PRECISION))?;
+    (value.unsigned_abs() < bound.unsigned_abs()).then_some(value)
+}
+
+impl DecimalChunked {
+    /// Add or subtract decimals of the same scale with the branchless kernels, which only
+    /// check for overflow once.
+    ///
+    /// Returns `None` if a value overflows, also if it is masked by a null, or the lengths don't
+    /// match, so the slow path can raise the right error.
+    fn add_sub_fast(
+        &self,
+        rhs: &Self,
+        op: DecimalArithmetic,
+        precision: Option<usize>,
+    ) -> Option<Int128Chunked> {
+        let precision = precision.unwrap_or(DECIMAL_MAX_PRECISION);
+        let (lhs, rhs) = (self.0.rechunk(), rhs.0.rechunk());
+        let lhs_arr = lhs.downcast_iter().next()?;
+        let rhs_arr = rhs.downcast_iter().next()?;
+        let sub = op == DecimalArithmetic::Sub;
+        let (values, validity) = match (lhs.len(), rhs.len()) {
+            (l, r) if l == r => {
+                let values = if sub {
+                    decimal::sub(lhs_arr.values(), rhs_arr.values(), precision)
+                } else {
+                    decimal::add(lhs_arr.values(), rhs_arr.values(), precision)
+                };
+                let validity = combine_validities_and(lhs_arr.validity(), rhs_arr.validity());
+                (values?, validity)
+            },
+            (_, 1) => {
+                let b = rhs.get(0)?;
+                let values = if sub {
+                    decimal::sub_scalar(lhs_arr.values(), b, precision)
+                } else {
+                    decimal::add_scalar(lhs_arr.values(), b, precision)
+                };
+                (values?, lhs_arr.validity().cloned())
+            },
+            (1, _) => {
+                let a = lhs.get(0)?;
+                let values = if sub {
+                    decimal::scalar_sub(a, rhs_arr.values(), precision)
+                } else {
+                    decimal::add_scalar(rhs_arr.values(), a, precision)
+                };
+                (values?, rhs_arr.validity().cloned())
+            },
+            _ => return None,
+        };
+        let arr = PrimitiveArray::from_vec(values).with_validity(validity);
+        Some(Int128Chunked::with_chunk(self.name(), arr))
+    }
+
+    fn arithmetic(&self, rhs: &Self, op: DecimalArithmetic) -> PolarsResult<Self> {
+        use DecimalArithmetic::*;
+        let (s1, s2) = (self.scale(), rhs.scale());
+        let (precision, scale) = decimal_arithmetic_dtype(
+            op,
+            (self.precision(), s1),
+            (rhs.precision(), s2),
+            config::decimal_overflow(),
+        );
+        let mode = config::decimal_rounding();
+
+        if matches!(op, Add | Sub) && s1 == scale && s2 == scale {
+            if let Some(out) = self.add_sub_fast(rhs, op, precision) {
+                return Ok(out.into_decimal_unchecked(precision, scale));
+            }
+        }
+
+        // The exact result and its scale, `None` if it overflows.
+        let exact = |a: i128, b: i128| -> Option<(i128, usize)> {
+            let common = s1.max(s2);
+            let (a_common, b_common) = (
+                rescale_value(a, s1, common, mode),
+                rescale_value(b, s2, common, mode),
+            );
+            Some(match op {
+                Add => (a_common?.checked_add(b_common?)?, common),
+                Sub => (a_common?.checked_sub(b_common?)?, common),
+                Rem => (a_common?.checked_rem(b_common?)?, common),
+                Mul => (a.checked_mul(b)?, s1 + s2),
+                // The quotient of the unscaled values has scale `s1 - s2`.
+                Div => {
+                    let quotient = if scale + s2 >= s1 {
+                        div_round(a.checked_mul(pow10(scale + s2 - s1)?)?, b, mode)?
+                    } else {
+                        div_round(a, b.checked_mul(pow10(s1 - scale - s2)?)?, mode)?
+                    };
+                    (quotient, scale)
+                },
+            })
+        };
+
+        let out = broadcast_try_binary_elementwise(&self.0, &rhs.0, |a, b| {
+            let (Some(a), Some(b)) = (a, b) else {
+                return Ok(None);
+            };
+            // Like integers, decimals divided by zero are null.
+            if b == 0 && matches!(op, Div | Rem) {
+                return Ok(None);
+            }
+            exact(a, b)
+                .and_then(|(value, from)| rescale_value(value, from, scale, mode))
+                .and_then(|value| fit_precision(value, precision))
+                .map(Some)
+                .ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "decimal overflow: the result of {:?} doesn't fit {}",
+                        op, DataType::Decimal(precision, Some(scale))
+                    )
+                })
+        })?;
+        Ok(out.into_decimal_unchecked(precision, scale))
+    }
+
+    /// Change the number of fractional digits to `scale`, rounding dropped digits with `mode`.
+    ///
+    /// The number of integer digits is kept, values that don't fit it after rounding raise an
+    /// error.
+    pub fn rescale(&self, scale: usize, mode: RoundMode) -> PolarsResult<Self> {
+        polars_ensure!(
+            scale <= DECIMAL_MAX_PRECISION,
+            InvalidOperation: "decimal scale {} exceeds the maximum of {}", scale, DECIMAL_MAX_PRECISION
+        );
+        let from = self.scale();
+        let precision = decimal_rescale_precision(self.precision(), from, scale);
+        let out = try_unary_elementwise(&self.0, |opt_v| {
+            opt_v
+                .map(|v| {
+                    rescale_value(v, from, scale, mode)
+                        .and_then(|v| fit_precision(v, precision))
+                        .ok_or_else(|| {
+                            polars_err!(
+                                ComputeError: "decimal overflow: {} doesn't fit {} after rescaling",
+                                AnyValue::Decimal(v, from), DataType::Decimal(precision, Some(scale))
+                            )
+                        })
+                })
+                .transpose()
+        })?;
+        Ok(out.into_decimal_unchecked(precision, scale))
+    }
+}
 
 impl Add for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let scale = self.scale().max(rhs.scale());
-        let lhs = self.to_scale(scale)?;
-        let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.0 + &rhs.0).into_decimal_unchecked(None, scale))
+        self.arithmetic(rhs, DecimalArithmetic::Add)
     }
 }
 
@@ -15,10 +317,7 @@ impl Sub for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let scale = self.scale().max(rhs.scale());
-        let lhs = self.to_scale(scale)?;
-        let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.0 - &rhs.0).into_decimal_unchecked(None, scale))
+        self.arithmetic(rhs, DecimalArithmetic::Sub)
     }
 }
 
@@ -26,8 +325,7 @@ impl Mul for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let scale = self.scale() + rhs.scale();
-        Ok((&self.0 * &rhs.0).into_decimal_unchecked(None, scale))
+        self.arithmetic(rhs, DecimalArithmetic::Mul)
     }
 }
 
@@ -35,9 +333,70 @@ impl Div for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn div(self, rhs: Self) -> Self::Output {
-        // Follow postgres and MySQL adding a fixed scale increment of 4
-        let scale = self.scale() + 4;
-        let lhs = self.to_scale(scale + rhs.scale())?;
-        Ok((&lhs.0 / &rhs.0).into_decimal_unchecked(None, scale))
+        self.arithmetic(rhs, DecimalArithmetic::Div)
+    }
+}
+
+impl Rem for &DecimalChunked {
+    type Output = PolarsResult<DecimalChunked>;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.arithmetic(rhs, DecimalArithmetic::Rem)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decimal_arithmetic_dtype() {
+        use DecimalArithmetic::*;
+        let promote = DecimalOverflow::Promote;
+        let dtype = |op, lhs, rhs| decimal_arithmetic_dtype(op, lhs, rhs, promote);
+
+        assert_eq!(dtype(Add, (Some(5), 2), (Some(10), 4)), (Some(11), 4));
+        assert_eq!(dtype(Mul, (Some(5), 2), (Some(10), 4)), (Some(16), 6));
+        assert_eq!(dtype(Div, (Some(5), 2), (Some(10), 4)), (Some(20), 13));
+        assert_eq!(dtype(Rem, (Some(5), 2), (Some(10), 4)), (Some(7), 4));
+        assert_eq!(dtype(Div, (None, 2), (Some(10), 4)), (None, 6));
+
+        // 20 integer digits are needed, leaving 18 for the fraction.
+        assert_eq!(dtype(Div, (Some(20), 10), (Some(20), 10)), (Some(38), 18));
+        assert_eq!(
+            decimal_arithmetic_dtype(Div, (Some(20), 10), (Some(20), 10), DecimalOverflow::Raise),
+            (Some(38), 31)
+        );
+    }
+
+    #[test]
+    fn test_decimal_div_round() {
+        let values = [15, 25, -15, 14, -16];
+        let half_away = values.map(|v| div_round(v, 10, RoundMode::HalfAway).unwrap());
+        let half_even = values.map(|v| div_round(v, 10, RoundMode::HalfEven).unwrap());
+        assert_eq!(half_away, [2, 3, -2, 1, -2]);
+        assert_eq!(half_even, [2, 2, -2, 1, -2]);
+        assert_eq!(div_round(1, 0, RoundMode::HalfAway), None);
+    }
+
+    #[test]
+    fn test_decimal_div() {
+        let lhs = Int128Chunked::from_slice("a", &[100, 200, -200]).into_decimal_unchecked(None, 2);
+        let rhs = Int128Chunked::from_slice("b", &[3, 3, 0]).into_decimal_unchecked(None, 0);
+        let out = (&lhs / &rhs).unwrap();
+        assert_eq!(out.dtype(), &DataType::Decimal(None, Some(6)));
+        let values = out.as_ref().into_iter().collect::<Vec<_>>();
+        assert_eq!(values, [Some(333_333), Some(666_667), None]);
+    }
+
+    #[test]
+    fn test_decimal_overflow() {
+        let lhs = Int128Chunked::from_slice("a", &[999]).into_decimal_unchecked(Some(3), 0);
+        let out = (&lhs * &lhs).unwrap();
+        assert_eq!(out.dtype(), &DataType::Decimal(Some(7), Some(0)));
+
+        let max =
+            Int128Chunked::from_slice("a", &[10_i128.pow(37)]).into_decimal_unchecked(Some(38), 0);
+        assert!((&max * &max).is_err());
     }
 }
@@ -6,6 +6,11 @@ mod numeric;
 use std::ops::{Add, Div, Mul, Rem, Sub};
 
 use arrow::compute::utils::combine_validities_and;
+#[cfg(feature = "dtype-decimal")]
+pub use decimal::{
+    decimal_arithmetic_dtype, decimal_rescale_precision, integer_decimal_dtype, DecimalArithmetic,
+    DecimalOverflow, DECIMAL_MAX_PRECISION,
+};
 use num_traits::{Num, NumCast, ToPrimitive};
 pub use numeric::ArithmeticChunked;
 
@@ -70,3 +70,22 @@ where
         }
     }
 }
+
+impl BinaryChunked {
+    /// Copy the non-inlined values to new buffers if the buffers hold bytes that are no longer
+    /// referenced, e.g. after a filter or slice. Otherwise the buffers are shared as is.
+    pub fn shrink_views(&self) -> Self {
+        Self::from_chunk_iter_like(
+            self,
+            self.downcast_iter().map(|arr| arr.clone().shrink_views()),
+        )
+    }
+}
+
+impl StringChunked {
+    /// See [`BinaryChunked::shrink_views`].
+    pub fn shrink_views(&self) -> Self {
+        // SAFETY: the views are not changed, only the buffers they point to.
+        unsafe { self.as_binary().shrink_views().to_string_unchecked() }
+    }
+}
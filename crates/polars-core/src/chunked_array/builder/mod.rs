@@ -4,6 +4,8 @@ pub mod fixed_size_list;
 pub mod list;
 mod null;
 mod primitive;
+#[cfg(feature = "rows")]
+mod series;
 mod string;
 
 use std::sync::Arc;
@@ -16,6 +18,8 @@ pub(crate) use fixed_size_list::*;
 pub use list::*;
 pub use null::*;
 pub use primitive::*;
+#[cfg(feature = "rows")]
+pub use series::*;
 pub use string::*;
 
 use crate::chunked_array::to_primitive;
@@ -225,4 +229,38 @@ mod test {
         assert_eq!(out.len(), 7);
         assert_eq!(out.get(6).unwrap(), AnyValue::Null);
     }
+
+    #[test]
+    #[cfg(all(feature = "rows", feature = "dtype-struct"))]
+    fn test_series_builder() -> PolarsResult<()> {
+        let fields = vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::String),
+        ];
+        let mut builder = SeriesBuilder::new("s", &DataType::Struct(fields.clone()), 3)?;
+        let value = |fields: Vec<Field>, values: Vec<AnyValue<'static>>| {
+            AnyValue::StructOwned(Box::new((values, fields)))
+        };
+        // The fields are matched by name.
+        builder.append(value(
+            vec![fields[1].clone(), fields[0].clone()],
+            vec![AnyValue::String("x"), AnyValue::Int32(1)],
+        ))?;
+        builder.append(value(vec![fields[0].clone()], vec![AnyValue::Int64(2)]))?;
+        builder.append_null();
+        assert!(builder
+            .append(value(
+                vec![Field::new("c", DataType::Int64)],
+                vec![AnyValue::Int64(3)]
+            ))
+            .is_err());
+        assert!(builder.append(AnyValue::Int64(3)).is_err());
+        assert_eq!(builder.len(), 3);
+
+        let s = builder.finish()?;
+        let df = s.struct_()?.clone().unnest();
+        assert_eq!(Vec::from(df.column("a")?.i64()?), &[Some(1), Some(2), None]);
+        assert_eq!(Vec::from(df.column("b")?.str()?), &[Some("x"), None, None]);
+        Ok(())
+    }
 }
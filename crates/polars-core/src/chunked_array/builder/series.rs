@@ -0,0 +1,227 @@
+use smartstring::alias::String as SmartString;
+
+use super::get_list_builder;
+use crate::frame::row::AnyValueBuffer;
+use crate::prelude::*;
+
+enum Inner<'a> {
+    Values(AnyValueBuffer<'a>),
+    /// Also used for arrays, which are cast from the lists when finished.
+    List(Box<dyn ListBuilderTrait>),
+    #[cfg(feature = "dtype-struct")]
+    Struct(Vec<SeriesBuilder<'a>>),
+}
+
+/// Builds a [`Series`] of any data type from [`AnyValue`]s, one value at a time.
+///
+/// This is meant for row-oriented sources, where the values of a column come one by one. Nested
+/// values are appended as a whole: a [`Series`] per list or array and the values of the fields
+/// per struct. Struct fields are matched by name, missing fields are null.
+///
+/// # Example
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// let dtype = DataType::List(Box::new(DataType::Int32));
+/// let mut builder = SeriesBuilder::new("a", &dtype, 2)?;
+/// builder.append(AnyValue::List(Series::new("", [1, 2])))?;
+/// builder.append_null();
+/// let s = builder.finish()?;
+/// assert_eq!(s.dtype(), &dtype);
+/// assert_eq!(s.null_count(), 1);
+/// # Ok::<(), PolarsError>(())
+/// ```
+pub struct SeriesBuilder<'a> {
+    name: SmartString,
+    dtype: DataType,
+    len: usize,
+    inner: Inner<'a>,
+}
+
+impl<'a> SeriesBuilder<'a> {
+    /// Create a builder for `capacity` values of `dtype`.
+    pub fn new(name: &str, dtype: &DataType, capacity: usize) -> PolarsResult<Self> {
+        let inner = match dtype {
+            DataType::List(inner) => {
+                Inner::List(get_list_builder(inner, capacity, capacity, name)?)
+            },
+            #[cfg(feature = "dtype-array")]
+            DataType::Array(inner, width) => {
+                Inner::List(get_list_builder(inner, capacity * width, capacity, name)?)
+            },
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(fields) => Inner::Struct(
+                fields
+                    .iter()
+                    .map(|f| SeriesBuilder::new(f.name(), f.data_type(), capacity))
+                    .collect::<PolarsResult<_>>()?,
+            ),
+            dtype => Inner::Values(AnyValueBuffer::new(dtype, capacity)),
+        };
+        Ok(Self {
+            name: name.into(),
+            dtype: dtype.clone(),
+            len: 0,
+            inner,
+        })
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    /// The number of values appended so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn append_null(&mut self) {
+        match &mut self.inner {
+            // Can't fail for a null.
+            Inner::Values(buf) => buf.add(AnyValue::Null).unwrap(),
+            Inner::List(builder) => builder.append_null(),
+            #[cfg(feature = "dtype-struct")]
+            Inner::Struct(fields) => fields.iter_mut().for_each(|f| f.append_null()),
+        }
+        self.len += 1;
+    }
+
+    /// Append a value, which is cast to the data type of the builder if it has another one.
+    ///
+    /// On error, nothing is appended, except if a field of a struct fails: then the row is
+    /// appended with nulls from that field on.
+    pub fn append(&mut self, value: AnyValue<'a>) -> PolarsResult<()> {
+        if value.is_null() {
+            self.append_null();
+            return Ok(());
+        }
+        let mismatch = |value: &AnyValue| {
+            polars_err!(
+                SchemaMismatch: "cannot append value {} of type {} to a builder of type {}",
+                value, value.dtype(), self.dtype
+            )
+        };
+
+        match (&mut self.inner, value) {
+            (Inner::Values(buf), value) => {
+                // `add` takes ownership, keep a copy for the error.
+                if buf.add(value.clone()).is_none() {
+                    return Err(mismatch(&value));
+                }
+            },
+            (Inner::List(builder), AnyValue::List(s))
+                if matches!(self.dtype, DataType::List(_)) =>
+            {
+                builder.append_series(&s.strict_cast(self.dtype.inner_dtype().unwrap())?)?
+            },
+            #[cfg(feature = "dtype-array")]
+            (Inner::List(builder), AnyValue::Array(s, width)) => {
+                let DataType::Array(inner, expected) = &self.dtype else {
+                    return Err(mismatch(&AnyValue::Array(s, width)));
+                };
+                polars_ensure!(
+                    s.len() == *expected,
+                    ShapeMismatch: "cannot append an array of width {} to a builder of width {}",
+                    s.len(), expected
+                );
+                builder.append_series(&s.strict_cast(inner)?)?
+            },
+            #[cfg(feature = "dtype-struct")]
+            (Inner::Struct(fields), value) => {
+                let field_values: Vec<(SmartString, AnyValue<'a>)> = match value {
+                    AnyValue::StructOwned(payload) => {
+                        let (values, value_fields) = *payload;
+                        value_fields
+                            .into_iter()
+                            .map(|f| f.name)
+                            .zip(values)
+                            .collect()
+                    },
+                    AnyValue::Struct(_, _, value_fields) => value
+                        ._iter_struct_av()
+                        .zip(value_fields)
+                        .map(|(v, f)| Ok((f.name.clone(), v.into_static()?)))
+                        .collect::<PolarsResult<_>>()?,
+                    value => return Err(mismatch(&value)),
+                };
+                let positions = field_positions(fields, &field_values)?;
+                let out = append_struct(fields, positions, field_values);
+                self.len += 1;
+                return out;
+            },
+            (_, value) => return Err(mismatch(&value)),
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn finish(self) -> PolarsResult<Series> {
+        let s = match self.inner {
+            // Values of these types are only checked when they are converted.
+            Inner::Values(AnyValueBuffer::All(dtype, values)) => {
+                Series::from_any_values_and_dtype(&self.name, &values, &dtype, true)?
+            },
+            Inner::Values(buf) => buf.into_series(),
+            Inner::List(mut builder) => {
+                let s = builder.finish().into_series();
+                match &self.dtype {
+                    #[cfg(feature = "dtype-array")]
+                    DataType::Array(..) => s.cast(&self.dtype)?,
+                    _ => s,
+                }
+            },
+            #[cfg(feature = "dtype-struct")]
+            Inner::Struct(fields) => {
+                let fields = fields
+                    .into_iter()
+                    .map(|f| f.finish())
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                StructChunked::new(&self.name, &fields)?.into_series()
+            },
+        };
+        Ok(s.with_name(&self.name))
+    }
+}
+
+/// The positions of the fields of a struct value in the fields of the builder.
+#[cfg(feature = "dtype-struct")]
+fn field_positions(
+    fields: &[SeriesBuilder<'_>],
+    values: &[(SmartString, AnyValue<'_>)],
+) -> PolarsResult<Vec<usize>> {
+    let mut positions = Vec::with_capacity(values.len());
+    for (name, _) in values {
+        let Some(i) = fields.iter().position(|f| &f.name == name) else {
+            polars_bail!(StructFieldNotFound: "{}", name);
+        };
+        polars_ensure!(
+            !positions.contains(&i),
+            Duplicate: "struct value has multiple fields with name {:?}", name.as_str()
+        );
+        positions.push(i);
+    }
+    Ok(positions)
+}
+
+/// Append the values of a struct to the builders of its fields at `positions`. The fields
+/// without a value are null, as are the fields from the first one that fails.
+#[cfg(feature = "dtype-struct")]
+fn append_struct<'a>(
+    fields: &mut [SeriesBuilder<'a>],
+    positions: Vec<usize>,
+    values: Vec<(SmartString, AnyValue<'a>)>,
+) -> PolarsResult<()> {
+    let len = fields.first().map_or(0, |f| f.len);
+    let out = positions
+        .into_iter()
+        .zip(values)
+        .try_for_each(|(i, (_, value))| fields[i].append(value));
+    for f in fields.iter_mut().filter(|f| f.len == len) {
+        f.append_null();
+    }
+    out
+}
@@ -75,6 +75,12 @@ fn cast_impl_inner(
     dtype: &DataType,
     options: CastOptions,
 ) -> PolarsResult<Series> {
+    // The physical type of Float16 holds bits, so the values are cast through Float32.
+    #[cfg(feature = "dtype-f16")]
+    if dtype == &DataType::Float16 {
+        let out = cast_impl_inner(name, chunks, &DataType::Float32, options)?;
+        return Ok(out.f32().unwrap().to_float16().into_series());
+    }
     let chunks = cast_chunks(chunks, &dtype.to_physical(), options)?;
     let out = Series::try_from((name, chunks))?;
     use DataType::*;
@@ -257,6 +263,13 @@ where
                     polars_bail!(ComputeError: "cannot cast numeric types to 'Categorical'");
                 }
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 if self.dtype() == &DataType::UInt16 => {
+                // SAFETY:
+                // we are guarded by the type system.
+                let ca = unsafe { &*(self as *const ChunkedArray<T> as *const UInt16Chunked) };
+                Ok(ca.clone().into_float16().into_series())
+            },
             _ => self.cast_impl(data_type, CastOptions::Overflowing),
         }
     }
@@ -412,6 +425,8 @@ impl ChunkCast for BinaryChunked {
     unsafe fn cast_unchecked(&self, data_type: &DataType) -> PolarsResult<Series> {
         match data_type {
             DataType::String => unsafe { Ok(self.to_string_unchecked().into_series()) },
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => Ok(self.clone().into_json().into_series()),
             _ => self.cast_with_options(data_type, CastOptions::Overflowing),
         }
     }
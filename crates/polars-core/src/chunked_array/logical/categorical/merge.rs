@@ -1,8 +1,11 @@
 use std::borrow::Cow;
 
+use rayon::prelude::*;
+
 use super::*;
 use crate::series::IsSorted;
 use crate::utils::align_chunks_binary;
+use crate::POOL;
 
 fn slots_to_mut(slots: &Utf8ViewArray) -> MutablePlString {
     slots.clone().make_mut()
@@ -131,6 +134,117 @@ fn merge_local_rhs_categorical<'a>(
     ))
 }
 
+/// Per categorical, the mapping of its old physicals to the ones of a merged rev-map.
+type PhysicalMappings = Vec<Option<Arc<Vec<u32>>>>;
+
+/// Merge the local categories of all `cas` into a single dictionary.
+///
+/// Returns the merged rev-map and, per categorical, the mapping of its old physicals to the
+/// new ones. That mapping is `None` if the physicals remain unchanged.
+fn merge_local_categories(cas: &[CategoricalChunked]) -> (Arc<RevMapping>, PhysicalMappings) {
+    let mut lookup = PlHashMap::new();
+    let mut new_categories = MutablePlString::new();
+    // Chunks of the same source share their rev-map, only compute its mapping once.
+    let mut cache: PlHashMap<u128, Option<Arc<Vec<u32>>>> = PlHashMap::new();
+
+    let mappings = cas
+        .iter()
+        .map(|ca| {
+            let RevMapping::Local(categories, hash) = &**ca.get_rev_map() else {
+                unreachable!()
+            };
+            cache
+                .entry(*hash)
+                .or_insert_with(|| {
+                    let mapping = categories
+                        .values_iter()
+                        .map(|s| {
+                            *lookup.entry(s).or_insert_with(|| {
+                                new_categories.push(Some(s));
+                                new_categories.len() as u32 - 1
+                            })
+                        })
+                        .collect::<Vec<_>>();
+                    let unchanged = mapping.iter().enumerate().all(|(i, v)| i as u32 == *v);
+                    (!unchanged).then(|| Arc::new(mapping))
+                })
+                .clone()
+        })
+        .collect();
+
+    (
+        Arc::new(RevMapping::build_local(new_categories.into())),
+        mappings,
+    )
+}
+
+/// Give all categoricals a single shared rev-map, so that appending them to each other doesn't
+/// need to re-encode them pair by pair.
+///
+/// Global rev-maps of the same string cache are merged. Local categoricals get a merged
+/// dictionary and their physicals are recoded in parallel. Enums and categoricals from
+/// incompatible sources are left untouched.
+pub fn unify_categorical_rev_maps(cas: &mut [CategoricalChunked]) {
+    let Some(first) = cas.first() else {
+        return;
+    };
+    let first = first.get_rev_map().clone();
+    if cas.iter().any(|ca| ca.is_enum()) || cas.iter().all(|ca| ca.get_rev_map().same_src(&first)) {
+        return;
+    }
+
+    match first.as_ref() {
+        RevMapping::Global(_, _, _) => {
+            let mut merger = GlobalRevMapMerger::new(first);
+            if cas[1..]
+                .iter()
+                .try_for_each(|ca| merger.merge_map(ca.get_rev_map()))
+                .is_err()
+            {
+                return;
+            }
+            let rev_map = merger.finish();
+            for ca in cas.iter_mut() {
+                // SAFETY: the merged rev-map contains the categories of all global rev-maps.
+                unsafe { ca.set_rev_map(rev_map.clone(), false) };
+            }
+        },
+        RevMapping::Local(_, _) => {
+            if !cas.iter().all(|ca| ca.get_rev_map().is_local()) {
+                return;
+            }
+            let (rev_map, mappings) = merge_local_categories(cas);
+            POOL.install(|| {
+                cas.par_iter_mut()
+                    .zip(mappings)
+                    .for_each(|(ca, mapping)| recode_local(ca, rev_map.clone(), mapping))
+            });
+        },
+    }
+}
+
+fn recode_local(
+    ca: &mut CategoricalChunked,
+    rev_map: Arc<RevMapping>,
+    mapping: Option<Arc<Vec<u32>>>,
+) {
+    let physical = match mapping {
+        Some(mapping) => ca
+            .physical()
+            .apply(|opt_v| opt_v.map(|v| mapping[v as usize])),
+        None => ca.physical().clone(),
+    };
+    // SAFETY: the mapping points every physical to its category in the merged rev-map.
+    *ca = unsafe {
+        CategoricalChunked::from_cats_and_rev_map_unchecked(
+            physical,
+            rev_map,
+            false,
+            ca.get_ordering(),
+        )
+    };
+}
+
 pub trait CategoricalMergeOperation {
     fn finish(self, lhs: &UInt32Chunked, rhs: &UInt32Chunked) -> PolarsResult<UInt32Chunked>;
 }
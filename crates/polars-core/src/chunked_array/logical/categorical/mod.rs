@@ -492,6 +492,29 @@ mod test {
         assert_eq!(appended.str_value(5).unwrap(), "y");
     }
 
+    #[test]
+    fn test_unify_local_categoricals() {
+        let _lock = SINGLE_LOCK.lock();
+        disable_string_cache();
+
+        let dtype = DataType::Categorical(None, Default::default());
+        let df1 = DataFrame::new(vec![Series::new("a", [Some("x"), None, Some("y")])
+            .cast(&dtype)
+            .unwrap()])
+        .unwrap();
+        let df2 = DataFrame::new(vec![Series::new("a", ["z", "x"]).cast(&dtype).unwrap()]).unwrap();
+
+        let out = crate::utils::accumulate_dataframes_vertical([df1, df2]).unwrap();
+        let ca = out.column("a").unwrap().categorical().unwrap();
+        assert_eq!(ca.get_rev_map().len(), 3);
+        assert_eq!(
+            ca.physical().into_iter().collect::<Vec<_>>(),
+            &[Some(0), None, Some(1), Some(2), Some(0)]
+        );
+        let vals = ca.iter_str().collect::<Vec<_>>();
+        assert_eq!(vals, &[Some("x"), None, Some("y"), Some("z"), Some("x")]);
+    }
+
     #[test]
     fn test_fast_unique() {
         let _lock = SINGLE_LOCK.lock();
@@ -0,0 +1,213 @@
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// The data of a [`DataType::Extension`]: a Series of the storage type that is tagged with the
+/// extension type.
+#[derive(Clone)]
+pub struct ExtensionChunked {
+    storage: Series,
+    dtype: DataType,
+}
+
+impl ExtensionChunked {
+    /// Tag `storage` with the extension data type `dtype`, errors if `storage` doesn't have the
+    /// storage type of `dtype`.
+    pub fn from_storage(storage: Series, dtype: DataType) -> PolarsResult<Self> {
+        let DataType::Extension(name, storage_dtype, _) = &dtype else {
+            polars_bail!(ComputeError: "expected an extension data type, got {}", dtype)
+        };
+        polars_ensure!(
+            storage.dtype() == storage_dtype.as_ref(),
+            SchemaMismatch: "extension type '{}' is stored as {}, got {}",
+            name, storage_dtype, storage.dtype()
+        );
+        Ok(Self::new_unchecked(storage, dtype))
+    }
+
+    pub(crate) fn new_unchecked(storage: Series, dtype: DataType) -> Self {
+        debug_assert!(
+            matches!(&dtype, DataType::Extension(_, s, _) if s.as_ref() == storage.dtype())
+        );
+        Self { storage, dtype }
+    }
+
+    /// Tag a Series that has the same storage type.
+    pub(crate) fn with_storage(&self, storage: Series) -> Self {
+        Self::new_unchecked(storage, self.dtype.clone())
+    }
+
+    /// The values as a Series of the storage type.
+    pub fn storage(&self) -> &Series {
+        &self.storage
+    }
+
+    pub fn into_storage(self) -> Series {
+        self.storage
+    }
+
+    pub(crate) fn storage_mut(&mut self) -> &mut Series {
+        &mut self.storage
+    }
+
+    pub fn name(&self) -> &str {
+        self.storage.name()
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
+
+    /// The name of the extension type.
+    pub fn extension_name(&self) -> &str {
+        match &self.dtype {
+            DataType::Extension(name, _, _) => name,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The metadata of the extension data type.
+    pub fn metadata(&self) -> Option<&str> {
+        match &self.dtype {
+            DataType::Extension(_, _, metadata) => metadata.as_deref(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// The registered extension type, if any.
+    pub fn extension_type(&self) -> Option<Arc<dyn ExtensionType>> {
+        get_extension_type(self.extension_name())
+    }
+
+    /// Format a value with the `format` hook of the extension type.
+    pub(crate) fn format_value(&self, value: &AnyValue) -> Option<String> {
+        if value.is_null() {
+            return None;
+        }
+        self.extension_type()?.format(value, self.metadata())
+    }
+
+    pub fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> PolarsResult<Series> {
+        if dtype == &self.dtype {
+            return Ok(self.clone().into_series());
+        }
+        if dtype.is_extension() {
+            return cast_to_extension(&self.storage, dtype, options);
+        }
+        if let Some(out) = self
+            .extension_type()
+            .and_then(|ext| ext.cast_to(&self.storage, dtype, self.metadata()))
+        {
+            return out;
+        }
+        self.storage.cast_with_options(dtype, options)
+    }
+}
+
+/// Cast a Series to an extension data type, with the `cast_from` hook of the extension type or
+/// else by casting it to the storage type.
+pub(crate) fn cast_to_extension(
+    s: &Series,
+    dtype: &DataType,
+    options: CastOptions,
+) -> PolarsResult<Series> {
+    let DataType::Extension(name, storage_dtype, metadata) = dtype else {
+        unreachable!()
+    };
+    if s.dtype() == dtype {
+        return Ok(s.clone());
+    }
+    let storage =
+        match get_extension_type(name).and_then(|ext| ext.cast_from(s, metadata.as_deref())) {
+            Some(storage) => storage?,
+            None => {
+                let s = match s.dtype() {
+                    DataType::Extension(_, _, _) => s.extension()?.storage(),
+                    _ => s,
+                };
+                s.cast_with_options(storage_dtype, options)?
+            },
+        };
+    let mut out = ExtensionChunked::from_storage(storage, dtype.clone())?;
+    out.storage.rename(s.name());
+    Ok(out.into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Ipv4;
+
+    impl ExtensionType for Ipv4 {
+        fn name(&self) -> &str {
+            "test.ipv4"
+        }
+
+        fn storage(&self) -> DataType {
+            DataType::UInt32
+        }
+
+        fn format(&self, value: &AnyValue, _metadata: Option<&str>) -> Option<String> {
+            let v = value.extract::<u32>()?;
+            let [a, b, c, d] = v.to_be_bytes();
+            Some(format!("{a}.{b}.{c}.{d}"))
+        }
+
+        fn cast_to(
+            &self,
+            storage: &Series,
+            dtype: &DataType,
+            metadata: Option<&str>,
+        ) -> Option<PolarsResult<Series>> {
+            (dtype == &DataType::String).then(|| {
+                let ca = storage.u32()?;
+                let out: StringChunked = ca
+                    .into_iter()
+                    .map(|v| v.and_then(|v| self.format(&AnyValue::UInt32(v), metadata)))
+                    .collect();
+                Ok(out.with_name(ca.name()).into_series())
+            })
+        }
+    }
+
+    #[test]
+    fn test_extension_type() -> PolarsResult<()> {
+        register_extension_type(Arc::new(Ipv4));
+        let dtype = create_extension_data_type("test.ipv4", None)?;
+
+        let s = Series::new("ip", [3232235777u32, 167772161])
+            .cast(&dtype)?
+            .filter(&BooleanChunked::new("", &[true, true]))?;
+        assert_eq!(s.dtype(), &dtype);
+        assert_eq!(s.str_value(0)?, "192.168.1.1");
+        assert_eq!(s.dtype().to_string(), "ext[test.ipv4]");
+
+        let out = s.cast(&DataType::String)?;
+        assert_eq!(out.str()?.get(1), Some("10.0.0.1"));
+        let out = s.cast(&DataType::Int64)?;
+        assert_eq!(out.i64()?.get(1), Some(167772161));
+
+        let arr = s.to_arrow(0, true);
+        assert!(
+            matches!(arr.data_type(), ArrowDataType::Extension(name, _, _) if name == "test.ipv4")
+        );
+        let out = Series::try_from(("ip", arr))?;
+        assert!(out.equals_missing(&s));
+        assert_eq!(out.dtype(), &dtype);
+
+        assert!(create_extension_data_type("test.unknown", None).is_err());
+        Ok(())
+    }
+}
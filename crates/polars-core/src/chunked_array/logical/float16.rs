@@ -0,0 +1,107 @@
+use arrow::types::f16;
+
+use super::*;
+use crate::prelude::*;
+
+/// Half-precision floats, the physical [`UInt16Chunked`] holds the bits of the `f16` values.
+pub type Float16Chunked = Logical<Float16Type, UInt16Type>;
+
+impl UInt16Chunked {
+    /// Interpret the values as the bits of `f16` values.
+    pub fn into_float16(self) -> Float16Chunked {
+        Float16Chunked::new_logical(self)
+    }
+}
+
+impl Float32Chunked {
+    /// Round the values to the nearest `f16`, values out of its range become infinite.
+    pub fn to_float16(&self) -> Float16Chunked {
+        let bits: UInt16Chunked = self.apply_values_generic(|v| f16::from_f32(v).to_bits());
+        bits.into_float16()
+    }
+}
+
+impl Float16Chunked {
+    /// Widen the values to `f32`, which represents every `f16` exactly.
+    pub fn to_float32(&self) -> Float32Chunked {
+        self.0
+            .apply_values_generic(|bits| f16::from_bits(bits).to_f32())
+    }
+}
+
+fn bits_to_any_value(av: AnyValue<'_>) -> AnyValue<'_> {
+    match av {
+        AnyValue::UInt16(bits) => AnyValue::Float32(f16::from_bits(bits).to_f32()),
+        av => av,
+    }
+}
+
+impl LogicalType for Float16Chunked {
+    fn dtype(&self) -> &DataType {
+        &DataType::Float16
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.0.get_any_value(i).map(bits_to_any_value)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        bits_to_any_value(self.0.get_any_value_unchecked(i))
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Float16 => Ok(self.clone().into_series()),
+            DataType::Float32 => Ok(self.to_float32().into_series()),
+            dt => self.to_float32().cast_with_options(dt, cast_options),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_float16_cast() -> PolarsResult<()> {
+        let s = Series::new("a", [Some(1.5f32), None, Some(-0.1), Some(1e6)]);
+        let out = s.cast(&DataType::Float16)?;
+        assert_eq!(out.dtype(), &DataType::Float16);
+        assert_eq!(out.get(0)?, AnyValue::Float32(1.5));
+        assert_eq!(out.get(1)?, AnyValue::Null);
+        assert_eq!(out.get(3)?, AnyValue::Float32(f32::INFINITY));
+
+        let back = out.cast(&DataType::Float64)?;
+        let back = back.f64()?;
+        assert!((back.get(2).unwrap() + 0.1).abs() < 1e-3);
+
+        let arr = out.to_arrow(0, true);
+        assert_eq!(arr.data_type(), &ArrowDataType::Float16);
+        let out2 = Series::try_from(("a", arr))?;
+        assert!(out2.equals_missing(&out));
+        Ok(())
+    }
+
+    #[test]
+    fn test_float16_compare_sort() -> PolarsResult<()> {
+        let s = Series::new("a", [2.0f32, -1.0, 0.5, -3.0]).cast(&DataType::Float16)?;
+        let mask = s.gt(0)?;
+        let mask = mask.into_iter().collect::<Vec<_>>();
+        assert_eq!(mask, &[Some(true), Some(false), Some(true), Some(false)]);
+
+        let sorted = s.sort(Default::default())?.cast(&DataType::Float32)?;
+        assert_eq!(
+            Vec::from(sorted.f32()?),
+            &[Some(-3.0), Some(-1.0), Some(0.5), Some(2.0)]
+        );
+        assert_eq!(s.max_reduce()?.value(), &AnyValue::Float32(2.0),);
+
+        let sum = (&s + &s)?;
+        assert_eq!(sum.dtype(), &DataType::Float32);
+        Ok(())
+    }
+}
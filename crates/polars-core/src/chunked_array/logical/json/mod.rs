@@ -0,0 +1,341 @@
+mod value;
+
+pub use value::*;
+
+use super::*;
+use crate::prelude::*;
+
+/// JSON values, the physical [`BinaryChunked`] holds their binary encoding, see [`JsonValue`].
+pub type JsonChunked = Logical<JsonType, BinaryType>;
+
+impl BinaryChunked {
+    /// Interpret the values as encoded JSON values, see [`JsonValue`] for the encoding.
+    pub fn into_json(self) -> JsonChunked {
+        JsonChunked::new_logical(self)
+    }
+}
+
+impl StringChunked {
+    /// Parse the values as JSON text, values that are not valid JSON become null.
+    pub fn parse_json(&self) -> JsonChunked {
+        let mut builder = BinaryChunkedBuilder::new(self.name(), self.len());
+        let mut buf = vec![];
+        for opt_text in self.iter() {
+            buf.clear();
+            match opt_text {
+                Some(text) if parse_json(&mut buf, text).is_ok() => builder.append_value(&buf),
+                _ => builder.append_null(),
+            }
+        }
+        builder.finish().into_json()
+    }
+}
+
+impl JsonChunked {
+    /// Convert a [`Series`] to JSON values.
+    ///
+    /// Strings are parsed as JSON text. Lists, arrays and structs become JSON arrays and objects,
+    /// values that have no JSON counterpart become JSON strings.
+    pub fn from_series(s: &Series) -> PolarsResult<JsonChunked> {
+        match s.dtype() {
+            DataType::Json => Ok(s.json()?.clone()),
+            DataType::String => Ok(s.str()?.parse_json()),
+            _ => {
+                let rows = encode_rows(s)?;
+                let mut builder = BinaryChunkedBuilder::new(s.name(), s.len());
+                for row in rows {
+                    builder.append_option(row)
+                }
+                Ok(builder.finish().into_json())
+            },
+        }
+    }
+
+    /// Iterate over the values.
+    pub fn iter_values(&self) -> impl Iterator<Item = Option<JsonValue<'_>>> {
+        self.0.iter().map(|opt_v| opt_v.map(JsonValue::from_bytes))
+    }
+
+    /// Format the values as JSON text.
+    pub fn to_text(&self) -> StringChunked {
+        let mut builder = StringChunkedBuilder::new(self.name(), self.len());
+        let mut buf = String::new();
+        for opt_v in self.iter_values() {
+            match opt_v {
+                Some(v) => {
+                    buf.clear();
+                    v.write_text(&mut buf);
+                    builder.append_value(&buf)
+                },
+                None => builder.append_null(),
+            }
+        }
+        builder.finish()
+    }
+
+    /// Convert the values to `dtype`, values that do not fit `dtype` become null.
+    ///
+    /// JSON arrays convert to lists and arrays, objects to structs. Values of other data types are
+    /// cast from their JSON scalar or, if there is no such scalar, from their text.
+    pub fn decode(&self, dtype: &DataType) -> PolarsResult<Series> {
+        let decode_dtype = decode_dtype(dtype);
+        let values = self
+            .iter_values()
+            .map(|opt_v| opt_v.map_or(Ok(AnyValue::Null), |v| decode(v, &decode_dtype)))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let s = Series::from_any_values_and_dtype(self.name(), &values, &decode_dtype, false)?;
+        if &decode_dtype == dtype {
+            Ok(s)
+        } else {
+            s.cast(dtype)
+        }
+    }
+}
+
+fn encoded<F: FnOnce(&mut Vec<u8>)>(f: F) -> Vec<u8> {
+    let mut out = vec![];
+    f(&mut out);
+    out
+}
+
+/// Encode the rows of `s` as JSON values, null rows are `None`.
+fn encode_rows(s: &Series) -> PolarsResult<Vec<Option<Vec<u8>>>> {
+    let rows = match s.dtype() {
+        DataType::Null => vec![None; s.len()],
+        DataType::Boolean => s
+            .bool()?
+            .iter()
+            .map(|opt_v| opt_v.map(|v| encoded(|out| write_bool(out, v))))
+            .collect(),
+        DataType::UInt64 => s
+            .u64()?
+            .iter()
+            .map(|opt_v| {
+                opt_v.map(|v| match i64::try_from(v) {
+                    Ok(v) => encoded(|out| write_i64(out, v)),
+                    Err(_) => encoded(|out| write_f64(out, v as f64)),
+                })
+            })
+            .collect(),
+        dt if dt.is_integer() => s
+            .cast(&DataType::Int64)?
+            .i64()?
+            .iter()
+            .map(|opt_v| opt_v.map(|v| encoded(|out| write_i64(out, v))))
+            .collect(),
+        dt if dt.is_float() => s
+            .cast(&DataType::Float64)?
+            .f64()?
+            .iter()
+            .map(|opt_v| opt_v.map(|v| encoded(|out| write_f64(out, v))))
+            .collect(),
+        DataType::Json => s
+            .json()?
+            .0
+            .iter()
+            .map(|opt_v| opt_v.map(|v| v.to_vec()))
+            .collect(),
+        DataType::List(_) => {
+            let ca = s.list()?.rechunk();
+            let values = encode_rows(&ca.get_inner())?;
+            let null = encoded(write_null);
+            let arr = ca.downcast_iter().next().unwrap();
+            let mut elements = vec![];
+            (0..arr.len())
+                .map(|i| {
+                    if !arr.is_valid(i) {
+                        return Ok(None);
+                    }
+                    let (start, end) = arr.offsets().start_end(i);
+                    elements.clear();
+                    elements.extend(
+                        values[start..end]
+                            .iter()
+                            .map(|v| v.as_deref().unwrap_or(&null)),
+                    );
+                    let mut out = vec![];
+                    write_array(&mut out, &elements)?;
+                    Ok(Some(out))
+                })
+                .collect::<PolarsResult<_>>()?
+        },
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(inner, _) => encode_rows(&s.cast(&DataType::List(inner.clone()))?)?,
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(_) => {
+            let ca = s.struct_()?;
+            let fields = ca
+                .fields()
+                .iter()
+                .map(|field| Ok((field.name(), encode_rows(field)?)))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let null = encoded(write_null);
+            let mut entries = vec![];
+            (0..s.len())
+                .map(|i| {
+                    // A struct row is null if all of its fields are null.
+                    if fields.iter().all(|(_, values)| values[i].is_none()) {
+                        return Ok(None);
+                    }
+                    entries.clear();
+                    entries.extend(
+                        fields
+                            .iter()
+                            .map(|(name, values)| (*name, values[i].as_deref().unwrap_or(&null))),
+                    );
+                    let mut out = vec![];
+                    write_object(&mut out, &mut entries)?;
+                    Ok(Some(out))
+                })
+                .collect::<PolarsResult<_>>()?
+        },
+        _ => s
+            .cast(&DataType::String)?
+            .str()?
+            .iter()
+            .map(|opt_v| {
+                opt_v
+                    .map(|v| {
+                        let mut out = vec![];
+                        write_str(&mut out, v).map(|_| out)
+                    })
+                    .transpose()
+            })
+            .collect::<PolarsResult<_>>()?,
+    };
+    Ok(rows)
+}
+
+/// The data type that is built from the JSON values, before it is cast to `dtype`.
+fn decode_dtype(dtype: &DataType) -> DataType {
+    match dtype {
+        DataType::List(inner) => DataType::List(Box::new(decode_dtype(inner))),
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(inner, _) => DataType::List(Box::new(decode_dtype(inner))),
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|f| Field::new(f.name(), decode_dtype(f.data_type())))
+                .collect(),
+        ),
+        DataType::Null | DataType::Boolean | DataType::String | DataType::Json => dtype.clone(),
+        dt if dt.is_integer() || dt.is_float() => dtype.clone(),
+        _ => DataType::String,
+    }
+}
+
+fn decode(v: JsonValue<'_>, dtype: &DataType) -> PolarsResult<AnyValue<'static>> {
+    let av = match (v.json_type(), dtype) {
+        (JsonValueType::Null, _) | (_, DataType::Null) => AnyValue::Null,
+        (JsonValueType::Array, DataType::List(inner)) => {
+            let values = v
+                .elements()
+                .map(|v| decode(v, inner))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            AnyValue::List(Series::from_any_values_and_dtype(
+                "", &values, inner, false,
+            )?)
+        },
+        #[cfg(feature = "dtype-struct")]
+        (JsonValueType::Object, DataType::Struct(fields)) => {
+            let values = fields
+                .iter()
+                .map(|f| {
+                    v.get_key(f.name())
+                        .map_or(Ok(AnyValue::Null), |v| decode(v, f.data_type()))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            AnyValue::StructOwned(Box::new((values, fields.clone())))
+        },
+        (JsonValueType::String, DataType::String) => {
+            AnyValue::StringOwned(v.as_str().unwrap().into())
+        },
+        (_, DataType::String | DataType::Json) => AnyValue::StringOwned(v.to_text().into()),
+        (JsonValueType::Boolean, _) => AnyValue::Boolean(v.as_bool().unwrap()),
+        (JsonValueType::Integer, _) => AnyValue::Int64(v.as_i64().unwrap()),
+        (JsonValueType::Float, _) => AnyValue::Float64(v.as_f64().unwrap()),
+        (JsonValueType::String, _) => AnyValue::StringOwned(v.as_str().unwrap().into()),
+        _ => AnyValue::Null,
+    };
+    Ok(av)
+}
+
+fn bytes_to_any_value(av: AnyValue<'_>) -> AnyValue<'_> {
+    match av {
+        AnyValue::Binary(bytes) => {
+            AnyValue::StringOwned(JsonValue::from_bytes(bytes).to_text().into())
+        },
+        av => av,
+    }
+}
+
+impl LogicalType for JsonChunked {
+    fn dtype(&self) -> &DataType {
+        &DataType::Json
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.0.get_any_value(i).map(bytes_to_any_value)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        bytes_to_any_value(self.0.get_any_value_unchecked(i))
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        _cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Json => Ok(self.clone().into_series()),
+            DataType::String => Ok(self.to_text().into_series()),
+            // Casting to the physical type keeps the encoding, like the other logical types.
+            DataType::Binary => Ok(self.0.clone().into_series()),
+            dt => self.decode(dt),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_json_cast() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            [
+                Some(r#"{"b": 1, "a": [1, 2.5]}"#),
+                Some("[true]"),
+                Some("not json"),
+                None,
+            ],
+        );
+        let out = s.cast(&DataType::Json)?;
+        assert_eq!(out.dtype(), &DataType::Json);
+        assert_eq!(out.null_count(), 2);
+        assert_eq!(
+            out.get(0)?,
+            AnyValue::StringOwned(r#"{"a":[1,2.5],"b":1}"#.into())
+        );
+        assert!(s.strict_cast(&DataType::Json).is_err());
+
+        let text = out.cast(&DataType::String)?;
+        assert_eq!(text.str()?.get(1), Some("[true]"));
+
+        let dtype = DataType::Struct(vec![
+            Field::new("a", DataType::List(Box::new(DataType::Float64))),
+            Field::new("c", DataType::Int32),
+        ]);
+        let st = out.head(Some(1)).cast(&dtype)?;
+        assert_eq!(st.dtype(), &dtype);
+        let back = st.cast(&DataType::Json)?;
+        assert_eq!(
+            back.get(0)?,
+            AnyValue::StringOwned(r#"{"a":[1.0,2.5],"c":null}"#.into())
+        );
+        Ok(())
+    }
+}
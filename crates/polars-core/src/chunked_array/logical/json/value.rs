@@ -0,0 +1,444 @@
+//! The binary encoding of the values of a [`DataType::Json`] column.
+//!
+//! A value starts with a tag byte. Scalars are followed by their payload, arrays and objects by
+//! tables of offsets, so that an element or a key is found without decoding the values before
+//! it. The keys of an object are sorted: a key lookup is a binary search and equal objects have
+//! equal encodings, regardless of the order of their keys in the JSON text.
+//!
+//! ```text
+//! null, false, true   tag
+//! integer, float      tag, i64 | f64
+//! string              tag, u32 length, bytes
+//! array               tag, u32 n, n x u32 element end, elements
+//! object              tag, u32 n, n x u32 key end, n x u32 value end, keys, values
+//! ```
+//!
+//! Numbers are little endian. The end offsets are relative to the start of the elements, keys
+//! or values.
+use std::fmt::Write;
+
+use crate::prelude::*;
+
+const TAG_NULL: u8 = 0;
+const TAG_FALSE: u8 = 1;
+const TAG_TRUE: u8 = 2;
+const TAG_INTEGER: u8 = 3;
+const TAG_FLOAT: u8 = 4;
+const TAG_STRING: u8 = 5;
+const TAG_ARRAY: u8 = 6;
+const TAG_OBJECT: u8 = 7;
+
+/// The type of a JSON value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JsonValueType {
+    Null,
+    Boolean,
+    Integer,
+    Float,
+    String,
+    Array,
+    Object,
+}
+
+impl JsonValueType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JsonValueType::Null => "null",
+            JsonValueType::Boolean => "boolean",
+            JsonValueType::Integer => "integer",
+            JsonValueType::Float => "float",
+            JsonValueType::String => "string",
+            JsonValueType::Array => "array",
+            JsonValueType::Object => "object",
+        }
+    }
+}
+
+/// A view on an encoded JSON value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct JsonValue<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> JsonValue<'a> {
+    /// View the encoded value in `bytes`, which must come from a [`JsonChunked`] or one of the
+    /// `write_*` functions of this module.
+    pub fn from_bytes(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    pub fn json_type(&self) -> JsonValueType {
+        match self.bytes[0] {
+            TAG_NULL => JsonValueType::Null,
+            TAG_FALSE | TAG_TRUE => JsonValueType::Boolean,
+            TAG_INTEGER => JsonValueType::Integer,
+            TAG_FLOAT => JsonValueType::Float,
+            TAG_STRING => JsonValueType::String,
+            TAG_ARRAY => JsonValueType::Array,
+            TAG_OBJECT => JsonValueType::Object,
+            tag => panic!("invalid json value tag {tag}"),
+        }
+    }
+
+    fn read_u32(&self, pos: usize) -> usize {
+        u32::from_le_bytes(self.bytes[pos..pos + 4].try_into().unwrap()) as usize
+    }
+
+    fn read_u64(&self) -> [u8; 8] {
+        self.bytes[1..9].try_into().unwrap()
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.bytes[0] == TAG_NULL
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.bytes[0] {
+            TAG_FALSE => Some(false),
+            TAG_TRUE => Some(true),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        (self.bytes[0] == TAG_INTEGER).then(|| i64::from_le_bytes(self.read_u64()))
+    }
+
+    /// The value of a number, integers are converted.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self.bytes[0] {
+            TAG_INTEGER => Some(i64::from_le_bytes(self.read_u64()) as f64),
+            TAG_FLOAT => Some(f64::from_le_bytes(self.read_u64())),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&'a str> {
+        if self.bytes[0] != TAG_STRING {
+            return None;
+        }
+        let len = self.read_u32(1);
+        std::str::from_utf8(&self.bytes[5..5 + len]).ok()
+    }
+
+    /// The number of elements of an array or entries of an object, 0 for other values.
+    pub fn len(&self) -> usize {
+        match self.bytes[0] {
+            TAG_ARRAY | TAG_OBJECT => self.read_u32(1),
+            _ => 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The range of item `i` of the table that ends at offset `table` of a region that starts at
+    /// offset `data`.
+    fn item(&self, table: usize, data: usize, i: usize) -> &'a [u8] {
+        let start = if i == 0 {
+            0
+        } else {
+            self.read_u32(table + 4 * (i - 1))
+        };
+        let end = self.read_u32(table + 4 * i);
+        &self.bytes[data + start..data + end]
+    }
+
+    /// The element at index `i` of an array.
+    pub fn get_index(&self, i: usize) -> Option<JsonValue<'a>> {
+        if self.bytes[0] != TAG_ARRAY || i >= self.len() {
+            return None;
+        }
+        let n = self.len();
+        Some(JsonValue::from_bytes(self.item(5, 5 + 4 * n, i)))
+    }
+
+    fn key(&self, i: usize) -> &'a [u8] {
+        let n = self.len();
+        self.item(5, 5 + 8 * n, i)
+    }
+
+    fn value(&self, i: usize) -> JsonValue<'a> {
+        let n = self.len();
+        let keys_len = self.read_u32(5 + 4 * (n - 1));
+        JsonValue::from_bytes(self.item(5 + 4 * n, 5 + 8 * n + keys_len, i))
+    }
+
+    /// The value of `key` in an object.
+    pub fn get_key(&self, key: &str) -> Option<JsonValue<'a>> {
+        if self.bytes[0] != TAG_OBJECT {
+            return None;
+        }
+        let mut lo = 0;
+        let mut hi = self.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            match self.key(mid).cmp(key.as_bytes()) {
+                std::cmp::Ordering::Less => lo = mid + 1,
+                std::cmp::Ordering::Greater => hi = mid,
+                std::cmp::Ordering::Equal => return Some(self.value(mid)),
+            }
+        }
+        None
+    }
+
+    /// The elements of an array.
+    pub fn elements(self) -> impl Iterator<Item = JsonValue<'a>> {
+        let n = if self.bytes[0] == TAG_ARRAY {
+            self.len()
+        } else {
+            0
+        };
+        (0..n).map(move |i| self.get_index(i).unwrap())
+    }
+
+    /// The entries of an object, sorted by key.
+    pub fn entries(self) -> impl Iterator<Item = (&'a str, JsonValue<'a>)> {
+        let n = if self.bytes[0] == TAG_OBJECT {
+            self.len()
+        } else {
+            0
+        };
+        (0..n).map(move |i| {
+            let key = std::str::from_utf8(self.key(i)).unwrap();
+            (key, self.value(i))
+        })
+    }
+
+    /// Write the value as JSON text.
+    pub fn write_text(&self, out: &mut String) {
+        match self.json_type() {
+            JsonValueType::Null => out.push_str("null"),
+            JsonValueType::Boolean => out.push_str(if self.as_bool().unwrap() {
+                "true"
+            } else {
+                "false"
+            }),
+            JsonValueType::Integer => write!(out, "{}", self.as_i64().unwrap()).unwrap(),
+            // The debug format keeps the fraction of integral floats, e.g. `1.0`.
+            JsonValueType::Float => write!(out, "{:?}", self.as_f64().unwrap()).unwrap(),
+            JsonValueType::String => write_escaped(out, self.as_str().unwrap()),
+            JsonValueType::Array => {
+                out.push('[');
+                for (i, element) in self.elements().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    element.write_text(out);
+                }
+                out.push(']');
+            },
+            JsonValueType::Object => {
+                out.push('{');
+                for (i, (key, value)) in self.entries().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_escaped(out, key);
+                    out.push(':');
+                    value.write_text(out);
+                }
+                out.push('}');
+            },
+        }
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        self.write_text(&mut out);
+        out
+    }
+}
+
+fn write_escaped(out: &mut String, s: &str) {
+    out.push_str(&serde_json::to_string(s).unwrap())
+}
+
+fn write_len(out: &mut Vec<u8>, len: usize) -> PolarsResult<()> {
+    let len = u32::try_from(len)
+        .map_err(|_| polars_err!(ComputeError: "json value is too large to encode"))?;
+    out.extend_from_slice(&len.to_le_bytes());
+    Ok(())
+}
+
+pub fn write_null(out: &mut Vec<u8>) {
+    out.push(TAG_NULL)
+}
+
+pub fn write_bool(out: &mut Vec<u8>, v: bool) {
+    out.push(if v { TAG_TRUE } else { TAG_FALSE })
+}
+
+pub fn write_i64(out: &mut Vec<u8>, v: i64) {
+    out.push(TAG_INTEGER);
+    out.extend_from_slice(&v.to_le_bytes());
+}
+
+/// Write a float, `NaN` and infinities have no JSON representation and are written as null.
+pub fn write_f64(out: &mut Vec<u8>, v: f64) {
+    if v.is_finite() {
+        out.push(TAG_FLOAT);
+        out.extend_from_slice(&v.to_le_bytes());
+    } else {
+        write_null(out)
+    }
+}
+
+pub fn write_str(out: &mut Vec<u8>, v: &str) -> PolarsResult<()> {
+    out.push(TAG_STRING);
+    write_len(out, v.len())?;
+    out.extend_from_slice(v.as_bytes());
+    Ok(())
+}
+
+/// Write an array of encoded elements.
+pub fn write_array(out: &mut Vec<u8>, elements: &[&[u8]]) -> PolarsResult<()> {
+    out.push(TAG_ARRAY);
+    write_len(out, elements.len())?;
+    let mut end = 0;
+    for element in elements {
+        end += element.len();
+        write_len(out, end)?;
+    }
+    for element in elements {
+        out.extend_from_slice(element);
+    }
+    Ok(())
+}
+
+/// Write an object of keys and encoded values. If a key occurs more than once, the last value
+/// is kept.
+pub fn write_object(out: &mut Vec<u8>, entries: &mut [(&str, &[u8])]) -> PolarsResult<()> {
+    // The sort is stable, so the last of the duplicate keys is the last of its run.
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    let mut deduped: Vec<(&str, &[u8])> = Vec::with_capacity(entries.len());
+    for &(key, value) in entries.iter() {
+        match deduped.last_mut() {
+            Some(last) if last.0 == key => last.1 = value,
+            _ => deduped.push((key, value)),
+        }
+    }
+
+    out.push(TAG_OBJECT);
+    write_len(out, deduped.len())?;
+    let mut end = 0;
+    for (key, _) in &deduped {
+        end += key.len();
+        write_len(out, end)?;
+    }
+    let mut end = 0;
+    for (_, value) in &deduped {
+        end += value.len();
+        write_len(out, end)?;
+    }
+    for (key, _) in &deduped {
+        out.extend_from_slice(key.as_bytes());
+    }
+    for (_, value) in &deduped {
+        out.extend_from_slice(value);
+    }
+    Ok(())
+}
+
+fn write_serde_value(out: &mut Vec<u8>, value: &serde_json::Value) -> PolarsResult<()> {
+    use serde_json::Value;
+    match value {
+        Value::Null => write_null(out),
+        Value::Bool(v) => write_bool(out, *v),
+        Value::Number(v) => match v.as_i64() {
+            Some(v) => write_i64(out, v),
+            // Integers beyond the range of i64 become floats.
+            None => write_f64(out, v.as_f64().unwrap()),
+        },
+        Value::String(v) => write_str(out, v)?,
+        Value::Array(values) => {
+            let encoded = values
+                .iter()
+                .map(|v| {
+                    let mut buf = vec![];
+                    write_serde_value(&mut buf, v).map(|_| buf)
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let elements = encoded.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
+            write_array(out, &elements)?
+        },
+        Value::Object(map) => {
+            let encoded = map
+                .values()
+                .map(|v| {
+                    let mut buf = vec![];
+                    write_serde_value(&mut buf, v).map(|_| buf)
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let mut entries = map
+                .keys()
+                .map(|k| k.as_str())
+                .zip(encoded.iter().map(|v| v.as_slice()))
+                .collect::<Vec<_>>();
+            write_object(out, &mut entries)?
+        },
+    }
+    Ok(())
+}
+
+/// Parse JSON text and write its encoding.
+pub fn parse_json(out: &mut Vec<u8>, text: &str) -> PolarsResult<()> {
+    let value: serde_json::Value = serde_json::from_str(text)
+        .map_err(|e| polars_err!(ComputeError: "invalid json value {:?}: {}", text, e))?;
+    write_serde_value(out, &value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn encode(text: &str) -> Vec<u8> {
+        let mut out = vec![];
+        parse_json(&mut out, text).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_json_value_roundtrip() {
+        for text in [
+            "null",
+            "true",
+            "-12",
+            "1.0",
+            "0.25",
+            r#""a \"b\"""#,
+            "[]",
+            "{}",
+            r#"[1,[2,"x"],{"k":null}]"#,
+            r#"{"a":{"b":[0,1.5]},"c":"d"}"#,
+        ] {
+            assert_eq!(JsonValue::from_bytes(&encode(text)).to_text(), text);
+        }
+    }
+
+    #[test]
+    fn test_json_value_access() {
+        let bytes = encode(r#"{"z": 1, "a": {"b": [10, "x", null]}, "m": true, "z": 2}"#);
+        let v = JsonValue::from_bytes(&bytes);
+        assert_eq!(v.json_type(), JsonValueType::Object);
+        // Keys are sorted and the last duplicate wins.
+        assert_eq!(v.to_text(), r#"{"a":{"b":[10,"x",null]},"m":true,"z":2}"#);
+        assert_eq!(v.get_key("z").unwrap().as_i64(), Some(2));
+        assert_eq!(v.get_key("m").unwrap().as_bool(), Some(true));
+        assert!(v.get_key("q").is_none());
+
+        let b = v.get_key("a").unwrap().get_key("b").unwrap();
+        assert_eq!(b.len(), 3);
+        assert_eq!(b.get_index(0).unwrap().as_f64(), Some(10.0));
+        assert_eq!(b.get_index(1).unwrap().as_str(), Some("x"));
+        assert!(b.get_index(2).unwrap().is_null());
+        assert!(b.get_index(3).is_none());
+
+        assert_eq!(encode(r#"{"a": 1, "b": 2}"#), encode(r#"{"b": 2, "a": 1}"#));
+    }
+}
@@ -12,6 +12,12 @@ mod decimal;
 pub use decimal::*;
 #[cfg(feature = "dtype-duration")]
 mod duration;
+#[cfg(feature = "dtype-extension")]
+mod extension;
+#[cfg(feature = "dtype-f16")]
+mod float16;
+#[cfg(feature = "dtype-json")]
+mod json;
 #[cfg(feature = "dtype-duration")]
 pub use duration::*;
 #[cfg(feature = "dtype-categorical")]
@@ -26,6 +32,12 @@ use std::ops::{Deref, DerefMut};
 
 #[cfg(feature = "dtype-categorical")]
 pub use categorical::*;
+#[cfg(feature = "dtype-extension")]
+pub use extension::*;
+#[cfg(feature = "dtype-f16")]
+pub use float16::*;
+#[cfg(feature = "dtype-json")]
+pub use json::*;
 #[cfg(feature = "dtype-struct")]
 pub use struct_::*;
 #[cfg(feature = "dtype-time")]
@@ -976,4 +976,40 @@ mod test {
             Some(4.6)
         );
     }
+
+    #[test]
+    #[cfg(feature = "dtype-array")]
+    fn test_quantiles() {
+        use super::quantiles_slice;
+
+        let values = [
+            Some(7),
+            Some(1),
+            None,
+            Some(4),
+            Some(2),
+            Some(9),
+            Some(3),
+            Some(3),
+        ];
+        let ca = Int32Chunked::new("", &values);
+        let quantiles = [0.9, 0.1, 0.5, 0.0, 1.0, 0.5, 0.35];
+        for interpol in [
+            QuantileInterpolOptions::Nearest,
+            QuantileInterpolOptions::Lower,
+            QuantileInterpolOptions::Higher,
+            QuantileInterpolOptions::Midpoint,
+            QuantileInterpolOptions::Linear,
+        ] {
+            let mut vals = values.iter().flatten().copied().collect::<Vec<_>>();
+            let out = quantiles_slice(&mut vals, 1, &quantiles, interpol);
+            for (q, v) in quantiles.iter().zip(out) {
+                assert_eq!(v, ca.quantile(*q, interpol).unwrap(), "{q} {interpol:?}");
+            }
+        }
+        assert_eq!(
+            quantiles_slice::<i32>(&mut [], 0, &[0.5], QuantileInterpolOptions::Linear),
+            [None]
+        );
+    }
 }
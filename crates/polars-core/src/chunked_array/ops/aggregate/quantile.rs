@@ -97,6 +97,68 @@ fn quantile_slice<T: ToPrimitive + TotalOrd + Copy>(
     }
 }
 
+/// Check that every quantile is between 0 and 1.
+#[cfg(feature = "dtype-array")]
+pub(crate) fn check_quantiles(quantiles: &[f64]) -> PolarsResult<()> {
+    polars_ensure!(!quantiles.is_empty(), ComputeError: "at least one quantile is required");
+    for q in quantiles {
+        polars_ensure!(
+            (0.0..=1.0).contains(q),
+            ComputeError: "quantiles should be between 0.0 and 1.0, got {}", q
+        );
+    }
+    Ok(())
+}
+
+/// The `quantiles` of `vals`, which are reordered. The quantiles must be checked with
+/// [`check_quantiles`]. `null_count` is the number of nulls that were left out of `vals`, the
+/// quantile positions account for them the same way a quantile of a nullable array does.
+///
+/// The quantiles are selected from the lowest to the highest, every selection only reorders the
+/// values above the previous quantile. This costs about as much as selecting a single quantile,
+/// rather than a selection per quantile.
+#[cfg(feature = "dtype-array")]
+pub(crate) fn quantiles_slice<T: ToPrimitive + TotalOrd + Copy>(
+    vals: &mut [T],
+    null_count: usize,
+    quantiles: &[f64],
+    interpol: QuantileInterpolOptions,
+) -> Vec<Option<f64>> {
+    let mut out = vec![None; quantiles.len()];
+    if vals.is_empty() {
+        return out;
+    }
+
+    let mut order = (0..quantiles.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| quantiles[a].total_cmp(&quantiles[b]));
+    // The values before `start` are at most the value at `start`, the values after it at least.
+    let mut start = 0;
+    for i in order {
+        let (idx, float_idx, top_idx) =
+            quantile_idx(quantiles[i], vals.len() + null_count, null_count, interpol);
+        // The nulls sort before the values.
+        let pos = idx - null_count;
+        let (_lhs, lower, rhs) =
+            vals[start..].select_nth_unstable_by(pos - start, TotalOrd::tot_cmp);
+        let lower = lower.to_f64().unwrap();
+        out[i] = Some(if idx == top_idx {
+            lower
+        } else {
+            let upper = || {
+                let upper = rhs.iter().copied().min_by(TotalOrd::tot_cmp).unwrap();
+                upper.to_f64().unwrap()
+            };
+            match interpol {
+                QuantileInterpolOptions::Midpoint => midpoint_interpol(lower, upper()),
+                QuantileInterpolOptions::Linear => linear_interpol(lower, upper(), idx, float_idx),
+                _ => lower,
+            }
+        });
+        start = pos;
+    }
+    out
+}
+
 fn generic_quantile<T>(
     ca: ChunkedArray<T>,
     quantile: f64,
@@ -34,6 +34,11 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
     match dtype {
         DataType::String => downcast_and_pack!(Utf8ViewArray, String),
         DataType::Binary => downcast_and_pack!(BinaryViewArray, Binary),
+        #[cfg(feature = "dtype-json")]
+        DataType::Json => {
+            let v = downcast!(BinaryViewArray);
+            AnyValue::StringOwned(JsonValue::from_bytes(v).to_text().into())
+        },
         DataType::Boolean => downcast_and_pack!(BooleanArray, Boolean),
         DataType::UInt8 => downcast_and_pack!(UInt8Array, UInt8),
         DataType::UInt16 => downcast_and_pack!(UInt16Array, UInt16),
@@ -93,6 +98,12 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let v = arr.value_unchecked(idx);
             AnyValue::Datetime(v, *tu, tz)
         },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let arr = &*(arr as *const dyn Array as *const UInt16Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Float32(arrow::types::f16::from_bits(v).to_f32())
+        },
         #[cfg(feature = "dtype-date")]
         DataType::Date => {
             let arr = &*(arr as *const dyn Array as *const Int32Array);
@@ -225,3 +225,29 @@ impl<'a> IntoTotalOrdInner<'a> for &'a CategoricalChunked {
         }
     }
 }
+
+#[cfg(feature = "dtype-f16")]
+struct Float16Values<'a>(&'a UInt16Chunked);
+
+#[cfg(feature = "dtype-f16")]
+impl<'a> GetInner for Float16Values<'a> {
+    type Item = Option<arrow::types::f16>;
+    unsafe fn get_unchecked(&self, idx: usize) -> Self::Item {
+        let bits = self.0.get_unchecked(idx)?;
+        Some(arrow::types::f16::from_bits(bits))
+    }
+}
+
+#[cfg(feature = "dtype-f16")]
+impl<'a> IntoTotalEqInner<'a> for &'a Float16Chunked {
+    fn into_total_eq_inner(self) -> Box<dyn TotalEqInner + 'a> {
+        Box::new(Float16Values(&self.0))
+    }
+}
+
+#[cfg(feature = "dtype-f16")]
+impl<'a> IntoTotalOrdInner<'a> for &'a Float16Chunked {
+    fn into_total_ord_inner(self) -> Box<dyn TotalOrdInner + 'a> {
+        Box::new(Float16Values(&self.0))
+    }
+}
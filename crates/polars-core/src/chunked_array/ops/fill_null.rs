@@ -66,6 +66,20 @@ impl Series {
     /// ```
     pub fn fill_null(&self, strategy: FillNullStrategy) -> PolarsResult<Series> {
         let logical_type = self.dtype();
+
+        #[cfg(feature = "dtype-f16")]
+        if logical_type == &DataType::Float16 {
+            // The largest finite f16.
+            const F16_MAX: f32 = 65504.0;
+            let ca = self.float16().unwrap().to_float32();
+            let out = match strategy {
+                FillNullStrategy::MinBound => ca.fill_null_with_values(-F16_MAX)?,
+                FillNullStrategy::MaxBound => ca.fill_null_with_values(F16_MAX)?,
+                strategy => fill_null_numeric(&ca, strategy)?,
+            };
+            return Ok(out.to_float16().into_series());
+        }
+
         let s = self.to_physical_repr();
 
         use DataType::*;
@@ -13,7 +13,11 @@ where
             r
         }
     };
-    arity::binary_elementwise_values(left, right, op)
+    if left.null_count() == 0 && right.null_count() == 0 {
+        arity::binary_elementwise_values(left, right, op)
+    } else {
+        arity::binary_elementwise(left, right, |l, r| null_ignoring(l, r, op))
+    }
 }
 
 fn max_binary<T>(left: &ChunkedArray<T>, right: &ChunkedArray<T>) -> ChunkedArray<T>
@@ -28,7 +32,21 @@ where
             r
         }
     };
-    arity::binary_elementwise_values(left, right, op)
+    if left.null_count() == 0 && right.null_count() == 0 {
+        arity::binary_elementwise_values(left, right, op)
+    } else {
+        arity::binary_elementwise(left, right, |l, r| null_ignoring(l, r, op))
+    }
+}
+
+/// Apply `op` if both values are valid, otherwise return the valid one.
+#[inline]
+fn null_ignoring<T>(l: Option<T>, r: Option<T>, op: impl Fn(T, T) -> T) -> Option<T> {
+    match (l, r) {
+        (Some(l), Some(r)) => Some(op(l, r)),
+        (l, None) => l,
+        (None, r) => r,
+    }
 }
 
 pub(crate) fn min_max_binary_series(
@@ -36,11 +54,7 @@ pub(crate) fn min_max_binary_series(
     right: &Series,
     min: bool,
 ) -> PolarsResult<Series> {
-    if left.dtype().to_physical().is_numeric()
-        && left.null_count() == 0
-        && right.null_count() == 0
-        && left.len() == right.len()
-    {
+    if left.dtype().to_physical().is_numeric() && left.len() == right.len() {
         let (lhs, rhs) = coerce_lhs_rhs(left, right)?;
         let logical = lhs.dtype();
         let lhs = lhs.to_physical_repr();
@@ -40,6 +40,7 @@ pub mod zip;
 
 #[cfg(feature = "serde-lazy")]
 use serde::{Deserialize, Serialize};
+pub use sort::collation::*;
 pub use sort::options::*;
 
 use crate::chunked_array::cast::CastOptions;
@@ -10,13 +10,17 @@ struct CompareRow<'a> {
 
 impl PartialEq for CompareRow<'_> {
     fn eq(&self, other: &Self) -> bool {
-        self.bytes == other.bytes
+        self.idx == other.idx && self.bytes == other.bytes
     }
 }
 
+// Equal rows are ordered by their index, such that the unstable selection and sorts below give
+// the same result as a stable sort.
 impl Ord for CompareRow<'_> {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.bytes.cmp(other.bytes)
+        self.bytes
+            .cmp(other.bytes)
+            .then_with(|| self.idx.cmp(&other.idx))
     }
 }
 
@@ -28,7 +32,8 @@ impl PartialOrd for CompareRow<'_> {
 
 /// Return the indices of the bottom k elements.
 ///
-/// Similar to .argsort() then .slice(0, k) but with a more efficient implementation.
+/// Similar to .argsort() then .slice(0, k) but with a more efficient implementation. Equal
+/// rows keep their order.
 pub fn _arg_bottom_k(
     k: usize,
     by_column: &[Series],
@@ -51,27 +56,14 @@ pub fn _arg_bottom_k(
         .collect::<Vec<_>>();
 
     let sorted = if k >= from_n_rows {
-        match (sort_options.multithreaded, sort_options.maintain_order) {
-            (true, true) => POOL.install(|| {
-                rows.par_sort();
-            }),
-            (true, false) => POOL.install(|| {
-                rows.par_sort_unstable();
-            }),
-            (false, true) => rows.sort(),
-            (false, false) => rows.sort_unstable(),
-        }
-        &rows
-    } else if sort_options.maintain_order {
-        // todo: maybe there is some more efficient method, comparable to select_nth_unstable
         if sort_options.multithreaded {
             POOL.install(|| {
-                rows.par_sort();
+                rows.par_sort_unstable();
             })
         } else {
-            rows.sort();
+            rows.sort_unstable();
         }
-        &rows[..k]
+        &rows
     } else {
         // todo: possible multi threaded `select_nth_unstable`?
         let (lower, _el, _upper) = rows.select_nth_unstable(k);
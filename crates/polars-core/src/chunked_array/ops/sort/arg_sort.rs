@@ -1,16 +1,63 @@
+use arrow::array::View;
+
 use super::*;
 
+/// A value of a binary view array. Values are compared by the prefixes inlined in their views
+/// first, so that the buffers are only read if the prefixes are equal.
+#[derive(Clone, Copy)]
+pub(super) struct ViewValue<'a> {
+    view: View,
+    buffers: &'a [Buffer<u8>],
+}
+
+impl<'a> ViewValue<'a> {
+    pub(super) fn iter(arr: &'a BinaryViewArray) -> impl ExactSizeIterator<Item = Self> + 'a {
+        let buffers = arr.data_buffers().as_ref();
+        arr.views().iter().map(move |view| ViewValue {
+            view: *view,
+            buffers,
+        })
+    }
+}
+
+impl TotalEq for ViewValue<'_> {
+    #[inline]
+    fn tot_eq(&self, other: &Self) -> bool {
+        // SAFETY: the views belong to the buffers.
+        unsafe {
+            self.view
+                .eq_unchecked(&other.view, self.buffers, other.buffers)
+        }
+    }
+}
+
+impl TotalOrd for ViewValue<'_> {
+    #[inline]
+    fn tot_cmp(&self, other: &Self) -> Ordering {
+        // SAFETY: the views belong to the buffers.
+        unsafe {
+            self.view
+                .cmp_unchecked(&other.view, self.buffers, other.buffers)
+        }
+    }
+}
+
 // Reduce monomorphisation.
 fn sort_impl<T>(vals: &mut [(IdxSize, T)], options: SortOptions)
 where
     T: TotalOrd + Send + Sync,
 {
-    sort_by_branch(
-        vals,
-        options.descending,
-        |a, b| a.1.tot_cmp(&b.1),
-        options.multithreaded,
-    );
+    if options.is_stable() {
+        sort_by_branch(
+            vals,
+            options.descending,
+            |a, b| a.1.tot_cmp(&b.1),
+            options.multithreaded,
+        );
+    } else {
+        // Pattern-defeating quicksort, which is notably faster on large numeric keys.
+        sort_unstable_by_branch(vals, options, |a, b| a.1.tot_cmp(&b.1));
+    }
 }
 
 pub(super) fn arg_sort<I, J, T>(
@@ -9,16 +9,23 @@ use crate::utils::_split_offsets;
 pub(crate) fn args_validate<T: PolarsDataType>(
     ca: &ChunkedArray<T>,
     other: &[Series],
-    descending: &[bool],
+    options: &SortMultipleOptions,
 ) -> PolarsResult<()> {
     for s in other {
         assert_eq!(ca.len(), s.len());
     }
+    let descending = &options.descending;
     polars_ensure!(other.len() == (descending.len() - 1),
         ComputeError:
         "the amount of ordering booleans: {} does not match the number of series: {}",
         descending.len(), other.len() + 1,
     );
+    let nulls_last = &options.nulls_last;
+    polars_ensure!(nulls_last.len() == 1 || other.len() == (nulls_last.len() - 1),
+        ComputeError:
+        "the amount of `nulls_last` booleans: {} does not match the number of series: {}",
+        nulls_last.len(), other.len() + 1,
+    );
     Ok(())
 }
 
@@ -27,7 +34,9 @@ pub(crate) fn arg_sort_multiple_impl<T: NullOrderCmp + Send + Copy>(
     by: &[Series],
     options: &SortMultipleOptions,
 ) -> PolarsResult<IdxCa> {
-    let nulls_last = &options.nulls_last;
+    // A single `nulls_last` applies to every column, not only to the first one.
+    let mut nulls_last = options.nulls_last.clone();
+    _broadcast_bools(by.len() + 1, &mut nulls_last);
     let descending = &options.descending;
 
     debug_assert_eq!(descending.len() - 1, by.len());
@@ -69,7 +78,7 @@ pub(crate) fn arg_sort_multiple_impl<T: NullOrderCmp + Send + Copy>(
         }
     };
 
-    match (options.multithreaded, options.maintain_order) {
+    match (options.multithreaded, options.is_stable()) {
         (true, true) => POOL.install(|| {
             vals.par_sort_by(compare);
         }),
@@ -53,6 +53,8 @@ impl CategoricalChunked {
             descending,
             multithreaded: true,
             maintain_order: false,
+            stable: true,
+            collation: None,
         })
     }
 
@@ -80,7 +82,7 @@ impl CategoricalChunked {
         options: &SortMultipleOptions,
     ) -> PolarsResult<IdxCa> {
         if self.uses_lexical_ordering() {
-            args_validate(self.physical(), by, &options.descending)?;
+            args_validate(self.physical(), by, options)?;
             let mut count: IdxSize = 0;
 
             // we use bytes to save a monomorphisized str impl
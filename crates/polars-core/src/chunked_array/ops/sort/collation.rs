@@ -0,0 +1,124 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::prelude::*;
+
+/// A locale-aware ordering of strings, instead of the order of their bytes.
+///
+/// The collation is given as a BCP 47 language tag such as `de-AT`. Like the ICU collations of
+/// databases, the strength is set with the `ks` Unicode extension keyword: `de-AT-u-ks-level2`
+/// ignores case and `de-AT-u-ks-level1` ignores both case and accents.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Collation {
+    // Interned, so that the sort options stay `Copy`.
+    locale: &'static str,
+}
+
+static LOCALES: Lazy<Mutex<PlHashSet<&'static str>>> = Lazy::new(Default::default);
+
+impl Collation {
+    /// Create a collation, errors if the language tag is invalid.
+    pub fn new(locale: &str) -> PolarsResult<Self> {
+        #[cfg(feature = "collation")]
+        {
+            polars_compute::collation::collator(locale)?;
+            let mut locales = LOCALES.lock().unwrap();
+            let locale = match locales.get(locale) {
+                Some(locale) => *locale,
+                None => {
+                    let locale: &'static str = Box::leak(locale.into());
+                    locales.insert(locale);
+                    locale
+                },
+            };
+            Ok(Self { locale })
+        }
+        #[cfg(not(feature = "collation"))]
+        {
+            let _ = (locale, &LOCALES);
+            polars_bail!(InvalidOperation: "activate 'collation' feature")
+        }
+    }
+
+    pub fn locale(&self) -> &'static str {
+        self.locale
+    }
+}
+
+impl Debug for Collation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Collation({})", self.locale)
+    }
+}
+
+impl Display for Collation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.locale)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Collation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.locale)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Collation {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let locale = String::deserialize(deserializer)?;
+        Collation::new(&locale).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Compute the collation keys of multiple string arrays at once, so that the keys can be compared
+/// between them.
+///
+/// The keys are dense indices with the order of the collation, strings that are equal under the
+/// collation have equal keys.
+pub fn collation_keys(cas: &[&StringChunked], collation: Collation) -> Vec<IdxCa> {
+    #[cfg(feature = "collation")]
+    {
+        // The locale was validated when the collation was created.
+        let collator = polars_compute::collation::collator(collation.locale).unwrap();
+        let arrays = cas
+            .iter()
+            .flat_map(|ca| ca.downcast_iter())
+            .collect::<Vec<_>>();
+        let mut keys = polars_compute::collation::collation_keys(&arrays, &collator).into_iter();
+        cas.iter()
+            .map(|ca| {
+                let chunks = keys.by_ref().take(ca.chunks().len());
+                IdxCa::from_chunk_iter(ca.name(), chunks)
+            })
+            .collect()
+    }
+    #[cfg(not(feature = "collation"))]
+    {
+        let _ = (cas, collation);
+        panic!("activate 'collation' feature")
+    }
+}
+
+impl StringChunked {
+    /// The collation keys of the strings, see [`collation_keys`].
+    pub fn collation_keys(&self, collation: Collation) -> IdxCa {
+        collation_keys(&[self], collation).pop().unwrap()
+    }
+}
+
+/// Replace a string column by its collation keys, so that it can be sorted by the collation
+/// together with other columns.
+pub fn _collated_sort_column(s: Series, collation: Option<Collation>) -> Series {
+    match (collation, s.dtype()) {
+        (Some(collation), DataType::String) => {
+            s.str().unwrap().collation_keys(collation).into_series()
+        },
+        _ => s,
+    }
+}
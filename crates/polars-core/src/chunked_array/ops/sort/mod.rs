@@ -5,12 +5,15 @@ pub mod arg_sort_multiple;
 pub mod arg_bottom_k;
 pub mod options;
 
+pub mod collation;
+
 #[cfg(feature = "dtype-categorical")]
 mod categorical;
 
 use std::cmp::Ordering;
 
 pub(crate) use arg_sort_multiple::argsort_multiple_row_fmt;
+use arrow::bitmap::utils::ZipValidity;
 use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::buffer::Buffer;
 use arrow::legacy::trusted_len::TrustedLenPush;
@@ -238,7 +241,7 @@ fn arg_sort_multiple_numeric<T: PolarsNumericType>(
     by: &[Series],
     options: &SortMultipleOptions,
 ) -> PolarsResult<IdxCa> {
-    args_validate(ca, by, &options.descending)?;
+    args_validate(ca, by, options)?;
     let mut count: IdxSize = 0;
 
     let no_nulls = ca.null_count() == 0;
@@ -320,6 +323,11 @@ fn ordering_other_columns<'a>(
 
 impl ChunkSort<StringType> for StringChunked {
     fn sort_with(&self, options: SortOptions) -> ChunkedArray<StringType> {
+        if options.collation.is_some() {
+            let idx = self.arg_sort(options);
+            // SAFETY: the sort indices are in bounds.
+            return unsafe { self.take_unchecked(&idx) };
+        }
         unsafe { self.as_binary().sort_with(options).to_string_unchecked() }
     }
 
@@ -329,10 +337,17 @@ impl ChunkSort<StringType> for StringChunked {
             nulls_last: false,
             multithreaded: true,
             maintain_order: false,
+            stable: true,
+            collation: None,
         })
     }
 
     fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        if let Some(collation) = options.collation {
+            return self
+                .collation_keys(collation)
+                .arg_sort(options.with_collation(None));
+        }
         self.as_binary().arg_sort(options)
     }
 
@@ -349,6 +364,15 @@ impl ChunkSort<StringType> for StringChunked {
         by: &[Series],
         options: &SortMultipleOptions,
     ) -> PolarsResult<IdxCa> {
+        if let Some(collation) = options.collation {
+            let by = by
+                .iter()
+                .map(|s| _collated_sort_column(s.clone(), Some(collation)))
+                .collect::<Vec<_>>();
+            return self
+                .collation_keys(collation)
+                .arg_sort_multiple(&by, options);
+        }
         self.as_binary().arg_sort_multiple(by, options)
     }
 }
@@ -366,9 +390,9 @@ impl ChunkSort<BinaryType> for BinaryChunked {
 
         let (partitioned_part, validity) = partition_nulls(&mut views, validity, options);
 
+        // Most comparisons are decided by the prefixes inlined in the views.
         sort_unstable_by_branch(partitioned_part, options, |a, b| unsafe {
-            a.get_slice_unchecked(&buffers)
-                .tot_cmp(&b.get_slice_unchecked(&buffers))
+            a.cmp_unchecked(b, &buffers, &buffers)
         });
 
         let array = unsafe {
@@ -399,21 +423,27 @@ impl ChunkSort<BinaryType> for BinaryChunked {
             nulls_last: false,
             multithreaded: true,
             maintain_order: false,
+            stable: true,
+            collation: None,
         })
     }
 
     fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        use arg_sort::ViewValue;
+
         if self.null_count() == 0 {
             arg_sort::arg_sort_no_nulls(
                 self.name(),
-                self.downcast_iter().map(|arr| arr.values_iter()),
+                self.downcast_iter().map(ViewValue::iter),
                 options,
                 self.len(),
             )
         } else {
             arg_sort::arg_sort(
                 self.name(),
-                self.downcast_iter().map(|arr| arr.iter()),
+                self.downcast_iter().map(|arr| {
+                    ZipValidity::new_with_validity(ViewValue::iter(arr), arr.validity())
+                }),
                 options,
                 self.null_count(),
                 self.len(),
@@ -426,7 +456,7 @@ impl ChunkSort<BinaryType> for BinaryChunked {
         by: &[Series],
         options: &SortMultipleOptions,
     ) -> PolarsResult<IdxCa> {
-        args_validate(self, by, &options.descending)?;
+        args_validate(self, by, options)?;
 
         let mut count: IdxSize = 0;
 
@@ -528,6 +558,8 @@ impl ChunkSort<BinaryOffsetType> for BinaryOffsetChunked {
             nulls_last: false,
             multithreaded: true,
             maintain_order: false,
+            stable: true,
+            collation: None,
         })
     }
 
@@ -568,7 +600,7 @@ impl ChunkSort<BinaryOffsetType> for BinaryOffsetChunked {
         by: &[Series],
         options: &SortMultipleOptions,
     ) -> PolarsResult<IdxCa> {
-        args_validate(self, by, &options.descending)?;
+        args_validate(self, by, options)?;
 
         let mut count: IdxSize = 0;
 
@@ -641,6 +673,8 @@ impl ChunkSort<BooleanType> for BooleanChunked {
             nulls_last: false,
             multithreaded: true,
             maintain_order: false,
+            stable: true,
+            collation: None,
         })
     }
 
@@ -704,7 +738,7 @@ pub(crate) fn convert_sort_column_multi_sort(s: &Series) -> PolarsResult<Series>
         List(inner) if !inner.is_nested() => s.clone(),
         Null => s.clone(),
         _ => {
-            let phys = s.to_physical_repr().into_owned();
+            let phys = s.to_physical_value_repr().into_owned();
             polars_ensure!(
                 phys.dtype().is_numeric(),
                 InvalidOperation: "cannot sort column of dtype `{}`", s.dtype()
@@ -799,6 +833,8 @@ mod test {
             nulls_last: false,
             multithreaded: true,
             maintain_order: false,
+            stable: true,
+            collation: None,
         });
         assert_eq!(
             Vec::from(&out),
@@ -818,6 +854,8 @@ mod test {
             nulls_last: true,
             multithreaded: true,
             maintain_order: false,
+            stable: true,
+            collation: None,
         });
         assert_eq!(
             Vec::from(&out),
@@ -897,6 +935,37 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_arg_sort_stable() -> PolarsResult<()> {
+        let a = Int32Chunked::new("a", &[Some(2), Some(1), None, Some(2), Some(1)]);
+        let out = a.arg_sort(SortOptions::default().with_order_descending(true));
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(2), Some(0), Some(3), Some(1), Some(4)]
+        );
+
+        let out = a.arg_sort(SortOptions::default().with_stable(false));
+        let sorted = unsafe { a.take_unchecked(&out) };
+        assert_eq!(
+            Vec::from(&sorted),
+            &[None, Some(1), Some(1), Some(2), Some(2)]
+        );
+
+        // A single `nulls_last` applies to every column.
+        let b = Int32Chunked::new("b", &[Some(1), None, Some(1), Some(2), Some(0)]);
+        let out = a.arg_sort_multiple(
+            &[b.into_series()],
+            &SortMultipleOptions::default()
+                .with_order_descending_multi([false, false])
+                .with_nulls_last(true),
+        )?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(4), Some(1), Some(0), Some(3), Some(2)]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_sort_string() {
         let ca = StringChunked::new("a", &[Some("a"), None, Some("c"), None, Some("b")]);
@@ -905,6 +974,8 @@ mod test {
             nulls_last: false,
             multithreaded: true,
             maintain_order: false,
+            stable: true,
+            collation: None,
         });
         let expected = &[None, None, Some("a"), Some("b"), Some("c")];
         assert_eq!(Vec::from(&out), expected);
@@ -914,6 +985,8 @@ mod test {
             nulls_last: false,
             multithreaded: true,
             maintain_order: false,
+            stable: true,
+            collation: None,
         });
 
         let expected = &[None, None, Some("c"), Some("b"), Some("a")];
@@ -924,6 +997,8 @@ mod test {
             nulls_last: true,
             multithreaded: true,
             maintain_order: false,
+            stable: true,
+            collation: None,
         });
         let expected = &[Some("a"), Some("b"), Some("c"), None, None];
         assert_eq!(Vec::from(&out), expected);
@@ -933,6 +1008,8 @@ mod test {
             nulls_last: true,
             multithreaded: true,
             maintain_order: false,
+            stable: true,
+            collation: None,
         });
         let expected = &[Some("c"), Some("b"), Some("a"), None, None];
         assert_eq!(Vec::from(&out), expected);
@@ -947,4 +1024,37 @@ mod test {
         let expected = &[Some("c"), Some("b"), Some("a")];
         assert_eq!(Vec::from(&out), expected);
     }
+
+    #[test]
+    fn test_sort_string_prefixes() {
+        // Values that share their first four bytes must be compared on their full bytes, both
+        // inlined in the view and stored in a buffer.
+        let values = [
+            Some("abcdefghijklmnop"),
+            Some("abcd"),
+            None,
+            Some("abcdefghijklmnoa"),
+            Some("abce"),
+            Some("abcdefgh"),
+            Some("ab"),
+        ];
+        let ca = StringChunked::new("a", &values);
+        let mut expected = values.to_vec();
+        expected.sort();
+        assert_eq!(Vec::from(&ca.sort(false)), expected);
+
+        let idx = ca.arg_sort(SortOptions::default());
+        assert_eq!(
+            Vec::from(&idx),
+            &[
+                Some(2),
+                Some(6),
+                Some(1),
+                Some(5),
+                Some(3),
+                Some(0),
+                Some(4)
+            ]
+        );
+    }
 }
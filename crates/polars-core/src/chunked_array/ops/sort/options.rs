@@ -41,6 +41,15 @@ pub struct SortOptions {
     /// If true maintain the order of equal elements.
     /// Default `false`.
     pub maintain_order: bool,
+    /// If false equal elements may be reordered, which lets large sorts use a faster unstable
+    /// algorithm. Ignored if `maintain_order` is set.
+    /// Default `true`.
+    #[cfg_attr(feature = "serde-lazy", serde(default = "default_stable"))]
+    pub stable: bool,
+    /// Order strings by this collation instead of by their bytes.
+    /// Default `None`.
+    #[cfg_attr(feature = "serde-lazy", serde(default))]
+    pub collation: Option<Collation>,
 }
 
 /// Sort options for multi-series sorting.
@@ -96,6 +105,19 @@ pub struct SortMultipleOptions {
     pub multithreaded: bool,
     /// Whether maintain the order of equal elements. Default `false`.
     pub maintain_order: bool,
+    /// Whether equal elements keep their order in the in-memory engine. Default `true`.
+    ///
+    /// Unlike `maintain_order`, this doesn't prevent the streaming engine from sorting.
+    #[cfg_attr(feature = "serde-lazy", serde(default = "default_stable"))]
+    pub stable: bool,
+    /// Order string columns by this collation instead of by their bytes. Default `None`.
+    #[cfg_attr(feature = "serde-lazy", serde(default))]
+    pub collation: Option<Collation>,
+}
+
+#[cfg(feature = "serde-lazy")]
+fn default_stable() -> bool {
+    true
 }
 
 impl Default for SortOptions {
@@ -105,6 +127,8 @@ impl Default for SortOptions {
             nulls_last: false,
             multithreaded: true,
             maintain_order: false,
+            stable: true,
+            collation: None,
         }
     }
 }
@@ -116,6 +140,8 @@ impl Default for SortMultipleOptions {
             nulls_last: vec![false],
             multithreaded: true,
             maintain_order: false,
+            stable: true,
+            collation: None,
         }
     }
 }
@@ -173,6 +199,23 @@ impl SortMultipleOptions {
         self
     }
 
+    /// Whether equal elements keep their order. Default `true`.
+    pub fn with_stable(mut self, enabled: bool) -> Self {
+        self.stable = enabled;
+        self
+    }
+
+    /// Whether the sort must keep the order of equal elements.
+    pub fn is_stable(&self) -> bool {
+        self.stable || self.maintain_order
+    }
+
+    /// Order string columns by a locale-aware collation. Default `None`.
+    pub fn with_collation(mut self, collation: Option<Collation>) -> Self {
+        self.collation = collation;
+        self
+    }
+
     /// Reverse the order of sorting for each column.
     pub fn with_order_reversed(mut self) -> Self {
         self.descending.iter_mut().for_each(|x| *x = !*x);
@@ -210,6 +253,23 @@ impl SortOptions {
         self
     }
 
+    /// Whether equal elements keep their order. Default `true`.
+    pub fn with_stable(mut self, enabled: bool) -> Self {
+        self.stable = enabled;
+        self
+    }
+
+    /// Whether the sort must keep the order of equal elements.
+    pub fn is_stable(&self) -> bool {
+        self.stable || self.maintain_order
+    }
+
+    /// Order strings by a locale-aware collation. Default `None`.
+    pub fn with_collation(mut self, collation: Option<Collation>) -> Self {
+        self.collation = collation;
+        self
+    }
+
     /// Reverse the order of sorting.
     pub fn with_order_reversed(mut self) -> Self {
         self.descending = !self.descending;
@@ -224,6 +284,8 @@ impl From<&SortOptions> for SortMultipleOptions {
             nulls_last: vec![value.nulls_last],
             multithreaded: value.multithreaded,
             maintain_order: value.maintain_order,
+            stable: value.stable,
+            collation: value.collation,
         }
     }
 }
@@ -235,6 +297,8 @@ impl From<&SortMultipleOptions> for SortOptions {
             nulls_last: value.nulls_last.first().copied().unwrap_or(false),
             multithreaded: value.multithreaded,
             maintain_order: value.maintain_order,
+            stable: value.stable,
+            collation: value.collation,
         }
     }
 }
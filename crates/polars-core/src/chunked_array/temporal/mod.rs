@@ -15,6 +15,8 @@ use chrono::NaiveDateTime;
 use chrono::NaiveTime;
 #[cfg(feature = "timezones")]
 use chrono_tz::Tz;
+#[cfg(feature = "dtype-date")]
+pub(crate) use date::naive_date_to_date;
 #[cfg(feature = "timezones")]
 use once_cell::sync::Lazy;
 #[cfg(all(feature = "regex", feature = "timezones"))]
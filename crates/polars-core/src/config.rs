@@ -1,3 +1,6 @@
+#[cfg(feature = "dtype-decimal")]
+use crate::chunked_array::arithmetic::DecimalOverflow;
+use crate::series::ops::RoundMode;
 use crate::POOL;
 
 // Formatting environment variables (typically referenced/set from the python-side Config object)
@@ -33,6 +36,14 @@ pub fn verbose() -> bool {
     std::env::var("POLARS_VERBOSE").as_deref().unwrap_or("") == "1"
 }
 
+/// Whether `set_sorted` should verify that the data is sorted instead of trusting the caller.
+pub fn validate_sorted() -> bool {
+    std::env::var("POLARS_VALIDATE_SORTED")
+        .as_deref()
+        .unwrap_or("")
+        == "1"
+}
+
 pub fn get_file_prefetch_size() -> usize {
     std::env::var("POLARS_PREFETCH_SIZE")
         .map(|s| s.parse::<usize>().expect("integer"))
@@ -46,8 +57,42 @@ pub fn get_rg_prefetch_size() -> usize {
         .unwrap_or_else(|_| std::cmp::max(get_file_prefetch_size(), 128))
 }
 
+/// Files in a multi-file scan with fewer rows than this are read together in a single task.
+pub fn get_small_file_batch_rows() -> usize {
+    std::env::var("POLARS_SMALL_FILE_BATCH_ROWS")
+        .map(|s| s.parse::<usize>().expect("integer"))
+        .unwrap_or(1 << 16)
+}
+
 pub fn force_async() -> bool {
     std::env::var("POLARS_FORCE_ASYNC")
         .map(|value| value == "1")
         .unwrap_or_default()
 }
+
+/// Whether the query planner raises instead of implicitly casting columns to the supertype of
+/// the operands of an expression, e.g. an `Int32` column to `Float64` when it is added to a
+/// `Float64` column.
+pub fn strict_supertypes() -> bool {
+    std::env::var("POLARS_STRICT_SUPERTYPES")
+        .as_deref()
+        .unwrap_or("")
+        == "1"
+}
+
+/// How decimal arithmetic rounds results to their scale.
+pub fn decimal_rounding() -> RoundMode {
+    match std::env::var("POLARS_DECIMAL_ROUNDING").as_deref() {
+        Ok("half_even") => RoundMode::HalfEven,
+        _ => RoundMode::HalfAway,
+    }
+}
+
+/// What decimal arithmetic does with results that need more digits than a decimal holds.
+#[cfg(feature = "dtype-decimal")]
+pub fn decimal_overflow() -> DecimalOverflow {
+    match std::env::var("POLARS_DECIMAL_OVERFLOW").as_deref() {
+        Ok("raise") => DecimalOverflow::Raise,
+        _ => DecimalOverflow::Promote,
+    }
+}
@@ -114,6 +114,12 @@ enum SerializableDataType {
     Enum(Option<Wrap<Utf8ViewArray>>, CategoricalOrdering),
     #[cfg(feature = "object")]
     Object(String),
+    #[cfg(feature = "dtype-extension")]
+    Extension(String, Box<SerializableDataType>, Option<String>),
+    #[cfg(feature = "dtype-f16")]
+    Float16,
+    #[cfg(feature = "dtype-json")]
+    Json,
 }
 
 impl From<&DataType> for SerializableDataType {
@@ -129,10 +135,14 @@ impl From<&DataType> for SerializableDataType {
             Int16 => Self::Int16,
             Int32 => Self::Int32,
             Int64 => Self::Int64,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Self::Float16,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
             String => Self::String,
             Binary => Self::Binary,
+            #[cfg(feature = "dtype-json")]
+            Json => Self::Json,
             Date => Self::Date,
             Datetime(tu, tz) => Self::Datetime(*tu, tz.clone()),
             Duration(tu) => Self::Duration(*tu),
@@ -156,6 +166,12 @@ impl From<&DataType> for SerializableDataType {
             Decimal(precision, scale) => Self::Decimal(*precision, *scale),
             #[cfg(feature = "object")]
             Object(name, _) => Self::Object(name.to_string()),
+            #[cfg(feature = "dtype-extension")]
+            Extension(name, storage, metadata) => Self::Extension(
+                name.clone(),
+                Box::new(storage.as_ref().into()),
+                metadata.clone(),
+            ),
             dt => panic!("{dt:?} not supported"),
         }
     }
@@ -173,10 +189,14 @@ impl From<SerializableDataType> for DataType {
             Int16 => Self::Int16,
             Int32 => Self::Int32,
             Int64 => Self::Int64,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Self::Float16,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
             String => Self::String,
             Binary => Self::Binary,
+            #[cfg(feature = "dtype-json")]
+            Json => Self::Json,
             Date => Self::Date,
             Datetime(tu, tz) => Self::Datetime(tu, tz),
             Duration(tu) => Self::Duration(tu),
@@ -198,6 +218,10 @@ impl From<SerializableDataType> for DataType {
             Decimal(precision, scale) => Self::Decimal(precision, scale),
             #[cfg(feature = "object")]
             Object(_) => Self::Object("unknown", None),
+            #[cfg(feature = "dtype-extension")]
+            Extension(name, storage, metadata) => {
+                Self::Extension(name, Box::new((*storage).into()), metadata)
+            },
         }
     }
 }
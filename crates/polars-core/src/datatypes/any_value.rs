@@ -1,5 +1,7 @@
 #[cfg(feature = "dtype-struct")]
 use arrow::legacy::trusted_len::TrustedLenPush;
+#[cfg(feature = "dtype-f16")]
+use arrow::types::f16;
 use arrow::types::PrimitiveType;
 use polars_utils::format_smartstring;
 #[cfg(feature = "dtype-struct")]
@@ -554,6 +556,11 @@ impl<'a> AnyValue<'a> {
             (av, DataType::Int64) => AnyValue::Int64(av.extract::<i64>()?),
             (av, DataType::Float32) => AnyValue::Float32(av.extract::<f32>()?),
             (av, DataType::Float64) => AnyValue::Float64(av.extract::<f64>()?),
+            // Float16 values are represented by the `Float32` they round to.
+            #[cfg(feature = "dtype-f16")]
+            (av, DataType::Float16) => {
+                AnyValue::Float32(f16::from_f32(av.extract::<f32>()?).to_f32())
+            },
 
             // to boolean
             (AnyValue::UInt8(v), DataType::Boolean) => AnyValue::Boolean(*v != u8::default()),
@@ -51,6 +51,10 @@ pub enum DataType {
     Int16,
     Int32,
     Int64,
+    /// Half-precision floating point, stored as the bits of an `f16`. Arithmetic and
+    /// aggregations are done in `Float32`.
+    #[cfg(feature = "dtype-f16")]
+    Float16,
     Float32,
     Float64,
     /// Fixed point decimal type optional precision and non-negative scale.
@@ -61,6 +65,9 @@ pub enum DataType {
     String,
     Binary,
     BinaryOffset,
+    /// JSON values, stored in a binary encoding that is queried without parsing the text.
+    #[cfg(feature = "dtype-json")]
+    Json,
     /// A 32-bit date representing the elapsed time since UNIX epoch (1970-01-01)
     /// in days (32 bits).
     Date,
@@ -89,6 +96,10 @@ pub enum DataType {
     Enum(Option<Arc<RevMapping>>, CategoricalOrdering),
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<Field>),
+    /// A user-defined logical type with its name, the data type that stores the values and
+    /// optional metadata. See [`ExtensionType`].
+    #[cfg(feature = "dtype-extension")]
+    Extension(String, Box<DataType>, Option<String>),
     // some logical types we cannot know statically, e.g. Datetime
     Unknown(UnknownKind),
 }
@@ -136,6 +147,10 @@ impl PartialEq for DataType {
                 (Array(left_inner, left_width), Array(right_inner, right_width)) => {
                     left_width == right_width && left_inner == right_inner
                 },
+                #[cfg(feature = "dtype-extension")]
+                (Extension(lhs, lhs_storage, lhs_md), Extension(rhs, rhs_storage, rhs_md)) => {
+                    lhs == rhs && lhs_storage == rhs_storage && lhs_md == rhs_md
+                },
                 (Unknown(l), Unknown(r)) => match (l, r) {
                     (UnknownKind::Int(_), UnknownKind::Int(_)) => true,
                     _ => l == r,
@@ -248,6 +263,10 @@ impl DataType {
     pub fn to_physical(&self) -> DataType {
         use DataType::*;
         match self {
+            #[cfg(feature = "dtype-f16")]
+            Float16 => UInt16,
+            #[cfg(feature = "dtype-json")]
+            Json => Binary,
             Date => Int32,
             Datetime(_, _) => Int64,
             Duration(_) => Int64,
@@ -265,6 +284,8 @@ impl DataType {
                     .collect();
                 Struct(new_fields)
             },
+            #[cfg(feature = "dtype-extension")]
+            Extension(_, storage, _) => storage.to_physical(),
             _ => self.clone(),
         }
     }
@@ -352,10 +373,24 @@ impl DataType {
         matches!(self, DataType::Null)
     }
 
+    /// Check if this [`DataType`] is a user-defined extension type.
+    pub fn is_extension(&self) -> bool {
+        #[cfg(feature = "dtype-extension")]
+        {
+            matches!(self, DataType::Extension(_, _, _))
+        }
+        #[cfg(not(feature = "dtype-extension"))]
+        {
+            false
+        }
+    }
+
     pub fn contains_views(&self) -> bool {
         use DataType::*;
         match self {
             Binary | String => true,
+            #[cfg(feature = "dtype-json")]
+            Json => true,
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => true,
             List(inner) => inner.contains_views(),
@@ -363,6 +398,8 @@ impl DataType {
             Array(inner, _) => inner.contains_views(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_views()),
+            #[cfg(feature = "dtype-extension")]
+            Extension(_, storage, _) => storage.contains_views(),
             _ => false,
         }
     }
@@ -539,6 +576,8 @@ impl DataType {
             Int16 => Ok(ArrowDataType::Int16),
             Int32 => Ok(ArrowDataType::Int32),
             Int64 => Ok(ArrowDataType::Int64),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Ok(ArrowDataType::Float16),
             Float32 => Ok(ArrowDataType::Float32),
             Float64 => Ok(ArrowDataType::Float64),
             #[cfg(feature = "dtype-decimal")]
@@ -563,6 +602,13 @@ impl DataType {
                 };
                 Ok(dt)
             },
+            // Exported as text, with the canonical extension name for JSON.
+            #[cfg(feature = "dtype-json")]
+            Json => Ok(ArrowDataType::Extension(
+                "arrow.json".to_string(),
+                Box::new(String.try_to_arrow(pl_flavor)?),
+                None,
+            )),
             Date => Ok(ArrowDataType::Date32),
             Datetime(unit, tz) => Ok(ArrowDataType::Timestamp(unit.to_arrow(), tz.clone())),
             Duration(unit) => Ok(ArrowDataType::Duration(unit.to_arrow())),
@@ -603,6 +649,12 @@ impl DataType {
                 let fields = fields.iter().map(|fld| fld.to_arrow(pl_flavor)).collect();
                 Ok(ArrowDataType::Struct(fields))
             },
+            #[cfg(feature = "dtype-extension")]
+            Extension(name, storage, metadata) => Ok(ArrowDataType::Extension(
+                name.clone(),
+                Box::new(storage.try_to_arrow(pl_flavor)?),
+                metadata.clone(),
+            )),
             BinaryOffset => Ok(ArrowDataType::LargeBinary),
             Unknown(kind) => {
                 let dt = match kind {
@@ -680,6 +732,8 @@ impl Display for DataType {
             DataType::Int16 => "i16",
             DataType::Int32 => "i32",
             DataType::Int64 => "i64",
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => "f16",
             DataType::Float32 => "f32",
             DataType::Float64 => "f64",
             #[cfg(feature = "dtype-decimal")]
@@ -704,6 +758,8 @@ impl Display for DataType {
             },
             DataType::Duration(tu) => return write!(f, "duration[{tu}]"),
             DataType::Time => "time",
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => "json",
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
                 let tp = self.leaf_dtype();
@@ -725,6 +781,8 @@ impl Display for DataType {
             DataType::Enum(_, _) => "enum",
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => return write!(f, "struct[{}]", fields.len()),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(name, _, _) => return write!(f, "ext[{name}]"),
             DataType::Unknown(kind) => match kind {
                 UnknownKind::Any => "unknown",
                 UnknownKind::Int(_) => "dyn int",
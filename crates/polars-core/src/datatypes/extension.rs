@@ -0,0 +1,81 @@
+//! Registry of extension data types: logical types that are layered on a physical storage
+//! type, such as a UUID stored as `Binary` or an IPv4 address stored as `UInt32`.
+//!
+//! A [`DataType::Extension`] holds the name of the type, its storage type and optional
+//! metadata. It is kept through operations and through IPC and Parquet files (as an Arrow
+//! extension type), also if no type is registered under its name. Registering an
+//! [`ExtensionType`] adds custom formatting and cast rules.
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+
+use super::*;
+
+/// The behavior of an extension data type.
+///
+/// All hooks get the values of the type as a Series or [`AnyValue`] of the storage type, and
+/// the metadata of the data type.
+pub trait ExtensionType: Send + Sync {
+    /// The name that identifies the type. Names are global, so prefer a namespaced name such as
+    /// `"myapp.uuid"`.
+    fn name(&self) -> &str;
+
+    /// The data type that holds the values.
+    fn storage(&self) -> DataType;
+
+    /// Format a single non-null value for display. Returning `None` formats it like a value of
+    /// the storage type.
+    fn format(&self, _value: &AnyValue, _metadata: Option<&str>) -> Option<String> {
+        None
+    }
+
+    /// Cast the values of this type to another data type. Returning `None` casts the storage.
+    fn cast_to(
+        &self,
+        _storage: &Series,
+        _dtype: &DataType,
+        _metadata: Option<&str>,
+    ) -> Option<PolarsResult<Series>> {
+        None
+    }
+
+    /// Cast a Series of another data type to the storage of this type. Returning `None` casts
+    /// the Series to the storage type.
+    fn cast_from(&self, _s: &Series, _metadata: Option<&str>) -> Option<PolarsResult<Series>> {
+        None
+    }
+}
+
+static EXTENSION_TYPES: Lazy<RwLock<PlHashMap<String, Arc<dyn ExtensionType>>>> =
+    Lazy::new(Default::default);
+
+/// Register an extension type under its name. This replaces a type that was registered
+/// earlier under the same name.
+pub fn register_extension_type(ext: Arc<dyn ExtensionType>) {
+    let mut types = EXTENSION_TYPES.write().unwrap();
+    types.insert(ext.name().to_string(), ext);
+}
+
+/// Remove an extension type from the registry. Data of the type keeps its data type, but is
+/// formatted and cast like its storage from now on.
+pub fn unregister_extension_type(name: &str) -> Option<Arc<dyn ExtensionType>> {
+    let mut types = EXTENSION_TYPES.write().unwrap();
+    types.remove(name)
+}
+
+/// Get the extension type that is registered under `name`.
+pub fn get_extension_type(name: &str) -> Option<Arc<dyn ExtensionType>> {
+    let types = EXTENSION_TYPES.read().unwrap();
+    types.get(name).cloned()
+}
+
+/// Create the [`DataType`] of a registered extension type.
+pub fn create_extension_data_type(name: &str, metadata: Option<String>) -> PolarsResult<DataType> {
+    let ext = get_extension_type(name)
+        .ok_or_else(|| polars_err!(ComputeError: "extension type '{}' is not registered", name))?;
+    Ok(DataType::Extension(
+        name.to_string(),
+        Box::new(ext.storage()),
+        metadata,
+    ))
+}
@@ -143,6 +143,10 @@ impl DataType {
             ArrowDataType::Int32 => DataType::Int32,
             ArrowDataType::Int64 => DataType::Int64,
             ArrowDataType::Boolean => DataType::Boolean,
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => DataType::Float16,
+            #[cfg(not(feature = "dtype-f16"))]
+            ArrowDataType::Float16 => DataType::Float32,
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
             #[cfg(feature = "dtype-array")]
@@ -173,6 +177,10 @@ impl DataType {
                     panic!("activate the 'object' feature to be able to load POLARS_EXTENSION_TYPE")
                 }
             }
+            #[cfg(feature = "dtype-json")]
+            ArrowDataType::Extension(name, _, _) if name == "arrow.json" => DataType::Json,
+            #[cfg(feature = "dtype-extension")]
+            ArrowDataType::Extension(name, storage, metadata) => DataType::Extension(name.clone(), DataType::from_arrow(storage, bin_to_view).boxed(), metadata.clone()),
             #[cfg(feature = "dtype-decimal")]
             ArrowDataType::Decimal(precision, scale) => DataType::Decimal(Some(*precision), Some(*scale)),
             ArrowDataType::Utf8View |ArrowDataType::LargeUtf8 | ArrowDataType::Utf8 => DataType::String,
@@ -11,6 +11,8 @@ mod _serde;
 mod aliases;
 mod any_value;
 mod dtype;
+#[cfg(feature = "dtype-extension")]
+mod extension;
 mod field;
 mod into_scalar;
 #[cfg(feature = "object")]
@@ -32,6 +34,8 @@ use arrow::types::simd::Simd;
 use arrow::types::NativeType;
 use bytemuck::Zeroable;
 pub use dtype::*;
+#[cfg(feature = "dtype-extension")]
+pub use extension::*;
 pub use field::*;
 pub use into_scalar::*;
 use num_traits::{Bounded, FromPrimitive, Num, NumCast, One, Zero};
@@ -183,10 +187,14 @@ impl_polars_num_datatype!(PolarsIntegerType, Int32Type, Int32, i32, i32);
 impl_polars_num_datatype!(PolarsIntegerType, Int64Type, Int64, i64, i64);
 impl_polars_num_datatype!(PolarsFloatType, Float32Type, Float32, f32, f32);
 impl_polars_num_datatype!(PolarsFloatType, Float64Type, Float64, f64, f64);
+#[cfg(feature = "dtype-f16")]
+impl_polars_datatype!(Float16Type, Float16, PrimitiveArray<u16>, 'a, u16, u16, u16);
 impl_polars_datatype!(DateType, Date, PrimitiveArray<i32>, 'a, i32, i32, i32);
 impl_polars_datatype!(TimeType, Time, PrimitiveArray<i64>, 'a, i64, i64, i64);
 impl_polars_binview_datatype!(StringType, String, Utf8ViewArray, 'a, &'a str, Option<&'a str>, String);
 impl_polars_binview_datatype!(BinaryType, Binary, BinaryViewArray, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>);
+#[cfg(feature = "dtype-json")]
+impl_polars_binview_datatype!(JsonType, Json, BinaryViewArray, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>);
 impl_polars_datatype!(BinaryOffsetType, BinaryOffset, BinaryArray<i64>, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>);
 impl_polars_datatype!(BooleanType, Boolean, BooleanArray, 'a, bool, bool, bool);
 
@@ -27,6 +27,11 @@ use num_traits::{Num, NumCast};
 use crate::config::*;
 use crate::prelude::*;
 
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+mod render;
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+pub use render::{Alignment, RenderOptions};
+
 // Note: see https://github.com/pola-rs/polars/pull/13699 for the rationale
 // behind choosing 10 as the default value for default number of rows displayed
 const DEFAULT_ROW_LIMIT: usize = 10;
@@ -41,6 +46,43 @@ pub enum FloatFmt {
     Mixed,
     Full,
 }
+
+/// Display options of a single column, see [`FmtConfig`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnFmt {
+    /// The number of decimals of floats.
+    pub float_precision: Option<usize>,
+    /// The separator between groups of thousands of integers and floats.
+    pub thousands_separator: Option<char>,
+    /// A `strftime` format of dates, datetimes and times.
+    pub datetime_format: Option<String>,
+    /// The maximum number of characters shown of a value.
+    pub max_str_len: Option<usize>,
+}
+
+/// Per-column display options of a [`DataFrame`]. Columns without options follow the global
+/// configuration.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FmtConfig {
+    columns: PlHashMap<String, ColumnFmt>,
+}
+
+impl FmtConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the display options of column `name`.
+    pub fn with_column(mut self, name: impl Into<String>, fmt: ColumnFmt) -> Self {
+        self.columns.insert(name.into(), fmt);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ColumnFmt> {
+        self.columns.get(name)
+    }
+}
+
 static FLOAT_PRECISION: RwLock<Option<usize>> = RwLock::new(None);
 static FLOAT_FMT: AtomicU8 = AtomicU8::new(FloatFmt::Mixed as u8);
 
@@ -220,6 +262,39 @@ fn format_object_array(
     }
 }
 
+#[cfg(feature = "dtype-extension")]
+fn format_extension_array(f: &mut Formatter<'_>, s: &Series, array_type: &str) -> fmt::Result {
+    write!(
+        f,
+        "shape: ({},)\n{}: '{}' [{}]\n[\n",
+        fmt_int_string_custom(&s.len().to_string(), 3, "_"),
+        array_type,
+        s.name(),
+        s.dtype()
+    )?;
+    // Formatted with `str_value`, which applies the `format` hook of the extension type.
+    let write_value = |f: &mut Formatter<'_>, i| -> fmt::Result {
+        writeln!(f, "\t{}", s.str_value(i).map_err(|_| fmt::Error)?)
+    };
+
+    let limit = get_row_limit();
+    if s.len() > limit {
+        let half = limit / 2;
+        for i in 0..(half + limit % 2) {
+            write_value(f, i)?;
+        }
+        writeln!(f, "\t…")?;
+        for i in (s.len() - half)..s.len() {
+            write_value(f, i)?;
+        }
+    } else {
+        for i in 0..s.len() {
+            write_value(f, i)?;
+        }
+    }
+    write!(f, "]")
+}
+
 impl<T> Debug for ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -407,6 +482,8 @@ impl Debug for Series {
                     "Series"
                 )
             },
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(_, _, _) => format_extension_array(f, self, "Series"),
             dt => panic!("{dt:?} not impl"),
         }
     }
@@ -485,7 +562,7 @@ fn prepare_row(
     row: Vec<Cow<'_, str>>,
     n_first: usize,
     n_last: usize,
-    str_truncate: usize,
+    str_truncate: &[usize],
     max_elem_lengths: &mut [usize],
 ) -> Vec<String> {
     let reduce_columns = n_first + n_last < row.len();
@@ -493,7 +570,7 @@ fn prepare_row(
     let mut row_strings = Vec::with_capacity(n_elems);
 
     for (idx, v) in row[0..n_first].iter().enumerate() {
-        let elem_str = make_str_val(v, str_truncate);
+        let elem_str = make_str_val(v, str_truncate[idx]);
         let elem_len = elem_str.len() + 2;
         if max_elem_lengths[idx] < elem_len {
             max_elem_lengths[idx] = elem_len;
@@ -505,8 +582,9 @@ fn prepare_row(
         max_elem_lengths[n_first] = 3;
     }
     let elem_offset = n_first + reduce_columns as usize;
-    for (idx, v) in row[row.len() - n_last..].iter().enumerate() {
-        let elem_str = make_str_val(v, str_truncate);
+    let last_offset = row.len() - n_last;
+    for (idx, v) in row[last_offset..].iter().enumerate() {
+        let elem_str = make_str_val(v, str_truncate[last_offset + idx]);
         let elem_len = elem_str.len() + 2;
         let elem_idx = elem_offset + idx;
         if max_elem_lengths[elem_idx] < elem_len {
@@ -534,6 +612,155 @@ fn fmt_df_shape((shape0, shape1): &(usize, usize)) -> String {
 
 impl Display for DataFrame {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_table(f, self.fmt_config.as_deref())
+    }
+}
+
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+fn try_format(value: impl Display) -> Option<String> {
+    // Formatting fails on invalid `strftime` specifiers.
+    let mut out = String::new();
+    write!(out, "{value}").ok()?;
+    Some(out)
+}
+
+/// Format a value with the options of its column. Returns `None` if the options don't apply to
+/// the value, in which case it is formatted as usual.
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+fn fmt_value_with(av: &AnyValue, fmt: &ColumnFmt) -> Option<String> {
+    let thousands_separator = || {
+        fmt.thousands_separator
+            .map(String::from)
+            .unwrap_or_else(get_thousands_separator)
+    };
+    match av {
+        AnyValue::Float32(_) | AnyValue::Float64(_) => {
+            if fmt.float_precision.is_none() && fmt.thousands_separator.is_none() {
+                return None;
+            }
+            let v: f64 = av.extract()?;
+            let s = match fmt.float_precision {
+                Some(precision) => format!("{v:.precision$}"),
+                None => format!("{v}"),
+            };
+            Some(fmt_float_string_custom(
+                &s,
+                3,
+                &thousands_separator(),
+                get_decimal_separator(),
+            ))
+        },
+        av if av.is_integer() => {
+            fmt.thousands_separator?;
+            let v: i128 = av.extract()?;
+            Some(fmt_int_string_custom(
+                &v.to_string(),
+                3,
+                &thousands_separator(),
+            ))
+        },
+        #[cfg(feature = "dtype-date")]
+        AnyValue::Date(v) => try_format(date32_to_date(*v).format(fmt.datetime_format.as_deref()?)),
+        #[cfg(feature = "dtype-datetime")]
+        AnyValue::Datetime(v, tu, tz) => {
+            let format = fmt.datetime_format.as_deref()?;
+            let ndt = match tu {
+                TimeUnit::Nanoseconds => timestamp_ns_to_datetime(*v),
+                TimeUnit::Microseconds => timestamp_us_to_datetime(*v),
+                TimeUnit::Milliseconds => timestamp_ms_to_datetime(*v),
+            };
+            match tz {
+                None => try_format(ndt.format(format)),
+                #[cfg(feature = "timezones")]
+                Some(tz) => {
+                    let tz = tz.parse::<chrono_tz::Tz>().ok()?;
+                    try_format(
+                        chrono::Utc
+                            .from_utc_datetime(&ndt)
+                            .with_timezone(&tz)
+                            .format(format),
+                    )
+                },
+                #[cfg(not(feature = "timezones"))]
+                Some(_) => None,
+            }
+        },
+        #[cfg(feature = "dtype-time")]
+        AnyValue::Time(_) => {
+            let nt: chrono::NaiveTime = av.into();
+            try_format(nt.format(fmt.datetime_format.as_deref()?))
+        },
+        _ => None,
+    }
+}
+
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+fn fmt_cell<'a>(s: &'a Series, idx: usize, fmt: Option<&ColumnFmt>) -> Cow<'a, str> {
+    let av = s.get(idx).unwrap();
+    match fmt.and_then(|fmt| fmt_value_with(&av, fmt)) {
+        Some(formatted) => Cow::Owned(formatted),
+        None => s.str_value(idx).unwrap(),
+    }
+}
+
+/// Displays a [`DataFrame`] with the given [`FmtConfig`].
+struct DataFrameWithConfig<'a> {
+    df: &'a DataFrame,
+    config: &'a FmtConfig,
+}
+
+impl Display for DataFrameWithConfig<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.df.fmt_table(f, Some(self.config))
+    }
+}
+
+impl DataFrame {
+    /// Attach per-column display options, which are used whenever the `DataFrame` is displayed.
+    ///
+    /// The options are kept by operations that modify the `DataFrame` in place, but not by
+    /// operations that return a new `DataFrame`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # use polars_core::fmt::{ColumnFmt, FmtConfig};
+    /// let df = df!("price" => [1234.5678, 0.5])?.with_fmt_config(Some(
+    ///     FmtConfig::new().with_column(
+    ///         "price",
+    ///         ColumnFmt {
+    ///             float_precision: Some(2),
+    ///             thousands_separator: Some(','),
+    ///             ..Default::default()
+    ///         },
+    ///     ),
+    /// ));
+    /// // Shows `1,234.57` and `0.50`.
+    /// println!("{df}");
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn with_fmt_config(mut self, config: Option<FmtConfig>) -> Self {
+        self.set_fmt_config(config);
+        self
+    }
+
+    pub fn set_fmt_config(&mut self, config: Option<FmtConfig>) {
+        self.fmt_config = config.map(Arc::new);
+    }
+
+    pub fn fmt_config(&self) -> Option<&FmtConfig> {
+        self.fmt_config.as_deref()
+    }
+
+    /// Render the `DataFrame` as a table with the given display options, ignoring the options
+    /// attached with [`DataFrame::with_fmt_config`].
+    pub fn to_string_with_config(&self, config: &FmtConfig) -> String {
+        DataFrameWithConfig { df: self, config }.to_string()
+    }
+
+    #[allow(unused_variables)]
+    fn fmt_table(&self, f: &mut Formatter<'_>, config: Option<&FmtConfig>) -> fmt::Result {
         #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
         {
             let height = self.height();
@@ -544,7 +771,27 @@ impl Display for DataFrame {
 
             let max_n_cols = get_col_limit();
             let max_n_rows = get_row_limit();
-            let str_truncate = get_str_len_limit();
+            let default_str_truncate = get_str_len_limit();
+
+            let column_fmts = self
+                .columns
+                .iter()
+                .map(|s| config.and_then(|config| config.get(s.name())))
+                .collect::<Vec<_>>();
+            let str_truncate = column_fmts
+                .iter()
+                .map(|fmt| {
+                    fmt.and_then(|fmt| fmt.max_str_len)
+                        .unwrap_or(default_str_truncate)
+                })
+                .collect::<Vec<_>>();
+            let get_row = |i: usize| {
+                self.columns
+                    .iter()
+                    .zip(&column_fmts)
+                    .map(|(s, fmt)| fmt_cell(s, i, *fmt))
+                    .collect::<Vec<_>>()
+            };
 
             let (n_first, n_last) = if self.width() > max_n_cols {
                 ((max_n_cols + 1) / 2, max_n_cols / 2)
@@ -558,8 +805,8 @@ impl Display for DataFrame {
             let mut name_lengths = Vec::with_capacity(n_tbl_cols);
 
             let fields = self.fields();
-            for field in fields[0..n_first].iter() {
-                let (s, l) = field_to_str(field, str_truncate);
+            for (idx, field) in fields[0..n_first].iter().enumerate() {
+                let (s, l) = field_to_str(field, str_truncate[idx]);
                 names.push(s);
                 name_lengths.push(l);
             }
@@ -567,8 +814,8 @@ impl Display for DataFrame {
                 names.push("…".into());
                 name_lengths.push(3);
             }
-            for field in fields[self.width() - n_last..].iter() {
-                let (s, l) = field_to_str(field, str_truncate);
+            for (idx, field) in fields.iter().enumerate().skip(self.width() - n_last) {
+                let (s, l) = field_to_str(field, str_truncate[idx]);
                 names.push(s);
                 name_lengths.push(l);
             }
@@ -615,45 +862,33 @@ impl Display for DataFrame {
                     let rest = max_n_rows % 2;
 
                     for i in 0..(half + rest) {
-                        let row = self
-                            .columns
-                            .iter()
-                            .map(|s| s.str_value(i).unwrap())
-                            .collect();
+                        let row = get_row(i);
 
                         let row_strings =
-                            prepare_row(row, n_first, n_last, str_truncate, &mut max_elem_lengths);
+                            prepare_row(row, n_first, n_last, &str_truncate, &mut max_elem_lengths);
 
                         rows.push(row_strings);
                     }
                     let dots = rows[0].iter().map(|_| "…".to_string()).collect();
                     rows.push(dots);
                     for i in (height - half)..height {
-                        let row = self
-                            .columns
-                            .iter()
-                            .map(|s| s.str_value(i).unwrap())
-                            .collect();
+                        let row = get_row(i);
 
                         let row_strings =
-                            prepare_row(row, n_first, n_last, str_truncate, &mut max_elem_lengths);
+                            prepare_row(row, n_first, n_last, &str_truncate, &mut max_elem_lengths);
                         rows.push(row_strings);
                     }
                     table.add_rows(rows);
                 } else {
                     for i in 0..height {
                         if self.width() > 0 {
-                            let row = self
-                                .columns
-                                .iter()
-                                .map(|s| s.str_value(i).unwrap())
-                                .collect();
+                            let row = get_row(i);
 
                             let row_strings = prepare_row(
                                 row,
                                 n_first,
                                 n_last,
-                                str_truncate,
+                                &str_truncate,
                                 &mut max_elem_lengths,
                             );
                             table.add_row(row_strings);
@@ -686,8 +921,16 @@ impl Display for DataFrame {
             };
             let min_col_width = 5;
             for (idx, elem_len) in max_elem_lengths.iter().enumerate() {
+                // Map the table column to the column of the frame.
+                let col_str_truncate = if idx < n_first {
+                    str_truncate[idx]
+                } else if reduce_columns && idx == n_first {
+                    default_str_truncate
+                } else {
+                    str_truncate[idx - n_tbl_cols + self.width()]
+                };
                 let mx = std::cmp::min(
-                    str_truncate + 3, // (3 = 2 space chars of padding + ellipsis char)
+                    col_str_truncate + 3, // (3 = 2 space chars of padding + ellipsis char)
                     std::cmp::max(name_lengths[idx], *elem_len),
                 );
                 if mx <= min_col_width {
@@ -1379,4 +1622,37 @@ ChunkedArray: 'name' [str]
             format!("{:?}", ca)
         );
     }
+
+    #[test]
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    fn test_fmt_value_with() {
+        use super::{fmt_value_with, ColumnFmt};
+
+        let fmt = ColumnFmt {
+            float_precision: Some(2),
+            thousands_separator: Some(','),
+            ..Default::default()
+        };
+        assert_eq!(
+            fmt_value_with(&AnyValue::Float64(1234567.891), &fmt).as_deref(),
+            Some("1,234,567.89")
+        );
+        assert_eq!(
+            fmt_value_with(&AnyValue::Int64(-1234567), &fmt).as_deref(),
+            Some("-1,234,567")
+        );
+        assert_eq!(fmt_value_with(&AnyValue::String("a"), &fmt), None);
+
+        #[cfg(feature = "dtype-date")]
+        {
+            let fmt = ColumnFmt {
+                datetime_format: Some("%d/%m/%Y".into()),
+                ..Default::default()
+            };
+            assert_eq!(
+                fmt_value_with(&AnyValue::Date(1), &fmt).as_deref(),
+                Some("02/01/1970")
+            );
+        }
+    }
 }
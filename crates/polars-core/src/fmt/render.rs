@@ -0,0 +1,354 @@
+//! Render a [`DataFrame`] as an HTML or Markdown table, e.g. to show it in a web page.
+use std::fmt::Write;
+
+use super::{
+    fmt_cell, fmt_df_shape, get_col_limit, get_row_limit, get_str_len_limit, make_str_val,
+    ColumnFmt,
+};
+use crate::prelude::*;
+
+/// The horizontal alignment of the cells of a column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
+/// Options of [`DataFrame::to_html`] and [`DataFrame::to_markdown`].
+///
+/// Limits that aren't set follow the same configuration as the `Display` of a `DataFrame`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// The maximum number of rows, the rows in the middle are elided.
+    pub max_rows: Option<usize>,
+    /// The maximum number of columns, the columns in the middle are elided.
+    pub max_cols: Option<usize>,
+    /// The maximum number of characters shown of a value.
+    pub max_str_len: Option<usize>,
+    /// The alignment of all columns. By default numbers are aligned right and other values left.
+    pub alignment: Option<Alignment>,
+    /// Show the data type of every column with its name.
+    pub show_dtypes: bool,
+    /// Show the shape of the `DataFrame` above the table.
+    pub show_shape: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            max_rows: None,
+            max_cols: None,
+            max_str_len: None,
+            alignment: None,
+            show_dtypes: true,
+            show_shape: true,
+        }
+    }
+}
+
+/// The indices of the rows or columns that are shown, `None` stands for the elided ones.
+fn shown(len: usize, limit: usize) -> Vec<Option<usize>> {
+    if len <= limit {
+        return (0..len).map(Some).collect();
+    }
+    let (n_first, n_last) = ((limit + 1) / 2, limit / 2);
+    (0..n_first)
+        .map(Some)
+        .chain(std::iter::once(None))
+        .chain((len - n_last..len).map(Some))
+        .collect()
+}
+
+/// The cells of a `DataFrame` that are shown, formatted as in the `Display` table.
+struct Layout<'a> {
+    df: &'a DataFrame,
+    options: &'a RenderOptions,
+    rows: Vec<Option<usize>>,
+    cols: Vec<Option<usize>>,
+    fmts: Vec<Option<&'a ColumnFmt>>,
+}
+
+impl<'a> Layout<'a> {
+    fn new(df: &'a DataFrame, options: &'a RenderOptions) -> Self {
+        let max_rows = options.max_rows.unwrap_or_else(get_row_limit);
+        let max_cols = options.max_cols.unwrap_or_else(get_col_limit);
+        let config = df.fmt_config();
+        Self {
+            df,
+            options,
+            rows: shown(df.height(), max_rows),
+            cols: shown(df.width(), max_cols),
+            fmts: df
+                .get_columns()
+                .iter()
+                .map(|s| config.and_then(|config| config.get(s.name())))
+                .collect(),
+        }
+    }
+
+    fn max_str_len(&self, col: usize) -> usize {
+        self.fmts[col]
+            .and_then(|fmt| fmt.max_str_len)
+            .or(self.options.max_str_len)
+            .unwrap_or_else(get_str_len_limit)
+    }
+
+    fn name(&self, col: usize) -> String {
+        let name = self.df.get_columns()[col].name();
+        make_str_val(name, self.max_str_len(col))
+    }
+
+    fn dtype(&self, col: usize) -> String {
+        self.df.get_columns()[col].dtype().to_string()
+    }
+
+    fn cell(&self, row: usize, col: usize) -> String {
+        let s = &self.df.get_columns()[col];
+        make_str_val(&fmt_cell(s, row, self.fmts[col]), self.max_str_len(col))
+    }
+
+    fn alignment(&self, col: Option<usize>) -> Alignment {
+        if let Some(alignment) = self.options.alignment {
+            return alignment;
+        }
+        match col.map(|col| self.df.get_columns()[col].dtype()) {
+            Some(dtype) if dtype.is_numeric() || dtype.is_decimal() => Alignment::Right,
+            Some(_) => Alignment::Left,
+            None => Alignment::Center,
+        }
+    }
+}
+
+fn escape_html(out: &mut String, s: &str) {
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#x27;"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Escape the characters that would break a cell of a Markdown table.
+fn escape_markdown(s: &str) -> String {
+    s.replace('|', "\\|").replace('\n', "<br>")
+}
+
+impl DataFrame {
+    /// Render the `DataFrame` as an HTML table, with the same values as its `Display`.
+    ///
+    /// Names and values are HTML-escaped. Column display options attached with
+    /// [`DataFrame::with_fmt_config`] are applied.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # use polars_core::fmt::RenderOptions;
+    /// let df = df!("a" => [1, 2], "b" => ["<x>", "y"])?;
+    /// let html = df.to_html(&RenderOptions::default());
+    /// assert!(html.contains("<td>&lt;x&gt;</td>"));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn to_html(&self, options: &RenderOptions) -> String {
+        let layout = Layout::new(self, options);
+        let mut out = String::from("<div>");
+        if options.show_shape {
+            write!(out, "<small>shape: {}</small>", fmt_df_shape(&self.shape())).unwrap();
+        }
+        out.push_str(r#"<table border="1" class="dataframe"><thead>"#);
+
+        let write_cell = |out: &mut String, tag: &str, col: Option<usize>, text: &str| {
+            match layout.alignment(col) {
+                Alignment::Left => write!(out, "<{tag}>"),
+                Alignment::Center => write!(out, r#"<{tag} style="text-align: center">"#),
+                Alignment::Right => write!(out, r#"<{tag} style="text-align: right">"#),
+            }
+            .unwrap();
+            match col {
+                Some(_) => escape_html(out, text),
+                None => out.push_str("&hellip;"),
+            }
+            write!(out, "</{tag}>").unwrap();
+        };
+
+        out.push_str("<tr>");
+        for col in &layout.cols {
+            let name = col.map(|col| layout.name(col)).unwrap_or_default();
+            write_cell(&mut out, "th", *col, &name);
+        }
+        out.push_str("</tr>");
+        if options.show_dtypes {
+            out.push_str("<tr>");
+            for col in &layout.cols {
+                let dtype = col.map(|col| layout.dtype(col)).unwrap_or_default();
+                write_cell(&mut out, "td", *col, &dtype);
+            }
+            out.push_str("</tr>");
+        }
+        out.push_str("</thead><tbody>");
+
+        for row in &layout.rows {
+            out.push_str("<tr>");
+            for col in &layout.cols {
+                match (row, col) {
+                    (Some(row), Some(col)) => {
+                        let cell = layout.cell(*row, *col);
+                        write_cell(&mut out, "td", Some(*col), &cell)
+                    },
+                    _ => write_cell(&mut out, "td", None, ""),
+                }
+            }
+            out.push_str("</tr>");
+        }
+        out.push_str("</tbody></table></div>");
+        out
+    }
+
+    /// Render the `DataFrame` as a Markdown table, with the same values as its `Display`.
+    ///
+    /// The data types are shown after the column names, as a Markdown table has a single
+    /// header row.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # use polars_core::fmt::RenderOptions;
+    /// let df = df!("a" => [1, 2], "b" => ["x", "y"])?;
+    /// let options = RenderOptions {
+    ///     show_shape: false,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     df.to_markdown(&options),
+    ///     "\
+    /// | a (i32) | b (str) |
+    /// | ------: | :------ |
+    /// |       1 | x       |
+    /// |       2 | y       |
+    /// "
+    /// );
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn to_markdown(&self, options: &RenderOptions) -> String {
+        let layout = Layout::new(self, options);
+        let header = layout.cols.iter().map(|col| match col {
+            Some(col) if options.show_dtypes => {
+                format!("{} ({})", layout.name(*col), layout.dtype(*col))
+            },
+            Some(col) => layout.name(*col),
+            None => "…".to_string(),
+        });
+        let mut table = vec![header.map(|s| escape_markdown(&s)).collect::<Vec<_>>()];
+        for row in &layout.rows {
+            let cells = layout.cols.iter().map(|col| match (row, col) {
+                (Some(row), Some(col)) => escape_markdown(&layout.cell(*row, *col)),
+                _ => "…".to_string(),
+            });
+            table.push(cells.collect());
+        }
+
+        let widths = (0..layout.cols.len())
+            .map(|i| {
+                let width = table.iter().map(|row| row[i].chars().count()).max();
+                width.unwrap_or(0).max(3)
+            })
+            .collect::<Vec<_>>();
+        let alignments = layout
+            .cols
+            .iter()
+            .map(|col| layout.alignment(*col))
+            .collect::<Vec<_>>();
+
+        let mut out = String::new();
+        if options.show_shape {
+            writeln!(out, "shape: {}\n", fmt_df_shape(&self.shape())).unwrap();
+        }
+        let write_row = |out: &mut String, row: &[String]| {
+            out.push('|');
+            for ((cell, width), alignment) in row.iter().zip(&widths).zip(&alignments) {
+                let width = *width;
+                match alignment {
+                    Alignment::Left => write!(out, " {cell:<width$} |"),
+                    Alignment::Center => write!(out, " {cell:^width$} |"),
+                    Alignment::Right => write!(out, " {cell:>width$} |"),
+                }
+                .unwrap();
+            }
+            out.push('\n');
+        };
+        write_row(&mut out, &table[0]);
+        out.push('|');
+        for (width, alignment) in widths.iter().zip(&alignments) {
+            let dashes = "-".repeat(width - 1);
+            match alignment {
+                Alignment::Left => write!(out, " :{dashes} |"),
+                Alignment::Center => write!(out, " :{}: |", &dashes[1..]),
+                Alignment::Right => write!(out, " {dashes}: |"),
+            }
+            .unwrap();
+        }
+        out.push('\n');
+        for row in &table[1..] {
+            write_row(&mut out, row);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_render_limits() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1, 2, 3, 4, 5],
+            "b" => ["x|y", "a\nb", "c", "d", "e"],
+            "c" => [1.5, 2.5, 3.5, 4.5, 5.5],
+        ]?;
+        let options = RenderOptions {
+            max_rows: Some(2),
+            max_cols: Some(2),
+            show_dtypes: false,
+            show_shape: false,
+            ..Default::default()
+        };
+
+        let expected = "\
+|   a |  …  |   c |
+| --: | :-: | --: |
+|   1 |  …  | 1.5 |
+|   … |  …  |   … |
+|   5 |  …  | 5.5 |
+";
+        assert_eq!(df.to_markdown(&options), expected);
+
+        let options = RenderOptions {
+            alignment: Some(Alignment::Left),
+            max_rows: Some(2),
+            ..Default::default()
+        };
+        let expected = "\
+shape: (5, 3)
+
+| a (i32) | b (str) | c (f64) |
+| :------ | :------ | :------ |
+| 1       | x\\|y    | 1.5     |
+| …       | …       | …       |
+| 5       | e       | 5.5     |
+";
+        assert_eq!(df.to_markdown(&options), expected);
+
+        let html = df.head(Some(2)).to_html(&options);
+        assert!(html.starts_with("<div><small>shape: (2, 3)</small><table"));
+        assert!(html.contains("<tr><th>a</th><th>b</th><th>c</th></tr>"));
+        assert!(html.contains("<td>a\nb</td>"));
+        Ok(())
+    }
+}
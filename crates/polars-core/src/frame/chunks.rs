@@ -1,3 +1,5 @@
+use arrow::array::StructArray;
+use arrow::ffi;
 use arrow::record_batch::RecordBatch;
 use rayon::prelude::*;
 
@@ -21,7 +23,44 @@ impl TryFrom<(RecordBatch, &[ArrowField])> for DataFrame {
     }
 }
 
+/// Export `batches` of a query with the given `schema` to the
+/// [Arrow C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html).
+///
+/// The chunks of every batch are exported as struct arrays of their columns, which is how record
+/// batches are passed through the interface. The batches are pulled as the consumer asks for them.
+pub fn export_arrow_stream<I>(schema: &Schema, batches: I, pl_flavor: bool) -> ffi::ArrowArrayStream
+where
+    I: Iterator<Item = PolarsResult<DataFrame>> + 'static,
+{
+    let data_type = ArrowDataType::Struct(schema.to_arrow(pl_flavor).fields);
+    let field = ArrowField::new("", data_type.clone(), false);
+
+    let arrays = batches.flat_map(move |df| {
+        let arrays: Vec<PolarsResult<ArrayRef>> = match df {
+            Ok(mut df) => {
+                df.align_chunks();
+                df.iter_chunks(pl_flavor, false)
+                    .map(|rb| {
+                        StructArray::try_new(data_type.clone(), rb.into_arrays(), None)
+                            .map(|arr| arr.boxed())
+                    })
+                    .collect()
+            },
+            Err(err) => vec![Err(err)],
+        };
+        arrays
+    });
+    ffi::export_iterator(Box::new(arrays), field)
+}
+
 impl DataFrame {
+    /// Export to the [Arrow C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html),
+    /// with a record batch per chunk.
+    pub fn into_arrow_stream(self, pl_flavor: bool) -> ffi::ArrowArrayStream {
+        let schema = self.schema();
+        export_arrow_stream(&schema, std::iter::once(Ok(self)), pl_flavor)
+    }
+
     pub fn split_chunks(&mut self) -> impl Iterator<Item = DataFrame> + '_ {
         self.align_chunks();
 
@@ -36,6 +75,47 @@ impl DataFrame {
         })
     }
 
+    /// Rechunk such that every chunk has exactly `rows_per_chunk` rows, except for the last one,
+    /// which holds the remaining rows. The chunks of all columns are aligned, so every chunk
+    /// exports as a record batch of that length.
+    ///
+    /// Larger chunks are sliced without copying, smaller ones are concatenated.
+    pub fn rechunk_by_rows(&self, rows_per_chunk: usize) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            rows_per_chunk > 0,
+            InvalidOperation: "`rows_per_chunk` must be positive, got 0"
+        );
+        let height = self.height();
+        let lengths = (0..height)
+            .step_by(rows_per_chunk)
+            .map(|offset| std::cmp::min(rows_per_chunk, height - offset))
+            .collect::<Vec<_>>();
+
+        let rechunk = |s: &Series| -> PolarsResult<Series> {
+            if height == 0 {
+                return Ok(s.rechunk());
+            }
+            if s.chunk_lengths().eq(lengths.iter().copied()) {
+                return Ok(s.clone());
+            }
+            let mut offset = 0;
+            let mut out = s.clear();
+            for len in &lengths {
+                // Appending keeps the chunks of both sides, so every part stays a chunk.
+                out.append(&s.slice(offset as i64, *len).rechunk())?;
+                offset += len;
+            }
+            Ok(out)
+        };
+        let columns = POOL.install(|| {
+            self.get_columns()
+                .par_iter()
+                .map(rechunk)
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        Ok(unsafe { DataFrame::new_no_checks(columns) })
+    }
+
     pub fn split_chunks_by_n(self, n: usize, parallel: bool) -> Vec<DataFrame> {
         let split = _split_offsets(self.height(), n);
 
@@ -0,0 +1,218 @@
+//! Conform a [`DataFrame`] to a target schema, see [`DataFrame::conform_to`].
+use std::fmt::{Display, Formatter};
+
+use smartstring::alias::String as SmartString;
+
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// How [`DataFrame::conform_to`] deals with columns that don't match the target schema.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ConformPolicy {
+    /// Add the columns of the schema that are missing as null columns, instead of raising an
+    /// error.
+    pub add_missing: bool,
+    /// Drop the columns that are not in the schema, instead of raising an error.
+    pub drop_extra: bool,
+    /// How to cast columns of another data type. If `None`, these raise an error.
+    pub cast: Option<CastOptions>,
+}
+
+impl Default for ConformPolicy {
+    fn default() -> Self {
+        Self {
+            add_missing: true,
+            drop_extra: true,
+            cast: Some(CastOptions::Strict),
+        }
+    }
+}
+
+impl ConformPolicy {
+    /// Raise an error on missing and extra columns and on columns of another data type.
+    pub fn exact() -> Self {
+        Self {
+            add_missing: false,
+            drop_extra: false,
+            cast: None,
+        }
+    }
+}
+
+/// What [`DataFrame::conform_to`] did with a column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConformAction {
+    /// The column already had the data type of the schema.
+    Kept,
+    /// The column was cast from another data type.
+    Cast {
+        from: DataType,
+        /// The number of values that became null because they couldn't be cast. This is only
+        /// non-zero if the cast isn't strict.
+        nulls_introduced: usize,
+    },
+    /// The column was missing and was added with only nulls.
+    Added,
+    /// The column is not in the schema and was dropped.
+    Dropped,
+}
+
+impl Display for ConformAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Kept => write!(f, "kept"),
+            Self::Cast {
+                from,
+                nulls_introduced: 0,
+            } => write!(f, "cast from {from}"),
+            Self::Cast {
+                from,
+                nulls_introduced,
+            } => write!(f, "cast from {from}, {nulls_introduced} values became null"),
+            Self::Added => write!(f, "added as nulls"),
+            Self::Dropped => write!(f, "dropped"),
+        }
+    }
+}
+
+impl DataFrame {
+    /// Conform the `DataFrame` to `schema`: the columns are put in the order of the schema,
+    /// missing columns are added as nulls, columns that are not in the schema are dropped and
+    /// columns of another data type are cast, as far as `policy` allows.
+    ///
+    /// Next to the result, this returns the action taken for every column: first for the
+    /// columns of the schema, in order, and then for the dropped columns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!["b" => ["1", "2"], "c" => [true, false]]?;
+    /// let schema = Schema::from_iter([
+    ///     Field::new("a", DataType::Float64),
+    ///     Field::new("b", DataType::Int64),
+    /// ]);
+    /// let (out, report) = df.conform_to(&schema, ConformPolicy::default())?;
+    /// assert_eq!(out.schema(), schema);
+    /// assert_eq!(report[0], ("a".into(), ConformAction::Added));
+    /// assert_eq!(report[2], ("c".into(), ConformAction::Dropped));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn conform_to(
+        &self,
+        schema: &Schema,
+        policy: ConformPolicy,
+    ) -> PolarsResult<(DataFrame, Vec<(SmartString, ConformAction)>)> {
+        let mut report = Vec::with_capacity(self.width().max(schema.len()));
+
+        let columns = schema
+            .iter()
+            .map(|(name, dtype)| {
+                let Some(s) = self.column(name).ok() else {
+                    polars_ensure!(
+                        policy.add_missing,
+                        ColumnNotFound: "column {:?} of the schema is missing", name.as_str()
+                    );
+                    report.push((name.clone(), ConformAction::Added));
+                    return Ok(Series::full_null(name, self.height(), dtype));
+                };
+                if s.dtype() == dtype {
+                    report.push((name.clone(), ConformAction::Kept));
+                    return Ok(s.clone());
+                }
+
+                let Some(options) = policy.cast else {
+                    polars_bail!(
+                        SchemaMismatch: "column {:?} has data type {}, expected {}",
+                        name.as_str(), s.dtype(), dtype
+                    );
+                };
+                let out = s.cast_with_options(dtype, options).map_err(|e| {
+                    e.context(format!("casting column {:?} to {}", name.as_str(), dtype).into())
+                })?;
+                report.push((
+                    name.clone(),
+                    ConformAction::Cast {
+                        from: s.dtype().clone(),
+                        nulls_introduced: out.null_count() - s.null_count(),
+                    },
+                ));
+                Ok(out)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        for s in self.get_columns() {
+            if schema.contains(s.name()) {
+                continue;
+            }
+            polars_ensure!(
+                policy.drop_extra,
+                SchemaMismatch: "column {:?} is not in the schema", s.name()
+            );
+            report.push((s.name().into(), ConformAction::Dropped));
+        }
+
+        // SAFETY: the columns have the names of the schema, which are unique, and have the
+        // height of `self`.
+        let df = unsafe { DataFrame::new_no_checks(columns) };
+        Ok((df, report))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_conform_to() -> PolarsResult<()> {
+        let df = df![
+            "c" => ["1", "x", "3"],
+            "a" => [1i32, 2, 3],
+            "extra" => [true, false, true],
+        ]?;
+        let schema = Schema::from_iter([
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::String),
+            Field::new("c", DataType::Int32),
+        ]);
+
+        let policy = ConformPolicy {
+            cast: Some(CastOptions::NonStrict),
+            ..Default::default()
+        };
+        let (out, report) = df.conform_to(&schema, policy)?;
+        assert_eq!(out.schema(), schema);
+        assert_eq!(out.column("b")?.null_count(), 3);
+        assert_eq!(
+            report,
+            [
+                (
+                    "a".into(),
+                    ConformAction::Cast {
+                        from: DataType::Int32,
+                        nulls_introduced: 0
+                    }
+                ),
+                ("b".into(), ConformAction::Added),
+                (
+                    "c".into(),
+                    ConformAction::Cast {
+                        from: DataType::String,
+                        nulls_introduced: 1
+                    }
+                ),
+                ("extra".into(), ConformAction::Dropped),
+            ]
+        );
+
+        // A strict cast fails on the value that can't be cast.
+        assert!(df.conform_to(&schema, ConformPolicy::default()).is_err());
+        let exact = ConformPolicy::exact();
+        assert!(df.conform_to(&df.schema(), exact).is_ok());
+        assert!(df.drop("extra")?.conform_to(&df.schema(), exact).is_err());
+        assert!(df
+            .conform_to(&Schema::from_iter(df.schema().iter_fields().take(2)), exact)
+            .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use polars_compute::decimal;
+
+use super::*;
+use crate::chunked_array::arithmetic::DECIMAL_MAX_PRECISION;
+
+impl DecimalChunked {
+    /// The sum of every group, `None` if it doesn't fit a decimal.
+    unsafe fn agg_sum_opt(&self, groups: &GroupsProxy) -> Vec<Option<i128>> {
+        let ca = self.0.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let (values, validity) = (arr.values().as_slice(), arr.validity());
+        let bound = 10_u128.pow(DECIMAL_MAX_PRECISION as u32);
+        let fits = |sum: Option<i128>| sum.filter(|v| v.unsigned_abs() < bound);
+        POOL.install(|| match groups {
+            GroupsProxy::Idx(groups) => groups
+                .all()
+                .par_iter()
+                .map(|idx| fits(decimal::sum_gather(values, validity, idx)))
+                .collect(),
+            GroupsProxy::Slice { groups, .. } => groups
+                .par_iter()
+                .map(|&[first, len]| {
+                    let (first, len) = (first as usize, len as usize);
+                    let validity = validity.map(|v| v.clone().sliced_unchecked(first, len));
+                    let values = values.get_unchecked(first..first + len);
+                    fits(decimal::sum(values, validity.as_ref()))
+                })
+                .collect(),
+        })
+    }
+
+    /// The sum of every group, which raises an error if it doesn't fit a decimal, instead of
+    /// wrapping around like the sum of integers.
+    ///
+    /// # Safety
+    /// The groups must be in bounds.
+    pub unsafe fn agg_sum_checked(&self, groups: &GroupsProxy) -> PolarsResult<Series> {
+        let sums = self
+            .agg_sum_opt(groups)
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| {
+                polars_err!(
+                    ComputeError: "decimal overflow: the sum of a group of '{}' doesn't fit {}",
+                    self.name(), self.dtype()
+                )
+            })?;
+        Ok(Int128Chunked::from_vec(self.name(), sums)
+            .into_decimal_unchecked(self.precision(), self.scale())
+            .into_series())
+    }
+
+    /// The sum of every group, which is null if it doesn't fit a decimal.
+    pub(crate) unsafe fn agg_sum(&self, groups: &GroupsProxy) -> Series {
+        let sums: Int128Chunked = self.agg_sum_opt(groups).into_iter().collect();
+        sums.with_name(self.name())
+            .into_decimal_unchecked(self.precision(), self.scale())
+            .into_series()
+    }
+}
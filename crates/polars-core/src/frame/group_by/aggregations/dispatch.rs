@@ -141,6 +141,8 @@ impl Series {
             Boolean => s.cast(&Float64).unwrap().agg_mean(groups),
             Float32 => SeriesWrap(s.f32().unwrap().clone()).agg_mean(groups),
             Float64 => SeriesWrap(s.f64().unwrap().clone()).agg_mean(groups),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s.cast(&Float32).unwrap().agg_mean(groups),
             dt if dt.is_numeric() => apply_method_physical_integer!(s, agg_mean, groups),
             #[cfg(feature = "dtype-datetime")]
             dt @ Datetime(_, _) => self
@@ -193,6 +195,8 @@ impl Series {
             Boolean => s.cast(&Float64).unwrap().agg_median(groups),
             Float32 => SeriesWrap(s.f32().unwrap().clone()).agg_median(groups),
             Float64 => SeriesWrap(s.f64().unwrap().clone()).agg_median(groups),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s.cast(&Float32).unwrap().agg_median(groups),
             dt if dt.is_numeric() => apply_method_physical_integer!(s, agg_median, groups),
             #[cfg(feature = "dtype-datetime")]
             dt @ Datetime(_, _) => self
@@ -249,6 +253,11 @@ impl Series {
         match s.dtype() {
             Float32 => s.f32().unwrap().agg_quantile(groups, quantile, interpol),
             Float64 => s.f64().unwrap().agg_quantile(groups, quantile, interpol),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s
+                .cast(&Float32)
+                .unwrap()
+                .agg_quantile(groups, quantile, interpol),
             dt if dt.is_numeric() || dt.is_temporal() => {
                 let ca = s.to_physical_repr();
                 let physical_type = ca.dtype();
@@ -266,6 +275,83 @@ impl Series {
         }
     }
 
+    /// The `quantiles` of every group, as an array with a value per quantile.
+    ///
+    /// The values of a group are selected once for all quantiles.
+    #[cfg(feature = "dtype-array")]
+    #[doc(hidden)]
+    pub unsafe fn agg_quantiles(
+        &self,
+        groups: &GroupsProxy,
+        quantiles: &[f64],
+        interpol: QuantileInterpolOptions,
+    ) -> PolarsResult<Series> {
+        use arrow::array::FixedSizeListArray;
+
+        use crate::chunked_array::ops::aggregate::{check_quantiles, quantiles_slice};
+
+        check_quantiles(quantiles)?;
+        let k = quantiles.len();
+        let dtype = self.dtype();
+        polars_ensure!(
+            dtype.is_numeric(),
+            InvalidOperation: "`quantiles` operation not supported for dtype `{}`", dtype
+        );
+        let ca = self.cast(&DataType::Float64)?.f64()?.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let (values, validity) = (arr.values().as_slice(), arr.validity());
+        let is_valid = |i: usize| validity.map_or(true, |v| v.get_bit_unchecked(i));
+
+        let per_group: Vec<_> = POOL.install(|| {
+            groups
+                .par_iter()
+                .map(|g| {
+                    let group_len = g.len();
+                    let mut group_values: Vec<f64> = match g {
+                        GroupsIndicator::Idx((_, idx)) => idx
+                            .iter()
+                            .map(|&i| i as usize)
+                            .filter(|&i| is_valid(i))
+                            .map(|i| *values.get_unchecked(i))
+                            .collect(),
+                        GroupsIndicator::Slice([first, len]) => (first as usize
+                            ..(first + len) as usize)
+                            .filter(|&i| is_valid(i))
+                            .map(|i| *values.get_unchecked(i))
+                            .collect(),
+                    };
+                    let null_count = group_len - group_values.len();
+                    quantiles_slice(&mut group_values, null_count, quantiles, interpol)
+                })
+                .collect()
+        });
+
+        let flat: Float64Chunked = per_group.into_iter().flatten().collect();
+        let arrow_dtype = FixedSizeListArray::default_datatype(ArrowDataType::Float64, k);
+        let arr = FixedSizeListArray::new(arrow_dtype, flat.chunks()[0].clone(), None);
+        let out = ArrayChunked::with_chunk(self.name(), arr).into_series();
+        if dtype == &DataType::Float32 {
+            out.cast(&DataType::Array(Box::new(DataType::Float32), k))
+        } else {
+            Ok(out)
+        }
+    }
+
+    /// The `quantiles` of the values, as a single array with a value per quantile.
+    #[cfg(feature = "dtype-array")]
+    pub fn quantiles_reduce(
+        &self,
+        quantiles: &[f64],
+        interpol: QuantileInterpolOptions,
+    ) -> PolarsResult<Series> {
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, self.len() as IdxSize]],
+            rolling: false,
+        };
+        // SAFETY: the group is in bounds.
+        unsafe { self.agg_quantiles(&groups, quantiles, interpol) }
+    }
+
     #[doc(hidden)]
     pub unsafe fn agg_last(&self, groups: &GroupsProxy) -> Series {
         // Prevent a rechunk for every individual group.
@@ -1,5 +1,7 @@
 mod agg_list;
 mod boolean;
+#[cfg(feature = "dtype-decimal")]
+mod decimal;
 mod dispatch;
 mod string;
 
@@ -18,6 +20,11 @@ use arrow::legacy::trusted_len::TrustedLenPush;
 use arrow::types::NativeType;
 use num_traits::pow::Pow;
 use num_traits::{Bounded, Float, Num, NumCast, ToPrimitive, Zero};
+use polars_compute::float_sum::FloatSum;
+use polars_compute::min_max::MinMaxKernel;
+use polars_compute::segmented::{
+    segmented_max, segmented_mean, segmented_min, segmented_sum, slices_to_segments, SegmentSum,
+};
 use polars_utils::float::IsFloat;
 use polars_utils::idx_vec::IdxVec;
 use polars_utils::ord::{compare_fn_nan_max, compare_fn_nan_min};
@@ -455,6 +462,14 @@ where
     }
 }
 
+impl<T: PolarsNumericType> ChunkedArray<T> {
+    /// Whether the groups can be reduced with the segmented kernels, which require a single
+    /// chunk without nulls.
+    fn use_segmented_kernels(&self) -> bool {
+        self.chunks().len() == 1 && self.null_count() == 0
+    }
+}
+
 impl<T> ChunkedArray<T>
 where
     T: PolarsNumericType + Sync,
@@ -465,7 +480,9 @@ where
         + Zero
         + Bounded
         + std::iter::Sum<T::Native>
-        + TakeExtremum,
+        + TakeExtremum
+        + SegmentSum<T::Native>,
+    [T::Native]: for<'a> MinMaxKernel<Scalar<'a> = T::Native>,
     ChunkedArray<T>: IntoSeries + ChunkAgg<T::Native>,
 {
     pub(crate) unsafe fn agg_min(&self, groups: &GroupsProxy) -> Series {
@@ -524,6 +541,10 @@ where
                         ),
                     };
                     Self::from(arr).into_series()
+                } else if self.use_segmented_kernels() {
+                    let values = self.downcast_iter().next().unwrap().values().as_slice();
+                    Self::from(segmented_min(values, slices_to_segments(groups_slice)))
+                        .into_series()
                 } else {
                     _agg_helper_slice::<T, _>(groups_slice, |[first, len]| {
                         debug_assert!(len <= self.len() as IdxSize);
@@ -598,6 +619,10 @@ where
                         ),
                     };
                     Self::from(arr).into_series()
+                } else if self.use_segmented_kernels() {
+                    let values = self.downcast_iter().next().unwrap().values().as_slice();
+                    Self::from(segmented_max(values, slices_to_segments(groups_slice)))
+                        .into_series()
                 } else {
                     _agg_helper_slice::<T, _>(groups_slice, |[first, len]| {
                         debug_assert!(len <= self.len() as IdxSize);
@@ -656,6 +681,10 @@ where
                         ),
                     };
                     Self::from(arr).into_series()
+                } else if self.use_segmented_kernels() {
+                    let values = self.downcast_iter().next().unwrap().values().as_slice();
+                    let sums = segmented_sum(values, slices_to_segments(groups));
+                    Self::from_vec(self.name(), sums).into_series()
                 } else {
                     _agg_helper_slice_no_null::<T, _>(groups, |[first, len]| {
                         debug_assert!(len <= self.len() as IdxSize);
@@ -685,7 +714,10 @@ where
         + ChunkAgg<T::Native>,
     T::Native: Pow<T::Native, Output = T::Native>,
 {
-    pub(crate) unsafe fn agg_mean(&self, groups: &GroupsProxy) -> Series {
+    pub(crate) unsafe fn agg_mean(&self, groups: &GroupsProxy) -> Series
+    where
+        T::Native: FloatSum<T::Native> + Float,
+    {
         match groups {
             GroupsProxy::Idx(groups) => {
                 let ca = self.rechunk();
@@ -748,6 +780,10 @@ where
                         ),
                     };
                     ChunkedArray::from(arr).into_series()
+                } else if self.use_segmented_kernels() {
+                    let values = self.downcast_iter().next().unwrap().values().as_slice();
+                    ChunkedArray::<T>::from(segmented_mean(values, slices_to_segments(groups)))
+                        .into_series()
                 } else {
                     _agg_helper_slice::<T, _>(groups, |[first, len]| {
                         debug_assert!(len <= self.len() as IdxSize);
@@ -934,7 +970,10 @@ where
     ChunkedArray<T>: IntoSeries + ChunkAgg<T::Native> + ChunkVar,
     T::Native: NumericNative + Ord,
 {
-    pub(crate) unsafe fn agg_mean(&self, groups: &GroupsProxy) -> Series {
+    pub(crate) unsafe fn agg_mean(&self, groups: &GroupsProxy) -> Series
+    where
+        T::Native: FloatSum<f64>,
+    {
         match groups {
             GroupsProxy::Idx(groups) => {
                 let ca = self.rechunk();
@@ -992,6 +1031,10 @@ where
                         .cast_with_options(&DataType::Float64, CastOptions::Overflowing)
                         .unwrap();
                     ca.agg_mean(groups)
+                } else if self.use_segmented_kernels() {
+                    let values = self.downcast_iter().next().unwrap().values().as_slice();
+                    Float64Chunked::from(segmented_mean(values, slices_to_segments(groups_slice)))
+                        .into_series()
                 } else {
                     _agg_helper_slice::<Float64Type, _>(groups_slice, |[first, len]| {
                         debug_assert!(first + len <= self.len() as IdxSize);
@@ -1,3 +1,4 @@
+use arrow::bitmap::utils::ZipValidity;
 use arrow::legacy::kernels::sort_partition::{create_clean_partitions, partition_to_groups};
 use polars_utils::total_ord::{ToTotalOrd, TotalHash};
 
@@ -265,9 +266,48 @@ impl IntoGroupsProxy for StringChunked {
     }
 }
 
+/// Group binary values that are all inlined in their views. The views then identify the values,
+/// so they are grouped as integers without hashing or comparing any bytes.
+fn group_inlined_views(ca: &BinaryChunked, multithreaded: bool, sorted: bool) -> GroupsProxy {
+    fn views(
+        arr: &BinaryViewArray,
+    ) -> impl ExactSizeIterator<Item = u128> + Clone + Send + Sync + '_ {
+        arr.views().iter().map(|v| v.as_u128())
+    }
+
+    if multithreaded && group_multithreaded(ca) {
+        let n_partitions = _set_partition_size();
+        if ca.null_count() == 0 {
+            let keys = ca.downcast_iter().map(views).collect::<Vec<_>>();
+            group_by_threaded_iter(&keys, n_partitions, sorted)
+        } else {
+            let keys = ca
+                .downcast_iter()
+                .map(|arr| ZipValidity::new_with_validity(views(arr), arr.validity()))
+                .collect::<Vec<_>>();
+            group_by_threaded_iter(&keys, n_partitions, sorted)
+        }
+    } else if ca.null_count() == 0 {
+        group_by(ca.downcast_iter().flat_map(views), sorted)
+    } else {
+        group_by(
+            ca.downcast_iter()
+                .flat_map(|arr| ZipValidity::new_with_validity(views(arr), arr.validity())),
+            sorted,
+        )
+    }
+}
+
 impl IntoGroupsProxy for BinaryChunked {
     #[allow(clippy::needless_lifetimes)]
     fn group_tuples<'a>(&'a self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
+        let all_inlined = self.downcast_iter().all(|arr| {
+            arr.total_buffer_len() == 0 || arr.views().iter().all(|view| view.is_inline())
+        });
+        if all_inlined {
+            return Ok(group_inlined_views(self, multithreaded, sorted));
+        }
+
         let bh = self.to_bytes_hashes(multithreaded, Default::default());
 
         let out = if multithreaded {
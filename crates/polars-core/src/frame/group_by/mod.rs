@@ -1185,4 +1185,29 @@ mod test {
         let _ = df.group_by(["g"])?.sum()?;
         Ok(())
     }
+
+    #[test]
+    fn test_group_by_inlined_strings() -> PolarsResult<()> {
+        // All values are inlined in their views, so the views are used as keys.
+        let df = df![
+            "g" => [Some("a"), Some("bb"), None, Some("a"), Some("twelve bytes"), None],
+            "int" => [1, 2, 3, 4, 5, 6]
+        ]?;
+        let out = df.group_by_stable(["g"])?.groups()?;
+        let groups = out.get_columns()[1].list()?;
+        let groups = groups
+            .into_iter()
+            .map(|g| Vec::from(g.unwrap().idx().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            &[
+                vec![Some(0), Some(3)],
+                vec![Some(1)],
+                vec![Some(2), Some(5)],
+                vec![Some(4)]
+            ]
+        );
+        Ok(())
+    }
 }
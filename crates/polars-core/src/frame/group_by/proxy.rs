@@ -505,23 +505,16 @@ impl GroupsProxy {
             },
         }
     }
+    /// The indices of every group as a list, built at once from the offsets of the groups.
     pub fn as_list_chunked(&self) -> ListChunked {
-        match self {
-            GroupsProxy::Idx(groups) => groups
-                .iter()
-                .map(|(_first, idx)| {
-                    let ca: NoNull<IdxCa> = idx.iter().map(|&v| v as IdxSize).collect();
-                    ca.into_inner().into_series()
-                })
-                .collect_trusted(),
-            GroupsProxy::Slice { groups, .. } => groups
-                .iter()
-                .map(|&[first, len]| {
-                    let ca: NoNull<IdxCa> = (first..first + len).collect_trusted();
-                    ca.into_inner().into_series()
-                })
-                .collect_trusted(),
+        let (indices, offsets, can_fast_explode) = self.prepare_list_agg(self.len());
+        let values = indices.unwrap().chunks()[0].clone();
+        let dtype = LargeListArray::default_datatype(IDX_DTYPE.to_arrow(true));
+        let mut ca = ListChunked::with_chunk("", LargeListArray::new(dtype, offsets, values, None));
+        if can_fast_explode {
+            ca.set_fast_explode()
         }
+        ca
     }
 
     pub fn unroll(self) -> GroupsProxy {
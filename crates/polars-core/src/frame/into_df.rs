@@ -0,0 +1,275 @@
+//! Conversion of Rust structs into the rows of a [`DataFrame`].
+//!
+//! [`IntoDataFrame`] is implemented with `#[derive(IntoDataFrame)]` of the `polars` crate, its
+//! fields must implement [`IntoColumnValue`].
+use super::*;
+
+#[cfg(feature = "dtype-datetime")]
+static NO_TIMEZONE: Option<TimeZone> = None;
+
+/// The value of a field of a struct that implements [`IntoDataFrame`], the field becomes a
+/// column of this data type.
+pub trait IntoColumnValue {
+    /// The data type of the column.
+    fn dtype() -> DataType;
+
+    /// The value of this row in the column.
+    fn into_any_value(self) -> AnyValue<'static>;
+}
+
+/// A struct of which every field is a column of a [`DataFrame`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use polars::prelude::*;
+///
+/// #[derive(IntoDataFrame)]
+/// struct Trip {
+///     id: u32,
+///     #[polars(rename = "distance_km")]
+///     distance: Option<f64>,
+/// }
+///
+/// let trips = vec![Trip { id: 1, distance: Some(2.5) }, Trip { id: 2, distance: None }];
+/// let df = Trip::into_df(trips)?;
+/// ```
+pub trait IntoDataFrame: Sized {
+    /// The name and data type of the column of every field, in the order of the fields.
+    fn fields() -> Vec<Field>;
+
+    /// The values of the fields, in the order of [`IntoDataFrame::fields`].
+    fn into_values(self) -> Vec<AnyValue<'static>>;
+
+    fn schema() -> Schema {
+        Self::fields().into_iter().collect()
+    }
+
+    /// Create a [`DataFrame`] with a row for every struct in `rows`.
+    fn into_df<I: IntoIterator<Item = Self>>(rows: I) -> PolarsResult<DataFrame> {
+        let fields = Self::fields();
+        let rows = rows.into_iter();
+        let mut columns = fields
+            .iter()
+            .map(|_| Vec::with_capacity(rows.size_hint().0))
+            .collect::<Vec<_>>();
+        for row in rows {
+            for (column, value) in columns.iter_mut().zip(row.into_values()) {
+                column.push(value);
+            }
+        }
+        let columns = fields
+            .iter()
+            .zip(columns)
+            .map(|(field, values)| {
+                Series::from_any_values_and_dtype(field.name(), &values, field.data_type(), true)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+}
+
+/// A nested struct becomes a column of the `Struct` data type.
+#[cfg(feature = "dtype-struct")]
+impl<T: IntoDataFrame> IntoColumnValue for T {
+    fn dtype() -> DataType {
+        DataType::Struct(T::fields())
+    }
+
+    fn into_any_value(self) -> AnyValue<'static> {
+        AnyValue::StructOwned(Box::new((self.into_values(), T::fields())))
+    }
+}
+
+/// `None` becomes a null.
+impl<T: IntoColumnValue> IntoColumnValue for Option<T> {
+    fn dtype() -> DataType {
+        T::dtype()
+    }
+
+    fn into_any_value(self) -> AnyValue<'static> {
+        match self {
+            Some(value) => value.into_any_value(),
+            None => AnyValue::Null,
+        }
+    }
+}
+
+macro_rules! impl_into_column_value {
+    ($type:ty, $dtype:ident) => {
+        impl IntoColumnValue for $type {
+            fn dtype() -> DataType {
+                DataType::$dtype
+            }
+
+            fn into_any_value(self) -> AnyValue<'static> {
+                AnyValue::$dtype(self)
+            }
+        }
+    };
+}
+
+impl_into_column_value!(bool, Boolean);
+#[cfg(feature = "dtype-i8")]
+impl_into_column_value!(i8, Int8);
+#[cfg(feature = "dtype-i16")]
+impl_into_column_value!(i16, Int16);
+impl_into_column_value!(i32, Int32);
+impl_into_column_value!(i64, Int64);
+#[cfg(feature = "dtype-u8")]
+impl_into_column_value!(u8, UInt8);
+#[cfg(feature = "dtype-u16")]
+impl_into_column_value!(u16, UInt16);
+impl_into_column_value!(u32, UInt32);
+impl_into_column_value!(u64, UInt64);
+impl_into_column_value!(f32, Float32);
+impl_into_column_value!(f64, Float64);
+
+impl IntoColumnValue for String {
+    fn dtype() -> DataType {
+        DataType::String
+    }
+
+    fn into_any_value(self) -> AnyValue<'static> {
+        AnyValue::StringOwned(self.into())
+    }
+}
+
+impl IntoColumnValue for Vec<u8> {
+    fn dtype() -> DataType {
+        DataType::Binary
+    }
+
+    fn into_any_value(self) -> AnyValue<'static> {
+        AnyValue::BinaryOwned(self)
+    }
+}
+
+#[cfg(feature = "dtype-date")]
+impl IntoColumnValue for chrono::NaiveDate {
+    fn dtype() -> DataType {
+        DataType::Date
+    }
+
+    fn into_any_value(self) -> AnyValue<'static> {
+        AnyValue::Date(crate::chunked_array::temporal::naive_date_to_date(self))
+    }
+}
+
+#[cfg(feature = "dtype-datetime")]
+impl IntoColumnValue for chrono::NaiveDateTime {
+    fn dtype() -> DataType {
+        DataType::Datetime(TimeUnit::Microseconds, None)
+    }
+
+    fn into_any_value(self) -> AnyValue<'static> {
+        AnyValue::Datetime(
+            datetime_to_timestamp_us(self),
+            TimeUnit::Microseconds,
+            &NO_TIMEZONE,
+        )
+    }
+}
+
+#[cfg(feature = "dtype-time")]
+impl IntoColumnValue for chrono::NaiveTime {
+    fn dtype() -> DataType {
+        DataType::Time
+    }
+
+    fn into_any_value(self) -> AnyValue<'static> {
+        AnyValue::Time(crate::chunked_array::temporal::time_to_time64ns(&self))
+    }
+}
+
+/// In milliseconds, as larger durations don't fit in microseconds.
+#[cfg(feature = "dtype-duration")]
+impl IntoColumnValue for chrono::Duration {
+    fn dtype() -> DataType {
+        DataType::Duration(TimeUnit::Milliseconds)
+    }
+
+    fn into_any_value(self) -> AnyValue<'static> {
+        AnyValue::Duration(self.num_milliseconds(), TimeUnit::Milliseconds)
+    }
+}
+
+/// As its hyphenated string, e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+#[cfg(feature = "uuid")]
+impl IntoColumnValue for uuid::Uuid {
+    fn dtype() -> DataType {
+        DataType::String
+    }
+
+    fn into_any_value(self) -> AnyValue<'static> {
+        AnyValue::StringOwned(self.hyphenated().to_string().into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Point {
+        x: i32,
+        y: Option<f64>,
+    }
+
+    // What `#[derive(IntoDataFrame)]` generates.
+    impl IntoDataFrame for Point {
+        fn fields() -> Vec<Field> {
+            vec![
+                Field::new("x", <i32 as IntoColumnValue>::dtype()),
+                Field::new("y", <Option<f64> as IntoColumnValue>::dtype()),
+            ]
+        }
+
+        fn into_values(self) -> Vec<AnyValue<'static>> {
+            vec![self.x.into_any_value(), self.y.into_any_value()]
+        }
+    }
+
+    #[test]
+    fn test_into_df() -> PolarsResult<()> {
+        let points = vec![Point { x: 1, y: Some(0.5) }, Point { x: 2, y: None }];
+        let df = Point::into_df(points)?;
+        assert!(df.equals_missing(&df!["x" => [1, 2], "y" => [Some(0.5), None]]?));
+
+        let empty = Point::into_df(vec![])?;
+        assert_eq!(empty.schema(), Point::schema());
+        assert_eq!(empty.height(), 0);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_into_df_nested() -> PolarsResult<()> {
+        struct Segment {
+            start: Point,
+            end: Option<Point>,
+        }
+
+        impl IntoDataFrame for Segment {
+            fn fields() -> Vec<Field> {
+                vec![
+                    Field::new("start", <Point as IntoColumnValue>::dtype()),
+                    Field::new("end", <Option<Point> as IntoColumnValue>::dtype()),
+                ]
+            }
+
+            fn into_values(self) -> Vec<AnyValue<'static>> {
+                vec![self.start.into_any_value(), self.end.into_any_value()]
+            }
+        }
+
+        let segments = vec![Segment {
+            start: Point { x: 1, y: None },
+            end: None,
+        }];
+        let df = Segment::into_df(segments)?;
+        let point = DataType::Struct(Point::fields());
+        assert_eq!(df.dtypes(), &[point.clone(), point]);
+        assert_eq!(df.column("end")?.null_count(), 1);
+        Ok(())
+    }
+}
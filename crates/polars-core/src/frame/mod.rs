@@ -4,11 +4,15 @@ use std::borrow::Cow;
 use std::{mem, ops};
 
 use ahash::AHashSet;
+use polars_utils::cancel::CancellationToken;
 use rayon::prelude::*;
 
 #[cfg(feature = "algorithm_group_by")]
 use crate::chunked_array::ops::unique::is_unique_helper;
+use crate::fmt::FmtConfig;
 use crate::prelude::*;
+#[cfg(feature = "zip_with")]
+use crate::utils::dtypes_to_supertype;
 #[cfg(feature = "row_hash")]
 use crate::utils::split_df;
 use crate::utils::{slice_offsets, try_get_supertype, NoNull};
@@ -16,16 +20,21 @@ use crate::utils::{slice_offsets, try_get_supertype, NoNull};
 #[cfg(feature = "dataframe_arithmetic")]
 mod arithmetic;
 mod chunks;
+pub mod conform;
 pub mod explode;
 mod from;
 #[cfg(feature = "algorithm_group_by")]
 pub mod group_by;
+pub mod into_df;
 #[cfg(any(feature = "rows", feature = "object"))]
 pub mod row;
+#[cfg(all(feature = "random", feature = "algorithm_group_by"))]
+pub mod sample;
 mod top_k;
 mod upstream_traits;
 
 use arrow::record_batch::RecordBatch;
+pub use chunks::export_arrow_stream;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
@@ -137,6 +146,8 @@ pub enum UniqueKeepStrategy {
 #[derive(Clone)]
 pub struct DataFrame {
     pub(crate) columns: Vec<Series>,
+    /// Per-column display options, see [`DataFrame::with_fmt_config`].
+    pub(crate) fmt_config: Option<Arc<FmtConfig>>,
 }
 
 impl DataFrame {
@@ -302,6 +313,7 @@ impl DataFrame {
 
         Ok(DataFrame {
             columns: series_cols,
+            fmt_config: None,
         })
     }
 
@@ -419,6 +431,58 @@ impl DataFrame {
         self
     }
 
+    /// Add a new column at index 0 that counts the rows within each group of `by`.
+    ///
+    /// The counter of a group starts at `offset` and increases in row order. This is equivalent
+    /// to `int_range(offset, offset + len()).over(by)`, but only needs a single group-by.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let df1: DataFrame = df!("g" => &["a", "b", "a", "a", "b"])?;
+    /// let df2: DataFrame = df1.with_row_index_over("idx", ["g"], None)?;
+    /// let idx = df2.column("idx")?.idx()?;
+    /// assert_eq!(Vec::from(idx), &[Some(0), Some(0), Some(1), Some(2), Some(1)]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    #[cfg(feature = "algorithm_group_by")]
+    pub fn with_row_index_over<I, S>(
+        &self,
+        name: &str,
+        by: I,
+        offset: Option<IdxSize>,
+    ) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let offset = offset.unwrap_or(0);
+        let mut index = vec![offset; self.height()];
+        match self.group_by(by)?.get_groups() {
+            GroupsProxy::Idx(groups) => {
+                // The row indices of a group are in ascending order.
+                for group in groups.all() {
+                    for (i, &row) in group.iter().enumerate() {
+                        index[row as usize] = offset + i as IdxSize;
+                    }
+                }
+            },
+            GroupsProxy::Slice { groups, .. } => {
+                for &[first, len] in groups {
+                    for i in 0..len {
+                        index[(first + i) as usize] = offset + i;
+                    }
+                }
+            },
+        }
+
+        let mut columns = Vec::with_capacity(self.columns.len() + 1);
+        columns.push(IdxCa::from_vec(name, index).into_series());
+        columns.extend_from_slice(&self.columns);
+        DataFrame::new(columns)
+    }
+
     /// Create a new `DataFrame` but does not check the length or duplicate occurrence of the `Series`.
     ///
     /// It is advised to use [DataFrame::new] in favor of this method.
@@ -428,7 +492,10 @@ impl DataFrame {
     /// It is the callers responsibility to uphold the contract of all `Series`
     /// having an equal length and a unique name, if not this may panic down the line.
     pub const unsafe fn new_no_checks(columns: Vec<Series>) -> DataFrame {
-        DataFrame { columns }
+        DataFrame {
+            columns,
+            fmt_config: None,
+        }
     }
 
     /// Create a new `DataFrame` but does not check the length of the `Series`,
@@ -451,7 +518,10 @@ impl DataFrame {
         // we drop early as the brchk thinks the &str borrows are used when calling the drop
         // of both `columns` and `names`
         drop(names);
-        Ok(DataFrame { columns })
+        Ok(DataFrame {
+            columns,
+            fmt_config: None,
+        })
     }
 
     /// Shrink the capacity of this DataFrame to fit its length.
@@ -1690,6 +1760,23 @@ impl DataFrame {
         Ok(unsafe { DataFrame::new_no_checks(new_col) })
     }
 
+    /// Take the [`DataFrame`] rows in `set`, in ascending order.
+    ///
+    /// The rows are gathered as sorted indices, so this is as cheap as a filter with a mask of
+    /// the same selection.
+    pub fn take_index_set(&self, set: &IndexSet) -> PolarsResult<Self> {
+        if let Some(max) = set.max() {
+            polars_ensure!(
+                (max as usize) < self.height(),
+                OutOfBounds: "index {} is out of bounds for a frame of height {}",
+                max, self.height()
+            );
+        }
+        let idx = set.to_vec();
+        // SAFETY: the indices are in bounds and ascending.
+        Ok(unsafe { self._take_unchecked_slice_sorted(&idx, true, IsSorted::Ascending) })
+    }
+
     /// # Safety
     /// The indices must be in-bounds.
     pub unsafe fn take_unchecked(&self, idx: &IdxCa) -> Self {
@@ -1777,7 +1864,25 @@ impl DataFrame {
         let first_descending = sort_options.descending[0];
         let first_by_column = by_column[0].name().to_string();
 
+        // String columns are sorted by their collation keys, so the frame is not sorted by the
+        // byte order of these columns.
+        let has_collation_keys = sort_options.collation.is_some()
+            && by_column.iter().any(|s| s.dtype() == &DataType::String);
+        let first_collated =
+            sort_options.collation.is_some() && by_column[0].dtype() == &DataType::String;
+        let by_column = if has_collation_keys {
+            by_column
+                .into_iter()
+                .map(|s| _collated_sort_column(s, sort_options.collation))
+                .collect()
+        } else {
+            by_column
+        };
+
         let set_sorted = |df: &mut DataFrame| {
+            if first_collated {
+                return;
+            }
             // Mark the first sort column as sorted; if the column does not exist it
             // is ok, because we sorted by an expression not present in the dataframe
             let _ = df.apply(&first_by_column, |s| {
@@ -1796,7 +1901,9 @@ impl DataFrame {
             return Ok(out);
         }
         if let Some((0, k)) = slice {
-            return self.bottom_k_impl(k, by_column, sort_options);
+            if !first_collated {
+                return self.bottom_k_impl(k, by_column, sort_options);
+            }
         }
 
         #[cfg(feature = "dtype-struct")]
@@ -1819,11 +1926,14 @@ impl DataFrame {
                     nulls_last: sort_options.nulls_last[0],
                     multithreaded: sort_options.multithreaded,
                     maintain_order: sort_options.maintain_order,
+                    stable: sort_options.stable,
+                    collation: None,
                 };
                 // fast path for a frame with a single series
                 // no need to compute the sort indices and then take by these indices
                 // simply sort and return as frame
-                if df.width() == 1 && df.check_name_to_idx(s.name()).is_ok() {
+                if df.width() == 1 && !has_collation_keys && df.check_name_to_idx(s.name()).is_ok()
+                {
                     let mut out = s.sort_with(options)?;
                     if let Some((offset, len)) = slice {
                         out = out.slice(offset, len);
@@ -1853,6 +1963,8 @@ impl DataFrame {
         if let Some((offset, len)) = slice {
             take = take.slice(offset, len);
         }
+        // Don't gather the columns of a cancelled query.
+        CancellationToken::check_current()?;
 
         // SAFETY:
         // the created indices are in bounds
@@ -2451,46 +2563,38 @@ impl DataFrame {
     /// Aggregate the column horizontally to their min values.
     #[cfg(feature = "zip_with")]
     pub fn min_horizontal(&self) -> PolarsResult<Option<Series>> {
-        let min_fn = |acc: &Series, s: &Series| min_max_binary_series(acc, s, true);
-
-        match self.columns.len() {
-            0 => Ok(None),
-            1 => Ok(Some(self.columns[0].clone())),
-            2 => min_fn(&self.columns[0], &self.columns[1]).map(Some),
-            _ => {
-                // the try_reduce_with is a bit slower in parallelism,
-                // but I don't think it matters here as we parallelize over columns, not over elements
-                POOL.install(|| {
-                    self.columns
-                        .par_iter()
-                        .map(|s| Ok(Cow::Borrowed(s)))
-                        .try_reduce_with(|l, r| min_fn(&l, &r).map(Cow::Owned))
-                        // we can unwrap the option, because we are certain there is a column
-                        // we started this operation on 3 columns
-                        .unwrap()
-                        .map(|cow| Some(cow.into_owned()))
-                })
-            },
-        }
+        self.min_max_horizontal(true)
     }
 
     /// Aggregate the column horizontally to their max values.
     #[cfg(feature = "zip_with")]
     pub fn max_horizontal(&self) -> PolarsResult<Option<Series>> {
-        let max_fn = |acc: &Series, s: &Series| min_max_binary_series(acc, s, false);
+        self.min_max_horizontal(false)
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn min_max_horizontal(&self, min: bool) -> PolarsResult<Option<Series>> {
+        let min_max_fn = |acc: &Series, s: &Series| min_max_binary_series(acc, s, min);
 
         match self.columns.len() {
             0 => Ok(None),
             1 => Ok(Some(self.columns[0].clone())),
-            2 => max_fn(&self.columns[0], &self.columns[1]).map(Some),
+            2 => min_max_fn(&self.columns[0], &self.columns[1]).map(Some),
             _ => {
+                // Cast once up front, so that the steps of the reduction don't have to.
+                let dtype = dtypes_to_supertype(self.columns.iter().map(|s| s.dtype()))?;
+                let columns = self
+                    .columns
+                    .iter()
+                    .map(|s| s.cast(&dtype))
+                    .collect::<PolarsResult<Vec<_>>>()?;
                 // the try_reduce_with is a bit slower in parallelism,
                 // but I don't think it matters here as we parallelize over columns, not over elements
                 POOL.install(|| {
-                    self.columns
+                    columns
                         .par_iter()
                         .map(|s| Ok(Cow::Borrowed(s)))
-                        .try_reduce_with(|l, r| max_fn(&l, &r).map(Cow::Owned))
+                        .try_reduce_with(|l, r| min_max_fn(&l, &r).map(Cow::Owned))
                         // we can unwrap the option, because we are certain there is a column
                         // we started this operation on 3 columns
                         .unwrap()
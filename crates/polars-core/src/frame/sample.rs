@@ -0,0 +1,290 @@
+//! Stratified and weighted sampling of the rows of a [`DataFrame`].
+//!
+//! Samples without replacement use the weighted reservoir sampling of Efraimidis and Spirakis:
+//! every row gets a random key `u^(1/w)` for a uniform `u` and its weight `w`, and the rows with
+//! the largest keys are the sample. The keys of different chunks are independent, so chunks can
+//! be sampled separately and the samples merged by keeping the largest keys again.
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use rand::distributions::Uniform;
+use rand::prelude::*;
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
+use smartstring::alias::String as SmartString;
+
+use crate::prelude::*;
+use crate::random::get_global_random_u64;
+use crate::utils::NoNull;
+
+/// The size of a sample, for every stratum if the sample is stratified.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub enum SampleSize {
+    N(usize),
+    /// A fraction of the rows, rounded down.
+    Fraction(f64),
+}
+
+impl Eq for SampleSize {}
+
+impl Hash for SampleSize {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            SampleSize::N(n) => n.hash(state),
+            SampleSize::Fraction(f) => f.to_bits().hash(state),
+        }
+    }
+}
+
+impl SampleSize {
+    fn of(&self, len: usize) -> usize {
+        match self {
+            SampleSize::N(n) => *n,
+            SampleSize::Fraction(f) => (len as f64 * f) as usize,
+        }
+    }
+}
+
+/// Arguments for [`DataFrame::sample_by`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub struct SampleArgs {
+    pub size: SampleSize,
+    /// Sample every group of these columns separately.
+    pub by: Vec<SmartString>,
+    /// A column of non-negative weights, rows are sampled proportionally to their weight.
+    pub weights: Option<SmartString>,
+    pub with_replacement: bool,
+    /// Return the sampled rows in random order instead of in the order of the input.
+    pub shuffle: bool,
+    pub seed: Option<u64>,
+}
+
+impl SampleArgs {
+    /// Whether the sample can be taken in a single pass with a bounded amount of memory.
+    pub fn is_reservoir(&self) -> bool {
+        matches!(self.size, SampleSize::N(_)) && !self.with_replacement
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed.unwrap_or_else(get_global_random_u64)
+    }
+
+    /// The weights of the rows of `df`, rows with a null weight have weight 0.
+    pub fn weights(&self, df: &DataFrame) -> PolarsResult<Option<Float64Chunked>> {
+        let Some(name) = &self.weights else {
+            return Ok(None);
+        };
+        let weights = df.column(name)?.cast(&DataType::Float64)?;
+        let weights = weights.f64()?;
+        polars_ensure!(
+            weights.min().map_or(true, |min| min >= 0.0),
+            InvalidOperation: "sample weights in column '{}' must not be negative", name
+        );
+        Ok(Some(weights.fill_null_with_values(0.0)?))
+    }
+}
+
+/// The key of a row in weighted reservoir sampling, `ln(u) / w`. It orders like `u^(1/w)`,
+/// without underflowing for small weights, and is `-inf` for rows with weight 0.
+fn key(rng: &mut SmallRng, weight: f64) -> f64 {
+    let u: f64 = rng.gen_range(f64::MIN_POSITIVE..1.0);
+    u.ln() / weight
+}
+
+/// The reservoir sampling keys of `len` rows, rows with weight 0 get `-inf`.
+pub fn sample_keys(len: usize, weights: Option<&Float64Chunked>, seed: u64) -> Float64Chunked {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let keys: NoNull<Float64Chunked> = match weights {
+        None => (0..len).map(|_| key(&mut rng, 1.0)).collect_trusted(),
+        Some(weights) => weights
+            .into_no_null_iter()
+            .map(|w| key(&mut rng, w))
+            .collect_trusted(),
+    };
+    keys.into_inner().with_name("key")
+}
+
+fn ensure_population(n: usize, population: usize) -> PolarsResult<()> {
+    polars_ensure!(
+        n <= population,
+        ShapeMismatch:
+        "cannot take a larger sample than the total population when `with_replacement=false`"
+    );
+    Ok(())
+}
+
+impl DataFrame {
+    /// The groups of the strata of `by` in order of appearance, or a single group of all rows.
+    fn sample_strata(&self, by: &[SmartString]) -> PolarsResult<Vec<Vec<IdxSize>>> {
+        if by.is_empty() {
+            return Ok(vec![(0..self.height() as IdxSize).collect()]);
+        }
+        let groups = self.group_by_stable(by.iter().map(|s| s.as_str()))?;
+        let groups = groups.take_groups().into_idx();
+        Ok(groups.all().iter().map(|idx| idx.to_vec()).collect())
+    }
+
+    /// The rows with the largest `keys` in every stratum of `by`, the rows of a reservoir
+    /// sample. Rows with a key of `-inf` are never taken.
+    ///
+    /// Strata with fewer rows than `size` are taken completely if `partial` is set, otherwise
+    /// they raise an error. The rows are returned in no particular order.
+    pub fn sample_top_keys(
+        &self,
+        keys: &Float64Chunked,
+        by: &[SmartString],
+        size: SampleSize,
+        partial: bool,
+    ) -> PolarsResult<Vec<IdxSize>> {
+        let keys = keys.rechunk();
+        let keys = keys.cont_slice()?;
+        let mut out = vec![];
+        for mut stratum in self.sample_strata(by)? {
+            let n = size.of(stratum.len());
+            stratum.retain(|i| keys[*i as usize] > f64::NEG_INFINITY);
+            let n = if partial { n.min(stratum.len()) } else { n };
+            ensure_population(n, stratum.len())?;
+            let by_key_desc = |a: &IdxSize, b: &IdxSize| {
+                keys[*b as usize]
+                    .partial_cmp(&keys[*a as usize])
+                    .unwrap_or(Ordering::Equal)
+            };
+            if n > 0 && n < stratum.len() {
+                stratum.select_nth_unstable_by(n - 1, by_key_desc);
+            }
+            stratum.truncate(n);
+            out.extend(stratum);
+        }
+        Ok(out)
+    }
+
+    /// Sample with replacement: draw rows uniformly, or proportionally to their weight.
+    fn sample_with_replacement(
+        &self,
+        args: &SampleArgs,
+        weights: Option<&Float64Chunked>,
+        rng: &mut SmallRng,
+    ) -> PolarsResult<Vec<IdxSize>> {
+        let weights = weights.map(|w| w.rechunk());
+        let weights = weights.as_ref().map(|w| w.cont_slice()).transpose()?;
+        let mut out = vec![];
+        for stratum in self.sample_strata(&args.by)? {
+            let n = args.size.of(stratum.len());
+            if n == 0 {
+                continue;
+            }
+            match weights {
+                None => {
+                    polars_ensure!(
+                        !stratum.is_empty(),
+                        ShapeMismatch: "cannot sample from an empty population"
+                    );
+                    let dist = Uniform::new(0, stratum.len());
+                    out.extend((0..n).map(|_| stratum[dist.sample(rng)]));
+                },
+                Some(weights) => {
+                    let cumulative = stratum
+                        .iter()
+                        .scan(0.0, |total, i| {
+                            *total += weights[*i as usize];
+                            Some(*total)
+                        })
+                        .collect::<Vec<_>>();
+                    let total = cumulative.last().copied().unwrap_or(0.0);
+                    polars_ensure!(
+                        total > 0.0,
+                        ShapeMismatch: "cannot sample from a population without positive weights"
+                    );
+                    let dist = Uniform::new(0.0, total);
+                    out.extend((0..n).map(|_| {
+                        let target = dist.sample(rng);
+                        let pos = cumulative.partition_point(|c| *c <= target);
+                        stratum[pos.min(stratum.len() - 1)]
+                    }));
+                },
+            }
+        }
+        Ok(out)
+    }
+
+    /// Sample the rows of every stratum of `args.by`, optionally proportionally to a column of
+    /// weights.
+    pub fn sample_by(&self, args: &SampleArgs) -> PolarsResult<DataFrame> {
+        let seed = args.seed();
+        let weights = args.weights(self)?;
+        let mut idx = if args.with_replacement {
+            let mut rng = SmallRng::seed_from_u64(seed);
+            self.sample_with_replacement(args, weights.as_ref(), &mut rng)?
+        } else {
+            let keys = sample_keys(self.height(), weights.as_ref(), seed);
+            self.sample_top_keys(&keys, &args.by, args.size, false)?
+        };
+        if args.shuffle {
+            idx.shuffle(&mut SmallRng::seed_from_u64(seed.wrapping_add(1)));
+        } else {
+            idx.sort_unstable();
+        }
+        // SAFETY: the indices are rows of `self`.
+        Ok(unsafe { self.take_unchecked(&IdxCa::from_vec("", idx)) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(size: SampleSize) -> SampleArgs {
+        SampleArgs {
+            size,
+            by: vec!["g".into()],
+            weights: None,
+            with_replacement: false,
+            shuffle: false,
+            seed: Some(0),
+        }
+    }
+
+    #[test]
+    fn test_sample_by() -> PolarsResult<()> {
+        let df = df![
+            "g" => [1, 1, 1, 1, 2, 2, 2, 2, 2, 2],
+            "w" => [0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+            "x" => [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+        ]?;
+
+        let out = df.sample_by(&args(SampleSize::N(2)))?;
+        assert_eq!(
+            Vec::from(out.column("g")?.i32()?),
+            &[Some(1), Some(1), Some(2), Some(2)]
+        );
+
+        let out = df.sample_by(&args(SampleSize::Fraction(0.5)))?;
+        assert_eq!(out.height(), 5);
+
+        // Rows with weight 0 are never sampled.
+        let weighted = SampleArgs {
+            weights: Some("w".into()),
+            ..args(SampleSize::N(2))
+        };
+        let out = df.sample_by(&weighted)?;
+        let g1 = out.filter(&out.column("g")?.equal(1)?)?;
+        assert_eq!(Vec::from(g1.column("x")?.i32()?), &[Some(2), Some(3)]);
+
+        let too_many = SampleArgs {
+            weights: Some("w".into()),
+            ..args(SampleSize::N(3))
+        };
+        assert!(df.sample_by(&too_many).is_err());
+
+        let with_replacement = SampleArgs {
+            with_replacement: true,
+            ..too_many
+        };
+        let out = df.sample_by(&with_replacement)?;
+        assert_eq!(out.height(), 6);
+        Ok(())
+    }
+}
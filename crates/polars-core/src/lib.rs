@@ -15,6 +15,8 @@ pub mod fmt;
 pub mod frame;
 pub mod functions;
 pub mod hashing;
+#[cfg(feature = "mem_stats")]
+pub mod mem;
 mod named_from;
 pub mod prelude;
 #[cfg(feature = "random")]
@@ -0,0 +1,256 @@
+//! Allocation statistics for capacity planning.
+//!
+//! The statistics are collected by the [`TrackingAllocator`], which wraps the allocator that is
+//! actually used and must be installed as the global allocator:
+//!
+//! ```ignore
+//! use polars_core::mem::TrackingAllocator;
+//!
+//! #[global_allocator]
+//! static GLOBAL: TrackingAllocator<jemallocator::Jemalloc> =
+//!     TrackingAllocator::new(jemallocator::Jemalloc);
+//! ```
+//!
+//! [`stats`] then reports how much memory is in use by the process and by the calling thread,
+//! and a [`PeakScope`] measures the peak of a single query, which also works when several
+//! queries run at the same time. With the allocator installed, `LazyFrame::profile` reports
+//! the peak of every node.
+use std::alloc::{GlobalAlloc, Layout};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+
+/// The number of [`PeakScope`]s that can be active at the same time.
+const MAX_SCOPES: usize = 64;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+/// A bit per slot of [`SCOPE_PEAKS`] that is used by a [`PeakScope`].
+static ACTIVE_SCOPES: AtomicU64 = AtomicU64::new(0);
+#[allow(clippy::declare_interior_mutable_const)]
+const ZERO: AtomicUsize = AtomicUsize::new(0);
+static SCOPE_PEAKS: [AtomicUsize; MAX_SCOPES] = [ZERO; MAX_SCOPES];
+
+// These must not allocate nor have a destructor, as they are used from within the allocator.
+thread_local! {
+    static THREAD_CURRENT: Cell<isize> = const { Cell::new(0) };
+    static THREAD_PEAK: Cell<isize> = const { Cell::new(0) };
+}
+
+fn on_alloc(size: usize) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+    let mut active = ACTIVE_SCOPES.load(Ordering::Relaxed);
+    while active != 0 {
+        SCOPE_PEAKS[active.trailing_zeros() as usize].fetch_max(current, Ordering::Relaxed);
+        active &= active - 1;
+    }
+
+    // The thread locals are gone while a thread shuts down.
+    let _ = THREAD_CURRENT.try_with(|thread_current| {
+        let current = thread_current.get() + size as isize;
+        thread_current.set(current);
+        let _ = THREAD_PEAK.try_with(|peak| peak.set(peak.get().max(current)));
+    });
+}
+
+fn on_dealloc(size: usize) {
+    CURRENT.fetch_sub(size, Ordering::Relaxed);
+    let _ = THREAD_CURRENT.try_with(|current| current.set(current.get() - size as isize));
+}
+
+/// A global allocator that counts the bytes allocated by `A`.
+///
+/// The counting costs a few atomic operations per allocation.
+pub struct TrackingAllocator<A> {
+    inner: A,
+}
+
+impl<A> TrackingAllocator<A> {
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for TrackingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc(layout);
+        if !ptr.is_null() {
+            on_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            on_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout);
+        on_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = self.inner.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            if new_size > layout.size() {
+                on_alloc(new_size - layout.size());
+            } else {
+                on_dealloc(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// Allocation statistics in bytes, see [`stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemStats {
+    /// Memory that is allocated and not yet freed.
+    pub current: usize,
+    /// The highest `current` since the process started or [`reset_peak`] was called.
+    pub peak: usize,
+    /// Memory allocated minus memory freed by the calling thread. It is negative if the thread
+    /// freed more memory, allocated by other threads, than it allocated.
+    pub thread_current: isize,
+    /// The highest `thread_current` since the thread started or [`reset_thread_peak`] was
+    /// called.
+    pub thread_peak: isize,
+}
+
+/// Whether the [`TrackingAllocator`] is the global allocator.
+pub fn is_tracking() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// The allocation statistics of the process and the calling thread, or `None` if the
+/// [`TrackingAllocator`] is not installed.
+pub fn stats() -> Option<MemStats> {
+    if !is_tracking() {
+        return None;
+    }
+    Some(MemStats {
+        current: CURRENT.load(Ordering::Relaxed),
+        peak: PEAK.load(Ordering::Relaxed),
+        thread_current: THREAD_CURRENT.with(Cell::get),
+        thread_peak: THREAD_PEAK.with(Cell::get),
+    })
+}
+
+/// Start measuring the peak of the process again from the memory that is currently in use.
+pub fn reset_peak() {
+    PEAK.store(CURRENT.load(Ordering::Relaxed), Ordering::Relaxed);
+}
+
+/// Start measuring the peak of the calling thread again from its current allocations.
+pub fn reset_thread_peak() {
+    THREAD_PEAK.with(|peak| peak.set(THREAD_CURRENT.with(Cell::get)));
+}
+
+/// Measures the peak memory use while it is alive, for instance for a single query.
+///
+/// Unlike [`reset_peak`], scopes don't interfere with each other, so they can be used by
+/// queries that run concurrently. The peak counts the memory of the whole process, including
+/// allocations of other queries that run at the same time.
+pub struct PeakScope {
+    slot: Option<usize>,
+    start: usize,
+}
+
+impl PeakScope {
+    /// Start measuring. The scope measures nothing if the [`TrackingAllocator`] is not
+    /// installed or if too many scopes are active.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        let start = CURRENT.load(Ordering::Relaxed);
+        if !is_tracking() {
+            return Self { slot: None, start };
+        }
+        let mut active = ACTIVE_SCOPES.load(Ordering::Relaxed);
+        while active != u64::MAX {
+            let slot = (!active).trailing_zeros() as usize;
+            match ACTIVE_SCOPES.compare_exchange_weak(
+                active,
+                active | (1 << slot),
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => {
+                    SCOPE_PEAKS[slot].store(start, Ordering::Relaxed);
+                    return Self {
+                        slot: Some(slot),
+                        start,
+                    };
+                },
+                Err(current) => active = current,
+            }
+        }
+        Self { slot: None, start }
+    }
+
+    /// The memory that was in use when the scope started.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// The highest memory use since the scope started.
+    pub fn peak(&self) -> Option<usize> {
+        self.slot
+            .map(|slot| SCOPE_PEAKS[slot].load(Ordering::Relaxed).max(self.start))
+    }
+
+    /// How much the memory use grew at its peak, relative to the start of the scope.
+    pub fn peak_increase(&self) -> Option<usize> {
+        self.peak().map(|peak| peak - self.start)
+    }
+}
+
+impl Drop for PeakScope {
+    fn drop(&mut self) {
+        if let Some(slot) = self.slot {
+            ACTIVE_SCOPES.fetch_and(!(1 << slot), Ordering::AcqRel);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::alloc::System;
+
+    use super::*;
+
+    #[test]
+    fn test_tracking_allocator() {
+        // The accounting is global, so a local instance of the allocator is counted as well.
+        let alloc = TrackingAllocator::new(System);
+        let layout = Layout::from_size_align(1 << 20, 8).unwrap();
+        unsafe {
+            let ptr = alloc.alloc(layout);
+            assert!(is_tracking());
+            let scope = PeakScope::new();
+            let before = stats().unwrap();
+            assert!(before.current >= 1 << 20);
+            assert!(before.thread_current >= 1 << 20);
+
+            let ptr = alloc.realloc(ptr, layout, 3 << 20);
+            let grown = Layout::from_size_align(3 << 20, 8).unwrap();
+            assert!(scope.peak_increase().unwrap() >= 2 << 20);
+            assert!(stats().unwrap().thread_peak >= before.thread_current + (2 << 20));
+
+            alloc.dealloc(ptr, grown);
+            let after = stats().unwrap();
+            assert_eq!(after.thread_current, before.thread_current - (1 << 20));
+            assert!(scope.peak_increase().unwrap() >= 2 << 20);
+
+            reset_thread_peak();
+            assert_eq!(stats().unwrap().thread_peak, after.thread_current);
+        }
+    }
+}
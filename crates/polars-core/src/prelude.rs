@@ -7,6 +7,7 @@ pub use arrow::datatypes::{ArrowSchema, Field as ArrowField};
 pub use arrow::legacy::prelude::*;
 pub(crate) use arrow::trusted_len::TrustedLen;
 pub use polars_utils::index::{ChunkId, IdxSize, NullableChunkId, NullableIdxSize};
+pub use polars_utils::index_set::IndexSet;
 pub(crate) use polars_utils::total_ord::{TotalEq, TotalOrd};
 
 pub use crate::chunked_array::arithmetic::ArithmeticChunked;
@@ -15,6 +16,8 @@ pub use crate::chunked_array::builder::{
     ListBooleanChunkedBuilder, ListBuilderTrait, ListPrimitiveChunkedBuilder,
     ListStringChunkedBuilder, NewChunkedArray, PrimitiveChunkedBuilder, StringChunkedBuilder,
 };
+#[cfg(feature = "rows")]
+pub use crate::chunked_array::builder::SeriesBuilder;
 pub use crate::chunked_array::collect::{ChunkedCollectInferIterExt, ChunkedCollectIterExt};
 pub use crate::chunked_array::iterator::PolarsIterator;
 #[cfg(feature = "dtype-categorical")]
@@ -37,11 +40,15 @@ pub use crate::datatypes::{ArrayCollectIterExt, *};
 pub use crate::error::{
     polars_bail, polars_ensure, polars_err, polars_warn, PolarsError, PolarsResult,
 };
+pub use crate::frame::conform::{ConformAction, ConformPolicy};
 pub use crate::frame::explode::UnpivotArgs;
 #[cfg(feature = "algorithm_group_by")]
 pub(crate) use crate::frame::group_by::aggregations::*;
 #[cfg(feature = "algorithm_group_by")]
 pub use crate::frame::group_by::*;
+pub use crate::frame::into_df::{IntoColumnValue, IntoDataFrame};
+#[cfg(all(feature = "random", feature = "algorithm_group_by"))]
+pub use crate::frame::sample::{SampleArgs, SampleSize};
 pub use crate::frame::{DataFrame, UniqueKeepStrategy};
 pub use crate::hashing::VecHash;
 pub use crate::named_from::{NamedFrom, NamedFromOwned};
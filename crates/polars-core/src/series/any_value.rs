@@ -126,9 +126,17 @@ impl Series {
             DataType::Float64 => any_values_to_f64(values, strict)?
                 .with_cheap_metadata()
                 .into_series(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => any_values_to_f32(values, strict)?
+                .to_float16()
+                .into_series(),
             DataType::Boolean => any_values_to_bool(values, strict)?.into_series(),
             DataType::String => any_values_to_string(values, strict)?.into_series(),
             DataType::Binary => any_values_to_binary(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => any_values_to_string(values, strict)?
+                .parse_json()
+                .into_series(),
             #[cfg(feature = "dtype-date")]
             DataType::Date => any_values_to_date(values, strict)?.into_series(),
             #[cfg(feature = "dtype-time")]
@@ -156,6 +164,11 @@ impl Series {
             DataType::Struct(fields) => any_values_to_struct(values, fields, strict)?,
             #[cfg(feature = "object")]
             DataType::Object(_, registry) => any_values_to_object(values, registry)?,
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(_, storage, _) => {
+                let storage = Series::from_any_values_and_dtype(name, values, storage, strict)?;
+                ExtensionChunked::from_storage(storage, dtype.clone())?.into_series()
+            },
             DataType::Null => Series::new_null(name, values.len()),
             dt => {
                 polars_bail!(
@@ -170,6 +170,61 @@ impl NumOpsDispatchInner for FixedSizeListType {
     }
 }
 
+/// Arithmetic of an array with a number, where the number of a row is applied to every element
+/// of the array of that row. The operands keep their order, so the array can be on either side.
+#[cfg(feature = "dtype-array")]
+fn array_numeric_arithmetic(
+    lhs: &Series,
+    rhs: &Series,
+    op: &dyn Fn(&Series, &Series) -> PolarsResult<Series>,
+) -> PolarsResult<Series> {
+    let (array, number) = if lhs.dtype().is_array() {
+        (lhs, rhs)
+    } else {
+        (rhs, lhs)
+    };
+    let len = array.len().max(number.len());
+    let array = if array.len() == len {
+        array.rechunk()
+    } else {
+        array.new_from_index(0, len)
+    };
+    let shape = array.dtype().get_shape().unwrap();
+    let per_row = shape.iter().product::<usize>();
+
+    let leaf = array.get_leaf_array();
+    let number = if number.len() == 1 {
+        number.clone()
+    } else {
+        let idx = (0..len as IdxSize)
+            .flat_map(|row| std::iter::repeat(row).take(per_row))
+            .collect::<Vec<_>>();
+        // SAFETY: every row is in bounds of the numbers.
+        unsafe { number.take_unchecked(&IdxCa::from_vec("", idx)) }
+    };
+    let out = if lhs.dtype().is_array() {
+        op(&leaf, &number)?
+    } else {
+        op(&number, &leaf)?
+    };
+
+    let dims = std::iter::once(-1)
+        .chain(shape.iter().map(|width| *width as i64))
+        .collect::<Vec<_>>();
+    let out = out.reshape_array(&dims)?.rechunk();
+    // The rows that are null in the array stay null.
+    let validity = array.chunks()[0].validity().cloned();
+    let out = match validity {
+        Some(validity) => {
+            let chunks = vec![out.chunks()[0].with_validity(Some(validity))];
+            // SAFETY: only the validity changed.
+            unsafe { Series::from_chunks_and_dtype_unchecked(lhs.name(), chunks, out.dtype()) }
+        },
+        None => out.with_name(lhs.name()),
+    };
+    Ok(out)
+}
+
 #[cfg(feature = "checked_arithmetic")]
 pub mod checked {
     use num_traits::{CheckedDiv, One, ToPrimitive, Zero};
@@ -278,7 +333,7 @@ pub mod checked {
 
         fn checked_div_num<T: ToPrimitive>(&self, rhs: T) -> PolarsResult<Series> {
             use DataType::*;
-            let s = self.to_physical_repr();
+            let s = self.to_physical_value_repr();
 
             let out = match s.dtype() {
                 #[cfg(feature = "dtype-u8")]
@@ -388,6 +443,32 @@ pub(crate) fn coerce_lhs_rhs<'a>(
     Ok((left, right))
 }
 
+/// Like [`coerce_lhs_rhs`], but decimals keep their own precision and scale, which decide the
+/// type of the result. Integers become the decimal that holds all their values.
+fn coerce_arithmetic_lhs_rhs<'a>(
+    lhs: &'a Series,
+    rhs: &'a Series,
+) -> PolarsResult<(Cow<'a, Series>, Cow<'a, Series>)> {
+    #[cfg(feature = "dtype-decimal")]
+    if lhs.dtype().is_decimal() || rhs.dtype().is_decimal() {
+        let decimal_dtype = |s: &Series| match s.dtype() {
+            dt @ DataType::Decimal(_, _) => Some(dt.clone()),
+            dt => integer_decimal_dtype(dt),
+        };
+        let to_decimal = |s: &'a Series, dtype: DataType| -> PolarsResult<Cow<'a, Series>> {
+            if s.dtype() == &dtype {
+                Ok(Cow::Borrowed(s))
+            } else {
+                s.strict_cast(&dtype).map(Cow::Owned)
+            }
+        };
+        if let (Some(left), Some(right)) = (decimal_dtype(lhs), decimal_dtype(rhs)) {
+            return Ok((to_decimal(lhs, left)?, to_decimal(rhs, right)?));
+        }
+    }
+    coerce_lhs_rhs(lhs, rhs)
+}
+
 // Handle (Date | Datetime) +/- (Duration) | (Duration) +/- (Date | Datetime) | (Duration) +-
 // (Duration)
 // Time arithmetic is only implemented on the date / datetime so ensure that's on left
@@ -492,8 +573,12 @@ impl Add for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.add(b))
             },
+            #[cfg(feature = "dtype-array")]
+            (DataType::Array(_, _), dt) | (dt, DataType::Array(_, _)) if dt.is_numeric() => {
+                array_numeric_arithmetic(self, rhs, &|a, b| a.add(b))
+            },
             _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+                let (lhs, rhs) = coerce_arithmetic_lhs_rhs(self, rhs)?;
                 lhs.add_to(rhs.as_ref())
             },
         }
@@ -510,8 +595,12 @@ impl Sub for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.sub(b))
             },
+            #[cfg(feature = "dtype-array")]
+            (DataType::Array(_, _), dt) | (dt, DataType::Array(_, _)) if dt.is_numeric() => {
+                array_numeric_arithmetic(self, rhs, &|a, b| a.sub(b))
+            },
             _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+                let (lhs, rhs) = coerce_arithmetic_lhs_rhs(self, rhs)?;
                 lhs.subtract(rhs.as_ref())
             },
         }
@@ -533,6 +622,10 @@ impl Mul for &Series {
         match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (Struct(_), Struct(_)) => _struct_arithmetic(self, rhs, |a, b| a.mul(b)),
+            #[cfg(feature = "dtype-array")]
+            (Array(_, _), dt) | (dt, Array(_, _)) if dt.is_numeric() => {
+                array_numeric_arithmetic(self, rhs, &|a, b| a.mul(b))
+            },
             // temporal lh
             (Duration(_), _) | (Date, _) | (Datetime(_, _), _) | (Time, _) => self.multiply(rhs),
             // temporal rhs
@@ -545,7 +638,7 @@ impl Mul for &Series {
                 Ok(out.with_name(self.name()))
             },
             _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+                let (lhs, rhs) = coerce_arithmetic_lhs_rhs(self, rhs)?;
                 lhs.multiply(rhs.as_ref())
             },
         }
@@ -568,6 +661,10 @@ impl Div for &Series {
             (Struct(_), Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.div(b))
             },
+            #[cfg(feature = "dtype-array")]
+            (Array(_, _), dt) | (dt, Array(_, _)) if dt.is_numeric() => {
+                array_numeric_arithmetic(self, rhs, &|a, b| a.div(b))
+            },
             (Duration(_), _) => self.divide(rhs),
             | (Date, _)
             | (Datetime(_, _), _)
@@ -579,7 +676,7 @@ impl Div for &Series {
             | (_ , Datetime(_, _))
             => polars_bail!(opq = div, self.dtype(), rhs.dtype()),
             _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+                let (lhs, rhs) = coerce_arithmetic_lhs_rhs(self, rhs)?;
                 lhs.divide(rhs.as_ref())
             },
         }
@@ -601,8 +698,12 @@ impl Rem for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.rem(b))
             },
+            #[cfg(feature = "dtype-array")]
+            (DataType::Array(_, _), dt) | (dt, DataType::Array(_, _)) if dt.is_numeric() => {
+                array_numeric_arithmetic(self, rhs, &|a, b| a.rem(b))
+            },
             _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+                let (lhs, rhs) = coerce_arithmetic_lhs_rhs(self, rhs)?;
                 lhs.remainder(rhs.as_ref())
             },
         }
@@ -632,7 +733,7 @@ where
     type Output = Series;
 
     fn sub(self, rhs: T) -> Self::Output {
-        let s = self.to_physical_repr();
+        let s = self.to_physical_value_repr();
         macro_rules! sub {
             ($ca:expr) => {{
                 $ca.sub(rhs).into_series()
@@ -662,7 +763,7 @@ where
     type Output = Series;
 
     fn add(self, rhs: T) -> Self::Output {
-        let s = self.to_physical_repr();
+        let s = self.to_physical_value_repr();
         macro_rules! add {
             ($ca:expr) => {{
                 $ca.add(rhs).into_series()
@@ -691,7 +792,7 @@ where
     type Output = Series;
 
     fn div(self, rhs: T) -> Self::Output {
-        let s = self.to_physical_repr();
+        let s = self.to_physical_value_repr();
         macro_rules! div {
             ($ca:expr) => {{
                 $ca.div(rhs).into_series()
@@ -717,7 +818,7 @@ where
 // TODO: remove this, temporary band-aid.
 impl Series {
     pub fn wrapping_trunc_div_scalar<T: Num + NumCast>(&self, rhs: T) -> Self {
-        let s = self.to_physical_repr();
+        let s = self.to_physical_value_repr();
         macro_rules! div {
             ($ca:expr) => {{
                 let rhs = NumCast::from(rhs).unwrap();
@@ -737,7 +838,7 @@ where
     type Output = Series;
 
     fn mul(self, rhs: T) -> Self::Output {
-        let s = self.to_physical_repr();
+        let s = self.to_physical_value_repr();
         macro_rules! mul {
             ($ca:expr) => {{
                 $ca.mul(rhs).into_series()
@@ -766,7 +867,7 @@ where
     type Output = Series;
 
     fn rem(self, rhs: T) -> Self::Output {
-        let s = self.to_physical_repr();
+        let s = self.to_physical_value_repr();
         macro_rules! rem {
             ($ca:expr) => {{
                 $ca.rem(rhs).into_series()
@@ -839,7 +940,7 @@ where
         rhs + self
     }
     fn sub(self, rhs: &Series) -> Self::Output {
-        let s = rhs.to_physical_repr();
+        let s = rhs.to_physical_value_repr();
         macro_rules! sub {
             ($rhs:expr) => {{
                 $rhs.lhs_sub(self).into_series()
@@ -850,7 +951,7 @@ where
         finish_cast(rhs, out)
     }
     fn div(self, rhs: &Series) -> Self::Output {
-        let s = rhs.to_physical_repr();
+        let s = rhs.to_physical_value_repr();
         macro_rules! div {
             ($rhs:expr) => {{
                 $rhs.lhs_div(self).into_series()
@@ -865,7 +966,7 @@ where
         rhs * self
     }
     fn rem(self, rhs: &Series) -> Self::Output {
-        let s = rhs.to_physical_repr();
+        let s = rhs.to_physical_value_repr();
         macro_rules! rem {
             ($rhs:expr) => {{
                 $rhs.lhs_rem(self).into_series()
@@ -950,6 +1051,29 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "dtype-array")]
+    fn test_array_numeric_arithmetic() -> PolarsResult<()> {
+        let a = Series::new("a", [1.0, 2.0, 3.0, 4.0]).reshape_array(&[-1, 2])?;
+        let b = Series::new("b", [2.0, 10.0]);
+
+        let out = (&a * &b)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(out.dtype(), a.dtype());
+        let leaf = out.get_leaf_array();
+        assert_eq!(
+            Vec::from(leaf.f64()?),
+            [Some(2.0), Some(4.0), Some(30.0), Some(40.0)]
+        );
+
+        let out = (&Series::new("b", [1.0]) - &a)?.get_leaf_array();
+        assert_eq!(
+            Vec::from(out.f64()?),
+            [Some(0.0), Some(-1.0), Some(-2.0), Some(-3.0)]
+        );
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "checked_arithmetic")]
     fn test_checked_div() {
@@ -7,5 +7,7 @@ use std::ops::{Add, Div, Mul, Rem, Sub};
 pub use borrowed::*;
 use num_traits::{Num, NumCast};
 
+#[cfg(feature = "dtype-decimal")]
+use crate::chunked_array::arithmetic::integer_decimal_dtype;
 use crate::prelude::*;
 use crate::utils::{get_time_units, try_get_supertype};
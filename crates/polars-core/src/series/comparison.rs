@@ -41,8 +41,8 @@ macro_rules! impl_compare {
 
         let (lhs, rhs) = coerce_lhs_rhs(lhs, rhs).map_err(|_| polars_err!(SchemaMismatch: "could not evalulate comparison between series '{}' of dtype: {} and series '{}' of dtype: {}",
         lhs.name(), lhs.dtype(), rhs.name(), rhs.dtype()))?;
-        let lhs = lhs.to_physical_repr();
-        let rhs = rhs.to_physical_repr();
+        let lhs = lhs.to_physical_value_repr();
+        let rhs = rhs.to_physical_value_repr();
         let mut out = match lhs.dtype() {
             Null => lhs.null().unwrap().$method(rhs.null().unwrap()),
             Boolean => lhs.bool().unwrap().$method(rhs.bool().unwrap()),
@@ -154,49 +154,49 @@ where
 
     fn equal(&self, rhs: Rhs) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = self.to_physical_value_repr();
         Ok(apply_method_physical_numeric!(&s, equal, rhs))
     }
 
     fn equal_missing(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = self.to_physical_value_repr();
         Ok(apply_method_physical_numeric!(&s, equal_missing, rhs))
     }
 
     fn not_equal(&self, rhs: Rhs) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = self.to_physical_value_repr();
         Ok(apply_method_physical_numeric!(&s, not_equal, rhs))
     }
 
     fn not_equal_missing(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = self.to_physical_value_repr();
         Ok(apply_method_physical_numeric!(&s, not_equal_missing, rhs))
     }
 
     fn gt(&self, rhs: Rhs) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = self.to_physical_value_repr();
         Ok(apply_method_physical_numeric!(&s, gt, rhs))
     }
 
     fn gt_eq(&self, rhs: Rhs) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = self.to_physical_value_repr();
         Ok(apply_method_physical_numeric!(&s, gt_eq, rhs))
     }
 
     fn lt(&self, rhs: Rhs) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = self.to_physical_value_repr();
         Ok(apply_method_physical_numeric!(&s, lt, rhs))
     }
 
     fn lt_eq(&self, rhs: Rhs) -> PolarsResult<BooleanChunked> {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = self.to_physical_value_repr();
         Ok(apply_method_physical_numeric!(&s, lt_eq, rhs))
     }
 }
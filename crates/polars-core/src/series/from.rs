@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-extension")]
+use arrow::array::with_data_type;
 use arrow::compute::cast::cast_unchecked as cast;
 use arrow::datatypes::Metadata;
 #[cfg(any(feature = "dtype-struct", feature = "dtype-categorical"))]
@@ -81,6 +83,10 @@ impl Series {
                 .into_series(),
             String => StringChunked::from_chunks(name, chunks).into_series(),
             Binary => BinaryChunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-json")]
+            Json => BinaryChunked::from_chunks(name, chunks)
+                .into_json()
+                .into_series(),
             #[cfg(feature = "dtype-categorical")]
             dt @ (Categorical(rev_map, ordering) | Enum(rev_map, ordering)) => {
                 let cats = UInt32Chunked::from_chunks(name, chunks);
@@ -98,6 +104,10 @@ impl Series {
                 ca.into_series()
             },
             Boolean => BooleanChunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => UInt16Chunked::from_chunks(name, chunks)
+                .into_float16()
+                .into_series(),
             Float32 => Float32Chunked::from_chunks(name, chunks).into_series(),
             Float64 => Float64Chunked::from_chunks(name, chunks).into_series(),
             BinaryOffset => BinaryOffsetChunked::from_chunks(name, chunks).into_series(),
@@ -123,6 +133,11 @@ impl Series {
                     s
                 }
             },
+            #[cfg(feature = "dtype-extension")]
+            Extension(_, storage, _) => {
+                let storage = Series::from_chunks_and_dtype_unchecked(name, chunks, storage);
+                ExtensionChunked::new_unchecked(storage, dtype.clone()).into_series()
+            },
             Null => new_null(name, &chunks),
             Unknown(_) => {
                 panic!("dtype is unknown; consider supplying data-types for all operations")
@@ -207,6 +222,28 @@ impl Series {
             ArrowDataType::Int16 => Ok(Int16Chunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::Int32 => Ok(Int32Chunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::Int64 => Ok(Int64Chunked::from_chunks(name, chunks).into_series()),
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        let arr = arr
+                            .as_any()
+                            .downcast_ref::<PrimitiveArray<arrow::types::f16>>()
+                            .unwrap();
+                        let bits = arr.values().iter().map(|v| v.to_bits()).collect::<Vec<_>>();
+                        Box::new(PrimitiveArray::new(
+                            ArrowDataType::UInt16,
+                            bits.into(),
+                            arr.validity().cloned(),
+                        )) as ArrayRef
+                    })
+                    .collect();
+                Ok(UInt16Chunked::from_chunks(name, chunks)
+                    .into_float16()
+                    .into_series())
+            },
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => {
                 let chunks =
                     cast_chunks(&chunks, &DataType::Float32, CastOptions::NonStrict).unwrap();
@@ -396,6 +433,31 @@ impl Series {
                 };
                 Ok(s)
             },
+            #[cfg(feature = "dtype-json")]
+            ArrowDataType::Extension(ext_name, storage, _) if ext_name == "arrow.json" => {
+                // JSON is exchanged as text, which is parsed into the binary encoding.
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| with_data_type(arr.as_ref(), (**storage).clone()))
+                    .collect();
+                let text = Series::_try_from_arrow_unchecked_with_md(name, chunks, storage, md)?;
+                Ok(text.str()?.parse_json().into_series())
+            },
+            #[cfg(feature = "dtype-extension")]
+            ArrowDataType::Extension(ext_name, storage, metadata) => {
+                // Strip the extension type, so that the storage is converted like any other array.
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| with_data_type(arr.as_ref(), (**storage).clone()))
+                    .collect();
+                let storage = Series::_try_from_arrow_unchecked_with_md(name, chunks, storage, md)?;
+                let dtype = DataType::Extension(
+                    ext_name.clone(),
+                    Box::new(storage.dtype().clone()),
+                    metadata.clone(),
+                );
+                Ok(ExtensionChunked::new_unchecked(storage, dtype).into_series())
+            },
             #[cfg(feature = "dtype-struct")]
             ArrowDataType::Struct(logical_fields) => {
                 // We don't have to convert inner types, as that already
@@ -654,8 +716,22 @@ unsafe fn to_physical_and_dtype(
                 (vec![arrow_array], DataType::Struct(polars_fields))
             })
         },
+        #[cfg(feature = "dtype-json")]
+        dt @ ArrowDataType::Extension(name, _, _) if name == "arrow.json" => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked_with_md("", arrays, &dt, md).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
+        #[cfg(feature = "dtype-extension")]
+        dt @ ArrowDataType::Extension(name, _, _) if name != "POLARS_EXTENSION_TYPE" => {
+            let dt = dt.clone();
+            let s = Series::_try_from_arrow_unchecked_with_md("", arrays, &dt, md).unwrap();
+            (s.chunks().clone(), s.dtype().clone())
+        },
         // Use Series architecture to convert nested logical types to physical.
         dt @ (ArrowDataType::Duration(_)
+        | ArrowDataType::Float16
         | ArrowDataType::Time32(_)
         | ArrowDataType::Time64(_)
         | ArrowDataType::Timestamp(_, _)
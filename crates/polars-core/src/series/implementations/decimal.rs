@@ -1,4 +1,5 @@
 use super::*;
+use crate::chunked_array::arithmetic::DECIMAL_MAX_PRECISION;
 use crate::prelude::*;
 
 unsafe impl IntoSeries for DecimalChunked {
@@ -123,7 +124,7 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
 
     #[cfg(feature = "algorithm_group_by")]
     unsafe fn agg_sum(&self, groups: &GroupsProxy) -> Series {
-        self.agg_helper(|ca| ca.agg_sum(groups))
+        self.0.agg_sum(groups)
     }
 
     #[cfg(feature = "algorithm_group_by")]
@@ -157,6 +158,10 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
         let rhs = rhs.decimal()?;
         ((&self.0) / rhs).map(|ca| ca.into_series())
     }
+    fn remainder(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = rhs.decimal()?;
+        ((&self.0) % rhs).map(|ca| ca.into_series())
+    }
     #[cfg(feature = "algorithm_group_by")]
     fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
         self.0.group_tuples(multithreaded, sorted)
@@ -332,14 +337,20 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
     }
 
     fn sum_reduce(&self) -> PolarsResult<Scalar> {
-        Ok(self.apply_physical(|ca| {
-            let sum = ca.sum();
-            let DataType::Decimal(_, Some(scale)) = self.dtype() else {
-                unreachable!()
-            };
-            let av = AnyValue::Decimal(sum.unwrap(), *scale);
-            Scalar::new(self.dtype().clone(), av)
-        }))
+        let bound = 10_u128.pow(DECIMAL_MAX_PRECISION as u32);
+        let sum = self.0.downcast_iter().try_fold(0_i128, |acc, arr| {
+            polars_compute::decimal::sum(arr.values(), arr.validity())
+                .and_then(|sum| acc.checked_add(sum))
+                .filter(|sum| sum.unsigned_abs() < bound)
+        });
+        let sum = sum.ok_or_else(|| {
+            polars_err!(
+                ComputeError: "decimal overflow: the sum of '{}' doesn't fit {}",
+                self.name(), self.dtype()
+            )
+        })?;
+        let av = AnyValue::Decimal(sum, self.0.scale());
+        Ok(Scalar::new(self.dtype().clone(), av))
     }
     fn min_reduce(&self) -> PolarsResult<Scalar> {
         Ok(self.apply_physical(|ca| {
@@ -0,0 +1,242 @@
+use super::*;
+use crate::prelude::*;
+use crate::series::private::{PrivateSeries, PrivateSeriesNumeric};
+
+unsafe impl IntoSeries for ExtensionChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl SeriesWrap<ExtensionChunked> {
+    fn rewrap(&self, storage: Series) -> Series {
+        self.0.with_storage(storage).into_series()
+    }
+
+    fn try_rewrap(&self, storage: PolarsResult<Series>) -> PolarsResult<Series> {
+        storage.map(|s| self.rewrap(s))
+    }
+}
+
+impl PrivateSeriesNumeric for SeriesWrap<ExtensionChunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        self.0.storage().bit_repr()
+    }
+}
+
+impl PrivateSeries for SeriesWrap<ExtensionChunked> {
+    fn compute_len(&mut self) {
+        self.0.storage_mut()._get_inner_mut().compute_len()
+    }
+    fn _field(&self) -> Cow<Field> {
+        Cow::Owned(Field::new(self.0.name(), self.0.dtype().clone()))
+    }
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+    fn _get_flags(&self) -> MetadataFlags {
+        self.0.storage().get_flags()
+    }
+    fn _set_flags(&mut self, flags: MetadataFlags) {
+        self.0.storage_mut().set_flags(flags)
+    }
+
+    fn explode_by_offsets(&self, offsets: &[i64]) -> Series {
+        self.rewrap(self.0.storage().explode_by_offsets(offsets))
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        let other = other.extension().unwrap().storage();
+        self.0.storage().equal_element(idx_self, idx_other, other)
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        self.0.storage().into_total_eq_inner()
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        self.0.storage().into_total_ord_inner()
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.extension()?.storage();
+        self.try_rewrap(self.0.storage().zip_with_same_type(mask, other))
+    }
+
+    fn vec_hash(&self, random_state: RandomState, buf: &mut Vec<u64>) -> PolarsResult<()> {
+        self.0.storage().vec_hash(random_state, buf)
+    }
+
+    fn vec_hash_combine(&self, build_hasher: RandomState, hashes: &mut [u64]) -> PolarsResult<()> {
+        self.0.storage().vec_hash_combine(build_hasher, hashes)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsProxy) -> Series {
+        let out = self.0.storage().agg_list(groups);
+        let mut ca = out.list().unwrap().clone();
+        ca.set_inner_dtype(self.0.dtype().clone());
+        ca.into_series()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
+        self.0.storage().group_tuples(multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Series],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.0.storage().arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<ExtensionChunked> {
+    fn rename(&mut self, name: &str) {
+        self.0.storage_mut().rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter {
+        self.0.storage().chunk_lengths()
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.storage().chunks()
+    }
+
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.storage_mut()._get_inner_mut().chunks_mut()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.storage_mut().shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.rewrap(self.0.storage().slice(offset, length))
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.storage().split_at(offset);
+        (self.rewrap(a), self.rewrap(b))
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let other = other.extension()?.storage();
+        self.0.storage_mut().append(other)?;
+        Ok(())
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        let other = other.extension()?.storage();
+        self.0.storage_mut().extend(other)?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        self.try_rewrap(self.0.storage().filter(filter))
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        self.try_rewrap(self.0.storage().take(indices))
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.rewrap(self.0.storage().take_unchecked(indices))
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        self.try_rewrap(self.0.storage().take_slice(indices))
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.rewrap(self.0.storage().take_slice_unchecked(indices))
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.rewrap(self.0.storage().rechunk())
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.rewrap(self.0.storage().new_from_index(index, length))
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    fn get(&self, index: usize) -> PolarsResult<AnyValue> {
+        self.0.storage().get(index)
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.storage().get_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        self.try_rewrap(self.0.storage().sort_with(options))
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0.storage().arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.storage().null_count()
+    }
+
+    fn has_validity(&self) -> bool {
+        self.0.storage().has_validity()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        self.try_rewrap(self.0.storage().unique())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.0.storage().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.0.storage().arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.storage().is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.storage().is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.rewrap(self.0.storage().reverse())
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.rewrap(self.0.storage().shift(periods))
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+}
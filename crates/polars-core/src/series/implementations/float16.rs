@@ -0,0 +1,387 @@
+//! `Float16` stores the bits of the values in a `UInt16Chunked`. Moving values around (take,
+//! filter, slice, ...) is done on the bits, everything that looks at the values (comparisons,
+//! hashing, sorting, aggregations and arithmetic) is done on the values widened to `Float32`.
+use arrow::types::f16;
+use polars_utils::total_ord::TotalEq;
+
+use super::*;
+use crate::chunked_array::ops::compare_inner::{IntoTotalEqInner, IntoTotalOrdInner};
+#[cfg(feature = "algorithm_group_by")]
+use crate::frame::group_by::*;
+use crate::prelude::*;
+use crate::series::private::{PrivateSeries, PrivateSeriesNumeric};
+
+unsafe impl IntoSeries for Float16Chunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl SeriesWrap<Float16Chunked> {
+    fn rewrap(ca: UInt16Chunked) -> Series {
+        ca.into_float16().into_series()
+    }
+
+    fn to_float32(&self) -> Float32Chunked {
+        self.0.to_float32()
+    }
+
+    fn narrow(s: Series) -> Series {
+        s.f32().unwrap().to_float16().into_series()
+    }
+
+    fn float32_scalar_to_float16(sc: Scalar) -> Scalar {
+        Scalar::new(DataType::Float16, sc.value().clone())
+    }
+
+    fn arithmetic(
+        &self,
+        rhs: &Series,
+        f: impl Fn(&Series, &Series) -> PolarsResult<Series>,
+    ) -> PolarsResult<Series> {
+        let lhs = self.to_float32().into_series();
+        let rhs = rhs.cast(&DataType::Float32)?;
+        f(&lhs, &rhs)
+    }
+}
+
+impl PrivateSeriesNumeric for SeriesWrap<Float16Chunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        None
+    }
+}
+
+impl PrivateSeries for SeriesWrap<Float16Chunked> {
+    fn compute_len(&mut self) {
+        self.0.compute_len()
+    }
+
+    fn _field(&self) -> Cow<Field> {
+        Cow::Owned(self.0.field())
+    }
+
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+
+    // The bits don't sort like the values, so the sorted flags are never set on the physical
+    // array.
+    fn _get_flags(&self) -> MetadataFlags {
+        MetadataFlags::empty()
+    }
+
+    fn _set_flags(&mut self, _flags: MetadataFlags) {}
+
+    fn explode_by_offsets(&self, offsets: &[i64]) -> Series {
+        Self::rewrap(self.0.explode_by_offsets(offsets).u16().unwrap().clone())
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        let other = other.float16().unwrap();
+        match (
+            self.0.get_unchecked(idx_self),
+            other.0.get_unchecked(idx_other),
+        ) {
+            (Some(a), Some(b)) => f16::from_bits(a).tot_eq(&f16::from_bits(b)),
+            (a, b) => a.is_none() && b.is_none(),
+        }
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.to_physical_repr().into_owned();
+        self.0
+            .zip_with(mask, other.as_ref().as_ref())
+            .map(Self::rewrap)
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        (&self.0).into_total_eq_inner()
+    }
+
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        (&self.0).into_total_ord_inner()
+    }
+
+    fn vec_hash(&self, random_state: RandomState, buf: &mut Vec<u64>) -> PolarsResult<()> {
+        self.to_float32().vec_hash(random_state, buf)?;
+        Ok(())
+    }
+
+    fn vec_hash_combine(&self, build_hasher: RandomState, hashes: &mut [u64]) -> PolarsResult<()> {
+        self.to_float32().vec_hash_combine(build_hasher, hashes)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_min(&self, groups: &GroupsProxy) -> Series {
+        Self::narrow(self.to_float32().agg_min(groups))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_max(&self, groups: &GroupsProxy) -> Series {
+        Self::narrow(self.to_float32().agg_max(groups))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_sum(&self, groups: &GroupsProxy) -> Series {
+        self.to_float32().agg_sum(groups)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        SeriesWrap(self.to_float32()).agg_std(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        SeriesWrap(self.to_float32()).agg_var(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsProxy) -> Series {
+        // we cannot cast and dispatch as the inner type of the list would be incorrect
+        let out = self.0.agg_list(groups);
+        let mut ca = out.list().unwrap().clone();
+        ca.set_inner_dtype(DataType::Float16);
+        ca.into_series()
+    }
+
+    fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |lhs, rhs| lhs.subtract(rhs))
+    }
+
+    fn add_to(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |lhs, rhs| lhs.add_to(rhs))
+    }
+
+    fn multiply(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |lhs, rhs| lhs.multiply(rhs))
+    }
+
+    fn divide(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |lhs, rhs| lhs.divide(rhs))
+    }
+
+    fn remainder(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |lhs, rhs| lhs.remainder(rhs))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
+        self.to_float32().group_tuples(multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Series],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.to_float32().arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<Float16Chunked> {
+    fn rename(&mut self, name: &str) {
+        self.0.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter {
+        self.0.chunk_lengths()
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.chunks_mut()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        Self::rewrap(self.0.slice(offset, length))
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (Self::rewrap(a), Self::rewrap(b))
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let other = other.to_physical_repr();
+        self.0.append(other.as_ref().as_ref().as_ref());
+        Ok(())
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        let other = other.to_physical_repr();
+        self.0.extend(other.as_ref().as_ref().as_ref());
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        self.0.filter(filter).map(Self::rewrap)
+    }
+
+    fn mean(&self) -> Option<f64> {
+        self.to_float32().mean()
+    }
+
+    fn median(&self) -> Option<f64> {
+        self.to_float32().median().map(|v| v as f64)
+    }
+
+    fn std(&self, ddof: u8) -> Option<f64> {
+        self.to_float32().std(ddof)
+    }
+
+    fn var(&self, ddof: u8) -> Option<f64> {
+        self.to_float32().var(ddof)
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(Self::rewrap(self.0.take(indices)?))
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        Self::rewrap(self.0.take_unchecked(indices))
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        Ok(Self::rewrap(self.0.take(indices)?))
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        Self::rewrap(self.0.take_unchecked(indices))
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        Self::rewrap(self.0.rechunk())
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        Self::rewrap(self.0.new_from_index(index, length))
+    }
+
+    fn cast(&self, data_type: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(data_type, cast_options)
+    }
+
+    fn get(&self, index: usize) -> PolarsResult<AnyValue> {
+        self.0.get_any_value(index)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        let idx = self.to_float32().arg_sort(options);
+        Ok(unsafe { self.take_unchecked(&idx) })
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.to_float32().arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_validity(&self) -> bool {
+        self.0.has_validity()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        let idx = self.arg_unique()?;
+        Ok(unsafe { self.take_unchecked(&idx) })
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.to_float32().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.to_float32().arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        Self::rewrap(self.0.reverse())
+    }
+
+    fn as_single_ptr(&mut self) -> PolarsResult<usize> {
+        self.0.as_single_ptr()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        Self::rewrap(self.0.shift(periods))
+    }
+
+    fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(ChunkAggSeries::sum_reduce(&self.to_float32()))
+    }
+
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        let sc = ChunkAggSeries::max_reduce(&self.to_float32());
+        Ok(Self::float32_scalar_to_float16(sc))
+    }
+
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        let sc = ChunkAggSeries::min_reduce(&self.to_float32());
+        Ok(Self::float32_scalar_to_float16(sc))
+    }
+
+    fn median_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(QuantileAggSeries::median_reduce(&self.to_float32()))
+    }
+
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        Ok(VarAggSeries::var_reduce(&self.to_float32(), ddof))
+    }
+
+    fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        Ok(VarAggSeries::std_reduce(&self.to_float32(), ddof))
+    }
+
+    fn quantile_reduce(
+        &self,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> PolarsResult<Scalar> {
+        QuantileAggSeries::quantile_reduce(&self.to_float32(), quantile, interpol)
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+}
@@ -13,7 +13,13 @@ mod datetime;
 mod decimal;
 #[cfg(feature = "dtype-duration")]
 mod duration;
+#[cfg(feature = "dtype-extension")]
+mod extension;
+#[cfg(feature = "dtype-f16")]
+mod float16;
 mod floats;
+#[cfg(feature = "dtype-json")]
+mod json;
 mod list;
 pub(crate) mod null;
 #[cfg(feature = "object")]
@@ -1,3 +1,5 @@
+#[cfg(any(feature = "dtype-extension", feature = "dtype-json"))]
+use arrow::array::with_data_type;
 #[cfg(any(
     feature = "dtype-datetime",
     feature = "dtype-date",
@@ -76,6 +78,20 @@ impl Series {
 
                 new.to_arrow(pl_flavor, false)
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                let arr = self.float16().unwrap().downcast_get(chunk_idx).unwrap();
+                let values = arr
+                    .values()
+                    .iter()
+                    .map(|&bits| arrow::types::f16::from_bits(bits))
+                    .collect::<Vec<_>>();
+                Box::new(PrimitiveArray::new(
+                    ArrowDataType::Float16,
+                    values.into(),
+                    arr.validity().cloned(),
+                ))
+            },
             #[cfg(feature = "dtype-date")]
             DataType::Date => cast(
                 &*self.chunks()[chunk_idx],
@@ -132,6 +148,21 @@ impl Series {
                     cast_unchecked(arr.as_ref(), &ArrowDataType::LargeBinary).unwrap()
                 }
             },
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(_, _, _) => {
+                let storage = self.extension().unwrap().storage();
+                let arr = storage.to_arrow(chunk_idx, pl_flavor);
+                with_data_type(arr.as_ref(), self.dtype().to_arrow(pl_flavor))
+            },
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => {
+                let ca = self.json().unwrap();
+                let chunk = ca.chunks()[chunk_idx].clone();
+                // SAFETY: the chunk comes from a binary array.
+                let ca = unsafe { BinaryChunked::from_chunks(ca.name(), vec![chunk]) }.into_json();
+                let arr = ca.to_text().into_series().to_arrow(0, pl_flavor);
+                with_data_type(arr.as_ref(), self.dtype().to_arrow(pl_flavor))
+            },
             _ => self.array_ref(chunk_idx).clone(),
         }
     }
@@ -302,6 +302,30 @@ impl Series {
         self._get_inner_mut().shrink_to_fit()
     }
 
+    /// Drop the bytes of string and binary values that are no longer referenced from their
+    /// buffers, also in nested types. The values themselves are not changed.
+    ///
+    /// See [`BinaryChunked::shrink_views`].
+    pub fn shrink_views(&self) -> Series {
+        match self.dtype() {
+            DataType::String => self.str().unwrap().shrink_views().into_series(),
+            DataType::Binary => self.binary().unwrap().shrink_views().into_series(),
+            DataType::List(inner) if inner.contains_views() => self
+                .list()
+                .unwrap()
+                .apply_to_inner(&|s| Ok(s.shrink_views()))
+                .unwrap()
+                .into_series(),
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(_) => self
+                .struct_()
+                .unwrap()
+                ._apply_fields(|s| s.shrink_views())
+                .into_series(),
+            _ => self.clone(),
+        }
+    }
+
     /// Append in place. This is done by adding the chunks of `other` to this [`Series`].
     ///
     /// See [`ChunkedArray::append`] and [`ChunkedArray::extend`].
@@ -442,7 +466,13 @@ impl Series {
             opt => opt,
         };
 
-        let ret = self.0.cast(dtype, new_options);
+        let ret = match dtype {
+            #[cfg(feature = "dtype-extension")]
+            D::Extension(_, _, _) => cast_to_extension(self, dtype, new_options),
+            #[cfg(feature = "dtype-json")]
+            D::Json => JsonChunked::from_series(self).map(|ca| ca.into_series()),
+            _ => self.0.cast(dtype, new_options),
+        };
 
         match options {
             CastOptions::NonStrict | CastOptions::Overflowing => ret,
@@ -461,6 +491,14 @@ impl Series {
     /// # Safety
     /// This can lead to invalid memory access in downstream code.
     pub unsafe fn cast_unchecked(&self, dtype: &DataType) -> PolarsResult<Self> {
+        #[cfg(feature = "dtype-extension")]
+        if let DataType::Extension(_, storage, _) = dtype {
+            if self.dtype() == dtype {
+                return Ok(self.clone());
+            }
+            let storage = self.cast_unchecked(storage)?;
+            return Ok(ExtensionChunked::from_storage(storage, dtype.clone())?.into_series());
+        }
         match self.dtype() {
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(_) => self.struct_().unwrap().cast_unchecked(dtype),
@@ -594,6 +632,10 @@ impl Series {
         match self.dtype() {
             Date => Cow::Owned(self.cast(&Int32).unwrap()),
             Datetime(_, _) | Duration(_) | Time => Cow::Owned(self.cast(&Int64).unwrap()),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Cow::Owned(self.float16().unwrap().0.clone().into_series()),
+            #[cfg(feature = "dtype-json")]
+            Json => Cow::Owned(self.json().unwrap().0.clone().into_series()),
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => {
                 let ca = self.categorical().unwrap();
@@ -611,10 +653,25 @@ impl Series {
                 let ca = StructChunked::new(self.name(), &fields).unwrap();
                 Cow::Owned(ca.into_series())
             },
+            #[cfg(feature = "dtype-extension")]
+            Extension(_, _, _) => {
+                let storage = self.extension().unwrap().storage();
+                Cow::Owned(storage.to_physical_repr().into_owned())
+            },
             _ => Cow::Borrowed(self),
         }
     }
 
+    /// Like [`Series::to_physical_repr`], but for kernels that look at the values: the bits of a
+    /// `Float16` don't compare or compute like its values, so it is widened to `Float32`.
+    pub(crate) fn to_physical_value_repr(&self) -> Cow<Series> {
+        match self.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Cow::Owned(self.float16().unwrap().to_float32().into_series()),
+            _ => self.to_physical_repr(),
+        }
+    }
+
     /// Take by index if ChunkedArray contains a single chunk.
     ///
     /// # Safety
@@ -775,6 +832,13 @@ impl Series {
 
     // used for formatting
     pub fn str_value(&self, index: usize) -> PolarsResult<Cow<str>> {
+        #[cfg(feature = "dtype-extension")]
+        if let DataType::Extension(_, _, _) = self.dtype() {
+            let ca = self.extension().unwrap();
+            if let Some(out) = ca.format_value(&self.0.get(index)?) {
+                return Ok(Cow::Owned(out));
+            }
+        }
         let out = match self.0.get(index)? {
             AnyValue::String(s) => Cow::Borrowed(s),
             AnyValue::Null => Cow::Borrowed("null"),
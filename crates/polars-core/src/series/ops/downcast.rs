@@ -57,6 +57,12 @@ impl Series {
         unpack_chunked!(self, DataType::Int64 => Int64Chunked, "Int64")
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype `[DataType::Float16]`
+    #[cfg(feature = "dtype-f16")]
+    pub fn float16(&self) -> PolarsResult<&Float16Chunked> {
+        unpack_chunked!(self, DataType::Float16 => Float16Chunked, "Float16")
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype `[DataType::Float32]`
     pub fn f32(&self) -> PolarsResult<&Float32Chunked> {
         unpack_chunked!(self, DataType::Float32 => Float32Chunked, "Float32")
@@ -102,6 +108,12 @@ impl Series {
         unpack_chunked!(self, DataType::Binary => BinaryChunked, "Binary")
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype `[DataType::Json]`
+    #[cfg(feature = "dtype-json")]
+    pub fn json(&self) -> PolarsResult<&JsonChunked> {
+        unpack_chunked!(self, DataType::Json => JsonChunked, "Json")
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype `[DataType::Binary]`
     pub fn binary_offset(&self) -> PolarsResult<&BinaryOffsetChunked> {
         unpack_chunked!(self, DataType::BinaryOffset => BinaryOffsetChunked, "BinaryOffset")
@@ -167,6 +179,12 @@ impl Series {
         unpack_chunked!(self, DataType::Struct(_) => StructChunked, "Struct")
     }
 
+    /// Unpack to [`ExtensionChunked`] of dtype `[DataType::Extension]`
+    #[cfg(feature = "dtype-extension")]
+    pub fn extension(&self) -> PolarsResult<&ExtensionChunked> {
+        unpack_chunked!(self, DataType::Extension(_, _, _) => ExtensionChunked, "Extension")
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype `[DataType::Null]`
     pub fn null(&self) -> PolarsResult<&NullChunked> {
         unpack_chunked!(self, DataType::Null => NullChunked, "Null")
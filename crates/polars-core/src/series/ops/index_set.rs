@@ -0,0 +1,101 @@
+use arrow::bitmap::{Bitmap, MutableBitmap};
+
+use crate::prelude::*;
+
+impl BooleanChunked {
+    /// The indices of the rows that are `true`. Null rows are not in the set.
+    pub fn to_index_set(&self) -> IndexSet {
+        let mut set = IndexSet::new();
+        let mut offset = 0;
+        for arr in self.downcast_iter() {
+            let selected = match arr.validity() {
+                Some(validity) => arr.values() & validity,
+                None => arr.values().clone(),
+            };
+            for i in selected.true_idx_iter() {
+                set.insert((offset + i) as IdxSize);
+            }
+            offset += arr.len();
+        }
+        set
+    }
+
+    /// A mask of `len` rows that is `true` for the rows in `set`.
+    pub fn from_index_set(name: &str, set: &IndexSet, len: usize) -> PolarsResult<Self> {
+        if let Some(max) = set.max() {
+            polars_ensure!(
+                (max as usize) < len,
+                OutOfBounds: "index {} is out of bounds for a mask of {} rows", max, len
+            );
+        }
+        let mut bitmap = MutableBitmap::from_len_zeroed(len);
+        for idx in set.iter() {
+            bitmap.set(idx as usize, true);
+        }
+        let bitmap: Bitmap = bitmap.into();
+        Ok(BooleanChunked::from_chunk_iter(
+            name,
+            [BooleanArray::from_data_default(bitmap, None)],
+        ))
+    }
+}
+
+impl Series {
+    /// The set of row indices this series selects.
+    ///
+    /// For a boolean series these are the indices of the `true` rows, for an integer series
+    /// its values, which must not be negative. Null values are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let admin = Series::new("admin", &[true, false, false, true]);
+    /// let own = Series::new("own", &[1 as IdxSize, 3]);
+    /// let visible = admin.to_index_set()?.union(&own.to_index_set()?);
+    /// assert_eq!(visible.to_vec(), &[0, 1, 3]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn to_index_set(&self) -> PolarsResult<IndexSet> {
+        match self.dtype() {
+            DataType::Boolean => Ok(self.bool().unwrap().to_index_set()),
+            dt if dt.is_integer() => {
+                let idx = self.strict_cast(&IDX_DTYPE)?;
+                Ok(idx
+                    .idx()
+                    .unwrap()
+                    .downcast_iter()
+                    .flat_map(|arr| arr.non_null_values_iter())
+                    .collect())
+            },
+            dt => polars_bail!(
+                InvalidOperation: "cannot create an index set from a series of type {}", dt
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mask_index_set_round_trip() -> PolarsResult<()> {
+        let mut mask = BooleanChunked::new("a", &[Some(true), None, Some(false), Some(true)]);
+        // Indices continue over the chunks.
+        mask.append(&BooleanChunked::new("b", &[false, true]));
+
+        let set = mask.to_index_set();
+        assert_eq!(set.to_vec(), &[0, 3, 5]);
+        let out = BooleanChunked::from_index_set("mask", &set, mask.len())?;
+        assert_eq!(
+            Vec::from(out),
+            [true, false, false, true, false, true].map(Some)
+        );
+        assert!(BooleanChunked::from_index_set("mask", &set, 5).is_err());
+
+        let s = Series::new("idx", &[-1i32]);
+        assert!(s.to_index_set().is_err());
+        Ok(())
+    }
+}
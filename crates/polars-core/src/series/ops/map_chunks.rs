@@ -0,0 +1,124 @@
+use arrow::array::Array;
+use rayon::prelude::*;
+
+use crate::prelude::*;
+use crate::POOL;
+
+impl Series {
+    /// Apply a kernel to every chunk of the physical data, running the chunks in parallel.
+    ///
+    /// `f` gets the physical arrow arrays, e.g. the `i64` values of a `Datetime`, and must
+    /// return arrays of the physical type of `dtype`; the result is then given the logical type
+    /// `dtype`. The returned arrays may have a different length than their input, the length and
+    /// null count of the result are computed from them. Empty results are dropped so that no
+    /// empty chunks are kept.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// use arrow::array::{Array, PrimitiveArray};
+    /// use arrow::compute::arity::unary;
+    ///
+    /// let s = Series::new("a", [1i64, 2, 3]);
+    /// let out = s.map_chunks_parallel(&DataType::Float64, |arr| {
+    ///     let arr = arr.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+    ///     Ok(unary(arr, |v| v as f64 / 2.0, ArrowDataType::Float64).boxed())
+    /// })?;
+    /// assert_eq!(out, Series::new("a", [0.5, 1.0, 1.5]));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn map_chunks_parallel<F>(&self, dtype: &DataType, f: F) -> PolarsResult<Series>
+    where
+        F: Fn(&dyn Array) -> PolarsResult<Box<dyn Array>> + Send + Sync,
+    {
+        polars_ensure!(
+            !dtype.is_object() && !matches!(dtype, DataType::Unknown(_)),
+            InvalidOperation: "cannot map chunks to dtype {}", dtype
+        );
+        #[cfg(feature = "dtype-categorical")]
+        polars_ensure!(
+            !matches!(dtype, DataType::Categorical(None, _) | DataType::Enum(None, _)),
+            InvalidOperation: "mapping chunks to a categorical dtype requires its categories"
+        );
+        let physical = dtype.to_physical();
+        let expected = physical.to_arrow(true);
+
+        let chunks = self.chunks();
+        let mut out = if chunks.len() == 1 {
+            vec![f(chunks[0].as_ref())?]
+        } else {
+            POOL.install(|| {
+                chunks
+                    .par_iter()
+                    .map(|arr| f(arr.as_ref()))
+                    .collect::<PolarsResult<Vec<_>>>()
+            })?
+        };
+        for arr in &out {
+            polars_ensure!(
+                arr.data_type() == &expected,
+                SchemaMismatch: "chunk kernel returned arrow type {:?}, expected {:?} for dtype {}",
+                arr.data_type(), expected, dtype
+            );
+        }
+        if out.len() > 1 {
+            out.retain(|arr| !arr.is_empty());
+            if out.is_empty() {
+                out.push(arrow::array::new_empty_array(expected));
+            }
+        }
+
+        // Structs are built from the arrow type of their fields, so their logical types are set
+        // afterwards.
+        #[cfg(feature = "dtype-struct")]
+        if let DataType::Struct(_) = dtype {
+            // SAFETY: the arrow types of the chunks were checked above.
+            let s = unsafe { Series::from_chunks_and_dtype_unchecked(self.name(), out, &physical) };
+            return s.cast(dtype);
+        }
+        // SAFETY: the arrow types of the chunks were checked above.
+        Ok(unsafe { Series::from_chunks_and_dtype_unchecked(self.name(), out, dtype) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::array::{BooleanArray, PrimitiveArray};
+    use arrow::compute::arity::unary;
+
+    use super::*;
+
+    #[test]
+    fn test_map_chunks_parallel() -> PolarsResult<()> {
+        let mut s = Series::new("a", [1i32, 2]);
+        s.append(&Series::new("a", [Option::<i32>::None]))?;
+        s.append(&Series::new("a", [4i32]))?;
+        let s = s.cast(&DataType::Date)?;
+
+        // Keep the first value of every chunk, as days after the unix epoch.
+        let out = s.map_chunks_parallel(&DataType::Date, |arr| {
+            let arr = arr.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+            Ok(unary(&arr.clone().sliced(0, 1), |v| v * 2, ArrowDataType::Int32).boxed())
+        })?;
+        assert_eq!(out.dtype(), &DataType::Date);
+        assert_eq!(out.len(), 3);
+        assert_eq!(out.null_count(), 1);
+        assert_eq!(
+            out.to_physical_repr()
+                .i32()?
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(2), None, Some(8)]
+        );
+
+        let out = s.map_chunks_parallel(&DataType::Boolean, |_| {
+            Ok(BooleanArray::new_empty(ArrowDataType::Boolean).boxed())
+        })?;
+        assert_eq!(out.n_chunks(), 1);
+        assert!(out.is_empty());
+
+        let err = s.map_chunks_parallel(&DataType::Int64, |arr| Ok(arr.to_boxed()));
+        assert!(err.is_err());
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 mod downcast;
 mod extend;
+mod index_set;
+mod map_chunks;
 mod null;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -14,3 +16,14 @@ pub enum NullBehavior {
     #[default]
     Ignore,
 }
+
+/// How a value that lies exactly halfway between two rounded values is rounded.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RoundMode {
+    /// Round away from zero, e.g. 0.5 to 1 and -2.5 to -3.
+    #[default]
+    HalfAway,
+    /// Round to the even neighbour, e.g. 0.5 to 0 and 1.5 to 2.
+    HalfEven,
+}
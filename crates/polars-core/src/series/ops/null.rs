@@ -25,6 +25,14 @@ impl Series {
                 }
                 ca.into_series()
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => UInt16Chunked::full_null(name, size)
+                .into_float16()
+                .into_series(),
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => BinaryChunked::full_null(name, size)
+                .into_json()
+                .into_series(),
             #[cfg(feature = "dtype-date")]
             DataType::Date => Int32Chunked::full_null(name, size)
                 .into_date()
@@ -53,6 +61,11 @@ impl Series {
                     .collect::<Vec<_>>();
                 StructChunked::new(name, &fields).unwrap().into_series()
             },
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(_, storage, _) => {
+                let storage = Series::full_null(name, size, storage);
+                ExtensionChunked::new_unchecked(storage, dtype.clone()).into_series()
+            },
             DataType::Null => Series::new_null(name, size),
             DataType::Unknown(kind) => {
                 let dtype = kind.materialize().expect("expected known type");
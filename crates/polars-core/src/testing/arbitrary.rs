@@ -0,0 +1,441 @@
+//! Generate random [`DataFrame`]s for property-based tests with [`proptest`].
+//!
+//! A [`FrameSpec`] describes the columns to generate; its [`FrameSpec::strategy`] can be used
+//! as input of a `proptest!` test. Failing cases shrink to fewer rows and simpler values.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use polars_core::prelude::*;
+//! use polars_core::testing::arbitrary::{ColumnSpec, FrameSpec};
+//! use proptest::prelude::*;
+//!
+//! let spec = FrameSpec::new()
+//!     .with_height(0..50)
+//!     .with_column("id", ColumnSpec::new(DataType::Int64).with_sorted(true))
+//!     .with_column("name", ColumnSpec::new(DataType::String).with_null_density(0.3));
+//!
+//! proptest!(|(df in spec.strategy())| {
+//!     prop_assert_eq!(df.get_column_names(), &["id", "name"]);
+//!     prop_assert_eq!(df.column("id").unwrap().null_count(), 0);
+//! });
+//! ```
+use std::ops::Range;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::select;
+use proptest::strategy::Union;
+
+use crate::prelude::*;
+
+/// The date range that is generated, from the year 1 up to the year 9999.
+const DATE_RANGE: Range<i32> = -719_162..2_932_897;
+const NANOSECONDS_IN_DAY: i64 = 86_400_000_000_000;
+
+/// Specification of a generated column.
+#[derive(Clone, Debug)]
+pub struct ColumnSpec {
+    pub dtype: DataType,
+    /// Probability of a value being null, between 0.0 and 1.0.
+    ///
+    /// This also applies to the values within nested data types.
+    pub null_density: f64,
+    /// Sort the column in ascending order, with the nulls first.
+    pub sorted: bool,
+}
+
+impl ColumnSpec {
+    pub fn new(dtype: DataType) -> Self {
+        Self {
+            dtype,
+            null_density: 0.0,
+            sorted: false,
+        }
+    }
+
+    /// # Panics
+    /// If `null_density` is not between 0.0 and 1.0.
+    pub fn with_null_density(mut self, null_density: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&null_density),
+            "null density should be between 0.0 and 1.0, got {null_density}"
+        );
+        self.null_density = null_density;
+        self
+    }
+
+    pub fn with_sorted(mut self, sorted: bool) -> Self {
+        self.sorted = sorted;
+        self
+    }
+}
+
+/// Specification of a generated [`DataFrame`].
+#[derive(Clone, Debug)]
+pub struct FrameSpec {
+    pub columns: Vec<(SmartString, ColumnSpec)>,
+    /// Range of the number of rows.
+    pub height: Range<usize>,
+    /// Range of the number of characters of strings and the number of bytes of binary values.
+    pub string_length: Range<usize>,
+    /// Range of the number of elements of lists.
+    pub list_length: Range<usize>,
+}
+
+impl Default for FrameSpec {
+    fn default() -> Self {
+        Self {
+            columns: vec![],
+            height: 0..100,
+            string_length: 0..16,
+            list_length: 0..8,
+        }
+    }
+}
+
+impl FrameSpec {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generate the columns of a [`Schema`], with the given `null_density`.
+    pub fn from_schema(schema: &Schema, null_density: f64) -> Self {
+        schema.iter().fold(Self::new(), |spec, (name, dtype)| {
+            spec.with_column(
+                name,
+                ColumnSpec::new(dtype.clone()).with_null_density(null_density),
+            )
+        })
+    }
+
+    pub fn with_column(mut self, name: &str, column: ColumnSpec) -> Self {
+        self.columns.push((name.into(), column));
+        self
+    }
+
+    pub fn with_height(mut self, height: Range<usize>) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn with_string_length(mut self, string_length: Range<usize>) -> Self {
+        self.string_length = string_length;
+        self
+    }
+
+    pub fn with_list_length(mut self, list_length: Range<usize>) -> Self {
+        self.list_length = list_length;
+        self
+    }
+
+    /// A [`Strategy`] generating frames of this specification.
+    ///
+    /// # Panics
+    /// The generated values panic if a column has a data type that cannot be generated, such as
+    /// objects, decimals or enums without categories.
+    pub fn strategy(&self) -> BoxedStrategy<DataFrame> {
+        let spec = self.clone();
+        self.height
+            .clone()
+            .prop_flat_map(move |height| {
+                spec.columns
+                    .iter()
+                    .map(|(name, column)| spec.column_strategy(name.clone(), column, height))
+                    .collect::<Vec<_>>()
+            })
+            .prop_map(|columns| DataFrame::new(columns).unwrap())
+            .boxed()
+    }
+
+    fn column_strategy(
+        &self,
+        name: SmartString,
+        column: &ColumnSpec,
+        height: usize,
+    ) -> BoxedStrategy<Series> {
+        let dtype = column.dtype.clone();
+        let sorted = column.sorted;
+        let values = nullable(
+            self.values(&dtype, column.null_density),
+            column.null_density,
+        );
+        vec(values, height)
+            .prop_map(move |values| {
+                let s = Series::from_any_values_and_dtype(
+                    &name,
+                    &values,
+                    &generated_dtype(&dtype),
+                    true,
+                )
+                .unwrap();
+                let s = s.cast(&dtype).unwrap();
+                if sorted {
+                    s.sort(SortOptions::default()).unwrap()
+                } else {
+                    s
+                }
+            })
+            .boxed()
+    }
+
+    /// Values of the [`generated_dtype`] of `dtype`.
+    fn values(&self, dtype: &DataType, null_density: f64) -> BoxedStrategy<AnyValue<'static>> {
+        use DataType::*;
+        match dtype {
+            Boolean => any::<bool>().prop_map(AnyValue::Boolean).boxed(),
+            UInt8 => any::<u8>().prop_map(AnyValue::UInt8).boxed(),
+            UInt16 => any::<u16>().prop_map(AnyValue::UInt16).boxed(),
+            UInt32 => any::<u32>().prop_map(AnyValue::UInt32).boxed(),
+            UInt64 => any::<u64>().prop_map(AnyValue::UInt64).boxed(),
+            Int8 => any::<i8>().prop_map(AnyValue::Int8).boxed(),
+            Int16 => any::<i16>().prop_map(AnyValue::Int16).boxed(),
+            Int32 => any::<i32>().prop_map(AnyValue::Int32).boxed(),
+            Int64 => any::<i64>().prop_map(AnyValue::Int64).boxed(),
+            Float32 => any::<f32>().prop_map(AnyValue::Float32).boxed(),
+            Float64 => any::<f64>().prop_map(AnyValue::Float64).boxed(),
+            String => vec(any::<char>(), self.string_length.clone())
+                .prop_map(|chars| AnyValue::StringOwned(chars.into_iter().collect()))
+                .boxed(),
+            Binary => vec(any::<u8>(), self.string_length.clone())
+                .prop_map(AnyValue::BinaryOwned)
+                .boxed(),
+            Date => DATE_RANGE.prop_map(AnyValue::Int32).boxed(),
+            Datetime(tu, _) => {
+                // Stay within the range of nanosecond timestamps, so that every value can be
+                // converted to any time unit.
+                let per_ns = match tu {
+                    TimeUnit::Nanoseconds => 1,
+                    TimeUnit::Microseconds => 1_000,
+                    TimeUnit::Milliseconds => 1_000_000,
+                };
+                (i64::MIN / per_ns..=i64::MAX / per_ns)
+                    .prop_map(AnyValue::Int64)
+                    .boxed()
+            },
+            Duration(_) => any::<i64>().prop_map(AnyValue::Int64).boxed(),
+            Time => (0..NANOSECONDS_IN_DAY).prop_map(AnyValue::Int64).boxed(),
+            Null => Just(AnyValue::Null).boxed(),
+            // Draw from a small alphabet, so that categories repeat.
+            #[cfg(feature = "dtype-categorical")]
+            Categorical(_, _) => vec(select(vec!['a', 'b', 'c']), self.string_length.clone())
+                .prop_map(|chars| AnyValue::StringOwned(chars.into_iter().collect()))
+                .boxed(),
+            #[cfg(feature = "dtype-categorical")]
+            Enum(Some(rev_map), _) => {
+                let categories = rev_map
+                    .get_categories()
+                    .values_iter()
+                    .map(|s| AnyValue::StringOwned(s.into()))
+                    .collect::<Vec<_>>();
+                select(categories).boxed()
+            },
+            List(inner) => {
+                let generated = generated_dtype(inner);
+                vec(
+                    nullable(self.values(inner, null_density), null_density),
+                    self.list_length.clone(),
+                )
+                .prop_map(move |values| {
+                    AnyValue::List(
+                        Series::from_any_values_and_dtype("", &values, &generated, true).unwrap(),
+                    )
+                })
+                .boxed()
+            },
+            #[cfg(feature = "dtype-array")]
+            Array(inner, width) => {
+                let generated = generated_dtype(inner);
+                let width = *width;
+                vec(
+                    nullable(self.values(inner, null_density), null_density),
+                    width,
+                )
+                .prop_map(move |values| {
+                    let s =
+                        Series::from_any_values_and_dtype("", &values, &generated, true).unwrap();
+                    AnyValue::Array(s, width)
+                })
+                .boxed()
+            },
+            #[cfg(feature = "dtype-struct")]
+            Struct(fields) => {
+                let generated = fields
+                    .iter()
+                    .map(|f| Field::new(f.name(), generated_dtype(f.data_type())))
+                    .collect::<Vec<_>>();
+                fields
+                    .iter()
+                    .map(|f| nullable(self.values(f.data_type(), null_density), null_density))
+                    .collect::<Vec<_>>()
+                    .prop_map(move |values| {
+                        AnyValue::StructOwned(Box::new((values, generated.clone())))
+                    })
+                    .boxed()
+            },
+            dt => panic!("cannot generate values of dtype {dt}"),
+        }
+    }
+}
+
+/// The data type the values are generated as, before they are cast to `dtype`.
+fn generated_dtype(dtype: &DataType) -> DataType {
+    use DataType::*;
+    match dtype {
+        Date | Datetime(_, _) | Duration(_) | Time => dtype.to_physical(),
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_, _) | Enum(_, _) => String,
+        List(inner) => List(Box::new(generated_dtype(inner))),
+        #[cfg(feature = "dtype-array")]
+        Array(inner, width) => Array(Box::new(generated_dtype(inner)), *width),
+        #[cfg(feature = "dtype-struct")]
+        Struct(fields) => Struct(
+            fields
+                .iter()
+                .map(|f| Field::new(f.name(), generated_dtype(f.data_type())))
+                .collect(),
+        ),
+        dt => dt.clone(),
+    }
+}
+
+fn nullable(
+    values: BoxedStrategy<AnyValue<'static>>,
+    null_density: f64,
+) -> BoxedStrategy<AnyValue<'static>> {
+    if null_density == 0.0 {
+        values
+    } else if null_density == 1.0 {
+        // `weighted` only takes probabilities strictly between 0.0 and 1.0.
+        Just(AnyValue::Null).boxed()
+    } else {
+        proptest::option::weighted(1.0 - null_density, values)
+            .prop_map(|value| value.unwrap_or(AnyValue::Null))
+            .boxed()
+    }
+}
+
+/// A [`Strategy`] of data types, with lists (and arrays and structs, if enabled) nested up to
+/// `max_depth` levels deep.
+pub fn arbitrary_dtype(max_depth: u32) -> BoxedStrategy<DataType> {
+    let mut leaves = vec![
+        DataType::Boolean,
+        DataType::UInt32,
+        DataType::UInt64,
+        DataType::Int32,
+        DataType::Int64,
+        DataType::Float32,
+        DataType::Float64,
+        DataType::String,
+        DataType::Binary,
+    ];
+    #[cfg(feature = "dtype-u8")]
+    leaves.push(DataType::UInt8);
+    #[cfg(feature = "dtype-u16")]
+    leaves.push(DataType::UInt16);
+    #[cfg(feature = "dtype-i8")]
+    leaves.push(DataType::Int8);
+    #[cfg(feature = "dtype-i16")]
+    leaves.push(DataType::Int16);
+    #[cfg(feature = "dtype-date")]
+    leaves.push(DataType::Date);
+    #[cfg(feature = "dtype-datetime")]
+    leaves.push(DataType::Datetime(TimeUnit::Microseconds, None));
+    #[cfg(feature = "dtype-duration")]
+    leaves.push(DataType::Duration(TimeUnit::Milliseconds));
+    #[cfg(feature = "dtype-time")]
+    leaves.push(DataType::Time);
+    #[cfg(feature = "dtype-categorical")]
+    leaves.push(DataType::Categorical(None, Default::default()));
+
+    select(leaves)
+        .prop_recursive(max_depth, 16, 4, |inner| {
+            #[allow(unused_mut)]
+            let mut nested = vec![inner
+                .clone()
+                .prop_map(|dt| DataType::List(Box::new(dt)))
+                .boxed()];
+            #[cfg(feature = "dtype-array")]
+            nested.push(
+                (inner.clone(), 1..4usize)
+                    .prop_map(|(dt, width)| DataType::Array(Box::new(dt), width))
+                    .boxed(),
+            );
+            #[cfg(feature = "dtype-struct")]
+            nested.push(
+                vec(inner, 1..4)
+                    .prop_map(|dtypes| {
+                        let fields = dtypes
+                            .into_iter()
+                            .enumerate()
+                            .map(|(i, dt)| Field::new(&format!("field_{i}"), dt))
+                            .collect();
+                        DataType::Struct(fields)
+                    })
+                    .boxed(),
+            );
+            Union::new(nested)
+        })
+        .boxed()
+}
+
+/// A [`Strategy`] of frames with up to `max_columns` columns of [`arbitrary_dtype`]s, each with
+/// a random null density.
+pub fn arbitrary_frame(max_columns: usize, max_depth: u32) -> BoxedStrategy<DataFrame> {
+    vec((arbitrary_dtype(max_depth), 0.0..0.5), 0..=max_columns)
+        .prop_flat_map(|columns| {
+            columns
+                .into_iter()
+                .enumerate()
+                .fold(FrameSpec::new(), |spec, (i, (dtype, null_density))| {
+                    spec.with_column(
+                        &format!("column_{i}"),
+                        ColumnSpec::new(dtype).with_null_density(null_density),
+                    )
+                })
+                .strategy()
+        })
+        .boxed()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_frame_spec(df in FrameSpec::new()
+            .with_height(1..20)
+            .with_string_length(2..4)
+            .with_column("a", ColumnSpec::new(DataType::Int32).with_sorted(true))
+            .with_column("b", ColumnSpec::new(DataType::String).with_null_density(1.0))
+            .with_column(
+                "c",
+                ColumnSpec::new(DataType::List(Box::new(DataType::Float64))).with_null_density(0.2),
+            )
+            .strategy()
+        ) {
+            prop_assert!((1..20).contains(&df.height()));
+            prop_assert_eq!(
+                df.dtypes(),
+                &[
+                    DataType::Int32,
+                    DataType::String,
+                    DataType::List(Box::new(DataType::Float64)),
+                ]
+            );
+            let a = df.column("a").unwrap();
+            prop_assert_eq!(a.null_count(), 0);
+            prop_assert!(a.equals(&a.sort(SortOptions::default()).unwrap()));
+            prop_assert_eq!(df.column("b").unwrap().null_count(), df.height());
+        }
+
+        #[test]
+        fn test_arbitrary_frame(df in arbitrary_frame(4, 2)) {
+            prop_assert!(df.width() <= 4);
+            for s in df.get_columns() {
+                prop_assert!(s.dtype().is_known());
+            }
+        }
+    }
+}
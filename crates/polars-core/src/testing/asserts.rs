@@ -0,0 +1,678 @@
+//! Assertions on [`Series`] and [`DataFrame`]s that explain what differs on failure.
+use std::fmt::{Display, Write};
+
+use crate::prelude::*;
+use crate::utils::try_get_supertype;
+
+/// Number of differing rows shown in an assertion message.
+const MAX_DIFF_ROWS: usize = 10;
+
+/// Options of [`assert_series_equal`] and [`assert_series_not_equal`].
+#[derive(Clone, Debug)]
+pub struct SeriesEqualOptions {
+    /// Require the data types to match.
+    pub check_dtypes: bool,
+    /// Require the names to match.
+    pub check_names: bool,
+    /// Require the elements to appear in the same order.
+    pub check_order: bool,
+    /// Require float values to match exactly, instead of within `rtol` and `atol`.
+    pub check_exact: bool,
+    /// Relative tolerance of inexact float comparisons, as a fraction of the right values.
+    pub rtol: f64,
+    /// Absolute tolerance of inexact float comparisons.
+    pub atol: f64,
+    /// Compare categoricals by their string values, e.g. if they don't share a string cache.
+    pub categorical_as_str: bool,
+}
+
+impl Default for SeriesEqualOptions {
+    fn default() -> Self {
+        Self {
+            check_dtypes: true,
+            check_names: true,
+            check_order: true,
+            check_exact: false,
+            rtol: 1e-5,
+            atol: 1e-8,
+            categorical_as_str: false,
+        }
+    }
+}
+
+impl SeriesEqualOptions {
+    pub fn with_check_dtypes(mut self, enabled: bool) -> Self {
+        self.check_dtypes = enabled;
+        self
+    }
+
+    pub fn with_check_names(mut self, enabled: bool) -> Self {
+        self.check_names = enabled;
+        self
+    }
+
+    pub fn with_check_order(mut self, enabled: bool) -> Self {
+        self.check_order = enabled;
+        self
+    }
+
+    pub fn with_check_exact(mut self, enabled: bool) -> Self {
+        self.check_exact = enabled;
+        self
+    }
+
+    pub fn with_rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn with_atol(mut self, atol: f64) -> Self {
+        self.atol = atol;
+        self
+    }
+
+    pub fn with_categorical_as_str(mut self, enabled: bool) -> Self {
+        self.categorical_as_str = enabled;
+        self
+    }
+}
+
+/// Options of [`assert_frame_equal`] and [`assert_frame_not_equal`].
+#[derive(Clone, Debug)]
+pub struct FrameEqualOptions {
+    /// Require the rows to appear in the same order.
+    ///
+    /// If disabled, the rows are sorted by all columns before comparing them.
+    pub check_row_order: bool,
+    /// Require the columns to appear in the same order.
+    pub check_column_order: bool,
+    /// Require the data types of the columns to match.
+    pub check_dtypes: bool,
+    /// Require float values to match exactly, instead of within `rtol` and `atol`.
+    pub check_exact: bool,
+    /// Relative tolerance of inexact float comparisons, as a fraction of the right values.
+    pub rtol: f64,
+    /// Absolute tolerance of inexact float comparisons.
+    pub atol: f64,
+    /// Compare categoricals by their string values, e.g. if they don't share a string cache.
+    pub categorical_as_str: bool,
+    /// Match the rows by these key columns instead of by their position.
+    ///
+    /// Rows with equal keys are compared in their original order.
+    pub keys: Option<Vec<SmartString>>,
+}
+
+impl Default for FrameEqualOptions {
+    fn default() -> Self {
+        Self {
+            check_row_order: true,
+            check_column_order: true,
+            check_dtypes: true,
+            check_exact: false,
+            rtol: 1e-5,
+            atol: 1e-8,
+            categorical_as_str: false,
+            keys: None,
+        }
+    }
+}
+
+impl FrameEqualOptions {
+    pub fn with_check_row_order(mut self, enabled: bool) -> Self {
+        self.check_row_order = enabled;
+        self
+    }
+
+    pub fn with_check_column_order(mut self, enabled: bool) -> Self {
+        self.check_column_order = enabled;
+        self
+    }
+
+    pub fn with_check_dtypes(mut self, enabled: bool) -> Self {
+        self.check_dtypes = enabled;
+        self
+    }
+
+    pub fn with_check_exact(mut self, enabled: bool) -> Self {
+        self.check_exact = enabled;
+        self
+    }
+
+    pub fn with_rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn with_atol(mut self, atol: f64) -> Self {
+        self.atol = atol;
+        self
+    }
+
+    pub fn with_categorical_as_str(mut self, enabled: bool) -> Self {
+        self.categorical_as_str = enabled;
+        self
+    }
+
+    pub fn with_keys(mut self, keys: impl IntoVec<SmartString>) -> Self {
+        self.keys = Some(keys.into_vec());
+        self
+    }
+
+    fn series_options(&self) -> SeriesEqualOptions {
+        SeriesEqualOptions {
+            check_dtypes: self.check_dtypes,
+            check_names: true,
+            check_order: true,
+            check_exact: self.check_exact,
+            rtol: self.rtol,
+            atol: self.atol,
+            categorical_as_str: self.categorical_as_str,
+        }
+    }
+}
+
+/// Assert that two [`Series`] are equal.
+///
+/// # Panics
+/// If the [`Series`] differ, with a message listing the differing values.
+///
+/// # Example
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// use polars_core::testing::{assert_series_equal, SeriesEqualOptions};
+///
+/// let left = Series::new("a", &[1.0, 2.0, 3.0]);
+/// let right = Series::new("a", &[1.0, 2.0, 3.0 + 1e-9]);
+/// assert_series_equal(&left, &right, SeriesEqualOptions::default());
+/// ```
+#[track_caller]
+pub fn assert_series_equal(left: &Series, right: &Series, options: SeriesEqualOptions) {
+    if let Err(msg) = compare_series(left, right, &options) {
+        panic!("{msg}")
+    }
+}
+
+/// Assert that two [`Series`] are not equal.
+///
+/// # Panics
+/// If the [`Series`] are equal according to [`assert_series_equal`].
+#[track_caller]
+pub fn assert_series_not_equal(left: &Series, right: &Series, options: SeriesEqualOptions) {
+    if compare_series(left, right, &options).is_ok() {
+        panic!("Series are equal (but are expected not to be)")
+    }
+}
+
+/// Assert that two [`DataFrame`]s are equal.
+///
+/// # Panics
+/// If the [`DataFrame`]s differ, with a message listing the differing rows.
+///
+/// # Example
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// use polars_core::testing::{assert_frame_equal, FrameEqualOptions};
+///
+/// let left = df!("id" => [1, 2], "value" => ["a", "b"])?;
+/// let right = df!("id" => [2, 1], "value" => ["b", "a"])?;
+/// assert_frame_equal(&left, &right, FrameEqualOptions::default().with_keys(["id"]));
+/// # Ok::<(), PolarsError>(())
+/// ```
+#[track_caller]
+pub fn assert_frame_equal(left: &DataFrame, right: &DataFrame, options: FrameEqualOptions) {
+    if let Err(msg) = compare_frames(left, right, &options) {
+        panic!("{msg}")
+    }
+}
+
+/// Assert that two [`DataFrame`]s are not equal.
+///
+/// # Panics
+/// If the [`DataFrame`]s are equal according to [`assert_frame_equal`].
+#[track_caller]
+pub fn assert_frame_not_equal(left: &DataFrame, right: &DataFrame, options: FrameEqualOptions) {
+    if compare_frames(left, right, &options).is_ok() {
+        panic!("DataFrames are equal (but are expected not to be)")
+    }
+}
+
+fn different(objects: &str, detail: &str, left: impl Display, right: impl Display) -> String {
+    format!("{objects} are different ({detail})\n[left]:  {left}\n[right]: {right}")
+}
+
+/// Values that differ between two (compared) [`Series`].
+struct Mismatch {
+    detail: String,
+    left: Series,
+    right: Series,
+    rows: Vec<usize>,
+}
+
+impl Mismatch {
+    fn new(detail: impl Into<String>, left: &Series, right: &Series, rows: Vec<usize>) -> Self {
+        Self {
+            detail: detail.into(),
+            left: left.clone(),
+            right: right.clone(),
+            rows,
+        }
+    }
+
+    fn render(&self, objects: &str, row_label: impl Fn(usize) -> String) -> String {
+        if self.rows.is_empty() {
+            return different(objects, &self.detail, self.left.dtype(), self.right.dtype());
+        }
+        let mut msg = format!(
+            "{objects} are different ({})\n{} of {} values differ:",
+            self.detail,
+            self.rows.len(),
+            self.left.len()
+        );
+        for &i in self.rows.iter().take(MAX_DIFF_ROWS) {
+            let left = self.left.get(i).unwrap();
+            let right = self.right.get(i).unwrap();
+            write!(msg, "\n  {}: left = {left}, right = {right}", row_label(i)).unwrap();
+        }
+        if self.rows.len() > MAX_DIFF_ROWS {
+            write!(msg, "\n  ... and {} more", self.rows.len() - MAX_DIFF_ROWS).unwrap();
+        }
+        msg
+    }
+}
+
+fn compare_series(
+    left: &Series,
+    right: &Series,
+    options: &SeriesEqualOptions,
+) -> Result<(), String> {
+    if left.len() != right.len() {
+        return Err(different(
+            "Series",
+            "length mismatch",
+            left.len(),
+            right.len(),
+        ));
+    }
+    if options.check_names && left.name() != right.name() {
+        return Err(different(
+            "Series",
+            "name mismatch",
+            left.name(),
+            right.name(),
+        ));
+    }
+    if options.check_dtypes && left.dtype() != right.dtype() {
+        return Err(different(
+            "Series",
+            "dtype mismatch",
+            left.dtype(),
+            right.dtype(),
+        ));
+    }
+    compare_values(left, right, options)
+        .map_err(|mismatch| mismatch.render("Series", |i| format!("[{i}]")))
+}
+
+fn compare_frames(
+    left: &DataFrame,
+    right: &DataFrame,
+    options: &FrameEqualOptions,
+) -> Result<(), String> {
+    let left_schema = left.schema();
+    let right_schema = right.schema();
+    if let Some(name) = left_schema
+        .iter_names()
+        .find(|name| !right_schema.contains(name))
+    {
+        return Err(format!(
+            "DataFrames are different (column '{name}' in left, but not in right)"
+        ));
+    }
+    if let Some(name) = right_schema
+        .iter_names()
+        .find(|name| !left_schema.contains(name))
+    {
+        return Err(format!(
+            "DataFrames are different (column '{name}' in right, but not in left)"
+        ));
+    }
+    if options.check_column_order && left.get_column_names() != right.get_column_names() {
+        return Err(different(
+            "DataFrames",
+            "columns are not in the same order",
+            format!("{:?}", left.get_column_names()),
+            format!("{:?}", right.get_column_names()),
+        ));
+    }
+    if options.check_dtypes {
+        if let Some((name, dtype)) = left_schema
+            .iter()
+            .find(|(name, dtype)| right_schema.get(name) != Some(*dtype))
+        {
+            return Err(different(
+                "DataFrames",
+                &format!("dtype mismatch for column '{name}'"),
+                dtype,
+                right_schema.get(name).unwrap(),
+            ));
+        }
+    }
+    if left.height() != right.height() {
+        return Err(different(
+            "DataFrames",
+            "number of rows does not match",
+            left.height(),
+            right.height(),
+        ));
+    }
+
+    let right = right
+        .select(left.get_column_names())
+        .map_err(|e| e.to_string())?;
+    let sort_options = SortMultipleOptions::default().with_maintain_order(true);
+    let (left, right) = match &options.keys {
+        Some(keys) => {
+            let sorted = left
+                .sort(keys.clone(), sort_options.clone())
+                .and_then(|left| Ok((left, right.sort(keys.clone(), sort_options)?)));
+            sorted.map_err(|e| format!("cannot match the rows by their keys: {e}"))?
+        },
+        None if !options.check_row_order => {
+            let by = left.get_column_names_owned();
+            let sorted = left
+                .sort(by.clone(), sort_options.clone())
+                .and_then(|left| Ok((left, right.sort(by, sort_options)?)));
+            sorted.map_err(|e| {
+                format!("cannot compare the rows in any order, as they are unsortable: {e}")
+            })?
+        },
+        None => (left.clone(), right),
+    };
+
+    let series_options = options.series_options();
+    for (l, r) in left.get_columns().iter().zip(right.get_columns()) {
+        if let Err(mismatch) = compare_values(l, r, &series_options) {
+            let objects = format!("DataFrames (column '{}')", l.name());
+            let msg = match &options.keys {
+                Some(keys) => mismatch.render(&objects, |i| key_label(&left, keys, i)),
+                None => mismatch.render(&objects, |i| format!("row {i}")),
+            };
+            return Err(msg);
+        }
+    }
+    Ok(())
+}
+
+fn key_label(df: &DataFrame, keys: &[SmartString], i: usize) -> String {
+    let values = keys
+        .iter()
+        .map(|key| format!("{key}={}", df.column(key).unwrap().get(i).unwrap()))
+        .collect::<Vec<_>>();
+    format!("[{}]", values.join(", "))
+}
+
+fn compare_values(
+    left: &Series,
+    right: &Series,
+    options: &SeriesEqualOptions,
+) -> Result<(), Mismatch> {
+    let (mut left, mut right) = if options.categorical_as_str {
+        (categorical_to_string(left), categorical_to_string(right))
+    } else {
+        (left.clone(), right.clone())
+    };
+
+    if !options.check_order {
+        let sorted = left
+            .sort(Default::default())
+            .and_then(|left| Ok((left, right.sort(Default::default())?)));
+        match sorted {
+            Ok(sorted) => (left, right) = sorted,
+            Err(_) => {
+                return Err(Mismatch::new(
+                    "cannot compare the values in any order, as they are unsortable",
+                    &left,
+                    &right,
+                    vec![],
+                ))
+            },
+        }
+    }
+
+    if left.dtype() != right.dtype() {
+        let cast = try_get_supertype(left.dtype(), right.dtype())
+            .and_then(|dtype| Ok((left.cast(&dtype)?, right.cast(&dtype)?)));
+        match cast {
+            Ok(cast) => (left, right) = cast,
+            Err(_) => {
+                return Err(Mismatch::new(
+                    "incompatible data types",
+                    &left,
+                    &right,
+                    vec![],
+                ))
+            },
+        }
+    }
+
+    if !options.check_exact && left.dtype().is_nested() && contains_float(left.dtype()) {
+        return compare_nested_values(&left, &right, options);
+    }
+
+    let Ok(equal) = left.equal_missing(&right) else {
+        return Err(Mismatch::new(
+            "incompatible data types",
+            &left,
+            &right,
+            vec![],
+        ));
+    };
+    let unequal = equal
+        .iter()
+        .enumerate()
+        .filter_map(|(i, eq)| (eq != Some(true)).then_some(i))
+        .collect::<Vec<_>>();
+    if unequal.is_empty() {
+        return Ok(());
+    }
+    if options.check_exact || !left.dtype().is_float() {
+        return Err(Mismatch::new(
+            "exact value mismatch",
+            &left,
+            &right,
+            unequal,
+        ));
+    }
+    compare_float_values(&left, &right, unequal, options)
+}
+
+fn compare_float_values(
+    left: &Series,
+    right: &Series,
+    unequal: Vec<usize>,
+    options: &SeriesEqualOptions,
+) -> Result<(), Mismatch> {
+    let l = left.cast(&DataType::Float64).unwrap();
+    let r = right.cast(&DataType::Float64).unwrap();
+    let (l, r) = (l.f64().unwrap(), r.f64().unwrap());
+
+    let mut null_mismatch = vec![];
+    let mut nan_mismatch = vec![];
+    let mut value_mismatch = vec![];
+    for i in unequal {
+        match (l.get(i), r.get(i)) {
+            (None, None) => {},
+            (None, _) | (_, None) => null_mismatch.push(i),
+            (Some(a), Some(b)) if a.is_nan() || b.is_nan() => {
+                if a.is_nan() != b.is_nan() {
+                    nan_mismatch.push(i)
+                }
+            },
+            (Some(a), Some(b)) => {
+                let within_tolerance = (a - b).abs() <= options.atol + options.rtol * b.abs();
+                if !within_tolerance {
+                    value_mismatch.push(i)
+                }
+            },
+        }
+    }
+
+    for (detail, rows) in [
+        ("null value mismatch", null_mismatch),
+        ("nan value mismatch", nan_mismatch),
+        ("value mismatch", value_mismatch),
+    ] {
+        if !rows.is_empty() {
+            return Err(Mismatch::new(detail, left, right, rows));
+        }
+    }
+    Ok(())
+}
+
+/// Compare nested values containing floats element by element, so that the float tolerance
+/// applies to them.
+fn compare_nested_values(
+    left: &Series,
+    right: &Series,
+    options: &SeriesEqualOptions,
+) -> Result<(), Mismatch> {
+    let options = options.clone().with_check_order(true);
+    let mut unequal = vec![];
+    match left.dtype() {
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(_) => {
+            let (l, r) = (left.struct_().unwrap(), right.struct_().unwrap());
+            for (l, r) in l.fields().iter().zip(r.fields()) {
+                if let Err(mismatch) = compare_values(l, r, &options) {
+                    unequal.extend(mismatch.rows);
+                }
+            }
+            unequal.sort_unstable();
+            unequal.dedup();
+        },
+        dtype => {
+            let list_dtype = DataType::List(Box::new(dtype.inner_dtype().unwrap().clone()));
+            let l = left.cast(&list_dtype).unwrap();
+            let r = right.cast(&list_dtype).unwrap();
+            let (l, r) = (l.list().unwrap(), r.list().unwrap());
+            for (i, (l, r)) in l.amortized_iter().zip(r.amortized_iter()).enumerate() {
+                let equal = match (l, r) {
+                    (None, None) => true,
+                    (Some(l), Some(r)) => compare_values(l.as_ref(), r.as_ref(), &options).is_ok(),
+                    _ => false,
+                };
+                if !equal {
+                    unequal.push(i);
+                }
+            }
+        },
+    }
+
+    if unequal.is_empty() {
+        Ok(())
+    } else {
+        Err(Mismatch::new("nested value mismatch", left, right, unequal))
+    }
+}
+
+fn contains_float(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::List(inner) => contains_float(inner),
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(inner, _) => contains_float(inner),
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => fields.iter().any(|f| contains_float(f.data_type())),
+        dtype => dtype.is_float(),
+    }
+}
+
+fn categorical_to_string(s: &Series) -> Series {
+    let dtype = categorical_dtype_to_string(s.dtype());
+    if &dtype == s.dtype() {
+        s.clone()
+    } else {
+        s.cast(&dtype).unwrap()
+    }
+}
+
+fn categorical_dtype_to_string(dtype: &DataType) -> DataType {
+    match dtype {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => DataType::String,
+        DataType::List(inner) => DataType::List(Box::new(categorical_dtype_to_string(inner))),
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(inner, width) => {
+            DataType::Array(Box::new(categorical_dtype_to_string(inner)), *width)
+        },
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|f| Field::new(f.name(), categorical_dtype_to_string(f.data_type())))
+                .collect(),
+        ),
+        dtype => dtype.clone(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn panic_message(f: impl FnOnce()) -> String {
+        let err = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_err();
+        err.downcast_ref::<String>().unwrap().clone()
+    }
+
+    #[test]
+    fn test_assert_series_equal() {
+        let left = Series::new("a", &[Some(1.0), None, Some(f64::NAN)]);
+        let right = Series::new("a", &[Some(1.0 + 1e-9), None, Some(f64::NAN)]);
+        assert_series_equal(&left, &right, SeriesEqualOptions::default());
+        assert_series_not_equal(
+            &left,
+            &right,
+            SeriesEqualOptions::default().with_check_exact(true),
+        );
+
+        let right = Series::new("a", &[2.0, 3.0, f64::NAN]);
+        let msg =
+            panic_message(|| assert_series_equal(&left, &right, SeriesEqualOptions::default()));
+        assert_eq!(
+            msg,
+            "Series are different (null value mismatch)\n1 of 3 values differ:\n  [1]: left = null, right = 3.0"
+        );
+
+        let right = Series::new("a", &[1i32, 2, 3]);
+        assert_series_not_equal(&left, &right, SeriesEqualOptions::default());
+        let left = Series::new("a", &[3i64, 1, 2]);
+        assert_series_equal(
+            &left,
+            &right,
+            SeriesEqualOptions::default()
+                .with_check_dtypes(false)
+                .with_check_order(false),
+        );
+    }
+
+    #[test]
+    fn test_assert_frame_equal() {
+        let left = df!("id" => [1, 2, 3], "value" => [1.0, 2.0, 3.0]).unwrap();
+        let right = df!("value" => [3.0, 1.0, 2.5], "id" => [3, 1, 2]).unwrap();
+        let options = FrameEqualOptions::default()
+            .with_check_column_order(false)
+            .with_keys(["id"]);
+
+        let msg = panic_message(|| assert_frame_equal(&left, &right, options.clone()));
+        assert_eq!(
+            msg,
+            "DataFrames (column 'value') are different (value mismatch)\n1 of 3 values differ:\n  [id=2]: left = 2.0, right = 2.5"
+        );
+        assert_frame_equal(&left, &right, options.with_rtol(0.5));
+
+        let msg = panic_message(|| assert_frame_equal(&left, &right, FrameEqualOptions::default()));
+        assert!(msg.contains("columns are not in the same order"));
+    }
+}
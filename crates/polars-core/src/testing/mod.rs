@@ -1,6 +1,12 @@
 //! Testing utilities.
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+mod asserts;
+
 use std::ops::Deref;
 
+pub use asserts::*;
+
 use crate::prelude::*;
 
 impl Series {
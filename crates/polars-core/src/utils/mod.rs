@@ -698,11 +698,62 @@ pub fn get_time_units(tu_l: &TimeUnit, tu_r: &TimeUnit) -> TimeUnit {
     }
 }
 
+/// Give the top-level categorical columns of `dfs` a single shared dictionary.
+///
+/// Without this, vertically concatenating frames with local categoricals (e.g. from many
+/// scanned files) re-encodes the accumulated column for every appended frame.
+#[cfg(feature = "dtype-categorical")]
+pub fn unify_categorical_dictionaries(dfs: &mut [DataFrame]) {
+    let Some(first) = dfs.first() else {
+        return;
+    };
+    if dfs.len() < 2 {
+        return;
+    }
+    let cat_columns = first
+        .get_columns()
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| matches!(s.dtype(), DataType::Categorical(_, _)))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    for i in cat_columns {
+        let Some(mut cas) = dfs
+            .iter()
+            .map(|df| {
+                df.get_columns()
+                    .get(i)
+                    .and_then(|s| s.categorical().ok().cloned())
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+        unify_categorical_rev_maps(&mut cas);
+        for (df, ca) in dfs.iter_mut().zip(cas) {
+            // SAFETY: only the encoding changes, the length remains the same.
+            unsafe { df.get_columns_mut()[i] = ca.into_series() };
+        }
+    }
+}
+
+fn prepare_vertical<I>(dfs: I) -> Vec<DataFrame>
+where
+    I: IntoIterator<Item = DataFrame>,
+{
+    #[allow(unused_mut)]
+    let mut dfs = dfs.into_iter().collect::<Vec<_>>();
+    #[cfg(feature = "dtype-categorical")]
+    unify_categorical_dictionaries(&mut dfs);
+    dfs
+}
+
 pub fn accumulate_dataframes_vertical_unchecked_optional<I>(dfs: I) -> Option<DataFrame>
 where
     I: IntoIterator<Item = DataFrame>,
 {
-    let mut iter = dfs.into_iter();
+    let mut iter = prepare_vertical(dfs).into_iter();
     let additional = iter.size_hint().0;
     let mut acc_df = iter.next()?;
     acc_df.reserve_chunks(additional);
@@ -719,7 +770,7 @@ pub fn accumulate_dataframes_vertical_unchecked<I>(dfs: I) -> DataFrame
 where
     I: IntoIterator<Item = DataFrame>,
 {
-    let mut iter = dfs.into_iter();
+    let mut iter = prepare_vertical(dfs).into_iter();
     let additional = iter.size_hint().0;
     let mut acc_df = iter.next().unwrap();
     acc_df.reserve_chunks(additional);
@@ -735,7 +786,7 @@ pub fn accumulate_dataframes_vertical<I>(dfs: I) -> PolarsResult<DataFrame>
 where
     I: IntoIterator<Item = DataFrame>,
 {
-    let mut iter = dfs.into_iter();
+    let mut iter = prepare_vertical(dfs).into_iter();
     let additional = iter.size_hint().0;
     let mut acc_df = iter.next().unwrap();
     acc_df.reserve_chunks(additional);
@@ -750,14 +801,7 @@ pub fn concat_df<'a, I>(dfs: I) -> PolarsResult<DataFrame>
 where
     I: IntoIterator<Item = &'a DataFrame>,
 {
-    let mut iter = dfs.into_iter();
-    let additional = iter.size_hint().0;
-    let mut acc_df = iter.next().unwrap().clone();
-    acc_df.reserve_chunks(additional);
-    for df in iter {
-        acc_df.vstack_mut(df)?;
-    }
-    Ok(acc_df)
+    accumulate_dataframes_vertical(dfs.into_iter().cloned())
 }
 
 /// Concat the DataFrames to a single DataFrame.
@@ -765,14 +809,7 @@ pub fn concat_df_unchecked<'a, I>(dfs: I) -> DataFrame
 where
     I: IntoIterator<Item = &'a DataFrame>,
 {
-    let mut iter = dfs.into_iter();
-    let additional = iter.size_hint().0;
-    let mut acc_df = iter.next().unwrap().clone();
-    acc_df.reserve_chunks(additional);
-    for df in iter {
-        acc_df.vstack_mut_unchecked(df);
-    }
-    acc_df
+    accumulate_dataframes_vertical_unchecked(dfs.into_iter().cloned())
 }
 
 pub fn accumulate_dataframes_horizontal(dfs: Vec<DataFrame>) -> PolarsResult<DataFrame> {
@@ -187,6 +187,16 @@ pub fn get_supertype_with_options(
             (Boolean, Float32) => Some(Float32),
             (Boolean, Float64) => Some(Float64),
 
+            #[cfg(feature = "dtype-f16")]
+            (Float16, Boolean) => Some(Float16),
+            #[cfg(feature = "dtype-f16")]
+            (Float16, Float32) => Some(Float32),
+            #[cfg(feature = "dtype-f16")]
+            (Float16, Float64) => Some(Float64),
+            // f16 can't hold all integers of any width exactly, follow the f32 rules
+            #[cfg(feature = "dtype-f16")]
+            (Float16, dt) if dt.is_integer() => get_supertype(&Float32, dt),
+
             #[cfg(feature = "dtype-duration")]
             (Duration(_), UInt32) => Some(Int64),
             #[cfg(feature = "dtype-duration")]
@@ -278,6 +288,8 @@ pub fn get_supertype_with_options(
                 match kind {
                     // numeric vs float|str -> always float|str
                     UnknownKind::Float | UnknownKind::Int(_) if dt.is_float() | dt.is_string() => Some(dt.clone()),
+                    #[cfg(feature = "dtype-f16")]
+                    UnknownKind::Float | UnknownKind::Int(_) if dt == &Float16 => Some(Float16),
                     UnknownKind::Float if dt.is_integer() => Some(Unknown(UnknownKind::Float)),
                     // Materialize float
                     UnknownKind::Float if dt.is_float() => Some(dt.clone()),
@@ -0,0 +1,120 @@
+//! Derive macros of the `polars` crate, use them through its `derive` feature.
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, LitStr, Result};
+
+/// Derive `IntoDataFrame` for a struct with named fields, every field becomes a column.
+///
+/// The type of every field must implement `IntoColumnValue`:
+/// - `bool`, integers, floats, `String` and `Vec<u8>`.
+/// - `Option<T>`, `None` becomes a null.
+/// - Structs that derive `IntoDataFrame` themselves, they become a `Struct` column.
+/// - `chrono::NaiveDate`, `NaiveDateTime`, `NaiveTime` and `Duration`.
+/// - `uuid::Uuid` with the `uuid` feature, as a string.
+///
+/// A column is named after its field, unless it is renamed with `#[polars(rename = "name")]`.
+///
+/// ```rust,ignore
+/// use polars::prelude::*;
+///
+/// #[derive(IntoDataFrame)]
+/// struct Location {
+///     lat: f64,
+///     lon: f64,
+/// }
+///
+/// #[derive(IntoDataFrame)]
+/// struct Visit {
+///     #[polars(rename = "visitor_id")]
+///     visitor: u64,
+///     at: chrono::NaiveDateTime,
+///     location: Option<Location>,
+/// }
+///
+/// let df = Visit::into_df(visits)?;
+/// ```
+#[proc_macro_derive(IntoDataFrame, attributes(polars))]
+pub fn derive_into_dataframe(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    into_dataframe(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn into_dataframe(mut input: DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(unsupported(Span::call_site())),
+        },
+        _ => return Err(unsupported(Span::call_site())),
+    };
+
+    let mut idents = vec![];
+    let mut names = vec![];
+    let mut types = vec![];
+    for field in fields {
+        let ident = field.ident.clone().unwrap();
+        names.push(column_name(field)?.unwrap_or_else(|| ident.to_string()));
+        idents.push(ident);
+        types.push(field.ty.clone());
+    }
+
+    // Generic fields have to be column values too.
+    let where_clause = input.generics.make_where_clause();
+    for ty in &types {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: ::polars::prelude::IntoColumnValue));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::polars::prelude::IntoDataFrame for #name #ty_generics #where_clause {
+            fn fields() -> ::std::vec::Vec<::polars::prelude::Field> {
+                ::std::vec![#(
+                    ::polars::prelude::Field::new(
+                        #names,
+                        <#types as ::polars::prelude::IntoColumnValue>::dtype(),
+                    )
+                ),*]
+            }
+
+            fn into_values(self) -> ::std::vec::Vec<::polars::prelude::AnyValue<'static>> {
+                ::std::vec![#(
+                    ::polars::prelude::IntoColumnValue::into_any_value(self.#idents)
+                ),*]
+            }
+        }
+    })
+}
+
+fn unsupported(span: Span) -> Error {
+    Error::new(
+        span,
+        "`IntoDataFrame` can only be derived for structs with named fields",
+    )
+}
+
+/// The name set with `#[polars(rename = "name")]`.
+fn column_name(field: &syn::Field) -> Result<Option<String>> {
+    let mut name = None;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("polars"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                let value: LitStr = meta.value()?.parse()?;
+                name = Some(value.value());
+                Ok(())
+            } else {
+                Err(meta.error("unknown `polars` attribute, expected `rename`"))
+            }
+        })?;
+    }
+    Ok(name)
+}
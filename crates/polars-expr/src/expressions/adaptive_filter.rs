@@ -0,0 +1,153 @@
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+
+use polars_core::prelude::*;
+
+use super::*;
+use crate::expressions::{AggregationContext, PartitionedAggregation, PhysicalExpr};
+
+/// The number of rows on which all conjuncts are evaluated to measure them.
+const SAMPLE_ROWS: usize = 50_000;
+
+/// The measurements of the conjuncts on the sampled rows.
+struct Sample {
+    rows: usize,
+    /// Per conjunct, the number of rows for which it is true.
+    passed: Vec<usize>,
+    /// Per conjunct, the time spent evaluating it.
+    nanos: Vec<u128>,
+}
+
+impl Sample {
+    /// Order the conjuncts by the time they take per row that they remove, such that cheap
+    /// conjuncts that remove many rows come first. This is the optimal order if the conjuncts
+    /// are independent.
+    fn order(&self) -> Vec<usize> {
+        let rows = self.rows.max(1) as f64;
+        let rank = |i: usize| {
+            let removed = 1.0 - self.passed[i] as f64 / rows;
+            if removed > 0.0 {
+                self.nanos[i] as f64 / rows / removed
+            } else {
+                f64::INFINITY
+            }
+        };
+        let mut order = (0..self.passed.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| rank(a).total_cmp(&rank(b)));
+        order
+    }
+}
+
+/// The predicate of a filter that is a conjunction of row-wise predicates.
+///
+/// The first rows that are filtered are a sample on which every conjunct is evaluated, to
+/// measure how many rows it removes and how long it takes. After that, the conjuncts are
+/// evaluated in the order that removes rows at the lowest cost, each on the rows that the
+/// earlier ones kept. The sample is shared by all batches, so in the streaming engines the
+/// order is decided on the first morsels.
+pub struct AdaptiveFilterExpr {
+    /// `all_horizontal` of the conjuncts, which evaluates them in a given order.
+    conjuncts: ShortCircuitExpr,
+    sample: Mutex<Sample>,
+    order: OnceLock<Vec<usize>>,
+}
+
+impl AdaptiveFilterExpr {
+    pub(crate) fn new(conjuncts: ShortCircuitExpr) -> Self {
+        let n = conjuncts.inputs.len();
+        Self {
+            conjuncts,
+            sample: Mutex::new(Sample {
+                rows: 0,
+                passed: vec![0; n],
+                nanos: vec![0; n],
+            }),
+            order: OnceLock::new(),
+        }
+    }
+
+    /// Evaluate all conjuncts on all rows of `df` and add their measurements to the sample.
+    fn evaluate_sample(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        let n = self.conjuncts.inputs.len();
+        let mut passed = Vec::with_capacity(n);
+        let mut nanos = Vec::with_capacity(n);
+        let mut out: Option<Series> = None;
+        for i in 0..n {
+            let start = Instant::now();
+            let s = self.conjuncts.evaluate_input(i, df, state)?;
+            nanos.push(start.elapsed().as_nanos());
+            passed.push(s.bool()?.sum().unwrap_or(0) as usize);
+            out = Some(match out {
+                Some(out) => self.conjuncts.kind.combine(&out, &s)?,
+                None => s,
+            });
+        }
+
+        let mut sample = self.sample.lock().unwrap();
+        sample.rows += df.height();
+        for i in 0..n {
+            sample.passed[i] += passed[i];
+            sample.nanos[i] += nanos[i];
+        }
+        if sample.rows >= SAMPLE_ROWS {
+            self.order.get_or_init(|| {
+                let order = sample.order();
+                if state.verbose() {
+                    eprintln!("adaptive filter: evaluating the conjuncts in the order {order:?}");
+                }
+                order
+            });
+        }
+        Ok(out.unwrap())
+    }
+}
+
+impl PhysicalExpr for AdaptiveFilterExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        self.conjuncts.as_expression()
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        if let Some(order) = self.order.get() {
+            return self.conjuncts.evaluate_in_order(order, df, state);
+        }
+
+        let sampled = self.sample.lock().unwrap().rows;
+        let n_sample = SAMPLE_ROWS.saturating_sub(sampled).min(df.height());
+        let mut out = self.evaluate_sample(&df.slice(0, n_sample), state)?;
+        if n_sample == df.height() {
+            return Ok(out);
+        }
+
+        // The order is still undecided if other batches are being sampled concurrently.
+        let order = match self.order.get() {
+            Some(order) => order.clone(),
+            None => (0..self.conjuncts.inputs.len()).collect(),
+        };
+        let rest = df.slice(n_sample as i64, df.height() - n_sample);
+        out.append(&self.conjuncts.evaluate_in_order(&order, &rest, state)?)?;
+        Ok(out)
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        self.conjuncts.evaluate_on_groups(df, groups, state)
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        self.conjuncts.to_field(input_schema)
+    }
+
+    fn as_partitioned_aggregator(&self) -> Option<&dyn PartitionedAggregation> {
+        self.conjuncts.as_partitioned_aggregator()
+    }
+
+    fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
+        self.conjuncts.as_stats_evaluator()
+    }
+}
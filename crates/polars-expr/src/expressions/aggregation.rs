@@ -11,6 +11,7 @@ use polars_core::utils::{NoNull, _split_offsets};
 use polars_core::POOL;
 #[cfg(feature = "propagate_nans")]
 use polars_ops::prelude::nan_propagating_aggregate;
+use polars_ops::prelude::StrJoinAggOptions;
 use rayon::prelude::*;
 
 use super::*;
@@ -186,7 +187,8 @@ impl PhysicalExpr for AggregationExpr {
 
         if let AggregatedScalar(_) = ac.agg_state() {
             match self.agg_type.groupby {
-                GroupByMethod::Implode => {},
+                // The groups don't depend on the values of the input.
+                GroupByMethod::Implode | GroupByMethod::Groups => {},
                 _ => {
                     polars_bail!(ComputeError: "cannot aggregate as {}, the column is already aggregated", self.agg_type.groupby);
                 },
@@ -219,7 +221,12 @@ impl PhysicalExpr for AggregationExpr {
                 },
                 GroupByMethod::Sum => {
                     let (s, groups) = ac.get_final_aggregation();
-                    let agg_s = s.agg_sum(&groups);
+                    let agg_s = match s.dtype() {
+                        // Raise instead of returning null for sums that overflow.
+                        #[cfg(feature = "dtype-decimal")]
+                        DataType::Decimal(_, _) => s.decimal()?.agg_sum_checked(&groups)?,
+                        _ => s.agg_sum(&groups),
+                    };
                     AggregatedScalar(rename_series(agg_s, &keep_name))
                 },
                 GroupByMethod::Count { include_nulls } => {
@@ -689,15 +696,23 @@ impl AggQuantileExpr {
     }
 
     fn get_quantile(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<f64> {
-        let quantile = self.quantile.evaluate(df, state)?;
-        polars_ensure!(quantile.len() <= 1, ComputeError:
-            "polars only supports computing a single quantile; \
-            make sure the 'quantile' expression input produces a single quantile"
-        );
-        quantile.get(0).unwrap().try_extract()
+        evaluate_quantile(self.quantile.as_ref(), df, state)
     }
 }
 
+fn evaluate_quantile(
+    quantile: &dyn PhysicalExpr,
+    df: &DataFrame,
+    state: &ExecutionState,
+) -> PolarsResult<f64> {
+    let quantile = quantile.evaluate(df, state)?;
+    polars_ensure!(quantile.len() <= 1, ComputeError:
+        "polars only supports computing a single quantile; \
+        make sure the 'quantile' expression input produces a single quantile"
+    );
+    quantile.get(0).unwrap().try_extract()
+}
+
 impl PhysicalExpr for AggQuantileExpr {
     fn as_expression(&self) -> Option<&Expr> {
         None
@@ -742,6 +757,393 @@ impl PhysicalExpr for AggQuantileExpr {
     }
 }
 
+#[cfg(feature = "approx_quantile")]
+pub struct AggApproxQuantileExpr {
+    pub(crate) input: Arc<dyn PhysicalExpr>,
+    pub(crate) quantile: Arc<dyn PhysicalExpr>,
+    pub(crate) accuracy: u32,
+}
+
+#[cfg(feature = "approx_quantile")]
+impl AggApproxQuantileExpr {
+    pub fn new(
+        input: Arc<dyn PhysicalExpr>,
+        quantile: Arc<dyn PhysicalExpr>,
+        accuracy: u32,
+    ) -> Self {
+        Self {
+            input,
+            quantile,
+            accuracy,
+        }
+    }
+}
+
+#[cfg(feature = "approx_quantile")]
+impl PhysicalExpr for AggApproxQuantileExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        None
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        let input = self.input.evaluate(df, state)?;
+        let quantile = evaluate_quantile(self.quantile.as_ref(), df, state)?;
+        let value = polars_ops::prelude::approx_quantile(&input, quantile, self.accuracy)?;
+        Ok(Series::new(input.name(), [value]))
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let mut ac = self.input.evaluate_on_groups(df, groups, state)?;
+        let quantile = evaluate_quantile(self.quantile.as_ref(), df, state)?;
+
+        let input = ac.flat_naive().into_owned();
+        // SAFETY:
+        // groups are in bounds
+        let agg = unsafe {
+            polars_ops::prelude::group_agg_approx_quantile(
+                &input,
+                ac.groups(),
+                quantile,
+                self.accuracy,
+            )?
+        };
+        Ok(AggregationContext::from_agg_state(
+            AggregatedScalar(agg),
+            Cow::Borrowed(groups),
+        ))
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        let field = self.input.to_field(input_schema)?;
+        Ok(Field::new(field.name(), DataType::Float64))
+    }
+}
+
+#[cfg(feature = "dtype-array")]
+pub struct AggQuantilesExpr {
+    pub(crate) input: Arc<dyn PhysicalExpr>,
+    pub(crate) options: QuantilesOptions,
+}
+
+#[cfg(feature = "dtype-array")]
+impl AggQuantilesExpr {
+    pub fn new(input: Arc<dyn PhysicalExpr>, options: QuantilesOptions) -> Self {
+        Self { input, options }
+    }
+}
+
+#[cfg(feature = "dtype-array")]
+impl PhysicalExpr for AggQuantilesExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        None
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        let input = self.input.evaluate(df, state)?;
+        input.quantiles_reduce(&self.options.quantiles, self.options.interpol)
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let mut ac = self.input.evaluate_on_groups(df, groups, state)?;
+        let keep_name = ac.series().name().to_string();
+
+        // SAFETY:
+        // groups are in bounds
+        let mut agg = unsafe {
+            ac.flat_naive().into_owned().agg_quantiles(
+                ac.groups(),
+                &self.options.quantiles,
+                self.options.interpol,
+            )?
+        };
+        agg.rename(&keep_name);
+        Ok(AggregationContext::from_agg_state(
+            AggregatedScalar(agg),
+            Cow::Borrowed(groups),
+        ))
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        let field = self.input.to_field(input_schema)?;
+        let inner = match field.data_type() {
+            DataType::Float32 => DataType::Float32,
+            _ => DataType::Float64,
+        };
+        let dtype = DataType::Array(Box::new(inner), self.options.quantiles.len());
+        Ok(Field::new(field.name(), dtype))
+    }
+}
+
+pub struct AggStrJoinExpr {
+    pub(crate) input: Arc<dyn PhysicalExpr>,
+    pub(crate) options: StrJoinAggOptions,
+}
+
+impl AggStrJoinExpr {
+    pub fn new(input: Arc<dyn PhysicalExpr>, options: StrJoinAggOptions) -> Self {
+        Self { input, options }
+    }
+}
+
+impl PhysicalExpr for AggStrJoinExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        None
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        let input = self.input.evaluate(df, state)?;
+        let ca = input.cast(&DataType::String)?;
+        let value = polars_ops::prelude::str_join_agg(ca.str()?, &self.options);
+        Ok(Series::new(input.name(), [value]))
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let mut ac = self.input.evaluate_on_groups(df, groups, state)?;
+
+        let input = ac.flat_naive().cast(&DataType::String)?;
+        // SAFETY:
+        // groups are in bounds
+        let agg = unsafe {
+            polars_ops::prelude::group_agg_str_join(input.str()?, ac.groups(), &self.options)
+        };
+        Ok(AggregationContext::from_agg_state(
+            AggregatedScalar(agg.into_series()),
+            Cow::Borrowed(groups),
+        ))
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        let field = self.input.to_field(input_schema)?;
+        Ok(Field::new(field.name(), DataType::String))
+    }
+}
+
+pub struct AggFirstByExpr {
+    pub(crate) input: Arc<dyn PhysicalExpr>,
+    pub(crate) by: Arc<dyn PhysicalExpr>,
+    pub(crate) last: bool,
+}
+
+impl AggFirstByExpr {
+    pub fn new(input: Arc<dyn PhysicalExpr>, by: Arc<dyn PhysicalExpr>, last: bool) -> Self {
+        Self { input, by, last }
+    }
+}
+
+fn first_by_length_mismatch() -> PolarsError {
+    polars_err!(
+        ShapeMismatch: "the expression and `by` of `first_by`/`last_by` must have the same length"
+    )
+}
+
+impl PhysicalExpr for AggFirstByExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        None
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        let input = self.input.evaluate(df, state)?;
+        let by = self.by.evaluate(df, state)?;
+        if input.len() != by.len() {
+            return Err(first_by_length_mismatch());
+        }
+        match polars_ops::prelude::arg_first_by(&by, self.last)? {
+            Some(idx) => Ok(input.slice(idx as i64, 1)),
+            None => Ok(Series::full_null(input.name(), 1, input.dtype())),
+        }
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let mut ac = self.input.evaluate_on_groups(df, groups, state)?;
+        let mut ac_by = self.by.evaluate_on_groups(df, groups, state)?;
+
+        let by = ac_by.flat_naive().into_owned();
+        // SAFETY:
+        // groups are in bounds
+        let positions =
+            unsafe { polars_ops::prelude::group_arg_first_by(&by, ac_by.groups(), self.last)? };
+
+        // The positions are relative to the groups of `by`, which are mapped to rows of the input
+        // through its own groups.
+        let input = ac.flat_naive().into_owned();
+        if ac.groups().len() != positions.len() {
+            return Err(first_by_length_mismatch());
+        }
+        let idx: IdxCa = match ac.groups().as_ref() {
+            GroupsProxy::Idx(groups) => {
+                positions
+                    .into_iter()
+                    .zip(groups.all())
+                    .map(|(pos, group)| {
+                        pos.map(|pos| {
+                            group
+                                .get(pos as usize)
+                                .copied()
+                                .ok_or_else(first_by_length_mismatch)
+                        })
+                        .transpose()
+                    })
+                    .collect::<PolarsResult<_>>()?
+            },
+            GroupsProxy::Slice { groups, .. } => {
+                positions
+                    .into_iter()
+                    .zip(groups.iter())
+                    .map(|(pos, &[first, len])| {
+                        pos.map(|pos| {
+                            if pos < len {
+                                Ok(first + pos)
+                            } else {
+                                Err(first_by_length_mismatch())
+                            }
+                        })
+                        .transpose()
+                    })
+                    .collect::<PolarsResult<_>>()?
+            },
+        };
+        let agg = input.take(&idx)?;
+        Ok(AggregationContext::from_agg_state(
+            AggregatedScalar(agg),
+            Cow::Borrowed(groups),
+        ))
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        self.input.to_field(input_schema)
+    }
+}
+
+#[cfg(feature = "ffi_plugin")]
+pub struct AggPluginExpr {
+    pub(crate) input: Arc<dyn PhysicalExpr>,
+    pub(crate) function: PluginAggregation,
+    pub(crate) aggregator: Arc<PluginAggregator>,
+}
+
+#[cfg(feature = "ffi_plugin")]
+impl AggPluginExpr {
+    pub fn new(input: Arc<dyn PhysicalExpr>, function: PluginAggregation) -> PolarsResult<Self> {
+        let aggregator = function.load()?;
+        Ok(Self {
+            input,
+            function,
+            aggregator,
+        })
+    }
+}
+
+#[cfg(feature = "ffi_plugin")]
+impl PhysicalExpr for AggPluginExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        None
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        let input = self.input.evaluate(df, state)?;
+        // Aggregate parts of the input in parallel and merge their states.
+        let offsets = _split_offsets(input.len(), POOL.current_num_threads());
+        let states = POOL.install(|| {
+            offsets
+                .into_par_iter()
+                .map(|(offset, len)| {
+                    let mut agg_state = self.aggregator.init()?;
+                    agg_state.update(&input.slice(offset as i64, len))?;
+                    Ok(agg_state)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        let mut states = states.into_iter();
+        let mut out = match states.next() {
+            Some(agg_state) => agg_state,
+            None => self.aggregator.init()?,
+        };
+        for agg_state in states {
+            out.merge(&agg_state)?;
+        }
+        let mut out = out.finalize()?;
+        out.rename(input.name());
+        Ok(out)
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let mut ac = self.input.evaluate_on_groups(df, groups, state)?;
+        let input = ac.flat_naive().into_owned();
+
+        // Every group is aggregated in its own state, so no lists have to be materialized.
+        let aggregated = POOL.install(|| match ac.groups().as_ref() {
+            GroupsProxy::Idx(groups) => groups
+                .into_par_iter()
+                .map(|(_, idx)| {
+                    // SAFETY:
+                    // groups are in bounds
+                    let group = unsafe { input.take_slice_unchecked(idx) };
+                    self.aggregator.aggregate(&group)
+                })
+                .collect::<PolarsResult<Vec<_>>>(),
+            GroupsProxy::Slice { groups, .. } => groups
+                .par_iter()
+                .map(|&[first, len]| {
+                    self.aggregator
+                        .aggregate(&input.slice(first as i64, len as usize))
+                })
+                .collect::<PolarsResult<Vec<_>>>(),
+        })?;
+
+        let mut aggregated = aggregated.into_iter();
+        let mut agg = match aggregated.next() {
+            Some(s) => s,
+            None => {
+                let dtype = self.to_field(&df.schema())?.dtype;
+                Series::new_empty(input.name(), &dtype)
+            },
+        };
+        for s in aggregated {
+            agg.append(&s)?;
+        }
+        agg.rename(input.name());
+        Ok(AggregationContext::from_agg_state(
+            AggregatedScalar(agg.rechunk()),
+            Cow::Borrowed(groups),
+        ))
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        let field = self.input.to_field(input_schema)?;
+        self.function.output_field(&field)
+    }
+}
+
 /// Simple wrapper to parallelize functions that can be divided over threads aggregated and
 /// finally aggregated in the main thread. This can be done for sum, min, max, etc.
 fn parallel_op_series<F>(f: F, s: Series, allow_threading: bool) -> PolarsResult<Series>
@@ -14,6 +14,8 @@ pub struct BinaryExpr {
     right: Arc<dyn PhysicalExpr>,
     expr: Expr,
     has_literal: bool,
+    // Set by a `parallel` hint, overrides the heuristics below.
+    parallel: Option<bool>,
 }
 
 impl BinaryExpr {
@@ -23,6 +25,7 @@ impl BinaryExpr {
         right: Arc<dyn PhysicalExpr>,
         expr: Expr,
         has_literal: bool,
+        parallel: Option<bool>,
     ) -> Self {
         Self {
             left,
@@ -30,6 +33,7 @@ impl BinaryExpr {
             right,
             expr,
             has_literal,
+            parallel,
         }
     }
 }
@@ -63,10 +67,16 @@ pub fn apply_operator(left: &Series, right: &Series, op: Operator) -> PolarsResu
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => left / right,
             Duration(_) | Date | Datetime(_, _) | Float32 | Float64 => left / right,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => left / right,
             #[cfg(feature = "dtype-array")]
-            dt @ Array(_, _) => {
-                let left_dt = dt.cast_leaf(Float64);
-                let right_dt = right.dtype().cast_leaf(Float64);
+            _ if left.dtype().is_array() || right.dtype().is_array() => {
+                let float = match (left.dtype().leaf_dtype(), right.dtype().leaf_dtype()) {
+                    (Float32, Float32) => Float32,
+                    _ => Float64,
+                };
+                let left_dt = left.dtype().cast_leaf(float.clone());
+                let right_dt = right.dtype().cast_leaf(float);
                 left.cast(&left_dt)? / right.cast(&right_dt)?
             },
             _ => {
@@ -183,14 +193,18 @@ impl PhysicalExpr for BinaryExpr {
         #[cfg(not(feature = "streaming"))]
         let in_streaming = false;
 
+        // Literals are free, don't pay par cost.
+        let sequential = self
+            .parallel
+            .map_or(in_streaming || self.has_literal, |parallel| !parallel);
+
         let (lhs, rhs);
         if has_window {
             let mut state = state.split();
             state.remove_cache_window_flag();
             lhs = self.left.evaluate(df, &state)?;
             rhs = self.right.evaluate(df, &state)?;
-        } else if in_streaming || self.has_literal {
-            // Literals are free, don't pay par cost.
+        } else if sequential {
             lhs = self.left.evaluate(df, state)?;
             rhs = self.right.evaluate(df, state)?;
         } else {
@@ -218,12 +232,19 @@ impl PhysicalExpr for BinaryExpr {
         groups: &'a GroupsProxy,
         state: &ExecutionState,
     ) -> PolarsResult<AggregationContext<'a>> {
-        let (result_a, result_b) = POOL.install(|| {
-            rayon::join(
-                || self.left.evaluate_on_groups(df, groups, state),
-                || self.right.evaluate_on_groups(df, groups, state),
+        let (result_a, result_b) = if self.parallel == Some(false) {
+            (
+                self.left.evaluate_on_groups(df, groups, state),
+                self.right.evaluate_on_groups(df, groups, state),
             )
-        });
+        } else {
+            POOL.install(|| {
+                rayon::join(
+                    || self.left.evaluate_on_groups(df, groups, state),
+                    || self.right.evaluate_on_groups(df, groups, state),
+                )
+            })
+        };
         let mut ac_l = result_a?;
         let ac_r = result_b?;
 
@@ -1,3 +1,4 @@
+mod adaptive_filter;
 mod aggregation;
 mod alias;
 mod apply;
@@ -9,17 +10,21 @@ mod filter;
 mod gather;
 mod group_iter;
 mod literal;
+mod or_else;
 #[cfg(feature = "dynamic_group_by")]
 mod rolling;
+mod short_circuit;
 mod slice;
 mod sort;
 mod sortby;
 mod ternary;
 mod window;
+mod window_frame;
 
 use std::borrow::Cow;
 use std::fmt::{Display, Formatter};
 
+pub(crate) use adaptive_filter::*;
 pub(crate) use aggregation::*;
 pub(crate) use alias::*;
 pub(crate) use apply::*;
@@ -32,17 +37,20 @@ pub(crate) use count::*;
 pub(crate) use filter::*;
 pub(crate) use gather::*;
 pub(crate) use literal::*;
+pub(crate) use or_else::*;
 use polars_core::prelude::*;
 use polars_io::predicates::PhysicalIoExpr;
 use polars_plan::prelude::*;
 #[cfg(feature = "dynamic_group_by")]
 pub(crate) use rolling::RollingExpr;
+pub(crate) use short_circuit::*;
 pub(crate) use slice::*;
 pub(crate) use sort::*;
 pub(crate) use sortby::*;
 pub(crate) use ternary::*;
 pub use window::window_function_format_order_by;
 pub(crate) use window::*;
+pub(crate) use window_frame::WindowFrameExpr;
 
 use crate::state::ExecutionState;
 
@@ -610,9 +618,16 @@ impl PhysicalIoExpr for PhysicalIoHelper {
         if self.has_window_function {
             state.insert_has_window_function_flag();
         }
+        // Readers may evaluate the predicate on a frame that only has the columns it reads, so
+        // the columns are looked up in the frame rather than in the schema of the scan.
+        state.set_schema(Arc::new(df.schema()));
         self.expr.evaluate(df, &state)
     }
 
+    fn live_variables(&self) -> Option<Vec<Arc<str>>> {
+        self.expr.as_expression().map(expr_to_leaf_column_names)
+    }
+
     #[cfg(feature = "parquet")]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         self.expr.as_stats_evaluator()
@@ -0,0 +1,73 @@
+use polars_core::prelude::*;
+
+use super::*;
+use crate::expressions::{AggregationContext, PartitionedAggregation, PhysicalExpr};
+
+/// Evaluates `primary` and, if that fails, `fallback` on the same batch or groups.
+///
+/// Both are cast to their supertype when the plan is converted, so they only differ in name:
+/// the result has the name of `primary`.
+pub struct OrElseExpr {
+    pub(crate) primary: Arc<dyn PhysicalExpr>,
+    pub(crate) fallback: Arc<dyn PhysicalExpr>,
+    /// The expression that evaluates both inputs, for everything but the evaluation.
+    pub(crate) apply: Arc<dyn PhysicalExpr>,
+}
+
+impl OrElseExpr {
+    fn log_error(&self, err: &PolarsError, state: &ExecutionState) {
+        if state.verbose() {
+            eprintln!("or_else: evaluating the fallback, the primary expression failed: {err}")
+        }
+    }
+}
+
+impl PhysicalExpr for OrElseExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        self.apply.as_expression()
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        match self.primary.evaluate(df, state) {
+            Ok(s) => Ok(s),
+            Err(err) => {
+                self.log_error(&err, state);
+                let name = self.primary.to_field(&df.schema())?.name;
+                let s = self.fallback.evaluate(df, state)?;
+                Ok(s.with_name(&name))
+            },
+        }
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        match self.primary.evaluate_on_groups(df, groups, state) {
+            Ok(ac) => Ok(ac),
+            Err(err) => {
+                self.log_error(&err, state);
+                let name = self.primary.to_field(&df.schema())?.name;
+                let mut ac = self.fallback.evaluate_on_groups(df, groups, state)?;
+                let s = ac.take().with_name(&name);
+                if ac.is_literal() {
+                    ac.with_literal(s);
+                } else {
+                    ac.with_series(s, ac.is_aggregated(), self.as_expression())?;
+                }
+                Ok(ac)
+            },
+        }
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        self.apply.to_field(input_schema)
+    }
+
+    fn as_partitioned_aggregator(&self) -> Option<&dyn PartitionedAggregation> {
+        None
+    }
+}
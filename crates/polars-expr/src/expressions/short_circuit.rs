@@ -0,0 +1,176 @@
+use std::ops::Not;
+
+use polars_core::prelude::*;
+
+use super::*;
+use crate::expressions::{AggregationContext, PartitionedAggregation, PhysicalExpr};
+
+/// Only the undecided rows are selected if they are at most this fraction of the rows. Above
+/// it, gathering the rows and scattering the result costs more than evaluating all of them.
+const SELECTION_FRACTION: f64 = 0.25;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum ShortCircuit {
+    AnyHorizontal,
+    AllHorizontal,
+    Coalesce,
+}
+
+impl ShortCircuit {
+    /// The rows of which the value may still change.
+    fn undecided(&self, out: &Series) -> PolarsResult<BooleanChunked> {
+        Ok(match self {
+            Self::AnyHorizontal => out.bool()?.fill_null_with_values(false)?.not(),
+            Self::AllHorizontal => out.bool()?.fill_null_with_values(true)?,
+            Self::Coalesce => out.is_null(),
+        })
+    }
+
+    pub(crate) fn combine(&self, out: &Series, s: &Series) -> PolarsResult<Series> {
+        let (out, s) = broadcast(out, s)?;
+        Ok(match self {
+            Self::AnyHorizontal => (out.bool()? | s.bool()?).into_series(),
+            Self::AllHorizontal => (out.bool()? & s.bool()?).into_series(),
+            Self::Coalesce => out.zip_with_same_type(&out.is_not_null(), &s)?,
+        })
+    }
+}
+
+fn broadcast(a: &Series, b: &Series) -> PolarsResult<(Series, Series)> {
+    Ok(match (a.len(), b.len()) {
+        (1, n) if n != 1 => (a.new_from_index(0, n), b.clone()),
+        (n, 1) if n != 1 => (a.clone(), b.new_from_index(0, n)),
+        (n, m) => {
+            polars_ensure!(
+                n == m,
+                ShapeMismatch: "cannot combine inputs of length {} and {} horizontally", n, m
+            );
+            (a.clone(), b.clone())
+        },
+    })
+}
+
+/// A horizontal `any`, `all` or `coalesce` that evaluates its inputs one after the other and
+/// stops as soon as every row is decided.
+///
+/// An input that only maps rows on its own is evaluated on the undecided rows only if these are
+/// few. Other inputs are evaluated on all rows.
+pub struct ShortCircuitExpr {
+    pub(crate) kind: ShortCircuit,
+    pub(crate) inputs: Vec<Arc<dyn PhysicalExpr>>,
+    /// Per input, whether it can be evaluated on a subset of the rows.
+    pub(crate) separable: Vec<bool>,
+    /// Per input, the columns it reads.
+    pub(crate) columns: Vec<Vec<Arc<str>>>,
+    /// The expression that evaluates all inputs at once.
+    pub(crate) fallback: Arc<dyn PhysicalExpr>,
+}
+
+impl ShortCircuitExpr {
+    pub(crate) fn evaluate_input(
+        &self,
+        i: usize,
+        df: &DataFrame,
+        state: &ExecutionState,
+    ) -> PolarsResult<Series> {
+        let s = self.inputs[i].evaluate(df, state)?;
+        match self.kind {
+            ShortCircuit::AnyHorizontal | ShortCircuit::AllHorizontal => s.cast(&DataType::Boolean),
+            ShortCircuit::Coalesce => Ok(s),
+        }
+    }
+
+    /// Evaluate input `i` on the `undecided` rows and combine it with `out` on these rows.
+    fn evaluate_selected(
+        &self,
+        i: usize,
+        out: &Series,
+        undecided: &BooleanChunked,
+        df: &DataFrame,
+        state: &ExecutionState,
+    ) -> PolarsResult<Series> {
+        let selected = df
+            .select(self.columns[i].iter().map(|name| name.as_ref()))?
+            .filter(undecided)?;
+        let s = self.evaluate_input(i, &selected, state)?;
+        let combined = self.kind.combine(&out.filter(undecided)?, &s)?;
+
+        // Map every undecided row to its position in the selection.
+        let mut next: IdxSize = 0;
+        let positions: IdxCa = undecided
+            .into_no_null_iter()
+            .map(|u| {
+                u.then(|| {
+                    next += 1;
+                    next - 1
+                })
+            })
+            .collect();
+        combined.take(&positions)?.zip_with(undecided, out)
+    }
+
+    /// Evaluate the inputs in `order`, a permutation of their indices.
+    pub(crate) fn evaluate_in_order(
+        &self,
+        order: &[usize],
+        df: &DataFrame,
+        state: &ExecutionState,
+    ) -> PolarsResult<Series> {
+        let mut out = self.evaluate_input(order[0], df, state)?;
+        let name = out.name().to_string();
+
+        for &i in &order[1..] {
+            let undecided = self.kind.undecided(&out)?;
+            let n_undecided = undecided.sum().unwrap_or(0) as usize;
+            // A single value still has to be broadcast to the length of the other inputs.
+            if n_undecided == 0 && out.len() != 1 {
+                break;
+            }
+
+            let select = self.separable[i]
+                && !self.columns[i].is_empty()
+                && out.len() == df.height()
+                && (n_undecided as f64) <= SELECTION_FRACTION * out.len() as f64;
+            out = if select {
+                self.evaluate_selected(i, &out, &undecided, df, state)?
+            } else {
+                let s = self.evaluate_input(i, df, state)?;
+                self.kind.combine(&out, &s)?
+            };
+        }
+        Ok(out.with_name(&name))
+    }
+}
+
+impl PhysicalExpr for ShortCircuitExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        self.fallback.as_expression()
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        let order = (0..self.inputs.len()).collect::<Vec<_>>();
+        self.evaluate_in_order(&order, df, state)
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        self.fallback.evaluate_on_groups(df, groups, state)
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        self.fallback.to_field(input_schema)
+    }
+
+    fn as_partitioned_aggregator(&self) -> Option<&dyn PartitionedAggregation> {
+        self.fallback.as_partitioned_aggregator()
+    }
+
+    fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
+        self.fallback.as_stats_evaluator()
+    }
+}
@@ -72,11 +72,9 @@ fn sort_by_groups_single_by(
     options: &SortOptions,
 ) -> PolarsResult<(IdxSize, IdxVec)> {
     let options = SortOptions {
-        descending: options.descending,
-        nulls_last: options.nulls_last,
         // We are already in par iter.
         multithreaded: false,
-        ..Default::default()
+        ..*options
     };
     let new_idx = match indicator {
         GroupsIndicator::Idx((_, idx)) => {
@@ -102,7 +100,7 @@ fn sort_by_groups_single_by(
 fn sort_by_groups_no_match_single<'a>(
     mut ac_in: AggregationContext<'a>,
     mut ac_by: AggregationContext<'a>,
-    descending: bool,
+    options: SortOptions,
     expr: &Expr,
 ) -> PolarsResult<AggregationContext<'a>> {
     let s_in = ac_in.aggregated();
@@ -118,10 +116,9 @@ fn sort_by_groups_no_match_single<'a>(
                 (Some(s), Some(s_sort_by)) => {
                     polars_ensure!(s.len() == s_sort_by.len(), ComputeError: "series lengths don't match in 'sort_by' expression");
                     let idx = s_sort_by.arg_sort(SortOptions {
-                        descending,
                         // We are already in par iter.
                         multithreaded: false,
-                        ..Default::default()
+                        ..options
                     });
                     Ok(Some(unsafe { s.take_unchecked(&idx) }))
                 },
@@ -137,10 +134,7 @@ fn sort_by_groups_no_match_single<'a>(
 fn sort_by_groups_multiple_by(
     indicator: GroupsIndicator,
     sort_by_s: &[Series],
-    descending: &[bool],
-    nulls_last: &[bool],
-    multithreaded: bool,
-    maintain_order: bool,
+    options: &SortMultipleOptions,
 ) -> PolarsResult<(IdxSize, IdxVec)> {
     let new_idx = match indicator {
         GroupsIndicator::Idx((_first, idx)) => {
@@ -150,14 +144,7 @@ fn sort_by_groups_multiple_by(
                 .map(|s| unsafe { s.take_slice_unchecked(idx) })
                 .collect::<Vec<_>>();
 
-            let options = SortMultipleOptions {
-                descending: descending.to_owned(),
-                nulls_last: nulls_last.to_owned(),
-                multithreaded,
-                maintain_order,
-            };
-
-            let sorted_idx = groups[0].arg_sort_multiple(&groups[1..], &options).unwrap();
+            let sorted_idx = groups[0].arg_sort_multiple(&groups[1..], options)?;
             map_sorted_indices_to_group_idx(&sorted_idx, idx)
         },
         GroupsIndicator::Slice([first, len]) => {
@@ -166,13 +153,7 @@ fn sort_by_groups_multiple_by(
                 .map(|s| s.slice(first as i64, len as usize))
                 .collect::<Vec<_>>();
 
-            let options = SortMultipleOptions {
-                descending: descending.to_owned(),
-                nulls_last: nulls_last.to_owned(),
-                multithreaded,
-                maintain_order,
-            };
-            let sorted_idx = groups[0].arg_sort_multiple(&groups[1..], &options).unwrap();
+            let sorted_idx = groups[0].arg_sort_multiple(&groups[1..], options)?;
             map_sorted_indices_to_group_slice(&sorted_idx, first)
         },
     };
@@ -207,7 +188,10 @@ impl PhysicalExpr for SortByExpr {
                         e.evaluate(df, state).map(|s| match s.dtype() {
                             #[cfg(feature = "dtype-categorical")]
                             DataType::Categorical(_, _) | DataType::Enum(_, _) => s,
-                            _ => s.to_physical_repr().into_owned(),
+                            _ => _collated_sort_column(
+                                s.to_physical_repr().into_owned(),
+                                self.sort_options.collation,
+                            ),
                         })
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
@@ -266,7 +250,10 @@ impl PhysicalExpr for SortByExpr {
                 match s.dtype() {
                     #[cfg(feature = "dtype-categorical")]
                     DataType::Categorical(_, _) | DataType::Enum(_, _) => s.into_owned(),
-                    _ => s.to_physical_repr().into_owned(),
+                    _ => _collated_sort_column(
+                        s.to_physical_repr().into_owned(),
+                        self.sort_options.collation,
+                    ),
                 }
             })
             .collect::<Vec<_>>();
@@ -286,16 +273,16 @@ impl PhysicalExpr for SortByExpr {
 
         let groups = if self.by.len() == 1 {
             let mut ac_sort_by = ac_sort_by.pop().unwrap();
+            let options = SortOptions {
+                descending: descending[0],
+                nulls_last: nulls_last[0],
+                ..SortOptions::from(&self.sort_options)
+            };
 
             // The groups of the lhs of the expressions do not match the series values,
             // we must take the slower path.
             if !matches!(ac_in.update_groups, UpdateGroups::No) {
-                return sort_by_groups_no_match_single(
-                    ac_in,
-                    ac_sort_by,
-                    self.sort_options.descending[0],
-                    &self.expr,
-                );
+                return sort_by_groups_no_match_single(ac_in, ac_sort_by, options, &self.expr);
             };
 
             let sort_by_s = sort_by_s.pop().unwrap();
@@ -304,15 +291,12 @@ impl PhysicalExpr for SortByExpr {
             let (check, groups) = POOL.join(
                 || check_groups(groups, ac_in.groups()),
                 || {
-                    update_groups_sort_by(
-                        groups,
-                        &sort_by_s,
-                        &SortOptions {
-                            descending: descending[0],
-                            nulls_last: nulls_last[0],
-                            ..Default::default()
-                        },
-                    )
+                    // The sort column is already collated.
+                    let options = SortOptions {
+                        collation: None,
+                        ..options
+                    };
+                    update_groups_sort_by(groups, &sort_by_s, &options)
                 },
             );
             check?;
@@ -320,20 +304,18 @@ impl PhysicalExpr for SortByExpr {
             groups?
         } else {
             let groups = ac_sort_by[0].groups();
+            // The sort columns are already collated.
+            let options = self
+                .sort_options
+                .clone()
+                .with_order_descending_multi(descending)
+                .with_nulls_last_multi(nulls_last)
+                .with_collation(None);
 
             let groups = POOL.install(|| {
                 groups
                     .par_iter()
-                    .map(|indicator| {
-                        sort_by_groups_multiple_by(
-                            indicator,
-                            &sort_by_s,
-                            &descending,
-                            &nulls_last,
-                            self.sort_options.multithreaded,
-                            self.sort_options.maintain_order,
-                        )
-                    })
+                    .map(|indicator| sort_by_groups_multiple_by(indicator, &sort_by_s, &options))
                     .collect::<PolarsResult<_>>()
             });
             GroupsProxy::Idx(groups?)
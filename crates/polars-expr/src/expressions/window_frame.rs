@@ -0,0 +1,297 @@
+#[cfg(feature = "dynamic_group_by")]
+use polars_time::Duration;
+
+use super::*;
+
+/// A function computed over a frame of rows around every row of its partition.
+///
+/// The rows are gathered in the order of the partitions and of `order_by` within every
+/// partition, so that every frame is a slice. The frames then move forward monotonically and
+/// the function is evaluated with the rolling kernels, after which the output is scattered back
+/// to the positions of the rows.
+pub(crate) struct WindowFrameExpr {
+    pub(crate) partition_by: Vec<Arc<dyn PhysicalExpr>>,
+    pub(crate) order_by: Option<(Arc<dyn PhysicalExpr>, SortOptions)>,
+    pub(crate) frame: WindowFrame,
+    /// The columns used by `function`; only these are gathered.
+    pub(crate) apply_columns: Vec<Arc<str>>,
+    pub(crate) out_name: Option<Arc<str>>,
+    pub(crate) function: Expr,
+    pub(crate) phys_function: Arc<dyn PhysicalExpr>,
+    pub(crate) expr: Expr,
+}
+
+/// Find the frames of a partition of `len` rows, offset by `offset`, from row offsets.
+fn row_frames(
+    offset: IdxSize,
+    len: IdxSize,
+    start: Option<i64>,
+    end: Option<i64>,
+    frames: &mut Vec<[IdxSize; 2]>,
+) {
+    let len = len as i64;
+    frames.extend((0..len).map(|i| {
+        let lo = start.map_or(0, |s| i.saturating_add(s).clamp(0, len));
+        let hi = end.map_or(len, |e| i.saturating_add(e).saturating_add(1).clamp(0, len));
+        [
+            offset + lo as IdxSize,
+            hi.saturating_sub(lo).max(0) as IdxSize,
+        ]
+    }));
+}
+
+/// Find the frames of a partition from offsets on its sorted `values`.
+#[cfg(feature = "dynamic_group_by")]
+fn range_frames(
+    offset: IdxSize,
+    values: &[i64],
+    start: Option<i64>,
+    end: Option<i64>,
+    frames: &mut Vec<[IdxSize; 2]>,
+) {
+    frames.extend(values.iter().map(|&v| {
+        let lo = start.map_or(0, |s| {
+            let s = v.saturating_add(s);
+            values.partition_point(|&w| w < s)
+        });
+        let hi = end.map_or(values.len(), |e| {
+            let e = v.saturating_add(e);
+            values.partition_point(|&w| w <= e)
+        });
+        [offset + lo as IdxSize, hi.saturating_sub(lo) as IdxSize]
+    }));
+}
+
+/// The offset in the physical unit of `dtype`.
+#[cfg(feature = "dynamic_group_by")]
+fn physical_offset(duration: &Duration, dtype: &DataType) -> PolarsResult<i64> {
+    let offset = match dtype {
+        dt if dt.is_integer() => {
+            polars_ensure!(
+                duration.parsed_int,
+                InvalidOperation: "a range frame over an integer column needs integer offsets such as '3i', got '{}'", duration
+            );
+            duration.nanoseconds()
+        },
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => {
+            polars_ensure!(
+                duration.is_full_days() && duration.is_constant_duration(None),
+                InvalidOperation: "a range frame over a date column needs offsets of whole days or weeks, got '{}'", duration
+            );
+            duration.weeks() * 7 + duration.days()
+        },
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(tu, tz) => {
+            polars_ensure!(
+                duration.is_constant_duration(tz.as_deref()),
+                InvalidOperation: "a range frame over a datetime column needs offsets of a fixed length, got '{}'", duration
+            );
+            match tu {
+                TimeUnit::Nanoseconds => duration.duration_ns(),
+                TimeUnit::Microseconds => duration.duration_us(),
+                TimeUnit::Milliseconds => duration.duration_ms(),
+            }
+        },
+        #[cfg(feature = "dtype-duration")]
+        DataType::Duration(tu) => {
+            polars_ensure!(
+                duration.is_constant_duration(None),
+                InvalidOperation: "a range frame over a duration column needs offsets of a fixed length, got '{}'", duration
+            );
+            match tu {
+                TimeUnit::Nanoseconds => duration.duration_ns(),
+                TimeUnit::Microseconds => duration.duration_us(),
+                TimeUnit::Milliseconds => duration.duration_ms(),
+            }
+        },
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => {
+            polars_ensure!(
+                duration.is_constant_duration(None),
+                InvalidOperation: "a range frame over a time column needs offsets of a fixed length, got '{}'", duration
+            );
+            duration.duration_ns()
+        },
+        dt => polars_bail!(
+            InvalidOperation: "a range frame needs an integer or temporal `order_by` column, got {}", dt
+        ),
+    };
+    Ok(if duration.negative() { -offset } else { offset })
+}
+
+impl WindowFrameExpr {
+    /// The rows of every partition, sorted by `order_by`, and the `order_by` column.
+    fn partitions(
+        &self,
+        df: &DataFrame,
+        state: &ExecutionState,
+    ) -> PolarsResult<(GroupsProxy, Option<Series>)> {
+        let partition_by = self
+            .partition_by
+            .iter()
+            .map(|e| e.evaluate(df, state))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let groups = if partition_by.is_empty() {
+            GroupsProxy::Slice {
+                groups: vec![[0, df.height() as IdxSize]],
+                rolling: false,
+            }
+        } else {
+            df.group_by_with_series(partition_by, true, false)?
+                .take_groups()
+        };
+        match &self.order_by {
+            Some((order_by, options)) => {
+                let order_by = order_by.evaluate(df, state)?;
+                polars_ensure!(order_by.len() == df.height(), ShapeMismatch: "the order by expression evaluated to a length: {} that doesn't match the input DataFrame: {}", order_by.len(), df.height());
+                let groups = update_groups_sort_by(&groups, &order_by, options)?;
+                Ok((groups, Some(order_by)))
+            },
+            None => Ok((groups, None)),
+        }
+    }
+
+    /// Find the frame of every row, as a slice of the rows in the order of `perm`.
+    #[cfg_attr(not(feature = "dynamic_group_by"), allow(unused_variables))]
+    fn frames(
+        &self,
+        order_by: Option<&Series>,
+        perm: &IdxCa,
+        partitions: &[[IdxSize; 2]],
+    ) -> PolarsResult<Vec<[IdxSize; 2]>> {
+        let mut frames = Vec::with_capacity(perm.len());
+        match &self.frame {
+            WindowFrame::Rows { start, end } => {
+                if let (Some(start), Some(end)) = (start, end) {
+                    polars_ensure!(start <= end, InvalidOperation: "the start of a window frame cannot be after its end");
+                }
+                for &[offset, len] in partitions {
+                    row_frames(offset, len, *start, *end, &mut frames);
+                }
+            },
+            #[cfg(feature = "dynamic_group_by")]
+            WindowFrame::Range { start, end } => {
+                let (Some(order_by), Some((_, options))) = (order_by, &self.order_by) else {
+                    polars_bail!(InvalidOperation: "a range frame needs an `order_by` column");
+                };
+                let dtype = order_by.dtype();
+                let start = start
+                    .as_ref()
+                    .map(|d| physical_offset(d, dtype))
+                    .transpose()?;
+                let end = end
+                    .as_ref()
+                    .map(|d| physical_offset(d, dtype))
+                    .transpose()?;
+                if let (Some(start), Some(end)) = (start, end) {
+                    polars_ensure!(start <= end, InvalidOperation: "the start of a window frame cannot be after its end");
+                }
+                polars_ensure!(
+                    order_by.null_count() == 0,
+                    InvalidOperation: "the `order_by` column of a range frame cannot contain nulls"
+                );
+
+                // SAFETY: the permutation is in bounds.
+                let values = unsafe { order_by.take_unchecked(perm) }
+                    .to_physical_repr()
+                    .cast(&DataType::Int64)?;
+                let values = values.i64()?.rechunk();
+                let mut values = values.cont_slice()?.to_vec();
+                // In descending order the preceding rows have larger values, which negating the
+                // values turns into an ascending order.
+                if options.descending {
+                    values.iter_mut().for_each(|v| *v = v.wrapping_neg());
+                }
+                for &[offset, len] in partitions {
+                    let values = &values[offset as usize..(offset + len) as usize];
+                    range_frames(offset, values, start, end, &mut frames);
+                }
+            },
+        }
+        Ok(frames)
+    }
+}
+
+impl PhysicalExpr for WindowFrameExpr {
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        if df.is_empty() {
+            let field = self.phys_function.to_field(&df.schema())?;
+            return Ok(Series::full_null(field.name(), 0, field.data_type()));
+        }
+
+        let (groups, order_by) = self.partitions(df, state)?;
+        let mut perm = Vec::with_capacity(df.height());
+        let mut partitions = Vec::with_capacity(groups.len());
+        for g in groups.iter() {
+            let offset = perm.len() as IdxSize;
+            match g {
+                GroupsIndicator::Idx((_, idx)) => perm.extend_from_slice(idx),
+                GroupsIndicator::Slice([first, len]) => perm.extend(first..first + len),
+            }
+            partitions.push([offset, perm.len() as IdxSize - offset]);
+        }
+        let mut inverse = vec![0 as IdxSize; perm.len()];
+        for (i, &row) in perm.iter().enumerate() {
+            inverse[row as usize] = i as IdxSize;
+        }
+        let perm = IdxCa::from_vec("", perm);
+        let frames = self.frames(order_by.as_ref(), &perm, &partitions)?;
+
+        // Depending on the kernel, the aggregation of an empty frame is null or the identity of
+        // the aggregation, so they are nulled consistently.
+        let non_empty = frames.iter().any(|&[_, len]| len == 0).then(|| {
+            frames
+                .iter()
+                .map(|&[_, len]| len > 0)
+                .collect::<BooleanChunked>()
+        });
+
+        let df = if self.apply_columns.is_empty() {
+            df.clone()
+        } else {
+            df.select(self.apply_columns.iter().map(|name| name.as_ref()))?
+        };
+        // SAFETY: the permutation is in bounds.
+        let df = unsafe { df.take_unchecked(&perm) };
+        let groups = GroupsProxy::Slice {
+            groups: frames,
+            rolling: true,
+        };
+
+        let mut out = self
+            .phys_function
+            .evaluate_on_groups(&df, &groups, state)?
+            .finalize();
+        polars_ensure!(out.len() == groups.len(), agg_len = out.len(), groups.len());
+        if let Some(non_empty) = non_empty {
+            out = out.zip_with(
+                &non_empty,
+                &Series::full_null(out.name(), out.len(), out.dtype()),
+            )?;
+        }
+        // SAFETY: the inverse permutation is in bounds.
+        let mut out = unsafe { out.take_unchecked(&IdxCa::from_vec("", inverse)) };
+        if let Some(name) = &self.out_name {
+            out.rename(name.as_ref());
+        }
+        Ok(out)
+    }
+
+    fn evaluate_on_groups<'a>(
+        &self,
+        _df: &DataFrame,
+        _groups: &'a GroupsProxy,
+        _state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        polars_bail!(InvalidOperation: "window frame expression not allowed in aggregation");
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        self.function.to_field(input_schema, Context::Default)
+    }
+
+    fn as_expression(&self) -> Option<&Expr> {
+        Some(&self.expr)
+    }
+}
@@ -3,4 +3,6 @@ pub mod planner;
 pub mod prelude;
 pub mod state;
 
-pub use crate::planner::{create_physical_expr, ExpressionConversionState};
+pub use crate::planner::{
+    create_physical_expr, create_physical_filter_expr, ExpressionConversionState,
+};
@@ -94,6 +94,8 @@ pub struct ExpressionConversionState {
     // expressions
     pub allow_threading: bool,
     pub has_windows: bool,
+    // `parallel` hint of the enclosing expression, overrides `allow_threading`
+    parallel: Option<bool>,
     // settings per expression
     // those are reset every expression
     local: LocalConversionState,
@@ -127,6 +129,7 @@ impl ExpressionConversionState {
             depth_limit,
             allow_threading,
             has_windows: false,
+            parallel: None,
             local: LocalConversionState {
                 depth_limit,
                 ..Default::default()
@@ -201,6 +204,99 @@ pub fn create_physical_expr(
     }
 }
 
+/// Create the physical expression of the predicate of a filter.
+///
+/// The conjuncts of a predicate that `and`s row-wise predicates are evaluated in the order in
+/// which they remove the most rows at the lowest cost, as measured on the first rows that are
+/// filtered. Later conjuncts are only evaluated on the rows that the earlier ones kept.
+pub fn create_physical_filter_expr(
+    predicate: &ExprIR,
+    expr_arena: &Arena<AExpr>,
+    schema: Option<&SchemaRef>,
+    state: &mut ExpressionConversionState,
+) -> PolarsResult<Arc<dyn PhysicalExpr>> {
+    let phys_expr = create_physical_expr(predicate, Context::Default, expr_arena, schema, state)?;
+    let Some(schema) = schema else {
+        return Ok(phys_expr);
+    };
+
+    let mut conjuncts = vec![];
+    split_conjunction(predicate.node(), expr_arena, &mut conjuncts);
+    let columns = conjuncts
+        .iter()
+        .map(|&node| {
+            let mut names = aexpr_to_leaf_names(node, expr_arena);
+            names.sort_unstable();
+            names.dedup();
+            names
+        })
+        .collect::<Vec<_>>();
+    // Every conjunct must give a mask of the length of its input, such that it can be evaluated
+    // on a subset of the rows.
+    let adaptive = conjuncts.len() > 1
+        && conjuncts.iter().zip(&columns).all(|(&node, columns)| {
+            !columns.is_empty()
+                && is_row_separable(node, expr_arena)
+                && matches!(
+                    expr_arena
+                        .get(node)
+                        .to_dtype(schema, Context::Default, expr_arena),
+                    Ok(DataType::Boolean)
+                )
+        });
+    if !adaptive {
+        return Ok(phys_expr);
+    }
+
+    let inputs = conjuncts
+        .iter()
+        .map(|&node| {
+            create_physical_expr_inner(node, Context::Default, expr_arena, Some(schema), state)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(Arc::new(AdaptiveFilterExpr::new(ShortCircuitExpr {
+        kind: ShortCircuit::AllHorizontal,
+        separable: vec![true; inputs.len()],
+        inputs,
+        columns,
+        fallback: phys_expr,
+    })))
+}
+
+/// Collect the operands of the `and`s at the root of the expression.
+fn split_conjunction(node: Node, expr_arena: &Arena<AExpr>, out: &mut Vec<Node>) {
+    match expr_arena.get(node) {
+        AExpr::BinaryExpr {
+            left,
+            op: Operator::And | Operator::LogicalAnd,
+            right,
+        } => {
+            split_conjunction(*left, expr_arena, out);
+            split_conjunction(*right, expr_arena, out);
+        },
+        AExpr::Function {
+            input,
+            function: FunctionExpr::Boolean(BooleanFunction::AllHorizontal),
+            ..
+        } => input
+            .iter()
+            .for_each(|e| split_conjunction(e.node(), expr_arena, out)),
+        _ => out.push(node),
+    }
+}
+
+fn is_plugin_agg(agg: &IRAggExpr) -> bool {
+    #[cfg(feature = "ffi_plugin")]
+    {
+        matches!(agg, IRAggExpr::Plugin { .. })
+    }
+    #[cfg(not(feature = "ffi_plugin"))]
+    {
+        let _ = agg;
+        false
+    }
+}
+
 fn create_physical_expr_inner(
     expression: Node,
     ctxt: Context,
@@ -295,6 +391,29 @@ fn create_physical_expr_inner(
                         expr,
                     }))
                 },
+                WindowType::Frame(frame) => {
+                    let partition_by = create_physical_expressions_from_nodes(
+                        partition_by,
+                        Context::Default,
+                        expr_arena,
+                        schema,
+                        state,
+                    )?;
+                    let mut apply_columns = aexpr_to_leaf_names(function, expr_arena);
+                    apply_columns.sort();
+                    apply_columns.dedup();
+
+                    Ok(Arc::new(WindowFrameExpr {
+                        partition_by,
+                        order_by,
+                        frame: frame.clone(),
+                        apply_columns,
+                        out_name,
+                        function: function_expr,
+                        phys_function,
+                        expr,
+                    }))
+                },
                 #[cfg(feature = "dynamic_group_by")]
                 WindowType::Rolling(options) => Ok(Arc::new(RollingExpr {
                     function: function_expr,
@@ -321,6 +440,7 @@ fn create_physical_expr_inner(
                 rhs,
                 node_to_expr(expression, expr_arena),
                 state.local.has_lit,
+                state.parallel,
             )))
         },
         Column(column) => Ok(Arc::new(ColumnExpr::new(
@@ -380,10 +500,20 @@ fn create_physical_expr_inner(
             let input = create_physical_expr_inner(expr, ctxt, expr_arena, schema, state)?;
             polars_ensure!(!(state.has_implode() && matches!(ctxt, Context::Aggregation)), InvalidOperation: "'implode' followed by an aggregation is not allowed");
             state.local.has_implode |= matches!(agg, IRAggExpr::Implode(_));
-            let allow_threading = state.allow_threading;
+            let allow_threading = state.parallel.unwrap_or(state.allow_threading);
 
             match ctxt {
-                Context::Default if !matches!(agg, IRAggExpr::Quantile { .. }) => {
+                Context::Default
+                    if !matches!(
+                        agg,
+                        IRAggExpr::Quantile { .. }
+                            | IRAggExpr::ApproxQuantile { .. }
+                            | IRAggExpr::Quantiles { .. }
+                            | IRAggExpr::StrJoin { .. }
+                            | IRAggExpr::FirstBy { .. }
+                            | IRAggExpr::LastBy { .. }
+                    ) && !is_plugin_agg(agg) =>
+                {
                     use {GroupByMethod as GBM, IRAggExpr as I};
 
                     let groupby = match agg {
@@ -397,7 +527,14 @@ fn create_physical_expr_inner(
                         I::Last(_) => GBM::Last,
                         I::Mean(_) => GBM::Mean,
                         I::Implode(_) => GBM::Implode,
-                        I::Quantile { .. } => unreachable!(),
+                        I::Quantile { .. }
+                        | I::ApproxQuantile { .. }
+                        | I::Quantiles { .. }
+                        | I::StrJoin { .. }
+                        | I::FirstBy { .. }
+                        | I::LastBy { .. } => unreachable!(),
+                        #[cfg(feature = "ffi_plugin")]
+                        I::Plugin { .. } => unreachable!(),
                         I::Sum(_) => GBM::Sum,
                         I::Count(_, include_nulls) => GBM::Count {
                             include_nulls: *include_nulls,
@@ -405,7 +542,7 @@ fn create_physical_expr_inner(
                         I::Std(_, ddof) => GBM::Std(*ddof),
                         I::Var(_, ddof) => GBM::Var(*ddof),
                         I::AggGroups(_) => {
-                            panic!("agg groups expression only supported in aggregation context")
+                            polars_bail!(InvalidOperation: "agg groups expression only supported in aggregation context")
                         },
                     };
 
@@ -425,6 +562,48 @@ fn create_physical_expr_inner(
                             create_physical_expr_inner(*quantile, ctxt, expr_arena, schema, state)?;
                         return Ok(Arc::new(AggQuantileExpr::new(input, quantile, *interpol)));
                     }
+                    if let IRAggExpr::ApproxQuantile {
+                        quantile, accuracy, ..
+                    } = agg
+                    {
+                        #[cfg(feature = "approx_quantile")]
+                        {
+                            let quantile = create_physical_expr_inner(
+                                *quantile, ctxt, expr_arena, schema, state,
+                            )?;
+                            return Ok(Arc::new(AggApproxQuantileExpr::new(
+                                input, quantile, *accuracy,
+                            )));
+                        }
+                        #[cfg(not(feature = "approx_quantile"))]
+                        {
+                            let _ = (quantile, accuracy);
+                            panic!("activate 'approx_quantile' feature")
+                        }
+                    }
+                    if let IRAggExpr::Quantiles { options, .. } = agg {
+                        #[cfg(feature = "dtype-array")]
+                        {
+                            return Ok(Arc::new(AggQuantilesExpr::new(input, options.clone())));
+                        }
+                        #[cfg(not(feature = "dtype-array"))]
+                        {
+                            let _ = options;
+                            panic!("activate 'dtype-array' feature")
+                        }
+                    }
+                    if let IRAggExpr::StrJoin { options, .. } = agg {
+                        return Ok(Arc::new(AggStrJoinExpr::new(input, options.clone())));
+                    }
+                    if let IRAggExpr::FirstBy { by, .. } | IRAggExpr::LastBy { by, .. } = agg {
+                        let by = create_physical_expr_inner(*by, ctxt, expr_arena, schema, state)?;
+                        let last = matches!(agg, IRAggExpr::LastBy { .. });
+                        return Ok(Arc::new(AggFirstByExpr::new(input, by, last)));
+                    }
+                    #[cfg(feature = "ffi_plugin")]
+                    if let IRAggExpr::Plugin { function, .. } = agg {
+                        return Ok(Arc::new(AggPluginExpr::new(input, function.clone())?));
+                    }
 
                     let field = schema
                         .map(|schema| {
@@ -439,7 +618,7 @@ fn create_physical_expr_inner(
                     let groupby = GroupByMethod::from(agg.clone());
                     let agg_type = AggregationType {
                         groupby,
-                        allow_threading: false,
+                        allow_threading: state.parallel.unwrap_or(false),
                     };
                     Ok(Arc::new(AggregationExpr::new(input, agg_type, field)))
                 },
@@ -479,7 +658,7 @@ fn create_physical_expr_inner(
                 truthy,
                 falsy,
                 node_to_expr(expression, expr_arena),
-                lit_count < 2,
+                state.parallel.unwrap_or(lit_count < 2),
             )))
         },
         AnonymousFunction {
@@ -516,11 +695,22 @@ fn create_physical_expr_inner(
                 function.clone(),
                 node_to_expr(expression, expr_arena),
                 *options,
-                true,
+                state.parallel.unwrap_or(true),
                 schema.cloned(),
                 output_dtype,
             )))
         },
+        Function {
+            input,
+            function: FunctionExpr::Parallel(parallel),
+            ..
+        } => {
+            // The hint only affects how its input is evaluated.
+            let outer = std::mem::replace(&mut state.parallel, Some(*parallel));
+            let out = create_physical_expr_inner(input[0].node(), ctxt, expr_arena, schema, state);
+            state.parallel = outer;
+            out
+        },
         Function {
             input,
             function,
@@ -537,6 +727,34 @@ fn create_physical_expr_inner(
                 options.returns_scalar && matches!(options.collect_groups, ApplyOptions::GroupWise);
             // Will be reset in the function so get that here.
             let has_window = state.local.has_window;
+            // Horizontal folds that can stop evaluating their inputs once every row is decided.
+            let short_circuit = match function {
+                FunctionExpr::Boolean(BooleanFunction::AnyHorizontal) => {
+                    Some(ShortCircuit::AnyHorizontal)
+                },
+                FunctionExpr::Boolean(BooleanFunction::AllHorizontal) => {
+                    Some(ShortCircuit::AllHorizontal)
+                },
+                FunctionExpr::Coalesce => Some(ShortCircuit::Coalesce),
+                _ => None,
+            }
+            .filter(|_| input.len() > 1)
+            .map(|kind| {
+                let separable = input
+                    .iter()
+                    .map(|e| is_row_separable(e.node(), expr_arena))
+                    .collect::<Vec<_>>();
+                let columns = input
+                    .iter()
+                    .map(|e| {
+                        let mut names = aexpr_to_leaf_names(e.node(), expr_arena);
+                        names.sort_unstable();
+                        names.dedup();
+                        names
+                    })
+                    .collect::<Vec<_>>();
+                (kind, separable, columns)
+            });
             let input = create_physical_expressions_check_state(
                 input,
                 ctxt,
@@ -549,15 +767,33 @@ fn create_physical_expr_inner(
                 },
             )?;
 
-            Ok(Arc::new(ApplyExpr::new(
-                input,
+            let apply: Arc<dyn PhysicalExpr> = Arc::new(ApplyExpr::new(
+                input.clone(),
                 function.clone().into(),
                 node_to_expr(expression, expr_arena),
                 *options,
-                true,
+                state.parallel.unwrap_or(true),
                 schema.cloned(),
                 output_dtype,
-            )))
+            ));
+
+            if let FunctionExpr::OrElse = function {
+                return Ok(Arc::new(OrElseExpr {
+                    primary: input[0].clone(),
+                    fallback: input[1].clone(),
+                    apply,
+                }));
+            }
+            match short_circuit {
+                Some((kind, separable, columns)) => Ok(Arc::new(ShortCircuitExpr {
+                    kind,
+                    inputs: input,
+                    separable,
+                    columns,
+                    fallback: apply,
+                })),
+                None => Ok(apply),
+            }
         },
         Slice {
             input,
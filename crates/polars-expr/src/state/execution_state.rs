@@ -1,14 +1,17 @@
 use std::borrow::Cow;
-use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU8, Ordering};
+use std::sync::atomic::{AtomicI64, AtomicU8, Ordering};
 use std::sync::{Mutex, RwLock};
+use std::time::Instant;
 
 use bitflags::bitflags;
 use once_cell::sync::OnceCell;
 use polars_core::config::verbose;
 use polars_core::prelude::*;
 use polars_ops::prelude::ChunkJoinOptIds;
+use polars_utils::arena::Node;
+use polars_utils::cancel::CancellationToken;
 
-use super::NodeTimer;
+use super::{NodeStats, NodeStatsCollector, NodeTimer, ScanStats, ScanStatsCollector};
 
 pub type JoinTuplesCache = Arc<Mutex<PlHashMap<String, ChunkJoinOptIds>>>;
 pub type GroupsProxyCache = Arc<RwLock<PlHashMap<String, GroupsProxy>>>;
@@ -71,7 +74,11 @@ pub struct ExecutionState {
     pub flags: AtomicU8,
     pub ext_contexts: Arc<Vec<DataFrame>>,
     node_timer: Option<NodeTimer>,
-    stop: Arc<AtomicBool>,
+    scan_stats: Option<ScanStatsCollector>,
+    node_stats: Option<NodeStatsCollector>,
+    /// The plan node that is executing, set by the executors that collect node stats.
+    pub current_node: Option<Node>,
+    cancel_token: CancellationToken,
 }
 
 impl ExecutionState {
@@ -89,7 +96,10 @@ impl ExecutionState {
             flags: AtomicU8::new(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
-            stop: Arc::new(AtomicBool::new(false)),
+            scan_stats: None,
+            node_stats: None,
+            current_node: None,
+            cancel_token: CancellationToken::current().unwrap_or_default(),
         }
     }
 
@@ -97,31 +107,78 @@ impl ExecutionState {
     pub fn time_nodes(&mut self) {
         self.node_timer = Some(NodeTimer::new())
     }
+    /// Whether the executors should give their nodes a name in [`ExecutionState::record`].
     pub fn has_node_timer(&self) -> bool {
-        self.node_timer.is_some()
+        self.node_timer.is_some() || self.node_stats.is_some()
     }
 
     pub fn finish_timer(self) -> PolarsResult<DataFrame> {
         self.node_timer.unwrap().finish()
     }
 
-    // This is wrong when the U64 overflows which will never happen.
+    /// Toggle this to let the scans report what they read and pruned.
+    pub fn collect_scan_stats(&mut self) {
+        self.scan_stats = Some(Default::default())
+    }
+    pub fn has_scan_stats(&self) -> bool {
+        self.scan_stats.is_some()
+    }
+
+    pub fn record_scan_stats(&self, stats: ScanStats) {
+        if let Some(collector) = &self.scan_stats {
+            collector.store(stats)
+        }
+    }
+
+    pub fn finish_scan_stats(&self) -> PolarsResult<DataFrame> {
+        self.scan_stats.as_ref().unwrap().finish()
+    }
+
+    /// Toggle this to gather the rows and time of every plan node, see
+    /// [`ExecutionState::record_node_stats`].
+    pub fn collect_node_stats(&mut self) {
+        self.node_stats = Some(Default::default())
+    }
+
+    pub fn record_node_stats(
+        &self,
+        node: Node,
+        rows: usize,
+        start: Instant,
+        end: Instant,
+        peak_memory: Option<usize>,
+    ) {
+        if let Some(collector) = &self.node_stats {
+            collector.store(node, rows, start, end, peak_memory)
+        }
+    }
+
+    pub fn finish_node_stats(&self) -> PlHashMap<Node, NodeStats> {
+        self.node_stats.as_ref().unwrap().finish()
+    }
+
+    /// Returns a `Cancelled` error if the query was cancelled. Long-running operators call
+    /// this at chunk boundaries.
     pub fn should_stop(&self) -> PolarsResult<()> {
-        polars_ensure!(!self.stop.load(Ordering::Relaxed), ComputeError: "query interrupted");
-        Ok(())
+        self.cancel_token.check()
     }
 
-    pub fn cancel_token(&self) -> Arc<AtomicBool> {
-        self.stop.clone()
+    /// The token that cancels this query. It is the token installed by
+    /// [`CancellationToken::run`] when the state was created, or a fresh one otherwise.
+    pub fn cancel_token(&self) -> CancellationToken {
+        self.cancel_token.clone()
     }
 
     pub fn record<T, F: FnOnce() -> T>(&self, func: F, name: Cow<'static, str>) -> T {
+        if let (Some(collector), Some(node)) = (&self.node_stats, self.current_node) {
+            collector.store_name(node, name.as_ref());
+        }
         match &self.node_timer {
             None => func(),
             Some(timer) => {
-                let start = std::time::Instant::now();
+                let start = Instant::now();
                 let out = func();
-                let end = std::time::Instant::now();
+                let end = Instant::now();
 
                 timer.store(start, end, name.as_ref().to_string());
                 out
@@ -141,7 +198,10 @@ impl ExecutionState {
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
-            stop: self.stop.clone(),
+            scan_stats: self.scan_stats.clone(),
+            node_stats: self.node_stats.clone(),
+            current_node: self.current_node,
+            cancel_token: self.cancel_token.clone(),
         }
     }
 
@@ -263,7 +323,10 @@ impl Clone for ExecutionState {
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
-            stop: self.stop.clone(),
+            scan_stats: self.scan_stats.clone(),
+            node_stats: self.node_stats.clone(),
+            current_node: self.current_node,
+            cancel_token: self.cancel_token.clone(),
         }
     }
 }
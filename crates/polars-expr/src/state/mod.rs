@@ -1,5 +1,11 @@
 mod execution_state;
+mod node_stats;
 mod node_timer;
+mod scan_stats;
 
 pub use execution_state::*;
+pub use node_stats::NodeStats;
+use node_stats::NodeStatsCollector;
 use node_timer::*;
+pub use scan_stats::ScanStats;
+use scan_stats::ScanStatsCollector;
@@ -0,0 +1,64 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use polars_core::prelude::*;
+use polars_utils::arena::Node;
+
+/// What the executor of a plan node produced, summed over its executions.
+#[derive(Clone, Debug, Default)]
+pub struct NodeStats {
+    pub rows: usize,
+    pub executions: usize,
+    /// Wall time spent in the node, including the time spent in its inputs.
+    pub time: Duration,
+    /// When the first execution started.
+    pub start: Option<Instant>,
+    /// When the last execution ended.
+    pub end: Option<Instant>,
+    /// The name the executor gave to the node, see [`ExecutionState::record`].
+    ///
+    /// [`ExecutionState::record`]: super::ExecutionState::record
+    pub name: Option<String>,
+    /// The highest increase of the memory in use during an execution of the node, if the
+    /// allocations are counted.
+    pub peak_memory: Option<usize>,
+}
+
+#[derive(Clone, Default)]
+pub(super) struct NodeStatsCollector {
+    data: Arc<Mutex<PlHashMap<Node, NodeStats>>>,
+}
+
+impl NodeStatsCollector {
+    pub(super) fn store(
+        &self,
+        node: Node,
+        rows: usize,
+        start: Instant,
+        end: Instant,
+        peak_memory: Option<usize>,
+    ) {
+        let mut data = self.data.lock().unwrap();
+        let stats = data.entry(node).or_default();
+        stats.rows += rows;
+        stats.executions += 1;
+        stats.time += end.duration_since(start);
+        stats.start = Some(stats.start.map_or(start, |s| s.min(start)));
+        stats.end = Some(stats.end.map_or(end, |e| e.max(end)));
+        if let Some(peak) = peak_memory {
+            stats.peak_memory = Some(stats.peak_memory.map_or(peak, |p| p.max(peak)));
+        }
+    }
+
+    pub(super) fn store_name(&self, node: Node, name: &str) {
+        let mut data = self.data.lock().unwrap();
+        data.entry(node)
+            .or_default()
+            .name
+            .get_or_insert_with(|| name.to_string());
+    }
+
+    pub(super) fn finish(&self) -> PlHashMap<Node, NodeStats> {
+        std::mem::take(&mut self.data.lock().unwrap())
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+
+/// What a single file scan read and what it could skip.
+#[derive(Clone, Debug, Default)]
+pub struct ScanStats {
+    /// The first file of the scan.
+    pub source: String,
+    /// Files of the scan before partition pruning.
+    pub files_listed: usize,
+    pub files_read: usize,
+    pub row_groups_read: usize,
+    /// Row groups skipped because of their statistics.
+    pub row_groups_pruned: usize,
+    pub bytes_read: u64,
+    /// The predicate used for pruning, if any.
+    pub predicate: Option<String>,
+}
+
+#[derive(Clone, Default)]
+pub(super) struct ScanStatsCollector {
+    data: Arc<Mutex<Vec<ScanStats>>>,
+}
+
+impl ScanStatsCollector {
+    pub(super) fn store(&self, stats: ScanStats) {
+        self.data.lock().unwrap().push(stats)
+    }
+
+    pub(super) fn finish(&self) -> PolarsResult<DataFrame> {
+        let data = self.data.lock().unwrap();
+        let count =
+            |f: fn(&ScanStats) -> usize| data.iter().map(|s| f(s) as u64).collect::<Vec<_>>();
+
+        DataFrame::new(vec![
+            Series::new(
+                "source",
+                data.iter().map(|s| s.source.as_str()).collect::<Vec<_>>(),
+            ),
+            Series::new("files_listed", count(|s| s.files_listed)),
+            Series::new("files_read", count(|s| s.files_read)),
+            Series::new("row_groups_read", count(|s| s.row_groups_read)),
+            Series::new("row_groups_pruned", count(|s| s.row_groups_pruned)),
+            Series::new(
+                "bytes_read",
+                data.iter().map(|s| s.bytes_read).collect::<Vec<_>>(),
+            ),
+            Series::new(
+                "predicate",
+                data.iter()
+                    .map(|s| s.predicate.as_deref())
+                    .collect::<Vec<_>>(),
+            ),
+        ])
+    }
+}
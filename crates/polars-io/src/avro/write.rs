@@ -64,9 +64,14 @@ where
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let mut df = to_avro_compatible(df)?;
+        let df = &mut df;
         let schema = schema_to_arrow_checked(&df.schema(), false, "avro")?;
         let record = write::to_record(&schema, self.name.clone())?;
 
+        avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
+            .map_err(to_compute_err)?;
+
         let mut data = vec![];
         let mut compressed_block = avro_schema::file::CompressedBlock::default();
         for chunk in df.iter_chunks(false, true) {
@@ -83,9 +88,6 @@ where
                 avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
                     .map_err(to_compute_err)?;
 
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(to_compute_err)?;
-
             avro_schema::write::write_block(&mut self.writer, &compressed_block)
                 .map_err(to_compute_err)?;
             // reuse block for next iteration.
@@ -100,3 +102,42 @@ where
         Ok(())
     }
 }
+
+/// Avro has no unsigned or small integers, no nanosecond timestamps and no categoricals, so
+/// these are cast to the closest type that Avro can represent.
+fn to_avro_dtype(dtype: &DataType) -> DataType {
+    match dtype {
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 | DataType::UInt16 => DataType::Int32,
+        DataType::UInt32 => DataType::Int64,
+        DataType::Datetime(TimeUnit::Nanoseconds, tz) => {
+            DataType::Datetime(TimeUnit::Microseconds, tz.clone())
+        },
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => DataType::String,
+        DataType::List(inner) => DataType::List(Box::new(to_avro_dtype(inner))),
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|field| Field::new(field.name(), to_avro_dtype(field.data_type())))
+                .collect(),
+        ),
+        dtype => dtype.clone(),
+    }
+}
+
+fn to_avro_compatible(df: &DataFrame) -> PolarsResult<DataFrame> {
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|s| {
+            let dtype = to_avro_dtype(s.dtype());
+            if &dtype == s.dtype() {
+                Ok(s.clone())
+            } else {
+                s.cast(&dtype)
+            }
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    DataFrame::new(columns)
+}
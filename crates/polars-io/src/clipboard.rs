@@ -0,0 +1,90 @@
+//! # Clipboard
+//!
+//! Read and write DataFrames as CSV text on the clipboard of the desktop, for interactive
+//! sessions in e.g. `evcxr`.
+//!
+//! Spreadsheets copy cells as tab separated values, so a tab is the separator used by default.
+use std::io::Cursor;
+
+use polars_core::prelude::*;
+
+use crate::csv::read::CsvReadOptions;
+use crate::csv::write::CsvWriter;
+use crate::{SerReader, SerWriter};
+
+fn clipboard() -> PolarsResult<arboard::Clipboard> {
+    arboard::Clipboard::new()
+        .map_err(|e| polars_err!(ComputeError: "cannot access the clipboard: {}", e))
+}
+
+/// Read the text on the clipboard.
+pub fn read_clipboard_string() -> PolarsResult<String> {
+    clipboard()?
+        .get_text()
+        .map_err(|e| polars_err!(ComputeError: "cannot read the clipboard: {}", e))
+}
+
+/// Replace the contents of the clipboard with `text`.
+pub fn write_clipboard_string(text: &str) -> PolarsResult<()> {
+    clipboard()?
+        .set_text(text)
+        .map_err(|e| polars_err!(ComputeError: "cannot write to the clipboard: {}", e))
+}
+
+/// The options [`read_clipboard`] parses the clipboard with: those of a CSV file, with a tab as
+/// separator.
+pub fn clipboard_read_options() -> CsvReadOptions {
+    CsvReadOptions::default().map_parse_options(|options| options.with_separator(b'\t'))
+}
+
+/// Read a [`DataFrame`] from the text on the clipboard, parsed as CSV with `options`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use polars_io::clipboard::{clipboard_read_options, read_clipboard};
+///
+/// // Cells copied from a spreadsheet.
+/// let df = read_clipboard(clipboard_read_options())?;
+/// // Comma separated text.
+/// let df = read_clipboard(CsvReadOptions::default())?;
+/// ```
+pub fn read_clipboard(options: CsvReadOptions) -> PolarsResult<DataFrame> {
+    parse_text(read_clipboard_string()?, options)
+}
+
+/// Write `df` to the clipboard as CSV, with a header and `separator` between the fields.
+pub fn write_clipboard(df: &mut DataFrame, separator: u8) -> PolarsResult<()> {
+    write_clipboard_string(&format_text(df, separator)?)
+}
+
+fn parse_text(text: String, options: CsvReadOptions) -> PolarsResult<DataFrame> {
+    options
+        .into_reader_with_file_handle(Cursor::new(text))
+        .finish()
+}
+
+fn format_text(df: &mut DataFrame, separator: u8) -> PolarsResult<String> {
+    let mut buf = vec![];
+    CsvWriter::new(&mut buf)
+        .with_separator(separator)
+        .finish(df)?;
+    // The writer only writes valid UTF-8.
+    Ok(String::from_utf8(buf).unwrap())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_clipboard_text_round_trip() -> PolarsResult<()> {
+        let mut df = df!["a" => [1i64, 2], "b" => ["x", "y z"]]?;
+        let text = format_text(&mut df, b'\t')?;
+        assert_eq!(text, "a\tb\n1\tx\n2\ty z\n");
+
+        let out = parse_text(text, clipboard_read_options())?;
+        assert!(out.equals(&df));
+        Ok(())
+    }
+}
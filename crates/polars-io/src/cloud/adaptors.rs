@@ -2,21 +2,72 @@
 
 use std::sync::Arc;
 
+use futures::StreamExt;
 use object_store::path::Path;
-use object_store::{MultipartUpload, ObjectStore, PutPayload};
-use polars_error::{to_compute_err, PolarsResult};
+use object_store::{ObjectStore, WriteMultipart};
+use polars_core::config::verbose;
+use polars_error::{polars_bail, PolarsResult};
+use sha2::Digest;
 
-use super::CloudOptions;
+use super::{CloudOptions, UploadChecksum, UploadOptions};
 use crate::pl_async::get_runtime;
 
+/// Running checksum of the bytes that are written.
+enum Hasher {
+    Crc32c(u32),
+    Sha256(sha2::Sha256),
+}
+
+impl Hasher {
+    fn new(checksum: UploadChecksum) -> Self {
+        match checksum {
+            UploadChecksum::Crc32c => Self::Crc32c(0),
+            UploadChecksum::Sha256 => Self::Sha256(sha2::Sha256::new()),
+        }
+    }
+
+    fn kind(&self) -> UploadChecksum {
+        match self {
+            Self::Crc32c(_) => UploadChecksum::Crc32c,
+            Self::Sha256(_) => UploadChecksum::Sha256,
+        }
+    }
+
+    fn update(&mut self, buf: &[u8]) {
+        match self {
+            Self::Crc32c(crc) => *crc = crc32c::crc32c_append(*crc, buf),
+            Self::Sha256(hasher) => hasher.update(buf),
+        }
+    }
+
+    /// The hex encoded checksum.
+    fn finish(&self) -> String {
+        match self {
+            Self::Crc32c(crc) => hex::encode(crc.to_be_bytes()),
+            Self::Sha256(hasher) => hex::encode(hasher.clone().finalize()),
+        }
+    }
+}
+
 /// Adaptor which wraps the asynchronous interface of [ObjectStore::put_multipart](https://docs.rs/object_store/latest/object_store/trait.ObjectStore.html#tymethod.put_multipart)
 /// exposing a synchronous interface which implements `std::io::Write`.
 ///
 /// This allows it to be used in sync code which would otherwise write to a simple File or byte stream,
 /// such as with `polars::prelude::CsvWriter`.
+///
+/// The written bytes are buffered into parts of [`UploadOptions::part_size`], of which at most
+/// [`UploadOptions::max_concurrency`] are uploaded at the same time. The upload is completed by
+/// `flush`, or when the writer is dropped. Only `flush` reports errors, and verifies the
+/// [`UploadOptions::checksum`] of the uploaded object.
 pub struct CloudWriter {
-    // Internal writer, constructed at creation
-    writer: Box<dyn MultipartUpload>,
+    object_store: Arc<dyn ObjectStore>,
+    path: Path,
+    // Internal writer, constructed at creation and taken when the upload is completed.
+    writer: Option<WriteMultipart>,
+    max_concurrency: usize,
+    hasher: Option<Hasher>,
+    size: usize,
+    checksum: Option<String>,
 }
 
 impl CloudWriter {
@@ -29,8 +80,26 @@ impl CloudWriter {
         object_store: Arc<dyn ObjectStore>,
         path: Path,
     ) -> PolarsResult<Self> {
-        let writer = object_store.put_multipart(&path).await?;
-        Ok(CloudWriter { writer })
+        Self::new_with_upload_options(object_store, path, &UploadOptions::default()).await
+    }
+
+    /// Construct a new CloudWriter that uploads with the given [`UploadOptions`].
+    pub async fn new_with_upload_options(
+        object_store: Arc<dyn ObjectStore>,
+        path: Path,
+        upload_options: &UploadOptions,
+    ) -> PolarsResult<Self> {
+        let upload = object_store.put_multipart(&path).await?;
+        let writer = WriteMultipart::new_with_chunk_size(upload, upload_options.part_size);
+        Ok(CloudWriter {
+            object_store,
+            path,
+            writer: Some(writer),
+            max_concurrency: upload_options.max_concurrency.max(1),
+            hasher: upload_options.checksum.map(Hasher::new),
+            size: 0,
+            checksum: None,
+        })
     }
 
     /// Constructs a new CloudWriter from a path and an optional set of CloudOptions.
@@ -40,43 +109,92 @@ impl CloudWriter {
     pub async fn new(uri: &str, cloud_options: Option<&CloudOptions>) -> PolarsResult<Self> {
         let (cloud_location, object_store) =
             crate::cloud::build_object_store(uri, cloud_options).await?;
-        Self::new_with_object_store(object_store, cloud_location.prefix.into()).await
+        let upload_options = cloud_options.map(|o| o.upload.clone()).unwrap_or_default();
+        Self::new_with_upload_options(object_store, cloud_location.prefix.into(), &upload_options)
+            .await
+    }
+
+    /// The hex encoded checksum of the uploaded object, available once the upload is completed
+    /// with a checksum configured. CRC32C checksums are encoded big-endian.
+    pub fn checksum(&self) -> Option<&str> {
+        self.checksum.as_deref()
+    }
+
+    async fn write_part(&mut self, buf: &[u8]) -> PolarsResult<()> {
+        let Some(writer) = self.writer.as_mut() else {
+            polars_bail!(ComputeError: "cannot write to a completed upload of '{}'", self.path)
+        };
+        if let Err(e) = writer.wait_for_capacity(self.max_concurrency).await {
+            let _ = self.writer.take().unwrap().abort().await;
+            return Err(e.into());
+        }
+        writer.write(buf);
+        if let Some(hasher) = self.hasher.as_mut() {
+            hasher.update(buf);
+        }
+        self.size += buf.len();
+        Ok(())
+    }
+
+    async fn complete(&mut self) -> PolarsResult<()> {
+        let Some(writer) = self.writer.take() else {
+            return Ok(());
+        };
+        writer.finish().await?;
+        if let Some(hasher) = &self.hasher {
+            let checksum = hasher.finish();
+            self.verify(hasher.kind(), &checksum).await?;
+            if verbose() {
+                eprintln!("uploaded {} with checksum {}", self.path, checksum);
+            }
+            self.checksum = Some(checksum);
+        }
+        Ok(())
     }
 
-    async fn abort(&mut self) -> PolarsResult<()> {
-        self.writer.abort().await.map_err(to_compute_err)
+    /// Read back the uploaded object and compare it to what was written, the object is deleted
+    /// if it doesn't match.
+    async fn verify(&self, kind: UploadChecksum, checksum: &str) -> PolarsResult<()> {
+        let mut stream = self.object_store.get(&self.path).await?.into_stream();
+        let mut hasher = Hasher::new(kind);
+        let mut size = 0;
+        while let Some(bytes) = stream.next().await {
+            let bytes = bytes?;
+            hasher.update(&bytes);
+            size += bytes.len();
+        }
+
+        let uploaded = hasher.finish();
+        if size != self.size || uploaded != checksum {
+            let _ = self.object_store.delete(&self.path).await;
+            polars_bail!(
+                ComputeError: "checksum mismatch after uploading '{}': wrote {} bytes with checksum {}, \
+                but the store has {} bytes with checksum {}; the object was deleted",
+                self.path, self.size, checksum, size, uploaded
+            )
+        }
+        Ok(())
     }
 }
 
 impl std::io::Write for CloudWriter {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        // SAFETY:
-        // We extend the lifetime for the duration of this function. This is safe as well block the
-        // async runtime here
-        let buf = unsafe { std::mem::transmute::<&[u8], &'static [u8]>(buf) };
-        get_runtime().block_on(async {
-            let res = self.writer.put_part(PutPayload::from_static(buf)).await;
-            if res.is_err() {
-                let _ = self.abort().await;
-            }
-            Ok(buf.len())
-        })
+        get_runtime()
+            .block_on(self.write_part(buf))
+            .map_err(std::io::Error::other)?;
+        Ok(buf.len())
     }
 
     fn flush(&mut self) -> std::io::Result<()> {
-        get_runtime().block_on(async {
-            let res = self.writer.complete().await;
-            if res.is_err() {
-                let _ = self.abort().await;
-            }
-            Ok(())
-        })
+        get_runtime()
+            .block_on(self.complete())
+            .map_err(std::io::Error::other)
     }
 }
 
 impl Drop for CloudWriter {
     fn drop(&mut self) {
-        let _ = get_runtime().block_on(self.writer.complete());
+        let _ = get_runtime().block_on(self.complete());
     }
 }
 
@@ -118,6 +236,47 @@ mod tests {
             .expect("Could not write DataFrame as CSV to remote location");
     }
 
+    #[test]
+    fn cloudwriter_upload_checksum() {
+        use std::io::Write;
+
+        let dir = tempfile::tempdir().unwrap();
+        let object_store: Arc<dyn ObjectStore> = Arc::new(
+            object_store::local::LocalFileSystem::new_with_prefix(dir.path())
+                .expect("Could not initialize connection"),
+        );
+
+        for (checksum, expected) in [
+            (UploadChecksum::Crc32c, "e3069283"),
+            (
+                UploadChecksum::Sha256,
+                "15e2b0d3c33891ebb0f1ef609ec419420c20e320ce94c65fbc8c3312448eb225",
+            ),
+        ] {
+            let upload_options = UploadOptions {
+                part_size: 5 * 1024 * 1024,
+                max_concurrency: 2,
+                checksum: Some(checksum),
+            };
+            let mut cloud_writer = get_runtime()
+                .block_on(CloudWriter::new_with_upload_options(
+                    object_store.clone(),
+                    "checksum.txt".into(),
+                    &upload_options,
+                ))
+                .unwrap();
+            cloud_writer.write_all(b"1234").unwrap();
+            cloud_writer.write_all(b"56789").unwrap();
+            cloud_writer.flush().unwrap();
+            assert_eq!(cloud_writer.checksum(), Some(expected));
+            assert!(cloud_writer.write_all(b"0").is_err());
+            assert_eq!(
+                std::fs::read(dir.path().join("checksum.txt")).unwrap(),
+                b"123456789"
+            );
+        }
+    }
+
     // Skip this tests on Windows since it does not have a convenient /tmp/ location.
     #[cfg_attr(target_os = "windows", ignore)]
     #[test]
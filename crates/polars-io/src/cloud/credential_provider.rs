@@ -0,0 +1,358 @@
+//! User supplied credential providers.
+//!
+//! Static keys in [`CloudOptions`](super::CloudOptions) can expire during long running scans.
+//! A [`CredentialProvider`] is called whenever the object store needs credentials and the
+//! previously returned ones are (about to be) expired, so that short-lived tokens (e.g. from an
+//! AWS STS assume-role or a GCP/Azure OAuth flow) can be refreshed transparently.
+//!
+//! Providers are either set on the [`CloudOptions`](super::CloudOptions) of a scan, or
+//! registered once for a cloud or a single bucket with [`register_credential_provider`], in
+//! which case they are used for every scan that does not set its own provider.
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+#[cfg(feature = "aws")]
+use object_store::aws::AwsCredential;
+#[cfg(feature = "azure")]
+use object_store::azure::AzureCredential;
+#[cfg(feature = "gcp")]
+use object_store::gcp::GcpCredential;
+use once_cell::sync::Lazy;
+use polars_error::{polars_bail, PolarsResult};
+use polars_utils::aliases::PlHashMap;
+use tokio::sync::Mutex;
+
+use super::CloudType;
+
+/// Credentials are refreshed this many seconds before they expire, so that requests that are
+/// in flight don't fail.
+const EXPIRY_MARGIN_SECONDS: u64 = 60;
+
+/// Credentials returned by a [`CredentialProvider`].
+#[derive(Clone, PartialEq, Eq)]
+pub enum ObjectStoreCredential {
+    Aws {
+        key_id: String,
+        secret_key: String,
+        session_token: Option<String>,
+    },
+    /// An OAuth bearer token, used by GCP and Azure.
+    Bearer(String),
+}
+
+impl Debug for ObjectStoreCredential {
+    // Never print the secrets.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Aws { .. } => f.write_str("ObjectStoreCredential::Aws(..)"),
+            Self::Bearer(_) => f.write_str("ObjectStoreCredential::Bearer(..)"),
+        }
+    }
+}
+
+/// Credentials together with the moment they expire, in seconds since the unix epoch. `None`
+/// means the credentials never expire.
+#[derive(Clone, Debug)]
+pub struct FetchedCredential {
+    pub credential: ObjectStoreCredential,
+    pub expiry: Option<u64>,
+}
+
+/// Provides (and refreshes) the credentials used to connect to a cloud provider.
+///
+/// [`CredentialProvider::fetch_credential`] may block, it is called on a blocking thread.
+pub trait CredentialProvider: Send + Sync {
+    fn fetch_credential(&self) -> PolarsResult<FetchedCredential>;
+}
+
+impl<F> CredentialProvider for F
+where
+    F: Fn() -> PolarsResult<FetchedCredential> + Send + Sync,
+{
+    fn fetch_credential(&self) -> PolarsResult<FetchedCredential> {
+        self()
+    }
+}
+
+/// A shared [`CredentialProvider`] as stored in the cloud options. Equality and hashing are
+/// by identity, as the provider itself is opaque.
+#[derive(Clone)]
+pub struct PlCredentialProvider(Arc<dyn CredentialProvider>);
+
+impl PlCredentialProvider {
+    pub fn new(provider: Arc<dyn CredentialProvider>) -> Self {
+        Self(provider)
+    }
+
+    pub fn from_func(
+        func: impl Fn() -> PolarsResult<FetchedCredential> + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(func))
+    }
+
+    /// An identifier that is unique for the lifetime of this provider.
+    pub(super) fn id(&self) -> usize {
+        Arc::as_ptr(&self.0) as *const () as usize
+    }
+
+    #[cfg(feature = "aws")]
+    pub(super) fn into_aws_provider(self) -> object_store::aws::AwsCredentialProvider {
+        Arc::new(CachedCredentialProvider::new(
+            self,
+            |credential| match credential {
+                ObjectStoreCredential::Aws {
+                    key_id,
+                    secret_key,
+                    session_token,
+                } => Ok(AwsCredential {
+                    key_id,
+                    secret_key,
+                    token: session_token,
+                }),
+                _ => {
+                    polars_bail!(ComputeError: "credential provider returned non-AWS credentials for an AWS store")
+                },
+            },
+        ))
+    }
+
+    #[cfg(feature = "gcp")]
+    pub(super) fn into_gcp_provider(self) -> object_store::gcp::GcpCredentialProvider {
+        Arc::new(CachedCredentialProvider::new(
+            self,
+            |credential| match credential {
+                ObjectStoreCredential::Bearer(bearer) => Ok(GcpCredential { bearer }),
+                _ => {
+                    polars_bail!(ComputeError: "credential provider must return a bearer token for a GCP store")
+                },
+            },
+        ))
+    }
+
+    #[cfg(feature = "azure")]
+    pub(super) fn into_azure_provider(self) -> object_store::azure::AzureCredentialProvider {
+        Arc::new(CachedCredentialProvider::new(
+            self,
+            |credential| match credential {
+                ObjectStoreCredential::Bearer(token) => Ok(AzureCredential::BearerToken(token)),
+                _ => {
+                    polars_bail!(ComputeError: "credential provider must return a bearer token for an Azure store")
+                },
+            },
+        ))
+    }
+}
+
+impl Debug for PlCredentialProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PlCredentialProvider({:#x})", self.id())
+    }
+}
+
+impl PartialEq for PlCredentialProvider {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for PlCredentialProvider {}
+
+impl Hash for PlCredentialProvider {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id().hash(state)
+    }
+}
+
+/// Providers registered per cloud (bucket `None`) or per bucket.
+#[allow(clippy::type_complexity)]
+static REGISTERED_PROVIDERS: Lazy<
+    RwLock<PlHashMap<(CloudType, Option<String>), PlCredentialProvider>>,
+> = Lazy::new(Default::default);
+
+/// Register a credential provider for all buckets of `cloud_type`, or for a single bucket (or
+/// Azure container). It is used by scans whose [`CloudOptions`](super::CloudOptions) don't set
+/// a provider; a provider registered for the bucket takes precedence over one for the cloud.
+///
+/// Returns the provider that was registered before, if any. Object stores that were already
+/// built keep the provider they were built with.
+pub fn register_credential_provider(
+    cloud_type: CloudType,
+    bucket: Option<&str>,
+    provider: PlCredentialProvider,
+) -> Option<PlCredentialProvider> {
+    REGISTERED_PROVIDERS
+        .write()
+        .unwrap()
+        .insert((cloud_type, bucket.map(String::from)), provider)
+}
+
+/// Remove a provider registered with [`register_credential_provider`] and return it.
+pub fn unregister_credential_provider(
+    cloud_type: CloudType,
+    bucket: Option<&str>,
+) -> Option<PlCredentialProvider> {
+    REGISTERED_PROVIDERS
+        .write()
+        .unwrap()
+        .remove(&(cloud_type, bucket.map(String::from)))
+}
+
+/// The registered provider for `bucket`, falling back to the one for the whole cloud.
+pub(super) fn registered_credential_provider(
+    cloud_type: CloudType,
+    bucket: &str,
+) -> Option<PlCredentialProvider> {
+    let providers = REGISTERED_PROVIDERS.read().unwrap();
+    providers
+        .get(&(cloud_type, Some(bucket.to_string())))
+        .or_else(|| providers.get(&(cloud_type, None)))
+        .cloned()
+}
+
+fn now_unix_seconds() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn needs_refresh(expiry: Option<u64>, now: u64) -> bool {
+    expiry.map_or(false, |expiry| {
+        expiry.saturating_sub(EXPIRY_MARGIN_SECONDS) <= now
+    })
+}
+
+/// Adapts a [`PlCredentialProvider`] to the [`object_store::CredentialProvider`] of a single
+/// cloud, caching the credentials until they expire.
+struct CachedCredentialProvider<C> {
+    provider: PlCredentialProvider,
+    convert: fn(ObjectStoreCredential) -> PolarsResult<C>,
+    cache: Mutex<Option<(Arc<C>, Option<u64>)>>,
+}
+
+impl<C> CachedCredentialProvider<C> {
+    fn new(
+        provider: PlCredentialProvider,
+        convert: fn(ObjectStoreCredential) -> PolarsResult<C>,
+    ) -> Self {
+        Self {
+            provider,
+            convert,
+            cache: Mutex::new(None),
+        }
+    }
+}
+
+impl<C> Debug for CachedCredentialProvider<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CachedCredentialProvider({:?})", self.provider)
+    }
+}
+
+fn to_object_store_err(err: polars_error::PolarsError) -> object_store::Error {
+    object_store::Error::Generic {
+        store: "credential provider",
+        source: Box::new(err),
+    }
+}
+
+#[async_trait]
+impl<C: Send + Sync + 'static> object_store::CredentialProvider for CachedCredentialProvider<C> {
+    type Credential = C;
+
+    async fn get_credential(&self) -> object_store::Result<Arc<C>> {
+        // Hold the lock while fetching, so that concurrent requests don't all refresh.
+        let mut cache = self.cache.lock().await;
+        if let Some((credential, expiry)) = cache.as_ref() {
+            if !needs_refresh(*expiry, now_unix_seconds()) {
+                return Ok(credential.clone());
+            }
+        }
+
+        let provider = self.provider.clone();
+        let fetched = tokio::task::spawn_blocking(move || provider.0.fetch_credential())
+            .await
+            .map_err(|err| object_store::Error::Generic {
+                store: "credential provider",
+                source: Box::new(err),
+            })?
+            .map_err(to_object_store_err)?;
+
+        let credential = Arc::new((self.convert)(fetched.credential).map_err(to_object_store_err)?);
+        *cache = Some((credential.clone(), fetched.expiry));
+        Ok(credential)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_needs_refresh() {
+        assert!(!needs_refresh(None, 1_000));
+        assert!(!needs_refresh(
+            Some(1_000 + EXPIRY_MARGIN_SECONDS + 1),
+            1_000
+        ));
+        assert!(needs_refresh(Some(1_000 + EXPIRY_MARGIN_SECONDS), 1_000));
+        assert!(needs_refresh(Some(10), 1_000));
+    }
+
+    #[test]
+    fn test_provider_identity() {
+        let func = || {
+            Ok(FetchedCredential {
+                credential: ObjectStoreCredential::Bearer("token".into()),
+                expiry: None,
+            })
+        };
+        let a = PlCredentialProvider::from_func(func);
+        let b = PlCredentialProvider::from_func(func);
+        assert_eq!(a, a.clone());
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_registered_providers() {
+        let func = || {
+            Ok(FetchedCredential {
+                credential: ObjectStoreCredential::Bearer("token".into()),
+                expiry: None,
+            })
+        };
+        let cloud = PlCredentialProvider::from_func(func);
+        let bucket = PlCredentialProvider::from_func(func);
+        register_credential_provider(CloudType::Gcp, None, cloud.clone());
+        register_credential_provider(CloudType::Gcp, Some("bucket"), bucket.clone());
+
+        assert_eq!(
+            registered_credential_provider(CloudType::Gcp, "bucket"),
+            Some(bucket.clone())
+        );
+        assert_eq!(
+            registered_credential_provider(CloudType::Gcp, "other"),
+            Some(cloud.clone())
+        );
+        assert_eq!(
+            registered_credential_provider(CloudType::Azure, "bucket"),
+            None
+        );
+
+        assert_eq!(
+            unregister_credential_provider(CloudType::Gcp, Some("bucket")),
+            Some(bucket)
+        );
+        assert_eq!(
+            registered_credential_provider(CloudType::Gcp, "bucket"),
+            Some(cloud.clone())
+        );
+        assert_eq!(
+            unregister_credential_provider(CloudType::Gcp, None),
+            Some(cloud)
+        );
+    }
+}
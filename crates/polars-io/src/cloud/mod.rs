@@ -2,6 +2,8 @@
 
 #[cfg(feature = "cloud")]
 mod adaptors;
+#[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+mod credential_provider;
 #[cfg(feature = "cloud")]
 mod glob;
 #[cfg(feature = "cloud")]
@@ -12,6 +14,8 @@ mod polars_object_store;
 
 #[cfg(feature = "cloud")]
 pub use adaptors::*;
+#[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+pub use credential_provider::*;
 #[cfg(feature = "cloud")]
 pub use glob::*;
 #[cfg(feature = "cloud")]
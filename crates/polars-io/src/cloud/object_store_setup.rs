@@ -8,6 +8,8 @@ use polars_utils::aliases::PlHashMap;
 use tokio::sync::RwLock;
 use url::Url;
 
+#[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+use super::registered_credential_provider;
 use super::{parse_url, CloudLocation, CloudOptions, CloudType};
 
 /// Object stores must be cached. Every object-store will do DNS lookups and
@@ -32,11 +34,19 @@ fn err_missing_feature(feature: &str, scheme: &str) -> BuildResult {
 fn url_and_creds_to_key(url: &Url, options: Option<&CloudOptions>) -> String {
     // We include credentials as they can expire, so users will send new credentials for the same url.
     let creds = serde_json::to_string(&options).unwrap_or_else(|_| "".into());
+    // Credential providers are not serialized, so they are identified separately.
+    #[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+    let provider = options
+        .and_then(|options| options.credential_provider.as_ref())
+        .map(|provider| provider.id());
+    #[cfg(not(any(feature = "aws", feature = "gcp", feature = "azure")))]
+    let provider: Option<usize> = None;
     format!(
-        "{}://{}<\\creds\\>{}",
+        "{}://{}<\\creds\\>{}<\\provider\\>{:?}",
         url.scheme(),
         &url[url::Position::BeforeHost..url::Position::AfterPort],
-        creds
+        creds,
+        provider
     )
 }
 
@@ -51,6 +61,28 @@ pub async fn build_object_store(
 ) -> BuildResult {
     let parsed = parse_url(url).map_err(to_compute_err)?;
     let cloud_location = CloudLocation::from_url(&parsed)?;
+    let cloud_type = CloudType::from_url(&parsed)?;
+
+    // Fall back to a provider that was registered for the cloud or bucket.
+    #[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+    let with_registered_provider;
+    #[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+    let options = match options {
+        Some(CloudOptions {
+            credential_provider: Some(_),
+            ..
+        }) => options,
+        _ => match registered_credential_provider(cloud_type, &cloud_location.bucket) {
+            Some(provider) => {
+                with_registered_provider = options
+                    .cloned()
+                    .unwrap_or_default()
+                    .with_credential_provider(Some(provider));
+                Some(&with_registered_provider)
+            },
+            None => options,
+        },
+    };
 
     let key = url_and_creds_to_key(&parsed, options);
     let mut allow_cache = true;
@@ -65,7 +97,6 @@ pub async fn build_object_store(
     #[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
     let options = options.map(std::borrow::Cow::Borrowed).unwrap_or_default();
 
-    let cloud_type = CloudType::from_url(&parsed)?;
     let store = match cloud_type {
         CloudType::Aws => {
             #[cfg(feature = "aws")]
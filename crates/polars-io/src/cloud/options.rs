@@ -34,6 +34,8 @@ use smartstring::alias::String as SmartString;
 #[cfg(feature = "cloud")]
 use url::Url;
 
+#[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+use super::PlCredentialProvider;
 #[cfg(feature = "file_cache")]
 use crate::file_cache::get_env_file_cache_ttl;
 #[cfg(feature = "aws")]
@@ -61,12 +63,96 @@ pub struct CloudOptions {
     pub max_retries: usize,
     #[cfg(feature = "file_cache")]
     pub file_cache_ttl: u64,
+    /// Send unsigned requests, e.g. to read from public buckets. The credential chain is not
+    /// probed, which saves the latency of looking for credentials that don't exist.
+    /// Currently only used for S3.
+    pub anonymous: bool,
     #[cfg(feature = "aws")]
     aws: Option<Configs<AmazonS3ConfigKey>>,
+    /// Configuration that only applies to a single bucket, e.g. to read from MinIO and AWS in
+    /// the same query. It is applied on top of the general AWS configuration.
+    #[cfg(feature = "aws")]
+    aws_buckets: Option<Vec<(String, Configs<AmazonS3ConfigKey>)>>,
     #[cfg(feature = "azure")]
     azure: Option<Configs<AzureConfigKey>>,
     #[cfg(feature = "gcp")]
     gcp: Option<Configs<GoogleConfigKey>>,
+    /// Called to (re)fetch credentials when the configured ones are missing or expired.
+    /// Providers can't be serialized, they are dropped when the options are sent elsewhere.
+    #[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub credential_provider: Option<PlCredentialProvider>,
+    /// How sinks upload files.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub upload: UploadOptions,
+}
+
+/// A checksum of uploaded data, see [`UploadOptions::checksum`].
+#[derive(Clone, Copy, Debug, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UploadChecksum {
+    Crc32c,
+    Sha256,
+}
+
+impl FromStr for UploadChecksum {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> PolarsResult<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "crc32c" => Ok(Self::Crc32c),
+            "sha256" => Ok(Self::Sha256),
+            _ => {
+                polars_bail!(ComputeError: "upload checksum must be 'crc32c' or 'sha256', got '{}'", s)
+            },
+        }
+    }
+}
+
+/// Options for the multipart uploads of cloud sinks.
+#[derive(Clone, Debug, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UploadOptions {
+    /// The size in bytes of the parts that are uploaded. Stores have a minimum part size, which
+    /// is 5 MiB for S3.
+    pub part_size: usize,
+    /// The maximum number of parts that are uploaded at the same time.
+    pub max_concurrency: usize,
+    /// Compute this checksum of the data while it is uploaded and verify it against the stored
+    /// object once the upload is complete, which reads the object back. On S3, SHA256 checksums
+    /// are also sent with every part, so that S3 rejects corrupted parts.
+    pub checksum: Option<UploadChecksum>,
+}
+
+impl Default for UploadOptions {
+    fn default() -> Self {
+        Self {
+            part_size: 10 * 1024 * 1024,
+            max_concurrency: 8,
+            checksum: None,
+        }
+    }
+}
+
+impl UploadOptions {
+    /// Set the option of an untyped configuration key, returns `false` if `key` is not an
+    /// upload option.
+    fn set_untyped(&mut self, key: &str, value: &str) -> PolarsResult<bool> {
+        let parse_usize = |value: &str| {
+            value
+                .parse::<usize>()
+                .ok()
+                .filter(|v| *v > 0)
+                .ok_or_else(|| polars_err!(ComputeError: "'{}' must be a positive integer, got '{}'", key, value))
+        };
+        match key {
+            "upload_part_size" => self.part_size = parse_usize(value)?,
+            "upload_concurrency" => self.max_concurrency = parse_usize(value)?,
+            "upload_checksum" => self.checksum = Some(value.parse()?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
 }
 
 impl Default for CloudOptions {
@@ -75,12 +161,18 @@ impl Default for CloudOptions {
             max_retries: 2,
             #[cfg(feature = "file_cache")]
             file_cache_ttl: get_env_file_cache_ttl(),
+            anonymous: false,
             #[cfg(feature = "aws")]
             aws: Default::default(),
+            #[cfg(feature = "aws")]
+            aws_buckets: Default::default(),
             #[cfg(feature = "azure")]
             azure: Default::default(),
             #[cfg(feature = "gcp")]
             gcp: Default::default(),
+            #[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+            credential_provider: None,
+            upload: Default::default(),
         }
     }
 }
@@ -105,7 +197,7 @@ where
         .collect::<PolarsResult<Configs<T>>>()
 }
 
-#[derive(PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum CloudType {
     Aws,
     Azure,
@@ -225,6 +317,17 @@ fn read_config(
 }
 
 impl CloudOptions {
+    /// Set a [`CredentialProvider`](super::CredentialProvider) that is used to fetch and
+    /// refresh credentials. It takes precedence over statically configured keys.
+    #[cfg(any(feature = "aws", feature = "gcp", feature = "azure"))]
+    pub fn with_credential_provider(
+        mut self,
+        credential_provider: Option<PlCredentialProvider>,
+    ) -> Self {
+        self.credential_provider = credential_provider;
+        self
+    }
+
     /// Set the configuration for AWS connections. This is the preferred API from rust.
     #[cfg(feature = "aws")]
     pub fn with_aws<I: IntoIterator<Item = (AmazonS3ConfigKey, impl Into<String>)>>(
@@ -240,6 +343,37 @@ impl CloudOptions {
         self
     }
 
+    /// Set the AWS configuration for a single bucket. It overrides the configuration set with
+    /// [`CloudOptions::with_aws`] for urls in that bucket.
+    #[cfg(feature = "aws")]
+    pub fn with_aws_bucket<I: IntoIterator<Item = (AmazonS3ConfigKey, impl Into<String>)>>(
+        mut self,
+        bucket: impl Into<String>,
+        configs: I,
+    ) -> Self {
+        let bucket = bucket.into();
+        let configs = configs
+            .into_iter()
+            .map(|(k, v)| (k, v.into()))
+            .collect::<Configs<AmazonS3ConfigKey>>();
+        let buckets = self.aws_buckets.get_or_insert_with(Vec::new);
+        buckets.retain(|(b, _)| b != &bucket);
+        buckets.push((bucket, configs));
+        self
+    }
+
+    /// Send unsigned requests instead of looking for credentials.
+    pub fn with_anonymous(mut self, anonymous: bool) -> Self {
+        self.anonymous = anonymous;
+        self
+    }
+
+    /// Set how sinks upload files.
+    pub fn with_upload_options(mut self, upload: UploadOptions) -> Self {
+        self.upload = upload;
+        self
+    }
+
     /// Build the [`object_store::ObjectStore`] implementation for AWS.
     #[cfg(feature = "aws")]
     pub async fn build_aws(&self, url: &str) -> PolarsResult<impl object_store::ObjectStore> {
@@ -251,6 +385,18 @@ impl CloudOptions {
             }
         }
 
+        let bucket = crate::cloud::CloudLocation::new(url)?.bucket;
+        if let Some((_, options)) = self
+            .aws_buckets
+            .iter()
+            .flatten()
+            .find(|(b, _)| b == &bucket)
+        {
+            for (key, value) in options.iter() {
+                builder = builder.with_config(*key, value);
+            }
+        }
+
         read_config(
             &mut builder,
             &[(
@@ -258,19 +404,23 @@ impl CloudOptions {
                 &[("region = (.*)\n", AmazonS3ConfigKey::Region)],
             )],
         );
-        read_config(
-            &mut builder,
-            &[(
-                Path::new("~/.aws/credentials"),
-                &[
-                    ("aws_access_key_id = (.*)\n", AmazonS3ConfigKey::AccessKeyId),
-                    (
-                        "aws_secret_access_key = (.*)\n",
-                        AmazonS3ConfigKey::SecretAccessKey,
-                    ),
-                ],
-            )],
-        );
+        if self.anonymous {
+            builder = builder.with_skip_signature(true);
+        } else {
+            read_config(
+                &mut builder,
+                &[(
+                    Path::new("~/.aws/credentials"),
+                    &[
+                        ("aws_access_key_id = (.*)\n", AmazonS3ConfigKey::AccessKeyId),
+                        (
+                            "aws_secret_access_key = (.*)\n",
+                            AmazonS3ConfigKey::SecretAccessKey,
+                        ),
+                    ],
+                )],
+            );
+        }
 
         if builder
             .get_config_value(&AmazonS3ConfigKey::DefaultRegion)
@@ -279,7 +429,6 @@ impl CloudOptions {
                 .get_config_value(&AmazonS3ConfigKey::Region)
                 .is_none()
         {
-            let bucket = crate::cloud::CloudLocation::new(url)?.bucket;
             let region = {
                 let bucket_region = BUCKET_REGION.lock().unwrap();
                 bucket_region.get(bucket.as_str()).cloned()
@@ -321,6 +470,13 @@ impl CloudOptions {
             };
         };
 
+        if let Some(provider) = &self.credential_provider {
+            builder = builder.with_credentials(provider.clone().into_aws_provider());
+        }
+        if self.upload.checksum == Some(UploadChecksum::Sha256) {
+            builder = builder.with_config(AmazonS3ConfigKey::Checksum, "sha256");
+        }
+
         builder
             .with_client_options(get_client_options())
             .with_retry(get_retry_config(self.max_retries))
@@ -353,6 +509,9 @@ impl CloudOptions {
                 builder = builder.with_config(*key, value);
             }
         }
+        if let Some(provider) = &self.credential_provider {
+            builder = builder.with_credentials(provider.clone().into_azure_provider());
+        }
 
         builder
             .with_client_options(get_client_options())
@@ -387,6 +546,9 @@ impl CloudOptions {
                 builder = builder.with_config(*key, value);
             }
         }
+        if let Some(provider) = &self.credential_provider {
+            builder = builder.with_credentials(provider.clone().into_gcp_provider());
+        }
 
         builder
             .with_client_options(get_client_options())
@@ -397,17 +559,72 @@ impl CloudOptions {
     }
 
     /// Parse a configuration from a Hashmap. This is the interface from Python.
-    #[allow(unused_variables)]
+    ///
+    /// Besides the object store configuration keys, AWS accepts `anonymous` and per bucket keys
+    /// of the form `bucket.<bucket>.<key>`, e.g. `bucket.local.aws_endpoint`. Requester-pays
+    /// buckets are read by setting `aws_request_payer` to `true`.
+    ///
+    /// The [`UploadOptions`] are set with the `upload_part_size`, `upload_concurrency` and
+    /// `upload_checksum` keys, for all cloud providers.
     pub fn from_untyped_config<I: IntoIterator<Item = (impl AsRef<str>, impl Into<String>)>>(
         url: &str,
         config: I,
+    ) -> PolarsResult<Self> {
+        let mut upload = UploadOptions::default();
+        let mut provider_config = vec![];
+        for (key, value) in config {
+            let value: String = value.into();
+            if !upload.set_untyped(key.as_ref(), &value)? {
+                provider_config.push((key.as_ref().to_string(), value));
+            }
+        }
+        Ok(Self::from_untyped_provider_config(url, provider_config)?.with_upload_options(upload))
+    }
+
+    #[allow(unused_variables)]
+    fn from_untyped_provider_config<
+        I: IntoIterator<Item = (impl AsRef<str>, impl Into<String>)>,
+    >(
+        url: &str,
+        config: I,
     ) -> PolarsResult<Self> {
         match CloudType::from_str(url)? {
             CloudType::Aws => {
                 #[cfg(feature = "aws")]
                 {
-                    parsed_untyped_config::<AmazonS3ConfigKey, _>(config)
-                        .map(|aws| Self::default().with_aws(aws))
+                    let mut options = Self::default();
+                    let mut general = vec![];
+                    let mut buckets: Vec<(String, Vec<(String, String)>)> = vec![];
+                    for (key, value) in config {
+                        let key = key.as_ref();
+                        let value: String = value.into();
+                        if key == "anonymous" || key == "aws_anonymous" {
+                            options.anonymous = value.parse().map_err(|_| {
+                                polars_err!(ComputeError: "'{}' must be 'true' or 'false', got '{}'", key, value)
+                            })?;
+                        } else if let Some((bucket, key)) = key
+                            .strip_prefix("bucket.")
+                            .and_then(|key| key.rsplit_once('.'))
+                        {
+                            match buckets.iter_mut().find(|(b, _)| b == bucket) {
+                                Some((_, configs)) => configs.push((key.to_string(), value)),
+                                None => buckets
+                                    .push((bucket.to_string(), vec![(key.to_string(), value)])),
+                            }
+                        } else {
+                            general.push((key.to_string(), value));
+                        }
+                    }
+
+                    options =
+                        options.with_aws(parsed_untyped_config::<AmazonS3ConfigKey, _>(general)?);
+                    for (bucket, configs) in buckets {
+                        options = options.with_aws_bucket(
+                            bucket,
+                            parsed_untyped_config::<AmazonS3ConfigKey, _>(configs)?,
+                        );
+                    }
+                    Ok(options)
                 }
                 #[cfg(not(feature = "aws"))]
                 {
@@ -445,7 +662,75 @@ impl CloudOptions {
 #[cfg(feature = "cloud")]
 #[cfg(test)]
 mod tests {
-    use super::parse_url;
+    #[cfg(feature = "aws")]
+    use super::AmazonS3ConfigKey;
+    use super::{parse_url, CloudOptions, UploadChecksum, UploadOptions};
+
+    #[cfg(feature = "aws")]
+    #[test]
+    fn test_untyped_aws_bucket_config() {
+        let options = CloudOptions::from_untyped_config(
+            "s3://bucket/data.parquet",
+            [
+                ("aws_region", "eu-west-1"),
+                ("anonymous", "true"),
+                ("bucket.local.minio.aws_endpoint", "http://localhost:9000"),
+                ("bucket.local.minio.aws_region", "us-east-1"),
+            ],
+        )
+        .unwrap();
+
+        assert!(options.anonymous);
+        assert_eq!(
+            options.aws,
+            Some(vec![(AmazonS3ConfigKey::Region, "eu-west-1".to_string())])
+        );
+        assert_eq!(
+            options.aws_buckets,
+            Some(vec![(
+                "local.minio".to_string(),
+                vec![
+                    (
+                        AmazonS3ConfigKey::Endpoint,
+                        "http://localhost:9000".to_string()
+                    ),
+                    (AmazonS3ConfigKey::Region, "us-east-1".to_string()),
+                ]
+            )])
+        );
+    }
+
+    #[test]
+    fn test_untyped_upload_config() {
+        let options = CloudOptions::from_untyped_config(
+            "file:///tmp/data.parquet",
+            [
+                ("upload_part_size", "8388608"),
+                ("upload_concurrency", "4"),
+                ("upload_checksum", "SHA256"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            options.upload,
+            UploadOptions {
+                part_size: 8 * 1024 * 1024,
+                max_concurrency: 4,
+                checksum: Some(UploadChecksum::Sha256),
+            }
+        );
+
+        for (key, value) in [
+            ("upload_part_size", "0"),
+            ("upload_concurrency", "many"),
+            ("upload_checksum", "md5"),
+        ] {
+            assert!(
+                CloudOptions::from_untyped_config("file:///tmp/data.parquet", [(key, value)])
+                    .is_err()
+            );
+        }
+    }
 
     #[test]
     fn test_parse_url() {
@@ -1,30 +1,69 @@
 use std::ops::Range;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 use bytes::Bytes;
 use futures::StreamExt;
 use object_store::path::Path;
 use object_store::{ObjectMeta, ObjectStore};
 use polars_error::{to_compute_err, PolarsResult};
+use polars_utils::aliases::PlHashMap;
 use tokio::io::AsyncWriteExt;
 
+use super::CloudLocation;
+use crate::file_cache::RANGE_CACHE;
 use crate::pl_async::{
     self, tune_with_concurrency_budget, with_concurrency_budget, MAX_BUDGET_PER_REQUEST,
 };
 
 /// Polars specific wrapper for `Arc<dyn ObjectStore>` that limits the number of
 /// concurrent requests for the entire application.
+///
+/// If the range cache is enabled with `POLARS_RANGE_CACHE_MAX_SIZE` and the store knows its
+/// url, see [`PolarsObjectStore::with_cache_url`], byte ranges are served from the local disk
+/// when the ETag of the object did not change.
 #[derive(Debug, Clone)]
-pub struct PolarsObjectStore(Arc<dyn ObjectStore>);
+pub struct PolarsObjectStore {
+    store: Arc<dyn ObjectStore>,
+    /// `{scheme}://{bucket}/`, the url of an object is this prefix followed by its path.
+    url_prefix: Option<Arc<str>>,
+    /// ETags of the objects of this store, memoized for the range cache.
+    e_tags: Arc<RwLock<PlHashMap<Path, Option<Arc<str>>>>>,
+}
 
 impl PolarsObjectStore {
     pub fn new(store: Arc<dyn ObjectStore>) -> Self {
-        Self(store)
+        Self {
+            store,
+            url_prefix: None,
+            e_tags: Default::default(),
+        }
+    }
+
+    /// Use the range cache for the objects of this store, the `location` identifies the
+    /// bucket.
+    pub fn with_cache_url(mut self, location: &CloudLocation) -> Self {
+        if location.scheme != "file" {
+            self.url_prefix = Some(format!("{}://{}/", location.scheme, location.bucket).into());
+        }
+        self
+    }
+
+    /// The url and ETag of the object at `path`, if its ranges can be cached.
+    async fn cache_key(&self, path: &Path) -> PolarsResult<Option<(String, Arc<str>)>> {
+        let (Some(_), Some(url_prefix)) = (RANGE_CACHE.as_ref(), &self.url_prefix) else {
+            return Ok(None);
+        };
+        let e_tag = self.e_tags.read().unwrap().get(path).cloned();
+        let e_tag = match e_tag {
+            Some(e_tag) => e_tag,
+            None => self.head(path).await?.e_tag.map(Arc::from),
+        };
+        Ok(e_tag.map(|e_tag| (format!("{}{}", url_prefix, path), e_tag)))
     }
 
     pub async fn get(&self, path: &Path) -> PolarsResult<Bytes> {
         tune_with_concurrency_budget(1, || async {
-            self.0
+            self.store
                 .get(path)
                 .await
                 .map_err(to_compute_err)?
@@ -36,19 +75,70 @@ impl PolarsObjectStore {
     }
 
     pub async fn get_range(&self, path: &Path, range: Range<usize>) -> PolarsResult<Bytes> {
-        tune_with_concurrency_budget(1, || self.0.get_range(path, range))
+        let cache_key = self.cache_key(path).await?;
+        if let Some((url, e_tag)) = &cache_key {
+            let cache = RANGE_CACHE.as_ref().unwrap();
+            if let Some(bytes) = cache.get(url, e_tag, range.clone()) {
+                return Ok(bytes);
+            }
+        }
+
+        let bytes = tune_with_concurrency_budget(1, || self.store.get_range(path, range.clone()))
             .await
-            .map_err(to_compute_err)
+            .map_err(to_compute_err)?;
+
+        if let Some((url, e_tag)) = &cache_key {
+            RANGE_CACHE
+                .as_ref()
+                .unwrap()
+                .insert(url, e_tag, range, &bytes);
+        }
+        Ok(bytes)
     }
 
     pub async fn get_ranges(
         &self,
         path: &Path,
         ranges: &[Range<usize>],
+    ) -> PolarsResult<Vec<Bytes>> {
+        let Some((url, e_tag)) = self.cache_key(path).await? else {
+            return self.get_ranges_uncached(path, ranges).await;
+        };
+        let cache = RANGE_CACHE.as_ref().unwrap();
+
+        let mut out = ranges
+            .iter()
+            .map(|range| cache.get(&url, &e_tag, range.clone()))
+            .collect::<Vec<_>>();
+        let missing = ranges
+            .iter()
+            .zip(&out)
+            .filter(|(_, bytes)| bytes.is_none())
+            .map(|(range, _)| range.clone())
+            .collect::<Vec<_>>();
+        if missing.is_empty() {
+            return Ok(out.into_iter().map(Option::unwrap).collect());
+        }
+
+        let mut fetched = self.get_ranges_uncached(path, &missing).await?.into_iter();
+        for (range, bytes) in ranges.iter().zip(out.iter_mut()) {
+            if bytes.is_none() {
+                let fetched = fetched.next().unwrap();
+                cache.insert(&url, &e_tag, range.clone(), &fetched);
+                *bytes = Some(fetched);
+            }
+        }
+        Ok(out.into_iter().map(Option::unwrap).collect())
+    }
+
+    async fn get_ranges_uncached(
+        &self,
+        path: &Path,
+        ranges: &[Range<usize>],
     ) -> PolarsResult<Vec<Bytes>> {
         tune_with_concurrency_budget(
             (ranges.len() as u32).clamp(0, MAX_BUDGET_PER_REQUEST as u32),
-            || self.0.get_ranges(path, ranges),
+            || self.store.get_ranges(path, ranges),
         )
         .await
         .map_err(to_compute_err)
@@ -61,7 +151,7 @@ impl PolarsObjectStore {
     ) -> PolarsResult<()> {
         tune_with_concurrency_budget(1, || async {
             let mut stream = self
-                .0
+                .store
                 .get(path)
                 .await
                 .map_err(to_compute_err)?
@@ -82,8 +172,16 @@ impl PolarsObjectStore {
 
     /// Fetch the metadata of the parquet file, do not memoize it.
     pub async fn head(&self, path: &Path) -> PolarsResult<ObjectMeta> {
-        with_concurrency_budget(1, || self.0.head(path))
+        let meta = with_concurrency_budget(1, || self.store.head(path))
             .await
-            .map_err(to_compute_err)
+            .map_err(to_compute_err)?;
+        if self.url_prefix.is_some() {
+            // The ranges that are read after a `head` belong to this version of the object.
+            self.e_tags
+                .write()
+                .unwrap()
+                .insert(path.clone(), meta.e_tag.as_deref().map(Arc::from));
+        }
+        Ok(meta)
     }
 }
@@ -0,0 +1,270 @@
+//! Compression of text output (CSV and NDJSON) while it is being written.
+use std::io::{self, Write};
+use std::path::Path;
+
+use polars_core::prelude::*;
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use polars_core::POOL;
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The compression applied to a text file as a whole. The levels default to the level of the
+/// codec if `None`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OutputCompression {
+    /// A single gzip stream.
+    Gzip(Option<u32>),
+    /// BGZF: a series of independent gzip members of at most 64 KiB followed by an empty end
+    /// marker. Any gzip reader can decompress it, and tools like `bgzip` and `tabix` can seek in
+    /// it.
+    BlockGzip(Option<u32>),
+    /// Zstandard frames that are compressed in parallel.
+    Zstd(Option<i32>),
+}
+
+impl OutputCompression {
+    /// The compression implied by the extension of `path`: `.gz` for gzip, `.bgz` for block
+    /// gzip and `.zst` for zstd.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "gz" | "gzip" => Some(Self::Gzip(None)),
+            "bgz" => Some(Self::BlockGzip(None)),
+            "zst" | "zstd" => Some(Self::Zstd(None)),
+            _ => None,
+        }
+    }
+
+    /// The extension that is appended to the extension of the file type.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Gzip(_) | Self::BlockGzip(_) => "gz",
+            Self::Zstd(_) => "zst",
+        }
+    }
+}
+
+// Maximum number of uncompressed bytes in a BGZF block, such that the compressed block always
+// fits in the 64 KiB `BSIZE` field.
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+const BGZF_BLOCK_SIZE: usize = 0xff00;
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+const ZSTD_BLOCK_SIZE: usize = 1 << 21;
+// Header of a BGZF block up to the `BSIZE` field: a gzip header with the `FEXTRA` flag and a
+// `BC` subfield of length 2.
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+const BGZF_HEADER: [u8; 16] = [
+    0x1f, 0x8b, 0x08, 0x04, 0, 0, 0, 0, 0, 0xff, 0x06, 0, b'B', b'C', 0x02, 0,
+];
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+const BGZF_EOF: [u8; 28] = [
+    0x1f, 0x8b, 0x08, 0x04, 0, 0, 0, 0, 0, 0xff, 0x06, 0, b'B', b'C', 0x02, 0, 0x1b, 0, 0x03, 0, 0,
+    0, 0, 0, 0, 0, 0, 0,
+];
+
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+#[derive(Clone, Copy)]
+enum BlockCodec {
+    Bgzf(flate2::Compression),
+    Zstd(i32),
+}
+
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+impl BlockCodec {
+    fn block_size(&self) -> usize {
+        match self {
+            Self::Bgzf(_) => BGZF_BLOCK_SIZE,
+            Self::Zstd(_) => ZSTD_BLOCK_SIZE,
+        }
+    }
+
+    fn compress(&self, data: &[u8]) -> io::Result<Vec<u8>> {
+        match *self {
+            Self::Bgzf(level) => {
+                let mut encoder =
+                    flate2::write::DeflateEncoder::new(Vec::with_capacity(data.len() / 2), level);
+                encoder.write_all(data)?;
+                let deflated = encoder.finish()?;
+                let mut crc = flate2::Crc::new();
+                crc.update(data);
+
+                let block_size = BGZF_HEADER.len() + 2 + deflated.len() + 8;
+                let mut out = Vec::with_capacity(block_size);
+                out.extend_from_slice(&BGZF_HEADER);
+                out.extend_from_slice(&((block_size - 1) as u16).to_le_bytes());
+                out.extend_from_slice(&deflated);
+                out.extend_from_slice(&crc.sum().to_le_bytes());
+                out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+                Ok(out)
+            },
+            Self::Zstd(level) => zstd::bulk::compress(data, level),
+        }
+    }
+}
+
+enum Encoder<W: Write> {
+    Plain(W),
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    Gzip(flate2::write::GzEncoder<W>),
+    // Buffers the input and compresses it in independent blocks on the thread pool.
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    Blocks {
+        writer: W,
+        codec: BlockCodec,
+        buf: Vec<u8>,
+        finished: bool,
+    },
+}
+
+/// A writer that compresses everything written to it with an [`OutputCompression`].
+///
+/// [`CompressedWriter::finish`] must be called once all data is written to complete the
+/// compressed stream.
+pub struct CompressedWriter<W: Write> {
+    encoder: Encoder<W>,
+}
+
+impl<W: Write> CompressedWriter<W> {
+    pub fn new(writer: W, compression: Option<OutputCompression>) -> PolarsResult<Self> {
+        let encoder = match compression {
+            None => Encoder::Plain(writer),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Some(OutputCompression::Gzip(level)) => {
+                Encoder::Gzip(flate2::write::GzEncoder::new(writer, gzip_level(level)?))
+            },
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Some(OutputCompression::BlockGzip(level)) => Encoder::Blocks {
+                writer,
+                codec: BlockCodec::Bgzf(gzip_level(level)?),
+                buf: vec![],
+                finished: false,
+            },
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Some(OutputCompression::Zstd(level)) => {
+                let level = level.unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL);
+                let range = zstd::compression_level_range();
+                polars_ensure!(
+                    range.contains(&level),
+                    InvalidOperation: "zstd compression level must be in {}..={}, got {}",
+                    range.start(), range.end(), level
+                );
+                Encoder::Blocks {
+                    writer,
+                    codec: BlockCodec::Zstd(level),
+                    buf: vec![],
+                    finished: false,
+                }
+            },
+            #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+            Some(_) => polars_bail!(
+                ComputeError: "cannot write compressed output; \
+                compile with feature 'decompress' or 'decompress-fast'"
+            ),
+        };
+        Ok(Self { encoder })
+    }
+
+    /// Compress the remaining buffered data and write the trailer of the compressed stream.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        match &mut self.encoder {
+            Encoder::Plain(writer) => writer.flush()?,
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Encoder::Gzip(encoder) => encoder.try_finish()?,
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Encoder::Blocks {
+                writer,
+                codec,
+                buf,
+                finished,
+            } => {
+                write_blocks(writer, *codec, buf, true)?;
+                if !*finished {
+                    *finished = true;
+                    if matches!(codec, BlockCodec::Bgzf(_)) {
+                        writer.write_all(&BGZF_EOF)?;
+                    }
+                }
+                writer.flush()?
+            },
+        }
+        Ok(())
+    }
+}
+
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+fn gzip_level(level: Option<u32>) -> PolarsResult<flate2::Compression> {
+    match level {
+        None => Ok(flate2::Compression::default()),
+        Some(level) => {
+            polars_ensure!(
+                level <= 9,
+                InvalidOperation: "gzip compression level must be in 0..=9, got {}", level
+            );
+            Ok(flate2::Compression::new(level))
+        },
+    }
+}
+
+/// Compress the full blocks in `buf`, or all of it if `finish`, and write them in order.
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+fn write_blocks<W: Write>(
+    writer: &mut W,
+    codec: BlockCodec,
+    buf: &mut Vec<u8>,
+    finish: bool,
+) -> io::Result<()> {
+    let block_size = codec.block_size();
+    let len = if finish {
+        buf.len()
+    } else {
+        buf.len() - buf.len() % block_size
+    };
+    if len == 0 {
+        return Ok(());
+    }
+
+    let blocks = POOL.install(|| {
+        buf[..len]
+            .par_chunks(block_size)
+            .map(|block| codec.compress(block))
+            .collect::<io::Result<Vec<_>>>()
+    })?;
+    for block in blocks {
+        writer.write_all(&block)?;
+    }
+    buf.drain(..len);
+    Ok(())
+}
+
+impl<W: Write> Write for CompressedWriter<W> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        match &mut self.encoder {
+            Encoder::Plain(writer) => writer.write(data),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Encoder::Gzip(encoder) => encoder.write(data),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Encoder::Blocks {
+                writer, codec, buf, ..
+            } => {
+                buf.extend_from_slice(data);
+                // Wait for a block per thread before compressing.
+                if buf.len() >= codec.block_size() * POOL.current_num_threads() {
+                    write_blocks(writer, *codec, buf, false)?;
+                }
+                Ok(data.len())
+            },
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.encoder {
+            Encoder::Plain(writer) => writer.flush(),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Encoder::Gzip(encoder) => encoder.flush(),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Encoder::Blocks { writer, .. } => writer.flush(),
+        }
+    }
+}
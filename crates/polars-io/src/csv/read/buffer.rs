@@ -480,6 +480,83 @@ where
     }
 }
 
+#[cfg(feature = "dtype-decimal")]
+pub struct DecimalField {
+    builder: PrimitiveChunkedBuilder<Int128Type>,
+    precision: Option<usize>,
+    scale: usize,
+    decimal_comma: bool,
+    scratch: Vec<u8>,
+}
+
+#[cfg(feature = "dtype-decimal")]
+impl DecimalField {
+    fn new(
+        name: &str,
+        capacity: usize,
+        precision: Option<usize>,
+        scale: usize,
+        decimal_comma: bool,
+    ) -> Self {
+        Self {
+            builder: PrimitiveChunkedBuilder::new(name, capacity),
+            precision,
+            scale,
+            decimal_comma,
+            scratch: vec![],
+        }
+    }
+}
+
+#[cfg(feature = "dtype-decimal")]
+impl ParsedBuffer for DecimalField {
+    #[inline]
+    fn parse_bytes(
+        &mut self,
+        mut bytes: &[u8],
+        ignore_errors: bool,
+        needs_escaping: bool,
+        _missing_is_null: bool,
+        _time_unit: Option<TimeUnit>,
+    ) -> PolarsResult<()> {
+        if needs_escaping && bytes.len() >= 2 {
+            bytes = &bytes[1..bytes.len() - 1]
+        }
+        let bytes = skip_whitespace(bytes);
+        if bytes.is_empty() {
+            self.builder.append_null();
+            return Ok(());
+        }
+
+        let bytes = if self.decimal_comma {
+            prepare_decimal_comma(bytes, &mut self.scratch);
+            self.scratch.as_slice()
+        } else {
+            bytes
+        };
+        // Parsed straight into the integer representation, so no precision is lost through a
+        // float.
+        let value = arrow::compute::decimal::deserialize_decimal(
+            bytes,
+            self.precision.map(|p| p as u8),
+            self.scale as u8,
+        );
+        match value {
+            Some(value) => self.builder.append_value(value),
+            None => {
+                polars_ensure!(
+                    ignore_errors,
+                    ComputeError: "could not parse `{}` as dtype {}",
+                    String::from_utf8_lossy(bytes),
+                    DataType::Decimal(self.precision, Some(self.scale)),
+                );
+                self.builder.append_null()
+            },
+        }
+        Ok(())
+    }
+}
+
 pub fn init_buffers(
     projection: &[usize],
     capacity: usize,
@@ -541,6 +618,18 @@ pub fn init_buffers(
                 DataType::Categorical(_, ordering) => Buffer::Categorical(CategoricalField::new(
                     name, capacity, quote_char, *ordering,
                 )),
+                #[cfg(feature = "dtype-decimal")]
+                DataType::Decimal(precision, Some(scale)) => Buffer::Decimal(DecimalField::new(
+                    name,
+                    capacity,
+                    *precision,
+                    *scale,
+                    decimal_comma,
+                )),
+                #[cfg(feature = "dtype-decimal")]
+                DataType::Decimal(_, None) => polars_bail!(
+                    ComputeError: "'scale' must be set when reading csv column as Decimal"
+                ),
                 // TODO (ENUM) support writing to Enum
                 dt => polars_bail!(
                     ComputeError: "unsupported data type when reading CSV: {} when reading CSV", dt,
@@ -582,6 +671,8 @@ pub enum Buffer {
     Categorical(CategoricalField),
     DecimalFloat32(PrimitiveChunkedBuilder<Float32Type>, Vec<u8>),
     DecimalFloat64(PrimitiveChunkedBuilder<Float64Type>, Vec<u8>),
+    #[cfg(feature = "dtype-decimal")]
+    Decimal(DecimalField),
 }
 
 impl Buffer {
@@ -604,6 +695,12 @@ impl Buffer {
             Buffer::Float64(v) => v.finish().into_series(),
             Buffer::DecimalFloat32(v, _) => v.finish().into_series(),
             Buffer::DecimalFloat64(v, _) => v.finish().into_series(),
+            #[cfg(feature = "dtype-decimal")]
+            Buffer::Decimal(v) => v
+                .builder
+                .finish()
+                .into_decimal_unchecked(v.precision, v.scale)
+                .into_series(),
             #[cfg(feature = "dtype-datetime")]
             Buffer::Datetime {
                 buf,
@@ -661,6 +758,8 @@ impl Buffer {
             Buffer::Float64(v) => v.append_null(),
             Buffer::DecimalFloat32(v, _) => v.append_null(),
             Buffer::DecimalFloat64(v, _) => v.append_null(),
+            #[cfg(feature = "dtype-decimal")]
+            Buffer::Decimal(v) => v.builder.append_null(),
             Buffer::Utf8(v) => {
                 if valid {
                     v.mutable.push_value("")
@@ -703,6 +802,8 @@ impl Buffer {
             Buffer::UInt64(_) => DataType::UInt64,
             Buffer::Float32(_) | Buffer::DecimalFloat32(_, _) => DataType::Float32,
             Buffer::Float64(_) | Buffer::DecimalFloat64(_, _) => DataType::Float64,
+            #[cfg(feature = "dtype-decimal")]
+            Buffer::Decimal(v) => DataType::Decimal(v.precision, Some(v.scale)),
             Buffer::Utf8(_) => DataType::String,
             #[cfg(feature = "dtype-datetime")]
             Buffer::Datetime { time_unit, .. } => DataType::Datetime(*time_unit, None),
@@ -846,6 +947,15 @@ impl Buffer {
                     None,
                 )
             },
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(buf) => <DecimalField as ParsedBuffer>::parse_bytes(
+                buf,
+                bytes,
+                ignore_errors,
+                needs_escaping,
+                missing_is_null,
+                None,
+            ),
             Utf8(buf) => <Utf8Field as ParsedBuffer>::parse_bytes(
                 buf,
                 bytes,
@@ -22,10 +22,14 @@ mod parser;
 mod read_impl;
 mod reader;
 pub mod schema_inference;
+mod separator;
 mod splitfields;
 mod utils;
 
-pub use options::{CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
+pub use options::{
+    CommentPrefix, CsvEncoding, CsvOnError, CsvParseOptions, CsvReadOptions, NullValues,
+    SeparatorPattern,
+};
 pub use parser::count_rows;
 pub use read_impl::batched::{BatchedCsvReader, OwnedBatchedCsvReader};
 pub use reader::CsvReader;
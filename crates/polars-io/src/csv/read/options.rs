@@ -36,12 +36,15 @@ pub struct CsvReadOptions {
     pub infer_schema_length: Option<usize>,
     pub raise_if_empty: bool,
     pub ignore_errors: bool,
+    pub on_error: CsvOnError,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CsvParseOptions {
     pub separator: u8,
+    /// Takes the place of `separator` for separators of more than one byte.
+    pub separator_pattern: Option<SeparatorPattern>,
     pub quote_char: Option<u8>,
     pub eol_char: u8,
     pub encoding: CsvEncoding,
@@ -80,6 +83,7 @@ impl Default for CsvReadOptions {
             infer_schema_length: Some(100),
             raise_if_empty: true,
             ignore_errors: false,
+            on_error: CsvOnError::Raise,
         }
     }
 }
@@ -89,6 +93,7 @@ impl Default for CsvParseOptions {
     fn default() -> Self {
         Self {
             separator: b',',
+            separator_pattern: None,
             quote_char: Some(b'"'),
             eol_char: b'\n',
             encoding: Default::default(),
@@ -235,6 +240,13 @@ impl CsvReadOptions {
         self
     }
 
+    /// What to do with rows that cannot be parsed. Fields that fail to parse are null instead
+    /// with `ignore_errors`.
+    pub fn with_on_error(mut self, on_error: CsvOnError) -> Self {
+        self.on_error = on_error;
+        self
+    }
+
     /// Apply a function to the parse options.
     pub fn map_parse_options<F: Fn(CsvParseOptions) -> CsvParseOptions>(
         mut self,
@@ -251,6 +263,23 @@ impl CsvParseOptions {
     /// is most often a comma ','.
     pub fn with_separator(mut self, separator: u8) -> Self {
         self.separator = separator;
+        self.separator_pattern = None;
+        self
+    }
+
+    /// Set the separator from a string, which can be longer than a single byte, e.g. `"||"`.
+    pub fn with_separator_str(self, separator: &str) -> Self {
+        match separator.as_bytes() {
+            [separator] => self.with_separator(*separator),
+            _ => self.with_separator_pattern(Some(SeparatorPattern::Literal(Arc::from(separator)))),
+        }
+    }
+
+    /// Separate the fields by a string or a regex instead of a single byte. This is slower
+    /// than a single byte separator, as the input is rewritten to a single byte separator
+    /// before it is parsed.
+    pub fn with_separator_pattern(mut self, separator_pattern: Option<SeparatorPattern>) -> Self {
+        self.separator_pattern = separator_pattern;
         self
     }
 
@@ -311,7 +340,7 @@ impl CsvParseOptions {
         self
     }
 
-    /// Parse floats with a comma as decimal separator.
+    /// Parse floats and decimals with a comma as decimal separator.
     pub fn with_decimal_comma(mut self, decimal_comma: bool) -> Self {
         self.decimal_comma = decimal_comma;
         self
@@ -366,6 +395,31 @@ impl From<&str> for CommentPrefix {
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SeparatorPattern {
+    /// A separator of one or more bytes, such as `"||"` or `"\t|\t"`.
+    Literal(Arc<str>),
+    /// A regex that matches the separators, such as `r"\s*\|\s*"`. A separator never spans
+    /// a line end or a quote and empty matches are not separators.
+    Regex(Arc<str>),
+}
+
+/// What the CSV reader does with rows that cannot be parsed, such as rows with a field that
+/// does not parse as the dtype of its column or with more fields than the schema.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CsvOnError {
+    /// Raise an error at the first row that cannot be parsed.
+    #[default]
+    Raise,
+    /// Leave the rows out.
+    Skip,
+    /// Leave the rows out and collect them, with their row numbers and the reasons they were
+    /// rejected, see [`CsvReader::finish_with_rejected`](super::CsvReader::finish_with_rejected).
+    Collect,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NullValues {
@@ -1,4 +1,5 @@
 pub(super) mod batched;
+mod rejected;
 
 use std::fmt;
 
@@ -10,21 +11,25 @@ use polars_core::POOL;
 use polars_time::prelude::*;
 use polars_utils::flatten;
 use rayon::prelude::*;
+use rejected::{read_chunk_rejecting, RejectedRow};
 
 use super::buffer::init_buffers;
-use super::options::{CommentPrefix, CsvEncoding, NullValues, NullValuesCompiled};
+use super::options::{
+    CommentPrefix, CsvEncoding, CsvOnError, NullValues, NullValuesCompiled, SeparatorPattern,
+};
 use super::parser::{
     get_line_stats, is_comment_line, next_line_position, next_line_position_naive, parse_lines,
     skip_bom, skip_line_ending, skip_this_line, skip_whitespace_exclude,
 };
 use super::schema_inference::{check_decimal_comma, infer_file_schema};
+use super::separator::replace_separator;
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
 use super::utils::decompress;
 use super::utils::get_file_chunks;
 #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
 use super::utils::is_compressed;
 use crate::mmap::ReaderBytes;
-use crate::predicates::PhysicalIoExpr;
+use crate::predicates::{apply_predicate, PhysicalIoExpr};
 use crate::utils::update_row_counts;
 use crate::RowIndex;
 
@@ -99,6 +104,9 @@ pub(crate) struct CoreReader<'a> {
     /// Current line number, used in error reporting
     current_line: usize,
     ignore_errors: bool,
+    on_error: CsvOnError,
+    /// The rows that could not be parsed, with their position among the rows of the file.
+    rejected: Vec<RejectedRow>,
     skip_rows_before_header: usize,
     // after the header, we need to take embedded lines into account
     skip_rows_after_header: usize,
@@ -141,8 +149,10 @@ impl<'a> CoreReader<'a> {
         mut projection: Option<Vec<usize>>,
         max_records: Option<usize>,
         separator: Option<u8>,
+        separator_pattern: Option<SeparatorPattern>,
         has_header: bool,
         ignore_errors: bool,
+        on_error: CsvOnError,
         schema: Option<SchemaRef>,
         columns: Option<Arc<[String]>>,
         encoding: CsvEncoding,
@@ -166,10 +176,9 @@ impl<'a> CoreReader<'a> {
         truncate_ragged_lines: bool,
         decimal_comma: bool,
     ) -> PolarsResult<CoreReader<'a>> {
-        let separator = separator.unwrap_or(b',');
+        let mut separator = separator.unwrap_or(b',');
 
         check_decimal_comma(decimal_comma, separator)?;
-        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
         let mut reader_bytes = reader_bytes;
 
         #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
@@ -193,6 +202,12 @@ impl<'a> CoreReader<'a> {
             }
         }
 
+        if let Some(pattern) = &separator_pattern {
+            let (b, replacement) = replace_separator(&reader_bytes, pattern, quote_char, eol_char)?;
+            reader_bytes = ReaderBytes::Owned(b);
+            separator = replacement;
+        }
+
         let mut schema = match schema {
             Some(schema) => schema,
             None => {
@@ -241,6 +256,8 @@ impl<'a> CoreReader<'a> {
             projection,
             current_line: usize::from(has_header),
             ignore_errors,
+            on_error,
+            rejected: vec![],
             skip_rows_before_header: skip_rows,
             skip_rows_after_header,
             n_rows,
@@ -404,8 +421,10 @@ impl<'a> CoreReader<'a> {
         let (bytes, starting_point_offset) =
             self.find_starting_point(bytes, self.quote_char, self.eol_char)?;
 
+        // Rejected rows don't count towards `n_rows`, so the rows to read can't be bounded.
+        let set_upper_bound = self.on_error == CsvOnError::Raise;
         let (bytes, total_rows, remaining_bytes) =
-            self.estimate_rows_and_set_upper_bound(bytes, logging, true);
+            self.estimate_rows_and_set_upper_bound(bytes, logging, set_upper_bound);
         if total_rows == 128 {
             *n_threads = 1;
 
@@ -583,40 +602,69 @@ impl<'a> CoreReader<'a> {
                 std::cmp::min(rows_per_thread, max_proxy)
             };
 
-            let mut dfs = POOL.install(|| {
+            let chunks = POOL.install(|| {
                 file_chunks
                     .into_par_iter()
                     .map(|(bytes_offset_thread, stop_at_nbytes)| {
-                        let mut df = read_chunk(
-                            bytes,
-                            self.separator,
-                            self.schema.as_ref(),
-                            self.ignore_errors,
-                            &projection,
-                            bytes_offset_thread,
-                            self.quote_char,
-                            self.eol_char,
-                            self.comment_prefix.as_ref(),
-                            capacity,
-                            self.encoding,
-                            self.null_values.as_ref(),
-                            self.missing_is_null,
-                            self.truncate_ragged_lines,
-                            usize::MAX,
-                            stop_at_nbytes,
-                            starting_point_offset,
-                            self.decimal_comma,
-                        )?;
+                        let read = |start: usize, stop: usize, capacity: usize| {
+                            read_chunk(
+                                bytes,
+                                self.separator,
+                                self.schema.as_ref(),
+                                self.ignore_errors,
+                                &projection,
+                                start,
+                                self.quote_char,
+                                self.eol_char,
+                                self.comment_prefix.as_ref(),
+                                capacity,
+                                self.encoding,
+                                self.null_values.as_ref(),
+                                self.missing_is_null,
+                                self.truncate_ragged_lines,
+                                usize::MAX,
+                                stop,
+                                starting_point_offset,
+                                self.decimal_comma,
+                            )
+                        };
+                        let (mut df, rejected) = match self.on_error {
+                            CsvOnError::Raise => {
+                                (read(bytes_offset_thread, stop_at_nbytes, capacity)?, vec![])
+                            },
+                            CsvOnError::Skip | CsvOnError::Collect => read_chunk_rejecting(
+                                read,
+                                bytes,
+                                bytes_offset_thread,
+                                stop_at_nbytes,
+                                capacity,
+                                self.quote_char,
+                                self.eol_char,
+                            )?,
+                        };
 
                         cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
                         if let Some(rc) = &self.row_index {
                             df.with_row_index_mut(&rc.name, Some(rc.offset));
                         }
                         let n_read = df.height() as IdxSize;
-                        Ok((df, n_read))
+                        Ok(((df, n_read), rejected))
                     })
                     .collect::<PolarsResult<Vec<_>>>()
             })?;
+            let (mut dfs, rejected): (Vec<_>, Vec<_>) = chunks.into_iter().unzip();
+            if self.on_error == CsvOnError::Collect {
+                // Number the rejected rows by their position in the file.
+                let mut rows_before = 0;
+                for ((_, n_read), rejected) in dfs.iter().zip(rejected) {
+                    let n_rejected = rejected.len();
+                    self.rejected.extend(rejected.into_iter().map(|mut r| {
+                        r.row += rows_before;
+                        r
+                    }));
+                    rows_before += *n_read as usize + n_rejected;
+                }
+            }
             if let (Some(n_rows), Some(remaining_bytes)) = (self.n_rows, remaining_bytes) {
                 let rows_already_read: usize = dfs.iter().map(|x| x.1 as usize).sum();
                 if rows_already_read < n_rows {
@@ -675,7 +723,12 @@ impl<'a> CoreReader<'a> {
 
     /// Read the csv into a DataFrame. The predicate can come from a lazy physical plan.
     pub fn as_df(&mut self) -> PolarsResult<DataFrame> {
-        let predicate = self.predicate.take();
+        let mut predicate = self.predicate.take();
+        // Rows are rejected per chunk of the file, the predicate is applied to the result.
+        let post_predicate = match self.on_error {
+            CsvOnError::Raise => None,
+            CsvOnError::Skip | CsvOnError::Collect => predicate.take(),
+        };
         let n_threads = self.n_threads.unwrap_or_else(|| POOL.current_num_threads());
 
         let reader_bytes = self.reader_bytes.take().unwrap();
@@ -688,9 +741,26 @@ impl<'a> CoreReader<'a> {
             if n_rows < df.height() {
                 df = df.slice(0, n_rows)
             }
+            // The rows after the last row that is read are not rejected either, the i-th
+            // rejected row comes after `row - i` rows that are read.
+            if let Some(end) = self
+                .rejected
+                .iter()
+                .enumerate()
+                .position(|(i, r)| r.row - i >= n_rows)
+            {
+                self.rejected.truncate(end);
+            }
         }
+        apply_predicate(&mut df, post_predicate.as_deref(), true)?;
         Ok(df)
     }
+
+    /// The rows that could not be parsed with [`CsvOnError::Collect`], see
+    /// [`CsvReader::finish_with_rejected`](super::CsvReader::finish_with_rejected).
+    pub fn take_rejected(&mut self) -> PolarsResult<DataFrame> {
+        RejectedRow::into_df(std::mem::take(&mut self.rejected))
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -5,12 +5,12 @@ use polars_core::datatypes::Field;
 use polars_core::frame::DataFrame;
 use polars_core::schema::SchemaRef;
 use polars_core::POOL;
-use polars_error::PolarsResult;
+use polars_error::{polars_ensure, PolarsResult};
 use polars_utils::IdxSize;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use super::{cast_columns, read_chunk, CoreReader};
-use crate::csv::read::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
+use crate::csv::read::options::{CommentPrefix, CsvEncoding, CsvOnError, NullValuesCompiled};
 use crate::csv::read::parser::next_line_position;
 use crate::csv::read::CsvReader;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
@@ -117,6 +117,10 @@ impl<'a> Iterator for ChunkOffsetIter<'a> {
 impl<'a> CoreReader<'a> {
     /// Create a batched csv reader that uses mmap to load data.
     pub fn batched(mut self, _has_cat: bool) -> PolarsResult<BatchedCsvReader<'a>> {
+        polars_ensure!(
+            self.on_error == CsvOnError::Raise,
+            InvalidOperation: "the batched CSV reader does not support `on_error`"
+        );
         let reader_bytes = self.reader_bytes.take().unwrap();
         let bytes = reader_bytes.as_ref();
         let (bytes, starting_point_offset) =
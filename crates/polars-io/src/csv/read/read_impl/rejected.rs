@@ -0,0 +1,133 @@
+//! Rows that cannot be parsed, for [`CsvOnError::Skip`](super::CsvOnError::Skip) and
+//! [`CsvOnError::Collect`](super::CsvOnError::Collect).
+//!
+//! A chunk is parsed as a whole first. Only if that fails, its lines are split in halves until
+//! the lines that fail are found, so a chunk with a few bad rows takes a few more passes over
+//! the halves that contain them.
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+
+use super::super::parser::skip_this_line;
+
+/// A row that could not be parsed.
+#[derive(Debug)]
+pub(crate) struct RejectedRow {
+    /// Position of the row among the rows of its chunk, counting the rejected ones.
+    pub(crate) row: usize,
+    pub(crate) line: String,
+    pub(crate) error: String,
+}
+
+impl RejectedRow {
+    /// The rejected rows as a [`DataFrame`] with the columns `row`, `line` and `error`.
+    pub(crate) fn into_df(rows: Vec<Self>) -> PolarsResult<DataFrame> {
+        let mut row = Vec::with_capacity(rows.len());
+        let mut line = Vec::with_capacity(rows.len());
+        let mut error = Vec::with_capacity(rows.len());
+        for r in rows {
+            row.push(r.row as IdxSize);
+            line.push(r.line);
+            error.push(r.error);
+        }
+        DataFrame::new(vec![
+            Series::new("row", row),
+            Series::new("line", line),
+            Series::new("error", error),
+        ])
+    }
+}
+
+/// Parse `bytes[start..stop]` with `read`, which parses the lines in a byte range into a
+/// frame with the given capacity, leaving the lines that fail out of the frame.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn read_chunk_rejecting<F>(
+    read: F,
+    bytes: &[u8],
+    start: usize,
+    stop: usize,
+    capacity: usize,
+    quote_char: Option<u8>,
+    eol_char: u8,
+) -> PolarsResult<(DataFrame, Vec<RejectedRow>)>
+where
+    F: Fn(usize, usize, usize) -> PolarsResult<DataFrame>,
+{
+    let error = match read(start, stop, capacity) {
+        Ok(df) => return Ok((df, vec![])),
+        Err(e) => e,
+    };
+
+    let mut line_starts = vec![];
+    let mut rest = &bytes[start..stop];
+    while !rest.is_empty() {
+        line_starts.push(rest.as_ptr() as usize - bytes.as_ptr() as usize);
+        rest = skip_this_line(rest, quote_char, eol_char);
+    }
+    let mut rejecting = Rejecting {
+        read,
+        bytes,
+        eol_char,
+        dfs: vec![],
+        rejected: vec![],
+        row: 0,
+    };
+    rejecting.split(&line_starts, stop, error)?;
+
+    let df = if rejecting.dfs.is_empty() {
+        (rejecting.read)(start, start, 0)?
+    } else {
+        accumulate_dataframes_vertical(rejecting.dfs)?
+    };
+    Ok((df, rejecting.rejected))
+}
+
+struct Rejecting<'a, F> {
+    read: F,
+    bytes: &'a [u8],
+    eol_char: u8,
+    dfs: Vec<DataFrame>,
+    rejected: Vec<RejectedRow>,
+    /// The row of the chunk that the next line parses to.
+    row: usize,
+}
+
+impl<'a, F> Rejecting<'a, F>
+where
+    F: Fn(usize, usize, usize) -> PolarsResult<DataFrame>,
+{
+    /// Parse the lines that start at `line_starts`, the last of which ends at `end`.
+    fn read_lines(&mut self, line_starts: &[usize], end: usize) -> PolarsResult<()> {
+        match (self.read)(line_starts[0], end, line_starts.len()) {
+            Ok(df) => {
+                self.row += df.height();
+                self.dfs.push(df);
+                Ok(())
+            },
+            Err(e) => self.split(line_starts, end, e),
+        }
+    }
+
+    /// Handle lines that failed to parse with `error`.
+    fn split(&mut self, line_starts: &[usize], end: usize, error: PolarsError) -> PolarsResult<()> {
+        if line_starts.len() > 1 {
+            let mid = line_starts.len() / 2;
+            self.read_lines(&line_starts[..mid], line_starts[mid])?;
+            return self.read_lines(&line_starts[mid..], end);
+        } else if line_starts.is_empty() {
+            return Err(error);
+        }
+
+        let mut line = &self.bytes[line_starts[0]..end];
+        line = line.strip_suffix(&[self.eol_char]).unwrap_or(line);
+        line = line.strip_suffix(b"\r").unwrap_or(line);
+        let error = error.to_string();
+        self.rejected.push(RejectedRow {
+            row: self.row,
+            line: String::from_utf8_lossy(line).into_owned(),
+            // The first line holds the reason, the others suggest options to avoid the error.
+            error: error.lines().next().unwrap_or_default().to_string(),
+        });
+        self.row += 1;
+        Ok(())
+    }
+}
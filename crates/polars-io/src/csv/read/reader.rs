@@ -129,8 +129,10 @@ impl<R: MmapBytesReader> CsvReader<R> {
             self.options.projection.clone().map(|x| x.as_ref().clone()),
             self.options.infer_schema_length,
             Some(parse_options.separator),
+            parse_options.separator_pattern.clone(),
             self.options.has_header,
             self.options.ignore_errors,
+            self.options.on_error,
             self.options.schema.clone(),
             self.options.columns.clone(),
             parse_options.encoding,
@@ -169,12 +171,10 @@ impl<R: MmapBytesReader> CsvReader<R> {
         let mut to_cast = Vec::with_capacity(overwriting_schema.len());
 
         let mut _has_categorical = false;
-        let mut _err: Option<PolarsError> = None;
 
-        #[allow(unused_mut)]
         let schema = overwriting_schema
             .iter_fields()
-            .filter_map(|mut fld| {
+            .filter_map(|fld| {
                 use DataType::*;
                 match fld.data_type() {
                     Time => {
@@ -187,30 +187,13 @@ impl<R: MmapBytesReader> CsvReader<R> {
                         _has_categorical = true;
                         Some(fld)
                     },
-                    #[cfg(feature = "dtype-decimal")]
-                    Decimal(precision, scale) => match (precision, scale) {
-                        (_, Some(_)) => {
-                            to_cast.push(fld.clone());
-                            fld.coerce(String);
-                            Some(fld)
-                        },
-                        _ => {
-                            _err = Some(PolarsError::ComputeError(
-                                "'scale' must be set when reading csv column as Decimal".into(),
-                            ));
-                            None
-                        },
-                    },
+                    // Decimals are parsed natively, a missing scale is inferred.
                     _ => Some(fld),
                 }
             })
             .collect::<Schema>();
 
-        if let Some(err) = _err {
-            Err(err)
-        } else {
-            Ok((schema, to_cast, _has_categorical))
-        }
+        Ok((schema, to_cast, _has_categorical))
     }
 
     pub fn batched_borrowed(&mut self) -> PolarsResult<BatchedCsvReader> {
@@ -225,57 +208,13 @@ impl<R: MmapBytesReader> CsvReader<R> {
             csv_reader.batched(false)
         }
     }
-}
 
-impl CsvReader<Box<dyn MmapBytesReader>> {
-    pub fn batched(mut self, schema: Option<SchemaRef>) -> PolarsResult<OwnedBatchedCsvReader> {
-        match schema {
-            Some(schema) => Ok(to_batched_owned(self.with_schema(schema))),
-            None => {
-                let parse_options = self.options.get_parse_options();
-                let reader_bytes = get_reader_bytes(&mut self.reader)?;
-
-                let (inferred_schema, _, _) = infer_file_schema(
-                    &reader_bytes,
-                    parse_options.separator,
-                    self.options.infer_schema_length,
-                    self.options.has_header,
-                    None,
-                    self.options.skip_rows,
-                    self.options.skip_rows_after_header,
-                    parse_options.comment_prefix.as_ref(),
-                    parse_options.quote_char,
-                    parse_options.eol_char,
-                    parse_options.null_values.as_ref(),
-                    parse_options.try_parse_dates,
-                    self.options.raise_if_empty,
-                    &mut self.options.n_threads,
-                    parse_options.decimal_comma,
-                )?;
-                let schema = Arc::new(inferred_schema);
-                Ok(to_batched_owned(self.with_schema(schema)))
-            },
-        }
-    }
-}
-
-impl<R> SerReader<R> for CsvReader<R>
-where
-    R: MmapBytesReader,
-{
-    /// Create a new CsvReader from a file/stream using default read options. To
-    /// use non-default read options, first construct [CsvReadOptions] and then use
-    /// any of the `(try)_into_` methods.
-    fn new(reader: R) -> Self {
-        CsvReader {
-            reader,
-            options: Default::default(),
-            predicate: None,
-        }
-    }
-
-    /// Read the file and create the DataFrame.
-    fn finish(mut self) -> PolarsResult<DataFrame> {
+    /// Read the file and create the DataFrame, together with a DataFrame of the rows that could
+    /// not be parsed with [`CsvOnError::Collect`](super::CsvOnError::Collect). The latter has the columns `row`, the
+    /// position of the row among the rows of the file after the header and the skipped rows,
+    /// `line`, the raw line, and `error`, the reason it was rejected. It is empty with the other
+    /// modes, and [`SerReader::finish`] drops it.
+    pub fn finish_with_rejected(mut self) -> PolarsResult<(DataFrame, DataFrame)> {
         let rechunk = self.options.rechunk;
         let schema_overwrite = self.options.schema_overwrite.clone();
         let low_memory = self.options.low_memory;
@@ -283,7 +222,7 @@ where
         #[cfg(feature = "dtype-categorical")]
         let mut _cat_lock = None;
 
-        let mut df = if let Some(schema) = schema_overwrite.as_deref() {
+        let (mut df, rejected) = if let Some(schema) = schema_overwrite.as_deref() {
             let (schema, to_cast, _has_cat) = self.prepare_schema_overwrite(schema)?;
 
             #[cfg(feature = "dtype-categorical")]
@@ -292,7 +231,7 @@ where
             }
 
             let mut csv_reader = self.core_reader(Some(Arc::new(schema)), to_cast)?;
-            csv_reader.as_df()?
+            (csv_reader.as_df()?, csv_reader.take_rejected()?)
         } else {
             #[cfg(feature = "dtype-categorical")]
             {
@@ -311,7 +250,7 @@ where
                 }
             }
             let mut csv_reader = self.core_reader(self.options.schema.clone(), vec![])?;
-            csv_reader.as_df()?
+            (csv_reader.as_df()?, csv_reader.take_rejected()?)
         };
 
         // Important that this rechunk is never done in parallel.
@@ -348,7 +287,62 @@ where
             }
         }
 
-        Ok(df)
+        Ok((df, rejected))
+    }
+}
+
+impl CsvReader<Box<dyn MmapBytesReader>> {
+    pub fn batched(mut self, schema: Option<SchemaRef>) -> PolarsResult<OwnedBatchedCsvReader> {
+        match schema {
+            Some(schema) => Ok(to_batched_owned(self.with_schema(schema))),
+            None => {
+                let parse_options = self.options.get_parse_options();
+                let reader_bytes = get_reader_bytes(&mut self.reader)?;
+                let (reader_bytes, separator) =
+                    parse_options.apply_separator_pattern(reader_bytes)?;
+
+                let (inferred_schema, _, _) = infer_file_schema(
+                    &reader_bytes,
+                    separator,
+                    self.options.infer_schema_length,
+                    self.options.has_header,
+                    None,
+                    self.options.skip_rows,
+                    self.options.skip_rows_after_header,
+                    parse_options.comment_prefix.as_ref(),
+                    parse_options.quote_char,
+                    parse_options.eol_char,
+                    parse_options.null_values.as_ref(),
+                    parse_options.try_parse_dates,
+                    self.options.raise_if_empty,
+                    &mut self.options.n_threads,
+                    parse_options.decimal_comma,
+                )?;
+                let schema = Arc::new(inferred_schema);
+                Ok(to_batched_owned(self.with_schema(schema)))
+            },
+        }
+    }
+}
+
+impl<R> SerReader<R> for CsvReader<R>
+where
+    R: MmapBytesReader,
+{
+    /// Create a new CsvReader from a file/stream using default read options. To
+    /// use non-default read options, first construct [CsvReadOptions] and then use
+    /// any of the `(try)_into_` methods.
+    fn new(reader: R) -> Self {
+        CsvReader {
+            reader,
+            options: Default::default(),
+            predicate: None,
+        }
+    }
+
+    /// Read the file and create the DataFrame.
+    fn finish(self) -> PolarsResult<DataFrame> {
+        self.finish_with_rejected().map(|(df, _)| df)
     }
 }
 
@@ -31,7 +31,9 @@ impl SchemaInferenceResult {
     ) -> PolarsResult<Self> {
         let parse_options = options.get_parse_options();
 
-        let separator = parse_options.separator;
+        let (reader_bytes, separator) =
+            parse_options.apply_separator_pattern(ReaderBytes::Borrowed(reader_bytes))?;
+        let reader_bytes = &reader_bytes;
         let infer_schema_length = options.infer_schema_length;
         let has_header = options.has_header;
         let schema_overwrite_arc = options.schema_overwrite.clone();
@@ -178,6 +180,23 @@ pub fn infer_field_schema(string: &str, try_parse_dates: bool, decimal_comma: bo
     }
 }
 
+/// Number of fractional digits of a number.
+fn infer_decimal_scale(s: &str, decimal_comma: bool) -> usize {
+    let separator = if decimal_comma { ',' } else { '.' };
+    s.split_once(separator).map_or(0, |(_, fraction)| {
+        fraction.bytes().take_while(u8::is_ascii_digit).count()
+    })
+}
+
+/// Set the scale of a `Decimal` override without one to the scale seen during inference.
+fn resolve_decimal_scale(dtype: &DataType, _scale: usize) -> DataType {
+    match dtype {
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(precision, None) => DataType::Decimal(*precision, Some(_scale)),
+        dtype => dtype.clone(),
+    }
+}
+
 #[inline]
 fn parse_bytes_with_encoding(bytes: &[u8], encoding: CsvEncoding) -> PolarsResult<Cow<str>> {
     Ok(match encoding {
@@ -326,6 +345,8 @@ fn infer_file_schema_inner(
         vec![PlHashSet::with_capacity(4); header_length];
     // keep track of columns with nulls
     let mut nulls: Vec<bool> = vec![false; header_length];
+    // keep track of the largest number of fractional digits, the scale of a decimal override
+    let mut scales: Vec<usize> = vec![0; header_length];
 
     let mut rows_count = 0;
     let mut fields = Vec::with_capacity(header_length);
@@ -419,6 +440,10 @@ fn infer_file_schema_inner(
                         },
                     };
                     if let Some(dtype) = dtype {
+                        if matches!(&dtype, DataType::Int64 | DataType::Float64) {
+                            let scale = unsafe { scales.get_unchecked_release_mut(i) };
+                            *scale = (*scale).max(infer_decimal_scale(&s, decimal_comma));
+                        }
                         if matches!(&dtype, DataType::String)
                             && needs_escaping
                             && n_threads.unwrap_or(2) > 1
@@ -448,7 +473,7 @@ fn infer_file_schema_inner(
 
         if let Some(schema_overwrite) = schema_overwrite {
             if let Some((_, name, dtype)) = schema_overwrite.get_full(field_name) {
-                fields.push(Field::new(name, dtype.clone()));
+                fields.push(Field::new(name, resolve_decimal_scale(dtype, scales[i])));
                 continue;
             }
 
@@ -456,7 +481,7 @@ fn infer_file_schema_inner(
             // execute only if schema is complete
             if schema_overwrite.len() == header_length {
                 if let Some((name, dtype)) = schema_overwrite.get_at_index(i) {
-                    fields.push(Field::new(name, dtype.clone()));
+                    fields.push(Field::new(name, resolve_decimal_scale(dtype, scales[i])));
                     continue;
                 }
             }
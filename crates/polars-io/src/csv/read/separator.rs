@@ -0,0 +1,176 @@
+//! Separators of more than one byte.
+//!
+//! The field splitter looks for a single separator byte, so input that is separated by a
+//! [`SeparatorPattern`] is first rewritten with every separator replaced by a byte that does not
+//! occur in the input. Literal separators are found with the SIMD accelerated substring search of
+//! `memchr`, regex separators are matched on every unquoted part of a line.
+use memchr::memmem::Finder;
+use polars_error::{polars_ensure, polars_err, PolarsResult};
+use regex::bytes::Regex;
+
+use super::options::{CsvParseOptions, SeparatorPattern};
+use super::utils::is_compressed;
+use crate::mmap::ReaderBytes;
+
+impl CsvParseOptions {
+    /// The bytes to parse and the byte that separates their fields. With a
+    /// [`SeparatorPattern`] the bytes are rewritten to be separated by a single byte.
+    pub fn apply_separator_pattern<'a>(
+        &self,
+        reader_bytes: ReaderBytes<'a>,
+    ) -> PolarsResult<(ReaderBytes<'a>, u8)> {
+        match &self.separator_pattern {
+            // Compressed input is rewritten once it is decompressed.
+            Some(pattern) if !is_compressed(&reader_bytes) => {
+                replace_separator(&reader_bytes, pattern, self.quote_char, self.eol_char)
+                    .map(|(bytes, separator)| (ReaderBytes::Owned(bytes), separator))
+            },
+            _ => Ok((reader_bytes, self.separator)),
+        }
+    }
+}
+
+/// Rewrite `bytes` with the separators that match `pattern` replaced by a single byte, which is
+/// returned with the rewritten bytes.
+pub(super) fn replace_separator(
+    bytes: &[u8],
+    pattern: &SeparatorPattern,
+    quote_char: Option<u8>,
+    eol_char: u8,
+) -> PolarsResult<(Vec<u8>, u8)> {
+    let replacement = replacement_byte(bytes, quote_char, eol_char)?;
+    let bytes = match pattern {
+        SeparatorPattern::Literal(separator) => {
+            polars_ensure!(
+                !separator.is_empty(),
+                InvalidOperation: "CSV separator cannot be empty"
+            );
+            replace_literal(bytes, separator.as_bytes(), quote_char, replacement)
+        },
+        SeparatorPattern::Regex(regex) => {
+            let regex = Regex::new(regex)
+                .map_err(|e| polars_err!(ComputeError: "invalid CSV separator regex: {}", e))?;
+            replace_regex(bytes, &regex, quote_char, eol_char, replacement)
+        },
+    };
+    Ok((bytes, replacement))
+}
+
+/// An ASCII control character that does not occur in `bytes`, preferring the unit separator.
+fn replacement_byte(bytes: &[u8], quote_char: Option<u8>, eol_char: u8) -> PolarsResult<u8> {
+    (1..0x20u8)
+        .rev()
+        .find(|&b| b != eol_char && Some(b) != quote_char && memchr::memchr(b, bytes).is_none())
+        .ok_or_else(|| {
+            polars_err!(
+                ComputeError: "cannot read CSV with a multi-byte separator: \
+                the data contains every ASCII control character"
+            )
+        })
+}
+
+/// The end of the quoted part that starts at `start`, after its closing quote.
+fn quoted_end(bytes: &[u8], start: usize, quote_char: u8) -> usize {
+    memchr::memchr(quote_char, &bytes[start + 1..]).map_or(bytes.len(), |i| start + i + 2)
+}
+
+fn replace_literal(
+    bytes: &[u8],
+    separator: &[u8],
+    quote_char: Option<u8>,
+    replacement: u8,
+) -> Vec<u8> {
+    let next_quote =
+        |from: usize| quote_char.and_then(|q| memchr::memchr(q, &bytes[from..]).map(|i| from + i));
+
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut start = 0;
+    let finder = Finder::new(separator);
+    let mut separators = finder.find_iter(bytes);
+    let mut separator_pos = separators.next();
+    let mut quote_pos = next_quote(0);
+    loop {
+        match (separator_pos, quote_pos) {
+            (Some(pos), quote) if quote.map_or(true, |quote| pos < quote) => {
+                out.extend_from_slice(&bytes[start..pos]);
+                out.push(replacement);
+                start = pos + separator.len();
+                separator_pos = separators.next();
+            },
+            (_, Some(quote)) => {
+                // Separators within quotes are part of the field.
+                let end = quoted_end(bytes, quote, quote_char.unwrap());
+                out.extend_from_slice(&bytes[start..end]);
+                start = end;
+                while separator_pos.is_some_and(|pos| pos < end) {
+                    separator_pos = separators.next();
+                }
+                quote_pos = next_quote(end);
+            },
+            // Without a quote, the first arm takes any separator.
+            (_, None) => break,
+        }
+    }
+    out.extend_from_slice(&bytes[start..]);
+    out
+}
+
+fn replace_regex(
+    bytes: &[u8],
+    regex: &Regex,
+    quote_char: Option<u8>,
+    eol_char: u8,
+    replacement: u8,
+) -> Vec<u8> {
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut start = 0;
+    while start < bytes.len() {
+        let end = match quote_char {
+            Some(q) => memchr::memchr2(eol_char, q, &bytes[start..]),
+            None => memchr::memchr(eol_char, &bytes[start..]),
+        }
+        .map_or(bytes.len(), |i| start + i);
+
+        let part = &bytes[start..end];
+        let mut copied = 0;
+        for m in regex.find_iter(part).filter(|m| !m.is_empty()) {
+            out.extend_from_slice(&part[copied..m.start()]);
+            out.push(replacement);
+            copied = m.end();
+        }
+        out.extend_from_slice(&part[copied..]);
+
+        if end == bytes.len() {
+            break;
+        } else if bytes[end] == eol_char {
+            out.push(eol_char);
+            start = end + 1;
+        } else {
+            let quoted_end = quoted_end(bytes, end, bytes[end]);
+            out.extend_from_slice(&bytes[end..quoted_end]);
+            start = quoted_end;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_replace_separator() {
+        let bytes = b"a||b||c\n1||\"x||y\"||3\n";
+        assert_eq!(
+            replace_literal(bytes, b"||", Some(b'"'), b';'),
+            b"a;b;c\n1;\"x||y\";3\n"
+        );
+
+        let bytes = b"a | b|c\n1 |\"x | y\"|  3\n";
+        let regex = Regex::new(r"\s*\|\s*").unwrap();
+        assert_eq!(
+            replace_regex(bytes, &regex, Some(b'"'), b'\n', b';'),
+            b"a;b;c\n1;\"x | y\";3\n"
+        );
+    }
+}
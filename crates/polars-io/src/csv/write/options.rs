@@ -3,6 +3,9 @@ use std::num::NonZeroUsize;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::compression::OutputCompression;
+use crate::write_mode::WriteMode;
+
 /// Options for writing CSV files.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -12,6 +15,13 @@ pub struct CsvWriterOptions {
     pub batch_size: NonZeroUsize,
     pub maintain_order: bool,
     pub serialize_options: SerializeOptions,
+    /// Compress the whole file.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub compression: Option<OutputCompression>,
+    /// What to do if the file exists. When appending to a non-empty file, the header and BOM
+    /// are not written again.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub write_mode: WriteMode,
 }
 
 impl Default for CsvWriterOptions {
@@ -22,6 +32,8 @@ impl Default for CsvWriterOptions {
             batch_size: NonZeroUsize::new(1024).unwrap(),
             maintain_order: false,
             serialize_options: SerializeOptions::default(),
+            compression: None,
+            write_mode: WriteMode::Overwrite,
         }
     }
 }
@@ -42,6 +54,11 @@ pub struct SerializeOptions {
     /// and [`DataType::Float32`](polars_core::datatypes::DataType::Float32).
     pub float_scientific: Option<bool>,
     pub float_precision: Option<usize>,
+    /// When `float_scientific` is `None`, write floats with an absolute value of at least
+    /// `10^threshold`, or a non-zero absolute value below `10^-threshold`, in scientific
+    /// notation and all other floats positionally.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub float_scientific_threshold: Option<u32>,
     /// Used as separator.
     pub separator: u8,
     /// Quoting character.
@@ -62,6 +79,7 @@ impl Default for SerializeOptions {
             datetime_format: None,
             float_scientific: None,
             float_precision: None,
+            float_scientific_threshold: None,
             separator: b',',
             quote_char: b'"',
             null: String::new(),
@@ -114,17 +114,17 @@ pub(crate) fn write<W: Write>(
         .unzip();
 
     let len = df.height();
+    if len == 0 {
+        return Ok(());
+    }
     let total_rows_per_pool_iter = n_threads * chunk_size;
     let serializer_pool = LowContentionPool::<Vec<_>>::new(n_threads);
     let write_buffer_pool = LowContentionPool::<Vec<_>>::new(n_threads);
 
-    let mut n_rows_finished = 0;
-
-    // holds the buffers that will be written
-    let mut result_buf: Vec<PolarsResult<Vec<u8>>> = Vec::with_capacity(n_threads);
-
-    while n_rows_finished < len {
-        let buf_writer = |thread_no| {
+    // Serializes the `n_threads * chunk_size` rows from `n_rows_finished` into a buffer per
+    // thread.
+    let serialize_rows = |n_rows_finished: usize| -> Vec<PolarsResult<Vec<u8>>> {
+        let buf_writer = |thread_no: usize| -> PolarsResult<Vec<u8>> {
             let thread_offset = thread_no * chunk_size;
             let total_offset = n_rows_finished + thread_offset;
             let mut df = df.slice(total_offset as i64, chunk_size);
@@ -191,21 +191,49 @@ pub(crate) fn write<W: Write>(
         if n_threads > 1 {
             let par_iter = (0..n_threads).into_par_iter().map(buf_writer);
             // rayon will ensure the right order
+            let mut result_buf = Vec::with_capacity(n_threads);
             POOL.install(|| result_buf.par_extend(par_iter));
+            result_buf
         } else {
-            result_buf.push(buf_writer(0));
+            vec![buf_writer(0)]
         }
+    };
 
-        for buf in result_buf.drain(..) {
-            let mut buf = buf?;
-            writer.write_all(&buf)?;
-            buf.clear();
-            write_buffer_pool.set(buf);
-        }
+    let write_buffers =
+        |writer: &mut W, result_buf: Vec<PolarsResult<Vec<u8>>>| -> PolarsResult<()> {
+            for buf in result_buf {
+                let mut buf = buf?;
+                writer.write_all(&buf)?;
+                buf.clear();
+                write_buffer_pool.set(buf);
+            }
+            Ok(())
+        };
 
+    let mut n_rows_finished = 0;
+    let mut result_buf = serialize_rows(n_rows_finished);
+    loop {
         n_rows_finished += total_rows_per_pool_iter;
+        if n_rows_finished >= len {
+            return write_buffers(writer, result_buf);
+        }
+
+        let mut next_result_buf = None;
+        if n_threads > 1 {
+            // Serialize the next rows on the thread pool while the current ones are written, so
+            // that slow writers (compression, network) don't leave the threads idle.
+            let next = &mut next_result_buf;
+            let serialize_rows = &serialize_rows;
+            POOL.in_place_scope(|scope| {
+                scope.spawn(move |_| *next = Some(serialize_rows(n_rows_finished)));
+                write_buffers(writer, result_buf)
+            })?;
+        } else {
+            write_buffers(writer, result_buf)?;
+            next_result_buf = Some(serialize_rows(n_rows_finished));
+        }
+        result_buf = next_result_buf.unwrap();
     }
-    Ok(())
 }
 
 /// Writes a CSV header to `writer`.
@@ -30,7 +30,7 @@
 //!     but also with `QUOTE_NON_NULL = false`.
 //!  3. A serializer that quotes only non-nulls. This is a bare serializer with `QUOTE_NON_NULL = true`.
 
-use std::fmt::LowerExp;
+use std::fmt::{Display, LowerExp};
 use std::io::Write;
 
 use arrow::array::{Array, BooleanArray, NullArray, PrimitiveArray, Utf8ViewArray};
@@ -212,6 +212,34 @@ fn float_serializer_with_precision_positional<I: NativeType>(
     })
 }
 
+fn float_serializer_scientific_threshold<I: NativeType + NumCast + Display + LowerExp>(
+    array: &PrimitiveArray<I>,
+    precision: Option<usize>,
+    threshold: u32,
+) -> impl Serializer {
+    let upper = 10f64.powi(threshold as i32);
+    let lower = upper.recip();
+    let f = move |&item, buf: &mut Vec<u8>, _options: &SerializeOptions| {
+        let abs = <f64 as NumCast>::from(item).unwrap().abs();
+        let scientific = abs.is_finite() && abs != 0.0 && (abs >= upper || abs < lower);
+        // Float writing into a buffer of `Vec<u8>` cannot fail.
+        let _ = match (scientific, precision) {
+            (true, Some(precision)) => write!(buf, "{item:.precision$e}"),
+            (true, None) => write!(buf, "{item:e}"),
+            (false, Some(precision)) => write!(buf, "{item:.precision$}"),
+            (false, None) => write!(buf, "{item}"),
+        };
+    };
+
+    make_serializer::<_, _, false>(f, array.iter(), |array| {
+        array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<I>>()
+            .expect(ARRAY_MISMATCH_MSG)
+            .iter()
+    })
+}
+
 fn null_serializer(_array: &NullArray) -> impl Serializer {
     struct NullSerializer;
     impl<'a> Serializer<'a> for NullSerializer {
@@ -535,6 +563,26 @@ pub(super) fn serializer_for<'a>(
         DataType::UInt32 => quote_if_always!(integer_serializer::<u32>),
         DataType::Int64 => quote_if_always!(integer_serializer::<i64>),
         DataType::UInt64 => quote_if_always!(integer_serializer::<u64>),
+        DataType::Float32 | DataType::Float64
+            if options.float_scientific.is_none()
+                && options.float_scientific_threshold.is_some() =>
+        {
+            let threshold = options.float_scientific_threshold.unwrap();
+            let precision = options.float_precision;
+            if dtype == &DataType::Float32 {
+                quote_if_always!(
+                    float_serializer_scientific_threshold::<f32>,
+                    precision,
+                    threshold
+                )
+            } else {
+                quote_if_always!(
+                    float_serializer_scientific_threshold::<f64>,
+                    precision,
+                    threshold
+                )
+            }
+        },
         DataType::Float32 => match options.float_precision {
             Some(precision) => match options.float_scientific {
                 Some(true) => {
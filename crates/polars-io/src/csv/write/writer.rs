@@ -131,6 +131,15 @@ where
         self
     }
 
+    /// Set the magnitude from which floats are written in scientific notation when the
+    /// notation is not forced with [`CsvWriter::with_float_scientific`].
+    pub fn with_float_scientific_threshold(mut self, threshold: Option<u32>) -> Self {
+        if threshold.is_some() {
+            self.options.float_scientific_threshold = threshold;
+        }
+        self
+    }
+
     /// Set the single byte character used for quoting.
     pub fn with_quote_char(mut self, char: u8) -> Self {
         self.options.quote_char = char;
@@ -222,4 +231,9 @@ impl<W: Write> BatchedWriter<W> {
 
         Ok(())
     }
+
+    /// Get a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer.buffer
+    }
 }
@@ -0,0 +1,11 @@
+//! # Read query results from a database
+//!
+//! Queries are executed over the binary [Postgres] wire protocol and the resulting rows are
+//! streamed into [`DataFrame`](polars_core::frame::DataFrame)s in batches, so large results
+//! don't need to be buffered by the server or the driver.
+//!
+//! [Postgres]: https://www.postgresql.org/docs/current/protocol.html
+mod read;
+mod types;
+
+pub use read::*;
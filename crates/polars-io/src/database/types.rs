@@ -0,0 +1,123 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use tokio_postgres::types::Type;
+use tokio_postgres::Row;
+
+const UNIX_EPOCH_DATE: NaiveDate = match NaiveDate::from_ymd_opt(1970, 1, 1) {
+    Some(date) => date,
+    None => unreachable!(),
+};
+
+/// The polars dtype a column of the given Postgres type is read as.
+pub(super) fn to_polars_dtype(ty: &Type) -> PolarsResult<DataType> {
+    let dtype = match *ty {
+        Type::BOOL => DataType::Boolean,
+        Type::INT2 => DataType::Int16,
+        Type::INT4 => DataType::Int32,
+        Type::INT8 => DataType::Int64,
+        Type::OID => DataType::UInt32,
+        Type::FLOAT4 => DataType::Float32,
+        Type::FLOAT8 => DataType::Float64,
+        Type::TEXT | Type::VARCHAR | Type::BPCHAR | Type::NAME | Type::UNKNOWN => DataType::String,
+        Type::BYTEA => DataType::Binary,
+        Type::DATE => DataType::Date,
+        Type::TIME => DataType::Time,
+        Type::TIMESTAMP => DataType::Datetime(TimeUnit::Microseconds, None),
+        Type::TIMESTAMPTZ => DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())),
+        _ => return Err(unsupported_type(ty)),
+    };
+    Ok(dtype)
+}
+
+fn unsupported_type(ty: &Type) -> PolarsError {
+    polars_err!(
+        ComputeError:
+        "unsupported Postgres type '{}'; cast the column to a supported type in the query (e.g. `col::text`)",
+        ty
+    )
+}
+
+/// Collect column `idx` of `rows` into a [`Series`].
+pub(super) fn rows_to_series(
+    rows: &[Row],
+    idx: usize,
+    name: &str,
+    ty: &Type,
+) -> PolarsResult<Series> {
+    macro_rules! collect {
+        ($t:ty) => {
+            rows.iter()
+                .map(|row| row.try_get::<_, Option<$t>>(idx))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(to_compute_err)?
+        };
+    }
+
+    let s = match *ty {
+        Type::BOOL => Series::new(name, collect!(bool)),
+        Type::INT2 => Series::new(name, collect!(i16)),
+        Type::INT4 => Series::new(name, collect!(i32)),
+        Type::INT8 => Series::new(name, collect!(i64)),
+        Type::OID => Series::new(name, collect!(u32)),
+        Type::FLOAT4 => Series::new(name, collect!(f32)),
+        Type::FLOAT8 => Series::new(name, collect!(f64)),
+        Type::TEXT | Type::VARCHAR | Type::BPCHAR | Type::NAME | Type::UNKNOWN => {
+            Series::new(name, collect!(&str))
+        },
+        Type::BYTEA => Series::new(name, collect!(&[u8])),
+        Type::DATE => {
+            let days = collect!(NaiveDate)
+                .into_iter()
+                .map(|date| date.map(|date| (date - UNIX_EPOCH_DATE).num_days() as i32));
+            Int32Chunked::from_iter_options(name, days)
+                .into_date()
+                .into_series()
+        },
+        Type::TIME => {
+            let nanos = collect!(NaiveTime).into_iter().map(|time| {
+                time.map(|time| {
+                    time.num_seconds_from_midnight() as i64 * 1_000_000_000
+                        + time.nanosecond() as i64
+                })
+            });
+            Int64Chunked::from_iter_options(name, nanos)
+                .into_time()
+                .into_series()
+        },
+        Type::TIMESTAMP => {
+            let micros = collect!(NaiveDateTime)
+                .into_iter()
+                .map(|dt| dt.map(|dt| dt.and_utc().timestamp_micros()));
+            Int64Chunked::from_iter_options(name, micros)
+                .into_datetime(TimeUnit::Microseconds, None)
+                .into_series()
+        },
+        Type::TIMESTAMPTZ => {
+            let micros = collect!(chrono::DateTime<chrono::Utc>)
+                .into_iter()
+                .map(|dt| dt.map(|dt| dt.timestamp_micros()));
+            Int64Chunked::from_iter_options(name, micros)
+                .into_datetime(TimeUnit::Microseconds, Some("UTC".into()))
+                .into_series()
+        },
+        _ => return Err(unsupported_type(ty)),
+    };
+    Ok(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_polars_dtype() {
+        assert_eq!(to_polars_dtype(&Type::INT8).unwrap(), DataType::Int64);
+        assert_eq!(to_polars_dtype(&Type::VARCHAR).unwrap(), DataType::String);
+        assert_eq!(
+            to_polars_dtype(&Type::TIMESTAMPTZ).unwrap(),
+            DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into()))
+        );
+        assert!(to_polars_dtype(&Type::JSONB).is_err());
+    }
+}
@@ -0,0 +1,328 @@
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use serde::Deserialize;
+
+const Z85_ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// Magic number of a serialized `RoaringBitmapArray`, the 64-bit bitmap of Delta.
+const BITMAP_ARRAY_MAGIC: u32 = 1681511377;
+/// Cookies of the portable roaring bitmap format.
+const SERIAL_COOKIE_NO_RUNCONTAINER: u32 = 12346;
+const SERIAL_COOKIE: u32 = 12347;
+/// Bitmaps with run containers only store container offsets from this many containers on.
+const NO_OFFSET_THRESHOLD: usize = 4;
+/// Containers with more values are stored as bitsets instead of sorted arrays.
+const MAX_ARRAY_CONTAINER_SIZE: usize = 4096;
+
+/// Describes where the deletion vector of a data file is stored.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct DeletionVectorDescriptor {
+    /// `u` for a file next to the data, `p` for an absolute path, `i` for an inline vector.
+    pub storage_type: String,
+    pub path_or_inline_dv: String,
+    #[serde(default)]
+    pub offset: Option<i32>,
+    pub size_in_bytes: i32,
+    pub cardinality: i64,
+}
+
+impl DeletionVectorDescriptor {
+    /// Identifies the vector within a table. Together with the path of the data file, this is
+    /// the key of a file action in the log.
+    pub fn unique_id(&self) -> String {
+        match self.offset {
+            Some(offset) => format!("{}{}@{}", self.storage_type, self.path_or_inline_dv, offset),
+            None => format!("{}{}", self.storage_type, self.path_or_inline_dv),
+        }
+    }
+
+    fn absolute_path(&self, table_root: &Path) -> PolarsResult<PathBuf> {
+        match self.storage_type.as_str() {
+            "u" => {
+                // A random directory prefix followed by the z85 encoded uuid of the file.
+                let encoded = &self.path_or_inline_dv;
+                polars_ensure!(
+                    encoded.len() >= 20 && encoded.is_char_boundary(encoded.len() - 20),
+                    ComputeError: "invalid Delta deletion vector path '{}'", encoded
+                );
+                let (prefix, uuid) = encoded.split_at(encoded.len() - 20);
+                let uuid = z85_decode(uuid)?;
+                let file_name = format!("deletion_vector_{}.bin", format_uuid(&uuid));
+                Ok(table_root.join(prefix).join(file_name))
+            },
+            "p" => super::log::resolve_path(table_root, &self.path_or_inline_dv),
+            storage_type => polars_bail!(
+                ComputeError: "unsupported Delta deletion vector storage type '{}'", storage_type
+            ),
+        }
+    }
+
+    /// Read the serialized bitmap of the vector.
+    fn read_bitmap(&self, table_root: &Path) -> PolarsResult<Vec<u8>> {
+        let size = self.size_in_bytes as usize;
+        if self.storage_type == "i" {
+            let mut data = z85_decode(&self.path_or_inline_dv)?;
+            polars_ensure!(
+                data.len() >= size,
+                ComputeError: "invalid inline Delta deletion vector"
+            );
+            data.truncate(size);
+            return Ok(data);
+        }
+
+        // A deletion vector file starts with a format version byte and holds any number of
+        // vectors, each prefixed with its size and followed by its CRC-32 checksum.
+        let path = self.absolute_path(table_root)?;
+        let mut file = polars_utils::open_file(&path)?;
+        file.seek(SeekFrom::Start(self.offset.unwrap_or(1) as u64))?;
+        let mut buf = vec![0; size + 8];
+        file.read_exact(&mut buf)?;
+
+        let stored_size = u32::from_be_bytes(buf[..4].try_into().unwrap()) as usize;
+        let checksum = u32::from_be_bytes(buf[size + 4..].try_into().unwrap());
+        let data = buf[4..size + 4].to_vec();
+        let mut crc = flate2::Crc::new();
+        crc.update(&data);
+        polars_ensure!(
+            stored_size == size && crc.sum() == checksum,
+            ComputeError: "corrupt Delta deletion vector in '{}'", path.display()
+        );
+        Ok(data)
+    }
+
+    pub fn load(&self, table_root: &Path) -> PolarsResult<DeletionVector> {
+        let rows = decode_bitmap_array(&self.read_bitmap(table_root)?)?;
+        polars_ensure!(
+            rows.len() as i64 == self.cardinality,
+            ComputeError: "Delta deletion vector has {} rows, expected {}", rows.len(), self.cardinality
+        );
+        Ok(DeletionVector { rows })
+    }
+}
+
+/// The positions of the rows of a data file that were deleted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DeletionVector {
+    /// Sorted row positions.
+    rows: Vec<u64>,
+}
+
+impl DeletionVector {
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn contains(&self, row: u64) -> bool {
+        self.rows.binary_search(&row).is_ok()
+    }
+
+    /// Create a mask that is `false` for the positions in `row_index` that were deleted.
+    pub fn keep_mask(&self, row_index: &IdxCa) -> BooleanChunked {
+        BooleanChunked::from_iter_values(
+            row_index.name(),
+            row_index
+                .into_iter()
+                .map(|idx| idx.map_or(true, |idx| !self.contains(idx as u64))),
+        )
+    }
+}
+
+fn invalid_bitmap() -> PolarsError {
+    polars_err!(ComputeError: "invalid Delta deletion vector bitmap")
+}
+
+struct ByteReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, n: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(n <= self.data.len(), ComputeError: "invalid Delta deletion vector bitmap");
+        let (out, rest) = self.data.split_at(n);
+        self.data = rest;
+        Ok(out)
+    }
+
+    fn u16(&mut self) -> PolarsResult<u16> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> PolarsResult<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> PolarsResult<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+}
+
+/// Decode a `RoaringBitmapArray`: a sequence of 32-bit roaring bitmaps, each holding the low
+/// bits of the rows of which the high bits equal its key.
+fn decode_bitmap_array(data: &[u8]) -> PolarsResult<Vec<u64>> {
+    let mut reader = ByteReader { data };
+    if reader.u32()? != BITMAP_ARRAY_MAGIC {
+        return Err(invalid_bitmap());
+    }
+    let n_bitmaps = reader.u64()?;
+    let mut rows = vec![];
+    for _ in 0..n_bitmaps {
+        let high = (reader.u32()? as u64) << 32;
+        let start = rows.len();
+        decode_roaring_bitmap(&mut reader, &mut rows)?;
+        for row in &mut rows[start..] {
+            *row |= high;
+        }
+    }
+    Ok(rows)
+}
+
+/// Decode a bitmap in the portable roaring format.
+fn decode_roaring_bitmap(reader: &mut ByteReader, out: &mut Vec<u64>) -> PolarsResult<()> {
+    let cookie = reader.u32()?;
+    let (n_containers, run_flags) = if cookie & 0xFFFF == SERIAL_COOKIE {
+        let n = (cookie >> 16) as usize + 1;
+        (n, Some(reader.take(n.div_ceil(8))?))
+    } else if cookie == SERIAL_COOKIE_NO_RUNCONTAINER {
+        (reader.u32()? as usize, None)
+    } else {
+        return Err(invalid_bitmap());
+    };
+
+    let header = (0..n_containers)
+        .map(|_| Ok((reader.u16()?, reader.u16()? as usize + 1)))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    if run_flags.is_none() || n_containers >= NO_OFFSET_THRESHOLD {
+        reader.take(4 * n_containers)?;
+    }
+
+    for (i, (key, cardinality)) in header.into_iter().enumerate() {
+        let high = (key as u64) << 16;
+        let is_run = run_flags.map_or(false, |flags| flags[i / 8] & (1 << (i % 8)) != 0);
+        if is_run {
+            let n_runs = reader.u16()?;
+            for _ in 0..n_runs {
+                let start = reader.u16()? as u64;
+                let length = reader.u16()? as u64;
+                out.extend((start..=start + length).map(|low| high | low));
+            }
+        } else if cardinality > MAX_ARRAY_CONTAINER_SIZE {
+            for word_idx in 0..1024u64 {
+                let mut word = reader.u64()?;
+                while word != 0 {
+                    out.push(high | (word_idx * 64 + word.trailing_zeros() as u64));
+                    word &= word - 1;
+                }
+            }
+        } else {
+            for _ in 0..cardinality {
+                out.push(high | reader.u16()? as u64);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Decode Z85, which encodes every 4 bytes as 5 printable characters.
+fn z85_decode(encoded: &str) -> PolarsResult<Vec<u8>> {
+    let invalid = || polars_err!(ComputeError: "invalid z85 string '{}'", encoded);
+    let encoded = encoded.as_bytes();
+    polars_ensure!(encoded.len() % 5 == 0, ComputeError: "invalid z85 string length {}", encoded.len());
+
+    let mut out = Vec::with_capacity(encoded.len() / 5 * 4);
+    for chunk in encoded.chunks_exact(5) {
+        let mut value = 0u64;
+        for c in chunk {
+            let digit = Z85_ALPHABET
+                .iter()
+                .position(|a| a == c)
+                .ok_or_else(invalid)?;
+            value = value * 85 + digit as u64;
+        }
+        let value = u32::try_from(value).map_err(|_| invalid())?;
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+    Ok(out)
+}
+
+fn format_uuid(bytes: &[u8]) -> String {
+    let hex = bytes.iter().map(|b| format!("{b:02x}")).collect::<String>();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_z85_decode() {
+        assert_eq!(
+            z85_decode("HelloWorld").unwrap(),
+            [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B]
+        );
+        assert!(z85_decode("Hello").is_ok());
+        assert!(z85_decode("Hell").is_err());
+        assert!(z85_decode("Hell~").is_err());
+    }
+
+    #[test]
+    fn test_decode_bitmap_array() {
+        let mut data = vec![];
+        data.extend_from_slice(&BITMAP_ARRAY_MAGIC.to_le_bytes());
+        data.extend_from_slice(&2u64.to_le_bytes());
+
+        // An array container with rows 1, 5 and 7.
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&SERIAL_COOKIE_NO_RUNCONTAINER.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&[0, 0, 2, 0]);
+        data.extend_from_slice(&0u32.to_le_bytes());
+        for row in [1u16, 5, 7] {
+            data.extend_from_slice(&row.to_le_bytes());
+        }
+
+        // A run container with rows 10 to 12 of the second 32-bit range.
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&SERIAL_COOKIE.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(&[0, 0, 2, 0]);
+        for v in [1u16, 10, 2] {
+            data.extend_from_slice(&v.to_le_bytes());
+        }
+
+        let dv = DeletionVector {
+            rows: decode_bitmap_array(&data).unwrap(),
+        };
+        let second = 1u64 << 32;
+        assert_eq!(dv.rows, [1, 5, 7, second + 10, second + 11, second + 12]);
+        assert!(dv.contains(5));
+        assert!(!dv.contains(6));
+
+        let row_index = IdxCa::from_slice("idx", &[0, 1, 5, 6]);
+        let mask = dv.keep_mask(&row_index);
+        assert_eq!(
+            mask.into_iter().collect::<Vec<_>>(),
+            [Some(true), Some(false), Some(false), Some(true)]
+        );
+    }
+
+    #[test]
+    fn test_format_uuid() {
+        let bytes = (0u8..16).collect::<Vec<_>>();
+        assert_eq!(format_uuid(&bytes), "00010203-0405-0607-0809-0a0b0c0d0e0f");
+    }
+}
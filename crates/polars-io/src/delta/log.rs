@@ -0,0 +1,404 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use percent_encoding::percent_decode_str;
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use serde::Deserialize;
+use serde_json::{Map, Number, Value};
+
+use super::deletion_vector::DeletionVectorDescriptor;
+use crate::parquet::read::ParquetReader;
+use crate::SerReader;
+
+/// The reader features of which tables can be read.
+const SUPPORTED_READER_FEATURES: &[&str] =
+    &["deletionVectors", "timestampNtz", "vacuumProtocolCheck"];
+
+/// A line of a commit file, or a row of a checkpoint. Only one of the fields is set.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Action {
+    #[serde(default)]
+    pub add: Option<Add>,
+    #[serde(default)]
+    pub remove: Option<Remove>,
+    #[serde(default)]
+    pub meta_data: Option<Metadata>,
+    #[serde(default)]
+    pub protocol: Option<Protocol>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Add {
+    pub path: String,
+    #[serde(default)]
+    pub partition_values: BTreeMap<String, Option<String>>,
+    /// JSON encoded [`FileStats`].
+    #[serde(default)]
+    pub stats: Option<String>,
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Remove {
+    pub path: String,
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Metadata {
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+    #[serde(default)]
+    pub configuration: BTreeMap<String, Option<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Protocol {
+    pub min_reader_version: i32,
+    #[serde(default)]
+    pub reader_features: Option<Vec<String>>,
+}
+
+/// Column statistics of a data file, as written by most Delta writers.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct FileStats {
+    #[serde(default)]
+    pub num_records: Option<u64>,
+    #[serde(default)]
+    pub min_values: Map<String, Value>,
+    #[serde(default)]
+    pub max_values: Map<String, Value>,
+    #[serde(default)]
+    pub null_count: Map<String, Value>,
+}
+
+impl Add {
+    /// The key of the file in the set of live files of the table.
+    pub fn key(&self) -> (String, Option<String>) {
+        file_key(&self.path, self.deletion_vector.as_ref())
+    }
+
+    pub fn file_stats(&self) -> Option<FileStats> {
+        // Statistics are optional, so files with invalid statistics are simply not pruned.
+        serde_json::from_str(self.stats.as_deref()?).ok()
+    }
+}
+
+impl Remove {
+    pub fn key(&self) -> (String, Option<String>) {
+        file_key(&self.path, self.deletion_vector.as_ref())
+    }
+}
+
+fn file_key(path: &str, dv: Option<&DeletionVectorDescriptor>) -> (String, Option<String>) {
+    (path.to_string(), dv.map(|dv| dv.unique_id()))
+}
+
+impl Protocol {
+    pub fn check_supported(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            self.min_reader_version <= 3,
+            ComputeError: "unsupported Delta reader version {}", self.min_reader_version
+        );
+        for feature in self.reader_features.iter().flatten() {
+            polars_ensure!(
+                SUPPORTED_READER_FEATURES.contains(&feature.as_str()),
+                ComputeError: "unsupported Delta reader feature '{}'", feature
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Metadata {
+    pub fn check_supported(&self) -> PolarsResult<()> {
+        let column_mapping = self
+            .configuration
+            .get("delta.columnMapping.mode")
+            .cloned()
+            .flatten();
+        polars_ensure!(
+            matches!(column_mapping.as_deref(), None | Some("none")),
+            ComputeError: "Delta tables with column mapping are not supported"
+        );
+        Ok(())
+    }
+}
+
+/// Resolve a path of the log, which is relative to the table root or an absolute URI.
+pub(super) fn resolve_path(table_root: &Path, path: &str) -> PolarsResult<PathBuf> {
+    let path = percent_decode_str(path)
+        .decode_utf8()
+        .map_err(to_compute_err)?;
+    if let Some(local) = path
+        .strip_prefix("file://")
+        .or_else(|| path.strip_prefix("file:"))
+    {
+        return Ok(PathBuf::from(local));
+    }
+    polars_ensure!(
+        !path.contains("://"),
+        ComputeError: "reading Delta files from '{}' is not supported; only local tables can be scanned",
+        path
+    );
+    Ok(table_root.join(&*path))
+}
+
+/// The files of the log that make up a version of the table: the last checkpoint at or before
+/// the version and the commits after it.
+#[derive(Clone, Debug)]
+pub(super) struct LogSegment {
+    pub version: i64,
+    pub checkpoint: Vec<PathBuf>,
+    /// Commit files in the order of their versions.
+    pub commits: Vec<PathBuf>,
+}
+
+/// Parse `<version>.checkpoint.parquet` and `<version>.checkpoint.<part>.<parts>.parquet`.
+fn parse_checkpoint_name(file_name: &str) -> Option<(i64, usize)> {
+    let mut parts = file_name.strip_suffix(".parquet")?.split('.');
+    let version = parts.next()?.parse().ok()?;
+    if parts.next()? != "checkpoint" {
+        return None;
+    }
+    let n_parts = match (parts.next(), parts.next(), parts.next()) {
+        (None, _, _) => 1,
+        (Some(part), Some(n_parts), None) => {
+            part.parse::<usize>().ok()?;
+            n_parts.parse().ok()?
+        },
+        _ => return None,
+    };
+    Some((version, n_parts))
+}
+
+pub(super) fn list_log_segment(log_dir: &Path, version: Option<i64>) -> PolarsResult<LogSegment> {
+    let mut commits = BTreeMap::new();
+    let mut checkpoints: BTreeMap<i64, (usize, Vec<PathBuf>)> = BTreeMap::new();
+    let entries = std::fs::read_dir(log_dir).map_err(
+        |err| polars_err!(ComputeError: "no Delta log found in '{}': {}", log_dir.display(), err),
+    )?;
+    for entry in entries {
+        let entry = entry?;
+        let file_name = entry.file_name();
+        let Some(file_name) = file_name.to_str() else {
+            continue;
+        };
+        if let Some(v) = file_name
+            .strip_suffix(".json")
+            .and_then(|v| v.parse::<i64>().ok())
+        {
+            commits.insert(v, entry.path());
+        } else if let Some((v, n_parts)) = parse_checkpoint_name(file_name) {
+            let (_, paths) = checkpoints.entry(v).or_insert_with(|| (n_parts, vec![]));
+            paths.push(entry.path());
+        }
+    }
+
+    let latest = commits.keys().chain(checkpoints.keys()).max().copied();
+    let version = match (version, latest) {
+        (Some(version), Some(latest)) => {
+            polars_ensure!(
+                version <= latest,
+                ComputeError: "Delta table version {} does not exist, the latest version is {}", version, latest
+            );
+            version
+        },
+        (None, Some(latest)) => latest,
+        (_, None) => polars_bail!(ComputeError: "no Delta log found in '{}'", log_dir.display()),
+    };
+
+    // Checkpoints with missing parts are still being written.
+    let checkpoint = checkpoints
+        .range(..=version)
+        .rev()
+        .find(|(_, (n_parts, paths))| paths.len() == *n_parts);
+    let (start, checkpoint) = match checkpoint {
+        Some((v, (_, paths))) => {
+            let mut paths = paths.clone();
+            paths.sort();
+            (*v + 1, paths)
+        },
+        None => (0, vec![]),
+    };
+
+    let commits = (start..=version)
+        .map(|v| {
+            commits.get(&v).cloned().ok_or_else(
+                || polars_err!(ComputeError: "Delta table version {} is no longer available", version),
+            )
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(LogSegment {
+        version,
+        checkpoint,
+        commits,
+    })
+}
+
+pub(super) fn read_commit(path: &Path) -> PolarsResult<Vec<Action>> {
+    let data = std::fs::read(path)?;
+    data.split(|b| *b == b'\n')
+        .filter(|line| !line.iter().all(u8::is_ascii_whitespace))
+        .map(|line| serde_json::from_slice(line).map_err(to_compute_err))
+        .collect()
+}
+
+/// Read the actions of a checkpoint file. Only the action types in `columns` are read.
+pub(super) fn read_checkpoint(path: &Path, columns: &[&str]) -> PolarsResult<Vec<Action>> {
+    let mut reader = ParquetReader::new(polars_utils::open_file(path)?);
+    let schema = reader.schema()?;
+    let columns = columns
+        .iter()
+        .filter(|name| schema.fields.iter().any(|field| field.name == **name))
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+    if columns.is_empty() {
+        return Ok(vec![]);
+    }
+    let df = reader.with_columns(Some(columns)).finish()?;
+
+    let columns = df.get_columns();
+    (0..df.height())
+        .filter_map(|i| {
+            // Every row holds a single action, the other columns are null.
+            let mut action = Map::new();
+            for s in columns {
+                match s.get(i) {
+                    Ok(AnyValue::Null) => {},
+                    Ok(value) => {
+                        action.insert(s.name().to_string(), to_json(&value));
+                    },
+                    Err(err) => return Some(Err(err)),
+                }
+            }
+            (!action.is_empty())
+                .then(|| serde_json::from_value(Value::Object(action)).map_err(to_compute_err))
+        })
+        .collect()
+}
+
+/// Convert a value of a checkpoint to the JSON of the corresponding commit action.
+fn to_json(value: &AnyValue) -> Value {
+    match value {
+        AnyValue::Null => Value::Null,
+        AnyValue::Boolean(v) => Value::Bool(*v),
+        AnyValue::String(v) => Value::String(v.to_string()),
+        AnyValue::StringOwned(v) => Value::String(v.to_string()),
+        AnyValue::Struct(_, _, fields) => Value::Object(
+            fields
+                .iter()
+                .zip(value._iter_struct_av())
+                .map(|(field, value)| (field.name().to_string(), to_json(&value)))
+                .collect(),
+        ),
+        AnyValue::List(s) => match s.dtype() {
+            // Parquet maps are read as lists of key-value structs.
+            DataType::Struct(fields)
+                if fields.len() == 2
+                    && fields[0].name() == "key"
+                    && fields[1].name() == "value" =>
+            {
+                Value::Object(
+                    s.iter()
+                        .filter_map(|entry| match to_json(&entry) {
+                            Value::Object(mut entry) => match entry.remove("key") {
+                                Some(Value::String(key)) => {
+                                    Some((key, entry.remove("value").unwrap_or(Value::Null)))
+                                },
+                                _ => None,
+                            },
+                            _ => None,
+                        })
+                        .collect(),
+                )
+            },
+            _ => Value::Array(s.iter().map(|v| to_json(&v)).collect()),
+        },
+        value if value.dtype().is_integer() => value
+            .extract::<i64>()
+            .map_or(Value::Null, |v| Value::Number(v.into())),
+        value if value.dtype().is_float() => value
+            .extract::<f64>()
+            .and_then(Number::from_f64)
+            .map_or(Value::Null, Value::Number),
+        _ => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_actions() {
+        let commit = r#"
+{"protocol":{"minReaderVersion":3,"minWriterVersion":7,"readerFeatures":["deletionVectors"],"writerFeatures":["deletionVectors"]}}
+{"metaData":{"id":"a","format":{"provider":"parquet","options":{}},"schemaString":"{\"type\":\"struct\",\"fields\":[]}","partitionColumns":["p"],"configuration":{},"createdTime":0}}
+{"add":{"path":"p=1/part-0.parquet","partitionValues":{"p":"1"},"size":10,"modificationTime":0,"dataChange":true,"stats":"{\"numRecords\":3}","deletionVector":{"storageType":"i","pathOrInlineDv":"wi5b=000010000siXQKl0rr91000f55c8Xg0@@D72lkbi5=-{L","sizeInBytes":40,"cardinality":6}}}
+{"remove":{"path":"p=1/part-0.parquet","dataChange":true}}
+"#;
+        let actions = commit
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_str::<Action>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(actions.len(), 4);
+
+        let protocol = actions[0].protocol.as_ref().unwrap();
+        protocol.check_supported().unwrap();
+        let metadata = actions[1].meta_data.as_ref().unwrap();
+        assert_eq!(metadata.partition_columns, ["p"]);
+
+        let add = actions[2].add.as_ref().unwrap();
+        assert_eq!(add.partition_values.get("p"), Some(&Some("1".to_string())));
+        assert_eq!(add.file_stats().unwrap().num_records, Some(3));
+        // The remove action removes the file without its deletion vector.
+        let remove = actions[3].remove.as_ref().unwrap();
+        assert_eq!(add.key().0, remove.key().0);
+        assert_ne!(add.key(), remove.key());
+    }
+
+    #[test]
+    fn test_parse_checkpoint_name() {
+        assert_eq!(
+            parse_checkpoint_name("00000000000000000010.checkpoint.parquet"),
+            Some((10, 1))
+        );
+        assert_eq!(
+            parse_checkpoint_name("00000000000000000010.checkpoint.0000000002.0000000003.parquet"),
+            Some((10, 3))
+        );
+        assert_eq!(
+            parse_checkpoint_name(
+                "00000000000000000010.checkpoint.80a083e8-7026-4e79-81be-64bd76c43a11.parquet"
+            ),
+            None
+        );
+        assert_eq!(parse_checkpoint_name("00000000000000000010.json"), None);
+    }
+
+    #[test]
+    fn test_resolve_path() {
+        let root = Path::new("/data/table");
+        assert_eq!(
+            resolve_path(root, "p=a%20b/part-0.parquet").unwrap(),
+            Path::new("/data/table/p=a b/part-0.parquet")
+        );
+        assert_eq!(
+            resolve_path(root, "file:///other/part-0.parquet").unwrap(),
+            Path::new("/other/part-0.parquet")
+        );
+        assert!(resolve_path(root, "s3://bucket/part-0.parquet").is_err());
+    }
+}
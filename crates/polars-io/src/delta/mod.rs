@@ -0,0 +1,17 @@
+//! # Read Delta Lake tables
+//!
+//! A [Delta] table is a directory of Parquet files and a transaction log in `_delta_log`. The
+//! log consists of JSON commits, which add and remove data files, and Parquet checkpoints,
+//! which summarize all commits up to a version. Replaying the log gives the live data files of
+//! a version of the table. The partition values and statistics of the files in the log are
+//! used to skip files that can't match a predicate. Rows that are deleted without rewriting
+//! their file are recorded in deletion vectors. Tables with column mapping are not supported.
+//!
+//! [Delta]: https://github.com/delta-io/delta/blob/master/PROTOCOL.md
+mod deletion_vector;
+mod log;
+mod schema;
+mod table;
+
+pub use deletion_vector::DeletionVector;
+pub use table::{DeltaDataFile, DeltaTable};
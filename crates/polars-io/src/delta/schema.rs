@@ -0,0 +1,123 @@
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Clone, Debug, Deserialize)]
+struct StructField {
+    name: String,
+    #[serde(rename = "type")]
+    r#type: Value,
+}
+
+/// Parse the `schemaString` of the table metadata.
+pub(super) fn parse_schema(schema_string: &str) -> PolarsResult<Schema> {
+    let schema: Value = serde_json::from_str(schema_string).map_err(to_compute_err)?;
+    match to_polars_dtype(&schema)? {
+        DataType::Struct(fields) => Ok(fields.into_iter().collect()),
+        _ => polars_bail!(ComputeError: "Delta table schema must be a struct"),
+    }
+}
+
+/// Convert a Delta type to a polars [`DataType`].
+fn to_polars_dtype(r#type: &Value) -> PolarsResult<DataType> {
+    let dtype = match r#type {
+        Value::String(name) => match name.as_str() {
+            "boolean" => DataType::Boolean,
+            "byte" => DataType::Int8,
+            "short" => DataType::Int16,
+            "integer" => DataType::Int32,
+            "long" => DataType::Int64,
+            "float" => DataType::Float32,
+            "double" => DataType::Float64,
+            "date" => DataType::Date,
+            "timestamp" => DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())),
+            "timestamp_ntz" => DataType::Datetime(TimeUnit::Microseconds, None),
+            "string" => DataType::String,
+            "binary" => DataType::Binary,
+            #[cfg(feature = "dtype-decimal")]
+            name if name.starts_with("decimal(") => {
+                let parse = || {
+                    let (precision, scale) = name
+                        .strip_prefix("decimal(")?
+                        .strip_suffix(')')?
+                        .split_once(',')?;
+                    Some(DataType::Decimal(
+                        Some(precision.trim().parse().ok()?),
+                        Some(scale.trim().parse().ok()?),
+                    ))
+                };
+                parse().ok_or_else(
+                    || polars_err!(ComputeError: "invalid Delta decimal type '{}'", name),
+                )?
+            },
+            name => polars_bail!(ComputeError: "unsupported Delta type '{}'", name),
+        },
+        Value::Object(object) => match object.get("type").and_then(|t| t.as_str()) {
+            Some("struct") => {
+                let fields = object
+                    .get("fields")
+                    .cloned()
+                    .map(serde_json::from_value::<Vec<StructField>>)
+                    .transpose()
+                    .map_err(to_compute_err)?
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|field| Ok(Field::new(&field.name, to_polars_dtype(&field.r#type)?)))
+                    .collect::<PolarsResult<_>>()?;
+                DataType::Struct(fields)
+            },
+            Some("array") => {
+                let element = object.get("elementType").unwrap_or(&Value::Null);
+                DataType::List(Box::new(to_polars_dtype(element)?))
+            },
+            Some("map") => {
+                let key = object.get("keyType").unwrap_or(&Value::Null);
+                let value = object.get("valueType").unwrap_or(&Value::Null);
+                DataType::List(Box::new(DataType::Struct(vec![
+                    Field::new("key", to_polars_dtype(key)?),
+                    Field::new("value", to_polars_dtype(value)?),
+                ])))
+            },
+            _ => polars_bail!(ComputeError: "unsupported Delta type '{}'", r#type),
+        },
+        _ => polars_bail!(ComputeError: "unsupported Delta type '{}'", r#type),
+    };
+    Ok(dtype)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_schema() {
+        let schema = parse_schema(
+            r#"{"type":"struct","fields":[
+                {"name":"id","type":"long","nullable":false,"metadata":{}},
+                {"name":"ts","type":"timestamp_ntz","nullable":true,"metadata":{}},
+                {"name":"tags","type":{"type":"array","elementType":"string","containsNull":true},"nullable":true,"metadata":{}},
+                {"name":"point","type":{"type":"struct","fields":[
+                    {"name":"x","type":"double","nullable":true,"metadata":{}}
+                ]},"nullable":true,"metadata":{}}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(schema.get("id"), Some(&DataType::Int64));
+        assert_eq!(
+            schema.get("ts"),
+            Some(&DataType::Datetime(TimeUnit::Microseconds, None))
+        );
+        assert_eq!(
+            schema.get("tags"),
+            Some(&DataType::List(Box::new(DataType::String)))
+        );
+        assert_eq!(
+            schema.get("point"),
+            Some(&DataType::Struct(vec![Field::new("x", DataType::Float64)]))
+        );
+        assert!(
+            parse_schema(r#"{"type":"struct","fields":[{"name":"v","type":"variant"}]}"#).is_err()
+        );
+    }
+}
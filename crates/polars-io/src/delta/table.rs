@@ -0,0 +1,329 @@
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDateTime;
+use polars_core::prelude::*;
+use serde_json::{Map, Value};
+
+use super::deletion_vector::{DeletionVector, DeletionVectorDescriptor};
+use super::log::{
+    list_log_segment, read_checkpoint, read_commit, resolve_path, Action, Add, FileStats,
+    LogSegment, Metadata,
+};
+use super::schema::parse_schema;
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr, StatsEvaluator};
+
+/// A live data file of a Delta table.
+#[derive(Clone, Debug)]
+pub struct DeltaDataFile {
+    pub path: PathBuf,
+    /// Single-value series with the values of the partition columns of the file, in the order
+    /// of the partition columns of the table.
+    pub partition_values: Vec<Series>,
+    /// The number of rows in the file, before deletion vectors are applied.
+    pub num_records: Option<u64>,
+    deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+impl DeltaDataFile {
+    /// Whether rows of the file were deleted.
+    pub fn has_deletion_vector(&self) -> bool {
+        self.deletion_vector.is_some()
+    }
+}
+
+/// A version of a Delta table.
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::delta::DeltaTable;
+///
+/// fn example() -> PolarsResult<()> {
+///     let table = DeltaTable::open("/warehouse/trades", None)?;
+///     for data_file in table.data_files(None)? {
+///         println!("{}", data_file.path.display());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DeltaTable {
+    root: PathBuf,
+    segment: LogSegment,
+    metadata: Metadata,
+    schema: SchemaRef,
+}
+
+impl DeltaTable {
+    /// Open the table in directory `location` at `version`, or at its latest version.
+    pub fn open(location: impl AsRef<Path>, version: Option<i64>) -> PolarsResult<Self> {
+        let root = location.as_ref().to_path_buf();
+        let segment = list_log_segment(&root.join("_delta_log"), version)?;
+
+        // The latest metadata and protocol are usually found in the last few commits.
+        let mut metadata = None;
+        let mut protocol = None;
+        for path in segment.commits.iter().rev() {
+            for action in read_commit(path)? {
+                metadata = metadata.or(action.meta_data);
+                protocol = protocol.or(action.protocol);
+            }
+            if metadata.is_some() && protocol.is_some() {
+                break;
+            }
+        }
+        if metadata.is_none() || protocol.is_none() {
+            for path in &segment.checkpoint {
+                for action in read_checkpoint(path, &["metaData", "protocol"])? {
+                    metadata = metadata.or(action.meta_data);
+                    protocol = protocol.or(action.protocol);
+                }
+            }
+        }
+        let (Some(metadata), Some(protocol)) = (metadata, protocol) else {
+            polars_bail!(ComputeError: "Delta log of '{}' has no metadata", root.display())
+        };
+        protocol.check_supported()?;
+        metadata.check_supported()?;
+
+        let schema = Arc::new(parse_schema(&metadata.schema_string)?);
+        for name in &metadata.partition_columns {
+            polars_ensure!(
+                schema.contains(name),
+                ComputeError: "Delta partition column '{}' is not in the table schema", name
+            );
+        }
+        Ok(Self {
+            root,
+            segment,
+            metadata,
+            schema,
+        })
+    }
+
+    pub fn version(&self) -> i64 {
+        self.segment.version
+    }
+
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn partition_columns(&self) -> &[String] {
+        &self.metadata.partition_columns
+    }
+
+    /// Replay the log to find the live data files of the table. Files of which the partition
+    /// values and statistics show that no row matches `predicate` are dropped during the replay.
+    pub fn data_files(
+        &self,
+        predicate: Option<&dyn PhysicalIoExpr>,
+    ) -> PolarsResult<Vec<DeltaDataFile>> {
+        let evaluator = predicate.and_then(|predicate| predicate.as_stats_evaluator());
+        let mut files = PlIndexMap::default();
+
+        let mut apply = |action: Action| -> PolarsResult<()> {
+            if let Some(remove) = action.remove {
+                files.shift_remove(&remove.key());
+            }
+            if let Some(add) = action.add {
+                let key = add.key();
+                match self.to_data_file(&add, evaluator)? {
+                    Some(data_file) => files.insert(key, data_file),
+                    None => files.shift_remove(&key),
+                };
+            }
+            Ok(())
+        };
+        // Checkpoints only contain the live files; their remove actions are tombstones that
+        // don't have to be replayed.
+        for path in &self.segment.checkpoint {
+            read_checkpoint(path, &["add"])?
+                .into_iter()
+                .try_for_each(&mut apply)?;
+        }
+        for path in &self.segment.commits {
+            read_commit(path)?.into_iter().try_for_each(&mut apply)?;
+        }
+
+        Ok(files.into_values().collect())
+    }
+
+    /// Load the positions of the deleted rows of `data_file`.
+    pub fn deletion_vector(
+        &self,
+        data_file: &DeltaDataFile,
+    ) -> PolarsResult<Option<DeletionVector>> {
+        data_file
+            .deletion_vector
+            .as_ref()
+            .map(|dv| dv.load(&self.root))
+            .transpose()
+    }
+
+    /// Convert an add action, returns `None` if the file is pruned by `evaluator`.
+    fn to_data_file(
+        &self,
+        add: &Add,
+        evaluator: Option<&dyn StatsEvaluator>,
+    ) -> PolarsResult<Option<DeltaDataFile>> {
+        let partition_values = self
+            .metadata
+            .partition_columns
+            .iter()
+            .map(|name| {
+                let value = add.partition_values.get(name).cloned().flatten();
+                parse_partition_value(name, value.as_deref(), self.schema.try_get(name)?)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let file_stats = add.file_stats().unwrap_or_default();
+
+        if let Some(evaluator) = evaluator {
+            let stats = self
+                .schema
+                .iter_fields()
+                .map(
+                    |field| match partition_values.iter().find(|s| s.name() == field.name()) {
+                        Some(value) => partition_stats(field, value, file_stats.num_records),
+                        None => column_stats(field, &file_stats),
+                    },
+                )
+                .collect();
+            let stats = BatchStats::new(
+                self.schema.clone(),
+                stats,
+                file_stats.num_records.map(|n| n as usize),
+            );
+            let should_read = match evaluator.should_read(&stats) {
+                Err(PolarsError::ColumnNotFound(_)) => true,
+                should_read => should_read?,
+            };
+            if !should_read {
+                return Ok(None);
+            }
+        }
+
+        Ok(Some(DeltaDataFile {
+            path: resolve_path(&self.root, &add.path)?,
+            partition_values,
+            num_records: file_stats.num_records,
+            deletion_vector: add.deletion_vector.clone(),
+        }))
+    }
+}
+
+/// Parse a partition value, which is serialized as a string.
+fn parse_partition_value(
+    name: &str,
+    value: Option<&str>,
+    dtype: &DataType,
+) -> PolarsResult<Series> {
+    // Writers serialize null as an empty string, or omit it.
+    let value = match value {
+        Some(value) if !value.is_empty() => value,
+        _ => return Ok(Series::full_null(name, 1, dtype)),
+    };
+    let invalid = || polars_err!(ComputeError: "invalid value '{}' for Delta partition column '{}'", value, name);
+    let s = match dtype {
+        DataType::Boolean => Series::new(name, [value.parse::<bool>().map_err(|_| invalid())?]),
+        DataType::Datetime(_, _) => {
+            let datetime = ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.fZ"]
+                .iter()
+                .find_map(|fmt| NaiveDateTime::parse_from_str(value, fmt).ok())
+                .ok_or_else(invalid)?;
+            Series::new(name, [datetime.and_utc().timestamp_micros()]).cast(dtype)?
+        },
+        _ => Series::new(name, [value])
+            .strict_cast(dtype)
+            .map_err(|_| invalid())?,
+    };
+    Ok(s)
+}
+
+/// All rows of a file have the partition value of the file.
+fn partition_stats(field: Field, value: &Series, num_records: Option<u64>) -> ColumnStats {
+    if value.null_count() > 0 {
+        let null_count = num_records.map(|n| Series::new("", [n as IdxSize]));
+        return ColumnStats::new(field, null_count, None, None);
+    }
+    let mut value = value.clone();
+    value.rename("");
+    ColumnStats::new(
+        field,
+        Some(Series::new("", [0 as IdxSize])),
+        Some(value.clone()),
+        Some(value),
+    )
+}
+
+fn column_stats(field: Field, file_stats: &FileStats) -> ColumnStats {
+    let name = field.name().as_str();
+    let null_count = file_stats
+        .null_count
+        .get(name)
+        .and_then(|v| v.as_u64())
+        .map(|n| Series::new("", [n as IdxSize]));
+    let bound = |values: &Map<String, Value>| {
+        values
+            .get(name)
+            .and_then(|value| to_stat(value, field.data_type()))
+    };
+    let (min, max) = (bound(&file_stats.min_values), bound(&file_stats.max_values));
+    ColumnStats::new(field, null_count, min, max)
+}
+
+/// Convert a bound of the file statistics to a single-value [`Series`] of `dtype`.
+fn to_stat(value: &Value, dtype: &DataType) -> Option<Series> {
+    // Timestamp bounds are truncated to milliseconds, so the maximum may be smaller than the
+    // actual maximum. Nested columns have nested bounds, which aren't used.
+    if matches!(dtype, DataType::Datetime(_, _)) || dtype.is_nested() {
+        return None;
+    }
+    let s = match value {
+        Value::Bool(v) => Series::new("", [*v]),
+        Value::Number(v) => match v.as_i64() {
+            Some(v) => Series::new("", [v]),
+            None => Series::new("", [v.as_f64()?]),
+        },
+        Value::String(v) => Series::new("", [v.as_str()]),
+        _ => return None,
+    };
+    // Values that can't be cast become null and are ignored.
+    let s = s.cast(dtype).ok()?;
+    (s.null_count() == 0).then_some(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_partition_value() {
+        let date = parse_partition_value("d", Some("2024-03-01"), &DataType::Date).unwrap();
+        assert_eq!(date.dtype(), &DataType::Date);
+        assert_eq!(date.name(), "d");
+
+        let dtype = DataType::Datetime(TimeUnit::Microseconds, None);
+        let ts = parse_partition_value("ts", Some("2024-03-01 12:00:00.5"), &dtype).unwrap();
+        assert_eq!(
+            ts.to_physical_repr().i64().unwrap().get(0),
+            Some(1709294400500000)
+        );
+
+        let null = parse_partition_value("a", Some(""), &DataType::Int32).unwrap();
+        assert_eq!(null.null_count(), 1);
+        assert!(parse_partition_value("a", Some("x"), &DataType::Int32).is_err());
+    }
+
+    #[test]
+    fn test_to_stat() {
+        let stat = to_stat(&Value::from(3), &DataType::Int32).unwrap();
+        assert_eq!(stat.dtype(), &DataType::Int32);
+        let stat = to_stat(&Value::from("2024-03-01"), &DataType::Date).unwrap();
+        assert_eq!(stat.dtype(), &DataType::Date);
+        let dtype = DataType::Datetime(TimeUnit::Microseconds, None);
+        assert!(to_stat(&Value::from("2024-03-01T00:00:00.000Z"), &dtype).is_none());
+        assert!(to_stat(&Value::from("x"), &DataType::Int32).is_none());
+    }
+}
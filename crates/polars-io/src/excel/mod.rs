@@ -0,0 +1,12 @@
+//! # Reading Excel (xlsx) workbooks
+//!
+//! Reading is implemented natively: the zip container and the XML parts of the workbook are
+//! decoded without external libraries. Cells are read as their stored values, formatted numbers
+//! that show dates become dates. The legacy binary formats (xls, xlsb) are not supported.
+mod read;
+mod sheet;
+mod workbook;
+mod xml;
+mod zip;
+
+pub use read::*;
@@ -0,0 +1,629 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use polars_core::prelude::*;
+
+use super::sheet::{parse_reference, Cell, SheetRow, SheetRows, MS_PER_DAY};
+use super::workbook::Workbook;
+use crate::{RowIndex, SerReader};
+
+/// The sheet of a workbook to read.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExcelSheet {
+    /// Zero based position among the worksheets of the workbook. Chart sheets are not counted.
+    Index(usize),
+    Name(String),
+}
+
+impl Default for ExcelSheet {
+    fn default() -> Self {
+        Self::Index(0)
+    }
+}
+
+/// A rectangle of cells that is read from a sheet, parsed from references like `B2:D10`.
+///
+/// Either end may leave out the row or the column: `B2:D` reads all rows from the second one on,
+/// `B:D` reads all rows and a single reference like `B2` only sets where the range starts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CellRange {
+    /// Zero based row where the range starts.
+    pub first_row: usize,
+    /// Zero based column where the range starts.
+    pub first_column: usize,
+    /// Zero based, inclusive row where the range ends.
+    pub last_row: Option<usize>,
+    /// Zero based, inclusive column where the range ends.
+    pub last_column: Option<usize>,
+}
+
+impl CellRange {
+    fn contains_column(&self, column: usize) -> bool {
+        column >= self.first_column && self.last_column.map_or(true, |last| column <= last)
+    }
+}
+
+impl FromStr for CellRange {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> PolarsResult<Self> {
+        let invalid = || {
+            polars_err!(
+                InvalidOperation: "invalid cell range {:?}; expected a range like \"B2:D10\"", s
+            )
+        };
+        polars_ensure!(!s.trim().is_empty(), InvalidOperation: "cell range cannot be empty");
+        let (start, end) = match s.split_once(':') {
+            Some((start, end)) => (start, Some(end)),
+            None => (s, None),
+        };
+        let (first_column, first_row) = parse_reference(start).ok_or_else(invalid)?;
+        let (last_column, last_row) = match end {
+            Some(end) => parse_reference(end).ok_or_else(invalid)?,
+            None => (None, None),
+        };
+
+        let range = Self {
+            first_row: first_row.unwrap_or(0),
+            first_column: first_column.unwrap_or(0),
+            last_row,
+            last_column,
+        };
+        polars_ensure!(
+            range.last_row.map_or(true, |last| last >= range.first_row)
+                && range.last_column.map_or(true, |last| last >= range.first_column),
+            InvalidOperation: "cell range {:?} ends before it starts", s
+        );
+        Ok(range)
+    }
+}
+
+/// The cells of the part of a sheet that is read, by column.
+struct Table {
+    names: Vec<String>,
+    columns: Vec<Vec<Cell>>,
+}
+
+/// Rename duplicate column names like the CSV reader does.
+fn dedup_names(names: Vec<String>) -> Vec<String> {
+    let mut counts = PlHashMap::with_capacity(names.len());
+    names
+        .into_iter()
+        .map(|name| {
+            let count = counts.entry(name.clone()).or_insert(0usize);
+            *count += 1;
+            if *count > 1 {
+                format!("{}_duplicated_{}", name, *count - 2)
+            } else {
+                name
+            }
+        })
+        .collect()
+}
+
+/// The type of a column: integral numbers are read as integers, dates without a time of day as
+/// dates and columns that mix types as strings.
+fn infer_dtype(cells: &[Cell]) -> DataType {
+    let mut dtype = None;
+    for cell in cells {
+        let cell_dtype = match cell {
+            Cell::Empty | Cell::Error => continue,
+            Cell::Bool(_) => DataType::Boolean,
+            Cell::Number(v) if v.fract() == 0.0 && v.abs() < i64::MAX as f64 => DataType::Int64,
+            Cell::Number(_) => DataType::Float64,
+            Cell::DateTime(days) if days.fract() == 0.0 => DataType::Date,
+            Cell::DateTime(_) => DataType::Datetime(TimeUnit::Milliseconds, None),
+            Cell::String(_) => return DataType::String,
+        };
+        dtype = Some(match (dtype, cell_dtype) {
+            (None, cell_dtype) => cell_dtype,
+            (Some(dtype), cell_dtype) if dtype == cell_dtype => dtype,
+            (Some(DataType::Int64 | DataType::Float64), DataType::Int64 | DataType::Float64) => {
+                DataType::Float64
+            },
+            (
+                Some(DataType::Date | DataType::Datetime(_, _)),
+                DataType::Date | DataType::Datetime(_, _),
+            ) => DataType::Datetime(TimeUnit::Milliseconds, None),
+            _ => return DataType::String,
+        });
+    }
+    dtype.unwrap_or(DataType::String)
+}
+
+/// Build a column of the given type. Cells that do not fit the type become null.
+fn cells_to_series(name: &str, cells: &[Cell], dtype: &DataType) -> PolarsResult<Series> {
+    let s = match dtype {
+        DataType::Boolean => cells
+            .iter()
+            .map(|cell| match cell {
+                Cell::Bool(v) => Some(*v),
+                _ => None,
+            })
+            .collect::<BooleanChunked>()
+            .into_series(),
+        DataType::String => cells
+            .iter()
+            .map(Cell::to_text)
+            .collect::<StringChunked>()
+            .into_series(),
+        DataType::Date => cells
+            .iter()
+            .map(|cell| match cell {
+                Cell::DateTime(days) => Some(days.floor() as i32),
+                _ => None,
+            })
+            .collect::<Int32Chunked>()
+            .into_date()
+            .into_series(),
+        DataType::Datetime(tu, tz) => {
+            let per_ms = match tu {
+                TimeUnit::Nanoseconds => 1_000_000,
+                TimeUnit::Microseconds => 1_000,
+                TimeUnit::Milliseconds => 1,
+            };
+            // Serial numbers hold times with rounding errors, which are rounded to milliseconds.
+            cells
+                .iter()
+                .map(|cell| match cell {
+                    Cell::DateTime(days) => Some((days * MS_PER_DAY).round() as i64 * per_ms),
+                    _ => None,
+                })
+                .collect::<Int64Chunked>()
+                .into_datetime(*tu, tz.clone())
+                .into_series()
+        },
+        dtype if dtype.is_numeric() => cells
+            .iter()
+            .map(Cell::to_number)
+            .collect::<Float64Chunked>()
+            .into_series()
+            .cast(dtype)?,
+        dtype => cells_to_series(name, cells, &DataType::String)?.cast(dtype)?,
+    };
+    Ok(s.with_name(name))
+}
+
+/// Read a sheet of an Excel (xlsx) workbook into a [`DataFrame`]
+///
+/// Reading is implemented natively and stops at the last row that is needed, so reading the first
+/// rows of a large sheet is cheap. Formulas are not evaluated, the value that the workbook stores
+/// for them is read.
+///
+/// # Example
+/// ```
+/// use std::fs::File;
+/// use polars_core::prelude::*;
+/// use polars_io::excel::{ExcelReader, ExcelSheet};
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("file.xlsx").expect("file not found");
+///
+///     ExcelReader::new(file)
+///             .with_sheet(ExcelSheet::Name("data".into()))
+///             .with_range(Some("B2:E".parse()?))
+///             .finish()
+/// }
+/// ```
+#[must_use]
+pub struct ExcelReader<R> {
+    reader: R,
+    sheet: ExcelSheet,
+    has_header: bool,
+    skip_rows: usize,
+    range: Option<CellRange>,
+    n_rows: Option<usize>,
+    columns: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+    row_index: Option<RowIndex>,
+    schema_overwrite: Option<SchemaRef>,
+    infer_schema_length: Option<usize>,
+    workbook: Option<Workbook>,
+}
+
+impl<R: Read> ExcelReader<R> {
+    fn workbook(&mut self) -> PolarsResult<&Workbook> {
+        if self.workbook.is_none() {
+            let mut data = vec![];
+            self.reader.read_to_end(&mut data)?;
+            self.workbook = Some(Workbook::new(data)?);
+        }
+        Ok(self.workbook.as_ref().unwrap())
+    }
+
+    /// Names of the worksheets of the workbook, in order.
+    pub fn sheet_names(&mut self) -> PolarsResult<Vec<String>> {
+        Ok(self.workbook()?.sheet_names())
+    }
+
+    /// Get the schema of the sheet, which is inferred from its first rows.
+    pub fn schema(&mut self) -> PolarsResult<Schema> {
+        let table = self.read_table(self.infer_schema_length)?;
+        Ok(self.table_schema(&table))
+    }
+
+    /// Select the sheet to read, the first one by default.
+    pub fn with_sheet(mut self, sheet: ExcelSheet) -> Self {
+        self.sheet = sheet;
+        self
+    }
+
+    /// Set whether the first row holds the column names. Defaults to `true`.
+    pub fn with_has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Skip rows at the start of the range, before the header.
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+
+    /// Only read the cells within this range. By default the range starts at the first row and
+    /// column that hold a value and covers all cells after them.
+    pub fn with_range(mut self, range: Option<CellRange>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Add a row index column.
+    pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
+        self.row_index = row_index;
+        self
+    }
+
+    /// Overwrite the inferred types of the columns in this schema. Cells that do not fit the
+    /// type are read as null.
+    pub fn with_schema_overwrite(mut self, schema: Option<SchemaRef>) -> Self {
+        self.schema_overwrite = schema;
+        self
+    }
+
+    /// Set the number of rows that the column types are inferred from. `None` uses all rows.
+    pub fn with_infer_schema_length(mut self, infer_schema_length: Option<usize>) -> Self {
+        self.infer_schema_length = infer_schema_length;
+        self
+    }
+
+    /// Read the cells of the range, up to `max_rows` rows after the header.
+    fn read_table(&mut self, max_rows: Option<usize>) -> PolarsResult<Table> {
+        let range = self.range.unwrap_or_default();
+        let fixed_start = self.range.is_some();
+        let has_header = self.has_header;
+        let header_row = self.skip_rows;
+        let header_rows = self.skip_rows + has_header as usize;
+        let sheet = self.sheet.clone();
+
+        let workbook = self.workbook()?;
+        let data = workbook.sheet_data(&sheet)?;
+        let mut rows = SheetRows::new(workbook, &data);
+
+        // Without a range, the first row that holds a value starts it.
+        let mut first_row = fixed_start.then_some(range.first_row);
+        let mut read_rows: Vec<SheetRow> = vec![];
+        while let Some(mut row) = rows.next_row()? {
+            if row.index < range.first_row {
+                continue;
+            }
+            if range.last_row.is_some_and(|last| row.index > last) {
+                break;
+            }
+            row.cells
+                .retain(|(column, _)| range.contains_column(*column));
+            let first = match first_row {
+                Some(first) => first,
+                None if row.cells.is_empty() => continue,
+                None => *first_row.insert(row.index),
+            };
+            if max_rows.is_some_and(|max| row.index - first >= header_rows + max) {
+                break;
+            }
+            read_rows.push(row);
+        }
+        let first_row = first_row.unwrap_or(range.first_row);
+        let data_start = first_row + header_rows;
+
+        let columns = || {
+            read_rows
+                .iter()
+                .flat_map(|row| row.cells.iter().map(|(c, _)| *c))
+        };
+        let first_column = if fixed_start {
+            range.first_column
+        } else {
+            columns().min().unwrap_or(0)
+        };
+        let width = range
+            .last_column
+            .or_else(|| columns().max())
+            .map_or(0, |last| last + 1 - first_column);
+
+        let mut names = (1..=width)
+            .map(|i| format!("column_{i}"))
+            .collect::<Vec<_>>();
+        if has_header {
+            if let Some(header) = read_rows
+                .iter()
+                .find(|row| row.index == first_row + header_row)
+            {
+                for (column, cell) in &header.cells {
+                    if let Some(name) = cell.to_text() {
+                        names[column - first_column] = name;
+                    }
+                }
+            }
+        }
+
+        let mut height = read_rows
+            .iter()
+            .map(|row| (row.index + 1).saturating_sub(data_start))
+            .max()
+            .unwrap_or(0);
+        if let Some(max) = max_rows {
+            height = height.min(max);
+        }
+        let mut columns = vec![vec![Cell::Empty; height]; width];
+        for row in read_rows {
+            if row.index < data_start || row.index - data_start >= height {
+                continue;
+            }
+            for (column, cell) in row.cells {
+                columns[column - first_column][row.index - data_start] = cell;
+            }
+        }
+        Ok(Table {
+            names: dedup_names(names),
+            columns,
+        })
+    }
+
+    fn table_schema(&self, table: &Table) -> Schema {
+        table
+            .names
+            .iter()
+            .zip(&table.columns)
+            .map(|(name, cells)| {
+                let overwrite = self.schema_overwrite.as_ref().and_then(|s| s.get(name));
+                let dtype = match overwrite {
+                    Some(dtype) => dtype.clone(),
+                    None => {
+                        let n = self.infer_schema_length.unwrap_or(cells.len());
+                        infer_dtype(&cells[..n.min(cells.len())])
+                    },
+                };
+                Field::new(name, dtype)
+            })
+            .collect()
+    }
+}
+
+impl<R> SerReader<R> for ExcelReader<R>
+where
+    R: Read,
+{
+    fn new(reader: R) -> Self {
+        ExcelReader {
+            reader,
+            sheet: ExcelSheet::default(),
+            has_header: true,
+            skip_rows: 0,
+            range: None,
+            n_rows: None,
+            columns: None,
+            projection: None,
+            row_index: None,
+            schema_overwrite: None,
+            infer_schema_length: Some(100),
+            workbook: None,
+        }
+    }
+
+    /// The columns of a sheet are always read into a single chunk.
+    fn set_rechunk(self, _rechunk: bool) -> Self {
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let table = self.read_table(self.n_rows)?;
+        let schema = self.table_schema(&table);
+        if let Some(columns) = &self.columns {
+            self.projection = Some(
+                columns
+                    .iter()
+                    .map(|name| schema.try_get_full(name).map(|(i, _, _)| i))
+                    .collect::<PolarsResult<_>>()?,
+            );
+        }
+        let projection = self
+            .projection
+            .take()
+            .unwrap_or_else(|| (0..schema.len()).collect());
+
+        let columns = projection
+            .iter()
+            .map(|&i| {
+                let (name, dtype) = schema.try_get_at_index(i)?;
+                cells_to_series(name, &table.columns[i], dtype)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut df = DataFrame::new(columns)?;
+        if let Some(row_index) = &self.row_index {
+            df.with_row_index_mut(&row_index.name, Some(row_index.offset));
+        }
+        Ok(df)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use polars_core::df;
+
+    use super::*;
+
+    /// A zip archive that stores the files without compression.
+    fn zip(files: &[(&str, &str)]) -> Vec<u8> {
+        let mut out = vec![];
+        let mut directory = vec![];
+        for (name, content) in files {
+            let sizes = [content.len() as u32; 2].map(u32::to_le_bytes).concat();
+            let offset = out.len() as u32;
+            out.extend(0x04034b50u32.to_le_bytes());
+            // Version, flags, method, time, date and crc.
+            out.extend([0u8; 14]);
+            out.extend(&sizes);
+            out.extend((name.len() as u16).to_le_bytes());
+            out.extend([0u8; 2]);
+            out.extend(name.as_bytes());
+            out.extend(content.as_bytes());
+
+            directory.extend(0x02014b50u32.to_le_bytes());
+            directory.extend([0u8; 16]);
+            directory.extend(&sizes);
+            directory.extend((name.len() as u16).to_le_bytes());
+            directory.extend([0u8; 12]);
+            directory.extend(offset.to_le_bytes());
+            directory.extend(name.as_bytes());
+        }
+        let n = (files.len() as u16).to_le_bytes();
+        let directory_offset = (out.len() as u32).to_le_bytes();
+        let directory_size = (directory.len() as u32).to_le_bytes();
+        out.extend(directory);
+        out.extend(0x06054b50u32.to_le_bytes());
+        out.extend([0u8; 4]);
+        out.extend([n, n].concat());
+        out.extend(directory_size);
+        out.extend(directory_offset);
+        out.extend([0u8; 2]);
+        out
+    }
+
+    fn workbook() -> Vec<u8> {
+        let rel = |id: &str, kind: &str, target: &str| {
+            format!(
+                r#"<Relationship Id="{id}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/{kind}" Target="{target}"/>"#
+            )
+        };
+        let package_rels = format!(
+            "<Relationships>{}</Relationships>",
+            rel("rId1", "officeDocument", "xl/workbook.xml")
+        );
+        let workbook_rels = format!(
+            "<Relationships>{}{}{}{}</Relationships>",
+            rel("rId1", "worksheet", "worksheets/sheet1.xml"),
+            rel("rId2", "worksheet", "/xl/worksheets/sheet2.xml"),
+            rel("rId3", "sharedStrings", "sharedStrings.xml"),
+            rel("rId4", "styles", "styles.xml"),
+        );
+        let workbook = r#"<workbook xmlns:r="r"><workbookPr/><sheets><sheet name="first" sheetId="1" r:id="rId1"/><sheet name="second" sheetId="2" r:id="rId2"/></sheets></workbook>"#;
+        let shared_strings =
+            "<sst><si><t>name</t></si><si><r><t>a</t></r><rPh><t>x</t></rPh></si></sst>";
+        let styles = r#"<styleSheet><cellStyleXfs><xf numFmtId="0"/></cellStyleXfs><cellXfs><xf numFmtId="0"/><xf numFmtId="14"/></cellXfs></styleSheet>"#;
+        let sheet1 = r#"<worksheet><dimension ref="A1:D4"/><sheetData>
+            <row r="1"><c r="A1" t="s"><v>0</v></c><c r="B1" t="inlineStr"><is><t>value</t></is></c><c r="C1" t="inlineStr"><is><t>day</t></is></c><c r="D1" t="inlineStr"><is><t>flag</t></is></c></row>
+            <row r="2"><c r="A2" t="s"><v>1</v></c><c r="B2"><v>1</v></c><c r="C2" s="1"><v>45000</v></c><c r="D2" t="b"><v>1</v></c></row>
+            <row r="4"><c r="A4" t="str"><f>A2</f><v>b&amp;c</v></c><c r="B4"><v>2.5</v></c><c r="C4" s="1"><v>45000.5</v></c><c r="D4" t="e"><v>#N/A</v></c></row>
+        </sheetData><pageMargins/></worksheet>"#;
+        let sheet2 = r#"<worksheet><sheetData>
+            <row r="2"><c r="C2"><v>1</v></c><c r="D2"><v>2</v></c></row>
+            <row r="3"><c r="B3"><v>0</v></c><c r="C3"><v>3</v></c><c r="D3" t="inlineStr"><is><t>x</t></is></c></row>
+            <row r="4"><c r="C4"><v>5</v></c><c r="D4"><v>6</v></c></row>
+        </sheetData></worksheet>"#;
+        zip(&[
+            ("_rels/.rels", &package_rels),
+            ("xl/_rels/workbook.xml.rels", &workbook_rels),
+            ("xl/workbook.xml", workbook),
+            ("xl/sharedStrings.xml", shared_strings),
+            ("xl/styles.xml", styles),
+            ("xl/worksheets/sheet1.xml", sheet1),
+            ("xl/worksheets/sheet2.xml", sheet2),
+        ])
+    }
+
+    #[test]
+    fn test_read_excel() -> PolarsResult<()> {
+        let mut reader = ExcelReader::new(Cursor::new(workbook()));
+        assert_eq!(reader.sheet_names()?, ["first", "second"]);
+        let df = reader.finish()?;
+
+        // 2023-03-15 and noon of that day.
+        let day = Series::new("day", [Some(1678838400000i64), None, Some(1678881600000)])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+        let expected = DataFrame::new(vec![
+            Series::new("name", [Some("a"), None, Some("b&c")]),
+            Series::new("value", [Some(1.0), None, Some(2.5)]),
+            day,
+            Series::new("flag", [Some(true), None, None]),
+        ])?;
+        assert!(df.equals_missing(&expected));
+
+        let df = ExcelReader::new(Cursor::new(workbook()))
+            .with_columns(Some(vec!["day".into()]))
+            .with_n_rows(Some(1))
+            .with_row_index(Some(RowIndex {
+                name: Arc::from("idx"),
+                offset: 0,
+            }))
+            .finish()?;
+        assert_eq!(df.get_column_names(), ["idx", "day"]);
+        assert_eq!(df.column("day")?.dtype(), &DataType::Date);
+        assert_eq!(df.height(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_excel_range() -> PolarsResult<()> {
+        let read = |reader: ExcelReader<Cursor<Vec<u8>>>| {
+            reader
+                .with_sheet(ExcelSheet::Name("second".into()))
+                .finish()
+        };
+
+        // The range starts at the first cell with a value.
+        let df = read(ExcelReader::new(Cursor::new(workbook())).with_has_header(false))?;
+        assert_eq!(df.shape(), (3, 3));
+        assert_eq!(df.get_column_names(), ["column_1", "column_2", "column_3"]);
+
+        let df = read(
+            ExcelReader::new(Cursor::new(workbook()))
+                .with_range(Some("C2:D".parse()?))
+                .with_skip_rows(1),
+        )?;
+        let expected = df!["3" => [5i64], "x" => [6i64]]?;
+        assert!(df.equals(&expected));
+
+        let df = read(
+            ExcelReader::new(Cursor::new(workbook()))
+                .with_range(Some("C2:C3".parse()?))
+                .with_has_header(false),
+        )?;
+        assert!(df.equals(&df!["column_1" => [1i64, 3]]?));
+
+        assert!("D2:C3".parse::<CellRange>().is_err());
+        let reader = ExcelReader::new(Cursor::new(workbook())).with_sheet(ExcelSheet::Index(2));
+        assert!(reader.finish().is_err());
+        Ok(())
+    }
+}
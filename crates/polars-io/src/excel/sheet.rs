@@ -0,0 +1,270 @@
+use arrow::temporal_conversions::{date32_to_date, timestamp_ms_to_datetime};
+use chrono::{NaiveDate, NaiveDateTime};
+use polars_core::prelude::*;
+
+use super::workbook::{unescape_xstring, Workbook};
+use super::xml::{Tag, XmlEvent, XmlReader};
+
+pub(super) const MS_PER_DAY: f64 = 86_400_000.0;
+
+/// The value of a cell.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum Cell {
+    Empty,
+    Bool(bool),
+    Number(f64),
+    /// A number that is formatted as a date, in days since the unix epoch.
+    DateTime(f64),
+    String(String),
+    /// A formula that failed, like `#DIV/0!`.
+    Error,
+}
+
+impl Cell {
+    pub(super) fn to_text(&self) -> Option<String> {
+        match self {
+            Cell::Empty | Cell::Error => None,
+            Cell::Bool(v) => Some(v.to_string()),
+            Cell::Number(v) if v.fract() == 0.0 && v.abs() < 1e15 => Some((*v as i64).to_string()),
+            Cell::Number(v) => Some(v.to_string()),
+            Cell::DateTime(days) if days.fract() == 0.0 => {
+                Some(date32_to_date(*days as i32).to_string())
+            },
+            Cell::DateTime(days) => {
+                Some(timestamp_ms_to_datetime((days * MS_PER_DAY).round() as i64).to_string())
+            },
+            Cell::String(v) => Some(v.clone()),
+        }
+    }
+
+    pub(super) fn to_number(&self) -> Option<f64> {
+        match self {
+            Cell::Bool(v) => Some(*v as u8 as f64),
+            Cell::Number(v) => Some(*v),
+            Cell::String(v) => v.trim().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Convert a date serial number to days since the unix epoch.
+///
+/// The 1900 date system counts 1900-02-29, which did not exist, so serial numbers before it are
+/// one day off.
+fn serial_to_epoch_days(serial: f64, date1904: bool) -> f64 {
+    if date1904 {
+        serial - 24107.0
+    } else if serial < 60.0 {
+        serial - 25568.0
+    } else {
+        serial - 25569.0
+    }
+}
+
+/// Parse an ISO 8601 date, as stored in cells of type `d`, to days since the unix epoch.
+fn parse_iso_date(s: &str) -> Option<f64> {
+    let datetime = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.fZ"))
+        .or_else(|_| {
+            NaiveDate::parse_from_str(s, "%Y-%m-%d").map(|d| d.and_hms_opt(0, 0, 0).unwrap())
+        })
+        .ok()?;
+    Some(datetime.and_utc().timestamp_millis() as f64 / MS_PER_DAY)
+}
+
+/// Split an A1 style reference into its zero based column and row. Either may be missing, as
+/// in `B` or `3`.
+pub(super) fn parse_reference(s: &str) -> Option<(Option<usize>, Option<usize>)> {
+    let s = s.trim().replace('$', "");
+    let split = s
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(s.len());
+    let (letters, digits) = s.split_at(split);
+    let column = if letters.is_empty() {
+        None
+    } else {
+        let column = letters.bytes().try_fold(0usize, |acc, b| {
+            acc.checked_mul(26)?
+                .checked_add((b.to_ascii_uppercase() - b'A') as usize + 1)
+        })?;
+        Some(column - 1)
+    };
+    let row = if digits.is_empty() {
+        None
+    } else {
+        Some(digits.parse::<usize>().ok()?.checked_sub(1)?)
+    };
+    Some((column, row))
+}
+
+/// A row of a sheet with the cells that are stored for it.
+pub(super) struct SheetRow {
+    /// Zero based row number.
+    pub(super) index: usize,
+    /// Zero based column numbers and values.
+    pub(super) cells: Vec<(usize, Cell)>,
+}
+
+/// Reads the rows of a worksheet one at a time, so that reading can stop early.
+pub(super) struct SheetRows<'a> {
+    workbook: &'a Workbook,
+    reader: XmlReader<'a>,
+    next_row: usize,
+    done: bool,
+}
+
+impl<'a> SheetRows<'a> {
+    pub(super) fn new(workbook: &'a Workbook, data: &'a [u8]) -> Self {
+        Self {
+            workbook,
+            reader: XmlReader::new(data),
+            next_row: 0,
+            done: false,
+        }
+    }
+
+    /// The next row that has cells stored for it. Rows without stored cells are left out.
+    pub(super) fn next_row(&mut self) -> PolarsResult<Option<SheetRow>> {
+        while !self.done {
+            match self.reader.next_event()? {
+                Some(XmlEvent::Start(tag)) if tag.name == b"row" => {
+                    let index = match tag.attribute(b"r")? {
+                        Some(r) => {
+                            let index = r.parse::<usize>().ok().and_then(|r| r.checked_sub(1));
+                            index.ok_or_else(
+                                || polars_err!(ComputeError: "out of spec xlsx: invalid row {:?}", r),
+                            )?
+                        },
+                        None => self.next_row,
+                    };
+                    self.next_row = index + 1;
+                    let cells = if tag.empty {
+                        vec![]
+                    } else {
+                        self.read_cells()?
+                    };
+                    return Ok(Some(SheetRow { index, cells }));
+                },
+                // Everything after the cells, like merged ranges or page setup, is not needed.
+                Some(XmlEvent::End(b"sheetData")) | None => self.done = true,
+                Some(_) => {},
+            }
+        }
+        Ok(None)
+    }
+
+    fn read_cells(&mut self) -> PolarsResult<Vec<(usize, Cell)>> {
+        let mut cells = vec![];
+        let mut next_column = 0;
+        while let Some(event) = self.reader.next_event()? {
+            match event {
+                XmlEvent::Start(tag) if tag.name == b"c" => {
+                    let column = match tag.attribute(b"r")? {
+                        Some(r) => {
+                            let column = parse_reference(&r).and_then(|(column, _)| column);
+                            column.ok_or_else(
+                                || polars_err!(ComputeError: "out of spec xlsx: invalid cell {:?}", r),
+                            )?
+                        },
+                        None => next_column,
+                    };
+                    next_column = column + 1;
+                    let cell = self.read_cell(&tag)?;
+                    if cell != Cell::Empty {
+                        cells.push((column, cell));
+                    }
+                },
+                XmlEvent::Start(tag) => self.reader.skip_element(&tag)?,
+                XmlEvent::End(b"row") => break,
+                _ => {},
+            }
+        }
+        Ok(cells)
+    }
+
+    fn read_cell(&mut self, tag: &Tag) -> PolarsResult<Cell> {
+        let kind = tag.attribute(b"t")?;
+        let style = tag
+            .attribute(b"s")?
+            .and_then(|s| s.parse::<usize>().ok())
+            .unwrap_or(0);
+
+        let mut value = None;
+        if !tag.empty {
+            while let Some(event) = self.reader.next_event()? {
+                match event {
+                    XmlEvent::Start(child) if child.name == b"v" => {
+                        value = Some(self.reader.read_text(&child, b"")?)
+                    },
+                    XmlEvent::Start(child) if child.name == b"is" => {
+                        value = Some(self.reader.read_text(&child, b"rPh")?)
+                    },
+                    // Formulas are not evaluated, their cached value is read.
+                    XmlEvent::Start(child) => self.reader.skip_element(&child)?,
+                    XmlEvent::End(b"c") => break,
+                    _ => {},
+                }
+            }
+        }
+        let Some(value) = value else {
+            return Ok(Cell::Empty);
+        };
+
+        let invalid =
+            || polars_err!(ComputeError: "out of spec xlsx: invalid cell value {:?}", value);
+        let cell = match kind.as_deref().unwrap_or("n") {
+            "s" => {
+                let i = value.trim().parse::<usize>().map_err(|_| invalid())?;
+                let s = self.workbook.shared_strings.get(i).ok_or_else(
+                    || polars_err!(ComputeError: "out of spec xlsx: missing shared string {}", i),
+                )?;
+                Cell::String(s.clone())
+            },
+            "str" => Cell::String(value),
+            "inlineStr" => Cell::String(unescape_xstring(value)),
+            "b" => Cell::Bool(value.trim() == "1"),
+            "e" => Cell::Error,
+            "d" => Cell::DateTime(parse_iso_date(value.trim()).ok_or_else(invalid)?),
+            _ => {
+                let number = value.trim().parse::<f64>().map_err(|_| invalid())?;
+                if self
+                    .workbook
+                    .date_formats
+                    .get(style)
+                    .copied()
+                    .unwrap_or(false)
+                {
+                    Cell::DateTime(serial_to_epoch_days(number, self.workbook.date1904))
+                } else {
+                    Cell::Number(number)
+                }
+            },
+        };
+        Ok(cell)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_reference() {
+        assert_eq!(parse_reference("A1"), Some((Some(0), Some(0))));
+        assert_eq!(parse_reference("$AB$12"), Some((Some(27), Some(11))));
+        assert_eq!(parse_reference("c"), Some((Some(2), None)));
+        assert_eq!(parse_reference("7"), Some((None, Some(6))));
+        assert_eq!(parse_reference("A0"), None);
+        assert_eq!(parse_reference("A1B"), None);
+    }
+
+    #[test]
+    fn test_serial_dates() {
+        // 1900-01-01, 1900-03-01 and 1970-01-01 in the 1900 date system.
+        assert_eq!(serial_to_epoch_days(1.0, false), -25567.0);
+        assert_eq!(serial_to_epoch_days(61.0, false), -25508.0);
+        assert_eq!(serial_to_epoch_days(25569.0, false), 0.0);
+        assert_eq!(serial_to_epoch_days(24107.5, true), 0.5);
+        assert_eq!(parse_iso_date("1970-01-02T12:00:00"), Some(1.5));
+    }
+}
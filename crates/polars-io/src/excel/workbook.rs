@@ -0,0 +1,321 @@
+use polars_core::prelude::*;
+
+use super::read::ExcelSheet;
+use super::xml::{XmlEvent, XmlReader};
+use super::zip::ZipArchive;
+
+const DEFAULT_WORKBOOK: &str = "xl/workbook.xml";
+
+/// A relationship of a part of the workbook to another part.
+struct Relationship {
+    id: String,
+    /// The last segment of the relationship type, like `worksheet`.
+    kind: String,
+    /// Path of the target part in the archive.
+    target: String,
+}
+
+/// Resolve a relationship target, which is relative to the directory of its source part unless
+/// it starts with `/`.
+fn resolve(dir: &str, target: &str) -> String {
+    if let Some(absolute) = target.strip_prefix('/') {
+        return absolute.to_string();
+    }
+    let mut segments = dir.split('/').filter(|s| !s.is_empty()).collect::<Vec<_>>();
+    for segment in target.split('/') {
+        match segment {
+            ".." => {
+                segments.pop();
+            },
+            "." | "" => {},
+            segment => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
+
+/// The relationships of the part at `path`, which are stored in `<dir>/_rels/<name>.rels`. The
+/// empty path stands for the package.
+fn read_relationships(archive: &ZipArchive, path: &str) -> PolarsResult<Vec<Relationship>> {
+    let (dir, name) = path.rsplit_once('/').unwrap_or(("", path));
+    let rels_path = if dir.is_empty() {
+        format!("_rels/{name}.rels")
+    } else {
+        format!("{dir}/_rels/{name}.rels")
+    };
+    if !archive.contains(&rels_path) {
+        return Ok(vec![]);
+    }
+    let data = archive.read(&rels_path)?;
+    let mut reader = XmlReader::new(&data);
+    let mut relationships = vec![];
+    while let Some(event) = reader.next_event()? {
+        if let XmlEvent::Start(tag) = event {
+            if tag.name != b"Relationship"
+                || tag.attribute(b"TargetMode")?.as_deref() == Some("External")
+            {
+                continue;
+            }
+            let (Some(id), Some(kind), Some(target)) = (
+                tag.attribute(b"Id")?,
+                tag.attribute(b"Type")?,
+                tag.attribute(b"Target")?,
+            ) else {
+                continue;
+            };
+            relationships.push(Relationship {
+                id: id.into_owned(),
+                kind: kind.rsplit('/').next().unwrap_or_default().to_string(),
+                target: resolve(dir, &target),
+            });
+        }
+    }
+    Ok(relationships)
+}
+
+/// Resolve the `_xHHHH_` escapes that xlsx strings use for characters that XML cannot hold.
+pub(super) fn unescape_xstring(s: String) -> String {
+    if !s.contains("_x") {
+        return s;
+    }
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s.as_str();
+    while let Some(i) = rest.find("_x") {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        let c = rest
+            .get(2..6)
+            .filter(|_| rest.as_bytes().get(6) == Some(&b'_'))
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .and_then(char::from_u32);
+        match c {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[7..];
+            },
+            None => {
+                out.push_str("_x");
+                rest = &rest[2..];
+            },
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Whether a number format shows numbers as dates or times.
+fn is_date_format(id: u32, code: Option<&str>) -> bool {
+    if let Some(code) = code {
+        return is_date_format_code(code);
+    }
+    // The built-in formats, including the ones of east asian locales.
+    matches!(id, 14..=22 | 27..=36 | 45..=47 | 50..=58)
+}
+
+fn is_date_format_code(code: &str) -> bool {
+    let mut chars = code.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            // Literal text, escaped characters and sections like colors or `[h]`.
+            '"' => {
+                chars.find(|&c| c == '"');
+            },
+            '\\' | '_' | '*' => {
+                chars.next();
+            },
+            '[' => {
+                let section = chars.by_ref().take_while(|&c| c != ']').collect::<String>();
+                if matches!(
+                    section.to_ascii_lowercase().as_str(),
+                    "h" | "hh" | "m" | "mm" | "s" | "ss"
+                ) {
+                    return true;
+                }
+            },
+            // A format with several sections is a date format if its first one is.
+            ';' => return false,
+            'd' | 'D' | 'm' | 'M' | 'y' | 'Y' | 'h' | 'H' | 's' | 'S' => return true,
+            _ => {},
+        }
+    }
+    false
+}
+
+/// The parts of an xlsx workbook that are needed to read its sheets.
+pub(super) struct Workbook {
+    archive: ZipArchive,
+    /// Names and part paths of the worksheets, in the order of the workbook.
+    sheets: Vec<(String, String)>,
+    pub(super) shared_strings: Vec<String>,
+    /// Whether the cell format at the same index shows numbers as dates.
+    pub(super) date_formats: Vec<bool>,
+    /// Whether dates count from 1904 instead of 1900.
+    pub(super) date1904: bool,
+}
+
+impl Workbook {
+    pub(super) fn new(data: Vec<u8>) -> PolarsResult<Self> {
+        let archive = ZipArchive::new(data)?;
+        // The relationships of the package itself point to the workbook.
+        let workbook_path = read_relationships(&archive, "")?
+            .into_iter()
+            .find(|rel| rel.kind == "officeDocument")
+            .map_or_else(|| DEFAULT_WORKBOOK.to_string(), |rel| rel.target);
+        let relationships = read_relationships(&archive, &workbook_path)?;
+        let target = |id: &str, kind: &str| {
+            relationships
+                .iter()
+                .find(|rel| rel.id == id && rel.kind == kind)
+                .map(|rel| rel.target.clone())
+        };
+
+        let mut sheets = vec![];
+        let mut date1904 = false;
+        let data = archive.read(&workbook_path)?;
+        let mut reader = XmlReader::new(&data);
+        while let Some(event) = reader.next_event()? {
+            let XmlEvent::Start(tag) = event else {
+                continue;
+            };
+            match tag.name {
+                b"workbookPr" => {
+                    date1904 = matches!(tag.attribute(b"date1904")?.as_deref(), Some("1" | "true"));
+                },
+                b"sheet" => {
+                    let (Some(name), Some(id)) = (tag.attribute(b"name")?, tag.attribute(b"id")?)
+                    else {
+                        continue;
+                    };
+                    // Chart sheets hold no cells.
+                    if let Some(path) = target(&id, "worksheet") {
+                        sheets.push((name.into_owned(), path));
+                    }
+                },
+                _ => {},
+            }
+        }
+
+        let mut workbook = Self {
+            archive,
+            sheets,
+            shared_strings: vec![],
+            date_formats: vec![],
+            date1904,
+        };
+        for rel in &relationships {
+            match rel.kind.as_str() {
+                "sharedStrings" => workbook.read_shared_strings(&rel.target)?,
+                "styles" => workbook.read_styles(&rel.target)?,
+                _ => {},
+            }
+        }
+        Ok(workbook)
+    }
+
+    fn read_shared_strings(&mut self, path: &str) -> PolarsResult<()> {
+        let data = self.archive.read(path)?;
+        let mut reader = XmlReader::new(&data);
+        while let Some(event) = reader.next_event()? {
+            if let XmlEvent::Start(tag) = event {
+                if tag.name == b"si" {
+                    // Phonetic runs annotate the text, they are not part of it.
+                    let text = reader.read_text(&tag, b"rPh")?;
+                    self.shared_strings.push(unescape_xstring(text));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn read_styles(&mut self, path: &str) -> PolarsResult<()> {
+        let data = self.archive.read(path)?;
+        let mut reader = XmlReader::new(&data);
+        let mut custom_formats = PlHashMap::new();
+        let mut in_cell_formats = false;
+        while let Some(event) = reader.next_event()? {
+            match event {
+                XmlEvent::Start(tag) => match tag.name {
+                    b"numFmt" => {
+                        let id = tag
+                            .attribute(b"numFmtId")?
+                            .and_then(|id| id.parse::<u32>().ok());
+                        if let (Some(id), Some(code)) = (id, tag.attribute(b"formatCode")?) {
+                            custom_formats.insert(id, code.into_owned());
+                        }
+                    },
+                    b"cellXfs" => in_cell_formats = !tag.empty,
+                    b"xf" if in_cell_formats => {
+                        let id = tag
+                            .attribute(b"numFmtId")?
+                            .and_then(|id| id.parse::<u32>().ok())
+                            .unwrap_or(0);
+                        let code = custom_formats.get(&id).map(String::as_str);
+                        self.date_formats.push(is_date_format(id, code));
+                        reader.skip_element(&tag)?;
+                    },
+                    _ => {},
+                },
+                XmlEvent::End(b"cellXfs") => in_cell_formats = false,
+                _ => {},
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn sheet_names(&self) -> Vec<String> {
+        self.sheets.iter().map(|(name, _)| name.clone()).collect()
+    }
+
+    /// The XML of the selected sheet.
+    pub(super) fn sheet_data(&self, sheet: &ExcelSheet) -> PolarsResult<Vec<u8>> {
+        let path = match sheet {
+            ExcelSheet::Index(i) => self.sheets.get(*i).map(|(_, path)| path).ok_or_else(|| {
+                polars_err!(
+                    OutOfBounds: "sheet index {} is out of bounds for a workbook with {} sheets",
+                    i, self.sheets.len()
+                )
+            })?,
+            ExcelSheet::Name(name) => self
+                .sheets
+                .iter()
+                .find(|(sheet_name, _)| sheet_name == name)
+                .map(|(_, path)| path)
+                .ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "sheet {:?} not found; valid sheets: {:?}",
+                        name, self.sheet_names()
+                    )
+                })?,
+        };
+        self.archive.read(path)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_date_formats() {
+        assert!(is_date_format(14, None));
+        assert!(!is_date_format(2, None));
+        assert!(is_date_format(164, Some("yyyy-mm-dd hh:mm")));
+        assert!(is_date_format(164, Some("[h]:mm:ss")));
+        assert!(!is_date_format(164, Some("[Red]0.00;\"day\"")));
+        assert!(!is_date_format(164, Some("#,##0\\d")));
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(
+            resolve("xl", "worksheets/sheet1.xml"),
+            "xl/worksheets/sheet1.xml"
+        );
+        assert_eq!(resolve("xl", "/xl/styles.xml"), "xl/styles.xml");
+        assert_eq!(
+            resolve("xl/worksheets", "../sharedStrings.xml"),
+            "xl/sharedStrings.xml"
+        );
+        assert_eq!(unescape_xstring("a_x000D_b_x".to_string()), "a\rb_x");
+    }
+}
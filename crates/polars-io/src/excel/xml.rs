@@ -0,0 +1,258 @@
+use std::borrow::Cow;
+
+use polars_core::prelude::*;
+
+/// A tag that opens an element.
+pub(super) struct Tag<'a> {
+    /// Name without namespace prefix.
+    pub(super) name: &'a [u8],
+    attributes: &'a [u8],
+    /// Whether the element is closed by the same tag, as in `<a/>`.
+    pub(super) empty: bool,
+}
+
+impl<'a> Tag<'a> {
+    /// The unescaped value of the attribute with the given name, ignoring namespace prefixes.
+    pub(super) fn attribute(&self, name: &[u8]) -> PolarsResult<Option<Cow<'a, str>>> {
+        let mut rest = self.attributes;
+        loop {
+            rest = rest.trim_ascii_start();
+            let Some(eq) = memchr::memchr(b'=', rest) else {
+                return Ok(None);
+            };
+            let key = rest[..eq].trim_ascii_end();
+            rest = rest[eq + 1..].trim_ascii_start();
+            let quote = *rest
+                .first()
+                .filter(|&&q| q == b'"' || q == b'\'')
+                .ok_or_else(
+                    || polars_err!(ComputeError: "out of spec xlsx: unquoted XML attribute"),
+                )?;
+            let end = memchr::memchr(quote, &rest[1..]).ok_or_else(
+                || polars_err!(ComputeError: "out of spec xlsx: unterminated XML attribute"),
+            )?;
+            let value = &rest[1..end + 1];
+            rest = &rest[end + 2..];
+
+            if !key.starts_with(b"xmlns") && local_name(key) == name {
+                return unescape(value).map(Some);
+            }
+        }
+    }
+}
+
+pub(super) enum XmlEvent<'a> {
+    Start(Tag<'a>),
+    /// The name, without namespace prefix, of a closing tag.
+    End(&'a [u8]),
+    /// Unescaped character data.
+    Text(Cow<'a, str>),
+}
+
+fn local_name(name: &[u8]) -> &[u8] {
+    match memchr::memrchr(b':', name) {
+        Some(i) => &name[i + 1..],
+        None => name,
+    }
+}
+
+fn to_str(bytes: &[u8]) -> PolarsResult<&str> {
+    std::str::from_utf8(bytes)
+        .map_err(|_| polars_err!(ComputeError: "out of spec xlsx: invalid utf-8 in XML"))
+}
+
+/// Resolve the character and entity references of XML text.
+fn unescape(bytes: &[u8]) -> PolarsResult<Cow<'_, str>> {
+    let s = to_str(bytes)?;
+    if !s.contains('&') {
+        return Ok(Cow::Borrowed(s));
+    }
+
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let Some(semi) = rest.find(';') else { break };
+        let entity = &rest[1..semi];
+        let c = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(|dec| dec.parse()))
+                .and_then(|code| code.ok())
+                .and_then(char::from_u32),
+        };
+        match c {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[semi + 1..];
+            },
+            // Unknown references are kept as they are.
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            },
+        }
+    }
+    out.push_str(rest);
+    Ok(Cow::Owned(out))
+}
+
+/// A pull parser for the XML parts of an xlsx file.
+///
+/// It does not validate the document and skips declarations, comments and processing
+/// instructions, which is all that is needed for the machine written parts of a workbook.
+pub(super) struct XmlReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> XmlReader<'a> {
+    pub(super) fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    /// Position after `needle`, searching from `from`.
+    fn find_end(&self, from: usize, needle: &[u8]) -> PolarsResult<usize> {
+        memchr::memmem::find(&self.data[from..], needle)
+            .map(|i| from + i + needle.len())
+            .ok_or_else(|| polars_err!(ComputeError: "out of spec xlsx: unterminated XML markup"))
+    }
+
+    pub(super) fn next_event(&mut self) -> PolarsResult<Option<XmlEvent<'a>>> {
+        let data = self.data;
+        while self.pos < data.len() {
+            let start = self.pos;
+            if data[start] != b'<' {
+                let end = memchr::memchr(b'<', &data[start..]).map_or(data.len(), |i| start + i);
+                self.pos = end;
+                return Ok(Some(XmlEvent::Text(unescape(&data[start..end])?)));
+            }
+
+            let markup = &data[start + 1..];
+            if markup.starts_with(b"![CDATA[") {
+                self.pos = self.find_end(start, b"]]>")?;
+                let text = to_str(&data[start + 9..self.pos - 3])?;
+                return Ok(Some(XmlEvent::Text(Cow::Borrowed(text))));
+            } else if markup.starts_with(b"!--") {
+                self.pos = self.find_end(start, b"-->")?;
+            } else if markup.starts_with(b"?") {
+                self.pos = self.find_end(start, b"?>")?;
+            } else if markup.starts_with(b"!") {
+                self.pos = self.find_end(start, b">")?;
+            } else if markup.starts_with(b"/") {
+                self.pos = self.find_end(start, b">")?;
+                let name = data[start + 2..self.pos - 1].trim_ascii_end();
+                return Ok(Some(XmlEvent::End(local_name(name))));
+            } else {
+                return self.start_tag(start).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    fn start_tag(&mut self, start: usize) -> PolarsResult<XmlEvent<'a>> {
+        // Attribute values may contain '>'.
+        let data = self.data;
+        let mut pos = start + 1;
+        let end = loop {
+            let i = memchr::memchr3(b'>', b'"', b'\'', &data[pos..]).map(|i| pos + i);
+            match i {
+                Some(i) if data[i] == b'>' => break i,
+                Some(i) => pos = self.find_end(i + 1, &data[i..i + 1])?,
+                None => polars_bail!(ComputeError: "out of spec xlsx: unterminated XML tag"),
+            }
+        };
+        self.pos = end + 1;
+
+        let mut inner = &data[start + 1..end];
+        let empty = inner.last() == Some(&b'/');
+        if empty {
+            inner = &inner[..inner.len() - 1];
+        }
+        let name_end = inner
+            .iter()
+            .position(|b| b.is_ascii_whitespace())
+            .unwrap_or(inner.len());
+        Ok(XmlEvent::Start(Tag {
+            name: local_name(&inner[..name_end]),
+            attributes: &inner[name_end..],
+            empty,
+        }))
+    }
+
+    /// Skip to the end of the element that `tag` opened.
+    pub(super) fn skip_element(&mut self, tag: &Tag) -> PolarsResult<()> {
+        if tag.empty {
+            return Ok(());
+        }
+        let mut depth = 1;
+        while let Some(event) = self.next_event()? {
+            match event {
+                XmlEvent::Start(tag) if !tag.empty => depth += 1,
+                XmlEvent::End(_) => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                },
+                _ => {},
+            }
+        }
+        Ok(())
+    }
+
+    /// The text of the element that `tag` opened, leaving out the text of the child elements
+    /// named `exclude`.
+    pub(super) fn read_text(&mut self, tag: &Tag, exclude: &[u8]) -> PolarsResult<String> {
+        let mut text = String::new();
+        if tag.empty {
+            return Ok(text);
+        }
+        let mut depth = 1;
+        while let Some(event) = self.next_event()? {
+            match event {
+                XmlEvent::Start(tag) if tag.name == exclude => self.skip_element(&tag)?,
+                XmlEvent::Start(tag) if !tag.empty => depth += 1,
+                XmlEvent::Start(_) => {},
+                XmlEvent::End(_) => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                },
+                XmlEvent::Text(t) => text.push_str(&t),
+            }
+        }
+        Ok(text)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_xml_reader() -> PolarsResult<()> {
+        let xml = br#"<?xml version="1.0"?><!-- c --><x:a k='1>2' x:r="&lt;&#65;&#x42;&amp;"><b/>t&amp;u<![CDATA[&]]></x:a>"#;
+        let mut reader = XmlReader::new(xml);
+
+        let Some(XmlEvent::Start(a)) = reader.next_event()? else {
+            panic!("expected a start tag")
+        };
+        assert_eq!(a.name, b"a");
+        assert!(!a.empty);
+        assert_eq!(a.attribute(b"k")?.as_deref(), Some("1>2"));
+        assert_eq!(a.attribute(b"r")?.as_deref(), Some("<AB&"));
+        assert_eq!(a.attribute(b"missing")?, None);
+        assert_eq!(reader.read_text(&a, b"")?, "t&u&");
+        assert!(reader.next_event()?.is_none());
+        Ok(())
+    }
+}
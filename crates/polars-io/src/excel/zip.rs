@@ -0,0 +1,120 @@
+use std::io::Read;
+
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x06054b50;
+const CENTRAL_DIRECTORY_HEADER: u32 = 0x02014b50;
+const LOCAL_FILE_HEADER: u32 = 0x04034b50;
+
+const STORED: u16 = 0;
+const DEFLATED: u16 = 8;
+
+fn u16_at(data: &[u8], pos: usize) -> u16 {
+    u16::from_le_bytes([data[pos], data[pos + 1]])
+}
+
+fn u32_at(data: &[u8], pos: usize) -> u32 {
+    u32::from_le_bytes(data[pos..pos + 4].try_into().unwrap())
+}
+
+struct ZipEntry {
+    compression: u16,
+    compressed_size: usize,
+    uncompressed_size: usize,
+    local_header_offset: usize,
+}
+
+/// The files of a zip archive, which is the container of an xlsx workbook.
+///
+/// Only what xlsx writers produce is supported: stored and deflated entries without encryption.
+pub(super) struct ZipArchive {
+    data: Vec<u8>,
+    entries: PlHashMap<String, ZipEntry>,
+}
+
+impl ZipArchive {
+    pub(super) fn new(data: Vec<u8>) -> PolarsResult<Self> {
+        // The end of central directory record is at least 22 bytes and may be followed by a
+        // comment of at most 64 KiB.
+        polars_ensure!(data.len() >= 22, ComputeError: "not an xlsx file: file is too small");
+        let search_start = data.len().saturating_sub(22 + u16::MAX as usize);
+        let eocd = (search_start..=data.len() - 22)
+            .rev()
+            .find(|&pos| u32_at(&data, pos) == END_OF_CENTRAL_DIRECTORY)
+            .ok_or_else(|| polars_err!(ComputeError: "not an xlsx file: no zip directory found"))?;
+
+        let n_entries = u16_at(&data, eocd + 10) as usize;
+        let directory_offset = u32_at(&data, eocd + 16) as usize;
+        polars_ensure!(
+            directory_offset != u32::MAX as usize,
+            ComputeError: "xlsx files in zip64 format are not supported"
+        );
+
+        let mut entries = PlHashMap::with_capacity(n_entries);
+        let mut pos = directory_offset;
+        for _ in 0..n_entries {
+            polars_ensure!(
+                pos + 46 <= data.len() && u32_at(&data, pos) == CENTRAL_DIRECTORY_HEADER,
+                ComputeError: "out of spec xlsx: invalid zip directory entry"
+            );
+            let name_len = u16_at(&data, pos + 28) as usize;
+            let extra_len = u16_at(&data, pos + 30) as usize;
+            let comment_len = u16_at(&data, pos + 32) as usize;
+            polars_ensure!(
+                pos + 46 + name_len <= data.len(),
+                ComputeError: "out of spec xlsx: truncated zip directory"
+            );
+            let name = String::from_utf8_lossy(&data[pos + 46..pos + 46 + name_len]).into_owned();
+            let entry = ZipEntry {
+                compression: u16_at(&data, pos + 10),
+                compressed_size: u32_at(&data, pos + 20) as usize,
+                uncompressed_size: u32_at(&data, pos + 24) as usize,
+                local_header_offset: u32_at(&data, pos + 42) as usize,
+            };
+            entries.insert(name, entry);
+            pos += 46 + name_len + extra_len + comment_len;
+        }
+        Ok(Self { data, entries })
+    }
+
+    pub(super) fn contains(&self, name: &str) -> bool {
+        self.entries.contains_key(name)
+    }
+
+    /// The decompressed contents of the file `name`.
+    pub(super) fn read(&self, name: &str) -> PolarsResult<Vec<u8>> {
+        let entry = self.entries.get(name).ok_or_else(
+            || polars_err!(ComputeError: "out of spec xlsx: missing part {:?}", name),
+        )?;
+
+        // The local header repeats the name and has its own extra field.
+        let pos = entry.local_header_offset;
+        let data = &self.data;
+        polars_ensure!(
+            pos + 30 <= data.len() && u32_at(data, pos) == LOCAL_FILE_HEADER,
+            ComputeError: "out of spec xlsx: invalid zip header of {:?}", name
+        );
+        let start = pos + 30 + u16_at(data, pos + 26) as usize + u16_at(data, pos + 28) as usize;
+        let end = start + entry.compressed_size;
+        polars_ensure!(
+            end <= data.len(),
+            ComputeError: "out of spec xlsx: truncated part {:?}", name
+        );
+        let compressed = &data[start..end];
+
+        match entry.compression {
+            STORED => Ok(compressed.to_vec()),
+            DEFLATED => {
+                let mut out = Vec::with_capacity(entry.uncompressed_size);
+                flate2::read::DeflateDecoder::new(compressed)
+                    .read_to_end(&mut out)
+                    .map_err(to_compute_err)?;
+                Ok(out)
+            },
+            method => {
+                polars_bail!(ComputeError: "unsupported zip compression method {} in xlsx", method)
+            },
+        }
+    }
+}
@@ -5,7 +5,9 @@ mod eviction;
 mod file_fetcher;
 mod file_lock;
 mod metadata;
+mod range_cache;
 mod utils;
 pub use cache::{get_env_file_cache_ttl, FILE_CACHE};
 pub use entry::FileCacheEntry;
+pub use range_cache::{get_env_range_cache_max_size, RangeCache, RANGE_CACHE};
 pub use utils::{init_entries_from_uri_list, FILE_CACHE_PREFIX};
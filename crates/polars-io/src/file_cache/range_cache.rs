@@ -0,0 +1,357 @@
+//! Local disk cache of byte ranges of remote objects.
+//!
+//! Entries are keyed by the url and the ETag of the object and the byte range, so that an
+//! object that is overwritten never serves stale bytes. The total size of the cache is bounded,
+//! the least recently used entries are evicted first.
+use std::io::{Read, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use bytes::Bytes;
+use once_cell::sync::Lazy;
+use polars_core::config;
+use polars_utils::aliases::PlHashMap;
+
+use crate::prelude::POLARS_TEMP_DIR_BASE_PATH;
+use crate::utils::ensure_directory_init;
+
+const TMP_SUFFIX: &str = ".tmp";
+
+/// The range cache, `None` unless `POLARS_RANGE_CACHE_MAX_SIZE` is set to a non-zero size.
+///
+/// The entries are stored in `POLARS_RANGE_CACHE_DIR`, which defaults to the `range-cache`
+/// directory in the temporary directory of polars. Processes can share the directory.
+pub static RANGE_CACHE: Lazy<Option<RangeCache>> = Lazy::new(|| {
+    let max_size = get_env_range_cache_max_size();
+    if max_size == 0 {
+        return None;
+    }
+
+    let dir = get_env_range_cache_dir().into_boxed_path();
+    if let Err(err) = ensure_directory_init(&dir) {
+        panic!(
+            "failed to create range cache directory: path = {}, err = {}",
+            dir.to_str().unwrap(),
+            err
+        );
+    }
+
+    if config::verbose() {
+        eprintln!(
+            "range cache directory: {}, max size: {} bytes",
+            dir.to_str().unwrap(),
+            max_size
+        );
+    }
+
+    Some(RangeCache::new(dir, max_size))
+});
+
+/// Directory of the range cache, `POLARS_RANGE_CACHE_DIR` or the default in the temporary
+/// directory of polars.
+fn get_env_range_cache_dir() -> PathBuf {
+    std::env::var("POLARS_RANGE_CACHE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| POLARS_TEMP_DIR_BASE_PATH.join("range-cache/"))
+}
+
+/// Maximum size of the range cache in bytes, 0 disables the cache.
+pub fn get_env_range_cache_max_size() -> u64 {
+    std::env::var("POLARS_RANGE_CACHE_MAX_SIZE")
+        .map(|x| x.parse::<u64>().expect("integer"))
+        .unwrap_or(0)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    size: u64,
+    last_accessed: SystemTime,
+}
+
+#[derive(Default)]
+struct State {
+    entries: PlHashMap<String, Entry>,
+    total_size: u64,
+}
+
+impl State {
+    fn insert(&mut self, name: String, entry: Entry) {
+        self.total_size += entry.size;
+        if let Some(old) = self.entries.insert(name, entry) {
+            self.total_size -= old.size;
+        }
+    }
+
+    fn remove(&mut self, name: &str) {
+        if let Some(old) = self.entries.remove(name) {
+            self.total_size -= old.size;
+        }
+    }
+}
+
+pub struct RangeCache {
+    dir: Box<Path>,
+    max_size: u64,
+    state: Mutex<State>,
+}
+
+impl RangeCache {
+    /// Open the cache in `dir`, existing entries are kept.
+    pub fn new(dir: Box<Path>, max_size: u64) -> Self {
+        let mut state = State::default();
+
+        if let Ok(read_dir) = std::fs::read_dir(&dir) {
+            for dir_entry in read_dir.flatten() {
+                let path = dir_entry.path();
+                let Some(name) = path.file_name().and_then(|x| x.to_str()) else {
+                    continue;
+                };
+                // Left behind by an interrupted write.
+                if name.ends_with(TMP_SUFFIX) {
+                    let _ = std::fs::remove_file(&path);
+                    continue;
+                }
+                let Ok(metadata) = dir_entry.metadata() else {
+                    continue;
+                };
+                let last_accessed = metadata
+                    .accessed()
+                    .or_else(|_| metadata.modified())
+                    .unwrap_or(SystemTime::UNIX_EPOCH);
+                state.insert(
+                    name.to_string(),
+                    Entry {
+                        size: metadata.len(),
+                        last_accessed,
+                    },
+                );
+            }
+        }
+
+        let cache = Self {
+            dir,
+            max_size,
+            state: Mutex::new(state),
+        };
+        cache.evict(&mut cache.state.lock().unwrap());
+        cache
+    }
+
+    fn entry_name(url: &str, e_tag: &str, range: &Range<usize>) -> String {
+        let mut hasher = blake3::Hasher::new();
+        for part in [url, e_tag] {
+            hasher.update(&(part.len() as u64).to_le_bytes());
+            hasher.update(part.as_bytes());
+        }
+        hasher.update(&(range.start as u64).to_le_bytes());
+        hasher.update(&(range.end as u64).to_le_bytes());
+        hasher.finalize().to_hex().get(..32).unwrap().to_string()
+    }
+
+    /// Get the bytes of `range` of the object at `url` with the given `e_tag`, if they are
+    /// cached.
+    pub fn get(&self, url: &str, e_tag: &str, range: Range<usize>) -> Option<Bytes> {
+        let name = Self::entry_name(url, e_tag, &range);
+        let path = self.dir.join(&name);
+
+        // Other processes can share the cache directory, so the file is checked even if this
+        // process does not know the entry.
+        let read = || -> std::io::Result<Vec<u8>> {
+            let mut file = std::fs::File::open(&path)?;
+            let mut buf = Vec::with_capacity(range.len());
+            file.read_to_end(&mut buf)?;
+            let _ = file.set_times(std::fs::FileTimes::new().set_accessed(SystemTime::now()));
+            Ok(buf)
+        };
+
+        let result = read();
+        let mut state = self.state.lock().unwrap();
+        match result {
+            Ok(buf) if buf.len() == range.len() => {
+                state.insert(
+                    name,
+                    Entry {
+                        size: buf.len() as u64,
+                        last_accessed: SystemTime::now(),
+                    },
+                );
+                if config::verbose() {
+                    eprintln!("[range_cache] hit: url = {}, range = {:?}", url, range);
+                }
+                Some(Bytes::from(buf))
+            },
+            Ok(_) => {
+                // Truncated, e.g. by a full disk.
+                let _ = std::fs::remove_file(&path);
+                state.remove(&name);
+                None
+            },
+            Err(_) => {
+                state.remove(&name);
+                None
+            },
+        }
+    }
+
+    /// Cache the bytes of `range` of the object at `url` with the given `e_tag`. Errors are
+    /// ignored, a failed write only means the range is downloaded again.
+    pub fn insert(&self, url: &str, e_tag: &str, range: Range<usize>, bytes: &[u8]) {
+        debug_assert_eq!(range.len(), bytes.len());
+        if bytes.len() as u64 > self.max_size {
+            return;
+        }
+
+        static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let name = Self::entry_name(url, e_tag, &range);
+        let path = self.dir.join(&name);
+        let tmp_path = self.dir.join(format!(
+            "{}.{}.{}{}",
+            name,
+            std::process::id(),
+            TMP_COUNTER.fetch_add(1, Ordering::Relaxed),
+            TMP_SUFFIX
+        ));
+
+        // Write to a temporary file first, so that readers never see a partial entry.
+        let write = || -> std::io::Result<()> {
+            let mut file = std::fs::File::create(&tmp_path)?;
+            file.write_all(bytes)?;
+            std::fs::rename(&tmp_path, &path)
+        };
+        if let Err(err) = write() {
+            let _ = std::fs::remove_file(&tmp_path);
+            if config::verbose() {
+                eprintln!(
+                    "[range_cache] failed to write entry: url = {}, range = {:?}, err = {}",
+                    url, range, err
+                );
+            }
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        state.insert(
+            name,
+            Entry {
+                size: bytes.len() as u64,
+                last_accessed: SystemTime::now(),
+            },
+        );
+        self.evict(&mut state);
+    }
+
+    /// Remove the least recently used entries until the cache fits in its maximum size.
+    fn evict(&self, state: &mut State) {
+        if state.total_size <= self.max_size {
+            return;
+        }
+
+        let mut entries = state
+            .entries
+            .iter()
+            .map(|(name, entry)| (entry.last_accessed, name.clone()))
+            .collect::<Vec<_>>();
+        entries.sort_unstable();
+
+        for (_, name) in entries {
+            if state.total_size <= self.max_size {
+                break;
+            }
+            let path = self.dir.join(&name);
+            if let Err(err) = std::fs::remove_file(&path) {
+                if err.kind() != std::io::ErrorKind::NotFound {
+                    continue;
+                }
+            }
+            if config::verbose() {
+                eprintln!("[range_cache] evicted {}", path.to_str().unwrap());
+            }
+            state.remove(&name);
+        }
+    }
+
+    /// Total size of the entries in bytes.
+    pub fn size(&self) -> u64 {
+        self.state.lock().unwrap().total_size
+    }
+
+    /// Remove all entries, including the ones written by other processes.
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        if let Ok(read_dir) = std::fs::read_dir(&self.dir) {
+            for dir_entry in read_dir.flatten() {
+                let _ = std::fs::remove_file(dir_entry.path());
+            }
+        }
+        *state = State::default();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_range_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = RangeCache::new(dir.path().into(), 10);
+        let url = "s3://bucket/a.parquet";
+
+        assert!(cache.get(url, "v1", 0..4).is_none());
+        cache.insert(url, "v1", 0..4, b"abcd");
+        assert_eq!(cache.get(url, "v1", 0..4).unwrap().as_ref(), b"abcd");
+        // The ETag and the range are part of the key.
+        assert!(cache.get(url, "v2", 0..4).is_none());
+        assert!(cache.get(url, "v1", 1..5).is_none());
+
+        // Entries larger than the cache are not stored.
+        cache.insert(url, "v1", 0..11, b"abcdefghijk");
+        assert!(cache.get(url, "v1", 0..11).is_none());
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        cache.insert(url, "v1", 4..8, b"efgh");
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        // Use the first entry, so that the second one is the least recently used.
+        assert!(cache.get(url, "v1", 0..4).is_some());
+        cache.insert(url, "v1", 8..12, b"ijkl");
+
+        assert_eq!(cache.size(), 8);
+        assert!(cache.get(url, "v1", 4..8).is_none());
+        assert!(cache.get(url, "v1", 0..4).is_some());
+        assert!(cache.get(url, "v1", 8..12).is_some());
+
+        // Entries are kept when the cache is opened again.
+        drop(cache);
+        let cache = RangeCache::new(dir.path().into(), 10);
+        assert_eq!(cache.size(), 8);
+        assert_eq!(cache.get(url, "v1", 8..12).unwrap().as_ref(), b"ijkl");
+    }
+
+    #[test]
+    fn test_range_cache_dir_and_clear() {
+        let dir = tempfile::tempdir().unwrap();
+        std::env::set_var("POLARS_RANGE_CACHE_DIR", dir.path());
+        assert_eq!(get_env_range_cache_dir(), dir.path());
+        std::env::remove_var("POLARS_RANGE_CACHE_DIR");
+        assert_eq!(
+            get_env_range_cache_dir(),
+            POLARS_TEMP_DIR_BASE_PATH.join("range-cache/")
+        );
+
+        let url = "s3://bucket/a.parquet";
+        let cache = RangeCache::new(dir.path().into(), 10);
+        cache.insert(url, "v1", 0..4, b"abcd");
+        // Written by another process that shares the directory.
+        RangeCache::new(dir.path().into(), 10).insert(url, "v1", 4..8, b"efgh");
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 2);
+
+        cache.clear();
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+        assert_eq!(cache.size(), 0);
+        assert!(cache.get(url, "v1", 4..8).is_none());
+    }
+}
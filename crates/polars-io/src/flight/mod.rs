@@ -0,0 +1,11 @@
+//! # Read from an Arrow Flight server
+//!
+//! [Arrow Flight] is a gRPC protocol to transfer Arrow record batches. A query is resolved to a
+//! set of endpoints with `GetFlightInfo`, after which every endpoint is fetched with `DoGet`.
+//! [Flight SQL] statements are sent as a `CommandStatementQuery`.
+//!
+//! [Arrow Flight]: https://arrow.apache.org/docs/format/Flight.html
+//! [Flight SQL]: https://arrow.apache.org/docs/format/FlightSql.html
+mod read;
+
+pub use read::*;
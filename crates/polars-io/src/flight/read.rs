@@ -0,0 +1,375 @@
+use std::collections::VecDeque;
+
+use arrow::io::flight::{deserialize_message_with_projection, deserialize_schemas_from_info};
+use arrow::io::ipc::read::Dictionaries;
+use arrow::io::ipc::IpcSchema;
+use arrow::record_batch::RecordBatch;
+use arrow_format::flight::data::flight_descriptor::DescriptorType;
+use arrow_format::flight::data::{FlightData, FlightDescriptor, FlightInfo, Ticket};
+use arrow_format::flight::service::flight_service_client::FlightServiceClient;
+use futures::future::try_join_all;
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use tonic::transport::{Channel, Endpoint};
+use tonic::Streaming;
+
+use crate::pl_async::get_runtime;
+use crate::shared::{finish_reader, ArrowReader};
+use crate::utils::{apply_projection, columns_to_projection};
+
+const STATEMENT_QUERY_TYPE_URL: &str =
+    "type.googleapis.com/arrow.flight.protocol.sql.CommandStatementQuery";
+
+/// What to fetch from a Flight server.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FlightQuery {
+    /// A ticket that is passed to `DoGet` directly.
+    Ticket(Vec<u8>),
+    /// An opaque command that is resolved with `GetFlightInfo`.
+    Command(Vec<u8>),
+    /// A path that is resolved with `GetFlightInfo`.
+    Path(Vec<String>),
+    /// A Flight SQL statement.
+    Sql(String),
+}
+
+impl FlightQuery {
+    fn descriptor(&self) -> Option<FlightDescriptor> {
+        let (r#type, cmd, path) = match self {
+            Self::Ticket(_) => return None,
+            Self::Command(cmd) => (DescriptorType::Cmd, cmd.clone(), vec![]),
+            Self::Path(path) => (DescriptorType::Path, vec![], path.clone()),
+            Self::Sql(query) => (DescriptorType::Cmd, encode_statement_query(query), vec![]),
+        };
+        Some(FlightDescriptor {
+            r#type: r#type as i32,
+            cmd,
+            path,
+        })
+    }
+}
+
+fn encode_varint(mut value: usize, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn encode_bytes_field(field: u8, bytes: &[u8], out: &mut Vec<u8>) {
+    // Wire type 2: length delimited.
+    out.push(field << 3 | 2);
+    encode_varint(bytes.len(), out);
+    out.extend_from_slice(bytes);
+}
+
+/// Encode a `CommandStatementQuery { query }` wrapped in a `google.protobuf.Any`.
+fn encode_statement_query(query: &str) -> Vec<u8> {
+    let mut command = vec![];
+    encode_bytes_field(1, query.as_bytes(), &mut command);
+
+    let mut any = vec![];
+    encode_bytes_field(1, STATEMENT_QUERY_TYPE_URL.as_bytes(), &mut any);
+    encode_bytes_field(2, &command, &mut any);
+    any
+}
+
+/// Flight locations use `grpc` schemes, which the transport doesn't understand.
+fn location_to_uri(location: &str) -> String {
+    if let Some(rest) = location
+        .strip_prefix("grpc+tcp://")
+        .or_else(|| location.strip_prefix("grpc://"))
+    {
+        format!("http://{rest}")
+    } else if let Some(rest) = location.strip_prefix("grpc+tls://") {
+        format!("https://{rest}")
+    } else {
+        location.to_string()
+    }
+}
+
+async fn connect(location: &str) -> PolarsResult<FlightServiceClient<Channel>> {
+    let channel = Endpoint::from_shared(location_to_uri(location))
+        .map_err(to_compute_err)?
+        .connect()
+        .await
+        .map_err(to_compute_err)?;
+    Ok(FlightServiceClient::new(channel))
+}
+
+/// Where the record batches of an endpoint are read from.
+enum EndpointSource {
+    /// A ticket to fetch, with the location to fetch it from (`None` means the server that
+    /// was queried).
+    Ticket(Ticket, Option<String>),
+    /// A `DoGet` stream of which the schema message was already read.
+    Stream(Streaming<FlightData>),
+}
+
+/// The schema and the endpoints that together make up the result of a [`FlightQuery`].
+struct FlightPlan {
+    schema: ArrowSchema,
+    ipc_schema: IpcSchema,
+    endpoints: Vec<EndpointSource>,
+}
+
+async fn plan(location: &str, query: &FlightQuery) -> PolarsResult<FlightPlan> {
+    let mut client = connect(location).await?;
+
+    let Some(descriptor) = query.descriptor() else {
+        let FlightQuery::Ticket(ticket) = query else {
+            unreachable!()
+        };
+        let ticket = Ticket {
+            ticket: ticket.clone(),
+        };
+        // A bare ticket has no flight info; the schema is the first message of the stream. The
+        // stream is kept, so that the batches that follow are read from the same `DoGet`.
+        let mut stream = client
+            .do_get(ticket)
+            .await
+            .map_err(to_compute_err)?
+            .into_inner();
+        let Some(data) = stream.message().await.map_err(to_compute_err)? else {
+            polars_bail!(ComputeError: "flight stream ended before sending a schema")
+        };
+        let (schema, ipc_schema) = arrow::io::flight::deserialize_schemas(&data.data_header)?;
+        return Ok(FlightPlan {
+            schema,
+            ipc_schema,
+            endpoints: vec![EndpointSource::Stream(stream)],
+        });
+    };
+
+    let FlightInfo {
+        schema, endpoint, ..
+    } = client
+        .get_flight_info(descriptor)
+        .await
+        .map_err(to_compute_err)?
+        .into_inner();
+    let (schema, ipc_schema) = deserialize_schemas_from_info(&schema)?;
+
+    let endpoints = endpoint
+        .into_iter()
+        .map(|endpoint| {
+            let ticket = endpoint.ticket.ok_or_else(
+                || polars_err!(ComputeError: "flight endpoint does not have a ticket"),
+            )?;
+            // Any of the locations can serve the ticket, take the first one.
+            let location = endpoint
+                .location
+                .into_iter()
+                .map(|location| location.uri)
+                .find(|uri| !uri.starts_with("arrow-flight-reuse-connection://"));
+            Ok(EndpointSource::Ticket(ticket, location))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Ok(FlightPlan {
+        schema,
+        ipc_schema,
+        endpoints,
+    })
+}
+
+async fn fetch_endpoint(
+    location: &str,
+    source: EndpointSource,
+    schema: &ArrowSchema,
+    ipc_schema: &IpcSchema,
+    projection: Option<&[usize]>,
+    n_rows: Option<usize>,
+) -> PolarsResult<Vec<RecordBatch>> {
+    let mut stream = match source {
+        EndpointSource::Stream(stream) => stream,
+        EndpointSource::Ticket(ticket, endpoint_location) => {
+            let mut client = connect(endpoint_location.as_deref().unwrap_or(location)).await?;
+            client
+                .do_get(ticket)
+                .await
+                .map_err(to_compute_err)?
+                .into_inner()
+        },
+    };
+
+    let mut dictionaries = Dictionaries::default();
+    let mut batches = vec![];
+    let mut num_rows = 0;
+    while let Some(data) = stream.message().await.map_err(to_compute_err)? {
+        if let Some(batch) = deserialize_message_with_projection(
+            &data,
+            &schema.fields,
+            ipc_schema,
+            projection,
+            &mut dictionaries,
+        )? {
+            num_rows += batch.len();
+            batches.push(batch);
+            if n_rows.map_or(false, |n| num_rows >= n) {
+                break;
+            }
+        }
+    }
+    Ok(batches)
+}
+
+struct BatchReader(VecDeque<RecordBatch>);
+
+impl ArrowReader for BatchReader {
+    fn next_record_batch(&mut self) -> PolarsResult<Option<RecordBatch>> {
+        Ok(self.0.pop_front())
+    }
+}
+
+/// Read the result of a [`FlightQuery`] into a [`DataFrame`].
+///
+/// All endpoints of the query are fetched concurrently, the result keeps the order of the
+/// endpoints.
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::flight::{FlightQuery, FlightReader};
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     FlightReader::new("grpc://localhost:32010", FlightQuery::Sql("SELECT 1".into()))
+///         .finish()
+/// }
+/// ```
+#[must_use]
+pub struct FlightReader {
+    location: String,
+    query: FlightQuery,
+    columns: Option<Vec<String>>,
+    n_rows: Option<usize>,
+    rechunk: bool,
+    plan: Option<FlightPlan>,
+}
+
+impl FlightReader {
+    pub fn new(location: impl Into<String>, query: FlightQuery) -> Self {
+        Self {
+            location: location.into(),
+            query,
+            columns: None,
+            n_rows: None,
+            rechunk: false,
+            plan: None,
+        }
+    }
+
+    /// Only read the given columns.
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Stop reading after `n_rows`.
+    pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
+        self.n_rows = n_rows;
+        self
+    }
+
+    pub fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    /// Get the schema of the query result. The query is planned once, reading the result
+    /// afterwards reuses the plan.
+    pub fn schema(&mut self) -> PolarsResult<Schema> {
+        let plan = match self.plan.take() {
+            Some(plan) => plan,
+            None => get_runtime().block_on_potential_spawn(plan(&self.location, &self.query))?,
+        };
+        let schema = Schema::from_iter(&plan.schema.fields);
+        self.plan = Some(plan);
+        Ok(schema)
+    }
+
+    pub fn finish(self) -> PolarsResult<DataFrame> {
+        get_runtime().block_on_potential_spawn(self.finish_async())
+    }
+
+    pub async fn finish_async(mut self) -> PolarsResult<DataFrame> {
+        let FlightPlan {
+            schema,
+            ipc_schema,
+            endpoints,
+        } = match self.plan.take() {
+            Some(plan) => plan,
+            None => plan(&self.location, &self.query).await?,
+        };
+
+        // The IPC reader expects the projection to be in schema order.
+        let projection = self
+            .columns
+            .as_ref()
+            .map(|columns| {
+                let mut projection = columns_to_projection(columns, &schema)?;
+                projection.sort_unstable();
+                PolarsResult::Ok(projection)
+            })
+            .transpose()?;
+        let projected_schema = match &projection {
+            Some(projection) => apply_projection(&schema, projection),
+            None => schema.clone(),
+        };
+
+        let batches = try_join_all(endpoints.into_iter().map(|source| {
+            fetch_endpoint(
+                &self.location,
+                source,
+                &schema,
+                &ipc_schema,
+                projection.as_deref(),
+                self.n_rows,
+            )
+        }))
+        .await?;
+
+        let reader = BatchReader(batches.into_iter().flatten().collect());
+        let df = finish_reader(
+            reader,
+            self.rechunk,
+            self.n_rows,
+            None,
+            &projected_schema,
+            None,
+        )?;
+
+        match &self.columns {
+            Some(columns) => df.select(columns),
+            None => Ok(df),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_statement_query() {
+        let encoded = encode_statement_query("SELECT 1");
+        // Any.type_url
+        assert_eq!(encoded[0], 0x0a);
+        assert_eq!(encoded[1] as usize, STATEMENT_QUERY_TYPE_URL.len());
+        let offset = 2 + STATEMENT_QUERY_TYPE_URL.len();
+        // Any.value containing CommandStatementQuery.query
+        assert_eq!(&encoded[offset..offset + 4], &[0x12, 10, 0x0a, 8]);
+        assert_eq!(&encoded[offset + 4..], b"SELECT 1");
+    }
+
+    #[test]
+    fn test_location_to_uri() {
+        assert_eq!(location_to_uri("grpc://host:1"), "http://host:1");
+        assert_eq!(location_to_uri("grpc+tcp://host:1"), "http://host:1");
+        assert_eq!(location_to_uri("grpc+tls://host:1"), "https://host:1");
+        assert_eq!(location_to_uri("http://host:1"), "http://host:1");
+    }
+}
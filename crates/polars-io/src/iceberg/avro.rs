@@ -0,0 +1,420 @@
+//! A small Avro object container decoder for Iceberg manifests.
+//!
+//! Manifests contain records without fields (e.g. the partition tuple of an unpartitioned
+//! table), which can't be represented as arrow arrays. As manifests are small, they are decoded
+//! into [`AvroValue`]s instead.
+use std::io::Read;
+
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use serde_json::Value;
+
+const MAGIC: &[u8; 4] = b"Obj\x01";
+const SYNC_LEN: usize = 16;
+
+#[derive(Clone, Debug)]
+enum AvroSchema {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Record(Vec<(String, AvroSchema)>),
+    Enum(Vec<String>),
+    Array(Box<AvroSchema>),
+    Map(Box<AvroSchema>),
+    Union(Vec<AvroSchema>),
+    Fixed(usize),
+}
+
+/// A decoded Avro datum. `int` and `long` are both decoded as [`AvroValue::Long`], `float` and
+/// `double` as [`AvroValue::Double`]. Maps are decoded as arrays of `key`/`value` records, which
+/// is how Iceberg encodes maps with non-string keys.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum AvroValue {
+    Null,
+    Boolean(bool),
+    Long(i64),
+    Double(f64),
+    Bytes(Vec<u8>),
+    String(String),
+    Record(Vec<(String, AvroValue)>),
+    Array(Vec<AvroValue>),
+}
+
+impl AvroValue {
+    /// Get a field of a record. Missing fields are returned as [`AvroValue::Null`].
+    pub(super) fn field(&self, name: &str) -> &AvroValue {
+        match self {
+            Self::Record(fields) => fields
+                .iter()
+                .find_map(|(field, value)| (field == name).then_some(value))
+                .unwrap_or(&AvroValue::Null),
+            _ => &AvroValue::Null,
+        }
+    }
+
+    pub(super) fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Long(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_array(&self) -> &[AvroValue] {
+        match self {
+            Self::Array(v) => v,
+            _ => &[],
+        }
+    }
+}
+
+fn out_of_spec(msg: &str) -> PolarsError {
+    polars_err!(ComputeError: "out of spec Avro: {}", msg)
+}
+
+fn parse_schema(
+    value: &Value,
+    named: &mut PlHashMap<String, AvroSchema>,
+) -> PolarsResult<AvroSchema> {
+    let schema = match value {
+        Value::String(name) => match name.as_str() {
+            "null" => AvroSchema::Null,
+            "boolean" => AvroSchema::Boolean,
+            "int" => AvroSchema::Int,
+            "long" => AvroSchema::Long,
+            "float" => AvroSchema::Float,
+            "double" => AvroSchema::Double,
+            "bytes" => AvroSchema::Bytes,
+            "string" => AvroSchema::String,
+            name => named
+                .get(name)
+                .cloned()
+                .ok_or_else(|| out_of_spec(&format!("unknown type '{name}'")))?,
+        },
+        Value::Array(variants) => AvroSchema::Union(
+            variants
+                .iter()
+                .map(|v| parse_schema(v, named))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        Value::Object(object) => {
+            let r#type = object
+                .get("type")
+                .ok_or_else(|| out_of_spec("schema without type"))?;
+            let schema = match r#type.as_str() {
+                Some("record") => {
+                    let fields = object
+                        .get("fields")
+                        .and_then(|fields| fields.as_array())
+                        .ok_or_else(|| out_of_spec("record without fields"))?
+                        .iter()
+                        .map(|field| {
+                            let name = field
+                                .get("name")
+                                .and_then(|name| name.as_str())
+                                .ok_or_else(|| out_of_spec("field without name"))?;
+                            let r#type = field
+                                .get("type")
+                                .ok_or_else(|| out_of_spec("field without type"))?;
+                            Ok((name.to_string(), parse_schema(r#type, named)?))
+                        })
+                        .collect::<PolarsResult<_>>()?;
+                    AvroSchema::Record(fields)
+                },
+                Some("enum") => AvroSchema::Enum(
+                    object
+                        .get("symbols")
+                        .and_then(|symbols| symbols.as_array())
+                        .ok_or_else(|| out_of_spec("enum without symbols"))?
+                        .iter()
+                        .map(|symbol| symbol.as_str().unwrap_or_default().to_string())
+                        .collect(),
+                ),
+                Some("array") => AvroSchema::Array(Box::new(parse_schema(
+                    object
+                        .get("items")
+                        .ok_or_else(|| out_of_spec("array without items"))?,
+                    named,
+                )?)),
+                Some("map") => AvroSchema::Map(Box::new(parse_schema(
+                    object
+                        .get("values")
+                        .ok_or_else(|| out_of_spec("map without values"))?,
+                    named,
+                )?)),
+                Some("fixed") => AvroSchema::Fixed(
+                    object
+                        .get("size")
+                        .and_then(|size| size.as_u64())
+                        .ok_or_else(|| out_of_spec("fixed without size"))?
+                        as usize,
+                ),
+                // A primitive with attributes, e.g. a logical type.
+                _ => parse_schema(r#type, named)?,
+            };
+            if let Some(name) = object.get("name").and_then(|name| name.as_str()) {
+                named.insert(name.to_string(), schema.clone());
+            }
+            schema
+        },
+        _ => return Err(out_of_spec("invalid schema")),
+    };
+    Ok(schema)
+}
+
+struct Decoder<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    fn take(&mut self, n: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(n <= self.data.len(), ComputeError: "out of spec Avro: unexpected end of data");
+        let (out, rest) = self.data.split_at(n);
+        self.data = rest;
+        Ok(out)
+    }
+
+    fn long(&mut self) -> PolarsResult<i64> {
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            polars_ensure!(shift < 64, ComputeError: "out of spec Avro: varint too long");
+            let byte = self.take(1)?[0];
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        // Zig-zag decoding.
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+
+    fn len(&mut self) -> PolarsResult<usize> {
+        let len = self.long()?;
+        polars_ensure!(len >= 0, ComputeError: "out of spec Avro: negative length");
+        Ok(len as usize)
+    }
+
+    fn bytes(&mut self) -> PolarsResult<&'a [u8]> {
+        let len = self.len()?;
+        self.take(len)
+    }
+
+    fn string(&mut self) -> PolarsResult<String> {
+        let bytes = self.bytes()?;
+        String::from_utf8(bytes.to_vec()).map_err(to_compute_err)
+    }
+
+    /// Decode the blocks of an array or map, calling `f` for every item.
+    fn blocks(&mut self, mut f: impl FnMut(&mut Self) -> PolarsResult<()>) -> PolarsResult<()> {
+        loop {
+            let mut count = self.long()?;
+            if count == 0 {
+                return Ok(());
+            }
+            if count < 0 {
+                // A negative count is followed by the block size in bytes.
+                count = -count;
+                self.long()?;
+            }
+            for _ in 0..count {
+                f(self)?;
+            }
+        }
+    }
+
+    fn value(&mut self, schema: &AvroSchema) -> PolarsResult<AvroValue> {
+        let value = match schema {
+            AvroSchema::Null => AvroValue::Null,
+            AvroSchema::Boolean => AvroValue::Boolean(self.take(1)?[0] != 0),
+            AvroSchema::Int | AvroSchema::Long => AvroValue::Long(self.long()?),
+            AvroSchema::Float => {
+                AvroValue::Double(f32::from_le_bytes(self.take(4)?.try_into().unwrap()) as f64)
+            },
+            AvroSchema::Double => {
+                AvroValue::Double(f64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+            },
+            AvroSchema::Bytes => AvroValue::Bytes(self.bytes()?.to_vec()),
+            AvroSchema::String => AvroValue::String(self.string()?),
+            AvroSchema::Fixed(size) => AvroValue::Bytes(self.take(*size)?.to_vec()),
+            AvroSchema::Enum(symbols) => {
+                let idx = self.len()?;
+                let symbol = symbols
+                    .get(idx)
+                    .ok_or_else(|| out_of_spec("enum index out of bounds"))?;
+                AvroValue::String(symbol.clone())
+            },
+            AvroSchema::Union(variants) => {
+                let idx = self.len()?;
+                let variant = variants
+                    .get(idx)
+                    .ok_or_else(|| out_of_spec("union index out of bounds"))?;
+                self.value(variant)?
+            },
+            AvroSchema::Record(fields) => AvroValue::Record(
+                fields
+                    .iter()
+                    .map(|(name, schema)| Ok((name.clone(), self.value(schema)?)))
+                    .collect::<PolarsResult<_>>()?,
+            ),
+            AvroSchema::Array(items) => {
+                let mut out = vec![];
+                self.blocks(|decoder| {
+                    out.push(decoder.value(items)?);
+                    Ok(())
+                })?;
+                AvroValue::Array(out)
+            },
+            AvroSchema::Map(values) => {
+                let mut out = vec![];
+                self.blocks(|decoder| {
+                    let key = AvroValue::String(decoder.string()?);
+                    let value = decoder.value(values)?;
+                    out.push(AvroValue::Record(vec![
+                        ("key".to_string(), key),
+                        ("value".to_string(), value),
+                    ]));
+                    Ok(())
+                })?;
+                AvroValue::Array(out)
+            },
+        };
+        Ok(value)
+    }
+}
+
+fn decompress(codec: &str, data: &[u8]) -> PolarsResult<Vec<u8>> {
+    let mut out = vec![];
+    match codec {
+        "null" => out.extend_from_slice(data),
+        "deflate" => {
+            flate2::read::DeflateDecoder::new(data)
+                .read_to_end(&mut out)
+                .map_err(to_compute_err)?;
+        },
+        "zstandard" => {
+            zstd::stream::read::Decoder::new(data)
+                .and_then(|mut decoder| decoder.read_to_end(&mut out))
+                .map_err(to_compute_err)?;
+        },
+        "snappy" => {
+            // The block is followed by a 4 byte CRC32 checksum of the uncompressed data.
+            polars_ensure!(data.len() >= 4, ComputeError: "out of spec Avro: truncated snappy block");
+            out = snap::raw::Decoder::new()
+                .decompress_vec(&data[..data.len() - 4])
+                .map_err(to_compute_err)?;
+        },
+        codec => polars_bail!(ComputeError: "unsupported Avro codec '{}'", codec),
+    }
+    Ok(out)
+}
+
+/// Decode all records of an Avro object container file.
+pub(super) fn read_avro_file(data: &[u8]) -> PolarsResult<Vec<AvroValue>> {
+    let mut decoder = Decoder { data };
+    polars_ensure!(decoder.take(4)? == MAGIC, ComputeError: "not an Avro file");
+
+    let mut metadata = PlHashMap::new();
+    decoder.blocks(|decoder| {
+        let key = decoder.string()?;
+        metadata.insert(key, decoder.bytes()?);
+        Ok(())
+    })?;
+    let sync = decoder.take(SYNC_LEN)?;
+
+    let schema = metadata
+        .get("avro.schema")
+        .ok_or_else(|| out_of_spec("missing schema"))?;
+    let schema: Value = serde_json::from_slice(schema).map_err(to_compute_err)?;
+    let schema = parse_schema(&schema, &mut PlHashMap::new())?;
+    let codec = metadata
+        .get("avro.codec")
+        .map(|codec| std::str::from_utf8(codec).map_err(to_compute_err))
+        .transpose()?
+        .unwrap_or("null");
+
+    let mut values = vec![];
+    while !decoder.data.is_empty() {
+        let count = decoder.len()?;
+        let block = decoder.bytes()?;
+        let block = decompress(codec, block)?;
+        let mut block_decoder = Decoder { data: &block };
+        for _ in 0..count {
+            values.push(block_decoder.value(&schema)?);
+        }
+        polars_ensure!(decoder.take(SYNC_LEN)? == sync, ComputeError: "out of spec Avro: sync marker mismatch");
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_long(value: i64, out: &mut Vec<u8>) {
+        let mut value = ((value << 1) ^ (value >> 63)) as u64;
+        while value >= 0x80 {
+            out.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        out.push(value as u8);
+    }
+
+    fn encode_bytes(bytes: &[u8], out: &mut Vec<u8>) {
+        encode_long(bytes.len() as i64, out);
+        out.extend_from_slice(bytes);
+    }
+
+    #[test]
+    fn test_read_avro_file() {
+        let schema = r#"{"type": "record", "name": "r", "fields": [
+            {"name": "path", "type": "string"},
+            {"name": "count", "type": ["null", "long"]},
+            {"name": "partition", "type": {"type": "record", "name": "p", "fields": []}}
+        ]}"#;
+        let sync = [7u8; SYNC_LEN];
+
+        let mut file = MAGIC.to_vec();
+        encode_long(1, &mut file);
+        encode_bytes(b"avro.schema", &mut file);
+        encode_bytes(schema.as_bytes(), &mut file);
+        encode_long(0, &mut file);
+        file.extend_from_slice(&sync);
+
+        let mut block = vec![];
+        encode_bytes(b"a.parquet", &mut block);
+        encode_long(1, &mut block);
+        encode_long(-300, &mut block);
+        encode_bytes(b"b.parquet", &mut block);
+        encode_long(0, &mut block);
+        encode_long(2, &mut file);
+        encode_bytes(&block, &mut file);
+        file.extend_from_slice(&sync);
+
+        let values = read_avro_file(&file).unwrap();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[0].field("path").as_str(), Some("a.parquet"));
+        assert_eq!(values[0].field("count").as_i64(), Some(-300));
+        assert_eq!(values[0].field("partition"), &AvroValue::Record(vec![]));
+        assert_eq!(values[1].field("count"), &AvroValue::Null);
+    }
+}
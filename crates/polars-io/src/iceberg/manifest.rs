@@ -0,0 +1,290 @@
+use polars_core::prelude::*;
+
+use super::avro::{read_avro_file, AvroValue};
+use super::metadata::{resolve_path, PartitionField, Snapshot};
+use crate::predicates::{BatchStats, ColumnStats, StatsEvaluator};
+
+/// Status of a manifest entry that was removed in its snapshot.
+const STATUS_DELETED: i64 = 2;
+
+/// A top-level column of the table schema.
+pub(super) struct SchemaColumn {
+    pub id: i32,
+    pub field: Field,
+}
+
+/// An entry of a manifest list.
+pub(super) struct ManifestFile {
+    path: String,
+    /// 0 for data manifests, 1 for delete manifests.
+    content: i64,
+    pub partition_spec_id: Option<i32>,
+    /// The partition field summaries, in the order of the partition spec.
+    partitions: Vec<AvroValue>,
+}
+
+/// A data file of the table, selected from the manifests.
+#[derive(Clone, Debug)]
+pub struct IcebergDataFile {
+    pub path: std::path::PathBuf,
+    pub record_count: u64,
+}
+
+pub(super) fn read_manifest_files(snapshot: &Snapshot) -> PolarsResult<Vec<ManifestFile>> {
+    if let Some(manifest_list) = &snapshot.manifest_list {
+        let data = std::fs::read(resolve_path(manifest_list)?)?;
+        read_avro_file(&data)?
+            .iter()
+            .map(|entry| {
+                let path = entry.field("manifest_path").as_str().ok_or_else(
+                    || polars_err!(ComputeError: "Iceberg manifest list entry without a path"),
+                )?;
+                Ok(ManifestFile {
+                    path: path.to_string(),
+                    content: entry.field("content").as_i64().unwrap_or(0),
+                    partition_spec_id: entry
+                        .field("partition_spec_id")
+                        .as_i64()
+                        .map(|id| id as i32),
+                    partitions: entry.field("partitions").as_array().to_vec(),
+                })
+            })
+            .collect()
+    } else {
+        Ok(snapshot
+            .manifests
+            .iter()
+            .flatten()
+            .map(|path| ManifestFile {
+                path: path.clone(),
+                content: 0,
+                partition_spec_id: None,
+                partitions: vec![],
+            })
+            .collect())
+    }
+}
+
+/// Decode a single-value serialized bound into an [`AvroValue`] of the same shape as the
+/// partition values in the manifests.
+fn decode_bound(bytes: &[u8], dtype: &DataType) -> Option<AvroValue> {
+    let value = match dtype {
+        DataType::Boolean => AvroValue::Boolean(*bytes.first()? != 0),
+        DataType::Int32 | DataType::Date => {
+            AvroValue::Long(i32::from_le_bytes(bytes.try_into().ok()?) as i64)
+        },
+        DataType::Int64 | DataType::Time | DataType::Datetime(_, _) => match bytes.len() {
+            // A column that was promoted from `int` may still have 4 byte bounds.
+            4 => AvroValue::Long(i32::from_le_bytes(bytes.try_into().ok()?) as i64),
+            _ => AvroValue::Long(i64::from_le_bytes(bytes.try_into().ok()?)),
+        },
+        DataType::Float32 => AvroValue::Double(f32::from_le_bytes(bytes.try_into().ok()?) as f64),
+        DataType::Float64 => match bytes.len() {
+            4 => AvroValue::Double(f32::from_le_bytes(bytes.try_into().ok()?) as f64),
+            _ => AvroValue::Double(f64::from_le_bytes(bytes.try_into().ok()?)),
+        },
+        DataType::String => AvroValue::String(std::str::from_utf8(bytes).ok()?.to_string()),
+        DataType::Binary => AvroValue::Bytes(bytes.to_vec()),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Convert a bound or identity partition value to a single-value [`Series`] of `dtype`.
+fn to_stat(value: &AvroValue, dtype: &DataType) -> PolarsResult<Option<Series>> {
+    let s = match (value, dtype) {
+        // Iceberg times are in microseconds.
+        (AvroValue::Long(v), DataType::Time) => Series::new("", [*v * 1000]),
+        (AvroValue::Long(v), _) if dtype.is_numeric() || dtype.is_temporal() => {
+            Series::new("", [*v])
+        },
+        (AvroValue::Double(v), _) if dtype.is_float() => Series::new("", [*v]),
+        (AvroValue::Boolean(v), DataType::Boolean) => Series::new("", [*v]),
+        (AvroValue::String(v), DataType::String) => Series::new("", [v.as_str()]),
+        (AvroValue::Bytes(v), DataType::Binary) => Series::new("", [v.as_slice()]),
+        _ => return Ok(None),
+    };
+    s.cast(dtype).map(Some)
+}
+
+/// Find the value of the entry with key `id` in an int-keyed map.
+fn map_get(map: &AvroValue, id: i32) -> Option<&AvroValue> {
+    map.as_array()
+        .iter()
+        .find(|entry| entry.field("key").as_i64() == Some(id as i64))
+        .map(|entry| entry.field("value"))
+}
+
+fn identity_source_ids(
+    partition_fields: &[PartitionField],
+) -> impl Iterator<Item = (usize, i32)> + '_ {
+    partition_fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| field.transform == "identity")
+        .map(|(idx, field)| (idx, field.source_id))
+}
+
+fn should_read(evaluator: &dyn StatsEvaluator, stats: &BatchStats) -> PolarsResult<bool> {
+    match evaluator.should_read(stats) {
+        Err(PolarsError::ColumnNotFound(_)) => Ok(true),
+        should_read => should_read,
+    }
+}
+
+/// Prune a manifest with the bounds of its identity partition fields.
+pub(super) fn should_read_manifest(
+    manifest: &ManifestFile,
+    partition_fields: &[PartitionField],
+    columns: &[SchemaColumn],
+    schema: &SchemaRef,
+    evaluator: &dyn StatsEvaluator,
+) -> PolarsResult<bool> {
+    if manifest.partitions.is_empty() {
+        return Ok(true);
+    }
+
+    let mut stats = columns
+        .iter()
+        .map(|column| ColumnStats::from_field(column.field.clone()))
+        .collect::<Vec<_>>();
+    for (idx, source_id) in identity_source_ids(partition_fields) {
+        let (Some(summary), Some(column_idx)) = (
+            manifest.partitions.get(idx),
+            columns.iter().position(|c| c.id == source_id),
+        ) else {
+            continue;
+        };
+        let field = &columns[column_idx].field;
+        let bound = |name| {
+            summary
+                .field(name)
+                .as_bytes()
+                .and_then(|bytes| decode_bound(bytes, field.data_type()))
+                .map(|value| to_stat(&value, field.data_type()))
+                .transpose()
+                .map(Option::flatten)
+        };
+        let (min, max) = (bound("lower_bound")?, bound("upper_bound")?);
+        stats[column_idx] = ColumnStats::new(field.clone(), None, min, max);
+    }
+
+    should_read(evaluator, &BatchStats::new(schema.clone(), stats, None))
+}
+
+/// Build the [`BatchStats`] of a data file from its column bounds and partition values.
+fn data_file_stats(
+    data_file: &AvroValue,
+    partition_fields: &[PartitionField],
+    columns: &[SchemaColumn],
+    schema: &SchemaRef,
+) -> PolarsResult<BatchStats> {
+    let record_count = data_file.field("record_count").as_i64();
+    let partition = data_file.field("partition");
+
+    let stats = columns
+        .iter()
+        .map(|column| {
+            let dtype = column.field.data_type();
+            let null_count = map_get(data_file.field("null_value_counts"), column.id)
+                .and_then(|v| v.as_i64())
+                .map(|n| Series::new("", [n as IdxSize]));
+
+            let identity = identity_source_ids(partition_fields)
+                .find(|(_, source_id)| *source_id == column.id)
+                .map(|(idx, _)| partition.field(&partition_fields[idx].name));
+            let (min, max) = match identity {
+                Some(value) => {
+                    let value = to_stat(value, dtype)?;
+                    (value.clone(), value)
+                },
+                None => {
+                    let bound = |name| {
+                        map_get(data_file.field(name), column.id)
+                            .and_then(|v| v.as_bytes())
+                            .and_then(|bytes| decode_bound(bytes, dtype))
+                            .map(|value| to_stat(&value, dtype))
+                            .transpose()
+                            .map(Option::flatten)
+                    };
+                    (bound("lower_bounds")?, bound("upper_bounds")?)
+                },
+            };
+            Ok(ColumnStats::new(column.field.clone(), null_count, min, max))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Ok(BatchStats::new(
+        schema.clone(),
+        stats,
+        record_count.map(|n| n as usize),
+    ))
+}
+
+/// Read the live data files of a manifest, pruned with `evaluator`.
+pub(super) fn read_data_files(
+    manifest: &ManifestFile,
+    partition_fields: &[PartitionField],
+    columns: &[SchemaColumn],
+    schema: &SchemaRef,
+    evaluator: Option<&dyn StatsEvaluator>,
+) -> PolarsResult<Vec<IcebergDataFile>> {
+    polars_ensure!(
+        manifest.content == 0,
+        ComputeError: "Iceberg tables with delete files are not supported"
+    );
+
+    let data = std::fs::read(resolve_path(&manifest.path)?)?;
+    let mut out = vec![];
+    for entry in read_avro_file(&data)? {
+        if entry.field("status").as_i64() == Some(STATUS_DELETED) {
+            continue;
+        }
+        let data_file = entry.field("data_file");
+        polars_ensure!(
+            data_file.field("content").as_i64().unwrap_or(0) == 0,
+            ComputeError: "Iceberg tables with delete files are not supported"
+        );
+        let format = data_file.field("file_format").as_str().unwrap_or_default();
+        polars_ensure!(
+            format.eq_ignore_ascii_case("parquet"),
+            ComputeError: "unsupported Iceberg data file format '{}'; only Parquet is supported",
+            format
+        );
+
+        if let Some(evaluator) = evaluator {
+            let stats = data_file_stats(data_file, partition_fields, columns, schema)?;
+            if !should_read(evaluator, &stats)? {
+                continue;
+            }
+        }
+
+        let path = data_file
+            .field("file_path")
+            .as_str()
+            .ok_or_else(|| polars_err!(ComputeError: "Iceberg data file without a path"))?;
+        out.push(IcebergDataFile {
+            path: resolve_path(path)?,
+            record_count: data_file.field("record_count").as_i64().unwrap_or(0) as u64,
+        });
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_bound() {
+        let to_series = |bytes: &[u8], dtype: &DataType| {
+            to_stat(&decode_bound(bytes, dtype).unwrap(), dtype)
+                .unwrap()
+                .unwrap()
+        };
+        assert!(to_series(&7i32.to_le_bytes(), &DataType::Int64).equals(&Series::new("", [7i64])));
+        assert!(to_series(b"abc", &DataType::String).equals(&Series::new("", ["abc"])));
+        let time = to_series(&5i64.to_le_bytes(), &DataType::Time);
+        assert_eq!(time.to_physical_repr().i64().unwrap().get(0), Some(5000));
+    }
+}
@@ -0,0 +1,375 @@
+use std::path::{Path, PathBuf};
+
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Which snapshot of an Iceberg table to read.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IcebergSnapshot {
+    /// The current snapshot of the table.
+    #[default]
+    Current,
+    /// The snapshot with the given id.
+    Id(i64),
+    /// The snapshot that was current at the given moment, in milliseconds since the unix epoch.
+    AsOf(i64),
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct TableMetadata {
+    pub format_version: u8,
+    #[serde(default)]
+    pub current_schema_id: Option<i32>,
+    #[serde(default)]
+    pub schemas: Vec<IcebergSchema>,
+    /// The only schema of v1 tables that don't track schemas.
+    #[serde(default)]
+    pub schema: Option<IcebergSchema>,
+    #[serde(default)]
+    pub default_spec_id: Option<i32>,
+    #[serde(default)]
+    pub partition_specs: Vec<PartitionSpec>,
+    /// The only partition spec of v1 tables that don't track specs.
+    #[serde(default)]
+    pub partition_spec: Option<Vec<PartitionField>>,
+    #[serde(default)]
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<Snapshot>,
+    #[serde(default)]
+    pub snapshot_log: Vec<SnapshotLogEntry>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct IcebergSchema {
+    #[serde(default)]
+    pub schema_id: i32,
+    pub fields: Vec<NestedField>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(super) struct NestedField {
+    pub id: i32,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub r#type: Value,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct PartitionSpec {
+    pub spec_id: i32,
+    pub fields: Vec<PartitionField>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct PartitionField {
+    pub source_id: i32,
+    pub name: String,
+    pub transform: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct Snapshot {
+    pub snapshot_id: i64,
+    pub timestamp_ms: i64,
+    #[serde(default)]
+    pub manifest_list: Option<String>,
+    /// v1 tables may list the manifests in the metadata instead of in a manifest list.
+    #[serde(default)]
+    pub manifests: Option<Vec<String>>,
+    #[serde(default)]
+    pub schema_id: Option<i32>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct SnapshotLogEntry {
+    pub snapshot_id: i64,
+    pub timestamp_ms: i64,
+}
+
+impl TableMetadata {
+    pub fn from_json(json: &[u8]) -> PolarsResult<Self> {
+        let metadata: Self = serde_json::from_slice(json).map_err(to_compute_err)?;
+        polars_ensure!(
+            matches!(metadata.format_version, 1 | 2),
+            ComputeError: "unsupported Iceberg format version {}", metadata.format_version
+        );
+        Ok(metadata)
+    }
+
+    /// Select a snapshot. Returns `None` for a table without snapshots.
+    pub fn snapshot(&self, selection: IcebergSnapshot) -> PolarsResult<Option<&Snapshot>> {
+        let id = match selection {
+            // v1 tables use -1 for "no snapshot".
+            IcebergSnapshot::Current => match self.current_snapshot_id {
+                Some(id) if id >= 0 => id,
+                _ => return Ok(None),
+            },
+            IcebergSnapshot::Id(id) => id,
+            IcebergSnapshot::AsOf(timestamp_ms) => {
+                // The snapshot log holds the history of the current snapshot; it is ordered by
+                // time and doesn't contain snapshots of rolled back branches.
+                let log = if self.snapshot_log.is_empty() {
+                    let mut log = self
+                        .snapshots
+                        .iter()
+                        .map(|s| (s.timestamp_ms, s.snapshot_id))
+                        .collect::<Vec<_>>();
+                    log.sort_unstable();
+                    log
+                } else {
+                    self.snapshot_log
+                        .iter()
+                        .map(|entry| (entry.timestamp_ms, entry.snapshot_id))
+                        .collect()
+                };
+                match log.iter().rev().find(|(ts, _)| *ts <= timestamp_ms) {
+                    Some((_, id)) => *id,
+                    None => polars_bail!(
+                        ComputeError: "Iceberg table has no snapshot at or before timestamp {}", timestamp_ms
+                    ),
+                }
+            },
+        };
+        match self.snapshots.iter().find(|s| s.snapshot_id == id) {
+            Some(snapshot) => Ok(Some(snapshot)),
+            None => polars_bail!(ComputeError: "Iceberg snapshot {} not found", id),
+        }
+    }
+
+    /// Get the schema with the given id, or the current schema.
+    pub fn schema(&self, schema_id: Option<i32>) -> PolarsResult<&IcebergSchema> {
+        let schema_id = schema_id.or(self.current_schema_id);
+        let schema = match schema_id {
+            Some(id) => self.schemas.iter().find(|s| s.schema_id == id),
+            None => None,
+        };
+        schema
+            .or(self.schema.as_ref())
+            .or(self.schemas.last())
+            .ok_or_else(|| polars_err!(ComputeError: "Iceberg table metadata has no schema"))
+    }
+
+    /// Get the fields of the partition spec with the given id, or of the default spec.
+    pub fn partition_fields(&self, spec_id: Option<i32>) -> &[PartitionField] {
+        let spec_id = spec_id.or(self.default_spec_id);
+        self.partition_specs
+            .iter()
+            .find(|spec| Some(spec.spec_id) == spec_id)
+            .map(|spec| spec.fields.as_slice())
+            .or(self.partition_spec.as_deref())
+            .unwrap_or_default()
+    }
+}
+
+impl IcebergSchema {
+    pub fn to_polars_schema(&self) -> PolarsResult<Schema> {
+        self.fields
+            .iter()
+            .map(|field| Ok(Field::new(&field.name, to_polars_dtype(&field.r#type)?)))
+            .collect()
+    }
+}
+
+/// Convert an Iceberg type to a polars [`DataType`].
+pub(super) fn to_polars_dtype(r#type: &Value) -> PolarsResult<DataType> {
+    let dtype = match r#type {
+        Value::String(name) => match name.as_str() {
+            "boolean" => DataType::Boolean,
+            "int" => DataType::Int32,
+            "long" => DataType::Int64,
+            "float" => DataType::Float32,
+            "double" => DataType::Float64,
+            "date" => DataType::Date,
+            "time" => DataType::Time,
+            "timestamp" => DataType::Datetime(TimeUnit::Microseconds, None),
+            "timestamptz" => DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())),
+            "string" => DataType::String,
+            "uuid" | "binary" => DataType::Binary,
+            name if name.starts_with("fixed[") => DataType::Binary,
+            #[cfg(feature = "dtype-decimal")]
+            name if name.starts_with("decimal(") => {
+                let parse = || {
+                    let (precision, scale) = name
+                        .strip_prefix("decimal(")?
+                        .strip_suffix(')')?
+                        .split_once(',')?;
+                    Some(DataType::Decimal(
+                        Some(precision.trim().parse().ok()?),
+                        Some(scale.trim().parse().ok()?),
+                    ))
+                };
+                parse().ok_or_else(
+                    || polars_err!(ComputeError: "invalid Iceberg decimal type '{}'", name),
+                )?
+            },
+            name => polars_bail!(ComputeError: "unsupported Iceberg type '{}'", name),
+        },
+        Value::Object(object) => match object.get("type").and_then(|t| t.as_str()) {
+            Some("struct") => {
+                let fields = object
+                    .get("fields")
+                    .cloned()
+                    .map(serde_json::from_value::<Vec<NestedField>>)
+                    .transpose()
+                    .map_err(to_compute_err)?
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|field| Ok(Field::new(&field.name, to_polars_dtype(&field.r#type)?)))
+                    .collect::<PolarsResult<_>>()?;
+                DataType::Struct(fields)
+            },
+            Some("list") => {
+                let element = object.get("element").unwrap_or(&Value::Null);
+                DataType::List(Box::new(to_polars_dtype(element)?))
+            },
+            Some("map") => {
+                let key = object.get("key").unwrap_or(&Value::Null);
+                let value = object.get("value").unwrap_or(&Value::Null);
+                DataType::List(Box::new(DataType::Struct(vec![
+                    Field::new("key", to_polars_dtype(key)?),
+                    Field::new("value", to_polars_dtype(value)?),
+                ])))
+            },
+            _ => polars_bail!(ComputeError: "unsupported Iceberg type '{}'", r#type),
+        },
+        _ => polars_bail!(ComputeError: "unsupported Iceberg type '{}'", r#type),
+    };
+    Ok(dtype)
+}
+
+/// Iceberg stores absolute locations, which are local paths or `file:` URIs for tables on the
+/// local file system.
+pub(super) fn resolve_path(location: &str) -> PolarsResult<PathBuf> {
+    let path = location
+        .strip_prefix("file://")
+        .or_else(|| location.strip_prefix("file:"))
+        .unwrap_or(location);
+    polars_ensure!(
+        !path.contains("://"),
+        ComputeError: "reading Iceberg tables from '{}' is not supported; only local tables can be scanned",
+        location
+    );
+    Ok(PathBuf::from(path))
+}
+
+/// The version of a metadata file name, `v<N>.metadata.json` or `<N>-<uuid>.metadata.json`.
+fn metadata_version(file_name: &str) -> Option<u64> {
+    let stem = file_name.strip_suffix(".metadata.json")?;
+    let version = stem.strip_prefix('v').unwrap_or(stem);
+    let version = version.split_once('-').map_or(version, |(v, _)| v);
+    version.parse().ok()
+}
+
+/// Find the latest metadata file of the table at `location`. `location` may also point to a
+/// metadata file directly.
+pub(super) fn find_metadata_file(location: &Path) -> PolarsResult<PathBuf> {
+    if location.is_file() {
+        return Ok(location.to_path_buf());
+    }
+    let metadata_dir = location.join("metadata");
+
+    // Tables that are managed without a catalog record the current version in a hint file.
+    if let Ok(hint) = std::fs::read_to_string(metadata_dir.join("version-hint.text")) {
+        let path = metadata_dir.join(format!("v{}.metadata.json", hint.trim()));
+        if path.is_file() {
+            return Ok(path);
+        }
+    }
+
+    let mut latest = None;
+    for entry in std::fs::read_dir(&metadata_dir)? {
+        let entry = entry?;
+        let file_name = entry.file_name();
+        if let Some(version) = file_name.to_str().and_then(metadata_version) {
+            if latest.as_ref().map_or(true, |(v, _)| version > *v) {
+                latest = Some((version, entry.path()));
+            }
+        }
+    }
+    latest.map(|(_, path)| path).ok_or_else(
+        || polars_err!(ComputeError: "no Iceberg metadata file found in '{}'", metadata_dir.display()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METADATA: &str = r#"{
+        "format-version": 2,
+        "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+        "location": "/tmp/table",
+        "current-schema-id": 1,
+        "schemas": [
+            {"schema-id": 0, "type": "struct", "fields": [
+                {"id": 1, "name": "a", "required": true, "type": "int"}
+            ]},
+            {"schema-id": 1, "type": "struct", "fields": [
+                {"id": 1, "name": "a", "required": true, "type": "long"},
+                {"id": 2, "name": "b", "required": false, "type": "decimal(10, 2)"}
+            ]}
+        ],
+        "default-spec-id": 0,
+        "partition-specs": [{"spec-id": 0, "fields": []}],
+        "current-snapshot-id": 20,
+        "snapshots": [
+            {"snapshot-id": 10, "timestamp-ms": 1000, "manifest-list": "/tmp/table/metadata/snap-10.avro", "schema-id": 0},
+            {"snapshot-id": 20, "timestamp-ms": 2000, "manifest-list": "/tmp/table/metadata/snap-20.avro", "schema-id": 1}
+        ],
+        "snapshot-log": [
+            {"snapshot-id": 10, "timestamp-ms": 1000},
+            {"snapshot-id": 20, "timestamp-ms": 2000}
+        ]
+    }"#;
+
+    #[test]
+    fn test_snapshot_selection() {
+        let metadata = TableMetadata::from_json(METADATA.as_bytes()).unwrap();
+        let id = |selection| metadata.snapshot(selection).unwrap().map(|s| s.snapshot_id);
+        assert_eq!(id(IcebergSnapshot::Current), Some(20));
+        assert_eq!(id(IcebergSnapshot::Id(10)), Some(10));
+        assert_eq!(id(IcebergSnapshot::AsOf(1500)), Some(10));
+        assert_eq!(id(IcebergSnapshot::AsOf(2000)), Some(20));
+        assert!(metadata.snapshot(IcebergSnapshot::AsOf(999)).is_err());
+        assert!(metadata.snapshot(IcebergSnapshot::Id(30)).is_err());
+    }
+
+    #[test]
+    fn test_schema() {
+        let metadata = TableMetadata::from_json(METADATA.as_bytes()).unwrap();
+        let old = metadata
+            .schema(Some(0))
+            .unwrap()
+            .to_polars_schema()
+            .unwrap();
+        assert_eq!(old.get("a"), Some(&DataType::Int32));
+
+        let current = metadata.schema(None).unwrap();
+        assert_eq!(current.schema_id, 1);
+        #[cfg(feature = "dtype-decimal")]
+        assert_eq!(
+            current.to_polars_schema().unwrap().get("b"),
+            Some(&DataType::Decimal(Some(10), Some(2)))
+        );
+    }
+
+    #[test]
+    fn test_metadata_version() {
+        assert_eq!(metadata_version("v3.metadata.json"), Some(3));
+        assert_eq!(
+            metadata_version("00012-4f0e1a3c-9d1b-4d0b-bb6b-2a1f0c3a9e57.metadata.json"),
+            Some(12)
+        );
+        assert_eq!(metadata_version("version-hint.text"), None);
+    }
+}
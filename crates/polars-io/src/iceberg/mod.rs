@@ -0,0 +1,17 @@
+//! # Read Apache Iceberg tables
+//!
+//! An [Iceberg] table consists of a metadata file that lists the snapshots of the table. A
+//! snapshot points to a manifest list, which in turn points to the manifests that list the data
+//! files. Manifests record partition values and column bounds, which are used to skip data
+//! files that can't match a predicate. Format versions 1 and 2 are supported, tables with delete
+//! files are not.
+//!
+//! [Iceberg]: https://iceberg.apache.org/spec/
+mod avro;
+mod manifest;
+mod metadata;
+mod table;
+
+pub use manifest::IcebergDataFile;
+pub use metadata::IcebergSnapshot;
+pub use table::IcebergTable;
@@ -0,0 +1,144 @@
+use std::path::Path;
+
+use polars_core::prelude::*;
+
+use super::manifest::{
+    read_data_files, read_manifest_files, should_read_manifest, IcebergDataFile, SchemaColumn,
+};
+use super::metadata::{
+    find_metadata_file, IcebergSchema, IcebergSnapshot, Snapshot, TableMetadata,
+};
+use crate::parquet::read::ParquetReader;
+use crate::predicates::PhysicalIoExpr;
+use crate::SerReader;
+
+/// A snapshot of an Iceberg table.
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::iceberg::{IcebergSnapshot, IcebergTable};
+///
+/// fn example() -> PolarsResult<()> {
+///     let table = IcebergTable::open("/warehouse/db/trades", IcebergSnapshot::Current)?;
+///     for data_file in table.data_files(None)? {
+///         println!("{}: {} rows", data_file.path.display(), data_file.record_count);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct IcebergTable {
+    metadata: TableMetadata,
+    snapshot: Option<Snapshot>,
+    schema: IcebergSchema,
+}
+
+impl IcebergTable {
+    /// Open the table at `location`, which is either the table directory or one of its
+    /// `*.metadata.json` files.
+    pub fn open(location: impl AsRef<Path>, snapshot: IcebergSnapshot) -> PolarsResult<Self> {
+        let metadata_file = find_metadata_file(location.as_ref())?;
+        let metadata = TableMetadata::from_json(&std::fs::read(metadata_file)?)?;
+        let snapshot = metadata.snapshot(snapshot)?.cloned();
+        // Time travel reads a snapshot with the schema it was written with.
+        let schema = metadata
+            .schema(snapshot.as_ref().and_then(|s| s.schema_id))?
+            .clone();
+        Ok(Self {
+            metadata,
+            snapshot,
+            schema,
+        })
+    }
+
+    /// The id of the selected snapshot, `None` if the table is empty.
+    pub fn snapshot_id(&self) -> Option<i64> {
+        self.snapshot.as_ref().map(|s| s.snapshot_id)
+    }
+
+    pub fn schema(&self) -> PolarsResult<Schema> {
+        self.schema.to_polars_schema()
+    }
+
+    /// List the data files of the snapshot. Manifests and data files of which the partition
+    /// values and column bounds show that no row matches `predicate` are skipped.
+    pub fn data_files(
+        &self,
+        predicate: Option<&dyn PhysicalIoExpr>,
+    ) -> PolarsResult<Vec<IcebergDataFile>> {
+        let Some(snapshot) = &self.snapshot else {
+            return Ok(vec![]);
+        };
+
+        let schema = Arc::new(self.schema()?);
+        let columns = self
+            .schema
+            .fields
+            .iter()
+            .zip(schema.iter_fields())
+            .map(|(field, polars_field)| SchemaColumn {
+                id: field.id,
+                field: polars_field,
+            })
+            .collect::<Vec<_>>();
+        let evaluator = predicate.and_then(|predicate| predicate.as_stats_evaluator());
+
+        let mut data_files = vec![];
+        for manifest in read_manifest_files(snapshot)? {
+            let partition_fields = self.metadata.partition_fields(manifest.partition_spec_id);
+            if let Some(evaluator) = evaluator {
+                if !should_read_manifest(&manifest, partition_fields, &columns, &schema, evaluator)?
+                {
+                    continue;
+                }
+            }
+            data_files.extend(read_data_files(
+                &manifest,
+                partition_fields,
+                &columns,
+                &schema,
+                evaluator,
+            )?);
+        }
+        Ok(data_files)
+    }
+
+    /// Map the columns of the table schema to the columns of a data file.
+    ///
+    /// Columns are matched by the Iceberg field ids stored in the Parquet schema, so that
+    /// renamed columns are found. Files without field ids are matched by name. Returns, for
+    /// every column of the table schema, the name of the column in the file or `None` if the
+    /// file doesn't contain the column.
+    pub fn column_mapping(&self, data_file: &Path) -> PolarsResult<Vec<Option<String>>> {
+        let file = polars_utils::open_file(data_file)?;
+        let mut reader = ParquetReader::new(file);
+        let metadata = reader.get_metadata()?;
+        let file_fields = metadata
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| field.get_field_info())
+            .collect::<Vec<_>>();
+        let has_ids = file_fields.iter().any(|info| info.id.is_some());
+
+        Ok(self
+            .schema
+            .fields
+            .iter()
+            .map(|field| {
+                file_fields
+                    .iter()
+                    .find(|info| {
+                        if has_ids {
+                            info.id == Some(field.id)
+                        } else {
+                            info.name == field.name
+                        }
+                    })
+                    .map(|info| info.name.clone())
+            })
+            .collect())
+    }
+}
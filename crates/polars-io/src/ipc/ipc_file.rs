@@ -42,6 +42,7 @@ use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::statistics::prune_record_batches;
 use crate::mmap::MmapBytesReader;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
@@ -84,6 +85,7 @@ pub struct IpcReader<R: MmapBytesReader> {
     pub(super) memory_map: Option<PathBuf>,
     metadata: Option<read::FileMetadata>,
     schema: Option<ArrowSchemaRef>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
 }
 
 fn check_mmap_err(err: PolarsError) -> PolarsResult<()> {
@@ -139,6 +141,29 @@ impl<R: MmapBytesReader> IpcReader<R> {
         self
     }
 
+    /// Skip the record batches for which the statistics in the footer show that `predicate`
+    /// holds for none of their rows. The predicate is not applied to the rows that are read.
+    ///
+    /// Nothing is skipped with a row index or `n_rows`, as those count the rows of all batches.
+    pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
+        self.predicate = predicate;
+        self
+    }
+
+    pub(super) fn prune_record_batches(
+        &self,
+        metadata: &mut read::FileMetadata,
+        predicate: Option<&dyn PhysicalIoExpr>,
+        verbose: bool,
+    ) -> PolarsResult<()> {
+        match predicate.or(self.predicate.as_deref()) {
+            Some(predicate) if self.row_index.is_none() && self.n_rows.is_none() => {
+                prune_record_batches(metadata, predicate, verbose)
+            },
+            _ => Ok(()),
+        }
+    }
+
     /// Set if the file is to be memory_mapped. Only works with uncompressed files.
     /// The file name must be passed to register the memory mapped file.
     pub fn memory_mapped(mut self, path_buf: Option<PathBuf>) -> Self {
@@ -163,7 +188,8 @@ impl<R: MmapBytesReader> IpcReader<R> {
             }
         }
         let rechunk = self.rechunk;
-        let metadata = read::read_file_metadata(&mut self.reader)?;
+        let mut metadata = read::read_file_metadata(&mut self.reader)?;
+        self.prune_record_batches(&mut metadata, predicate.as_deref(), verbose)?;
 
         // NOTE: For some code paths this already happened. See
         // https://github.com/pola-rs/polars/pull/14984#discussion_r1520125000
@@ -205,6 +231,7 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             memory_map: None,
             metadata: None,
             schema: None,
+            predicate: None,
         }
     }
 
@@ -221,7 +248,8 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             }
         }
         let rechunk = self.rechunk;
-        let metadata = read::read_file_metadata(&mut self.reader)?;
+        let mut metadata = read::read_file_metadata(&mut self.reader)?;
+        self.prune_record_batches(&mut metadata, None, polars_core::config::verbose())?;
         let schema = &metadata.schema;
 
         if let Some(columns) = &self.columns {
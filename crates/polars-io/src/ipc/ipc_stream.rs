@@ -40,6 +40,8 @@ use arrow::io::ipc::read::{StreamMetadata, StreamState};
 use arrow::io::ipc::write::WriteOptions;
 use arrow::io::ipc::{read, write};
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 use crate::shared::{finish_reader, ArrowReader, WriterFactory};
@@ -207,6 +209,7 @@ where
 pub struct IpcStreamWriter<W> {
     writer: W,
     compression: Option<IpcCompression>,
+    compression_level: Option<i32>,
     pl_flavor: bool,
 }
 
@@ -221,10 +224,80 @@ impl<W> IpcStreamWriter<W> {
         self
     }
 
+    /// Set the level of the compression. Defaults to the default level of the codec.
+    pub fn with_compression_level(mut self, compression_level: Option<i32>) -> Self {
+        self.compression_level = compression_level;
+        self
+    }
+
     pub fn with_pl_flavor(mut self, pl_flavor: bool) -> Self {
         self.pl_flavor = pl_flavor;
         self
     }
+
+    fn write_options(&self) -> PolarsResult<WriteOptions> {
+        let compression = self
+            .compression
+            .map(|c| c.with_level(self.compression_level))
+            .transpose()?;
+        Ok(WriteOptions {
+            compression,
+            ..Default::default()
+        })
+    }
+}
+
+impl<W: Write> IpcStreamWriter<W> {
+    /// Start a stream that the batches of `schema` are written to.
+    ///
+    /// Dictionaries that extend the dictionary of the previous batch, like those of categoricals
+    /// under a global string cache, are sent as deltas holding only the new categories.
+    pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedStreamWriter<W>> {
+        let options = self.write_options()?;
+        let mut writer =
+            write::StreamWriter::new(self.writer, options).with_dictionary_deltas(true);
+        writer.start(&schema.to_arrow(self.pl_flavor), None)?;
+        Ok(BatchedStreamWriter {
+            writer,
+            pl_flavor: self.pl_flavor,
+        })
+    }
+}
+
+pub struct BatchedStreamWriter<W: Write> {
+    writer: write::StreamWriter<W>,
+    pl_flavor: bool,
+}
+
+impl<W: Write> BatchedStreamWriter<W> {
+    /// Write a batch to the stream.
+    ///
+    /// # Panics
+    /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let iter = df.iter_chunks(self.pl_flavor, true);
+        for batch in iter {
+            self.writer.write(&batch, None)?
+        }
+        Ok(())
+    }
+
+    /// Writes the end-of-stream marker.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        self.writer.finish()
+    }
+}
+
+/// Options of the streaming engine's IPC stream sink.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IpcStreamWriterOptions {
+    /// Buffer compression
+    pub compression: Option<IpcCompression>,
+    /// The level of the compression, `None` for the default level of the codec.
+    pub compression_level: Option<i32>,
+    /// maintain the order the data was processed
+    pub maintain_order: bool,
 }
 
 impl<W> SerWriter<W> for IpcStreamWriter<W>
@@ -235,17 +308,14 @@ where
         IpcStreamWriter {
             writer,
             compression: None,
+            compression_level: None,
             pl_flavor: false,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let mut ipc_stream_writer = write::StreamWriter::new(
-            &mut self.writer,
-            WriteOptions {
-                compression: self.compression.map(|c| c.into()),
-            },
-        );
+        let options = self.write_options()?;
+        let mut ipc_stream_writer = write::StreamWriter::new(&mut self.writer, options);
 
         ipc_stream_writer.start(&df.schema().to_arrow(self.pl_flavor), None)?;
         let df = chunk_df_for_writing(df, 512 * 512)?;
@@ -20,8 +20,13 @@ impl<R: MmapBytesReader> IpcReader<R> {
                 let mmap = unsafe { memmap::Mmap::map(file).unwrap() };
                 let mmap_key = self.memory_map.take().unwrap();
                 let semaphore = MMapSemaphore::new(mmap_key, mmap);
-                let metadata =
+                let mut metadata =
                     read::read_file_metadata(&mut std::io::Cursor::new(semaphore.as_ref()))?;
+                self.prune_record_batches(
+                    &mut metadata,
+                    predicate.as_deref(),
+                    polars_core::config::verbose(),
+                )?;
 
                 if let Some(columns) = &self.columns {
                     let schema = &metadata.schema;
@@ -0,0 +1,178 @@
+//! Statistics of the record batches of an IPC file, which are stored in the custom metadata of
+//! the footer so that scans can skip record batches like they skip the row groups of Parquet
+//! files.
+//!
+//! The statistics are a [`DataFrame`] with a row per record batch and the columns
+//! `min:{name}`, `max:{name}` and `null_count:{name}` for the columns of the file that have
+//! statistics. It is stored as a hex-encoded IPC file.
+use std::io::Cursor;
+
+use arrow::datatypes::Metadata;
+use arrow::io::ipc::read::{self, FileMetadata};
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+
+use super::IpcWriter;
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+use crate::SerWriter;
+
+/// The key of the statistics in the custom metadata of the footer.
+const STATISTICS_KEY: &str = "polars:record_batch_statistics";
+
+/// Whether the min and max of a column of `dtype` are written.
+fn has_min_max(dtype: &DataType) -> bool {
+    use DataType::*;
+    dtype.is_integer()
+        || dtype.is_temporal()
+        || matches!(dtype, Float32 | Float64 | String | Binary | Boolean)
+}
+
+/// Collects the statistics of the record batches that are written to an IPC file.
+pub(super) struct StatisticsWriter {
+    batches: Vec<DataFrame>,
+}
+
+impl StatisticsWriter {
+    pub(super) fn new() -> Self {
+        Self { batches: vec![] }
+    }
+
+    /// Continue the statistics of the file that is appended to, if all its record batches have
+    /// statistics.
+    pub(super) fn try_from_metadata(metadata: &FileMetadata) -> PolarsResult<Option<Self>> {
+        if metadata.blocks.is_empty() {
+            return Ok(Some(Self::new()));
+        }
+        Ok(read_statistics(metadata)?.map(|df| Self { batches: vec![df] }))
+    }
+
+    /// Add the statistics of the record batch `df`.
+    pub(super) fn push(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let mut columns = vec![];
+        for s in df.get_columns() {
+            let dtype = s.dtype();
+            if !has_min_max(dtype) {
+                continue;
+            }
+            // NaN is larger than every other value, but `min` and `max` ignore it.
+            let (min, max) = if dtype.is_float() && s.is_nan()?.any() {
+                let null = Series::full_null("", 1, dtype);
+                (null.clone(), null)
+            } else {
+                (
+                    s.min_reduce()?.into_series(""),
+                    s.max_reduce()?.into_series(""),
+                )
+            };
+            let name = s.name();
+            columns.push(min.with_name(&format!("min:{name}")));
+            columns.push(max.with_name(&format!("max:{name}")));
+            columns.push(Series::new(
+                &format!("null_count:{name}"),
+                [s.null_count() as IdxSize],
+            ));
+        }
+        self.batches.push(DataFrame::new(columns)?);
+        Ok(())
+    }
+
+    /// The custom metadata of the footer that stores the statistics.
+    pub(super) fn finish(&mut self) -> PolarsResult<Metadata> {
+        let batches = std::mem::take(&mut self.batches);
+        if batches.first().map_or(true, |df| df.width() == 0) {
+            return Ok(Metadata::new());
+        }
+        let mut df = accumulate_dataframes_vertical(batches)?;
+        let mut buf = vec![];
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        Ok(Metadata::from([(STATISTICS_KEY.to_string(), to_hex(&buf))]))
+    }
+}
+
+/// Read the statistics of the record batches of a file, if all of them have statistics.
+fn read_statistics(metadata: &FileMetadata) -> PolarsResult<Option<DataFrame>> {
+    let Some(value) = metadata.custom_metadata.get(STATISTICS_KEY) else {
+        return Ok(None);
+    };
+    let bytes = from_hex(value)
+        .ok_or_else(|| polars_err!(ComputeError: "invalid record batch statistics in IPC file"))?;
+    let mut reader = Cursor::new(bytes);
+    let stats_metadata = read::read_file_metadata(&mut reader)?;
+    let fields = stats_metadata.schema.fields.clone();
+    let dfs = read::FileReader::new(reader, stats_metadata, None, None)
+        .map(|batch| DataFrame::try_from((batch?, fields.as_slice())))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let df = accumulate_dataframes_vertical(dfs)?;
+    Ok((df.height() == metadata.blocks.len()).then_some(df))
+}
+
+/// Remove the record batches from `metadata` for which the statistics show that `predicate`
+/// holds for none of their rows.
+pub(super) fn prune_record_batches(
+    metadata: &mut FileMetadata,
+    predicate: &dyn PhysicalIoExpr,
+    verbose: bool,
+) -> PolarsResult<()> {
+    let Some(evaluator) = predicate.as_stats_evaluator() else {
+        return Ok(());
+    };
+    let Some(stats) = read_statistics(metadata)? else {
+        return Ok(());
+    };
+    let schema = Arc::new(Schema::from(metadata.schema.as_ref()));
+
+    let n_batches = metadata.blocks.len();
+    let mut keep = Vec::with_capacity(n_batches);
+    for i in 0..n_batches {
+        let column_stats = schema
+            .iter_fields()
+            .map(|field| {
+                let get = |stat: &str| {
+                    let s = stats.column(&format!("{stat}:{}", field.name())).ok()?;
+                    Some(s.slice(i as i64, 1))
+                };
+                let (null_count, min, max) = (get("null_count"), get("min"), get("max"));
+                ColumnStats::new(field, null_count, min, max)
+            })
+            .collect();
+        let batch_stats = BatchStats::new(schema.clone(), column_stats, None);
+        let should_read = evaluator.should_read(&batch_stats);
+        keep.push(match should_read {
+            Ok(read) => read,
+            Err(PolarsError::ColumnNotFound(_)) => true,
+            Err(err) => return Err(err),
+        });
+    }
+
+    let mut keep = keep.into_iter();
+    metadata.blocks.retain(|_| keep.next().unwrap());
+    if verbose {
+        eprintln!(
+            "ipc scan: skipping {} of {} record batches by their statistics",
+            n_batches - metadata.blocks.len(),
+            n_batches
+        );
+    }
+    Ok(())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        out.push(DIGITS[(b >> 4) as usize] as char);
+        out.push(DIGITS[(b & 0xf) as usize] as char);
+    }
+    out
+}
+
+fn from_hex(s: &str) -> Option<Vec<u8>> {
+    let digit = |c: u8| (c as char).to_digit(16).map(|d| d as u8);
+    s.as_bytes()
+        .chunks(2)
+        .map(|pair| match pair {
+            [hi, lo] => Some((digit(*hi)? << 4) | digit(*lo)?),
+            _ => None,
+        })
+        .collect()
+}
@@ -1,4 +1,4 @@
-use std::io::Write;
+use std::io::{Read, Seek, SeekFrom, Write};
 use std::path::PathBuf;
 
 use arrow::io::ipc::write;
@@ -7,16 +7,26 @@ use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::statistics::StatisticsWriter;
 use crate::prelude::*;
 use crate::shared::{schema_to_arrow_checked, WriterFactory};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IpcWriterOptions {
     /// Data page compression
     pub compression: Option<IpcCompression>,
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// What to do if the file exists.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub write_mode: WriteMode,
+    /// Columns that are not compressed.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub uncompressed_columns: Vec<String>,
+    /// Write the statistics of the record batches in the footer.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub statistics: bool,
 }
 
 /// Write a DataFrame to Arrow's IPC format
@@ -41,10 +51,26 @@ pub struct IpcWriterOptions {
 pub struct IpcWriter<W> {
     pub(super) writer: W,
     pub(super) compression: Option<IpcCompression>,
+    pub(super) uncompressed_columns: Vec<String>,
+    pub(super) statistics: bool,
     /// Polars' flavor of arrow. This might be temporary.
     pub(super) pl_flavor: bool,
 }
 
+impl<W> IpcWriter<W> {
+    pub(super) fn write_options(&self, schema: &Schema) -> PolarsResult<WriteOptions> {
+        let uncompressed_columns = self
+            .uncompressed_columns
+            .iter()
+            .map(|name| schema.try_index_of(name))
+            .collect::<PolarsResult<_>>()?;
+        Ok(WriteOptions {
+            compression: self.compression.map(|c| c.into()),
+            uncompressed_columns,
+        })
+    }
+}
+
 impl<W: Write> IpcWriter<W> {
     /// Set the compression used. Defaults to None.
     pub fn with_compression(mut self, compression: Option<IpcCompression>) -> Self {
@@ -52,26 +78,67 @@ impl<W: Write> IpcWriter<W> {
         self
     }
 
+    /// Set the columns that are written without compression, e.g. because they are already
+    /// compressed. Defaults to none.
+    pub fn with_uncompressed_columns(mut self, uncompressed_columns: Vec<String>) -> Self {
+        self.uncompressed_columns = uncompressed_columns;
+        self
+    }
+
+    /// Write the min, max and null count of the columns of every record batch in the footer of
+    /// the file, which scans use to skip record batches. The async writer doesn't write them.
+    /// Defaults to `false`.
+    pub fn with_statistics(mut self, statistics: bool) -> Self {
+        self.statistics = statistics;
+        self
+    }
+
     pub fn with_pl_flavor(mut self, pl_flavor: bool) -> Self {
         self.pl_flavor = pl_flavor;
         self
     }
 
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
-        let schema = schema_to_arrow_checked(schema, self.pl_flavor, "ipc")?;
-        let mut writer = write::FileWriter::new(
-            self.writer,
-            Arc::new(schema),
-            None,
-            WriteOptions {
-                compression: self.compression.map(|c| c.into()),
-            },
-        );
+        let options = self.write_options(schema)?;
+        let arrow_schema = schema_to_arrow_checked(schema, self.pl_flavor, "ipc")?;
+        let mut writer = write::FileWriter::new(self.writer, Arc::new(arrow_schema), None, options);
         writer.start()?;
 
         Ok(BatchedWriter {
             writer,
             pl_flavor: self.pl_flavor,
+            statistics: self.statistics.then(StatisticsWriter::new),
+        })
+    }
+}
+
+impl<W: Read + Seek + Write> IpcWriter<W> {
+    /// Append batches to the IPC file that is in the writer.
+    ///
+    /// The existing file must have the same schema. Its footer is replaced by one that lists
+    /// both the existing and the new batches when the writer is finished.
+    ///
+    /// Statistics are only written if the existing batches have statistics as well.
+    pub fn batched_append(mut self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        let options = self.write_options(schema)?;
+        let arrow_schema = schema_to_arrow_checked(schema, self.pl_flavor, "ipc")?;
+        self.writer.seek(SeekFrom::Start(0))?;
+        let metadata = arrow::io::ipc::read::read_file_metadata(&mut self.writer)?;
+        polars_ensure!(
+            metadata.schema.fields == arrow_schema.fields,
+            SchemaMismatch: "cannot append to an IPC file with a different schema"
+        );
+        let statistics = if self.statistics {
+            StatisticsWriter::try_from_metadata(&metadata)?
+        } else {
+            None
+        };
+        let writer = write::FileWriter::try_from_file(self.writer, metadata, options)?;
+
+        Ok(BatchedWriter {
+            writer,
+            pl_flavor: self.pl_flavor,
+            statistics,
         })
     }
 }
@@ -84,34 +151,36 @@ where
         IpcWriter {
             writer,
             compression: None,
+            uncompressed_columns: vec![],
+            statistics: false,
             pl_flavor: true,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(&df.schema(), self.pl_flavor, "ipc")?;
-        let mut ipc_writer = write::FileWriter::try_new(
-            &mut self.writer,
-            Arc::new(schema),
-            None,
-            WriteOptions {
-                compression: self.compression.map(|c| c.into()),
-            },
-        )?;
+        let schema = df.schema();
+        let options = self.write_options(&schema)?;
+        let arrow_schema = schema_to_arrow_checked(&schema, self.pl_flavor, "ipc")?;
+        let mut writer = BatchedWriter {
+            writer: write::FileWriter::try_new(
+                &mut self.writer,
+                Arc::new(arrow_schema),
+                None,
+                options,
+            )?,
+            pl_flavor: self.pl_flavor,
+            statistics: self.statistics.then(StatisticsWriter::new),
+        };
         df.align_chunks();
-        let iter = df.iter_chunks(self.pl_flavor, true);
-
-        for batch in iter {
-            ipc_writer.write(&batch, None)?
-        }
-        ipc_writer.finish()?;
-        Ok(())
+        writer.write_batch(df)?;
+        writer.finish()
     }
 }
 
 pub struct BatchedWriter<W: Write> {
     writer: write::FileWriter<W>,
     pl_flavor: bool,
+    statistics: Option<StatisticsWriter>,
 }
 
 impl<W: Write> BatchedWriter<W> {
@@ -121,7 +190,12 @@ impl<W: Write> BatchedWriter<W> {
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         let iter = df.iter_chunks(self.pl_flavor, true);
+        let mut offset = 0;
         for batch in iter {
+            if let Some(statistics) = &mut self.statistics {
+                statistics.push(&df.slice(offset as i64, batch.len()))?;
+            }
+            offset += batch.len();
             self.writer.write(&batch, None)?
         }
         Ok(())
@@ -129,6 +203,9 @@ impl<W: Write> BatchedWriter<W> {
 
     /// Writes the footer of the IPC file.
     pub fn finish(&mut self) -> PolarsResult<()> {
+        if let Some(statistics) = &mut self.statistics {
+            self.writer.set_custom_metadata(statistics.finish()?);
+        }
         self.writer.finish()?;
         Ok(())
     }
@@ -148,12 +225,34 @@ pub enum IpcCompression {
 impl From<IpcCompression> for write::Compression {
     fn from(value: IpcCompression) -> Self {
         match value {
-            IpcCompression::LZ4 => write::Compression::LZ4,
-            IpcCompression::ZSTD => write::Compression::ZSTD,
+            IpcCompression::LZ4 => write::Compression::LZ4(None),
+            IpcCompression::ZSTD => write::Compression::ZSTD(None),
         }
     }
 }
 
+impl IpcCompression {
+    /// The codec at the given compression level, `None` uses the default level of the codec.
+    pub fn with_level(self, level: Option<i32>) -> PolarsResult<write::Compression> {
+        let Some(level) = level else {
+            return Ok(self.into());
+        };
+        let range = match self {
+            IpcCompression::LZ4 => 0..=12,
+            IpcCompression::ZSTD => 1..=22,
+        };
+        polars_ensure!(
+            range.contains(&level),
+            ComputeError: "valid {:?} compression range {}..={} exceeded: {}",
+            self, range.start(), range.end(), level
+        );
+        Ok(match self {
+            IpcCompression::LZ4 => write::Compression::LZ4(Some(level as u32)),
+            IpcCompression::ZSTD => write::Compression::ZSTD(Some(level)),
+        })
+    }
+}
+
 pub struct IpcWriterOption {
     compression: Option<IpcCompression>,
     extension: PathBuf,
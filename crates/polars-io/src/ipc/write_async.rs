@@ -1,5 +1,4 @@
 use arrow::io::ipc::write::file_async::FileSink;
-use arrow::io::ipc::write::WriteOptions;
 use futures::{AsyncWrite, SinkExt};
 use polars_core::prelude::*;
 
@@ -10,19 +9,15 @@ impl<W: AsyncWrite + Unpin + Send> IpcWriter<W> {
         IpcWriter {
             writer,
             compression: None,
+            uncompressed_columns: vec![],
+            statistics: false,
             pl_flavor: false,
         }
     }
 
     pub fn batched_async(self, schema: &Schema) -> PolarsResult<BatchedWriterAsync<W>> {
-        let writer = FileSink::new(
-            self.writer,
-            schema.to_arrow(false),
-            None,
-            WriteOptions {
-                compression: self.compression.map(|c| c.into()),
-            },
-        );
+        let options = self.write_options(schema)?;
+        let writer = FileSink::new(self.writer, schema.to_arrow(false), None, options);
 
         Ok(BatchedWriterAsync { writer })
     }
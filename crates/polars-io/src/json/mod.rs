@@ -69,6 +69,7 @@ use std::num::NonZeroUsize;
 use std::ops::Deref;
 
 use arrow::legacy::conversion::chunk_to_struct;
+use arrow::record_batch::RecordBatch;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
 use polars_json::json::write::FallibleStreamingIterator;
@@ -76,6 +77,7 @@ use polars_json::json::write::FallibleStreamingIterator;
 use serde::{Deserialize, Serialize};
 use simd_json::BorrowedValue;
 
+use crate::compression::OutputCompression;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::*;
 
@@ -84,6 +86,12 @@ use crate::prelude::*;
 pub struct JsonWriterOptions {
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// Compress the whole file.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub compression: Option<OutputCompression>,
+    /// What to do if the file exists.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub write_mode: WriteMode,
 }
 
 /// The format to use to write the DataFrame to JSON: `Json` (a JSON array) or `JsonLines` (each row output on a
@@ -105,18 +113,53 @@ pub enum JsonFormat {
     JsonLines,
 }
 
+/// The layout of the JSON written by [`JsonWriter`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JsonOrient {
+    /// Every row is an object of column names to values: `[{"a":1,"b":2},{"a":3,"b":4}]`.
+    #[default]
+    Records,
+    /// An object of column names to arrays of values: `{"a":[1,3],"b":[2,4]}`.
+    ///
+    /// Not supported for [`JsonFormat::JsonLines`].
+    Columns,
+    /// Every row is an array of values: `[[1,2],[3,4]]`.
+    Values,
+}
+
+/// How [`JsonWriter`] serializes temporal values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JsonTemporalFormat {
+    /// ISO 8601 strings, e.g. `"2024-01-31T12:00:00"`.
+    #[default]
+    Iso,
+    /// The physical integer: days since the epoch for dates, the number of time units since the
+    /// epoch for datetimes, the number of time units for durations and nanoseconds since
+    /// midnight for times.
+    Epoch,
+}
+
 /// Writes a DataFrame to JSON.
 ///
 /// Under the hood, this uses [`arrow2::io::json`](https://docs.rs/arrow2/latest/arrow2/io/json/write/fn.write.html).
 /// `arrow2` generally serializes types that are not JSON primitives, such as Date and DateTime, as their
 /// `Display`-formatted versions. For instance, a (naive) DateTime column is formatted as the String `"yyyy-mm-dd
-/// HH:MM:SS"`. To control how non-primitive columns are serialized, convert them to String or another primitive type
-/// before serializing.
+/// HH:MM:SS"`. Top-level temporal columns can be written as integers with [`JsonTemporalFormat::Epoch`]. To control
+/// how other non-primitive columns are serialized, convert them to String or another primitive type before
+/// serializing.
+///
+/// The output is serialized and written in batches of rows, so that the JSON of the whole DataFrame is never held in
+/// memory.
 #[must_use]
 pub struct JsonWriter<W: Write> {
     /// File or Stream handler
     buffer: W,
     json_format: JsonFormat,
+    orient: JsonOrient,
+    temporal_format: JsonTemporalFormat,
+    batch_size: NonZeroUsize,
 }
 
 impl<W: Write> JsonWriter<W> {
@@ -124,6 +167,45 @@ impl<W: Write> JsonWriter<W> {
         self.json_format = format;
         self
     }
+
+    /// Set the layout of the output, defaults to [`JsonOrient::Records`].
+    pub fn with_orient(mut self, orient: JsonOrient) -> Self {
+        self.orient = orient;
+        self
+    }
+
+    /// Set how temporal columns are serialized, defaults to [`JsonTemporalFormat::Iso`].
+    pub fn with_temporal_format(mut self, temporal_format: JsonTemporalFormat) -> Self {
+        self.temporal_format = temporal_format;
+        self
+    }
+
+    /// Set the number of rows that are serialized before they are written.
+    pub fn with_batch_size(mut self, batch_size: NonZeroUsize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+}
+
+fn json_fields(df: &DataFrame) -> PolarsResult<Vec<ArrowField>> {
+    df.iter()
+        .map(|s| {
+            #[cfg(feature = "object")]
+            polars_ensure!(!matches!(s.dtype(), DataType::Object(_, _)), ComputeError: "cannot write 'Object' datatype to json");
+            Ok(s.field().to_arrow(true))
+        })
+        .collect()
+}
+
+/// Split `df` in record batches of at most `batch_size` rows.
+fn json_batches(df: &DataFrame, batch_size: usize) -> impl Iterator<Item = RecordBatch> + '_ {
+    (0..df.height())
+        .step_by(batch_size)
+        .flat_map(move |offset| {
+            df.slice(offset as i64, batch_size)
+                .iter_chunks(true, false)
+                .collect::<Vec<_>>()
+        })
 }
 
 impl<W> SerWriter<W> for JsonWriter<W>
@@ -136,33 +218,113 @@ where
         JsonWriter {
             buffer,
             json_format: JsonFormat::JsonLines,
+            orient: JsonOrient::Records,
+            temporal_format: JsonTemporalFormat::Iso,
+            batch_size: NonZeroUsize::new(4096).unwrap(),
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
         df.align_chunks();
-        let fields = df
-            .iter()
-            .map(|s| {
-                #[cfg(feature = "object")]
-                polars_ensure!(!matches!(s.dtype(), DataType::Object(_, _)), ComputeError: "cannot write 'Object' datatype to json");
-                Ok(s.field().to_arrow(true))
-            })
-            .collect::<PolarsResult<Vec<_>>>()?;
-        let batches = df
-            .iter_chunks(true, false)
-            .map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
-
-        match self.json_format {
-            JsonFormat::JsonLines => {
+        let epoch_df;
+        let df = match self.temporal_format {
+            JsonTemporalFormat::Iso => &*df,
+            JsonTemporalFormat::Epoch => {
+                let columns = df
+                    .get_columns()
+                    .iter()
+                    .map(|s| {
+                        if s.dtype().is_temporal() {
+                            s.to_physical_repr().into_owned()
+                        } else {
+                            s.clone()
+                        }
+                    })
+                    .collect();
+                epoch_df = unsafe { DataFrame::new_no_checks(columns) };
+                &epoch_df
+            },
+        };
+        let fields = json_fields(df)?;
+        let batch_size = self.batch_size.get();
+        let buffer = &mut self.buffer;
+
+        match (&self.json_format, self.orient) {
+            (JsonFormat::JsonLines, JsonOrient::Records) => {
+                let batches = json_batches(df, batch_size)
+                    .map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
                 let serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
-                let writer =
-                    polars_json::ndjson::write::FileWriter::new(&mut self.buffer, serializer);
+                let writer = polars_json::ndjson::write::FileWriter::new(buffer, serializer);
                 writer.collect::<PolarsResult<()>>()?;
             },
-            JsonFormat::Json => {
+            (JsonFormat::JsonLines, JsonOrient::Values) => {
+                let mut block = vec![];
+                for chunk in json_batches(df, batch_size) {
+                    block.clear();
+                    let arrays = chunk
+                        .arrays()
+                        .iter()
+                        .map(|a| a.as_ref())
+                        .collect::<Vec<_>>();
+                    polars_json::json::write::serialize_rows(&arrays, b'\n', &mut block);
+                    block.push(b'\n');
+                    buffer.write_all(&block)?;
+                }
+            },
+            (JsonFormat::JsonLines, JsonOrient::Columns) => {
+                polars_bail!(InvalidOperation: "the 'columns' orientation is not supported for JSON lines")
+            },
+            (JsonFormat::Json, JsonOrient::Records) => {
+                let batches = json_batches(df, batch_size)
+                    .map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
                 let serializer = polars_json::json::write::Serializer::new(batches, vec![]);
-                polars_json::json::write::write(&mut self.buffer, serializer)?;
+                polars_json::json::write::write(buffer, serializer)?;
+            },
+            (JsonFormat::Json, JsonOrient::Values) => {
+                let mut block = vec![];
+                buffer.write_all(b"[")?;
+                for (i, chunk) in json_batches(df, batch_size).enumerate() {
+                    block.clear();
+                    if i != 0 {
+                        block.push(b',');
+                    }
+                    let arrays = chunk
+                        .arrays()
+                        .iter()
+                        .map(|a| a.as_ref())
+                        .collect::<Vec<_>>();
+                    polars_json::json::write::serialize_rows(&arrays, b',', &mut block);
+                    buffer.write_all(&block)?;
+                }
+                buffer.write_all(b"]")?;
+            },
+            (JsonFormat::Json, JsonOrient::Columns) => {
+                let mut block = vec![];
+                buffer.write_all(b"{")?;
+                for (i, s) in df.get_columns().iter().enumerate() {
+                    if i != 0 {
+                        buffer.write_all(b",")?;
+                    }
+                    polars_json::json::write::write_str(buffer, s.name())?;
+                    buffer.write_all(b":[")?;
+                    let mut is_first = true;
+                    for chunk_idx in 0..s.n_chunks() {
+                        let array = s.to_arrow(chunk_idx, true);
+                        for offset in (0..array.len()).step_by(batch_size) {
+                            block.clear();
+                            if !is_first {
+                                block.push(b',');
+                            }
+                            is_first = false;
+                            let len = batch_size.min(array.len() - offset);
+                            let array = array.sliced(offset, len);
+                            polars_json::json::write::serialize_array(array.as_ref(), &mut block);
+                            buffer.write_all(&block)?;
+                        }
+                    }
+                    buffer.write_all(b"]")?;
+                }
+                buffer.write_all(b"}")?;
             },
         }
 
@@ -186,14 +348,7 @@ where
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
-        let fields = df
-            .iter()
-            .map(|s| {
-                #[cfg(feature = "object")]
-                polars_ensure!(!matches!(s.dtype(), DataType::Object(_, _)), ComputeError: "cannot write 'Object' datatype to json");
-                Ok(s.field().to_arrow(true))
-            })
-            .collect::<PolarsResult<Vec<_>>>()?;
+        let fields = json_fields(df)?;
         let chunks = df.iter_chunks(true, false);
         let batches =
             chunks.map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
@@ -203,6 +358,11 @@ where
         }
         Ok(())
     }
+
+    /// Get a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
 }
 
 /// Reads JSON in one of the formats in [`JsonFormat`] into a DataFrame.
@@ -0,0 +1,11 @@
+//! # Read from Apache Kafka
+//!
+//! [Apache Kafka] topics are read over a bounded range of offsets, so that a backfill over a
+//! topic can be run as a regular query. Every partition of the topic is read from the start
+//! offset up to the end offset, or up to the last offset that was written when the read
+//! started. The message payloads are decoded as JSON or Avro.
+//!
+//! [Apache Kafka]: https://kafka.apache.org/
+mod read;
+
+pub use read::*;
@@ -0,0 +1,433 @@
+use std::collections::VecDeque;
+use std::io::Cursor;
+
+use arrow::io::avro::avro_schema::file::Block;
+use arrow::io::avro::avro_schema::schema::{Field as AvroField, Schema as AvroSchema};
+use arrow::io::avro::read::{deserialize, infer_schema};
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::to_compute_err;
+use rskafka::client::partition::{OffsetAt, PartitionClient, UnknownTopicHandling};
+use rskafka::client::ClientBuilder;
+use rskafka::record::RecordAndOffset;
+
+use crate::ndjson::core::JsonLineReader;
+use crate::pl_async::get_runtime;
+use crate::SerReader;
+
+/// Maximum number of bytes that is fetched from a partition at once.
+const DEFAULT_BATCH_SIZE: usize = 4 * 1024 * 1024;
+/// Maximum time the broker waits to fill a fetch, in milliseconds.
+const MAX_WAIT_MS: i32 = 500;
+
+/// Name of the column with the partition of every message, see [`KafkaReader::with_metadata`].
+pub const KAFKA_PARTITION_COLUMN: &str = "_partition";
+/// Name of the column with the offset of every message, see [`KafkaReader::with_metadata`].
+pub const KAFKA_OFFSET_COLUMN: &str = "_offset";
+
+/// How the payloads of Kafka messages are decoded.
+#[derive(Clone, Debug, PartialEq)]
+pub enum KafkaFormat {
+    /// Every payload is a JSON object, decoded into the given schema.
+    Json(SchemaRef),
+    /// Every payload is a single Avro datum. The root of the Avro schema, given in its JSON
+    /// form, must be a record.
+    Avro {
+        schema: String,
+        /// Whether the payloads start with the 5-byte header of the Confluent schema registry
+        /// wire format: a zero byte and the id of the schema.
+        confluent_wire_format: bool,
+    },
+}
+
+enum Decoder {
+    Json(SchemaRef),
+    Avro {
+        fields: Vec<ArrowField>,
+        avro_fields: Vec<AvroField>,
+        confluent_wire_format: bool,
+    },
+}
+
+impl Decoder {
+    fn new(format: &KafkaFormat) -> PolarsResult<Self> {
+        match format {
+            KafkaFormat::Json(schema) => Ok(Self::Json(schema.clone())),
+            KafkaFormat::Avro {
+                schema,
+                confluent_wire_format,
+            } => {
+                let schema: AvroSchema = serde_json::from_str(schema).map_err(to_compute_err)?;
+                let AvroSchema::Record(record) = schema else {
+                    polars_bail!(ComputeError: "the root of the Avro schema of Kafka messages must be a record")
+                };
+                Ok(Self::Avro {
+                    fields: infer_schema(&record)?.fields,
+                    avro_fields: record.fields,
+                    confluent_wire_format: *confluent_wire_format,
+                })
+            },
+        }
+    }
+
+    fn schema(&self) -> Schema {
+        match self {
+            Self::Json(schema) => schema.as_ref().clone(),
+            Self::Avro { fields, .. } => fields.iter().map(Field::from).collect(),
+        }
+    }
+
+    fn decode(&self, payloads: &[&[u8]]) -> PolarsResult<DataFrame> {
+        if payloads.is_empty() {
+            return Ok(DataFrame::empty_with_schema(&self.schema()));
+        }
+        match self {
+            Self::Json(schema) => {
+                let mut lines = Vec::with_capacity(payloads.iter().map(|p| p.len() + 1).sum());
+                for payload in payloads {
+                    // Raw line breaks can only be whitespace between the tokens of a JSON
+                    // value, so every payload can be put on a single line.
+                    lines.extend(payload.iter().map(|&b| match b {
+                        b'\n' | b'\r' => b' ',
+                        b => b,
+                    }));
+                    lines.push(b'\n');
+                }
+                JsonLineReader::new(Cursor::new(lines))
+                    .with_schema(schema.clone())
+                    .finish()
+            },
+            Self::Avro {
+                fields,
+                avro_fields,
+                confluent_wire_format,
+            } => {
+                // Consecutive datums make up the data of an Avro block.
+                let mut data = Vec::with_capacity(payloads.iter().map(|p| p.len()).sum());
+                for payload in payloads {
+                    let datum = match (*confluent_wire_format, payload) {
+                        (true, [0, _, _, _, _, datum @ ..]) => datum,
+                        (true, _) => polars_bail!(
+                            ComputeError: "Kafka message does not start with a Confluent wire format header"
+                        ),
+                        (false, datum) => datum,
+                    };
+                    data.extend_from_slice(datum);
+                }
+                let block = Block::new(payloads.len(), data);
+                let projection = vec![true; fields.len()];
+                let batch = deserialize(&block, fields, avro_fields, &projection)?;
+                DataFrame::try_from((batch, fields.as_slice()))
+            },
+        }
+    }
+}
+
+/// Read a bounded range of offsets of a Kafka topic into a [`DataFrame`].
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::kafka::{KafkaFormat, KafkaReader};
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let schema = Schema::from_iter([
+///         Field::new("user", DataType::String),
+///         Field::new("amount", DataType::Float64),
+///     ]);
+///     KafkaReader::new(
+///         vec!["localhost:9092".to_string()],
+///         "payments",
+///         KafkaFormat::Json(Arc::new(schema)),
+///     )
+///     .with_offsets(1_000, Some(2_000))
+///     .with_metadata(true)
+///     .finish()
+/// }
+/// ```
+#[must_use]
+pub struct KafkaReader {
+    brokers: Vec<String>,
+    topic: String,
+    format: KafkaFormat,
+    partitions: Option<Vec<i32>>,
+    start_offset: i64,
+    end_offset: Option<i64>,
+    include_metadata: bool,
+    batch_size: usize,
+    n_rows: Option<usize>,
+    rechunk: bool,
+}
+
+impl KafkaReader {
+    pub fn new(brokers: Vec<String>, topic: impl Into<String>, format: KafkaFormat) -> Self {
+        Self {
+            brokers,
+            topic: topic.into(),
+            format,
+            partitions: None,
+            start_offset: 0,
+            end_offset: None,
+            include_metadata: false,
+            batch_size: DEFAULT_BATCH_SIZE,
+            n_rows: None,
+            rechunk: true,
+        }
+    }
+
+    /// Only read these partitions of the topic. Defaults to all partitions.
+    pub fn with_partitions(mut self, partitions: Option<Vec<i32>>) -> Self {
+        self.partitions = partitions;
+        self
+    }
+
+    /// Read the messages from `start` up to, but not including, `end` of every partition.
+    ///
+    /// Offsets that were already removed by the retention of the topic are skipped. If `end`
+    /// is `None`, the partitions are read up to the last message that was written when the
+    /// read started.
+    pub fn with_offsets(mut self, start: i64, end: Option<i64>) -> Self {
+        self.start_offset = start;
+        self.end_offset = end;
+        self
+    }
+
+    /// Add the [`KAFKA_PARTITION_COLUMN`] and [`KAFKA_OFFSET_COLUMN`] columns.
+    pub fn with_metadata(mut self, include_metadata: bool) -> Self {
+        self.include_metadata = include_metadata;
+        self
+    }
+
+    /// The maximum number of bytes that is fetched and decoded at once.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.clamp(2, i32::MAX as usize);
+        self
+    }
+
+    /// Stop reading after `n_rows`.
+    pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
+        self.n_rows = n_rows;
+        self
+    }
+
+    pub fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    /// Get the schema of the decoded messages, without connecting to the brokers.
+    pub fn schema(&self) -> PolarsResult<Schema> {
+        let mut schema = Decoder::new(&self.format)?.schema();
+        if self.include_metadata {
+            schema.with_column(KAFKA_PARTITION_COLUMN.into(), DataType::Int32);
+            schema.with_column(KAFKA_OFFSET_COLUMN.into(), DataType::Int64);
+        }
+        Ok(schema)
+    }
+
+    /// Resolve the offsets of every partition and stream the messages in batches.
+    pub fn batched(self) -> PolarsResult<BatchedKafkaReader> {
+        polars_ensure!(
+            self.start_offset >= 0 && self.end_offset.map_or(true, |end| end >= self.start_offset),
+            ComputeError: "invalid Kafka offset range {}..{:?}", self.start_offset, self.end_offset
+        );
+        let decoder = Decoder::new(&self.format)?;
+        let schema = Arc::new(self.schema()?);
+
+        let partitions = get_runtime().block_on_potential_spawn(async {
+            let client = ClientBuilder::new(self.brokers.clone())
+                .build()
+                .await
+                .map_err(to_compute_err)?;
+            let partitions = match &self.partitions {
+                Some(partitions) => partitions.clone(),
+                None => {
+                    let topics = client.list_topics().await.map_err(to_compute_err)?;
+                    let Some(topic) = topics.into_iter().find(|t| t.name == self.topic) else {
+                        polars_bail!(ComputeError: "Kafka topic '{}' does not exist", self.topic)
+                    };
+                    topic.partitions.into_iter().collect()
+                },
+            };
+
+            let mut cursors = VecDeque::with_capacity(partitions.len());
+            for partition in partitions {
+                let client = client
+                    .partition_client(self.topic.clone(), partition, UnknownTopicHandling::Error)
+                    .await
+                    .map_err(to_compute_err)?;
+                let earliest = client
+                    .get_offset(OffsetAt::Earliest)
+                    .await
+                    .map_err(to_compute_err)?;
+                let latest = client
+                    .get_offset(OffsetAt::Latest)
+                    .await
+                    .map_err(to_compute_err)?;
+                cursors.push_back(PartitionCursor {
+                    client,
+                    partition,
+                    offset: self.start_offset.max(earliest),
+                    end: self.end_offset.map_or(latest, |end| end.min(latest)),
+                });
+            }
+            PolarsResult::Ok(cursors)
+        })?;
+
+        Ok(BatchedKafkaReader {
+            partitions,
+            decoder,
+            schema,
+            include_metadata: self.include_metadata,
+            batch_size: self.batch_size,
+            remaining: self.n_rows,
+        })
+    }
+
+    pub fn finish(self) -> PolarsResult<DataFrame> {
+        let rechunk = self.rechunk;
+        let mut reader = self.batched()?;
+
+        let mut dfs = vec![];
+        while let Some(df) = reader.next_batch()? {
+            dfs.push(df);
+        }
+
+        if dfs.is_empty() {
+            return Ok(DataFrame::empty_with_schema(&reader.schema));
+        }
+        let mut df = accumulate_dataframes_vertical_unchecked(dfs);
+        if rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
+
+/// The next offset to read of a partition.
+struct PartitionCursor {
+    client: PartitionClient,
+    partition: i32,
+    offset: i64,
+    end: i64,
+}
+
+/// Streams the messages of a topic partition by partition, see [`KafkaReader::batched`].
+pub struct BatchedKafkaReader {
+    partitions: VecDeque<PartitionCursor>,
+    decoder: Decoder,
+    schema: SchemaRef,
+    include_metadata: bool,
+    batch_size: usize,
+    remaining: Option<usize>,
+}
+
+impl BatchedKafkaReader {
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Fetch and decode the next batch of messages, or `None` if all partitions are read up to
+    /// their end offset.
+    pub fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        loop {
+            if self.remaining == Some(0) {
+                return Ok(None);
+            }
+            let Some(cursor) = self.partitions.front_mut() else {
+                return Ok(None);
+            };
+            if cursor.offset >= cursor.end {
+                self.partitions.pop_front();
+                continue;
+            }
+
+            let (records, _high_watermark) = get_runtime()
+                .block_on_potential_spawn(cursor.client.fetch_records(
+                    cursor.offset,
+                    1..self.batch_size as i32,
+                    MAX_WAIT_MS,
+                ))
+                .map_err(to_compute_err)?;
+            // Fetches return whole record batches, which can start before the requested offset
+            // and extend beyond the end offset.
+            let records = records
+                .into_iter()
+                .filter(|r| (cursor.offset..cursor.end).contains(&r.offset))
+                .collect::<Vec<_>>();
+            let Some(last) = records.last() else {
+                // Only control records, e.g. transaction markers, are left before the end.
+                self.partitions.pop_front();
+                continue;
+            };
+            cursor.offset = last.offset + 1;
+            let partition = cursor.partition;
+
+            let mut df = self.decode(partition, &records)?;
+            if let Some(remaining) = self.remaining.as_mut() {
+                df = df.head(Some(*remaining));
+                *remaining -= df.height();
+            }
+            if df.height() > 0 {
+                return Ok(Some(df));
+            }
+        }
+    }
+
+    fn decode(&self, partition: i32, records: &[RecordAndOffset]) -> PolarsResult<DataFrame> {
+        // Tombstones, messages without a payload, don't have a row.
+        let (payloads, offsets): (Vec<&[u8]>, Vec<i64>) = records
+            .iter()
+            .filter_map(|r| Some((r.record.value.as_deref()?, r.offset)))
+            .unzip();
+        let mut df = self.decoder.decode(&payloads)?;
+        if self.include_metadata {
+            let partitions = Int32Chunked::full(KAFKA_PARTITION_COLUMN, partition, offsets.len());
+            df.with_column(partitions.into_series())?;
+            df.with_column(Series::new(KAFKA_OFFSET_COLUMN, offsets))?;
+        }
+        Ok(df)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_json() {
+        let schema = Schema::from_iter([
+            Field::new("id", DataType::Int64),
+            Field::new("name", DataType::String),
+        ]);
+        let decoder = Decoder::new(&KafkaFormat::Json(Arc::new(schema))).unwrap();
+        let payloads: [&[u8]; 2] = [b"{\"id\": 1, \"name\": \"a\"}", b"{\n  \"id\": 2\n}"];
+        let df = decoder.decode(&payloads).unwrap();
+        let expected = df!("id" => [1i64, 2], "name" => [Some("a"), None]).unwrap();
+        assert!(df.equals_missing(&expected));
+    }
+
+    #[test]
+    fn test_decode_avro() {
+        let schema = r#"{
+            "type": "record",
+            "name": "user",
+            "fields": [{"name": "id", "type": "long"}, {"name": "name", "type": "string"}]
+        }"#;
+        let decoder = Decoder::new(&KafkaFormat::Avro {
+            schema: schema.to_string(),
+            confluent_wire_format: true,
+        })
+        .unwrap();
+        // Zigzag encoded ids and length-prefixed names, after the wire format header.
+        let payloads: [&[u8]; 2] = [b"\0\0\0\0\x07\x02\x02a", b"\0\0\0\0\x07\x04\x04bc"];
+        let df = decoder.decode(&payloads).unwrap();
+        let expected = df!("id" => [1i64, 2], "name" => ["a", "bc"]).unwrap();
+        assert!(df.equals(&expected));
+
+        assert!(decoder.decode(&[&b"\x02\x02a"[..]]).is_err());
+    }
+}
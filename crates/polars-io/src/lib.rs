@@ -4,19 +4,37 @@
 
 #[cfg(feature = "avro")]
 pub mod avro;
+#[cfg(feature = "clipboard")]
+pub mod clipboard;
 pub mod cloud;
 #[cfg(any(feature = "csv", feature = "json"))]
+pub mod compression;
+#[cfg(any(feature = "csv", feature = "json"))]
 pub mod csv;
+#[cfg(feature = "database")]
+pub mod database;
+#[cfg(feature = "delta")]
+pub mod delta;
+#[cfg(feature = "excel")]
+pub mod excel;
 #[cfg(feature = "file_cache")]
 pub mod file_cache;
+#[cfg(feature = "flight")]
+pub mod flight;
+#[cfg(feature = "iceberg")]
+pub mod iceberg;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub mod ipc;
 #[cfg(feature = "json")]
 pub mod json;
+#[cfg(feature = "kafka")]
+pub mod kafka;
 pub mod mmap;
 #[cfg(feature = "json")]
 pub mod ndjson;
 mod options;
+#[cfg(feature = "orc")]
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
 #[cfg(feature = "partition")]
@@ -27,6 +45,7 @@ pub mod predicates;
 pub mod prelude;
 mod shared;
 pub mod utils;
+pub mod write_mode;
 
 #[cfg(feature = "cloud")]
 pub use cloud::glob as async_glob;
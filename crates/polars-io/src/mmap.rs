@@ -50,13 +50,18 @@ impl Drop for MMapSemaphore {
     }
 }
 
-/// Open a file to get write access. This will check if the file is currently registered as memory mapped.
-pub fn try_create_file(path: &Path) -> PolarsResult<File> {
+/// Raise an error if the file is currently registered as memory mapped.
+pub fn ensure_not_memory_mapped(path: &Path) -> PolarsResult<()> {
     let guard = MEMORY_MAPPED_FILES.lock().unwrap();
     if guard.contains_key(path) {
         polars_bail!(ComputeError: "cannot write to file: already memory mapped")
     }
-    drop(guard);
+    Ok(())
+}
+
+/// Open a file to get write access. This will check if the file is currently registered as memory mapped.
+pub fn try_create_file(path: &Path) -> PolarsResult<File> {
+    ensure_not_memory_mapped(path)?;
     create_file(path)
 }
 
@@ -26,7 +26,11 @@ pub(crate) struct Buffer<'a> {
     buf: AnyValueBuffer<'a>,
 }
 
-impl Buffer<'_> {
+impl<'a> Buffer<'a> {
+    pub(crate) fn name(&self) -> &'a str {
+        self.name
+    }
+
     pub fn into_series(self) -> Series {
         let mut s = self.buf.into_series();
         s.rename(self.name);
@@ -112,6 +116,22 @@ impl Buffer<'_> {
                 buf.append_option(v);
                 Ok(())
             },
+            #[cfg(feature = "dtype-decimal")]
+            All(DataType::Decimal(precision, Some(scale)), buf) => {
+                match deserialize_decimal(value, *precision, *scale) {
+                    Some(v) => buf.push(AnyValue::Decimal(v, *scale)),
+                    None if self.ignore_errors
+                        || matches!(value, Value::Static(StaticNode::Null)) =>
+                    {
+                        buf.push(AnyValue::Null)
+                    },
+                    None => polars_bail!(
+                        ComputeError: "cannot parse '{}' as dtype {}",
+                        value, DataType::Decimal(*precision, Some(*scale))
+                    ),
+                }
+                Ok(())
+            },
             All(dtype, buf) => {
                 let av = deserialize_all(value, dtype, self.ignore_errors)?;
                 buf.push(av);
@@ -160,6 +180,35 @@ fn deserialize_number<T: NativeType + NumCast>(value: &Value) -> Option<T> {
     }
 }
 
+/// JSON numbers are already parsed as floats, they are formatted to their shortest round-trip
+/// representation first, so that `0.1` is read as exactly `0.1`.
+#[cfg(feature = "dtype-decimal")]
+fn deserialize_decimal(value: &Value, precision: Option<usize>, scale: usize) -> Option<i128> {
+    let parse = |bytes: &[u8]| {
+        arrow::compute::decimal::deserialize_decimal(bytes, precision.map(|p| p as u8), scale as u8)
+    };
+    match value {
+        Value::String(s) => parse(s.as_bytes()),
+        Value::Static(StaticNode::I64(v)) => parse(v.to_string().as_bytes()),
+        Value::Static(StaticNode::U64(v)) => parse(v.to_string().as_bytes()),
+        Value::Static(StaticNode::F64(v)) => parse(v.to_string().as_bytes()),
+        _ => None,
+    }
+}
+
+/// Number of fractional digits of a JSON number or numeric string.
+#[cfg(feature = "dtype-decimal")]
+pub(crate) fn infer_decimal_scale(value: &Value) -> usize {
+    let scale = match value {
+        Value::String(s) => arrow::compute::decimal::infer_scale(s.as_bytes()),
+        Value::Static(StaticNode::F64(v)) => {
+            arrow::compute::decimal::infer_scale(v.to_string().as_bytes())
+        },
+        _ => 0,
+    };
+    scale as usize
+}
+
 #[cfg(feature = "dtype-datetime")]
 fn deserialize_datetime<T>(value: &Value) -> Option<T::Native>
 where
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::fs::File;
 use std::io::Cursor;
 use std::num::NonZeroUsize;
@@ -9,6 +10,8 @@ use polars_core::prelude::*;
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_core::POOL;
 use rayon::prelude::*;
+use serde::de::{DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde_json::value::RawValue;
 
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::ndjson::buffer::*;
@@ -235,6 +238,8 @@ impl<'a> CoreJsonReader<'a> {
         if let Some(overwriting_schema) = schema_overwrite {
             let schema = Arc::make_mut(&mut schema);
             overwrite_schema(schema, overwriting_schema)?;
+            #[cfg(feature = "dtype-decimal")]
+            infer_decimal_scales(&reader_bytes, schema, infer_schema_len)?;
         }
 
         Ok(CoreJsonReader {
@@ -301,34 +306,90 @@ impl<'a> CoreJsonReader<'a> {
         };
         let file_chunks = get_file_chunks_json(bytes, n_threads);
 
+        // Only the keys of the projected columns are parsed, the values of the other keys are
+        // skipped. At least one column is parsed, as it determines the number of rows.
+        let read_schema = match &self.projection {
+            Some(projection) => {
+                let schema = self
+                    .schema
+                    .iter()
+                    .filter(|(name, _)| projection.iter().any(|p| p == name.as_str()))
+                    .map(|(name, dtype)| Field::new(name, dtype.clone()))
+                    .collect::<Schema>();
+                if schema.is_empty() {
+                    Cow::Borrowed(self.schema.as_ref())
+                } else {
+                    Cow::Owned(schema)
+                }
+            },
+            None => Cow::Borrowed(self.schema.as_ref()),
+        };
+        // If the columns of the predicate are known, they are parsed first and the other columns
+        // are only parsed for the lines that match the predicate.
+        let predicate_schema = self.predicate.as_ref().and_then(|predicate| {
+            let live = predicate.live_variables()?;
+            let schema = self
+                .schema
+                .iter()
+                .filter(|(name, _)| live.iter().any(|l| l.as_ref() == name.as_str()))
+                .map(|(name, dtype)| Field::new(name, dtype.clone()))
+                .collect::<Schema>();
+            (!schema.is_empty()).then_some(schema)
+        });
+
         let row_index = self.row_index.as_ref().map(|ri| ri as &RowIndex);
         let (mut dfs, prepredicate_heights) = POOL.install(|| {
             file_chunks
                 .into_par_iter()
                 .map(|(start_pos, stop_at_nbytes)| {
-                    let mut buffers = init_buffers(&self.schema, capacity, self.ignore_errors)?;
-                    parse_lines(&bytes[start_pos..stop_at_nbytes], &mut buffers)?;
-                    let mut local_df = DataFrame::new(
-                        buffers
-                            .into_values()
-                            .map(|buf| buf.into_series())
-                            .collect::<_>(),
-                    )?;
-
-                    let prepredicate_height = local_df.height() as IdxSize;
-                    if let Some(row_index) = row_index {
-                        local_df = local_df
-                            .with_row_index(row_index.name.as_ref(), Some(row_index.offset))?;
-                    }
+                    let bytes = &bytes[start_pos..stop_at_nbytes];
+                    let (mut local_df, prepredicate_height) =
+                        match (&self.predicate, &predicate_schema) {
+                            (Some(predicate), Some(predicate_schema)) => self.parse_filtered(
+                                bytes,
+                                predicate.as_ref(),
+                                predicate_schema,
+                                &read_schema,
+                                capacity,
+                                row_index,
+                            )?,
+                            _ => {
+                                let mut buffers =
+                                    init_buffers(&read_schema, capacity, self.ignore_errors)?;
+                                if read_schema.len() < self.schema.len() {
+                                    parse_lines_projected(bytes, &mut buffers, None)?;
+                                } else {
+                                    parse_lines(bytes, &mut buffers)?;
+                                }
+                                let mut local_df = DataFrame::new(
+                                    buffers
+                                        .into_values()
+                                        .map(|buf| buf.into_series())
+                                        .collect::<_>(),
+                                )?;
+
+                                let prepredicate_height = local_df.height() as IdxSize;
+                                if let Some(row_index) = row_index {
+                                    local_df = local_df.with_row_index(
+                                        row_index.name.as_ref(),
+                                        Some(row_index.offset),
+                                    )?;
+                                }
+                                if let Some(predicate) = &self.predicate {
+                                    let s = predicate.evaluate_io(&local_df)?;
+                                    let mask = s.bool()?;
+                                    local_df = local_df.filter(mask)?;
+                                }
+                                (local_df, prepredicate_height)
+                            },
+                        };
 
                     if let Some(projection) = &self.projection {
-                        local_df = local_df.select(projection.as_ref())?;
-                    }
-
-                    if let Some(predicate) = &self.predicate {
-                        let s = predicate.evaluate_io(&local_df)?;
-                        let mask = s.bool()?;
-                        local_df = local_df.filter(mask)?;
+                        let names = row_index
+                            .map(|ri| ri.name.as_ref())
+                            .into_iter()
+                            .chain(projection.iter().map(|name| name.as_str()));
+                        local_df = local_df.select(names)?;
                     }
 
                     Ok((local_df, prepredicate_height))
@@ -344,6 +405,55 @@ impl<'a> CoreJsonReader<'a> {
         accumulate_dataframes_vertical(dfs)
     }
 
+    /// Parse the columns of `predicate_schema`, evaluate the predicate on them and parse the
+    /// other columns of `schema` for the matching lines only.
+    fn parse_filtered(
+        &self,
+        bytes: &[u8],
+        predicate: &dyn PhysicalIoExpr,
+        predicate_schema: &Schema,
+        schema: &Schema,
+        capacity: usize,
+        row_index: Option<&RowIndex>,
+    ) -> PolarsResult<(DataFrame, IdxSize)> {
+        let mut buffers = init_buffers(predicate_schema, capacity, self.ignore_errors)?;
+        let height = parse_lines_projected(bytes, &mut buffers, None)?;
+        let mut df = DataFrame::new(
+            buffers
+                .into_values()
+                .map(|buf| buf.into_series())
+                .collect::<_>(),
+        )?;
+        if let Some(row_index) = row_index {
+            df = df.with_row_index(row_index.name.as_ref(), Some(row_index.offset))?;
+        }
+
+        let s = predicate.evaluate_io(&df)?;
+        let mut mask = s.bool()?.clone();
+        if mask.len() != height {
+            mask = mask.new_from_index(0, height);
+        }
+        let mut df = df.filter(&mask)?;
+
+        let rest = schema
+            .iter()
+            .filter(|(name, _)| !predicate_schema.contains(name))
+            .map(|(name, dtype)| Field::new(name, dtype.clone()))
+            .collect::<Schema>();
+        let mut buffers = init_buffers(&rest, df.height(), self.ignore_errors)?;
+        parse_lines_projected(bytes, &mut buffers, Some(&mask))?;
+        for buf in buffers.into_values() {
+            df.with_column(buf.into_series())?;
+        }
+        // Restore the column order of a frame that is parsed at once.
+        let names = row_index
+            .map(|ri| ri.name.as_ref())
+            .into_iter()
+            .chain(schema.iter_names().map(|name| name.as_str()));
+        let df = df.select(names)?;
+        Ok((df, height as IdxSize))
+    }
+
     pub fn as_df(&mut self) -> PolarsResult<DataFrame> {
         let n_threads = self.n_threads.unwrap_or_else(|| POOL.current_num_threads());
 
@@ -362,6 +472,50 @@ impl<'a> CoreJsonReader<'a> {
     }
 }
 
+/// Set the scale of `Decimal` columns without one to the largest number of fractional digits
+/// in the first `infer_schema_len` lines.
+#[cfg(feature = "dtype-decimal")]
+fn infer_decimal_scales(
+    bytes: &[u8],
+    schema: &mut Schema,
+    infer_schema_len: Option<NonZeroUsize>,
+) -> PolarsResult<()> {
+    let mut columns = schema
+        .iter()
+        .filter_map(|(name, dtype)| match dtype {
+            DataType::Decimal(precision, None) => Some((name.clone(), *precision, 0)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if columns.is_empty() {
+        return Ok(());
+    }
+
+    let mut scratch = vec![];
+    let iter =
+        serde_json::Deserializer::from_slice(bytes).into_iter::<Box<serde_json::value::RawValue>>();
+    for value in iter.take(infer_schema_len.map_or(usize::MAX, |n| n.get())) {
+        let value = value.map_err(|e| polars_err!(ComputeError: "error parsing ndjson {}", e))?;
+        scratch.clear();
+        scratch.extend_from_slice(value.get().as_bytes());
+        let value = simd_json::to_borrowed_value(&mut scratch)
+            .map_err(|e| polars_err!(ComputeError: "error parsing line: {}", e))?;
+        let simd_json::BorrowedValue::Object(value) = value else {
+            continue;
+        };
+        for (name, _, scale) in columns.iter_mut() {
+            if let Some(v) = simd_json::KnownKey::from(name.as_str()).map_lookup(&value) {
+                *scale = (*scale).max(infer_decimal_scale(v));
+            }
+        }
+    }
+
+    for (name, precision, scale) in columns {
+        *schema.try_get_mut(&name)? = DataType::Decimal(precision, Some(scale));
+    }
+    Ok(())
+}
+
 #[inline(always)]
 fn parse_impl(
     bytes: &[u8],
@@ -420,6 +574,147 @@ fn parse_lines(bytes: &[u8], buffers: &mut PlIndexMap<BufferKey, Buffer>) -> Pol
     Ok(())
 }
 
+/// Visits the keys of a JSON object and keeps the raw text of the values of the wanted keys, the
+/// other values are skipped without being deserialized. Lines that are not objects have no values.
+struct ProjectedLine<'a, 'b> {
+    keys: &'b PlHashMap<&'b str, usize>,
+    values: &'b mut [Option<&'a RawValue>],
+}
+
+impl<'de, 'b> DeserializeSeed<'de> for ProjectedLine<'de, 'b> {
+    type Value = ();
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, 'b> Visitor<'de> for ProjectedLine<'de, 'b> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a JSON value")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(index) = map.next_key_seed(KeyIndex(self.keys))? {
+            match index {
+                Some(i) => self.values[i] = Some(map.next_value()?),
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                },
+            }
+        }
+        Ok(())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(())
+    }
+
+    fn visit_bool<E: serde::de::Error>(self, _: bool) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, _: i64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, _: u64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, _: f64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_str<E: serde::de::Error>(self, _: &str) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+}
+
+/// Looks up a key without allocating it.
+struct KeyIndex<'b>(&'b PlHashMap<&'b str, usize>);
+
+impl<'de> DeserializeSeed<'de> for KeyIndex<'_> {
+    type Value = Option<usize>;
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Option<usize>, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Visitor<'de> for KeyIndex<'_> {
+    type Value = Option<usize>;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("an object key")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, key: &str) -> Result<Option<usize>, E> {
+        Ok(self.0.get(key).copied())
+    }
+}
+
+/// Parse the lines into `buffers`, only deserializing the values of their keys. With a `mask`,
+/// only the lines that are `true` in it are added. Returns the number of lines.
+fn parse_lines_projected(
+    bytes: &[u8],
+    buffers: &mut PlIndexMap<BufferKey, Buffer>,
+    mask: Option<&BooleanChunked>,
+) -> PolarsResult<usize> {
+    let keys = buffers
+        .values()
+        .enumerate()
+        .map(|(i, buf)| (buf.name(), i))
+        .collect::<PlHashMap<_, _>>();
+    let mut values = vec![None; keys.len()];
+    let mut mask = mask.map(|mask| mask.into_iter());
+    let mut scratch = vec![];
+
+    let mut n_lines = 0;
+    let iter = serde_json::Deserializer::from_slice(bytes).into_iter::<&RawValue>();
+    for line in iter {
+        let line = line.map_err(|e| polars_err!(ComputeError: "error parsing ndjson {}", e))?;
+        n_lines += 1;
+        if let Some(mask) = &mut mask {
+            if mask.next() != Some(Some(true)) {
+                continue;
+            }
+        }
+
+        values.iter_mut().for_each(|v| *v = None);
+        ProjectedLine {
+            keys: &keys,
+            values: &mut values,
+        }
+        .deserialize(&mut serde_json::Deserializer::from_str(line.get()))
+        .map_err(|e| polars_err!(ComputeError: "error parsing line: {}", e))?;
+
+        for (buf, value) in buffers.values_mut().zip(&values) {
+            match value {
+                Some(value) => {
+                    scratch.clear();
+                    scratch.extend_from_slice(value.get().as_bytes());
+                    let value = simd_json::to_borrowed_value(&mut scratch)
+                        .map_err(|e| polars_err!(ComputeError: "error parsing line: {}", e))?;
+                    buf.add(&value)?;
+                },
+                None => buf.add_null(),
+            }
+        }
+    }
+    Ok(n_lines)
+}
+
 /// Find the nearest next line position.
 /// Does not check for new line characters embedded in String fields.
 /// This just looks for `}\n`
@@ -0,0 +1,452 @@
+use arrow::array::{
+    Array, BinaryViewArray, BooleanArray, ListArray, MutableBinaryViewArray, PrimitiveArray,
+    StructArray, Utf8ViewArray,
+};
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use arrow::datatypes::{ArrowDataType, TimeUnit};
+use arrow::offset::Offsets;
+use arrow::types::NativeType;
+use polars_core::prelude::*;
+
+use super::proto::{ColumnEncoding, OrcType, StreamKind, TypeKind};
+use super::rle::{decode_bool_rle, decode_byte_rle, decode_int_rle, read_varint_i128};
+
+/// Seconds between the unix epoch and the ORC timestamp epoch (2015-01-01 00:00:00).
+const ORC_EPOCH_SECONDS: i64 = 1_420_070_400;
+
+/// Convert the ORC type with id `column` to an arrow data type.
+pub(super) fn arrow_data_type(types: &[OrcType], column: u32) -> PolarsResult<ArrowDataType> {
+    let ty = types.get(column as usize).ok_or_else(
+        || polars_err!(ComputeError: "out of spec ORC: type id {} does not exist", column),
+    )?;
+    Ok(match ty.kind {
+        TypeKind::Boolean => ArrowDataType::Boolean,
+        TypeKind::Byte => ArrowDataType::Int8,
+        TypeKind::Short => ArrowDataType::Int16,
+        TypeKind::Int => ArrowDataType::Int32,
+        TypeKind::Long => ArrowDataType::Int64,
+        TypeKind::Float => ArrowDataType::Float32,
+        TypeKind::Double => ArrowDataType::Float64,
+        TypeKind::String | TypeKind::Varchar | TypeKind::Char => ArrowDataType::Utf8View,
+        TypeKind::Binary => ArrowDataType::BinaryView,
+        TypeKind::Timestamp => ArrowDataType::Timestamp(TimeUnit::Nanosecond, None),
+        TypeKind::TimestampInstant => {
+            ArrowDataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".to_string()))
+        },
+        TypeKind::Date => ArrowDataType::Date32,
+        TypeKind::Decimal => {
+            let precision = if ty.precision == 0 {
+                38
+            } else {
+                ty.precision as usize
+            };
+            ArrowDataType::Decimal(precision, ty.scale as usize)
+        },
+        TypeKind::List => {
+            let child = single_subtype(ty)?;
+            ArrowDataType::LargeList(Box::new(ArrowField::new(
+                "item",
+                arrow_data_type(types, child)?,
+                true,
+            )))
+        },
+        TypeKind::Map => {
+            polars_ensure!(ty.subtypes.len() == 2, ComputeError: "out of spec ORC: map type must have 2 subtypes");
+            let entries = ArrowDataType::Struct(vec![
+                ArrowField::new("key", arrow_data_type(types, ty.subtypes[0])?, false),
+                ArrowField::new("value", arrow_data_type(types, ty.subtypes[1])?, true),
+            ]);
+            ArrowDataType::LargeList(Box::new(ArrowField::new("entries", entries, true)))
+        },
+        TypeKind::Struct => {
+            polars_ensure!(
+                ty.subtypes.len() == ty.field_names.len(),
+                ComputeError: "out of spec ORC: struct type has {} subtypes but {} field names",
+                ty.subtypes.len(), ty.field_names.len()
+            );
+            let fields = ty
+                .subtypes
+                .iter()
+                .zip(&ty.field_names)
+                .map(|(child, name)| {
+                    Ok(ArrowField::new(name, arrow_data_type(types, *child)?, true))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            ArrowDataType::Struct(fields)
+        },
+        TypeKind::Union => polars_bail!(ComputeError: "ORC union types are not supported"),
+    })
+}
+
+fn single_subtype(ty: &OrcType) -> PolarsResult<u32> {
+    polars_ensure!(ty.subtypes.len() == 1, ComputeError: "out of spec ORC: list type must have 1 subtype");
+    Ok(ty.subtypes[0])
+}
+
+/// Collects all type ids in the subtree rooted at `column`, including `column` itself.
+pub(super) fn collect_column_ids(types: &[OrcType], column: u32, out: &mut Vec<u32>) {
+    out.push(column);
+    if let Some(ty) = types.get(column as usize) {
+        for child in &ty.subtypes {
+            collect_column_ids(types, *child, out);
+        }
+    }
+}
+
+/// The decompressed streams of a single stripe.
+pub(super) struct StripeData<'a> {
+    pub types: &'a [OrcType],
+    pub encodings: &'a [ColumnEncoding],
+    pub streams: PlHashMap<(u32, StreamKind), Vec<u8>>,
+}
+
+impl<'a> StripeData<'a> {
+    fn stream(&self, column: u32, kind: StreamKind) -> Option<&[u8]> {
+        self.streams.get(&(column, kind)).map(|v| v.as_slice())
+    }
+
+    fn required_stream(&self, column: u32, kind: StreamKind) -> PolarsResult<&[u8]> {
+        self.stream(column, kind).ok_or_else(
+            || polars_err!(ComputeError: "out of spec ORC: column {} is missing its {:?} stream", column, kind),
+        )
+    }
+
+    fn encoding(&self, column: u32) -> PolarsResult<ColumnEncoding> {
+        self.encodings.get(column as usize).copied().ok_or_else(
+            || polars_err!(ComputeError: "out of spec ORC: column {} has no encoding", column),
+        )
+    }
+
+    fn read_ints(
+        &self,
+        column: u32,
+        kind: StreamKind,
+        n: usize,
+        signed: bool,
+    ) -> PolarsResult<Vec<i64>> {
+        if n == 0 {
+            return Ok(vec![]);
+        }
+        let v2 = self.encoding(column)?.kind.is_v2();
+        decode_int_rle(self.required_stream(column, kind)?, n, signed, v2)
+    }
+
+    /// Compute the validity of `n` rows. Rows where the parent is null have no entry in the
+    /// present stream of the child and are null.
+    fn validity(
+        &self,
+        column: u32,
+        n: usize,
+        parent: Option<&Bitmap>,
+    ) -> PolarsResult<Option<Bitmap>> {
+        let slots = parent.map_or(n, |p| p.len() - p.unset_bits());
+        let present = match self.stream(column, StreamKind::Present) {
+            Some(data) => Some(decode_bool_rle(data, slots)?),
+            None => None,
+        };
+
+        let validity = match (parent, present) {
+            (None, None) => return Ok(None),
+            (Some(parent), None) => parent.clone(),
+            (None, Some(present)) => Bitmap::from_iter(present),
+            (Some(parent), Some(present)) => {
+                let mut present = present.into_iter();
+                let mut validity = MutableBitmap::with_capacity(n);
+                for parent_valid in parent.iter() {
+                    validity.push(parent_valid && present.next().unwrap());
+                }
+                validity.into()
+            },
+        };
+        Ok(if validity.unset_bits() == 0 {
+            None
+        } else {
+            Some(validity)
+        })
+    }
+
+    /// Decode `n` rows of `column`.
+    pub fn read_column(
+        &self,
+        column: u32,
+        n: usize,
+        parent: Option<&Bitmap>,
+    ) -> PolarsResult<Box<dyn Array>> {
+        let ty = &self.types[column as usize];
+        let data_type = arrow_data_type(self.types, column)?;
+        let validity = self.validity(column, n, parent)?;
+        let n_valid = validity.as_ref().map_or(n, |v| n - v.unset_bits());
+
+        let out: Box<dyn Array> = match ty.kind {
+            TypeKind::Boolean => {
+                let values = match n_valid {
+                    0 => vec![],
+                    _ => decode_bool_rle(self.required_stream(column, StreamKind::Data)?, n_valid)?,
+                };
+                let values = spread(values, validity.as_ref(), n);
+                BooleanArray::new(data_type, Bitmap::from_iter(values), validity).boxed()
+            },
+            TypeKind::Byte => {
+                let values = match n_valid {
+                    0 => vec![],
+                    _ => decode_byte_rle(self.required_stream(column, StreamKind::Data)?, n_valid)?,
+                };
+                let values = values.into_iter().map(|v| v as i8).collect();
+                primitive(data_type, values, validity, n)
+            },
+            TypeKind::Short => {
+                let values = self.read_ints(column, StreamKind::Data, n_valid, true)?;
+                primitive(
+                    data_type,
+                    values.into_iter().map(|v| v as i16).collect(),
+                    validity,
+                    n,
+                )
+            },
+            TypeKind::Int => {
+                let values = self.read_ints(column, StreamKind::Data, n_valid, true)?;
+                primitive(
+                    data_type,
+                    values.into_iter().map(|v| v as i32).collect(),
+                    validity,
+                    n,
+                )
+            },
+            TypeKind::Long => {
+                let values = self.read_ints(column, StreamKind::Data, n_valid, true)?;
+                primitive(data_type, values, validity, n)
+            },
+            TypeKind::Date => {
+                let values = self.read_ints(column, StreamKind::Data, n_valid, true)?;
+                primitive(
+                    data_type,
+                    values.into_iter().map(|v| v as i32).collect(),
+                    validity,
+                    n,
+                )
+            },
+            TypeKind::Float => {
+                let values = self.read_le::<f32, 4>(column, n_valid)?;
+                primitive(data_type, values, validity, n)
+            },
+            TypeKind::Double => {
+                let values = self.read_le::<f64, 8>(column, n_valid)?;
+                primitive(data_type, values, validity, n)
+            },
+            TypeKind::String | TypeKind::Varchar | TypeKind::Char | TypeKind::Binary => {
+                let values = self.read_binary(column, n_valid)?;
+                let mut array = MutableBinaryViewArray::<[u8]>::with_capacity(n);
+                let mut values = values.into_iter();
+                for i in 0..n {
+                    if validity.as_ref().map_or(true, |v| v.get_bit(i)) {
+                        array.push_value(values.next().unwrap());
+                    } else {
+                        array.push_null();
+                    }
+                }
+                let array: BinaryViewArray = array.into();
+                if ty.kind == TypeKind::Binary {
+                    array.boxed()
+                } else {
+                    let array: Utf8ViewArray = array.to_utf8view().map_err(
+                        |_| polars_err!(ComputeError: "invalid utf-8 in ORC string column {}", column),
+                    )?;
+                    array.boxed()
+                }
+            },
+            TypeKind::Timestamp | TypeKind::TimestampInstant => {
+                let seconds = self.read_ints(column, StreamKind::Data, n_valid, true)?;
+                let nanos = self.read_ints(column, StreamKind::Secondary, n_valid, false)?;
+                let values = seconds
+                    .into_iter()
+                    .zip(nanos)
+                    .map(|(seconds, nanos)| {
+                        let nanos = decode_nanos(nanos as u64);
+                        let mut seconds = seconds + ORC_EPOCH_SECONDS;
+                        // Writers round negative timestamps towards zero.
+                        if seconds < 0 && nanos > 999_999 {
+                            seconds -= 1;
+                        }
+                        seconds * 1_000_000_000 + nanos
+                    })
+                    .collect();
+                primitive(data_type, values, validity, n)
+            },
+            TypeKind::Decimal => {
+                let values = self.read_decimals(column, ty.scale, n_valid)?;
+                primitive(data_type, values, validity, n)
+            },
+            TypeKind::Struct => {
+                let children = ty
+                    .subtypes
+                    .iter()
+                    .map(|child| self.read_column(*child, n, validity.as_ref()))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                polars_ensure!(!children.is_empty(), ComputeError: "ORC structs without fields are not supported");
+                StructArray::try_new(data_type, children, validity)?.boxed()
+            },
+            TypeKind::List | TypeKind::Map => {
+                let lengths = self.read_ints(column, StreamKind::Length, n_valid, false)?;
+                let lengths = spread(lengths, validity.as_ref(), n);
+                let n_values = lengths.iter().sum::<i64>() as usize;
+                let offsets =
+                    Offsets::<i64>::try_from_lengths(lengths.into_iter().map(|l| l as usize))?;
+
+                let values = if ty.kind == TypeKind::List {
+                    self.read_column(single_subtype(ty)?, n_values, None)?
+                } else {
+                    let ArrowDataType::LargeList(entries) = &data_type else {
+                        unreachable!()
+                    };
+                    let keys = self.read_column(ty.subtypes[0], n_values, None)?;
+                    let values = self.read_column(ty.subtypes[1], n_values, None)?;
+                    StructArray::try_new(entries.data_type().clone(), vec![keys, values], None)?
+                        .boxed()
+                };
+                ListArray::<i64>::try_new(data_type, offsets.into(), values, validity)?.boxed()
+            },
+            TypeKind::Union => polars_bail!(ComputeError: "ORC union types are not supported"),
+        };
+        Ok(out)
+    }
+
+    fn read_le<T: NativeType, const N: usize>(&self, column: u32, n: usize) -> PolarsResult<Vec<T>>
+    where
+        T: FromLeBytes<N>,
+    {
+        if n == 0 {
+            return Ok(vec![]);
+        }
+        let data = self.required_stream(column, StreamKind::Data)?;
+        polars_ensure!(
+            data.len() >= n * N,
+            ComputeError: "out of spec ORC: stream ended before all values were decoded"
+        );
+        Ok(data
+            .chunks_exact(N)
+            .take(n)
+            .map(|b| T::from_le(b.try_into().unwrap()))
+            .collect())
+    }
+
+    /// Read `n` binary values, resolving dictionary encoding.
+    fn read_binary(&self, column: u32, n: usize) -> PolarsResult<Vec<&[u8]>> {
+        if n == 0 {
+            return Ok(vec![]);
+        }
+        let encoding = self.encoding(column)?;
+        if encoding.kind.is_dictionary() {
+            let dict_size = encoding.dictionary_size as usize;
+            let lengths = self.read_ints(column, StreamKind::Length, dict_size, false)?;
+            let dict_data = self
+                .stream(column, StreamKind::DictionaryData)
+                .unwrap_or_default();
+            let dictionary = split_by_lengths(dict_data, &lengths)?;
+            let indices = self.read_ints(column, StreamKind::Data, n, false)?;
+            indices
+                .into_iter()
+                .map(|i| {
+                    dictionary.get(i as usize).copied().ok_or_else(
+                        || polars_err!(ComputeError: "out of spec ORC: dictionary index {} out of bounds", i),
+                    )
+                })
+                .collect()
+        } else {
+            let lengths = self.read_ints(column, StreamKind::Length, n, false)?;
+            let data = self.stream(column, StreamKind::Data).unwrap_or_default();
+            split_by_lengths(data, &lengths)
+        }
+    }
+
+    /// Decimals are stored as unbounded varints with a per-value scale. Values are rescaled to
+    /// the scale of the column type.
+    fn read_decimals(&self, column: u32, scale: u32, n: usize) -> PolarsResult<Vec<i128>> {
+        if n == 0 {
+            return Ok(vec![]);
+        }
+        let data = self.required_stream(column, StreamKind::Data)?;
+        let scales = self.read_ints(column, StreamKind::Secondary, n, true)?;
+        let mut pos = 0;
+        scales
+            .into_iter()
+            .map(|value_scale| {
+                let v = read_varint_i128(data, &mut pos)?;
+                let diff = scale as i64 - value_scale;
+                Ok(if diff >= 0 {
+                    v * 10i128.pow(diff as u32)
+                } else {
+                    v / 10i128.pow((-diff) as u32)
+                })
+            })
+            .collect()
+    }
+}
+
+pub(super) trait FromLeBytes<const N: usize> {
+    fn from_le(bytes: [u8; N]) -> Self;
+}
+
+impl FromLeBytes<4> for f32 {
+    fn from_le(bytes: [u8; 4]) -> Self {
+        f32::from_le_bytes(bytes)
+    }
+}
+
+impl FromLeBytes<8> for f64 {
+    fn from_le(bytes: [u8; 8]) -> Self {
+        f64::from_le_bytes(bytes)
+    }
+}
+
+/// The nanosecond stream stores the number of trailing decimal zeros in the lowest 3 bits.
+fn decode_nanos(v: u64) -> i64 {
+    let zeros = v & 0x7;
+    let nanos = (v >> 3) as i64;
+    if zeros == 0 {
+        nanos
+    } else {
+        nanos * 10i64.pow(zeros as u32 + 1)
+    }
+}
+
+fn split_by_lengths<'b>(data: &'b [u8], lengths: &[i64]) -> PolarsResult<Vec<&'b [u8]>> {
+    let mut offset = 0;
+    lengths
+        .iter()
+        .map(|len| {
+            let end = offset + *len as usize;
+            let out = data.get(offset..end).ok_or_else(
+                || polars_err!(ComputeError: "out of spec ORC: binary data shorter than its lengths"),
+            )?;
+            offset = end;
+            Ok(out)
+        })
+        .collect()
+}
+
+/// Spread the values of the valid rows over all `n` rows, filling null slots with the default.
+fn spread<T: Default + Copy>(values: Vec<T>, validity: Option<&Bitmap>, n: usize) -> Vec<T> {
+    let Some(validity) = validity else {
+        return values;
+    };
+    let mut values = values.into_iter();
+    (0..n)
+        .map(|i| {
+            if validity.get_bit(i) {
+                values.next().unwrap()
+            } else {
+                T::default()
+            }
+        })
+        .collect()
+}
+
+fn primitive<T: NativeType>(
+    data_type: ArrowDataType,
+    values: Vec<T>,
+    validity: Option<Bitmap>,
+    n: usize,
+) -> Box<dyn Array> {
+    let values = spread(values, validity.as_ref(), n);
+    PrimitiveArray::new(data_type, values.into(), validity).boxed()
+}
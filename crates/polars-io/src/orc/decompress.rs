@@ -0,0 +1,61 @@
+use std::borrow::Cow;
+use std::io::Read;
+
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+
+use super::proto::CompressionKind;
+
+/// Decompress an ORC stream.
+///
+/// Compressed streams consist of chunks, each prefixed by a 3 byte little endian header holding
+/// the chunk length and a flag that signals whether the chunk was stored uncompressed.
+pub(super) fn decompress(
+    compression: CompressionKind,
+    block_size: usize,
+    data: &[u8],
+) -> PolarsResult<Cow<'_, [u8]>> {
+    if compression == CompressionKind::None {
+        return Ok(Cow::Borrowed(data));
+    }
+
+    let mut out = Vec::with_capacity(data.len() * 2);
+    let mut pos = 0;
+    while pos < data.len() {
+        polars_ensure!(pos + 3 <= data.len(), ComputeError: "out of spec ORC: truncated compression chunk header");
+        let header =
+            data[pos] as usize | (data[pos + 1] as usize) << 8 | (data[pos + 2] as usize) << 16;
+        pos += 3;
+        let is_original = header & 1 == 1;
+        let len = header >> 1;
+        polars_ensure!(pos + len <= data.len(), ComputeError: "out of spec ORC: truncated compression chunk");
+        let chunk = &data[pos..pos + len];
+        pos += len;
+
+        if is_original {
+            out.extend_from_slice(chunk);
+            continue;
+        }
+
+        match compression {
+            CompressionKind::Zlib => {
+                flate2::read::DeflateDecoder::new(chunk).read_to_end(&mut out)?;
+            },
+            CompressionKind::Snappy => {
+                let decompressed = snap::raw::Decoder::new()
+                    .decompress_vec(chunk)
+                    .map_err(to_compute_err)?;
+                out.extend_from_slice(&decompressed);
+            },
+            CompressionKind::Zstd => {
+                out.reserve(block_size);
+                zstd::Decoder::with_buffer(chunk)?.read_to_end(&mut out)?;
+            },
+            CompressionKind::Lzo | CompressionKind::Lz4 => {
+                polars_bail!(ComputeError: "ORC compression {:?} is not supported", compression)
+            },
+            CompressionKind::None => unreachable!(),
+        }
+    }
+    Ok(Cow::Owned(out))
+}
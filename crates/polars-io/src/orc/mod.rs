@@ -0,0 +1,12 @@
+//! # (De)serializing Apache ORC files
+//!
+//! Reading is implemented natively. Supported compressions are zlib, snappy and zstd, nested
+//! struct, list and map columns are decoded to their arrow counterparts. Union types are not
+//! supported.
+mod column;
+mod decompress;
+mod proto;
+mod read;
+mod rle;
+
+pub use read::*;
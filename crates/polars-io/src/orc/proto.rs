@@ -0,0 +1,497 @@
+//! Decoding of the protobuf messages that make up the ORC file tail and stripe footers.
+//!
+//! Only the fields that the reader needs are decoded; everything else is skipped.
+use polars_core::prelude::*;
+
+use super::rle::{read_varint, zigzag_decode};
+
+const WIRE_VARINT: u8 = 0;
+const WIRE_FIXED64: u8 = 1;
+const WIRE_LEN: u8 = 2;
+const WIRE_FIXED32: u8 = 5;
+
+struct ProtoReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ProtoReader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.buf.len()
+    }
+
+    fn varint(&mut self) -> PolarsResult<u64> {
+        read_varint(self.buf, &mut self.pos)
+    }
+
+    fn key(&mut self) -> PolarsResult<(u64, u8)> {
+        let key = self.varint()?;
+        Ok((key >> 3, (key & 7) as u8))
+    }
+
+    fn take(&mut self, len: usize) -> PolarsResult<&'a [u8]> {
+        let end = self.pos + len;
+        polars_ensure!(end <= self.buf.len(), ComputeError: "out of spec ORC: truncated protobuf message");
+        let out = &self.buf[self.pos..end];
+        self.pos = end;
+        Ok(out)
+    }
+
+    fn bytes(&mut self) -> PolarsResult<&'a [u8]> {
+        let len = self.varint()? as usize;
+        self.take(len)
+    }
+
+    fn string(&mut self) -> PolarsResult<String> {
+        let bytes = self.bytes()?;
+        String::from_utf8(bytes.to_vec()).map_err(
+            |_| polars_err!(ComputeError: "out of spec ORC: invalid utf8 in protobuf string"),
+        )
+    }
+
+    fn double(&mut self) -> PolarsResult<f64> {
+        let bytes = self.take(8)?;
+        Ok(f64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    /// Reads a repeated `uint32`, which may or may not be packed.
+    fn repeated_u32(&mut self, wire_type: u8, out: &mut Vec<u32>) -> PolarsResult<()> {
+        if wire_type == WIRE_LEN {
+            let mut packed = ProtoReader::new(self.bytes()?);
+            while !packed.is_empty() {
+                out.push(packed.varint()? as u32);
+            }
+        } else {
+            out.push(self.varint()? as u32);
+        }
+        Ok(())
+    }
+
+    fn skip(&mut self, wire_type: u8) -> PolarsResult<()> {
+        match wire_type {
+            WIRE_VARINT => {
+                self.varint()?;
+            },
+            WIRE_FIXED64 => {
+                self.take(8)?;
+            },
+            WIRE_LEN => {
+                self.bytes()?;
+            },
+            WIRE_FIXED32 => {
+                self.take(4)?;
+            },
+            _ => {
+                polars_bail!(ComputeError: "out of spec ORC: unsupported protobuf wire type {}", wire_type)
+            },
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum CompressionKind {
+    None,
+    Zlib,
+    Snappy,
+    Lzo,
+    Lz4,
+    Zstd,
+}
+
+#[derive(Debug)]
+pub(super) struct PostScript {
+    pub footer_length: u64,
+    pub compression: CompressionKind,
+    pub compression_block_size: u64,
+    pub metadata_length: u64,
+}
+
+impl PostScript {
+    pub fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut r = ProtoReader::new(buf);
+        let mut ps = PostScript {
+            footer_length: 0,
+            compression: CompressionKind::None,
+            compression_block_size: 256 * 1024,
+            metadata_length: 0,
+        };
+        while !r.is_empty() {
+            match r.key()? {
+                (1, WIRE_VARINT) => ps.footer_length = r.varint()?,
+                (2, WIRE_VARINT) => {
+                    ps.compression = match r.varint()? {
+                        0 => CompressionKind::None,
+                        1 => CompressionKind::Zlib,
+                        2 => CompressionKind::Snappy,
+                        3 => CompressionKind::Lzo,
+                        4 => CompressionKind::Lz4,
+                        5 => CompressionKind::Zstd,
+                        v => {
+                            polars_bail!(ComputeError: "out of spec ORC: unknown compression kind {}", v)
+                        },
+                    }
+                },
+                (3, WIRE_VARINT) => ps.compression_block_size = r.varint()?,
+                (5, WIRE_VARINT) => ps.metadata_length = r.varint()?,
+                (_, wire_type) => r.skip(wire_type)?,
+            }
+        }
+        Ok(ps)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct StripeInformation {
+    pub offset: u64,
+    pub index_length: u64,
+    pub data_length: u64,
+    pub footer_length: u64,
+    pub number_of_rows: u64,
+}
+
+impl StripeInformation {
+    fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut r = ProtoReader::new(buf);
+        let mut out = Self::default();
+        while !r.is_empty() {
+            match r.key()? {
+                (1, WIRE_VARINT) => out.offset = r.varint()?,
+                (2, WIRE_VARINT) => out.index_length = r.varint()?,
+                (3, WIRE_VARINT) => out.data_length = r.varint()?,
+                (4, WIRE_VARINT) => out.footer_length = r.varint()?,
+                (5, WIRE_VARINT) => out.number_of_rows = r.varint()?,
+                (_, wire_type) => r.skip(wire_type)?,
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum TypeKind {
+    Boolean,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    String,
+    Binary,
+    Timestamp,
+    List,
+    Map,
+    Struct,
+    Union,
+    Decimal,
+    Date,
+    Varchar,
+    Char,
+    TimestampInstant,
+}
+
+impl TypeKind {
+    fn from_u64(v: u64) -> PolarsResult<Self> {
+        use TypeKind::*;
+        Ok(match v {
+            0 => Boolean,
+            1 => Byte,
+            2 => Short,
+            3 => Int,
+            4 => Long,
+            5 => Float,
+            6 => Double,
+            7 => String,
+            8 => Binary,
+            9 => Timestamp,
+            10 => List,
+            11 => Map,
+            12 => Struct,
+            13 => Union,
+            14 => Decimal,
+            15 => Date,
+            16 => Varchar,
+            17 => Char,
+            18 => TimestampInstant,
+            v => polars_bail!(ComputeError: "out of spec ORC: unknown type kind {}", v),
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct OrcType {
+    pub kind: TypeKind,
+    pub subtypes: Vec<u32>,
+    pub field_names: Vec<String>,
+    pub precision: u32,
+    pub scale: u32,
+}
+
+impl OrcType {
+    fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut r = ProtoReader::new(buf);
+        let mut out = OrcType {
+            kind: TypeKind::Boolean,
+            subtypes: vec![],
+            field_names: vec![],
+            precision: 0,
+            scale: 0,
+        };
+        while !r.is_empty() {
+            match r.key()? {
+                (1, WIRE_VARINT) => out.kind = TypeKind::from_u64(r.varint()?)?,
+                (2, wire_type) => r.repeated_u32(wire_type, &mut out.subtypes)?,
+                (3, WIRE_LEN) => out.field_names.push(r.string()?),
+                (5, WIRE_VARINT) => out.precision = r.varint()? as u32,
+                (6, WIRE_VARINT) => out.scale = r.varint()? as u32,
+                (_, wire_type) => r.skip(wire_type)?,
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// Min/max statistics of a column, as far as the reader can use them for pruning.
+#[derive(Clone, Debug, Default)]
+pub(super) struct ColumnStatistics {
+    pub number_of_values: Option<u64>,
+    pub has_null: Option<bool>,
+    pub int: Option<(Option<i64>, Option<i64>)>,
+    pub double: Option<(Option<f64>, Option<f64>)>,
+    pub string: Option<(Option<String>, Option<String>)>,
+    pub date: Option<(Option<i32>, Option<i32>)>,
+}
+
+impl ColumnStatistics {
+    fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut r = ProtoReader::new(buf);
+        let mut out = Self::default();
+        while !r.is_empty() {
+            match r.key()? {
+                (1, WIRE_VARINT) => out.number_of_values = Some(r.varint()?),
+                (2, WIRE_LEN) => {
+                    let mut s = ProtoReader::new(r.bytes()?);
+                    let (mut min, mut max) = (None, None);
+                    while !s.is_empty() {
+                        match s.key()? {
+                            (1, WIRE_VARINT) => min = Some(zigzag_decode(s.varint()?)),
+                            (2, WIRE_VARINT) => max = Some(zigzag_decode(s.varint()?)),
+                            (_, wire_type) => s.skip(wire_type)?,
+                        }
+                    }
+                    out.int = Some((min, max));
+                },
+                (3, WIRE_LEN) => {
+                    let mut s = ProtoReader::new(r.bytes()?);
+                    let (mut min, mut max) = (None, None);
+                    while !s.is_empty() {
+                        match s.key()? {
+                            (1, WIRE_FIXED64) => min = Some(s.double()?),
+                            (2, WIRE_FIXED64) => max = Some(s.double()?),
+                            (_, wire_type) => s.skip(wire_type)?,
+                        }
+                    }
+                    out.double = Some((min, max));
+                },
+                (4, WIRE_LEN) => {
+                    let mut s = ProtoReader::new(r.bytes()?);
+                    let (mut min, mut max) = (None, None);
+                    while !s.is_empty() {
+                        match s.key()? {
+                            (1, WIRE_LEN) => min = Some(s.string()?),
+                            (2, WIRE_LEN) => max = Some(s.string()?),
+                            (_, wire_type) => s.skip(wire_type)?,
+                        }
+                    }
+                    out.string = Some((min, max));
+                },
+                (7, WIRE_LEN) => {
+                    let mut s = ProtoReader::new(r.bytes()?);
+                    let (mut min, mut max) = (None, None);
+                    while !s.is_empty() {
+                        match s.key()? {
+                            (1, WIRE_VARINT) => min = Some(zigzag_decode(s.varint()?) as i32),
+                            (2, WIRE_VARINT) => max = Some(zigzag_decode(s.varint()?) as i32),
+                            (_, wire_type) => s.skip(wire_type)?,
+                        }
+                    }
+                    out.date = Some((min, max));
+                },
+                (10, WIRE_VARINT) => out.has_null = Some(r.varint()? != 0),
+                (_, wire_type) => r.skip(wire_type)?,
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct Footer {
+    pub stripes: Vec<StripeInformation>,
+    pub types: Vec<OrcType>,
+    pub number_of_rows: u64,
+}
+
+impl Footer {
+    pub fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut r = ProtoReader::new(buf);
+        let mut out = Footer {
+            stripes: vec![],
+            types: vec![],
+            number_of_rows: 0,
+        };
+        while !r.is_empty() {
+            match r.key()? {
+                (3, WIRE_LEN) => out.stripes.push(StripeInformation::decode(r.bytes()?)?),
+                (4, WIRE_LEN) => out.types.push(OrcType::decode(r.bytes()?)?),
+                (6, WIRE_VARINT) => out.number_of_rows = r.varint()?,
+                (_, wire_type) => r.skip(wire_type)?,
+            }
+        }
+        polars_ensure!(!out.types.is_empty(), ComputeError: "out of spec ORC: footer has no types");
+        Ok(out)
+    }
+}
+
+/// Per-stripe column statistics stored in the file metadata section.
+pub(super) fn decode_stripe_statistics(buf: &[u8]) -> PolarsResult<Vec<Vec<ColumnStatistics>>> {
+    let mut r = ProtoReader::new(buf);
+    let mut out = vec![];
+    while !r.is_empty() {
+        match r.key()? {
+            (1, WIRE_LEN) => {
+                let mut s = ProtoReader::new(r.bytes()?);
+                let mut columns = vec![];
+                while !s.is_empty() {
+                    match s.key()? {
+                        (1, WIRE_LEN) => columns.push(ColumnStatistics::decode(s.bytes()?)?),
+                        (_, wire_type) => s.skip(wire_type)?,
+                    }
+                }
+                out.push(columns);
+            },
+            (_, wire_type) => r.skip(wire_type)?,
+        }
+    }
+    Ok(out)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(super) enum StreamKind {
+    Present,
+    Data,
+    Length,
+    DictionaryData,
+    Secondary,
+    /// Index and bloom filter streams, which the reader does not use.
+    Other,
+}
+
+#[derive(Debug)]
+pub(super) struct Stream {
+    pub kind: StreamKind,
+    pub column: u32,
+    pub length: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum ColumnEncodingKind {
+    Direct,
+    Dictionary,
+    DirectV2,
+    DictionaryV2,
+}
+
+impl ColumnEncodingKind {
+    pub fn is_v2(&self) -> bool {
+        matches!(self, Self::DirectV2 | Self::DictionaryV2)
+    }
+
+    pub fn is_dictionary(&self) -> bool {
+        matches!(self, Self::Dictionary | Self::DictionaryV2)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(super) struct ColumnEncoding {
+    pub kind: ColumnEncodingKind,
+    pub dictionary_size: u32,
+}
+
+#[derive(Debug)]
+pub(super) struct StripeFooter {
+    pub streams: Vec<Stream>,
+    pub columns: Vec<ColumnEncoding>,
+}
+
+impl StripeFooter {
+    pub fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut r = ProtoReader::new(buf);
+        let mut out = StripeFooter {
+            streams: vec![],
+            columns: vec![],
+        };
+        while !r.is_empty() {
+            match r.key()? {
+                (1, WIRE_LEN) => {
+                    let mut s = ProtoReader::new(r.bytes()?);
+                    let mut stream = Stream {
+                        kind: StreamKind::Present,
+                        column: 0,
+                        length: 0,
+                    };
+                    while !s.is_empty() {
+                        match s.key()? {
+                            (1, WIRE_VARINT) => {
+                                stream.kind = match s.varint()? {
+                                    0 => StreamKind::Present,
+                                    1 => StreamKind::Data,
+                                    2 => StreamKind::Length,
+                                    3 => StreamKind::DictionaryData,
+                                    5 => StreamKind::Secondary,
+                                    _ => StreamKind::Other,
+                                }
+                            },
+                            (2, WIRE_VARINT) => stream.column = s.varint()? as u32,
+                            (3, WIRE_VARINT) => stream.length = s.varint()?,
+                            (_, wire_type) => s.skip(wire_type)?,
+                        }
+                    }
+                    out.streams.push(stream);
+                },
+                (2, WIRE_LEN) => {
+                    let mut s = ProtoReader::new(r.bytes()?);
+                    let mut encoding = ColumnEncoding {
+                        kind: ColumnEncodingKind::Direct,
+                        dictionary_size: 0,
+                    };
+                    while !s.is_empty() {
+                        match s.key()? {
+                            (1, WIRE_VARINT) => {
+                                encoding.kind = match s.varint()? {
+                                    0 => ColumnEncodingKind::Direct,
+                                    1 => ColumnEncodingKind::Dictionary,
+                                    2 => ColumnEncodingKind::DirectV2,
+                                    3 => ColumnEncodingKind::DictionaryV2,
+                                    v => {
+                                        polars_bail!(ComputeError: "out of spec ORC: unknown column encoding {}", v)
+                                    },
+                                }
+                            },
+                            (2, WIRE_VARINT) => encoding.dictionary_size = s.varint()? as u32,
+                            (_, wire_type) => s.skip(wire_type)?,
+                        }
+                    }
+                    out.columns.push(encoding);
+                },
+                (_, wire_type) => r.skip(wire_type)?,
+            }
+        }
+        Ok(out)
+    }
+}
@@ -0,0 +1,496 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use arrow::record_batch::RecordBatch;
+use polars_core::prelude::*;
+
+use super::column::{arrow_data_type, collect_column_ids, StripeData};
+use super::decompress::decompress;
+use super::proto::{
+    decode_stripe_statistics, ColumnStatistics, Footer, OrcType, PostScript, StreamKind,
+    StripeFooter, TypeKind,
+};
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+use crate::prelude::*;
+use crate::shared::{finish_reader, ArrowReader};
+use crate::utils::{apply_projection, columns_to_projection};
+use crate::RowIndex;
+
+const ORC_MAGIC: &[u8] = b"ORC";
+
+/// The decoded tail of an ORC file.
+pub(super) struct OrcMetadata {
+    postscript: PostScript,
+    footer: Footer,
+    /// Column statistics per stripe, may be empty if the writer did not write them.
+    stripe_statistics: Vec<Vec<ColumnStatistics>>,
+}
+
+impl OrcMetadata {
+    fn read<R: Read + Seek>(reader: &mut R) -> PolarsResult<Self> {
+        let file_len = reader.seek(SeekFrom::End(0))?;
+        polars_ensure!(file_len > ORC_MAGIC.len() as u64 + 1, ComputeError: "ORC file is too small");
+
+        let mut header = [0u8; 3];
+        reader.seek(SeekFrom::Start(0))?;
+        reader.read_exact(&mut header)?;
+        polars_ensure!(header == ORC_MAGIC, ComputeError: "not an ORC file: invalid magic bytes");
+
+        reader.seek(SeekFrom::End(-1))?;
+        let mut ps_len = [0u8; 1];
+        reader.read_exact(&mut ps_len)?;
+        let ps_len = ps_len[0] as u64;
+        polars_ensure!(ps_len + 1 < file_len, ComputeError: "out of spec ORC: invalid postscript length");
+
+        let mut postscript = vec![0u8; ps_len as usize];
+        reader.seek(SeekFrom::Start(file_len - 1 - ps_len))?;
+        reader.read_exact(&mut postscript)?;
+        let postscript = PostScript::decode(&postscript)?;
+
+        let tail_len = postscript.footer_length + postscript.metadata_length;
+        polars_ensure!(
+            tail_len + ps_len + 1 <= file_len,
+            ComputeError: "out of spec ORC: footer does not fit in the file"
+        );
+        let mut tail = vec![0u8; tail_len as usize];
+        reader.seek(SeekFrom::Start(file_len - 1 - ps_len - tail_len))?;
+        reader.read_exact(&mut tail)?;
+        let (metadata, footer) = tail.split_at(postscript.metadata_length as usize);
+
+        let block_size = postscript.compression_block_size as usize;
+        let footer = Footer::decode(&decompress(postscript.compression, block_size, footer)?)?;
+        let Some(root) = footer.types.first() else {
+            polars_bail!(ComputeError: "out of spec ORC: the footer has no types");
+        };
+        polars_ensure!(
+            root.kind == TypeKind::Struct,
+            ComputeError: "ORC files with a non-struct root type are not supported"
+        );
+        let stripe_statistics = if metadata.is_empty() {
+            vec![]
+        } else {
+            decode_stripe_statistics(&decompress(postscript.compression, block_size, metadata)?)?
+        };
+
+        Ok(Self {
+            postscript,
+            footer,
+            stripe_statistics,
+        })
+    }
+
+    /// The root struct type, whose fields are the columns. Its presence is checked on read.
+    fn root(&self) -> &OrcType {
+        &self.footer.types[0]
+    }
+
+    fn arrow_schema(&self) -> PolarsResult<ArrowSchema> {
+        let root = self.root();
+        let fields = root
+            .subtypes
+            .iter()
+            .zip(&root.field_names)
+            .map(|(column, name)| {
+                Ok(ArrowField::new(
+                    name,
+                    arrow_data_type(&self.footer.types, *column)?,
+                    true,
+                ))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(ArrowSchema::from(fields))
+    }
+}
+
+/// Read [Apache ORC] format into a [`DataFrame`]
+///
+/// Stripes whose statistics show that they cannot match the predicate are skipped.
+///
+/// [Apache ORC]: https://orc.apache.org
+///
+/// # Example
+/// ```
+/// use std::fs::File;
+/// use polars_core::prelude::*;
+/// use polars_io::orc::OrcReader;
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("file.orc").expect("file not found");
+///
+///     OrcReader::new(file)
+///             .finish()
+/// }
+/// ```
+#[must_use]
+pub struct OrcReader<R> {
+    reader: R,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    columns: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+    row_index: Option<RowIndex>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    metadata: Option<OrcMetadata>,
+}
+
+impl<R: Read + Seek> OrcReader<R> {
+    fn metadata(&mut self) -> PolarsResult<&OrcMetadata> {
+        if self.metadata.is_none() {
+            self.metadata = Some(OrcMetadata::read(&mut self.reader)?);
+        }
+        Ok(self.metadata.as_ref().unwrap())
+    }
+
+    /// Get schema of the ORC File
+    pub fn schema(&mut self) -> PolarsResult<Schema> {
+        let schema = self.arrow_schema()?;
+        Ok(Schema::from_iter(&schema.fields))
+    }
+
+    /// Get arrow schema of the ORC File, this is faster than a polars schema.
+    pub fn arrow_schema(&mut self) -> PolarsResult<ArrowSchema> {
+        self.metadata()?.arrow_schema()
+    }
+
+    /// Number of rows in the ORC file.
+    pub fn num_rows(&mut self) -> PolarsResult<usize> {
+        Ok(self.metadata()?.footer.number_of_rows as usize)
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Add a row index column.
+    pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
+        self.row_index = row_index;
+        self
+    }
+
+    /// Filter rows with the predicate and skip stripes whose statistics don't match it.
+    pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
+        self.predicate = predicate;
+        self
+    }
+
+    /// Resolve the projection and set up the reader of the projected columns of every stripe.
+    fn into_stripe_reader(mut self) -> PolarsResult<OrcStripeReader<R>> {
+        let schema = self.arrow_schema()?;
+        if let Some(columns) = &self.columns {
+            self.projection = Some(columns_to_projection(columns, &schema)?);
+        }
+        let projection = self
+            .projection
+            .take()
+            .unwrap_or_else(|| (0..schema.fields.len()).collect());
+        let schema = apply_projection(&schema, &projection);
+
+        let metadata = self.metadata.take().unwrap();
+        let root = metadata.root();
+        let columns = projection
+            .iter()
+            .map(|i| {
+                root.subtypes.get(*i).copied().ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "projection index {} is out of bounds for an ORC file with {} columns",
+                        i, root.subtypes.len()
+                    )
+                })
+            })
+            .collect::<PolarsResult<_>>()?;
+
+        // Skipping stripes would shift the row index.
+        let predicate = if self.row_index.is_none() {
+            self.predicate
+        } else {
+            None
+        };
+
+        Ok(OrcStripeReader {
+            reader: self.reader,
+            metadata,
+            columns,
+            schema,
+            predicate,
+            next_stripe: 0,
+        })
+    }
+
+    /// Read the file one stripe at a time, see [`BatchedOrcReader`].
+    pub fn batched(self) -> PolarsResult<BatchedOrcReader<R>> {
+        let n_rows = self.n_rows;
+        let row_index = self.row_index.clone();
+        let predicate = self.predicate.clone();
+        Ok(BatchedOrcReader {
+            stripes: self.into_stripe_reader()?,
+            remaining: n_rows,
+            row_index,
+            predicate,
+            rows_read: 0,
+        })
+    }
+}
+
+impl<R> SerReader<R> for OrcReader<R>
+where
+    R: Read + Seek,
+{
+    fn new(reader: R) -> Self {
+        OrcReader {
+            reader,
+            rechunk: true,
+            n_rows: None,
+            columns: None,
+            projection: None,
+            row_index: None,
+            predicate: None,
+            metadata: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(self) -> PolarsResult<DataFrame> {
+        let rechunk = self.rechunk;
+        let n_rows = self.n_rows;
+        let row_index = self.row_index.clone();
+        let predicate = self.predicate.clone();
+        let stripes = self.into_stripe_reader()?;
+        let schema = stripes.schema.clone();
+        finish_reader(stripes, rechunk, n_rows, predicate, &schema, row_index)
+    }
+}
+
+/// Reads an ORC file into a [`DataFrame`] per stripe, so that files larger than memory can be
+/// processed by the streaming engine.
+///
+/// The projection, predicate, row index and row limit of the [`OrcReader`] it is created from
+/// are applied to every batch. Stripes that are skipped by their statistics or filtered out
+/// entirely produce no batch.
+pub struct BatchedOrcReader<R> {
+    stripes: OrcStripeReader<R>,
+    /// Rows that may still be produced.
+    remaining: Option<usize>,
+    row_index: Option<RowIndex>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    /// Rows read before the predicate, which the row index counts.
+    rows_read: usize,
+}
+
+impl<R: Read + Seek> BatchedOrcReader<R> {
+    /// Schema of the batches, without the row index.
+    pub fn schema(&self) -> &ArrowSchema {
+        &self.stripes.schema
+    }
+
+    pub fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        loop {
+            if self.remaining == Some(0) {
+                return Ok(None);
+            }
+            let Some(batch) = self.stripes.next_record_batch()? else {
+                return Ok(None);
+            };
+            let offset = self.rows_read as IdxSize;
+            self.rows_read += batch.len();
+            let mut df = DataFrame::try_from((batch, self.stripes.schema.fields.as_slice()))?;
+
+            if let Some(rc) = &self.row_index {
+                df.with_row_index_mut(&rc.name, Some(offset + rc.offset));
+            }
+            if let Some(predicate) = &self.predicate {
+                let s = predicate.evaluate_io(&df)?;
+                let mask = s.bool().map_err(
+                    |_| polars_err!(ComputeError: "filter predicate was not of type boolean"),
+                )?;
+                df = df.filter(mask)?;
+            }
+            if df.height() == 0 {
+                continue;
+            }
+            if let Some(remaining) = &mut self.remaining {
+                df = df.head(Some(*remaining));
+                *remaining -= df.height();
+            }
+            return Ok(Some(df));
+        }
+    }
+}
+
+/// Reads the projected columns of an ORC file one stripe at a time.
+struct OrcStripeReader<R> {
+    reader: R,
+    metadata: OrcMetadata,
+    /// Type ids of the projected top-level columns.
+    columns: Vec<u32>,
+    schema: ArrowSchema,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    next_stripe: usize,
+}
+
+impl<R: Read + Seek> OrcStripeReader<R> {
+    fn decompress(&self, data: &[u8]) -> PolarsResult<Vec<u8>> {
+        let ps = &self.metadata.postscript;
+        Ok(decompress(ps.compression, ps.compression_block_size as usize, data)?.into_owned())
+    }
+
+    fn read_range(&mut self, offset: u64, len: u64) -> PolarsResult<Vec<u8>> {
+        let mut buf = vec![0u8; len as usize];
+        self.reader.seek(SeekFrom::Start(offset))?;
+        self.reader.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Build the [`BatchStats`] of the projected columns in a stripe.
+    fn stripe_stats(&self, stripe: usize) -> PolarsResult<Option<BatchStats>> {
+        let Some(column_stats) = self.metadata.stripe_statistics.get(stripe) else {
+            return Ok(None);
+        };
+        let num_rows = self.metadata.footer.stripes[stripe].number_of_rows;
+        let types = &self.metadata.footer.types;
+
+        let stats = self
+            .columns
+            .iter()
+            .zip(&self.schema.fields)
+            .map(|(column, field)| {
+                let field: Field = field.into();
+                let Some(st) = column_stats.get(*column as usize) else {
+                    return Ok(ColumnStats::from_field(field));
+                };
+                let null_count = st
+                    .number_of_values
+                    .map(|n| Series::new("", [num_rows.saturating_sub(n) as IdxSize]));
+                let (min, max) = match types[*column as usize].kind {
+                    TypeKind::Byte | TypeKind::Short | TypeKind::Int | TypeKind::Long => {
+                        let (min, max) = st.int.unwrap_or_default();
+                        (
+                            min.map(|v| Series::new("", [v])),
+                            max.map(|v| Series::new("", [v])),
+                        )
+                    },
+                    TypeKind::Float | TypeKind::Double => {
+                        let (min, max) = st.double.unwrap_or_default();
+                        (
+                            min.map(|v| Series::new("", [v])),
+                            max.map(|v| Series::new("", [v])),
+                        )
+                    },
+                    TypeKind::String | TypeKind::Varchar | TypeKind::Char => {
+                        let (min, max) = st.string.clone().unwrap_or_default();
+                        (
+                            min.map(|v| Series::new("", [v.as_str()])),
+                            max.map(|v| Series::new("", [v.as_str()])),
+                        )
+                    },
+                    TypeKind::Date => {
+                        let (min, max) = st.date.unwrap_or_default();
+                        (
+                            min.map(|v| Series::new("", [v])),
+                            max.map(|v| Series::new("", [v])),
+                        )
+                    },
+                    _ => (None, None),
+                };
+                let cast = |s: Option<Series>| -> PolarsResult<Option<Series>> {
+                    s.map(|s| s.cast(field.data_type())).transpose()
+                };
+                let (min, max) = (cast(min)?, cast(max)?);
+                Ok(ColumnStats::new(field, null_count, min, max))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(Some(BatchStats::new(
+            Arc::new((&self.schema).into()),
+            stats,
+            Some(num_rows as usize),
+        )))
+    }
+
+    fn read_this_stripe(&self, stripe: usize) -> PolarsResult<bool> {
+        if let Some(pred) = self.predicate.as_deref() {
+            if let Some(pred) = pred.as_stats_evaluator() {
+                if let Some(stats) = self.stripe_stats(stripe)? {
+                    let should_read = pred.should_read(&stats);
+                    if matches!(should_read, Ok(false)) {
+                        return Ok(false);
+                    } else if !matches!(should_read, Err(PolarsError::ColumnNotFound(_))) {
+                        let _ = should_read?;
+                    }
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn read_stripe(&mut self, stripe: usize) -> PolarsResult<RecordBatch> {
+        let info = &self.metadata.footer.stripes[stripe];
+        let footer_offset = info.offset + info.index_length + info.data_length;
+        let (mut offset, n, footer_length) = (
+            info.offset,
+            info.number_of_rows as usize,
+            info.footer_length,
+        );
+        let footer = self.read_range(footer_offset, footer_length)?;
+        let footer = StripeFooter::decode(&self.decompress(&footer)?)?;
+
+        let mut needed = Vec::new();
+        for column in &self.columns {
+            collect_column_ids(&self.metadata.footer.types, *column, &mut needed);
+        }
+        let needed = needed.into_iter().collect::<PlHashSet<_>>();
+
+        // Streams are stored back to back, starting with the index streams.
+        let mut streams = PlHashMap::new();
+        for stream in &footer.streams {
+            if stream.kind != StreamKind::Other && needed.contains(&stream.column) {
+                let data = self.read_range(offset, stream.length)?;
+                streams.insert((stream.column, stream.kind), self.decompress(&data)?);
+            }
+            offset += stream.length;
+        }
+
+        let data = StripeData {
+            types: &self.metadata.footer.types,
+            encodings: &footer.columns,
+            streams,
+        };
+        let arrays = self
+            .columns
+            .iter()
+            .map(|column| data.read_column(*column, n, None))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        RecordBatch::try_new(arrays)
+    }
+}
+
+impl<R: Read + Seek> ArrowReader for OrcStripeReader<R> {
+    fn next_record_batch(&mut self) -> PolarsResult<Option<RecordBatch>> {
+        while self.next_stripe < self.metadata.footer.stripes.len() {
+            let stripe = self.next_stripe;
+            self.next_stripe += 1;
+            if self.read_this_stripe(stripe)? {
+                return self.read_stripe(stripe).map(Some);
+            }
+        }
+        Ok(None)
+    }
+}
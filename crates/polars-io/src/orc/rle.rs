@@ -0,0 +1,359 @@
+//! Decoders for the run length encodings used by ORC streams.
+use polars_core::prelude::*;
+
+fn truncated() -> PolarsError {
+    polars_err!(ComputeError: "out of spec ORC: stream ended before all values were decoded")
+}
+
+pub(super) fn read_varint(buf: &[u8], pos: &mut usize) -> PolarsResult<u64> {
+    let mut out = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = *buf.get(*pos).ok_or_else(truncated)?;
+        *pos += 1;
+        polars_ensure!(shift < 64, ComputeError: "out of spec ORC: varint overflows 64 bits");
+        out |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(out);
+        }
+        shift += 7;
+    }
+}
+
+#[inline]
+pub(super) fn zigzag_decode(v: u64) -> i64 {
+    ((v >> 1) as i64) ^ -((v & 1) as i64)
+}
+
+/// Decode a zigzag encoded varint of arbitrary width, as used by decimal streams.
+pub(super) fn read_varint_i128(buf: &[u8], pos: &mut usize) -> PolarsResult<i128> {
+    let mut out = 0u128;
+    let mut shift = 0;
+    loop {
+        let byte = *buf.get(*pos).ok_or_else(truncated)?;
+        *pos += 1;
+        polars_ensure!(shift < 128, ComputeError: "out of spec ORC: decimal does not fit in 128 bits");
+        out |= ((byte & 0x7f) as u128) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(((out >> 1) as i128) ^ -((out & 1) as i128));
+        }
+        shift += 7;
+    }
+}
+
+pub(super) fn decode_byte_rle(data: &[u8], n: usize) -> PolarsResult<Vec<u8>> {
+    let mut out = Vec::with_capacity(n);
+    let mut pos = 0;
+    while out.len() < n {
+        let header = *data.get(pos).ok_or_else(truncated)? as i8;
+        pos += 1;
+        if header >= 0 {
+            let run = header as usize + 3;
+            let value = *data.get(pos).ok_or_else(truncated)?;
+            pos += 1;
+            out.resize(out.len() + run, value);
+        } else {
+            let len = (-(header as isize)) as usize;
+            let literals = data.get(pos..pos + len).ok_or_else(truncated)?;
+            pos += len;
+            out.extend_from_slice(literals);
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+/// Booleans are byte run length encoded with 8 values per byte, most significant bit first.
+pub(super) fn decode_bool_rle(data: &[u8], n: usize) -> PolarsResult<Vec<bool>> {
+    let bytes = decode_byte_rle(data, n.div_ceil(8))?;
+    Ok((0..n)
+        .map(|i| bytes[i / 8] & (0x80 >> (i % 8)) != 0)
+        .collect())
+}
+
+/// Decode `n` integers encoded with integer RLE version 1 or 2.
+pub(super) fn decode_int_rle(
+    data: &[u8],
+    n: usize,
+    signed: bool,
+    v2: bool,
+) -> PolarsResult<Vec<i64>> {
+    let mut out = Vec::with_capacity(n);
+    let mut pos = 0;
+    while out.len() < n {
+        if v2 {
+            decode_rle_v2_run(data, &mut pos, signed, &mut out)?;
+        } else {
+            decode_rle_v1_run(data, &mut pos, signed, &mut out)?;
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+fn read_int(data: &[u8], pos: &mut usize, signed: bool) -> PolarsResult<i64> {
+    let v = read_varint(data, pos)?;
+    Ok(if signed { zigzag_decode(v) } else { v as i64 })
+}
+
+fn decode_rle_v1_run(
+    data: &[u8],
+    pos: &mut usize,
+    signed: bool,
+    out: &mut Vec<i64>,
+) -> PolarsResult<()> {
+    let header = *data.get(*pos).ok_or_else(truncated)? as i8;
+    *pos += 1;
+    if header >= 0 {
+        let run = header as usize + 3;
+        let delta = *data.get(*pos).ok_or_else(truncated)? as i8 as i64;
+        *pos += 1;
+        let base = read_int(data, pos, signed)?;
+        out.extend((0..run as i64).map(|i| base.wrapping_add(i * delta)));
+    } else {
+        for _ in 0..-(header as isize) {
+            out.push(read_int(data, pos, signed)?);
+        }
+    }
+    Ok(())
+}
+
+/// Maps the 5 bit encoded width of RLE v2 to the actual bit width.
+fn decode_bit_width(encoded: u8) -> usize {
+    match encoded {
+        0..=23 => encoded as usize + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+fn closest_fixed_bits(width: usize) -> usize {
+    match width {
+        0 => 1,
+        1..=24 => width,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+/// Read `len` big endian bit packed values of `width` bits. Runs always end on a byte boundary.
+fn read_bit_packed(
+    data: &[u8],
+    pos: &mut usize,
+    width: usize,
+    len: usize,
+    out: &mut Vec<u64>,
+) -> PolarsResult<()> {
+    let n_bytes = (width * len).div_ceil(8);
+    let bytes = data.get(*pos..*pos + n_bytes).ok_or_else(truncated)?;
+    *pos += n_bytes;
+
+    let mut bit = 0;
+    for _ in 0..len {
+        let mut v = 0u64;
+        let mut remaining = width;
+        while remaining > 0 {
+            let byte = bytes[bit / 8];
+            let offset = bit % 8;
+            let take = remaining.min(8 - offset);
+            let bits = (byte >> (8 - offset - take)) & (((1u16 << take) - 1) as u8);
+            v = (v << take) | bits as u64;
+            remaining -= take;
+            bit += take;
+        }
+        out.push(v);
+    }
+    Ok(())
+}
+
+fn read_be(data: &[u8], pos: &mut usize, n_bytes: usize) -> PolarsResult<u64> {
+    let bytes = data.get(*pos..*pos + n_bytes).ok_or_else(truncated)?;
+    *pos += n_bytes;
+    Ok(bytes.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64))
+}
+
+fn decode_rle_v2_run(
+    data: &[u8],
+    pos: &mut usize,
+    signed: bool,
+    out: &mut Vec<i64>,
+) -> PolarsResult<()> {
+    let first = *data.get(*pos).ok_or_else(truncated)?;
+    *pos += 1;
+    let decode = |v: u64| if signed { zigzag_decode(v) } else { v as i64 };
+
+    match first >> 6 {
+        // Short repeat.
+        0 => {
+            let width = ((first >> 3) & 0x7) as usize + 1;
+            let run = (first & 0x7) as usize + 3;
+            let value = decode(read_be(data, pos, width)?);
+            out.resize(out.len() + run, value);
+        },
+        // Direct.
+        1 => {
+            let width = decode_bit_width((first >> 1) & 0x1f);
+            let second = *data.get(*pos).ok_or_else(truncated)?;
+            *pos += 1;
+            let len = (((first & 1) as usize) << 8 | second as usize) + 1;
+            let mut values = Vec::with_capacity(len);
+            read_bit_packed(data, pos, width, len, &mut values)?;
+            out.extend(values.into_iter().map(decode));
+        },
+        // Patched base.
+        2 => {
+            let width = decode_bit_width((first >> 1) & 0x1f);
+            let header = data.get(*pos..*pos + 3).ok_or_else(truncated)?;
+            *pos += 3;
+            let len = (((first & 1) as usize) << 8 | header[0] as usize) + 1;
+            let base_width = ((header[1] >> 5) & 0x7) as usize + 1;
+            let patch_width = decode_bit_width(header[1] & 0x1f);
+            let gap_width = ((header[2] >> 5) & 0x7) as usize + 1;
+            let patch_len = (header[2] & 0x1f) as usize;
+            polars_ensure!(
+                width + patch_width <= 64,
+                ComputeError: "out of spec ORC: patched values exceed 64 bits"
+            );
+
+            let base = read_be(data, pos, base_width)?;
+            let sign_mask = 1u64 << (base_width * 8 - 1);
+            let base = if base & sign_mask != 0 {
+                -((base & !sign_mask) as i64)
+            } else {
+                base as i64
+            };
+
+            let mut values = Vec::with_capacity(len);
+            read_bit_packed(data, pos, width, len, &mut values)?;
+            let mut patches = Vec::with_capacity(patch_len);
+            read_bit_packed(
+                data,
+                pos,
+                closest_fixed_bits(gap_width + patch_width),
+                patch_len,
+                &mut patches,
+            )?;
+
+            let patch_mask = (1u64 << patch_width) - 1;
+            let mut idx = 0;
+            for entry in patches {
+                idx += (entry >> patch_width) as usize;
+                let patch = entry & patch_mask;
+                // A gap of 255 with an empty patch only advances the position.
+                if patch == 0 {
+                    continue;
+                }
+                let value = values.get_mut(idx).ok_or_else(
+                    || polars_err!(ComputeError: "out of spec ORC: patch outside of run"),
+                )?;
+                *value |= patch << width;
+            }
+            out.extend(values.into_iter().map(|v| base.wrapping_add(v as i64)));
+        },
+        // Delta.
+        _ => {
+            let encoded_width = (first >> 1) & 0x1f;
+            let second = *data.get(*pos).ok_or_else(truncated)?;
+            *pos += 1;
+            let len = (((first & 1) as usize) << 8 | second as usize) + 1;
+            let base = read_int(data, pos, signed)?;
+            let delta_base = read_int(data, pos, true)?;
+
+            out.push(base);
+            if len == 1 {
+                return Ok(());
+            }
+            let mut prev = base.wrapping_add(delta_base);
+            out.push(prev);
+
+            if encoded_width == 0 {
+                for _ in 2..len {
+                    prev = prev.wrapping_add(delta_base);
+                    out.push(prev);
+                }
+            } else {
+                let mut deltas = Vec::with_capacity(len - 2);
+                read_bit_packed(
+                    data,
+                    pos,
+                    decode_bit_width(encoded_width),
+                    len - 2,
+                    &mut deltas,
+                )?;
+                for delta in deltas {
+                    prev = if delta_base < 0 {
+                        prev.wrapping_sub(delta as i64)
+                    } else {
+                        prev.wrapping_add(delta as i64)
+                    };
+                    out.push(prev);
+                }
+            }
+        },
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rle_v2_spec_examples() {
+        // Examples from the ORC specification.
+        let short_repeat = [0x0a, 0x27, 0x10];
+        assert_eq!(
+            decode_int_rle(&short_repeat, 5, false, true).unwrap(),
+            [10000; 5]
+        );
+
+        let direct = [0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef];
+        assert_eq!(
+            decode_int_rle(&direct, 4, false, true).unwrap(),
+            [23713, 43806, 57005, 48879]
+        );
+
+        let delta = [0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46];
+        assert_eq!(
+            decode_int_rle(&delta, 10, false, true).unwrap(),
+            [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+
+        let patched_base = [
+            0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c, 0x46,
+            0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe, 0xfc, 0xe8,
+        ];
+        let mut expected = vec![2030, 2000, 2020, 1000000];
+        expected.extend((2040..=2190).step_by(10));
+        assert_eq!(
+            decode_int_rle(&patched_base, 20, false, true).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_rle_v1_and_byte_rle() {
+        // A run of 100 sevens and a run of literals.
+        let data = [0x61, 0x00, 0x07, 0xfb, 0x02, 0x03, 0x06, 0x07, 0x0b];
+        let mut expected = vec![7; 100];
+        expected.extend([2, 3, 6, 7, 11]);
+        assert_eq!(decode_int_rle(&data, 105, false, false).unwrap(), expected);
+
+        let bytes = [0x61, 0x00, 0xfe, 0x44, 0x45];
+        let mut expected = vec![0; 100];
+        expected.extend([0x44, 0x45]);
+        assert_eq!(decode_byte_rle(&bytes, 102).unwrap(), expected);
+    }
+}
@@ -16,7 +16,7 @@ use tokio::sync::Mutex;
 use super::mmap::ColumnStore;
 use super::predicates::read_this_row_group;
 use super::read_impl::compute_row_group_range;
-use crate::cloud::{build_object_store, CloudLocation, CloudOptions, PolarsObjectStore};
+use crate::cloud::{build_object_store, CloudOptions, PolarsObjectStore};
 use crate::parquet::metadata::FileMetaDataRef;
 use crate::pl_async::get_runtime;
 use crate::predicates::PhysicalIoExpr;
@@ -38,20 +38,18 @@ impl ParquetObjectStore {
         options: Option<&CloudOptions>,
         metadata: Option<FileMetaDataRef>,
     ) -> PolarsResult<Self> {
-        let (
-            CloudLocation {
-                prefix, expansion, ..
-            },
-            store,
-        ) = build_object_store(uri, options).await?;
+        let (location, store) = build_object_store(uri, options).await?;
 
         // Any wildcards should already have been resolved here. Without this assertion they would
         // be ignored.
-        debug_assert!(expansion.is_none(), "path should not contain wildcards");
-        let path = ObjectPath::from_url_path(prefix).map_err(to_compute_err)?;
+        debug_assert!(
+            location.expansion.is_none(),
+            "path should not contain wildcards"
+        );
+        let path = ObjectPath::from_url_path(&location.prefix).map_err(to_compute_err)?;
 
         Ok(ParquetObjectStore {
-            store: PolarsObjectStore::new(store),
+            store: PolarsObjectStore::new(store).with_cache_url(&location),
             path,
             length: None,
             metadata,
@@ -291,7 +289,7 @@ impl FetchRowGroupsFromObjectStore {
                 .enumerate()
                 .filter(|(i, rg)| {
                     let should_be_read =
-                        matches!(read_this_row_group(Some(pred), rg, &schema), Ok(true));
+                        matches!(read_this_row_group(Some(pred), rg, &schema, None), Ok(true));
 
                     // Already add the row groups that will be skipped to the prefetched data.
                     if !should_be_read {
@@ -0,0 +1,262 @@
+//! Heavy columns that stay in their Parquet file until they are used.
+//!
+//! A frame that carries a large column through many transformations moves its values around at
+//! every filter, sort or join. With [`LazyColumns`] the frame carries the row positions of the
+//! file instead, and the values are only read, from the row groups that hold the remaining rows,
+//! by [`LazyColumns::materialize`].
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+use arrow::datatypes::ArrowSchemaRef;
+use polars_core::prelude::*;
+use polars_core::POOL;
+use polars_parquet::read;
+use rayon::prelude::*;
+
+use super::mmap::ColumnStore;
+use super::read_impl::column_idx_to_series;
+use super::reader::ParquetReader;
+use crate::mmap::ReaderBytes;
+use crate::parquet::metadata::FileMetaDataRef;
+use crate::prelude::SerReader;
+use crate::utils::columns_to_projection;
+use crate::RowIndex;
+
+/// Name of the column that holds the row positions in the file, in place of the lazy columns.
+pub const LAZY_COLUMNS_POSITION: &str = "__POLARS_LAZY_COLUMNS_POSITION";
+
+/// Columns of a Parquet file that are read when they are materialized, see the
+/// [module docs](self).
+#[derive(Clone, Debug)]
+pub struct LazyColumns {
+    path: PathBuf,
+    metadata: FileMetaDataRef,
+    file_schema: ArrowSchemaRef,
+    /// Indices of the lazy columns in the file schema.
+    projection: Vec<usize>,
+    /// The first row of every row group, followed by the number of rows of the file.
+    row_group_offsets: Vec<usize>,
+}
+
+impl LazyColumns {
+    /// Keep `columns` of the Parquet file at `path` in the file.
+    pub fn try_new(path: impl Into<PathBuf>, columns: &[String]) -> PolarsResult<Self> {
+        let path = path.into();
+        let metadata = Arc::new(read::read_metadata(&mut polars_utils::open_file(&path)?)?);
+        let file_schema = Arc::new(read::infer_schema(&metadata)?);
+        let projection = columns_to_projection(columns, &file_schema)?;
+        Ok(Self::new(path, metadata, file_schema, projection))
+    }
+
+    /// Keep the columns of the Parquet file at `path` that take up at least `min_size` bytes,
+    /// uncompressed, in the file.
+    pub fn try_new_large(path: impl Into<PathBuf>, min_size: usize) -> PolarsResult<Self> {
+        let path = path.into();
+        let metadata = Arc::new(read::read_metadata(&mut polars_utils::open_file(&path)?)?);
+        let file_schema = Arc::new(read::infer_schema(&metadata)?);
+        let projection = file_schema
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| {
+                let size: i64 = metadata
+                    .row_groups
+                    .iter()
+                    .flat_map(|md| read::get_field_columns(md.columns(), &field.name))
+                    .map(|column| column.uncompressed_size())
+                    .sum();
+                size as usize >= min_size
+            })
+            .map(|(i, _)| i)
+            .collect();
+        Ok(Self::new(path, metadata, file_schema, projection))
+    }
+
+    fn new(
+        path: PathBuf,
+        metadata: FileMetaDataRef,
+        file_schema: ArrowSchemaRef,
+        projection: Vec<usize>,
+    ) -> Self {
+        let mut row_group_offsets = Vec::with_capacity(metadata.row_groups.len() + 1);
+        let mut offset = 0;
+        row_group_offsets.push(offset);
+        for md in &metadata.row_groups {
+            offset += md.num_rows();
+            row_group_offsets.push(offset);
+        }
+        Self {
+            path,
+            metadata,
+            file_schema,
+            projection,
+            row_group_offsets,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Names of the lazy columns.
+    pub fn names(&self) -> Vec<&str> {
+        self.projection
+            .iter()
+            .map(|&i| self.file_schema.fields[i].name.as_str())
+            .collect()
+    }
+
+    /// [`Schema`] of the lazy columns.
+    pub fn schema(&self) -> Schema {
+        self.projection
+            .iter()
+            .map(|&i| Field::from(&self.file_schema.fields[i]))
+            .collect()
+    }
+
+    /// Read the other columns of the file, with the row positions of the lazy columns in a
+    /// column named [`LAZY_COLUMNS_POSITION`].
+    pub fn read_frame(&self) -> PolarsResult<DataFrame> {
+        let projection = (0..self.file_schema.len())
+            .filter(|i| !self.projection.contains(i))
+            .collect();
+        ParquetReader::new(polars_utils::open_file(&self.path)?)
+            .with_schema(Some(self.file_schema.clone()))
+            .with_projection(Some(projection))
+            .with_row_index(Some(RowIndex {
+                name: Arc::from(LAZY_COLUMNS_POSITION),
+                offset: 0,
+            }))
+            .finish()
+    }
+
+    /// Replace the [`LAZY_COLUMNS_POSITION`] column of `df` by the lazy columns.
+    ///
+    /// Null positions, as introduced by outer joins, become null values.
+    pub fn materialize(&self, df: &DataFrame) -> PolarsResult<DataFrame> {
+        let index = df.try_get_column_index(LAZY_COLUMNS_POSITION)?;
+        let columns = self.take(df.get_columns()[index].idx()?)?;
+        let mut out = df.drop(LAZY_COLUMNS_POSITION)?;
+        for (i, s) in columns.into_iter().enumerate() {
+            out.insert_column(index + i, s)?;
+        }
+        Ok(out)
+    }
+
+    fn row_group_of(&self, position: usize) -> usize {
+        self.row_group_offsets
+            .partition_point(|&offset| offset <= position)
+            - 1
+    }
+
+    /// The values of the lazy columns at the given row positions in the file.
+    pub fn take(&self, positions: &IdxCa) -> PolarsResult<Vec<Series>> {
+        let n_rows = *self.row_group_offsets.last().unwrap();
+        let mut needed = vec![false; self.metadata.row_groups.len()];
+        for position in positions.into_iter().flatten() {
+            let position = position as usize;
+            polars_ensure!(
+                position < n_rows,
+                OutOfBounds: "row position {} is out of bounds for a file with {} rows",
+                position, n_rows
+            );
+            needed[self.row_group_of(position)] = true;
+        }
+        let row_groups = (0..needed.len()).filter(|&i| needed[i]).collect::<Vec<_>>();
+
+        // Where the rows of every needed row group start in the columns that are read.
+        let mut read_offsets = vec![0; needed.len()];
+        let mut offset = 0;
+        for &i in &row_groups {
+            read_offsets[i] = offset;
+            offset += self.metadata.row_groups[i].num_rows();
+        }
+        let indices: IdxCa = positions
+            .into_iter()
+            .map(|opt_position| {
+                opt_position.map(|position| {
+                    let position = position as usize;
+                    let i = self.row_group_of(position);
+                    (read_offsets[i] + position - self.row_group_offsets[i]) as IdxSize
+                })
+            })
+            .collect();
+
+        // The categories of the row groups have to be in the same string cache.
+        #[cfg(feature = "dtype-categorical")]
+        let _sc = polars_core::StringCacheHolder::hold();
+
+        let mut file = polars_utils::open_file(&self.path)?;
+        let reader = ReaderBytes::from(&mut file);
+        let store = ColumnStore::Local(reader.deref());
+        let buffers = read::DecompressionBufferPool::default();
+
+        POOL.install(|| {
+            self.projection
+                .par_iter()
+                .map(|&column_i| {
+                    let field = &self.file_schema.fields[column_i];
+                    if row_groups.is_empty() {
+                        let dtype = DataType::from_arrow(field.data_type(), true);
+                        return Ok(Series::full_null(&field.name, indices.len(), &dtype));
+                    }
+
+                    let mut parts = row_groups.iter().map(|&i| {
+                        let md = &self.metadata.row_groups[i];
+                        column_idx_to_series(
+                            column_i,
+                            md,
+                            md.num_rows(),
+                            &self.file_schema,
+                            &store,
+                            md.num_rows(),
+                            &buffers,
+                        )
+                    });
+                    let mut s = parts.next().unwrap()?;
+                    for part in parts {
+                        s.append(&part?)?;
+                    }
+                    s.take(&indices)
+                })
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+    use crate::prelude::ParquetWriter;
+
+    #[test]
+    fn test_lazy_columns() -> PolarsResult<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("lazy.parquet");
+        let mut df = df![
+            "id" => (0..10).collect::<Vec<i32>>(),
+            "blob" => (0..10).map(|i| "x".repeat(100 * i)).collect::<Vec<_>>(),
+        ]?;
+        ParquetWriter::new(std::fs::File::create(&path)?)
+            .with_row_group_size(Some(3))
+            .finish(&mut df)?;
+
+        let lazy = LazyColumns::try_new_large(&path, 1000)?;
+        assert_eq!(lazy.names(), ["blob"]);
+
+        let frame = lazy.read_frame()?;
+        assert_eq!(frame.get_column_names(), ["id", LAZY_COLUMNS_POSITION]);
+        // Only rows of the second and the last row group remain.
+        let mask: BooleanChunked = frame
+            .column("id")?
+            .i32()?
+            .into_iter()
+            .map(|id| matches!(id, Some(4 | 9)))
+            .collect();
+        let out = lazy.materialize(&frame.filter(&mask)?.reverse())?;
+        assert!(out.equals(&df.filter(&mask)?.reverse()));
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@ use polars_core::datatypes::PlHashMap;
 use polars_error::PolarsResult;
 use polars_parquet::read::{
     column_iter_to_arrays, get_field_columns, ArrayIter, BasicDecompressor, ColumnChunkMetaData,
-    PageReader,
+    DecompressionBufferPool, PageReader,
 };
 
 /// Store columns data in two scenarios:
@@ -66,6 +66,7 @@ pub(super) fn to_deserializer<'a>(
     field: Field,
     num_rows: usize,
     chunk_size: Option<usize>,
+    buffers: &DecompressionBufferPool,
 ) -> PolarsResult<ArrayIter<'a>> {
     let chunk_size = chunk_size.unwrap_or(usize::MAX).min(num_rows);
 
@@ -80,7 +81,7 @@ pub(super) fn to_deserializer<'a>(
                 usize::MAX,
             );
             (
-                BasicDecompressor::new(pages, vec![]),
+                BasicDecompressor::new_pooled(pages, buffers.clone()),
                 &column_meta.descriptor().descriptor.primitive_type,
             )
         })
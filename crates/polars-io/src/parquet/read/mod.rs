@@ -16,6 +16,7 @@
 
 #[cfg(feature = "cloud")]
 mod async_impl;
+mod lazy_columns;
 mod mmap;
 mod options;
 mod predicates;
@@ -24,7 +25,9 @@ mod reader;
 mod to_metadata;
 mod utils;
 
+pub use lazy_columns::{LazyColumns, LAZY_COLUMNS_POSITION};
 pub use options::{ParallelStrategy, ParquetOptions};
+pub use predicates::{row_group_pruning, RowGroupPruning};
 #[cfg(feature = "cloud")]
 pub use reader::ParquetAsyncReader;
 pub use reader::{BatchedParquetReader, ParquetReader};
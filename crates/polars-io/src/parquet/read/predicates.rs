@@ -3,6 +3,7 @@ use polars_core::prelude::*;
 use polars_parquet::read::statistics::{deserialize, Statistics};
 use polars_parquet::read::RowGroupMetaData;
 
+use crate::parquet::metadata::FileMetaData;
 use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
 
 impl ColumnStats {
@@ -17,9 +18,13 @@ impl ColumnStats {
 }
 
 /// Collect the statistics in a column chunk.
+///
+/// With a `row_index`, the name of the row index and its value at the first row of the row
+/// group, the row index gets statistics as well, so that row ranges select row groups.
 pub(crate) fn collect_statistics(
     md: &RowGroupMetaData,
     schema: &ArrowSchema,
+    row_index: Option<(&str, IdxSize)>,
 ) -> PolarsResult<Option<BatchStats>> {
     let mut stats = vec![];
 
@@ -28,11 +33,25 @@ pub(crate) fn collect_statistics(
         stats.push(ColumnStats::from_arrow_stats(st, field));
     }
 
+    let mut schema = Schema::from(schema);
+    if let Some((name, start)) = row_index {
+        if md.num_rows() > 0 && !schema.contains(name) {
+            let end = start + md.num_rows() as IdxSize - 1;
+            schema.with_column(name.into(), IDX_DTYPE);
+            stats.push(ColumnStats::new(
+                Field::new(name, IDX_DTYPE),
+                Some(Series::new("", [0 as IdxSize])),
+                Some(Series::new("", [start])),
+                Some(Series::new("", [end])),
+            ));
+        }
+    }
+
     Ok(if stats.is_empty() {
         None
     } else {
         Some(BatchStats::new(
-            Arc::new(schema.into()),
+            Arc::new(schema),
             stats,
             Some(md.num_rows()),
         ))
@@ -43,10 +62,11 @@ pub(super) fn read_this_row_group(
     predicate: Option<&dyn PhysicalIoExpr>,
     md: &RowGroupMetaData,
     schema: &ArrowSchemaRef,
+    row_index: Option<(&str, IdxSize)>,
 ) -> PolarsResult<bool> {
     if let Some(pred) = predicate {
         if let Some(pred) = pred.as_stats_evaluator() {
-            if let Some(stats) = collect_statistics(md, schema)? {
+            if let Some(stats) = collect_statistics(md, schema, row_index)? {
                 let should_read = pred.should_read(&stats);
                 // a parquet file may not have statistics of all columns
                 if matches!(should_read, Ok(false)) {
@@ -59,3 +79,43 @@ pub(super) fn read_this_row_group(
     }
     Ok(true)
 }
+
+/// How many row groups of a file are pruned by their statistics.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RowGroupPruning {
+    pub read: usize,
+    pub pruned: usize,
+    /// Compressed size of the column chunks that are read.
+    pub bytes_read: u64,
+}
+
+/// Determine which row groups of a file a scan with `predicate` reads, without reading them.
+///
+/// Only the column chunks of `columns` count towards the bytes read; `None` means all columns.
+pub fn row_group_pruning(
+    md: &FileMetaData,
+    predicate: Option<&dyn PhysicalIoExpr>,
+    schema: &ArrowSchemaRef,
+    columns: Option<&[String]>,
+) -> PolarsResult<RowGroupPruning> {
+    let mut out = RowGroupPruning::default();
+    for rg in md.row_groups.iter() {
+        if !read_this_row_group(predicate, rg, schema, None)? {
+            out.pruned += 1;
+            continue;
+        }
+        out.read += 1;
+        out.bytes_read += rg
+            .columns()
+            .iter()
+            .filter(|column| {
+                columns.map_or(true, |columns| {
+                    let name = &column.descriptor().path_in_schema[0];
+                    columns.iter().any(|c| c == name)
+                })
+            })
+            .map(|column| column.compressed_size() as u64)
+            .sum::<u64>();
+    }
+    Ok(out)
+}
@@ -5,9 +5,12 @@ use std::ops::{Deref, Range};
 use arrow::array::new_empty_array;
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_core::utils::{accumulate_dataframes_vertical, split_df};
 use polars_core::POOL;
-use polars_parquet::read::{self, ArrayIter, FileMetaData, PhysicalType, RowGroupMetaData};
+use polars_parquet::read::{
+    self, ArrayIter, DecompressionBufferPool, FileMetaData, PhysicalType, RowGroupMetaData,
+};
 use rayon::prelude::*;
 
 #[cfg(feature = "cloud")]
@@ -49,13 +52,14 @@ fn assert_dtypes(data_type: &ArrowDataType) {
     }
 }
 
-fn column_idx_to_series(
+pub(super) fn column_idx_to_series(
     column_i: usize,
     md: &RowGroupMetaData,
     remaining_rows: usize,
     file_schema: &ArrowSchema,
     store: &mmap::ColumnStore,
     chunk_size: usize,
+    buffers: &DecompressionBufferPool,
 ) -> PolarsResult<Series> {
     let field = &file_schema.fields[column_i];
 
@@ -65,7 +69,13 @@ fn column_idx_to_series(
     }
 
     let columns = mmap_columns(store, md.columns(), &field.name);
-    let iter = mmap::to_deserializer(columns, field.clone(), remaining_rows, Some(chunk_size))?;
+    let iter = mmap::to_deserializer(
+        columns,
+        field.clone(),
+        remaining_rows,
+        Some(chunk_size),
+        buffers,
+    )?;
 
     let mut series = if remaining_rows < md.num_rows() {
         array_iter_to_series(iter, field, Some(remaining_rows))
@@ -188,6 +198,31 @@ pub(crate) fn materialize_hive_partitions(
     }
 }
 
+/// Set the sorted flag of the column by which the writer declared the rows of the row group to
+/// be sorted. The flag is kept over row groups when they are appended and their boundaries are
+/// in order.
+fn set_sorted_from_metadata(df: &mut DataFrame, md: &RowGroupMetaData) {
+    // Only the first sorting column is sorted over all rows.
+    let Some(sorting) = md.sorting_columns().and_then(|columns| columns.first()) else {
+        return;
+    };
+    let Some(column) = md.columns().get(sorting.column_idx) else {
+        return;
+    };
+    let [name] = column.descriptor().path_in_schema.as_slice() else {
+        return;
+    };
+    if let Some(idx) = df.get_column_index(name) {
+        let flag = if sorting.descending {
+            IsSorted::Descending
+        } else {
+            IsSorted::Ascending
+        };
+        // SAFETY: only the sorted flag is changed.
+        unsafe { df.get_columns_mut()[idx].set_sorted_flag(flag) };
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn rg_to_dfs(
     store: &mmap::ColumnStore,
@@ -203,6 +238,7 @@ fn rg_to_dfs(
     projection: &[usize],
     use_statistics: bool,
     hive_partition_columns: Option<&[Series]>,
+    buffers: &DecompressionBufferPool,
 ) -> PolarsResult<Vec<DataFrame>> {
     if let ParallelStrategy::Columns | ParallelStrategy::None = parallel {
         rg_to_dfs_optionally_par_over_columns(
@@ -219,6 +255,7 @@ fn rg_to_dfs(
             projection,
             use_statistics,
             hive_partition_columns,
+            buffers,
         )
     } else {
         rg_to_dfs_par_over_rg(
@@ -234,6 +271,7 @@ fn rg_to_dfs(
             projection,
             use_statistics,
             hive_partition_columns,
+            buffers,
         )
     }
 }
@@ -254,6 +292,7 @@ fn rg_to_dfs_optionally_par_over_columns(
     projection: &[usize],
     use_statistics: bool,
     hive_partition_columns: Option<&[Series]>,
+    buffers: &DecompressionBufferPool,
 ) -> PolarsResult<Vec<DataFrame>> {
     let mut dfs = Vec::with_capacity(row_group_end - row_group_start);
 
@@ -261,8 +300,16 @@ fn rg_to_dfs_optionally_par_over_columns(
         let md = &file_metadata.row_groups[rg_idx];
         let current_row_count = md.num_rows() as IdxSize;
 
+        let rg_row_index = row_index
+            .as_ref()
+            .map(|rc| (rc.name.as_ref(), *previous_row_count + rc.offset));
         if use_statistics
-            && !read_this_row_group(predicate, &file_metadata.row_groups[rg_idx], schema)?
+            && !read_this_row_group(
+                predicate,
+                &file_metadata.row_groups[rg_idx],
+                schema,
+                rg_row_index,
+            )?
         {
             *previous_row_count += current_row_count;
             continue;
@@ -287,6 +334,7 @@ fn rg_to_dfs_optionally_par_over_columns(
                             schema,
                             store,
                             chunk_size,
+                            buffers,
                         )
                     })
                     .collect::<PolarsResult<Vec<_>>>()
@@ -302,6 +350,7 @@ fn rg_to_dfs_optionally_par_over_columns(
                         schema,
                         store,
                         chunk_size,
+                        buffers,
                     )
                 })
                 .collect::<PolarsResult<Vec<_>>>()?
@@ -310,6 +359,7 @@ fn rg_to_dfs_optionally_par_over_columns(
         *remaining_rows -= projection_height;
 
         let mut df = unsafe { DataFrame::new_no_checks(columns) };
+        set_sorted_from_metadata(&mut df, md);
         if let Some(rc) = &row_index {
             df.with_row_index_mut(&rc.name, Some(*previous_row_count + rc.offset));
         }
@@ -347,6 +397,7 @@ fn rg_to_dfs_par_over_rg(
     projection: &[usize],
     use_statistics: bool,
     hive_partition_columns: Option<&[Series]>,
+    buffers: &DecompressionBufferPool,
 ) -> PolarsResult<Vec<DataFrame>> {
     // compute the limits per row group and the row count offsets
     let row_groups = file_metadata
@@ -370,12 +421,16 @@ fn rg_to_dfs_par_over_rg(
         row_groups
             .into_par_iter()
             .map(|(rg_idx, md, projection_height, row_count_start)| {
+                let rg_row_index = row_index
+                    .as_ref()
+                    .map(|rc| (rc.name.as_ref(), row_count_start + rc.offset));
                 if projection_height == 0
                     || use_statistics
                         && !read_this_row_group(
                             predicate,
                             &file_metadata.row_groups[rg_idx],
                             schema,
+                            rg_row_index,
                         )?
                 {
                     return Ok(None);
@@ -397,11 +452,13 @@ fn rg_to_dfs_par_over_rg(
                             schema,
                             store,
                             chunk_size,
+                            buffers,
                         )
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
 
                 let mut df = unsafe { DataFrame::new_no_checks(columns) };
+                set_sorted_from_metadata(&mut df, md);
 
                 if let Some(rc) = &row_index {
                     df.with_row_index_mut(&rc.name, Some(row_count_start as IdxSize + rc.offset));
@@ -501,6 +558,7 @@ pub fn read_parquet<R: MmapBytesReader>(
         &materialized_projection,
         use_statistics,
         hive_partition_columns,
+        &DecompressionBufferPool::default(),
     )?;
 
     if dfs.is_empty() {
@@ -606,6 +664,8 @@ pub struct BatchedParquetReader {
     hive_partition_columns: Option<Arc<[Series]>>,
     /// Has returned at least one materialized frame.
     has_returned: bool,
+    /// The decompression buffers, reused by the batches.
+    buffers: DecompressionBufferPool,
 }
 
 impl BatchedParquetReader {
@@ -660,6 +720,7 @@ impl BatchedParquetReader {
             use_statistics,
             hive_partition_columns: hive_partition_columns.map(Arc::from),
             has_returned: false,
+            buffers: DecompressionBufferPool::default(),
         })
     }
 
@@ -722,6 +783,7 @@ impl BatchedParquetReader {
                     &self.projection,
                     self.use_statistics,
                     self.hive_partition_columns.as_deref(),
+                    &self.buffers,
                 ),
                 #[cfg(feature = "async")]
                 ColumnStore::Fetched(b) => {
@@ -743,6 +805,7 @@ impl BatchedParquetReader {
                     let projection = self.projection.clone();
                     let use_statistics = self.use_statistics;
                     let hive_partition_columns = self.hive_partition_columns.clone();
+                    let buffers = self.buffers.clone();
 
                     let f = move || {
                         let dfs = rg_to_dfs(
@@ -759,6 +822,7 @@ impl BatchedParquetReader {
                             &projection,
                             use_statistics,
                             hive_partition_columns.as_deref(),
+                            &buffers,
                         );
                         tx.send((dfs, rows_read, limit)).unwrap();
                     };
@@ -6,6 +6,8 @@ use polars_parquet::write::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::write_mode::WriteMode;
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetWriteOptions {
@@ -19,6 +21,13 @@ pub struct ParquetWriteOptions {
     pub data_pagesize_limit: Option<usize>,
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// What to do if the file exists. Parquet files cannot be appended to.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub write_mode: WriteMode,
+    /// Declare categorical columns as strings in the file. Their dictionary encoding is kept, so
+    /// string columns that are read as categoricals are written without decoding them.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub categoricals_as_strings: bool,
 }
 
 /// The compression strategy to use for writing Parquet files.
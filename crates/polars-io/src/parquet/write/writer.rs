@@ -27,6 +27,8 @@ pub struct ParquetWriter<W> {
     data_page_size: Option<usize>,
     /// Serialize columns in parallel
     parallel: bool,
+    /// Declare categorical columns as strings in the file
+    categoricals_as_strings: bool,
 }
 
 impl<W> ParquetWriter<W>
@@ -45,6 +47,7 @@ where
             row_group_size: None,
             data_page_size: None,
             parallel: true,
+            categoricals_as_strings: false,
         }
     }
 
@@ -82,12 +85,24 @@ where
         self
     }
 
+    /// Declare categorical columns as strings in the schema of the file, which is how they are
+    /// read back. The values are still written as the dictionary and the keys of the categorical.
+    pub fn with_categoricals_as_strings(mut self, categoricals_as_strings: bool) -> Self {
+        self.categoricals_as_strings = categoricals_as_strings;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, true, "parquet")?;
         let parquet_schema = to_parquet_schema(&schema)?;
         let encodings = get_encodings(&schema);
         let options = self.materialize_options();
-        let writer = Mutex::new(FileWriter::try_new(self.writer, schema, options)?);
+        let file_schema = if self.categoricals_as_strings {
+            categoricals_as_strings(schema)
+        } else {
+            schema
+        };
+        let writer = Mutex::new(FileWriter::try_new(self.writer, file_schema, options)?);
 
         Ok(BatchedWriter {
             writer,
@@ -116,6 +131,18 @@ where
     }
 }
 
+/// Replace the dictionary types of `schema` by their value types, for the schema that is stored
+/// in the metadata of the file. The Parquet schema is the same for both.
+fn categoricals_as_strings(mut schema: ArrowSchema) -> ArrowSchema {
+    for field in schema.fields.iter_mut() {
+        if let ArrowDataType::Dictionary(_, values, _) = &field.data_type {
+            field.data_type = values.as_ref().clone();
+            field.metadata.clear();
+        }
+    }
+    schema
+}
+
 fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
     schema
         .fields
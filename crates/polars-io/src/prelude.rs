@@ -1,13 +1,30 @@
+#[cfg(feature = "clipboard")]
+pub use crate::clipboard::*;
 pub use crate::cloud;
 #[cfg(feature = "csv")]
 pub use crate::csv::{read::*, write::*};
+#[cfg(feature = "database")]
+pub use crate::database::*;
+#[cfg(feature = "delta")]
+pub use crate::delta::*;
+#[cfg(feature = "excel")]
+pub use crate::excel::*;
+#[cfg(feature = "flight")]
+pub use crate::flight::*;
+#[cfg(feature = "iceberg")]
+pub use crate::iceberg::*;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub use crate::ipc::*;
 #[cfg(feature = "json")]
 pub use crate::json::*;
+#[cfg(feature = "kafka")]
+pub use crate::kafka::*;
 #[cfg(feature = "json")]
 pub use crate::ndjson::core::*;
+#[cfg(feature = "orc")]
+pub use crate::orc::*;
 #[cfg(feature = "parquet")]
 pub use crate::parquet::{metadata::*, read::*, write::*};
 pub use crate::shared::{SerReader, SerWriter};
 pub use crate::utils::*;
+pub use crate::write_mode::WriteMode;
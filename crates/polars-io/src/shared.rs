@@ -7,7 +7,13 @@ use arrow::record_batch::RecordBatch;
 use polars_core::prelude::*;
 
 use crate::options::RowIndex;
-#[cfg(any(feature = "ipc", feature = "avro", feature = "ipc_streaming",))]
+#[cfg(any(
+    feature = "ipc",
+    feature = "avro",
+    feature = "ipc_streaming",
+    feature = "orc",
+    feature = "flight"
+))]
 use crate::predicates::PhysicalIoExpr;
 
 pub trait SerReader<R>
@@ -50,7 +56,13 @@ pub trait ArrowReader {
     fn next_record_batch(&mut self) -> PolarsResult<Option<RecordBatch>>;
 }
 
-#[cfg(any(feature = "ipc", feature = "avro", feature = "ipc_streaming",))]
+#[cfg(any(
+    feature = "ipc",
+    feature = "avro",
+    feature = "ipc_streaming",
+    feature = "orc",
+    feature = "flight"
+))]
 pub(crate) fn finish_reader<R: ArrowReader>(
     mut reader: R,
     rechunk: bool,
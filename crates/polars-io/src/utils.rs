@@ -88,7 +88,9 @@ pub fn resolve_homedir(path: &Path) -> PathBuf {
     feature = "ipc",
     feature = "ipc_streaming",
     feature = "parquet",
-    feature = "avro"
+    feature = "avro",
+    feature = "orc",
+    feature = "flight"
 ))]
 pub(crate) fn apply_projection(schema: &ArrowSchema, projection: &[usize]) -> ArrowSchema {
     let fields = &schema.fields;
@@ -103,7 +105,9 @@ pub(crate) fn apply_projection(schema: &ArrowSchema, projection: &[usize]) -> Ar
     feature = "ipc",
     feature = "ipc_streaming",
     feature = "avro",
-    feature = "parquet"
+    feature = "parquet",
+    feature = "orc",
+    feature = "flight"
 ))]
 pub(crate) fn columns_to_projection(
     columns: &[String],
@@ -210,6 +214,42 @@ where
     .collect()
 }
 
+/// Group consecutive files into batches that are read by a single task.
+///
+/// Files are added to a batch until it holds at least `min_rows_per_batch` rows, so many tiny
+/// files don't each get their own task. Files that are large by themselves are never combined.
+/// Returns the number of files in every batch.
+///
+/// This takes an iterator over the number of rows per file.
+pub fn batch_small_files<I>(iter: I, min_rows_per_batch: usize) -> Vec<usize>
+where
+    I: Iterator<Item = usize>,
+{
+    let mut batches = vec![];
+    let mut files_in_batch = 0;
+    let mut rows_in_batch = 0;
+
+    for rows_this_file in iter {
+        if rows_this_file >= min_rows_per_batch && files_in_batch > 0 {
+            batches.push(files_in_batch);
+            files_in_batch = 0;
+            rows_in_batch = 0;
+        }
+        files_in_batch += 1;
+        rows_in_batch += rows_this_file;
+
+        if rows_in_batch >= min_rows_per_batch {
+            batches.push(files_in_batch);
+            files_in_batch = 0;
+            rows_in_batch = 0;
+        }
+    }
+    if files_in_batch > 0 {
+        batches.push(files_in_batch);
+    }
+    batches
+}
+
 #[cfg(feature = "json")]
 pub(crate) fn overwrite_schema(
     schema: &mut Schema,
@@ -396,11 +436,85 @@ pub fn is_cloud_url<P: AsRef<Path>>(p: P) -> bool {
     }
 }
 
+fn local_path_exists(path: &Path) -> PolarsResult<bool> {
+    match std::fs::metadata(resolve_homedir(path)) {
+        Ok(_) => Ok(true),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(err) => {
+            let msg = Some(format!("{}: {}", err, path.display()).into());
+            Err(PolarsError::IO {
+                error: err.into(),
+                msg,
+            })
+        },
+    }
+}
+
+/// Returns the indices of the `paths` that don't exist.
+///
+/// Local files are checked in parallel and cloud objects with concurrent `HEAD` requests, so
+/// that validating hundreds of files doesn't cost a round trip per file.
+pub fn missing_paths(
+    paths: &[PathBuf],
+    #[allow(unused_variables)] cloud_options: Option<&crate::cloud::CloudOptions>,
+) -> PolarsResult<Vec<usize>> {
+    use rayon::prelude::*;
+
+    let exists = if !paths.iter().any(is_cloud_url) {
+        polars_core::POOL.install(|| {
+            paths
+                .par_iter()
+                .map(|path| local_path_exists(path))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?
+    } else {
+        #[cfg(not(feature = "cloud"))]
+        panic!("One or more of the cloud storage features ('aws', 'gcp', ...) must be enabled.");
+
+        #[cfg(feature = "cloud")]
+        {
+            use futures::{StreamExt, TryStreamExt};
+
+            let n_concurrent =
+                polars_core::POOL.current_num_threads() * crate::pl_async::MAX_BUDGET_PER_REQUEST;
+            crate::pl_async::get_runtime().block_on_potential_spawn(async {
+                futures::stream::iter(paths.iter().cloned())
+                    .map(|path| async move {
+                        if !is_cloud_url(&path) {
+                            return local_path_exists(&path);
+                        }
+                        let (location, store) =
+                            crate::cloud::build_object_store(path.to_str().unwrap(), cloud_options)
+                                .await?;
+                        let location = object_store::path::Path::from(location.prefix);
+                        let head =
+                            crate::pl_async::with_concurrency_budget(1, || store.head(&location))
+                                .await;
+                        match head {
+                            Ok(_) => Ok(true),
+                            Err(object_store::Error::NotFound { .. }) => Ok(false),
+                            Err(err) => Err(polars_error::to_compute_err(err)),
+                        }
+                    })
+                    .buffered(n_concurrent)
+                    .try_collect::<Vec<_>>()
+                    .await
+            })?
+        }
+    };
+
+    Ok(exists
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, exists)| (!exists).then_some(i))
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
 
-    use super::{resolve_homedir, FLOAT_RE};
+    use super::{batch_small_files, missing_paths, resolve_homedir, FLOAT_RE};
 
     #[test]
     fn test_float_parse() {
@@ -423,6 +537,31 @@ mod tests {
         assert!(FLOAT_RE.is_match("+7e+05"));
     }
 
+    #[test]
+    fn test_batch_small_files() {
+        let rows = [10, 10, 10, 100, 5, 200, 1, 1];
+        assert_eq!(batch_small_files(rows.into_iter(), 25), [3, 1, 1, 1, 2]);
+        assert_eq!(batch_small_files(rows.into_iter(), 1), [1; 8]);
+        assert!(batch_small_files(std::iter::empty(), 25).is_empty());
+    }
+
+    #[test]
+    fn test_missing_paths() {
+        let dir = std::env::temp_dir().join("polars-test-missing-paths");
+        std::fs::create_dir_all(&dir).unwrap();
+        let existing = dir.join("a.parquet");
+        std::fs::write(&existing, b"").unwrap();
+
+        let paths = [
+            existing.clone(),
+            dir.join("b.parquet"),
+            existing,
+            dir.join("c.parquet"),
+        ];
+        assert_eq!(missing_paths(&paths, None).unwrap(), [1, 3]);
+        assert!(missing_paths(&[], None).unwrap().is_empty());
+    }
+
     #[cfg(not(target_os = "windows"))]
     #[test]
     fn test_resolve_homedir() {
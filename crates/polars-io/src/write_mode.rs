@@ -0,0 +1,185 @@
+//! Writing files without exposing partially written data.
+//!
+//! [`AtomicFileWrite`] writes to a temporary file in the directory of the destination and
+//! moves it into place on [`AtomicFileWrite::commit`]. Readers see either the old file or the
+//! complete new one, and a write that fails half-way leaves the destination untouched.
+use std::fs::{File, OpenOptions};
+use std::io::{self, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use polars_error::{polars_bail, PolarsResult};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// What to do if the file that is written to already exists.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WriteMode {
+    /// Replace the existing file.
+    #[default]
+    Overwrite,
+    /// Raise an error if the file exists.
+    ErrorIfExists,
+    /// Add the new rows after the rows of the existing file. Only file formats that can be
+    /// extended (CSV, NDJSON and IPC) support this.
+    Append,
+}
+
+impl WriteMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Overwrite => "overwrite",
+            Self::ErrorIfExists => "error_if_exists",
+            Self::Append => "append",
+        }
+    }
+
+    /// Raise an error if this mode is `Append` and the file format cannot be appended to.
+    pub fn check_append_supported(&self, file_format: &str) -> PolarsResult<()> {
+        if *self == Self::Append {
+            polars_bail!(InvalidOperation: "cannot append to {} files", file_format)
+        }
+        Ok(())
+    }
+}
+
+fn already_exists(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        format!("file already exists: {}", path.display()),
+    )
+}
+
+/// The temporary file that `path` is written to, hidden and unique within this process.
+fn temp_path(path: &Path) -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    path.with_file_name(format!(".{file_name}.{}-{n}.tmp", std::process::id()))
+}
+
+/// An atomic write of a single file, see the [module docs](self).
+///
+/// The temporary file is removed if this is dropped without being committed.
+#[derive(Debug)]
+pub struct AtomicFileWrite {
+    path: PathBuf,
+    temp_path: PathBuf,
+    mode: WriteMode,
+    /// Handle to the temporary file, used to sync it to disk before it is moved into place.
+    file: File,
+    appending: bool,
+    committed: bool,
+}
+
+impl AtomicFileWrite {
+    /// Start writing `path`, returning the file to write the data to.
+    ///
+    /// When appending to an existing file, the returned file holds a copy of its contents and
+    /// is positioned at its end. Appends from concurrent jobs are not merged: each of them
+    /// atomically replaces the file, so only the last one to commit is kept.
+    pub fn create(path: &Path, mode: WriteMode) -> PolarsResult<(Self, File)> {
+        let exists = path.try_exists()?;
+        if exists && mode == WriteMode::ErrorIfExists {
+            return Err(already_exists(path).into());
+        }
+
+        let temp_path = temp_path(path);
+        let appending = exists && mode == WriteMode::Append;
+        if appending {
+            std::fs::copy(path, &temp_path)?;
+        }
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(!appending)
+            .open(&temp_path)?;
+        let appending = appending && file.seek(SeekFrom::End(0))? > 0;
+
+        let write = Self {
+            path: path.to_path_buf(),
+            temp_path,
+            mode,
+            file: file.try_clone()?,
+            appending,
+            committed: false,
+        };
+        Ok((write, file))
+    }
+
+    /// Whether the data is appended to an existing, non-empty file.
+    pub fn is_appending(&self) -> bool {
+        self.appending
+    }
+
+    /// Move the written file into place.
+    ///
+    /// With [`WriteMode::ErrorIfExists`] this fails if the destination was created after this
+    /// write started.
+    pub fn commit(mut self) -> PolarsResult<()> {
+        self.file.sync_all()?;
+        match self.mode {
+            WriteMode::ErrorIfExists => {
+                // Linking fails instead of replacing an existing file.
+                std::fs::hard_link(&self.temp_path, &self.path).map_err(|e| {
+                    if e.kind() == io::ErrorKind::AlreadyExists {
+                        already_exists(&self.path)
+                    } else {
+                        e
+                    }
+                })?;
+                std::fs::remove_file(&self.temp_path)?;
+            },
+            WriteMode::Overwrite | WriteMode::Append => {
+                std::fs::rename(&self.temp_path, &self.path)?;
+            },
+        }
+        self.committed = true;
+        Ok(())
+    }
+}
+
+impl Drop for AtomicFileWrite {
+    fn drop(&mut self) {
+        if !self.committed {
+            let _ = std::fs::remove_file(&self.temp_path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn test_atomic_file_write() -> PolarsResult<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("out.txt");
+
+        let (write, mut file) = AtomicFileWrite::create(&path, WriteMode::ErrorIfExists)?;
+        file.write_all(b"a")?;
+        assert!(!path.exists());
+        write.commit()?;
+        assert_eq!(std::fs::read(&path)?, b"a");
+
+        let err = AtomicFileWrite::create(&path, WriteMode::ErrorIfExists).unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+
+        let (write, mut file) = AtomicFileWrite::create(&path, WriteMode::Append)?;
+        assert!(write.is_appending());
+        file.write_all(b"b")?;
+        write.commit()?;
+        assert_eq!(std::fs::read(&path)?, b"ab");
+
+        // A write that is not committed leaves the file as it was.
+        let (write, mut file) = AtomicFileWrite::create(&path, WriteMode::Overwrite)?;
+        file.write_all(b"c")?;
+        drop(write);
+        assert_eq!(std::fs::read(&path)?, b"ab");
+        assert_eq!(std::fs::read_dir(dir.path())?.count(), 1);
+        Ok(())
+    }
+}
@@ -12,7 +12,7 @@ pub use fallible_streaming_iterator::*;
 use polars_error::{PolarsError, PolarsResult};
 pub(crate) use serialize::new_serializer;
 use serialize::serialize;
-pub use utf8::serialize_to_utf8;
+pub use utf8::{serialize_to_utf8, write_str};
 
 /// [`FallibleStreamingIterator`] that serializes an [`Array`] to bytes of valid JSON
 /// # Implementation
@@ -138,6 +138,41 @@ impl<'a> FallibleStreamingIterator for RecordSerializer<'a> {
     }
 }
 
+/// Serializes the values of `array` to `buffer`, separated by commas.
+pub fn serialize_array(array: &dyn Array, buffer: &mut Vec<u8>) {
+    serialize(array, buffer)
+}
+
+/// Serializes every row of `arrays` as a JSON array of its values to `buffer`. Rows are
+/// separated by `separator`.
+///
+/// # Panics
+/// Panics if the arrays don't have the same length.
+pub fn serialize_rows(arrays: &[&dyn Array], separator: u8, buffer: &mut Vec<u8>) {
+    let len = arrays.first().map_or(0, |array| array.len());
+    let mut serializers = arrays
+        .iter()
+        .map(|array| {
+            assert_eq!(array.len(), len);
+            new_serializer(*array, 0, usize::MAX)
+        })
+        .collect::<Vec<_>>();
+
+    for i in 0..len {
+        if i != 0 {
+            buffer.push(separator);
+        }
+        buffer.push(b'[');
+        for (j, serializer) in serializers.iter_mut().enumerate() {
+            if j != 0 {
+                buffer.push(b',');
+            }
+            buffer.extend_from_slice(serializer.next().unwrap());
+        }
+        buffer.push(b']');
+    }
+}
+
 /// Writes valid JSON from an iterator of (assumed JSON-encoded) bytes to `writer`
 pub fn write<W, I>(writer: &mut W, mut blocks: I) -> PolarsResult<()>
 where
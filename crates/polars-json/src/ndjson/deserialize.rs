@@ -60,3 +60,20 @@ pub fn deserialize_iter<'a>(
         concatenate_owned_unchecked(&arr)
     }
 }
+
+/// Returns the positions of the rows that are not a valid JSON document on their own.
+///
+/// This parses every row separately, so it is meant to locate the rows that made
+/// [`deserialize_iter`] fail rather than to validate up front.
+pub fn invalid_rows<'a>(rows: impl Iterator<Item = &'a str>) -> Vec<usize> {
+    let mut scratch = Vec::new();
+    rows.enumerate()
+        .filter_map(|(i, row)| {
+            scratch.clear();
+            scratch.extend_from_slice(row.as_bytes());
+            simd_json::to_borrowed_value(&mut scratch)
+                .is_err()
+                .then_some(i)
+        })
+        .collect()
+}
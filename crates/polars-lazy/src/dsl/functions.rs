@@ -7,6 +7,7 @@ pub use polars_plan::dsl::functions::*;
 use polars_plan::prelude::UnionArgs;
 use rayon::prelude::*;
 
+use crate::frame::merge_optimizer_rules;
 use crate::prelude::*;
 
 pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
@@ -23,6 +24,7 @@ pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
 
     let mut opt_state = lf.opt_state;
     let cached_arenas = lf.cached_arena.clone();
+    let mut optimizer_rules = lf.optimizer_rules;
 
     let mut lps = Vec::with_capacity(inputs.len());
     lps.push(lf.logical_plan);
@@ -30,12 +32,18 @@ pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
     for lf in &mut inputs[1..] {
         // ensure we enable file caching if any lf has it enabled
         opt_state.file_caching |= lf.opt_state.file_caching;
+        merge_optimizer_rules(&mut optimizer_rules, &lf.optimizer_rules);
         let lp = std::mem::take(&mut lf.logical_plan);
         lps.push(lp)
     }
 
     let lp = DslPlan::Union { inputs: lps, args };
-    Ok(LazyFrame::from_inner(lp, opt_state, cached_arenas))
+    Ok(LazyFrame::from_inner(
+        lp,
+        opt_state,
+        cached_arenas,
+        optimizer_rules,
+    ))
 }
 
 #[cfg(feature = "diagonal_concat")]
@@ -55,9 +63,15 @@ pub fn concat_lf_horizontal<L: AsRef<[LazyFrame]>>(
     args: UnionArgs,
 ) -> PolarsResult<LazyFrame> {
     let lfs = inputs.as_ref();
-    let (mut opt_state, cached_arena) = lfs
+    let (mut opt_state, cached_arena, mut optimizer_rules) = lfs
         .first()
-        .map(|lf| (lf.opt_state, lf.cached_arena.clone()))
+        .map(|lf| {
+            (
+                lf.opt_state,
+                lf.cached_arena.clone(),
+                lf.optimizer_rules.clone(),
+            )
+        })
         .ok_or_else(
             || polars_err!(NoData: "Require at least one LazyFrame for horizontal concatenation"),
         )?;
@@ -65,6 +79,7 @@ pub fn concat_lf_horizontal<L: AsRef<[LazyFrame]>>(
     for lf in &lfs[1..] {
         // ensure we enable file caching if any lf has it enabled
         opt_state.file_caching |= lf.opt_state.file_caching;
+        merge_optimizer_rules(&mut optimizer_rules, &lf.optimizer_rules);
     }
 
     let options = HConcatOptions {
@@ -74,7 +89,12 @@ pub fn concat_lf_horizontal<L: AsRef<[LazyFrame]>>(
         inputs: lfs.iter().map(|lf| lf.logical_plan.clone()).collect(),
         options,
     };
-    Ok(LazyFrame::from_inner(lp, opt_state, cached_arena))
+    Ok(LazyFrame::from_inner(
+        lp,
+        opt_state,
+        cached_arena,
+        optimizer_rules,
+    ))
 }
 
 /// Concat multiple [`LazyFrame`]s vertically.
@@ -92,12 +112,65 @@ where
     polars_core::POOL.install(|| iter.map(|lf| lf.collect()).collect())
 }
 
+/// Look up the value of every key in a small dimension table, e.g. the label of a code.
+///
+/// The table is broadcast into the expression as a hash map instead of being joined, so the
+/// lookup works in every context, also within `over` and `list.eval`. The keys of the table
+/// are its column `other_key`, or `key` evaluated on `other` if that is `None`, and must be
+/// unique. A key that isn't in the table gives `default`, or null.
+#[cfg(feature = "replace")]
+pub fn lookup(
+    other: &DataFrame,
+    key: Expr,
+    value: &str,
+    default: Option<Expr>,
+    other_key: Option<&str>,
+) -> PolarsResult<Expr> {
+    let keys = match other_key {
+        Some(name) => other.column(name)?.clone(),
+        None => {
+            let df = other.clone().lazy().select([key.clone()]).collect()?;
+            df.get_columns()[0].clone()
+        },
+    };
+    polars_ensure!(
+        keys.n_unique()? == keys.len(),
+        InvalidOperation: "the keys of the `lookup` table must be unique"
+    );
+    let values = other.column(value)?.clone();
+    let dtype = values.dtype().clone();
+    let default = default.unwrap_or_else(|| lit(NULL).cast(dtype.clone()));
+
+    Ok(key.replace_strict(lit(keys), lit(values), Some(default), Some(dtype)))
+}
+
 #[cfg(test)]
 mod test {
-    // used only if feature="diagonal_concat"
+    // used only if feature="diagonal_concat" or feature="replace"
     #[allow(unused_imports)]
     use super::*;
 
+    #[test]
+    #[cfg(feature = "replace")]
+    fn test_lookup() -> PolarsResult<()> {
+        let codes = df![
+            "code" => ["a", "b"],
+            "label" => ["apple", "banana"]
+        ]?;
+        let df = df![
+            "code" => [Some("b"), Some("c"), None, Some("a")]
+        ]?;
+
+        let label = lookup(&codes, col("code"), "label", None, None)?;
+        let out = df.lazy().select([label]).collect()?;
+        let expected = Series::new("code", &[Some("banana"), None, None, Some("apple")]);
+        assert!(out.column("code")?.equals_missing(&expected));
+
+        let dupes = df!["code" => ["a", "a"], "label" => ["x", "y"]]?;
+        assert!(lookup(&dupes, col("code"), "label", None, None).is_err());
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "diagonal_concat")]
     fn test_diag_concat_lf() -> PolarsResult<()> {
@@ -139,6 +139,74 @@ fn run_on_group_by_engine(
     Ok(Some(out.with_name(name)))
 }
 
+/// Reduce every list to a single value with `expr`, run once on the values of all lists with
+/// a group per list.
+fn run_agg_on_group_by_engine(name: &str, lst: &ListChunked, expr: &Expr) -> PolarsResult<Series> {
+    let lst = lst.rechunk();
+    let arr = lst.downcast_iter().next().unwrap();
+    polars_ensure!(
+        lst.get_values_size() <= (IdxSize::MAX as usize),
+        ComputeError: "the lists in `list.agg` have more values than fit in the index type"
+    );
+    let groups = offsets_to_groups(arr.offsets()).unwrap();
+
+    let values = Series::try_from(("", arr.values().clone())).unwrap();
+    let inner_dtype = lst.inner_dtype();
+    // SAFETY:
+    // Invariant in List means values physicals can be cast to inner dtype
+    let values = unsafe { values.cast_unchecked(inner_dtype).unwrap() };
+
+    let df_context = values.into_frame();
+    let phys_expr = prepare_expression_for_context("", expr, inner_dtype, Context::Aggregation)?;
+
+    let state = ExecutionState::new();
+    let ac = phys_expr.evaluate_on_groups(&df_context, &groups, &state)?;
+    let out = match ac.agg_state() {
+        AggState::AggregatedScalar(s) => s.clone(),
+        AggState::Literal(s) if s.len() == 1 => s.new_from_index(0, lst.len()),
+        _ => polars_bail!(
+            InvalidOperation: "the expression in `list.agg` must produce a single value per list; \
+            consider using `list.eval`"
+        ),
+    };
+
+    // Null lists may still span values, their result is null.
+    let out = if lst.null_count() > 0 {
+        let null = Series::full_null(name, lst.len(), out.dtype());
+        out.zip_with(&lst.is_not_null(), &null)?
+    } else {
+        out
+    };
+    Ok(out.with_name(name))
+}
+
+/// Check that `expr` only refers to the list elements.
+fn check_element_expr(expr: &Expr, function: &str) -> PolarsResult<()> {
+    for e in expr.into_iter() {
+        match e {
+            #[cfg(feature = "dtype-categorical")]
+            Expr::Cast {
+                data_type: DataType::Categorical(_, _) | DataType::Enum(_, _),
+                ..
+            } => {
+                polars_bail!(
+                    ComputeError: "casting to categorical not allowed in `{}`", function
+                )
+            },
+            Expr::Column(name) => {
+                polars_ensure!(
+                    name.is_empty(),
+                    ComputeError:
+                    "named columns are not allowed in `{}`; consider using `element` or `col(\"\")`",
+                    function
+                );
+            },
+            _ => {},
+        }
+    }
+    Ok(())
+}
+
 pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
     /// Run any [`Expr`] on these lists elements
     fn eval(self, expr: Expr, parallel: bool) -> Expr {
@@ -146,27 +214,7 @@ pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
 
         let expr2 = expr.clone();
         let func = move |s: Series| {
-            for e in expr.into_iter() {
-                match e {
-                    #[cfg(feature = "dtype-categorical")]
-                    Expr::Cast {
-                        data_type: DataType::Categorical(_, _) | DataType::Enum(_, _),
-                        ..
-                    } => {
-                        polars_bail!(
-                            ComputeError: "casting to categorical not allowed in `list.eval`"
-                        )
-                    },
-                    Expr::Column(name) => {
-                        polars_ensure!(
-                            name.is_empty(),
-                            ComputeError:
-                            "named columns are not allowed in `list.eval`; consider using `element` or `col(\"\")`"
-                        );
-                    },
-                    _ => {},
-                }
-            }
+            check_element_expr(&expr, "list.eval")?;
             let lst = s.list()?.clone();
 
             // # fast returns
@@ -200,6 +248,42 @@ pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
             )
             .with_fmt("eval")
     }
+
+    /// Reduce the elements of every list to a single value with `expr`, e.g.
+    /// `col("").quantile(..)` or `col("").arg_max()`.
+    ///
+    /// Unlike [`eval`](Self::eval), `expr` isn't run per list: it runs once on the values of
+    /// all lists, with a group per list, so that reductions use the segmented kernels of the
+    /// group-by engine. `expr` must produce a single value per list. Null lists give null.
+    fn agg(self, expr: Expr) -> Expr {
+        let this = self.into_list_name_space();
+
+        let expr2 = expr.clone();
+        let func = move |s: Series| {
+            check_element_expr(&expr, "list.agg")?;
+            let lst = s.list()?.clone();
+
+            let output_field = eval_field_to_dtype(lst.ref_field(), &expr, false);
+            if lst.is_empty() {
+                return Ok(Some(Series::new_empty(s.name(), output_field.data_type())));
+            }
+            if lst.null_count() == lst.len() {
+                return Ok(Some(Series::full_null(
+                    s.name(),
+                    lst.len(),
+                    output_field.data_type(),
+                )));
+            }
+            run_agg_on_group_by_engine(s.name(), &lst, &expr).map(Some)
+        };
+
+        this.0
+            .map(
+                func,
+                GetOutput::map_field(move |f| Ok(eval_field_to_dtype(f, &expr2, false))),
+            )
+            .with_fmt("agg")
+    }
 }
 
 impl ListNameSpaceExtension for ListNameSpace {}
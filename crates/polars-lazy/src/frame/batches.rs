@@ -0,0 +1,65 @@
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::thread::JoinHandle;
+
+use polars_core::frame::export_arrow_stream;
+use polars_core::utils::arrow::ffi::ArrowArrayStream;
+
+use super::*;
+
+impl LazyFrame {
+    /// Execute the query with the streaming engine and iterate over the result in batches, as
+    /// they are produced.
+    ///
+    /// The query runs on a background thread that waits for the consumer, so the result doesn't
+    /// have to fit into memory. This returns an error if the query cannot be completely done in
+    /// a streaming fashion.
+    pub fn collect_batches(self) -> PolarsResult<CollectBatches> {
+        // One batch is buffered while the consumer works on the previous one.
+        let (tx, rx) = sync_channel(1);
+        let (mut state, mut physical_plan) =
+            self.prepare_sink(SinkType::Batches(BatchSender::new(tx)), "collect")?;
+
+        let handle = std::thread::spawn(move || -> PolarsResult<()> {
+            physical_plan.execute(&mut state)?;
+            Ok(())
+        });
+        Ok(CollectBatches {
+            rx,
+            handle: Some(handle),
+        })
+    }
+
+    /// Execute the query with the streaming engine and export the result to the
+    /// [Arrow C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html).
+    ///
+    /// The batches are produced as the consumer pulls them, see [`LazyFrame::collect_batches`].
+    pub fn collect_arrow_stream(mut self, pl_flavor: bool) -> PolarsResult<ArrowArrayStream> {
+        let schema = self.schema()?;
+        let batches = self.collect_batches()?;
+        Ok(export_arrow_stream(&schema, batches, pl_flavor))
+    }
+}
+
+/// The result batches of [`LazyFrame::collect_batches`], in order.
+///
+/// Dropping this before all batches are consumed doesn't stop the query, its remaining batches
+/// are discarded.
+pub struct CollectBatches {
+    rx: Receiver<DataFrame>,
+    handle: Option<JoinHandle<PolarsResult<()>>>,
+}
+
+impl Iterator for CollectBatches {
+    type Item = PolarsResult<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.rx.recv() {
+            Ok(df) => Some(Ok(df)),
+            // The sender is dropped once the query is done.
+            Err(_) => match self.handle.take()?.join() {
+                Ok(result) => result.err().map(Err),
+                Err(payload) => std::panic::resume_unwind(payload),
+            },
+        }
+    }
+}
@@ -1,8 +1,8 @@
-use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{channel, Receiver};
 use std::sync::Mutex;
 
 use polars_core::POOL;
+use polars_utils::cancel::CancellationToken;
 
 use super::*;
 
@@ -27,13 +27,18 @@ impl LazyFrame {
 #[derive(Clone)]
 pub struct InProcessQuery {
     rx: Arc<Mutex<Receiver<PolarsResult<DataFrame>>>>,
-    token: Arc<AtomicBool>,
+    token: CancellationToken,
 }
 
 impl InProcessQuery {
     /// Cancel the query at earliest convenience.
     pub fn cancel(&self) {
-        self.token.store(true, Ordering::Relaxed)
+        self.token.cancel()
+    }
+
+    /// The token that cancels this query.
+    pub fn cancel_token(&self) -> CancellationToken {
+        self.token.clone()
     }
 
     /// Fetch the result.
@@ -54,6 +59,6 @@ impl InProcessQuery {
 
 impl Drop for InProcessQuery {
     fn drop(&mut self) {
-        self.token.store(true, Ordering::Relaxed);
+        self.token.cancel();
     }
 }
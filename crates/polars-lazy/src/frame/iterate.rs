@@ -0,0 +1,201 @@
+use super::*;
+
+/// The optimized plan of the step of [`LazyFrame::iterate`], with the subplans that don't
+/// depend on the state materialized.
+struct IterationPlan {
+    lp_top: Node,
+    lp_arena: Arena<IR>,
+    expr_arena: Arena<AExpr>,
+    /// The scans of the state, whose `DataFrame` is replaced by the state of every iteration.
+    state_scans: Vec<Node>,
+}
+
+/// Collect the scans of `state` under `node` and the inputs that don't depend on it, and
+/// return whether `node` depends on it.
+fn find_state_dependence(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    state: &Arc<DataFrame>,
+    state_scans: &mut Vec<Node>,
+    invariant: &mut Vec<Node>,
+) -> bool {
+    let ir = lp_arena.get(node);
+    if let IR::DataFrameScan { df, .. } = ir {
+        if Arc::ptr_eq(df, state) {
+            state_scans.push(node);
+            return true;
+        }
+    }
+    let inputs = ir.get_inputs();
+    let depends = inputs
+        .iter()
+        .map(|&input| find_state_dependence(input, lp_arena, state, state_scans, invariant))
+        .collect::<Vec<_>>();
+    if !depends.iter().any(|&d| d) {
+        return false;
+    }
+    invariant.extend(
+        inputs
+            .iter()
+            .zip(depends)
+            .filter_map(|(&input, d)| (!d).then_some(input)),
+    );
+    true
+}
+
+impl IterationPlan {
+    fn new(step: LazyFrame, placeholder: &Arc<DataFrame>) -> PolarsResult<Self> {
+        let IRPlan {
+            lp_top,
+            mut lp_arena,
+            expr_arena,
+        } = step.to_alp_optimized()?;
+
+        let mut state_scans = vec![];
+        let mut invariant = vec![];
+        if !find_state_dependence(
+            lp_top,
+            &lp_arena,
+            placeholder,
+            &mut state_scans,
+            &mut invariant,
+        ) {
+            invariant.push(lp_top);
+        }
+        invariant.sort_unstable();
+        invariant.dedup();
+
+        // The loop-invariant subplans are only computed once.
+        for node in invariant {
+            let mut arena = lp_arena.clone();
+            let mut executor = create_physical_plan(node, &mut arena, &expr_arena)?;
+            let df = executor.execute(&mut ExecutionState::new())?;
+            let schema = Arc::new(df.schema());
+            lp_arena.replace(
+                node,
+                IR::DataFrameScan {
+                    df: Arc::new(df),
+                    schema,
+                    output_schema: None,
+                    filter: None,
+                },
+            );
+        }
+
+        Ok(Self {
+            lp_top,
+            lp_arena,
+            expr_arena,
+            state_scans,
+        })
+    }
+
+    fn run(&self, state: &Arc<DataFrame>) -> PolarsResult<DataFrame> {
+        let mut lp_arena = self.lp_arena.clone();
+        for &node in &self.state_scans {
+            if let IR::DataFrameScan { df, .. } = lp_arena.get_mut(node) {
+                *df = state.clone();
+            }
+        }
+        let mut executor = create_physical_plan(self.lp_top, &mut lp_arena, &self.expr_arena)?;
+        executor.execute(&mut ExecutionState::new())
+    }
+}
+
+fn is_done(until: Option<&Expr>, previous: &DataFrame, state: &DataFrame) -> PolarsResult<bool> {
+    let Some(until) = until else {
+        return Ok(state.equals_missing(previous));
+    };
+    let out = state.clone().lazy().select([until.clone()]).collect()?;
+    let done = &out.get_columns()[0];
+    polars_ensure!(
+        done.len() == 1,
+        ComputeError: "the `until` condition of `iterate` must produce a single value, got {}", done.len()
+    );
+    Ok(done.bool()?.get(0) == Some(true))
+}
+
+impl LazyFrame {
+    /// Repeatedly apply `step` to a state, starting with `self`, until `until` is true for the
+    /// new state or `max_iterations` steps are done.
+    ///
+    /// The `step` is called once with a [`LazyFrame`] of the state and must return a query of
+    /// the next state, with the same schema. That query is optimized once and then run for every
+    /// iteration. Its parts that don't depend on the state, such as the edges of a graph, are
+    /// only computed in the first iteration.
+    ///
+    /// If `until` is `None`, the iteration stops at a fixed point: once a step returns the same
+    /// rows, in the same order, as it was given. Otherwise `until` is evaluated on every new
+    /// state and must produce a single boolean. The last state is returned if `max_iterations`
+    /// is reached.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// // Double `x` until it is at least 100.
+    /// fn example(df: DataFrame) -> PolarsResult<DataFrame> {
+    ///     df.lazy()
+    ///         .iterate(
+    ///             |lf| Ok(lf.select([col("x") * lit(2)])),
+    ///             Some(col("x").min().gt_eq(lit(100))),
+    ///             1000,
+    ///         )?
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn iterate<F>(
+        mut self,
+        step: F,
+        until: Option<Expr>,
+        max_iterations: usize,
+    ) -> PolarsResult<LazyFrame>
+    where
+        F: FnOnce(LazyFrame) -> PolarsResult<LazyFrame>,
+    {
+        let schema = self.schema()?;
+        // The state is a scan of this `DataFrame` in the query of the step.
+        let placeholder = Arc::new(DataFrame::empty_with_schema(&schema));
+        let state = LazyFrame::from_logical_plan(
+            DslPlan::DataFrameScan {
+                df: placeholder.clone(),
+                schema: schema.clone(),
+                output_schema: None,
+                filter: None,
+            },
+            self.opt_state,
+            self.optimizer_rules.clone(),
+        );
+        let mut step = step(state)?;
+        let step_schema = step.schema()?;
+        polars_ensure!(
+            step_schema == schema,
+            SchemaMismatch: "the step of `iterate` must return the schema of the state\n\nexpected: {:?}\ngot: {:?}",
+            schema, step_schema
+        );
+
+        let function = move |df: DataFrame| {
+            let plan = IterationPlan::new(step.clone(), &placeholder)?;
+            let mut state = Arc::new(df);
+            for _ in 0..max_iterations {
+                let next = Arc::new(plan.run(&state)?);
+                let done = is_done(until.as_ref(), &state, &next)?;
+                state = next;
+                if done {
+                    break;
+                }
+            }
+            Ok(Arc::unwrap_or_clone(state))
+        };
+        // The iterations don't commute with filters or projections on the result.
+        let optimizations = AllowedOptimizations {
+            predicate_pushdown: false,
+            projection_pushdown: false,
+            streaming: false,
+            ..Default::default()
+        };
+        Ok(self.map(function, optimizations, None, Some("ITERATE")))
+    }
+}
@@ -2,42 +2,71 @@
 #[cfg(feature = "python")]
 mod python;
 
+#[cfg(feature = "streaming")]
+mod batches;
 mod cached_arenas;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+mod iterate;
+mod pages;
+mod pipeline;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+mod profile;
+mod watch;
 
 #[cfg(any(
     feature = "parquet",
     feature = "ipc",
+    feature = "ipc_streaming",
     feature = "csv",
     feature = "json"
 ))]
 use std::path::Path;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 pub use anonymous_scan::*;
+#[cfg(feature = "avro")]
+pub use avro::*;
+#[cfg(feature = "streaming")]
+pub use batches::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
+#[cfg(feature = "delta")]
+pub use delta::*;
+#[cfg(feature = "excel")]
+pub use excel::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
+#[cfg(feature = "flight")]
+pub use flight::*;
+#[cfg(feature = "iceberg")]
+pub use iceberg::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
+#[cfg(feature = "kafka")]
+pub use kafka::*;
 #[cfg(feature = "json")]
 pub use ndjson::*;
+#[cfg(feature = "orc")]
+pub use orc::*;
+pub use pages::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
+pub use pipeline::*;
 use polars_core::prelude::*;
 use polars_expr::{create_physical_expr, ExpressionConversionState};
 use polars_io::RowIndex;
-use polars_mem_engine::{create_physical_plan, Executor};
+use polars_mem_engine::{create_analyzed_physical_plan, create_physical_plan, Executor};
 use polars_ops::frame::JoinCoalesce;
+use polars_plan::constants::SET_WHERE_MASK;
 pub use polars_plan::frame::{AllowedOptimizations, OptState};
 use polars_plan::global::FETCH_ROWS;
 use smartstring::alias::String as SmartString;
+pub use watch::*;
 
 use crate::frame::cached_arenas::CachedArena;
 #[cfg(feature = "streaming")]
@@ -56,6 +85,7 @@ impl IntoLazy for DataFrame {
             logical_plan: lp,
             opt_state: Default::default(),
             cached_arena: Default::default(),
+            optimizer_rules: vec![],
         }
     }
 }
@@ -75,6 +105,19 @@ pub struct LazyFrame {
     pub logical_plan: DslPlan,
     pub(crate) opt_state: OptState,
     pub(crate) cached_arena: Arc<Mutex<Option<CachedArena>>>,
+    pub(crate) optimizer_rules: Vec<SharedOptimizationRule>,
+}
+
+/// Add the rules of `other` that are not in `rules` yet, e.g. when two queries are joined.
+pub(crate) fn merge_optimizer_rules(
+    rules: &mut Vec<SharedOptimizationRule>,
+    other: &[SharedOptimizationRule],
+) {
+    for rule in other {
+        if !rules.iter().any(|r| r.ptr_eq(rule)) {
+            rules.push(rule.clone())
+        }
+    }
 }
 
 impl From<DslPlan> for LazyFrame {
@@ -86,6 +129,7 @@ impl From<DslPlan> for LazyFrame {
                 ..Default::default()
             },
             cached_arena: Default::default(),
+            optimizer_rules: vec![],
         }
     }
 }
@@ -95,11 +139,13 @@ impl LazyFrame {
         logical_plan: DslPlan,
         opt_state: OptState,
         cached_arena: Arc<Mutex<Option<CachedArena>>>,
+        optimizer_rules: Vec<SharedOptimizationRule>,
     ) -> Self {
         Self {
             logical_plan,
             opt_state,
             cached_arena,
+            optimizer_rules,
         }
     }
 
@@ -111,11 +157,16 @@ impl LazyFrame {
         self.opt_state
     }
 
-    fn from_logical_plan(logical_plan: DslPlan, opt_state: OptState) -> Self {
+    fn from_logical_plan(
+        logical_plan: DslPlan,
+        opt_state: OptState,
+        optimizer_rules: Vec<SharedOptimizationRule>,
+    ) -> Self {
         LazyFrame {
             logical_plan,
             opt_state,
             cached_arena: Default::default(),
+            optimizer_rules,
         }
     }
 
@@ -150,6 +201,7 @@ impl LazyFrame {
             fast_projection: false,
             row_estimate: false,
             new_streaming: false,
+            join_reorder: false,
         })
     }
 
@@ -220,12 +272,52 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle reordering of inner joins by the estimated size of the scanned files.
+    pub fn with_join_reorder(mut self, toggle: bool) -> Self {
+        self.opt_state.join_reorder = toggle;
+        self
+    }
+
     /// Run every node eagerly. This turns off multi-node optimizations.
     pub fn _with_eager(mut self, toggle: bool) -> Self {
         self.opt_state.eager = toggle;
         self
     }
 
+    /// Register a custom [`OptimizationRule`] that rewrites the query plan.
+    ///
+    /// The rule runs together with the built-in rules on the IR and its expressions after
+    /// predicate, projection and slice pushdown, until no rule changes the plan anymore. It
+    /// is kept by the operations that build on this `LazyFrame`, and the rules of both sides
+    /// are combined in joins and concatenations.
+    pub fn with_optimizer_rule(mut self, rule: Box<dyn OptimizationRule + Send>) -> Self {
+        self.optimizer_rules.push(SharedOptimizationRule::new(rule));
+        self
+    }
+
+    /// Serialize the logical plan in a versioned format.
+    ///
+    /// Plans written by this version of Polars can be read by every later version that reads
+    /// the same [`PLAN_FORMAT_VERSION`]. Plans with anonymous or custom scan sources can't be
+    /// serialized.
+    #[cfg(feature = "serde")]
+    pub fn serialize_plan<W: std::io::Write>(
+        &self,
+        writer: W,
+        format: PlanSerializationFormat,
+    ) -> PolarsResult<()> {
+        self.logical_plan.serialize_versioned(writer, format)
+    }
+
+    /// Create a [`LazyFrame`] from a plan written by [`LazyFrame::serialize_plan`].
+    #[cfg(feature = "serde")]
+    pub fn from_serialized_plan<R: std::io::Read>(
+        reader: R,
+        format: PlanSerializationFormat,
+    ) -> PolarsResult<Self> {
+        DslPlan::deserialize_versioned(reader, format).map(LazyFrame::from)
+    }
+
     /// Return a String describing the naive (un-optimized) logical plan.
     pub fn describe_plan(&self) -> PolarsResult<String> {
         Ok(self.clone().to_alp()?.describe())
@@ -274,6 +366,64 @@ impl LazyFrame {
         }
     }
 
+    /// Execute the query and return a String describing the optimized logical plan, of which the
+    /// nodes are annotated with the rows they produced, the fraction of their input rows kept by
+    /// filters and in-memory scans with a predicate, and their wall time in microseconds,
+    /// including the time spent in their inputs.
+    ///
+    /// If `dot` is `true`, the plan is described in the dot language, as in
+    /// [`LazyFrame::to_dot`]. Nodes that are executed as part of another node, like a filter
+    /// that is pushed into a scan, are not annotated.
+    pub fn explain_analyze(mut self, dot: bool) -> PolarsResult<String> {
+        use std::fmt::Write;
+
+        let (mut lp_arena, mut expr_arena) = self.get_arenas();
+        let lp_top =
+            self.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut vec![], true)?;
+
+        // Creating the physical plan takes the nodes out of the arena, keep them for the output.
+        let mut physical_plan =
+            create_analyzed_physical_plan(lp_top, &mut lp_arena.clone(), &expr_arena)?;
+        let mut state = ExecutionState::new();
+        state.collect_node_stats();
+        physical_plan.execute(&mut state)?;
+        let stats = state.finish_node_stats();
+
+        let annotations = stats
+            .iter()
+            .map(|(node, node_stats)| {
+                let mut annotation = format!("rows: {}", node_stats.rows);
+                let input_rows = match lp_arena.get(*node) {
+                    IR::Filter { input, .. } => stats.get(input).map(|s| s.rows),
+                    IR::DataFrameScan {
+                        df,
+                        filter: Some(_),
+                        ..
+                    } => Some(df.height() * node_stats.executions),
+                    _ => None,
+                };
+                if let Some(input_rows) = input_rows.filter(|rows| *rows > 0) {
+                    let selectivity = node_stats.rows as f64 / input_rows as f64;
+                    write!(annotation, ", selectivity: {selectivity:.2}").unwrap();
+                }
+                write!(annotation, ", time: {}µs", node_stats.time.as_micros()).unwrap();
+                if node_stats.executions > 1 {
+                    write!(annotation, ", executions: {}", node_stats.executions).unwrap();
+                }
+                (*node, annotation)
+            })
+            .collect::<PlHashMap<_, _>>();
+
+        let plan = IRPlan::new(lp_top, lp_arena, expr_arena);
+        Ok(if dot {
+            plan.display_dot()
+                .with_annotations(&annotations)
+                .to_string()
+        } else {
+            plan.display().with_annotations(&annotations).to_string()
+        })
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// Sorts the LazyFrame by the column name specified using the provided options.
@@ -315,6 +465,7 @@ impl LazyFrame {
     /// See [`SortMultipleOptions`] for more options.
     pub fn sort(self, by: impl IntoVec<SmartString>, sort_options: SortMultipleOptions) -> Self {
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self
             .get_plan_builder()
             .sort(
@@ -322,7 +473,7 @@ impl LazyFrame {
                 sort_options,
             )
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     /// Add a sort operation to the logical plan.
@@ -354,8 +505,9 @@ impl LazyFrame {
             self
         } else {
             let opt_state = self.get_opt_state();
+            let optimizer_rules = self.optimizer_rules.clone();
             let lp = self.get_plan_builder().sort(by_exprs, sort_options).build();
-            Self::from_logical_plan(lp, opt_state)
+            Self::from_logical_plan(lp, opt_state, optimizer_rules)
         }
     }
 
@@ -455,8 +607,9 @@ impl LazyFrame {
             .collect::<PlHashSet<_>>();
 
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self.get_plan_builder().drop(to_drop, strict).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     /// Removes columns from the DataFrame.
@@ -507,15 +660,17 @@ impl LazyFrame {
     /// Fill None values in the DataFrame with an expression.
     pub fn fill_null<E: Into<Expr>>(self, fill_value: E) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self.get_plan_builder().fill_null(fill_value.into()).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     /// Fill NaN values in the DataFrame with an expression.
     pub fn fill_nan<E: Into<Expr>>(self, fill_value: E) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self.get_plan_builder().fill_nan(fill_value.into()).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     /// Caches the result into a new LazyFrame.
@@ -523,8 +678,9 @@ impl LazyFrame {
     /// This should be used to prevent computations running multiple times.
     pub fn cache(self) -> Self {
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self.get_plan_builder().cache().build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     /// Cast named frame columns, resulting in a new LazyFrame with updated dtypes
@@ -569,6 +725,31 @@ impl LazyFrame {
         res
     }
 
+    /// Collect up to `n` rows for a quick look at the result of the query.
+    ///
+    /// Every scan reads at most `n` rows, so the sources are never read completely, even if
+    /// the limit can't be pushed down to them because of e.g. a filter or a join. Like
+    /// [`fetch`](LazyFrame::fetch), this means that fewer than `n` rows may be returned.
+    pub fn glimpse(self, n: IdxSize) -> PolarsResult<DataFrame> {
+        self.limit(n).fetch(n as usize)
+    }
+
+    /// Collect the first and the last `n` rows of the query, or all rows if there are at most
+    /// `2 * n`.
+    ///
+    /// The head and the tail are collected as separate slices, which are pushed down to the
+    /// scans where possible. Parquet scans only read the files that contain the tail.
+    pub fn head_tail(self, n: IdxSize) -> PolarsResult<DataFrame> {
+        let mut head = self.clone().limit(n.saturating_mul(2)).collect()?;
+        if (head.height() as IdxSize) < n.saturating_mul(2) {
+            return Ok(head);
+        }
+        head = head.head(Some(n as usize));
+        let tail = self.tail(n).collect()?;
+        head.vstack_mut(&tail)?;
+        Ok(head)
+    }
+
     pub fn optimize(
         self,
         lp_arena: &mut Arena<IR>,
@@ -630,6 +811,7 @@ impl LazyFrame {
                 let io_expr = phys_expr_to_io_expr(phys_expr);
                 Some(io_expr)
             }),
+            &self.optimizer_rules,
         )?;
 
         if streaming {
@@ -702,7 +884,8 @@ impl LazyFrame {
 
     /// Execute all the lazy operations and collect them into a [`DataFrame`].
     ///
-    /// The query is optimized prior to execution.
+    /// The query is optimized prior to execution. If the result cache is enabled, parts of the
+    /// query that were collected before are read from the cache.
     ///
     /// # Example
     ///
@@ -734,6 +917,20 @@ impl LazyFrame {
                 return polars_stream::run_query(lp_top, ir_arena, expr_arena);
             }
         }
+        #[cfg(feature = "result_cache")]
+        {
+            if using_result_cache() {
+                let pending = std::cell::Cell::new(None);
+                let df = self._collect_post_opt(|lp_top, lp_arena, expr_arena| {
+                    pending.set(use_cached_results(lp_top, lp_arena, expr_arena));
+                    Ok(())
+                })?;
+                if let Some(pending) = pending.into_inner() {
+                    pending.store(&df);
+                }
+                return Ok(df);
+            }
+        }
         self._collect_post_opt(|_, _, _| Ok(()))
     }
 
@@ -743,13 +940,88 @@ impl LazyFrame {
     /// containing the materialized DataFrame and a DataFrame that contains profiling information
     /// of each node that is executed.
     ///
-    /// The units of the timings are microseconds.
-    pub fn profile(self) -> PolarsResult<(DataFrame, DataFrame)> {
+    /// The profile has a row per node of the optimized plan with when it started and ended,
+    /// including the time spent in its inputs, and the rows it received and produced. The nodes
+    /// form a tree: every node has an `id` and the `parent` column holds the `id` of the node that
+    /// consumes its output. The units of the timings are microseconds.
+    ///
+    /// With the new streaming engine the profile has a row per node of the streaming graph,
+    /// which also lists the (estimated) bytes the node received and produced. These nodes run
+    /// concurrently, so their timings overlap.
+    pub fn profile(mut self) -> PolarsResult<(DataFrame, DataFrame)> {
+        #[cfg(feature = "new_streaming")]
+        {
+            if self.opt_state.new_streaming {
+                let alp_plan = self.to_alp_optimized()?;
+                let lp_top = alp_plan.lp_top;
+                let mut ir_arena = alp_plan.lp_arena;
+                let expr_arena = alp_plan.expr_arena;
+
+                let lp_top = ir_arena.add(IR::Sink {
+                    input: lp_top,
+                    payload: SinkType::Memory,
+                });
+
+                return polars_stream::profile_query(lp_top, ir_arena, expr_arena);
+            }
+        }
+        let query_start = Instant::now();
+        let (mut lp_arena, mut expr_arena) = self.get_arenas();
+        let lp_top =
+            self.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut vec![], false)?;
+
+        // Creating the physical plan takes the nodes out of the arena, keep them for the profile.
+        let mut physical_plan =
+            create_analyzed_physical_plan(lp_top, &mut lp_arena.clone(), &expr_arena)?;
+        let optimized = Instant::now();
+        let mut state = ExecutionState::new();
+        state.collect_node_stats();
+        let out = physical_plan.execute(&mut state)?;
+        let profile = profile::profile_nodes(
+            query_start,
+            optimized,
+            lp_top,
+            &lp_arena,
+            state.finish_node_stats(),
+        )?;
+        Ok((out, profile))
+    }
+
+    /// Execute the query and report what its file scans read.
+    ///
+    /// Returns the materialized DataFrame and a DataFrame with a row per Parquet scan, listing
+    /// the files before and after partition pruning, the row groups read and pruned by their
+    /// statistics, the bytes of the column chunks read and the predicate used for pruning.
+    /// Scans of which every file is pruned are not executed and don't show up.
+    pub fn collect_with_scan_stats(self) -> PolarsResult<(DataFrame, DataFrame)> {
         let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
-        state.time_nodes();
+        state.collect_scan_stats();
         let out = physical_plan.execute(&mut state)?;
-        let timer_df = state.finish_timer()?;
-        Ok((out, timer_df))
+        let stats_df = state.finish_scan_stats()?;
+        Ok((out, stats_df))
+    }
+
+    /// Execute the query and split the result in chunks of exactly `rows_per_chunk` rows, of which
+    /// only the last may be shorter.
+    ///
+    /// The chunks are aligned over the columns, so every chunk converts to a record batch of that
+    /// length, e.g. for a writer or a Flight server that needs batches of a fixed size. See
+    /// [`DataFrame::rechunk_by_rows`].
+    pub fn collect_chunked(self, rows_per_chunk: usize) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            rows_per_chunk > 0,
+            InvalidOperation: "`rows_per_chunk` must be positive, got 0"
+        );
+        self.collect()?.rechunk_by_rows(rows_per_chunk)
+    }
+
+    /// Execute the query, stopping early if `token` is cancelled.
+    ///
+    /// The token is checked at chunk boundaries of the scans, joins, sorts and group-bys, so
+    /// cancelling from another thread makes this return a [`PolarsError::Cancelled`] shortly
+    /// after. Sinks can be cancelled by running them inside [`CancellationToken::run`].
+    pub fn collect_with_cancellation(self, token: &CancellationToken) -> PolarsResult<DataFrame> {
+        token.run(|| self.collect())
     }
 
     /// Stream a query result into a parquet file. This is useful if the final result doesn't fit
@@ -774,6 +1046,9 @@ impl LazyFrame {
     /// into memory, and where you do not want to write to a local file but to a location in the cloud.
     /// This method will return an error if the query cannot be completely done in a
     /// streaming fashion.
+    ///
+    /// The part size, concurrency and checksum of the upload are set by the
+    /// [`UploadOptions`](polars_io::cloud::UploadOptions) of the `cloud_options`.
     #[cfg(all(feature = "cloud_write", feature = "parquet"))]
     pub fn sink_parquet_cloud(
         self,
@@ -805,11 +1080,32 @@ impl LazyFrame {
         )
     }
 
+    /// Stream a query result into the Arrow IPC streaming format. This methods will return an
+    /// error if the query cannot be completely done in a streaming fashion.
+    ///
+    /// The batches are written as they are produced, and `path` is written in place, so it can
+    /// be a named pipe that another process reads the stream from. Categorical dictionaries
+    /// that only gained new categories since the previous batch are sent as deltas.
+    #[cfg(feature = "ipc_streaming")]
+    pub fn sink_ipc_stream(
+        self,
+        path: impl AsRef<Path>,
+        options: IpcStreamWriterOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            SinkType::File {
+                path: Arc::new(path.as_ref().to_path_buf()),
+                file_type: FileType::IpcStream(options),
+            },
+            "collect().write_ipc_stream()",
+        )
+    }
+
     /// Stream a query result into an ipc/arrow file on an ObjectStore-compatible cloud service.
     /// This is useful if the final result doesn't fit
     /// into memory, and where you do not want to write to a local file but to a location in the cloud.
     /// This method will return an error if the query cannot be completely done in a
-    /// streaming fashion.
+    /// streaming fashion. See [`LazyFrame::sink_parquet_cloud`] for configuring the upload.
     #[cfg(all(feature = "cloud_write", feature = "ipc"))]
     pub fn sink_ipc_cloud(
         mut self,
@@ -864,27 +1160,117 @@ impl LazyFrame {
         )
     }
 
+    /// Stream a query result into a hive partitioned directory of parquet files.
+    ///
+    /// Rows are written to `path/key=value/.../data-0000.parquet` based on the values of the
+    /// `partition_by` columns. This methods will return an error if the query cannot be completely
+    /// done in a streaming fashion.
+    #[cfg(feature = "parquet")]
+    pub fn sink_parquet_partitioned(
+        self,
+        path: impl AsRef<Path>,
+        partition_options: PartitionSinkOptions,
+        options: ParquetWriteOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            SinkType::Partition {
+                path: Arc::new(path.as_ref().to_path_buf()),
+                file_type: FileType::Parquet(options),
+                options: partition_options,
+            },
+            "collect().write_parquet()",
+        )
+    }
+
+    /// Stream a query result into a hive partitioned directory of ipc/arrow files.
+    ///
+    /// See [`LazyFrame::sink_parquet_partitioned`] for the directory layout.
+    #[cfg(feature = "ipc")]
+    pub fn sink_ipc_partitioned(
+        self,
+        path: impl AsRef<Path>,
+        partition_options: PartitionSinkOptions,
+        options: IpcWriterOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            SinkType::Partition {
+                path: Arc::new(path.as_ref().to_path_buf()),
+                file_type: FileType::Ipc(options),
+                options: partition_options,
+            },
+            "collect().write_ipc()",
+        )
+    }
+
+    /// Stream a query result into a hive partitioned directory of csv files.
+    ///
+    /// See [`LazyFrame::sink_parquet_partitioned`] for the directory layout.
+    #[cfg(feature = "csv")]
+    pub fn sink_csv_partitioned(
+        self,
+        path: impl AsRef<Path>,
+        partition_options: PartitionSinkOptions,
+        options: CsvWriterOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            SinkType::Partition {
+                path: Arc::new(path.as_ref().to_path_buf()),
+                file_type: FileType::Csv(options),
+                options: partition_options,
+            },
+            "collect().write_csv()",
+        )
+    }
+
+    /// Stream a query result into a [`SinkPlugin`], e.g. to insert it into a database. This
+    /// method will return an error if the query cannot be completely done in a streaming
+    /// fashion.
+    #[cfg(feature = "streaming")]
+    pub fn sink_plugin(self, plugin: Arc<dyn SinkPlugin>) -> PolarsResult<()> {
+        self.sink(SinkType::Plugin(SinkPluginRef(plugin)), "collect()")
+    }
+
+    #[cfg(any(
+        feature = "ipc",
+        feature = "ipc_streaming",
+        feature = "parquet",
+        feature = "cloud_write",
+        feature = "csv",
+        feature = "json",
+        feature = "streaming",
+    ))]
+    fn sink(self, payload: SinkType, msg_alternative: &str) -> Result<(), PolarsError> {
+        let (mut state, mut physical_plan) = self.prepare_sink(payload, msg_alternative)?;
+        let _ = physical_plan.execute(&mut state)?;
+        Ok(())
+    }
+
     #[cfg(any(
         feature = "ipc",
+        feature = "ipc_streaming",
         feature = "parquet",
         feature = "cloud_write",
         feature = "csv",
         feature = "json",
+        feature = "streaming",
     ))]
-    fn sink(mut self, payload: SinkType, msg_alternative: &str) -> Result<(), PolarsError> {
+    fn prepare_sink(
+        mut self,
+        payload: SinkType,
+        msg_alternative: &str,
+    ) -> PolarsResult<(ExecutionState, Box<dyn Executor>)> {
         self.opt_state.streaming = true;
         self.logical_plan = DslPlan::Sink {
             input: Arc::new(self.logical_plan),
             payload,
         };
-        let (mut state, mut physical_plan, is_streaming) = self.prepare_collect(true)?;
+        let (state, physical_plan, is_streaming) = self.prepare_collect(true)?;
         polars_ensure!(
             is_streaming,
             ComputeError: format!("cannot run the whole query in a streaming order; \
             use `{msg_alternative}` instead", msg_alternative=msg_alternative)
         );
-        let _ = physical_plan.execute(&mut state)?;
-        Ok(())
+        Ok((state, physical_plan))
     }
 
     /// Filter by some predicate expression.
@@ -905,8 +1291,9 @@ impl LazyFrame {
     /// ```
     pub fn filter(self, predicate: Expr) -> Self {
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self.get_plan_builder().filter(predicate).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     /// Select (and optionally rename, with [`alias`](crate::dsl::Expr::alias)) columns from the query.
@@ -960,8 +1347,9 @@ impl LazyFrame {
 
     fn select_impl(self, exprs: Vec<Expr>, options: ProjectionOptions) -> Self {
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self.get_plan_builder().project(exprs, options).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     /// Performs a "group-by" on a `LazyFrame`, producing a [`LazyGroupBy`], which can subsequently be aggregated.
@@ -998,6 +1386,7 @@ impl LazyFrame {
             LazyGroupBy {
                 logical_plan: self.logical_plan,
                 opt_state,
+                optimizer_rules: self.optimizer_rules,
                 keys,
                 maintain_order: false,
                 dynamic_options: None,
@@ -1010,6 +1399,7 @@ impl LazyFrame {
             LazyGroupBy {
                 logical_plan: self.logical_plan,
                 opt_state,
+                optimizer_rules: self.optimizer_rules,
                 keys,
                 maintain_order: false,
             }
@@ -1046,6 +1436,7 @@ impl LazyFrame {
         LazyGroupBy {
             logical_plan: self.logical_plan,
             opt_state,
+            optimizer_rules: self.optimizer_rules,
             keys: group_by.as_ref().to_vec(),
             maintain_order: true,
             dynamic_options: None,
@@ -1091,6 +1482,7 @@ impl LazyFrame {
         LazyGroupBy {
             logical_plan: self.logical_plan,
             opt_state,
+            optimizer_rules: self.optimizer_rules,
             keys: group_by.as_ref().to_vec(),
             maintain_order: true,
             dynamic_options: Some(options),
@@ -1098,6 +1490,176 @@ impl LazyFrame {
         }
     }
 
+    /// Aggregate the data in windows of `every` and add the windows without data.
+    ///
+    /// This is a [`group_by_dynamic`][`Self::group_by_dynamic`] with non-overlapping windows,
+    /// followed by upsampling the result to a window every `every` within every group, in a
+    /// single operation. The aggregated columns of the added windows, and any other nulls
+    /// in them, are filled according to `fill`.
+    #[cfg(feature = "dynamic_group_by")]
+    pub fn resample<E: AsRef<[Expr]>, A: AsRef<[Expr]>>(
+        self,
+        index_column: Expr,
+        group_by: E,
+        every: Duration,
+        aggs: A,
+        fill: ResampleFill,
+    ) -> LazyFrame {
+        let options = DynamicGroupOptions {
+            every,
+            period: every,
+            offset: Duration::new(0),
+            ..Default::default()
+        };
+        let mut lf = self
+            .group_by_dynamic(index_column, group_by, options)
+            .agg(aggs);
+        if let DslPlan::GroupBy { options, .. } = &mut lf.logical_plan {
+            Arc::make_mut(options).resample = Some(fill);
+        }
+        lf
+    }
+
+    /// Aggregate by several sets of keys at once, as SQL's `GROUP BY GROUPING SETS`.
+    ///
+    /// The result holds the groups of every set, in the order of `sets`. It has a column for
+    /// every distinct key of the sets; the keys that are not in a set are null in the rows of
+    /// that set. If `grouping_id` is given, a `UInt64` column of that name is added after the
+    /// keys, with a bit set for every key that is not in the set of the row. The first key is
+    /// the most significant bit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///     df.lazy().group_by_grouping_sets(
+    ///         &[vec![col("store"), col("day")], vec![col("store")], vec![]],
+    ///         [col("sales").sum()],
+    ///         Some("grouping_id"),
+    ///     )
+    /// }
+    /// ```
+    #[cfg(feature = "grouping_sets")]
+    pub fn group_by_grouping_sets<S: AsRef<[Expr]>, A: AsRef<[Expr]>>(
+        self,
+        sets: &[S],
+        aggs: A,
+        grouping_id: Option<&str>,
+    ) -> LazyFrame {
+        let mut keys: Vec<Expr> = vec![];
+        let sets = sets
+            .iter()
+            .map(|set| {
+                let mut indices = Vec::with_capacity(set.as_ref().len());
+                for key in set.as_ref() {
+                    let idx = keys.iter().position(|k| k == key).unwrap_or_else(|| {
+                        keys.push(key.clone());
+                        keys.len() - 1
+                    });
+                    if !indices.contains(&idx) {
+                        indices.push(idx)
+                    }
+                }
+                indices
+            })
+            .collect();
+
+        let mut lf = self.group_by(keys).agg(aggs);
+        if let DslPlan::GroupBy { options, .. } = &mut lf.logical_plan {
+            Arc::make_mut(options).grouping_sets = Some(GroupingSets {
+                sets,
+                grouping_id: grouping_id.map(Arc::from),
+                level: None,
+            });
+        }
+        lf
+    }
+
+    /// Aggregate by the hierarchy of `by`, as SQL's `GROUP BY ROLLUP`.
+    ///
+    /// This is a [`group_by_grouping_sets`][`Self::group_by_grouping_sets`] on the sets
+    /// `(a, b, c)`, `(a, b)`, `(a)` and `()` for the keys `[a, b, c]`.
+    #[cfg(feature = "grouping_sets")]
+    pub fn group_by_rollup<E: AsRef<[Expr]>, A: AsRef<[Expr]>>(
+        self,
+        by: E,
+        aggs: A,
+        grouping_id: Option<&str>,
+    ) -> LazyFrame {
+        let by = by.as_ref();
+        let sets = (0..=by.len()).rev().map(|n| &by[..n]).collect::<Vec<_>>();
+        self.group_by_grouping_sets(&sets, aggs, grouping_id)
+    }
+
+    /// Aggregate by every level of the hierarchy of `by`, with subtotal rows.
+    ///
+    /// For the keys `[region, country, city]` this gives the groups of `(region, country, city)`,
+    /// `(region, country)`, `(region)` and the grand total, like
+    /// [`group_by_rollup`][`Self::group_by_rollup`]. A `UInt32` column named `level` is added
+    /// after the keys, holding the number of keys that are grouped: the grand total is level
+    /// `0` and the finest groups are level `by.len()`. The input is hashed once, the coarser
+    /// levels are derived from the groups of the finest level.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///     df.lazy().group_by_hierarchy(
+    ///         [col("region"), col("country"), col("city")],
+    ///         [col("sales").sum()],
+    ///         "level",
+    ///     )
+    /// }
+    /// ```
+    #[cfg(feature = "grouping_sets")]
+    pub fn group_by_hierarchy<E: AsRef<[Expr]>, A: AsRef<[Expr]>>(
+        self,
+        by: E,
+        aggs: A,
+        level: &str,
+    ) -> LazyFrame {
+        let mut lf = self.group_by_rollup(by, aggs, None);
+        if let DslPlan::GroupBy { options, .. } = &mut lf.logical_plan {
+            if let Some(grouping_sets) = &mut Arc::make_mut(options).grouping_sets {
+                grouping_sets.level = Some(Arc::from(level));
+            }
+        }
+        lf
+    }
+
+    /// Aggregate by every combination of the keys in `by`, as SQL's `GROUP BY CUBE`.
+    ///
+    /// This is a [`group_by_grouping_sets`][`Self::group_by_grouping_sets`] on the sets
+    /// `(a, b)`, `(a)`, `(b)` and `()` for the keys `[a, b]`.
+    #[cfg(feature = "grouping_sets")]
+    pub fn group_by_cube<E: AsRef<[Expr]>, A: AsRef<[Expr]>>(
+        self,
+        by: E,
+        aggs: A,
+        grouping_id: Option<&str>,
+    ) -> LazyFrame {
+        let by = by.as_ref();
+        let n = by.len();
+        // A set bit in the mask means that the key is grouped, the first key being the most
+        // significant bit, so that the sets come in the order of their grouping id.
+        let sets = (0..1usize << n)
+            .rev()
+            .map(|mask| {
+                (0..n)
+                    .filter(|i| mask & (1 << (n - 1 - i)) != 0)
+                    .map(|i| by[i].clone())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        self.group_by_grouping_sets(&sets, aggs, grouping_id)
+    }
+
     /// Similar to [`group_by`][`Self::group_by`], but order of the DataFrame is maintained.
     pub fn group_by_stable<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(self, by: E) -> LazyGroupBy {
         let keys = by
@@ -1112,6 +1674,7 @@ impl LazyFrame {
             LazyGroupBy {
                 logical_plan: self.logical_plan,
                 opt_state,
+                optimizer_rules: self.optimizer_rules,
                 keys,
                 maintain_order: true,
                 dynamic_options: None,
@@ -1124,6 +1687,7 @@ impl LazyFrame {
             LazyGroupBy {
                 logical_plan: self.logical_plan,
                 opt_state,
+                optimizer_rules: self.optimizer_rules,
                 keys,
                 maintain_order: true,
             }
@@ -1315,6 +1879,9 @@ impl LazyFrame {
         if let Some(suffix) = args.suffix {
             builder = builder.suffix(suffix);
         }
+        if let Some(naming) = args.naming {
+            builder = builder.naming(naming);
+        }
         // Note: args.slice is set by the optimizer
         builder.finish()
     }
@@ -1328,6 +1895,63 @@ impl LazyFrame {
         JoinBuilder::new(self)
     }
 
+    /// Combine the rows of this query with the rows of another lazy query.
+    ///
+    /// The columns of both queries must line up, either by position or by name depending on
+    /// the [`FrameSetOperation`]. See [`intersect`](LazyFrame::intersect),
+    /// [`except`](LazyFrame::except) and [`union`](LazyFrame::union) for the common cases.
+    pub fn set_operation(self, other: LazyFrame, op: FrameSetOperation) -> LazyFrame {
+        let mut opt_state = self.opt_state;
+        let mut optimizer_rules = self.optimizer_rules.clone();
+        opt_state.file_caching |= other.opt_state.file_caching;
+        merge_optimizer_rules(&mut optimizer_rules, &other.optimizer_rules);
+
+        let lp = self
+            .get_plan_builder()
+            .set_operation(other.logical_plan, op)
+            .build();
+        LazyFrame::from_logical_plan(lp, opt_state, optimizer_rules)
+    }
+
+    /// Keep the distinct rows of this query that also occur in `other`.
+    ///
+    /// Columns are matched by position and null values are considered equal, as in SQL's
+    /// `INTERSECT`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_lazy::prelude::*;
+    /// fn in_both(ldf: LazyFrame, other: LazyFrame) -> LazyFrame {
+    ///     ldf.intersect(other)
+    /// }
+    /// ```
+    #[cfg(feature = "semi_anti_join")]
+    pub fn intersect(self, other: LazyFrame) -> LazyFrame {
+        self.set_operation(other, FrameSetOperation::Intersect { by_name: false })
+    }
+
+    /// Keep the distinct rows of this query that don't occur in `other`.
+    ///
+    /// Columns are matched by position and null values are considered equal, as in SQL's
+    /// `EXCEPT`.
+    #[cfg(feature = "semi_anti_join")]
+    pub fn except(self, other: LazyFrame) -> LazyFrame {
+        self.set_operation(other, FrameSetOperation::Except { by_name: false })
+    }
+
+    /// Append the rows of `other` to this query, matching columns by position. If `distinct`
+    /// is set, duplicate rows are removed from the result, as in SQL's `UNION`.
+    pub fn union(self, other: LazyFrame, distinct: bool) -> LazyFrame {
+        self.set_operation(
+            other,
+            FrameSetOperation::Union {
+                by_name: false,
+                distinct,
+            },
+        )
+    }
+
     /// Add or replace a column, given as an expression, to a DataFrame.
     ///
     /// # Example
@@ -1347,6 +1971,7 @@ impl LazyFrame {
     /// ```
     pub fn with_column(self, expr: Expr) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self
             .get_plan_builder()
             .with_columns(
@@ -1358,7 +1983,7 @@ impl LazyFrame {
                 },
             )
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     /// Add or replace multiple columns, given as expressions, to a DataFrame.
@@ -1400,10 +2025,57 @@ impl LazyFrame {
         )
     }
 
+    /// Set the values of columns in the rows where `mask` is true, keeping the other rows.
+    ///
+    /// The mask and the values are evaluated on the frame before the update, and the mask is
+    /// evaluated once for all columns instead of once per `when/then/otherwise`. A null in the
+    /// mask keeps the row.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn clear_invalid(df: DataFrame) -> LazyFrame {
+    ///     df.lazy().set_where(
+    ///         col("valid").not(),
+    ///         [("price", lit(0.0)), ("quantity", lit(0))],
+    ///     )
+    /// }
+    /// ```
+    pub fn set_where<I, S>(self, mask: Expr, values: I) -> LazyFrame
+    where
+        I: IntoIterator<Item = (S, Expr)>,
+        S: AsRef<str>,
+    {
+        let values = values.into_iter().collect::<Vec<_>>();
+        let set = |mask: Expr| {
+            values
+                .iter()
+                .map(|(name, value)| {
+                    let name = name.as_ref();
+                    when(mask.clone())
+                        .then(value.clone())
+                        .otherwise(col(name))
+                        .alias(name)
+                })
+                .collect::<Vec<_>>()
+        };
+        match values.len() {
+            0 => self,
+            1 => self.with_columns(set(mask)),
+            _ => self
+                .with_column(mask.alias(SET_WHERE_MASK))
+                .with_columns(set(col(SET_WHERE_MASK)))
+                .drop([SET_WHERE_MASK]),
+        }
+    }
+
     fn with_columns_impl(self, exprs: Vec<Expr>, options: ProjectionOptions) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self.get_plan_builder().with_columns(exprs, options).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     pub fn with_context<C: AsRef<[LazyFrame]>>(self, contexts: C) -> LazyFrame {
@@ -1413,8 +2085,9 @@ impl LazyFrame {
             .map(|lf| lf.logical_plan.clone())
             .collect();
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self.get_plan_builder().with_context(contexts).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     /// Aggregate all the columns as their maximum values.
@@ -1506,8 +2179,9 @@ impl LazyFrame {
             .map(|e| e.clone().into())
             .collect::<Vec<_>>();
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self.get_plan_builder().explode(columns).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     /// Aggregate all the columns as the sum of their null value count.
@@ -1525,6 +2199,7 @@ impl LazyFrame {
         keep_strategy: UniqueKeepStrategy,
     ) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let options = DistinctOptions {
             subset: subset.map(Arc::new),
             maintain_order: true,
@@ -1532,7 +2207,7 @@ impl LazyFrame {
             ..Default::default()
         };
         let lp = self.get_plan_builder().distinct(options).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     /// Drop non-unique rows without maintaining the order of kept rows.
@@ -1548,6 +2223,7 @@ impl LazyFrame {
         keep_strategy: UniqueKeepStrategy,
     ) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let options = DistinctOptions {
             subset: subset.map(Arc::new),
             maintain_order: false,
@@ -1555,7 +2231,7 @@ impl LazyFrame {
             ..Default::default()
         };
         let lp = self.get_plan_builder().distinct(options).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     /// Drop rows containing None.
@@ -1564,8 +2240,9 @@ impl LazyFrame {
     /// columns are considered.
     pub fn drop_nulls(self, subset: Option<Vec<Expr>>) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self.get_plan_builder().drop_nulls(subset).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     /// Slice the DataFrame using an offset (starting row) and a length.
@@ -1579,8 +2256,9 @@ impl LazyFrame {
     /// case, the number of rows in the returned DataFrame will be less than `len`.
     pub fn slice(self, offset: i64, len: IdxSize) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self.get_plan_builder().slice(offset, len).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     /// Get the first row.
@@ -1610,8 +2288,33 @@ impl LazyFrame {
     /// See [`UnpivotArgs`] for information on how to unpivot a DataFrame.
     pub fn unpivot(self, args: UnpivotArgs) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self.get_plan_builder().unpivot(args).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
+    }
+
+    /// Pivot the DataFrame from long to wide format.
+    ///
+    /// If the values of the `on` columns are given in [`PivotArgs::on_columns`], the schema is
+    /// known up front and the pivot is fully lazy. Otherwise the distinct values are collected
+    /// first, in order of appearance, which runs the input query an extra time.
+    ///
+    /// See [`PivotArgs`] for information on how to pivot a LazyFrame.
+    #[cfg(feature = "pivot")]
+    pub fn pivot(self, mut args: PivotArgs) -> PolarsResult<LazyFrame> {
+        if args.on_columns.is_none() {
+            let on = args.on.iter().map(|name| col(name)).collect::<Vec<_>>();
+            let on_columns = self
+                .clone()
+                .select(on)
+                .unique_stable(None, UniqueKeepStrategy::First)
+                .collect()?;
+            args.on_columns = Some(Arc::new(on_columns));
+        }
+        let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
+        let lp = self.get_plan_builder().pivot(args).build();
+        Ok(Self::from_logical_plan(lp, opt_state, optimizer_rules))
     }
 
     /// Limit the DataFrame to the first `n` rows.
@@ -1645,6 +2348,7 @@ impl LazyFrame {
         F: 'static + Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
     {
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self
             .get_plan_builder()
             .map(
@@ -1654,7 +2358,7 @@ impl LazyFrame {
                 name.unwrap_or("ANONYMOUS UDF"),
             )
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     #[cfg(feature = "python")]
@@ -1666,17 +2370,65 @@ impl LazyFrame {
         validate_output: bool,
     ) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self
             .get_plan_builder()
             .map_python(function, optimizations, schema, validate_output)
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
+    }
+
+    /// Declare the input columns read by the function added with the directly preceding
+    /// [`map`](LazyFrame::map) or `map_python`.
+    ///
+    /// Projection pushdown can then prune all other columns below the function, even if it
+    /// otherwise blocks the optimization. The function receives only these columns, so any
+    /// column it passes through must be declared as well.
+    pub fn with_columns_used<S: AsRef<str>>(self, columns: &[S]) -> PolarsResult<LazyFrame> {
+        self.set_columns_used(columns, false, "with_columns_used")
+    }
+
+    /// Declare that the function added with the directly preceding [`map`](LazyFrame::map) or
+    /// `map_python` reads only `columns` and returns one row for every row of them.
+    ///
+    /// The function is called on these columns only and the columns it returns are added to
+    /// its input, replacing those with the same name. Projection pushdown passes the other
+    /// columns around the function, and predicates on columns the function doesn't return are
+    /// pushed down below it.
+    pub fn with_row_independent_columns<S: AsRef<str>>(
+        self,
+        columns: &[S],
+    ) -> PolarsResult<LazyFrame> {
+        self.set_columns_used(columns, true, "with_row_independent_columns")
+    }
+
+    fn set_columns_used<S: AsRef<str>>(
+        mut self,
+        columns: &[S],
+        row_independent: bool,
+        method: &str,
+    ) -> PolarsResult<LazyFrame> {
+        let columns = columns
+            .iter()
+            .map(|name| ColumnName::from(name.as_ref()))
+            .collect();
+        match &mut self.logical_plan {
+            DslPlan::MapFunction {
+                function: DslFunction::FunctionNode(function),
+                ..
+            } => function.set_columns_used(columns, row_independent)?,
+            _ => polars_bail!(
+                InvalidOperation: "'{}' must directly follow a 'map' operation", method
+            ),
+        }
+        Ok(self)
     }
 
     pub(crate) fn map_private(self, function: DslFunction) -> LazyFrame {
         let opt_state = self.get_opt_state();
+        let optimizer_rules = self.optimizer_rules.clone();
         let lp = self.get_plan_builder().map_private(function).build();
-        Self::from_logical_plan(lp, opt_state)
+        Self::from_logical_plan(lp, opt_state, optimizer_rules)
     }
 
     /// Add a new column at index 0 that counts the rows.
@@ -1693,7 +2445,13 @@ impl LazyFrame {
                 file_options: options,
                 scan_type,
                 ..
-            } if !matches!(scan_type, FileScan::Anonymous { .. }) => {
+            } if match &*scan_type {
+                FileScan::Anonymous { .. } => false,
+                FileScan::Source { source } => source.supports_row_index(),
+                #[allow(unreachable_patterns)]
+                _ => true,
+            } =>
+            {
                 options.row_index = Some(RowIndex {
                     name: Arc::from(name),
                     offset: offset.unwrap_or(0),
@@ -1707,12 +2465,37 @@ impl LazyFrame {
             self.map_private(DslFunction::RowIndex {
                 name: Arc::from(name),
                 offset,
+                over: None,
             })
         } else {
             self
         }
     }
 
+    /// Add a new column at index 0 that counts the rows within each group of `by`.
+    ///
+    /// This computes the same as `int_range(offset, offset + len()).over(by)` in a single
+    /// group-by. If `by` is empty, this is the same as [`LazyFrame::with_row_index`].
+    pub fn with_row_index_over<E, S>(self, name: &str, by: E, offset: Option<IdxSize>) -> LazyFrame
+    where
+        E: AsRef<[S]>,
+        S: AsRef<str>,
+    {
+        let by = by
+            .as_ref()
+            .iter()
+            .map(|s| Arc::from(s.as_ref()))
+            .collect::<Arc<[Arc<str>]>>();
+        if by.is_empty() {
+            return self.with_row_index(name, offset);
+        }
+        self.map_private(DslFunction::RowIndex {
+            name: Arc::from(name),
+            offset,
+            over: Some(by),
+        })
+    }
+
     /// Return the number of non-null elements for each column.
     pub fn count(self) -> LazyFrame {
         self.select(vec![col("*").count()])
@@ -1748,6 +2531,84 @@ impl LazyFrame {
             })),
         )
     }
+
+    fn assert(self, assertion: Assertion) -> LazyFrame {
+        self.map_private(DslFunction::FunctionNode(FunctionNode::Assert {
+            assertion,
+        }))
+    }
+
+    /// Raise an error during execution if the frame has no rows.
+    ///
+    /// The data is passed through unchanged.
+    pub fn assert_non_empty(self) -> LazyFrame {
+        self.assert(Assertion::NonEmpty)
+    }
+
+    /// Raise an error during execution if the frame does not have exactly `n` rows.
+    ///
+    /// The data is passed through unchanged.
+    pub fn assert_row_count(self, n: IdxSize) -> LazyFrame {
+        self.assert(Assertion::RowCount(n))
+    }
+
+    /// Raise an error during execution if any two rows share the same values in `subset`.
+    ///
+    /// If `subset` is `None` or empty, all columns are considered. The data is passed through
+    /// unchanged.
+    pub fn assert_unique<I: IntoIterator<Item = S>, S: AsRef<str>>(
+        self,
+        subset: Option<I>,
+    ) -> LazyFrame {
+        let subset = subset
+            .map(|cols| {
+                cols.into_iter()
+                    .map(|s| Arc::from(s.as_ref()))
+                    .collect::<Arc<[_]>>()
+            })
+            .filter(|cols| !cols.is_empty());
+        self.assert(Assertion::Unique { subset })
+    }
+
+    /// Raise an error during execution if the non-null values of `column` are not sorted.
+    ///
+    /// The data is passed through unchanged.
+    pub fn assert_sorted(self, column: &str, descending: bool) -> LazyFrame {
+        self.assert(Assertion::Sorted {
+            column: Arc::from(column),
+            descending,
+        })
+    }
+
+    /// Sample rows, from every stratum of `args.by` separately and proportionally to the
+    /// weights in `args.weights` if given.
+    ///
+    /// A sample of `n` rows without replacement is a reservoir sample in the streaming engine,
+    /// which only keeps `n` rows per stratum in memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// // Two rows of every label, rows with a higher `weight` are more likely to be chosen.
+    /// fn balanced(lf: LazyFrame) -> LazyFrame {
+    ///     lf.sample(SampleArgs {
+    ///         size: SampleSize::N(2),
+    ///         by: vec!["label".into()],
+    ///         weights: Some("weight".into()),
+    ///         with_replacement: false,
+    ///         shuffle: true,
+    ///         seed: Some(0),
+    ///     })
+    /// }
+    /// ```
+    pub fn sample(self, args: SampleArgs) -> LazyFrame {
+        self.map_private(DslFunction::FunctionNode(FunctionNode::Sample {
+            args: Arc::new(args),
+        }))
+    }
 }
 
 /// Utility struct for lazy group_by operation.
@@ -1755,6 +2616,7 @@ impl LazyFrame {
 pub struct LazyGroupBy {
     pub logical_plan: DslPlan,
     opt_state: OptState,
+    optimizer_rules: Vec<SharedOptimizationRule>,
     keys: Vec<Expr>,
     maintain_order: bool,
     #[cfg(feature = "dynamic_group_by")]
@@ -1769,6 +2631,7 @@ impl From<LazyGroupBy> for LazyFrame {
             logical_plan: lgb.logical_plan,
             opt_state: lgb.opt_state,
             cached_arena: Default::default(),
+            optimizer_rules: lgb.optimizer_rules,
         }
     }
 }
@@ -1813,7 +2676,7 @@ impl LazyGroupBy {
         let lp = DslBuilder::from(self.logical_plan)
             .group_by(self.keys, aggs, None, self.maintain_order)
             .build();
-        LazyFrame::from_logical_plan(lp, self.opt_state)
+        LazyFrame::from_logical_plan(lp, self.opt_state, self.optimizer_rules)
     }
 
     /// Return first n rows of each group
@@ -1848,16 +2711,14 @@ impl LazyGroupBy {
     where
         F: 'static + Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
     {
-        #[cfg(feature = "dynamic_group_by")]
         let options = GroupbyOptions {
+            #[cfg(feature = "dynamic_group_by")]
             dynamic: self.dynamic_options,
+            #[cfg(feature = "dynamic_group_by")]
             rolling: self.rolling_options,
-            slice: None,
+            ..Default::default()
         };
 
-        #[cfg(not(feature = "dynamic_group_by"))]
-        let options = GroupbyOptions { slice: None };
-
         let lp = DslPlan::GroupBy {
             input: Arc::new(self.logical_plan),
             keys: self.keys,
@@ -1866,7 +2727,7 @@ impl LazyGroupBy {
             maintain_order: self.maintain_order,
             options: Arc::new(options),
         };
-        LazyFrame::from_logical_plan(lp, self.opt_state)
+        LazyFrame::from_logical_plan(lp, self.opt_state, self.optimizer_rules)
     }
 }
 
@@ -1880,6 +2741,7 @@ pub struct JoinBuilder {
     allow_parallel: bool,
     force_parallel: bool,
     suffix: Option<String>,
+    naming: Option<JoinNaming>,
     validation: JoinValidation,
     coalesce: JoinCoalesce,
     join_nulls: bool,
@@ -1897,6 +2759,7 @@ impl JoinBuilder {
             force_parallel: false,
             join_nulls: false,
             suffix: None,
+            naming: None,
             validation: Default::default(),
             coalesce: Default::default(),
         }
@@ -1970,6 +2833,14 @@ impl JoinBuilder {
         self
     }
 
+    /// How to name the columns of the right table that are also in the left table, e.g. with
+    /// a prefix or a template, or to raise an error for them. Takes precedence over
+    /// [`suffix`](JoinBuilder::suffix).
+    pub fn naming(mut self, naming: JoinNaming) -> Self {
+        self.naming = Some(naming);
+        self
+    }
+
     /// Whether to coalesce join columns.
     pub fn coalesce(mut self, coalesce: JoinCoalesce) -> Self {
         self.coalesce = coalesce;
@@ -1979,15 +2850,18 @@ impl JoinBuilder {
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
         let mut opt_state = self.lf.opt_state;
+        let mut optimizer_rules = self.lf.optimizer_rules.clone();
         let other = self.other.expect("with not set");
 
         // if any of the nodes reads from files we must activate this this plan as well.
         opt_state.file_caching |= other.opt_state.file_caching;
+        merge_optimizer_rules(&mut optimizer_rules, &other.optimizer_rules);
 
         let args = JoinArgs {
             how: self.how,
             validation: self.validation,
             suffix: self.suffix,
+            naming: self.naming,
             slice: None,
             join_nulls: self.join_nulls,
             coalesce: self.coalesce,
@@ -2009,6 +2883,129 @@ impl JoinBuilder {
                 .into(),
             )
             .build();
-        LazyFrame::from_logical_plan(lp, opt_state)
+        LazyFrame::from_logical_plan(lp, opt_state, optimizer_rules)
+    }
+
+    /// Finish builder as an inner join on arbitrary `predicates`, which must all hold.
+    ///
+    /// Columns are looked up in the left table first, a column of the right table that is
+    /// also in the left table is referred to by its name after the join. Up to two
+    /// comparisons (`<`, `<=`, `>`, `>=`) between a column of each table are evaluated with
+    /// an inequality join, and the remaining predicates filter its output. Without such
+    /// comparisons the predicates filter the cross join.
+    #[cfg(feature = "iejoin")]
+    pub fn join_where(mut self, predicates: Vec<Expr>) -> PolarsResult<LazyFrame> {
+        let mut other = self.other.take().expect("with not set");
+        let schema_left = self.lf.schema()?;
+        let schema_right = other.schema()?;
+        let naming = JoinArgs::default()
+            .with_suffix(self.suffix.clone())
+            .with_naming(self.naming.clone())
+            .naming();
+
+        let mut conjuncts = Vec::with_capacity(predicates.len());
+        for e in predicates {
+            split_conjunction(e, &mut conjuncts);
+        }
+
+        let mut left_on = vec![];
+        let mut right_on = vec![];
+        let mut operators = vec![];
+        let mut remaining = vec![];
+        for e in conjuncts {
+            let inequality = if operators.len() < 2 {
+                as_inequality(&e, &schema_left, &schema_right, &naming)
+            } else {
+                None
+            };
+            match inequality {
+                Some((left, operator, right)) => {
+                    left_on.push(Expr::Column(left));
+                    right_on.push(Expr::Column(right));
+                    operators.push(operator);
+                },
+                None => remaining.push(e),
+            }
+        }
+
+        let how = match operators[..] {
+            [] => JoinType::Cross,
+            [operator1] => JoinType::IEJoin(IEJoinOptions {
+                operator1,
+                operator2: None,
+            }),
+            [operator1, operator2] => JoinType::IEJoin(IEJoinOptions {
+                operator1,
+                operator2: Some(operator2),
+            }),
+            _ => unreachable!(),
+        };
+
+        let lf = self
+            .with(other)
+            .how(how)
+            .left_on(left_on)
+            .right_on(right_on)
+            .finish();
+        Ok(match remaining.into_iter().reduce(|acc, e| acc.and(e)) {
+            Some(predicate) => lf.filter(predicate),
+            None => lf,
+        })
+    }
+}
+
+#[cfg(feature = "iejoin")]
+fn split_conjunction(e: Expr, out: &mut Vec<Expr>) {
+    match e {
+        Expr::BinaryExpr {
+            left,
+            op: Operator::And,
+            right,
+        } => {
+            split_conjunction((*left).clone(), out);
+            split_conjunction((*right).clone(), out);
+        },
+        e => out.push(e),
+    }
+}
+
+/// Resolves a comparison of a column of each table to `(left column, operator, right column)`.
+#[cfg(feature = "iejoin")]
+fn as_inequality(
+    e: &Expr,
+    schema_left: &Schema,
+    schema_right: &Schema,
+    naming: &JoinNaming,
+) -> Option<(ColumnName, InequalityOperator, ColumnName)> {
+    let Expr::BinaryExpr { left, op, right } = e else {
+        return None;
+    };
+    let operator = match op {
+        Operator::Lt => InequalityOperator::Lt,
+        Operator::LtEq => InequalityOperator::LtEq,
+        Operator::Gt => InequalityOperator::Gt,
+        Operator::GtEq => InequalityOperator::GtEq,
+        _ => return None,
+    };
+    let (Expr::Column(a), Expr::Column(b)) = (left.as_ref(), right.as_ref()) else {
+        return None;
+    };
+
+    // The name of a column in the right table, as referred to in the joined table.
+    let right_column = |name: &str| -> Option<ColumnName> {
+        if schema_left.contains(name) {
+            return None;
+        }
+        match naming.original(name) {
+            Some(stripped) if schema_left.contains(stripped) && schema_right.contains(stripped) => {
+                Some(ColumnName::from(stripped))
+            },
+            _ => schema_right.contains(name).then(|| ColumnName::from(name)),
+        }
+    };
+    match (right_column(a), right_column(b)) {
+        (None, Some(b)) if schema_left.contains(a) => Some((a.clone(), operator, b)),
+        (Some(a), None) if schema_left.contains(b) => Some((b.clone(), operator.swap(), a)),
+        _ => None,
     }
 }
@@ -0,0 +1,108 @@
+use super::*;
+
+/// A sorted query whose result is read in pages, e.g. by an API server.
+///
+/// The plan is optimized once by [`LazyFrame::paginate`]. Every page runs that plan with the
+/// sort limited to the rows of the page, so that it only keeps the first `offset + limit` rows
+/// instead of sorting the whole result.
+pub struct Pages {
+    lp_arena: Arena<IR>,
+    expr_arena: Arena<AExpr>,
+    lp_top: Node,
+}
+
+impl Pages {
+    /// Collect the rows `offset..offset + limit` of the sorted result.
+    pub fn collect_page(&self, offset: usize, limit: usize) -> PolarsResult<DataFrame> {
+        // Creating the physical plan takes the nodes out of the arena.
+        let mut lp_arena = self.lp_arena.clone();
+        let lp_top = match lp_arena.get_mut(self.lp_top) {
+            IR::Sort { slice, .. } => {
+                *slice = Some((offset as i64, limit));
+                self.lp_top
+            },
+            _ => lp_arena.add(IR::Slice {
+                input: self.lp_top,
+                offset: offset as i64,
+                len: limit as IdxSize,
+            }),
+        };
+        let mut physical_plan = create_physical_plan(lp_top, &mut lp_arena, &self.expr_arena)?;
+        let mut state = ExecutionState::new();
+        physical_plan.execute(&mut state)
+    }
+}
+
+impl LazyFrame {
+    /// Sort the query by `by` and optimize it for reading its result in pages with
+    /// [`Pages::collect_page`].
+    ///
+    /// Rows that are equal in `by` are ordered by the other columns, except nested ones, so the
+    /// pages don't depend on the order in which the query produces its rows. Only rows that are
+    /// also equal in those columns keep their order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// let df = df!["a" => [3, 1, 2, 1], "b" => ["w", "y", "z", "x"]]?;
+    /// let pages = df.lazy().paginate([col("a")], Default::default())?;
+    /// let page = pages.collect_page(1, 2)?;
+    /// assert_eq!(page, df!["a" => [1, 2], "b" => ["y", "z"]]?);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn paginate<E: AsRef<[Expr]>>(
+        mut self,
+        by: E,
+        sort_options: SortMultipleOptions,
+    ) -> PolarsResult<Pages> {
+        let schema = self.schema()?;
+        let mut by = by.as_ref().to_vec();
+        let n_by = by.len();
+        for (name, dtype) in schema.iter() {
+            let is_sorted_on = by
+                .iter()
+                .take(n_by)
+                .any(|e| matches!(e, Expr::Column(c) if c.as_ref() == name.as_str()));
+            if !is_sorted_on && !dtype.is_nested() && !dtype.is_object() {
+                by.push(col(name));
+            }
+        }
+
+        let mut sort_options = sort_options.with_maintain_order(true);
+        for values in [&mut sort_options.descending, &mut sort_options.nulls_last] {
+            // Invalid lengths are left to the sort to report.
+            if values.len() == 1 {
+                values.resize(n_by, values[0]);
+            }
+            if values.len() == n_by {
+                values.resize(by.len(), false);
+            }
+        }
+
+        let mut lf = self.sort_by_exprs(by, sort_options);
+        let (mut lp_arena, mut expr_arena) = lf.get_arenas();
+        let lp_top =
+            lf.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut vec![], false)?;
+        Ok(Pages {
+            lp_arena,
+            expr_arena,
+            lp_top,
+        })
+    }
+
+    /// Collect the rows `offset..offset + limit` of the query sorted by `by`.
+    ///
+    /// This optimizes the query for a single page, use [`LazyFrame::paginate`] to read
+    /// multiple pages with the same plan.
+    pub fn collect_page<E: AsRef<[Expr]>>(
+        self,
+        offset: usize,
+        limit: usize,
+        by: E,
+        sort_options: SortMultipleOptions,
+    ) -> PolarsResult<DataFrame> {
+        self.paginate(by, sort_options)?.collect_page(offset, limit)
+    }
+}
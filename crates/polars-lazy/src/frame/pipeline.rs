@@ -0,0 +1,259 @@
+use super::*;
+
+type StepFunction = Arc<dyn Fn(LazyFrame) -> LazyFrame + Send + Sync>;
+
+#[derive(Clone)]
+struct Step {
+    name: String,
+    function: StepFunction,
+    expected_schema: Option<SchemaRef>,
+}
+
+/// A sequence of named transformations of a [`LazyFrame`].
+///
+/// The schema after every step is resolved when the pipeline is applied, before anything
+/// runs, so a step that uses a column that an earlier step removed fails with the name of the
+/// step. [`Pipeline::expect_schema`] pins the schema after a step, to catch steps that change
+/// it by accident.
+///
+/// # Example
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// # use polars_lazy::prelude::*;
+/// let pipeline = Pipeline::new()
+///     .step("valid", |lf| lf.filter(col("a").is_not_null()))
+///     .step("double", |lf| lf.with_column((col("a") * lit(2)).alias("b")))
+///     .expect_schema(Schema::from_iter([
+///         Field::new("a", DataType::Int32),
+///         Field::new("b", DataType::Int32),
+///     ]));
+///
+/// let lf = df!["a" => [Some(1), None, Some(3)]]?.lazy();
+/// assert_eq!(pipeline.apply(lf.clone())?.collect()?.shape(), (2, 2));
+/// // Only run the steps up to and including "valid".
+/// assert_eq!(pipeline.apply_until(lf, "valid")?.collect()?.shape(), (2, 1));
+/// # Ok::<(), PolarsError>(())
+/// ```
+#[derive(Clone, Default)]
+pub struct Pipeline {
+    input_schema: Option<SchemaRef>,
+    steps: Vec<Step>,
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a step named `name` that applies `function`.
+    pub fn step<F>(mut self, name: &str, function: F) -> Self
+    where
+        F: Fn(LazyFrame) -> LazyFrame + Send + Sync + 'static,
+    {
+        self.steps.push(Step {
+            name: name.to_string(),
+            function: Arc::new(function),
+            expected_schema: None,
+        });
+        self
+    }
+
+    /// Require the schema after the last step added so far to be `schema`, or the schema of
+    /// the input if no step was added yet.
+    pub fn expect_schema(mut self, schema: impl Into<SchemaRef>) -> Self {
+        let schema = Some(schema.into());
+        match self.steps.last_mut() {
+            Some(step) => step.expected_schema = schema,
+            None => self.input_schema = schema,
+        }
+        self
+    }
+
+    /// The names of the steps, in order.
+    pub fn step_names(&self) -> impl Iterator<Item = &str> {
+        self.steps.iter().map(|step| step.name.as_str())
+    }
+
+    /// Apply all steps to `lf`.
+    pub fn apply(&self, lf: LazyFrame) -> PolarsResult<LazyFrame> {
+        self.apply_steps(lf, self.steps.len())
+    }
+
+    /// Apply the steps up to and including the step named `name` to `lf`, e.g. to inspect the
+    /// intermediate result of a pipeline.
+    pub fn apply_until(&self, lf: LazyFrame, name: &str) -> PolarsResult<LazyFrame> {
+        let Some(i) = self.steps.iter().position(|step| step.name == name) else {
+            polars_bail!(ComputeError: "pipeline has no step named {:?}", name);
+        };
+        self.apply_steps(lf, i + 1)
+    }
+
+    fn check_names(&self) -> PolarsResult<()> {
+        for (i, step) in self.steps.iter().enumerate() {
+            polars_ensure!(
+                !self.steps[..i].iter().any(|s| s.name == step.name),
+                Duplicate: "pipeline has multiple steps named {:?}", step.name
+            );
+        }
+        Ok(())
+    }
+
+    fn apply_steps(&self, mut lf: LazyFrame, n: usize) -> PolarsResult<LazyFrame> {
+        self.check_names()?;
+        if let Some(expected) = &self.input_schema {
+            let schema = lf.schema()?;
+            check_schema(&schema, expected, "the input")?;
+        }
+        for step in &self.steps[..n] {
+            (lf, _) = step.apply(lf)?;
+        }
+        Ok(lf)
+    }
+
+    /// Serialize the plans of the steps for an input with schema `input_schema`, in a
+    /// versioned format like [`LazyFrame::serialize_plan`].
+    ///
+    /// The functions of the steps are applied to a placeholder for the input, and the
+    /// resulting plans are written. A step that inspects the data, e.g. to choose its columns,
+    /// is thus fixed to its decision for `input_schema`.
+    #[cfg(feature = "serde")]
+    pub fn serialize<W: std::io::Write>(
+        &self,
+        input_schema: SchemaRef,
+        writer: W,
+        format: PlanSerializationFormat,
+    ) -> PolarsResult<()> {
+        self.check_names()?;
+        if let Some(expected) = &self.input_schema {
+            check_schema(&input_schema, expected, "the input")?;
+        }
+        let mut pipeline = DslPipeline {
+            input_schema: input_schema.as_ref().clone(),
+            steps: Vec::with_capacity(self.steps.len()),
+        };
+
+        let mut schema = input_schema;
+        for step in &self.steps {
+            let lf;
+            (lf, schema) = step.apply(LazyFrame::from(DslPipeline::placeholder(schema)))?;
+            pipeline.steps.push(DslPipelineStep {
+                name: step.name.clone(),
+                plan: lf.logical_plan,
+                expected_schema: step.expected_schema.as_deref().cloned(),
+            });
+        }
+        pipeline.serialize_versioned(writer, format)
+    }
+
+    /// Read a pipeline written by [`Pipeline::serialize`].
+    ///
+    /// The input schema of the serialized pipeline becomes the expected schema of the input.
+    #[cfg(feature = "serde")]
+    pub fn deserialize<R: std::io::Read>(
+        reader: R,
+        format: PlanSerializationFormat,
+    ) -> PolarsResult<Self> {
+        let pipeline = DslPipeline::deserialize_versioned(reader, format)?;
+        let steps = pipeline
+            .steps
+            .into_iter()
+            .map(|step| {
+                let plan = step.plan;
+                let function = move |lf: LazyFrame| {
+                    let mut plan = plan.clone();
+                    replace_placeholder(&mut plan, &lf.logical_plan);
+                    LazyFrame::from_logical_plan(plan, lf.opt_state, lf.optimizer_rules)
+                };
+                Step {
+                    name: step.name,
+                    function: Arc::new(function),
+                    expected_schema: step.expected_schema.map(Arc::new),
+                }
+            })
+            .collect();
+        Ok(Self {
+            input_schema: Some(Arc::new(pipeline.input_schema)),
+            steps,
+        })
+    }
+}
+
+impl Step {
+    /// Apply the step and resolve the schema of the result.
+    fn apply(&self, lf: LazyFrame) -> PolarsResult<(LazyFrame, SchemaRef)> {
+        let mut lf = (self.function)(lf);
+        let schema = lf
+            .schema()
+            .map_err(|e| e.context(format!("in pipeline step {:?}", self.name).into()))?;
+        if let Some(expected) = &self.expected_schema {
+            check_schema(
+                &schema,
+                expected,
+                &format!("the output of step {:?}", self.name),
+            )?;
+        }
+        Ok((lf, schema))
+    }
+}
+
+fn check_schema(schema: &Schema, expected: &Schema, what: &str) -> PolarsResult<()> {
+    polars_ensure!(
+        schema == expected,
+        SchemaMismatch: "expected {} of the pipeline to have schema {:?}, got {:?}",
+        what, expected, schema
+    );
+    Ok(())
+}
+
+/// Replace the placeholders for the input of a step in `plan` by `input`.
+#[cfg(feature = "serde")]
+fn replace_placeholder(plan: &mut DslPlan, input: &DslPlan) {
+    if DslPipeline::is_placeholder(plan) {
+        *plan = input.clone();
+        return;
+    }
+    match plan {
+        DslPlan::Filter { input: i, .. }
+        | DslPlan::Cache { input: i, .. }
+        | DslPlan::Select { input: i, .. }
+        | DslPlan::GroupBy { input: i, .. }
+        | DslPlan::HStack { input: i, .. }
+        | DslPlan::Distinct { input: i, .. }
+        | DslPlan::Sort { input: i, .. }
+        | DslPlan::Slice { input: i, .. }
+        | DslPlan::MapFunction { input: i, .. }
+        | DslPlan::Sink { input: i, .. } => replace_placeholder(Arc::make_mut(i), input),
+        DslPlan::Join {
+            input_left,
+            input_right,
+            ..
+        }
+        | DslPlan::SetOperation {
+            input_left,
+            input_right,
+            ..
+        } => {
+            replace_placeholder(Arc::make_mut(input_left), input);
+            replace_placeholder(Arc::make_mut(input_right), input);
+        },
+        DslPlan::Union { inputs, .. } | DslPlan::HConcat { inputs, .. } => inputs
+            .iter_mut()
+            .for_each(|plan| replace_placeholder(plan, input)),
+        DslPlan::ExtContext { input: i, contexts } => {
+            replace_placeholder(Arc::make_mut(i), input);
+            contexts
+                .iter_mut()
+                .for_each(|plan| replace_placeholder(plan, input));
+        },
+        // The converted plan is dropped, it doesn't have the new input.
+        DslPlan::IR { dsl, .. } => {
+            let mut dsl = dsl.as_ref().clone();
+            replace_placeholder(&mut dsl, input);
+            *plan = dsl;
+        },
+        DslPlan::Scan { .. } | DslPlan::DataFrameScan { .. } => {},
+        #[cfg(feature = "python")]
+        DslPlan::PythonScan { .. } => {},
+    }
+}
@@ -0,0 +1,85 @@
+use std::time::Instant;
+
+use polars_expr::state::NodeStats;
+
+use super::*;
+
+/// Build the profile of a query from the stats of the nodes of its optimized plan.
+///
+/// Every executed node gets a row with when it started and ended, in microseconds since
+/// `query_start` and including the time spent in its inputs, the rows it received and
+/// produced, its `id` and the `id` of the node that consumes its output. The first row is the
+/// optimization of the plan.
+///
+/// If the allocations are counted, a `peak_memory` column holds how many bytes the memory use
+/// grew at most while the node, including its inputs, ran.
+pub(super) fn profile_nodes(
+    query_start: Instant,
+    optimized: Instant,
+    lp_top: Node,
+    lp_arena: &Arena<IR>,
+    mut stats: PlHashMap<Node, NodeStats>,
+) -> PolarsResult<DataFrame> {
+    let micros = |t: Instant| t.duration_since(query_start).as_micros() as u64;
+
+    let mut names = vec!["optimization".to_string()];
+    let mut start = vec![0];
+    let mut end = vec![micros(optimized)];
+    let mut rows_out = vec![None];
+    let mut ids = vec![None];
+    let mut parents = vec![None];
+    let mut peak_memory = vec![None];
+
+    let mut visited = PlHashSet::new();
+    let mut stack = vec![(lp_top, None)];
+    while let Some((node, parent)) = stack.pop() {
+        // The input of a cache is shared by all its consumers, it runs once.
+        if !visited.insert(node) {
+            continue;
+        }
+        let ir = lp_arena.get(node);
+        // Nodes that are executed as part of another node, such as a filter that is fused into
+        // a scan, don't have stats. Their inputs belong to the closest executed node above.
+        let id = match stats.remove(&node) {
+            Some(node_stats) => {
+                let id = (ids.len() - 1) as u32;
+                names.push(node_stats.name.unwrap_or_else(|| ir.name().to_string()));
+                start.push(node_stats.start.map_or(0, micros));
+                end.push(node_stats.end.map_or(0, micros));
+                rows_out.push(Some(node_stats.rows as u64));
+                ids.push(Some(id));
+                parents.push(parent);
+                peak_memory.push(node_stats.peak_memory.map(|peak| peak as u64));
+                Some(id)
+            },
+            None => parent,
+        };
+        stack.extend(ir.get_inputs().iter().rev().map(|input| (*input, id)));
+    }
+
+    let mut rows_in = vec![Some(0u64); ids.len()];
+    rows_in[0] = None;
+    for (parent, rows) in parents.iter().zip(&rows_out) {
+        if let (Some(parent), Some(rows)) = (parent, rows) {
+            *rows_in[*parent as usize + 1].as_mut().unwrap() += rows;
+        }
+    }
+
+    let mut columns = vec![
+        Series::new("node", names),
+        Series::new("start", start),
+        Series::new("end", end),
+        Series::new("rows_in", rows_in),
+        Series::new("rows_out", rows_out),
+        Series::new("id", ids),
+        Series::new("parent", parents),
+    ];
+    if peak_memory.iter().any(Option::is_some) {
+        columns.push(Series::new("peak_memory", peak_memory));
+    }
+    let df = DataFrame::new(columns)?;
+    df.sort(
+        vec!["start"],
+        SortMultipleOptions::default().with_maintain_order(true),
+    )
+}
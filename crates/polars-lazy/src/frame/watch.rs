@@ -0,0 +1,199 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use polars_io::cloud::CloudOptions;
+use polars_plan::plans::expr_ir::ExprIR;
+
+use super::*;
+use crate::scan::file_list_reader::expand_paths;
+
+/// The file scan at the bottom of a chain of filters and projections.
+fn file_scan(plan: &mut DslPlan) -> PolarsResult<&mut DslPlan> {
+    if matches!(plan, DslPlan::Scan { .. }) {
+        return Ok(plan);
+    }
+    match plan {
+        DslPlan::Filter { input, .. }
+        | DslPlan::Select { input, .. }
+        | DslPlan::HStack { input, .. }
+        | DslPlan::MapFunction { input, .. } => file_scan(Arc::make_mut(input)),
+        _ => polars_bail!(
+            InvalidOperation:
+            "only filters and projections over a single file scan can be watched"
+        ),
+    }
+}
+
+/// Check that every row of the output of `node` only depends on one row of the files, such that
+/// the query can run on the new files alone.
+fn check_incremental(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<()> {
+    let separable = |exprs: &[ExprIR]| exprs.iter().all(|e| is_row_separable(e.node(), expr_arena));
+    let ir = lp_arena.get(node);
+    let incremental = match ir {
+        IR::Scan {
+            scan_type,
+            file_options,
+            ..
+        } => {
+            polars_ensure!(
+                file_options.row_index.is_none() && file_options.n_rows.is_none(),
+                InvalidOperation:
+                "cannot watch a scan with a row index or a row limit, these depend on the files \
+                that were read before"
+            );
+            !matches!(
+                scan_type,
+                FileScan::Anonymous { .. } | FileScan::Source { .. }
+            )
+        },
+        IR::Filter { predicate, .. } => is_row_separable(predicate.node(), expr_arena),
+        IR::Select { expr, .. } => separable(expr),
+        IR::HStack { exprs, .. } => separable(exprs),
+        IR::SimpleProjection { .. } => true,
+        IR::MapFunction { function, .. } => matches!(
+            function,
+            FunctionNode::Rename { .. } | FunctionNode::Unnest { .. }
+        ),
+        _ => false,
+    };
+    polars_ensure!(
+        incremental,
+        InvalidOperation:
+        "cannot watch a query with a `{}` node, it must map the rows of the files on their own",
+        ir.name()
+    );
+    ir.get_inputs()
+        .iter()
+        .try_for_each(|&input| check_incremental(input, lp_arena, expr_arena))
+}
+
+impl LazyFrame {
+    /// Watch the files in `path` and run the query on the files that are added to it.
+    ///
+    /// `path` is a directory or a glob. The query must be a chain of filters and row-wise
+    /// projections over a single file scan, whose format and options are used to read the
+    /// files. The returned [`Watch`] yields the result of the query on the files that exist
+    /// when it is first polled, and then on every batch of new files, checking for these every
+    /// `poll_interval`.
+    ///
+    /// Only new files are detected, rows that are appended to a file that was already read are
+    /// not. Files should thus appear at once, e.g. by writing them under another name and
+    /// renaming them.
+    pub fn watch(self, path: impl AsRef<Path>, poll_interval: Duration) -> PolarsResult<Watch> {
+        let mut plan = self.logical_plan.clone();
+        let DslPlan::Scan { scan_type, .. } = file_scan(&mut plan)? else {
+            unreachable!()
+        };
+        let cloud_options = scan_type.cloud_options().cloned();
+
+        let opt_state = self.opt_state;
+        let optimizer_rules = self.optimizer_rules.clone();
+        let IRPlan {
+            lp_top,
+            lp_arena,
+            expr_arena,
+        } = self.to_alp_optimized()?;
+        check_incremental(lp_top, &lp_arena, &expr_arena)?;
+        let schema = lp_arena.get(lp_top).schema(&lp_arena).into_owned();
+
+        Ok(Watch {
+            plan,
+            opt_state,
+            optimizer_rules,
+            path: path.as_ref().to_path_buf(),
+            cloud_options,
+            schema,
+            seen: Default::default(),
+            poll_interval,
+        })
+    }
+}
+
+/// The results of [`LazyFrame::watch`] on the new files, in the order in which the files are
+/// found.
+///
+/// Iterating blocks until new files are found. The iterator doesn't end, dropping it stops
+/// watching.
+pub struct Watch {
+    plan: DslPlan,
+    opt_state: OptState,
+    optimizer_rules: Vec<SharedOptimizationRule>,
+    path: PathBuf,
+    cloud_options: Option<CloudOptions>,
+    schema: SchemaRef,
+    seen: PlHashSet<PathBuf>,
+    poll_interval: Duration,
+}
+
+impl Watch {
+    /// The schema of the results.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Run the query on the files that were added since the last poll, if any.
+    ///
+    /// The files are only marked as read if the query succeeds, so a file that couldn't be read
+    /// is read again at the next poll.
+    pub fn poll(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let (paths, hive_start_idx) = expand_paths(
+            std::slice::from_ref(&self.path),
+            self.cloud_options.as_ref(),
+            true,
+            false,
+        )?;
+        let new_paths = paths
+            .iter()
+            .filter(|path| !self.seen.contains(*path))
+            .cloned()
+            .collect::<Arc<[_]>>();
+        if new_paths.is_empty() {
+            return Ok(None);
+        }
+
+        let mut plan = self.plan.clone();
+        let DslPlan::Scan {
+            paths,
+            file_info,
+            hive_parts,
+            file_options,
+            ..
+        } = file_scan(&mut plan)?
+        else {
+            unreachable!()
+        };
+        *paths = new_paths.clone();
+        // Inferred again from the new files.
+        *file_info = None;
+        *hive_parts = None;
+        file_options.hive_options.hive_start_idx = hive_start_idx;
+
+        let df = LazyFrame::from_logical_plan(plan, self.opt_state, self.optimizer_rules.clone())
+            .collect()?;
+        polars_ensure!(
+            df.schema() == *self.schema,
+            SchemaMismatch: "the new files {:?} give a different schema than the query",
+            new_paths
+        );
+        self.seen.extend(new_paths.iter().cloned());
+        Ok(Some(df))
+    }
+}
+
+impl Iterator for Watch {
+    type Item = PolarsResult<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.poll() {
+                Ok(Some(df)) => return Some(Ok(df)),
+                Ok(None) => std::thread::sleep(self.poll_interval),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
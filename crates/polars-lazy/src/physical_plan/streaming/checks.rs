@@ -8,7 +8,8 @@ pub(super) fn is_streamable_sort(
     sort_options: &SortMultipleOptions,
 ) -> bool {
     // check if slice is positive or maintain order is true
-    if sort_options.maintain_order {
+    // collation keys are only comparable within a single batch
+    if sort_options.maintain_order || sort_options.collation.is_some() {
         false
     } else if let Some((offset, _)) = slice {
         *offset >= 0
@@ -162,14 +162,19 @@ pub(crate) fn insert_streaming_nodes(
         state.execution_id = execution_id;
         execution_id += 1;
         match lp_arena.get(root) {
+            // Expressions hinted with `parallel(false)` are left to the in-memory engine.
             Filter { input, predicate }
-                if is_streamable(predicate.node(), expr_arena, Context::Default) =>
+                if is_streamable(predicate.node(), expr_arena, Context::Default)
+                    && is_parallel_safe(predicate.node(), expr_arena) =>
             {
                 state.streamable = true;
                 state.operators_sinks.push(PipelineNode::Operator(root));
                 stack.push(StackFrame::new(*input, state, current_idx))
             },
-            HStack { input, exprs, .. } if all_streamable(exprs, expr_arena, Context::Default) => {
+            HStack { input, exprs, .. }
+                if all_streamable(exprs, expr_arena, Context::Default)
+                    && all_parallel_safe(exprs, expr_arena) =>
+            {
                 state.streamable = true;
                 state.operators_sinks.push(PipelineNode::Operator(root));
                 stack.push(StackFrame::new(*input, state, current_idx))
@@ -194,7 +199,10 @@ pub(crate) fn insert_streaming_nodes(
                 state.operators_sinks.push(PipelineNode::Sink(root));
                 stack.push(StackFrame::new(*input, state, current_idx))
             },
-            Select { input, expr, .. } if all_streamable(expr, expr_arena, Context::Default) => {
+            Select { input, expr, .. }
+                if all_streamable(expr, expr_arena, Context::Default)
+                    && all_parallel_safe(expr, expr_arena) =>
+            {
                 state.streamable = true;
                 state.operators_sinks.push(PipelineNode::Operator(root));
                 stack.push(StackFrame::new(*input, state, current_idx))
@@ -217,6 +225,15 @@ pub(crate) fn insert_streaming_nodes(
                 state.streamable = true;
                 stack.push(StackFrame::new(*input, state, current_idx))
             },
+            // Reservoir samples are taken by a sink.
+            MapFunction {
+                input,
+                function: FunctionNode::Sample { args },
+            } if args.is_reservoir() => {
+                state.streamable = true;
+                state.operators_sinks.push(PipelineNode::Sink(root));
+                stack.push(StackFrame::new(*input, state, current_idx))
+            },
             // Streamable functions will be converted
             lp @ MapFunction { input, function } => {
                 if function.is_streamable() {
@@ -357,6 +374,9 @@ pub(crate) fn insert_streaming_nodes(
                         DataType::Unknown(_) => false,
                         #[cfg(feature = "dtype-decimal")]
                         DataType::Decimal(_, _) => false,
+                        // The streaming kernels work on the bits of the values.
+                        #[cfg(feature = "dtype-f16")]
+                        DataType::Float16 => false,
                         _ => true,
                     }
                 }
@@ -370,6 +390,12 @@ pub(crate) fn insert_streaming_nodes(
                         can_stream = false
                     }
                 }
+                #[cfg(feature = "grouping_sets")]
+                {
+                    if options.grouping_sets.is_some() {
+                        can_stream = false
+                    }
+                }
 
                 let valid_agg = || {
                     aggs.iter().all(|e| {
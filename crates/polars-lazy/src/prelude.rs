@@ -1,26 +1,48 @@
 pub(crate) use polars_expr::prelude::*;
 #[cfg(feature = "csv")]
 pub use polars_io::csv::write::CsvWriterOptions;
+#[cfg(feature = "ipc_streaming")]
+pub use polars_io::ipc::IpcStreamWriterOptions;
 #[cfg(feature = "ipc")]
 pub use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
 pub use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
+pub use polars_io::parquet::read::{LazyColumns, LAZY_COLUMNS_POSITION};
+#[cfg(feature = "parquet")]
 pub use polars_io::parquet::write::ParquetWriteOptions;
-pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
+#[cfg(feature = "round_series")]
+pub use polars_ops::prelude::RoundMode;
+#[cfg(feature = "hypothesis_tests")]
+pub use polars_ops::prelude::TTestVariant;
+#[cfg(feature = "iejoin")]
+pub use polars_ops::prelude::{IEJoinOptions, InequalityOperator};
+pub use polars_ops::prelude::{JoinArgs, JoinNaming, JoinType, JoinValidation};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
+#[cfg(feature = "pivot")]
+pub use polars_plan::plans::PivotArgs;
+#[cfg(feature = "dynamic_group_by")]
+pub use polars_plan::prelude::ResampleFill;
+#[cfg(feature = "result_cache")]
+pub use polars_plan::plans::{
+    clear_result_cache, disable_result_cache, enable_result_cache, using_result_cache,
+    ResultCacheConfig,
+};
 pub use polars_plan::plans::{
     AnonymousScan, AnonymousScanArgs, AnonymousScanOptions, DslPlan, Literal, LiteralValue, Null,
-    NULL,
+    OptimizationRule, ScanPredicate, ScanSource, ScanSourceArgs, ScanSourceStream, NULL,
 };
-pub use polars_plan::prelude::UnionArgs;
+#[cfg(feature = "serde")]
+pub use polars_plan::plans::{PlanFormatVersion, PlanSerializationFormat, PLAN_FORMAT_VERSION};
 pub(crate) use polars_plan::prelude::*;
-#[cfg(feature = "rolling_window_by")]
+pub use polars_plan::prelude::{FrameSetOperation, PartitionSinkOptions, SinkPlugin, UnionArgs};
+#[cfg(any(feature = "rolling_window_by", feature = "dynamic_group_by"))]
 pub use polars_time::Duration;
 #[cfg(feature = "dynamic_group_by")]
 pub use polars_time::{DynamicGroupOptions, PolarsTemporalGroupby, RollingGroupOptions};
 pub(crate) use polars_utils::arena::{Arena, Node};
+pub use polars_utils::cancel::CancellationToken;
 
 pub use crate::dsl::*;
 pub use crate::frame::*;
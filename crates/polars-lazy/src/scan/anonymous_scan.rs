@@ -47,4 +47,12 @@ impl LazyFrame {
 
         Ok(lf)
     }
+
+    /// Create a [`LazyFrame`] that reads from a custom [`ScanSource`].
+    ///
+    /// Unlike [`LazyFrame::anonymous_scan`], the source is read in batches, is supported by
+    /// the streaming engine and can take pushed down predicates and row indices.
+    pub fn scan_source(source: Arc<dyn ScanSource>) -> PolarsResult<Self> {
+        Ok(DslBuilder::scan_source(source)?.build().into())
+    }
 }
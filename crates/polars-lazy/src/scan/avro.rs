@@ -0,0 +1,131 @@
+use std::any::Any;
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_io::avro::AvroReader;
+use polars_io::{RowIndex, SerReader};
+
+use crate::prelude::*;
+
+#[derive(Clone, Default)]
+pub struct ScanArgsAvro {
+    pub n_rows: Option<usize>,
+    pub rechunk: bool,
+    pub row_index: Option<RowIndex>,
+}
+
+/// Scans a single Avro file. Avro blocks carry no statistics, so only projections and slices
+/// are pushed into the reader; predicates are applied after reading.
+struct AvroScan {
+    path: PathBuf,
+    rechunk: bool,
+}
+
+impl AnonymousScan for AvroScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let file = polars_utils::open_file(&self.path)?;
+        AvroReader::new(file)
+            .with_columns(scan_opts.with_columns.map(|columns| columns.to_vec()))
+            .with_n_rows(scan_opts.n_rows)
+            .set_rechunk(self.rechunk)
+            .finish()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        let file = polars_utils::open_file(&self.path)?;
+        Ok(Arc::new(AvroReader::new(file).schema()?))
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Clone)]
+struct LazyAvroReader {
+    args: ScanArgsAvro,
+    paths: Arc<[PathBuf]>,
+}
+
+impl LazyAvroReader {
+    fn new(args: ScanArgsAvro) -> Self {
+        Self {
+            args,
+            paths: Arc::new([]),
+        }
+    }
+}
+
+impl LazyFileListReader for LazyAvroReader {
+    fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+        let path = self.paths[0].clone();
+        let function = Arc::new(AvroScan {
+            path,
+            rechunk: self.args.rechunk,
+        });
+        let args = ScanArgsAnonymous {
+            n_rows: self.args.n_rows,
+            row_index: self.args.row_index,
+            name: "AVRO SCAN",
+            ..Default::default()
+        };
+        LazyFrame::anonymous_scan(function, args)
+    }
+
+    fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    fn with_paths(mut self, paths: Arc<[PathBuf]>) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    fn with_n_rows(mut self, n_rows: impl Into<Option<usize>>) -> Self {
+        self.args.n_rows = n_rows.into();
+        self
+    }
+
+    fn with_row_index(mut self, row_index: impl Into<Option<RowIndex>>) -> Self {
+        self.args.row_index = row_index.into();
+        self
+    }
+
+    fn rechunk(&self) -> bool {
+        self.args.rechunk
+    }
+
+    fn with_rechunk(mut self, toggle: bool) -> Self {
+        self.args.rechunk = toggle;
+        self
+    }
+
+    fn n_rows(&self) -> Option<usize> {
+        self.args.n_rows
+    }
+
+    fn row_index(&self) -> Option<&RowIndex> {
+        self.args.row_index.as_ref()
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame directly from an Avro scan.
+    pub fn scan_avro(path: impl AsRef<Path>, args: ScanArgsAvro) -> PolarsResult<Self> {
+        LazyAvroReader::new(args)
+            .with_paths(Arc::new([path.as_ref().to_path_buf()]))
+            .finish()
+    }
+
+    pub fn scan_avro_files(paths: Arc<[PathBuf]>, args: ScanArgsAvro) -> PolarsResult<Self> {
+        LazyAvroReader::new(args).with_paths(paths).finish()
+    }
+}
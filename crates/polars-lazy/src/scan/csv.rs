@@ -4,6 +4,7 @@ use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::csv::read::{
     infer_file_schema, CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues,
+    SeparatorPattern,
 };
 use polars_io::utils::get_reader_bytes;
 use polars_io::RowIndex;
@@ -115,6 +116,18 @@ impl LazyCsvReader {
         self.map_parse_options(|opts| opts.with_separator(separator))
     }
 
+    /// Set the separator from a string, which can be longer than a single byte.
+    #[must_use]
+    pub fn with_separator_str(self, separator: &str) -> Self {
+        self.map_parse_options(|opts| opts.with_separator_str(separator))
+    }
+
+    /// Separate the fields by a string or a regex instead of a single byte.
+    #[must_use]
+    pub fn with_separator_pattern(self, separator_pattern: Option<SeparatorPattern>) -> Self {
+        self.map_parse_options(|opts| opts.with_separator_pattern(separator_pattern.clone()))
+    }
+
     /// Set the comment prefix for this instance. Lines starting with this prefix will be ignored.
     #[must_use]
     pub fn with_comment_prefix(self, comment_prefix: Option<&str>) -> Self {
@@ -226,10 +239,11 @@ impl LazyCsvReader {
         let reader_bytes = get_reader_bytes(&mut file).expect("could not mmap file");
         let skip_rows = self.read_options.skip_rows;
         let parse_options = self.read_options.get_parse_options();
+        let (reader_bytes, separator) = parse_options.apply_separator_pattern(reader_bytes)?;
 
         let (schema, _, _) = infer_file_schema(
             &reader_bytes,
-            parse_options.separator,
+            separator,
             self.read_options.infer_schema_length,
             self.read_options.has_header,
             // we set it to None and modify them after the schema is updated
@@ -0,0 +1,178 @@
+use std::any::Any;
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_expr::{create_physical_expr, ExpressionConversionState};
+use polars_io::delta::{DeltaDataFile, DeltaTable};
+use polars_io::parquet::read::ParquetReader;
+use polars_io::{HiveOptions, RowIndex, SerReader};
+
+use crate::prelude::*;
+
+/// Holds the position of the rows in their data file, to which the deletion vectors refer.
+const ROW_POSITION: &str = "__delta_row_position";
+
+#[derive(Clone, Default)]
+pub struct ScanArgsDelta {
+    /// The version of the table to read, the latest version if `None`.
+    pub version: Option<i64>,
+    pub n_rows: Option<usize>,
+    pub rechunk: bool,
+    pub row_index: Option<RowIndex>,
+}
+
+/// Scans a version of a Delta table. The predicate is used to prune data files during the log
+/// replay, after which every remaining file is read with the regular Parquet scan and the rows
+/// in its deletion vector are filtered out.
+struct DeltaScan {
+    table: DeltaTable,
+    rechunk: bool,
+}
+
+impl DeltaScan {
+    fn scan_file(
+        &self,
+        data_file: &DeltaDataFile,
+        columns: &[String],
+        predicate: Option<&Expr>,
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.table.schema();
+        // Columns that were added to the table after the file was written are missing from it.
+        let file_schema = ParquetReader::new(polars_utils::open_file(&data_file.path)?).schema()?;
+
+        let mut exprs = columns
+            .iter()
+            .map(|name| {
+                let dtype = schema.try_get(name)?;
+                // Partition columns are not stored in the data files.
+                let expr = match data_file
+                    .partition_values
+                    .iter()
+                    .find(|s| s.name() == name.as_str())
+                {
+                    Some(value) => lit(value.clone()).first(),
+                    None if file_schema.fields.iter().any(|f| &f.name == name) => {
+                        col(name).strict_cast(dtype.clone())
+                    },
+                    None => lit(Null {}).cast(dtype.clone()),
+                };
+                Ok(expr.alias(name))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        exprs.push(col(ROW_POSITION));
+
+        let args = ScanArgsParquet {
+            row_index: Some(RowIndex {
+                name: Arc::from(ROW_POSITION),
+                offset: 0,
+            }),
+            hive_options: HiveOptions {
+                enabled: Some(false),
+                ..Default::default()
+            },
+            glob: false,
+            ..Default::default()
+        };
+        let mut lf = LazyFrame::scan_parquet(&data_file.path, args)?.select(exprs);
+
+        if let Some(dv) = self.table.deletion_vector(data_file)? {
+            let keep = col(ROW_POSITION).map(
+                move |s| Ok(Some(dv.keep_mask(s.idx()?).into_series())),
+                GetOutput::from_type(DataType::Boolean),
+            );
+            lf = lf.filter(keep);
+        }
+        if let Some(predicate) = predicate {
+            lf = lf.filter(predicate.clone());
+        }
+        Ok(lf.drop([ROW_POSITION]))
+    }
+}
+
+impl AnonymousScan for DeltaScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let io_predicate = scan_opts
+            .predicate
+            .as_ref()
+            .map(|predicate| {
+                let mut arena = Arena::with_capacity(16);
+                let expr = to_expr_ir(predicate.clone(), &mut arena);
+                let phys_expr = create_physical_expr(
+                    &expr,
+                    Context::Default,
+                    &arena,
+                    Some(&scan_opts.schema),
+                    &mut ExpressionConversionState::new(true, 0),
+                )?;
+                PolarsResult::Ok(phys_expr_to_io_expr(phys_expr))
+            })
+            .transpose()?;
+        let data_files = self.table.data_files(io_predicate.as_deref())?;
+
+        let schema = &scan_opts.schema;
+        let columns = match &scan_opts.with_columns {
+            Some(columns) => columns.to_vec(),
+            None => schema.iter_names().map(|name| name.to_string()).collect(),
+        };
+
+        let lfs = data_files
+            .iter()
+            .map(|data_file| self.scan_file(data_file, &columns, scan_opts.predicate.as_ref()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        if lfs.is_empty() {
+            let output_schema = scan_opts.output_schema.as_ref().unwrap_or(schema);
+            return Ok(DataFrame::empty_with_schema(output_schema));
+        }
+
+        let mut lf = concat(
+            lfs,
+            UnionArgs {
+                rechunk: self.rechunk,
+                ..Default::default()
+            },
+        )?;
+        if let Some(n_rows) = scan_opts.n_rows {
+            lf = lf.slice(0, n_rows as IdxSize);
+        }
+        lf.collect()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.table.schema())
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a version of the Delta table in directory `location`.
+    pub fn scan_delta(location: impl AsRef<Path>, args: ScanArgsDelta) -> PolarsResult<Self> {
+        let table = DeltaTable::open(location, args.version)?;
+        let function = Arc::new(DeltaScan {
+            table,
+            rechunk: args.rechunk,
+        });
+        let args = ScanArgsAnonymous {
+            n_rows: args.n_rows,
+            row_index: args.row_index,
+            name: "DELTA SCAN",
+            ..Default::default()
+        };
+        LazyFrame::anonymous_scan(function, args)
+    }
+}
@@ -0,0 +1,169 @@
+use std::any::Any;
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_io::excel::{CellRange, ExcelReader, ExcelSheet};
+use polars_io::{RowIndex, SerReader};
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsExcel {
+    pub sheet: ExcelSheet,
+    pub has_header: bool,
+    pub skip_rows: usize,
+    pub range: Option<CellRange>,
+    pub n_rows: Option<usize>,
+    pub rechunk: bool,
+    pub row_index: Option<RowIndex>,
+    pub schema_overwrite: Option<SchemaRef>,
+    pub infer_schema_length: Option<usize>,
+}
+
+impl Default for ScanArgsExcel {
+    fn default() -> Self {
+        Self {
+            sheet: ExcelSheet::default(),
+            has_header: true,
+            skip_rows: 0,
+            range: None,
+            n_rows: None,
+            rechunk: false,
+            row_index: None,
+            schema_overwrite: None,
+            infer_schema_length: Some(100),
+        }
+    }
+}
+
+/// Scans a sheet of a single xlsx workbook. Slices are pushed into the reader, which stops
+/// parsing the sheet after the last row that is needed.
+struct ExcelScan {
+    path: PathBuf,
+    args: ScanArgsExcel,
+}
+
+impl ExcelScan {
+    fn reader(&self) -> PolarsResult<ExcelReader<std::fs::File>> {
+        let file = polars_utils::open_file(&self.path)?;
+        Ok(ExcelReader::new(file)
+            .with_sheet(self.args.sheet.clone())
+            .with_has_header(self.args.has_header)
+            .with_skip_rows(self.args.skip_rows)
+            .with_range(self.args.range)
+            .with_infer_schema_length(self.args.infer_schema_length))
+    }
+}
+
+impl AnonymousScan for ExcelScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        // The types are inferred from the first rows, which a slice may leave out.
+        self.reader()?
+            .with_schema_overwrite(Some(scan_opts.schema))
+            .with_columns(scan_opts.with_columns.map(|columns| columns.to_vec()))
+            .with_n_rows(scan_opts.n_rows)
+            .finish()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        let schema = self
+            .reader()?
+            .with_schema_overwrite(self.args.schema_overwrite.clone())
+            .schema()?;
+        Ok(Arc::new(schema))
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Clone)]
+struct LazyExcelReader {
+    args: ScanArgsExcel,
+    paths: Arc<[PathBuf]>,
+}
+
+impl LazyExcelReader {
+    fn new(args: ScanArgsExcel) -> Self {
+        Self {
+            args,
+            paths: Arc::new([]),
+        }
+    }
+}
+
+impl LazyFileListReader for LazyExcelReader {
+    fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+        let path = self.paths[0].clone();
+        let args = ScanArgsAnonymous {
+            n_rows: self.args.n_rows,
+            row_index: self.args.row_index.clone(),
+            name: "EXCEL SCAN",
+            ..Default::default()
+        };
+        let function = Arc::new(ExcelScan {
+            path,
+            args: self.args,
+        });
+        LazyFrame::anonymous_scan(function, args)
+    }
+
+    fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    fn with_paths(mut self, paths: Arc<[PathBuf]>) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    fn with_n_rows(mut self, n_rows: impl Into<Option<usize>>) -> Self {
+        self.args.n_rows = n_rows.into();
+        self
+    }
+
+    fn with_row_index(mut self, row_index: impl Into<Option<RowIndex>>) -> Self {
+        self.args.row_index = row_index.into();
+        self
+    }
+
+    fn rechunk(&self) -> bool {
+        self.args.rechunk
+    }
+
+    fn with_rechunk(mut self, toggle: bool) -> Self {
+        self.args.rechunk = toggle;
+        self
+    }
+
+    fn n_rows(&self) -> Option<usize> {
+        self.args.n_rows
+    }
+
+    fn row_index(&self) -> Option<&RowIndex> {
+        self.args.row_index.as_ref()
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame directly from a scan of a sheet of an xlsx workbook.
+    pub fn scan_excel(path: impl AsRef<Path>, args: ScanArgsExcel) -> PolarsResult<Self> {
+        LazyExcelReader::new(args)
+            .with_paths(Arc::new([path.as_ref().to_path_buf()]))
+            .finish()
+    }
+
+    /// Scan the same sheet of several xlsx workbooks, concatenating their rows.
+    pub fn scan_excel_files(paths: Arc<[PathBuf]>, args: ScanArgsExcel) -> PolarsResult<Self> {
+        LazyExcelReader::new(args).with_paths(paths).finish()
+    }
+}
@@ -20,7 +20,7 @@ pub(super) fn get_glob_start_idx(path: &[u8]) -> Option<usize> {
 /// Recursively traverses directories and expands globs if `glob` is `true`.
 /// Returns the expanded paths and the index at which to start parsing hive
 /// partitions from the path.
-fn expand_paths(
+pub(crate) fn expand_paths(
     paths: &[PathBuf],
     #[allow(unused_variables)] cloud_options: Option<&CloudOptions>,
     glob: bool,
@@ -0,0 +1,69 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_io::flight::{FlightQuery, FlightReader};
+
+use crate::prelude::*;
+
+#[derive(Clone, Default)]
+pub struct ScanArgsFlight {
+    pub n_rows: Option<usize>,
+    pub rechunk: bool,
+}
+
+/// Scans the result of a query on an Arrow Flight server. Only the projected columns are
+/// deserialized and a slice stops fetching once enough rows have arrived.
+struct FlightScan {
+    location: String,
+    query: FlightQuery,
+    rechunk: bool,
+}
+
+impl AnonymousScan for FlightScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        FlightReader::new(self.location.clone(), self.query.clone())
+            .with_columns(scan_opts.with_columns.map(|columns| columns.to_vec()))
+            .with_n_rows(scan_opts.n_rows)
+            .set_rechunk(self.rechunk)
+            .finish()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        let mut reader = FlightReader::new(self.location.clone(), self.query.clone());
+        Ok(Arc::new(reader.schema()?))
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from the result of a query on an Arrow Flight server, e.g.
+    /// `"grpc://localhost:32010"`. All endpoints of the query are fetched in parallel.
+    pub fn scan_flight(
+        location: impl Into<String>,
+        query: FlightQuery,
+        args: ScanArgsFlight,
+    ) -> PolarsResult<Self> {
+        let function = Arc::new(FlightScan {
+            location: location.into(),
+            query,
+            rechunk: args.rechunk,
+        });
+        let args = ScanArgsAnonymous {
+            n_rows: args.n_rows,
+            name: "FLIGHT SCAN",
+            ..Default::default()
+        };
+        LazyFrame::anonymous_scan(function, args)
+    }
+}
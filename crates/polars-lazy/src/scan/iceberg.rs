@@ -0,0 +1,146 @@
+use std::any::Any;
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_expr::{create_physical_expr, ExpressionConversionState};
+use polars_io::iceberg::{IcebergSnapshot, IcebergTable};
+use polars_io::{HiveOptions, RowIndex};
+
+use crate::prelude::*;
+
+#[derive(Clone, Default)]
+pub struct ScanArgsIceberg {
+    pub snapshot: IcebergSnapshot,
+    pub n_rows: Option<usize>,
+    pub rechunk: bool,
+    pub row_index: Option<RowIndex>,
+}
+
+/// Scans a snapshot of an Iceberg table. The predicate is used to prune manifests and data
+/// files, after which the remaining Parquet files are read with the regular Parquet scan.
+struct IcebergScan {
+    table: IcebergTable,
+    rechunk: bool,
+}
+
+impl AnonymousScan for IcebergScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let io_predicate = scan_opts
+            .predicate
+            .as_ref()
+            .map(|predicate| {
+                let mut arena = Arena::with_capacity(16);
+                let expr = to_expr_ir(predicate.clone(), &mut arena);
+                let phys_expr = create_physical_expr(
+                    &expr,
+                    Context::Default,
+                    &arena,
+                    Some(&scan_opts.schema),
+                    &mut ExpressionConversionState::new(true, 0),
+                )?;
+                PolarsResult::Ok(phys_expr_to_io_expr(phys_expr))
+            })
+            .transpose()?;
+        let data_files = self.table.data_files(io_predicate.as_deref())?;
+
+        let schema = &scan_opts.schema;
+        let columns = match &scan_opts.with_columns {
+            Some(columns) => columns.to_vec(),
+            None => schema.iter_names().map(|name| name.to_string()).collect(),
+        };
+
+        // Files that were written with the same schema are read with a single Parquet scan.
+        let mut groups: PlIndexMap<Vec<Option<String>>, Vec<PathBuf>> = PlIndexMap::default();
+        for data_file in data_files {
+            let mapping = self.table.column_mapping(&data_file.path)?;
+            groups.entry(mapping).or_default().push(data_file.path);
+        }
+
+        let lfs = groups
+            .into_iter()
+            .map(|(mapping, paths)| {
+                let args = ScanArgsParquet {
+                    hive_options: HiveOptions {
+                        enabled: Some(false),
+                        ..Default::default()
+                    },
+                    glob: false,
+                    ..Default::default()
+                };
+                let exprs = columns
+                    .iter()
+                    .map(|name| {
+                        let (idx, _, dtype) = schema.try_get_full(name)?;
+                        let expr = match &mapping[idx] {
+                            Some(file_name) => col(file_name).strict_cast(dtype.clone()),
+                            None => lit(Null {}).cast(dtype.clone()),
+                        };
+                        Ok(expr.alias(name))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+
+                let mut lf = LazyFrame::scan_parquet_files(paths.into(), args)?.select(exprs);
+                if let Some(predicate) = &scan_opts.predicate {
+                    lf = lf.filter(predicate.clone());
+                }
+                Ok(lf)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        if lfs.is_empty() {
+            let output_schema = scan_opts.output_schema.as_ref().unwrap_or(schema);
+            return Ok(DataFrame::empty_with_schema(output_schema));
+        }
+
+        let mut lf = concat(
+            lfs,
+            UnionArgs {
+                rechunk: self.rechunk,
+                ..Default::default()
+            },
+        )?;
+        if let Some(n_rows) = scan_opts.n_rows {
+            lf = lf.slice(0, n_rows as IdxSize);
+        }
+        lf.collect()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(Arc::new(self.table.schema()?))
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a snapshot of the Iceberg table at `location`, which is either
+    /// the table directory or one of its metadata files.
+    pub fn scan_iceberg(location: impl AsRef<Path>, args: ScanArgsIceberg) -> PolarsResult<Self> {
+        let table = IcebergTable::open(location, args.snapshot)?;
+        let function = Arc::new(IcebergScan {
+            table,
+            rechunk: args.rechunk,
+        });
+        let args = ScanArgsAnonymous {
+            n_rows: args.n_rows,
+            row_index: args.row_index,
+            name: "ICEBERG SCAN",
+            ..Default::default()
+        };
+        LazyFrame::anonymous_scan(function, args)
+    }
+}
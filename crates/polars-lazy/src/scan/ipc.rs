@@ -15,6 +15,8 @@ pub struct ScanArgsIpc {
     pub row_index: Option<RowIndex>,
     pub memory_map: bool,
     pub cloud_options: Option<CloudOptions>,
+    /// Skip files that don't exist with a warning instead of raising an error.
+    pub allow_missing_files: bool,
 }
 
 impl Default for ScanArgsIpc {
@@ -26,6 +28,7 @@ impl Default for ScanArgsIpc {
             row_index: None,
             memory_map: true,
             cloud_options: Default::default(),
+            allow_missing_files: false,
         }
     }
 }
@@ -62,6 +65,7 @@ impl LazyFileListReader for LazyIpcReader {
             args.row_index,
             args.rechunk,
             args.cloud_options,
+            args.allow_missing_files,
         )?
         .build()
         .into();
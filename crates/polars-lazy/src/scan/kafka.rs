@@ -0,0 +1,103 @@
+use polars_core::prelude::*;
+use polars_io::kafka::{KafkaFormat, KafkaReader};
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsKafka {
+    /// Partitions to read, `None` reads all partitions of the topic.
+    pub partitions: Option<Vec<i32>>,
+    /// First offset to read of every partition.
+    pub start_offset: i64,
+    /// Offset to stop reading at, exclusive. `None` reads up to the last message that was
+    /// written when the scan is executed.
+    pub end_offset: Option<i64>,
+    /// Add the `_partition` and `_offset` of every message as columns.
+    pub include_metadata: bool,
+    /// Maximum number of bytes that is fetched at once.
+    pub batch_size: Option<usize>,
+}
+
+impl Default for ScanArgsKafka {
+    fn default() -> Self {
+        Self {
+            partitions: None,
+            start_offset: 0,
+            end_offset: None,
+            include_metadata: false,
+            batch_size: None,
+        }
+    }
+}
+
+/// Reads a bounded offset range of a Kafka topic in batches, one partition after the other.
+struct KafkaScan {
+    brokers: Vec<String>,
+    topic: String,
+    format: KafkaFormat,
+    args: ScanArgsKafka,
+}
+
+impl KafkaScan {
+    fn reader(&self) -> KafkaReader {
+        let mut reader = KafkaReader::new(self.brokers.clone(), &self.topic, self.format.clone())
+            .with_partitions(self.args.partitions.clone())
+            .with_offsets(self.args.start_offset, self.args.end_offset)
+            .with_metadata(self.args.include_metadata);
+        if let Some(batch_size) = self.args.batch_size {
+            reader = reader.with_batch_size(batch_size);
+        }
+        reader
+    }
+}
+
+impl ScanSource for KafkaScan {
+    fn name(&self) -> &'static str {
+        "KAFKA SCAN"
+    }
+
+    fn schema(&self) -> PolarsResult<SchemaRef> {
+        Ok(Arc::new(self.reader().schema()?))
+    }
+
+    fn open(&self, args: ScanSourceArgs) -> PolarsResult<ScanSourceStream> {
+        let mut reader = self.reader().with_n_rows(args.n_rows).batched()?;
+        let projection = args
+            .with_columns
+            .map(|_| args.output_schema.iter_names().cloned().collect::<Vec<_>>());
+        Ok(Box::new(std::iter::from_fn(move || {
+            reader
+                .next_batch()
+                .transpose()
+                .map(|batch| match &projection {
+                    Some(columns) => batch?.select(columns),
+                    None => batch,
+                })
+        })))
+    }
+
+    fn supports_projection_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a bounded offset range of a Kafka topic.
+    ///
+    /// The payloads of the messages are decoded according to `format`. The scan is read in
+    /// batches, so a backfill over a large range can run in the streaming engine.
+    pub fn scan_kafka(
+        brokers: Vec<String>,
+        topic: impl Into<String>,
+        format: KafkaFormat,
+        args: ScanArgsKafka,
+    ) -> PolarsResult<Self> {
+        let source = Arc::new(KafkaScan {
+            brokers,
+            topic: topic.into(),
+            format,
+            args,
+        });
+        LazyFrame::scan_source(source)
+    }
+}
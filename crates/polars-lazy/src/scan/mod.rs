@@ -1,10 +1,24 @@
 pub(super) mod anonymous_scan;
+#[cfg(feature = "avro")]
+pub(super) mod avro;
 #[cfg(feature = "csv")]
 pub(super) mod csv;
+#[cfg(feature = "delta")]
+pub(super) mod delta;
+#[cfg(feature = "excel")]
+pub(super) mod excel;
 pub(super) mod file_list_reader;
+#[cfg(feature = "flight")]
+pub(super) mod flight;
+#[cfg(feature = "iceberg")]
+pub(super) mod iceberg;
 #[cfg(feature = "ipc")]
 pub(super) mod ipc;
+#[cfg(feature = "kafka")]
+pub(super) mod kafka;
 #[cfg(feature = "json")]
 pub(super) mod ndjson;
+#[cfg(feature = "orc")]
+pub(super) mod orc;
 #[cfg(feature = "parquet")]
 pub(super) mod parquet;
@@ -100,11 +100,14 @@ impl LazyFileListReader for LazyJsonLineReader {
 
         let file_options = FileScanOptions {
             n_rows: self.n_rows,
+            tail: None,
             with_columns: None,
             cache: false,
             row_index: self.row_index,
             rechunk: self.rechunk,
             file_counter: 0,
+            hive_pruned_files: 0,
+            allow_missing_files: false,
             hive_options: Default::default(),
         };
 
@@ -132,11 +135,14 @@ impl LazyFileListReader for LazyJsonLineReader {
     fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
         let file_options = FileScanOptions {
             n_rows: self.n_rows,
+            tail: None,
             with_columns: None,
             cache: false,
             row_index: self.row_index.clone(),
             rechunk: self.rechunk,
             file_counter: 0,
+            hive_pruned_files: 0,
+            allow_missing_files: false,
             hive_options: Default::default(),
         };
 
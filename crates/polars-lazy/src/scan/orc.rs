@@ -0,0 +1,168 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_io::orc::OrcReader;
+use polars_io::{RowIndex, SerReader};
+
+use crate::prelude::*;
+
+#[derive(Clone, Default)]
+pub struct ScanArgsOrc {
+    pub n_rows: Option<usize>,
+    pub rechunk: bool,
+    pub row_index: Option<RowIndex>,
+}
+
+/// Scans a single ORC file one stripe at a time, so that it can run in the streaming engine.
+/// Projections, predicates, row indices and slices are pushed into the reader, so that only
+/// the needed columns and stripes are read.
+struct OrcScan {
+    path: PathBuf,
+}
+
+impl OrcScan {
+    fn reader(&self) -> PolarsResult<OrcReader<File>> {
+        Ok(OrcReader::new(polars_utils::open_file(&self.path)?))
+    }
+}
+
+impl ScanSource for OrcScan {
+    fn name(&self) -> &'static str {
+        "ORC SCAN"
+    }
+
+    fn schema(&self) -> PolarsResult<SchemaRef> {
+        Ok(Arc::new(self.reader()?.schema()?))
+    }
+
+    fn open(&self, args: ScanSourceArgs) -> PolarsResult<ScanSourceStream> {
+        // The row index is added by the reader, it is not a column of the file.
+        let columns = args.with_columns.as_ref().map(|columns| {
+            columns
+                .iter()
+                .filter(|name| args.schema.contains(name))
+                .cloned()
+                .collect::<Vec<_>>()
+        });
+        let projection = args
+            .with_columns
+            .map(|_| args.output_schema.iter_names().cloned().collect::<Vec<_>>());
+        let mut reader = self
+            .reader()?
+            .with_columns(columns)
+            .with_predicate(args.predicate.map(|predicate| predicate.evaluator))
+            .with_row_index(args.row_index)
+            .with_n_rows(args.n_rows)
+            .batched()?;
+        Ok(Box::new(std::iter::from_fn(move || {
+            reader
+                .next_batch()
+                .transpose()
+                .map(|batch| match &projection {
+                    Some(columns) => batch?.select(columns),
+                    None => batch,
+                })
+        })))
+    }
+
+    fn supports_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn supports_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn supports_row_index(&self) -> bool {
+        true
+    }
+
+    fn row_count(&self) -> Option<usize> {
+        self.reader().and_then(|mut reader| reader.num_rows()).ok()
+    }
+}
+
+#[derive(Clone)]
+struct LazyOrcReader {
+    args: ScanArgsOrc,
+    paths: Arc<[PathBuf]>,
+}
+
+impl LazyOrcReader {
+    fn new(args: ScanArgsOrc) -> Self {
+        Self {
+            args,
+            paths: Arc::new([]),
+        }
+    }
+}
+
+impl LazyFileListReader for LazyOrcReader {
+    fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+        let source = Arc::new(OrcScan {
+            path: self.paths[0].clone(),
+        });
+        let mut lf = LazyFrame::scan_source(source)?;
+        if let DslPlan::Scan { file_options, .. } = &mut lf.logical_plan {
+            file_options.rechunk = self.args.rechunk;
+        }
+        // The row index is added first, so that it is pushed into the scan.
+        if let Some(rc) = self.args.row_index {
+            lf = lf.with_row_index(&rc.name, Some(rc.offset));
+        }
+        if let Some(n_rows) = self.args.n_rows {
+            lf = lf.slice(0, n_rows as IdxSize);
+        }
+        Ok(lf)
+    }
+
+    fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    fn with_paths(mut self, paths: Arc<[PathBuf]>) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    fn with_n_rows(mut self, n_rows: impl Into<Option<usize>>) -> Self {
+        self.args.n_rows = n_rows.into();
+        self
+    }
+
+    fn with_row_index(mut self, row_index: impl Into<Option<RowIndex>>) -> Self {
+        self.args.row_index = row_index.into();
+        self
+    }
+
+    fn rechunk(&self) -> bool {
+        self.args.rechunk
+    }
+
+    fn with_rechunk(mut self, toggle: bool) -> Self {
+        self.args.rechunk = toggle;
+        self
+    }
+
+    fn n_rows(&self) -> Option<usize> {
+        self.args.n_rows
+    }
+
+    fn row_index(&self) -> Option<&RowIndex> {
+        self.args.row_index.as_ref()
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame directly from an ORC scan.
+    pub fn scan_orc(path: impl AsRef<Path>, args: ScanArgsOrc) -> PolarsResult<Self> {
+        LazyOrcReader::new(args)
+            .with_paths(Arc::new([path.as_ref().to_path_buf()]))
+            .finish()
+    }
+
+    pub fn scan_orc_files(paths: Arc<[PathBuf]>, args: ScanArgsOrc) -> PolarsResult<Self> {
+        LazyOrcReader::new(args).with_paths(paths).finish()
+    }
+}
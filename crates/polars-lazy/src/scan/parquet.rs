@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
-use polars_io::parquet::read::ParallelStrategy;
+use polars_io::parquet::read::{LazyColumns, ParallelStrategy, LAZY_COLUMNS_POSITION};
 use polars_io::utils::is_cloud_url;
 use polars_io::{HiveOptions, RowIndex};
 
@@ -22,6 +22,8 @@ pub struct ScanArgsParquet {
     pub cache: bool,
     /// Expand path given via globbing rules.
     pub glob: bool,
+    /// Skip files that don't exist with a warning instead of raising an error.
+    pub allow_missing_files: bool,
 }
 
 impl Default for ScanArgsParquet {
@@ -37,6 +39,7 @@ impl Default for ScanArgsParquet {
             low_memory: false,
             cache: true,
             glob: true,
+            allow_missing_files: false,
         }
     }
 }
@@ -86,6 +89,7 @@ impl LazyFileListReader for LazyParquetReader {
             self.args.cloud_options,
             self.args.use_statistics,
             self.args.hive_options,
+            self.args.allow_missing_files,
         )?
         .build()
         .into();
@@ -160,4 +164,60 @@ impl LazyFrame {
     pub fn scan_parquet_files(paths: Arc<[PathBuf]>, args: ScanArgsParquet) -> PolarsResult<Self> {
         LazyParquetReader::new(args).with_paths(paths).finish()
     }
+
+    /// Scan the Parquet file of `lazy_columns`, with the row positions of the lazy columns in
+    /// place of their values.
+    ///
+    /// Filters, sorts, slices and joins then only move the positions around. Use
+    /// [`materialize_lazy_columns`](LazyFrame::materialize_lazy_columns) before the first
+    /// expression that uses the values.
+    pub fn scan_parquet_lazy_columns(
+        lazy_columns: &LazyColumns,
+        args: ScanArgsParquet,
+    ) -> PolarsResult<Self> {
+        let args = ScanArgsParquet {
+            row_index: Some(RowIndex {
+                name: Arc::from(LAZY_COLUMNS_POSITION),
+                offset: 0,
+            }),
+            ..args
+        };
+        Ok(Self::scan_parquet(lazy_columns.path(), args)?.drop(lazy_columns.names()))
+    }
+
+    /// Read the values of the lazy columns at the row positions in the
+    /// [`LAZY_COLUMNS_POSITION`] column, which they replace.
+    ///
+    /// Only the row groups that hold the remaining rows are read.
+    pub fn materialize_lazy_columns(self, lazy_columns: LazyColumns) -> Self {
+        let lazy_schema = lazy_columns.schema();
+        let schema = move |input_schema: &Schema| {
+            polars_ensure!(
+                input_schema.contains(LAZY_COLUMNS_POSITION),
+                ColumnNotFound: "{}", LAZY_COLUMNS_POSITION
+            );
+            let mut schema = Schema::with_capacity(input_schema.len() + lazy_schema.len());
+            for (name, dtype) in input_schema.iter() {
+                if name.as_str() == LAZY_COLUMNS_POSITION {
+                    schema.merge_from_ref(&lazy_schema);
+                } else {
+                    schema.with_column(name.clone(), dtype.clone());
+                }
+            }
+            Ok(Arc::new(schema))
+        };
+        // The function adds columns that don't exist below it, so neither projections nor
+        // predicates can be pushed through it.
+        let optimizations = AllowedOptimizations {
+            projection_pushdown: false,
+            predicate_pushdown: false,
+            ..Default::default()
+        };
+        self.map(
+            move |df| lazy_columns.materialize(&df),
+            optimizations,
+            Some(Arc::new(schema)),
+            Some("MATERIALIZE LAZY COLUMNS"),
+        )
+    }
 }
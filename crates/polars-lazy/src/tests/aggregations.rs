@@ -450,6 +450,8 @@ fn take_aggregations() -> PolarsResult<()> {
                             nulls_last: false,
                             multithreaded: true,
                             maintain_order: false,
+                            stable: true,
+                            collation: None,
                         })
                         .head(Some(2)),
                 )
@@ -489,6 +491,8 @@ fn test_take_consistency() -> PolarsResult<()> {
                 nulls_last: false,
                 multithreaded: true,
                 maintain_order: false,
+                stable: true,
+                collation: None,
             })
             .get(lit(0))])
         .collect()?;
@@ -507,6 +511,8 @@ fn test_take_consistency() -> PolarsResult<()> {
                 nulls_last: false,
                 multithreaded: true,
                 maintain_order: false,
+                stable: true,
+                collation: None,
             })
             .get(lit(0))])
         .collect()?;
@@ -526,6 +532,8 @@ fn test_take_consistency() -> PolarsResult<()> {
                     nulls_last: false,
                     multithreaded: true,
                     maintain_order: false,
+                    stable: true,
+                    collation: None,
                 })
                 .get(lit(0))
                 .alias("1"),
@@ -537,6 +545,8 @@ fn test_take_consistency() -> PolarsResult<()> {
                             nulls_last: false,
                             multithreaded: true,
                             maintain_order: false,
+                            stable: true,
+                            collation: None,
                         })
                         .get(lit(0)),
                 )
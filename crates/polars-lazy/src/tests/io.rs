@@ -36,6 +36,31 @@ fn test_parquet_exec() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_lazy_columns() -> PolarsResult<()> {
+    init_files();
+    let lazy_columns = LazyColumns::try_new(FOODS_PARQUET, &["category".into()])?;
+    let out = LazyFrame::scan_parquet_lazy_columns(&lazy_columns, Default::default())?
+        .filter(col("sugars_g").gt(lit(5)))
+        .sort(
+            ["calories"],
+            SortMultipleOptions::default().with_maintain_order(true),
+        )
+        .materialize_lazy_columns(lazy_columns)
+        .collect()?;
+
+    let expected = LazyFrame::scan_parquet(FOODS_PARQUET, Default::default())?
+        .filter(col("sugars_g").gt(lit(5)))
+        .sort(
+            ["calories"],
+            SortMultipleOptions::default().with_maintain_order(true),
+        )
+        .collect()?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
 #[test]
 #[cfg(all(feature = "parquet", feature = "is_between"))]
 fn test_parquet_statistics_no_skip() {
@@ -380,6 +405,79 @@ fn test_parquet_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn test_parquet_tail_skips_files() -> PolarsResult<()> {
+    init_files();
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let scan = || {
+        LazyFrame::scan_parquet("../../examples/datasets/foods*.parquet", Default::default())
+            .unwrap()
+            .with_row_index("idx", Some(10))
+    };
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = scan().tail(3).optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!((&lp_arena).iter(lp).any(|(_, lp)| matches!(
+        lp,
+        IR::Scan {
+            file_options: FileScanOptions { tail: Some(3), .. },
+            ..
+        }
+    )));
+
+    let expected = scan().collect()?.tail(Some(3));
+    assert!(scan().tail(3).collect()?.equals(&expected));
+
+    let head_tail = scan().head_tail(2)?;
+    assert_eq!(head_tail.height(), 4);
+    let idx = head_tail.column("idx")?.idx()?;
+    assert_eq!(
+        idx.into_no_null_iter().collect::<Vec<_>>(),
+        [10, 11, 62, 63]
+    );
+    assert_eq!(scan().head_tail(40)?.height(), 54);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(not(target_os = "windows"), feature = "is_between"))]
+fn test_parquet_row_index_range_pushdown() -> PolarsResult<()> {
+    init_files();
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let scan = || {
+        LazyFrame::scan_parquet("../../examples/datasets/foods*.parquet", Default::default())
+            .unwrap()
+            .with_row_index("idx", Some(10))
+    };
+    let predicate = col("idx")
+        .is_between(lit(20), lit(40), ClosedInterval::Left)
+        .or(col("idx").eq(lit(50)));
+
+    // The end of the range limits the rows that are read.
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = scan()
+        .filter(predicate.clone())
+        .optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!((&lp_arena).iter(lp).any(|(_, lp)| matches!(
+        lp,
+        IR::Scan {
+            file_options: FileScanOptions {
+                n_rows: Some(41),
+                ..
+            },
+            ..
+        }
+    )));
+
+    let out = scan().filter(predicate.clone()).collect()?;
+    let expected = scan().collect()?.lazy().filter(predicate).collect()?;
+    assert_eq!(out.height(), 21);
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
 #[test]
 fn test_scan_parquet_limit_9001() {
     init_files();
@@ -419,6 +517,7 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             row_index: None,
             memory_map: true,
             cloud_options: None,
+            allow_missing_files: false,
         },
     )?
     .collect()?;
@@ -489,6 +588,30 @@ fn test_ndjson_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "json")]
+fn test_ndjson_projection_predicate_pushdown() -> PolarsResult<()> {
+    // for side effects
+    init_files();
+    let path = "../../examples/datasets/foods1.ndjson";
+    let scan = || LazyJsonLineReader::new(path).finish();
+    let out = scan()?
+        .with_row_index("idx", Some(1))
+        .filter(col("calories").gt(lit(100)))
+        .select([col("idx"), col("category")])
+        .collect()?;
+    let expected = scan()?
+        .collect()?
+        .lazy()
+        .with_row_index("idx", Some(1))
+        .filter(col("calories").gt(lit(100)))
+        .select([col("idx"), col("category")])
+        .collect()?;
+    assert!(out.height() > 0);
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
 #[test]
 pub fn test_simple_slice() -> PolarsResult<()> {
     let _guard = SINGLE_LOCK.lock().unwrap();
@@ -701,6 +824,78 @@ fn scan_anonymous_fn_with_options() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn scan_source_pushdown() -> PolarsResult<()> {
+    struct BatchedSource {
+        expect_pushdown: bool,
+    }
+
+    impl ScanSource for BatchedSource {
+        fn name(&self) -> &'static str {
+            "BATCHED"
+        }
+
+        fn schema(&self) -> PolarsResult<SchemaRef> {
+            Ok(Arc::new(fruits_cars().schema()))
+        }
+
+        fn open(&self, args: ScanSourceArgs) -> PolarsResult<ScanSourceStream> {
+            assert_eq!(args.predicate.is_some(), self.expect_pushdown);
+            assert_eq!(args.with_columns.is_some(), self.expect_pushdown);
+            assert_eq!(args.row_index.is_some(), self.expect_pushdown);
+
+            let mut df = fruits_cars();
+            if let Some(row_index) = &args.row_index {
+                df = df.with_row_index(&row_index.name, Some(row_index.offset))?;
+            }
+            if let Some(predicate) = &args.predicate {
+                df = predicate.apply(&df)?;
+            }
+            let df = df.select(args.output_schema.iter_names())?;
+            // Ignore `n_rows`, the engine must cut off the stream.
+            let batches = (0..df.height())
+                .step_by(2)
+                .map(|offset| Ok(df.slice(offset as i64, 2)))
+                .collect::<Vec<_>>();
+            Ok(Box::new(batches.into_iter()))
+        }
+
+        fn supports_projection_pushdown(&self) -> bool {
+            true
+        }
+
+        fn supports_predicate_pushdown(&self) -> bool {
+            true
+        }
+
+        fn supports_row_index(&self) -> bool {
+            true
+        }
+    }
+
+    let source = Arc::new(BatchedSource {
+        expect_pushdown: true,
+    });
+    let q = LazyFrame::scan_source(source)?
+        .with_row_index("idx", Some(10))
+        .filter(col("A").gt(lit(2)))
+        .select([col("idx"), col("cars")]);
+    let expected = df![
+        "idx" => [12 as IdxSize, 13, 14],
+        "cars" => ["beetle", "beetle", "beetle"],
+    ]?;
+    assert!(q.clone().collect()?.equals(&expected));
+    #[cfg(feature = "streaming")]
+    assert!(q.with_streaming(true).collect()?.equals(&expected));
+
+    let source = Arc::new(BatchedSource {
+        expect_pushdown: false,
+    });
+    let df = LazyFrame::scan_source(source)?.limit(3).collect()?;
+    assert!(df.equals(&fruits_cars().head(Some(3))));
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "dtype-full")]
 fn scan_small_dtypes() -> PolarsResult<()> {
@@ -725,3 +920,302 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "streaming"))]
+fn test_sink_parquet_partitioned() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join("polars_test_sink_parquet_partitioned");
+    let _ = std::fs::remove_dir_all(&root);
+
+    let df = df![
+        "a" => [1, 1, 2, 2, 2, 3],
+        "b" => ["x", "x", "y", "y", "y", "z"],
+        "c" => [1, 2, 3, 4, 5, 6]
+    ]?;
+    df.clone().lazy().sink_parquet_partitioned(
+        &root,
+        PartitionSinkOptions::new(["a"]).with_max_rows_per_file(Some(2)),
+        Default::default(),
+    )?;
+
+    assert!(root.join("a=1/data-0000.parquet").exists());
+    assert!(!root.join("a=1/data-0001.parquet").exists());
+    assert!(root.join("a=2/data-0001.parquet").exists());
+    assert!(root.join("a=3/data-0000.parquet").exists());
+
+    let out = LazyFrame::scan_parquet(root.join("a=2/*.parquet"), Default::default())?.collect()?;
+    assert_eq!(out.column("c")?, &Series::new("c", [3, 4, 5]));
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "ipc", feature = "streaming"))]
+fn test_sink_ipc_write_mode() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join("polars_test_sink_ipc_write_mode");
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root)?;
+    let path = root.join("data.ipc");
+
+    let df = df!["a" => [1, 2], "b" => ["x", "y"]]?;
+    let options = |write_mode| IpcWriterOptions {
+        write_mode,
+        ..Default::default()
+    };
+    df.clone()
+        .lazy()
+        .sink_ipc(&path, options(WriteMode::ErrorIfExists))?;
+    let err = df
+        .clone()
+        .lazy()
+        .sink_ipc(&path, options(WriteMode::ErrorIfExists));
+    assert!(err.is_err());
+
+    df.clone()
+        .lazy()
+        .sink_ipc(&path, options(WriteMode::Append))?;
+    let out = LazyFrame::scan_ipc(&path, Default::default())?.collect()?;
+    assert!(out.equals(&df.vstack(&df)?));
+
+    let other = df!["c" => [1]]?;
+    assert!(other
+        .lazy()
+        .sink_ipc(&path, options(WriteMode::Append))
+        .is_err());
+    // Only the destination is left, failed writes remove their temporary files.
+    assert_eq!(std::fs::read_dir(&root)?.count(), 1);
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "ipc", feature = "streaming"))]
+fn test_sink_cancelled() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join("polars_test_sink_cancelled");
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root)?;
+    let path = root.join("data.ipc");
+
+    // Cancel from within the running pipeline, before the chunk reaches the sink.
+    let token = CancellationToken::new();
+    let cancel = token.clone();
+    let lf = df!["a" => [1, 2, 3]]?.lazy().map(
+        move |df| {
+            cancel.cancel();
+            Ok(df)
+        },
+        AllowedOptimizations {
+            streaming: true,
+            ..Default::default()
+        },
+        None,
+        Some("cancel"),
+    );
+    let err = token
+        .run(|| lf.sink_ipc(&path, Default::default()))
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::Cancelled(_)));
+    // The sink didn't commit the file.
+    assert!(!path.exists());
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "ipc_streaming",
+    feature = "streaming",
+    feature = "dtype-categorical"
+))]
+fn test_sink_ipc_stream() -> PolarsResult<()> {
+    use polars_io::ipc::{IpcCompression, IpcStreamReader};
+
+    let path = std::env::temp_dir().join("polars_test_sink_ipc_stream.arrows");
+    let df = df!["a" => ["x", "y", "x"], "b" => [1, 2, 3]]?;
+    let lf = df
+        .clone()
+        .lazy()
+        .with_column(col("a").cast(DataType::Categorical(None, Default::default())));
+
+    let options = IpcStreamWriterOptions {
+        compression: Some(IpcCompression::ZSTD),
+        compression_level: Some(3),
+        maintain_order: true,
+    };
+    lf.clone().sink_ipc_stream(&path, options)?;
+    let out = IpcStreamReader::new(std::fs::File::open(&path)?).finish()?;
+    assert!(out.column("a")?.dtype().is_categorical());
+    let out = out
+        .lazy()
+        .with_column(col("a").cast(DataType::String))
+        .collect()?;
+    assert!(out.equals(&df));
+
+    let options = IpcStreamWriterOptions {
+        compression_level: Some(30),
+        ..options
+    };
+    assert!(lf.sink_ipc_stream(&path, options).is_err());
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_join_reorder_parquet_statistics() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join("polars_test_join_reorder");
+    std::fs::create_dir_all(&root)?;
+    let write = |name: &str, mut df: DataFrame| -> PolarsResult<LazyFrame> {
+        let path = root.join(name);
+        ParquetWriter::new(std::fs::File::create(&path)?)
+            .with_statistics(StatisticsOptions::full())
+            .finish(&mut df)?;
+        LazyFrame::scan_parquet(path, Default::default())
+    };
+
+    let ids = (0..1000).collect::<Vec<i32>>();
+    let fact = write(
+        "fact.parquet",
+        df![
+            "id" => &ids,
+            "a_id" => ids.iter().map(|i| i % 100).collect::<Vec<_>>(),
+            "b_id" => ids.iter().map(|i| i % 10).collect::<Vec<_>>(),
+        ]?,
+    )?;
+    let dim_a = write(
+        "dim_a.parquet",
+        df![
+            "a_id" => (0..100).collect::<Vec<i32>>(),
+            "a" => (0..100).map(|i| i * 2).collect::<Vec<i32>>(),
+        ]?,
+    )?;
+    let dim_b = write(
+        "dim_b.parquet",
+        df![
+            "b_id" => (0..10).collect::<Vec<i32>>(),
+            "b" => (0..10).map(|i| format!("b{i}")).collect::<Vec<_>>(),
+        ]?,
+    )?;
+
+    // The selective dimension is joined last, but should be joined first.
+    let q = fact.inner_join(dim_a, col("a_id"), col("a_id")).inner_join(
+        dim_b.filter(col("b_id").lt(lit(3))),
+        col("b_id"),
+        col("b_id"),
+    );
+
+    let reordered = q.clone().describe_optimized_plan()?;
+    let original = q
+        .clone()
+        .with_join_reorder(false)
+        .describe_optimized_plan()?;
+    assert_ne!(reordered, original);
+
+    let out = q.clone().sort(["id"], Default::default()).collect()?;
+    let expected = q
+        .with_join_reorder(false)
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["id", "a_id", "b_id", "a", "b"]);
+    assert!(out.equals(&expected));
+    assert_eq!(out.height(), 300);
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "result_cache", feature = "csv"))]
+fn test_result_cache() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let path = std::env::temp_dir().join("polars_test_result_cache.csv");
+    let write = |values: &[i32]| -> PolarsResult<()> {
+        let mut df = df!["a" => values]?;
+        CsvWriter::new(std::fs::File::create(&path)?).finish(&mut df)
+    };
+    let filtered = || -> PolarsResult<LazyFrame> {
+        Ok(LazyCsvReader::new(&path)
+            .finish()?
+            .filter(col("a").gt(lit(1))))
+    };
+    write(&[1, 2, 3, 4])?;
+    enable_result_cache(Default::default());
+
+    assert_eq!(filtered()?.collect()?.height(), 3);
+
+    // A query that builds on the collected query reads its result from the cache.
+    let hit = std::cell::Cell::new(false);
+    let out = filtered()?
+        .with_column((col("a") * lit(2)).alias("b"))
+        ._collect_post_opt(|lp_top, lp_arena, expr_arena| {
+            assert!(use_cached_results(lp_top, lp_arena, expr_arena).is_some());
+            let mut stack = vec![lp_top];
+            while let Some(node) = stack.pop() {
+                let ir = lp_arena.get(node);
+                hit.set(hit.get() || matches!(ir, IR::DataFrameScan { .. }));
+                ir.copy_inputs(&mut stack);
+            }
+            Ok(())
+        })?;
+    assert!(hit.get());
+    assert!(out.column("b")?.equals(&Series::new("b", [4i64, 6, 8])));
+
+    // Modifying the file drops the results that were computed from it.
+    write(&[1, 2, 3, 4, 5])?;
+    assert_eq!(filtered()?.collect()?.height(), 4);
+
+    disable_result_cache();
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_watch_parquet() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join("polars_test_watch_parquet");
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root)?;
+    let write = |name: &str, values: &[i32]| -> PolarsResult<()> {
+        let mut df = df!["a" => values]?;
+        ParquetWriter::new(std::fs::File::create(root.join(name))?).finish(&mut df)?;
+        Ok(())
+    };
+    write("0.parquet", &[1, 2, 3])?;
+
+    let glob = root.join("*.parquet");
+    let lf = LazyFrame::scan_parquet(&glob, Default::default())?.filter(col("a").gt(lit(1)));
+    let mut watch = lf
+        .clone()
+        .with_column((col("a") * lit(10)).alias("b"))
+        .watch(&glob, std::time::Duration::from_millis(10))?;
+
+    // The files that exist when watching starts are read first.
+    let out = watch.next().unwrap()?;
+    assert!(out.column("b")?.equals(&Series::new("b", [20, 30])));
+    assert!(watch.poll()?.is_none());
+
+    write("1.parquet", &[0, 5])?;
+    let out = watch.next().unwrap()?;
+    assert!(out.column("a")?.equals(&Series::new("a", [5])));
+    assert!(out.column("b")?.equals(&Series::new("b", [50])));
+    assert!(watch.poll()?.is_none());
+
+    // Aggregations and sorts depend on the files that were read before.
+    let interval = std::time::Duration::from_millis(10);
+    assert!(lf
+        .clone()
+        .select([col("a").sum()])
+        .watch(&glob, interval)
+        .is_err());
+    assert!(lf
+        .sort(["a"], Default::default())
+        .watch(&glob, interval)
+        .is_err());
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
@@ -501,6 +501,82 @@ fn test_with_column_prune() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_udf_columns_used_prune() -> PolarsResult<()> {
+    let df = df![
+        "c0" => [0],
+        "c1" => [1],
+        "c2" => [2],
+    ]?;
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+
+    // the udf itself doesn't allow projection pushdown
+    let optimizations = AllowedOptimizations {
+        projection_pushdown: false,
+        ..Default::default()
+    };
+    let q = df
+        .lazy()
+        .map(Ok, optimizations, None, Some("identity"))
+        .with_columns_used(&["c0", "c1"])?
+        .select([col("c1")]);
+
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!((&lp_arena).iter(lp).any(|(_, lp)| {
+        matches!(lp, IR::DataFrameScan { output_schema: Some(schema), .. } if schema.len() == 2)
+    }));
+
+    let out = q.collect()?;
+    assert!(out.equals(&df!["c1" => [1]]?));
+    Ok(())
+}
+
+#[test]
+fn test_udf_row_independent_pushdown() -> PolarsResult<()> {
+    let df = df![
+        "c0" => [0, 1, 2, 3, 4],
+        "c1" => [0, 1, 2, 3, 4],
+        "c2" => [0, 1, 2, 3, 4],
+    ]?;
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+
+    let optimizations = AllowedOptimizations {
+        predicate_pushdown: false,
+        projection_pushdown: false,
+        ..Default::default()
+    };
+    let q = df
+        .lazy()
+        .map(
+            |df: DataFrame| DataFrame::new(vec![(df.column("c0")? * 10).with_name("c0")]),
+            optimizations,
+            None,
+            Some("times_ten"),
+        )
+        .with_row_independent_columns(&["c0"])?
+        .filter(col("c2").gt(lit(1)))
+        .filter(col("c0").lt(lit(35)))
+        .select([col("c0"), col("c2")]);
+
+    // `c2` is passed around the udf, the predicate on it is applied at the scan. The predicate
+    // on the output of the udf stays above it.
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!((&lp_arena).iter(lp).any(|(_, lp)| {
+        matches!(
+            lp,
+            IR::DataFrameScan { output_schema: Some(schema), filter: Some(_), .. }
+                if schema.len() == 2
+        )
+    }));
+    assert!((&lp_arena)
+        .iter(lp)
+        .any(|(_, lp)| matches!(lp, IR::Filter { .. })));
+
+    let out = q.collect()?;
+    assert!(out.equals(&df!["c0" => [20, 30], "c2" => [2, 3]]?));
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "csv")]
 fn test_slice_at_scan_group_by() -> PolarsResult<()> {
@@ -691,3 +767,105 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_user_optimizer_rule() -> PolarsResult<()> {
+    struct PlusToMinus;
+
+    impl OptimizationRule for PlusToMinus {
+        fn optimize_expr(
+            &mut self,
+            expr_arena: &mut Arena<AExpr>,
+            expr_node: Node,
+            _lp_arena: &Arena<IR>,
+            _lp_node: Node,
+        ) -> PolarsResult<Option<AExpr>> {
+            Ok(match expr_arena.get(expr_node) {
+                AExpr::BinaryExpr {
+                    left,
+                    op: Operator::Plus,
+                    right,
+                } => Some(AExpr::BinaryExpr {
+                    left: *left,
+                    op: Operator::Minus,
+                    right: *right,
+                }),
+                _ => None,
+            })
+        }
+    }
+
+    let lf = df!("a" => [1, 2, 3])?
+        .lazy()
+        .with_optimizer_rule(Box::new(PlusToMinus));
+
+    // The rule is kept by the operations on the frame.
+    let out = lf
+        .clone()
+        .filter(col("a").gt(lit(1)))
+        .select([col("a") + lit(1)])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(1), Some(2)]);
+
+    // And by joins with a frame that doesn't have it.
+    let out = df!("a" => [2, 3])?
+        .lazy()
+        .inner_join(lf, col("a"), col("a"))
+        .select([col("a") + lit(10)])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(-8), Some(-7)]);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-array")]
+fn test_fuse_quantiles() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 2, 1, 2, 1, 1],
+        "v" => [Some(4.0), Some(1.0), None, Some(3.0), Some(2.0), Some(8.0)],
+    ]?;
+    let q = df.lazy().group_by_stable([col("g")]).agg([
+        col("v")
+            .quantile(lit(0.5), QuantileInterpolOptions::Linear)
+            .alias("median"),
+        col("v").sum().alias("sum"),
+        col("v")
+            .quantile(lit(0.9), QuantileInterpolOptions::Linear)
+            .alias("p90"),
+        col("v")
+            .quantile(lit(0.9), QuantileInterpolOptions::Nearest)
+            .alias("p90_nearest"),
+    ]);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    let n_fused = (&lp_arena)
+        .iter(lp)
+        .filter_map(|(_, lp)| match lp {
+            IR::GroupBy { aggs, .. } => Some(aggs),
+            _ => None,
+        })
+        .flatten()
+        .filter(|agg| {
+            matches!(
+                expr_arena.get(agg.node()),
+                AExpr::Agg(IRAggExpr::Quantiles { .. })
+            )
+        })
+        .count();
+    assert_eq!(n_fused, 1);
+
+    let out = q.clone().collect()?;
+    let expected = q.with_simplify_expr(false).collect()?;
+    assert!(out.equals_missing(&expected));
+    assert_eq!(
+        out.get_column_names(),
+        &["g", "median", "sum", "p90", "p90_nearest"]
+    );
+    assert_eq!(
+        Vec::from(out.column("median")?.f64()?),
+        &[Some(4.0), Some(2.0)]
+    );
+    Ok(())
+}
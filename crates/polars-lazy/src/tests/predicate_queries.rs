@@ -333,3 +333,36 @@ fn test_push_join_col_predicates_to_both_sides_semi_12565() -> PolarsResult<()>
     assert_eq!(out, expected);
     Ok(())
 }
+
+#[test]
+fn test_predicate_on_window_partition_key() -> PolarsResult<()> {
+    let df = fruits_cars();
+
+    // Filtering on the partition key removes whole windows, so the filter can be applied
+    // before the window is computed.
+    let q = df
+        .clone()
+        .lazy()
+        .with_column(col("B").sum().over([col("fruits")]).alias("sum"))
+        .filter(col("fruits").eq(lit("banana")));
+    assert!(predicate_at_scan(q.clone()));
+    let out = q.collect()?;
+    assert_eq!(
+        Vec::from(out.column("sum")?.i32()?),
+        &[Some(10), Some(10), Some(10)]
+    );
+
+    // Any other column changes the windows.
+    let q = df
+        .lazy()
+        .with_column(col("B").sum().over([col("fruits")]).alias("sum"))
+        .filter(col("A").gt(lit(1i32)));
+    assert!(!predicate_at_scan(q.clone()));
+    let out = q.collect()?;
+    assert_eq!(
+        Vec::from(out.column("sum")?.i32()?),
+        &[Some(10), Some(5), Some(5), Some(10)]
+    );
+
+    Ok(())
+}
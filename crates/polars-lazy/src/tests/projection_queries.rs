@@ -36,6 +36,43 @@ fn test_join_suffix_and_drop() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_join_naming() -> PolarsResult<()> {
+    let left = df!["id" => [1, 2], "a" => [1, 2], "b" => [3, 4]]?.lazy();
+    let right = df!["id" => [1, 2], "a" => [5, 6], "b" => [7, 8]]?.lazy();
+    let join = |naming: JoinNaming| {
+        left.clone()
+            .join_builder()
+            .with(right.clone())
+            .on([col("id")])
+            .naming(naming)
+            .finish()
+    };
+
+    let out = join(JoinNaming::template("{name}_{side}")?).collect()?;
+    assert_eq!(
+        out.get_column_names(),
+        ["id", "a", "b", "a_right", "b_right"]
+    );
+    let q = join(JoinNaming::Prefix("r_".into()));
+    assert_eq!(
+        q.clone().collect()?.get_column_names(),
+        ["id", "a", "b", "r_a", "r_b"]
+    );
+
+    // Renamed columns are projected and filtered under their original name.
+    let out = q
+        .filter(col("r_a").gt(lit(5)))
+        .select([col("r_a"), col("a")])
+        .collect()?;
+    assert!(out.equals(&df!["r_a" => [6], "a" => [2]]?));
+
+    assert!(join(JoinNaming::Error).collect().is_err());
+    assert!(JoinNaming::template("right").is_err());
+    assert!(JoinNaming::template("{name}_{name}").is_err());
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "cross_join")]
 fn test_cross_join_pd() -> PolarsResult<()> {
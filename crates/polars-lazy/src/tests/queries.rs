@@ -14,6 +14,33 @@ fn test_lazy_with_column() {
     assert!(df.column("foo").is_ok());
 }
 
+#[test]
+fn test_lazy_set_where() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [Some(4.0), Some(5.0), None],
+        "c" => ["x", "y", "z"],
+    ]?;
+    // The mask and the values see the frame before the update.
+    let out = df
+        .lazy()
+        .set_where(
+            col("a").gt(lit(1)),
+            [
+                ("a", col("a") * lit(10)),
+                ("b", col("a").cast(DataType::Float64)),
+            ],
+        )
+        .collect()?;
+    let expected = df![
+        "a" => [1, 20, 30],
+        "b" => [4.0, 2.0, 3.0],
+        "c" => ["x", "y", "z"],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
 #[test]
 fn test_lazy_exec() {
     let df = get_df();
@@ -65,6 +92,39 @@ fn test_lazy_unpivot() {
     assert_eq!(out.shape(), (7, 3));
 }
 
+#[test]
+#[cfg(feature = "pivot")]
+fn test_lazy_pivot() -> PolarsResult<()> {
+    let df = df![
+        "name" => ["a", "a", "b", "b", "b"],
+        "subject" => ["x", "y", "x", "x", "z"],
+        "score" => [1, 2, 3, 4, 5],
+    ]?;
+    let args = PivotArgs {
+        on: Arc::from([Arc::from("subject")]),
+        on_columns: Some(Arc::new(df!["subject" => ["x", "y"]]?)),
+        index: Some(Arc::from([Arc::from("name")])),
+        values: None,
+        agg: col("").sum(),
+        maintain_order: true,
+        sort_columns: false,
+        separator: Arc::from("_"),
+    };
+
+    let out = df
+        .lazy()
+        .pivot(args)?
+        .filter(col("name").eq(lit("b")))
+        .collect()?;
+    let expected = df![
+        "name" => ["b"],
+        "x" => [Some(7)],
+        "y" => [None::<i32>],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
 #[test]
 fn test_lazy_drop_nulls() {
     let df = df! {
@@ -1485,7 +1545,7 @@ fn test_round_after_agg() -> PolarsResult<()> {
         .agg([col("A")
             .cast(DataType::Float32)
             .mean()
-            .round(2)
+            .round(2, RoundMode::HalfAway)
             .alias("foo")])
         .collect()?;
 
@@ -1519,7 +1579,7 @@ fn test_round_after_agg() -> PolarsResult<()> {
         .lazy()
         .group_by_stable([col("groups")])
         .agg([((col("b") * col("c")).sum() / col("b").sum())
-            .round(2)
+            .round(2, RoundMode::HalfAway)
             .alias("foo")])
         .collect()?;
 
@@ -1640,6 +1700,8 @@ fn test_single_group_result() -> PolarsResult<()> {
                 nulls_last: false,
                 multithreaded: true,
                 maintain_order: false,
+                stable: true,
+                collation: None,
             })
             .over([col("a")])])
         .collect()?;
@@ -1921,3 +1983,309 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+fn test_assertions() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 2, 3],
+        "b" => [Some(4), None, Some(3), Some(1)],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .assert_non_empty()
+        .assert_row_count(4)
+        .assert_unique(Some(["a", "b"]))
+        .assert_sorted("a", false)
+        .assert_sorted("b", true)
+        .collect()?;
+    assert!(out.equals_missing(&df));
+
+    let err = df
+        .clone()
+        .lazy()
+        .assert_unique(Some(["a"]))
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("found 2 duplicated rows"));
+
+    let err = df
+        .clone()
+        .lazy()
+        .assert_sorted("b", false)
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("value 3 at row 2 follows 4"));
+
+    let err = df
+        .clone()
+        .lazy()
+        .filter(col("a").gt(lit(5)))
+        .assert_non_empty()
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("non-empty"));
+
+    // Predicates must not be pushed below the assertion.
+    let err = df
+        .clone()
+        .lazy()
+        .assert_unique(Some(["a"]))
+        .filter(col("a").eq(lit(1)))
+        .collect()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::ComputeError(_)));
+
+    // Projections keep the asserted columns.
+    let out = df
+        .lazy()
+        .assert_sorted("a", false)
+        .select([col("b")])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["b"]);
+
+    assert!(DataFrame::empty()
+        .lazy()
+        .assert_sorted("c", false)
+        .collect()
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_with_row_index_over() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "b", "a", "a", "b", "c"],
+        "h" => [1, 1, 2, 1, 1, 1],
+        "v" => [1, 2, 3, 4, 5, 6],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .with_row_index_over("idx", ["g"], Some(1))
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["idx", "g", "h", "v"]);
+    assert_eq!(
+        Vec::from(out.column("idx")?.idx()?),
+        &[Some(1), Some(1), Some(2), Some(3), Some(2), Some(1)]
+    );
+
+    let out = df
+        .clone()
+        .lazy()
+        .with_row_index_over("idx", ["g", "h"], None)
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("idx")?.idx()?),
+        &[Some(0), Some(0), Some(0), Some(1), Some(1), Some(0)]
+    );
+
+    // The group keys are kept when they are projected away and predicates are not pushed
+    // below the row index.
+    let out = df
+        .clone()
+        .lazy()
+        .with_row_index_over("idx", ["g"], None)
+        .filter(col("v").gt(lit(2)))
+        .select([col("idx"), col("v")])
+        .collect()?;
+    assert!(out.equals(&df![
+        "idx" => [1 as IdxSize, 2, 1, 0],
+        "v" => [3, 4, 5, 6],
+    ]?));
+
+    assert!(df
+        .lazy()
+        .with_row_index_over("idx", ["x"], None)
+        .collect()
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_lazy_iterate() -> PolarsResult<()> {
+    let df = df!["x" => [1, 3]]?;
+    let doubled = |lf: LazyFrame| Ok(lf.select([col("x") * lit(2)]));
+    let out = df
+        .clone()
+        .lazy()
+        .iterate(doubled, Some(col("x").min().gt_eq(lit(100))), 1000)?
+        .collect()?;
+    assert_eq!(out, df!["x" => [128, 384]]?);
+
+    // The last state is returned once the iterations run out.
+    let out = df.lazy().iterate(doubled, Some(lit(false)), 3)?.collect()?;
+    assert_eq!(out, df!["x" => [8, 24]]?);
+    Ok(())
+}
+
+#[test]
+fn test_lazy_iterate_fixed_point() -> PolarsResult<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Transitive closure of the edges of a graph.
+    let calls = Arc::new(AtomicUsize::new(0));
+    let edges = {
+        let calls = calls.clone();
+        df!["src" => [1, 2, 3], "dst" => [2, 3, 4]]?.lazy().map(
+            move |df| {
+                calls.fetch_add(1, Ordering::Relaxed);
+                Ok(df)
+            },
+            AllowedOptimizations::default(),
+            None,
+            None,
+        )
+    };
+    let out = df!["src" => [1, 2, 3], "dst" => [2, 3, 4]]?
+        .lazy()
+        .iterate(
+            |reach| {
+                let next = reach
+                    .clone()
+                    .join(
+                        edges,
+                        [col("dst")],
+                        [col("src")],
+                        JoinArgs::new(JoinType::Inner),
+                    )
+                    .select([col("src"), col("dst_right").alias("dst")]);
+                Ok(concat([reach, next], UnionArgs::default())?
+                    .unique_stable(None, UniqueKeepStrategy::First)
+                    .sort(["src", "dst"], Default::default()))
+            },
+            None,
+            100,
+        )?
+        .collect()?;
+
+    let expected = df![
+        "src" => [1, 1, 1, 2, 2, 3],
+        "dst" => [2, 3, 4, 3, 4, 4]
+    ]?;
+    assert_eq!(out, expected);
+    // The edges don't depend on the state, so they are only computed once.
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+    // The step must keep the schema of the state.
+    assert!(df!["x" => [1]]?
+        .lazy()
+        .iterate(
+            |lf| Ok(lf.select([col("x").cast(DataType::Float64)])),
+            None,
+            10
+        )
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_collect_chunked() -> PolarsResult<()> {
+    let a = df!["a" => [1, 2, 3], "b" => ["x", "y", "z"]]?;
+    let b = df!["a" => [4, 5, 6, 7, 8], "b" => ["v", "w", "x", "y", "z"]]?;
+    let lf = concat([a.lazy(), b.lazy()], UnionArgs::default())?;
+
+    let out = lf.clone().collect_chunked(3)?;
+    assert_eq!(out.height(), 8);
+    for s in out.get_columns() {
+        assert_eq!(s.chunk_lengths().collect::<Vec<_>>(), [3, 3, 2]);
+    }
+    assert!(out.equals(&lf.clone().collect()?));
+
+    let out = lf.clone().collect_chunked(100)?;
+    assert_eq!(out.n_chunks(), 1);
+    assert!(lf.collect_chunked(0).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_pipeline() -> PolarsResult<()> {
+    let df = df!["a" => [Some(1), None, Some(3)], "b" => ["x", "y", "z"]]?;
+    let pipeline = Pipeline::new()
+        .step("valid", |lf| lf.filter(col("a").is_not_null()))
+        .step("double", |lf| lf.with_column(col("a") * lit(2)))
+        .step("project", |lf| lf.select([col("a"), col("b")]))
+        .expect_schema(df.schema());
+
+    let out = pipeline.apply(df.clone().lazy())?.collect()?;
+    assert!(out.equals(&df!["a" => [2, 6], "b" => ["x", "z"]]?));
+    let out = pipeline
+        .apply_until(df.clone().lazy(), "valid")?
+        .collect()?;
+    assert_eq!(out.height(), 2);
+    assert!(pipeline.apply_until(df.clone().lazy(), "other").is_err());
+
+    // The schemas are checked before the pipeline runs.
+    let err = pipeline
+        .clone()
+        .step("drop", |lf| lf.drop(["b"]))
+        .step("use", |lf| lf.select([col("b")]))
+        .apply(df.clone().lazy())
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("\"use\""));
+    let err = Pipeline::new()
+        .step("cast", |lf| {
+            lf.with_column(col("a").cast(DataType::Float64))
+        })
+        .expect_schema(df.schema())
+        .apply(df.clone().lazy())
+        .err()
+        .unwrap();
+    assert!(matches!(err, PolarsError::SchemaMismatch(_)));
+    let duplicate = pipeline.clone().step("valid", |lf| lf);
+    assert!(duplicate.apply(df.clone().lazy()).is_err());
+
+    #[cfg(feature = "serde")]
+    for format in [
+        PlanSerializationFormat::Binary,
+        PlanSerializationFormat::Json,
+    ] {
+        let mut buf = vec![];
+        pipeline.serialize(Arc::new(df.schema()), &mut buf, format)?;
+        let read = Pipeline::deserialize(buf.as_slice(), format)?;
+        assert!(read.step_names().eq(["valid", "double", "project"]));
+        let out = read.apply(df.clone().lazy())?.collect()?;
+        assert!(out.equals(&pipeline.apply(df.clone().lazy())?.collect()?));
+        // The input must have the schema the pipeline was serialized for.
+        assert!(read.apply(df.drop("b")?.lazy()).is_err());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_paginate() -> PolarsResult<()> {
+    let df = df![
+        "a" => [3, 1, 2, 1, 3, 2, 1],
+        "b" => ["g", "c", "e", "a", "f", "d", "b"],
+    ]?;
+    let sort_options = SortMultipleOptions::default().with_order_descending(true);
+    let expected = df
+        .clone()
+        .lazy()
+        .sort_by_exprs(
+            [col("a"), col("b")],
+            sort_options
+                .clone()
+                .with_order_descending_multi([true, false]),
+        )
+        .collect()?;
+
+    let pages = df
+        .clone()
+        .lazy()
+        .paginate([col("a")], sort_options.clone())?;
+    let out = polars_core::utils::accumulate_dataframes_vertical([
+        pages.collect_page(0, 3)?,
+        pages.collect_page(3, 3)?,
+        pages.collect_page(6, 3)?,
+    ])?;
+    assert_eq!(out, expected);
+    assert_eq!(pages.collect_page(10, 3)?.height(), 0);
+
+    let page = df.lazy().collect_page(2, 2, [col("a")], sort_options)?;
+    assert_eq!(page, expected.slice(2, 2));
+    Ok(())
+}
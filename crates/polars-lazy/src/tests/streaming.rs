@@ -80,6 +80,21 @@ fn test_streaming_union_order() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_parallel_hint() -> PolarsResult<()> {
+    let q = get_csv_file();
+    let q = q.select([
+        (col("sugars_g") * lit(2)).parallel(false),
+        col("calories").parallel(true),
+    ]);
+
+    // Expressions that may not run in parallel are left to the in-memory engine.
+    let q_streaming = q.clone().with_streaming(true);
+    assert!(!optimization_checks::is_pipeline(q_streaming.clone()));
+    assert_eq!(q_streaming.collect()?, q.collect()?);
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "cross_join")]
 fn test_streaming_union_join() -> PolarsResult<()> {
@@ -428,3 +443,73 @@ fn test_streaming_full_outer_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_streaming_collect_batches() -> PolarsResult<()> {
+    let q = get_csv_file().filter(col("calories").gt(lit(50)));
+    let expected = q.clone().collect()?;
+
+    let batches = q.clone().collect_batches()?;
+    let out = polars_core::utils::accumulate_dataframes_vertical(
+        batches.collect::<PolarsResult<Vec<_>>>()?,
+    )?;
+    assert_eq!(out, expected);
+
+    let stream = Box::new(q.collect_arrow_stream(false)?);
+    let mut reader =
+        unsafe { polars_core::utils::arrow::ffi::ArrowArrayStreamReader::try_new(stream)? };
+    let mut height = 0;
+    while let Some(batch) = unsafe { reader.next() } {
+        height += batch?.len();
+    }
+    assert_eq!(height, expected.height());
+    Ok(())
+}
+
+#[derive(Default)]
+struct CollectPlugin {
+    schema: std::sync::Mutex<Option<Schema>>,
+    batches: std::sync::Mutex<Vec<DataFrame>>,
+    finalized: std::sync::atomic::AtomicBool,
+}
+
+impl SinkPlugin for CollectPlugin {
+    fn name(&self) -> &str {
+        "collect"
+    }
+
+    fn open(&self, schema: &Schema) -> PolarsResult<()> {
+        *self.schema.lock().unwrap() = Some(schema.clone());
+        Ok(())
+    }
+
+    fn write_batch(&self, df: &DataFrame) -> PolarsResult<()> {
+        self.batches.lock().unwrap().push(df.clone());
+        Ok(())
+    }
+
+    fn finalize(&self) -> PolarsResult<()> {
+        self.finalized
+            .store(true, std::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_streaming_sink_plugin() -> PolarsResult<()> {
+    let q = get_csv_file().filter(col("calories").gt(lit(50)));
+    let expected = q.clone().collect()?;
+
+    let plugin = Arc::new(CollectPlugin::default());
+    q.sink_plugin(plugin.clone())?;
+    assert_eq!(
+        plugin.schema.lock().unwrap().as_ref(),
+        Some(&expected.schema())
+    );
+    assert!(plugin.finalized.load(std::sync::atomic::Ordering::Relaxed));
+
+    let batches = std::mem::take(&mut *plugin.batches.lock().unwrap());
+    let out = polars_core::utils::accumulate_dataframes_vertical(batches)?;
+    assert_eq!(out, expected);
+    Ok(())
+}
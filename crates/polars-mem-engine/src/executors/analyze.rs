@@ -0,0 +1,29 @@
+use std::time::Instant;
+
+use super::*;
+
+/// Records the rows and time of the executor of a plan node.
+pub(crate) struct AnalyzeExec {
+    pub(crate) node: Node,
+    pub(crate) input: Box<dyn Executor>,
+}
+
+impl Executor for AnalyzeExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let start = Instant::now();
+        #[cfg(feature = "mem_stats")]
+        let memory = polars_core::mem::PeakScope::new();
+        // The inputs set their own node, which is restored before the executor of this node
+        // records its name.
+        let parent = state.current_node.replace(self.node);
+        let df = self.input.execute(state);
+        state.current_node = parent;
+        let df = df?;
+        #[cfg(feature = "mem_stats")]
+        let peak_memory = memory.peak_increase();
+        #[cfg(not(feature = "mem_stats"))]
+        let peak_memory = None;
+        state.record_node_stats(self.node, df.height(), start, Instant::now(), peak_memory);
+        Ok(df)
+    }
+}
@@ -71,6 +71,7 @@ pub(super) fn group_by_helper(
     }
 
     let mut groups = gb.get_groups();
+    state.should_stop()?;
 
     #[allow(unused_assignments)]
     // it is unused because we only use it to keep the lifetime of sliced_group valid
@@ -96,6 +97,7 @@ pub(super) fn group_by_helper(
 
 impl GroupByExec {
     fn execute_impl(&mut self, state: &ExecutionState, df: DataFrame) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
         let keys = self
             .keys
             .iter()
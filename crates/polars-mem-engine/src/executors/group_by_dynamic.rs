@@ -12,6 +12,8 @@ pub(crate) struct GroupByDynamicExec {
     pub(crate) input_schema: SchemaRef,
     pub(crate) slice: Option<(i64, usize)>,
     pub(crate) apply: Option<Arc<dyn DataFrameUdf>>,
+    #[cfg(feature = "dynamic_group_by")]
+    pub(crate) resample: Option<ResampleFill>,
 }
 
 impl GroupByDynamicExec {
@@ -45,7 +47,14 @@ impl GroupByDynamicExec {
         // it is unused because we only use it to keep the lifetime of sliced_group valid
         let mut sliced_groups = None;
 
-        if let Some((offset, len)) = self.slice {
+        // The windows without data are only added after aggregating, so the slice is
+        // applied to the resampled result instead.
+        let slice = if self.resample.is_some() {
+            None
+        } else {
+            self.slice
+        };
+        if let Some((offset, len)) = slice {
             sliced_groups = Some(groups.slice(offset, len));
             groups = sliced_groups.as_deref().unwrap();
 
@@ -65,7 +74,76 @@ impl GroupByDynamicExec {
         columns.push(time_key);
         columns.extend_from_slice(&agg_columns);
 
+        let out = DataFrame::new(columns)?;
+        match &self.resample {
+            Some(fill) => {
+                let out = resample(out, keys.len(), &self.options, fill)?;
+                Ok(if let Some((offset, len)) = self.slice {
+                    out.slice(offset, len)
+                } else {
+                    out
+                })
+            },
+            None => Ok(out),
+        }
+    }
+}
+
+/// Add the windows without data between the first and last window of every group and fill
+/// the aggregated columns.
+#[cfg(feature = "dynamic_group_by")]
+fn resample(
+    df: DataFrame,
+    n_keys: usize,
+    options: &DynamicGroupOptions,
+    fill: &ResampleFill,
+) -> PolarsResult<DataFrame> {
+    if df.is_empty() {
+        return Ok(df);
+    }
+    let names = df.get_column_names_owned();
+    let index_column = options.index_column.as_str();
+
+    let resample_group = |group: DataFrame| -> PolarsResult<DataFrame> {
+        let upsampled = group.upsample(Vec::<String>::new(), index_column, options.every)?;
+        let height = upsampled.height();
+        let columns = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                if i < n_keys {
+                    // The keys are the same for all windows of the group.
+                    Ok(group.column(name)?.new_from_index(0, height))
+                } else if name.as_str() == index_column {
+                    Ok(upsampled.column(name)?.clone())
+                } else {
+                    fill_nulls(upsampled.column(name)?, fill)
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
         DataFrame::new(columns)
+    };
+
+    if n_keys == 0 {
+        resample_group(df)
+    } else {
+        df.group_by_stable(&names[..n_keys])?.apply(resample_group)
+    }
+}
+
+#[cfg(feature = "dynamic_group_by")]
+fn fill_nulls(s: &Series, fill: &ResampleFill) -> PolarsResult<Series> {
+    match fill {
+        ResampleFill::Null => Ok(s.clone()),
+        ResampleFill::Forward => s.fill_null(FillNullStrategy::Forward(None)),
+        ResampleFill::Value(value) => {
+            let value = value.to_any_value().ok_or_else(
+                || polars_err!(ComputeError: "cannot fill the resampled windows with {:?}", value),
+            )?;
+            let value =
+                Series::from_any_values(s.name(), &[value], true)?.strict_cast(s.dtype())?;
+            s.zip_with(&s.is_not_null(), &value.new_from_index(0, s.len()))
+        },
     }
 }
 
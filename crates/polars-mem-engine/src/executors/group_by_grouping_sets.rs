@@ -0,0 +1,209 @@
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_utils::idx_vec::IdxVec;
+
+use super::*;
+
+/// Aggregates the input once for every set of a `GROUPING SETS` and stacks the results.
+///
+/// The input is only hashed by all keys together. The groups of every set are derived from
+/// these finest groups, by grouping their keys, which are usually far fewer than the rows.
+pub(crate) struct GroupByGroupingSetsExec {
+    pub(crate) input: Box<dyn Executor>,
+    pub(crate) keys: Vec<Arc<dyn PhysicalExpr>>,
+    pub(crate) aggs: Vec<Arc<dyn PhysicalExpr>>,
+    pub(crate) grouping_sets: GroupingSets,
+    pub(crate) maintain_order: bool,
+    pub(crate) input_schema: SchemaRef,
+    pub(crate) slice: Option<(i64, usize)>,
+}
+
+/// The finest groups of a grouping sets aggregation, by all keys.
+struct FinestGroups {
+    keys: Vec<Series>,
+    groups: GroupsProxy,
+    /// The finest group of every row.
+    group_of_row: Vec<IdxSize>,
+}
+
+impl FinestGroups {
+    fn new(df: &DataFrame, keys: Vec<Series>, maintain_order: bool) -> PolarsResult<Self> {
+        let gb = df.group_by_with_series(keys, true, maintain_order)?;
+        let keys = gb.keys();
+        let groups = gb.take_groups();
+
+        let mut group_of_row = vec![0; df.height()];
+        for (group, indicator) in groups.iter().enumerate() {
+            match indicator {
+                GroupsIndicator::Idx((_, idx)) => {
+                    for &i in idx.iter() {
+                        group_of_row[i as usize] = group as IdxSize;
+                    }
+                },
+                GroupsIndicator::Slice([first, len]) => {
+                    group_of_row[first as usize..(first + len) as usize].fill(group as IdxSize);
+                },
+            }
+        }
+        Ok(Self {
+            keys,
+            groups,
+            group_of_row,
+        })
+    }
+
+    /// Group the rows by a subset of the keys, by merging the finest groups that share them.
+    fn coarsen(
+        &self,
+        set: &[usize],
+        maintain_order: bool,
+    ) -> PolarsResult<(Vec<Series>, GroupsProxy)> {
+        let by = set.iter().map(|&i| self.keys[i].clone()).collect();
+        let df = DataFrame::empty();
+        let gb = df.group_by_with_series(by, true, maintain_order)?;
+        let keys = gb.keys();
+        let merged = gb.take_groups();
+
+        let mut coarse_of_finest = vec![0 as IdxSize; self.groups.len()];
+        for (group, indicator) in merged.iter().enumerate() {
+            match indicator {
+                GroupsIndicator::Idx((_, idx)) => {
+                    for &i in idx.iter() {
+                        coarse_of_finest[i as usize] = group as IdxSize;
+                    }
+                },
+                GroupsIndicator::Slice([first, len]) => {
+                    coarse_of_finest[first as usize..(first + len) as usize].fill(group as IdxSize);
+                },
+            }
+        }
+
+        // Visiting the rows in order keeps the indices of every group sorted.
+        let mut all = vec![IdxVec::new(); merged.len()];
+        for (row, &finest) in self.group_of_row.iter().enumerate() {
+            all[coarse_of_finest[finest as usize] as usize].push(row as IdxSize);
+        }
+        let first = all.iter().map(|idx| idx[0]).collect();
+        let groups = GroupsIdx::new(first, all, maintain_order);
+        Ok((keys, GroupsProxy::Idx(groups)))
+    }
+}
+
+impl GroupByGroupingSetsExec {
+    fn aggregate_set(
+        &self,
+        df: &DataFrame,
+        keys: &[Series],
+        finest: Option<&FinestGroups>,
+        set: &[usize],
+        state: &ExecutionState,
+    ) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
+        let (set_keys, groups) = match finest {
+            // The empty set is a single group of all rows, even if there are none.
+            _ if set.is_empty() => {
+                let groups = GroupsProxy::Slice {
+                    groups: vec![[0, df.height() as IdxSize]],
+                    rolling: false,
+                };
+                (vec![], Cow::Owned(groups))
+            },
+            Some(finest) if set.len() == keys.len() => {
+                let set_keys = set.iter().map(|&i| finest.keys[i].clone()).collect();
+                (set_keys, Cow::Borrowed(&finest.groups))
+            },
+            Some(finest) => {
+                let (set_keys, groups) = finest.coarsen(set, self.maintain_order)?;
+                (set_keys, Cow::Owned(groups))
+            },
+            None => unreachable!(),
+        };
+        let n_groups = groups.len();
+
+        let mut columns = Vec::with_capacity(keys.len() + 2 + self.aggs.len());
+        for (i, key) in keys.iter().enumerate() {
+            match set.iter().position(|&j| j == i) {
+                Some(pos) => columns.push(set_keys[pos].clone()),
+                None => columns.push(Series::full_null(key.name(), n_groups, key.dtype())),
+            }
+        }
+        if let Some(name) = &self.grouping_sets.grouping_id {
+            let n_keys = keys.len();
+            let grouping_id = (0..n_keys)
+                .filter(|i| !set.contains(i))
+                .fold(0u64, |id, i| id | (1 << (n_keys - 1 - i)));
+            columns.push(UInt64Chunked::full(name.as_ref(), grouping_id, n_groups).into_series());
+        }
+        if let Some(name) = &self.grouping_sets.level {
+            let level = set.len() as u32;
+            columns.push(UInt32Chunked::full(name.as_ref(), level, n_groups).into_series());
+        }
+        columns.extend(evaluate_aggs(df, &self.aggs, &groups, state)?);
+        DataFrame::new(columns)
+    }
+
+    fn execute_impl(
+        &mut self,
+        state: &ExecutionState,
+        mut df: DataFrame,
+    ) -> PolarsResult<DataFrame> {
+        df.as_single_chunk_par();
+
+        // The keys are evaluated once and shared by all sets.
+        let keys = self
+            .keys
+            .iter()
+            .map(|e| e.evaluate(&df, state))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let finest = if keys.is_empty() {
+            None
+        } else {
+            Some(FinestGroups::new(&df, keys.clone(), self.maintain_order)?)
+        };
+
+        let parts = self
+            .grouping_sets
+            .sets
+            .iter()
+            .map(|set| self.aggregate_set(&df, &keys, finest.as_ref(), set, state))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let out = accumulate_dataframes_vertical_unchecked(parts);
+
+        Ok(if let Some((offset, len)) = self.slice {
+            out.slice(offset, len)
+        } else {
+            out
+        })
+    }
+}
+
+impl Executor for GroupByGroupingSetsExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
+        #[cfg(debug_assertions)]
+        {
+            if state.verbose() {
+                eprintln!("run GroupByGroupingSetsExec")
+            }
+        }
+        let df = self.input.execute(state)?;
+        let profile_name = if state.has_node_timer() {
+            let by = self
+                .keys
+                .iter()
+                .map(|s| Ok(s.to_field(&self.input_schema)?.name))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let name = comma_delimited("group_by_grouping_sets".to_string(), &by);
+            Cow::Owned(name)
+        } else {
+            Cow::Borrowed("")
+        };
+
+        if state.has_node_timer() {
+            let new_state = state.clone();
+            new_state.record(|| self.execute_impl(state, df), profile_name)
+        } else {
+            self.execute_impl(state, df)
+        }
+    }
+}
@@ -83,6 +83,7 @@ fn run_partitions(
         dfs.into_par_iter()
             .zip(splitted_keys)
             .map(|(df, keys)| {
+                state.should_stop()?;
                 let gb = df.group_by_with_series(keys.into(), false, maintain_order)?;
                 let groups = gb.get_groups();
 
@@ -288,6 +289,7 @@ impl PartitionGroupByExec {
         };
 
         // MERGE phase
+        state.should_stop()?;
 
         let df = accumulate_dataframes_vertical(splitted_dfs)?;
         let keys = splitted_keys
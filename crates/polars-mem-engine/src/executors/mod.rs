@@ -1,9 +1,12 @@
+mod analyze;
 mod cache;
 mod executor;
 mod ext_context;
 mod filter;
 mod group_by;
 mod group_by_dynamic;
+#[cfg(feature = "grouping_sets")]
+mod group_by_grouping_sets;
 mod group_by_partitioned;
 pub(super) mod group_by_rolling;
 mod hconcat;
@@ -30,16 +33,21 @@ use polars_plan::utils::*;
 use projection_utils::*;
 use rayon::prelude::*;
 
+pub(super) use self::analyze::*;
 pub(super) use self::cache::*;
 pub(super) use self::ext_context::*;
 pub(super) use self::filter::*;
 pub(super) use self::group_by::*;
 #[cfg(feature = "dynamic_group_by")]
 pub(super) use self::group_by_dynamic::*;
+#[cfg(feature = "grouping_sets")]
+pub(super) use self::group_by_grouping_sets::GroupByGroupingSetsExec;
 pub(super) use self::group_by_partitioned::*;
 #[cfg(feature = "dynamic_group_by")]
 pub(super) use self::group_by_rolling::GroupByRollingExec;
 pub(super) use self::hconcat::*;
+#[cfg(feature = "asof_join")]
+pub use self::join::resolve_asof_tolerance;
 pub(super) use self::join::*;
 pub(super) use self::projection::*;
 pub(super) use self::projection_simple::*;
@@ -14,6 +14,8 @@ pub struct ProjectionExec {
     pub(crate) options: ProjectionOptions,
     // Can run all operations elementwise
     pub(crate) streamable: bool,
+    // Per expression, whether it must not run concurrently with the others
+    pub(crate) sequential: Vec<bool>,
 }
 
 impl ProjectionExec {
@@ -36,6 +38,7 @@ impl ProjectionExec {
                     state,
                     self.has_windows,
                     self.options.run_parallel,
+                    &self.sequential,
                 )?;
                 check_expand_literals(selected_cols, df.is_empty(), self.options)
             });
@@ -52,6 +55,7 @@ impl ProjectionExec {
                 state,
                 self.has_windows,
                 self.options.run_parallel,
+                &self.sequential,
             )?;
             check_expand_literals(selected_cols, df.is_empty(), self.options)?
         };
@@ -98,6 +98,7 @@ fn execute_projection_cached_window_fns(
     df: &DataFrame,
     exprs: &[Arc<dyn PhysicalExpr>],
     state: &ExecutionState,
+    sequential: &[bool],
 ) -> PolarsResult<Vec<Series>> {
     // We partition by normal expression and window expression
     // - the normal expressions can run in parallel
@@ -113,10 +114,16 @@ fn execute_projection_cached_window_fns(
     #[cfg(feature = "dynamic_group_by")]
     let mut rolling: PlHashMap<&RollingGroupOptions, Vec<IdAndExpression>> = PlHashMap::default();
     let mut other = Vec::with_capacity(exprs.len());
+    // Expressions that may not run concurrently with others, they are evaluated last.
+    let mut other_seq = vec![];
 
     // first we partition the window function by the values they group over.
     // the group_by values should be cached
     exprs.iter().enumerate_u32().for_each(|(index, phys)| {
+        if sequential[index as usize] {
+            other_seq.push((index, phys.as_ref()));
+            return;
+        }
         let mut is_window = false;
         if let Some(e) = phys.as_expression() {
             for e in e.into_iter() {
@@ -143,6 +150,8 @@ fn execute_projection_cached_window_fns(
                         WindowType::Rolling(options) => {
                             rolling.entry(options).or_insert_with(Vec::new)
                         },
+                        // Frames compute their own groups, so they don't share cached groups.
+                        WindowType::Frame(_) => continue,
                     };
                     entry.push((index, phys.clone()));
                     is_window = true;
@@ -192,6 +201,10 @@ fn execute_projection_cached_window_fns(
         }
     }
 
+    for (idx, expr) in other_seq {
+        selected_columns.push((idx, expr.evaluate(df, state)?));
+    }
+
     selected_columns.sort_unstable_by_key(|tpl| tpl.0);
     let selected_columns = selected_columns.into_iter().map(|tpl| tpl.1).collect();
     Ok(selected_columns)
@@ -201,19 +214,45 @@ fn run_exprs_par(
     df: &DataFrame,
     exprs: &[Arc<dyn PhysicalExpr>],
     state: &ExecutionState,
+    sequential: &[bool],
 ) -> PolarsResult<Vec<Series>> {
-    POOL.install(|| {
+    if !sequential.contains(&true) {
+        return POOL.install(|| {
+            exprs
+                .par_iter()
+                .map(|expr| expr.evaluate(df, state))
+                .collect()
+        });
+    }
+
+    // Run the expressions that may be parallelized first, then the others on this thread.
+    let out = POOL.install(|| {
         exprs
             .par_iter()
-            .map(|expr| expr.evaluate(df, state))
-            .collect()
-    })
+            .zip(sequential)
+            .map(|(expr, seq)| {
+                if *seq {
+                    Ok(None)
+                } else {
+                    expr.evaluate(df, state).map(Some)
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+    out.into_iter()
+        .zip(exprs)
+        .map(|(s, expr)| match s {
+            Some(s) => Ok(s),
+            None => expr.evaluate(df, state),
+        })
+        .collect()
 }
 
 fn run_exprs_seq(
     df: &DataFrame,
     exprs: &[Arc<dyn PhysicalExpr>],
     state: &ExecutionState,
+    _sequential: &[bool],
 ) -> PolarsResult<Vec<Series>> {
     exprs.iter().map(|expr| expr.evaluate(df, state)).collect()
 }
@@ -224,6 +263,7 @@ pub(super) fn evaluate_physical_expressions(
     state: &ExecutionState,
     has_windows: bool,
     run_parallel: bool,
+    sequential: &[bool],
 ) -> PolarsResult<Vec<Series>> {
     let expr_runner = if has_windows {
         execute_projection_cached_window_fns
@@ -233,7 +273,7 @@ pub(super) fn evaluate_physical_expressions(
         run_exprs_seq
     };
 
-    let selected_columns = expr_runner(df, exprs, state)?;
+    let selected_columns = expr_runner(df, exprs, state, sequential)?;
 
     if has_windows {
         state.clear_window_expr_cache();
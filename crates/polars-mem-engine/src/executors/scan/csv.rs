@@ -5,6 +5,7 @@ use polars_core::config;
 use polars_core::utils::{
     accumulate_dataframes_vertical, accumulate_dataframes_vertical_unchecked,
 };
+use polars_utils::cancel::CancellationToken;
 
 use super::*;
 
@@ -17,7 +18,7 @@ pub struct CsvExec {
 }
 
 impl CsvExec {
-    fn read(&self) -> PolarsResult<DataFrame> {
+    fn read(&self, token: &CancellationToken) -> PolarsResult<DataFrame> {
         let with_columns = self
             .file_options
             .with_columns
@@ -106,6 +107,7 @@ impl CsvExec {
                 .filter(|_| n_rows.is_none() && self.file_options.row_index.is_none());
 
             for i in 0..self.paths.len() {
+                token.check()?;
                 let opts = options_base
                     .clone()
                     .with_row_index(self.file_options.row_index.clone().map(|mut ri| {
@@ -181,6 +183,7 @@ impl CsvExec {
                 (0..self.paths.len())
                     .step_by(step)
                     .map(|start| {
+                        token.check()?;
                         (start..std::cmp::min(start.saturating_add(step), self.paths.len()))
                             .into_par_iter()
                             .map(|i| finish_read(i, options_base.clone(), predicate.clone()))
@@ -220,6 +223,7 @@ impl Executor for CsvExec {
             Cow::Borrowed("")
         };
 
-        state.record(|| self.read(), profile_name)
+        let token = state.cancel_token();
+        state.record(|| self.read(&token), profile_name)
     }
 }
@@ -8,6 +8,7 @@ use polars_io::cloud::CloudOptions;
 use polars_io::predicates::apply_predicate;
 use polars_io::utils::is_cloud_url;
 use polars_io::RowIndex;
+use polars_utils::cancel::CancellationToken;
 use rayon::prelude::*;
 
 use super::*;
@@ -23,7 +24,7 @@ pub struct IpcExec {
 }
 
 impl IpcExec {
-    fn read(&mut self, verbose: bool) -> PolarsResult<DataFrame> {
+    fn read(&mut self, verbose: bool, token: &CancellationToken) -> PolarsResult<DataFrame> {
         let is_cloud = self.paths.iter().any(is_cloud_url);
         let force_async = config::force_async();
 
@@ -43,7 +44,7 @@ impl IpcExec {
                     .block_on_potential_spawn(self.read_async(verbose))?
             }
         } else {
-            self.read_sync()?
+            self.read_sync(token)?
         };
 
         if self.file_options.rechunk {
@@ -53,7 +54,7 @@ impl IpcExec {
         Ok(out)
     }
 
-    fn read_sync(&mut self) -> PolarsResult<DataFrame> {
+    fn read_sync(&mut self, token: &CancellationToken) -> PolarsResult<DataFrame> {
         if config::verbose() {
             eprintln!("executing ipc read sync with row_index = {:?}, n_rows = {:?}, predicate = {:?} for paths {:?}",
                 self.file_options.row_index.as_ref(),
@@ -84,6 +85,7 @@ impl IpcExec {
         let index_and_dfs = (0..self.paths.len())
             .into_par_iter()
             .map(|_| -> PolarsResult<(usize, DataFrame)> {
+                token.check()?;
                 let index = path_index.fetch_add(1, Ordering::Relaxed);
                 let path = &self.paths[index];
 
@@ -113,6 +115,7 @@ impl IpcExec {
                     )
                     .with_row_index(self.file_options.row_index.clone())
                     .with_projection(projection.clone())
+                    .with_predicate(self.predicate.clone().map(phys_expr_to_io_expr))
                     .memory_mapped(memory_mapped)
                     .finish()?;
 
@@ -283,6 +286,7 @@ impl Executor for IpcExec {
             Cow::Borrowed("")
         };
 
-        state.record(|| self.read(state.verbose()), profile_name)
+        let token = state.cancel_token();
+        state.record(|| self.read(state.verbose(), &token), profile_name)
     }
 }
@@ -19,6 +19,7 @@ pub(crate) use ipc::IpcExec;
 pub(crate) use ndjson::JsonExec;
 #[cfg(feature = "parquet")]
 pub(crate) use parquet::ParquetExec;
+use polars_core::utils::accumulate_dataframes_vertical;
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
 use polars_io::predicates::PhysicalIoExpr;
 #[cfg(any(feature = "parquet", feature = "csv", feature = "ipc", feature = "cse"))]
@@ -148,3 +149,44 @@ impl Executor for AnonymousScanExec {
         }
     }
 }
+
+pub(crate) struct ScanSourceExec {
+    pub(crate) source: Arc<dyn ScanSource>,
+    pub(crate) file_options: FileScanOptions,
+    pub(crate) file_info: FileInfo,
+    pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
+    pub(crate) output_schema: Option<SchemaRef>,
+}
+
+impl Executor for ScanSourceExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        // The predicate is only set if the source accepted it during predicate pushdown.
+        let predicate = self.predicate.as_ref().map(|predicate| ScanPredicate {
+            expr: predicate.as_expression().unwrap().clone(),
+            evaluator: phys_expr_to_io_expr(predicate.clone()),
+        });
+        let args = ScanSourceArgs::new(
+            &self.file_info,
+            &self.file_options,
+            self.output_schema.as_ref(),
+            predicate,
+        );
+
+        state.record(
+            || {
+                let output_schema = args.output_schema.clone();
+                let dfs = open_scan_source(self.source.as_ref(), args)?
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                if dfs.is_empty() {
+                    return Ok(DataFrame::empty_with_schema(&output_schema));
+                }
+                let mut df = accumulate_dataframes_vertical(dfs)?;
+                if self.file_options.rechunk {
+                    df.as_single_chunk_par();
+                }
+                Ok(df)
+            },
+            self.source.name().into(),
+        )
+    }
+}
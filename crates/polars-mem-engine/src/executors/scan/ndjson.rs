@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use polars_core::utils::accumulate_dataframes_vertical;
+use polars_utils::cancel::CancellationToken;
 
 use super::*;
 
@@ -29,7 +30,7 @@ impl JsonExec {
         }
     }
 
-    fn read(&mut self) -> PolarsResult<DataFrame> {
+    fn read(&mut self, token: &CancellationToken) -> PolarsResult<DataFrame> {
         let schema = self
             .file_info
             .reader_schema
@@ -47,6 +48,9 @@ impl JsonExec {
                 if n_rows == Some(0) {
                     return None;
                 }
+                if let Err(e) = token.check() {
+                    return Some(Err(e));
+                }
 
                 let reader = match JsonLineReader::from_path(p) {
                     Ok(r) => r,
@@ -94,6 +98,7 @@ impl Executor for JsonExec {
             Cow::Borrowed("")
         };
 
-        state.record(|| self.read(), profile_name)
+        let token = state.cancel_token();
+        state.record(|| self.read(&token), profile_name)
     }
 }
@@ -2,13 +2,18 @@ use std::path::PathBuf;
 
 use hive::HivePartitions;
 use polars_core::config;
+use polars_core::config::get_small_file_batch_rows;
 #[cfg(feature = "cloud")]
 use polars_core::config::{get_file_prefetch_size, verbose};
-use polars_core::utils::accumulate_dataframes_vertical;
+use polars_core::utils::{
+    accumulate_dataframes_vertical, accumulate_dataframes_vertical_unchecked,
+};
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::metadata::FileMetaDataRef;
-use polars_io::utils::is_cloud_url;
+use polars_io::parquet::read::{row_group_pruning, RowGroupPruning};
+use polars_io::utils::{batch_small_files, is_cloud_url};
 use polars_io::RowIndex;
+use polars_utils::cancel::CancellationToken;
 
 use super::*;
 
@@ -23,6 +28,7 @@ pub struct ParquetExec {
     file_options: FileScanOptions,
     #[allow(dead_code)]
     metadata: Option<FileMetaDataRef>,
+    scan_stats: Option<ScanStats>,
 }
 
 impl ParquetExec {
@@ -46,10 +52,11 @@ impl ParquetExec {
             cloud_options,
             file_options,
             metadata,
+            scan_stats: None,
         }
     }
 
-    fn read_par(&mut self) -> PolarsResult<Vec<DataFrame>> {
+    fn read_par(&mut self, token: &CancellationToken) -> PolarsResult<Vec<DataFrame>> {
         let parallel = match self.options.parallel {
             ParallelStrategy::Auto if self.paths.len() > POOL.current_num_threads() => {
                 ParallelStrategy::RowGroups
@@ -61,10 +68,12 @@ impl ParquetExec {
 
         let mut remaining_rows_to_read = self.file_options.n_rows.unwrap_or(usize::MAX);
         let mut base_row_index = self.file_options.row_index.take();
+        let min_rows_per_task = get_small_file_batch_rows();
         // Limit no. of files at a time to prevent open file limits.
         let step = std::cmp::min(POOL.current_num_threads(), 128);
 
         for i in (0..self.paths.len()).step_by(step) {
+            token.check()?;
             let end = std::cmp::min(i.saturating_add(step), self.paths.len());
             let paths = &self.paths[i..end];
             let hive_parts = self.hive_parts.as_ref().map(|x| &x[i..end]);
@@ -103,6 +112,17 @@ impl ParquetExec {
                         .set_rechunk(false)
                         .with_hive_partition_columns(hive_partitions);
 
+                    if let Some(stats) = self.scan_stats.as_mut() {
+                        let schema = reader.schema()?;
+                        let pruning = row_group_pruning(
+                            reader.get_metadata()?,
+                            predicate.as_deref().filter(|_| self.options.use_statistics),
+                            &schema,
+                            self.file_options.with_columns.as_deref(),
+                        )?;
+                        add_file_stats(stats, pruning);
+                    }
+
                     reader
                         .num_rows()
                         .map(|num_rows| (reader, num_rows, predicate, projection))
@@ -114,43 +134,73 @@ impl ParquetExec {
                 .map(|(_, num_rows, _, _)| *num_rows);
 
             let rows_statistics = get_sequential_row_statistics(iter, remaining_rows_to_read);
+            // The rows of these files before the predicate is applied, which the row index and
+            // the slice count.
+            let rows_in_files = readers_and_metadata
+                .iter()
+                .map(|(_, num_rows, _, _)| *num_rows)
+                .sum::<usize>()
+                .min(remaining_rows_to_read);
+
+            // Read many tiny files in a single task instead of spawning a task per file.
+            let iter = readers_and_metadata
+                .iter()
+                .map(|(_, num_rows, _, _)| *num_rows);
+            let batches = batch_small_files(iter, min_rows_per_task);
+
+            let mut readers = readers_and_metadata.into_iter().zip(rows_statistics);
+            let tasks = batches
+                .into_iter()
+                .map(|n_files| readers.by_ref().take(n_files).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
 
             let out = POOL.install(|| {
-                readers_and_metadata
+                tasks
                     .into_par_iter()
-                    .zip(rows_statistics.par_iter())
-                    .map(
-                        |(
-                            (reader, num_rows_this_file, predicate, projection),
-                            (remaining_rows_to_read, cumulative_read),
-                        )| {
-                            let remaining_rows_to_read = *remaining_rows_to_read;
-                            let remaining_rows_to_read =
-                                if num_rows_this_file < remaining_rows_to_read {
-                                    None
-                                } else {
-                                    Some(remaining_rows_to_read)
-                                };
-                            let row_index = base_row_index.as_ref().map(|rc| RowIndex {
-                                name: rc.name.clone(),
-                                offset: rc.offset + *cumulative_read as IdxSize,
-                            });
-
-                            reader
-                                .with_n_rows(remaining_rows_to_read)
-                                .with_row_index(row_index)
-                                .with_predicate(predicate.clone())
-                                .with_projection(projection.clone())
-                                .finish()
-                        },
-                    )
+                    .map(|task| {
+                        let mut dfs = task
+                            .into_iter()
+                            .map(
+                                |(
+                                    (reader, num_rows_this_file, predicate, projection),
+                                    (remaining_rows_to_read, cumulative_read),
+                                )| {
+                                    let remaining_rows_to_read =
+                                        if num_rows_this_file < remaining_rows_to_read {
+                                            None
+                                        } else {
+                                            Some(remaining_rows_to_read)
+                                        };
+                                    let row_index = base_row_index.as_ref().map(|rc| RowIndex {
+                                        name: rc.name.clone(),
+                                        offset: rc.offset + cumulative_read as IdxSize,
+                                    });
+
+                                    reader
+                                        .with_n_rows(remaining_rows_to_read)
+                                        .with_row_index(row_index)
+                                        .with_predicate(predicate)
+                                        .with_projection(projection)
+                                        .finish()
+                                },
+                            )
+                            .collect::<PolarsResult<Vec<_>>>()?;
+
+                        if dfs.len() == 1 {
+                            Ok(dfs.pop().unwrap())
+                        } else {
+                            // Merge the files of this task into a single chunk.
+                            let mut df = accumulate_dataframes_vertical_unchecked(dfs);
+                            df.as_single_chunk();
+                            Ok(df)
+                        }
+                    })
                     .collect::<PolarsResult<Vec<_>>>()
             })?;
 
-            let n_read = out.iter().map(|df| df.height()).sum();
-            remaining_rows_to_read = remaining_rows_to_read.saturating_sub(n_read);
+            remaining_rows_to_read = remaining_rows_to_read.saturating_sub(rows_in_files);
             if let Some(rc) = &mut base_row_index {
-                rc.offset += n_read as IdxSize;
+                rc.offset += rows_in_files as IdxSize;
             }
             if result.is_empty() {
                 result = out;
@@ -162,7 +212,7 @@ impl ParquetExec {
     }
 
     #[cfg(feature = "cloud")]
-    async fn read_async(&mut self) -> PolarsResult<Vec<DataFrame>> {
+    async fn read_async(&mut self, token: &CancellationToken) -> PolarsResult<Vec<DataFrame>> {
         let verbose = verbose();
         let first_schema = self
             .file_info
@@ -185,8 +235,15 @@ impl ParquetExec {
         let mut remaining_rows_to_read = self.file_options.n_rows.unwrap_or(usize::MAX);
         let mut base_row_index = self.file_options.row_index.take();
         let mut processed = 0;
+        let collect_scan_stats = self.scan_stats.is_some();
+        let stats_predicate = self
+            .predicate
+            .clone()
+            .filter(|_| self.options.use_statistics)
+            .map(phys_expr_to_io_expr);
 
         for batch_start in (0..self.paths.len()).step_by(batch_size) {
+            token.check()?;
             let end = std::cmp::min(batch_start.saturating_add(batch_size), self.paths.len());
             let paths = &self.paths[batch_start..end];
             let hive_parts = self.hive_parts.as_ref().map(|x| &x[batch_start..end]);
@@ -204,6 +261,7 @@ impl ParquetExec {
             }
 
             // First initialize the readers and get the metadata concurrently.
+            let stats_predicate = &stats_predicate;
             let iter = paths.iter().enumerate().map(|(i, path)| async move {
                 let first_file = batch_start == 0 && i == 0;
                 // use the cached one as this saves a cloud call
@@ -231,10 +289,31 @@ impl ParquetExec {
                     )?
                 }
 
+                let pruning = if collect_scan_stats {
+                    let schema = reader.schema().await?;
+                    Some(row_group_pruning(
+                        reader.get_metadata().await?,
+                        stats_predicate.as_deref(),
+                        &schema,
+                        with_columns,
+                    )?)
+                } else {
+                    None
+                };
+
                 let num_rows = reader.num_rows().await?;
-                PolarsResult::Ok((num_rows, reader))
+                PolarsResult::Ok((num_rows, reader, pruning))
             });
             let readers_and_metadata = futures::future::try_join_all(iter).await?;
+            let readers_and_metadata = readers_and_metadata
+                .into_iter()
+                .map(|(num_rows, reader, pruning)| {
+                    if let (Some(stats), Some(pruning)) = (self.scan_stats.as_mut(), pruning) {
+                        add_file_stats(stats, pruning);
+                    }
+                    (num_rows, reader)
+                })
+                .collect::<Vec<_>>();
 
             // Then compute `n_rows` to be taken per file up front, so we can actually read concurrently
             // after this.
@@ -315,8 +394,39 @@ impl ParquetExec {
         Ok(result)
     }
 
-    fn read(&mut self) -> PolarsResult<DataFrame> {
-        // FIXME: The row index implementation is incorrect when a predicate is
+    /// Drop the files that precede the last `n_rows` rows of the scan.
+    fn skip_leading_files(&mut self, n_rows: usize) -> PolarsResult<()> {
+        let num_rows = |path: &PathBuf| ParquetReader::new(std::fs::File::open(path)?).num_rows();
+
+        let mut first = self.paths.len();
+        let mut rows = 0;
+        while first > 0 && rows < n_rows {
+            first -= 1;
+            rows += num_rows(&self.paths[first])?;
+        }
+        if first == 0 {
+            return Ok(());
+        }
+
+        if let Some(row_index) = &mut self.file_options.row_index {
+            let skipped_rows = self.paths[..first]
+                .iter()
+                .map(num_rows)
+                .sum::<PolarsResult<usize>>()?;
+            row_index.offset += skipped_rows as IdxSize;
+        }
+        self.paths = self.paths[first..].into();
+        self.hive_parts = self.hive_parts.as_ref().map(|parts| parts[first..].into());
+        // The metadata is that of the first file.
+        self.metadata = None;
+        Ok(())
+    }
+
+    fn read(&mut self, token: &CancellationToken) -> PolarsResult<DataFrame> {
+        let is_cloud = is_cloud_url(self.paths.first().unwrap());
+        let force_async = config::force_async();
+
+        // FIXME: The row index of the async reader is incorrect when a predicate is
         // applied. This code mitigates that by applying the predicate after the
         // collection of the entire dataframe if a row index is requested. This is
         // inefficient.
@@ -324,12 +434,10 @@ impl ParquetExec {
             .file_options
             .row_index
             .as_ref()
+            .filter(|_| is_cloud || force_async)
             .and_then(|_| self.predicate.take())
             .map(phys_expr_to_io_expr);
 
-        let is_cloud = is_cloud_url(self.paths.first().unwrap());
-        let force_async = config::force_async();
-
         let out = if is_cloud || force_async {
             #[cfg(not(feature = "cloud"))]
             {
@@ -342,10 +450,17 @@ impl ParquetExec {
                     eprintln!("ASYNC READING FORCED");
                 }
 
-                polars_io::pl_async::get_runtime().block_on_potential_spawn(self.read_async())?
+                polars_io::pl_async::get_runtime()
+                    .block_on_potential_spawn(self.read_async(token))?
             }
         } else {
-            self.read_par()?
+            if let Some(tail) = self.file_options.tail {
+                // Rows that are filtered out don't count towards the tail.
+                if self.predicate.is_none() && post_predicate.is_none() {
+                    self.skip_leading_files(tail)?;
+                }
+            }
+            self.read_par(token)?
         };
 
         let mut out = accumulate_dataframes_vertical(out)?;
@@ -359,6 +474,13 @@ impl ParquetExec {
     }
 }
 
+fn add_file_stats(stats: &mut ScanStats, pruning: RowGroupPruning) {
+    stats.files_read += 1;
+    stats.row_groups_read += pruning.read;
+    stats.row_groups_pruned += pruning.pruned;
+    stats.bytes_read += pruning.bytes_read;
+}
+
 impl Executor for ParquetExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
         let profile_name = if state.has_node_timer() {
@@ -372,6 +494,24 @@ impl Executor for ParquetExec {
             Cow::Borrowed("")
         };
 
-        state.record(|| self.read(), profile_name)
+        if state.has_scan_stats() {
+            self.scan_stats = Some(ScanStats {
+                source: self.paths[0].to_string_lossy().into_owned(),
+                files_listed: self.paths.len() + self.file_options.hive_pruned_files,
+                predicate: self
+                    .predicate
+                    .as_ref()
+                    .and_then(|predicate| predicate.as_expression())
+                    .map(|predicate| predicate.to_string()),
+                ..Default::default()
+            });
+        }
+
+        let token = state.cancel_token();
+        let out = state.record(|| self.read(&token), profile_name)?;
+        if let Some(stats) = self.scan_stats.take() {
+            state.record_scan_stats(stats);
+        }
+        Ok(out)
     }
 }
@@ -32,8 +32,12 @@ impl SortExec {
                 Ok(s)
             })
             .collect::<PolarsResult<Vec<_>>>()?;
+        state.should_stop()?;
 
-        df.sort_impl(by_columns, self.sort_options.clone(), self.slice)
+        // The sort checks the token again before it gathers the columns.
+        state
+            .cancel_token()
+            .run(|| df.sort_impl(by_columns, self.sort_options.clone(), self.slice))
     }
 }
 
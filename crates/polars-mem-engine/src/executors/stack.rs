@@ -10,6 +10,8 @@ pub struct StackExec {
     pub(crate) options: ProjectionOptions,
     // Can run all operations elementwise
     pub(crate) streamable: bool,
+    // Per expression, whether it must not run concurrently with the others
+    pub(crate) sequential: Vec<bool>,
 }
 
 impl StackExec {
@@ -32,6 +34,7 @@ impl StackExec {
                         state,
                         self.has_windows,
                         self.options.run_parallel,
+                        &self.sequential,
                     )?;
                     if !self.options.should_broadcast {
                         debug_assert!(
@@ -63,6 +66,7 @@ impl StackExec {
                     state,
                     self.has_windows,
                     self.options.run_parallel,
+                    &self.sequential,
                 )?;
                 if !self.options.should_broadcast {
                     debug_assert!(
@@ -21,6 +21,9 @@ impl Executor for UdfExec {
         } else {
             Cow::Borrowed("")
         };
-        state.record(|| self.function.evaluate(df), profile_name)
+        // Functions that run a query of their own, like the streaming pipeline, pick up the
+        // cancellation token of this thread.
+        let token = state.cancel_token();
+        state.record(|| token.run(|| self.function.evaluate(df)), profile_name)
     }
 }
@@ -3,5 +3,7 @@ mod planner;
 mod prelude;
 mod utils;
 
+#[cfg(feature = "asof_join")]
+pub use executors::resolve_asof_tolerance;
 pub use executors::Executor;
-pub use planner::create_physical_plan;
+pub use planner::{create_analyzed_physical_plan, create_physical_plan};
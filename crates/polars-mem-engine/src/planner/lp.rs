@@ -126,14 +126,25 @@ fn partitionable_gb(
     partitionable
 }
 
+/// Mark the expressions that have a `parallel(false)` hint.
+fn sequential_exprs(exprs: &[ExprIR], expr_arena: &Arena<AExpr>) -> Vec<bool> {
+    exprs
+        .iter()
+        .map(|e| !is_parallel_safe(e.node(), expr_arena))
+        .collect()
+}
+
 struct ConversionState {
     expr_depth: u16,
+    /// Wrap the executor of every node to record its rows and time.
+    analyze: bool,
 }
 
 impl ConversionState {
     fn new() -> PolarsResult<Self> {
         Ok(ConversionState {
             expr_depth: get_expr_depth_limit()?,
+            analyze: false,
         })
     }
 }
@@ -147,11 +158,43 @@ pub fn create_physical_plan(
     create_physical_plan_impl(root, lp_arena, expr_arena, &state)
 }
 
+/// Create a physical plan of which the executors record the rows they produce and the time they
+/// take per plan node, see [`ExecutionState::collect_node_stats`].
+///
+/// Nodes that are executed as part of another node, such as a filter that is fused into a scan,
+/// don't get an executor of their own and are not recorded.
+pub fn create_analyzed_physical_plan(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<Box<dyn Executor>> {
+    let mut state = ConversionState::new()?;
+    state.analyze = true;
+    create_physical_plan_impl(root, lp_arena, expr_arena, &state)
+}
+
 fn create_physical_plan_impl(
     root: Node,
     lp_arena: &mut Arena<IR>,
     expr_arena: &Arena<AExpr>,
     state: &ConversionState,
+) -> PolarsResult<Box<dyn Executor>> {
+    let executor = create_physical_plan_node(root, lp_arena, expr_arena, state)?;
+    if state.analyze {
+        Ok(Box::new(executors::AnalyzeExec {
+            node: root,
+            input: executor,
+        }))
+    } else {
+        Ok(executor)
+    }
+}
+
+fn create_physical_plan_node(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    state: &ConversionState,
 ) -> PolarsResult<Box<dyn Executor>> {
     use IR::*;
 
@@ -172,6 +215,15 @@ fn create_physical_plan_impl(
             SinkType::Cloud { .. } => {
                 polars_bail!(InvalidOperation: "cloud sink not supported in standard engine.")
             },
+            SinkType::Partition { .. } => {
+                polars_bail!(InvalidOperation: "partitioned sink not supported in standard engine.")
+            },
+            SinkType::Batches(_) => {
+                polars_bail!(InvalidOperation: "batch sink not supported in standard engine.")
+            },
+            SinkType::Plugin(_) => {
+                polars_bail!(InvalidOperation: "plugin sink not supported in standard engine.")
+            },
         },
         Union { inputs, options } => {
             let inputs = inputs
@@ -194,7 +246,8 @@ fn create_physical_plan_impl(
             Ok(Box::new(executors::SliceExec { input, offset, len }))
         },
         Filter { input, predicate } => {
-            let mut streamable = is_streamable(predicate.node(), expr_arena, Context::Default);
+            let mut streamable = is_streamable(predicate.node(), expr_arena, Context::Default)
+                && is_parallel_safe(predicate.node(), expr_arena);
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
             if streamable {
                 // This can cause problems with string caches
@@ -215,9 +268,8 @@ fn create_physical_plan_impl(
             }
             let input = create_physical_plan_impl(input, lp_arena, expr_arena, state)?;
             let mut state = ExpressionConversionState::new(true, state.expr_depth);
-            let predicate = create_physical_expr(
+            let predicate = create_physical_filter_expr(
                 &predicate,
-                Context::Default,
                 expr_arena,
                 Some(&input_schema),
                 &mut state,
@@ -309,6 +361,13 @@ fn create_physical_plan_impl(
                         predicate_has_windows: state.has_windows,
                     }))
                 },
+                FileScan::Source { source } => Ok(Box::new(executors::ScanSourceExec {
+                    source,
+                    file_options,
+                    file_info,
+                    predicate,
+                    output_schema,
+                })),
             }
         },
         Select {
@@ -325,7 +384,9 @@ fn create_physical_plan_impl(
                 state.expr_depth,
             );
 
-            let streamable = all_streamable(&expr, expr_arena, Context::Default);
+            let sequential = sequential_exprs(&expr, expr_arena);
+            let streamable =
+                all_streamable(&expr, expr_arena, Context::Default) && !sequential.contains(&true);
             let phys_expr = create_physical_expressions_from_irs(
                 &expr,
                 Context::Default,
@@ -342,6 +403,7 @@ fn create_physical_plan_impl(
                 schema: _schema,
                 options,
                 streamable,
+                sequential,
             }))
         },
         Reduce {
@@ -356,7 +418,7 @@ fn create_physical_plan_impl(
                 options: Default::default(),
             };
             let node = lp_arena.add(select);
-            create_physical_plan(node, lp_arena, expr_arena)
+            create_physical_plan_node(node, lp_arena, expr_arena, state)
         },
         DataFrameScan {
             df,
@@ -366,15 +428,11 @@ fn create_physical_plan_impl(
             ..
         } => {
             let mut state = ExpressionConversionState::new(true, state.expr_depth);
+            // The filter is applied after the projection.
+            let filter_schema = output_schema.as_ref().unwrap_or(&schema);
             let selection = predicate
                 .map(|pred| {
-                    create_physical_expr(
-                        &pred,
-                        Context::Default,
-                        expr_arena,
-                        Some(&schema),
-                        &mut state,
-                    )
+                    create_physical_filter_expr(&pred, expr_arena, Some(filter_schema), &mut state)
                 })
                 .transpose()?;
             Ok(Box::new(executors::DataFrameExec {
@@ -450,16 +508,17 @@ fn create_physical_plan_impl(
 
             let _slice = options.slice;
             #[cfg(feature = "dynamic_group_by")]
-            if let Some(options) = options.dynamic {
+            if let Some(dynamic) = options.dynamic {
                 let input = create_physical_plan_impl(input, lp_arena, expr_arena, state)?;
                 return Ok(Box::new(executors::GroupByDynamicExec {
                     input,
                     keys: phys_keys,
                     aggs: phys_aggs,
-                    options,
+                    options: dynamic,
                     input_schema,
                     slice: _slice,
                     apply,
+                    resample: options.resample,
                 }));
             }
 
@@ -477,6 +536,20 @@ fn create_physical_plan_impl(
                 }));
             }
 
+            #[cfg(feature = "grouping_sets")]
+            if let Some(grouping_sets) = options.grouping_sets {
+                let input = create_physical_plan_impl(input, lp_arena, expr_arena, state)?;
+                return Ok(Box::new(executors::GroupByGroupingSetsExec {
+                    input,
+                    keys: phys_keys,
+                    aggs: phys_aggs,
+                    grouping_sets,
+                    maintain_order,
+                    input_schema,
+                    slice: _slice,
+                }));
+            }
+
             // We first check if we can partition the group_by on the latest moment.
             let partitionable = partitionable_gb(&keys, &aggs, &input_schema, expr_arena, &apply);
             if partitionable {
@@ -579,7 +652,9 @@ fn create_physical_plan_impl(
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
             let input = create_physical_plan_impl(input, lp_arena, expr_arena, state)?;
 
-            let streamable = all_streamable(&exprs, expr_arena, Context::Default);
+            let sequential = sequential_exprs(&exprs, expr_arena);
+            let streamable =
+                all_streamable(&exprs, expr_arena, Context::Default) && !sequential.contains(&true);
 
             let mut state = ExpressionConversionState::new(
                 POOL.current_num_threads() > exprs.len(),
@@ -600,6 +675,7 @@ fn create_physical_plan_impl(
                 input_schema,
                 options,
                 streamable,
+                sequential,
             }))
         },
         MapFunction {
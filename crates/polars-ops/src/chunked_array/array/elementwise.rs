@@ -0,0 +1,154 @@
+//! Aggregations that combine the arrays of all rows position by position, e.g. to sum count
+//! vectors or to average embeddings. The result is a single array.
+use arrow::array::{Array, FixedSizeListArray};
+use arrow::bitmap::Bitmap;
+use polars_core::with_match_physical_numeric_polars_type;
+
+use super::*;
+
+/// The dtype of the element-wise sum of arrays with this inner dtype.
+pub fn sum_elementwise_dtype(inner: &DataType) -> PolarsResult<DataType> {
+    use DataType::*;
+    Ok(match inner {
+        Boolean => IDX_DTYPE,
+        Int8 | UInt8 | Int16 | UInt16 => Int64,
+        dt if dt.is_numeric() => dt.clone(),
+        dt => polars_bail!(
+            InvalidOperation: "`sum_elementwise` operation not supported for dtype `{}`", dt
+        ),
+    })
+}
+
+/// The dtype of the element-wise mean of arrays with this inner dtype.
+pub fn mean_elementwise_dtype(inner: &DataType) -> PolarsResult<DataType> {
+    use DataType::*;
+    Ok(match inner {
+        Float32 => Float32,
+        Boolean => Float64,
+        dt if dt.is_numeric() => Float64,
+        dt => polars_bail!(
+            InvalidOperation: "`mean_elementwise` operation not supported for dtype `{}`", dt
+        ),
+    })
+}
+
+/// The sums and the number of non-null values at every position of the valid rows.
+fn sum_positions<T: PolarsNumericType>(
+    values: &ChunkedArray<T>,
+    width: usize,
+    row_validity: Option<&Bitmap>,
+) -> (Vec<T::Native>, Vec<IdxSize>) {
+    let mut sums = vec![T::Native::default(); width];
+    let mut counts = vec![0 as IdxSize; width];
+    let arr = values.downcast_iter().next().unwrap();
+    for (row, row_values) in arr.values().chunks_exact(width).enumerate() {
+        if !row_validity.map_or(true, |v| v.get_bit(row)) {
+            continue;
+        }
+        for (i, v) in row_values.iter().enumerate() {
+            if arr.is_valid(row * width + i) {
+                sums[i] += *v;
+                counts[i] += 1;
+            }
+        }
+    }
+    (sums, counts)
+}
+
+/// Wrap the aggregated positions in an array column of a single row, which is null if there
+/// were no valid rows.
+fn single_array(name: &str, inner: Series, width: usize, any_valid: bool) -> Series {
+    let inner = inner.rechunk();
+    let values = inner.chunks()[0].clone();
+    let arrow_dtype = FixedSizeListArray::default_datatype(values.data_type().clone(), width);
+    let validity = (!any_valid).then(|| Bitmap::new_zeroed(1));
+    let arr = FixedSizeListArray::new(arrow_dtype, values, validity).boxed();
+    let dtype = DataType::Array(Box::new(inner.dtype().clone()), width);
+    // SAFETY: the array is built from `dtype`.
+    unsafe { Series::from_chunks_and_dtype_unchecked(name, vec![arr], &dtype) }
+}
+
+fn prepare(ca: &ArrayChunked, dtype: &DataType) -> PolarsResult<(Series, Option<Bitmap>, bool)> {
+    let ca = ca.rechunk();
+    let row_validity = ca.downcast_iter().next().unwrap().validity().cloned();
+    let any_valid = ca.null_count() < ca.len();
+    let values = ca.get_inner().cast(dtype)?.rechunk();
+    Ok((values, row_validity, any_valid))
+}
+
+/// Sum the arrays of all rows element-wise. Null values are skipped, a position without any
+/// values sums to zero.
+pub(super) fn sum_elementwise(ca: &ArrayChunked) -> PolarsResult<Series> {
+    let width = ca.width();
+    let dtype = sum_elementwise_dtype(ca.inner_dtype())?;
+    let (values, row_validity, any_valid) = prepare(ca, &dtype)?;
+
+    let inner = if width == 0 {
+        Series::new_empty("", &dtype)
+    } else {
+        with_match_physical_numeric_polars_type!(&dtype, |$T| {
+            let values: &ChunkedArray<$T> = values.as_ref().as_ref().as_ref();
+            let (sums, _) = sum_positions(values, width, row_validity.as_ref());
+            ChunkedArray::<$T>::from_vec("", sums).into_series()
+        })
+    };
+    Ok(single_array(ca.name(), inner, width, any_valid))
+}
+
+/// Average the arrays of all rows element-wise. Null values are skipped, a position without any
+/// values is null.
+pub(super) fn mean_elementwise(ca: &ArrayChunked) -> PolarsResult<Series> {
+    let width = ca.width();
+    let dtype = mean_elementwise_dtype(ca.inner_dtype())?;
+    let (values, row_validity, any_valid) = prepare(ca, &dtype)?;
+
+    let inner = if width == 0 {
+        Series::new_empty("", &dtype)
+    } else if dtype == DataType::Float32 {
+        let (sums, counts) = sum_positions(values.f32()?, width, row_validity.as_ref());
+        sums.into_iter()
+            .zip(counts)
+            .map(|(sum, n)| (n > 0).then(|| sum / n as f32))
+            .collect::<Float32Chunked>()
+            .into_series()
+    } else {
+        let (sums, counts) = sum_positions(values.f64()?, width, row_validity.as_ref());
+        sums.into_iter()
+            .zip(counts)
+            .map(|(sum, n)| (n > 0).then(|| sum / n as f64))
+            .collect::<Float64Chunked>()
+            .into_series()
+    };
+    Ok(single_array(ca.name(), inner, width, any_valid))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn array(values: &[Option<i32>], width: usize) -> ArrayChunked {
+        Series::new("a", values)
+            .reshape_array(&[-1, width as i64])
+            .unwrap()
+            .array()
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn test_elementwise() -> PolarsResult<()> {
+        let ca = array(&[Some(1), Some(2), Some(3), None, Some(5), Some(6)], 2);
+        let sum = sum_elementwise(&ca)?;
+        assert_eq!(sum.dtype(), &DataType::Array(Box::new(DataType::Int32), 2));
+        let sum = sum.array()?.get_inner();
+        assert_eq!(Vec::from(sum.i32()?), &[Some(9), Some(8)]);
+
+        let mean = mean_elementwise(&ca)?.array()?.get_inner();
+        assert_eq!(Vec::from(mean.f64()?), &[Some(3.0), Some(4.0)]);
+
+        let ca = ca.slice(0, 2);
+        let sum = sum_elementwise(&ca)?.array()?.get_inner();
+        assert_eq!(Vec::from(sum.i32()?), &[Some(4), Some(2)]);
+        Ok(())
+    }
+}
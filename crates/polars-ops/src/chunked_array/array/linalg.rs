@@ -0,0 +1,292 @@
+//! Linear algebra on array columns of numbers, e.g. similarities between embeddings. Every row
+//! is a vector, or a matrix for arrays of arrays, and the kernels of
+//! [`polars_compute::linalg`] are applied to the rows.
+use arrow::array::{FixedSizeListArray, PrimitiveArray};
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use num_traits::{Float, Zero};
+use polars_compute::linalg;
+
+use super::*;
+
+/// The float dtype of the result of a linear algebra operation on arrays with these dtypes,
+/// which is `Float32` if all leaves are `Float32` and `Float64` otherwise.
+pub fn linalg_dtype(name: &str, dtypes: &[&DataType]) -> PolarsResult<DataType> {
+    let mut all_f32 = true;
+    for dtype in dtypes {
+        let leaf = dtype.leaf_dtype();
+        polars_ensure!(
+            dtype.is_array() && leaf.is_numeric(),
+            InvalidOperation: "`arr.{}` expects arrays of numbers, got {}", name, dtype
+        );
+        all_f32 &= leaf == &DataType::Float32;
+    }
+    Ok(if all_f32 {
+        DataType::Float32
+    } else {
+        DataType::Float64
+    })
+}
+
+/// Check that the arrays of a vector operation are 1-dimensional and of the same width.
+fn check_vectors(name: &str, a: &DataType, b: Option<&DataType>) -> PolarsResult<()> {
+    let shape = |dtype: &DataType| dtype.get_shape().unwrap_or_default();
+    let a_shape = shape(a);
+    polars_ensure!(
+        a_shape.len() == 1,
+        InvalidOperation: "`arr.{}` expects 1-dimensional arrays, got {}", name, a
+    );
+    if let Some(b) = b {
+        polars_ensure!(
+            shape(b) == a_shape,
+            ShapeMismatch: "`arr.{}` expects arrays of the same shape, got {} and {}", name, a, b
+        );
+    }
+    Ok(())
+}
+
+/// The shape of the result of `arr.matmul` of arrays with these dtypes.
+pub fn matmul_shape(a: &DataType, b: &DataType) -> PolarsResult<Vec<usize>> {
+    let a_shape = a.get_shape().unwrap_or_default();
+    let b_shape = b.get_shape().unwrap_or_default();
+    let shape = match (a_shape.as_slice(), b_shape.as_slice()) {
+        ([n, k], [k2, m]) if k == k2 => vec![*n, *m],
+        ([n, k], [k2]) if k == k2 => vec![*n],
+        ([k], [k2, m]) if k == k2 => vec![*m],
+        ([_], [_]) => polars_bail!(
+            InvalidOperation: "`arr.matmul` of two vectors is not supported, use `arr.dot` instead"
+        ),
+        _ => polars_bail!(
+            ShapeMismatch: "`arr.matmul` can't multiply arrays of dtypes {} and {}", a, b
+        ),
+    };
+    Ok(shape)
+}
+
+/// The dtype of the result of `arr.matmul` of arrays with these dtypes.
+pub fn matmul_dtype(a: &DataType, b: &DataType) -> PolarsResult<DataType> {
+    let leaf = linalg_dtype("matmul", &[a, b])?;
+    let shape = matmul_shape(a, b)?;
+    Ok(shape
+        .into_iter()
+        .rev()
+        .fold(leaf, |dtype, width| DataType::Array(Box::new(dtype), width)))
+}
+
+/// The rows of an array column as contiguous slices of floats. A row that contains a null is
+/// treated as null.
+struct Rows<T: PolarsFloatType> {
+    values: ChunkedArray<T>,
+    width: usize,
+    validity: Option<Bitmap>,
+    len: usize,
+}
+
+impl<T: PolarsFloatType> Rows<T>
+where
+    T::Native: Float,
+{
+    fn new(ca: &ArrayChunked) -> PolarsResult<Self> {
+        let ca = ca.rechunk();
+        let width = ca.dtype().get_shape().unwrap().iter().product::<usize>();
+        let values = ca
+            .clone()
+            .into_series()
+            .get_leaf_array()
+            .cast(&T::get_dtype())?
+            .rechunk();
+        let values = values.unpack::<T>()?.clone();
+        let row_validity = ca.downcast_iter().next().unwrap().validity();
+        let value_validity = values.downcast_iter().next().unwrap().validity();
+        let validity = if row_validity.is_none() && value_validity.is_none() {
+            None
+        } else {
+            let row_is_valid = |row: usize| {
+                row_validity.map_or(true, |v| v.get_bit(row))
+                    && value_validity.map_or(true, |v| {
+                        (row * width..(row + 1) * width).all(|i| v.get_bit(i))
+                    })
+            };
+            Some((0..ca.len()).map(row_is_valid).collect())
+        };
+        Ok(Self {
+            values,
+            width,
+            validity,
+            len: ca.len(),
+        })
+    }
+
+    /// The row at `row`, where a single row is broadcast to every row.
+    fn get(&self, row: usize) -> Option<&[T::Native]> {
+        let row = if self.len == 1 { 0 } else { row };
+        if !self.validity.as_ref().map_or(true, |v| v.get_bit(row)) {
+            return None;
+        }
+        let values = self.values.downcast_iter().next().unwrap().values();
+        Some(&values[row * self.width..(row + 1) * self.width])
+    }
+}
+
+fn broadcast_len(name: &str, a: usize, b: usize) -> PolarsResult<usize> {
+    match (a, b) {
+        (a, b) if a == b => Ok(a),
+        (1, len) | (len, 1) => Ok(len),
+        _ => polars_bail!(
+            ShapeMismatch: "`arr.{}` got columns of lengths {} and {}", name, a, b
+        ),
+    }
+}
+
+fn binary_reduce<T: PolarsFloatType>(
+    name: &str,
+    a: &ArrayChunked,
+    b: &ArrayChunked,
+    f: impl Fn(&[T::Native], &[T::Native]) -> T::Native,
+) -> PolarsResult<Series>
+where
+    T::Native: Float,
+    ChunkedArray<T>: IntoSeries,
+{
+    let len = broadcast_len(name, a.len(), b.len())?;
+    let (a_rows, b_rows) = (Rows::<T>::new(a)?, Rows::<T>::new(b)?);
+    let out: ChunkedArray<T> = (0..len)
+        .map(|row| Some(f(a_rows.get(row)?, b_rows.get(row)?)))
+        .collect();
+    Ok(out.with_name(a.name()).into_series())
+}
+
+/// The dot product of the arrays of every row.
+pub(super) fn dot(a: &ArrayChunked, b: &ArrayChunked) -> PolarsResult<Series> {
+    check_vectors("dot", a.dtype(), Some(b.dtype()))?;
+    match linalg_dtype("dot", &[a.dtype(), b.dtype()])? {
+        DataType::Float32 => binary_reduce::<Float32Type>("dot", a, b, linalg::dot),
+        _ => binary_reduce::<Float64Type>("dot", a, b, linalg::dot),
+    }
+}
+
+/// The cosine similarity of the arrays of every row.
+pub(super) fn cosine_similarity(a: &ArrayChunked, b: &ArrayChunked) -> PolarsResult<Series> {
+    let name = "cosine_similarity";
+    check_vectors(name, a.dtype(), Some(b.dtype()))?;
+    match linalg_dtype(name, &[a.dtype(), b.dtype()])? {
+        DataType::Float32 => binary_reduce::<Float32Type>(name, a, b, linalg::cosine_similarity),
+        _ => binary_reduce::<Float64Type>(name, a, b, linalg::cosine_similarity),
+    }
+}
+
+fn l2_norm_impl<T: PolarsFloatType>(ca: &ArrayChunked) -> PolarsResult<Series>
+where
+    T::Native: Float,
+    ChunkedArray<T>: IntoSeries,
+{
+    let rows = Rows::<T>::new(ca)?;
+    let out: ChunkedArray<T> = (0..ca.len())
+        .map(|row| rows.get(row).map(linalg::l2_norm))
+        .collect();
+    Ok(out.with_name(ca.name()).into_series())
+}
+
+/// The euclidean norm of the array of every row.
+pub(super) fn l2_norm(ca: &ArrayChunked) -> PolarsResult<Series> {
+    check_vectors("l2_norm", ca.dtype(), None)?;
+    match linalg_dtype("l2_norm", &[ca.dtype()])? {
+        DataType::Float32 => l2_norm_impl::<Float32Type>(ca),
+        _ => l2_norm_impl::<Float64Type>(ca),
+    }
+}
+
+fn matmul_impl<T: PolarsFloatType>(a: &ArrayChunked, b: &ArrayChunked) -> PolarsResult<Series>
+where
+    T::Native: Float,
+{
+    let len = broadcast_len("matmul", a.len(), b.len())?;
+    let a_shape = a.dtype().get_shape().unwrap();
+    let b_shape = b.dtype().get_shape().unwrap();
+    let (n, k) = match a_shape.as_slice() {
+        [n, k] => (*n, *k),
+        [k] => (1, *k),
+        _ => unreachable!(),
+    };
+    let m = b_shape.get(1).copied().unwrap_or(1);
+    let shape = matmul_shape(a.dtype(), b.dtype())?;
+
+    let (a_rows, b_rows) = (Rows::<T>::new(a)?, Rows::<T>::new(b)?);
+    let mut values = vec![T::Native::zero(); len * n * m];
+    let mut validity = MutableBitmap::with_capacity(len);
+    for row in 0..len {
+        let out = &mut values[row * n * m..(row + 1) * n * m];
+        match (a_rows.get(row), b_rows.get(row)) {
+            (Some(a), Some(b)) => {
+                linalg::matmul(a, b, out, n, k, m);
+                validity.push(true);
+            },
+            _ => validity.push(false),
+        }
+    }
+    let validity: Bitmap = validity.into();
+    let validity = (validity.unset_bits() > 0).then_some(validity);
+
+    let mut arr = PrimitiveArray::from_vec(values).boxed();
+    let mut dtype = T::get_dtype();
+    for (i, width) in shape.iter().enumerate().rev() {
+        let arrow_dtype = FixedSizeListArray::default_datatype(arr.data_type().clone(), *width);
+        let validity = if i == 0 { validity.clone() } else { None };
+        arr = FixedSizeListArray::new(arrow_dtype, arr, validity).boxed();
+        dtype = DataType::Array(Box::new(dtype), *width);
+    }
+    // SAFETY: the array is built from `dtype`.
+    Ok(unsafe { Series::from_chunks_and_dtype_unchecked(a.name(), vec![arr], &dtype) })
+}
+
+/// The matrix product of the arrays of every row. The arrays are matrices of 2 dimensions or
+/// vectors of 1 dimension, like for `numpy.matmul`.
+pub(super) fn matmul(a: &ArrayChunked, b: &ArrayChunked) -> PolarsResult<Series> {
+    match matmul_dtype(a.dtype(), b.dtype())?.leaf_dtype() {
+        DataType::Float32 => matmul_impl::<Float32Type>(a, b),
+        _ => matmul_impl::<Float64Type>(a, b),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn array(values: &[Option<f64>], shape: &[i64]) -> ArrayChunked {
+        Series::new("a", values)
+            .reshape_array(shape)
+            .unwrap()
+            .array()
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn test_array_linalg() -> PolarsResult<()> {
+        let a = array(
+            &[Some(1.0), Some(2.0), Some(3.0), None, Some(0.0), Some(4.0)],
+            &[-1, 2],
+        );
+        let b = array(&[Some(3.0), Some(4.0)], &[-1, 2]);
+
+        let out = dot(&a, &b)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(11.0), None, Some(16.0)]);
+        let out = l2_norm(&b)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(5.0)]);
+        let out = cosine_similarity(&b, &b)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(1.0)]);
+
+        // [[1, 2], [3, 4]] x [3, 4]
+        let m = array(&[Some(1.0), Some(2.0), Some(3.0), Some(4.0)], &[-1, 2, 2]);
+        let out = matmul(&m, &b)?;
+        assert_eq!(
+            out.dtype(),
+            &DataType::Array(Box::new(DataType::Float64), 2)
+        );
+        let out = out.get_leaf_array();
+        assert_eq!(Vec::from(out.f64()?), &[Some(11.0), Some(25.0)]);
+
+        assert!(matmul(&b, &b).is_err());
+        assert!(dot(&a, &m).is_err());
+        Ok(())
+    }
+}
@@ -2,16 +2,22 @@
 mod any_all;
 mod count;
 mod dispersion;
+mod elementwise;
 mod get;
 mod join;
+mod linalg;
 mod min_max;
 mod namespace;
+mod reshape;
 mod sum_mean;
 #[cfg(feature = "array_to_struct")]
 mod to_struct;
 
+pub use elementwise::{mean_elementwise_dtype, sum_elementwise_dtype};
+pub use linalg::{linalg_dtype, matmul_dtype};
 pub use namespace::ArrayNameSpace;
 use polars_core::prelude::*;
+pub use reshape::array_reshape_shape;
 #[cfg(feature = "array_to_struct")]
 pub use to_struct::*;
 
@@ -8,6 +8,7 @@ use crate::chunked_array::array::sum_mean::sum_with_nulls;
 use crate::prelude::array::any_all::{array_all, array_any};
 use crate::prelude::array::get::array_get;
 use crate::prelude::array::join::array_join;
+use crate::prelude::array::reshape::{array_explode_to_rows, array_reshape};
 use crate::prelude::array::sum_mean::sum_array_numerical;
 use crate::series::ArgAgg;
 
@@ -170,6 +171,46 @@ pub trait ArrayNameSpace: AsArray {
         };
         Ok(out.into_series())
     }
+
+    fn array_explode_to_rows(&self) -> Series {
+        let ca = self.as_array();
+        array_explode_to_rows(ca)
+    }
+
+    fn array_reshape(&self, dimensions: &[i64]) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        array_reshape(ca, dimensions)
+    }
+
+    fn array_sum_elementwise(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        elementwise::sum_elementwise(ca)
+    }
+
+    fn array_mean_elementwise(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        elementwise::mean_elementwise(ca)
+    }
+
+    fn array_dot(&self, other: &Series) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        linalg::dot(ca, other.array()?)
+    }
+
+    fn array_l2_norm(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        linalg::l2_norm(ca)
+    }
+
+    fn array_cosine_similarity(&self, other: &Series) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        linalg::cosine_similarity(ca, other.array()?)
+    }
+
+    fn array_matmul(&self, other: &Series) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        linalg::matmul(ca, other.array()?)
+    }
 }
 
 impl ArrayNameSpace for ArrayChunked {}
@@ -0,0 +1,129 @@
+use arrow::array::FixedSizeListArray;
+use arrow::bitmap::MutableBitmap;
+use arrow::compute::utils::combine_validities_and;
+use polars_utils::format_tuple;
+
+use super::*;
+
+/// Explode the outer dimension of the arrays into rows.
+///
+/// Every row produces exactly `width` rows, so a null array results in `width` nulls.
+/// Contrary to `explode`, the remaining dimensions stay `Array`s.
+pub fn array_explode_to_rows(ca: &ArrayChunked) -> Series {
+    let width = ca.width();
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| {
+            let values = arr.values();
+            match arr.validity() {
+                Some(validity) if validity.unset_bits() > 0 => {
+                    let mut repeated = MutableBitmap::with_capacity(values.len());
+                    for is_valid in validity.iter() {
+                        repeated.extend_constant(width, is_valid);
+                    }
+                    let validity =
+                        combine_validities_and(Some(&repeated.freeze()), values.validity());
+                    values.with_validity(validity)
+                },
+                _ => values.clone(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // SAFETY: the values of an array are of its inner dtype.
+    unsafe { Series::from_chunks_and_dtype_unchecked(ca.name(), chunks, ca.inner_dtype()) }
+}
+
+/// Resolve the shape `arr.reshape` produces for arrays of `dtype`.
+///
+/// A single `-1` dimension is inferred from the total width of the arrays.
+pub fn array_reshape_shape(dtype: &DataType, dimensions: &[i64]) -> PolarsResult<Vec<usize>> {
+    polars_ensure!(
+        matches!(dtype, DataType::Array(_, _)),
+        SchemaMismatch: "invalid series dtype: expected `Array`, got `{}`", dtype
+    );
+    polars_ensure!(
+        !dimensions.is_empty(),
+        InvalidOperation: "at least one dimension must be specified"
+    );
+
+    let mut size = 1;
+    let mut dt = dtype;
+    while let DataType::Array(inner, width) = dt {
+        size *= *width;
+        dt = inner;
+    }
+    polars_ensure!(size > 0, InvalidOperation: "cannot reshape arrays of width zero");
+
+    let mut known_size = 1;
+    let mut infer_index = None;
+    for (index, &dim) in dimensions.iter().enumerate() {
+        if dim > 0 {
+            known_size *= dim as usize;
+        } else if dim == -1 {
+            polars_ensure!(
+                infer_index.is_none(),
+                InvalidOperation: "can only specify one unknown dimension"
+            );
+            infer_index = Some(index);
+        } else {
+            polars_bail!(
+                InvalidOperation: "invalid dimension {} in shape {}; dimensions must be positive or -1",
+                dim, format_tuple!(dimensions)
+            );
+        }
+    }
+
+    let mut shape = dimensions
+        .iter()
+        .map(|&dim| dim as usize)
+        .collect::<Vec<_>>();
+    if let Some(index) = infer_index {
+        shape[index] = size / known_size;
+    }
+    polars_ensure!(
+        shape.iter().product::<usize>() == size,
+        InvalidOperation: "cannot reshape arrays of size {} into shape {}", size, format_tuple!(dimensions)
+    );
+    Ok(shape)
+}
+
+/// Reshape every array into the given dimensions, keeping the number of rows.
+pub fn array_reshape(ca: &ArrayChunked, dimensions: &[i64]) -> PolarsResult<ArrayChunked> {
+    let shape = array_reshape_shape(ca.dtype(), dimensions)?;
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+
+    // Peel off the nested arrays down to the leaf values.
+    let mut values = arr.values().clone();
+    let mut dtype = ca.inner_dtype().clone();
+    while let DataType::Array(inner, _) = dtype {
+        polars_ensure!(
+            values.null_count() == 0,
+            InvalidOperation: "cannot reshape arrays containing null sub-arrays"
+        );
+        values = values
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap()
+            .values()
+            .clone();
+        dtype = *inner;
+    }
+
+    for (i, &dim) in shape.iter().enumerate().rev() {
+        dtype = DataType::Array(Box::new(dtype), dim);
+        // The outer dimension holds the rows.
+        let validity = if i == 0 {
+            arr.validity().cloned()
+        } else {
+            None
+        };
+        let arrow_dtype = FixedSizeListArray::default_datatype(values.data_type().clone(), dim);
+        values = FixedSizeListArray::new(arrow_dtype, values, validity).boxed();
+    }
+
+    // SAFETY: the arrays are built from `dtype`.
+    let out = unsafe { Series::from_chunks_and_dtype_unchecked(ca.name(), vec![values], &dtype) };
+    Ok(out.array().unwrap().clone())
+}
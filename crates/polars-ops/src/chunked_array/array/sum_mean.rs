@@ -1,27 +1,18 @@
 use arrow::array::{Array, PrimitiveArray};
 use arrow::bitmap::Bitmap;
-use arrow::legacy::utils::CustomIterTools;
 use arrow::types::NativeType;
-use polars_core::export::num::{NumCast, ToPrimitive};
+use polars_compute::segmented::{fixed_size_segments, segmented_sum, SegmentSum};
 use polars_core::prelude::*;
 
-use crate::chunked_array::sum::sum_slice;
-
 fn dispatch_sum<T, S>(arr: &dyn Array, width: usize, validity: Option<&Bitmap>) -> ArrayRef
 where
-    T: NativeType + ToPrimitive,
-    S: NativeType + NumCast + std::iter::Sum,
+    T: NativeType + SegmentSum<S>,
+    S: NativeType,
 {
     let values = arr.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
     let values = values.values().as_slice();
 
-    let summed: Vec<_> = (0..values.len())
-        .step_by(width)
-        .map(|start| {
-            let slice = unsafe { values.get_unchecked(start..start + width) };
-            sum_slice::<T, S>(slice)
-        })
-        .collect_trusted();
+    let summed = segmented_sum::<_, S>(values, fixed_size_segments(values.len(), width));
 
     Box::new(PrimitiveArray::from_data_default(
         summed.into(),
@@ -5,123 +5,169 @@ use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_polars_type;
 use polars_utils::float::IsFloat;
 use polars_utils::total_ord::ToTotalOrd;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-fn compute_hist<T>(
-    ca: &ChunkedArray<T>,
-    bin_count: Option<usize>,
-    bins: Option<&[f64]>,
-    include_category: bool,
-    include_breakpoint: bool,
-) -> Series
-where
-    T: PolarsNumericType,
-    ChunkedArray<T>: ChunkAgg<T::Native>,
-{
-    let (breaks, count) = if let Some(bins) = bins {
-        let mut breaks = Vec::with_capacity(bins.len() + 1);
-        breaks.extend_from_slice(bins);
-        breaks.sort_unstable_by_key(|k| k.to_total_ord());
-        breaks.push(f64::INFINITY);
-
-        let sorted = ca.sort(false);
-
-        let mut count: Vec<IdxSize> = Vec::with_capacity(breaks.len());
-        let mut current_count: IdxSize = 0;
-        let mut breaks_iter = breaks.iter();
-
-        // We start with the lower garbage bin.
-        // (-inf, B0]
-        let mut lower_bound = f64::NEG_INFINITY;
-        let mut upper_bound = *breaks_iter.next().unwrap();
-
-        for chunk in sorted.downcast_iter() {
-            for item in chunk.non_null_values_iter() {
-                let item = item.to_f64().unwrap();
-
-                // Not a member of current interval
-                if !(item <= upper_bound && item > lower_bound) {
-                    loop {
-                        // So we push the previous interval
-                        count.push(current_count);
-                        current_count = 0;
-                        lower_bound = upper_bound;
-                        upper_bound = *breaks_iter.next().unwrap();
-                        if item <= upper_bound && item > lower_bound {
-                            break;
-                        }
-                    }
-                }
-                current_count += 1;
-            }
+/// Rule that determines the number of bins of a histogram from the data.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HistBinStrategy {
+    /// Bins of width `2 * IQR / n^(1/3)`. Falls back to [`HistBinStrategy::Sturges`] if
+    /// the interquartile range is zero.
+    FreedmanDiaconis,
+    /// `log2(n) + 1` bins.
+    Sturges,
+}
+
+/// The upper bounds of the bins of a histogram. The first bin has no lower bound and the
+/// upper bound of the last bin is infinite.
+struct Breaks {
+    upper: Vec<f64>,
+    /// The lower bound of the first finite bin and the width of the bins, if the bins are
+    /// equally wide. Used to find the bin of a value without searching.
+    uniform: Option<(f64, f64)>,
+}
+
+impl Breaks {
+    fn explicit(bins: &[f64]) -> Self {
+        let mut upper = Vec::with_capacity(bins.len() + 1);
+        upper.extend_from_slice(bins);
+        upper.sort_unstable_by_key(|k| k.to_total_ord());
+        upper.push(f64::INFINITY);
+        Self {
+            upper,
+            uniform: None,
         }
-        // Add last value, this is the garbage bin. E.g. anything that doesn't fit in the bounds.
-        count.push(current_count);
-        // Add the remaining buckets
-        while count.len() < breaks.len() {
-            count.push(0)
+    }
+
+    /// A single bin that holds everything, used if there is no data to derive the bins from.
+    fn unbounded() -> Self {
+        Self {
+            upper: vec![f64::INFINITY],
+            uniform: None,
         }
-        (breaks, count)
-    } else if ca.null_count() == ca.len() {
-        let breaks: Vec<f64> = vec![f64::INFINITY];
-        let count: Vec<IdxSize> = vec![0];
-        (breaks, count)
-    } else {
-        let min = ChunkAgg::min(ca).unwrap().to_f64().unwrap();
-        let max = ChunkAgg::max(ca).unwrap().to_f64().unwrap();
+    }
+
+    /// Equally wide bins that cover the range of `s`.
+    fn uniform(
+        s: &Series,
+        bin_count: Option<usize>,
+        strategy: Option<HistBinStrategy>,
+    ) -> PolarsResult<Self> {
+        let (Some(min), Some(max)) = (s.min::<f64>()?, s.max::<f64>()?) else {
+            return Ok(Self::unbounded());
+        };
 
         let start = min.floor() - 1.0;
         let end = max.ceil() + 1.0;
 
         // If bin_count is omitted, default to the difference between start and stop (unit bins)
-        let bin_count = if let Some(bin_count) = bin_count {
-            bin_count
-        } else {
-            (end - start).round() as usize
+        let bin_count = match (bin_count, strategy) {
+            (Some(bin_count), _) => bin_count,
+            (None, Some(strategy)) => strategy_bin_count(s, strategy, min, max)?,
+            (None, None) => (end - start).round() as usize,
         };
+        polars_ensure!(bin_count > 0, InvalidOperation: "'bin_count' must be positive");
 
         // Calculate the breakpoints and make the array
         let interval = (end - start) / (bin_count as f64);
 
-        let breaks_iter = (0..(bin_count)).map(|b| start + (b as f64) * interval);
-
-        let mut breaks = Vec::with_capacity(breaks_iter.size_hint().0 + 1);
-        breaks.extend(breaks_iter);
-        breaks.push(f64::INFINITY);
-
-        let mut count: Vec<IdxSize> = vec![0; breaks.len()];
-        let end_idx = count.len() - 1;
+        let mut upper = Vec::with_capacity(bin_count + 1);
+        upper.extend((0..bin_count).map(|b| start + (b as f64) * interval));
+        upper.push(f64::INFINITY);
 
         // start is the closed rhs of the interval, so we subtract the bucket width
-        let start_range = start - interval;
-        for chunk in ca.downcast_iter() {
-            for item in chunk.non_null_values_iter() {
-                let item = item.to_f64().unwrap() - start_range;
+        Ok(Self {
+            upper,
+            uniform: Some((start - interval, interval)),
+        })
+    }
 
+    fn new(
+        s: &Series,
+        bin_count: Option<usize>,
+        bins: Option<&[f64]>,
+        strategy: Option<HistBinStrategy>,
+    ) -> PolarsResult<Self> {
+        match bins {
+            Some(bins) => Ok(Self::explicit(bins)),
+            None => Self::uniform(s, bin_count, strategy),
+        }
+    }
+
+    /// The index of the bin `value` falls in.
+    fn index(&self, value: f64, is_float: bool) -> usize {
+        let end_idx = self.upper.len() - 1;
+        match self.uniform {
+            Some((start, interval)) => {
                 // This is needed for numeric stability.
                 // Only for integers.
                 // we can fall directly on a boundary with an integer.
-                let item = item / interval;
-                let item = if !T::Native::is_float() && (item.round() - item).abs() < 0.0000001 {
+                let item = (value - start) / interval;
+                let item = if !is_float && (item.round() - item).abs() < 0.0000001 {
                     item.round() - 1.0
                 } else {
                     item.ceil() - 1.0
                 };
+                std::cmp::min(item as usize, end_idx)
+            },
+            // The bins are right-closed.
+            None => std::cmp::min(self.upper.partition_point(|b| *b < value), end_idx),
+        }
+    }
+}
 
-                let idx = item as usize;
-                let idx = std::cmp::min(idx, end_idx);
-                count[idx] += 1;
+fn strategy_bin_count(
+    s: &Series,
+    strategy: HistBinStrategy,
+    min: f64,
+    max: f64,
+) -> PolarsResult<usize> {
+    let n = (s.len() - s.null_count()) as f64;
+    let sturges = (n.log2() + 1.0).ceil() as usize;
+    match strategy {
+        HistBinStrategy::Sturges => Ok(sturges),
+        HistBinStrategy::FreedmanDiaconis => {
+            let quantile = |q| -> PolarsResult<f64> {
+                let q = s.quantile_reduce(q, QuantileInterpolOptions::Linear)?;
+                Ok(q.value().extract::<f64>().unwrap_or(f64::NAN))
+            };
+            let iqr = quantile(0.75)? - quantile(0.25)?;
+            if iqr > 0.0 {
+                let width = 2.0 * iqr / n.cbrt();
+                Ok(std::cmp::max(((max - min) / width).ceil() as usize, 1))
+            } else {
+                Ok(sturges)
             }
+        },
+    }
+}
+
+fn compute_hist<T>(
+    ca: &ChunkedArray<T>,
+    breaks: &Breaks,
+    include_category: bool,
+    include_breakpoint: bool,
+) -> Series
+where
+    T: PolarsNumericType,
+{
+    let mut count: Vec<IdxSize> = vec![0; breaks.upper.len()];
+    for chunk in ca.downcast_iter() {
+        for item in chunk.non_null_values_iter() {
+            let idx = breaks.index(item.to_f64().unwrap(), T::Native::is_float());
+            count[idx] += 1;
         }
-        (breaks, count)
-    };
+    }
+
     let mut fields = Vec::with_capacity(3);
     if include_category {
         // Use AnyValue for formatting.
         let mut lower = AnyValue::Float64(f64::NEG_INFINITY);
-        let mut categories = StringChunkedBuilder::new("category", breaks.len());
+        let mut categories = StringChunkedBuilder::new("category", breaks.upper.len());
 
         let mut buf = String::new();
-        for br in &breaks {
+        for br in &breaks.upper {
             let br = AnyValue::Float64(*br);
             buf.clear();
             write!(buf, "({lower}, {br}]").unwrap();
@@ -135,7 +181,7 @@ where
         fields.push(categories);
     };
     if include_breakpoint {
-        fields.insert(0, Series::new("breakpoint", breaks))
+        fields.insert(0, Series::new("breakpoint", &breaks.upper))
     }
 
     let count = Series::new("count", count);
@@ -151,30 +197,176 @@ where
     }
 }
 
+fn hist_with_breaks(
+    s: &Series,
+    breaks: &Breaks,
+    include_category: bool,
+    include_breakpoint: bool,
+) -> Series {
+    with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+         let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+         compute_hist(ca, breaks, include_category, include_breakpoint)
+    })
+}
+
+fn bins_to_f64(bins: Option<Series>) -> PolarsResult<Option<Float64Chunked>> {
+    bins.map(|bins| {
+        polars_ensure!(bins.null_count() == 0, InvalidOperation: "nulls not supported in 'bins' argument");
+        let bins = bins.cast(&DataType::Float64)?.rechunk();
+        Ok(bins.f64().unwrap().clone())
+    })
+    .transpose()
+}
+
+/// Compute the histogram of `s`.
+///
+/// The bins are either given explicitly by their upper bounds, or are `bin_count` equally
+/// wide bins over the range of the data. Without a `bin_count`, the number of bins is
+/// determined by `strategy`, or the bins have unit width.
+///
+/// If `s` is a list, a histogram is computed for every element of the list. The bins are
+/// derived from all elements together, so all histograms share the same bins.
 pub fn hist_series(
     s: &Series,
     bin_count: Option<usize>,
     bins: Option<Series>,
+    strategy: Option<HistBinStrategy>,
     include_category: bool,
     include_breakpoint: bool,
 ) -> PolarsResult<Series> {
-    let mut bins_arg = None;
+    let bins = bins_to_f64(bins)?;
+    let bins = bins.as_ref().map(|bins| bins.cont_slice().unwrap());
+
+    if let DataType::List(inner) = s.dtype() {
+        polars_ensure!(inner.is_numeric(), InvalidOperation: "'hist' is only supported for numeric data");
+        let ca = s.list()?;
+        let breaks = Breaks::new(&ca.get_inner(), bin_count, bins, strategy)?;
+        let out = ca.try_apply_amortized(|s| {
+            Ok(hist_with_breaks(
+                s.as_ref(),
+                &breaks,
+                include_category,
+                include_breakpoint,
+            ))
+        })?;
+        return Ok(out.into_series());
+    }
 
-    let owned_bins;
-    if let Some(bins) = bins {
-        polars_ensure!(bins.null_count() == 0, InvalidOperation: "nulls not supported in 'bins' argument");
-        let bins = bins.cast(&DataType::Float64)?;
-        let bins_s = bins.rechunk();
-        owned_bins = bins_s;
-        let bins = owned_bins.f64().unwrap();
-        let bins = bins.cont_slice().unwrap();
-        bins_arg = Some(bins);
-    };
     polars_ensure!(s.dtype().is_numeric(), InvalidOperation: "'hist' is only supported for numeric data");
+    let breaks = Breaks::new(s, bin_count, bins, strategy)?;
+    Ok(hist_with_breaks(
+        s,
+        &breaks,
+        include_category,
+        include_breakpoint,
+    ))
+}
 
-    let out = with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
-         let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
-         compute_hist(ca, bin_count, bins_arg, include_category, include_breakpoint)
-    });
-    Ok(out)
+/// Compute the two-dimensional histogram of `x` and `y`.
+///
+/// The bins of both axes are determined as in [`hist_series`]. The output is a struct with
+/// a row for every pair of bins, holding the upper bounds of the bins and the number of rows
+/// in which both values are not null and fall in the bins.
+pub fn hist_2d_series(
+    x: &Series,
+    y: &Series,
+    bin_count: Option<usize>,
+    bins: Option<Series>,
+    strategy: Option<HistBinStrategy>,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        x.dtype().is_numeric() && y.dtype().is_numeric(),
+        InvalidOperation: "'hist_2d' is only supported for numeric data"
+    );
+    polars_ensure!(
+        x.len() == y.len(),
+        ShapeMismatch: "'hist_2d' got inputs of lengths {} and {}", x.len(), y.len()
+    );
+    let bins = bins_to_f64(bins)?;
+    let bins = bins.as_ref().map(|bins| bins.cont_slice().unwrap());
+    let x_breaks = Breaks::new(x, bin_count, bins, strategy)?;
+    let y_breaks = Breaks::new(y, bin_count, bins, strategy)?;
+
+    let (x_float, y_float) = (x.dtype().is_float(), y.dtype().is_float());
+    let x_values = x.cast(&DataType::Float64)?;
+    let y_values = y.cast(&DataType::Float64)?;
+    let n_y = y_breaks.upper.len();
+    let mut count: Vec<IdxSize> = vec![0; x_breaks.upper.len() * n_y];
+    for (x, y) in x_values.f64()?.into_iter().zip(y_values.f64()?) {
+        if let (Some(x), Some(y)) = (x, y) {
+            let idx = x_breaks.index(x, x_float) * n_y + y_breaks.index(y, y_float);
+            count[idx] += 1;
+        }
+    }
+
+    let x_breakpoint = x_breaks
+        .upper
+        .iter()
+        .flat_map(|x| std::iter::repeat(*x).take(n_y))
+        .collect::<Vec<_>>();
+    let y_breakpoint = x_breaks
+        .upper
+        .iter()
+        .flat_map(|_| y_breaks.upper.iter().copied())
+        .collect::<Vec<_>>();
+    let fields = [
+        Series::new("x_breakpoint", x_breakpoint),
+        Series::new("y_breakpoint", y_breakpoint),
+        Series::new("count", count),
+    ];
+    Ok(StructChunked::new(x.name(), &fields)?.into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hist_shared_bins() -> PolarsResult<()> {
+        let s = Series::new("a", [1, 2, 3, 8]);
+        let groups = Series::new("", [s.slice(0, 2), s.slice(2, 2)]);
+        let out = hist_series(&groups, Some(3), None, None, false, false)?;
+        let out = out.list()?;
+        let first = out.get_as_series(0).unwrap();
+        let second = out.get_as_series(1).unwrap();
+        assert_eq!(first.len(), 4);
+        assert_eq!(second.len(), 4);
+        assert_eq!(
+            Vec::from(first.idx()?),
+            &[Some(0), Some(2), Some(0), Some(0)]
+        );
+        assert_eq!(
+            Vec::from(second.idx()?),
+            &[Some(0), Some(1), Some(0), Some(1)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_hist_strategy() -> PolarsResult<()> {
+        let s = Series::new("a", (0..16).collect::<Vec<i32>>());
+        let out = hist_series(&s, None, None, Some(HistBinStrategy::Sturges), false, false)?;
+        // 5 bins and the unbounded lower bin.
+        assert_eq!(out.len(), 6);
+        assert_eq!(out.idx()?.sum(), Some(16));
+        Ok(())
+    }
+
+    #[test]
+    fn test_hist_2d() -> PolarsResult<()> {
+        let x = Series::new("x", [Some(1.0), Some(2.0), None, Some(2.0)]);
+        let y = Series::new("y", [Some(1.0), Some(3.0), Some(3.0), Some(3.0)]);
+        let bins = Series::new("", [1.0, 2.0]);
+        let out = hist_2d_series(&x, &y, None, Some(bins), None)?;
+        let out = out.struct_()?;
+        let count = out.field_by_name("count")?;
+        assert_eq!(
+            Vec::from(count.idx()?),
+            &[1, 0, 0, 0, 0, 2, 0, 0, 0]
+                .into_iter()
+                .map(Some)
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,144 @@
+use polars_core::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum PathSegment {
+    Key(String),
+    Index(i64),
+}
+
+/// A parsed JSON path, like `$.a.b[0]` or `$["a b"][-1]`.
+///
+/// Negative indices count from the end of an array.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonPath {
+    segments: Vec<PathSegment>,
+}
+
+impl JsonPath {
+    pub fn parse(path: &str) -> PolarsResult<Self> {
+        let invalid = || polars_err!(ComputeError: "invalid json path: {:?}", path);
+        let rest = path.trim();
+        let mut rest = rest.strip_prefix('$').unwrap_or(rest);
+        let mut segments = vec![];
+        while !rest.is_empty() {
+            if let Some(r) = rest.strip_prefix('.') {
+                let end = r.find(['.', '[']).unwrap_or(r.len());
+                if end == 0 {
+                    return Err(invalid());
+                }
+                segments.push(PathSegment::Key(r[..end].to_string()));
+                rest = &r[end..];
+            } else if let Some(r) = rest.strip_prefix('[') {
+                let (segment, r) = match r.chars().next() {
+                    Some(q @ ('"' | '\'')) => {
+                        let close = 1 + r[1..].find(q).ok_or_else(invalid)?;
+                        let after = r[close + 1..].strip_prefix(']').ok_or_else(invalid)?;
+                        (PathSegment::Key(r[1..close].to_string()), after)
+                    },
+                    _ => {
+                        let end = r.find(']').ok_or_else(invalid)?;
+                        let index = r[..end].trim().parse().map_err(|_| invalid())?;
+                        (PathSegment::Index(index), &r[end + 1..])
+                    },
+                };
+                segments.push(segment);
+                rest = r;
+            } else if segments.is_empty() && !path.trim_start().starts_with('$') {
+                // Allow a leading key without a dot, as in `a.b`.
+                let end = rest.find(['.', '[']).unwrap_or(rest.len());
+                segments.push(PathSegment::Key(rest[..end].to_string()));
+                rest = &rest[end..];
+            } else {
+                return Err(invalid());
+            }
+        }
+        Ok(Self { segments })
+    }
+
+    /// The value at this path in `value`, `None` if it doesn't exist.
+    pub fn eval<'a>(&self, value: JsonValue<'a>) -> Option<JsonValue<'a>> {
+        self.segments
+            .iter()
+            .try_fold(value, |value, segment| match segment {
+                PathSegment::Key(key) => value.get_key(key),
+                PathSegment::Index(i) => {
+                    let i = if *i < 0 {
+                        (value.len() as i64).checked_add(*i)?
+                    } else {
+                        *i
+                    };
+                    value.get_index(usize::try_from(i).ok()?)
+                },
+            })
+    }
+}
+
+pub trait AsJson {
+    fn as_json(&self) -> &JsonChunked;
+}
+
+impl AsJson for JsonChunked {
+    fn as_json(&self) -> &JsonChunked {
+        self
+    }
+}
+
+pub trait JsonNameSpaceImpl: AsJson {
+    /// Extract the values at `path`, values without that path become null.
+    fn path(&self, path: &str) -> PolarsResult<JsonChunked> {
+        let ca = self.as_json();
+        let path = JsonPath::parse(path)?;
+        let mut builder = BinaryChunkedBuilder::new(ca.name(), ca.len());
+        for opt_v in ca.iter_values() {
+            builder.append_option(opt_v.and_then(|v| path.eval(v)).map(|v| v.as_bytes()));
+        }
+        Ok(builder.finish().into_json())
+    }
+
+    /// The JSON type of the values: `null`, `boolean`, `integer`, `float`, `string`, `array` or
+    /// `object`.
+    fn get_type(&self) -> StringChunked {
+        let ca = self.as_json();
+        let mut builder = StringChunkedBuilder::new(ca.name(), ca.len());
+        for opt_v in ca.iter_values() {
+            builder.append_option(opt_v.map(|v| v.json_type().as_str()));
+        }
+        builder.finish()
+    }
+}
+
+impl JsonNameSpaceImpl for JsonChunked {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_json_path() -> PolarsResult<()> {
+        let s = Series::new(
+            "a",
+            [
+                Some(r#"{"a": {"b": [10, 20, 30]}, "c d": "x"}"#),
+                Some(r#"{"a": 1}"#),
+                None,
+            ],
+        )
+        .cast(&DataType::Json)?;
+        let ca = s.json()?;
+
+        let out = ca.path("$.a.b[0]")?.to_text();
+        assert_eq!(Vec::from(&out), &[Some("10"), None, None]);
+        let out = ca.path("$.a.b[-1]")?.to_text();
+        assert_eq!(Vec::from(&out), &[Some("30"), None, None]);
+        let out = ca.path(r#"$["c d"]"#)?.to_text();
+        assert_eq!(Vec::from(&out), &[Some("\"x\""), None, None]);
+        let out = ca.path("$")?.get_type();
+        assert_eq!(Vec::from(&out), &[Some("object"), Some("object"), None]);
+        let out = ca.path("a")?.get_type();
+        assert_eq!(Vec::from(&out), &[Some("object"), Some("integer"), None]);
+
+        assert!(ca.path("$.a[x]").is_err());
+        assert!(ca.path("$..a").is_err());
+        Ok(())
+    }
+}
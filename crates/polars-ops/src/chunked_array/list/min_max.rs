@@ -2,33 +2,12 @@ use arrow::array::{Array, PrimitiveArray};
 use arrow::bitmap::Bitmap;
 use arrow::types::NativeType;
 use polars_compute::min_max::MinMaxKernel;
+use polars_compute::segmented::{offsets_to_segments, segmented_max, segmented_min};
 use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_polars_type;
 
 use crate::chunked_array::list::namespace::has_inner_nulls;
 
-fn min_between_offsets<T>(values: &[T], offset: &[i64]) -> PrimitiveArray<T>
-where
-    T: NativeType,
-    [T]: for<'a> MinMaxKernel<Scalar<'a> = T>,
-{
-    let mut running_offset = offset[0];
-
-    (offset[1..])
-        .iter()
-        .map(|end| {
-            let current_offset = running_offset;
-            running_offset = *end;
-            if current_offset == *end {
-                return None;
-            }
-
-            let slice = unsafe { values.get_unchecked(current_offset as usize..*end as usize) };
-            slice.min_ignore_nan_kernel()
-        })
-        .collect()
-}
-
 fn dispatch_min<T>(arr: &dyn Array, offsets: &[i64], validity: Option<&Bitmap>) -> ArrayRef
 where
     T: NativeType,
@@ -36,7 +15,7 @@ where
 {
     let values = arr.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
     let values = values.values().as_slice();
-    let mut out = min_between_offsets(values, offsets);
+    let mut out = segmented_min(values, offsets_to_segments(offsets));
 
     if let Some(validity) = validity {
         if out.has_validity() {
@@ -116,28 +95,6 @@ pub(super) fn list_min_function(ca: &ListChunked) -> PolarsResult<Series> {
     }
 }
 
-fn max_between_offsets<T>(values: &[T], offset: &[i64]) -> PrimitiveArray<T>
-where
-    T: NativeType,
-    [T]: for<'a> MinMaxKernel<Scalar<'a> = T>,
-{
-    let mut running_offset = offset[0];
-
-    (offset[1..])
-        .iter()
-        .map(|end| {
-            let current_offset = running_offset;
-            running_offset = *end;
-            if current_offset == *end {
-                return None;
-            }
-
-            let slice = unsafe { values.get_unchecked(current_offset as usize..*end as usize) };
-            slice.max_ignore_nan_kernel()
-        })
-        .collect()
-}
-
 fn dispatch_max<T>(arr: &dyn Array, offsets: &[i64], validity: Option<&Bitmap>) -> ArrayRef
 where
     T: NativeType,
@@ -145,7 +102,7 @@ where
 {
     let values = arr.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
     let values = values.values().as_slice();
-    let mut out = max_between_offsets(values, offsets);
+    let mut out = segmented_max(values, offsets_to_segments(offsets));
 
     if let Some(validity) = validity {
         if out.has_validity() {
@@ -1,5 +1,7 @@
 use std::fmt::Write;
 
+#[cfg(feature = "dtype-array")]
+use arrow::array::Array;
 use arrow::array::ValueSize;
 use arrow::legacy::kernels::list::{index_is_oob, sublist_get};
 use polars_core::chunked_array::builder::get_list_builder;
@@ -12,6 +14,8 @@ use polars_core::series::ops::NullBehavior;
 use polars_core::utils::try_get_supertype;
 
 use super::*;
+#[cfg(feature = "dtype-array")]
+use crate::chunked_array::array::ArrayNameSpace;
 #[cfg(feature = "list_any_all")]
 use crate::chunked_array::list::any_all::*;
 use crate::chunked_array::list::min_max::{list_max_function, list_min_function};
@@ -74,6 +78,37 @@ fn cast_rhs(
     Ok(())
 }
 
+/// Cast lists that all have the same length to arrays, to aggregate them element-wise.
+#[cfg(feature = "dtype-array")]
+fn elementwise_array(ca: &ListChunked) -> PolarsResult<Series> {
+    let mut width = None;
+    for arr in ca.downcast_iter() {
+        for (i, len) in arr.offsets().lengths().enumerate() {
+            if arr.is_null(i) {
+                continue;
+            }
+            match width {
+                None => width = Some(len),
+                Some(width) => polars_ensure!(
+                    width == len,
+                    ShapeMismatch: "element-wise aggregation requires lists of equal length, got {} and {}",
+                    width, len
+                ),
+            }
+        }
+    }
+    let dtype = DataType::Array(Box::new(ca.inner_dtype().clone()), width.unwrap_or(0));
+    ca.cast(&dtype)
+}
+
+#[cfg(feature = "dtype-array")]
+fn list_of_array_inner(s: &Series) -> DataType {
+    match s.dtype() {
+        DataType::Array(inner, _) => DataType::List(inner.clone()),
+        _ => unreachable!(),
+    }
+}
+
 pub trait ListNameSpaceImpl: AsList {
     /// In case the inner dtype [`DataType::String`], the individual items will be joined into a
     /// single string separated by `separator`.
@@ -216,6 +251,20 @@ pub trait ListNameSpaceImpl: AsList {
         }
     }
 
+    #[cfg(feature = "dtype-array")]
+    fn lst_sum_elementwise(&self) -> PolarsResult<Series> {
+        let arr = elementwise_array(self.as_list())?;
+        let out = arr.array()?.array_sum_elementwise()?;
+        out.cast(&list_of_array_inner(&out))
+    }
+
+    #[cfg(feature = "dtype-array")]
+    fn lst_mean_elementwise(&self) -> PolarsResult<Series> {
+        let arr = elementwise_array(self.as_list())?;
+        let out = arr.array()?.array_mean_elementwise()?;
+        out.cast(&list_of_array_inner(&out))
+    }
+
     fn lst_median(&self) -> Series {
         let ca = self.as_list();
         dispersion::median_with_nulls(ca)
@@ -1,38 +1,21 @@
-use std::ops::Div;
-
 use arrow::array::{Array, PrimitiveArray};
 use arrow::bitmap::Bitmap;
 use arrow::types::NativeType;
-use polars_core::export::num::{NumCast, ToPrimitive};
+use polars_compute::float_sum::FloatSum;
+use polars_compute::segmented::{offsets_to_segments, segmented_mean, segmented_sum, SegmentSum};
+use polars_core::export::num::Float;
 
 use super::*;
-use crate::chunked_array::sum::sum_slice;
-
-fn sum_between_offsets<T, S>(values: &[T], offset: &[i64]) -> Vec<S>
-where
-    T: NativeType + ToPrimitive,
-    S: NumCast + std::iter::Sum,
-{
-    offset
-        .windows(2)
-        .map(|w| {
-            values
-                .get(w[0] as usize..w[1] as usize)
-                .map(sum_slice)
-                .unwrap_or(S::from(0).unwrap())
-        })
-        .collect()
-}
 
 fn dispatch_sum<T, S>(arr: &dyn Array, offsets: &[i64], validity: Option<&Bitmap>) -> ArrayRef
 where
-    T: NativeType + ToPrimitive,
-    S: NativeType + NumCast + std::iter::Sum,
+    T: NativeType + SegmentSum<S>,
+    S: NativeType,
 {
     let values = arr.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
     let values = values.values().as_slice();
     Box::new(PrimitiveArray::from_data_default(
-        sum_between_offsets::<_, S>(values, offsets).into(),
+        segmented_sum::<_, S>(values, offsets_to_segments(offsets)).into(),
         validity.cloned(),
     )) as ArrayRef
 }
@@ -114,30 +97,14 @@ pub(super) fn sum_with_nulls(ca: &ListChunked, inner_dtype: &DataType) -> Polars
     Ok(out)
 }
 
-fn mean_between_offsets<T, S>(values: &[T], offset: &[i64]) -> PrimitiveArray<S>
-where
-    T: NativeType + ToPrimitive,
-    S: NativeType + NumCast + std::iter::Sum + Div<Output = S>,
-{
-    offset
-        .windows(2)
-        .map(|w| {
-            values
-                .get(w[0] as usize..w[1] as usize)
-                .filter(|sl| !sl.is_empty())
-                .map(|sl| sum_slice::<_, S>(sl) / NumCast::from(sl.len()).unwrap())
-        })
-        .collect()
-}
-
 fn dispatch_mean<T, S>(arr: &dyn Array, offsets: &[i64], validity: Option<&Bitmap>) -> ArrayRef
 where
-    T: NativeType + ToPrimitive,
-    S: NativeType + NumCast + std::iter::Sum + Div<Output = S>,
+    T: NativeType + FloatSum<S>,
+    S: NativeType + Float,
 {
     let values = arr.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
     let values = values.values().as_slice();
-    let mut out = mean_between_offsets::<_, S>(values, offsets);
+    let mut out = segmented_mean::<_, S>(values, offsets_to_segments(offsets));
     if let Some(validity) = validity {
         if out.has_validity() {
             out.apply_validity(|other_validity| validity & &other_validity)
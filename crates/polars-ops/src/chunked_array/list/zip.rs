@@ -0,0 +1,102 @@
+use arrow::array::Array;
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use arrow::offset::Offsets;
+
+use super::*;
+
+/// Zip list columns into a single list column of structs, with a field per input named after
+/// that input.
+///
+/// The lists of a row must have the same length, a row is null if it is null in any of the
+/// inputs. Inputs of length 1 are broadcast. The values of an input are only gathered if its
+/// offsets differ from the output, so zipping lists that were created together is cheap.
+pub fn zip_lists(lists: &[Series]) -> PolarsResult<ListChunked> {
+    polars_ensure!(!lists.is_empty(), ComputeError: "`zip_lists` needs one or more lists");
+    let len = lists.iter().map(|s| s.len()).max().unwrap();
+    let lists = lists
+        .iter()
+        .map(|s| {
+            let ca = s.list()?;
+            polars_ensure!(
+                ca.len() == len || ca.len() == 1,
+                ShapeMismatch: "`zip_lists` got lists of lengths {} and {}", ca.len(), len
+            );
+            let ca = if ca.len() == len {
+                ca.rechunk()
+            } else {
+                ca.new_from_index(0, len)
+            };
+            Ok(ca)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let arrays = lists
+        .iter()
+        .map(|ca| ca.downcast_iter().next().unwrap())
+        .collect::<Vec<_>>();
+
+    let mut offsets = Vec::with_capacity(len + 1);
+    offsets.push(0i64);
+    let mut validity = MutableBitmap::with_capacity(len);
+    for row in 0..len {
+        let mut width = None;
+        let mut valid = true;
+        for arr in &arrays {
+            if !arr.is_valid(row) {
+                valid = false;
+                continue;
+            }
+            let (start, end) = arr.offsets().start_end(row);
+            let row_width = end - start;
+            match width {
+                Some(width) => polars_ensure!(
+                    width == row_width,
+                    ShapeMismatch: "`zip_lists` got lists of lengths {} and {} in row {}",
+                    width, row_width, row
+                ),
+                None => width = Some(row_width),
+            }
+        }
+        let width = if valid { width.unwrap() } else { 0 };
+        offsets.push(offsets.last().unwrap() + width as i64);
+        validity.push(valid);
+    }
+
+    let fields = lists
+        .iter()
+        .zip(&arrays)
+        .map(|(ca, arr)| {
+            let values = ca.get_inner();
+            let values = if arr.offsets().as_slice() == offsets.as_slice() {
+                values.slice(0, *offsets.last().unwrap() as usize)
+            } else {
+                let idx = (0..len)
+                    .filter(|&row| validity.get(row))
+                    .flat_map(|row| {
+                        let (start, end) = arr.offsets().start_end(row);
+                        start as IdxSize..end as IdxSize
+                    })
+                    .collect::<Vec<_>>();
+                // SAFETY: the offsets are in bounds of the values.
+                unsafe { values.take_unchecked(&IdxCa::from_vec("", idx)) }
+            };
+            values.with_name(ca.name())
+        })
+        .collect::<Vec<_>>();
+    let mut values = StructChunked::new(lists[0].name(), &fields)?;
+    values.rechunk();
+    let dtype = DataType::List(Box::new(values.dtype().clone()));
+
+    let values = values.chunks()[0].clone();
+    let validity: Bitmap = validity.into();
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(values.data_type().clone()),
+        // SAFETY: the offsets are monotonically increasing.
+        unsafe { Offsets::new_unchecked(offsets) }.into(),
+        values,
+        (validity.unset_bits() > 0).then_some(validity),
+    );
+    // SAFETY: the inner dtype is the dtype of the struct values.
+    Ok(unsafe {
+        ListChunked::from_chunks_and_dtype(lists[0].name(), vec![Box::new(arr)], dtype)
+    })
+}
@@ -3,6 +3,8 @@ pub mod array;
 mod binary;
 #[cfg(feature = "timezones")]
 pub mod datetime;
+#[cfg(feature = "dtype-json")]
+pub mod json;
 pub mod list;
 #[cfg(feature = "propagate_nans")]
 pub mod nan_propagating_aggregate;
@@ -10,7 +12,6 @@ pub mod nan_propagating_aggregate;
 pub mod peaks;
 mod scatter;
 pub mod strings;
-mod sum;
 #[cfg(feature = "top_k")]
 mod top_k;
 
@@ -34,6 +35,8 @@ pub use datetime::*;
 pub use gather::*;
 #[cfg(feature = "hist")]
 pub use hist::*;
+#[cfg(feature = "dtype-json")]
+pub use json::*;
 pub use list::*;
 #[allow(unused_imports)]
 use polars_core::prelude::*;
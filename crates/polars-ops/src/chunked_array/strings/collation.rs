@@ -0,0 +1,64 @@
+//! Locale-aware comparisons of strings, see [`Collation`].
+use polars_core::prelude::arity::broadcast_binary_elementwise;
+
+use super::*;
+
+fn collated_arg_extremum(ca: &StringChunked, collation: Collation, max: bool) -> Option<usize> {
+    let keys = ca.collation_keys(collation);
+    let keyed = keys
+        .iter()
+        .enumerate()
+        .filter_map(|(i, key)| key.map(|key| (key, i)));
+    if max {
+        keyed.max_by_key(|(key, _)| *key)
+    } else {
+        keyed.min_by_key(|(key, _)| *key)
+    }
+    .map(|(_, i)| i)
+}
+
+/// The smallest string under the collation, `None` if all values are null.
+pub fn collated_min(ca: &StringChunked, collation: Collation) -> Option<&str> {
+    collated_arg_extremum(ca, collation, false).and_then(|i| ca.get(i))
+}
+
+/// The largest string under the collation, `None` if all values are null.
+pub fn collated_max(ca: &StringChunked, collation: Collation) -> Option<&str> {
+    collated_arg_extremum(ca, collation, true).and_then(|i| ca.get(i))
+}
+
+/// Compare the strings under the collation: `-1` if the left string orders first, `0` if they
+/// are equal under the collation and `1` otherwise.
+pub fn collated_compare(
+    ca: &StringChunked,
+    other: &StringChunked,
+    collation: Collation,
+) -> Int8Chunked {
+    let keys = collation_keys(&[ca, other], collation);
+    broadcast_binary_elementwise(
+        &keys[0],
+        &keys[1],
+        |a: Option<IdxSize>, b: Option<IdxSize>| Some(a?.cmp(&b?) as i8),
+    )
+    .with_name(ca.name())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_collated() -> PolarsResult<()> {
+        let collation = Collation::new("sv")?;
+        let ca = StringChunked::new("a", [Some("zebra"), Some("ångström"), None, Some("apa")]);
+        // In Swedish å sorts after z.
+        assert_eq!(collated_min(&ca, collation), Some("apa"));
+        assert_eq!(collated_max(&ca, collation), Some("ångström"));
+
+        let collation = Collation::new("en-u-ks-level2")?;
+        let other = StringChunked::new("b", ["Apa"]);
+        let out = collated_compare(&ca, &other, collation);
+        assert_eq!(Vec::from(&out), &[Some(1), Some(-1), None, Some(0)]);
+        Ok(())
+    }
+}
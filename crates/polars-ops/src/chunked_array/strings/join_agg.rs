@@ -0,0 +1,123 @@
+use arrow::array::StaticArray;
+use polars_core::prelude::*;
+use polars_core::POOL;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StrJoinAggOptions {
+    pub separator: String,
+    /// Only join the first occurrence of every string.
+    pub distinct: bool,
+    /// Sort the strings before they are joined.
+    pub sort: bool,
+    pub descending: bool,
+}
+
+/// Join `values` as configured by `options`.
+///
+/// Without sorting the strings are joined in the order they are given.
+pub fn str_join_agg_values<S: AsRef<str>>(values: &[S], options: &StrJoinAggOptions) -> String {
+    let mut values = values.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+    if options.sort {
+        values.sort_unstable();
+        if options.descending {
+            values.reverse();
+        }
+        if options.distinct {
+            values.dedup();
+        }
+    } else if options.distinct {
+        let mut seen = PlHashSet::with_capacity(values.len());
+        values.retain(|v| seen.insert(*v));
+    }
+    values.join(&options.separator)
+}
+
+/// Join the non-null strings of `ca` into a single string, see [`StrJoinAggOptions`].
+///
+/// Unlike `str_join`, nulls are always ignored, so an empty or all-null input gives an empty
+/// string.
+///
+/// # Example
+///
+/// ```
+/// # use polars_core::prelude::*;
+/// # use polars_ops::prelude::*;
+/// let ca = StringChunked::new("s", &[Some("b"), None, Some("a"), Some("b")]);
+/// let options = StrJoinAggOptions {
+///     separator: ",".into(),
+///     distinct: true,
+///     sort: true,
+///     descending: false,
+/// };
+/// assert_eq!(str_join_agg(&ca, &options), "a,b");
+/// ```
+pub fn str_join_agg(ca: &StringChunked, options: &StrJoinAggOptions) -> String {
+    let values = ca.iter().flatten().collect::<Vec<_>>();
+    str_join_agg_values(&values, options)
+}
+
+/// Join the strings of every group. See [`str_join_agg`].
+///
+/// # Safety
+/// The `groups` must be in bounds of `ca`.
+pub unsafe fn group_agg_str_join(
+    ca: &StringChunked,
+    groups: &GroupsProxy,
+    options: &StrJoinAggOptions,
+) -> StringChunked {
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let mut out: StringChunked = POOL.install(|| match groups {
+        GroupsProxy::Idx(groups) => groups
+            .into_par_iter()
+            .map(|(_, idx)| {
+                debug_assert!(idx.len() <= arr.len());
+                let values = idx
+                    .iter()
+                    .filter_map(|&i| arr.get_unchecked(i as usize))
+                    .collect::<Vec<_>>();
+                Some(str_join_agg_values(&values, options))
+            })
+            .collect(),
+        GroupsProxy::Slice { groups, .. } => groups
+            .par_iter()
+            .map(|&[first, len]| {
+                debug_assert!(first + len <= arr.len() as IdxSize);
+                let values = (first..first + len)
+                    .filter_map(|i| arr.get_unchecked(i as usize))
+                    .collect::<Vec<_>>();
+                Some(str_join_agg_values(&values, options))
+            })
+            .collect(),
+    });
+    out.rename(ca.name());
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_str_join_agg_values() {
+        let values = ["b", "a", "c", "a", "b"];
+        let mut options = StrJoinAggOptions {
+            separator: "-".into(),
+            ..Default::default()
+        };
+        assert_eq!(str_join_agg_values(&values, &options), "b-a-c-a-b");
+        options.distinct = true;
+        assert_eq!(str_join_agg_values(&values, &options), "b-a-c");
+        options.sort = true;
+        assert_eq!(str_join_agg_values(&values, &options), "a-b-c");
+        options.descending = true;
+        assert_eq!(str_join_agg_values(&values, &options), "c-b-a");
+        options.distinct = false;
+        assert_eq!(str_join_agg_values(&values, &options), "c-b-b-a-a");
+        assert_eq!(str_join_agg_values::<&str>(&[], &options), "");
+    }
+}
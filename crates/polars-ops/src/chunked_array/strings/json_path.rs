@@ -1,12 +1,20 @@
 use std::borrow::Cow;
 
 use arrow::array::ValueSize;
+use arrow::datatypes::ArrowDataType;
+use arrow::legacy::utils::CustomIterTools;
 use jsonpath_lib::PathCompiled;
 use polars_core::prelude::arity::{broadcast_try_binary_elementwise, unary_elementwise};
+use polars_core::utils::_split_offsets;
+use polars_core::POOL;
+use rayon::prelude::*;
 use serde_json::Value;
 
 use super::*;
 
+/// Inputs are only split over the thread pool in parts of at least this many rows.
+const MIN_JSON_DECODE_PART_LEN: usize = 1 << 12;
+
 pub fn extract_json(expr: &PathCompiled, json_str: &str) -> Option<String> {
     serde_json::from_str(json_str).ok().and_then(|value| {
         // TODO: a lot of heap allocations here. Improve json path by adding a take?
@@ -39,6 +47,89 @@ pub fn select_json<'a>(expr: &PathCompiled, json_str: &'a str) -> Option<Cow<'a,
     })
 }
 
+fn invalid_json_rows(ca: &StringChunked) -> Vec<usize> {
+    polars_json::ndjson::deserialize::invalid_rows(ca.iter().map(|x| x.unwrap_or("null")))
+}
+
+/// Sets the given rows, which must be sorted, to null.
+fn null_rows(ca: &StringChunked, rows: &[usize]) -> StringChunked {
+    let mut rows = rows.iter().copied().peekable();
+    ca.iter()
+        .enumerate()
+        .map(|(i, opt_s)| rows.next_if_eq(&i).map_or(opt_s, |_| None))
+        .collect_trusted()
+}
+
+fn deserialize_json_part(ca: &StringChunked, dtype: &ArrowDataType) -> PolarsResult<ArrayRef> {
+    let buf_size = ca.get_values_size() + ca.null_count() * "null".len();
+    let iter = ca.iter().map(|x| x.unwrap_or("null"));
+    polars_json::ndjson::deserialize::deserialize_iter(iter, dtype.clone(), buf_size, ca.len())
+}
+
+/// Decodes a part of a [`StringChunked`] that starts at row `offset`. Returns the decoded
+/// array and the rows that are not valid JSON, which are decoded as null unless `strict`.
+fn decode_json_part(
+    ca: &StringChunked,
+    offset: usize,
+    dtype: &ArrowDataType,
+    strict: bool,
+) -> PolarsResult<(ArrayRef, Vec<IdxSize>)> {
+    let err = match deserialize_json_part(ca, dtype) {
+        Ok(arr) => return Ok((arr, vec![])),
+        Err(err) => err,
+    };
+
+    // Only look for the invalid rows once the whole part failed to parse.
+    let invalid = invalid_json_rows(ca);
+    let Some(&first) = invalid.first() else {
+        polars_bail!(ComputeError: "error deserializing JSON: {}", err)
+    };
+    polars_ensure!(
+        !strict,
+        ComputeError: "error deserializing JSON: {}; row {} is not valid JSON",
+        err, offset + first
+    );
+
+    let arr = deserialize_json_part(&null_rows(ca, &invalid), dtype)
+        .map_err(|e| polars_err!(ComputeError: "error deserializing JSON: {}", e))?;
+    let invalid = invalid
+        .into_iter()
+        .map(|i| (offset + i) as IdxSize)
+        .collect();
+    Ok((arr, invalid))
+}
+
+/// Decodes `ca` in parts that are spread over the thread pool.
+fn decode_json(
+    ca: &StringChunked,
+    dtype: &DataType,
+    strict: bool,
+) -> PolarsResult<(Series, Vec<IdxSize>)> {
+    let dtype = dtype.to_arrow(true);
+    let n_parts = (ca.len() / MIN_JSON_DECODE_PART_LEN).clamp(1, POOL.current_num_threads());
+    let parts = if n_parts == 1 {
+        vec![decode_json_part(ca, 0, &dtype, strict)?]
+    } else {
+        POOL.install(|| {
+            _split_offsets(ca.len(), n_parts)
+                .into_par_iter()
+                .map(|(offset, len)| {
+                    let part = ca.slice(offset as i64, len);
+                    decode_json_part(&part, offset, &dtype, strict)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?
+    };
+
+    let mut chunks = Vec::with_capacity(parts.len());
+    let mut invalid = vec![];
+    for (arr, part_invalid) in parts {
+        chunks.push(arr);
+        invalid.extend(part_invalid);
+    }
+    Ok((Series::try_from(("", chunks))?, invalid))
+}
+
 pub trait Utf8JsonPathImpl: AsString {
     /// Extract json path, first match
     /// Refer to <https://goessner.net/articles/JsonPath/>
@@ -91,7 +182,8 @@ pub trait Utf8JsonPathImpl: AsString {
             .map_err(|e| polars_err!(ComputeError: "error inferring JSON: {}", e))
     }
 
-    /// Extracts a typed-JSON value for each row in the StringChunked
+    /// Extracts a typed-JSON value for each row in the StringChunked. If `dtype` is given,
+    /// the schema is not inferred. Large inputs are decoded in parallel.
     fn json_decode(
         &self,
         dtype: Option<DataType>,
@@ -102,17 +194,28 @@ pub trait Utf8JsonPathImpl: AsString {
             Some(dt) => dt,
             None => ca.json_infer(infer_schema_len)?,
         };
-        let buf_size = ca.get_values_size() + ca.null_count() * "null".len();
-        let iter = ca.iter().map(|x| x.unwrap_or("null"));
-
-        let array = polars_json::ndjson::deserialize::deserialize_iter(
-            iter,
-            dtype.to_arrow(true),
-            buf_size,
-            ca.len(),
-        )
-        .map_err(|e| polars_err!(ComputeError: "error deserializing JSON: {}", e))?;
-        Series::try_from(("", array))
+        decode_json(ca, &dtype, true).map(|(s, _)| s)
+    }
+
+    /// Like [`json_decode`](Self::json_decode), but decodes the rows that are not valid JSON
+    /// as null instead of raising an error. Returns the decoded values and the indices of the
+    /// rows that are not valid JSON.
+    fn json_decode_lossy(
+        &self,
+        dtype: Option<DataType>,
+        infer_schema_len: Option<usize>,
+    ) -> PolarsResult<(Series, IdxCa)> {
+        let ca = self.as_string();
+        let dtype = match dtype {
+            Some(dt) => dt,
+            // Infer the schema from the valid rows only.
+            None => ca.json_infer(infer_schema_len).or_else(|_| {
+                let head = ca.slice(0, infer_schema_len.unwrap_or(ca.len()));
+                null_rows(&head, &invalid_json_rows(&head)).json_infer(None)
+            })?,
+        };
+        let (s, invalid) = decode_json(ca, &dtype, false)?;
+        Ok((s, IdxCa::from_vec(ca.name(), invalid)))
     }
 
     fn json_path_select(&self, json_path: &str) -> PolarsResult<StringChunked> {
@@ -223,6 +326,63 @@ mod tests {
             .equals_missing(&expected_series));
     }
 
+    #[test]
+    fn test_json_decode_invalid_rows() {
+        let s = Series::new(
+            "json",
+            [
+                Some(r#"{"a": 1}"#),
+                Some(r#"{"a": "#),
+                None,
+                Some(r#"{"a": 2}"#),
+                Some("not json"),
+            ],
+        );
+        let ca = s.str().unwrap();
+
+        let expected_series = StructChunked::new(
+            "",
+            &[Series::new("a", &[Some(1), None, None, Some(2), None])],
+        )
+        .unwrap()
+        .into_series();
+        let dtype = expected_series.dtype().clone();
+
+        let err = ca.json_decode(Some(dtype), None).unwrap_err();
+        assert!(err.to_string().contains("row 1 is not valid JSON"));
+
+        // The schema is inferred from the valid rows.
+        let (decoded, invalid) = ca.json_decode_lossy(None, None).unwrap();
+        assert!(decoded.equals_missing(&expected_series));
+        assert_eq!(invalid.into_no_null_iter().collect::<Vec<_>>(), &[1, 4]);
+    }
+
+    #[test]
+    fn test_json_decode_parallel() {
+        let n = 4 * MIN_JSON_DECODE_PART_LEN + 1;
+        let rows = (0..n)
+            .map(|i| format!(r#"{{"a": {i}}}"#))
+            .collect::<Vec<_>>();
+        let ca = StringChunked::from_iter_values("json", rows.iter().map(|s| s.as_str()));
+        let decoded = ca.json_decode(None, None).unwrap();
+        let a = decoded.struct_().unwrap().field_by_name("a").unwrap();
+        assert!(a.equals(&Series::new("a", (0..n as i64).collect::<Vec<_>>())));
+
+        // The index of an invalid row is relative to the whole input.
+        let ca = ca.apply_values(|s| {
+            if s == r#"{"a": 12345}"# {
+                "{".into()
+            } else {
+                s.into()
+            }
+        });
+        let dtype = Some(decoded.dtype().clone());
+        let err = ca.json_decode(dtype.clone(), None).unwrap_err();
+        assert!(err.to_string().contains("row 12345 is not valid JSON"));
+        let (_, invalid) = ca.json_decode_lossy(dtype, None).unwrap();
+        assert_eq!(invalid.into_no_null_iter().collect::<Vec<_>>(), &[12345]);
+    }
+
     #[test]
     fn test_json_path_select() {
         let s = Series::new(
@@ -1,11 +1,14 @@
 #[cfg(feature = "strings")]
 mod case;
+#[cfg(feature = "collation")]
+mod collation;
 #[cfg(feature = "strings")]
 mod concat;
 #[cfg(feature = "strings")]
 mod extract;
 #[cfg(feature = "find_many")]
 mod find_many;
+mod join_agg;
 #[cfg(feature = "extract_jsonpath")]
 mod json_path;
 #[cfg(feature = "strings")]
@@ -14,6 +17,8 @@ mod namespace;
 mod pad;
 #[cfg(feature = "string_reverse")]
 mod reverse;
+#[cfg(feature = "string_similarity")]
+mod similarity;
 #[cfg(feature = "strings")]
 mod split;
 #[cfg(feature = "strings")]
@@ -24,15 +29,20 @@ mod substring;
 #[cfg(all(not(feature = "nightly"), feature = "strings"))]
 mod unicode_internals;
 
+#[cfg(feature = "collation")]
+pub use collation::*;
 #[cfg(feature = "strings")]
 pub use concat::*;
 #[cfg(feature = "find_many")]
 pub use find_many::*;
+pub use join_agg::*;
 #[cfg(feature = "extract_jsonpath")]
 pub use json_path::*;
 #[cfg(feature = "strings")]
 pub use namespace::*;
 use polars_core::prelude::*;
+#[cfg(feature = "string_similarity")]
+pub use similarity::*;
 #[cfg(feature = "strings")]
 pub use split::*;
 #[cfg(feature = "strings")]
@@ -0,0 +1,290 @@
+//! Edit distances and similarities between strings.
+//!
+//! All functions compare unicode scalar values, not bytes, and broadcast if one of the sides
+//! has a single value. Normalized similarities are in `[0, 1]`, where `1` means the strings are
+//! equal.
+use polars_core::prelude::arity::broadcast_binary_elementwise;
+use polars_utils::aliases::PlHashMap;
+
+use super::*;
+
+/// Reusable buffers for the characters of both strings, to not allocate for every row.
+#[derive(Default)]
+struct Chars {
+    a: Vec<char>,
+    b: Vec<char>,
+}
+
+impl Chars {
+    fn load(&mut self, a: &str, b: &str) -> (&[char], &[char]) {
+        self.a.clear();
+        self.a.extend(a.chars());
+        self.b.clear();
+        self.b.extend(b.chars());
+        (&self.a, &self.b)
+    }
+}
+
+/// Turn a distance into a similarity, relative to the length of the longest string.
+fn normalize(distance: usize, a: &[char], b: &[char]) -> f64 {
+    let len = a.len().max(b.len());
+    if len == 0 {
+        1.0
+    } else {
+        1.0 - distance as f64 / len as f64
+    }
+}
+
+fn levenshtein_chars(a: &[char], b: &[char], row: &mut Vec<usize>) -> usize {
+    if a.is_empty() || b.is_empty() {
+        return a.len().max(b.len());
+    }
+    row.clear();
+    row.extend(0..=b.len());
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + (ca != cb) as usize;
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Unrestricted Damerau-Levenshtein distance: transpositions of characters that are not
+/// adjacent after other edits are also counted as a single edit.
+fn damerau_levenshtein_chars(a: &[char], b: &[char]) -> usize {
+    if a.is_empty() || b.is_empty() {
+        return a.len().max(b.len());
+    }
+    let width = b.len() + 2;
+    let max_distance = a.len() + b.len();
+    let mut matrix = vec![0; (a.len() + 2) * width];
+    matrix[0] = max_distance;
+    for i in 0..=a.len() {
+        matrix[(i + 1) * width] = max_distance;
+        matrix[(i + 1) * width + 1] = i;
+    }
+    for j in 0..=b.len() {
+        matrix[j + 1] = max_distance;
+        matrix[width + j + 1] = j;
+    }
+
+    // The last row in which every character of `a` occurred.
+    let mut last_row = PlHashMap::<char, usize>::new();
+    for i in 1..=a.len() {
+        // The last column in this row where the characters matched.
+        let mut last_match_col = 0;
+        for j in 1..=b.len() {
+            let last_match_row = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let prev_match_col = last_match_col;
+            let cost = (a[i - 1] != b[j - 1]) as usize;
+            if cost == 0 {
+                last_match_col = j;
+            }
+            let substitution = matrix[i * width + j] + cost;
+            let insertion = matrix[(i + 1) * width + j] + 1;
+            let deletion = matrix[i * width + j + 1] + 1;
+            let transposition = matrix[last_match_row * width + prev_match_col]
+                + (i - last_match_row - 1)
+                + 1
+                + (j - prev_match_col - 1);
+            matrix[(i + 1) * width + j + 1] =
+                substitution.min(insertion).min(deletion).min(transposition);
+        }
+        last_row.insert(a[i - 1], i);
+    }
+    matrix[(a.len() + 1) * width + b.len() + 1]
+}
+
+/// Hamming distance, `None` if the strings have a different length.
+fn hamming_chars(a: &[char], b: &[char]) -> Option<usize> {
+    (a.len() == b.len()).then(|| a.iter().zip(b).filter(|(a, b)| a != b).count())
+}
+
+fn jaro_chars(a: &[char], b: &[char], b_matched: &mut Vec<bool>) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    b_matched.clear();
+    b_matched.resize(b.len(), false);
+
+    let mut matches = 0;
+    let mut transpositions = 0;
+    // Index into the matched characters of `a`, which are in the same order as in `b` unless
+    // they are transposed.
+    let mut b_pos = 0;
+    let mut a_matched = Vec::with_capacity(a.len());
+    for (i, ca) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        let found = (start..end).find(|&j| !b_matched[j] && b[j] == *ca);
+        if let Some(j) = found {
+            b_matched[j] = true;
+            a_matched.push(*ca);
+            matches += 1;
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+    for (j, cb) in b.iter().enumerate() {
+        if b_matched[j] {
+            if a_matched[b_pos] != *cb {
+                transpositions += 1;
+            }
+            b_pos += 1;
+        }
+    }
+
+    let matches = matches as f64;
+    (matches / a.len() as f64
+        + matches / b.len() as f64
+        + (matches - transpositions as f64 / 2.0) / matches)
+        / 3.0
+}
+
+fn jaro_winkler_chars(a: &[char], b: &[char], b_matched: &mut Vec<bool>) -> f64 {
+    const PREFIX_SCALE: f64 = 0.1;
+    const MAX_PREFIX: usize = 4;
+
+    let jaro = jaro_chars(a, b, b_matched);
+    let prefix = a
+        .iter()
+        .zip(b)
+        .take(MAX_PREFIX)
+        .take_while(|(a, b)| a == b)
+        .count();
+    jaro + prefix as f64 * PREFIX_SCALE * (1.0 - jaro)
+}
+
+/// Levenshtein distance: the number of insertions, deletions and substitutions of characters
+/// to turn one string into the other.
+pub fn levenshtein(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    let mut chars = Chars::default();
+    let mut row = vec![];
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        let (a, b) = chars.load(a?, b?);
+        Some(levenshtein_chars(a, b, &mut row) as u32)
+    })
+}
+
+/// Normalized similarity based on the [`levenshtein`] distance.
+pub fn levenshtein_similarity(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    let mut chars = Chars::default();
+    let mut row = vec![];
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        let (a, b) = chars.load(a?, b?);
+        Some(normalize(levenshtein_chars(a, b, &mut row), a, b))
+    })
+}
+
+/// Damerau-Levenshtein distance: like the [`levenshtein`] distance, but a transposition of two
+/// characters also counts as a single edit.
+pub fn damerau_levenshtein(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    let mut chars = Chars::default();
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        let (a, b) = chars.load(a?, b?);
+        Some(damerau_levenshtein_chars(a, b) as u32)
+    })
+}
+
+/// Normalized similarity based on the [`damerau_levenshtein`] distance.
+pub fn damerau_levenshtein_similarity(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    let mut chars = Chars::default();
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        let (a, b) = chars.load(a?, b?);
+        Some(normalize(damerau_levenshtein_chars(a, b), a, b))
+    })
+}
+
+/// Hamming distance: the number of positions at which the characters differ. Strings of a
+/// different length have a null distance.
+pub fn hamming(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    let mut chars = Chars::default();
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        let (a, b) = chars.load(a?, b?);
+        hamming_chars(a, b).map(|d| d as u32)
+    })
+}
+
+/// Normalized similarity based on the [`hamming`] distance.
+pub fn hamming_similarity(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    let mut chars = Chars::default();
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        let (a, b) = chars.load(a?, b?);
+        hamming_chars(a, b).map(|d| normalize(d, a, b))
+    })
+}
+
+/// Jaro-Winkler similarity: the Jaro similarity, boosted for strings with a common prefix of up
+/// to 4 characters.
+pub fn jaro_winkler(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    let mut chars = Chars::default();
+    let mut b_matched = vec![];
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        let (a, b) = chars.load(a?, b?);
+        Some(jaro_winkler_chars(a, b, &mut b_matched))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn test_edit_distances() {
+        let cases = [
+            ("", "", 0, 0),
+            ("abc", "", 3, 3),
+            ("kitten", "sitting", 3, 3),
+            ("ca", "abc", 3, 2),
+            ("abcdef", "abdcef", 2, 1),
+            ("héllo", "hello", 1, 1),
+        ];
+        let mut row = vec![];
+        for (a, b, lev, dl) in cases {
+            let (a, b) = (chars(a), chars(b));
+            assert_eq!(levenshtein_chars(&a, &b, &mut row), lev);
+            assert_eq!(levenshtein_chars(&b, &a, &mut row), lev);
+            assert_eq!(damerau_levenshtein_chars(&a, &b), dl);
+            assert_eq!(damerau_levenshtein_chars(&b, &a), dl);
+        }
+    }
+
+    #[test]
+    fn test_jaro_winkler() {
+        let mut buf = vec![];
+        let jw =
+            |a: &str, b: &str, buf: &mut Vec<bool>| jaro_winkler_chars(&chars(a), &chars(b), buf);
+        assert!((jw("MARTHA", "MARHTA", &mut buf) - 0.961111).abs() < 1e-5);
+        assert!((jw("DIXON", "DICKSONX", &mut buf) - 0.813333).abs() < 1e-5);
+        assert_eq!(jw("abc", "abc", &mut buf), 1.0);
+        assert_eq!(jw("abc", "xyz", &mut buf), 0.0);
+        assert_eq!(jw("", "", &mut buf), 1.0);
+    }
+
+    #[test]
+    fn test_broadcast() {
+        let ca = StringChunked::new("a", [Some("kitten"), Some("abc"), None]);
+        let other = StringChunked::new("b", ["sitting"]);
+        let out = levenshtein(&ca, &other);
+        assert_eq!(Vec::from(&out), &[Some(3), Some(6), None]);
+
+        let other = StringChunked::new("b", [Some("kitter"), Some("abcd"), Some("x")]);
+        let out = hamming(&ca, &other);
+        assert_eq!(Vec::from(&out), &[Some(1), None, None]);
+        let out = hamming_similarity(&ca, &other);
+        assert_eq!(Vec::from(&out), &[Some(1.0 - 1.0 / 6.0), None, None]);
+    }
+}
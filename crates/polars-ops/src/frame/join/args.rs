@@ -24,6 +24,10 @@ pub struct JoinArgs {
     pub how: JoinType,
     pub validation: JoinValidation,
     pub suffix: Option<String>,
+    /// How to name the columns of the right side that are also on the left side. Takes
+    /// precedence over `suffix`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub naming: Option<JoinNaming>,
     pub slice: Option<(i64, usize)>,
     pub join_nulls: bool,
     pub coalesce: JoinCoalesce,
@@ -57,6 +61,8 @@ impl JoinCoalesce {
             },
             #[cfg(feature = "asof_join")]
             AsOf(_) => matches!(self, JoinSpecific | CoalesceColumns),
+            #[cfg(feature = "iejoin")]
+            IEJoin(_) => false,
             Cross => false,
             #[cfg(feature = "semi_anti_join")]
             Semi | Anti => false,
@@ -70,6 +76,7 @@ impl Default for JoinArgs {
             how: JoinType::Inner,
             validation: Default::default(),
             suffix: None,
+            naming: None,
             slice: None,
             join_nulls: false,
             coalesce: Default::default(),
@@ -83,6 +90,7 @@ impl JoinArgs {
             how,
             validation: Default::default(),
             suffix: None,
+            naming: None,
             slice: None,
             join_nulls: false,
             coalesce: Default::default(),
@@ -102,6 +110,91 @@ impl JoinArgs {
     pub fn suffix(&self) -> &str {
         self.suffix.as_deref().unwrap_or("_right")
     }
+
+    pub fn with_naming(mut self, naming: Option<JoinNaming>) -> Self {
+        self.naming = naming;
+        self
+    }
+
+    /// The naming of the columns of the right side that are also on the left side.
+    pub fn naming(&self) -> JoinNaming {
+        match &self.naming {
+            Some(naming) => naming.clone(),
+            None => JoinNaming::Suffix(self.suffix().to_string()),
+        }
+    }
+}
+
+/// How a join names the columns of the right side that have the name of a column of the left
+/// side. Only these columns are renamed.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinNaming {
+    /// Append a suffix to the name.
+    Suffix(String),
+    /// Prepend a prefix to the name.
+    Prefix(String),
+    /// Put the name between the parts of a template, see [`JoinNaming::template`].
+    Template { before: String, after: String },
+    /// Raise an error.
+    Error,
+}
+
+impl Default for JoinNaming {
+    fn default() -> Self {
+        Self::Suffix("_right".to_string())
+    }
+}
+
+impl JoinNaming {
+    /// Format the name with a template, in which `{name}` is replaced by the name of the column
+    /// and `{side}` by `right`. The template must contain `{name}` once.
+    pub fn template(template: &str) -> PolarsResult<Self> {
+        let template = template.replace("{side}", "right");
+        let mut parts = template.split("{name}");
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(before), Some(after), None) => Ok(Self::Template {
+                before: before.to_string(),
+                after: after.to_string(),
+            }),
+            _ => polars_bail!(
+                InvalidOperation: "join naming template {:?} must contain '{{name}}' once",
+                template
+            ),
+        }
+    }
+
+    /// The parts of the name before and after the name of the column, `None` if the columns
+    /// are not renamed.
+    fn affixes(&self) -> Option<(&str, &str)> {
+        match self {
+            Self::Suffix(suffix) => Some(("", suffix)),
+            Self::Prefix(prefix) => Some((prefix, "")),
+            Self::Template { before, after } => Some((before, after)),
+            Self::Error => None,
+        }
+    }
+
+    /// The name of the column `name` of the right side, which is also on the left side.
+    pub fn rename(&self, name: &str) -> PolarsResult<String> {
+        let Some((before, after)) = self.affixes() else {
+            polars_bail!(
+                Duplicate: "column with name '{}' is on both sides of the join\n\n\
+                You may want to try:\n\
+                - renaming the column prior to joining\n\
+                - using a join naming that renames the columns of the right side",
+                name
+            )
+        };
+        Ok(format!("{before}{name}{after}"))
+    }
+
+    /// The inverse of [`JoinNaming::rename`]: the original name of the renamed column `name`,
+    /// if it is one.
+    pub fn original<'a>(&self, name: &'a str) -> Option<&'a str> {
+        let (before, after) = self.affixes()?;
+        name.strip_prefix(before)?.strip_suffix(after)
+    }
 }
 
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -112,6 +205,8 @@ pub enum JoinType {
     Full,
     #[cfg(feature = "asof_join")]
     AsOf(AsOfOptions),
+    #[cfg(feature = "iejoin")]
+    IEJoin(IEJoinOptions),
     Cross,
     #[cfg(feature = "semi_anti_join")]
     Semi,
@@ -134,6 +229,8 @@ impl Display for JoinType {
             Full { .. } => "FULL",
             #[cfg(feature = "asof_join")]
             AsOf(_) => "ASOF",
+            #[cfg(feature = "iejoin")]
+            IEJoin(_) => "IEJOIN",
             Cross => "CROSS",
             #[cfg(feature = "semi_anti_join")]
             Semi => "SEMI",
@@ -604,7 +604,7 @@ pub trait AsofJoinBy: IntoDf {
         right_by: Vec<SmartString>,
         strategy: AsofStrategy,
         tolerance: Option<AnyValue<'static>>,
-        suffix: Option<&str>,
+        naming: &JoinNaming,
         slice: Option<(i64, usize)>,
         coalesce: bool,
     ) -> PolarsResult<DataFrame> {
@@ -681,7 +681,7 @@ pub trait AsofJoinBy: IntoDf {
         let right_df =
             unsafe { proj_other_df.take_unchecked(&IdxCa::with_chunk("", right_join_tuples)) };
 
-        _finish_join(left, right_df, suffix)
+        _finish_join(left, right_df, naming)
     }
 
     /// This is similar to a left-join except that we match on nearest key
@@ -709,7 +709,16 @@ pub trait AsofJoinBy: IntoDf {
         let left_key = self_df.column(left_on)?;
         let right_key = other.column(right_on)?;
         self_df._join_asof_by(
-            other, left_key, right_key, left_by, right_by, strategy, tolerance, None, None, true,
+            other,
+            left_key,
+            right_key,
+            left_by,
+            right_by,
+            strategy,
+            tolerance,
+            &JoinNaming::default(),
+            None,
+            true,
         )
     }
 }
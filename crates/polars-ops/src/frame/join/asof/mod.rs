@@ -11,7 +11,7 @@ use smartstring::alias::String as SmartString;
 
 #[cfg(feature = "dtype-categorical")]
 use super::_check_categorical_src;
-use super::{_finish_join, build_tables, prepare_bytes};
+use super::{_finish_join, build_tables, prepare_bytes, JoinNaming};
 use crate::frame::IntoDf;
 use crate::series::SeriesMethods;
 
@@ -212,7 +212,7 @@ pub trait AsofJoin: IntoDf {
         right_key: &Series,
         strategy: AsofStrategy,
         tolerance: Option<AnyValue<'static>>,
-        suffix: Option<String>,
+        naming: &JoinNaming,
         slice: Option<(i64, usize)>,
         coalesce: bool,
     ) -> PolarsResult<DataFrame> {
@@ -284,7 +284,7 @@ pub trait AsofJoin: IntoDf {
         // SAFETY: join tuples are in bounds.
         let right_df = unsafe { other.take_unchecked(&take_idx) };
 
-        _finish_join(left, right_df, suffix.as_deref())
+        _finish_join(left, right_df, naming)
     }
 }
 
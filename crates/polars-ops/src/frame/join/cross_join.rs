@@ -122,12 +122,12 @@ pub trait CrossJoin: IntoDf {
     fn cross_join(
         &self,
         other: &DataFrame,
-        suffix: Option<&str>,
+        naming: &JoinNaming,
         slice: Option<(i64, usize)>,
     ) -> PolarsResult<DataFrame> {
         let (l_df, r_df) = self.cross_join_dfs(other, slice, true)?;
 
-        _finish_join(l_df, r_df, suffix)
+        _finish_join(l_df, r_df, naming)
     }
 }
 
@@ -5,16 +5,12 @@ pub fn _join_suffix_name(name: &str, suffix: &str) -> String {
     format!("{name}{suffix}")
 }
 
-fn get_suffix(suffix: Option<&str>) -> &str {
-    suffix.unwrap_or("_right")
-}
-
 /// Utility method to finish a join.
 #[doc(hidden)]
 pub fn _finish_join(
     mut df_left: DataFrame,
     mut df_right: DataFrame,
-    suffix: Option<&str>,
+    naming: &JoinNaming,
 ) -> PolarsResult<DataFrame> {
     let mut left_names = PlHashSet::with_capacity(df_left.width());
 
@@ -29,10 +25,9 @@ pub fn _finish_join(
             rename_strs.push(series.name().to_owned())
         }
     });
-    let suffix = get_suffix(suffix);
 
     for name in rename_strs {
-        let new_name = _join_suffix_name(&name, suffix);
+        let new_name = naming.rename(&name)?;
         df_right.rename(&name, new_name.as_str()).map_err(|_| {
             polars_err!(Duplicate: "column with name '{}' already exists\n\n\
             You may want to try:\n\
@@ -50,12 +45,12 @@ pub fn _coalesce_full_join(
     mut df: DataFrame,
     keys_left: &[&str],
     keys_right: &[&str],
-    suffix: Option<&str>,
+    naming: &JoinNaming,
     df_left: &DataFrame,
 ) -> DataFrame {
     // No need to allocate the schema because we already
     // know for certain that the column name for left left is `name`
-    // and for right is `name + suffix`
+    // and for right is the renamed `name`
     let schema_left = if keys_left == keys_right {
         Schema::default()
     } else {
@@ -71,8 +66,8 @@ pub fn _coalesce_full_join(
         let pos_l = schema.get_full(l).unwrap().0;
 
         let r = if l == r || schema_left.contains(r) {
-            let suffix = get_suffix(suffix);
-            Cow::Owned(_join_suffix_name(r, suffix))
+            // The join that produced `df` succeeded, so the naming renames.
+            Cow::Owned(naming.rename(r).unwrap())
         } else {
             Cow::Borrowed(r)
         };
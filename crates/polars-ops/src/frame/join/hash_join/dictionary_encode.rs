@@ -0,0 +1,123 @@
+use arrow::array::Array;
+use polars_core::utils::split;
+use polars_utils::aliases::{PlHashMap, PlHashSet};
+
+use super::*;
+
+/// Joins with fewer rows than this on both sides together don't gain from encoding.
+const MIN_ROWS: usize = 1 << 14;
+/// Number of keys sampled from every side to estimate the cardinality of the keys.
+const SAMPLE_SIZE: usize = 1024;
+/// The keys are encoded if at most one in this many sampled keys is distinct.
+const MAX_CARDINALITY_FRACTION: usize = 8;
+
+/// The dictionary of a single partition, with the keys in the order they were first seen.
+struct LocalDictionary<'a> {
+    keys: Vec<&'a [u8]>,
+    // Null keys get code 0; the validity of the input is reused for the codes.
+    codes: Vec<u32>,
+}
+
+fn encode_partition(ca: &BinaryChunked) -> LocalDictionary<'_> {
+    let mut map = PlHashMap::new();
+    let mut keys = vec![];
+    let codes = ca
+        .iter()
+        .map(|opt_v| match opt_v {
+            Some(v) => *map.entry(v).or_insert_with(|| {
+                keys.push(v);
+                (keys.len() - 1) as u32
+            }),
+            None => 0,
+        })
+        .collect();
+    LocalDictionary { keys, codes }
+}
+
+fn has_low_cardinality(lhs: &BinaryChunked, rhs: &BinaryChunked) -> bool {
+    let mut distinct = PlHashSet::new();
+    let mut n_sampled = 0;
+    for ca in [lhs, rhs] {
+        let step = std::cmp::max(ca.len() / SAMPLE_SIZE, 1);
+        for i in (0..ca.len()).step_by(step).take(SAMPLE_SIZE) {
+            distinct.insert(ca.get(i));
+            n_sampled += 1;
+        }
+    }
+    distinct.len() * MAX_CARDINALITY_FRACTION <= n_sampled
+}
+
+fn to_codes(ca: &BinaryChunked, codes: Vec<Vec<u32>>) -> UInt32Chunked {
+    let validity = ca
+        .downcast_iter()
+        .next()
+        .and_then(|arr| arr.validity().cloned());
+    UInt32Chunked::from_vec_validity(ca.name(), codes.concat(), validity)
+}
+
+/// Replace low cardinality binary join keys by `u32` codes of a dictionary shared by both sides.
+///
+/// Hashing and comparing the codes is a lot cheaper than the bytes, and the hash tables of the
+/// join hold integers instead of byte slices and their hashes. The dictionaries are built per
+/// partition in parallel and then merged. Returns `None` if the keys are not worth encoding, or
+/// if `POLARS_NO_JOIN_DICT_ENCODING` is set.
+///
+/// The codes always have a single chunk, so the join indices don't refer to chunks.
+pub(super) fn dictionary_encode_keys(
+    lhs: &BinaryChunked,
+    rhs: &BinaryChunked,
+) -> Option<(UInt32Chunked, UInt32Chunked)> {
+    if lhs.len() + rhs.len() < MIN_ROWS
+        || std::env::var("POLARS_NO_JOIN_DICT_ENCODING").is_ok()
+        || !has_low_cardinality(lhs, rhs)
+    {
+        return None;
+    }
+
+    let lhs = lhs.rechunk();
+    let rhs = rhs.rechunk();
+    let n_threads = POOL.current_num_threads();
+    let lhs_parts = split(&lhs, n_threads);
+    let rhs_parts = split(&rhs, n_threads);
+
+    let locals = POOL.install(|| {
+        lhs_parts
+            .par_iter()
+            .chain(rhs_parts.par_iter())
+            .map(encode_partition)
+            .collect::<Vec<_>>()
+    });
+
+    let mut global = PlHashMap::new();
+    let remaps = locals
+        .iter()
+        .map(|local| {
+            local
+                .keys
+                .iter()
+                .map(|key| {
+                    let code = global.len() as u32;
+                    *global.entry(*key).or_insert(code)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut codes = POOL.install(|| {
+        locals
+            .into_par_iter()
+            .zip(remaps.par_iter())
+            .map(|(local, remap)| {
+                let mut codes = local.codes;
+                // A partition without keys only has nulls.
+                if !remap.is_empty() {
+                    codes.iter_mut().for_each(|c| *c = remap[*c as usize]);
+                }
+                codes
+            })
+            .collect::<Vec<_>>()
+    });
+    let rhs_codes = codes.split_off(lhs_parts.len());
+
+    Some((to_codes(&lhs, codes), to_codes(&rhs, rhs_codes)))
+}
@@ -1,3 +1,4 @@
+mod dictionary_encode;
 pub(super) mod single_keys;
 mod single_keys_dispatch;
 mod single_keys_inner;
@@ -9,6 +10,7 @@ pub(super) mod sort_merge;
 use arrow::array::ArrayRef;
 use polars_core::utils::_set_partition_size;
 use polars_core::POOL;
+use polars_utils::cancel::CancellationToken;
 use polars_utils::index::ChunkId;
 pub(super) use single_keys::*;
 #[cfg(feature = "asof_join")]
@@ -51,6 +53,16 @@ macro_rules! det_hash_prone_order {
 use arrow::legacy::conversion::primitive_to_vec;
 pub(super) use det_hash_prone_order;
 
+/// The number of keys a probe processes between two checks of the cancellation token.
+const CANCEL_CHECK_INTERVAL: usize = 1 << 16;
+
+/// Whether the probe of the `i`-th key should stop. The token must be taken from the thread that
+/// runs the join, the probes themselves run on the thread pool.
+#[inline]
+fn probe_cancelled(token: Option<&CancellationToken>, i: usize) -> bool {
+    i % CANCEL_CHECK_INTERVAL == 0 && token.is_some_and(CancellationToken::is_cancelled)
+}
+
 pub trait JoinDispatch: IntoDf {
     /// # Safety
     /// Join tuples must be in bounds
@@ -111,7 +123,7 @@ pub trait JoinDispatch: IntoDf {
         };
         let (df_left, df_right) = POOL.join(materialize_left, materialize_right);
 
-        _finish_join(df_left, df_right, args.suffix.as_deref())
+        _finish_join(df_left, df_right, &args.naming())
     }
 
     #[cfg(feature = "chunked_ids")]
@@ -122,7 +134,6 @@ pub trait JoinDispatch: IntoDf {
         args: JoinArgs,
     ) -> PolarsResult<DataFrame> {
         let ca_self = self.to_df();
-        let suffix = &args.suffix;
         let (left_idx, right_idx) = ids;
         let materialize_left = || match left_idx {
             ChunkJoinIds::Left(left_idx) => unsafe {
@@ -159,7 +170,7 @@ pub trait JoinDispatch: IntoDf {
         };
         let (df_left, df_right) = POOL.join(materialize_left, materialize_right);
 
-        _finish_join(df_left, df_right, suffix.as_deref())
+        _finish_join(df_left, df_right, &args.naming())
     }
 
     fn _left_join_from_series(
@@ -272,13 +283,14 @@ pub trait JoinDispatch: IntoDf {
         );
 
         let coalesce = args.coalesce.coalesce(&JoinType::Full);
-        let out = _finish_join(df_left, df_right, args.suffix.as_deref());
+        let naming = args.naming();
+        let out = _finish_join(df_left, df_right, &naming);
         if coalesce {
             Ok(_coalesce_full_join(
                 out?,
                 &[s_left.name()],
                 &[s_right.name()],
-                args.suffix.as_deref(),
+                &naming,
                 df_self,
             ))
         } else {
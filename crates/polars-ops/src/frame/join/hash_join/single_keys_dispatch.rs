@@ -6,6 +6,7 @@ use polars_utils::hashing::DirtyHash;
 use polars_utils::nulls::IsNull;
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
 
+use super::dictionary_encode::dictionary_encode_keys;
 use super::*;
 use crate::series::SeriesSealed;
 
@@ -31,6 +32,10 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = rhs.cast(&T::Binary).unwrap();
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
+                if let Some((lhs, rhs)) = dictionary_encode_keys(lhs, rhs) {
+                    // Turbofish: see #17137.
+                    return num_group_join_left::<UInt32Type>(&lhs, &rhs, validate, join_nulls);
+                }
                 let (lhs, rhs, _, _) = prepare_binary::<BinaryType>(lhs, rhs, false);
                 let lhs = lhs.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
@@ -100,6 +105,12 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = rhs.cast(&T::Binary).unwrap();
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
+                if let Some((lhs, rhs)) = dictionary_encode_keys(lhs, rhs) {
+                    // Turbofish: see #17137.
+                    return Ok(num_group_join_anti_semi::<UInt32Type>(
+                        &lhs, &rhs, anti, join_nulls,
+                    ));
+                }
                 let (lhs, rhs, _, _) = prepare_binary::<BinaryType>(lhs, rhs, false);
                 // Take slices so that vecs are not copied
                 let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
@@ -179,6 +190,10 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = rhs.cast(&T::Binary).unwrap();
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
+                if let Some((lhs, rhs)) = dictionary_encode_keys(lhs, rhs) {
+                    // Turbofish: see #17137.
+                    return group_join_inner::<UInt32Type>(&lhs, &rhs, validate, join_nulls);
+                }
                 let (lhs, rhs, swapped, _) = prepare_binary::<BinaryType>(lhs, rhs, true);
                 // Take slices so that vecs are not copied
                 let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
@@ -255,6 +270,10 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = rhs.cast(&T::Binary).unwrap();
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
+                if let Some((lhs, rhs)) = dictionary_encode_keys(lhs, rhs) {
+                    // Turbofish: see #17137.
+                    return hash_join_outer::<UInt32Type>(&lhs, &rhs, validate, join_nulls);
+                }
                 let (lhs, rhs, swapped, _) = prepare_binary::<BinaryType>(lhs, rhs, true);
                 // Take slices so that vecs are not copied
                 let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
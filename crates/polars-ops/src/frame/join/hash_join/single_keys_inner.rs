@@ -1,7 +1,6 @@
 use polars_core::utils::flatten;
 use polars_utils::hashing::{hash_to_partition, DirtyHash};
 use polars_utils::idx_vec::IdxVec;
-use polars_utils::iter::EnumerateIdxTrait;
 use polars_utils::nulls::IsNull;
 use polars_utils::sync::SyncPtr;
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
@@ -14,6 +13,7 @@ pub(super) fn probe_inner<T, F, I>(
     results: &mut Vec<(IdxSize, IdxSize)>,
     local_offset: IdxSize,
     n_tables: usize,
+    token: Option<&CancellationToken>,
     swap_fn: F,
 ) where
     T: TotalHash + TotalEq + DirtyHash + ToTotalOrd,
@@ -21,9 +21,12 @@ pub(super) fn probe_inner<T, F, I>(
     I: IntoIterator<Item = T>,
     F: Fn(IdxSize, IdxSize) -> (IdxSize, IdxSize),
 {
-    probe.into_iter().enumerate_idx().for_each(|(idx_a, k)| {
+    for (i, k) in probe.into_iter().enumerate() {
+        if probe_cancelled(token, i) {
+            return;
+        }
         let k = k.to_total_ord();
-        let idx_a = idx_a + local_offset;
+        let idx_a = i as IdxSize + local_offset;
         // probe table that contains the hashed value
         let current_probe_table =
             unsafe { hash_tbls.get_unchecked(hash_to_partition(k.dirty_hash(), n_tables)) };
@@ -34,7 +37,7 @@ pub(super) fn probe_inner<T, F, I>(
             let tuples = indexes_b.iter().map(|&idx_b| swap_fn(idx_a, idx_b));
             results.extend(tuples);
         }
-    });
+    }
 }
 
 pub(super) fn hash_join_tuples_inner<T, I>(
@@ -67,10 +70,11 @@ where
 
     let n_tables = hash_tbls.len();
     let offsets = probe_to_offsets(&probe);
+    let token = CancellationToken::current();
     // next we probe the other relation
     // code duplication is because we want to only do the swap check once
-    let out = POOL.install(|| {
-        let tuples = probe
+    let tuples = POOL.install(|| {
+        probe
             .into_par_iter()
             .zip(offsets)
             .map(|(probe, offset)| {
@@ -88,6 +92,7 @@ where
                         &mut results,
                         local_offset,
                         n_tables,
+                        token.as_ref(),
                         |idx_a, idx_b| (idx_b, idx_a),
                     )
                 } else {
@@ -97,15 +102,20 @@ where
                         &mut results,
                         local_offset,
                         n_tables,
+                        token.as_ref(),
                         |idx_a, idx_b| (idx_a, idx_b),
                     )
                 }
 
                 results
             })
-            .collect::<Vec<_>>();
+            .collect::<Vec<_>>()
+    });
+    // The probes stop early if the query is cancelled.
+    CancellationToken::check_current()?;
 
-        // parallel materialization
+    // parallel materialization
+    let out = POOL.install(|| {
         let (cap, offsets) = flatten::cap_and_offsets(&tuples);
         let mut left = Vec::with_capacity(cap);
         let mut right = Vec::with_capacity(cap);
@@ -135,6 +135,8 @@ where
 
     // we determine the offset so that we later know which index to store in the join tuples
     let offsets = probe_to_offsets(&probe);
+    let token = CancellationToken::current();
+    let token = token.as_ref();
 
     // next we probe the other relation
     let result: Vec<LeftJoinIds> = POOL.install(move || {
@@ -151,7 +153,10 @@ where
                 let mut result_idx_left = Vec::with_capacity(probe.size_hint().1.unwrap());
                 let mut result_idx_right = Vec::with_capacity(probe.size_hint().1.unwrap());
 
-                probe.enumerate().for_each(|(idx_a, k)| {
+                for (idx_a, k) in probe.enumerate() {
+                    if probe_cancelled(token, idx_a) {
+                        break;
+                    }
                     let k = k.to_total_ord();
                     let idx_a = (idx_a + offset) as IdxSize;
                     // probe table that contains the hashed value
@@ -174,7 +179,7 @@ where
                             result_idx_right.push(NullableIdxSize::null());
                         },
                     }
-                });
+                }
                 finish_left_join_mappings(
                     result_idx_left,
                     result_idx_right,
@@ -184,6 +189,8 @@ where
             })
             .collect()
     });
+    // The probes stop early if the query is cancelled.
+    CancellationToken::check_current()?;
 
     Ok(flatten_left_join_ids(result))
 }
@@ -122,10 +122,14 @@ fn probe_outer<T, F, G, H>(
     // idx_b -> ...
     H: Fn(IdxSize) -> (Option<IdxSize>, Option<IdxSize>),
 {
+    let token = CancellationToken::current();
     // needed for the partition shift instead of modulo to make sense
     let mut idx_a = 0;
     for probe_hashes in probe_hashes {
         for (h, key) in probe_hashes {
+            if probe_cancelled(token.as_ref(), idx_a as usize) {
+                return;
+            }
             let key = key.to_total_ord();
             let h = *h;
             // probe table that contains the hashed value
@@ -260,5 +264,7 @@ where
             join_nulls,
         )
     }
+    // The probe stops early if the query is cancelled.
+    CancellationToken::check_current()?;
     Ok((results.0.into(), results.1.into()))
 }
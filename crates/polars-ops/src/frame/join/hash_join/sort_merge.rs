@@ -165,6 +165,91 @@ fn to_left_join_ids(left_idx: Vec<IdxSize>, right_idx: Vec<NullableIdxSize>) ->
     }
 }
 
+/// The offset and length of the non-null values of a sorted key. The nulls of a sorted key are
+/// either all at the start or all at the end.
+#[cfg(feature = "performant")]
+fn sorted_non_null_range(s: &Series) -> (usize, usize) {
+    let null_count = s.null_count();
+    let offset = if null_count > 0 && matches!(s.get(0), Ok(AnyValue::Null)) {
+        null_count
+    } else {
+        0
+    };
+    (offset, s.len() - null_count)
+}
+
+/// Sorted merge inner join of keys that may have nulls, which don't match.
+#[cfg(feature = "performant")]
+fn par_sorted_merge_inner_skip_nulls(s_left: &Series, s_right: &Series) -> InnerJoinIds {
+    let (left_offset, left_len) = sorted_non_null_range(s_left);
+    let (right_offset, right_len) = sorted_non_null_range(s_right);
+    let (mut left, mut right) = par_sorted_merge_inner_no_nulls(
+        &s_left.slice(left_offset as i64, left_len),
+        &s_right.slice(right_offset as i64, right_len),
+    );
+
+    POOL.install(|| {
+        if left_offset > 0 {
+            left.par_iter_mut()
+                .for_each(|idx| *idx += left_offset as IdxSize);
+        }
+        if right_offset > 0 {
+            right
+                .par_iter_mut()
+                .for_each(|idx| *idx += right_offset as IdxSize);
+        }
+    });
+    (left, right)
+}
+
+/// Sorted merge left join of keys that may have nulls, which don't match.
+#[cfg(feature = "performant")]
+fn par_sorted_merge_left_skip_nulls(
+    s_left: &Series,
+    s_right: &Series,
+) -> (Vec<IdxSize>, Vec<NullableIdxSize>) {
+    let (left_offset, left_len) = sorted_non_null_range(s_left);
+    let (right_offset, right_len) = sorted_non_null_range(s_right);
+    let (left, mut right) = par_sorted_merge_left(
+        &s_left.slice(left_offset as i64, left_len),
+        &s_right.slice(right_offset as i64, right_len),
+    );
+
+    if right_offset > 0 {
+        POOL.install(|| {
+            right.par_iter_mut().for_each(|opt_idx| {
+                if !opt_idx.is_null_idx() {
+                    *opt_idx = (opt_idx.idx() + right_offset as IdxSize).into();
+                }
+            })
+        });
+    }
+
+    // Every left row is in the output, in order, including the ones with a null key.
+    let left_nulls = s_left.len() - left_len;
+    if left_nulls == 0 {
+        return (left, right);
+    }
+    let null_rows = if left_offset > 0 {
+        0..left_nulls
+    } else {
+        left_len..s_left.len()
+    };
+    let mut out_left = Vec::with_capacity(left.len() + left_nulls);
+    let mut out_right = Vec::with_capacity(right.len() + left_nulls);
+    if left_offset > 0 {
+        out_left.extend(null_rows.clone().map(|idx| idx as IdxSize));
+        out_right.extend(null_rows.clone().map(|_| NullableIdxSize::null()));
+    }
+    out_left.extend(left.into_iter().map(|idx| idx + left_offset as IdxSize));
+    out_right.extend(right);
+    if left_offset == 0 {
+        out_left.extend(null_rows.clone().map(|idx| idx as IdxSize));
+        out_right.extend(null_rows.map(|_| NullableIdxSize::null()));
+    }
+    (out_left, out_right)
+}
+
 #[cfg(feature = "performant")]
 fn create_reverse_map_from_arg_sort(mut arg_sort: IdxCa) -> Vec<IdxSize> {
     let arr = unsafe { arg_sort.chunks_mut() }.pop().unwrap();
@@ -213,6 +298,15 @@ pub(crate) fn _sort_or_hash_inner(
             }
             Ok((par_sorted_merge_inner_no_nulls(s_left, s_right), true))
         },
+        // Nulls don't match, so we can merge the non-null parts of the keys.
+        (IsSorted::Ascending, IsSorted::Ascending, false) if is_numeric && !join_nulls => {
+            if verbose {
+                eprintln!(
+                    "inner join: keys are sorted: use sorted merge join on the non-null keys"
+                );
+            }
+            Ok((par_sorted_merge_inner_skip_nulls(s_left, s_right), true))
+        },
         (IsSorted::Ascending, _, true)
             if is_numeric && size_factor_rhs < size_factor_acceptable =>
         {
@@ -225,6 +319,8 @@ pub(crate) fn _sort_or_hash_inner(
                 nulls_last: false,
                 multithreaded: true,
                 maintain_order: false,
+                stable: true,
+                collation: None,
             });
             let s_right = unsafe { s_right.take_unchecked(&sort_idx) };
             let ids = par_sorted_merge_inner_no_nulls(s_left, &s_right);
@@ -252,6 +348,8 @@ pub(crate) fn _sort_or_hash_inner(
                 nulls_last: false,
                 multithreaded: true,
                 maintain_order: false,
+                stable: true,
+                collation: None,
             });
             let s_left = unsafe { s_left.take_unchecked(&sort_idx) };
             let ids = par_sorted_merge_inner_no_nulls(&s_left, s_right);
@@ -311,6 +409,13 @@ pub(crate) fn sort_or_hash_left(
             let (left_idx, right_idx) = par_sorted_merge_left(s_left, s_right);
             Ok(to_left_join_ids(left_idx, right_idx))
         },
+        (IsSorted::Ascending, IsSorted::Ascending, false) if is_numeric && !join_nulls => {
+            if verbose {
+                eprintln!("left join: keys are sorted: use sorted merge join on the non-null keys");
+            }
+            let (left_idx, right_idx) = par_sorted_merge_left_skip_nulls(s_left, s_right);
+            Ok(to_left_join_ids(left_idx, right_idx))
+        },
         (IsSorted::Ascending, _, true)
             if is_numeric && size_factor_rhs < size_factor_acceptable =>
         {
@@ -323,6 +428,8 @@ pub(crate) fn sort_or_hash_left(
                 nulls_last: false,
                 multithreaded: true,
                 maintain_order: false,
+                stable: true,
+                collation: None,
             });
             let s_right = unsafe { s_right.take_unchecked(&sort_idx) };
 
@@ -0,0 +1,446 @@
+//! Inequality joins.
+//!
+//! Joins on one or two inequality predicates between the keys of both sides, e.g.
+//! `left.start <= right.ts AND left.end > right.ts`, without evaluating all pairs of rows.
+//!
+//! The right keys are sorted once. For a single predicate the matching right rows of every
+//! left row form a contiguous range in the order of the first key, found by binary search.
+//! With a second predicate the left rows are visited in the order of their second key, while
+//! the right rows that satisfy the second predicate for the current left row are marked in a
+//! bitset indexed by the order of the first key. Scanning the range of the first predicate in
+//! that bitset gives the matches, as in the IEJoin algorithm of Khayyat et al.
+use std::fmt::{Display, Formatter};
+
+use polars_core::chunked_array::ops::search_sorted::SearchSortedSide;
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_core::utils::{slice_slice, try_get_supertype, NoNull};
+use polars_core::POOL;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{_finish_join, JoinNaming};
+use crate::series::search_sorted;
+
+/// The comparison of a left key with a right key, `left <op> right`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InequalityOperator {
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+impl InequalityOperator {
+    /// The operator with the sides swapped, such that `a <op> b` equals `b <op.swap()> a`.
+    pub fn swap(self) -> Self {
+        use InequalityOperator::*;
+        match self {
+            Lt => Gt,
+            LtEq => GtEq,
+            Gt => Lt,
+            GtEq => LtEq,
+        }
+    }
+
+    /// Whether the right values that satisfy the predicate lie above the left value.
+    fn is_lower_bound(self) -> bool {
+        matches!(self, InequalityOperator::Lt | InequalityOperator::LtEq)
+    }
+
+    /// The side to search the sorted right values at, such that the position found splits
+    /// the right values that satisfy the predicate from those that don't.
+    fn search_side(self) -> SearchSortedSide {
+        use InequalityOperator::*;
+        match self {
+            Lt | GtEq => SearchSortedSide::Right,
+            LtEq | Gt => SearchSortedSide::Left,
+        }
+    }
+}
+
+impl Display for InequalityOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use InequalityOperator::*;
+        let s = match self {
+            Lt => "<",
+            LtEq => "<=",
+            Gt => ">",
+            GtEq => ">=",
+        };
+        write!(f, "{s}")
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IEJoinOptions {
+    /// The operator between the first left and right key.
+    pub operator1: InequalityOperator,
+    /// The operator between the second left and right key, if joined on two keys.
+    pub operator2: Option<InequalityOperator>,
+}
+
+impl IEJoinOptions {
+    fn n_keys(&self) -> usize {
+        1 + self.operator2.is_some() as usize
+    }
+}
+
+fn check_key_dtype(s: &Series) -> PolarsResult<()> {
+    let dtype = s.dtype();
+    polars_ensure!(
+        dtype.to_physical().is_numeric() || dtype == &DataType::String,
+        InvalidOperation: "inequality join is only supported on numeric, temporal or string keys, got `{}`", dtype
+    );
+    Ok(())
+}
+
+/// The rows of which none of the keys are null, or `None` if there are no nulls.
+fn valid_rows(keys: &[Series]) -> Option<IdxCa> {
+    if keys.iter().all(|s| s.null_count() == 0) {
+        return None;
+    }
+    let valid = keys
+        .iter()
+        .map(|s| s.is_not_null())
+        .reduce(|acc, mask| &acc & &mask)?;
+    let idx: NoNull<IdxCa> = valid
+        .into_iter()
+        .zip(0 as IdxSize..)
+        .filter_map(|(valid, i)| (valid == Some(true)).then_some(i))
+        .collect();
+    Some(idx.into_inner())
+}
+
+fn arg_sort(s: &Series) -> IdxCa {
+    s.arg_sort(
+        SortOptions::default()
+            .with_multithreaded(false)
+            .with_maintain_order(true),
+    )
+}
+
+/// The right side of an inequality join, sorted on its keys to be probed with left rows.
+pub struct IEJoinTable {
+    options: IEJoinOptions,
+    /// The first key of the right rows that have no null keys, sorted ascending.
+    sorted1: Series,
+    /// The right row at each position of `sorted1`.
+    rows1: Vec<IdxSize>,
+    /// The second key, sorted ascending.
+    sorted2: Option<Series>,
+    /// Positions in `sorted1`, in the order in which the rows start to satisfy the second
+    /// predicate when going through the left rows by their second key.
+    walk: Vec<IdxSize>,
+}
+
+impl IEJoinTable {
+    pub fn new(right_keys: &[Series], options: IEJoinOptions) -> PolarsResult<Self> {
+        polars_ensure!(
+            right_keys.len() == options.n_keys(),
+            ComputeError: "inequality join with {} operators expects as many keys, got {}",
+            options.n_keys(), right_keys.len()
+        );
+        for s in right_keys {
+            check_key_dtype(s)?;
+        }
+
+        let valid = valid_rows(right_keys);
+        let keys = match &valid {
+            // SAFETY: the valid rows are in bounds.
+            Some(idx) => right_keys
+                .iter()
+                .map(|s| unsafe { s.take_unchecked(idx) })
+                .collect(),
+            None => right_keys.to_vec(),
+        };
+        let to_row = |i: IdxSize| match &valid {
+            Some(idx) => idx.get(i as usize).unwrap(),
+            None => i,
+        };
+
+        let order1 = arg_sort(&keys[0]);
+        let sorted1 = keys[0].take(&order1)?;
+        let rows1 = order1.into_no_null_iter().map(to_row).collect();
+
+        let (sorted2, walk) = match options.operator2 {
+            Some(operator2) => {
+                let mut pos1 = vec![0 as IdxSize; order1.len()];
+                for (pos, i) in order1.into_no_null_iter().enumerate() {
+                    pos1[i as usize] = pos as IdxSize;
+                }
+                let order2 = arg_sort(&keys[1]);
+                let sorted2 = keys[1].take(&order2)?;
+                let walk = order2.into_no_null_iter().map(|i| pos1[i as usize]);
+                // The rows above a left value are those that satisfy a lower bound, they are
+                // added from the largest value down.
+                let walk = if operator2.is_lower_bound() {
+                    walk.rev().collect()
+                } else {
+                    walk.collect()
+                };
+                (Some(sorted2), walk)
+            },
+            None => (None, vec![]),
+        };
+
+        Ok(Self {
+            options,
+            sorted1,
+            rows1,
+            sorted2,
+            walk,
+        })
+    }
+
+    /// The pairs of matching left and right rows, ordered by the left and then the right row.
+    pub fn probe(
+        &self,
+        left_keys: &[Series],
+        slice: Option<(i64, usize)>,
+    ) -> PolarsResult<(IdxCa, IdxCa)> {
+        polars_ensure!(
+            left_keys.len() == self.options.n_keys(),
+            ComputeError: "inequality join with {} operators expects as many keys, got {}",
+            self.options.n_keys(), left_keys.len()
+        );
+        for (l, r) in left_keys
+            .iter()
+            .zip(std::iter::once(&self.sorted1).chain(&self.sorted2))
+        {
+            polars_ensure!(
+                l.dtype() == r.dtype(),
+                SchemaMismatch: "datatypes of inequality join keys don't match - `{}`: {} on left does not match {} on right",
+                l.name(), l.dtype(), r.dtype()
+            );
+        }
+        let m = self.rows1.len();
+        let n = left_keys[0].len();
+        if m == 0 || n == 0 {
+            return Ok((IdxCa::from_vec("", vec![]), IdxCa::from_vec("", vec![])));
+        }
+
+        let valid = valid_rows(left_keys);
+        let left_rows = || -> Box<dyn Iterator<Item = IdxSize>> {
+            match &valid {
+                Some(idx) => Box::new(idx.into_no_null_iter()),
+                None => Box::new(0..n as IdxSize),
+            }
+        };
+
+        let operator1 = self.options.operator1;
+        let bound1 = search_sorted(&self.sorted1, &left_keys[0], operator1.search_side(), false)?;
+        let bound1 = bound1.cont_slice()?;
+        let range1 = |l: IdxSize| {
+            let p = bound1[l as usize] as usize;
+            if operator1.is_lower_bound() {
+                p..m
+            } else {
+                0..p
+            }
+        };
+
+        let mut pairs = Vec::new();
+        match (self.options.operator2, &self.sorted2) {
+            (Some(operator2), Some(sorted2)) => {
+                let bound2 = search_sorted(sorted2, &left_keys[1], operator2.search_side(), false)?;
+                let bound2 = bound2.cont_slice()?;
+                // The number of right rows that satisfy the second predicate, these are the
+                // first rows of the walk.
+                let mut by_count = left_rows()
+                    .map(|l| {
+                        let p = bound2[l as usize];
+                        let count = if operator2.is_lower_bound() {
+                            m as IdxSize - p
+                        } else {
+                            p
+                        };
+                        (count, l)
+                    })
+                    .collect::<Vec<_>>();
+                by_count.sort_unstable();
+
+                let mut bits = vec![0u64; m.div_ceil(64)];
+                let mut inserted = 0;
+                for (count, l) in by_count {
+                    for &pos in &self.walk[inserted..count as usize] {
+                        bits[pos as usize / 64] |= 1 << (pos % 64);
+                    }
+                    inserted = count as usize;
+
+                    for_each_set_bit(&bits, range1(l), |pos| pairs.push((l, self.rows1[pos])));
+                }
+            },
+            _ => {
+                for l in left_rows() {
+                    pairs.extend(range1(l).map(|pos| (l, self.rows1[pos])));
+                }
+            },
+        }
+        pairs.sort_unstable();
+
+        let pairs = match slice {
+            Some((offset, len)) => slice_slice(&pairs, offset, len),
+            None => &pairs,
+        };
+        let (left_idx, right_idx): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+        let mut left_idx = IdxCa::from_vec("", left_idx);
+        left_idx.set_sorted_flag(IsSorted::Ascending);
+        Ok((left_idx, IdxCa::from_vec("", right_idx)))
+    }
+
+    /// Joins the `left` rows with the `right` rows this table was built from.
+    pub fn join(
+        &self,
+        left: &DataFrame,
+        left_keys: &[Series],
+        right: &DataFrame,
+        naming: &JoinNaming,
+        slice: Option<(i64, usize)>,
+    ) -> PolarsResult<DataFrame> {
+        let (left_idx, right_idx) = self.probe(left_keys, slice)?;
+        // SAFETY: the indices of the probe are in bounds.
+        let (df_left, df_right) = POOL.join(
+            || unsafe { left.take_unchecked(&left_idx) },
+            || unsafe { right.take_unchecked(&right_idx) },
+        );
+        _finish_join(df_left, df_right, naming)
+    }
+}
+
+/// Calls `f` with the position of every set bit in `range`.
+fn for_each_set_bit(bits: &[u64], range: std::ops::Range<usize>, mut f: impl FnMut(usize)) {
+    if range.is_empty() {
+        return;
+    }
+    let (start, end) = (range.start, range.end);
+    for word_idx in start / 64..end.div_ceil(64) {
+        let mut word = bits[word_idx];
+        let word_start = word_idx * 64;
+        if word_start < start {
+            word &= u64::MAX << (start - word_start);
+        }
+        if word_start + 64 > end {
+            word &= u64::MAX >> (word_start + 64 - end);
+        }
+        while word != 0 {
+            f(word_start + word.trailing_zeros() as usize);
+            word &= word - 1;
+        }
+    }
+}
+
+pub(super) fn iejoin(
+    left: &DataFrame,
+    right: &DataFrame,
+    mut selected_left: Vec<Series>,
+    mut selected_right: Vec<Series>,
+    options: IEJoinOptions,
+    naming: &JoinNaming,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    // Unlike equi-joins, keys of e.g. different numeric types can be compared.
+    for (l, r) in selected_left.iter_mut().zip(selected_right.iter_mut()) {
+        if l.dtype() != r.dtype() {
+            let dtype = try_get_supertype(l.dtype(), r.dtype())?;
+            *l = l.cast(&dtype)?;
+            *r = r.cast(&dtype)?;
+        }
+    }
+    IEJoinTable::new(&selected_right, options)?.join(left, &selected_left, right, naming, slice)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn pairs(
+        left: &[Series],
+        right: &[Series],
+        operator1: InequalityOperator,
+        operator2: Option<InequalityOperator>,
+    ) -> Vec<(IdxSize, IdxSize)> {
+        let options = IEJoinOptions {
+            operator1,
+            operator2,
+        };
+        let (l, r) = IEJoinTable::new(right, options)
+            .unwrap()
+            .probe(left, None)
+            .unwrap();
+        l.into_no_null_iter().zip(r.into_no_null_iter()).collect()
+    }
+
+    /// All pairs for which the predicates hold, by checking every pair.
+    fn nested_loop(
+        left: &[Vec<i32>],
+        right: &[Vec<i32>],
+        ops: &[InequalityOperator],
+    ) -> Vec<(IdxSize, IdxSize)> {
+        let holds = |op: InequalityOperator, a: i32, b: i32| match op {
+            InequalityOperator::Lt => a < b,
+            InequalityOperator::LtEq => a <= b,
+            InequalityOperator::Gt => a > b,
+            InequalityOperator::GtEq => a >= b,
+        };
+        let mut out = vec![];
+        for l in 0..left[0].len() {
+            for r in 0..right[0].len() {
+                if ops
+                    .iter()
+                    .enumerate()
+                    .all(|(k, op)| holds(*op, left[k][l], right[k][r]))
+                {
+                    out.push((l as IdxSize, r as IdxSize));
+                }
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_iejoin_matches_nested_loop() {
+        use InequalityOperator::*;
+        let left = vec![vec![1, 5, 3, 8, 3, 0], vec![2, 2, 9, 4, 7, 5]];
+        let right = vec![vec![3, 1, 6, 3, 9], vec![4, 7, 2, 5, 4]];
+        let to_series =
+            |v: &[Vec<i32>]| -> Vec<Series> { v.iter().map(|v| Series::new("", v)).collect() };
+
+        for op1 in [Lt, LtEq, Gt, GtEq] {
+            assert_eq!(
+                pairs(&to_series(&left[..1]), &to_series(&right[..1]), op1, None),
+                nested_loop(&left, &right, &[op1])
+            );
+            for op2 in [Lt, LtEq, Gt, GtEq] {
+                assert_eq!(
+                    pairs(&to_series(&left), &to_series(&right), op1, Some(op2)),
+                    nested_loop(&left, &right, &[op1, op2])
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_iejoin_nulls() {
+        use InequalityOperator::*;
+        let left = [
+            Series::new("", &[Some(1), None, Some(2)]),
+            Series::new("", &[Some(9), Some(9), Some(9)]),
+        ];
+        let right = [
+            Series::new("", &[Some(3), Some(3), None]),
+            Series::new("", &[None, Some(5), Some(5)]),
+        ];
+        assert_eq!(pairs(&left, &right, Lt, Some(Gt)), [(0, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn test_for_each_set_bit() {
+        let bits = [0b1011u64, 1 << 63, 1];
+        let mut out = vec![];
+        for_each_set_bit(&bits, 1..129, |i| out.push(i));
+        assert_eq!(out, [1, 3, 127, 128]);
+    }
+}
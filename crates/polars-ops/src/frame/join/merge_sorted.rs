@@ -1,5 +1,6 @@
 use arrow::legacy::utils::{CustomIterTools, FromTrustedLenIterator};
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_core::with_match_physical_numeric_polars_type;
 
 pub fn _merge_sorted_dfs(
@@ -28,6 +29,9 @@ pub fn _merge_sorted_dfs(
     }
 
     let merge_indicator = series_to_merge_indicator(left_s, right_s);
+    // The inputs are sorted on the key, so the output is as well. This lets e.g. joins on the key
+    // use a sorted merge join.
+    let key_sorted = left_s.null_count() == 0 && right_s.null_count() == 0;
     let new_columns = left
         .get_columns()
         .iter()
@@ -39,6 +43,9 @@ pub fn _merge_sorted_dfs(
             let out = merge_series(&lhs_phys, &rhs_phys, &merge_indicator);
             let mut out = out.cast(lhs.dtype()).unwrap();
             out.rename(lhs.name());
+            if key_sorted && lhs.name() == left_s.name() {
+                out.set_sorted_flag(IsSorted::Ascending);
+            }
             out
         })
         .collect();
@@ -6,6 +6,8 @@ mod checks;
 mod cross_join;
 mod general;
 mod hash_join;
+#[cfg(feature = "iejoin")]
+mod iejoin;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
 
@@ -28,6 +30,8 @@ use general::create_chunked_index_mapping;
 pub use general::{_coalesce_full_join, _finish_join, _join_suffix_name};
 pub use hash_join::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
+#[cfg(feature = "iejoin")]
+pub use iejoin::{IEJoinOptions, IEJoinTable, InequalityOperator};
 #[cfg(feature = "merge_sorted")]
 pub use merge_sorted::_merge_sorted_dfs;
 use polars_core::hashing::_HASHMAP_INIT_SIZE;
@@ -114,7 +118,20 @@ pub trait DataFrameJoinOps: IntoDf {
 
         #[cfg(feature = "cross_join")]
         if let JoinType::Cross = args.how {
-            return left_df.cross_join(other, args.suffix.as_deref(), args.slice);
+            return left_df.cross_join(other, &args.naming(), args.slice);
+        }
+
+        #[cfg(feature = "iejoin")]
+        if let JoinType::IEJoin(options) = args.how {
+            return iejoin::iejoin(
+                left_df,
+                other,
+                selected_left,
+                selected_right,
+                options,
+                &args.naming(),
+                args.slice,
+            );
         }
 
         // Clear literals if a frame is empty. Otherwise we could get an oob
@@ -206,6 +223,8 @@ pub trait DataFrameJoinOps: IntoDf {
             let s_left = &selected_left[0];
             let s_right = &selected_right[0];
             let drop_names: Option<&[&str]> = if should_coalesce { None } else { Some(&[]) };
+            #[cfg(feature = "asof_join")]
+            let naming = args.naming();
             return match args.how {
                 JoinType::Inner => left_df
                     ._inner_join_from_series(other, s_left, s_right, args, _verbose, drop_names),
@@ -238,7 +257,7 @@ pub trait DataFrameJoinOps: IntoDf {
                         right_by,
                         options.strategy,
                         options.tolerance,
-                        args.suffix.as_deref(),
+                        &naming,
                         args.slice,
                         should_coalesce,
                     ),
@@ -248,7 +267,7 @@ pub trait DataFrameJoinOps: IntoDf {
                         s_right,
                         options.strategy,
                         options.tolerance,
-                        args.suffix,
+                        &naming,
                         args.slice,
                         should_coalesce,
                     ),
@@ -259,6 +278,10 @@ pub trait DataFrameJoinOps: IntoDf {
                 JoinType::Cross => {
                     unreachable!()
                 },
+                #[cfg(feature = "iejoin")]
+                JoinType::IEJoin(_) => {
+                    unreachable!()
+                },
             };
         }
 
@@ -280,10 +303,14 @@ pub trait DataFrameJoinOps: IntoDf {
             JoinType::Cross => {
                 unreachable!()
             },
+            #[cfg(feature = "iejoin")]
+            JoinType::IEJoin(_) => {
+                unreachable!()
+            },
             JoinType::Full => {
                 let names_left = selected_left.iter().map(|s| s.name()).collect::<Vec<_>>();
                 args.coalesce = JoinCoalesce::KeepColumns;
-                let suffix = args.suffix.clone();
+                let naming = args.naming();
                 let out = left_df._full_join_from_series(other, &lhs_keys, &rhs_keys, args);
 
                 if should_coalesce {
@@ -291,7 +318,7 @@ pub trait DataFrameJoinOps: IntoDf {
                         out?,
                         &names_left,
                         drop_names.as_ref().unwrap(),
-                        suffix.as_deref(),
+                        &naming,
                         left_df,
                     ))
                 } else {
@@ -448,7 +475,7 @@ trait DataFrameJoinOpsPrivate: IntoDf {
                 ._take_unchecked_slice(join_tuples_right, true)
             },
         );
-        _finish_join(df_left, df_right, args.suffix.as_deref())
+        _finish_join(df_left, df_right, &args.naming())
     }
 }
 
@@ -111,4 +111,58 @@ pub trait DataFrameOps: IntoDf {
 
         accumulate_dataframes_horizontal(cols)
     }
+
+    /// Get a mask of the duplicated rows, comparing only the `subset` columns if given.
+    ///
+    /// `keep` decides which row of a group of equal rows is not marked: the first, the last or
+    /// none of them.
+    #[cfg(feature = "is_unique")]
+    fn is_duplicated_by(
+        &self,
+        subset: Option<&[String]>,
+        keep: UniqueKeepStrategy,
+    ) -> PolarsResult<BooleanChunked> {
+        let by = duplicate_subset(self.to_df(), subset)?;
+        Ok(duplicate_groups_multiple(&by)?.is_duplicated("", keep))
+    }
+
+    /// Report the groups of duplicated rows, comparing only the `subset` columns if given.
+    ///
+    /// Returns the `subset` columns of every group of equal rows that occurs more than once, with
+    /// the number of rows in the group and the indices of its first and last row, in order of
+    /// the first row.
+    #[cfg(feature = "is_unique")]
+    fn duplicates(&self, subset: Option<&[String]>) -> PolarsResult<DataFrame> {
+        let by = duplicate_subset(self.to_df(), subset)?;
+        let groups = duplicate_groups_multiple(&by)?;
+
+        let mut counts = Vec::new();
+        let mut first = Vec::new();
+        let mut last = Vec::new();
+        for (g, &count) in groups.counts.iter().enumerate() {
+            if count > 1 {
+                counts.push(count);
+                first.push(groups.first[g]);
+                last.push(groups.last[g]);
+            }
+        }
+        let first = IdxCa::from_vec("first_index", first);
+        let mut out = DataFrame::new(by)?.take(&first)?;
+        out.hstack_mut(&[
+            IdxCa::from_vec("count", counts).into_series(),
+            first.into_series(),
+            IdxCa::from_vec("last_index", last).into_series(),
+        ])?;
+        Ok(out)
+    }
+}
+
+#[cfg(feature = "is_unique")]
+fn duplicate_subset(df: &DataFrame, subset: Option<&[String]>) -> PolarsResult<Vec<Series>> {
+    let by = match subset {
+        Some(subset) => df.columns(subset)?.into_iter().cloned().collect::<Vec<_>>(),
+        None => df.get_columns().to_vec(),
+    };
+    polars_ensure!(!by.is_empty(), ComputeError: "cannot find duplicates without columns");
+    Ok(by)
 }
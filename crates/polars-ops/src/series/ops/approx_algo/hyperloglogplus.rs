@@ -81,6 +81,24 @@ where
         }
     }
 
+    /// Creates a HyperLogLog from the registers exposed by its [`AsRef`] implementation.
+    ///
+    /// Returns `None` if the number of registers or one of their values is invalid.
+    pub(crate) fn from_registers(registers: &[u8]) -> Option<Self> {
+        let registers: [u8; NUM_REGISTERS] = registers.try_into().ok()?;
+        registers
+            .iter()
+            .all(|&r| r as usize <= HLL_Q + 1)
+            .then(|| Self::new_with_registers(registers))
+    }
+
+    /// Reinterprets the registers as a HyperLogLog of another type.
+    ///
+    /// The registers don't depend on the type, only on the hashes of the added values.
+    pub(crate) fn cast<U: Hash + ?Sized>(&self) -> HyperLogLog<U> {
+        HyperLogLog::new_with_registers(self.registers)
+    }
+
     /// choice of hash function: ahash is already an dependency
     /// and it fits the requirements of being a 64bit hash with
     /// reasonable performance.
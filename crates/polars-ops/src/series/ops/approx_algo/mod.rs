@@ -1,5 +1,9 @@
 #[cfg(feature = "approx_unique")]
 mod hyperloglogplus;
+#[cfg(feature = "approx_quantile")]
+mod tdigest;
 
 #[cfg(feature = "approx_unique")]
 pub use hyperloglogplus::*;
+#[cfg(feature = "approx_quantile")]
+pub use tdigest::*;
@@ -0,0 +1,327 @@
+//! # TDigest
+//!
+//! `tdigest` module contains an implementation of the merging t-digest of Ted Dunning
+//! ("Computing Extremely Accurate Quantiles Using t-Digests") so that
+//! [`crate::series::approx_quantile`] can be computed in a single pass and in bounded memory.
+//!
+//! Digests of disjoint parts of the data can be merged, which makes them suitable as partial
+//! aggregation state.
+//!
+//! # Examples
+//!
+//! ```
+//!     # use polars_ops::prelude::*;
+//!     let mut digest = TDigest::new(100);
+//!     digest.extend((1..=1000).map(|v| v as f64));
+//!
+//!     let median = digest.quantile(0.5).unwrap();
+//!     assert!((median - 500.5).abs() < 1.0);
+//! ```
+
+use std::f64::consts::PI;
+
+/// Number of values that are buffered per unit of compression before they are merged into
+/// the centroids.
+const BUFFER_FACTOR: usize = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+impl Centroid {
+    fn absorb(&mut self, other: &Centroid) {
+        self.weight += other.weight;
+        self.mean += (other.mean - self.mean) * other.weight / self.weight;
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TDigest {
+    /// Merged centroids, sorted by their mean.
+    centroids: Vec<Centroid>,
+    /// Values that are not yet merged into the centroids.
+    buffer: Vec<f64>,
+    compression: f64,
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    /// Creates a new, empty TDigest.
+    ///
+    /// The `compression` bounds the number of centroids; higher values give more accurate
+    /// quantiles at the cost of memory.
+    pub fn new(compression: u32) -> Self {
+        let compression = compression.max(1);
+        Self {
+            centroids: vec![],
+            // Don't reserve the buffer up front, digests of small groups stay small.
+            buffer: vec![],
+            compression: compression as f64,
+            count: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// The number of values added to the digest.
+    pub fn count(&self) -> usize {
+        self.count as usize + self.buffer.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+
+    /// Adds a value to the digest. NaN values are ignored.
+    pub fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.buffer.push(value);
+        if self.buffer.len() >= self.compression as usize * BUFFER_FACTOR {
+            self.flush();
+        }
+    }
+
+    /// Merges the values of `other` into this digest.
+    pub fn merge(&mut self, other: &TDigest) {
+        if other.is_empty() {
+            return;
+        }
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        let incoming = other
+            .centroids
+            .iter()
+            .copied()
+            .chain(
+                other
+                    .buffer
+                    .iter()
+                    .map(|&mean| Centroid { mean, weight: 1.0 }),
+            )
+            .collect();
+        self.compress(incoming);
+    }
+
+    /// Estimates the `quantile` of the added values, `None` if the digest is empty.
+    ///
+    /// The estimate interpolates linearly between neighbouring values, so it is exact as long
+    /// as the digest holds fewer values than it can keep as separate centroids.
+    pub fn quantile(&mut self, quantile: f64) -> Option<f64> {
+        self.flush();
+        let (first, last) = (self.centroids.first()?, self.centroids.last()?);
+        if self.centroids.len() == 1 {
+            return Some(first.mean);
+        }
+
+        // The rank of the quantile, where the i-th smallest value is at rank i + 0.5.
+        let rank = quantile.clamp(0.0, 1.0) * (self.count - 1.0) + 0.5;
+        if rank < first.weight / 2.0 {
+            let fraction = (rank - 0.5) / (first.weight / 2.0 - 0.5);
+            return Some(self.min + fraction * (first.mean - self.min));
+        }
+        if rank > self.count - last.weight / 2.0 {
+            let fraction = (self.count - 0.5 - rank) / (last.weight / 2.0 - 0.5);
+            return Some(self.max - fraction * (self.max - last.mean));
+        }
+
+        let mut rank_so_far = first.weight / 2.0;
+        for window in self.centroids.windows(2) {
+            let (left, right) = (&window[0], &window[1]);
+            let step = (left.weight + right.weight) / 2.0;
+            if rank_so_far + step >= rank {
+                let fraction = (rank - rank_so_far) / step;
+                return Some(left.mean + fraction * (right.mean - left.mean));
+            }
+            rank_so_far += step;
+        }
+        Some(last.mean)
+    }
+
+    /// Serializes the digest; the inverse of [`TDigest::from_bytes`].
+    ///
+    /// The layout is the compression, count, minimum and maximum, followed by the mean and
+    /// weight of every centroid, all as little-endian `f64`s.
+    #[cfg(feature = "approx_unique")]
+    pub(crate) fn to_bytes(&mut self) -> Vec<u8> {
+        self.flush();
+        let header = [self.compression, self.count, self.min, self.max];
+        let mut out = Vec::with_capacity((header.len() + 2 * self.centroids.len()) * 8);
+        let centroids = self.centroids.iter().flat_map(|c| [c.mean, c.weight]);
+        for v in header.into_iter().chain(centroids) {
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        out
+    }
+
+    /// Deserializes a digest written by [`TDigest::to_bytes`], `None` if the bytes are malformed.
+    #[cfg(feature = "approx_unique")]
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 32 || bytes.len() % 16 != 0 {
+            return None;
+        }
+        let mut values = bytes
+            .chunks_exact(8)
+            .map(|b| f64::from_le_bytes(b.try_into().unwrap()));
+        let (compression, count, min, max) = (
+            values.next()?,
+            values.next()?,
+            values.next()?,
+            values.next()?,
+        );
+        let mut centroids = Vec::with_capacity(values.len() / 2);
+        while let (Some(mean), Some(weight)) = (values.next(), values.next()) {
+            centroids.push(Centroid { mean, weight });
+        }
+        if compression < 1.0 || centroids.iter().map(|c| c.weight).sum::<f64>() != count {
+            return None;
+        }
+        Some(Self {
+            centroids,
+            buffer: vec![],
+            compression,
+            count,
+            min,
+            max,
+        })
+    }
+
+    fn flush(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let incoming = self
+            .buffer
+            .drain(..)
+            .map(|mean| Centroid { mean, weight: 1.0 })
+            .collect();
+        self.compress(incoming);
+    }
+
+    /// Merge `incoming` centroids into the digest, combining neighbours as long as they stay
+    /// within the size limit of the scale function.
+    fn compress(&mut self, mut incoming: Vec<Centroid>) {
+        incoming.append(&mut self.centroids);
+        incoming.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+        let total: f64 = incoming.iter().map(|c| c.weight).sum();
+
+        let mut merged = Vec::with_capacity(self.compression as usize);
+        let mut iter = incoming.into_iter();
+        let Some(mut current) = iter.next() else {
+            return;
+        };
+        let mut weight_so_far = 0.0;
+        let mut limit = self.weight_limit(0.0, total);
+        for centroid in iter {
+            if weight_so_far + current.weight + centroid.weight <= limit {
+                current.absorb(&centroid);
+            } else {
+                weight_so_far += current.weight;
+                limit = self.weight_limit(weight_so_far, total);
+                merged.push(current);
+                current = centroid;
+            }
+        }
+        merged.push(current);
+
+        self.centroids = merged;
+        self.count = total;
+    }
+
+    /// The cumulative weight up to which a centroid starting at `weight_so_far` may grow.
+    ///
+    /// This uses the `k1` scale function `k(q) = compression / (2 * pi) * asin(2q - 1)`, which
+    /// keeps the centroids small near the tails, where quantiles are most sensitive.
+    fn weight_limit(&self, weight_so_far: f64, total: f64) -> f64 {
+        let q = weight_so_far / total;
+        let k = self.compression / (2.0 * PI) * (2.0 * q - 1.0).asin() + 1.0;
+        let q_limit = if k >= self.compression / 4.0 {
+            1.0
+        } else {
+            ((k * 2.0 * PI / self.compression).sin() + 1.0) / 2.0
+        };
+        q_limit * total
+    }
+}
+
+impl Extend<f64> for TDigest {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|v| self.add(v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TDigest;
+
+    fn exact_quantile(sorted: &[f64], quantile: f64) -> f64 {
+        let rank = quantile * (sorted.len() - 1) as f64;
+        let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+        sorted[lower] + (rank - lower as f64) * (sorted[upper] - sorted[lower])
+    }
+
+    #[test]
+    fn test_small_input_is_exact() {
+        let values = [5.0, 1.0, 4.0, 2.0, 3.0];
+        let mut digest = TDigest::new(100);
+        digest.extend(values);
+
+        let sorted = [1.0, 2.0, 3.0, 4.0, 5.0];
+        for q in [0.0, 0.1, 0.25, 0.5, 0.8, 1.0] {
+            assert_eq!(digest.quantile(q), Some(exact_quantile(&sorted, q)));
+        }
+        assert_eq!(TDigest::new(100).quantile(0.5), None);
+    }
+
+    #[test]
+    #[cfg(feature = "approx_unique")]
+    fn test_roundtrip_bytes() {
+        let mut digest = TDigest::new(50);
+        digest.extend((0..1000).map(|v| v as f64));
+        let mut restored = TDigest::from_bytes(&digest.to_bytes()).unwrap();
+        assert_eq!(restored.count(), 1000);
+        assert_eq!(restored.quantile(0.3), digest.quantile(0.3));
+
+        assert!(TDigest::from_bytes(&[0; 20]).is_none());
+    }
+
+    #[test]
+    fn test_merged_digests() {
+        let n = 100_000;
+        // A deterministic permutation of 0..n.
+        let values = (0..n).map(|i| ((i * 7919) % n) as f64).collect::<Vec<_>>();
+
+        let mut digests = values
+            .chunks(10_000)
+            .map(|chunk| {
+                let mut digest = TDigest::new(100);
+                digest.extend(chunk.iter().copied());
+                digest
+            })
+            .collect::<Vec<_>>();
+        let mut digest = digests.pop().unwrap();
+        for other in &digests {
+            digest.merge(other);
+        }
+        assert_eq!(digest.count(), n);
+
+        let mut sorted = values;
+        sorted.sort_by(f64::total_cmp);
+        for q in [0.0, 0.001, 0.01, 0.25, 0.5, 0.75, 0.99, 0.999, 1.0] {
+            let estimate = digest.quantile(q).unwrap();
+            let expected = exact_quantile(&sorted, q);
+            // The rank error should stay well below 1%.
+            assert!(
+                (estimate - expected).abs() <= 0.005 * n as f64,
+                "q={q}: estimated {estimate}, expected {expected}"
+            );
+        }
+    }
+}
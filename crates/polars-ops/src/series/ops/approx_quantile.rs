@@ -0,0 +1,120 @@
+use arrow::array::{Array, PrimitiveArray};
+use polars_core::frame::group_by::aggregations::{
+    _agg_helper_idx, _agg_helper_slice, _slice_from_offsets,
+};
+use polars_core::prelude::*;
+use polars_core::POOL;
+use rayon::prelude::*;
+
+use crate::series::ops::approx_algo::TDigest;
+
+fn check_arguments(quantile: f64, accuracy: u32) -> PolarsResult<()> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "`quantile` should be between 0.0 and 1.0",
+    );
+    polars_ensure!(accuracy > 0, ComputeError: "`accuracy` should be positive");
+    Ok(())
+}
+
+fn to_float(s: &Series) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(
+        s.dtype().to_physical().is_numeric(),
+        opq = approx_quantile,
+        s.dtype()
+    );
+    let s = s.to_physical_repr().cast(&DataType::Float64)?;
+    Ok(s.f64().unwrap().clone())
+}
+
+fn digest_array(digest: &mut TDigest, arr: &PrimitiveArray<f64>) {
+    if arr.null_count() == 0 {
+        digest.extend(arr.values().iter().copied())
+    } else {
+        digest.extend(arr.iter().flatten().copied())
+    }
+}
+
+fn digest_ca(ca: &Float64Chunked, accuracy: u32) -> TDigest {
+    let mut digest = TDigest::new(accuracy);
+    ca.downcast_iter()
+        .for_each(|arr| digest_array(&mut digest, arr));
+    digest
+}
+
+/// Build a [`TDigest`] of the non-null values of a numeric [`Series`].
+///
+/// The chunks are sketched in parallel and merged afterwards. The resulting digest can be
+/// merged with the digests of other parts of the data.
+pub fn approx_quantile_digest(s: &Series, accuracy: u32) -> PolarsResult<TDigest> {
+    polars_ensure!(accuracy > 0, ComputeError: "`accuracy` should be positive");
+    let ca = to_float(s)?;
+    let chunks = ca.downcast_iter().collect::<Vec<_>>();
+    let digests = POOL.install(|| {
+        chunks
+            .par_iter()
+            .map(|arr| {
+                let mut digest = TDigest::new(accuracy);
+                digest_array(&mut digest, arr);
+                digest
+            })
+            .collect::<Vec<_>>()
+    });
+    Ok(digests
+        .into_iter()
+        .reduce(|mut acc, digest| {
+            acc.merge(&digest);
+            acc
+        })
+        .unwrap_or_else(|| TDigest::new(accuracy)))
+}
+
+/// Approximate the `quantile` of the values of a numeric [`Series`].
+///
+/// This is done using a t-digest sketch with a compression of `accuracy`; higher values give
+/// more accurate quantiles at the cost of memory. Null and NaN values are ignored.
+///
+/// # Example
+///
+/// ```
+/// # use polars_core::prelude::*;
+/// # use polars_ops::prelude::*;
+/// let s = Series::new("s", (0..1000).collect::<Vec<i32>>());
+/// let q = approx_quantile(&s, 0.9, 100).unwrap().unwrap();
+/// assert!((q - 899.1).abs() < 5.0);
+/// ```
+pub fn approx_quantile(s: &Series, quantile: f64, accuracy: u32) -> PolarsResult<Option<f64>> {
+    check_arguments(quantile, accuracy)?;
+    Ok(approx_quantile_digest(s, accuracy)?.quantile(quantile))
+}
+
+/// Approximate the `quantile` of every group. See [`approx_quantile`].
+///
+/// # Safety
+/// The `groups` must be in bounds of `s`.
+pub unsafe fn group_agg_approx_quantile(
+    s: &Series,
+    groups: &GroupsProxy,
+    quantile: f64,
+    accuracy: u32,
+) -> PolarsResult<Series> {
+    check_arguments(quantile, accuracy)?;
+    let ca = to_float(s)?.rechunk();
+    let mut out = match groups {
+        GroupsProxy::Idx(groups) => _agg_helper_idx::<Float64Type, _>(groups, |(_, idx)| {
+            debug_assert!(idx.len() <= ca.len());
+            let mut digest = TDigest::new(accuracy);
+            digest.extend(idx.iter().filter_map(|&i| ca.get_unchecked(i as usize)));
+            digest.quantile(quantile)
+        }),
+        GroupsProxy::Slice { groups, .. } => {
+            _agg_helper_slice::<Float64Type, _>(groups, |[first, len]| {
+                debug_assert!(first + len <= ca.len() as IdxSize);
+                let group = _slice_from_offsets(&ca, first, len);
+                digest_ca(&group, accuracy).quantile(quantile)
+            })
+        },
+    };
+    out.rename(s.name());
+    Ok(out)
+}
@@ -7,7 +7,9 @@ use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
 #[cfg(feature = "approx_unique")]
 use crate::series::ops::approx_algo::HyperLogLog;
 
-fn approx_n_unique_ca<'a, T>(ca: &'a ChunkedArray<T>) -> PolarsResult<Series>
+/// A sketch of the values of `ca`, with the type of the hashed values erased so that the sketches
+/// of different columns can be merged.
+fn hyperloglog_ca<'a, T>(ca: &'a ChunkedArray<T>) -> PolarsResult<HyperLogLog<[u8]>>
 where
     T: PolarsDataType,
     T::Physical<'a>: TotalHash + TotalEq + Copy + ToTotalOrd,
@@ -15,31 +17,30 @@ where
 {
     let mut hllp = HyperLogLog::new();
     ca.iter().for_each(|item| hllp.add(&item.to_total_ord()));
-    let c = hllp.count() as IdxSize;
-
-    Ok(Series::new(ca.name(), &[c]))
+    Ok(hllp.cast())
 }
 
-fn dispatcher(s: &Series) -> PolarsResult<Series> {
+/// Build a [`HyperLogLog`] sketch of the values of `s`, including the nulls.
+pub(crate) fn hyperloglog(s: &Series) -> PolarsResult<HyperLogLog<[u8]>> {
     let s = s.to_physical_repr();
     use DataType::*;
     match s.dtype() {
-        Boolean => s.bool().and_then(approx_n_unique_ca),
-        Binary => s.binary().and_then(approx_n_unique_ca),
+        Boolean => s.bool().and_then(hyperloglog_ca),
+        Binary => s.binary().and_then(hyperloglog_ca),
         String => {
             let ca = s.str().unwrap().as_binary();
-            approx_n_unique_ca(&ca)
+            hyperloglog_ca(&ca)
         },
-        Float32 => approx_n_unique_ca(AsRef::<ChunkedArray<Float32Type>>::as_ref(
+        Float32 => hyperloglog_ca(AsRef::<ChunkedArray<Float32Type>>::as_ref(
             s.as_ref().as_ref(),
         )),
-        Float64 => approx_n_unique_ca(AsRef::<ChunkedArray<Float64Type>>::as_ref(
+        Float64 => hyperloglog_ca(AsRef::<ChunkedArray<Float64Type>>::as_ref(
             s.as_ref().as_ref(),
         )),
         dt if dt.is_numeric() => {
             with_match_physical_integer_polars_type!(s.dtype(), |$T| {
                 let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
-                approx_n_unique_ca(ca)
+                hyperloglog_ca(ca)
             })
         },
         dt => polars_bail!(opq = approx_n_unique, dt),
@@ -74,5 +75,6 @@ fn dispatcher(s: &Series) -> PolarsResult<Series> {
 /// ]
 /// ```
 pub fn approx_n_unique(s: &Series) -> PolarsResult<Series> {
-    dispatcher(s)
+    let c = hyperloglog(s)?.count() as IdxSize;
+    Ok(Series::new(s.name(), &[c]))
 }
@@ -0,0 +1,199 @@
+//! Downsampling of time series for plotting.
+//!
+//! The kernels return a mask of the points to keep, rather than the points themselves, so that
+//! the other columns of a frame can be filtered along and the points can be selected per series
+//! with a window expression.
+use polars_core::prelude::*;
+
+/// The points that can be selected: the rows where neither `x` nor `y` is null or NaN.
+struct Points {
+    idx: Vec<usize>,
+    x: Vec<f64>,
+    y: Vec<f64>,
+}
+
+fn to_f64(s: &Series, arg: &str) -> PolarsResult<Float64Chunked> {
+    let phys = s.to_physical_repr();
+    polars_ensure!(
+        phys.dtype().is_numeric(),
+        InvalidOperation: "`{}` must be numeric or temporal for downsampling, got {}", arg, s.dtype()
+    );
+    Ok(phys.cast(&DataType::Float64)?.f64()?.clone())
+}
+
+impl Points {
+    /// Without `x`, the row index is used as the `x` coordinate.
+    fn try_new(y: &Series, x: Option<&Series>) -> PolarsResult<Self> {
+        let y = to_f64(y, "y")?;
+        let x = match x {
+            Some(x) => {
+                polars_ensure!(
+                    x.len() == y.len(),
+                    ShapeMismatch: "`x` column has length {}, expected {}", x.len(), y.len()
+                );
+                Some(to_f64(x, "x")?)
+            },
+            None => None,
+        };
+
+        let mut points = Self {
+            idx: Vec::with_capacity(y.len()),
+            x: Vec::with_capacity(y.len()),
+            y: Vec::with_capacity(y.len()),
+        };
+        let mut push = |idx: usize, x: Option<f64>, y: Option<f64>| {
+            if let (Some(x), Some(y)) = (x, y) {
+                if !x.is_nan() && !y.is_nan() {
+                    points.idx.push(idx);
+                    points.x.push(x);
+                    points.y.push(y);
+                }
+            }
+        };
+        match &x {
+            Some(x) => {
+                for (idx, (x, y)) in x.iter().zip(y.iter()).enumerate() {
+                    push(idx, x, y)
+                }
+            },
+            None => {
+                for (idx, y) in y.iter().enumerate() {
+                    push(idx, Some(idx as f64), y)
+                }
+            },
+        }
+        Ok(points)
+    }
+
+    /// The mask of the rows of the `selected` points.
+    fn mask(&self, name: &str, len: usize, selected: impl IntoIterator<Item = usize>) -> Series {
+        let mut mask = vec![false; len];
+        for i in selected {
+            mask[self.idx[i]] = true;
+        }
+        BooleanChunked::from_slice(name, &mask).into_series()
+    }
+}
+
+/// Select `n_out` points with the Largest-Triangle-Three-Buckets algorithm.
+///
+/// The first and last point are always selected. The points in between are split in `n_out - 2`
+/// buckets of (about) the same number of points. From every bucket the point is selected that
+/// forms the largest triangle with the point selected from the previous bucket and the average
+/// of the next bucket.
+fn lttb_select(x: &[f64], y: &[f64], n_out: usize) -> Vec<usize> {
+    let n = x.len();
+    if n <= n_out {
+        return (0..n).collect();
+    }
+
+    let every = (n - 2) as f64 / (n_out - 2) as f64;
+    let mut selected = Vec::with_capacity(n_out);
+    let mut a = 0;
+    selected.push(a);
+    for bucket in 0..n_out - 2 {
+        let start = (bucket as f64 * every) as usize + 1;
+        let end = ((bucket + 1) as f64 * every) as usize + 1;
+        let next_start = end;
+        let next_end = std::cmp::min(((bucket + 2) as f64 * every) as usize + 1, n);
+
+        let next_len = (next_end - next_start) as f64;
+        let avg_x = x[next_start..next_end].iter().sum::<f64>() / next_len;
+        let avg_y = y[next_start..next_end].iter().sum::<f64>() / next_len;
+
+        let (ax, ay) = (x[a], y[a]);
+        let mut max_area = -1.0;
+        for (i, (xi, yi)) in x[start..end].iter().zip(&y[start..end]).enumerate() {
+            // Twice the area, which doesn't change the maximum.
+            let area = ((ax - avg_x) * (yi - ay) - (ax - xi) * (avg_y - ay)).abs();
+            if area > max_area {
+                max_area = area;
+                a = start + i;
+            }
+        }
+        selected.push(a);
+    }
+    selected.push(n - 1);
+    selected
+}
+
+/// Select the minimum and maximum of `n_out / 2` buckets of (about) the same number of points.
+fn min_max_select(y: &[f64], n_out: usize) -> Vec<usize> {
+    let n = y.len();
+    if n <= n_out {
+        return (0..n).collect();
+    }
+
+    let n_buckets = n_out / 2;
+    let mut selected = Vec::with_capacity(n_out);
+    for bucket in 0..n_buckets {
+        let start = bucket * n / n_buckets;
+        let end = (bucket + 1) * n / n_buckets;
+        let (mut min, mut max) = (start, start);
+        for (i, &v) in y.iter().enumerate().take(end).skip(start + 1) {
+            if v < y[min] {
+                min = i;
+            }
+            if v > y[max] {
+                max = i;
+            }
+        }
+        selected.push(std::cmp::min(min, max));
+        if min != max {
+            selected.push(std::cmp::max(min, max));
+        }
+    }
+    selected
+}
+
+/// A mask of the points of the time series (`x`, `y`) that are kept when downsampling it to
+/// `n_out` points with the Largest-Triangle-Three-Buckets algorithm, which preserves the visual
+/// shape of the series.
+///
+/// The points are expected to be sorted by `x`; without `x` the row index is used. Points with
+/// a null or NaN coordinate are never selected. If there are at most `n_out` other points, they
+/// are all selected.
+pub fn lttb(y: &Series, x: Option<&Series>, n_out: usize) -> PolarsResult<Series> {
+    polars_ensure!(
+        n_out >= 3,
+        InvalidOperation: "LTTB downsampling needs `n_out` of at least 3, got {}", n_out
+    );
+    let points = Points::try_new(y, x)?;
+    let selected = lttb_select(&points.x, &points.y, n_out);
+    Ok(points.mask(y.name(), y.len(), selected))
+}
+
+/// A mask of the points of `s` that are kept when downsampling it to (at most) `n_out` points by
+/// splitting it in `n_out / 2` buckets of consecutive points and keeping the minimum and maximum
+/// of every bucket.
+///
+/// Null and NaN values are never selected. If there are at most `n_out` other values, they are
+/// all selected.
+pub fn min_max_downsample(s: &Series, n_out: usize) -> PolarsResult<Series> {
+    polars_ensure!(
+        n_out >= 2,
+        InvalidOperation: "min-max downsampling needs `n_out` of at least 2, got {}", n_out
+    );
+    let points = Points::try_new(s, None)?;
+    let selected = min_max_select(&points.y, n_out);
+    Ok(points.mask(s.name(), s.len(), selected))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lttb_select() {
+        let x = (0..10).map(|i| i as f64).collect::<Vec<_>>();
+        let y = [0.0, 1.0, 0.0, 0.0, 5.0, 0.0, 0.0, -3.0, 0.0, 0.0];
+        assert_eq!(lttb_select(&x, &y, 4), [0, 4, 7, 9]);
+        assert_eq!(lttb_select(&x, &y, 10), (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_min_max_select() {
+        let y = [3.0, 1.0, 2.0, 7.0, 5.0, 4.0, 6.0];
+        assert_eq!(min_max_select(&y, 4), [0, 1, 3, 5]);
+    }
+}
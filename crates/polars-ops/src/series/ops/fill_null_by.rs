@@ -0,0 +1,154 @@
+use polars_core::prelude::*;
+
+/// Fill the null values of `s` with the previous (forward) or next (backward) non-null value
+/// within each group.
+///
+/// `groups` holds the rows of every group in ascending order; `None` treats all rows as one
+/// group. If `by` is set, a null value is only filled if the distance between it and the
+/// filling value in the `by` column (in its physical representation) is at most the given
+/// maximum gap. The `limit` of the strategy counts the consecutive null values that are
+/// filled after a non-null value, within the group.
+pub fn fill_null_by(
+    s: &Series,
+    groups: Option<&GroupsProxy>,
+    by: Option<(&Series, i64)>,
+    strategy: FillNullStrategy,
+) -> PolarsResult<Series> {
+    let (forward, limit) = match strategy {
+        FillNullStrategy::Forward(limit) => (true, limit),
+        FillNullStrategy::Backward(limit) => (false, limit),
+        _ => polars_bail!(
+            InvalidOperation: "only the 'forward' and 'backward' strategies can fill null values \
+            per group or within a maximum gap, got {:?}", strategy
+        ),
+    };
+    let by = match by {
+        Some((by, max_gap)) => {
+            polars_ensure!(
+                by.len() == s.len(),
+                ShapeMismatch: "`by` column has length {}, expected {}", by.len(), s.len()
+            );
+            polars_ensure!(max_gap >= 0, InvalidOperation: "`max_gap` must not be negative");
+            let by = by.to_physical_repr().cast(&DataType::Int64)?;
+            let by = by.i64()?.into_iter().collect::<Vec<_>>();
+            Some((by, max_gap as u64))
+        },
+        None => None,
+    };
+    if s.null_count() == 0 || s.null_count() == s.len() {
+        return Ok(s.clone());
+    }
+
+    let valid = s.is_not_null().into_no_null_iter().collect::<Vec<_>>();
+    let mut filler = GroupFiller {
+        valid: &valid,
+        by: by.as_ref().map(|(by, max_gap)| (by.as_slice(), *max_gap)),
+        limit: limit.unwrap_or(IdxSize::MAX),
+        source: vec![None; s.len()],
+    };
+    let len = s.len() as IdxSize;
+    match (groups, forward) {
+        (None, true) => filler.fill(0..len),
+        (None, false) => filler.fill((0..len).rev()),
+        (Some(GroupsProxy::Idx(groups)), true) => {
+            for group in groups.all() {
+                filler.fill(group.iter().copied())
+            }
+        },
+        (Some(GroupsProxy::Idx(groups)), false) => {
+            for group in groups.all() {
+                filler.fill(group.iter().rev().copied())
+            }
+        },
+        (Some(GroupsProxy::Slice { groups, .. }), true) => {
+            for &[first, len] in groups {
+                filler.fill(first..first + len)
+            }
+        },
+        (Some(GroupsProxy::Slice { groups, .. }), false) => {
+            for &[first, len] in groups {
+                filler.fill((first..first + len).rev())
+            }
+        },
+    }
+
+    let source = IdxCa::from_slice_options("", &filler.source);
+    s.take(&source)
+}
+
+struct GroupFiller<'a> {
+    valid: &'a [bool],
+    by: Option<(&'a [Option<i64>], u64)>,
+    limit: IdxSize,
+    /// The row every output value is taken from.
+    source: Vec<Option<IdxSize>>,
+}
+
+impl GroupFiller<'_> {
+    /// Fill the rows of a single group, visited in the direction of the fill.
+    fn fill(&mut self, rows: impl Iterator<Item = IdxSize>) {
+        let mut previous = None;
+        let mut n_filled = 0;
+        for row in rows {
+            let i = row as usize;
+            if self.valid[i] {
+                previous = Some(row);
+                n_filled = 0;
+                self.source[i] = Some(row);
+                continue;
+            }
+            let Some(previous) = previous else {
+                continue;
+            };
+            if n_filled >= self.limit {
+                continue;
+            }
+            if let Some((by, max_gap)) = self.by {
+                match (by[i], by[previous as usize]) {
+                    (Some(a), Some(b)) if a.abs_diff(b) <= max_gap => {},
+                    _ => continue,
+                }
+            }
+            n_filled += 1;
+            self.source[i] = Some(previous);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fill_null_by() -> PolarsResult<()> {
+        let s = Series::new("a", [Some(1), None, None, Some(4), None, None]);
+        let keys = Series::new("g", [0, 1, 0, 1, 0, 1]);
+        let groups = keys.group_tuples(false, false)?;
+
+        let out = fill_null_by(&s, Some(&groups), None, FillNullStrategy::Forward(None))?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), None, Some(1), Some(4), Some(1), Some(4)]
+        );
+        let out = fill_null_by(&s, Some(&groups), None, FillNullStrategy::Forward(Some(1)))?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), None, Some(1), Some(4), None, Some(4)]
+        );
+        let out = fill_null_by(&s, Some(&groups), None, FillNullStrategy::Backward(None))?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), Some(4), None, Some(4), None, None]
+        );
+
+        let by = Series::new("t", [0, 1, 2, 3, 10, 11]);
+        let out = fill_null_by(&s, None, Some((&by, 1)), FillNullStrategy::Forward(None))?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), Some(1), None, Some(4), None, None]
+        );
+
+        assert!(fill_null_by(&s, None, None, FillNullStrategy::Mean).is_err());
+        Ok(())
+    }
+}
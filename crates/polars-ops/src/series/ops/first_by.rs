@@ -0,0 +1,81 @@
+use polars_core::frame::group_by::aggregations::{_agg_helper_idx, _agg_helper_slice};
+use polars_core::prelude::*;
+
+use super::ArgAgg;
+
+fn check_by_dtype(dtype: &DataType) -> PolarsResult<()> {
+    let supported = dtype.to_physical().is_numeric()
+        || matches!(dtype, DataType::String | DataType::Boolean | DataType::Null);
+    polars_ensure!(
+        supported,
+        InvalidOperation: "cannot order by dtype {} in `first_by`/`last_by`", dtype
+    );
+    Ok(())
+}
+
+/// Get the position of the smallest value of `by`, or of the largest value if `last` is set.
+///
+/// Ties are broken by taking the first smallest or the last largest value, which is the row
+/// that `first`/`last` return after a stable sort by `by`. Nulls are ignored, so `None` is only
+/// returned if `by` has no valid values.
+pub fn arg_first_by(by: &Series, last: bool) -> PolarsResult<Option<usize>> {
+    check_by_dtype(by.dtype())?;
+    Ok(arg_first_by_impl(by, last))
+}
+
+fn arg_first_by_impl(by: &Series, last: bool) -> Option<usize> {
+    if last {
+        let len = by.len();
+        by.reverse().arg_max().map(|idx| len - 1 - idx)
+    } else {
+        by.arg_min()
+    }
+}
+
+/// [`arg_first_by`] for every group, the positions are relative to the start of their group.
+///
+/// # Safety
+/// The `groups` must be in bounds of `by`.
+pub unsafe fn group_arg_first_by(
+    by: &Series,
+    groups: &GroupsProxy,
+    last: bool,
+) -> PolarsResult<IdxCa> {
+    check_by_dtype(by.dtype())?;
+    let by = by.rechunk();
+    let out = match groups {
+        GroupsProxy::Idx(groups) => _agg_helper_idx::<IdxType, _>(groups, |(_, idx)| {
+            debug_assert!(idx.len() <= by.len());
+            let group = by.take_slice_unchecked(idx);
+            arg_first_by_impl(&group, last).map(|i| i as IdxSize)
+        }),
+        GroupsProxy::Slice { groups, .. } => {
+            _agg_helper_slice::<IdxType, _>(groups, |[first, len]| {
+                debug_assert!(first + len <= by.len() as IdxSize);
+                let group = by.slice(first as i64, len as usize);
+                arg_first_by_impl(&group, last).map(|i| i as IdxSize)
+            })
+        },
+    };
+    Ok(out.idx().unwrap().clone())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_arg_first_by() -> PolarsResult<()> {
+        let by = Series::new("by", [Some(3), None, Some(1), Some(3), Some(1)]);
+        assert_eq!(arg_first_by(&by, false)?, Some(2));
+        assert_eq!(arg_first_by(&by, true)?, Some(3));
+
+        let by = Series::new("by", [Option::<i32>::None, None]);
+        assert_eq!(arg_first_by(&by, false)?, None);
+        assert_eq!(arg_first_by(&by, true)?, None);
+
+        let by = Series::new("by", [Series::new("", [1, 2])]);
+        assert!(arg_first_by(&by, false).is_err());
+        Ok(())
+    }
+}
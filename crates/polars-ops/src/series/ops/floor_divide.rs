@@ -1,9 +1,17 @@
+use num_traits::{One, WrappingAdd, Zero};
 use polars_compute::arithmetic::ArithmeticKernel;
-use polars_core::chunked_array::ops::arity::apply_binary_kernel_broadcast;
+use polars_core::chunked_array::ops::arity::{
+    apply_binary_kernel_broadcast, broadcast_binary_elementwise,
+};
 use polars_core::prelude::*;
 #[cfg(feature = "dtype-struct")]
 use polars_core::series::arithmetic::_struct_arithmetic;
-use polars_core::with_match_physical_numeric_polars_type;
+#[cfg(feature = "dtype-decimal")]
+use polars_core::utils::try_get_supertype;
+use polars_core::{
+    with_match_physical_integer_polars_type, with_match_physical_numeric_polars_type,
+};
+use polars_utils::floor_divmod::FloorDivMod;
 
 fn floor_div_ca<T: PolarsNumericType>(
     lhs: &ChunkedArray<T>,
@@ -18,12 +26,61 @@ fn floor_div_ca<T: PolarsNumericType>(
     )
 }
 
+/// Integer division rounding up, null where dividing by zero.
+fn ceil_div_ca<T>(lhs: &ChunkedArray<T>, rhs: &ChunkedArray<T>) -> ChunkedArray<T>
+where
+    T: PolarsIntegerType,
+    T::Native: FloorDivMod + WrappingAdd,
+{
+    broadcast_binary_elementwise(
+        lhs,
+        rhs,
+        |l: Option<T::Native>, r: Option<T::Native>| -> Option<T::Native> {
+            let (l, r) = (l?, r?);
+            if r.is_zero() {
+                return None;
+            }
+            let (div, rem) = l.wrapping_floor_div_mod(r);
+            Some(if rem.is_zero() {
+                div
+            } else {
+                div.wrapping_add(&T::Native::one())
+            })
+        },
+    )
+}
+
+/// Divide decimals, rounding the quotient to an integer.
+///
+/// Both sides are brought to the same scale, so that the quotient of their unscaled values is
+/// the quotient of the decimals.
+#[cfg(feature = "dtype-decimal")]
+fn decimal_div(a: &Series, b: &Series, ceil: bool) -> PolarsResult<Series> {
+    let dtype = try_get_supertype(a.dtype(), b.dtype())?;
+    let (a, b) = (a.cast(&dtype)?, b.cast(&dtype)?);
+    let (a, b) = (a.decimal()?, b.decimal()?);
+    let (precision, scale) = (a.precision(), a.scale());
+
+    let quotient = if ceil {
+        ceil_div_ca(a.as_ref(), b.as_ref())
+    } else {
+        floor_div_ca(a.as_ref(), b.as_ref())
+    };
+    let multiplier = 10_i128.pow(scale as u32);
+    let out = quotient.apply(|q| q?.checked_mul(multiplier));
+    Ok(out.into_decimal_unchecked(precision, scale).into_series())
+}
+
 pub fn floor_div_series(a: &Series, b: &Series) -> PolarsResult<Series> {
     match (a.dtype(), b.dtype()) {
         #[cfg(feature = "dtype-struct")]
         (DataType::Struct(_), DataType::Struct(_)) => {
             return _struct_arithmetic(a, b, floor_div_series);
         },
+        #[cfg(feature = "dtype-decimal")]
+        (DataType::Decimal(_, _), DataType::Decimal(_, _)) => {
+            return decimal_div(a, b, false);
+        },
         _ => {},
     }
 
@@ -45,3 +102,48 @@ pub fn floor_div_series(a: &Series, b: &Series) -> PolarsResult<Series> {
 
     out.cast(logical_type)
 }
+
+/// Divide and round the quotient up, the counterpart of [`floor_div_series`].
+///
+/// Integer division by zero is null, like it is for the floor division.
+pub fn ceil_div_series(a: &Series, b: &Series) -> PolarsResult<Series> {
+    match (a.dtype(), b.dtype()) {
+        #[cfg(feature = "dtype-struct")]
+        (DataType::Struct(_), DataType::Struct(_)) => {
+            return _struct_arithmetic(a, b, ceil_div_series);
+        },
+        #[cfg(feature = "dtype-decimal")]
+        (DataType::Decimal(_, _), DataType::Decimal(_, _)) => {
+            return decimal_div(a, b, true);
+        },
+        (DataType::Float32, DataType::Float32) => {
+            let out: Float32Chunked = broadcast_binary_elementwise(
+                a.f32()?,
+                b.f32()?,
+                |l: Option<f32>, r: Option<f32>| -> Option<f32> { Some((l? / r?).ceil()) },
+            );
+            return Ok(out.into_series());
+        },
+        (DataType::Float64, DataType::Float64) => {
+            let out: Float64Chunked = broadcast_binary_elementwise(
+                a.f64()?,
+                b.f64()?,
+                |l: Option<f64>, r: Option<f64>| -> Option<f64> { Some((l? / r?).ceil()) },
+            );
+            return Ok(out.into_series());
+        },
+        _ => {},
+    }
+
+    polars_ensure!(
+        a.dtype().is_integer() && a.dtype() == b.dtype(),
+        InvalidOperation: "ceil_div not supported for dtypes `{}` and `{}`", a.dtype(), b.dtype()
+    );
+    let out = with_match_physical_integer_polars_type!(a.dtype(), |$T| {
+        let a: &ChunkedArray<$T> = a.as_ref().as_ref().as_ref();
+        let b: &ChunkedArray<$T> = b.as_ref().as_ref().as_ref();
+
+        ceil_div_ca(a, b).into_series()
+    });
+    Ok(out)
+}
@@ -1,5 +1,9 @@
 use polars_core::frame::NullStrategy;
+use polars_core::prelude::arity::binary_elementwise;
 use polars_core::prelude::*;
+use polars_core::utils::dtypes_to_supertype;
+use polars_core::{with_match_physical_numeric_polars_type, POOL};
+use rayon::prelude::*;
 
 pub fn max_horizontal(s: &[Series]) -> PolarsResult<Option<Series>> {
     let df = unsafe { DataFrame::new_no_checks(Vec::from(s)) };
@@ -13,6 +17,87 @@ pub fn min_horizontal(s: &[Series]) -> PolarsResult<Option<Series>> {
         .map(|opt_s| opt_s.map(|res| res.with_name(s[0].name())))
 }
 
+/// The index of the first column that holds the minimum (`max == false`) or maximum value of
+/// every row.
+///
+/// Null values are ignored, the index of a row with only null values is null. The columns
+/// are compared pairwise in a tournament, so the values are cast and compared once per column
+/// instead of once per step of a chain of `when/then` expressions.
+pub fn arg_min_max_horizontal(s: &[Series], max: bool) -> PolarsResult<Option<IdxCa>> {
+    let Some(len) = s.iter().map(|s| s.len()).max() else {
+        return Ok(None);
+    };
+    let dtype = dtypes_to_supertype(s.iter().map(|s| s.dtype()))?;
+    // Temporal values are ordered like their physical values, categoricals may not be.
+    let to_physical = dtype.is_temporal();
+    let columns = s
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            let mut values = s.cast(&dtype)?;
+            if to_physical {
+                values = values.to_physical_repr().into_owned();
+            }
+            Ok((values, IdxCa::full("", i as IdxSize, len)))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let (values, idx) = POOL.install(|| {
+        columns
+            .into_par_iter()
+            .map(Ok)
+            .try_reduce_with(|left, right| arg_min_max_binary(left, right, max))
+            // There is at least one column.
+            .unwrap()
+    })?;
+    if values.null_count() == 0 {
+        return Ok(Some(idx));
+    }
+    idx.set(&values.is_null(), None).map(Some)
+}
+
+/// Keep the winner of every row of two columns, together with the index of its column.
+fn arg_min_max_binary(
+    (left, left_idx): (Series, IdxCa),
+    (right, right_idx): (Series, IdxCa),
+    max: bool,
+) -> PolarsResult<(Series, IdxCa)> {
+    // On ties the left value wins, the left columns come first.
+    let take_right = if left.dtype().is_numeric() && left.len() == right.len() {
+        with_match_physical_numeric_polars_type!(left.dtype(), |$T| {
+            let l: &ChunkedArray<$T> = left.as_ref().as_ref().as_ref();
+            let r: &ChunkedArray<$T> = right.as_ref().as_ref().as_ref();
+            take_right_numeric(l, r, max)
+        })
+    } else {
+        let wins = if max {
+            right.gt(&left)?
+        } else {
+            right.lt(&left)?
+        };
+        (wins.fill_null_with_values(false)? | left.is_null()) & right.is_not_null()
+    };
+    Ok((
+        right.zip_with(&take_right, &left)?,
+        right_idx.zip_with(&take_right, &left_idx)?,
+    ))
+}
+
+fn take_right_numeric<T>(
+    left: &ChunkedArray<T>,
+    right: &ChunkedArray<T>,
+    max: bool,
+) -> BooleanChunked
+where
+    T: PolarsNumericType,
+{
+    binary_elementwise(left, right, |l, r| match (l, r) {
+        (Some(l), Some(r)) => Some(if max { r > l } else { r < l }),
+        (None, Some(_)) => Some(true),
+        (_, None) => Some(false),
+    })
+}
+
 pub fn sum_horizontal(s: &[Series]) -> PolarsResult<Option<Series>> {
     let df = unsafe { DataFrame::new_no_checks(Vec::from(s)) };
     df.sum_horizontal(NullStrategy::Ignore)
@@ -30,7 +115,7 @@ pub fn coalesce_series(s: &[Series]) -> PolarsResult<Series> {
     polars_ensure!(!s.is_empty(), NoData: "cannot coalesce empty list");
     let mut out = s[0].clone();
     for s in s {
-        if !out.null_count() == 0 {
+        if out.null_count() == 0 {
             return Ok(out);
         } else {
             let mask = out.is_not_null();
@@ -0,0 +1,348 @@
+//! Statistical hypothesis tests that compare two samples.
+//!
+//! Every test returns a single row struct with the `statistic` and the (two-sided) `p_value`,
+//! so that it can be used as an aggregation per group.
+use std::f64::consts::PI;
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The t-test to compare the means of two samples with.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TTestVariant {
+    /// Independent samples that may have different variances.
+    #[default]
+    Welch,
+    /// Independent samples with equal variances.
+    Student,
+    /// The pairs of values of two samples of the same length, e.g. before and after.
+    Paired,
+}
+
+const EPS: f64 = 1e-15;
+const TINY: f64 = 1e-300;
+const MAX_ITER: usize = 1000;
+
+/// The natural logarithm of the gamma function, with the Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const G: f64 = 7.0;
+    const COEF: [f64; 9] = [
+        0.9999999999998099,
+        676.5203681218851,
+        -1259.1392167224028,
+        771.3234287776531,
+        -176.6150291621406,
+        12.507343278686905,
+        -0.13857109526572012,
+        9.984369578019572e-6,
+        1.5056327351493116e-7,
+    ];
+    if x < 0.5 {
+        // Reflection formula.
+        return PI.ln() - (PI * x).sin().ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let t = x + G + 0.5;
+    let a = COEF
+        .iter()
+        .enumerate()
+        .skip(1)
+        .fold(COEF[0], |a, (i, c)| a + c / (x + i as f64));
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + a.ln()
+}
+
+/// Guard the denominators of the continued fractions against zero.
+fn non_tiny(x: f64) -> f64 {
+    if x.abs() < TINY {
+        TINY
+    } else {
+        x
+    }
+}
+
+/// The continued fraction of the incomplete beta function, with the modified Lentz method.
+fn beta_cf(a: f64, b: f64, x: f64) -> f64 {
+    let mut c = 1.0;
+    let mut d = 1.0 / non_tiny(1.0 - (a + b) * x / (a + 1.0));
+    let mut h = d;
+    for m in 1..=MAX_ITER {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+        // The even and odd step.
+        let even = m * (b - m) * x / ((a - 1.0 + m2) * (a + m2));
+        let odd = -(a + m) * (a + b + m) * x / ((a + m2) * (a + 1.0 + m2));
+        for coef in [even, odd] {
+            d = 1.0 / non_tiny(1.0 + coef * d);
+            c = non_tiny(1.0 + coef / c);
+            h *= d * c;
+        }
+        if (d * c - 1.0).abs() < EPS {
+            break;
+        }
+    }
+    h
+}
+
+/// The regularized incomplete beta function `I_x(a, b)`.
+fn beta_inc(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let front = (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (-x).ln_1p()).exp();
+    // The continued fraction converges fast on this side, use the symmetry on the other side.
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_cf(a, b, x) / a
+    } else {
+        1.0 - front * beta_cf(b, a, 1.0 - x) / b
+    }
+}
+
+/// The regularized upper incomplete gamma function `Q(a, x)`.
+fn gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let front = (-x + a * x.ln() - ln_gamma(a)).exp();
+    if x < a + 1.0 {
+        // The series of `P(a, x) = 1 - Q(a, x)`.
+        let mut ap = a;
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        for _ in 0..MAX_ITER {
+            ap += 1.0;
+            term *= x / ap;
+            sum += term;
+            if term.abs() < sum.abs() * EPS {
+                break;
+            }
+        }
+        1.0 - sum * front
+    } else {
+        // The continued fraction, with the modified Lentz method.
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / TINY;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for i in 1..=MAX_ITER {
+            let i = i as f64;
+            let an = -i * (i - a);
+            b += 2.0;
+            d = 1.0 / non_tiny(an * d + b);
+            c = non_tiny(b + an / c);
+            h *= d * c;
+            if (d * c - 1.0).abs() < EPS {
+                break;
+            }
+        }
+        front * h
+    }
+}
+
+/// The probability that a Student's t distributed variable with `df` degrees of freedom is at
+/// least `|t|` away from zero.
+fn t_two_sided_p(t: f64, df: f64) -> f64 {
+    beta_inc(df / 2.0, 0.5, df / (df + t * t))
+}
+
+/// The probability that a chi-squared distributed variable with `df` degrees of freedom is at
+/// least `x`.
+fn chi2_sf(x: f64, df: f64) -> f64 {
+    gamma_q(df / 2.0, x / 2.0)
+}
+
+/// The probability that the Kolmogorov distribution is at least `lambda`.
+fn kolmogorov_sf(lambda: f64) -> f64 {
+    let a = -2.0 * lambda * lambda;
+    let mut sign = 1.0;
+    let mut sum = 0.0;
+    let mut prev = 0.0;
+    for j in 1..=100 {
+        let term = 2.0 * sign * (a * (j * j) as f64).exp();
+        sum += term;
+        if term.abs() <= 1e-10 * prev || term.abs() <= 1e-16 * sum {
+            return sum.clamp(0.0, 1.0);
+        }
+        sign = -sign;
+        prev = term.abs();
+    }
+    // The series doesn't converge for small `lambda`, where the probability is one.
+    1.0
+}
+
+fn to_f64(s: &Series, test: &str) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(
+        s.dtype().is_numeric() || s.dtype().is_null(),
+        InvalidOperation: "{} expects numeric samples, got {}", test, s.dtype()
+    );
+    Ok(s.cast(&DataType::Float64)?.f64()?.clone())
+}
+
+/// The non-null values of `s`.
+fn values(s: &Series, test: &str) -> PolarsResult<Vec<f64>> {
+    Ok(to_f64(s, test)?.into_iter().flatten().collect())
+}
+
+/// The pairs of values of `a` and `b` in the rows where neither is null.
+fn pairs(a: &Series, b: &Series, test: &str) -> PolarsResult<Vec<(f64, f64)>> {
+    polars_ensure!(
+        a.len() == b.len(),
+        ShapeMismatch: "{} expects samples of the same length, got {} and {}",
+        test, a.len(), b.len()
+    );
+    let (a, b) = (to_f64(a, test)?, to_f64(b, test)?);
+    Ok(a.into_iter()
+        .zip(b.into_iter())
+        .filter_map(|(a, b)| Some((a?, b?)))
+        .collect())
+}
+
+/// The mean and the sample variance, NaN without enough values.
+fn mean_var(values: &[f64]) -> (f64, f64) {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let ss = values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>();
+    (mean, ss / (n - 1.0))
+}
+
+fn test_result(name: &str, statistic: f64, p_value: f64) -> PolarsResult<Series> {
+    let fields = [
+        Series::new("statistic", &[statistic]),
+        Series::new("p_value", &[p_value]),
+    ];
+    Ok(StructChunked::new(name, &fields)?.into_series())
+}
+
+/// The data type of the result of the hypothesis tests.
+pub fn hypothesis_test_dtype() -> DataType {
+    DataType::Struct(vec![
+        Field::new("statistic", DataType::Float64),
+        Field::new("p_value", DataType::Float64),
+    ])
+}
+
+/// Test whether the means of the samples `a` and `b` differ, with a two-sided t-test.
+///
+/// Nulls are ignored, the paired test ignores the rows where either sample is null. The
+/// statistic and p-value are NaN if a sample has fewer than two values.
+pub fn ttest(a: &Series, b: &Series, variant: TTestVariant) -> PolarsResult<Series> {
+    let (t, df) = match variant {
+        TTestVariant::Paired => {
+            let diff = pairs(a, b, "paired t-test")?
+                .into_iter()
+                .map(|(a, b)| a - b)
+                .collect::<Vec<_>>();
+            let n = diff.len() as f64;
+            let (mean, var) = mean_var(&diff);
+            (mean / (var / n).sqrt(), n - 1.0)
+        },
+        _ => {
+            let (x, y) = (values(a, "t-test")?, values(b, "t-test")?);
+            let (n1, n2) = (x.len() as f64, y.len() as f64);
+            let ((m1, v1), (m2, v2)) = (mean_var(&x), mean_var(&y));
+            if variant == TTestVariant::Welch {
+                let (se1, se2) = (v1 / n1, v2 / n2);
+                let se = se1 + se2;
+                let df = se * se / (se1 * se1 / (n1 - 1.0) + se2 * se2 / (n2 - 1.0));
+                ((m1 - m2) / se.sqrt(), df)
+            } else {
+                let df = n1 + n2 - 2.0;
+                let pooled = ((n1 - 1.0) * v1 + (n2 - 1.0) * v2) / df;
+                ((m1 - m2) / (pooled * (1.0 / n1 + 1.0 / n2)).sqrt(), df)
+            }
+        },
+    };
+    test_result(a.name(), t, t_two_sided_p(t, df))
+}
+
+/// Test whether the samples `a` and `b` come from the same distribution, with the two-sample
+/// Kolmogorov-Smirnov test.
+///
+/// The statistic is the largest distance between the empirical distribution functions of the
+/// samples, the p-value is computed with its asymptotic distribution. Nulls and NaN values are
+/// ignored.
+pub fn ks_test(a: &Series, b: &Series) -> PolarsResult<Series> {
+    let mut x = values(a, "Kolmogorov-Smirnov test")?;
+    let mut y = values(b, "Kolmogorov-Smirnov test")?;
+    x.retain(|v| !v.is_nan());
+    y.retain(|v| !v.is_nan());
+    if x.is_empty() || y.is_empty() {
+        return test_result(a.name(), f64::NAN, f64::NAN);
+    }
+    x.sort_by(f64::total_cmp);
+    y.sort_by(f64::total_cmp);
+
+    let (n1, n2) = (x.len(), y.len());
+    let (mut i, mut j) = (0, 0);
+    let mut statistic: f64 = 0.0;
+    while i < n1 && j < n2 {
+        let v = x[i].min(y[j]);
+        while i < n1 && x[i] <= v {
+            i += 1;
+        }
+        while j < n2 && y[j] <= v {
+            j += 1;
+        }
+        statistic = statistic.max((i as f64 / n1 as f64 - j as f64 / n2 as f64).abs());
+    }
+
+    let en = (n1 * n2) as f64 / (n1 + n2) as f64;
+    let lambda = (en.sqrt() + 0.12 + 0.11 / en.sqrt()) * statistic;
+    test_result(a.name(), statistic, kolmogorov_sf(lambda))
+}
+
+/// Test whether the `observed` frequencies of categories differ from the `expected`
+/// frequencies, with Pearson's chi-squared test.
+///
+/// The frequencies of a category are in the same row, the rows where either is null are
+/// ignored. The expected frequencies should sum to the same total as the observed ones.
+pub fn chi2_test(observed: &Series, expected: &Series) -> PolarsResult<Series> {
+    let pairs = pairs(observed, expected, "chi-squared test")?;
+    let statistic = pairs
+        .iter()
+        .map(|(o, e)| (o - e) * (o - e) / e)
+        .sum::<f64>();
+    let p_value = match pairs.len() {
+        0 | 1 => f64::NAN,
+        k => chi2_sf(statistic, (k - 1) as f64),
+    };
+    test_result(observed.name(), statistic, p_value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-12, "{a} != {b}");
+    }
+
+    #[test]
+    fn test_distributions() {
+        for t in [0.5, 2.0, 10.0] {
+            // The Cauchy distribution.
+            assert_close(t_two_sided_p(t, 1.0), 1.0 - 2.0 * t.atan() / PI);
+            assert_close(t_two_sided_p(t, 2.0), 1.0 - t / (t * t + 2.0).sqrt());
+        }
+        for x in [0.5, 2.0, 7.5, 40.0] {
+            // The exponential distribution.
+            assert_close(chi2_sf(x, 2.0), (-x / 2.0).exp());
+        }
+        assert_close(kolmogorov_sf(1.0), 0.26999967167735456);
+        assert_eq!(kolmogorov_sf(0.01), 1.0);
+    }
+
+    #[test]
+    fn test_ks_statistic() -> PolarsResult<()> {
+        let a = Series::new("a", [1.0, 2.0, 3.0, 4.0]);
+        let b = Series::new("b", [Some(3.0), None, Some(4.0), Some(5.0), Some(6.0)]);
+        let out = ks_test(&a, &b)?;
+        let statistic = out.struct_()?.field_by_name("statistic")?;
+        assert_eq!(statistic.f64()?.get(0), Some(0.5));
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use std::hash::Hash;
 
 use arrow::array::BooleanArray;
 use arrow::bitmap::MutableBitmap;
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca_unordered;
 use polars_core::prelude::*;
 use polars_core::with_match_physical_integer_polars_type;
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
@@ -97,3 +98,112 @@ pub fn is_unique(s: &Series) -> PolarsResult<BooleanChunked> {
 pub fn is_duplicated(s: &Series) -> PolarsResult<BooleanChunked> {
     dispatcher(s, true)
 }
+
+/// The groups of equal values of a column, or of equal rows of multiple columns, in order of
+/// their first occurrence.
+#[derive(Debug, Default)]
+pub struct DuplicateGroups {
+    /// The group of every row.
+    pub group_ids: Vec<IdxSize>,
+    /// The index of the first row of every group.
+    pub first: Vec<IdxSize>,
+    /// The index of the last row of every group.
+    pub last: Vec<IdxSize>,
+    /// The number of rows of every group.
+    pub counts: Vec<IdxSize>,
+}
+
+impl DuplicateGroups {
+    /// Mark the rows that have an equal row before them (`First`), after them (`Last`) or
+    /// anywhere (`None`). `Any` behaves like `First`.
+    pub fn is_duplicated(&self, name: &str, keep: UniqueKeepStrategy) -> BooleanChunked {
+        let kept = match keep {
+            UniqueKeepStrategy::First | UniqueKeepStrategy::Any => Some(&self.first),
+            UniqueKeepStrategy::Last => Some(&self.last),
+            UniqueKeepStrategy::None => None,
+        };
+        let values = self
+            .group_ids
+            .iter()
+            .enumerate()
+            .map(|(idx, &g)| {
+                let g = g as usize;
+                self.counts[g] > 1 && kept.map_or(true, |kept| kept[g] != idx as IdxSize)
+            })
+            .collect::<MutableBitmap>();
+        let arr = BooleanArray::from_data_default(values.into(), None);
+        BooleanChunked::with_chunk(name, arr)
+    }
+}
+
+fn duplicate_groups_ca<'a, T>(ca: &'a ChunkedArray<T>) -> DuplicateGroups
+where
+    T: PolarsDataType,
+    T::Physical<'a>: TotalHash + TotalEq + Copy + ToTotalOrd,
+    <Option<T::Physical<'a>> as ToTotalOrd>::TotalOrdItem: Hash + Eq,
+{
+    let mut groups = DuplicateGroups {
+        group_ids: Vec::with_capacity(ca.len()),
+        ..Default::default()
+    };
+    let mut group_key = PlHashMap::new();
+    for (idx, key) in ca.iter().enumerate() {
+        let idx = idx as IdxSize;
+        let next = groups.first.len() as IdxSize;
+        let g = *group_key.entry(key.to_total_ord()).or_insert(next);
+        if g == next {
+            groups.first.push(idx);
+            groups.last.push(idx);
+            groups.counts.push(1);
+        } else {
+            groups.last[g as usize] = idx;
+            groups.counts[g as usize] += 1;
+        }
+        groups.group_ids.push(g);
+    }
+    groups
+}
+
+/// Find the groups of equal values in a single hash pass.
+pub fn duplicate_groups(s: &Series) -> PolarsResult<DuplicateGroups> {
+    let s = s.to_physical_repr();
+    use DataType::*;
+    let out = match s.dtype() {
+        Boolean => duplicate_groups_ca(s.bool().unwrap()),
+        Binary => duplicate_groups_ca(s.binary().unwrap()),
+        String => duplicate_groups_ca(&s.str().unwrap().as_binary()),
+        Float32 => duplicate_groups_ca(s.f32().unwrap()),
+        Float64 => duplicate_groups_ca(s.f64().unwrap()),
+        Null => duplicate_groups_ca(&BooleanChunked::full_null(s.name(), s.len())),
+        dt if dt.is_numeric() => {
+            with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+                let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                duplicate_groups_ca(ca)
+            })
+        },
+        #[cfg(feature = "dtype-struct")]
+        Struct(_) => return duplicate_groups_multiple(&[s.into_owned()]),
+        dt => polars_bail!(opq = is_duplicated, dt),
+    };
+    Ok(out)
+}
+
+/// Find the groups of equal rows of multiple columns in a single hash pass.
+pub fn duplicate_groups_multiple(by: &[Series]) -> PolarsResult<DuplicateGroups> {
+    match by {
+        [s] if !s.dtype().is_struct() => duplicate_groups(s),
+        _ => {
+            let rows = _get_rows_encoded_ca_unordered("", by)?;
+            Ok(duplicate_groups_ca(&rows))
+        },
+    }
+}
+
+/// Get a mask of the duplicated values, where `keep` decides which occurrence of a value is not
+/// marked as duplicated.
+pub fn is_duplicated_keep(s: &Series, keep: UniqueKeepStrategy) -> PolarsResult<BooleanChunked> {
+    match keep {
+        UniqueKeepStrategy::None => is_duplicated(s),
+        keep => Ok(duplicate_groups(s)?.is_duplicated(s.name(), keep)),
+    }
+}
@@ -1,7 +1,9 @@
 #[cfg(feature = "abs")]
 mod abs;
-#[cfg(feature = "approx_unique")]
+#[cfg(any(feature = "approx_unique", feature = "approx_quantile"))]
 mod approx_algo;
+#[cfg(feature = "approx_quantile")]
+mod approx_quantile;
 #[cfg(feature = "approx_unique")]
 mod approx_unique;
 mod arg_min_max;
@@ -14,15 +16,22 @@ mod cum_agg;
 mod cut;
 #[cfg(feature = "diff")]
 mod diff;
+#[cfg(feature = "downsample")]
+mod downsample;
 #[cfg(feature = "ewma")]
 mod ewm;
 #[cfg(feature = "ewma_by")]
 mod ewm_by;
+#[cfg(feature = "fill_null_by")]
+mod fill_null_by;
+mod first_by;
 #[cfg(feature = "round_series")]
 mod floor_divide;
 #[cfg(feature = "fused")]
 mod fused;
 mod horizontal;
+#[cfg(feature = "hypothesis_tests")]
+mod hypothesis_tests;
 mod index;
 mod int_range;
 #[cfg(any(feature = "interpolate_by", feature = "interpolate"))]
@@ -44,6 +53,8 @@ mod moment;
 mod negate;
 #[cfg(feature = "pct_change")]
 mod pct_change;
+#[cfg(feature = "random")]
+mod random;
 #[cfg(feature = "rank")]
 mod rank;
 #[cfg(feature = "reinterpret")]
@@ -58,16 +69,21 @@ mod rolling;
 mod round;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
+#[cfg(feature = "approx_unique")]
+mod sketch;
 #[cfg(feature = "to_dummies")]
 mod to_dummies;
 #[cfg(feature = "unique_counts")]
 mod unique;
+mod validity;
 mod various;
 
 #[cfg(feature = "abs")]
 pub use abs::*;
-#[cfg(feature = "approx_unique")]
+#[cfg(any(feature = "approx_unique", feature = "approx_quantile"))]
 pub use approx_algo::*;
+#[cfg(feature = "approx_quantile")]
+pub use approx_quantile::*;
 #[cfg(feature = "approx_unique")]
 pub use approx_unique::*;
 pub use arg_min_max::ArgAgg;
@@ -80,15 +96,22 @@ pub use cum_agg::*;
 pub use cut::*;
 #[cfg(feature = "diff")]
 pub use diff::*;
+#[cfg(feature = "downsample")]
+pub use downsample::*;
 #[cfg(feature = "ewma")]
 pub use ewm::*;
 #[cfg(feature = "ewma_by")]
 pub use ewm_by::*;
+#[cfg(feature = "fill_null_by")]
+pub use fill_null_by::*;
+pub use first_by::*;
 #[cfg(feature = "round_series")]
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
 pub use fused::*;
 pub use horizontal::*;
+#[cfg(feature = "hypothesis_tests")]
+pub use hypothesis_tests::*;
 pub use index::*;
 pub use int_range::*;
 #[cfg(feature = "interpolate")]
@@ -116,6 +139,8 @@ pub use negate::*;
 pub use pct_change::*;
 pub use polars_core::chunked_array::ops::search_sorted::SearchSortedSide;
 use polars_core::prelude::*;
+#[cfg(feature = "random")]
+pub use random::*;
 #[cfg(feature = "rank")]
 pub use rank::*;
 #[cfg(feature = "reinterpret")]
@@ -130,10 +155,13 @@ pub use rolling::*;
 pub use round::*;
 #[cfg(feature = "search_sorted")]
 pub use search_sorted::*;
+#[cfg(feature = "approx_unique")]
+pub use sketch::*;
 #[cfg(feature = "to_dummies")]
 pub use to_dummies::*;
 #[cfg(feature = "unique_counts")]
 pub use unique::*;
+pub use validity::*;
 pub use various::*;
 mod not;
 pub use not::*;
@@ -0,0 +1,196 @@
+//! Random values drawn from probability distributions.
+//!
+//! Every row draws from its own generator, seeded with the seed of the expression and the
+//! index of the row. The values thus only depend on the seed and the position of the row, not
+//! on how the rows are split in chunks, morsels or threads.
+use polars_core::prelude::*;
+use polars_core::random::get_global_random_u64;
+use polars_error::to_compute_err;
+use rand::prelude::*;
+use rand_distr::{Poisson, StandardNormal};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RandomDistribution {
+    /// Parameters: the lower (inclusive) and upper (exclusive) bound.
+    Uniform,
+    /// Parameters: the mean and the standard deviation.
+    Normal,
+    /// Parameters: the expected number of events.
+    Poisson,
+}
+
+impl RandomDistribution {
+    pub fn n_params(&self) -> usize {
+        match self {
+            Self::Uniform | Self::Normal => 2,
+            Self::Poisson => 1,
+        }
+    }
+
+    /// The data type of the values.
+    pub fn dtype(&self) -> DataType {
+        match self {
+            Self::Uniform | Self::Normal => DataType::Float64,
+            Self::Poisson => DataType::Int64,
+        }
+    }
+}
+
+/// The generator of the row at `idx`.
+fn row_rng(seed: u64, idx: usize) -> SmallRng {
+    // SplitMix64, such that the streams of seeds that differ by a few don't overlap.
+    let mut z = seed.wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^= z >> 31;
+    SmallRng::seed_from_u64(z.wrapping_add(idx as u64))
+}
+
+/// The parameter of every row, broadcast if it is a single value.
+fn param(s: &Series, n: usize, name: &str) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(
+        s.len() == 1 || s.len() == n,
+        ShapeMismatch: "`{}` must be a single value or have {} values, got {}", name, n, s.len()
+    );
+    polars_ensure!(
+        s.dtype().is_numeric() || s.dtype().is_null(),
+        InvalidOperation: "`{}` must be numeric, got {}", name, s.dtype()
+    );
+    let ca = s.cast(&DataType::Float64)?.f64()?.clone();
+    Ok(if ca.len() == 1 {
+        ca.new_from_index(0, n)
+    } else {
+        ca
+    })
+}
+
+/// Draw `n` values from `distribution`. The rows where a parameter is null are null.
+///
+/// The parameters are single values or have a value for every row. Without a `seed`, the
+/// seed is drawn from the global random generator.
+pub fn sample_distribution(
+    name: &str,
+    distribution: RandomDistribution,
+    params: &[Series],
+    n: usize,
+    seed: Option<u64>,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        params.len() == distribution.n_params(),
+        ComputeError: "{:?} distribution expects {} parameters, got {}",
+        distribution, distribution.n_params(), params.len()
+    );
+    let seed = seed.unwrap_or_else(get_global_random_u64);
+
+    let out = match distribution {
+        RandomDistribution::Uniform => {
+            let low = param(&params[0], n, "low")?;
+            let high = param(&params[1], n, "high")?;
+            let ca: Float64Chunked = low
+                .into_iter()
+                .zip(high.into_iter())
+                .enumerate()
+                .map(|(idx, (low, high))| {
+                    let (low, high) = match (low, high) {
+                        (Some(low), Some(high)) => (low, high),
+                        _ => return Ok(None),
+                    };
+                    polars_ensure!(
+                        low <= high,
+                        ComputeError: "`low` must not exceed `high`, got {} and {}", low, high
+                    );
+                    let u: f64 = row_rng(seed, idx).gen();
+                    Ok(Some(low + (high - low) * u))
+                })
+                .collect::<PolarsResult<_>>()?;
+            ca.into_series()
+        },
+        RandomDistribution::Normal => {
+            let mean = param(&params[0], n, "mean")?;
+            let std = param(&params[1], n, "std")?;
+            let ca: Float64Chunked = mean
+                .into_iter()
+                .zip(std.into_iter())
+                .enumerate()
+                .map(|(idx, (mean, std))| {
+                    let (mean, std) = match (mean, std) {
+                        (Some(mean), Some(std)) => (mean, std),
+                        _ => return Ok(None),
+                    };
+                    polars_ensure!(
+                        std >= 0.0,
+                        ComputeError: "`std` must be non-negative, got {}", std
+                    );
+                    let z: f64 = row_rng(seed, idx).sample(StandardNormal);
+                    Ok(Some(mean + std * z))
+                })
+                .collect::<PolarsResult<_>>()?;
+            ca.into_series()
+        },
+        RandomDistribution::Poisson => {
+            let lambda = param(&params[0], n, "lambda")?;
+            let ca: Int64Chunked = lambda
+                .into_iter()
+                .enumerate()
+                .map(|(idx, lambda)| {
+                    let Some(lambda) = lambda else {
+                        return Ok(None);
+                    };
+                    polars_ensure!(
+                        lambda >= 0.0 && lambda.is_finite(),
+                        ComputeError: "`lambda` must be non-negative and finite, got {}", lambda
+                    );
+                    if lambda == 0.0 {
+                        return Ok(Some(0));
+                    }
+                    let dist = Poisson::new(lambda).map_err(to_compute_err)?;
+                    let v: f64 = row_rng(seed, idx).sample(dist);
+                    Ok(Some(v as i64))
+                })
+                .collect::<PolarsResult<_>>()?;
+            ca.into_series()
+        },
+    };
+    Ok(out.with_name(name))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sample_distribution_rows() -> PolarsResult<()> {
+        let params = [Series::new("", &[0.0]), Series::new("", &[1.0])];
+        let a = sample_distribution("a", RandomDistribution::Uniform, &params, 100, Some(7))?;
+        // A row's value doesn't depend on the number of rows.
+        let b = sample_distribution("a", RandomDistribution::Uniform, &params, 10, Some(7))?;
+        assert!(a.head(Some(10)).equals(&b));
+        assert!(a
+            .f64()?
+            .into_no_null_iter()
+            .all(|v| (0.0..1.0).contains(&v)));
+
+        let other = sample_distribution("a", RandomDistribution::Uniform, &params, 10, Some(8))?;
+        assert!(!other.equals(&b));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_distribution_params() -> PolarsResult<()> {
+        let lambda = Series::new("", &[Some(0.0), None, Some(3.0)]);
+        let out = sample_distribution("p", RandomDistribution::Poisson, &[lambda], 3, Some(0))?;
+        assert_eq!(out.dtype(), &DataType::Int64);
+        assert_eq!(out.i64()?.get(0), Some(0));
+        assert_eq!(out.null_count(), 1);
+
+        let std = Series::new("", &[-1.0]);
+        let mean = Series::new("", &[0.0]);
+        assert!(
+            sample_distribution("n", RandomDistribution::Normal, &[mean, std], 3, None).is_err()
+        );
+        Ok(())
+    }
+}
@@ -25,6 +25,9 @@ pub enum RankMethod {
 pub struct RankOptions {
     pub method: RankMethod,
     pub descending: bool,
+    /// Rank strings by this collation instead of by their bytes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub collation: Option<Collation>,
 }
 
 impl Default for RankOptions {
@@ -32,6 +35,7 @@ impl Default for RankOptions {
         Self {
             method: RankMethod::Dense,
             descending: false,
+            collation: None,
         }
     }
 }
@@ -184,7 +188,15 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
 
 pub trait SeriesRank: SeriesSealed {
     fn rank(&self, options: RankOptions, seed: Option<u64>) -> Series {
-        rank(self.as_series(), options.method, options.descending, seed)
+        let s = self.as_series();
+        match (options.collation, s.dtype()) {
+            // Strings that are equal under the collation have equal keys, so they are ties.
+            (Some(collation), DataType::String) => {
+                let keys = s.str().unwrap().collation_keys(collation).into_series();
+                rank(&keys, options.method, options.descending, seed)
+            },
+            _ => rank(s, options.method, options.descending, seed),
+        }
     }
 }
 
@@ -1,61 +1,234 @@
-use num_traits::pow::Pow;
+use num_traits::{NumCast, ToPrimitive};
 use polars_core::prelude::*;
-use polars_core::with_match_physical_numeric_polars_type;
+pub use polars_core::series::ops::RoundMode;
+use polars_core::with_match_physical_integer_polars_type;
 
 use crate::series::ops::SeriesSealed;
 
+/// The powers of ten that are exactly representable as a `f64`.
+const EXACT_POW10: [f64; 23] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+    1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+];
+
+/// Multiply `value` by `10^exp`.
+///
+/// Up to `10^22` the power is exact and the result is a single correctly rounded operation, so
+/// it doesn't depend on the `pow` implementation of the platform.
+fn scale_pow10(value: f64, exp: i32) -> f64 {
+    match EXACT_POW10.get(exp.unsigned_abs() as usize) {
+        Some(pow) if exp >= 0 => value * pow,
+        Some(pow) => value / pow,
+        None => value * 10.0_f64.powi(exp),
+    }
+}
+
+fn round_f64(value: f64, mode: RoundMode) -> f64 {
+    match mode {
+        RoundMode::HalfAway => value.round(),
+        RoundMode::HalfEven => value.round_ties_even(),
+    }
+}
+
+/// Round `value * 10^exp` to an integer, or return `None` if it overflows.
+///
+/// Scaling may round a value that is close to a tie onto the tie, e.g. `0.35 * 10` is `3.5`
+/// while `0.35` is slightly below its decimal. The exact error of the scaling decides those.
+fn round_scaled(value: f64, exp: i32, mode: RoundMode) -> Option<f64> {
+    let scaled = scale_pow10(value, exp);
+    if !scaled.is_finite() {
+        return None;
+    }
+    let rounded = round_f64(scaled, mode);
+    if (scaled - scaled.trunc()).abs() != 0.5 {
+        return Some(rounded);
+    }
+    let error = match EXACT_POW10.get(exp.unsigned_abs() as usize) {
+        // Both are exact, see "error-free transformations" of floating point arithmetic.
+        Some(pow) if exp >= 0 => value.mul_add(*pow, -scaled),
+        Some(pow) => (-scaled).mul_add(*pow, value),
+        None => 0.0,
+    };
+    Some(if error > 0.0 {
+        scaled.ceil()
+    } else if error < 0.0 {
+        scaled.floor()
+    } else {
+        rounded
+    })
+}
+
+fn round_f64_decimals(value: f64, decimals: u32, mode: RoundMode) -> f64 {
+    if decimals == 0 {
+        return round_f64(value, mode);
+    }
+    let decimals = decimals.min(i32::MAX as u32) as i32;
+    match round_scaled(value, decimals, mode) {
+        Some(rounded) => scale_pow10(rounded, -decimals),
+        // The value has no digits beyond this many decimals.
+        None => value,
+    }
+}
+
+/// The exponent of the most significant decimal digit of a finite, non-zero value.
+fn decimal_exponent(value: f64) -> i32 {
+    let value = value.abs();
+    let exp = value.log10().floor() as i32;
+    // `log10` may be off by one close to powers of ten.
+    if scale_pow10(1.0, exp) > value {
+        exp - 1
+    } else if scale_pow10(1.0, exp + 1) <= value {
+        exp + 1
+    } else {
+        exp
+    }
+}
+
+/// Round `value` to a multiple of `multiple`, which must be positive.
+fn round_to_multiple(value: i128, multiple: i128, mode: RoundMode) -> i128 {
+    let quotient = value / multiple;
+    let remainder = (value % multiple).abs();
+    let away = match remainder.cmp(&(multiple - remainder)) {
+        std::cmp::Ordering::Less => false,
+        std::cmp::Ordering::Greater => true,
+        std::cmp::Ordering::Equal => match mode {
+            RoundMode::HalfAway => true,
+            RoundMode::HalfEven => quotient % 2 != 0,
+        },
+    };
+    if away {
+        (quotient + value.signum()) * multiple
+    } else {
+        quotient * multiple
+    }
+}
+
+/// The number of decimal digits of `value`, ignoring its sign.
+fn decimal_digits(value: i128) -> u32 {
+    value
+        .unsigned_abs()
+        .checked_ilog10()
+        .map_or(1, |log| log + 1)
+}
+
+/// Round an integer to `digits` significant figures.
+fn round_i128_sig_figs(value: i128, digits: u32) -> i128 {
+    let n_digits = decimal_digits(value);
+    if n_digits <= digits {
+        value
+    } else {
+        round_to_multiple(value, 10_i128.pow(n_digits - digits), RoundMode::HalfAway)
+    }
+}
+
+fn round_sig_figs_integer<T>(ca: &ChunkedArray<T>, digits: u32) -> ChunkedArray<T>
+where
+    T: PolarsIntegerType,
+{
+    // Values that no longer fit the type after rounding become null.
+    ca.apply(|opt_v| opt_v.and_then(|v| NumCast::from(round_i128_sig_figs(v.to_i128()?, digits))))
+}
+
 pub trait RoundSeries: SeriesSealed {
     /// Round underlying floating point array to given decimal.
-    fn round(&self, decimals: u32) -> PolarsResult<Series> {
+    ///
+    /// Integers are returned as is and decimals are rounded to at most `decimals` digits
+    /// after the point, keeping their scale.
+    fn round(&self, decimals: u32, mode: RoundMode) -> PolarsResult<Series> {
         let s = self.as_series();
 
         if let Ok(ca) = s.f32() {
-            return if decimals == 0 {
-                let s = ca.apply_values(|val| val.round()).into_series();
-                Ok(s)
-            } else {
-                // Note we do the computation on f64 floats to not lose precision
-                // when the computation is done, we cast to f32
-                let multiplier = 10.0.pow(decimals as f64);
-                let s = ca
-                    .apply_values(|val| ((val as f64 * multiplier).round() / multiplier) as f32)
-                    .into_series();
-                Ok(s)
-            };
+            // Note we do the computation on f64 floats to not lose precision
+            // when the computation is done, we cast to f32
+            let s = ca
+                .apply_values(|val| round_f64_decimals(val as f64, decimals, mode) as f32)
+                .into_series();
+            return Ok(s);
         }
         if let Ok(ca) = s.f64() {
-            return if decimals == 0 {
-                let s = ca.apply_values(|val| val.round()).into_series();
-                Ok(s)
-            } else {
-                let multiplier = 10.0.pow(decimals as f64);
-                let s = ca
-                    .apply_values(|val| (val * multiplier).round() / multiplier)
-                    .into_series();
-                Ok(s)
-            };
+            let s = ca
+                .apply_values(|val| round_f64_decimals(val, decimals, mode))
+                .into_series();
+            return Ok(s);
+        }
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = s.decimal() {
+            let precision = ca.precision();
+            let scale = ca.scale();
+            if decimals as usize >= scale {
+                return Ok(s.clone());
+            }
+            let multiple = 10_i128.pow(scale as u32 - decimals);
+            let out = ca
+                .as_ref()
+                .apply_values(|v| round_to_multiple(v, multiple, mode));
+            return Ok(out.into_decimal_unchecked(precision, scale).into_series());
         }
 
         polars_ensure!(s.dtype().is_numeric(), InvalidOperation: "round can only be used on numeric types" );
         Ok(s.clone())
     }
 
+    /// Change the scale of decimals to `scale`, rounding dropped fractional digits with `mode`.
+    ///
+    /// Integers are converted to decimals first. Values that no longer fit their precision
+    /// raise an error.
+    #[allow(unused_variables)]
+    fn rescale(&self, scale: usize, mode: RoundMode) -> PolarsResult<Series> {
+        let s = self.as_series();
+        #[cfg(feature = "dtype-decimal")]
+        {
+            use polars_core::chunked_array::arithmetic::integer_decimal_dtype;
+
+            if let Some(dtype) = integer_decimal_dtype(s.dtype()) {
+                return s.strict_cast(&dtype)?.rescale(scale, mode);
+            }
+            if let Ok(ca) = s.decimal() {
+                return Ok(ca.rescale(scale, mode)?.into_series());
+            }
+        }
+        polars_bail!(InvalidOperation: "rescale can only be used on decimal and integer types, got {}", s.dtype())
+    }
+
+    /// Round to `digits` significant figures, rounding halfway values away from zero.
+    ///
+    /// Integers and decimals are rounded exactly.
     fn round_sig_figs(&self, digits: i32) -> PolarsResult<Series> {
         let s = self.as_series();
         polars_ensure!(digits >= 1, InvalidOperation: "digits must be an integer >= 1");
-        polars_ensure!(s.dtype().is_numeric(), InvalidOperation: "round_sig_figs can only be used on numeric types" );
-        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
-            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
-            let s = ca.apply_values(|value| {
-                let value = value as f64;
-                if value == 0.0 {
-                    return value as <$T as PolarsNumericType>::Native;
-                }
-                let magnitude = 10.0_f64.powi(digits - 1 - value.abs().log10().floor() as i32);
-                ((value * magnitude).round() / magnitude) as <$T as PolarsNumericType>::Native
-            }).into_series();
-            return Ok(s);
-        });
+        polars_ensure!(s.dtype().is_numeric() || s.dtype().is_decimal(), InvalidOperation: "round_sig_figs can only be used on numeric types" );
+        let sig_figs = |value: f64| {
+            if value == 0.0 || !value.is_finite() {
+                return value;
+            }
+            let exp = digits - 1 - decimal_exponent(value);
+            match round_scaled(value, exp, RoundMode::HalfAway) {
+                Some(rounded) => scale_pow10(rounded, -exp),
+                None => value,
+            }
+        };
+
+        match s.dtype() {
+            DataType::Float32 => {
+                let ca = s.f32().unwrap();
+                Ok(ca.apply_values(|v| sig_figs(v as f64) as f32).into_series())
+            },
+            DataType::Float64 => Ok(s.f64().unwrap().apply_values(sig_figs).into_series()),
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => {
+                let ca = s.decimal().unwrap();
+                let out = ca
+                    .as_ref()
+                    .apply_values(|v| round_i128_sig_figs(v, digits as u32));
+                Ok(out
+                    .into_decimal_unchecked(ca.precision(), ca.scale())
+                    .into_series())
+            },
+            dt => with_match_physical_integer_polars_type!(dt, |$T| {
+                let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                Ok(round_sig_figs_integer(ca, digits as u32).into_series())
+            }),
+        }
     }
 
     /// Floor underlying floating point array to the lowest integers smaller or equal to the float value.
@@ -102,8 +275,50 @@ mod test {
     #[test]
     fn test_round_series() {
         let series = Series::new("a", &[1.003, 2.23222, 3.4352]);
-        let out = series.round(2).unwrap();
+        let out = series.round(2, RoundMode::HalfAway).unwrap();
         let ca = out.f64().unwrap();
         assert_eq!(ca.get(0), Some(1.0));
     }
+
+    #[test]
+    fn test_round_modes() {
+        let series = Series::new("a", &[0.5, 1.5, 2.5, -0.5, -2.5, 0.25, 0.35]);
+        let out = series.round(0, RoundMode::HalfEven).unwrap();
+        let expected = [0.0, 2.0, 2.0, -0.0, -2.0, 0.0, 0.0];
+        assert_eq!(Vec::from(out.f64().unwrap()), expected.map(Some));
+        let out = series.round(0, RoundMode::HalfAway).unwrap();
+        let expected = [1.0, 2.0, 3.0, -1.0, -3.0, 0.0, 0.0];
+        assert_eq!(Vec::from(out.f64().unwrap()), expected.map(Some));
+
+        // 0.25 is a tie, 0.35 is slightly below its decimal.
+        let out = series.round(1, RoundMode::HalfEven).unwrap();
+        assert_eq!(out.f64().unwrap().get(5), Some(0.2));
+        assert_eq!(out.f64().unwrap().get(6), Some(0.3));
+        let out = series.round(1, RoundMode::HalfAway).unwrap();
+        assert_eq!(out.f64().unwrap().get(5), Some(0.3));
+        assert_eq!(out.f64().unwrap().get(6), Some(0.3));
+        let out = series.round(400, RoundMode::HalfEven).unwrap();
+        assert!(out.equals(&series));
+    }
+
+    #[test]
+    fn test_round_to_multiple() {
+        for (value, away, even) in [(15, 20, 20), (25, 30, 20), (-25, -30, -20), (24, 20, 20)] {
+            assert_eq!(round_to_multiple(value, 10, RoundMode::HalfAway), away);
+            assert_eq!(round_to_multiple(value, 10, RoundMode::HalfEven), even);
+        }
+    }
+
+    #[test]
+    fn test_round_sig_figs_integer() {
+        let series = Series::new("a", &[i64::MAX, 1234, -1250, 0]);
+        let out = series.round_sig_figs(2).unwrap();
+        let expected = [9200000000000000000, 1200, -1300, 0];
+        assert_eq!(Vec::from(out.i64().unwrap()), expected.map(Some));
+
+        // 250 rounds to 300, which doesn't fit an u8.
+        let series = Series::new("a", &[250u8, 149]);
+        let out = series.round_sig_figs(1).unwrap();
+        assert_eq!(Vec::from(out.u8().unwrap()), [None, Some(100)]);
+    }
 }
@@ -0,0 +1,244 @@
+//! Sketches of approximate aggregations that can be stored and merged.
+//!
+//! A sketch is serialized to a single binary value. The sketches of different frames or
+//! partitions can be merged with [`merge_sketches`] and finalized later, e.g. to combine the
+//! stored sketches of every day into the number of unique values of a month.
+//!
+//! The serialized form starts with a kind and a format version byte, followed by the state of
+//! the sketch. Only sketches of the same kind can be merged.
+use polars_core::prelude::*;
+
+#[cfg(feature = "approx_quantile")]
+use super::approx_quantile::approx_quantile_digest;
+use super::approx_unique::hyperloglog;
+use crate::series::ops::approx_algo::HyperLogLog;
+#[cfg(feature = "approx_quantile")]
+use crate::series::ops::approx_algo::TDigest;
+
+const FORMAT_VERSION: u8 = 1;
+const KIND_HYPERLOGLOG: u8 = 1;
+#[cfg(feature = "approx_quantile")]
+const KIND_TDIGEST: u8 = 2;
+
+/// The state of an approximate aggregation.
+#[derive(Debug)]
+pub enum Sketch {
+    /// The state of [`approx_n_unique`](crate::series::approx_n_unique).
+    HyperLogLog(HyperLogLog<[u8]>),
+    /// The state of [`approx_quantile`](crate::series::approx_quantile).
+    #[cfg(feature = "approx_quantile")]
+    TDigest(TDigest),
+}
+
+impl Sketch {
+    fn kind(&self) -> &'static str {
+        match self {
+            Sketch::HyperLogLog(_) => "approx_n_unique",
+            #[cfg(feature = "approx_quantile")]
+            Sketch::TDigest(_) => "approx_quantile",
+        }
+    }
+
+    pub fn to_bytes(&mut self) -> Vec<u8> {
+        let (kind, state) = match self {
+            Sketch::HyperLogLog(hll) => (KIND_HYPERLOGLOG, hll.as_ref().to_vec()),
+            #[cfg(feature = "approx_quantile")]
+            Sketch::TDigest(digest) => (KIND_TDIGEST, digest.to_bytes()),
+        };
+        let mut out = Vec::with_capacity(state.len() + 2);
+        out.extend_from_slice(&[kind, FORMAT_VERSION]);
+        out.extend_from_slice(&state);
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> PolarsResult<Self> {
+        let invalid = || polars_err!(ComputeError: "invalid sketch of {} bytes", bytes.len());
+        let [kind, version, state @ ..] = bytes else {
+            return Err(invalid());
+        };
+        polars_ensure!(
+            *version == FORMAT_VERSION,
+            ComputeError: "unsupported sketch format version {}, expected {}",
+            version, FORMAT_VERSION
+        );
+        let sketch = match *kind {
+            KIND_HYPERLOGLOG => HyperLogLog::from_registers(state).map(Sketch::HyperLogLog),
+            #[cfg(feature = "approx_quantile")]
+            KIND_TDIGEST => TDigest::from_bytes(state).map(Sketch::TDigest),
+            _ => None,
+        };
+        sketch.ok_or_else(invalid)
+    }
+
+    /// Merge the state of `other` into this sketch.
+    pub fn merge(&mut self, other: &Sketch) -> PolarsResult<()> {
+        match (self, other) {
+            (Sketch::HyperLogLog(hll), Sketch::HyperLogLog(other)) => hll.merge(other),
+            #[cfg(feature = "approx_quantile")]
+            (Sketch::TDigest(digest), Sketch::TDigest(other)) => digest.merge(other),
+            #[allow(unreachable_patterns)]
+            (lhs, rhs) => polars_bail!(
+                ComputeError: "cannot merge a sketch of `{}` with a sketch of `{}`",
+                lhs.kind(), rhs.kind()
+            ),
+        }
+        Ok(())
+    }
+}
+
+fn sketch_to_series(name: &str, mut sketch: Sketch) -> Series {
+    BinaryChunked::from_slice(name, &[sketch.to_bytes()]).into_series()
+}
+
+fn sketches(s: &Series) -> PolarsResult<impl Iterator<Item = PolarsResult<Option<Sketch>>> + '_> {
+    polars_ensure!(
+        s.dtype() == &DataType::Binary,
+        InvalidOperation: "expected sketches of dtype binary, got {}", s.dtype()
+    );
+    Ok(s.binary()
+        .unwrap()
+        .iter()
+        .map(|opt_bytes| opt_bytes.map(Sketch::from_bytes).transpose()))
+}
+
+/// Sketch the number of unique values of `s` into a single binary value.
+///
+/// See [`approx_n_unique_from_sketch`] to get the estimate from the sketch.
+pub fn approx_n_unique_sketch(s: &Series) -> PolarsResult<Series> {
+    let sketch = Sketch::HyperLogLog(hyperloglog(s)?);
+    Ok(sketch_to_series(s.name(), sketch))
+}
+
+/// Sketch the distribution of the non-null values of `s` into a single binary value.
+///
+/// See [`approx_quantile_from_sketch`] to get quantiles from the sketch.
+#[cfg(feature = "approx_quantile")]
+pub fn approx_quantile_sketch(s: &Series, accuracy: u32) -> PolarsResult<Series> {
+    let sketch = Sketch::TDigest(approx_quantile_digest(s, accuracy)?);
+    Ok(sketch_to_series(s.name(), sketch))
+}
+
+/// Merge all sketches of `s` into a single binary value. Null values are ignored and the result
+/// is null if there are no sketches.
+///
+/// # Example
+///
+/// ```
+/// # use polars_core::prelude::*;
+/// # use polars_ops::prelude::*;
+/// let monday = approx_n_unique_sketch(&Series::new("s", [1, 2, 3])).unwrap();
+/// let tuesday = approx_n_unique_sketch(&Series::new("s", [3, 4])).unwrap();
+///
+/// let mut sketches = monday.clone();
+/// sketches.append(&tuesday).unwrap();
+/// let merged = merge_sketches(&sketches).unwrap();
+///
+/// let n_unique = approx_n_unique_from_sketch(&merged).unwrap();
+/// assert_eq!(n_unique.idx().unwrap().get(0), Some(4));
+/// ```
+pub fn merge_sketches(s: &Series) -> PolarsResult<Series> {
+    let mut merged: Option<Sketch> = None;
+    for sketch in sketches(s)? {
+        let Some(sketch) = sketch? else {
+            continue;
+        };
+        match &mut merged {
+            Some(merged) => merged.merge(&sketch)?,
+            None => merged = Some(sketch),
+        }
+    }
+    Ok(match merged {
+        Some(sketch) => sketch_to_series(s.name(), sketch),
+        None => Series::full_null(s.name(), 1, &DataType::Binary),
+    })
+}
+
+/// Estimate the number of unique values of every sketch of `s`.
+pub fn approx_n_unique_from_sketch(s: &Series) -> PolarsResult<Series> {
+    let ca: IdxCa = sketches(s)?
+        .map(|sketch| match sketch? {
+            Some(Sketch::HyperLogLog(hll)) => Ok(Some(hll.count() as IdxSize)),
+            #[allow(unreachable_patterns)]
+            Some(sketch) => polars_bail!(
+                ComputeError: "expected a sketch of `approx_n_unique`, got a sketch of `{}`",
+                sketch.kind()
+            ),
+            None => Ok(None),
+        })
+        .collect::<PolarsResult<_>>()?;
+    Ok(ca.with_name(s.name()).into_series())
+}
+
+/// Estimate the `quantile` of every sketch of `s`.
+#[cfg(feature = "approx_quantile")]
+pub fn approx_quantile_from_sketch(s: &Series, quantile: f64) -> PolarsResult<Series> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "`quantile` should be between 0.0 and 1.0",
+    );
+    let ca: Float64Chunked = sketches(s)?
+        .map(|sketch| match sketch? {
+            Some(Sketch::TDigest(mut digest)) => Ok(digest.quantile(quantile)),
+            Some(sketch) => polars_bail!(
+                ComputeError: "expected a sketch of `approx_quantile`, got a sketch of `{}`",
+                sketch.kind()
+            ),
+            None => Ok(None),
+        })
+        .collect::<PolarsResult<_>>()?;
+    Ok(ca.with_name(s.name()).into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_merge_sketches() {
+        let a = approx_n_unique_sketch(&Series::new("a", (0..1000).collect::<Vec<i32>>())).unwrap();
+        let b =
+            approx_n_unique_sketch(&Series::new("a", (500..1500).collect::<Vec<i32>>())).unwrap();
+        let mut sketches = a.clone();
+        sketches
+            .append(&Series::full_null("a", 1, &DataType::Binary))
+            .unwrap();
+        sketches.append(&b).unwrap();
+
+        let merged = merge_sketches(&sketches).unwrap();
+        let n_unique = approx_n_unique_from_sketch(&merged).unwrap();
+        let n_unique = n_unique.idx().unwrap().get(0).unwrap();
+        assert!((1450..1550).contains(&n_unique), "{n_unique}");
+
+        let empty = Series::full_null("a", 2, &DataType::Binary);
+        assert_eq!(merge_sketches(&empty).unwrap().null_count(), 1);
+    }
+
+    #[test]
+    fn test_invalid_sketches() {
+        let s = Series::new("a", [&b"\x01\x01\x00"[..]]);
+        assert!(approx_n_unique_from_sketch(&s).is_err());
+        let s = Series::new("a", [&b"\x01\x02"[..]]);
+        assert!(merge_sketches(&s).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "approx_quantile")]
+    fn test_merge_quantile_sketches() {
+        let a = Series::new("a", (0..500).collect::<Vec<i32>>());
+        let b = Series::new("a", (500..1000).collect::<Vec<i32>>());
+        let mut sketches = approx_quantile_sketch(&a, 100).unwrap();
+        sketches
+            .append(&approx_quantile_sketch(&b, 100).unwrap())
+            .unwrap();
+
+        let merged = merge_sketches(&sketches).unwrap();
+        let median = approx_quantile_from_sketch(&merged, 0.5).unwrap();
+        let median = median.f64().unwrap().get(0).unwrap();
+        assert!((median - 499.5).abs() < 5.0, "{median}");
+
+        sketches
+            .append(&approx_n_unique_sketch(&a).unwrap())
+            .unwrap();
+        assert!(merge_sketches(&sketches).is_err());
+    }
+}
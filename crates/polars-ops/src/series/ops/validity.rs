@@ -0,0 +1,110 @@
+use arrow::array::BooleanArray;
+use arrow::bitmap::Bitmap;
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+
+/// The validity of `s` as a mask that is `true` where a value is valid.
+///
+/// The mask shares the validity buffers of the physical arrays, only chunks without nulls
+/// allocate a mask. Structs have no validity of their own, their mask is that of
+/// [`Series::is_not_null`].
+pub fn is_valid_mask(s: &Series) -> BooleanChunked {
+    match s.dtype() {
+        DataType::Null => BooleanChunked::full(s.name(), false, s.len()),
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(_) => s.is_not_null(),
+        _ => {
+            let chunks = s.chunks().iter().map(|arr| {
+                let validity = arr
+                    .validity()
+                    .cloned()
+                    .unwrap_or_else(|| Bitmap::new_with_value(true, arr.len()));
+                BooleanArray::from_data_default(validity, None)
+            });
+            BooleanChunked::from_chunk_iter(s.name(), chunks)
+        },
+    }
+}
+
+/// Replace the validity of `s` by `mask`, without touching the values.
+///
+/// The result is null where the mask is `false` or null. Where it is `true`, the value is valid
+/// even if it was null before; such a value is whatever the physical array holds in that slot,
+/// which is usually zero or empty. A mask of length 1 is broadcast.
+pub fn with_validity(s: &Series, mask: &BooleanChunked) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Null => {
+            polars_bail!(InvalidOperation: "cannot set the validity of a Series of dtype Null")
+        },
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(_) => polars_bail!(
+            InvalidOperation: "cannot set the validity of a Struct; set the validity of its fields"
+        ),
+        _ => {},
+    }
+    let mask = if mask.len() == 1 && s.len() != 1 {
+        BooleanChunked::full(mask.name(), mask.get(0).unwrap_or(false), s.len())
+    } else {
+        mask.clone()
+    };
+    polars_ensure!(
+        mask.len() == s.len(),
+        ShapeMismatch: "validity mask has length {} but the Series has length {}",
+        mask.len(), s.len()
+    );
+
+    let (mut out, mask) = if s.chunk_lengths().eq(mask.chunk_lengths()) {
+        (s.clone(), mask)
+    } else {
+        (s.rechunk(), mask.rechunk())
+    };
+    let validities = mask.downcast_iter().map(|arr| match arr.validity() {
+        Some(validity) => arr.values() & validity,
+        None => arr.values().clone(),
+    });
+    // SAFETY: only the validity of the arrays changes, their types and lengths stay the same.
+    unsafe {
+        for (arr, validity) in out.chunks_mut().iter_mut().zip(validities) {
+            *arr = arr.with_validity(Some(validity));
+        }
+    }
+    out.compute_len();
+    // Values that become null or valid can break the order.
+    out.set_sorted_flag(IsSorted::Not);
+    Ok(out)
+}
+
+/// Set `s` to null where `other` is null, so that the result is only valid where both are.
+///
+/// `other` can have any type. If it has length 1, the result is all null if it is null.
+pub fn coalesce_validity(s: &Series, other: &Series) -> PolarsResult<Series> {
+    if other.len() == 1 && s.len() != 1 {
+        return Ok(if other.null_count() == 0 {
+            s.clone()
+        } else {
+            Series::full_null(s.name(), s.len(), s.dtype())
+        });
+    }
+    polars_ensure!(
+        other.len() == s.len(),
+        ShapeMismatch: "cannot coalesce the validity of a Series of length {} with one of length {}",
+        s.len(), other.len()
+    );
+    if other.null_count() == 0 || s.dtype() == &DataType::Null {
+        return Ok(s.clone());
+    }
+    #[cfg(feature = "dtype-struct")]
+    if let DataType::Struct(_) = s.dtype() {
+        // Structs have no validity of their own, their fields are set to null.
+        let fields = s
+            .struct_()?
+            .fields()
+            .iter()
+            .map(|field| coalesce_validity(field, other))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        return Ok(StructChunked::new(s.name(), &fields)?.into_series());
+    }
+
+    let mask = &is_valid_mask(s) & &is_valid_mask(other);
+    with_validity(s, &mask)
+}
@@ -3,8 +3,10 @@ use num_traits::Bounded;
 use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
-use polars_core::with_match_physical_numeric_polars_type;
+use polars_core::utils::_split_offsets;
+use polars_core::{with_match_physical_numeric_polars_type, POOL};
 use polars_utils::total_ord::TotalOrd;
+use rayon::prelude::*;
 
 use crate::series::ops::SeriesSealed;
 
@@ -138,6 +140,91 @@ pub trait SeriesMethods: SeriesSealed {
         };
         Ok(cmp_op(&s1, &s2)?.all())
     }
+
+    /// Checks if the non-null values of a [`Series`] never decrease, or never increase if
+    /// `descending`. If `strict`, consecutive values must also differ. Nulls are ignored.
+    fn is_monotonic(&self, descending: bool, strict: bool) -> PolarsResult<bool> {
+        let s = self.as_series();
+        let flagged = match s.is_sorted_flag() {
+            IsSorted::Ascending => !descending,
+            IsSorted::Descending => descending,
+            IsSorted::Not => false,
+        };
+        if flagged && !strict {
+            return Ok(true);
+        }
+        let s = s.drop_nulls();
+        Ok(first_out_of_order(&s, descending, strict)?.is_none())
+    }
+
+    /// Verifies that a [`Series`] may be flagged as sorted: its non-null values are sorted and
+    /// its nulls are all at the start or all at the end. The check runs in parallel and ignores
+    /// the current sorted flag.
+    fn check_sorted(&self, descending: bool) -> PolarsResult<()> {
+        let s = self.as_series();
+        let len = s.len();
+        let null_count = s.null_count();
+        let offset = if null_count == 0 || s.slice(0, null_count).null_count() == null_count {
+            null_count
+        } else {
+            polars_ensure!(
+                s.slice((len - null_count) as i64, null_count).null_count() == null_count,
+                ComputeError: "series '{}' is not sorted: its nulls are not all at the start or at the end",
+                s.name()
+            );
+            0
+        };
+        let values = s.slice(offset as i64, len - null_count);
+        let Some(i) = first_out_of_order(&values, descending, false)? else {
+            return Ok(());
+        };
+        polars_bail!(
+            ComputeError:
+            "series '{}' is not sorted in {} order; value {} at index {} follows {}",
+            s.name(),
+            if descending { "descending" } else { "ascending" },
+            values.get(i + 1)?,
+            offset + i + 1,
+            values.get(i)?
+        )
+    }
+}
+
+/// Returns the first index `i` at which `s[i + 1]` is out of order with respect to `s[i]`.
+/// `s` must not contain nulls. The comparisons are split over the thread pool.
+fn first_out_of_order(s: &Series, descending: bool, strict: bool) -> PolarsResult<Option<usize>> {
+    if s.len() < 2 {
+        return Ok(None);
+    }
+
+    // Row encoding preserves the order, so structs can be compared as binary.
+    #[cfg(feature = "dtype-struct")]
+    if matches!(s.dtype(), DataType::Struct(_)) {
+        let encoded = _get_rows_encoded_ca("", &[s.clone()], &[false], &[false])?;
+        return first_out_of_order(&encoded.into_series(), descending, strict);
+    }
+
+    // Each part compares its values to their successors, so the parts overlap by one value.
+    let n_comparisons = s.len() - 1;
+    let parts = _split_offsets(n_comparisons, POOL.current_num_threads());
+    let firsts = POOL.install(|| {
+        parts
+            .into_par_iter()
+            .map(|(offset, len)| {
+                let prev = s.slice(offset as i64, len);
+                let next = s.slice(offset as i64 + 1, len);
+                let out_of_order = match (descending, strict) {
+                    (false, false) => prev.gt(&next),
+                    (false, true) => prev.gt_eq(&next),
+                    (true, false) => prev.lt(&next),
+                    (true, true) => prev.lt_eq(&next),
+                }?;
+                let first = out_of_order.into_iter().position(|v| v == Some(true));
+                Ok(first.map(|i| offset + i))
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+    Ok(firsts.into_iter().flatten().next())
 }
 
 fn check_cmp<T: NumericNative, Cmp: Fn(&T, &T) -> bool>(
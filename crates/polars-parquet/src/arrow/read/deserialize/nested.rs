@@ -1,5 +1,6 @@
 use arrow::array::PrimitiveArray;
 use arrow::match_integer_type;
+use arrow::types::f16;
 use ethnum::I256;
 use polars_error::polars_bail;
 
@@ -195,6 +196,18 @@ where
                 |x: f32| x,
             ))
         },
+        Primitive(Float16) => {
+            init.push(InitNested::Primitive(field.is_nullable));
+            types.pop();
+            primitive(primitive::NestedIter::new(
+                columns.pop().unwrap(),
+                init,
+                field.data_type().clone(),
+                num_rows,
+                chunk_size,
+                f16::from_f32,
+            ))
+        },
         Primitive(Float64) => {
             init.push(InitNested::Primitive(field.is_nullable));
             types.pop();
@@ -1,7 +1,7 @@
 use arrow::array::{Array, DictionaryKey, MutablePrimitiveArray, PrimitiveArray};
 use arrow::datatypes::{ArrowDataType, IntervalUnit, TimeUnit};
 use arrow::match_integer_type;
-use arrow::types::{days_ms, i256, NativeType};
+use arrow::types::{days_ms, f16, i256, NativeType};
 use ethnum::I256;
 use polars_error::{polars_bail, PolarsResult};
 
@@ -324,6 +324,13 @@ pub fn page_iter_to_arrays<'a, I: PagesIter + 'a>(
             chunk_size,
             |x: f32| x,
         ))),
+        (PhysicalType::Float, Float16) => dyn_iter(iden(primitive::Iter::new(
+            pages,
+            data_type,
+            num_rows,
+            chunk_size,
+            f16::from_f32,
+        ))),
         (PhysicalType::Double, Float64) => dyn_iter(iden(primitive::Iter::new(
             pages,
             data_type,
@@ -629,6 +636,13 @@ fn dict_read<'a, K: DictionaryKey, I: PagesIter + 'a>(
             chunk_size,
             |x: f32| x,
         )),
+        (PhysicalType::Float, Float16) => dyn_iter(primitive::DictIter::<K, _, _, _, _>::new(
+            iter,
+            data_type,
+            num_rows,
+            chunk_size,
+            f16::from_f32,
+        )),
         (PhysicalType::Double, Float64) => dyn_iter(primitive::DictIter::<K, _, _, _, _>::new(
             iter,
             data_type,
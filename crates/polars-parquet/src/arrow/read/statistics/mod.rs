@@ -3,7 +3,7 @@ use std::collections::VecDeque;
 
 use arrow::array::*;
 use arrow::datatypes::{ArrowDataType, Field, IntervalUnit, PhysicalType};
-use arrow::types::i256;
+use arrow::types::{f16, i256};
 use arrow::with_match_primitive_type_full;
 use ethnum::I256;
 use polars_error::{polars_bail, PolarsResult};
@@ -489,6 +489,9 @@ fn push(
                 })
             }
         },
+        Float16 => primitive::push(rmap!(from, expect_as_float), min, max, |x: f32| {
+            Ok(f16::from_f32(x))
+        }),
         Float32 => primitive::push::<f32, f32, _>(rmap!(from, expect_as_float), min, max, Ok),
         Float64 => primitive::push::<f64, f64, _>(rmap!(from, expect_as_double), min, max, Ok),
         Decimal(_, _) => match physical_type {
@@ -255,6 +255,39 @@ pub fn get_max_length(nested: &[Nested]) -> usize {
     length
 }
 
+/// Binary values of at least this average size are written with `DELTA_LENGTH_BYTE_ARRAY` rather
+/// than dictionary encoded.
+const LARGE_BINARY_VALUE_SIZE: usize = 4 * 1024;
+
+/// Whether `array` holds large binary values, such as images or serialized objects.
+///
+/// Those are rarely repeated, so dictionary encoding them is a waste of hashing every value.
+/// `DELTA_LENGTH_BYTE_ARRAY` writes the lengths apart from the values, which are then copied
+/// into the page as they are.
+fn is_large_binary(array: &dyn Array) -> bool {
+    let total_bytes_len = match array.data_type().to_physical_type() {
+        PhysicalType::BinaryView => array
+            .as_any()
+            .downcast_ref::<BinaryViewArray>()
+            .unwrap()
+            .total_bytes_len(),
+        PhysicalType::Utf8View => array
+            .as_any()
+            .downcast_ref::<Utf8ViewArray>()
+            .unwrap()
+            .total_bytes_len(),
+        PhysicalType::LargeBinary => array
+            .as_any()
+            .downcast_ref::<BinaryArray<i64>>()
+            .unwrap()
+            .offsets()
+            .range() as usize,
+        _ => return false,
+    };
+    let valid = array.len() - array.null_count();
+    valid > 0 && total_bytes_len / valid >= LARGE_BINARY_VALUE_SIZE
+}
+
 /// Returns an iterator of [`Page`].
 pub fn array_to_pages(
     primitive_array: &dyn Array,
@@ -277,16 +310,21 @@ pub fn array_to_pages(
     };
     if let Encoding::RleDictionary = encoding {
         // Only take this path for primitive columns
-        if matches!(nested.first(), Some(Nested::Primitive(_))) {
-            if let Some(result) =
-                encode_as_dictionary_optional(primitive_array, nested, type_.clone(), options)
-            {
-                return result;
+        let is_primitive = matches!(nested.first(), Some(Nested::Primitive(_)));
+        if is_primitive && is_large_binary(primitive_array) {
+            encoding = Encoding::DeltaLengthByteArray;
+        } else {
+            if is_primitive {
+                if let Some(result) =
+                    encode_as_dictionary_optional(primitive_array, nested, type_.clone(), options)
+                {
+                    return result;
+                }
             }
-        }
 
-        // We didn't succeed, fallback to plain
-        encoding = Encoding::Plain;
+            // We didn't succeed, fallback to plain
+            encoding = Encoding::Plain;
+        }
     }
 
     let nested = nested.to_vec();
@@ -443,6 +481,16 @@ pub fn array_to_page_simple(
             options,
             type_,
         ),
+        ArrowDataType::Float16 => {
+            let array =
+                arrow::compute::cast::cast(array, &ArrowDataType::Float32, Default::default())
+                    .unwrap();
+            primitive::array_to_page_plain::<f32, f32>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+            )
+        },
         ArrowDataType::LargeUtf8 => {
             let array =
                 arrow::compute::cast::cast(array, &ArrowDataType::LargeBinary, Default::default())
@@ -794,6 +842,11 @@ fn array_to_page_nested(
             let array = array.as_any().downcast_ref().unwrap();
             primitive::nested_array_to_page::<f64, f64>(array, options, type_, nested)
         },
+        Float16 => {
+            let array = arrow::compute::cast::cast(array, &Float32, Default::default()).unwrap();
+            let array = array.as_any().downcast_ref().unwrap();
+            primitive::nested_array_to_page::<f32, f32>(array, options, type_, nested)
+        },
         Decimal(precision, _) => {
             let precision = *precision;
             let array = array
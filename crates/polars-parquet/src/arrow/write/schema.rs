@@ -129,7 +129,9 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             None,
             None,
         )?),
-        ArrowDataType::Float32 => Ok(ParquetType::try_from_primitive(
+        // no half-precision type in the parquet format we support; it is stored as `FLOAT` and
+        // restored from the arrow schema in the metadata.
+        ArrowDataType::Float16 | ArrowDataType::Float32 => Ok(ParquetType::try_from_primitive(
             name,
             PhysicalType::Float,
             repetition,
@@ -1,4 +1,4 @@
-use parquet_format_safe::{ColumnChunk, ColumnMetaData, Encoding};
+use parquet_format_safe::{ColumnChunk, ColumnMetaData, Encoding, PageType};
 
 use super::column_descriptor::ColumnDescriptor;
 use crate::parquet::compression::Compression;
@@ -163,6 +163,36 @@ impl ColumnChunkMetaData {
         &self.metadata().encodings
     }
 
+    /// Returns `true` if all data pages of this column chunk are dictionary-encoded, such that the
+    /// values can be read as keys into the dictionary page.
+    ///
+    /// Without page encoding statistics this is only known if no data page can be plain-encoded,
+    /// as writers list the same encoding for a plain dictionary page and for data pages that
+    /// fell back to plain.
+    pub fn is_dictionary_encoded(&self) -> bool {
+        let is_dictionary = |encoding: &Encoding| {
+            matches!(
+                *encoding,
+                Encoding::PLAIN_DICTIONARY | Encoding::RLE_DICTIONARY
+            )
+        };
+        let meta = self.metadata();
+        match &meta.encoding_stats {
+            Some(stats) => {
+                stats
+                    .iter()
+                    .any(|s| s.page_type == PageType::DICTIONARY_PAGE)
+                    && stats.iter().all(|s| {
+                        s.page_type == PageType::DICTIONARY_PAGE || is_dictionary(&s.encoding)
+                    })
+            },
+            None => {
+                meta.encodings.iter().any(is_dictionary)
+                    && !meta.encodings.contains(&Encoding::PLAIN)
+            },
+        }
+    }
+
     /// Returns the offset and length in bytes of the column chunk within the file
     pub fn byte_range(&self) -> (u64, u64) {
         let start = if let Some(dict_page_offset) = self.dictionary_page_offset() {
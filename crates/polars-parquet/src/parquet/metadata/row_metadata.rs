@@ -1,4 +1,4 @@
-use parquet_format_safe::RowGroup;
+use parquet_format_safe::{RowGroup, SortingColumn as ThriftSortingColumn};
 #[cfg(feature = "serde_types")]
 use serde::{Deserialize, Serialize};
 
@@ -7,6 +7,16 @@ use super::schema_descriptor::SchemaDescriptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::write::ColumnOffsetsMetadata;
 
+/// A column by which the rows of a row group are sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_types", derive(Deserialize, Serialize))]
+pub struct SortingColumn {
+    /// The index of the column in the row group.
+    pub column_idx: usize,
+    pub descending: bool,
+    pub nulls_first: bool,
+}
+
 /// Metadata for a row group.
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde_types", derive(Deserialize, Serialize))]
@@ -14,6 +24,7 @@ pub struct RowGroupMetaData {
     columns: Vec<ColumnChunkMetaData>,
     num_rows: usize,
     total_byte_size: usize,
+    sorting_columns: Option<Vec<SortingColumn>>,
 }
 
 impl RowGroupMetaData {
@@ -27,6 +38,7 @@ impl RowGroupMetaData {
             columns,
             num_rows,
             total_byte_size,
+            sorting_columns: None,
         }
     }
 
@@ -45,6 +57,12 @@ impl RowGroupMetaData {
         self.total_byte_size
     }
 
+    /// The columns by which the rows are sorted, as declared by the writer. The rows are sorted
+    /// by the first column and every next column breaks the ties of the previous ones.
+    pub fn sorting_columns(&self) -> Option<&[SortingColumn]> {
+        self.sorting_columns.as_deref()
+    }
+
     /// Total size of all compressed column data in this row group.
     pub fn compressed_size(&self) -> usize {
         self.columns
@@ -71,11 +89,27 @@ impl RowGroupMetaData {
                 ColumnChunkMetaData::try_from_thrift(descriptor.clone(), column_chunk)
             })
             .collect::<ParquetResult<Vec<_>>>()?;
+        let sorting_columns = rg
+            .sorting_columns
+            .map(|sorting_columns| {
+                sorting_columns
+                    .into_iter()
+                    .map(|c| {
+                        Ok(SortingColumn {
+                            column_idx: c.column_idx.try_into()?,
+                            descending: c.descending,
+                            nulls_first: c.nulls_first,
+                        })
+                    })
+                    .collect::<ParquetResult<Vec<_>>>()
+            })
+            .transpose()?;
 
         Ok(RowGroupMetaData {
             columns,
             num_rows,
             total_byte_size,
+            sorting_columns,
         })
     }
 
@@ -94,7 +128,16 @@ impl RowGroupMetaData {
             columns: self.columns.into_iter().map(|v| v.into_thrift()).collect(),
             total_byte_size: self.total_byte_size as i64,
             num_rows: self.num_rows as i64,
-            sorting_columns: None,
+            sorting_columns: self.sorting_columns.map(|sorting_columns| {
+                sorting_columns
+                    .into_iter()
+                    .map(|c| ThriftSortingColumn {
+                        column_idx: c.column_idx as i32,
+                        descending: c.descending,
+                        nulls_first: c.nulls_first,
+                    })
+                    .collect()
+            }),
             file_offset,
             total_compressed_size,
             ordinal: None,
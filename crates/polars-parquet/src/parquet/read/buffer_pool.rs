@@ -0,0 +1,81 @@
+use std::sync::{Arc, Mutex};
+
+/// The pool doesn't keep more than this many bytes of buffers.
+const MAX_POOLED_BYTES: usize = 64 * 1024 * 1024;
+
+#[derive(Default)]
+struct Buffers {
+    buffers: Vec<Vec<u8>>,
+    bytes: usize,
+}
+
+/// A pool of the buffers pages are decompressed into, shared between the column chunks and
+/// batches of a read.
+///
+/// A [`BasicDecompressor`](super::BasicDecompressor) otherwise allocates a buffer for every
+/// column chunk, and frees it after the chunk is decoded. The pool is reference counted, clones
+/// share the same buffers.
+#[derive(Clone, Default)]
+pub struct DecompressionBufferPool(Arc<Mutex<Buffers>>);
+
+impl DecompressionBufferPool {
+    /// Take a buffer of the pool, or a new one if the pool is empty.
+    pub fn take(&self) -> Vec<u8> {
+        let mut pool = self.0.lock().unwrap();
+        match pool.buffers.pop() {
+            Some(buffer) => {
+                pool.bytes -= buffer.capacity();
+                buffer
+            },
+            None => vec![],
+        }
+    }
+
+    /// Give a buffer back to the pool. It is freed if the pool is full.
+    pub fn put(&self, mut buffer: Vec<u8>) {
+        if buffer.capacity() == 0 {
+            return;
+        }
+        let mut pool = self.0.lock().unwrap();
+        if pool.bytes + buffer.capacity() <= MAX_POOLED_BYTES {
+            buffer.clear();
+            pool.bytes += buffer.capacity();
+            pool.buffers.push(buffer);
+        }
+    }
+
+    /// The number of buffers in the pool.
+    pub fn len(&self) -> usize {
+        self.0.lock().unwrap().buffers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reuse_buffers() {
+        let pool = DecompressionBufferPool::default();
+        let shared = pool.clone();
+
+        let mut buffer = pool.take();
+        buffer.extend_from_slice(&[1, 2, 3]);
+        let capacity = buffer.capacity();
+        shared.put(buffer);
+        assert_eq!(pool.len(), 1);
+
+        let buffer = pool.take();
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.capacity(), capacity);
+        assert!(shared.is_empty());
+
+        // Buffers that would exceed the limit are freed.
+        pool.put(Vec::with_capacity(MAX_POOLED_BYTES + 1));
+        assert!(pool.is_empty());
+    }
+}
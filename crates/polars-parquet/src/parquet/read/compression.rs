@@ -1,6 +1,7 @@
 use parquet_format_safe::DataPageHeaderV2;
 
 use super::page::PageIterator;
+use super::DecompressionBufferPool;
 use crate::parquet::compression::{self, Compression};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::page::{CompressedPage, DataPage, DataPageHeader, DictPage, Page};
@@ -265,7 +266,9 @@ impl streaming_decompression::Decompressed for Page {
 /// is reused across pages, so that a single allocation is required.
 /// If the pages are not compressed, the internal buffer is not used.
 pub struct BasicDecompressor<I: Iterator<Item = ParquetResult<CompressedPage>>> {
-    iter: _Decompressor<I>,
+    // Only `None` after the buffer is taken out.
+    iter: Option<_Decompressor<I>>,
+    pool: Option<DecompressionBufferPool>,
 }
 
 impl<I> BasicDecompressor<I>
@@ -275,13 +278,34 @@ where
     /// Returns a new [`BasicDecompressor`].
     pub fn new(iter: I, buffer: Vec<u8>) -> Self {
         Self {
-            iter: _Decompressor::new(iter, buffer, decompress),
+            iter: Some(_Decompressor::new(iter, buffer, decompress)),
+            pool: None,
+        }
+    }
+
+    /// Returns a new [`BasicDecompressor`] that takes its buffer from `pool` and gives it
+    /// back when it is dropped.
+    pub fn new_pooled(iter: I, pool: DecompressionBufferPool) -> Self {
+        Self {
+            iter: Some(_Decompressor::new(iter, pool.take(), decompress)),
+            pool: Some(pool),
         }
     }
 
     /// Returns its internal buffer, consuming itself.
-    pub fn into_inner(self) -> Vec<u8> {
-        self.iter.into_inner()
+    pub fn into_inner(mut self) -> Vec<u8> {
+        self.iter.take().unwrap().into_inner()
+    }
+}
+
+impl<I> Drop for BasicDecompressor<I>
+where
+    I: Iterator<Item = ParquetResult<CompressedPage>>,
+{
+    fn drop(&mut self) {
+        if let (Some(pool), Some(iter)) = (self.pool.take(), self.iter.take()) {
+            pool.put(iter.into_inner());
+        }
     }
 }
 
@@ -293,10 +317,10 @@ where
     type Error = ParquetError;
 
     fn advance(&mut self) -> ParquetResult<()> {
-        self.iter.advance()
+        self.iter.as_mut().unwrap().advance()
     }
 
     fn get(&self) -> Option<&Self::Item> {
-        self.iter.get()
+        self.iter.as_ref().unwrap().get()
     }
 }
@@ -1,3 +1,4 @@
+mod buffer_pool;
 mod column;
 mod compression;
 mod indexes;
@@ -10,6 +11,7 @@ mod stream;
 use std::io::{Read, Seek, SeekFrom};
 use std::sync::Arc;
 
+pub use buffer_pool::DecompressionBufferPool;
 pub use column::*;
 pub use compression::{decompress, BasicDecompressor, Decompressor};
 pub use indexes::{read_columns_indexes, read_pages_locations};
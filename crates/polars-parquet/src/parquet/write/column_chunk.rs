@@ -5,7 +5,7 @@ use futures::AsyncWrite;
 use parquet_format_safe::thrift::protocol::TCompactOutputProtocol;
 #[cfg(feature = "async")]
 use parquet_format_safe::thrift::protocol::TCompactOutputStreamProtocol;
-use parquet_format_safe::{ColumnChunk, ColumnMetaData, Type};
+use parquet_format_safe::{ColumnChunk, ColumnMetaData, PageEncodingStats, Type};
 use polars_utils::aliases::PlHashSet;
 
 #[cfg(feature = "async")]
@@ -170,6 +170,29 @@ fn build_column_chunk(
     // Sort the encodings to have deterministic metadata
     encodings.sort();
 
+    // The number of pages per page type and encoding, which tells readers whether all data pages
+    // are dictionary-encoded.
+    let mut encoding_stats: Vec<PageEncodingStats> = vec![];
+    for spec in specs {
+        let header = &spec.header;
+        let encoding = match header.type_.try_into().unwrap() {
+            PageType::DataPage => header.data_page_header.as_ref().unwrap().encoding,
+            PageType::DataPageV2 => header.data_page_header_v2.as_ref().unwrap().encoding,
+            PageType::DictionaryPage => header.dictionary_page_header.as_ref().unwrap().encoding,
+        };
+        match encoding_stats
+            .iter_mut()
+            .find(|stats| stats.page_type == header.type_ && stats.encoding == encoding)
+        {
+            Some(stats) => stats.count += 1,
+            None => encoding_stats.push(PageEncodingStats {
+                page_type: header.type_,
+                encoding,
+                count: 1,
+            }),
+        }
+    }
+
     let statistics = specs.iter().map(|x| &x.statistics).collect::<Vec<_>>();
     let statistics = reduce(&statistics)?;
     let statistics = statistics.map(|x| x.serialize());
@@ -189,7 +212,7 @@ fn build_column_chunk(
         index_page_offset: None,
         dictionary_page_offset: None,
         statistics,
-        encoding_stats: None,
+        encoding_stats: Some(encoding_stats),
         bloom_filter_offset: None,
     };
 
@@ -2,13 +2,13 @@ use std::sync::Arc;
 
 use polars_core::error::PolarsResult;
 use polars_core::prelude::polars_err;
+use polars_expr::prelude::PhysicalExpr;
 
-use crate::expressions::PhysicalPipedExpr;
 use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
 
 #[derive(Clone)]
 pub(crate) struct FilterOperator {
-    pub(crate) predicate: Arc<dyn PhysicalPipedExpr>,
+    pub(crate) predicate: Arc<dyn PhysicalExpr>,
 }
 
 impl Operator for FilterOperator {
@@ -17,7 +17,9 @@ impl Operator for FilterOperator {
         context: &PExecutionContext,
         chunk: &DataChunk,
     ) -> PolarsResult<OperatorResult> {
-        let s = self.predicate.evaluate(chunk, &context.execution_state)?;
+        let s = self
+            .predicate
+            .evaluate(&chunk.data, &context.execution_state)?;
         let mask = s.bool().map_err(|_| {
             polars_err!(
                 ComputeError: "filter predicate must be of type `Boolean`, got `{}`", s.dtype()
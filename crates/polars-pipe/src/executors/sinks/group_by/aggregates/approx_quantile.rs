@@ -0,0 +1,90 @@
+use std::any::Any;
+
+use arrow::array::{Array, PrimitiveArray};
+use polars_core::export::num::NumCast;
+use polars_core::prelude::*;
+use polars_ops::prelude::TDigest;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+
+pub(crate) struct ApproxQuantileAgg {
+    digest: TDigest,
+    quantile: f64,
+    accuracy: u32,
+}
+
+impl ApproxQuantileAgg {
+    pub(crate) fn new(quantile: f64, accuracy: u32) -> Self {
+        Self {
+            digest: TDigest::new(accuracy),
+            quantile,
+            accuracy,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.quantile, self.accuracy)
+    }
+}
+
+impl AggregateFn for ApproxQuantileAgg {
+    fn has_physical_agg(&self) -> bool {
+        true
+    }
+
+    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.pre_agg_primitive(0, item.extract::<f64>())
+    }
+
+    fn pre_agg_primitive<T: NumCast>(&mut self, _chunk_idx: IdxSize, item: Option<T>) {
+        if let Some(val) = item.and_then(|v| v.to_f64()) {
+            self.digest.add(val)
+        }
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        let arr = unsafe {
+            let arr = values.chunks().get_unchecked(0);
+            arr.sliced_unchecked(offset as usize, length as usize)
+        };
+        let arr =
+            arrow::compute::cast::cast_unchecked(arr.as_ref(), &ArrowDataType::Float64).unwrap();
+        let arr = unsafe {
+            arr.as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap_unchecked_release()
+        };
+        if arr.null_count() == 0 {
+            self.digest.extend(arr.values().iter().copied())
+        } else {
+            self.digest.extend(arr.iter().flatten().copied())
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::Float64
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        self.digest.merge(&other.digest);
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        self.digest
+            .quantile(self.quantile)
+            .map_or(AnyValue::Null, AnyValue::Float64)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -14,12 +14,18 @@ use polars_plan::prelude::{AExpr, IRAggExpr};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::IdxSize;
 
+use crate::executors::sinks::group_by::aggregates::approx_quantile::ApproxQuantileAgg;
 use crate::executors::sinks::group_by::aggregates::count::CountAgg;
 use crate::executors::sinks::group_by::aggregates::first::FirstAgg;
+#[cfg(feature = "dtype-struct")]
+use crate::executors::sinks::group_by::aggregates::first_by::{FirstByAgg, FirstByInput};
 use crate::executors::sinks::group_by::aggregates::last::LastAgg;
 use crate::executors::sinks::group_by::aggregates::mean::MeanAgg;
 use crate::executors::sinks::group_by::aggregates::min_max::{new_max, new_min};
 use crate::executors::sinks::group_by::aggregates::null::NullAgg;
+#[cfg(feature = "ffi_plugin")]
+use crate::executors::sinks::group_by::aggregates::plugin::PluginAgg;
+use crate::executors::sinks::group_by::aggregates::str_join::StrJoinAgg;
 use crate::executors::sinks::group_by::aggregates::{AggregateFunction, SumAgg};
 use crate::expressions::PhysicalPipedExpr;
 use crate::operators::DataChunk;
@@ -82,6 +88,59 @@ pub fn can_convert_to_hash_agg(
         }
         match expr_arena.get(node) {
             AExpr::Len => true,
+            AExpr::Agg(IRAggExpr::ApproxQuantile { expr, quantile, .. }) => {
+                // Invalid quantiles are left to the default engine to raise.
+                matches!(
+                    literal_quantile(*quantile, expr_arena),
+                    Some(quantile) if (0.0..=1.0).contains(&quantile)
+                ) && matches!(
+                    expr_arena
+                        .get(*expr)
+                        .to_field(input_schema, Context::Default, expr_arena),
+                    Ok(field) if field.dtype.to_physical().is_numeric()
+                )
+            },
+            AExpr::Agg(IRAggExpr::StrJoin { expr, .. }) => matches!(
+                expr_arena
+                    .get(*expr)
+                    .to_field(input_schema, Context::Default, expr_arena),
+                Ok(field) if field.dtype == DataType::String
+            ),
+            // The rows are compared on the physical values of `by`, which doesn't work for the
+            // lexical order of categoricals.
+            #[cfg(feature = "dtype-struct")]
+            AExpr::Agg(IRAggExpr::FirstBy { expr, by } | IRAggExpr::LastBy { expr, by }) => {
+                let to_field = |node: Node| {
+                    expr_arena
+                        .get(node)
+                        .to_field(input_schema, Context::Default, expr_arena)
+                };
+                matches!(to_field(*expr), Ok(field) if !field.dtype.contains_objects())
+                    && matches!(to_field(*by), Ok(field) if match field.dtype.to_physical() {
+                        DataType::String => true,
+                        #[cfg(feature = "dtype-decimal")]
+                        DataType::Decimal(..) => false,
+                        dt => dt.is_numeric() && !field.dtype.contains_categoricals(),
+                    })
+            },
+            // The values are passed to the plugin in their physical representation, so only
+            // inputs that have no logical types are supported.
+            #[cfg(feature = "ffi_plugin")]
+            AExpr::Agg(IRAggExpr::Plugin { expr, function }) => {
+                match expr_arena
+                    .get(*expr)
+                    .to_field(input_schema, Context::Default, expr_arena)
+                {
+                    Ok(field) if field.dtype.to_physical() == field.dtype => {
+                        matches!(
+                            function.output_field(&field),
+                            Ok(out) if !out.dtype.contains_categoricals()
+                                && !out.dtype.contains_objects()
+                        ) && function.load().is_ok()
+                    },
+                    _ => false,
+                }
+            },
             ae @ AExpr::Agg(agg_fn) => {
                 matches!(
                     agg_fn,
@@ -119,6 +178,13 @@ pub fn can_convert_to_hash_agg(
     }
 }
 
+fn literal_quantile(node: Node, expr_arena: &Arena<AExpr>) -> Option<f64> {
+    match expr_arena.get(node) {
+        AExpr::Literal(lv) => lv.to_any_value()?.extract::<f64>(),
+        _ => None,
+    }
+}
+
 /// # Returns:
 ///  - input_dtype: dtype that goes into the agg expression
 ///  - physical expr: physical expression that produces the input of the aggregation
@@ -306,6 +372,77 @@ where
                     AggregateFunction::Count(CountAgg::new()),
                 )
             },
+            IRAggExpr::ApproxQuantile {
+                expr,
+                quantile,
+                accuracy,
+            } => {
+                let phys_expr = to_physical(
+                    &ExprIR::from_node(*expr, expr_arena),
+                    expr_arena,
+                    Some(schema),
+                )
+                .unwrap();
+                let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+                let quantile = literal_quantile(*quantile, expr_arena).unwrap();
+                (
+                    logical_dtype,
+                    phys_expr,
+                    AggregateFunction::ApproxQuantile(ApproxQuantileAgg::new(quantile, *accuracy)),
+                )
+            },
+            IRAggExpr::StrJoin { expr, options } => {
+                let phys_expr = to_physical(
+                    &ExprIR::from_node(*expr, expr_arena),
+                    expr_arena,
+                    Some(schema),
+                )
+                .unwrap();
+                (
+                    DataType::String,
+                    phys_expr,
+                    AggregateFunction::StrJoin(StrJoinAgg::new(Arc::new(options.clone()))),
+                )
+            },
+            #[cfg(feature = "dtype-struct")]
+            IRAggExpr::FirstBy { expr, by } | IRAggExpr::LastBy { expr, by } => {
+                let create = |node: Node| {
+                    to_physical(
+                        &ExprIR::from_node(node, expr_arena),
+                        expr_arena,
+                        Some(schema),
+                    )
+                    .unwrap()
+                };
+                let input = create(*expr);
+                let dtype = input.field(schema).unwrap().dtype.to_physical();
+                let last = matches!(agg, IRAggExpr::LastBy { .. });
+                let phys_expr = Arc::new(FirstByInput {
+                    input,
+                    by: create(*by),
+                    last,
+                });
+                // The aggregated column is the struct of the input and `by`.
+                (
+                    phys_expr.field(schema).unwrap().dtype,
+                    phys_expr,
+                    AggregateFunction::FirstBy(FirstByAgg::new(dtype, last)),
+                )
+            },
+            #[cfg(feature = "ffi_plugin")]
+            IRAggExpr::Plugin { expr, function } => {
+                let phys_expr = to_physical(
+                    &ExprIR::from_node(*expr, expr_arena),
+                    expr_arena,
+                    Some(schema),
+                )
+                .unwrap();
+                let input = phys_expr.field(schema).unwrap();
+                let output_dtype = function.output_field(&input).unwrap().dtype;
+                let agg =
+                    PluginAgg::new(function.load().unwrap(), input.dtype.clone(), output_dtype);
+                (input.dtype, phys_expr, AggregateFunction::Plugin(agg))
+            },
             agg => panic!("{agg:?} not yet implemented."),
         },
         _ => todo!(),
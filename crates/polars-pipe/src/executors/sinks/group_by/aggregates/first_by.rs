@@ -0,0 +1,152 @@
+use std::any::Any;
+use std::cmp::Ordering;
+
+use polars_core::prelude::*;
+use polars_expr::state::ExecutionState;
+use polars_io::predicates::PhysicalIoExpr;
+use polars_plan::dsl::Expr;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::DataChunk;
+
+/// Evaluates the input and the `by` of `first_by`/`last_by` into a single struct column, as
+/// every aggregation gets one input. Both are converted to their physical types.
+pub(crate) struct FirstByInput {
+    pub(crate) input: Arc<dyn PhysicalPipedExpr>,
+    pub(crate) by: Arc<dyn PhysicalPipedExpr>,
+    pub(crate) last: bool,
+}
+
+impl PhysicalIoExpr for FirstByInput {
+    fn evaluate_io(&self, _df: &DataFrame) -> PolarsResult<Series> {
+        unimplemented!()
+    }
+}
+
+impl PhysicalPipedExpr for FirstByInput {
+    fn evaluate(&self, chunk: &DataChunk, lazy_state: &ExecutionState) -> PolarsResult<Series> {
+        let input = self.input.evaluate(chunk, lazy_state)?;
+        let by = self.by.evaluate(chunk, lazy_state)?;
+        let fields = [
+            input.to_physical_repr().into_owned().with_name("input"),
+            by.to_physical_repr().into_owned().with_name("by"),
+        ];
+        Ok(StructChunked::new(input.name(), &fields)?.into_series())
+    }
+
+    fn field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        let input = self.input.field(input_schema)?;
+        let by = self.by.field(input_schema)?;
+        let fields = vec![
+            Field::new("input", input.dtype.to_physical()),
+            Field::new("by", by.dtype.to_physical()),
+        ];
+        Ok(Field::new(input.name(), DataType::Struct(fields)))
+    }
+
+    fn expression(&self) -> Expr {
+        let (input, by) = (self.input.expression(), self.by.expression());
+        if self.last {
+            input.last_by(by)
+        } else {
+            input.first_by(by)
+        }
+    }
+}
+
+pub(crate) struct FirstByAgg {
+    last: bool,
+    chunk_idx: IdxSize,
+    /// The `by` and input value of the selected row.
+    selected: Option<(AnyValue<'static>, AnyValue<'static>)>,
+    dtype: DataType,
+}
+
+impl FirstByAgg {
+    pub(crate) fn new(dtype: DataType, last: bool) -> Self {
+        Self {
+            last,
+            chunk_idx: IdxSize::MAX,
+            selected: None,
+            dtype,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.dtype.clone(), self.last)
+    }
+
+    /// Whether a row with `by` from chunk `chunk_idx` replaces the selected row. Ties are decided
+    /// by the order of the chunks, rows of the same chunk are seen in order.
+    fn replaces(&self, by: &AnyValue, chunk_idx: IdxSize) -> bool {
+        let Some((selected, _)) = &self.selected else {
+            return true;
+        };
+        match (by.partial_cmp(selected), self.last) {
+            (Some(Ordering::Less), false) | (Some(Ordering::Greater), true) => true,
+            (Some(Ordering::Equal), false) => chunk_idx < self.chunk_idx,
+            (Some(Ordering::Equal), true) => chunk_idx >= self.chunk_idx,
+            _ => false,
+        }
+    }
+
+    fn update(&mut self, chunk_idx: IdxSize, item: AnyValue) {
+        let mut fields = item._iter_struct_av();
+        let (value, by) = unsafe {
+            (
+                fields.next().unwrap_unchecked_release(),
+                fields.next().unwrap_unchecked_release(),
+            )
+        };
+        if !by.is_null() && self.replaces(&by, chunk_idx) {
+            self.chunk_idx = chunk_idx;
+            self.selected = Some((by.into_static().unwrap(), value.into_static().unwrap()));
+        }
+    }
+}
+
+impl AggregateFn for FirstByAgg {
+    fn pre_agg(&mut self, chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.update(chunk_idx, item)
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        for i in offset..offset + length {
+            let item = unsafe { values.get_unchecked(i as usize) };
+            self.update(chunk_idx, item)
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        self.dtype.clone()
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        if let Some((by, _)) = &other.selected {
+            if self.replaces(by, other.chunk_idx) {
+                self.chunk_idx = other.chunk_idx;
+                self.selected.clone_from(&other.selected);
+            }
+        }
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        std::mem::take(&mut self.selected)
+            .map(|(_, value)| value)
+            .unwrap_or(AnyValue::Null)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
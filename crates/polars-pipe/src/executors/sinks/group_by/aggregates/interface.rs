@@ -5,12 +5,18 @@ use num_traits::NumCast;
 use polars_core::datatypes::DataType;
 use polars_core::prelude::{AnyValue, Series};
 
+use crate::executors::sinks::group_by::aggregates::approx_quantile::ApproxQuantileAgg;
 use crate::executors::sinks::group_by::aggregates::count::CountAgg;
 use crate::executors::sinks::group_by::aggregates::first::FirstAgg;
+#[cfg(feature = "dtype-struct")]
+use crate::executors::sinks::group_by::aggregates::first_by::FirstByAgg;
 use crate::executors::sinks::group_by::aggregates::last::LastAgg;
 use crate::executors::sinks::group_by::aggregates::mean::MeanAgg;
 use crate::executors::sinks::group_by::aggregates::min_max::MinMaxAgg;
 use crate::executors::sinks::group_by::aggregates::null::NullAgg;
+#[cfg(feature = "ffi_plugin")]
+use crate::executors::sinks::group_by::aggregates::plugin::PluginAgg;
+use crate::executors::sinks::group_by::aggregates::str_join::StrJoinAgg;
 use crate::executors::sinks::group_by::aggregates::SumAgg;
 use crate::operators::IdxSize;
 
@@ -67,6 +73,12 @@ pub(crate) enum AggregateFunction {
     MinMaxI16(MinMaxAgg<i16, fn(i16, i16) -> i16>),
     MinMaxI32(MinMaxAgg<i32, fn(i32, i32) -> i32>),
     MinMaxI64(MinMaxAgg<i64, fn(i64, i64) -> i64>),
+    ApproxQuantile(ApproxQuantileAgg),
+    StrJoin(StrJoinAgg),
+    #[cfg(feature = "dtype-struct")]
+    FirstBy(FirstByAgg),
+    #[cfg(feature = "ffi_plugin")]
+    Plugin(PluginAgg),
 }
 
 impl AggregateFunction {
@@ -96,6 +108,12 @@ impl AggregateFunction {
             MinMaxI16(inner) => MinMaxI16(inner.split()),
             MinMaxI32(inner) => MinMaxI32(inner.split()),
             MinMaxI64(inner) => MinMaxI64(inner.split()),
+            ApproxQuantile(inner) => ApproxQuantile(inner.split()),
+            StrJoin(inner) => StrJoin(inner.split()),
+            #[cfg(feature = "dtype-struct")]
+            FirstBy(inner) => FirstBy(inner.split()),
+            #[cfg(feature = "ffi_plugin")]
+            Plugin(inner) => Plugin(inner.split()),
         }
     }
 }
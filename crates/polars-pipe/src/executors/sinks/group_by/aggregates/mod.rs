@@ -1,11 +1,17 @@
+mod approx_quantile;
 mod convert;
 mod count;
 mod first;
+#[cfg(feature = "dtype-struct")]
+mod first_by;
 mod interface;
 mod last;
 mod mean;
 mod min_max;
 mod null;
+#[cfg(feature = "ffi_plugin")]
+mod plugin;
+mod str_join;
 mod sum;
 
 pub use convert::*;
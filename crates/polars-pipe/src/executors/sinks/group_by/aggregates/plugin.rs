@@ -0,0 +1,112 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_plan::dsl::{PluginAggState, PluginAggregator};
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+
+/// The number of values that are collected before they are passed to the plugin at once.
+const BATCH_SIZE: usize = 1024;
+
+pub(crate) struct PluginAgg {
+    aggregator: Arc<PluginAggregator>,
+    /// Created when the first values are passed to the plugin.
+    state: Option<PluginAggState>,
+    /// Values that are not yet passed to the plugin.
+    pending: Vec<AnyValue<'static>>,
+    input_dtype: DataType,
+    output_dtype: DataType,
+}
+
+impl PluginAgg {
+    pub(crate) fn new(
+        aggregator: Arc<PluginAggregator>,
+        input_dtype: DataType,
+        output_dtype: DataType,
+    ) -> Self {
+        Self {
+            aggregator,
+            state: None,
+            pending: vec![],
+            input_dtype,
+            output_dtype,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(
+            self.aggregator.clone(),
+            self.input_dtype.clone(),
+            self.output_dtype.clone(),
+        )
+    }
+
+    fn state(&mut self) -> &mut PluginAggState {
+        let aggregator = &self.aggregator;
+        self.state.get_or_insert_with(|| aggregator.init().unwrap())
+    }
+
+    /// Only used for inputs that are their own physical type, so the values can be passed as is.
+    fn update(&mut self, values: &Series) {
+        self.state().update(values).unwrap()
+    }
+
+    fn update_pending(&mut self, pending: &[AnyValue]) {
+        if pending.is_empty() {
+            return;
+        }
+        let values =
+            Series::from_any_values_and_dtype("", pending, &self.input_dtype, true).unwrap();
+        self.update(&values)
+    }
+
+    fn flush(&mut self) {
+        let pending = std::mem::take(&mut self.pending);
+        self.update_pending(&pending);
+    }
+}
+
+impl AggregateFn for PluginAgg {
+    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.pending.push(item.into_static().unwrap());
+        if self.pending.len() >= BATCH_SIZE {
+            self.flush()
+        }
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        self.flush();
+        self.update(&values.slice(offset as i64, length as usize))
+    }
+
+    fn dtype(&self) -> DataType {
+        self.output_dtype.to_physical()
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        if let Some(other_state) = &other.state {
+            self.state().merge(other_state).unwrap();
+        }
+        self.update_pending(&other.pending);
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        self.flush();
+        let out = self.state().finalize().unwrap();
+        let out = out.to_physical_repr();
+        unsafe { out.get_unchecked(0) }.into_static().unwrap()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,102 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_ops::prelude::{str_join_agg_values, StrJoinAggOptions};
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+
+/// The strings seen so far. With `distinct` every string is kept once, which keeps the state of
+/// low cardinality groups small.
+enum Values {
+    All(Vec<String>),
+    Distinct(PlIndexSet<String>),
+}
+
+pub(crate) struct StrJoinAgg {
+    values: Values,
+    options: Arc<StrJoinAggOptions>,
+}
+
+impl StrJoinAgg {
+    pub(crate) fn new(options: Arc<StrJoinAggOptions>) -> Self {
+        let values = if options.distinct {
+            Values::Distinct(PlIndexSet::default())
+        } else {
+            Values::All(vec![])
+        };
+        Self { values, options }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.options.clone())
+    }
+
+    fn push(&mut self, value: &str) {
+        match &mut self.values {
+            Values::All(values) => values.push(value.to_string()),
+            Values::Distinct(values) => {
+                if !values.contains(value) {
+                    values.insert(value.to_string());
+                }
+            },
+        }
+    }
+}
+
+impl AggregateFn for StrJoinAgg {
+    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        match item {
+            AnyValue::String(v) => self.push(v),
+            AnyValue::StringOwned(v) => self.push(&v),
+            _ => {},
+        }
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        let values = values.slice(offset as i64, length as usize);
+        let ca = unsafe { values.str().unwrap_unchecked_release() };
+        ca.iter().flatten().for_each(|v| self.push(v));
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::String
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        match (&mut self.values, &other.values) {
+            (Values::All(values), Values::All(other)) => values.extend(other.iter().cloned()),
+            (Values::Distinct(values), Values::Distinct(other)) => {
+                for v in other {
+                    if !values.contains(v) {
+                        values.insert(v.clone());
+                    }
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        let out = match std::mem::replace(&mut self.values, Values::All(vec![])) {
+            Values::All(values) => str_join_agg_values(&values, &self.options),
+            Values::Distinct(values) => {
+                let values = values.into_iter().collect::<Vec<_>>();
+                str_join_agg_values(&values, &self.options)
+            },
+        };
+        AnyValue::StringOwned(out.into())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
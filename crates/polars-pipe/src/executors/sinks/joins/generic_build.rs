@@ -5,7 +5,7 @@ use hashbrown::hash_map::RawEntryMut;
 use polars_core::export::ahash::RandomState;
 use polars_core::prelude::*;
 use polars_core::utils::{_set_partition_size, accumulate_dataframes_vertical_unchecked};
-use polars_ops::prelude::JoinArgs;
+use polars_ops::prelude::{JoinArgs, JoinNaming};
 use polars_utils::arena::Node;
 use polars_utils::slice::GetSaferUnchecked;
 use polars_utils::unitvec;
@@ -33,7 +33,7 @@ pub struct GenericBuild<K: ExtraPayload> {
     //      * chunk_offset = (idx * n_join_keys)
     //      * end = (offset + n_join_keys)
     materialized_join_cols: Vec<BinaryArray<i64>>,
-    suffix: Arc<str>,
+    naming: Arc<JoinNaming>,
     hb: RandomState,
     join_args: JoinArgs,
     // partitioned tables that will be used for probing
@@ -59,7 +59,7 @@ pub struct GenericBuild<K: ExtraPayload> {
 impl<K: ExtraPayload> GenericBuild<K> {
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
-        suffix: Arc<str>,
+        naming: Arc<JoinNaming>,
         join_args: JoinArgs,
         swapped: bool,
         join_columns_left: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
@@ -78,7 +78,7 @@ impl<K: ExtraPayload> GenericBuild<K> {
         GenericBuild {
             chunks: vec![],
             join_args,
-            suffix,
+            naming,
             hb,
             swapped,
             join_columns_left,
@@ -278,7 +278,7 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
 
     fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
         let mut new = Self::new(
-            self.suffix.clone(),
+            self.naming.clone(),
             self.join_args.clone(),
             self.swapped,
             self.join_columns_left.clone(),
@@ -306,7 +306,7 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
         // Reallocate to Arc<[]> to get rid of double indirection as this is accessed on every
         // hashtable cmp.
         let materialized_join_cols = Arc::from(std::mem::take(&mut self.materialized_join_cols));
-        let suffix = self.suffix.clone();
+        let naming = self.naming.clone();
         let hb = self.hb.clone();
         let hash_tables = Arc::new(PartitionedHashMap::new(std::mem::take(
             self.hash_tables.inner_mut(),
@@ -323,7 +323,7 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
                 let probe_operator = GenericJoinProbe::new(
                     left_df,
                     materialized_join_cols,
-                    suffix,
+                    naming,
                     hb,
                     hash_tables,
                     join_columns_left,
@@ -342,7 +342,7 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
                 let probe_operator = GenericFullOuterJoinProbe::new(
                     left_df,
                     materialized_join_cols,
-                    suffix,
+                    naming,
                     hb,
                     hash_tables,
                     join_columns_left,
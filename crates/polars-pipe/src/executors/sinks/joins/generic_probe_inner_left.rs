@@ -6,7 +6,7 @@ use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_ops::chunked_array::DfTake;
 use polars_ops::frame::join::_finish_join;
-use polars_ops::prelude::{JoinArgs, JoinType};
+use polars_ops::prelude::{JoinArgs, JoinNaming, JoinType};
 use polars_utils::nulls::IsNull;
 use smartstring::alias::String as SmartString;
 
@@ -30,7 +30,7 @@ pub struct GenericJoinProbe<K: ExtraPayload> {
     ///      * chunk_offset = (idx * n_join_keys)
     ///      * end = (offset + n_join_keys)
     materialized_join_cols: Arc<[BinaryArray<i64>]>,
-    suffix: Arc<str>,
+    naming: Arc<JoinNaming>,
     hb: RandomState,
     /// partitioned tables that will be used for probing
     /// stores the key and the chunk_idx, df_idx of the left table
@@ -58,7 +58,7 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
     pub(super) fn new(
         mut df_a: DataFrame,
         materialized_join_cols: Arc<[BinaryArray<i64>]>,
-        suffix: Arc<str>,
+        naming: Arc<JoinNaming>,
         hb: RandomState,
         hash_tables: Arc<PartitionedMap<K>>,
         join_columns_left: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
@@ -93,7 +93,7 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
         GenericJoinProbe {
             df_a: Arc::new(df_a),
             materialized_join_cols,
-            suffix,
+            naming,
             hb,
             hash_tables,
             join_tuples_a: vec![],
@@ -114,7 +114,7 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
     ) -> PolarsResult<DataFrame> {
         Ok(match &self.output_names {
             None => {
-                let out = _finish_join(left_df, right_df, Some(self.suffix.as_ref()))?;
+                let out = _finish_join(left_df, right_df, &self.naming)?;
                 self.output_names = Some(out.get_column_names_owned());
                 out
             },
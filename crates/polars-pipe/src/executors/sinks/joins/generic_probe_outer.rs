@@ -6,7 +6,7 @@ use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_ops::chunked_array::DfTake;
 use polars_ops::frame::join::_finish_join;
-use polars_ops::prelude::_coalesce_full_join;
+use polars_ops::prelude::{JoinNaming, _coalesce_full_join};
 use smartstring::alias::String as SmartString;
 
 use crate::executors::sinks::joins::generic_build::*;
@@ -32,7 +32,7 @@ pub struct GenericFullOuterJoinProbe<K: ExtraPayload> {
     ///      * chunk_offset = (idx * n_join_keys)
     ///      * end = (offset + n_join_keys)
     materialized_join_cols: Arc<[BinaryArray<i64>]>,
-    suffix: Arc<str>,
+    naming: Arc<JoinNaming>,
     hb: RandomState,
     /// partitioned tables that will be used for probing.
     /// stores the key and the chunk_idx, df_idx of the left table.
@@ -63,7 +63,7 @@ impl<K: ExtraPayload> GenericFullOuterJoinProbe<K> {
     pub(super) fn new(
         df_a: DataFrame,
         materialized_join_cols: Arc<[BinaryArray<i64>]>,
-        suffix: Arc<str>,
+        naming: Arc<JoinNaming>,
         hb: RandomState,
         hash_tables: Arc<PartitionedMap<K>>,
         join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
@@ -79,7 +79,7 @@ impl<K: ExtraPayload> GenericFullOuterJoinProbe<K> {
             df_a: Arc::new(df_a),
             df_b_flush_dummy: None,
             materialized_join_cols,
-            suffix,
+            naming,
             hb,
             hash_tables,
             join_tuples_a: vec![],
@@ -100,7 +100,7 @@ impl<K: ExtraPayload> GenericFullOuterJoinProbe<K> {
         fn inner(
             left_df: DataFrame,
             right_df: DataFrame,
-            suffix: &str,
+            naming: &JoinNaming,
             swapped: bool,
             output_names: &mut Option<Vec<SmartString>>,
         ) -> PolarsResult<DataFrame> {
@@ -111,7 +111,7 @@ impl<K: ExtraPayload> GenericFullOuterJoinProbe<K> {
             };
             Ok(match output_names {
                 None => {
-                    let out = _finish_join(left_df, right_df, Some(suffix))?;
+                    let out = _finish_join(left_df, right_df, naming)?;
                     *output_names = Some(out.get_column_names_owned());
                     out
                 },
@@ -138,7 +138,7 @@ impl<K: ExtraPayload> GenericFullOuterJoinProbe<K> {
             let out = inner(
                 left_df.clone(),
                 right_df,
-                self.suffix.as_ref(),
+                &self.naming,
                 self.swapped,
                 &mut self.output_names,
             )?;
@@ -152,18 +152,12 @@ impl<K: ExtraPayload> GenericFullOuterJoinProbe<K> {
                 .iter()
                 .map(|s| s.as_str())
                 .collect::<Vec<_>>();
-            Ok(_coalesce_full_join(
-                out,
-                &l,
-                &r,
-                Some(self.suffix.as_ref()),
-                &left_df,
-            ))
+            Ok(_coalesce_full_join(out, &l, &r, &self.naming, &left_df))
         } else {
             inner(
                 left_df.clone(),
                 right_df,
-                self.suffix.as_ref(),
+                &self.naming,
                 self.swapped,
                 &mut self.output_names,
             )
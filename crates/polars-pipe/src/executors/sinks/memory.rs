@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use polars_utils::sys::MEMINFO;
 
-use crate::pipeline::FORCE_OOC;
+use crate::pipeline::{max_memory, FORCE_OOC};
 
 const TO_MB: usize = 2 << 19;
 
@@ -16,6 +16,10 @@ pub(super) struct MemTracker {
     thread_count: usize,
     available_at_start: usize,
     refresh_interval: usize,
+    // free memory of the system at the start of this node
+    free_at_start: usize,
+    // the memory budget set with `POLARS_MAX_MEMORY`
+    budget: Option<usize>,
 }
 
 impl MemTracker {
@@ -33,17 +37,32 @@ impl MemTracker {
             thread_count,
             available_at_start: 0,
             refresh_interval,
+            free_at_start: MEMINFO.free() as usize,
+            budget: max_memory(),
         };
-        let available = MEMINFO.free() as usize;
+        let available = out.available();
         out.available_mem.store(available, Ordering::Relaxed);
         out.available_at_start = available;
         out
     }
 
+    /// The free memory of the system, capped by what is left of the memory budget. Memory
+    /// that was taken since the start of this node counts against the budget.
+    fn available(&self) -> usize {
+        let free = MEMINFO.free() as usize;
+        match self.budget {
+            Some(budget) => {
+                let used_since_start = self.free_at_start.saturating_sub(free);
+                free.min(budget.saturating_sub(used_since_start))
+            },
+            None => free,
+        }
+    }
+
     /// This shouldn't be called often as this is expensive.
     pub fn refresh_memory(&self) {
         self.available_mem
-            .store(MEMINFO.free() as usize, Ordering::Relaxed);
+            .store(self.available(), Ordering::Relaxed);
     }
 
     /// Get available memory of the system measured on latest refresh.
@@ -5,6 +5,7 @@ mod memory;
 mod ordered;
 mod output;
 mod reproject;
+mod sample;
 mod slice;
 mod sort;
 mod utils;
@@ -13,14 +14,9 @@ use std::sync::OnceLock;
 
 pub(crate) use joins::*;
 pub(crate) use ordered::*;
-#[cfg(any(
-    feature = "parquet",
-    feature = "ipc",
-    feature = "csv",
-    feature = "json"
-))]
 pub(crate) use output::*;
 pub(crate) use reproject::*;
+pub(crate) use sample::*;
 pub(crate) use slice::*;
 pub(crate) use sort::*;
 
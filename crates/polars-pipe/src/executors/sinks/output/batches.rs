@@ -0,0 +1,54 @@
+use crossbeam_channel::bounded;
+use polars_core::prelude::*;
+use polars_plan::prelude::BatchSender;
+
+use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::pipeline::morsels_per_sink;
+
+struct BatchWriter {
+    sender: BatchSender,
+    disconnected: bool,
+}
+
+impl SinkWriter for BatchWriter {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        // The consumer may stop reading before the query is done, the remaining batches are
+        // dropped.
+        if !self.disconnected {
+            self.disconnected = !self.sender.send(df.clone());
+        }
+        Ok(())
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        Ok(())
+    }
+}
+
+/// Hands the output batches to a [`BatchSender`], in order.
+pub struct BatchSink {}
+impl BatchSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(sender: BatchSender) -> FilesSink {
+        let writer = Box::new(BatchWriter {
+            sender,
+            disconnected: false,
+        });
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            true,
+            morsels_per_sink,
+        )));
+
+        FilesSink {
+            sender,
+            io_thread_handle,
+        }
+    }
+}
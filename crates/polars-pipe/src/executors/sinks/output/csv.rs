@@ -2,35 +2,61 @@ use std::path::Path;
 
 use crossbeam_channel::bounded;
 use polars_core::prelude::*;
-use polars_io::csv::write::{CsvWriter, CsvWriterOptions};
+use polars_core::POOL;
+use polars_io::compression::CompressedWriter;
+use polars_io::csv::write::{BatchedWriter, CsvWriter, CsvWriterOptions};
+use polars_io::write_mode::AtomicFileWrite;
 use polars_io::SerWriter;
 
-use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::executors::sinks::output::file_sink::{
+    init_writer_thread, CommitOnFinish, FilesSink, SinkWriter,
+};
 use crate::pipeline::morsels_per_sink;
 
+pub(super) fn batched_csv_writer(
+    file: std::fs::File,
+    options: CsvWriterOptions,
+    schema: &Schema,
+) -> PolarsResult<BatchedWriter<CompressedWriter<std::fs::File>>> {
+    let file = CompressedWriter::new(file, options.compression)?;
+    CsvWriter::new(file)
+        .include_bom(options.include_bom)
+        .include_header(options.include_header)
+        .with_separator(options.serialize_options.separator)
+        .with_line_terminator(options.serialize_options.line_terminator)
+        .with_quote_char(options.serialize_options.quote_char)
+        .with_batch_size(options.batch_size)
+        .with_datetime_format(options.serialize_options.datetime_format)
+        .with_date_format(options.serialize_options.date_format)
+        .with_time_format(options.serialize_options.time_format)
+        .with_float_scientific(options.serialize_options.float_scientific)
+        .with_float_precision(options.serialize_options.float_precision)
+        .with_float_scientific_threshold(options.serialize_options.float_scientific_threshold)
+        .with_null_value(options.serialize_options.null)
+        .with_quote_style(options.serialize_options.quote_style)
+        // The writer runs on its own thread, serializing on the pool while it writes the
+        // previous rows and the pipeline computes the next morsels.
+        .n_threads(POOL.current_num_threads())
+        .batched(schema)
+}
+
 pub struct CsvSink {}
 impl CsvSink {
     #[allow(clippy::new_ret_no_self)]
-    pub fn new(path: &Path, options: CsvWriterOptions, schema: &Schema) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
-        let writer = CsvWriter::new(file)
-            .include_bom(options.include_bom)
-            .include_header(options.include_header)
-            .with_separator(options.serialize_options.separator)
-            .with_line_terminator(options.serialize_options.line_terminator)
-            .with_quote_char(options.serialize_options.quote_char)
-            .with_batch_size(options.batch_size)
-            .with_datetime_format(options.serialize_options.datetime_format)
-            .with_date_format(options.serialize_options.date_format)
-            .with_time_format(options.serialize_options.time_format)
-            .with_float_scientific(options.serialize_options.float_scientific)
-            .with_float_precision(options.serialize_options.float_precision)
-            .with_null_value(options.serialize_options.null)
-            .with_quote_style(options.serialize_options.quote_style)
-            .n_threads(1)
-            .batched(schema)?;
-
-        let writer = Box::new(writer) as Box<dyn SinkWriter + Send + Sync>;
+    pub fn new(
+        path: &Path,
+        mut options: CsvWriterOptions,
+        schema: &Schema,
+    ) -> PolarsResult<FilesSink> {
+        let (atomic_write, file) = AtomicFileWrite::create(path, options.write_mode)?;
+        if atomic_write.is_appending() {
+            options.include_header = false;
+            options.include_bom = false;
+        }
+        let maintain_order = options.maintain_order;
+        let writer = batched_csv_writer(file, options, schema)?;
+
+        let writer = Box::new(CommitOnFinish::new(Box::new(writer), atomic_write));
 
         let morsels_per_sink = morsels_per_sink();
         let backpressure = morsels_per_sink * 2;
@@ -39,7 +65,7 @@ impl CsvSink {
         let io_thread_handle = Arc::new(Some(init_writer_thread(
             receiver,
             writer,
-            options.maintain_order,
+            maintain_order,
             morsels_per_sink,
         )));
 
@@ -50,12 +76,13 @@ impl CsvSink {
     }
 }
 
-impl SinkWriter for polars_io::csv::write::BatchedWriter<std::fs::File> {
+impl SinkWriter for BatchedWriter<CompressedWriter<std::fs::File>> {
     fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         self.write_batch(df)
     }
 
     fn _finish(&mut self) -> PolarsResult<()> {
-        self.finish()
+        self.finish()?;
+        self.get_mut().finish()
     }
 }
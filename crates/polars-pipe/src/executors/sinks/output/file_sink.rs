@@ -3,6 +3,13 @@ use std::thread::JoinHandle;
 
 use crossbeam_channel::{Receiver, Sender};
 use polars_core::prelude::*;
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json"
+))]
+use polars_io::write_mode::AtomicFileWrite;
 
 use crate::operators::{
     DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult, StreamingVstacker,
@@ -14,6 +21,53 @@ pub(super) trait SinkWriter {
     fn _finish(&mut self) -> PolarsResult<()>;
 }
 
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json"
+))]
+/// Moves the file that `writer` writes to into place once the writer is finished.
+pub(super) struct CommitOnFinish {
+    writer: Box<dyn SinkWriter + Send>,
+    file: Option<AtomicFileWrite>,
+}
+
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json"
+))]
+impl CommitOnFinish {
+    pub(super) fn new(writer: Box<dyn SinkWriter + Send>, file: AtomicFileWrite) -> Self {
+        Self {
+            writer,
+            file: Some(file),
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json"
+))]
+impl SinkWriter for CommitOnFinish {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.writer._write_batch(df)
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        self.writer._finish()?;
+        match self.file.take() {
+            Some(file) => file.commit(),
+            None => Ok(()),
+        }
+    }
+}
+
 pub(super) fn init_writer_thread(
     receiver: Receiver<Option<DataChunk>>,
     mut writer: Box<dyn SinkWriter + Send>,
@@ -81,7 +135,8 @@ pub struct FilesSink {
 }
 
 impl Sink for FilesSink {
-    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        context.execution_state.should_stop()?;
         // don't add empty dataframes
         if chunk.data.height() > 0 {
             self.sender.send(Some(chunk)).unwrap();
@@ -96,7 +151,9 @@ impl Sink for FilesSink {
     fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
         Box::new(self.clone())
     }
-    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        // A cancelled sink drops its sender without flushing, so the writer doesn't commit.
+        context.execution_state.should_stop()?;
         // `None` indicates that we can flush all remaining chunks.
         self.sender.send(None).unwrap();
 
@@ -4,20 +4,29 @@ use crossbeam_channel::bounded;
 use polars_core::prelude::*;
 use polars_io::ipc::IpcWriterOptions;
 use polars_io::prelude::*;
+use polars_io::write_mode::AtomicFileWrite;
 
-use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::executors::sinks::output::file_sink::{
+    init_writer_thread, CommitOnFinish, FilesSink, SinkWriter,
+};
 use crate::pipeline::morsels_per_sink;
 
 pub struct IpcSink {}
 impl IpcSink {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(path: &Path, options: IpcWriterOptions, schema: &Schema) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
+        let (atomic_write, file) = AtomicFileWrite::create(path, options.write_mode)?;
         let writer = IpcWriter::new(file)
             .with_compression(options.compression)
-            .batched(schema)?;
+            .with_uncompressed_columns(options.uncompressed_columns)
+            .with_statistics(options.statistics);
+        let writer = if atomic_write.is_appending() {
+            writer.batched_append(schema)?
+        } else {
+            writer.batched(schema)?
+        };
 
-        let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
+        let writer = Box::new(CommitOnFinish::new(Box::new(writer), atomic_write));
 
         let morsels_per_sink = morsels_per_sink();
         let backpressure = morsels_per_sink * 2;
@@ -49,9 +58,16 @@ impl IpcCloudSink {
         ipc_options: IpcWriterOptions,
         schema: &Schema,
     ) -> PolarsResult<FilesSink> {
+        // Multipart uploads only become visible once they are complete.
+        polars_ensure!(
+            ipc_options.write_mode == WriteMode::Overwrite,
+            InvalidOperation: "cloud sinks can only overwrite files"
+        );
         let cloud_writer = polars_io::cloud::CloudWriter::new(uri, cloud_options).await?;
         let writer = IpcWriter::new(cloud_writer)
             .with_compression(ipc_options.compression)
+            .with_uncompressed_columns(ipc_options.uncompressed_columns)
+            .with_statistics(ipc_options.statistics)
             .batched(schema)?;
 
         let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
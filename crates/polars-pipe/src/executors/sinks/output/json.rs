@@ -2,18 +2,30 @@ use std::path::Path;
 
 use crossbeam_channel::bounded;
 use polars_core::prelude::*;
+use polars_io::compression::CompressedWriter;
 use polars_io::json::{BatchedWriter, JsonWriterOptions};
+use polars_io::write_mode::AtomicFileWrite;
 
-use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::executors::sinks::output::file_sink::{
+    init_writer_thread, CommitOnFinish, FilesSink, SinkWriter,
+};
 use crate::pipeline::morsels_per_sink;
 
-impl SinkWriter for BatchedWriter<std::fs::File> {
+pub(super) fn batched_json_writer(
+    file: std::fs::File,
+    options: &JsonWriterOptions,
+) -> PolarsResult<BatchedWriter<CompressedWriter<std::fs::File>>> {
+    let file = CompressedWriter::new(file, options.compression)?;
+    Ok(BatchedWriter::new(file))
+}
+
+impl SinkWriter for BatchedWriter<CompressedWriter<std::fs::File>> {
     fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         self.write_batch(df)
     }
 
     fn _finish(&mut self) -> PolarsResult<()> {
-        Ok(())
+        self.get_mut().finish()
     }
 }
 
@@ -25,10 +37,10 @@ impl JsonSink {
         options: JsonWriterOptions,
         _schema: &Schema,
     ) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
-        let writer = BatchedWriter::new(file);
+        let (atomic_write, file) = AtomicFileWrite::create(path, options.write_mode)?;
+        let writer = batched_json_writer(file, &options)?;
 
-        let writer = Box::new(writer) as Box<dyn SinkWriter + Send + Sync>;
+        let writer = Box::new(CommitOnFinish::new(Box::new(writer), atomic_write));
 
         let morsels_per_sink = morsels_per_sink();
         let backpressure = morsels_per_sink * 2;
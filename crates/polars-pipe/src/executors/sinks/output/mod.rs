@@ -1,24 +1,40 @@
+mod batches;
 #[cfg(feature = "csv")]
 mod csv;
-#[cfg(any(
-    feature = "parquet",
-    feature = "ipc",
-    feature = "csv",
-    feature = "json"
-))]
 mod file_sink;
 #[cfg(feature = "ipc")]
 mod ipc;
+#[cfg(feature = "ipc_streaming")]
+mod ipc_stream;
 #[cfg(feature = "json")]
 mod json;
 #[cfg(feature = "parquet")]
 mod parquet;
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json"
+))]
+mod partitioned;
+mod plugin;
 
+pub use batches::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
+#[cfg(feature = "ipc_streaming")]
+pub use ipc_stream::*;
 #[cfg(feature = "json")]
 pub use json::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json"
+))]
+pub use partitioned::*;
+pub use plugin::*;
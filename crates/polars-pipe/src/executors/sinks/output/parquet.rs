@@ -7,6 +7,7 @@ use polars_core::prelude::*;
 use polars_io::parquet::write::{
     BatchedWriter, ParquetWriteOptions, ParquetWriter, RowGroupIterColumns,
 };
+use polars_io::write_mode::AtomicFileWrite;
 
 use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
@@ -14,15 +15,33 @@ use crate::pipeline::morsels_per_sink;
 
 type RowGroups = Vec<RowGroupIterColumns<'static, PolarsError>>;
 
+pub(super) fn batched_parquet_writer<W: std::io::Write>(
+    writer: W,
+    options: ParquetWriteOptions,
+    schema: &Schema,
+) -> PolarsResult<BatchedWriter<W>> {
+    ParquetWriter::new(writer)
+        .with_compression(options.compression)
+        .with_data_page_size(options.data_pagesize_limit)
+        .with_statistics(options.statistics)
+        .with_row_group_size(options.row_group_size)
+        .with_categoricals_as_strings(options.categoricals_as_strings)
+        // This is important! Otherwise we will deadlock
+        // See: #7074
+        .set_parallel(false)
+        .batched(schema)
+}
+
 pub(super) fn init_row_group_writer_thread(
     receiver: Receiver<Option<(IdxSize, RowGroups)>>,
     writer: Arc<BatchedWriter<std::fs::File>>,
+    atomic_write: AtomicFileWrite,
     // this is used to determine when a batch of chunks should be written to disk
     // all chunks per push should be collected to determine in which order they should
     // be written
     morsels_per_sink: usize,
-) -> JoinHandle<()> {
-    std::thread::spawn(move || {
+) -> JoinHandle<PolarsResult<()>> {
+    std::thread::spawn(move || -> PolarsResult<()> {
         // keep chunks around until all chunks per sink are written
         // then we write them all at once.
         let mut batched = Vec::with_capacity(morsels_per_sink);
@@ -40,36 +59,30 @@ pub(super) fn init_row_group_writer_thread(
                 batched.sort_by_key(|chunk| chunk.0);
 
                 for (_, rg) in batched.drain(0..) {
-                    writer.write_row_groups(rg).unwrap()
+                    writer.write_row_groups(rg)?
                 }
             }
             if last_write {
-                writer.finish().unwrap();
-                return;
+                writer.finish()?;
+                return atomic_write.commit();
             }
         }
+        Ok(())
     })
 }
 
 #[derive(Clone)]
 pub struct ParquetSink {
     writer: Arc<BatchedWriter<std::fs::File>>,
-    io_thread_handle: Arc<Option<JoinHandle<()>>>,
+    io_thread_handle: Arc<Option<JoinHandle<PolarsResult<()>>>>,
     sender: Sender<Option<(IdxSize, RowGroups)>>,
 }
 impl ParquetSink {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(path: &Path, options: ParquetWriteOptions, schema: &Schema) -> PolarsResult<Self> {
-        let file = std::fs::File::create(path)?;
-        let writer = ParquetWriter::new(file)
-            .with_compression(options.compression)
-            .with_data_page_size(options.data_pagesize_limit)
-            .with_statistics(options.statistics)
-            .with_row_group_size(options.row_group_size)
-            // This is important! Otherwise we will deadlock
-            // See: #7074
-            .set_parallel(false)
-            .batched(schema)?;
+        options.write_mode.check_append_supported("parquet")?;
+        let (atomic_write, file) = AtomicFileWrite::create(path, options.write_mode)?;
+        let writer = batched_parquet_writer(file, options, schema)?;
 
         let writer = Arc::new(writer);
         let morsels_per_sink = morsels_per_sink();
@@ -80,6 +93,7 @@ impl ParquetSink {
         let io_thread_handle = Arc::new(Some(init_row_group_writer_thread(
             receiver,
             writer.clone(),
+            atomic_write,
             morsels_per_sink,
         )));
 
@@ -92,7 +106,8 @@ impl ParquetSink {
 }
 
 impl Sink for ParquetSink {
-    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        context.execution_state.should_stop()?;
         // Encode and compress row-groups on every thread.
         let row_groups = self
             .writer
@@ -113,7 +128,9 @@ impl Sink for ParquetSink {
         Box::new(self.clone())
     }
 
-    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        // A cancelled sink drops its sender without flushing, so the writer doesn't commit.
+        context.execution_state.should_stop()?;
         // `None` indicates that we can flush all remaining chunks.
         self.sender.send(None).unwrap();
 
@@ -124,7 +141,7 @@ impl Sink for ParquetSink {
             .take()
             .unwrap()
             .join()
-            .unwrap();
+            .unwrap()?;
 
         // return a dummy dataframe;
         Ok(FinalizedSink::Finished(Default::default()))
@@ -151,16 +168,13 @@ impl ParquetCloudSink {
         parquet_options: ParquetWriteOptions,
         schema: &Schema,
     ) -> PolarsResult<FilesSink> {
+        // Multipart uploads only become visible once they are complete.
+        polars_ensure!(
+            parquet_options.write_mode == polars_io::write_mode::WriteMode::Overwrite,
+            InvalidOperation: "cloud sinks can only overwrite files"
+        );
         let cloud_writer = polars_io::cloud::CloudWriter::new(uri, cloud_options).await?;
-        let writer = ParquetWriter::new(cloud_writer)
-            .with_compression(parquet_options.compression)
-            .with_data_page_size(parquet_options.data_pagesize_limit)
-            .with_statistics(parquet_options.statistics)
-            .with_row_group_size(parquet_options.row_group_size)
-            // This is important! Otherwise we will deadlock
-            // See: #7074
-            .set_parallel(false)
-            .batched(schema)?;
+        let writer = batched_parquet_writer(cloud_writer, parquet_options, schema)?;
 
         let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
 
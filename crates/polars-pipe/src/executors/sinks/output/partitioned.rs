@@ -0,0 +1,209 @@
+use std::path::{Path, PathBuf};
+
+use crossbeam_channel::bounded;
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_io::write_mode::AtomicFileWrite;
+#[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
+use polars_io::SerWriter;
+use polars_plan::plans::hive::format_hive_partition;
+use polars_plan::prelude::{FileType, PartitionSinkOptions};
+
+use crate::executors::sinks::output::file_sink::{
+    init_writer_thread, CommitOnFinish, FilesSink, SinkWriter,
+};
+use crate::pipeline::morsels_per_sink;
+
+/// The file that is currently being written to in a single partition directory.
+struct PartitionFile {
+    writer: Box<dyn SinkWriter + Send>,
+    /// Number of rows written to `writer`.
+    rows: usize,
+    /// Index of the file within the partition directory.
+    part: usize,
+}
+
+/// Writes batches into a `key=value/` directory tree, keeping a file open per partition.
+struct PartitionedWriter {
+    root: PathBuf,
+    file_type: FileType,
+    options: PartitionSinkOptions,
+    schema: SchemaRef,
+    open_files: PlHashMap<PathBuf, PartitionFile>,
+}
+
+impl PartitionedWriter {
+    fn create_file_writer(&self, path: &Path) -> PolarsResult<Box<dyn SinkWriter + Send>> {
+        let (atomic_write, file) = AtomicFileWrite::create(path, self.file_type.write_mode())?;
+        let writer = match &self.file_type {
+            #[cfg(feature = "parquet")]
+            FileType::Parquet(options) => Box::new(super::parquet::batched_parquet_writer(
+                file,
+                *options,
+                &self.schema,
+            )?) as Box<dyn SinkWriter + Send>,
+            #[cfg(feature = "ipc")]
+            FileType::Ipc(options) => Box::new(
+                polars_io::ipc::IpcWriter::new(file)
+                    .with_compression(options.compression)
+                    .with_uncompressed_columns(options.uncompressed_columns.clone())
+                    .with_statistics(options.statistics)
+                    .batched(&self.schema)?,
+            ) as Box<dyn SinkWriter + Send>,
+            #[cfg(feature = "ipc_streaming")]
+            FileType::IpcStream(options) => Box::new(
+                polars_io::ipc::IpcStreamWriter::new(file)
+                    .with_compression(options.compression)
+                    .with_compression_level(options.compression_level)
+                    .batched(&self.schema)?,
+            ) as Box<dyn SinkWriter + Send>,
+            #[cfg(feature = "csv")]
+            FileType::Csv(options) => Box::new(super::csv::batched_csv_writer(
+                file,
+                options.clone(),
+                &self.schema,
+            )?) as Box<dyn SinkWriter + Send>,
+            #[cfg(feature = "json")]
+            FileType::Json(options) => Box::new(super::json::batched_json_writer(file, options)?)
+                as Box<dyn SinkWriter + Send>,
+            #[allow(unreachable_patterns)]
+            _ => unreachable!(),
+        };
+        Ok(Box::new(CommitOnFinish::new(writer, atomic_write)))
+    }
+
+    fn partition_dir(&self, partition_df: &DataFrame) -> PolarsResult<PathBuf> {
+        let mut path = self.root.clone();
+        for key in &self.options.partition_by {
+            let value = partition_df.column(key)?.get(0)?;
+            path.push(format_hive_partition(key, &value));
+        }
+        Ok(path)
+    }
+
+    fn new_partition_file(&self, dir: &Path, part: usize) -> PolarsResult<PartitionFile> {
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(self.options.file_name(part, &self.file_type.extension()));
+        Ok(PartitionFile {
+            writer: self.create_file_writer(&path)?,
+            rows: 0,
+            part,
+        })
+    }
+
+    fn write_partition(&mut self, partition_df: DataFrame) -> PolarsResult<()> {
+        let dir = self.partition_dir(&partition_df)?;
+        if !self.open_files.contains_key(&dir) {
+            let file = self.new_partition_file(&dir, 0)?;
+            self.open_files.insert(dir.clone(), file);
+        }
+
+        let mut offset = 0;
+        while offset < partition_df.height() {
+            let file = self.open_files.get_mut(&dir).unwrap();
+            let remaining = match self.options.max_rows_per_file {
+                Some(max_rows) => max_rows.saturating_sub(file.rows),
+                None => usize::MAX,
+            };
+
+            if remaining == 0 {
+                file.writer._finish()?;
+                let part = file.part + 1;
+                let new_file = self.new_partition_file(&dir, part)?;
+                self.open_files.insert(dir.clone(), new_file);
+                continue;
+            }
+
+            let len = std::cmp::min(remaining, partition_df.height() - offset);
+            let batch = partition_df.slice(offset as i64, len);
+            file.writer._write_batch(&batch)?;
+            file.rows += len;
+            offset += len;
+        }
+        Ok(())
+    }
+}
+
+impl SinkWriter for PartitionedWriter {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let groups = df.group_by_stable(self.options.partition_by.iter())?;
+        let groups = groups.take_groups();
+
+        let partitions = match groups {
+            GroupsProxy::Idx(idx) => idx
+                .into_iter()
+                .map(|(_, group)| {
+                    // groups are in bounds and sorted
+                    unsafe { df._take_unchecked_slice_sorted(&group, false, IsSorted::Ascending) }
+                })
+                .collect::<Vec<_>>(),
+            GroupsProxy::Slice { groups, .. } => groups
+                .into_iter()
+                .map(|[first, len]| df.slice(first as i64, len as usize))
+                .collect::<Vec<_>>(),
+        };
+
+        for partition_df in partitions {
+            self.write_partition(partition_df)?;
+        }
+        Ok(())
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        for (_, mut file) in self.open_files.drain() {
+            file.writer._finish()?;
+        }
+        Ok(())
+    }
+}
+
+pub struct PartitionedSink {}
+impl PartitionedSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        path: &Path,
+        file_type: FileType,
+        options: PartitionSinkOptions,
+        schema: &Schema,
+    ) -> PolarsResult<FilesSink> {
+        polars_ensure!(
+            !options.partition_by.is_empty(),
+            InvalidOperation: "partitioned sink requires at least one `partition_by` column"
+        );
+        for key in &options.partition_by {
+            schema.try_get(key)?;
+        }
+        polars_ensure!(
+            options.max_rows_per_file != Some(0),
+            InvalidOperation: "`max_rows_per_file` must be larger than 0"
+        );
+        file_type
+            .write_mode()
+            .check_append_supported("partitioned")?;
+
+        let writer = PartitionedWriter {
+            root: polars_io::utils::resolve_homedir(path),
+            file_type,
+            options,
+            schema: Arc::new(schema.clone()),
+            open_files: PlHashMap::new(),
+        };
+        let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            true,
+            morsels_per_sink,
+        )));
+
+        Ok(FilesSink {
+            sender,
+            io_thread_handle,
+        })
+    }
+}
@@ -0,0 +1,84 @@
+use std::any::Any;
+
+use crossbeam_channel::bounded;
+use polars_core::prelude::*;
+use polars_plan::prelude::{SinkPlugin, SinkPluginRef};
+
+use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+use crate::pipeline::morsels_per_sink;
+
+struct PluginWriter(Arc<dyn SinkPlugin>);
+
+impl SinkWriter for PluginWriter {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.0.write_batch(df)
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        self.0.finalize()
+    }
+}
+
+/// Writes the output batches with a [`SinkPlugin`].
+pub struct PluginSink {}
+impl PluginSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(plugin: SinkPluginRef, schema: &Schema) -> PolarsResult<Box<dyn Sink>> {
+        plugin.0.open(schema)?;
+        if plugin.0.parallel() {
+            return Ok(Box::new(ParallelPluginSink { plugin }));
+        }
+
+        let writer = Box::new(PluginWriter(plugin.0));
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            true,
+            morsels_per_sink,
+        )));
+
+        Ok(Box::new(FilesSink {
+            sender,
+            io_thread_handle,
+        }))
+    }
+}
+
+/// Writes the batches on the threads that produce them.
+#[derive(Clone)]
+struct ParallelPluginSink {
+    plugin: SinkPluginRef,
+}
+
+impl Sink for ParallelPluginSink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        self.plugin.0.write_batch(&chunk.data)?;
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, _other: &mut dyn Sink) {
+        // Nothing to do
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(self.clone())
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        self.plugin.0.finalize()?;
+        Ok(FinalizedSink::Finished(Default::default()))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "plugin_sink"
+    }
+}
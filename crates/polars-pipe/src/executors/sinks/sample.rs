@@ -0,0 +1,125 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use polars_core::frame::sample::sample_keys;
+use polars_core::prelude::*;
+use polars_core::utils::{accumulate_dataframes_vertical_unchecked, NoNull};
+
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+
+const KEY: &str = "__POLARS_SAMPLE_KEY";
+const POSITION: &str = "__POLARS_SAMPLE_POSITION";
+
+/// The reservoir isn't compacted before it has this many rows.
+const MIN_COMPACT_HEIGHT: usize = 1 << 16;
+
+/// Keeps a reservoir sample of `n` rows per stratum of the rows that stream by.
+///
+/// Every row gets the random key of weighted reservoir sampling, only the rows with the largest
+/// keys are kept. Their position in the stream is kept too, to return them in the order of the
+/// input.
+#[derive(Clone)]
+pub struct ReservoirSampleSink {
+    args: Arc<SampleArgs>,
+    // Shared by the sinks of all threads, so the keys of a chunk don't depend on the thread
+    // that sees it.
+    seed: u64,
+    schema: SchemaRef,
+    reservoir: Vec<DataFrame>,
+    height: usize,
+    compacted_height: usize,
+}
+
+impl ReservoirSampleSink {
+    pub fn new(args: Arc<SampleArgs>, schema: SchemaRef) -> Self {
+        let seed = args.seed();
+        ReservoirSampleSink {
+            args,
+            seed,
+            schema,
+            reservoir: vec![],
+            height: 0,
+            compacted_height: 0,
+        }
+    }
+
+    /// Keep the rows with the largest keys of every stratum.
+    fn compact(&mut self, partial: bool) -> PolarsResult<DataFrame> {
+        let df = accumulate_dataframes_vertical_unchecked(std::mem::take(&mut self.reservoir));
+        let keys = df.column(KEY)?.f64()?;
+        let idx = df.sample_top_keys(keys, &self.args.by, self.args.size, partial)?;
+        // SAFETY: the indices are rows of `df`.
+        Ok(unsafe { df.take_unchecked(&IdxCa::from_vec("", idx)) })
+    }
+
+    fn push(&mut self, df: DataFrame) -> PolarsResult<()> {
+        self.height += df.height();
+        self.reservoir.push(df);
+        if self.height > MIN_COMPACT_HEIGHT.max(2 * self.compacted_height) {
+            let df = self.compact(true)?;
+            self.height = df.height();
+            self.compacted_height = df.height();
+            self.reservoir.push(df);
+        }
+        Ok(())
+    }
+}
+
+impl Sink for ReservoirSampleSink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        let mut df = chunk.data;
+        let height = df.height();
+        if height == 0 {
+            return Ok(SinkResult::CanHaveMoreInput);
+        }
+        let weights = self.args.weights(&df)?;
+        let seed = self.seed.wrapping_add(chunk.chunk_index as u64);
+        let keys = sample_keys(height, weights.as_ref(), seed);
+        let offset = (chunk.chunk_index as u64) << 32;
+        let position: NoNull<UInt64Chunked> = (0..height as u64).map(|i| offset | i).collect();
+        df.with_column(keys.with_name(KEY))?;
+        df.with_column(position.into_inner().with_name(POSITION))?;
+        self.push(df)?;
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other
+            .as_any()
+            .downcast_mut::<ReservoirSampleSink>()
+            .unwrap();
+        self.height += other.height;
+        self.reservoir.append(&mut other.reservoir);
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        let mut new = self.clone();
+        new.reservoir = vec![];
+        new.height = 0;
+        new.compacted_height = 0;
+        Box::new(new)
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        if self.reservoir.is_empty() {
+            let df = DataFrame::empty_with_schema(&self.schema).sample_by(&self.args)?;
+            return Ok(FinalizedSink::Finished(df));
+        }
+        let df = self.compact(false)?;
+        let df = if self.args.shuffle {
+            // The keys are random, so are the rows in their order.
+            df.sort([KEY], SortMultipleOptions::default())?
+        } else {
+            df.sort([POSITION], SortMultipleOptions::default())?
+        };
+        Ok(FinalizedSink::Finished(df.drop(KEY)?.drop(POSITION)?))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "reservoir_sample_sink"
+    }
+}
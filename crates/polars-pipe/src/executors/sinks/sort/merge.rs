@@ -0,0 +1,327 @@
+//! External merge sort.
+//!
+//! The sink sorts the chunks it has in memory and spills them as a sorted run of record
+//! batches. The source merges the runs, holding only a single batch of every run in memory.
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+
+use arrow::datatypes::ArrowSchema;
+use arrow::io::ipc::read::{read_file_metadata, FileReader};
+use polars_core::chunked_array::ops::search_sorted::{binary_search_ca, SearchSortedSide};
+use polars_core::prelude::*;
+use polars_core::utils::{accumulate_dataframes_vertical_unchecked, split_df};
+use polars_core::POOL;
+use polars_io::ipc::{IpcCompression, IpcWriter};
+use polars_io::SerWriter;
+use polars_ops::prelude::search_sorted;
+
+use crate::executors::sinks::io::IOThread;
+use crate::executors::sinks::sort::ooc::read_df;
+use crate::executors::sinks::sort::sink::sort_accumulated;
+use crate::executors::sources::get_source_index;
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Source, SourceResult};
+
+// Maximum size of the record batches of a run. The merge keeps one of those per run in memory.
+const RUN_BATCH_SIZE: usize = 1 << 22;
+const RUNS_DIR: &str = "runs";
+// Rows with a null sort key are spilled separately as they are emitted as a whole before or
+// after the merged runs.
+const NULLS_DIR: &str = "nulls";
+
+fn write_ipc(path: &Path, df: &DataFrame, batch_rows: usize) -> PolarsResult<()> {
+    let file = File::create(path)?;
+    let mut writer = IpcWriter::new(file)
+        .with_compression(Some(IpcCompression::LZ4))
+        .batched(&df.schema())?;
+    for offset in (0..df.height()).step_by(batch_rows) {
+        writer.write_batch(&df.slice(offset as i64, batch_rows))?;
+    }
+    writer.finish()
+}
+
+/// Sort `df` and spill it as a run of record batches. `run_size` is the size of the runs the
+/// sink aims for.
+pub(super) fn spill_run(
+    io_thread: &IOThread,
+    df: DataFrame,
+    sort_idx: usize,
+    sort_options: SortOptions,
+    run_size: usize,
+) -> PolarsResult<()> {
+    let df = sort_accumulated(df, sort_idx, None, sort_options)?;
+    let run = io_thread.thread_local_count.fetch_add(1, Ordering::Relaxed);
+
+    let null_count = df.get_columns()[sort_idx].null_count();
+    let valid_count = df.height() - null_count;
+    let (valid, nulls) = if sort_options.nulls_last {
+        df.split_at(valid_count as i64)
+    } else {
+        let (nulls, valid) = df.split_at(null_count as i64);
+        (valid, nulls)
+    };
+
+    if valid.height() > 0 {
+        let batch_size = std::cmp::min(run_size / 8, RUN_BATCH_SIZE);
+        let batch_rows = batch_size * valid.height() / valid.estimated_size().max(1);
+        let dir = io_thread.dir.join(RUNS_DIR);
+        std::fs::create_dir_all(&dir)?;
+        write_ipc(&dir.join(format!("{run}.ipc")), &valid, batch_rows.max(1))?;
+    }
+    if nulls.height() > 0 {
+        let dir = io_thread.dir.join(NULLS_DIR);
+        std::fs::create_dir_all(&dir)?;
+        write_ipc(&dir.join(format!("{run}.ipc")), &nulls, nulls.height())?;
+    }
+    Ok(())
+}
+
+fn list_files(dir: &Path) -> PolarsResult<Vec<PathBuf>> {
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+    let files = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    Ok(files)
+}
+
+/// Index at which `value` would be inserted in the sorted `keys`.
+fn search(
+    keys: &Series,
+    value: &Series,
+    side: SearchSortedSide,
+    descending: bool,
+) -> PolarsResult<usize> {
+    let idx = match keys.dtype() {
+        // The row-encoded keys of a sort by multiple columns.
+        DataType::BinaryOffset => binary_search_ca(
+            keys.binary_offset()?,
+            value.binary_offset()?.iter(),
+            side,
+            descending,
+        )[0],
+        _ => search_sorted(keys, value, side, descending)?
+            .get(0)
+            .unwrap(),
+    };
+    Ok(idx as usize)
+}
+
+/// A spilled run that is read a record batch at a time.
+struct Run {
+    path: PathBuf,
+    reader: FileReader<File>,
+    schema: ArrowSchema,
+    n_batches: usize,
+    batches_read: usize,
+    // The rows of the last read batch that are not merged yet.
+    current: DataFrame,
+}
+
+impl Run {
+    fn try_new(path: PathBuf) -> PolarsResult<Self> {
+        let mut file = polars_utils::open_file(&path)?;
+        let metadata = read_file_metadata(&mut file)?;
+        let schema = metadata.schema.as_ref().clone();
+        let n_batches = metadata.blocks.len();
+        Ok(Self {
+            path,
+            reader: FileReader::new(file, metadata, None, None),
+            schema,
+            n_batches,
+            batches_read: 0,
+            current: DataFrame::empty(),
+        })
+    }
+
+    /// Read the next batch if all rows of the current one are merged. Returns `false` once
+    /// the run is merged completely.
+    fn fill(&mut self) -> PolarsResult<bool> {
+        while self.current.height() == 0 {
+            let Some(batch) = self.reader.next() else {
+                return Ok(false);
+            };
+            self.current = DataFrame::try_from((batch?, self.schema.fields.as_slice()))?;
+            self.batches_read += 1;
+        }
+        Ok(true)
+    }
+
+    fn has_more_batches(&self) -> bool {
+        self.batches_read < self.n_batches
+    }
+}
+
+pub(super) fn merge_runs(
+    io_thread: IOThread,
+    sort_idx: usize,
+    sort_options: SortOptions,
+    slice: Option<(i64, usize)>,
+    verbose: bool,
+    ooc_start: Instant,
+) -> PolarsResult<FinalizedSink> {
+    let runs = list_files(&io_thread.dir.join(RUNS_DIR))?
+        .into_iter()
+        .map(Run::try_new)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let nulls = list_files(&io_thread.dir.join(NULLS_DIR))?;
+    if verbose {
+        eprintln!("merging {} sorted runs", runs.len());
+    }
+
+    Ok(FinalizedSink::Source(Box::new(MergeSource {
+        runs,
+        nulls: nulls.into_iter(),
+        sort_idx,
+        sort_options,
+        slice,
+        n_threads: POOL.current_num_threads(),
+        chunk_offset: get_source_index(1) as IdxSize,
+        finished: false,
+        io_thread,
+        ooc_start,
+    })))
+}
+
+struct MergeSource {
+    runs: Vec<Run>,
+    nulls: std::vec::IntoIter<PathBuf>,
+    sort_idx: usize,
+    sort_options: SortOptions,
+    slice: Option<(i64, usize)>,
+    n_threads: usize,
+    chunk_offset: IdxSize,
+    finished: bool,
+    io_thread: IOThread,
+    // Start of the OOC sort operation.
+    ooc_start: Instant,
+}
+
+impl MergeSource {
+    fn next_nulls(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let Some(path) = self.nulls.next() else {
+            return Ok(None);
+        };
+        let df = read_df(&path)?;
+        self.io_thread.clean(path);
+        Ok(Some(df))
+    }
+
+    /// Merge the next rows of the runs. All rows up to the smallest last key of the current
+    /// batches can be merged, as the rows that follow in any run are larger.
+    fn merge_next(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let mut i = 0;
+        while i < self.runs.len() {
+            if self.runs[i].fill()? {
+                i += 1;
+            } else {
+                // Close the file before it is removed.
+                let Run { path, .. } = self.runs.swap_remove(i);
+                self.io_thread.clean(path);
+            }
+        }
+        if self.runs.is_empty() {
+            return Ok(None);
+        }
+
+        let descending = self.sort_options.descending;
+        let mut bound: Option<Series> = None;
+        for run in self.runs.iter().filter(|run| run.has_more_batches()) {
+            let keys = &run.current.get_columns()[self.sort_idx];
+            let last = keys.slice(-1, 1);
+            let is_smaller = match &bound {
+                None => true,
+                Some(bound) => search(bound, &last, SearchSortedSide::Left, descending)? == 0,
+            };
+            if is_smaller {
+                bound = Some(last);
+            }
+        }
+
+        let mut merged = Vec::with_capacity(self.runs.len());
+        for run in self.runs.iter_mut() {
+            let n = match &bound {
+                Some(bound) => search(
+                    &run.current.get_columns()[self.sort_idx],
+                    bound,
+                    SearchSortedSide::Right,
+                    descending,
+                )?,
+                // None of the runs has more batches.
+                None => run.current.height(),
+            };
+            let (head, tail) = run.current.split_at(n as i64);
+            merged.push(head);
+            run.current = tail;
+        }
+        let df = accumulate_dataframes_vertical_unchecked(merged);
+        sort_accumulated(df, self.sort_idx, None, self.sort_options).map(Some)
+    }
+
+    fn next_df(&mut self) -> PolarsResult<Option<DataFrame>> {
+        if !self.sort_options.nulls_last {
+            if let Some(df) = self.next_nulls()? {
+                return Ok(Some(df));
+            }
+        }
+        if let Some(df) = self.merge_next()? {
+            return Ok(Some(df));
+        }
+        self.next_nulls()
+    }
+
+    /// Apply the slice of the sort to the next output, returns `None` if `df` is skipped.
+    fn apply_slice(&mut self, df: DataFrame) -> Option<DataFrame> {
+        let Some((offset, len)) = &mut self.slice else {
+            return Some(df);
+        };
+        debug_assert!(*offset >= 0);
+        if *offset as usize >= df.height() {
+            *offset -= df.height() as i64;
+            return None;
+        }
+        let df = df.slice(*offset, *len);
+        *offset = 0;
+        *len -= df.height();
+        if *len == 0 {
+            self.finished = true;
+        }
+        Some(df)
+    }
+
+    fn finish_batch(&mut self, dfs: Vec<DataFrame>) -> Vec<DataChunk> {
+        let chunk_offset = self.chunk_offset;
+        self.chunk_offset += dfs.len() as IdxSize;
+        dfs.into_iter()
+            .enumerate()
+            .map(|(i, df)| DataChunk {
+                chunk_index: chunk_offset + i as IdxSize,
+                data: df,
+            })
+            .collect()
+    }
+}
+
+impl Source for MergeSource {
+    fn get_batches(&mut self, context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        while !self.finished {
+            let Some(df) = self.next_df()? else {
+                break;
+            };
+            if let Some(mut df) = self.apply_slice(df) {
+                let dfs = split_df(&mut df, self.n_threads, true);
+                return Ok(SourceResult::GotMoreData(self.finish_batch(dfs)));
+            }
+        }
+        if context.verbose {
+            eprintln!("full ooc sort took: {:?}", self.ooc_start.elapsed());
+        }
+        Ok(SourceResult::Finished)
+    }
+
+    fn fmt(&self) -> &str {
+        "sort_merge_source"
+    }
+}
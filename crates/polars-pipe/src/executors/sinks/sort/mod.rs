@@ -1,3 +1,4 @@
+mod merge;
 mod ooc;
 mod sink;
 mod sink_multiple;
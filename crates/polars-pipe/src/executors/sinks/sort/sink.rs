@@ -8,9 +8,11 @@ use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{AnyValue, SchemaRef, Series, SortOptions};
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::POOL;
 
 use crate::executors::sinks::io::{block_thread_until_io_thread_done, IOThread};
 use crate::executors::sinks::memory::MemTracker;
+use crate::executors::sinks::sort::merge::{merge_runs, spill_run};
 use crate::executors::sinks::sort::ooc::sort_ooc;
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
 use crate::pipeline::{morsels_per_sink, FORCE_OOC};
@@ -23,6 +25,8 @@ pub struct SortSink {
     mem_track: MemTracker,
     // sort in-memory or out-of-core
     ooc: bool,
+    // out-of-core, spill sorted runs and merge them instead of partitioning the data
+    merge_runs: bool,
     // when ooc, we write to disk using an IO thread
     // RwLock as we want to have multiple readers at once.
     io_thread: Arc<RwLock<Option<IOThread>>>,
@@ -50,6 +54,9 @@ impl SortSink {
     ) -> Self {
         // for testing purposes
         let ooc = std::env::var(FORCE_OOC).is_ok();
+        // Spilling the partitions only exists in the partitioning sort.
+        let merge_runs = std::env::var("POLARS_OOC_SORT_PARTITION").is_err()
+            && std::env::var("POLARS_SPILL_SORT_PARTITIONS").is_err();
         let n_morsels_per_sink = morsels_per_sink();
 
         let mut out = Self {
@@ -57,6 +64,7 @@ impl SortSink {
             chunks: Default::default(),
             mem_track: MemTracker::new(n_morsels_per_sink),
             ooc,
+            merge_runs,
             io_thread: Default::default(),
             sort_idx,
             slice,
@@ -113,6 +121,9 @@ impl SortSink {
     }
 
     fn dump(&mut self, force: bool) -> PolarsResult<()> {
+        if self.merge_runs {
+            return self.dump_run(force);
+        }
         let larger_than_32_mb = self.current_chunks_size > (1 << 25);
         if (force || larger_than_32_mb) && !self.chunks.is_empty() {
             // into a single chunk because multiple file IO's is expensive
@@ -139,6 +150,34 @@ impl SortSink {
         }
         Ok(())
     }
+
+    fn dump_run(&mut self, force: bool) -> PolarsResult<()> {
+        // Larger runs mean fewer runs to merge, but we need memory to sort them.
+        let run_size = std::env::var("POLARS_OOC_SORT_RUN_SIZE")
+            .map(|v| v.parse::<usize>().expect("integer"))
+            .unwrap_or_else(|_| {
+                std::cmp::max(
+                    self.mem_track.get_available() / (3 * POOL.current_num_threads()),
+                    1 << 25,
+                )
+            });
+        if (force || self.current_chunks_size > run_size) && !self.chunks.is_empty() {
+            let df = accumulate_dataframes_vertical_unchecked(self.chunks.drain(..));
+            if df.height() > 0 {
+                let iot = self.io_thread.read().unwrap();
+                spill_run(
+                    iot.as_ref().unwrap(),
+                    df,
+                    self.sort_idx,
+                    SortOptions::from(&self.sort_options),
+                    run_size,
+                )?;
+            }
+            self.current_chunk_rows = 0;
+            self.current_chunks_size = 0;
+        }
+        Ok(())
+    }
 }
 
 impl Sink for SortSink {
@@ -172,6 +211,7 @@ impl Sink for SortSink {
             chunks: Default::default(),
             mem_track: self.mem_track.clone(),
             ooc: self.ooc,
+            merge_runs: self.merge_runs,
             io_thread: self.io_thread.clone(),
             sort_idx: self.sort_idx,
             slice: self.slice,
@@ -190,6 +230,21 @@ impl Sink for SortSink {
             let mut lock = self.io_thread.write().unwrap();
             let io_thread = lock.take().unwrap();
 
+            if self.merge_runs {
+                let instant = self.ooc_start.unwrap();
+                if context.verbose {
+                    eprintln!("finished sinking into OOC sort in {:?}", instant.elapsed());
+                }
+                return merge_runs(
+                    io_thread,
+                    self.sort_idx,
+                    SortOptions::from(&self.sort_options),
+                    self.slice,
+                    context.verbose,
+                    instant,
+                );
+            }
+
             let dist = Series::from_any_values("", &self.dist_sample, true).unwrap();
             let dist = dist.sort_with(SortOptions::from(&self.sort_options))?;
 
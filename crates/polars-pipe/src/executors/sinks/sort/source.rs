@@ -101,6 +101,8 @@ impl SortSource {
                     nulls_last: self.nulls_last,
                     multithreaded: true,
                     maintain_order: false,
+                    stable: true,
+                    collation: None,
                 },
             ),
             Some((offset, len)) => {
@@ -119,6 +121,8 @@ impl SortSource {
                             nulls_last: self.nulls_last,
                             multithreaded: true,
                             maintain_order: false,
+                            stable: true,
+                            collation: None,
                         },
                     );
                     *len = len.saturating_sub(df_len);
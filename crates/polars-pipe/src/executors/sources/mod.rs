@@ -5,6 +5,7 @@ mod ipc_one_shot;
 #[cfg(feature = "parquet")]
 mod parquet;
 mod reproject;
+mod scan_source;
 mod union;
 
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -16,6 +17,7 @@ pub(crate) use ipc_one_shot::*;
 #[cfg(feature = "parquet")]
 pub(crate) use parquet::*;
 pub(crate) use reproject::*;
+pub(crate) use scan_source::*;
 pub(crate) use union::*;
 
 #[cfg(feature = "csv")]
@@ -6,7 +6,8 @@ use std::sync::Arc;
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::config::{self, get_file_prefetch_size};
 use polars_core::error::*;
-use polars_core::prelude::Series;
+use polars_core::prelude::{DataFrame, Series};
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_core::POOL;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::metadata::FileMetaDataRef;
@@ -45,6 +46,8 @@ pub struct ParquetSource {
     run_async: bool,
     prefetch_size: usize,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    /// Minimum number of rows of the morsels this source produces.
+    chunk_size: usize,
 }
 
 impl ParquetSource {
@@ -206,6 +209,13 @@ impl ParquetSource {
         }
         let run_async = paths.first().map(is_cloud_url).unwrap_or(false) || config::force_async();
 
+        let n_cols = file_options
+            .with_columns
+            .as_ref()
+            .map(|v| v.len())
+            .unwrap_or(file_info.schema.len());
+        let chunk_size = determine_chunk_size(n_cols, n_threads)?;
+
         let mut source = ParquetSource {
             batched_readers: VecDeque::new(),
             n_threads,
@@ -222,6 +232,7 @@ impl ParquetSource {
             run_async,
             prefetch_size,
             predicate,
+            chunk_size,
         };
         // Already start downloading when we deal with cloud urls.
         if run_async {
@@ -273,55 +284,98 @@ impl ParquetSource {
     }
 }
 
-impl Source for ParquetSource {
-    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
-        self.prefetch_files()?;
+/// Merge consecutive batches that are smaller than `chunk_size`, so that batches of many small
+/// files form a single morsel.
+fn coalesce_batches(batches: Vec<DataFrame>, chunk_size: usize) -> Vec<DataFrame> {
+    let mut out = Vec::with_capacity(batches.len());
+    let mut pending: Vec<DataFrame> = vec![];
+    let mut pending_rows = 0;
 
-        let Some(mut reader) = self.batched_readers.pop_front() else {
-            // If there was no new reader, we depleted all of them and are finished.
-            return Ok(SourceResult::Finished);
-        };
+    for df in batches {
+        pending_rows += df.height();
+        pending.push(df);
 
-        let batches =
-            get_runtime().block_on_potential_spawn(reader.next_batches(self.n_threads))?;
+        if pending_rows >= chunk_size {
+            out.push(finish_pending(&mut pending));
+            pending_rows = 0;
+        }
+    }
+    if !pending.is_empty() {
+        out.push(finish_pending(&mut pending));
+    }
+    out
+}
 
-        Ok(match batches {
-            None => {
-                if reader.limit_reached() {
-                    return Ok(SourceResult::Finished);
-                }
+fn finish_pending(pending: &mut Vec<DataFrame>) -> DataFrame {
+    if pending.len() == 1 {
+        return pending.pop().unwrap();
+    }
+    let mut df = accumulate_dataframes_vertical_unchecked(pending.drain(..));
+    df.as_single_chunk();
+    df
+}
 
-                // reset the reader
-                self.init_next_reader()?;
-                return self.get_batches(_context);
-            },
-            Some(batches) => {
-                let idx_offset = get_source_index(0);
-                let out = batches
-                    .into_iter()
-                    .enumerate_u32()
-                    .map(|(i, data)| {
+impl Source for ParquetSource {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        // Small files produce small batches. We keep pulling from the next readers until we
+        // have enough rows to fill a morsel for every thread.
+        let min_rows = self.chunk_size.saturating_mul(self.n_threads);
+        let mut batches = vec![];
+        let mut n_rows = 0;
+
+        while n_rows < min_rows {
+            self.prefetch_files()?;
+
+            let Some(mut reader) = self.batched_readers.pop_front() else {
+                // If there was no new reader, we depleted all of them.
+                break;
+            };
+
+            match get_runtime().block_on_potential_spawn(reader.next_batches(self.n_threads))? {
+                None => {
+                    if reader.limit_reached() {
+                        // Don't open any other files.
+                        self.batched_readers.clear();
+                        self.iter = self.paths.len()..self.paths.len();
+                        break;
+                    }
+
+                    // reset the reader
+                    self.init_next_reader()?;
+                },
+                Some(new_batches) => {
+                    for data in &new_batches {
                         // Keep the row limit updated so the next reader will have a correct limit.
-                        if let Some(n_rows) = &mut self.file_options.n_rows {
-                            *n_rows = n_rows.saturating_sub(data.height())
+                        if let Some(limit) = &mut self.file_options.n_rows {
+                            *limit = limit.saturating_sub(data.height())
                         }
+                        n_rows += data.height();
+                    }
+                    batches.extend(new_batches);
 
-                        DataChunk {
-                            chunk_index: (idx_offset + i) as IdxSize,
-                            data,
-                        }
-                    })
-                    .collect::<Vec<_>>();
-                get_source_index(out.len() as u32);
-
-                let result = SourceResult::GotMoreData(out);
-                // We are not yet done with this reader.
-                // Ensure it is used in next iteration.
-                self.batched_readers.push_front(reader);
-
-                result
-            },
-        })
+                    // We are not yet done with this reader.
+                    // Ensure it is used in next iteration.
+                    self.batched_readers.push_front(reader);
+                },
+            }
+        }
+
+        if batches.is_empty() {
+            return Ok(SourceResult::Finished);
+        }
+
+        let idx_offset = get_source_index(0);
+        let out = coalesce_batches(batches, self.chunk_size)
+            .into_iter()
+            .enumerate_u32()
+            .map(|(i, data)| DataChunk {
+                chunk_index: (idx_offset + i) as IdxSize,
+                data,
+            })
+            .collect::<Vec<_>>();
+        get_source_index(out.len() as u32);
+
+        Ok(SourceResult::GotMoreData(out))
     }
     fn fmt(&self) -> &str {
         "parquet"
@@ -0,0 +1,47 @@
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+use polars_plan::plans::{open_scan_source, ScanSource, ScanSourceArgs, ScanSourceStream};
+use polars_utils::IdxSize;
+
+use crate::executors::sources::get_source_index;
+use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+
+/// Pulls the batches of a user defined [`ScanSource`].
+pub struct ScanSourceSource {
+    name: &'static str,
+    batches: Mutex<ScanSourceStream>,
+}
+
+impl ScanSourceSource {
+    pub(crate) fn new(source: &dyn ScanSource, args: ScanSourceArgs) -> PolarsResult<Self> {
+        Ok(Self {
+            name: source.name(),
+            batches: Mutex::new(open_scan_source(source, args)?),
+        })
+    }
+}
+
+impl Source for ScanSourceSource {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        let batches = self.batches.get_mut().unwrap();
+        for df in batches {
+            let mut df = df?;
+            // Empty batches would only be passed through the pipeline.
+            if df.height() == 0 {
+                continue;
+            }
+            df.as_single_chunk();
+            let chunk_index = get_source_index(1) as IdxSize;
+            return Ok(SourceResult::GotMoreData(vec![DataChunk::new(
+                chunk_index,
+                df,
+            )]));
+        }
+        Ok(SourceResult::Finished)
+    }
+
+    fn fmt(&self) -> &str {
+        self.name
+    }
+}
@@ -4,7 +4,7 @@ use std::rc::Rc;
 use hashbrown::hash_map::Entry;
 use polars_core::prelude::*;
 use polars_core::with_match_physical_integer_polars_type;
-#[cfg(feature = "parquet")]
+use polars_expr::{create_physical_filter_expr, ExpressionConversionState};
 use polars_io::predicates::{PhysicalIoExpr, StatsEvaluator};
 use polars_ops::prelude::JoinType;
 use polars_plan::prelude::expr_ir::{ExprIR, OutputName};
@@ -18,7 +18,7 @@ use crate::executors::{operators, sources};
 use crate::expressions::PhysicalPipedExpr;
 use crate::operators::{Operator, Sink as SinkTrait, Source};
 use crate::pipeline::dispatcher::ThreadedSink;
-use crate::pipeline::{PhysOperator, PipeLine};
+use crate::pipeline::{max_memory, PhysOperator, PipeLine};
 
 pub type CallBacks = PlHashMap<Node, PlaceHolder>;
 
@@ -37,6 +37,25 @@ where
         .collect()
 }
 
+fn to_io_expr(p: Arc<dyn PhysicalPipedExpr>) -> Arc<dyn PhysicalIoExpr> {
+    // Arc's all the way down. :(
+    // Temporarily until: https://github.com/rust-lang/rust/issues/65991
+    // stabilizes
+    struct Wrap {
+        p: Arc<dyn PhysicalPipedExpr>,
+    }
+    impl PhysicalIoExpr for Wrap {
+        fn evaluate_io(&self, df: &DataFrame) -> PolarsResult<Series> {
+            self.p.evaluate_io(df)
+        }
+        fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
+            self.p.as_stats_evaluator()
+        }
+    }
+
+    Arc::new(Wrap { p })
+}
+
 #[allow(unused_variables)]
 fn get_source<F>(
     source: IR,
@@ -60,10 +79,10 @@ where
             let mut df = (*df).clone();
             if push_predicate {
                 if let Some(predicate) = selection {
-                    let predicate = to_physical(&predicate, expr_arena, output_schema.as_ref())?;
-                    let op = operators::FilterOperator { predicate };
-                    let op = Box::new(op) as Box<dyn Operator>;
-                    operator_objects.push(op)
+                    let schema = output_schema
+                        .clone()
+                        .unwrap_or_else(|| Arc::new(df.schema()));
+                    operator_objects.push(filter_operator(&predicate, expr_arena, &schema)?)
                 }
                 // projection is free
                 if let Some(schema) = output_schema {
@@ -83,20 +102,23 @@ where
             scan_type,
         } => {
             // Add predicate to operators.
-            // Except for parquet, as that format can use statistics to prune file/row-groups.
+            // Except for parquet, as that format can use statistics to prune file/row-groups,
+            // and for scan sources, which apply the predicate they accepted themselves.
             #[cfg(feature = "parquet")]
             let is_parquet = matches!(scan_type, FileScan::Parquet { .. });
             #[cfg(not(feature = "parquet"))]
             let is_parquet = false;
+            let applies_predicate = is_parquet || matches!(scan_type, FileScan::Source { .. });
 
-            if let (false, true, Some(predicate)) = (is_parquet, push_predicate, predicate.clone())
+            if let (false, true, Some(predicate)) =
+                (applies_predicate, push_predicate, predicate.clone())
             {
                 #[cfg(feature = "parquet")]
                 debug_assert!(!matches!(scan_type, FileScan::Parquet { .. }));
-                let predicate = to_physical(&predicate, expr_arena, output_schema.as_ref())?;
-                let op = operators::FilterOperator { predicate };
-                let op = Box::new(op) as Box<dyn Operator>;
-                operator_objects.push(op)
+                let schema = output_schema
+                    .clone()
+                    .unwrap_or_else(|| file_info.schema.clone());
+                operator_objects.push(filter_operator(&predicate, expr_arena, &schema)?)
             }
             match scan_type {
                 #[cfg(feature = "csv")]
@@ -120,22 +142,7 @@ where
                         .as_ref()
                         .map(|predicate| {
                             let p = to_physical(predicate, expr_arena, output_schema.as_ref())?;
-                            // Arc's all the way down. :(
-                            // Temporarily until: https://github.com/rust-lang/rust/issues/65991
-                            // stabilizes
-                            struct Wrap {
-                                p: Arc<dyn PhysicalPipedExpr>,
-                            }
-                            impl PhysicalIoExpr for Wrap {
-                                fn evaluate_io(&self, df: &DataFrame) -> PolarsResult<Series> {
-                                    self.p.evaluate_io(df)
-                                }
-                                fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
-                                    self.p.as_stats_evaluator()
-                                }
-                            }
-
-                            PolarsResult::Ok(Arc::new(Wrap { p }) as Arc<dyn PhysicalIoExpr>)
+                            PolarsResult::Ok(to_io_expr(p))
                         })
                         .transpose()?;
                     let src = sources::ParquetSource::new(
@@ -151,6 +158,26 @@ where
                     )?;
                     Ok(Box::new(src) as Box<dyn Source>)
                 },
+                FileScan::Source { source } => {
+                    let predicate = predicate
+                        .as_ref()
+                        .map(|predicate| {
+                            let p = to_physical(predicate, expr_arena, output_schema.as_ref())?;
+                            PolarsResult::Ok(ScanPredicate {
+                                expr: p.expression(),
+                                evaluator: to_io_expr(p),
+                            })
+                        })
+                        .transpose()?;
+                    let args = ScanSourceArgs::new(
+                        &file_info,
+                        &file_options,
+                        output_schema.as_ref(),
+                        predicate,
+                    );
+                    let src = sources::ScanSourceSource::new(source.as_ref(), args)?;
+                    Ok(Box::new(src) as Box<dyn Source>)
+                },
                 _ => todo!(),
             }
         },
@@ -189,7 +216,12 @@ where
                         },
                         #[cfg(feature = "ipc")]
                         FileType::Ipc(options) => {
-                            Box::new(IpcSink::new(path, *options, input_schema.as_ref())?)
+                            Box::new(IpcSink::new(path, options.clone(), input_schema.as_ref())?)
+                                as Box<dyn SinkTrait>
+                        },
+                        #[cfg(feature = "ipc_streaming")]
+                        FileType::IpcStream(options) => {
+                            Box::new(IpcStreamSink::new(path, *options, input_schema.as_ref())?)
                                 as Box<dyn SinkTrait>
                         },
                         #[cfg(feature = "csv")]
@@ -228,7 +260,7 @@ where
                         FileType::Ipc(ipc_options) => Box::new(IpcCloudSink::new(
                             uri.as_ref().as_str(),
                             cloud_options.as_ref(),
-                            *ipc_options,
+                            ipc_options.clone(),
                             lp_arena.get(*input).schema(lp_arena).as_ref(),
                         )?)
                             as Box<dyn SinkTrait>,
@@ -236,6 +268,33 @@ where
                         other_file_type => todo!("Cloud-sinking of the file type {other_file_type:?} is not (yet) supported."),
                     }
                 },
+                #[cfg(any(
+                    feature = "parquet",
+                    feature = "ipc",
+                    feature = "csv",
+                    feature = "json"
+                ))]
+                SinkType::Partition {
+                    path,
+                    file_type,
+                    options,
+                } => Box::new(PartitionedSink::new(
+                    path.as_ref().as_path(),
+                    file_type.clone(),
+                    options.clone(),
+                    input_schema.as_ref(),
+                )?) as Box<dyn SinkTrait>,
+                #[cfg(not(any(
+                    feature = "parquet",
+                    feature = "ipc",
+                    feature = "csv",
+                    feature = "json"
+                )))]
+                SinkType::Partition { .. } => unreachable!(),
+                SinkType::Batches(sender) => {
+                    Box::new(BatchSink::new(sender.clone())) as Box<dyn SinkTrait>
+                },
+                SinkType::Plugin(plugin) => PluginSink::new(plugin.clone(), input_schema.as_ref())?,
             }
         },
         Join {
@@ -254,7 +313,7 @@ where
             match &options.args.how {
                 #[cfg(feature = "cross_join")]
                 JoinType::Cross => Box::new(CrossJoin::new(
-                    options.args.suffix().into(),
+                    Arc::new(options.args.naming()),
                     swapped,
                     node,
                     placeholder,
@@ -288,7 +347,7 @@ where
                             let (join_columns_left, join_columns_right) = swap_eval();
 
                             Box::new(GenericBuild::<()>::new(
-                                Arc::from(options.args.suffix()),
+                                Arc::new(options.args.naming()),
                                 options.args.clone(),
                                 swapped,
                                 join_columns_left,
@@ -315,7 +374,7 @@ where
                             let (join_columns_left, join_columns_right) = swap_eval();
 
                             Box::new(GenericBuild::<Tracker>::new(
-                                Arc::from(options.args.suffix()),
+                                Arc::new(options.args.naming()),
                                 options.args.clone(),
                                 swapped,
                                 join_columns_left,
@@ -337,6 +396,13 @@ where
             let slice = SliceSink::new(*offset as u64, *len as usize, input_schema.into_owned());
             Box::new(slice) as Box<dyn SinkTrait>
         },
+        MapFunction {
+            input,
+            function: FunctionNode::Sample { args },
+        } => {
+            let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
+            Box::new(ReservoirSampleSink::new(args.clone(), input_schema)) as Box<dyn SinkTrait>
+        },
         Reduce {
             input: _,
             exprs: _,
@@ -498,7 +564,9 @@ where
             }
             let aggregation_columns = Arc::new(aggregation_columns);
 
-            if std::env::var("POLARS_STREAMING_GB2").as_deref() == Ok("1") {
+            // Only the generic group-by can spill, so a memory budget requires it.
+            if std::env::var("POLARS_STREAMING_GB2").as_deref() == Ok("1") || max_memory().is_some()
+            {
                 Box::new(GenericGroupby2::new(
                     key_columns,
                     aggregation_columns,
@@ -571,6 +639,21 @@ where
     })
 }
 
+/// The filter operator for `predicate` on an input with schema `input_schema`.
+fn filter_operator(
+    predicate: &ExprIR,
+    expr_arena: &Arena<AExpr>,
+    input_schema: &SchemaRef,
+) -> PolarsResult<Box<dyn Operator>> {
+    let predicate = create_physical_filter_expr(
+        predicate,
+        expr_arena,
+        Some(input_schema),
+        &mut ExpressionConversionState::new(false, 0),
+    )?;
+    Ok(Box::new(operators::FilterOperator { predicate }))
+}
+
 pub fn get_operator<F>(
     node: Node,
     lp_arena: &Arena<IR>,
@@ -620,10 +703,8 @@ where
             Box::new(op) as Box<dyn Operator>
         },
         Filter { predicate, input } => {
-            let input_schema = lp_arena.get(*input).schema(lp_arena);
-            let predicate = to_physical(predicate, expr_arena, Some(input_schema.as_ref()))?;
-            let op = operators::FilterOperator { predicate };
-            Box::new(op) as Box<dyn Operator>
+            let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
+            filter_operator(predicate, expr_arena, &input_schema)?
         },
         MapFunction { function, .. } => {
             let op = operators::FunctionOperator::new(function.clone());
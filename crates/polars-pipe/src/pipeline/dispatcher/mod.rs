@@ -305,6 +305,8 @@ impl PipeLine {
         let (sink_shared_count, mut reduced_sink) = self.run_pipeline_no_finalize(ec, pipelines)?;
         assert_eq!(sink_shared_count, 0);
 
+        // Don't let a cancelled query finish its sink, e.g. commit a file.
+        ec.execution_state.should_stop()?;
         let finalized_reduced_sink = reduced_sink.finalize(ec)?;
         Ok(Some(finalized_reduced_sink))
     }
@@ -374,6 +376,7 @@ fn consume_source(src: &mut dyn Source, context: &PExecutionContext) -> PolarsRe
     let mut frames = Vec::with_capacity(32);
 
     while let SourceResult::GotMoreData(batch) = src.get_batches(context)? {
+        context.execution_state.should_stop()?;
         frames.extend(batch.into_iter().map(|chunk| chunk.data))
     }
     Ok(accumulate_dataframes_vertical_unchecked(frames))
@@ -23,6 +23,16 @@ pub(crate) const PARTITION_SIZE: usize = 64;
 
 // env vars
 pub(crate) static FORCE_OOC: &str = "POLARS_FORCE_OOC";
+static MAX_MEMORY: &str = "POLARS_MAX_MEMORY";
+
+/// The number of bytes the out-of-core operators may use before they spill to disk, in
+/// addition to going out-of-core when the system runs low on memory.
+pub(crate) fn max_memory() -> Option<usize> {
+    std::env::var(MAX_MEMORY).ok().map(|val| {
+        val.parse()
+            .unwrap_or_else(|_| panic!("could not parse '{MAX_MEMORY}' env var: {val}"))
+    })
+}
 
 /// ideal chunk size we strive to have
 /// scale the chunk size depending on the number of
@@ -4,7 +4,11 @@ use crate::prelude::ColumnName;
 
 pub static MAP_LIST_NAME: &str = "map_list";
 pub static CSE_REPLACED: &str = "__POLARS_CSER_";
+pub const SET_WHERE_MASK: &str = "__POLARS_SET_WHERE_MASK";
+#[cfg(feature = "dtype-array")]
+pub(crate) const QUANTILES_FUSED: &str = "__POLARS_QUANTILES_";
 pub const LEN: &str = "len";
+pub const ROW_INDICES: &str = "row_indices";
 pub const LITERAL_NAME: &str = "literal";
 pub const UNLIMITED_CACHE: u32 = u32::MAX;
 
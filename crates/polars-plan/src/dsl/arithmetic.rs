@@ -57,6 +57,14 @@ impl Expr {
         binary_expr(self, Operator::FloorDivide, rhs)
     }
 
+    /// Divide `self` by `rhs` and round the quotient up.
+    ///
+    /// Like the floor division, integer division by zero is null.
+    #[cfg(feature = "round_series")]
+    pub fn ceil_div<E: Into<Expr>>(self, rhs: E) -> Self {
+        self.map_many_private(FunctionExpr::CeilDiv, &[rhs.into()], false, true)
+    }
+
     /// Raise expression to the power `exponent`
     pub fn pow<E: Into<Expr>>(self, exponent: E) -> Self {
         self.map_many_private(
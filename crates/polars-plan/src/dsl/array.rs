@@ -183,6 +183,84 @@ impl ArrayNameSpace {
             .with_fmt("arr.to_struct")
     }
 
+    /// Explode the outer dimension of the arrays into rows, keeping the remaining
+    /// dimensions as `Array`s. A null array produces `width` null rows.
+    pub fn explode_to_rows(self) -> Expr {
+        self.0
+            .apply_private(FunctionExpr::ArrayExpr(ArrayFunction::ExplodeToRows))
+    }
+
+    /// Reshape every sub-array into `dimensions`, keeping the number of rows.
+    ///
+    /// A single dimension may be `-1`, it is inferred from the width of the arrays.
+    pub fn reshape(self, dimensions: &[i64]) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Reshape(
+                dimensions.to_vec(),
+            )))
+    }
+
+    /// Sum the arrays element-wise over all rows, or over the rows of every group in a
+    /// `group_by` context. The result is a single array of the same width.
+    pub fn sum_elementwise(self) -> Expr {
+        self.0
+            .apply_private(FunctionExpr::ArrayExpr(ArrayFunction::SumElementwise))
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
+    /// Average the arrays element-wise over all rows, or over the rows of every group in a
+    /// `group_by` context. The result is a single array of the same width.
+    pub fn mean_elementwise(self) -> Expr {
+        self.0
+            .apply_private(FunctionExpr::ArrayExpr(ArrayFunction::MeanElementwise))
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
+    /// The dot product of the arrays of every row with the arrays of `other`, which may be a
+    /// single array that is used for every row.
+    pub fn dot(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ArrayExpr(ArrayFunction::Dot),
+            &[other],
+            false,
+            false,
+        )
+    }
+
+    /// The euclidean norm of every sub-array.
+    pub fn l2_norm(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::L2Norm))
+    }
+
+    /// The cosine similarity of the arrays of every row with the arrays of `other`, which may
+    /// be a single array that is used for every row.
+    pub fn cosine_similarity(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ArrayExpr(ArrayFunction::CosineSimilarity),
+            &[other],
+            false,
+            false,
+        )
+    }
+
+    /// The matrix product of the arrays of every row with the arrays of `other`. Arrays of
+    /// arrays are matrices and flat arrays are vectors, like for `numpy.matmul`.
+    pub fn matmul(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ArrayExpr(ArrayFunction::MatMul),
+            &[other],
+            false,
+            false,
+        )
+    }
+
     /// Shift every sub-array.
     pub fn shift(self, n: Expr) -> Expr {
         self.0.map_many_private(
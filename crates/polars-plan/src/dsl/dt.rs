@@ -212,6 +212,18 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Truncate the Datetime/Date range into buckets that are shifted by `offset`, a single
+    /// duration, e.g. `"5m"` for 15 minute buckets starting at 5, 20, 35 and 50 minutes past
+    /// the hour. The offset is applied in local time, like the `offset` of a dynamic group-by.
+    pub fn truncate_with_offset(self, every: Expr, offset: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::TemporalExpr(TemporalFunction::Truncate),
+            &[every, offset],
+            false,
+            false,
+        )
+    }
+
     /// Roll backward to the first day of the month.
     #[cfg(feature = "month_start")]
     pub fn month_start(self) -> Expr {
@@ -250,6 +262,17 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Round the Datetime/Date range into buckets that are shifted by `offset`, see
+    /// [`DateLikeNameSpace::truncate_with_offset`].
+    pub fn round_with_offset(self, every: Expr, offset: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::TemporalExpr(TemporalFunction::Round),
+            &[every, offset],
+            false,
+            false,
+        )
+    }
+
     /// Offset this `Date/Datetime` by a given offset [`Duration`].
     /// This will take leap years/ months into account.
     #[cfg(feature = "offset_by")]
@@ -33,6 +33,34 @@ pub enum AggExpr {
         quantile: Arc<Expr>,
         interpol: QuantileInterpolOptions,
     },
+    ApproxQuantile {
+        expr: Arc<Expr>,
+        quantile: Arc<Expr>,
+        accuracy: u32,
+    },
+    Quantiles {
+        expr: Arc<Expr>,
+        options: QuantilesOptions,
+    },
+    StrJoin {
+        expr: Arc<Expr>,
+        options: StrJoinAggOptions,
+    },
+    FirstBy {
+        expr: Arc<Expr>,
+        by: Arc<Expr>,
+    },
+    LastBy {
+        expr: Arc<Expr>,
+        by: Arc<Expr>,
+    },
+    #[cfg(feature = "ffi_plugin")]
+    /// Creating this node is unsafe
+    /// This will lead to calls over FFI.
+    Plugin {
+        expr: Arc<Expr>,
+        function: PluginAggregation,
+    },
     Sum(Arc<Expr>),
     AggGroups(Arc<Expr>),
     Std(Arc<Expr>, u8),
@@ -53,6 +81,12 @@ impl AsRef<Expr> for AggExpr {
             Implode(e) => e,
             Count(e, _) => e,
             Quantile { expr, .. } => expr,
+            ApproxQuantile { expr, .. } => expr,
+            Quantiles { expr, .. } => expr,
+            StrJoin { expr, .. } => expr,
+            FirstBy { expr, .. } | LastBy { expr, .. } => expr,
+            #[cfg(feature = "ffi_plugin")]
+            Plugin { expr, .. } => expr,
             Sum(e) => e,
             AggGroups(e) => e,
             Std(e, _) => e,
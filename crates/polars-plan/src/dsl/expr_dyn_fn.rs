@@ -46,32 +46,27 @@ impl Serialize for SpecialEq<Arc<dyn SeriesUdf>> {
 
 #[cfg(feature = "serde")]
 impl<'a> Deserialize<'a> for SpecialEq<Arc<dyn SeriesUdf>> {
-    fn deserialize<D>(_deserializer: D) -> std::result::Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: Deserializer<'a>,
     {
         use serde::de::Error;
-        #[cfg(feature = "python")]
-        {
-            use crate::dsl::python_udf::MAGIC_BYTE_MARK;
-            let buf = Vec::<u8>::deserialize(_deserializer)?;
-
-            if buf.starts_with(MAGIC_BYTE_MARK) {
-                let udf = python_udf::PythonUdfExpression::try_deserialize(&buf)
-                    .map_err(|e| D::Error::custom(format!("{e}")))?;
-                Ok(SpecialEq::new(udf))
-            } else {
-                Err(D::Error::custom(
-                    "deserialize not supported for this 'opaque' function",
-                ))
-            }
+        let buf = Vec::<u8>::deserialize(deserializer)?;
+
+        if buf.starts_with(super::udf_registry::MAGIC_BYTE_MARK) {
+            let udf = super::udf_registry::RegisteredUdf::try_deserialize(&buf)
+                .map_err(|e| D::Error::custom(format!("{e}")))?;
+            return Ok(SpecialEq::new(udf));
         }
-        #[cfg(not(feature = "python"))]
-        {
-            Err(D::Error::custom(
-                "deserialize not supported for this 'opaque' function",
-            ))
+        #[cfg(feature = "python")]
+        if buf.starts_with(python_udf::MAGIC_BYTE_MARK) {
+            let udf = python_udf::PythonUdfExpression::try_deserialize(&buf)
+                .map_err(|e| D::Error::custom(format!("{e}")))?;
+            return Ok(SpecialEq::new(udf));
         }
+        Err(D::Error::custom(
+            "deserialize not supported for this 'opaque' function",
+        ))
     }
 }
 
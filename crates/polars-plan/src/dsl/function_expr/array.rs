@@ -3,7 +3,7 @@ use polars_ops::chunked_array::array::*;
 use super::*;
 use crate::{map, map_as_slice};
 
-#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ArrayFunction {
     Min,
@@ -30,6 +30,14 @@ pub enum ArrayFunction {
     #[cfg(feature = "array_count")]
     CountMatches,
     Shift,
+    ExplodeToRows,
+    Reshape(Vec<i64>),
+    SumElementwise,
+    MeanElementwise,
+    Dot,
+    L2Norm,
+    CosineSimilarity,
+    MatMul,
 }
 
 impl ArrayFunction {
@@ -56,10 +64,43 @@ impl ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => mapper.with_dtype(IDX_DTYPE),
             Shift => mapper.with_same_dtype(),
+            ExplodeToRows => mapper.map_to_list_and_array_inner_dtype(),
+            Reshape(dims) => mapper.try_map_dtype(|dt| reshape_dtype(dt, dims)),
+            SumElementwise => mapper.try_map_dtype(|dt| map_array_inner(dt, sum_elementwise_dtype)),
+            MeanElementwise => {
+                mapper.try_map_dtype(|dt| map_array_inner(dt, mean_elementwise_dtype))
+            },
+            Dot => mapper.try_map_dtypes(|dts| linalg_dtype("dot", dts)),
+            L2Norm => mapper.try_map_dtypes(|dts| linalg_dtype("l2_norm", dts)),
+            CosineSimilarity => mapper.try_map_dtypes(|dts| linalg_dtype("cosine_similarity", dts)),
+            MatMul => mapper.try_map_dtypes(|dts| matmul_dtype(dts[0], dts[1])),
         }
     }
 }
 
+fn reshape_dtype(datatype: &DataType, dimensions: &[i64]) -> PolarsResult<DataType> {
+    let shape = array_reshape_shape(datatype, dimensions)?;
+    let mut leaf = datatype;
+    while let DataType::Array(inner, _) = leaf {
+        leaf = inner;
+    }
+    let mut dtype = leaf.clone();
+    for dim in shape.into_iter().rev() {
+        dtype = DataType::Array(Box::new(dtype), dim);
+    }
+    Ok(dtype)
+}
+
+fn map_array_inner(
+    datatype: &DataType,
+    map: impl Fn(&DataType) -> PolarsResult<DataType>,
+) -> PolarsResult<DataType> {
+    match datatype {
+        DataType::Array(inner, width) => Ok(DataType::Array(Box::new(map(inner)?), *width)),
+        _ => polars_bail!(ComputeError: "expected array dtype"),
+    }
+}
+
 fn map_array_dtype_to_list_dtype(datatype: &DataType) -> PolarsResult<DataType> {
     if let DataType::Array(inner, _) = datatype {
         Ok(DataType::List(inner.clone()))
@@ -96,6 +137,14 @@ impl Display for ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => "count_matches",
             Shift => "shift",
+            ExplodeToRows => "explode_to_rows",
+            Reshape(_) => "reshape",
+            SumElementwise => "sum_elementwise",
+            MeanElementwise => "mean_elementwise",
+            Dot => "dot",
+            L2Norm => "l2_norm",
+            CosineSimilarity => "cosine_similarity",
+            MatMul => "matmul",
         };
         write!(f, "arr.{name}")
     }
@@ -129,6 +178,14 @@ impl From<ArrayFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "array_count")]
             CountMatches => map_as_slice!(count_matches),
             Shift => map_as_slice!(shift),
+            ExplodeToRows => map!(explode_to_rows),
+            Reshape(dims) => map!(reshape, &dims),
+            SumElementwise => map!(sum_elementwise),
+            MeanElementwise => map!(mean_elementwise),
+            Dot => map_as_slice!(dot),
+            L2Norm => map!(l2_norm),
+            CosineSimilarity => map_as_slice!(cosine_similarity),
+            MatMul => map_as_slice!(matmul),
         }
     }
 }
@@ -149,6 +206,30 @@ pub(super) fn std(s: &Series, ddof: u8) -> PolarsResult<Series> {
     s.array()?.array_std(ddof)
 }
 
+pub(super) fn sum_elementwise(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_sum_elementwise()
+}
+
+pub(super) fn mean_elementwise(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_mean_elementwise()
+}
+
+pub(super) fn dot(s: &[Series]) -> PolarsResult<Series> {
+    s[0].array()?.array_dot(&s[1])
+}
+
+pub(super) fn l2_norm(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_l2_norm()
+}
+
+pub(super) fn cosine_similarity(s: &[Series]) -> PolarsResult<Series> {
+    s[0].array()?.array_cosine_similarity(&s[1])
+}
+
+pub(super) fn matmul(s: &[Series]) -> PolarsResult<Series> {
+    s[0].array()?.array_matmul(&s[1])
+}
+
 pub(super) fn var(s: &Series, ddof: u8) -> PolarsResult<Series> {
     s.array()?.array_var(ddof)
 }
@@ -243,3 +324,11 @@ pub(super) fn shift(s: &[Series]) -> PolarsResult<Series> {
 
     ca.array_shift(n)
 }
+
+pub(super) fn explode_to_rows(s: &Series) -> PolarsResult<Series> {
+    Ok(s.array()?.array_explode_to_rows())
+}
+
+pub(super) fn reshape(s: &Series, dimensions: &[i64]) -> PolarsResult<Series> {
+    Ok(s.array()?.array_reshape(dimensions)?.into_series())
+}
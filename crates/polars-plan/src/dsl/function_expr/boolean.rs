@@ -31,12 +31,21 @@ pub enum BooleanFunction {
     IsUnique,
     #[cfg(feature = "is_unique")]
     IsDuplicated,
+    /// `IsDuplicated` that doesn't mark the first or last occurrence of a value.
+    #[cfg(feature = "is_unique")]
+    IsDuplicatedKeep {
+        keep: UniqueKeepStrategy,
+    },
     #[cfg(feature = "is_between")]
     IsBetween {
         closed: ClosedInterval,
     },
     #[cfg(feature = "is_in")]
     IsIn,
+    IsMonotonic {
+        descending: bool,
+        strict: bool,
+    },
     AllHorizontal,
     AnyHorizontal,
     // Also bitwise negate
@@ -80,11 +89,17 @@ impl Display for BooleanFunction {
             #[cfg(feature = "is_unique")]
             IsUnique => "is_unique",
             #[cfg(feature = "is_unique")]
-            IsDuplicated => "is_duplicated",
+            IsDuplicated | IsDuplicatedKeep { .. } => "is_duplicated",
             #[cfg(feature = "is_between")]
             IsBetween { .. } => "is_between",
             #[cfg(feature = "is_in")]
             IsIn => "is_in",
+            IsMonotonic {
+                descending: false, ..
+            } => "is_monotonic_increasing",
+            IsMonotonic {
+                descending: true, ..
+            } => "is_monotonic_decreasing",
             AnyHorizontal => "any_horizontal",
             AllHorizontal => "all_horizontal",
             Not => "not",
@@ -112,11 +127,14 @@ impl From<BooleanFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "is_unique")]
             IsUnique => map!(is_unique),
             #[cfg(feature = "is_unique")]
-            IsDuplicated => map!(is_duplicated),
+            IsDuplicated => map!(is_duplicated, UniqueKeepStrategy::None),
+            #[cfg(feature = "is_unique")]
+            IsDuplicatedKeep { keep } => map!(is_duplicated, keep),
             #[cfg(feature = "is_between")]
             IsBetween { closed } => map_as_slice!(is_between, closed),
             #[cfg(feature = "is_in")]
             IsIn => wrap!(is_in),
+            IsMonotonic { descending, strict } => map!(is_monotonic, descending, strict),
             Not => map!(not),
             AllHorizontal => map_as_slice!(all_horizontal),
             AnyHorizontal => map_as_slice!(any_horizontal),
@@ -188,8 +206,8 @@ fn is_unique(s: &Series) -> PolarsResult<Series> {
 }
 
 #[cfg(feature = "is_unique")]
-fn is_duplicated(s: &Series) -> PolarsResult<Series> {
-    polars_ops::prelude::is_duplicated(s).map(|ca| ca.into_series())
+fn is_duplicated(s: &Series, keep: UniqueKeepStrategy) -> PolarsResult<Series> {
+    polars_ops::prelude::is_duplicated_keep(s, keep).map(|ca| ca.into_series())
 }
 
 #[cfg(feature = "is_between")]
@@ -207,6 +225,11 @@ fn is_in(s: &mut [Series]) -> PolarsResult<Option<Series>> {
     polars_ops::prelude::is_in(left, other).map(|ca| Some(ca.into_series()))
 }
 
+fn is_monotonic(s: &Series, descending: bool, strict: bool) -> PolarsResult<Series> {
+    let out = s.is_monotonic(descending, strict)?;
+    Ok(Series::new(s.name(), [out]))
+}
+
 fn not(s: &Series) -> PolarsResult<Series> {
     polars_ops::series::negate_bitwise(s)
 }
@@ -383,20 +383,39 @@ pub(super) fn cast_time_unit(s: &Series, tu: TimeUnit) -> PolarsResult<Series> {
     }
 }
 
+/// The optional `offset` input of `truncate` and `round`, which must be a single duration.
+fn parse_offset(s: &[Series]) -> PolarsResult<Duration> {
+    let Some(offset) = s.get(2) else {
+        return Ok(Duration::new(0));
+    };
+    let offset = offset.str()?;
+    match (offset.len(), offset.get(0)) {
+        (1, Some(offset)) => Ok(Duration::parse(offset)),
+        _ => polars_bail!(ComputeError: "`offset` must be a single non-null duration"),
+    }
+}
+
 pub(super) fn truncate(s: &[Series]) -> PolarsResult<Series> {
     let time_series = &s[0];
     let every = s[1].str()?;
+    let offset = parse_offset(s)?;
 
     let mut out = match time_series.dtype() {
         DataType::Datetime(_, tz) => match tz {
             #[cfg(feature = "timezones")]
             Some(tz) => time_series
                 .datetime()?
-                .truncate(tz.parse::<Tz>().ok().as_ref(), every)?
+                .truncate(tz.parse::<Tz>().ok().as_ref(), every, offset)?
+                .into_series(),
+            _ => time_series
+                .datetime()?
+                .truncate(None, every, offset)?
                 .into_series(),
-            _ => time_series.datetime()?.truncate(None, every)?.into_series(),
         },
-        DataType::Date => time_series.date()?.truncate(None, every)?.into_series(),
+        DataType::Date => time_series
+            .date()?
+            .truncate(None, every, offset)?
+            .into_series(),
         dt => polars_bail!(opq = round, got = dt, expected = "date/datetime"),
     };
     out.set_sorted_flag(time_series.is_sorted_flag());
@@ -478,6 +497,7 @@ pub(super) fn dst_offset(s: &Series) -> PolarsResult<Series> {
 pub(super) fn round(s: &[Series]) -> PolarsResult<Series> {
     let time_series = &s[0];
     let every = s[1].str()?;
+    let offset = parse_offset(s)?;
 
     Ok(match time_series.dtype() {
         DataType::Datetime(_, tz) => match tz {
@@ -485,18 +505,18 @@ pub(super) fn round(s: &[Series]) -> PolarsResult<Series> {
             Some(tz) => time_series
                 .datetime()
                 .unwrap()
-                .round(every, tz.parse::<Tz>().ok().as_ref())?
+                .round(every, offset, tz.parse::<Tz>().ok().as_ref())?
                 .into_series(),
             _ => time_series
                 .datetime()
                 .unwrap()
-                .round(every, None)?
+                .round(every, offset, None)?
                 .into_series(),
         },
         DataType::Date => time_series
             .date()
             .unwrap()
-            .round(every, None)?
+            .round(every, offset, None)?
             .into_series(),
         dt => polars_bail!(opq = round, got = dt, expected = "date/datetime"),
     })
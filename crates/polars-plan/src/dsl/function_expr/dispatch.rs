@@ -35,12 +35,52 @@ pub(super) fn to_physical(s: &Series) -> PolarsResult<Series> {
     Ok(s.to_physical_repr().into_owned())
 }
 
+pub(super) fn is_valid_mask(s: &Series) -> PolarsResult<Series> {
+    Ok(polars_ops::prelude::is_valid_mask(s).into_series())
+}
+
+pub(super) fn with_validity(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::prelude::with_validity(&s[0], s[1].bool()?)
+}
+
+pub(super) fn coalesce_validity(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::prelude::coalesce_validity(&s[0], &s[1])
+}
+
 pub(super) fn set_sorted_flag(s: &Series, sorted: IsSorted) -> PolarsResult<Series> {
+    if polars_core::config::validate_sorted() {
+        match sorted {
+            IsSorted::Ascending => s.check_sorted(false)?,
+            IsSorted::Descending => s.check_sorted(true)?,
+            IsSorted::Not => {},
+        }
+    }
     let mut s = s.clone();
     s.set_sorted_flag(sorted);
     Ok(s)
 }
 
+pub(super) fn identity(s: &Series) -> PolarsResult<Series> {
+    Ok(s.clone())
+}
+
+/// Both inputs were evaluated, so the primary one didn't fail. The physical planner normally
+/// evaluates the fallback only after a failure.
+pub(super) fn or_else(s: &[Series]) -> PolarsResult<Series> {
+    Ok(s[0].clone())
+}
+
+pub(super) fn check_sorted(s: &Series, descending: bool) -> PolarsResult<Series> {
+    s.check_sorted(descending)?;
+    let mut s = s.clone();
+    s.set_sorted_flag(if descending {
+        IsSorted::Descending
+    } else {
+        IsSorted::Ascending
+    });
+    Ok(s)
+}
+
 #[cfg(feature = "timezones")]
 pub(super) fn replace_time_zone(
     s: &[Series],
@@ -102,6 +142,23 @@ pub(super) fn min_horizontal(s: &mut [Series]) -> PolarsResult<Option<Series>> {
     polars_ops::prelude::min_horizontal(s)
 }
 
+pub(super) fn arg_min_max_horizontal(
+    s: &mut [Series],
+    max: bool,
+    return_names: bool,
+) -> PolarsResult<Option<Series>> {
+    let Some(idx) = polars_ops::prelude::arg_min_max_horizontal(s, max)? else {
+        return Ok(None);
+    };
+    let out = if return_names {
+        let names = s.iter().map(|s| s.name()).collect::<Vec<_>>();
+        Series::new("", names).take(&idx)?
+    } else {
+        idx.into_series()
+    };
+    Ok(Some(out.with_name(s[0].name())))
+}
+
 pub(super) fn sum_horizontal(s: &mut [Series]) -> PolarsResult<Option<Series>> {
     polars_ops::prelude::sum_horizontal(s)
 }
@@ -143,6 +200,7 @@ pub(super) fn rank(s: &Series, options: RankOptions, seed: Option<u64>) -> Polar
 pub(super) fn hist(
     s: &[Series],
     bin_count: Option<usize>,
+    bin_strategy: Option<HistBinStrategy>,
     include_category: bool,
     include_breakpoint: bool,
 ) -> PolarsResult<Series> {
@@ -152,7 +210,24 @@ pub(super) fn hist(
         None
     };
     let s = &s[0];
-    hist_series(s, bin_count, bins, include_category, include_breakpoint)
+    hist_series(
+        s,
+        bin_count,
+        bins,
+        bin_strategy,
+        include_category,
+        include_breakpoint,
+    )
+}
+
+#[cfg(feature = "hist")]
+pub(super) fn hist_2d(
+    s: &[Series],
+    bin_count: Option<usize>,
+    bin_strategy: Option<HistBinStrategy>,
+) -> PolarsResult<Series> {
+    let bins = s.get(2).cloned();
+    hist_2d_series(&s[0], &s[1], bin_count, bins, bin_strategy)
 }
 
 #[cfg(feature = "replace")]
@@ -177,6 +252,58 @@ pub(super) fn fill_null_with_strategy(
     s.fill_null(strategy)
 }
 
+#[cfg(feature = "fill_null_by")]
+pub(super) fn fill_null_by(
+    s: &[Series],
+    strategy: FillNullStrategy,
+    max_gap: Option<polars_time::Duration>,
+) -> PolarsResult<Series> {
+    use polars_time::prelude::{ensure_duration_matches_data_type, ensure_is_constant_duration};
+
+    let (by, keys) = match max_gap {
+        Some(max_gap) => {
+            let by = &s[1];
+            ensure_duration_matches_data_type(max_gap, by.dtype(), "max_gap")?;
+            let in_time_unit = |tu: &TimeUnit| match tu {
+                TimeUnit::Nanoseconds => max_gap.duration_ns(),
+                TimeUnit::Microseconds => max_gap.duration_us(),
+                TimeUnit::Milliseconds => max_gap.duration_ms(),
+            };
+            let (by, max_gap) = match by.dtype() {
+                DataType::Date => (
+                    by.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
+                    max_gap.duration_ms(),
+                ),
+                DataType::Datetime(tu, tz) => {
+                    ensure_is_constant_duration(max_gap, tz.as_deref(), "max_gap")?;
+                    (by.clone(), in_time_unit(tu))
+                },
+                DataType::Duration(tu) => (by.clone(), in_time_unit(tu)),
+                DataType::Time => (by.clone(), max_gap.duration_ns()),
+                // Integers, the duration is parsed as an integer.
+                _ => (by.clone(), max_gap.nanoseconds()),
+            };
+            (Some((by, max_gap)), &s[2..])
+        },
+        None => (None, &s[1..]),
+    };
+    let groups = if keys.is_empty() {
+        None
+    } else {
+        Some(
+            DataFrame::empty()
+                .group_by_with_series(keys.to_vec(), true, false)?
+                .take_groups(),
+        )
+    };
+    polars_ops::prelude::fill_null_by(
+        &s[0],
+        groups.as_ref(),
+        by.as_ref().map(|(by, max_gap)| (by, *max_gap)),
+        strategy,
+    )
+}
+
 pub(super) fn gather_every(s: &Series, n: usize, offset: usize) -> PolarsResult<Series> {
     polars_ensure!(n > 0, InvalidOperation: "gather_every(n): n should be positive");
     Ok(s.gather_every(n, offset))
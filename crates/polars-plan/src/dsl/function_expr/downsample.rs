@@ -0,0 +1,51 @@
+use super::*;
+use crate::{map, map_as_slice};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum DownsampleFunction {
+    /// Inputs: the `y` values, optionally followed by the `x` values.
+    Lttb {
+        n_out: usize,
+    },
+    MinMax {
+        n_out: usize,
+    },
+}
+
+impl DownsampleFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        mapper.with_dtype(DataType::Boolean)
+    }
+}
+
+impl Display for DownsampleFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use DownsampleFunction::*;
+        let s = match self {
+            Lttb { .. } => "lttb",
+            MinMax { .. } => "min_max",
+        };
+        write!(f, "ts.{s}")
+    }
+}
+
+impl From<DownsampleFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
+    fn from(func: DownsampleFunction) -> Self {
+        use DownsampleFunction::*;
+        match func {
+            Lttb { n_out } => map_as_slice!(lttb, n_out),
+            MinMax { n_out } => map!(polars_ops::series::min_max_downsample, n_out),
+        }
+    }
+}
+
+impl From<DownsampleFunction> for FunctionExpr {
+    fn from(func: DownsampleFunction) -> Self {
+        FunctionExpr::Downsample(func)
+    }
+}
+
+fn lttb(s: &[Series], n_out: usize) -> PolarsResult<Series> {
+    polars_ops::series::lttb(&s[0], s.get(1), n_out)
+}
@@ -0,0 +1,62 @@
+use polars_ops::series::TTestVariant;
+
+use super::*;
+use crate::map_as_slice;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum HypothesisTestFunction {
+    TTest {
+        variant: TTestVariant,
+    },
+    KsTest,
+    /// Inputs: the observed and the expected frequencies.
+    Chi2,
+}
+
+impl HypothesisTestFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        mapper.with_dtype(polars_ops::series::hypothesis_test_dtype())
+    }
+}
+
+impl Display for HypothesisTestFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use HypothesisTestFunction::*;
+        let s = match self {
+            TTest { .. } => "ttest",
+            KsTest => "ks_test",
+            Chi2 => "chi2",
+        };
+        write!(f, "stats.{s}")
+    }
+}
+
+impl From<HypothesisTestFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
+    fn from(func: HypothesisTestFunction) -> Self {
+        use HypothesisTestFunction::*;
+        match func {
+            TTest { variant } => map_as_slice!(ttest, variant),
+            KsTest => map_as_slice!(ks_test),
+            Chi2 => map_as_slice!(chi2_test),
+        }
+    }
+}
+
+impl From<HypothesisTestFunction> for FunctionExpr {
+    fn from(func: HypothesisTestFunction) -> Self {
+        FunctionExpr::HypothesisTest(func)
+    }
+}
+
+fn ttest(s: &[Series], variant: TTestVariant) -> PolarsResult<Series> {
+    polars_ops::series::ttest(&s[0], &s[1], variant)
+}
+
+fn ks_test(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::series::ks_test(&s[0], &s[1])
+}
+
+fn chi2_test(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::series::chi2_test(&s[0], &s[1])
+}
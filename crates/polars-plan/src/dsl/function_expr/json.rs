@@ -0,0 +1,58 @@
+use polars_ops::chunked_array::JsonNameSpaceImpl;
+
+use super::*;
+use crate::map;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum JsonFunction {
+    Path(String),
+    GetType,
+}
+
+impl JsonFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use JsonFunction::*;
+        match self {
+            Path(_) => mapper.with_dtype(DataType::Json),
+            GetType => mapper.with_dtype(DataType::String),
+        }
+    }
+}
+
+impl Display for JsonFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use JsonFunction::*;
+        let s = match self {
+            Path(_) => "path",
+            GetType => "get_type",
+        };
+        write!(f, "json.{s}")
+    }
+}
+
+impl From<JsonFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
+    fn from(func: JsonFunction) -> Self {
+        use JsonFunction::*;
+        match func {
+            Path(path) => map!(extract_path, &path),
+            GetType => map!(get_type),
+        }
+    }
+}
+
+impl From<JsonFunction> for FunctionExpr {
+    fn from(func: JsonFunction) -> Self {
+        FunctionExpr::JsonExpr(func)
+    }
+}
+
+fn extract_path(s: &Series, path: &str) -> PolarsResult<Series> {
+    let ca = s.json()?;
+    ca.path(path).map(|ca| ca.into_series())
+}
+
+fn get_type(s: &Series) -> PolarsResult<Series> {
+    let ca = s.json()?;
+    Ok(ca.get_type().into_series())
+}
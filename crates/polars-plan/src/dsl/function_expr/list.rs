@@ -56,6 +56,12 @@ pub enum ListFunction {
     Join(bool),
     #[cfg(feature = "dtype-array")]
     ToArray(usize),
+    #[cfg(feature = "dtype-array")]
+    SumElementwise,
+    #[cfg(feature = "dtype-array")]
+    MeanElementwise,
+    #[cfg(feature = "dtype-struct")]
+    Zip,
 }
 
 impl ListFunction {
@@ -102,7 +108,30 @@ impl ListFunction {
             Join(_) => mapper.with_dtype(DataType::String),
             #[cfg(feature = "dtype-array")]
             ToArray(width) => mapper.try_map_dtype(|dt| map_list_dtype_to_array_dtype(dt, *width)),
+            #[cfg(feature = "dtype-array")]
+            SumElementwise => mapper.try_map_dtype(|dt| {
+                map_list_inner(dt, polars_ops::chunked_array::array::sum_elementwise_dtype)
+            }),
+            #[cfg(feature = "dtype-array")]
+            MeanElementwise => mapper.try_map_dtype(|dt| {
+                map_list_inner(dt, polars_ops::chunked_array::array::mean_elementwise_dtype)
+            }),
             NUnique => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "dtype-struct")]
+            Zip => {
+                let fields = mapper
+                    .args()
+                    .iter()
+                    .map(|field| match field.data_type() {
+                        DataType::List(inner) => Ok(Field::new(field.name(), *inner.clone())),
+                        dt => polars_bail!(
+                            InvalidOperation: "`zip_lists` expects lists, got {} for '{}'",
+                            dt, field.name()
+                        ),
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                mapper.with_dtype(DataType::List(Box::new(DataType::Struct(fields))))
+            },
         }
     }
 }
@@ -116,6 +145,18 @@ fn map_list_dtype_to_array_dtype(datatype: &DataType, width: usize) -> PolarsRes
     }
 }
 
+#[cfg(feature = "dtype-array")]
+fn map_list_inner(
+    datatype: &DataType,
+    map: impl Fn(&DataType) -> PolarsResult<DataType>,
+) -> PolarsResult<DataType> {
+    if let DataType::List(inner) = datatype {
+        Ok(DataType::List(Box::new(map(inner)?)))
+    } else {
+        polars_bail!(ComputeError: "expected List dtype")
+    }
+}
+
 impl Display for ListFunction {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         use ListFunction::*;
@@ -174,6 +215,12 @@ impl Display for ListFunction {
             Join(_) => "join",
             #[cfg(feature = "dtype-array")]
             ToArray(_) => "to_array",
+            #[cfg(feature = "dtype-array")]
+            SumElementwise => "sum_elementwise",
+            #[cfg(feature = "dtype-array")]
+            MeanElementwise => "mean_elementwise",
+            #[cfg(feature = "dtype-struct")]
+            Zip => "zip",
         };
         write!(f, "list.{name}")
     }
@@ -234,7 +281,13 @@ impl From<ListFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Join(ignore_nulls) => map_as_slice!(join, ignore_nulls),
             #[cfg(feature = "dtype-array")]
             ToArray(width) => map!(to_array, width),
+            #[cfg(feature = "dtype-array")]
+            SumElementwise => map!(sum_elementwise),
+            #[cfg(feature = "dtype-array")]
+            MeanElementwise => map!(mean_elementwise),
             NUnique => map!(n_unique),
+            #[cfg(feature = "dtype-struct")]
+            Zip => map_as_slice!(zip),
         }
     }
 }
@@ -382,6 +435,11 @@ pub(super) fn slice(args: &mut [Series]) -> PolarsResult<Option<Series>> {
     Ok(Some(out.into_series()))
 }
 
+#[cfg(feature = "dtype-struct")]
+pub(super) fn zip(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::chunked_array::list::zip_lists(s).map(|ca| ca.into_series())
+}
+
 pub(super) fn concat(s: &mut [Series]) -> PolarsResult<Option<Series>> {
     let mut first = std::mem::take(&mut s[0]);
     let other = &s[1..];
@@ -638,6 +696,16 @@ pub(super) fn to_array(s: &Series, width: usize) -> PolarsResult<Series> {
     s.cast(&array_dtype)
 }
 
+#[cfg(feature = "dtype-array")]
+pub(super) fn sum_elementwise(s: &Series) -> PolarsResult<Series> {
+    s.list()?.lst_sum_elementwise()
+}
+
+#[cfg(feature = "dtype-array")]
+pub(super) fn mean_elementwise(s: &Series) -> PolarsResult<Series> {
+    s.list()?.lst_mean_elementwise()
+}
+
 pub(super) fn n_unique(s: &Series) -> PolarsResult<Series> {
     Ok(s.list()?.lst_n_unique()?.into_series())
 }
@@ -23,6 +23,8 @@ mod cum;
 #[cfg(feature = "temporal")]
 mod datetime;
 mod dispatch;
+#[cfg(feature = "downsample")]
+mod downsample;
 #[cfg(feature = "ewma")]
 mod ewm;
 #[cfg(feature = "ewma_by")]
@@ -30,6 +32,10 @@ mod ewm_by;
 mod fill_null;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "hypothesis_tests")]
+mod hypothesis_tests;
+#[cfg(feature = "dtype-json")]
+mod json;
 mod list;
 #[cfg(feature = "log")]
 mod log;
@@ -58,6 +64,8 @@ mod shift_and_fill;
 mod shrink_type;
 #[cfg(feature = "sign")]
 mod sign;
+#[cfg(feature = "approx_unique")]
+mod sketch;
 #[cfg(feature = "strings")]
 mod strings;
 #[cfg(feature = "dtype-struct")]
@@ -93,6 +101,14 @@ pub(super) use self::business::BusinessFunction;
 pub use self::cat::CategoricalFunction;
 #[cfg(feature = "temporal")]
 pub use self::datetime::TemporalFunction;
+#[cfg(feature = "downsample")]
+pub use self::downsample::DownsampleFunction;
+#[cfg(feature = "hypothesis_tests")]
+pub use self::hypothesis_tests::HypothesisTestFunction;
+#[cfg(feature = "dtype-json")]
+pub use self::json::JsonFunction;
+#[cfg(feature = "ffi_plugin")]
+pub use self::plugin::{PluginAggState, PluginAggregation, PluginAggregator};
 pub use self::pow::PowFunction;
 #[cfg(feature = "range")]
 pub(super) use self::range::RangeFunction;
@@ -100,6 +116,8 @@ pub(super) use self::range::RangeFunction;
 pub(super) use self::rolling::RollingFunction;
 #[cfg(feature = "rolling_window_by")]
 pub(super) use self::rolling_by::RollingFunctionBy;
+#[cfg(feature = "approx_unique")]
+pub use self::sketch::SketchFunction;
 #[cfg(feature = "strings")]
 pub use self::strings::StringFunction;
 #[cfg(feature = "dtype-struct")]
@@ -117,6 +135,8 @@ pub enum FunctionExpr {
     BinaryExpr(BinaryFunction),
     #[cfg(feature = "dtype-categorical")]
     Categorical(CategoricalFunction),
+    #[cfg(feature = "dtype-json")]
+    JsonExpr(JsonFunction),
     ListExpr(ListFunction),
     #[cfg(feature = "strings")]
     StringExpr(StringFunction),
@@ -124,6 +144,10 @@ pub enum FunctionExpr {
     StructExpr(StructFunction),
     #[cfg(feature = "temporal")]
     TemporalExpr(TemporalFunction),
+    #[cfg(feature = "downsample")]
+    Downsample(DownsampleFunction),
+    #[cfg(feature = "hypothesis_tests")]
+    HypothesisTest(HypothesisTestFunction),
 
     // Other expressions
     Boolean(BooleanFunction),
@@ -137,6 +161,15 @@ pub enum FunctionExpr {
         bin_count: Option<usize>,
         include_category: bool,
         include_breakpoint: bool,
+        #[cfg_attr(feature = "serde", serde(default))]
+        bin_strategy: Option<HistBinStrategy>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        shared_bins: bool,
+    },
+    #[cfg(feature = "hist")]
+    Hist2d {
+        bin_count: Option<usize>,
+        bin_strategy: Option<HistBinStrategy>,
     },
     NullCount,
     Pow(PowFunction),
@@ -156,6 +189,14 @@ pub enum FunctionExpr {
     Sign,
     FillNull,
     FillNullWithStrategy(FillNullStrategy),
+    /// Forward or backward fill per group and/or within a maximum gap.
+    ///
+    /// Inputs: the values, the `by` column if `max_gap` is set, then the group keys.
+    #[cfg(feature = "fill_null_by")]
+    FillNullBy {
+        strategy: FillNullStrategy,
+        max_gap: Option<polars_time::Duration>,
+    },
     #[cfg(feature = "rolling_window")]
     RollingExpr(RollingFunction),
     #[cfg(feature = "rolling_window_by")]
@@ -226,6 +267,8 @@ pub enum FunctionExpr {
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
     ApproxNUnique,
+    #[cfg(feature = "approx_unique")]
+    Sketch(SketchFunction),
     Coalesce,
     ShrinkType,
     #[cfg(feature = "diff")]
@@ -253,6 +296,8 @@ pub enum FunctionExpr {
     #[cfg(feature = "round_series")]
     Round {
         decimals: u32,
+        #[cfg_attr(feature = "serde", serde(default))]
+        mode: RoundMode,
     },
     #[cfg(feature = "round_series")]
     RoundSF {
@@ -262,6 +307,13 @@ pub enum FunctionExpr {
     Floor,
     #[cfg(feature = "round_series")]
     Ceil,
+    #[cfg(feature = "round_series")]
+    CeilDiv,
+    #[cfg(feature = "round_series")]
+    Rescale {
+        scale: usize,
+        mode: RoundMode,
+    },
     UpperBound,
     LowerBound,
     #[cfg(feature = "fused")]
@@ -296,12 +348,22 @@ pub enum FunctionExpr {
     #[cfg(feature = "rle")]
     RLEID,
     ToPhysical,
+    IsValidMask,
+    WithValidity,
+    CoalesceValidity,
     #[cfg(feature = "random")]
     Random {
         method: random::RandomMethod,
         seed: Option<u64>,
     },
     SetSortedFlag(IsSorted),
+    /// Hint whether the input may be evaluated in parallel. This is a no-op on the data.
+    Parallel(bool),
+    /// Evaluate the second input if evaluating the first one fails.
+    OrElse,
+    CheckSorted {
+        descending: bool,
+    },
     #[cfg(feature = "ffi_plugin")]
     /// Creating this node is unsafe
     /// This will lead to calls over FFI>
@@ -321,6 +383,12 @@ pub enum FunctionExpr {
     },
     MaxHorizontal,
     MinHorizontal,
+    ArgMaxHorizontal {
+        return_names: bool,
+    },
+    ArgMinHorizontal {
+        return_names: bool,
+    },
     SumHorizontal,
     MeanHorizontal,
     #[cfg(feature = "ewma")]
@@ -365,6 +433,8 @@ impl Hash for FunctionExpr {
             BinaryExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-categorical")]
             Categorical(f) => f.hash(state),
+            #[cfg(feature = "dtype-json")]
+            JsonExpr(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
@@ -372,6 +442,10 @@ impl Hash for FunctionExpr {
             StructExpr(f) => f.hash(state),
             #[cfg(feature = "temporal")]
             TemporalExpr(f) => f.hash(state),
+            #[cfg(feature = "downsample")]
+            Downsample(f) => f.hash(state),
+            #[cfg(feature = "hypothesis_tests")]
+            HypothesisTest(f) => f.hash(state),
 
             // Other expressions
             Boolean(f) => f.hash(state),
@@ -408,6 +482,9 @@ impl Hash for FunctionExpr {
             },
             MaxHorizontal | MinHorizontal | SumHorizontal | MeanHorizontal | DropNans
             | DropNulls | Reverse | ArgUnique | Shift | ShiftAndFill => {},
+            ArgMaxHorizontal { return_names } | ArgMinHorizontal { return_names } => {
+                return_names.hash(state)
+            },
             #[cfg(feature = "mode")]
             Mode => {},
             #[cfg(feature = "abs")]
@@ -478,6 +555,8 @@ impl Hash for FunctionExpr {
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => {},
+            #[cfg(feature = "approx_unique")]
+            Sketch(f) => f.hash(state),
             Coalesce => {},
             ShrinkType => {},
             #[cfg(feature = "pct_change")]
@@ -495,13 +574,23 @@ impl Hash for FunctionExpr {
             Exp => {},
             Unique(a) => a.hash(state),
             #[cfg(feature = "round_series")]
-            Round { decimals } => decimals.hash(state),
+            Round { decimals, mode } => {
+                decimals.hash(state);
+                mode.hash(state);
+            },
             #[cfg(feature = "round_series")]
             FunctionExpr::RoundSF { digits } => digits.hash(state),
             #[cfg(feature = "round_series")]
             FunctionExpr::Floor => {},
             #[cfg(feature = "round_series")]
             Ceil => {},
+            #[cfg(feature = "round_series")]
+            CeilDiv => {},
+            #[cfg(feature = "round_series")]
+            Rescale { scale, mode } => {
+                scale.hash(state);
+                mode.hash(state);
+            },
             UpperBound => {},
             LowerBound => {},
             ConcatExpr(a) => a.hash(state),
@@ -548,7 +637,11 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "rle")]
             RLEID => {},
             ToPhysical => {},
+            IsValidMask | WithValidity | CoalesceValidity => {},
             SetSortedFlag(is_sorted) => is_sorted.hash(state),
+            Parallel(parallel) => parallel.hash(state),
+            OrElse => {},
+            CheckSorted { descending } => descending.hash(state),
             BackwardFill { limit } | ForwardFill { limit } => limit.hash(state),
             #[cfg(feature = "ewma")]
             EwmMean { options } => options.hash(state),
@@ -563,16 +656,33 @@ impl Hash for FunctionExpr {
                 bin_count,
                 include_category,
                 include_breakpoint,
+                bin_strategy,
+                shared_bins,
             } => {
                 bin_count.hash(state);
                 include_category.hash(state);
                 include_breakpoint.hash(state);
+                bin_strategy.hash(state);
+                shared_bins.hash(state);
+            },
+            #[cfg(feature = "hist")]
+            Hist2d {
+                bin_count,
+                bin_strategy,
+            } => {
+                bin_count.hash(state);
+                bin_strategy.hash(state);
             },
             #[cfg(feature = "replace")]
             Replace => {},
             #[cfg(feature = "replace")]
             ReplaceStrict { return_dtype } => return_dtype.hash(state),
             FillNullWithStrategy(strategy) => strategy.hash(state),
+            #[cfg(feature = "fill_null_by")]
+            FillNullBy { strategy, max_gap } => {
+                strategy.hash(state);
+                max_gap.hash(state);
+            },
             GatherEvery { n, offset } => (n, offset).hash(state),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => signed.hash(state),
@@ -593,6 +703,8 @@ impl Display for FunctionExpr {
             BinaryExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-json")]
+            JsonExpr(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
@@ -600,6 +712,10 @@ impl Display for FunctionExpr {
             StructExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "temporal")]
             TemporalExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "downsample")]
+            Downsample(func) => return write!(f, "{func}"),
+            #[cfg(feature = "hypothesis_tests")]
+            HypothesisTest(func) => return write!(f, "{func}"),
 
             // Other expressions
             Boolean(func) => return write!(f, "{func}"),
@@ -678,6 +794,8 @@ impl Display for FunctionExpr {
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => "approx_n_unique",
+            #[cfg(feature = "approx_unique")]
+            Sketch(func) => return write!(f, "{func}"),
             Coalesce => "coalesce",
             ShrinkType => "shrink_dtype",
             #[cfg(feature = "diff")]
@@ -711,6 +829,10 @@ impl Display for FunctionExpr {
             Floor => "floor",
             #[cfg(feature = "round_series")]
             Ceil => "ceil",
+            #[cfg(feature = "round_series")]
+            CeilDiv => "ceil_div",
+            #[cfg(feature = "round_series")]
+            Rescale { .. } => "rescale",
             UpperBound => "upper_bound",
             LowerBound => "lower_bound",
             #[cfg(feature = "fused")]
@@ -734,15 +856,23 @@ impl Display for FunctionExpr {
             #[cfg(feature = "rle")]
             RLEID => "rle_id",
             ToPhysical => "to_physical",
+            IsValidMask => "is_valid_mask",
+            WithValidity => "with_validity",
+            CoalesceValidity => "coalesce_validity",
             #[cfg(feature = "random")]
             Random { method, .. } => method.into(),
             SetSortedFlag(_) => "set_sorted",
+            Parallel(_) => "parallel",
+            OrElse => "or_else",
+            CheckSorted { .. } => "check_sorted",
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin { lib, symbol, .. } => return write!(f, "{lib}:{symbol}"),
             BackwardFill { .. } => "backward_fill",
             ForwardFill { .. } => "forward_fill",
             MaxHorizontal => "max_horizontal",
             MinHorizontal => "min_horizontal",
+            ArgMaxHorizontal { .. } => "arg_max_horizontal",
+            ArgMinHorizontal { .. } => "arg_min_horizontal",
             SumHorizontal => "sum_horizontal",
             MeanHorizontal => "mean_horizontal",
             #[cfg(feature = "ewma")]
@@ -755,11 +885,15 @@ impl Display for FunctionExpr {
             EwmVar { .. } => "ewm_var",
             #[cfg(feature = "hist")]
             Hist { .. } => "hist",
+            #[cfg(feature = "hist")]
+            Hist2d { .. } => "hist_2d",
             #[cfg(feature = "replace")]
             Replace => "replace",
             #[cfg(feature = "replace")]
             ReplaceStrict { .. } => "replace_strict",
             FillNullWithStrategy(_) => "fill_null_with_strategy",
+            #[cfg(feature = "fill_null_by")]
+            FillNullBy { .. } => "fill_null_by",
             GatherEvery { .. } => "gather_every",
             #[cfg(feature = "reinterpret")]
             Reinterpret(_) => "reinterpret",
@@ -861,6 +995,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             BinaryExpr(func) => func.into(),
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.into(),
+            #[cfg(feature = "dtype-json")]
+            JsonExpr(func) => func.into(),
             ListExpr(func) => func.into(),
             #[cfg(feature = "strings")]
             StringExpr(func) => func.into(),
@@ -868,6 +1004,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             StructExpr(func) => func.into(),
             #[cfg(feature = "temporal")]
             TemporalExpr(func) => func.into(),
+            #[cfg(feature = "downsample")]
+            Downsample(func) => func.into(),
+            #[cfg(feature = "hypothesis_tests")]
+            HypothesisTest(func) => func.into(),
 
             // Other expressions
             Boolean(func) => func.into(),
@@ -954,14 +1094,22 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                 bin_count,
                 include_category,
                 include_breakpoint,
+                bin_strategy,
+                ..
             } => {
                 map_as_slice!(
                     dispatch::hist,
                     bin_count,
+                    bin_strategy,
                     include_category,
                     include_breakpoint
                 )
             },
+            #[cfg(feature = "hist")]
+            Hist2d {
+                bin_count,
+                bin_strategy,
+            } => map_as_slice!(dispatch::hist_2d, bin_count, bin_strategy),
             ShiftAndFill => {
                 map_as_slice!(shift_and_fill::shift_and_fill)
             },
@@ -1019,6 +1167,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Reverse => map!(dispatch::reverse),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => map!(dispatch::approx_n_unique),
+            #[cfg(feature = "approx_unique")]
+            Sketch(func) => func.into(),
             Coalesce => map_as_slice!(fill_null::coalesce),
             ShrinkType => map_owned!(shrink_type::shrink),
             #[cfg(feature = "diff")]
@@ -1043,13 +1193,17 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Exp => map!(log::exp),
             Unique(stable) => map!(unique::unique, stable),
             #[cfg(feature = "round_series")]
-            Round { decimals } => map!(round::round, decimals),
+            Round { decimals, mode } => map!(round::round, decimals, mode),
             #[cfg(feature = "round_series")]
             RoundSF { digits } => map!(round::round_sig_figs, digits),
             #[cfg(feature = "round_series")]
             Floor => map!(round::floor),
             #[cfg(feature = "round_series")]
             Ceil => map!(round::ceil),
+            #[cfg(feature = "round_series")]
+            CeilDiv => map_as_slice!(round::ceil_div),
+            #[cfg(feature = "round_series")]
+            Rescale { scale, mode } => map!(round::rescale, scale, mode),
             UpperBound => map!(bounds::upper_bound),
             LowerBound => map!(bounds::lower_bound),
             #[cfg(feature = "fused")]
@@ -1097,6 +1251,9 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "rle")]
             RLEID => map!(rle_id),
             ToPhysical => map!(dispatch::to_physical),
+            IsValidMask => map!(dispatch::is_valid_mask),
+            WithValidity => map_as_slice!(dispatch::with_validity),
+            CoalesceValidity => map_as_slice!(dispatch::coalesce_validity),
             #[cfg(feature = "random")]
             Random { method, seed } => {
                 use RandomMethod::*;
@@ -1113,9 +1270,15 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                             map_as_slice!(random::sample_n, with_replacement, shuffle, seed)
                         }
                     },
+                    Distribution(distribution) => {
+                        map_as_slice!(random::distribution, distribution, seed)
+                    },
                 }
             },
             SetSortedFlag(sorted) => map!(dispatch::set_sorted_flag, sorted),
+            Parallel(_) => map!(dispatch::identity),
+            OrElse => map_as_slice!(dispatch::or_else),
+            CheckSorted { descending } => map!(dispatch::check_sorted, descending),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 lib,
@@ -1133,6 +1296,12 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             ForwardFill { limit } => map!(dispatch::forward_fill, limit),
             MaxHorizontal => wrap!(dispatch::max_horizontal),
             MinHorizontal => wrap!(dispatch::min_horizontal),
+            ArgMaxHorizontal { return_names } => {
+                wrap!(dispatch::arg_min_max_horizontal, true, return_names)
+            },
+            ArgMinHorizontal { return_names } => {
+                wrap!(dispatch::arg_min_max_horizontal, false, return_names)
+            },
             SumHorizontal => wrap!(dispatch::sum_horizontal),
             MeanHorizontal => wrap!(dispatch::mean_horizontal),
             #[cfg(feature = "ewma")]
@@ -1153,6 +1322,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             },
 
             FillNullWithStrategy(strategy) => map!(dispatch::fill_null_with_strategy, strategy),
+            #[cfg(feature = "fill_null_by")]
+            FillNullBy { strategy, max_gap } => {
+                map_as_slice!(dispatch::fill_null_by, strategy, max_gap)
+            },
             GatherEvery { n, offset } => map!(dispatch::gather_every, n, offset),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => map!(dispatch::reinterpret, signed),
@@ -1,9 +1,10 @@
-use std::ffi::CStr;
+use std::ffi::{c_void, CStr};
 use std::sync::RwLock;
 
 use arrow::ffi::{import_field_from_c, ArrowSchema};
 use libloading::Library;
 use once_cell::sync::Lazy;
+use polars_ffi::version_0::{export_series, import_series, SeriesExport};
 
 use super::*;
 
@@ -116,7 +117,7 @@ pub(super) unsafe fn call_plugin(
     }
 }
 
-pub(super) unsafe fn plugin_field(
+pub(crate) unsafe fn plugin_field(
     fields: &[Field],
     lib: &str,
     symbol: &str,
@@ -197,6 +198,192 @@ pub(super) unsafe fn plugin_field(
     }
 }
 
+/// A user-defined aggregation of a plugin.
+///
+/// Its output field is determined by `_polars_plugin_field_{symbol}`, as for plugin functions.
+/// The values are aggregated into a state that is created, updated and merged by these
+/// functions of the plugin:
+///
+/// - `_polars_plugin_agg_init_{symbol}(kwargs: *const u8, kwargs_len: usize) -> *mut c_void`
+///   creates an empty state.
+/// - `_polars_plugin_agg_update_{symbol}(state: *mut c_void, inputs: *const SeriesExport,
+///   n_inputs: usize) -> bool` adds a part of the values of a group. The plugin releases the
+///   inputs.
+/// - `_polars_plugin_agg_merge_{symbol}(state: *mut c_void, other: *const c_void) -> bool` adds
+///   the values of another state of the same group. Parts are not merged in a defined order.
+/// - `_polars_plugin_agg_finalize_{symbol}(state: *mut c_void, out: *mut SeriesExport)` writes
+///   the result, a series of length 1.
+/// - `_polars_plugin_agg_drop_{symbol}(state: *mut c_void)` frees the state.
+///
+/// On failure `init` returns a null pointer, `update` and `merge` return `false` and `finalize`
+/// doesn't write its output; the error is given by `_polars_plugin_get_last_error_message`.
+/// States may be moved to other threads, but a state is never used by two threads at once.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PluginAggregation {
+    /// Shared library.
+    pub lib: Arc<str>,
+    /// Identifier of the aggregation in the shared lib.
+    pub symbol: Arc<str>,
+    /// Pickle serialized keyword arguments.
+    pub kwargs: Arc<[u8]>,
+    /// Whether the inputs are packed into a struct, whose fields are passed to the plugin as
+    /// separate inputs.
+    pub packed: bool,
+}
+
+type AggInitFn = unsafe extern "C" fn(*const u8, usize) -> *mut c_void;
+type AggUpdateFn = unsafe extern "C" fn(*mut c_void, *const SeriesExport, usize) -> bool;
+type AggMergeFn = unsafe extern "C" fn(*mut c_void, *const c_void) -> bool;
+type AggFinalizeFn = unsafe extern "C" fn(*mut c_void, *mut SeriesExport);
+type AggDropFn = unsafe extern "C" fn(*mut c_void);
+
+impl PluginAggregation {
+    /// Load the functions of the aggregation from the plugin.
+    pub fn load(&self) -> PolarsResult<Arc<PluginAggregator>> {
+        let plugin = get_lib(&self.lib)?;
+        polars_ensure!(
+            plugin.1 == 0,
+            ComputeError: "this Polars engine doesn't support plugin version: {}", plugin.1
+        );
+        let lib = &plugin.0;
+        unsafe fn get<T: Copy>(lib: &Library, name: &str, symbol: &str) -> PolarsResult<T> {
+            let name = format!("_polars_plugin_agg_{name}_{symbol}");
+            let function = lib.get::<T>(name.as_bytes()).map_err(|_| {
+                polars_err!(ComputeError: "the plugin doesn't export the aggregation function '{}'", name)
+            })?;
+            Ok(*function)
+        }
+        unsafe {
+            Ok(Arc::new(PluginAggregator {
+                lib,
+                kwargs: self.kwargs.clone(),
+                packed: self.packed,
+                init: get(lib, "init", &self.symbol)?,
+                update: get(lib, "update", &self.symbol)?,
+                merge: get(lib, "merge", &self.symbol)?,
+                finalize: get(lib, "finalize", &self.symbol)?,
+                drop_state: get(lib, "drop", &self.symbol)?,
+            }))
+        }
+    }
+
+    /// The output field of the aggregation. The output keeps the name of the input.
+    pub fn output_field(&self, input: &Field) -> PolarsResult<Field> {
+        let fields = match input.data_type() {
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(fields) if self.packed => fields.clone(),
+            _ => vec![input.clone()],
+        };
+        let field = unsafe { plugin_field(&fields, &self.lib, &self.symbol, &self.kwargs)? };
+        Ok(Field::new(input.name(), field.dtype))
+    }
+}
+
+/// The loaded functions of a [`PluginAggregation`].
+pub struct PluginAggregator {
+    lib: &'static Library,
+    kwargs: Arc<[u8]>,
+    packed: bool,
+    init: AggInitFn,
+    update: AggUpdateFn,
+    merge: AggMergeFn,
+    finalize: AggFinalizeFn,
+    drop_state: AggDropFn,
+}
+
+impl PluginAggregator {
+    /// Create an empty aggregation state.
+    pub fn init(self: &Arc<Self>) -> PolarsResult<PluginAggState> {
+        let state = unsafe { (self.init)(self.kwargs.as_ptr(), self.kwargs.len()) };
+        if state.is_null() {
+            return Err(self.last_error());
+        }
+        Ok(PluginAggState {
+            state,
+            aggregator: self.clone(),
+        })
+    }
+
+    /// Aggregate `input` in a single state.
+    pub fn aggregate(self: &Arc<Self>, input: &Series) -> PolarsResult<Series> {
+        let mut state = self.init()?;
+        state.update(input)?;
+        state.finalize()
+    }
+
+    fn last_error(&self) -> PolarsError {
+        let msg = unsafe { retrieve_error_msg(self.lib) }.to_string_lossy();
+        match check_panic(msg.as_ref()) {
+            Ok(()) => polars_err!(ComputeError: "the plugin failed with message: {}", msg),
+            Err(e) => e,
+        }
+    }
+}
+
+/// The state of a [`PluginAggregation`] for a single group.
+pub struct PluginAggState {
+    state: *mut c_void,
+    aggregator: Arc<PluginAggregator>,
+}
+
+// SAFETY: plugins must allow their states to be moved between threads. A state is only used
+// through mutable references, so it is never used by two threads at once.
+unsafe impl Send for PluginAggState {}
+unsafe impl Sync for PluginAggState {}
+
+impl PluginAggState {
+    /// Add the values of `input` to the state.
+    pub fn update(&mut self, input: &Series) -> PolarsResult<()> {
+        let inputs = match input.dtype() {
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(_) if self.aggregator.packed => input.struct_()?.fields().to_vec(),
+            _ => vec![input.clone()],
+        };
+        let exports = inputs.iter().map(export_series).collect::<Vec<_>>();
+        let ok = unsafe { (self.aggregator.update)(self.state, exports.as_ptr(), exports.len()) };
+        // The inputs get dropped when the ffi side calls the drop callback.
+        for e in exports {
+            std::mem::forget(e);
+        }
+        if ok {
+            Ok(())
+        } else {
+            Err(self.aggregator.last_error())
+        }
+    }
+
+    /// Add the values of another state of the same aggregation.
+    pub fn merge(&mut self, other: &PluginAggState) -> PolarsResult<()> {
+        if unsafe { (self.aggregator.merge)(self.state, other.state) } {
+            Ok(())
+        } else {
+            Err(self.aggregator.last_error())
+        }
+    }
+
+    /// The aggregated value, as a series of length 1.
+    pub fn finalize(&mut self) -> PolarsResult<Series> {
+        let mut out = SeriesExport::empty();
+        unsafe { (self.aggregator.finalize)(self.state, &mut out) };
+        if out.is_null() {
+            return Err(self.aggregator.last_error());
+        }
+        let out = unsafe { import_series(out)? };
+        polars_ensure!(
+            out.len() == 1,
+            ComputeError: "the plugin aggregation returned {} values instead of 1", out.len()
+        );
+        Ok(out)
+    }
+}
+
+impl Drop for PluginAggState {
+    fn drop(&mut self) {
+        unsafe { (self.aggregator.drop_state)(self.state) }
+    }
+}
+
 fn check_panic(msg: &str) -> PolarsResult<()> {
     polars_ensure!(msg != "PANIC", ComputeError: "the plugin panicked\n\nThe message is suppressed. Set POLARS_VERBOSE=1 to send the panic message to stderr.");
     Ok(())
@@ -15,11 +15,17 @@ pub enum RandomMethod {
         with_replacement: bool,
         shuffle: bool,
     },
+    /// Draw values from a distribution, with the parameters and the number of values as
+    /// inputs.
+    Distribution(RandomDistribution),
 }
 
 impl Hash for RandomMethod {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        std::mem::discriminant(self).hash(state)
+        std::mem::discriminant(self).hash(state);
+        if let Self::Distribution(distribution) = self {
+            distribution.hash(state)
+        }
     }
 }
 
@@ -50,6 +56,23 @@ pub(super) fn sample_frac(
     }
 }
 
+pub(super) fn distribution(
+    s: &[Series],
+    distribution: RandomDistribution,
+    seed: Option<u64>,
+) -> PolarsResult<Series> {
+    let (n_s, params) = s.split_last().unwrap();
+
+    polars_ensure!(
+        n_s.len() == 1,
+        ComputeError: "Number of random values must be a single value."
+    );
+
+    let n_s = n_s.cast(&IDX_DTYPE)?;
+    let n = n_s.idx()?.get(0).unwrap_or(0) as usize;
+    polars_ops::series::sample_distribution(params[0].name(), distribution, params, n, seed)
+}
+
 pub(super) fn sample_n(
     s: &[Series],
     with_replacement: bool,
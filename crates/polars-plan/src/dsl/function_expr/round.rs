@@ -1,7 +1,7 @@
 use super::*;
 
-pub(super) fn round(s: &Series, decimals: u32) -> PolarsResult<Series> {
-    s.round(decimals)
+pub(super) fn round(s: &Series, decimals: u32, mode: RoundMode) -> PolarsResult<Series> {
+    s.round(decimals, mode)
 }
 
 pub(super) fn round_sig_figs(s: &Series, digits: i32) -> PolarsResult<Series> {
@@ -15,3 +15,27 @@ pub(super) fn floor(s: &Series) -> PolarsResult<Series> {
 pub(super) fn ceil(s: &Series) -> PolarsResult<Series> {
     s.ceil()
 }
+
+pub(super) fn rescale(s: &Series, scale: usize, mode: RoundMode) -> PolarsResult<Series> {
+    s.rescale(scale, mode)
+}
+
+#[allow(unused_variables)]
+pub(super) fn rescale_dtype(dtype: &DataType, scale: usize) -> DataType {
+    #[cfg(feature = "dtype-decimal")]
+    {
+        use polars_core::chunked_array::arithmetic::{
+            decimal_rescale_precision, integer_decimal_dtype,
+        };
+        let dtype = integer_decimal_dtype(dtype).unwrap_or_else(|| dtype.clone());
+        if let DataType::Decimal(precision, from) = dtype {
+            let precision = decimal_rescale_precision(precision, from.unwrap_or(0), scale);
+            return DataType::Decimal(precision, Some(scale));
+        }
+    }
+    dtype.clone()
+}
+
+pub(super) fn ceil_div(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::prelude::ceil_div_series(&s[0], &s[1])
+}
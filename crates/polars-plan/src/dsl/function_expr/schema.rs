@@ -19,6 +19,8 @@ impl FunctionExpr {
             BinaryExpr(s) => s.get_field(mapper),
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-json")]
+            JsonExpr(func) => func.get_field(mapper),
             ListExpr(func) => func.get_field(mapper),
             #[cfg(feature = "strings")]
             StringExpr(s) => s.get_field(mapper),
@@ -26,6 +28,10 @@ impl FunctionExpr {
             StructExpr(s) => s.get_field(mapper),
             #[cfg(feature = "temporal")]
             TemporalExpr(fun) => fun.get_field(mapper),
+            #[cfg(feature = "downsample")]
+            Downsample(func) => func.get_field(mapper),
+            #[cfg(feature = "hypothesis_tests")]
+            HypothesisTest(func) => func.get_field(mapper),
 
             // Other expressions
             Boolean(func) => func.get_field(mapper),
@@ -134,6 +140,8 @@ impl FunctionExpr {
             CumMax { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "approx_unique")]
+            Sketch(func) => func.get_field(mapper),
             #[cfg(feature = "hist")]
             Hist {
                 include_category,
@@ -157,6 +165,12 @@ impl FunctionExpr {
                     mapper.with_dtype(IDX_DTYPE)
                 }
             },
+            #[cfg(feature = "hist")]
+            Hist2d { .. } => mapper.with_dtype(DataType::Struct(vec![
+                Field::new("x_breakpoint", DataType::Float64),
+                Field::new("y_breakpoint", DataType::Float64),
+                Field::new("count", IDX_DTYPE),
+            ])),
             #[cfg(feature = "diff")]
             Diff(_, _) => mapper.map_dtype(|dt| match dt {
                 #[cfg(feature = "dtype-datetime")]
@@ -210,6 +224,10 @@ impl FunctionExpr {
             Unique(_) => mapper.with_same_dtype(),
             #[cfg(feature = "round_series")]
             Round { .. } | RoundSF { .. } | Floor | Ceil => mapper.with_same_dtype(),
+            #[cfg(feature = "round_series")]
+            CeilDiv => mapper.map_to_supertype(),
+            #[cfg(feature = "round_series")]
+            Rescale { scale, .. } => mapper.map_dtype(|dt| round::rescale_dtype(dt, *scale)),
             UpperBound | LowerBound => mapper.with_same_dtype(),
             #[cfg(feature = "fused")]
             Fused(_) => mapper.map_to_supertype(),
@@ -284,9 +302,17 @@ impl FunctionExpr {
             #[cfg(feature = "rle")]
             RLEID => mapper.with_dtype(IDX_DTYPE),
             ToPhysical => mapper.to_physical_type(),
+            IsValidMask => mapper.with_dtype(DataType::Boolean),
+            WithValidity | CoalesceValidity => mapper.with_same_dtype(),
+            #[cfg(feature = "random")]
+            Random {
+                method: RandomMethod::Distribution(distribution),
+                ..
+            } => mapper.with_dtype(distribution.dtype()),
             #[cfg(feature = "random")]
             Random { .. } => mapper.with_same_dtype(),
-            SetSortedFlag(_) => mapper.with_same_dtype(),
+            SetSortedFlag(_) | Parallel(_) | CheckSorted { .. } => mapper.with_same_dtype(),
+            OrElse => mapper.map_to_supertype(),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 lib,
@@ -297,6 +323,13 @@ impl FunctionExpr {
             ForwardFill { .. } => mapper.with_same_dtype(),
             MaxHorizontal => mapper.map_to_supertype(),
             MinHorizontal => mapper.map_to_supertype(),
+            ArgMaxHorizontal { return_names } | ArgMinHorizontal { return_names } => {
+                if *return_names {
+                    mapper.with_dtype(DataType::String)
+                } else {
+                    mapper.with_dtype(IDX_DTYPE)
+                }
+            },
             SumHorizontal => {
                 if mapper.fields[0].data_type() == &DataType::Boolean {
                     mapper.with_dtype(DataType::UInt32)
@@ -318,6 +351,8 @@ impl FunctionExpr {
             #[cfg(feature = "replace")]
             ReplaceStrict { return_dtype } => mapper.replace_dtype(return_dtype.clone()),
             FillNullWithStrategy(_) => mapper.with_same_dtype(),
+            #[cfg(feature = "fill_null_by")]
+            FillNullBy { .. } => mapper.with_same_dtype(),
             GatherEvery { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => {
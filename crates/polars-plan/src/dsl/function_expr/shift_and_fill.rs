@@ -11,20 +11,18 @@ where
     ca.shift_and_fill(n, fill_value)
 }
 
-#[cfg(any(
-    feature = "object",
-    feature = "dtype-struct",
-    feature = "dtype-categorical"
-))]
+/// Shift `s` and take the rows shifted in from `fill_value`, which is a single value or has a
+/// value for every row.
 fn shift_and_fill_with_mask(s: &Series, n: i64, fill_value: &Series) -> PolarsResult<Series> {
     use polars_core::export::arrow::array::BooleanArray;
     use polars_core::export::arrow::bitmap::MutableBitmap;
 
     let mask: BooleanChunked = if n > 0 {
         let len = s.len();
+        let n = std::cmp::min(n as usize, len);
         let mut bits = MutableBitmap::with_capacity(s.len());
-        bits.extend_constant(n as usize, false);
-        bits.extend_constant(len.saturating_sub(n as usize), true);
+        bits.extend_constant(n, false);
+        bits.extend_constant(len - n, true);
         let mask = BooleanArray::from_data_default(bits.into(), None);
         mask.into()
     } else {
@@ -33,7 +31,7 @@ fn shift_and_fill_with_mask(s: &Series, n: i64, fill_value: &Series) -> PolarsRe
         let tipping_point = std::cmp::max(length + n, 0);
         let mut bits = MutableBitmap::with_capacity(s.len());
         bits.extend_constant(tipping_point as usize, true);
-        bits.extend_constant(-n as usize, false);
+        bits.extend_constant((length - tipping_point) as usize, false);
         let mask = BooleanArray::from_data_default(bits.into(), None);
         mask.into()
     };
@@ -55,6 +53,15 @@ pub(super) fn shift_and_fill(args: &[Series]) -> PolarsResult<Series> {
         let logical = s.dtype();
         let physical = s.to_physical_repr();
         let fill_value_s = &args[2];
+        // A fill value per row, e.g. a column.
+        if fill_value_s.len() != 1 {
+            polars_ensure!(
+                fill_value_s.len() == s.len(),
+                ShapeMismatch: "fill value has length {}, expected 1 or {}",
+                fill_value_s.len(), s.len()
+            );
+            return shift_and_fill_with_mask(s, n, &fill_value_s.cast(logical)?);
+        }
         let fill_value = fill_value_s.get(0)?;
 
         use DataType::*;
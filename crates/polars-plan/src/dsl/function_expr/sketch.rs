@@ -0,0 +1,82 @@
+use super::*;
+use crate::map;
+
+/// Functions that export, merge and finalize the state of approximate aggregations.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SketchFunction {
+    ApproxNUniqueSketch,
+    #[cfg(feature = "approx_quantile")]
+    ApproxQuantileSketch {
+        accuracy: u32,
+    },
+    Merge,
+    ApproxNUniqueFromSketch,
+    #[cfg(feature = "approx_quantile")]
+    ApproxQuantileFromSketch {
+        quantile: f64,
+    },
+}
+
+impl SketchFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use SketchFunction::*;
+        match self {
+            ApproxNUniqueSketch | Merge => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantileSketch { .. } => mapper.with_dtype(DataType::Binary),
+            ApproxNUniqueFromSketch => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantileFromSketch { .. } => mapper.with_dtype(DataType::Float64),
+        }
+    }
+}
+
+impl Hash for SketchFunction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        use SketchFunction::*;
+        match self {
+            ApproxNUniqueSketch | Merge | ApproxNUniqueFromSketch => {},
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantileSketch { accuracy } => accuracy.hash(state),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantileFromSketch { quantile } => quantile.to_bits().hash(state),
+        }
+    }
+}
+
+impl Display for SketchFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use SketchFunction::*;
+        let s = match self {
+            ApproxNUniqueSketch => "approx_n_unique_sketch",
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantileSketch { .. } => "approx_quantile_sketch",
+            Merge => "merge_sketches",
+            ApproxNUniqueFromSketch => "approx_n_unique_from_sketch",
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantileFromSketch { .. } => "approx_quantile_from_sketch",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl From<SketchFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
+    fn from(func: SketchFunction) -> Self {
+        use SketchFunction::*;
+        match func {
+            ApproxNUniqueSketch => map!(polars_ops::prelude::approx_n_unique_sketch),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantileSketch { accuracy } => {
+                map!(polars_ops::prelude::approx_quantile_sketch, accuracy)
+            },
+            Merge => map!(polars_ops::prelude::merge_sketches),
+            ApproxNUniqueFromSketch => map!(polars_ops::prelude::approx_n_unique_from_sketch),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantileFromSketch { quantile } => {
+                map!(polars_ops::prelude::approx_quantile_from_sketch, quantile)
+            },
+        }
+    }
+}
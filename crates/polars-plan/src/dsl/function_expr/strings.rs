@@ -61,6 +61,7 @@ pub enum StringFunction {
     JsonDecode {
         dtype: Option<DataType>,
         infer_schema_len: Option<usize>,
+        strict: bool,
     },
     #[cfg(feature = "extract_jsonpath")]
     JsonPathMatch,
@@ -130,6 +131,26 @@ pub enum StringFunction {
         ascii_case_insensitive: bool,
         overlapping: bool,
     },
+    #[cfg(feature = "string_similarity")]
+    Levenshtein {
+        normalized: bool,
+    },
+    #[cfg(feature = "string_similarity")]
+    DamerauLevenshtein {
+        normalized: bool,
+    },
+    #[cfg(feature = "string_similarity")]
+    Hamming {
+        normalized: bool,
+    },
+    #[cfg(feature = "string_similarity")]
+    JaroWinkler,
+    #[cfg(feature = "collation")]
+    CollatedCompare(Collation),
+    #[cfg(feature = "collation")]
+    CollatedMin(Collation),
+    #[cfg(feature = "collation")]
+    CollatedMax(Collation),
 }
 
 impl StringFunction {
@@ -197,6 +218,20 @@ impl StringFunction {
             ReplaceMany { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "find_many")]
             ExtractMany { .. } => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { normalized }
+            | DamerauLevenshtein { normalized }
+            | Hamming { normalized } => mapper.with_dtype(if *normalized {
+                DataType::Float64
+            } else {
+                DataType::UInt32
+            }),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "collation")]
+            CollatedCompare(_) => mapper.with_dtype(DataType::Int8),
+            #[cfg(feature = "collation")]
+            CollatedMin(_) | CollatedMax(_) => mapper.with_same_dtype(),
         }
     }
 }
@@ -285,6 +320,20 @@ impl Display for StringFunction {
             ReplaceMany { .. } => "replace_many",
             #[cfg(feature = "find_many")]
             ExtractMany { .. } => "extract_many",
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { .. } => "levenshtein",
+            #[cfg(feature = "string_similarity")]
+            DamerauLevenshtein { .. } => "damerau_levenshtein",
+            #[cfg(feature = "string_similarity")]
+            Hamming { .. } => "hamming",
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => "jaro_winkler",
+            #[cfg(feature = "collation")]
+            CollatedCompare(_) => "compare",
+            #[cfg(feature = "collation")]
+            CollatedMin(_) => "min",
+            #[cfg(feature = "collation")]
+            CollatedMax(_) => "max",
         };
         write!(f, "str.{s}")
     }
@@ -378,7 +427,13 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             JsonDecode {
                 dtype,
                 infer_schema_len,
-            } => map!(strings::json_decode, dtype.clone(), infer_schema_len),
+                strict,
+            } => map!(
+                strings::json_decode,
+                dtype.clone(),
+                infer_schema_len,
+                strict
+            ),
             #[cfg(feature = "extract_jsonpath")]
             JsonPathMatch => map_as_slice!(strings::json_path_match),
             #[cfg(feature = "find_many")]
@@ -400,6 +455,22 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             } => {
                 map_as_slice!(extract_many, ascii_case_insensitive, overlapping)
             },
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { normalized } => map_as_slice!(strings::levenshtein, normalized),
+            #[cfg(feature = "string_similarity")]
+            DamerauLevenshtein { normalized } => {
+                map_as_slice!(strings::damerau_levenshtein, normalized)
+            },
+            #[cfg(feature = "string_similarity")]
+            Hamming { normalized } => map_as_slice!(strings::hamming, normalized),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => map_as_slice!(strings::jaro_winkler),
+            #[cfg(feature = "collation")]
+            CollatedCompare(collation) => map_as_slice!(strings::collated_compare, collation),
+            #[cfg(feature = "collation")]
+            CollatedMin(collation) => map!(strings::collated_min, collation),
+            #[cfg(feature = "collation")]
+            CollatedMax(collation) => map!(strings::collated_max, collation),
         }
     }
 }
@@ -1008,9 +1079,21 @@ pub(super) fn json_decode(
     s: &Series,
     dtype: Option<DataType>,
     infer_schema_len: Option<usize>,
+    strict: bool,
 ) -> PolarsResult<Series> {
     let ca = s.str()?;
-    ca.json_decode(dtype, infer_schema_len)
+    if strict {
+        return ca.json_decode(dtype, infer_schema_len);
+    }
+    let (out, invalid) = ca.json_decode_lossy(dtype, infer_schema_len)?;
+    if let (true, Some(first)) = (polars_core::config::verbose(), invalid.get(0)) {
+        eprintln!(
+            "json_decode: set {} rows that are not valid JSON to null, the first is row {}",
+            invalid.len(),
+            first
+        );
+    }
+    Ok(out)
 }
 
 #[cfg(feature = "extract_jsonpath")]
@@ -1019,3 +1102,78 @@ pub(super) fn json_path_match(s: &[Series]) -> PolarsResult<Series> {
     let pat = s[1].str()?;
     Ok(ca.json_path_match(pat)?.into_series())
 }
+
+#[cfg(feature = "string_similarity")]
+fn similarity_args(s: &[Series]) -> PolarsResult<(&StringChunked, &StringChunked)> {
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    polars_ensure!(
+        ca.len() == other.len() || ca.len() == 1 || other.len() == 1,
+        ShapeMismatch: "cannot compare strings of lengths {} and {}", ca.len(), other.len()
+    );
+    Ok((ca, other))
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn levenshtein(s: &[Series], normalized: bool) -> PolarsResult<Series> {
+    use polars_ops::chunked_array::strings::{levenshtein, levenshtein_similarity};
+    let (ca, other) = similarity_args(s)?;
+    Ok(if normalized {
+        levenshtein_similarity(ca, other).into_series()
+    } else {
+        levenshtein(ca, other).into_series()
+    })
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn damerau_levenshtein(s: &[Series], normalized: bool) -> PolarsResult<Series> {
+    use polars_ops::chunked_array::strings::{damerau_levenshtein, damerau_levenshtein_similarity};
+    let (ca, other) = similarity_args(s)?;
+    Ok(if normalized {
+        damerau_levenshtein_similarity(ca, other).into_series()
+    } else {
+        damerau_levenshtein(ca, other).into_series()
+    })
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn hamming(s: &[Series], normalized: bool) -> PolarsResult<Series> {
+    use polars_ops::chunked_array::strings::{hamming, hamming_similarity};
+    let (ca, other) = similarity_args(s)?;
+    Ok(if normalized {
+        hamming_similarity(ca, other).into_series()
+    } else {
+        hamming(ca, other).into_series()
+    })
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn jaro_winkler(s: &[Series]) -> PolarsResult<Series> {
+    let (ca, other) = similarity_args(s)?;
+    Ok(polars_ops::chunked_array::strings::jaro_winkler(ca, other).into_series())
+}
+
+#[cfg(feature = "collation")]
+pub(super) fn collated_compare(s: &[Series], collation: Collation) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    polars_ensure!(
+        ca.len() == other.len() || ca.len() == 1 || other.len() == 1,
+        ShapeMismatch: "cannot compare strings of lengths {} and {}", ca.len(), other.len()
+    );
+    Ok(polars_ops::chunked_array::strings::collated_compare(ca, other, collation).into_series())
+}
+
+#[cfg(feature = "collation")]
+pub(super) fn collated_min(s: &Series, collation: Collation) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    let min = polars_ops::chunked_array::strings::collated_min(ca, collation);
+    Ok(StringChunked::from_slice_options(s.name(), &[min]).into_series())
+}
+
+#[cfg(feature = "collation")]
+pub(super) fn collated_max(s: &Series, collation: Collation) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    let max = polars_ops::chunked_array::strings::collated_max(ca, collation);
+    Ok(StringChunked::from_slice_options(s.name(), &[max]).into_series())
+}
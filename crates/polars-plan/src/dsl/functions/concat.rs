@@ -69,6 +69,28 @@ pub fn concat_list<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(s: E) -> PolarsResult
     })
 }
 
+/// Zip list columns into a list of structs, so that an expression can be evaluated over the
+/// elements of all of them with `list().eval()` instead of exploding them to rows.
+///
+/// The fields of the structs are named after the inputs. The lists of a row must have the
+/// same length.
+#[cfg(feature = "dtype-struct")]
+pub fn zip_lists<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(s: E) -> PolarsResult<Expr> {
+    let s: Vec<_> = s.as_ref().iter().map(|e| e.clone().into()).collect();
+
+    polars_ensure!(!s.is_empty(), ComputeError: "`zip_lists` needs one or more expressions");
+
+    Ok(Expr::Function {
+        input: s,
+        function: FunctionExpr::ListExpr(ListFunction::Zip),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            input_wildcard_expansion: true,
+            ..Default::default()
+        },
+    })
+}
+
 pub fn concat_expr<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
     s: E,
     rechunk: bool,
@@ -0,0 +1,30 @@
+use super::*;
+
+/// Compute the two-dimensional histogram of two columns.
+///
+/// Both axes are binned as in [`Expr::hist`]. The result is a struct with the upper bounds
+/// of the bins of both axes and the number of rows that fall in every pair of bins.
+pub fn hist_2d(
+    x: Expr,
+    y: Expr,
+    bins: Option<Expr>,
+    bin_count: Option<usize>,
+    bin_strategy: Option<HistBinStrategy>,
+) -> Expr {
+    let mut input = vec![x, y];
+    if let Some(bins) = bins {
+        input.push(bins)
+    }
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Hist2d {
+            bin_count,
+            bin_strategy,
+        },
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            ..Default::default()
+        },
+    }
+}
@@ -263,6 +263,44 @@ pub fn min_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
     })
 }
 
+/// Create a new column with the index of the column that holds the maximum value per row.
+///
+/// Null values are ignored and ties resolve to the first column. If `return_names` is set,
+/// the name of the column is returned instead of its index.
+pub fn arg_max_horizontal<E: AsRef<[Expr]>>(exprs: E, return_names: bool) -> PolarsResult<Expr> {
+    arg_min_max_horizontal(
+        exprs.as_ref(),
+        FunctionExpr::ArgMaxHorizontal { return_names },
+    )
+}
+
+/// Create a new column with the index of the column that holds the minimum value per row.
+///
+/// Null values are ignored and ties resolve to the first column. If `return_names` is set,
+/// the name of the column is returned instead of its index.
+pub fn arg_min_horizontal<E: AsRef<[Expr]>>(exprs: E, return_names: bool) -> PolarsResult<Expr> {
+    arg_min_max_horizontal(
+        exprs.as_ref(),
+        FunctionExpr::ArgMinHorizontal { return_names },
+    )
+}
+
+fn arg_min_max_horizontal(exprs: &[Expr], function: FunctionExpr) -> PolarsResult<Expr> {
+    polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
+
+    Ok(Expr::Function {
+        input: exprs.to_vec(),
+        function,
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            input_wildcard_expansion: true,
+            returns_scalar: false,
+            allow_rename: true,
+            ..Default::default()
+        },
+    })
+}
+
 /// Sum all values horizontally across columns.
 pub fn sum_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
     let exprs = exprs.as_ref().to_vec();
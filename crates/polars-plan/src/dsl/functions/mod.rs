@@ -9,9 +9,13 @@ mod coerce;
 mod concat;
 #[cfg(feature = "cov")]
 mod correlation;
+#[cfg(feature = "hist")]
+mod hist;
 pub(crate) mod horizontal;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 mod index;
+#[cfg(feature = "random")]
+mod random;
 #[cfg(feature = "range")]
 mod range;
 mod repeat;
@@ -27,11 +31,15 @@ pub use coerce::*;
 pub use concat::*;
 #[cfg(feature = "cov")]
 pub use correlation::*;
+#[cfg(feature = "hist")]
+pub use hist::*;
 pub use horizontal::*;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 pub use index::*;
 #[cfg(feature = "dtype-struct")]
 use polars_core::utils::get_supertype;
+#[cfg(feature = "random")]
+pub use random::*;
 #[cfg(all(feature = "range", feature = "temporal"))]
 pub use range::date_range; // This shouldn't be necessary, but clippy complains about dead code
 #[cfg(all(feature = "range", feature = "dtype-time"))]
@@ -0,0 +1,44 @@
+use super::*;
+
+fn distribution(params: Vec<Expr>, distribution: RandomDistribution, seed: Option<u64>) -> Expr {
+    let mut input = params;
+    input.push(len());
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Random {
+            method: RandomMethod::Distribution(distribution),
+            seed,
+        },
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            allow_rename: true,
+            ..Default::default()
+        },
+    }
+}
+
+/// Draw a value for every row from the uniform distribution between `low` (inclusive) and
+/// `high` (exclusive).
+///
+/// The value of a row only depends on the `seed` and the index of the row, so the values don't
+/// change with the way the rows are split over threads. Without a `seed`, a seed is drawn from
+/// the global random generator.
+pub fn rand_uniform(low: Expr, high: Expr, seed: Option<u64>) -> Expr {
+    distribution(vec![low, high], RandomDistribution::Uniform, seed)
+}
+
+/// Draw a value for every row from the normal distribution with `mean` and standard deviation
+/// `std`.
+///
+/// See [`rand_uniform`] for how the values are seeded.
+pub fn rand_normal(mean: Expr, std: Expr, seed: Option<u64>) -> Expr {
+    distribution(vec![mean, std], RandomDistribution::Normal, seed)
+}
+
+/// Draw a value for every row from the Poisson distribution with `lambda` expected events.
+///
+/// See [`rand_uniform`] for how the values are seeded.
+pub fn rand_poisson(lambda: Expr, seed: Option<u64>) -> Expr {
+    distribution(vec![lambda], RandomDistribution::Poisson, seed)
+}
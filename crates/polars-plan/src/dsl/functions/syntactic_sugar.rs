@@ -64,3 +64,20 @@ pub fn cast(expr: Expr, data_type: DataType) -> Expr {
         options: CastOptions::NonStrict,
     }
 }
+
+/// The value of `expr` `n` rows before every row, like SQL `LAG`.
+///
+/// The first `n` rows take the value of `default`, or are null without it. A `default` with a
+/// value for every row takes the value of the row itself. Within `over`, the rows are those of
+/// the partition, in the order of its `order_by`.
+pub fn lag(expr: Expr, n: i64, default: Option<Expr>) -> Expr {
+    match default {
+        Some(default) => expr.shift_and_fill(lit(n), default),
+        None => expr.shift(lit(n)),
+    }
+}
+
+/// The value of `expr` `n` rows after every row, like SQL `LEAD`. See [`lag`].
+pub fn lead(expr: Expr, n: i64, default: Option<Expr>) -> Expr {
+    lag(expr, -n, default)
+}
@@ -0,0 +1,17 @@
+use super::*;
+
+/// Specialized expressions for Json dtypes.
+pub struct JsonNameSpace(pub(crate) Expr);
+
+impl JsonNameSpace {
+    /// Extract the values at a JSON path like `$.a.b[0]`, values without that path become null.
+    pub fn path(self, path: &str) -> Expr {
+        self.0
+            .map_private(JsonFunction::Path(path.to_string()).into())
+    }
+
+    /// Get the JSON type of the values as a string, e.g. `"object"` or `"integer"`.
+    pub fn get_type(self) -> Expr {
+        self.0.map_private(JsonFunction::GetType.into())
+    }
+}
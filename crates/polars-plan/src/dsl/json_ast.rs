@@ -0,0 +1,561 @@
+//! A stable JSON representation of expressions, for tools that inspect queries before they
+//! are executed.
+//!
+//! Unlike the serde representation of [`Expr`], which follows the internal layout of the
+//! enum, this representation is documented and versioned, and only changes with
+//! [`EXPR_AST_VERSION`].
+//!
+//! # Schema
+//!
+//! A document is an object `{"version": 1, "expr": <node>}`. Every node is an object with a
+//! `"kind"`, the other fields depend on the kind:
+//!
+//! | kind       | fields                                                              |
+//! |------------|---------------------------------------------------------------------|
+//! | `column`   | `name`                                                              |
+//! | `columns`  | `names`                                                             |
+//! | `wildcard` |                                                                     |
+//! | `nth`      | `index`                                                             |
+//! | `len`      |                                                                     |
+//! | `literal`  | `dtype`, `value`                                                    |
+//! | `alias`    | `name`, `input`                                                     |
+//! | `binary`   | `op`, `left`, `right`                                               |
+//! | `cast`     | `dtype`, `options`, `input`                                         |
+//! | `agg`      | `name`, `input`, and `include_nulls` or `ddof`                      |
+//! | `ternary`  | `predicate`, `truthy`, `falsy`                                      |
+//! | `filter`   | `input`, `by`                                                       |
+//! | `function` | `name`, `inputs`, `payload`                                         |
+//! | `opaque`   | `display`, `columns`, `payload`                                     |
+//!
+//! - A `literal` has one of the dtypes `null`, `boolean`, `string`, `int8` up to `int64`,
+//!   `uint8` up to `uint64`, `float32`, `float64`, or `dyn_int` and `dyn_float` for literals
+//!   whose type is inferred from the context. Floats that are not finite are written as the
+//!   strings `"NaN"`, `"inf"` and `"-inf"`.
+//! - The `op` of a `binary` node is one of `eq`, `eq_validity`, `neq`, `neq_validity`, `lt`,
+//!   `lt_eq`, `gt`, `gt_eq`, `add`, `sub`, `mul`, `div`, `true_div`, `floor_div`, `mod`,
+//!   `and`, `or`, `xor`, `logical_and` and `logical_or`.
+//! - The `dtype` of a `cast` is the serde JSON representation of the [`DataType`] and its
+//!   `options` are `strict`, `non_strict` or `overflowing`.
+//! - The `name` of an `agg` is one of `min`, `max`, `nan_min`, `nan_max`, `median`,
+//!   `n_unique`, `first`, `last`, `mean`, `implode`, `count`, `sum`, `agg_groups`, `std` and
+//!   `var`. A `count` has `include_nulls` and a `std` or `var` has `ddof`.
+//! - The `name` of a `function` is its display name, e.g. `abs`, and `inputs` are its
+//!   arguments.
+//! - Every other expression is an `opaque` node, with `display` the formatted expression
+//!   and `columns` the names of the columns it reads. Tools that don't know an expression
+//!   should treat it as if it could do anything with these columns.
+//!
+//! The `payload` of `function` and `opaque` nodes holds the internal serde representation of
+//! the expression, tagged with the [`PLAN_FORMAT_VERSION`] that wrote it. It is only needed
+//! to read the node back and should not be inspected.
+use polars_core::error::to_compute_err;
+use serde_json::{json, Map, Value};
+
+use super::*;
+use crate::plans::{PlanFormatVersion, PLAN_FORMAT_VERSION};
+use crate::utils::expr_to_leaf_column_names;
+
+/// The version of the JSON representation of expressions written by this version of Polars.
+pub const EXPR_AST_VERSION: u64 = 1;
+
+impl Expr {
+    /// Write the expression in the stable JSON representation, see [`EXPR_AST_VERSION`].
+    ///
+    /// Fails if the expression holds a function that can't be serialized, e.g. a closure.
+    pub fn to_json_ast(&self) -> PolarsResult<String> {
+        let doc = json!({
+            "version": EXPR_AST_VERSION,
+            "expr": to_node(self)?,
+        });
+        serde_json::to_string(&doc).map_err(to_compute_err)
+    }
+
+    /// Read an expression written by [`Expr::to_json_ast`].
+    pub fn from_json_ast(json: &str) -> PolarsResult<Expr> {
+        let doc: Value = serde_json::from_str(json).map_err(to_compute_err)?;
+        let version = get(&doc, "version")?.as_u64();
+        polars_ensure!(
+            version == Some(EXPR_AST_VERSION),
+            ComputeError: "cannot read expression AST of version {}; this version of Polars \
+            reads version {}",
+            get(&doc, "version")?, EXPR_AST_VERSION
+        );
+        from_node(get(&doc, "expr")?)
+    }
+}
+
+fn to_node(expr: &Expr) -> PolarsResult<Value> {
+    let node = match expr {
+        Expr::Column(name) => json!({"kind": "column", "name": name.as_ref()}),
+        Expr::Columns(names) => {
+            let names = names.iter().map(|n| n.as_ref()).collect::<Vec<_>>();
+            json!({"kind": "columns", "names": names})
+        },
+        Expr::Wildcard => json!({"kind": "wildcard"}),
+        Expr::Nth(index) => json!({"kind": "nth", "index": index}),
+        Expr::Len => json!({"kind": "len"}),
+        Expr::Literal(lv) => match literal_to_node(lv) {
+            Some(node) => node,
+            None => opaque_to_node(expr)?,
+        },
+        Expr::Alias(input, name) => {
+            json!({"kind": "alias", "name": name.as_ref(), "input": to_node(input)?})
+        },
+        Expr::BinaryExpr { left, op, right } => json!({
+            "kind": "binary",
+            "op": operator_name(*op),
+            "left": to_node(left)?,
+            "right": to_node(right)?,
+        }),
+        Expr::Cast {
+            expr: input,
+            data_type,
+            options,
+        } => {
+            let options = match options {
+                CastOptions::Strict => "strict",
+                CastOptions::NonStrict => "non_strict",
+                CastOptions::Overflowing => "overflowing",
+            };
+            json!({
+                "kind": "cast",
+                "dtype": serde_json::to_value(data_type).map_err(to_compute_err)?,
+                "options": options,
+                "input": to_node(input)?,
+            })
+        },
+        Expr::Agg(agg) => match agg_to_node(agg)? {
+            Some(node) => node,
+            None => opaque_to_node(expr)?,
+        },
+        Expr::Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => json!({
+            "kind": "ternary",
+            "predicate": to_node(predicate)?,
+            "truthy": to_node(truthy)?,
+            "falsy": to_node(falsy)?,
+        }),
+        Expr::Filter { input, by } => json!({
+            "kind": "filter",
+            "input": to_node(input)?,
+            "by": to_node(by)?,
+        }),
+        Expr::Function {
+            input,
+            function,
+            options,
+        } => json!({
+            "kind": "function",
+            "name": function.to_string(),
+            "inputs": input.iter().map(to_node).collect::<PolarsResult<Vec<_>>>()?,
+            "payload": payload(json!({
+                "function": serde_json::to_value(function).map_err(to_compute_err)?,
+                "options": serde_json::to_value(options).map_err(to_compute_err)?,
+            })),
+        }),
+        _ => opaque_to_node(expr)?,
+    };
+    Ok(node)
+}
+
+fn opaque_to_node(expr: &Expr) -> PolarsResult<Value> {
+    let columns = expr_to_leaf_column_names(expr);
+    let columns = columns.iter().map(|n| n.as_ref()).collect::<Vec<_>>();
+    Ok(json!({
+        "kind": "opaque",
+        "display": format!("{expr:?}"),
+        "columns": columns,
+        "payload": payload(serde_json::to_value(expr).map_err(to_compute_err)?),
+    }))
+}
+
+fn payload(value: Value) -> Value {
+    json!({
+        "plan_format": PLAN_FORMAT_VERSION,
+        "value": value,
+    })
+}
+
+fn float_to_value(v: f64) -> Value {
+    if v.is_nan() {
+        json!("NaN")
+    } else if v.is_infinite() {
+        json!(if v > 0.0 { "inf" } else { "-inf" })
+    } else {
+        json!(v)
+    }
+}
+
+/// Only scalars of the basic types get a `literal` node, the others are opaque.
+fn literal_to_node(lv: &LiteralValue) -> Option<Value> {
+    use LiteralValue as L;
+    let (dtype, value) = match lv {
+        L::Null => ("null", Value::Null),
+        L::Boolean(v) => ("boolean", json!(v)),
+        L::String(v) => ("string", json!(v)),
+        #[cfg(feature = "dtype-i8")]
+        L::Int8(v) => ("int8", json!(v)),
+        #[cfg(feature = "dtype-i16")]
+        L::Int16(v) => ("int16", json!(v)),
+        L::Int32(v) => ("int32", json!(v)),
+        L::Int64(v) => ("int64", json!(v)),
+        #[cfg(feature = "dtype-u8")]
+        L::UInt8(v) => ("uint8", json!(v)),
+        #[cfg(feature = "dtype-u16")]
+        L::UInt16(v) => ("uint16", json!(v)),
+        L::UInt32(v) => ("uint32", json!(v)),
+        L::UInt64(v) => ("uint64", json!(v)),
+        L::Float32(v) => ("float32", float_to_value(*v as f64)),
+        L::Float64(v) => ("float64", float_to_value(*v)),
+        L::Int(v) => ("dyn_int", json!(i64::try_from(*v).ok()?)),
+        L::Float(v) => ("dyn_float", float_to_value(*v)),
+        _ => return None,
+    };
+    Some(json!({"kind": "literal", "dtype": dtype, "value": value}))
+}
+
+fn agg_to_node(agg: &AggExpr) -> PolarsResult<Option<Value>> {
+    use AggExpr as A;
+    let mut extra = Map::new();
+    let (name, input) = match agg {
+        A::Min {
+            input,
+            propagate_nans,
+        } => (if *propagate_nans { "nan_min" } else { "min" }, input),
+        A::Max {
+            input,
+            propagate_nans,
+        } => (if *propagate_nans { "nan_max" } else { "max" }, input),
+        A::Median(input) => ("median", input),
+        A::NUnique(input) => ("n_unique", input),
+        A::First(input) => ("first", input),
+        A::Last(input) => ("last", input),
+        A::Mean(input) => ("mean", input),
+        A::Implode(input) => ("implode", input),
+        A::Count(input, include_nulls) => {
+            extra.insert("include_nulls".into(), json!(include_nulls));
+            ("count", input)
+        },
+        A::Sum(input) => ("sum", input),
+        A::AggGroups(input) => ("agg_groups", input),
+        A::Std(input, ddof) => {
+            extra.insert("ddof".into(), json!(ddof));
+            ("std", input)
+        },
+        A::Var(input, ddof) => {
+            extra.insert("ddof".into(), json!(ddof));
+            ("var", input)
+        },
+        _ => return Ok(None),
+    };
+    let mut node = Map::new();
+    node.insert("kind".into(), json!("agg"));
+    node.insert("name".into(), json!(name));
+    node.insert("input".into(), to_node(input)?);
+    node.extend(extra);
+    Ok(Some(Value::Object(node)))
+}
+
+fn operator_name(op: Operator) -> &'static str {
+    use Operator::*;
+    match op {
+        Eq => "eq",
+        EqValidity => "eq_validity",
+        NotEq => "neq",
+        NotEqValidity => "neq_validity",
+        Lt => "lt",
+        LtEq => "lt_eq",
+        Gt => "gt",
+        GtEq => "gt_eq",
+        Plus => "add",
+        Minus => "sub",
+        Multiply => "mul",
+        Divide => "div",
+        TrueDivide => "true_div",
+        FloorDivide => "floor_div",
+        Modulus => "mod",
+        And => "and",
+        Or => "or",
+        Xor => "xor",
+        LogicalAnd => "logical_and",
+        LogicalOr => "logical_or",
+    }
+}
+
+fn operator_from_name(name: &str) -> PolarsResult<Operator> {
+    use Operator::*;
+    let op = match name {
+        "eq" => Eq,
+        "eq_validity" => EqValidity,
+        "neq" => NotEq,
+        "neq_validity" => NotEqValidity,
+        "lt" => Lt,
+        "lt_eq" => LtEq,
+        "gt" => Gt,
+        "gt_eq" => GtEq,
+        "add" => Plus,
+        "sub" => Minus,
+        "mul" => Multiply,
+        "div" => Divide,
+        "true_div" => TrueDivide,
+        "floor_div" => FloorDivide,
+        "mod" => Modulus,
+        "and" => And,
+        "or" => Or,
+        "xor" => Xor,
+        "logical_and" => LogicalAnd,
+        "logical_or" => LogicalOr,
+        _ => polars_bail!(ComputeError: "unknown binary operator '{}' in expression AST", name),
+    };
+    Ok(op)
+}
+
+fn get<'a>(node: &'a Value, key: &str) -> PolarsResult<&'a Value> {
+    node.get(key)
+        .ok_or_else(|| polars_err!(ComputeError: "expression AST node is missing '{}'", key))
+}
+
+fn get_str<'a>(node: &'a Value, key: &str) -> PolarsResult<&'a str> {
+    get(node, key)?.as_str().ok_or_else(
+        || polars_err!(ComputeError: "'{}' of an expression AST node must be a string", key),
+    )
+}
+
+fn get_node(node: &Value, key: &str) -> PolarsResult<Arc<Expr>> {
+    from_node(get(node, key)?).map(Arc::new)
+}
+
+fn invalid(key: &str, value: &Value) -> PolarsError {
+    polars_err!(ComputeError: "invalid '{}' in expression AST node: {}", key, value)
+}
+
+/// Deserialize the internal representation in a `payload`, which must be written by a
+/// version of Polars whose plans this version can read.
+fn from_payload<T: serde::de::DeserializeOwned>(node: &Value) -> PolarsResult<T> {
+    let payload = get(node, "payload")?;
+    let version: PlanFormatVersion =
+        serde_json::from_value(get(payload, "plan_format")?.clone()).map_err(to_compute_err)?;
+    polars_ensure!(
+        version.is_supported(),
+        ComputeError: "cannot read expression AST node written with plan format version {}; \
+        this version of Polars reads format versions {}.0 up to {}",
+        version, PLAN_FORMAT_VERSION.major, PLAN_FORMAT_VERSION
+    );
+    serde_json::from_value(get(payload, "value")?.clone()).map_err(to_compute_err)
+}
+
+fn from_node(node: &Value) -> PolarsResult<Expr> {
+    let expr = match get_str(node, "kind")? {
+        "column" => Expr::Column(get_str(node, "name")?.into()),
+        "columns" => {
+            let names = get(node, "names")?;
+            let names = names
+                .as_array()
+                .and_then(|names| {
+                    names
+                        .iter()
+                        .map(|n| n.as_str().map(ColumnName::from))
+                        .collect::<Option<Arc<[_]>>>()
+                })
+                .ok_or_else(|| invalid("names", names))?;
+            Expr::Columns(names)
+        },
+        "wildcard" => Expr::Wildcard,
+        "nth" => {
+            let index = get(node, "index")?;
+            Expr::Nth(index.as_i64().ok_or_else(|| invalid("index", index))?)
+        },
+        "len" => Expr::Len,
+        "literal" => Expr::Literal(literal_from_node(node)?),
+        "alias" => Expr::Alias(get_node(node, "input")?, get_str(node, "name")?.into()),
+        "binary" => Expr::BinaryExpr {
+            left: get_node(node, "left")?,
+            op: operator_from_name(get_str(node, "op")?)?,
+            right: get_node(node, "right")?,
+        },
+        "cast" => {
+            let options = match get_str(node, "options")? {
+                "strict" => CastOptions::Strict,
+                "non_strict" => CastOptions::NonStrict,
+                "overflowing" => CastOptions::Overflowing,
+                _ => return Err(invalid("options", get(node, "options")?)),
+            };
+            Expr::Cast {
+                expr: get_node(node, "input")?,
+                data_type: serde_json::from_value(get(node, "dtype")?.clone())
+                    .map_err(to_compute_err)?,
+                options,
+            }
+        },
+        "agg" => Expr::Agg(agg_from_node(node)?),
+        "ternary" => Expr::Ternary {
+            predicate: get_node(node, "predicate")?,
+            truthy: get_node(node, "truthy")?,
+            falsy: get_node(node, "falsy")?,
+        },
+        "filter" => Expr::Filter {
+            input: get_node(node, "input")?,
+            by: get_node(node, "by")?,
+        },
+        "function" => {
+            #[derive(serde::Deserialize)]
+            struct Function {
+                function: FunctionExpr,
+                options: FunctionOptions,
+            }
+            let Function { function, options } = from_payload(node)?;
+            let inputs = get(node, "inputs")?;
+            let input = inputs
+                .as_array()
+                .ok_or_else(|| invalid("inputs", inputs))?
+                .iter()
+                .map(from_node)
+                .collect::<PolarsResult<Vec<_>>>()?;
+            Expr::Function {
+                input,
+                function,
+                options,
+            }
+        },
+        "opaque" => from_payload(node)?,
+        kind => polars_bail!(ComputeError: "unknown expression AST node kind '{}'", kind),
+    };
+    Ok(expr)
+}
+
+fn float_from_value(value: &Value) -> Option<f64> {
+    match value {
+        Value::String(s) => match s.as_str() {
+            "NaN" => Some(f64::NAN),
+            "inf" => Some(f64::INFINITY),
+            "-inf" => Some(f64::NEG_INFINITY),
+            _ => None,
+        },
+        _ => value.as_f64(),
+    }
+}
+
+fn literal_from_node(node: &Value) -> PolarsResult<LiteralValue> {
+    use LiteralValue as L;
+    let value = get(node, "value")?;
+    let int = || value.as_i64();
+    let uint = || value.as_u64();
+    let lv = match get_str(node, "dtype")? {
+        "null" => Some(L::Null),
+        "boolean" => value.as_bool().map(L::Boolean),
+        "string" => value.as_str().map(|v| L::String(v.to_string())),
+        #[cfg(feature = "dtype-i8")]
+        "int8" => int().and_then(|v| v.try_into().ok()).map(L::Int8),
+        #[cfg(feature = "dtype-i16")]
+        "int16" => int().and_then(|v| v.try_into().ok()).map(L::Int16),
+        "int32" => int().and_then(|v| v.try_into().ok()).map(L::Int32),
+        "int64" => int().map(L::Int64),
+        #[cfg(feature = "dtype-u8")]
+        "uint8" => uint().and_then(|v| v.try_into().ok()).map(L::UInt8),
+        #[cfg(feature = "dtype-u16")]
+        "uint16" => uint().and_then(|v| v.try_into().ok()).map(L::UInt16),
+        "uint32" => uint().and_then(|v| v.try_into().ok()).map(L::UInt32),
+        "uint64" => uint().map(L::UInt64),
+        "float32" => float_from_value(value).map(|v| L::Float32(v as f32)),
+        "float64" => float_from_value(value).map(L::Float64),
+        "dyn_int" => int().map(|v| L::Int(v as i128)),
+        "dyn_float" => float_from_value(value).map(L::Float),
+        dtype => polars_bail!(ComputeError: "unknown literal dtype '{}' in expression AST", dtype),
+    };
+    lv.ok_or_else(|| invalid("value", value))
+}
+
+fn agg_from_node(node: &Value) -> PolarsResult<AggExpr> {
+    use AggExpr as A;
+    let input = get_node(node, "input")?;
+    let ddof = || -> PolarsResult<u8> {
+        let ddof = get(node, "ddof")?;
+        ddof.as_u64()
+            .and_then(|v| v.try_into().ok())
+            .ok_or_else(|| invalid("ddof", ddof))
+    };
+    let agg = match get_str(node, "name")? {
+        "min" | "nan_min" => A::Min {
+            input,
+            propagate_nans: get_str(node, "name")? == "nan_min",
+        },
+        "max" | "nan_max" => A::Max {
+            input,
+            propagate_nans: get_str(node, "name")? == "nan_max",
+        },
+        "median" => A::Median(input),
+        "n_unique" => A::NUnique(input),
+        "first" => A::First(input),
+        "last" => A::Last(input),
+        "mean" => A::Mean(input),
+        "implode" => A::Implode(input),
+        "count" => {
+            let include_nulls = get(node, "include_nulls")?;
+            let include_nulls = include_nulls
+                .as_bool()
+                .ok_or_else(|| invalid("include_nulls", include_nulls))?;
+            A::Count(input, include_nulls)
+        },
+        "sum" => A::Sum(input),
+        "agg_groups" => A::AggGroups(input),
+        "std" => A::Std(input, ddof()?),
+        "var" => A::Var(input, ddof()?),
+        name => polars_bail!(ComputeError: "unknown aggregation '{}' in expression AST", name),
+    };
+    Ok(agg)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn roundtrip(expr: Expr) -> PolarsResult<Value> {
+        let json = expr.to_json_ast()?;
+        assert_eq!(Expr::from_json_ast(&json)?, expr);
+        Ok(serde_json::from_str(&json).unwrap())
+    }
+
+    #[test]
+    fn test_json_ast_roundtrip() -> PolarsResult<()> {
+        let expr = when(col("a").gt(lit(1)))
+            .then(col("b").sum().alias("s"))
+            .otherwise(lit(f64::INFINITY))
+            .cast(DataType::Float32);
+        let doc = roundtrip(expr)?;
+        assert_eq!(doc["version"], json!(1));
+        assert_eq!(doc["expr"]["kind"], json!("cast"));
+        let ternary = &doc["expr"]["input"];
+        assert_eq!(
+            ternary["predicate"],
+            json!({
+                "kind": "binary",
+                "op": "gt",
+                "left": {"kind": "column", "name": "a"},
+                "right": {"kind": "literal", "dtype": "dyn_int", "value": 1},
+            })
+        );
+        assert_eq!(ternary["truthy"]["input"]["name"], json!("sum"));
+        assert_eq!(ternary["falsy"]["value"], json!("inf"));
+
+        let doc = roundtrip(col("a").is_null().std(1))?;
+        assert_eq!(doc["expr"]["ddof"], json!(1));
+        assert_eq!(doc["expr"]["input"]["kind"], json!("function"));
+        assert_eq!(doc["expr"]["input"]["name"], json!("is_null"));
+
+        let doc = roundtrip(col("a").sort(Default::default()).head(Some(2)))?;
+        assert_eq!(doc["expr"]["kind"], json!("opaque"));
+        assert_eq!(doc["expr"]["columns"], json!(["a"]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_ast_invalid() {
+        let json = r#"{"version": 2, "expr": {"kind": "len"}}"#;
+        assert!(Expr::from_json_ast(json).is_err());
+        let json = r#"{"version": 1, "expr": {"kind": "binary", "op": "pow"}}"#;
+        assert!(Expr::from_json_ast(json).is_err());
+    }
+}
@@ -268,6 +268,30 @@ impl ListNameSpace {
             .map_private(FunctionExpr::ListExpr(ListFunction::ToArray(width)))
     }
 
+    #[cfg(feature = "dtype-array")]
+    /// Sum the lists element-wise over all rows, or over the rows of every group in a
+    /// `group_by` context. All lists must have the same length.
+    pub fn sum_elementwise(self) -> Expr {
+        self.0
+            .apply_private(FunctionExpr::ListExpr(ListFunction::SumElementwise))
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
+    #[cfg(feature = "dtype-array")]
+    /// Average the lists element-wise over all rows, or over the rows of every group in a
+    /// `group_by` context. All lists must have the same length.
+    pub fn mean_elementwise(self) -> Expr {
+        self.0
+            .apply_private(FunctionExpr::ListExpr(ListFunction::MeanElementwise))
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
     #[cfg(feature = "list_to_struct")]
     #[allow(clippy::wrong_self_convention)]
     /// Convert this `List` to a `Series` of type `Struct`. The width will be determined according to
@@ -23,6 +23,10 @@ mod expr_dyn_fn;
 mod from;
 pub mod function_expr;
 pub mod functions;
+#[cfg(feature = "dtype-json")]
+pub mod json;
+#[cfg(feature = "serde")]
+mod json_ast;
 mod list;
 #[cfg(feature = "meta")]
 mod meta;
@@ -32,13 +36,20 @@ mod options;
 pub mod python_udf;
 #[cfg(feature = "random")]
 mod random;
+#[cfg(feature = "dtype-struct")]
+mod scale;
 mod selector;
 mod statistics;
+#[cfg(feature = "hypothesis_tests")]
+mod stats;
 #[cfg(feature = "strings")]
 pub mod string;
 #[cfg(feature = "dtype-struct")]
 mod struct_;
+#[cfg(feature = "downsample")]
+mod ts;
 pub mod udf;
+mod udf_registry;
 
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -51,6 +62,8 @@ pub use expr::*;
 pub use function_expr::schema::FieldsMapper;
 pub use function_expr::*;
 pub use functions::*;
+#[cfg(feature = "serde")]
+pub use json_ast::EXPR_AST_VERSION;
 pub use list::*;
 #[cfg(feature = "meta")]
 pub use meta::*;
@@ -63,12 +76,19 @@ use polars_core::prelude::*;
 use polars_core::series::ops::NullBehavior;
 use polars_core::series::IsSorted;
 use polars_core::utils::try_get_supertype;
+#[cfg(feature = "dtype-struct")]
+pub use scale::*;
 pub(crate) use selector::Selector;
+#[cfg(feature = "hypothesis_tests")]
+pub use stats::*;
 #[cfg(feature = "dtype-struct")]
 pub use struct_::*;
+#[cfg(feature = "downsample")]
+pub use ts::*;
 pub use udf::UserDefinedFunction;
+pub use udf_registry::{register_udf, registered_udf, unregister_udf};
 
-use crate::constants::MAP_LIST_NAME;
+use crate::constants::{MAP_LIST_NAME, ROW_INDICES};
 pub use crate::plans::lit;
 use crate::prelude::*;
 
@@ -209,6 +229,30 @@ impl Expr {
         AggExpr::Last(Arc::new(self)).into()
     }
 
+    /// Get the value in the row where `by` is smallest.
+    ///
+    /// This gives the same value as [`Expr::first`] after a stable sort by `by`, without sorting
+    /// the frame. Rows where `by` is null are skipped.
+    pub fn first_by(self, by: Expr) -> Self {
+        AggExpr::FirstBy {
+            expr: Arc::new(self),
+            by: Arc::new(by),
+        }
+        .into()
+    }
+
+    /// Get the value in the row where `by` is largest.
+    ///
+    /// On ties the last of these rows is taken, which gives the same value as [`Expr::last`]
+    /// after a stable sort by `by`. Rows where `by` is null are skipped.
+    pub fn last_by(self, by: Expr) -> Self {
+        AggExpr::LastBy {
+            expr: Arc::new(self),
+            by: Arc::new(by),
+        }
+        .into()
+    }
+
     /// GroupBy the group to a Series.
     pub fn implode(self) -> Self {
         AggExpr::Implode(Arc::new(self)).into()
@@ -224,6 +268,35 @@ impl Expr {
         .into()
     }
 
+    /// Compute several quantiles per group, as an array with a value per quantile.
+    ///
+    /// The values of a group are selected once for all quantiles, whereas a [`Expr::quantile`]
+    /// per quantile selects them again for each.
+    pub fn quantiles(self, quantiles: &[f64], interpol: QuantileInterpolOptions) -> Self {
+        AggExpr::Quantiles {
+            expr: Arc::new(self),
+            options: QuantilesOptions {
+                quantiles: quantiles.into(),
+                interpol,
+            },
+        }
+        .into()
+    }
+
+    /// Approximate the quantile per group with a t-digest sketch.
+    ///
+    /// The `accuracy` is the compression of the sketch: higher values give more accurate
+    /// quantiles at the cost of memory. Unlike [`Expr::quantile`], this doesn't need to
+    /// materialize and sort the values, and it can be computed by the streaming engine.
+    pub fn approx_quantile(self, quantile: Expr, accuracy: u32) -> Self {
+        AggExpr::ApproxQuantile {
+            expr: Arc::new(self),
+            quantile: Arc::new(quantile),
+            accuracy,
+        }
+        .into()
+    }
+
     /// Get the group indexes of the group by operation.
     pub fn agg_groups(self) -> Self {
         AggExpr::AggGroups(Arc::new(self)).into()
@@ -834,9 +907,19 @@ impl Expr {
     }
 
     /// Round underlying floating point array to given decimal numbers.
+    ///
+    /// `mode` decides how values halfway between two rounded values are rounded.
+    #[cfg(feature = "round_series")]
+    pub fn round(self, decimals: u32, mode: RoundMode) -> Self {
+        self.map_private(FunctionExpr::Round { decimals, mode })
+    }
+
+    /// Change the scale of a decimal to `scale`, rounding dropped fractional digits with `mode`.
+    ///
+    /// Integers are converted to decimals first.
     #[cfg(feature = "round_series")]
-    pub fn round(self, decimals: u32) -> Self {
-        self.map_private(FunctionExpr::Round { decimals })
+    pub fn rescale(self, scale: usize, mode: RoundMode) -> Self {
+        self.map_private(FunctionExpr::Rescale { scale, mode })
     }
 
     /// Round to a number of significant figures.
@@ -970,6 +1053,42 @@ impl Expr {
         partition_by: E,
         order_by: Option<(E, SortOptions)>,
         options: WindowMapping,
+    ) -> Self {
+        self.window_impl(partition_by, order_by, options.into())
+    }
+
+    /// Compute an aggregation over a frame of rows around every row of its partition, like a
+    /// SQL window function with a `ROWS BETWEEN` or `RANGE BETWEEN` clause.
+    ///
+    /// The rows of every partition are ordered by `order_by`, or kept in their order if it is
+    /// `None`. A `Range` frame needs `order_by`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::SortOptions;
+    /// # use polars_plan::prelude::*;
+    /// // The sum of the value and the two preceding values, per group.
+    /// let e = col("value").sum().over_frame(
+    ///     [col("group")],
+    ///     Some(([col("time")], SortOptions::default())),
+    ///     WindowFrame::rows_between(Some(-2), Some(0)),
+    /// );
+    /// ```
+    pub fn over_frame<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        partition_by: E,
+        order_by: Option<(E, SortOptions)>,
+        frame: WindowFrame,
+    ) -> Self {
+        self.window_impl(partition_by, order_by, frame.into())
+    }
+
+    fn window_impl<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        partition_by: E,
+        order_by: Option<(E, SortOptions)>,
+        options: WindowType,
     ) -> Self {
         let partition_by = partition_by
             .as_ref()
@@ -994,7 +1113,7 @@ impl Expr {
             function: Arc::new(self),
             partition_by,
             order_by,
-            options: options.into(),
+            options,
         }
     }
 
@@ -1034,6 +1153,29 @@ impl Expr {
         self.apply_private(FunctionExpr::FillNullWithStrategy(strategy))
     }
 
+    /// Fill the null values forward or backward, but only from values that are at most
+    /// `max_gap` away in the `by` column.
+    ///
+    /// `max_gap` is a duration such as `"1h"` for a temporal `by` column, or an integer
+    /// duration such as `"3i"` for an integer `by` column.
+    #[cfg(feature = "fill_null_by")]
+    pub fn fill_null_by(
+        self,
+        by: Expr,
+        strategy: FillNullStrategy,
+        max_gap: polars_time::Duration,
+    ) -> Self {
+        self.apply_many_private(
+            FunctionExpr::FillNullBy {
+                strategy,
+                max_gap: Some(max_gap),
+            },
+            &[by],
+            false,
+            false,
+        )
+    }
+
     /// Replace the floating point `NaN` values by a value.
     pub fn fill_nan<E: Into<Expr>>(self, fill_value: E) -> Self {
         // we take the not branch so that self is truthy value of `when -> then -> otherwise`
@@ -1061,6 +1203,18 @@ impl Expr {
         self.apply_private(BooleanFunction::IsDuplicated.into())
     }
 
+    /// Get a mask of duplicated values, where `keep` decides which occurrence of a value is not
+    /// marked: the first, the last or none of them.
+    #[allow(clippy::wrong_self_convention)]
+    #[cfg(feature = "is_unique")]
+    pub fn is_duplicated_keep(self, keep: UniqueKeepStrategy) -> Self {
+        let function = match keep {
+            UniqueKeepStrategy::None => BooleanFunction::IsDuplicated,
+            keep => BooleanFunction::IsDuplicatedKeep { keep },
+        };
+        self.apply_private(function.into())
+    }
+
     #[allow(clippy::wrong_self_convention)]
     #[cfg(feature = "is_between")]
     pub fn is_between<E: Into<Expr>>(self, lower: E, upper: E, closed: ClosedInterval) -> Self {
@@ -1089,6 +1243,57 @@ impl Expr {
             })
     }
 
+    /// Sketch the unique values into a single binary value that can be stored and merged with
+    /// [`Expr::merge_sketches`]. See [`Expr::approx_n_unique_from_sketch`] for the estimate.
+    #[cfg(feature = "approx_unique")]
+    pub fn approx_n_unique_sketch(self) -> Self {
+        self.apply_private(FunctionExpr::Sketch(SketchFunction::ApproxNUniqueSketch))
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
+    /// Sketch the distribution of the values into a single binary value that can be stored and
+    /// merged with [`Expr::merge_sketches`]. See [`Expr::approx_quantile_from_sketch`] for the
+    /// quantiles.
+    #[cfg(all(feature = "approx_unique", feature = "approx_quantile"))]
+    pub fn approx_quantile_sketch(self, accuracy: u32) -> Self {
+        self.apply_private(FunctionExpr::Sketch(SketchFunction::ApproxQuantileSketch {
+            accuracy,
+        }))
+        .with_function_options(|mut options| {
+            options.returns_scalar = true;
+            options
+        })
+    }
+
+    /// Merge binary sketches of the same kind into one. Null sketches are ignored.
+    #[cfg(feature = "approx_unique")]
+    pub fn merge_sketches(self) -> Self {
+        self.apply_private(FunctionExpr::Sketch(SketchFunction::Merge))
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
+    /// Estimate the number of unique values of every sketch of [`Expr::approx_n_unique_sketch`].
+    #[cfg(feature = "approx_unique")]
+    pub fn approx_n_unique_from_sketch(self) -> Self {
+        self.map_private(FunctionExpr::Sketch(
+            SketchFunction::ApproxNUniqueFromSketch,
+        ))
+    }
+
+    /// Estimate the `quantile` of every sketch of [`Expr::approx_quantile_sketch`].
+    #[cfg(all(feature = "approx_unique", feature = "approx_quantile"))]
+    pub fn approx_quantile_from_sketch(self, quantile: f64) -> Self {
+        self.map_private(FunctionExpr::Sketch(
+            SketchFunction::ApproxQuantileFromSketch { quantile },
+        ))
+    }
+
     /// "and" operation.
     pub fn and<E: Into<Expr>>(self, expr: E) -> Self {
         binary_expr(self, Operator::And, expr.into())
@@ -1840,6 +2045,66 @@ impl Expr {
         self.map_private(FunctionExpr::SetSortedFlag(sorted))
     }
 
+    /// Hint whether this expression may be evaluated in parallel.
+    ///
+    /// `false` guarantees that the expression never runs concurrently with other
+    /// expressions or with itself, e.g. for plugins that are not thread-safe. `true`
+    /// allows parallelism within the expression, even in a sequential context such as
+    /// `select_seq`. The data is passed through unchanged.
+    pub fn parallel(self, parallel: bool) -> Expr {
+        self.map_private(FunctionExpr::Parallel(parallel))
+    }
+
+    /// Evaluate `fallback` instead of this expression on every batch on which evaluating this
+    /// expression fails, e.g. on an overflow. In an aggregation, all groups of a batch fall back
+    /// together. Both are cast to their supertype.
+    ///
+    /// Only errors when the data is processed are caught, not those when the query is planned,
+    /// such as a missing column.
+    pub fn or_else<E: Into<Expr>>(self, fallback: E) -> Expr {
+        self.map_many_private(FunctionExpr::OrElse, &[fallback.into()], false, true)
+    }
+
+    /// Flag this `Series` as sorted like [`Expr::set_sorted_flag`], but verify that it is
+    /// sorted first and raise an error if it is not.
+    pub fn check_sorted(self, descending: bool) -> Expr {
+        self.map_private(FunctionExpr::CheckSorted { descending })
+    }
+
+    /// Returns whether the non-null values never decrease. If `strict`, they must also
+    /// be unique. Uses the sorted flag to skip the check where possible.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_monotonic_increasing(self, strict: bool) -> Expr {
+        self.apply_private(
+            BooleanFunction::IsMonotonic {
+                descending: false,
+                strict,
+            }
+            .into(),
+        )
+        .with_function_options(|mut opt| {
+            opt.returns_scalar = true;
+            opt
+        })
+    }
+
+    /// Returns whether the non-null values never increase. If `strict`, they must also
+    /// be unique. Uses the sorted flag to skip the check where possible.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_monotonic_decreasing(self, strict: bool) -> Expr {
+        self.apply_private(
+            BooleanFunction::IsMonotonic {
+                descending: true,
+                strict,
+            }
+            .into(),
+        )
+        .with_function_options(|mut opt| {
+            opt.returns_scalar = true;
+            opt
+        })
+    }
+
     #[cfg(feature = "row_hash")]
     /// Compute the hash of every element.
     pub fn hash(self, k0: u64, k1: u64, k2: u64, k3: u64) -> Expr {
@@ -1850,6 +2115,26 @@ impl Expr {
         self.map_private(FunctionExpr::ToPhysical)
     }
 
+    /// Get the validity of the values as a boolean mask, without copying the validity buffers.
+    pub fn is_valid_mask(self) -> Expr {
+        self.map_private(FunctionExpr::IsValidMask)
+    }
+
+    /// Replace the validity of the values by a boolean `mask`, keeping the physical values.
+    pub fn with_validity<E: Into<Expr>>(self, mask: E) -> Expr {
+        self.map_many_private(FunctionExpr::WithValidity, &[mask.into()], false, false)
+    }
+
+    /// Set the values to null where `other` is null.
+    pub fn coalesce_validity<E: Into<Expr>>(self, other: E) -> Expr {
+        self.map_many_private(
+            FunctionExpr::CoalesceValidity,
+            &[other.into()],
+            false,
+            false,
+        )
+    }
+
     pub fn gather_every(self, n: usize, offset: usize) -> Expr {
         self.apply_private(FunctionExpr::GatherEvery { n, offset })
     }
@@ -1902,17 +2187,41 @@ impl Expr {
         cat::CategoricalNameSpace(self)
     }
 
+    /// Get the [`json::JsonNameSpace`].
+    #[cfg(feature = "dtype-json")]
+    pub fn json(self) -> json::JsonNameSpace {
+        json::JsonNameSpace(self)
+    }
+
     /// Get the [`struct_::StructNameSpace`].
     #[cfg(feature = "dtype-struct")]
     pub fn struct_(self) -> struct_::StructNameSpace {
         struct_::StructNameSpace(self)
     }
 
+    /// Get the [`scale::ScaleNameSpace`].
+    #[cfg(feature = "dtype-struct")]
+    pub fn scale(self) -> scale::ScaleNameSpace {
+        scale::ScaleNameSpace(self)
+    }
+
     /// Get the [`meta::MetaNameSpace`]
     #[cfg(feature = "meta")]
     pub fn meta(self) -> meta::MetaNameSpace {
         meta::MetaNameSpace(self)
     }
+
+    /// Get the [`ts::TimeSeriesNameSpace`].
+    #[cfg(feature = "downsample")]
+    pub fn ts(self) -> ts::TimeSeriesNameSpace {
+        ts::TimeSeriesNameSpace(self)
+    }
+
+    /// Get the [`stats::StatsNameSpace`].
+    #[cfg(feature = "hypothesis_tests")]
+    pub fn stats(self) -> stats::StatsNameSpace {
+        stats::StatsNameSpace(self)
+    }
 }
 
 /// Apply a function/closure over multiple columns once the logical plan get executed.
@@ -2011,6 +2320,15 @@ pub fn len() -> Expr {
     Expr::Len
 }
 
+/// Return the indices of the rows of every group as a list.
+///
+/// Only valid in a group by context. In `rolling` and `group_by_dynamic` these are the rows of
+/// every window, so a window can be gathered later on without assigning the rows to the
+/// windows again.
+pub fn row_indices() -> Expr {
+    Expr::Len.agg_groups().alias(ROW_INDICES)
+}
+
 /// First column in a DataFrame.
 pub fn first() -> Expr {
     Expr::Nth(0)
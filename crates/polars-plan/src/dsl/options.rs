@@ -1,6 +1,6 @@
 use polars_ops::prelude::{JoinArgs, JoinType};
 #[cfg(feature = "dynamic_group_by")]
-use polars_time::RollingGroupOptions;
+use polars_time::{Duration, RollingGroupOptions};
 use polars_utils::IdxSize;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -70,6 +70,8 @@ pub enum WindowType {
     Over(WindowMapping),
     #[cfg(feature = "dynamic_group_by")]
     Rolling(RollingGroupOptions),
+    /// Compute the function over a frame of rows around every row of its partition.
+    Frame(WindowFrame),
 }
 
 impl From<WindowMapping> for WindowType {
@@ -78,6 +80,12 @@ impl From<WindowMapping> for WindowType {
     }
 }
 
+impl From<WindowFrame> for WindowType {
+    fn from(value: WindowFrame) -> Self {
+        Self::Frame(value)
+    }
+}
+
 impl Default for WindowType {
     fn default() -> Self {
         Self::Over(WindowMapping::default())
@@ -98,6 +106,62 @@ pub enum WindowMapping {
     Join,
 }
 
+/// The rows of a partition that a window function is computed over for every row, like the
+/// frame of a SQL window function.
+///
+/// The rows of a partition are ordered by the `order_by` of the window, and a frame contains
+/// the rows from `start` up to and including `end`. Negative offsets are before the current
+/// row, and `None` is the start or end of the partition.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WindowFrame {
+    /// Offsets in number of rows.
+    Rows {
+        start: Option<i64>,
+        end: Option<i64>,
+    },
+    /// Offsets from the `order_by` value of the current row, which must be a single integer or
+    /// temporal column.
+    #[cfg(feature = "dynamic_group_by")]
+    Range {
+        start: Option<Duration>,
+        end: Option<Duration>,
+    },
+}
+
+impl WindowFrame {
+    /// A frame from `start` rows up to `end` rows from the current row, e.g. `(Some(-3), Some(0))`
+    /// for the three preceding rows and the current row.
+    pub fn rows_between(start: Option<i64>, end: Option<i64>) -> Self {
+        Self::Rows { start, end }
+    }
+
+    /// A frame of the rows with an `order_by` value within `start` and `end` of the value of the
+    /// current row. Integer columns take integer durations, such as `"-3i"`.
+    #[cfg(feature = "dynamic_group_by")]
+    pub fn range_between(start: Option<Duration>, end: Option<Duration>) -> Self {
+        Self::Range { start, end }
+    }
+}
+
+impl std::fmt::Display for WindowFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn bound<T: std::fmt::Display>(v: &Option<T>) -> String {
+            v.as_ref()
+                .map_or_else(|| "unbounded".to_string(), |v| v.to_string())
+        }
+        match self {
+            Self::Rows { start, end } => {
+                write!(f, "rows_between({}, {})", bound(start), bound(end))
+            },
+            #[cfg(feature = "dynamic_group_by")]
+            Self::Range { start, end } => {
+                write!(f, "range_between({}, {})", bound(start), bound(end))
+            },
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NestedType {
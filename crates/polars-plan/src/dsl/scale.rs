@@ -0,0 +1,120 @@
+use super::*;
+
+/// Specialized expressions to scale numeric features.
+///
+/// Every scaling is a linear transformation `(x - center) / scale`. The `*_params` expressions
+/// compute `center` and `scale` as a struct, so that they can be fitted on one dataset and
+/// applied to another with [`ScaleNameSpace::apply`].
+pub struct ScaleNameSpace(pub(crate) Expr);
+
+impl ScaleNameSpace {
+    fn values(&self) -> Expr {
+        self.0.clone().cast(DataType::Float64)
+    }
+
+    fn standard_stats(&self, ddof: u8) -> (Expr, Expr) {
+        let x = self.values();
+        (x.clone().mean(), x.std(ddof))
+    }
+
+    fn robust_stats(&self, q_low: f64, q_high: f64) -> (Expr, Expr) {
+        let x = self.values();
+        let interpol = QuantileInterpolOptions::Linear;
+        let center = x.clone().quantile(lit(0.5), interpol);
+        let scale = x.clone().quantile(lit(q_high), interpol) - x.quantile(lit(q_low), interpol);
+        (center, scale)
+    }
+
+    fn min_max_stats(&self, range: (f64, f64)) -> (Expr, Expr) {
+        let x = self.values();
+        let (low, high) = range;
+        let scale = non_zero((x.clone().max() - x.clone().min()) / lit(high - low));
+        let center = x.min() - lit(low) * scale.clone();
+        (center, scale)
+    }
+
+    /// Scale to zero mean and unit standard deviation.
+    pub fn standard(self, ddof: u8) -> Expr {
+        let (center, scale) = self.standard_stats(ddof);
+        transform(self.values(), center, scale)
+    }
+
+    /// Scale by subtracting the median and dividing by the range between the `q_low` and
+    /// `q_high` quantiles, which is insensitive to outliers.
+    pub fn robust(self, q_low: f64, q_high: f64) -> Expr {
+        let (center, scale) = self.robust_stats(q_low, q_high);
+        transform(self.values(), center, scale)
+    }
+
+    /// Scale the minimum and maximum to the bounds of `range`.
+    pub fn min_max(self, range: (f64, f64)) -> Expr {
+        let (center, scale) = self.min_max_stats(range);
+        transform(self.values(), center, scale)
+    }
+
+    /// The parameters of [`ScaleNameSpace::standard`].
+    pub fn standard_params(self, ddof: u8) -> Expr {
+        let (center, scale) = self.standard_stats(ddof);
+        params(center, scale)
+    }
+
+    /// The parameters of [`ScaleNameSpace::robust`].
+    pub fn robust_params(self, q_low: f64, q_high: f64) -> Expr {
+        let (center, scale) = self.robust_stats(q_low, q_high);
+        params(center, scale)
+    }
+
+    /// The parameters of [`ScaleNameSpace::min_max`].
+    pub fn min_max_params(self, range: (f64, f64)) -> Expr {
+        let (center, scale) = self.min_max_stats(range);
+        params(center, scale)
+    }
+
+    /// Scale with previously computed parameters, a struct with a `center` and `scale` field.
+    pub fn apply(self, params: Expr) -> Expr {
+        let (center, scale) = fields(params);
+        (self.values() - center) / scale
+    }
+
+    /// Undo the scaling with the given parameters.
+    pub fn inverse(self, params: Expr) -> Expr {
+        let (center, scale) = fields(params);
+        self.values() * scale + center
+    }
+}
+
+/// A scale of zero, e.g. of a constant column, is replaced by one, such that the scaled values
+/// only have the center subtracted.
+fn non_zero(scale: Expr) -> Expr {
+    when(scale.clone().eq(lit(0.0)))
+        .then(lit(1.0))
+        .otherwise(scale)
+}
+
+fn transform(x: Expr, center: Expr, scale: Expr) -> Expr {
+    (x - center) / non_zero(scale)
+}
+
+fn params(center: Expr, scale: Expr) -> Expr {
+    // Not `as_struct`, which would expand wildcards in the inputs to the fields of a single
+    // struct rather than to a struct per column. The struct is named after the first field
+    // and thus after the scaled column, the fields are named afterwards.
+    Expr::Function {
+        input: vec![center, non_zero(scale)],
+        function: FunctionExpr::AsStruct,
+        options: FunctionOptions {
+            pass_name_to_apply: true,
+            collect_groups: ApplyOptions::ElementWise,
+            ..Default::default()
+        },
+    }
+    .struct_()
+    .rename_fields(vec!["center".to_string(), "scale".to_string()])
+}
+
+fn fields(params: Expr) -> (Expr, Expr) {
+    (
+        params.clone().struct_().field_by_name("center"),
+        params.struct_().field_by_name("scale"),
+    )
+}
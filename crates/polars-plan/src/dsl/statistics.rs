@@ -63,14 +63,23 @@ impl Expr {
     }
 
     /// Compute the histogram of a dataset.
+    ///
+    /// The bins are given by their upper bounds in `bins`, or are `bin_count` equally wide
+    /// bins over the range of the data. Without a `bin_count`, `bin_strategy` determines the
+    /// number of bins. In a group context every group gets its own bins, unless
+    /// `shared_bins` is set and the bins are derived from the data of all groups together.
     #[cfg(feature = "hist")]
     pub fn hist(
         self,
         bins: Option<Expr>,
         bin_count: Option<usize>,
+        bin_strategy: Option<HistBinStrategy>,
+        shared_bins: bool,
         include_category: bool,
         include_breakpoint: bool,
     ) -> Self {
+        // Explicit bins are the same for every group already.
+        let shared_bins = shared_bins && bins.is_none();
         let mut input = vec![self];
         if let Some(bins) = bins {
             input.push(bins)
@@ -82,9 +91,16 @@ impl Expr {
                 bin_count,
                 include_category,
                 include_breakpoint,
+                bin_strategy,
+                shared_bins,
             },
             options: FunctionOptions {
-                collect_groups: ApplyOptions::GroupWise,
+                // Shared bins need the data of all groups, which is passed as a list.
+                collect_groups: if shared_bins {
+                    ApplyOptions::ApplyList
+                } else {
+                    ApplyOptions::GroupWise
+                },
                 ..Default::default()
             },
         }
@@ -0,0 +1,40 @@
+use polars_ops::series::TTestVariant;
+
+use super::*;
+
+/// Statistical hypothesis tests that compare the expression with another sample.
+///
+/// The tests are aggregations that return a struct with a `statistic` and a `p_value` field,
+/// so that samples can be compared per group.
+pub struct StatsNameSpace(pub(crate) Expr);
+
+impl StatsNameSpace {
+    fn test(self, other: Expr, function: HypothesisTestFunction) -> Expr {
+        Expr::Function {
+            input: vec![self.0, other],
+            function: function.into(),
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::GroupWise,
+                returns_scalar: true,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Test whether the mean differs from the mean of `other`, with a two-sided t-test.
+    pub fn ttest(self, other: Expr, variant: TTestVariant) -> Expr {
+        self.test(other, HypothesisTestFunction::TTest { variant })
+    }
+
+    /// Test whether the values come from the same distribution as `other`, with the two-sample
+    /// Kolmogorov-Smirnov test.
+    pub fn ks_test(self, other: Expr) -> Expr {
+        self.test(other, HypothesisTestFunction::KsTest)
+    }
+
+    /// Test whether the observed frequencies of categories differ from the `expected`
+    /// frequencies, with Pearson's chi-squared test.
+    pub fn chi2(self, expected: Expr) -> Expr {
+        self.test(expected, HypothesisTestFunction::Chi2)
+    }
+}
@@ -345,6 +345,18 @@ impl StringNameSpace {
             })
     }
 
+    /// Join the strings of every group into a single string, ignoring nulls.
+    ///
+    /// Unlike [`StringNameSpace::join`], this is an aggregation that can drop duplicate strings
+    /// and sort them before joining, and it can be computed by the streaming engine.
+    pub fn join_agg(self, options: StrJoinAggOptions) -> Expr {
+        AggExpr::StrJoin {
+            expr: Arc::new(self.0),
+            options,
+        }
+        .into()
+    }
+
     /// Split the string by a substring. The resulting dtype is `List<String>`.
     pub fn split(self, by: Expr) -> Expr {
         self.0
@@ -466,6 +478,89 @@ impl StringNameSpace {
         )
     }
 
+    #[cfg(feature = "string_similarity")]
+    /// Levenshtein distance to the strings of `other`, or the normalized similarity in `[0, 1]`
+    /// if `normalized` is set.
+    pub fn levenshtein(self, other: Expr, normalized: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::Levenshtein { normalized }),
+            &[other],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Damerau-Levenshtein distance to the strings of `other`, or the normalized similarity in
+    /// `[0, 1]` if `normalized` is set.
+    pub fn damerau_levenshtein(self, other: Expr, normalized: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::DamerauLevenshtein { normalized }),
+            &[other],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Hamming distance to the strings of `other`, or the normalized similarity in `[0, 1]` if
+    /// `normalized` is set. Strings of a different length result in null.
+    pub fn hamming(self, other: Expr, normalized: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::Hamming { normalized }),
+            &[other],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Jaro-Winkler similarity in `[0, 1]` to the strings of `other`.
+    pub fn jaro_winkler(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::JaroWinkler),
+            &[other],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "collation")]
+    /// Compare to the strings of `other` under a locale-aware collation: `-1` if the string
+    /// orders first, `0` if they are equal under the collation and `1` otherwise.
+    pub fn compare(self, other: Expr, collation: Collation) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::CollatedCompare(collation)),
+            &[other],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "collation")]
+    /// The smallest string under a locale-aware collation.
+    pub fn min(self, collation: Collation) -> Expr {
+        self.0
+            .apply_private(StringFunction::CollatedMin(collation).into())
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options.collect_groups = ApplyOptions::GroupWise;
+                options
+            })
+    }
+
+    #[cfg(feature = "collation")]
+    /// The largest string under a locale-aware collation.
+    pub fn max(self, collation: Collation) -> Expr {
+        self.0
+            .apply_private(StringFunction::CollatedMax(collation).into())
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options.collect_groups = ApplyOptions::GroupWise;
+                options
+            })
+    }
+
     /// Remove prefix.
     pub fn strip_prefix(self, prefix: Expr) -> Expr {
         self.0.map_many_private(
@@ -572,11 +667,20 @@ impl StringNameSpace {
     }
 
     #[cfg(feature = "extract_jsonpath")]
-    pub fn json_decode(self, dtype: Option<DataType>, infer_schema_len: Option<usize>) -> Expr {
+    /// Parse the strings as JSON. The schema is inferred from the first `infer_schema_len`
+    /// rows unless `dtype` is given. If `strict` is `false`, strings that are not valid
+    /// JSON are parsed as null instead of raising an error.
+    pub fn json_decode(
+        self,
+        dtype: Option<DataType>,
+        infer_schema_len: Option<usize>,
+        strict: bool,
+    ) -> Expr {
         self.0
             .map_private(FunctionExpr::StringExpr(StringFunction::JsonDecode {
                 dtype,
                 infer_schema_len,
+                strict,
             }))
     }
 
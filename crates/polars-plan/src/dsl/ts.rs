@@ -0,0 +1,26 @@
+use super::*;
+
+/// Specialized expressions for time series.
+pub struct TimeSeriesNameSpace(pub(crate) Expr);
+
+impl TimeSeriesNameSpace {
+    /// A mask of the points that are kept when downsampling the series to `n_out` points with
+    /// the Largest-Triangle-Three-Buckets algorithm. The row index is used as `x` coordinate if
+    /// `x` is `None`.
+    ///
+    /// Use it with [`Expr::over`] to downsample every series of a frame separately.
+    pub fn lttb(self, n_out: usize, x: Option<Expr>) -> Expr {
+        let function = DownsampleFunction::Lttb { n_out }.into();
+        match x {
+            Some(x) => self.0.apply_many_private(function, &[x], false, false),
+            None => self.0.apply_private(function),
+        }
+    }
+
+    /// A mask of the points that are kept when downsampling the series to (at most) `n_out`
+    /// points by keeping the minimum and maximum of `n_out / 2` buckets of consecutive points.
+    pub fn min_max(self, n_out: usize) -> Expr {
+        self.0
+            .apply_private(DownsampleFunction::MinMax { n_out }.into())
+    }
+}
@@ -0,0 +1,179 @@
+//! A process wide registry of Rust UDFs, referenced by name.
+//!
+//! An [`Expr::AnonymousFunction`] that wraps a closure can't be serialized. A UDF that is
+//! registered with [`register_udf`] and called with [`registered_udf`] is serialized as its
+//! name instead, so the plan can be deserialized and executed by any process that registered
+//! a UDF under the same name.
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+#[cfg(feature = "serde")]
+use polars_core::error::to_compute_err;
+
+use super::*;
+
+pub(super) const MAGIC_BYTE_MARK: &[u8] = "POLARS_REGISTERED_UDF".as_bytes();
+
+type SchemaFn = dyn Fn(&[Field]) -> PolarsResult<Field> + Send + Sync;
+type EvalFn = dyn Fn(&mut [Series]) -> PolarsResult<Option<Series>> + Send + Sync;
+
+#[derive(Clone)]
+struct Registered {
+    schema_fn: Arc<SchemaFn>,
+    eval_fn: Arc<EvalFn>,
+}
+
+static REGISTRY: Lazy<RwLock<PlHashMap<String, Registered>>> = Lazy::new(Default::default);
+
+/// Register a vectorized UDF under `name`, replacing the UDF that was registered under that
+/// name before.
+///
+/// `schema_fn` gives the output field for the fields of the inputs and `eval_fn` computes the
+/// output from the inputs.
+///
+/// # Example
+///
+/// ```rust
+/// use polars_core::prelude::*;
+/// use polars_plan::prelude::*;
+///
+/// register_udf(
+///     "double",
+///     |fields| Ok(fields[0].clone()),
+///     |s| Ok(Some(&s[0] * 2)),
+/// );
+/// let expr = registered_udf("double", vec![col("a")]).unwrap();
+/// ```
+pub fn register_udf<S, F>(name: &str, schema_fn: S, eval_fn: F)
+where
+    S: Fn(&[Field]) -> PolarsResult<Field> + Send + Sync + 'static,
+    F: Fn(&mut [Series]) -> PolarsResult<Option<Series>> + Send + Sync + 'static,
+{
+    let udf = Registered {
+        schema_fn: Arc::new(schema_fn),
+        eval_fn: Arc::new(eval_fn),
+    };
+    REGISTRY.write().unwrap().insert(name.to_string(), udf);
+}
+
+/// Remove the UDF registered under `name`, returns whether there was one.
+///
+/// Expressions that were created before keep calling the removed UDF, but plans that refer
+/// to it can no longer be deserialized.
+pub fn unregister_udf(name: &str) -> bool {
+    REGISTRY.write().unwrap().remove(name).is_some()
+}
+
+/// Call the UDF registered under `name` on `input`.
+///
+/// The UDF is applied to the whole column, or to every group in an aggregation.
+pub fn registered_udf(name: &str, input: Vec<Expr>) -> PolarsResult<Expr> {
+    let udf = RegisteredUdf::get(name)?;
+    let output_type = udf.output_type();
+    Ok(Expr::AnonymousFunction {
+        input,
+        function: SpecialEq::new(Arc::new(udf)),
+        output_type,
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            fmt_str: "registered_udf",
+            ..Default::default()
+        },
+    })
+}
+
+/// A registered UDF together with its name, which is what is serialized.
+pub(super) struct RegisteredUdf {
+    name: String,
+    udf: Registered,
+}
+
+impl RegisteredUdf {
+    fn get(name: &str) -> PolarsResult<Self> {
+        let udf = REGISTRY.read().unwrap().get(name).cloned().ok_or_else(
+            || polars_err!(ComputeError: "no UDF is registered under the name '{}'", name),
+        )?;
+        Ok(Self {
+            name: name.to_string(),
+            udf,
+        })
+    }
+
+    fn output_type(&self) -> GetOutput {
+        let schema_fn = self.udf.schema_fn.clone();
+        GetOutput::map_fields(move |fields| schema_fn(fields))
+    }
+
+    #[cfg(feature = "serde")]
+    pub(super) fn try_deserialize(buf: &[u8]) -> PolarsResult<Arc<dyn SeriesUdf>> {
+        debug_assert!(buf.starts_with(MAGIC_BYTE_MARK));
+        let name = std::str::from_utf8(&buf[MAGIC_BYTE_MARK.len()..]).map_err(to_compute_err)?;
+        Ok(Arc::new(Self::get(name)?))
+    }
+}
+
+impl SeriesUdf for RegisteredUdf {
+    fn call_udf(&self, s: &mut [Series]) -> PolarsResult<Option<Series>> {
+        (self.udf.eval_fn)(s)
+    }
+
+    fn try_serialize(&self, buf: &mut Vec<u8>) -> PolarsResult<()> {
+        buf.extend_from_slice(MAGIC_BYTE_MARK);
+        buf.extend_from_slice(self.name.as_bytes());
+        Ok(())
+    }
+
+    // The output type of the expression is not serialized.
+    fn get_output(&self) -> Option<GetOutput> {
+        Some(self.output_type())
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod test {
+    use super::*;
+    use crate::plans::{DslBuilder, PlanSerializationFormat};
+
+    #[test]
+    fn test_registered_udf_roundtrip() -> PolarsResult<()> {
+        register_udf(
+            "test_registered_udf_roundtrip",
+            |fields| Ok(Field::new(fields[0].name(), DataType::Float64)),
+            |s| s[0].cast(&DataType::Float64).map(Some),
+        );
+        let df = DataFrame::new(vec![Series::new("a", [1i64, 2, 3])])?;
+        let plan = DslBuilder::from_existing_df(df)
+            .project(
+                vec![registered_udf(
+                    "test_registered_udf_roundtrip",
+                    vec![col("a")],
+                )?],
+                Default::default(),
+            )
+            .build();
+
+        let mut buf = vec![];
+        plan.serialize_versioned(&mut buf, PlanSerializationFormat::Binary)?;
+        let out = DslPlan::deserialize_versioned(buf.as_slice(), PlanSerializationFormat::Binary)?;
+        let DslPlan::Select { expr, .. } = out else {
+            panic!("expected a select")
+        };
+        let Expr::AnonymousFunction { function, .. } = &expr[0] else {
+            panic!("expected a function")
+        };
+        let field = function.get_output().unwrap().get_field(
+            &Schema::default(),
+            Context::Default,
+            &[Field::new("a", DataType::Int64)],
+        )?;
+        assert_eq!(field, Field::new("a", DataType::Float64));
+
+        // Another process without the UDF can't read the plan.
+        assert!(unregister_udf("test_registered_udf_roundtrip"));
+        assert!(
+            DslPlan::deserialize_versioned(buf.as_slice(), PlanSerializationFormat::Binary)
+                .is_err()
+        );
+        Ok(())
+    }
+}
@@ -32,6 +32,8 @@ pub struct OptState {
     /// Try to estimate the number of rows so that joins can determine which side to keep in memory.
     pub row_estimate: bool,
     pub new_streaming: bool,
+    /// Reorder chains of inner joins on file scans by their estimated cardinality.
+    pub join_reorder: bool,
 }
 
 impl Default for OptState {
@@ -54,6 +56,7 @@ impl Default for OptState {
             eager: false,
             row_estimate: true,
             new_streaming: false,
+            join_reorder: true,
         }
     }
 }
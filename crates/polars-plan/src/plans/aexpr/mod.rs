@@ -39,6 +39,32 @@ pub enum IRAggExpr {
         quantile: Node,
         interpol: QuantileInterpolOptions,
     },
+    ApproxQuantile {
+        expr: Node,
+        quantile: Node,
+        accuracy: u32,
+    },
+    Quantiles {
+        expr: Node,
+        options: QuantilesOptions,
+    },
+    StrJoin {
+        expr: Node,
+        options: StrJoinAggOptions,
+    },
+    FirstBy {
+        expr: Node,
+        by: Node,
+    },
+    LastBy {
+        expr: Node,
+        by: Node,
+    },
+    #[cfg(feature = "ffi_plugin")]
+    Plugin {
+        expr: Node,
+        function: PluginAggregation,
+    },
     Sum(Node),
     Count(Node, bool),
     Std(Node, u8),
@@ -54,6 +80,11 @@ impl Hash for IRAggExpr {
                 propagate_nans.hash(state)
             },
             Self::Quantile { interpol, .. } => interpol.hash(state),
+            Self::ApproxQuantile { accuracy, .. } => accuracy.hash(state),
+            Self::Quantiles { options, .. } => options.hash(state),
+            Self::StrJoin { options, .. } => options.hash(state),
+            #[cfg(feature = "ffi_plugin")]
+            Self::Plugin { function, .. } => function.hash(state),
             Self::Std(_, v) | Self::Var(_, v) => v.hash(state),
             _ => {},
         }
@@ -82,6 +113,11 @@ impl IRAggExpr {
                 },
             ) => l == r,
             (Quantile { interpol: l, .. }, Quantile { interpol: r, .. }) => l == r,
+            (ApproxQuantile { accuracy: l, .. }, ApproxQuantile { accuracy: r, .. }) => l == r,
+            (Quantiles { options: l, .. }, Quantiles { options: r, .. }) => l == r,
+            (StrJoin { options: l, .. }, StrJoin { options: r, .. }) => l == r,
+            #[cfg(feature = "ffi_plugin")]
+            (Plugin { function: l, .. }, Plugin { function: r, .. }) => l == r,
             (Std(_, l), Std(_, r)) => l == r,
             (Var(_, l), Var(_, r)) => l == r,
             _ => std::mem::discriminant(self) == std::mem::discriminant(other),
@@ -118,7 +154,14 @@ impl From<IRAggExpr> for GroupByMethod {
             Std(_, ddof) => GroupByMethod::Std(ddof),
             Var(_, ddof) => GroupByMethod::Var(ddof),
             AggGroups(_) => GroupByMethod::Groups,
-            Quantile { .. } => unreachable!(),
+            Quantile { .. }
+            | ApproxQuantile { .. }
+            | Quantiles { .. }
+            | StrJoin { .. }
+            | FirstBy { .. }
+            | LastBy { .. } => unreachable!(),
+            #[cfg(feature = "ffi_plugin")]
+            Plugin { .. } => unreachable!(),
         }
     }
 }
@@ -362,10 +405,15 @@ impl AExpr {
             },
             Agg(a) => {
                 match a {
-                    IRAggExpr::Quantile { expr, quantile, .. } => {
+                    IRAggExpr::Quantile { expr, quantile, .. }
+                    | IRAggExpr::ApproxQuantile { expr, quantile, .. } => {
                         *expr = inputs[0];
                         *quantile = inputs[1];
                     },
+                    IRAggExpr::FirstBy { expr, by } | IRAggExpr::LastBy { expr, by } => {
+                        *expr = inputs[0];
+                        *by = inputs[1];
+                    },
                     _ => {
                         a.set_input(inputs[0]);
                     },
@@ -445,6 +493,12 @@ impl IRAggExpr {
             Mean(input) => Single(*input),
             Implode(input) => Single(*input),
             Quantile { expr, quantile, .. } => Many(vec![*expr, *quantile]),
+            ApproxQuantile { expr, quantile, .. } => Many(vec![*expr, *quantile]),
+            Quantiles { expr, .. } => Single(*expr),
+            StrJoin { expr, .. } => Single(*expr),
+            FirstBy { expr, by } | LastBy { expr, by } => Many(vec![*expr, *by]),
+            #[cfg(feature = "ffi_plugin")]
+            Plugin { expr, .. } => Single(*expr),
             Sum(input) => Single(*input),
             Count(input, _) => Single(*input),
             Std(input, _) => Single(*input),
@@ -464,6 +518,12 @@ impl IRAggExpr {
             Mean(input) => input,
             Implode(input) => input,
             Quantile { expr, .. } => expr,
+            ApproxQuantile { expr, .. } => expr,
+            Quantiles { expr, .. } => expr,
+            StrJoin { expr, .. } => expr,
+            FirstBy { expr, .. } | LastBy { expr, .. } => expr,
+            #[cfg(feature = "ffi_plugin")]
+            Plugin { expr, .. } => expr,
             Sum(input) => input,
             Count(input, _) => input,
             Std(input, _) => input,
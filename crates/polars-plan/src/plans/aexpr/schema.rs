@@ -128,7 +128,9 @@ impl AExpr {
                     Max { input: expr, .. }
                     | Min { input: expr, .. }
                     | First(expr)
-                    | Last(expr) => {
+                    | Last(expr)
+                    | FirstBy { expr, .. }
+                    | LastBy { expr, .. } => {
                         *nested = nested.saturating_sub(1);
                         arena.get(*expr).to_field_impl(schema, arena, nested)
                     },
@@ -204,6 +206,42 @@ impl AExpr {
                         float_type(&mut field);
                         Ok(field)
                     },
+                    ApproxQuantile { expr, .. } => {
+                        *nested = nested.saturating_sub(1);
+                        let mut field = arena.get(*expr).to_field_impl(schema, arena, nested)?;
+                        field.coerce(Float64);
+                        Ok(field)
+                    },
+                    Quantiles { expr, options } => {
+                        *nested = nested.saturating_sub(1);
+                        let mut field = arena.get(*expr).to_field_impl(schema, arena, nested)?;
+                        float_type(&mut field);
+                        #[cfg(feature = "dtype-array")]
+                        {
+                            let inner = field.data_type().clone();
+                            field.coerce(Array(Box::new(inner), options.quantiles.len()));
+                            Ok(field)
+                        }
+                        #[cfg(not(feature = "dtype-array"))]
+                        {
+                            let _ = options;
+                            polars_bail!(
+                                InvalidOperation: "`quantiles` needs the 'dtype-array' feature"
+                            )
+                        }
+                    },
+                    StrJoin { expr, .. } => {
+                        *nested = nested.saturating_sub(1);
+                        let mut field = arena.get(*expr).to_field_impl(schema, arena, nested)?;
+                        field.coerce(String);
+                        Ok(field)
+                    },
+                    #[cfg(feature = "ffi_plugin")]
+                    Plugin { expr, function } => {
+                        *nested = nested.saturating_sub(1);
+                        let field = arena.get(*expr).to_field_impl(schema, arena, nested)?;
+                        function.output_field(&field)
+                    },
                 }
             },
             Cast {
@@ -344,7 +382,7 @@ fn get_arithmetic_field(
                 (_, Time) | (Time, _) => {
                     polars_bail!(InvalidOperation: "{} not allowed on {} and {}", op, left_field.dtype, right_type)
                 },
-                (left, right) => try_get_supertype(left, right)?,
+                (left, right) => get_arithmetic_supertype(op, left, right)?,
             }
         },
         Operator::Plus => {
@@ -369,7 +407,7 @@ fn get_arithmetic_field(
                     polars_bail!(InvalidOperation: "{} not allowed on {} and {}", op, left_field.dtype, right_type)
                 },
                 (Boolean, Boolean) => IDX_DTYPE,
-                (left, right) => try_get_supertype(left, right)?,
+                (left, right) => get_arithmetic_supertype(op, left, right)?,
             }
         },
         _ => {
@@ -424,14 +462,82 @@ fn get_arithmetic_field(
                 },
             }
 
-            try_get_supertype(&left_field.dtype, &right_type)?
+            get_arithmetic_supertype(op, &left_field.dtype, &right_type)?
         },
     };
+    // Float16 arithmetic is computed in Float32.
+    #[cfg(feature = "dtype-f16")]
+    let super_type = match super_type {
+        Float16 => Float32,
+        dt => dt,
+    };
 
     left_field.coerce(super_type);
     Ok(left_field)
 }
 
+/// The type both sides of an arithmetic operation are cast to. For decimals this is the type of
+/// the result, which depends on the operation.
+#[allow(unused_variables)]
+fn get_arithmetic_supertype(
+    op: Operator,
+    left: &DataType,
+    right: &DataType,
+) -> PolarsResult<DataType> {
+    #[cfg(feature = "dtype-decimal")]
+    if let Some(dtype) = get_decimal_arithmetic_dtype(op, left, right) {
+        return Ok(dtype);
+    }
+    // A number is applied to every element of an array.
+    #[cfg(feature = "dtype-array")]
+    match (left, right) {
+        (DataType::Array(_, _), number) | (number, DataType::Array(_, _))
+            if number.is_numeric() =>
+        {
+            let array = if left.is_array() { left } else { right };
+            let leaf = try_get_supertype(array.leaf_dtype(), number)?;
+            return Ok(array.cast_leaf(leaf));
+        },
+        _ => {},
+    }
+    try_get_supertype(left, right)
+}
+
+#[cfg(feature = "dtype-decimal")]
+fn get_decimal_arithmetic_dtype(
+    op: Operator,
+    left: &DataType,
+    right: &DataType,
+) -> Option<DataType> {
+    use polars_core::chunked_array::arithmetic::{
+        decimal_arithmetic_dtype, integer_decimal_dtype, DecimalArithmetic,
+    };
+
+    if !(left.is_decimal() || right.is_decimal()) {
+        return None;
+    }
+    let op = match op {
+        Operator::Plus => DecimalArithmetic::Add,
+        Operator::Minus => DecimalArithmetic::Sub,
+        Operator::Multiply => DecimalArithmetic::Mul,
+        Operator::Divide | Operator::TrueDivide => DecimalArithmetic::Div,
+        Operator::Modulus => DecimalArithmetic::Rem,
+        _ => return None,
+    };
+    let precision_scale =
+        |dtype: &DataType| match integer_decimal_dtype(dtype).as_ref().unwrap_or(dtype) {
+            DataType::Decimal(precision, scale) => Some((*precision, scale.unwrap_or(0))),
+            _ => None,
+        };
+    let (precision, scale) = decimal_arithmetic_dtype(
+        op,
+        precision_scale(left)?,
+        precision_scale(right)?,
+        polars_core::config::decimal_overflow(),
+    );
+    Some(DataType::Decimal(precision, Some(scale)))
+}
+
 fn get_truediv_field(
     left: Node,
     right: Node,
@@ -441,8 +547,34 @@ fn get_truediv_field(
 ) -> PolarsResult<Field> {
     let mut left_field = arena.get(left).to_field_impl(schema, arena, nested)?;
     use DataType::*;
+    // The elements of arrays are divided, by the elements of another array or by a number.
+    #[cfg(feature = "dtype-array")]
+    if left_field.dtype.is_array() || left_field.dtype.is_numeric() {
+        let right_type = arena.get(right).to_field_impl(schema, arena, nested)?.dtype;
+        if left_field.dtype.is_array() || right_type.is_array() {
+            let float = match (left_field.dtype.leaf_dtype(), right_type.leaf_dtype()) {
+                (Float32, Float32) => Float32,
+                _ => Float64,
+            };
+            let array = if left_field.dtype.is_array() {
+                &left_field.dtype
+            } else {
+                &right_type
+            };
+            let out_type = array.cast_leaf(float);
+            left_field.coerce(out_type);
+            return Ok(left_field);
+        }
+    }
     let out_type = match left_field.data_type() {
         Float32 => Float32,
+        #[cfg(feature = "dtype-f16")]
+        Float16 => Float32,
+        #[cfg(feature = "dtype-decimal")]
+        dt @ Decimal(_, _) => {
+            let right_type = arena.get(right).to_field_impl(schema, arena, nested)?.dtype;
+            get_arithmetic_supertype(Operator::TrueDivide, dt, &right_type)?
+        },
         dt if dt.is_numeric() => Float64,
         #[cfg(feature = "dtype-duration")]
         Duration(_) => match arena
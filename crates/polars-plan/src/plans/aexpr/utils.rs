@@ -69,3 +69,38 @@ pub fn all_streamable(exprs: &[ExprIR], expr_arena: &Arena<AExpr>, context: Cont
         .iter()
         .all(|e| is_streamable(e.node(), expr_arena, context))
 }
+
+/// Whether the expression may be evaluated concurrently with other expressions,
+/// i.e. it contains no `parallel(false)` hint.
+pub fn is_parallel_safe(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    !has_aexpr(node, expr_arena, |ae| {
+        matches!(
+            ae,
+            AExpr::Function {
+                function: FunctionExpr::Parallel(false),
+                ..
+            }
+        )
+    })
+}
+
+pub fn all_parallel_safe(exprs: &[ExprIR], expr_arena: &Arena<AExpr>) -> bool {
+    exprs.iter().all(|e| is_parallel_safe(e.node(), expr_arena))
+}
+
+/// Whether the expression maps every row on its own, such that evaluating it on a subset of the
+/// rows gives that subset of the output.
+pub fn is_row_separable(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    expr_arena.iter(node).all(|(_, ae)| match ae {
+        AExpr::Column(_)
+        | AExpr::Alias(_, _)
+        | AExpr::Cast { .. }
+        | AExpr::BinaryExpr { .. }
+        | AExpr::Ternary { .. } => true,
+        AExpr::Literal(lv) => lv.projects_as_scalar(),
+        AExpr::Function { options, .. } | AExpr::AnonymousFunction { options, .. } => {
+            matches!(options.collect_groups, ApplyOptions::ElementWise) && !options.returns_scalar
+        },
+        _ => false,
+    })
+}
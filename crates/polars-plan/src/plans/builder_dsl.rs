@@ -1,3 +1,4 @@
+use either::Either;
 use polars_core::prelude::*;
 #[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
 use polars_io::cloud::CloudOptions;
@@ -41,6 +42,7 @@ impl DslBuilder {
         let file_info = FileInfo::new(schema.clone(), None, (n_rows, n_rows.unwrap_or(usize::MAX)));
         let file_options = FileScanOptions {
             n_rows,
+            tail: None,
             with_columns: None,
             cache: false,
             row_index: None,
@@ -51,6 +53,8 @@ impl DslBuilder {
                 enabled: Some(false),
                 ..Default::default()
             },
+            hive_pruned_files: 0,
+            allow_missing_files: false,
         };
 
         Ok(DslPlan::Scan {
@@ -70,6 +74,41 @@ impl DslBuilder {
         .into())
     }
 
+    pub fn scan_source(source: Arc<dyn ScanSource>) -> PolarsResult<Self> {
+        let schema = source.schema()?;
+        let n_rows = source.row_count();
+        let file_info = FileInfo::new(
+            schema.clone(),
+            Some(Either::Right(schema)),
+            (n_rows, n_rows.unwrap_or(usize::MAX)),
+        );
+        let file_options = FileScanOptions {
+            n_rows: None,
+            tail: None,
+            with_columns: None,
+            cache: false,
+            row_index: None,
+            rechunk: false,
+            file_counter: Default::default(),
+            hive_pruned_files: 0,
+            allow_missing_files: false,
+            hive_options: HiveOptions {
+                enabled: Some(false),
+                ..Default::default()
+            },
+        };
+
+        Ok(DslPlan::Scan {
+            paths: Arc::new([]),
+            file_info: Some(file_info),
+            hive_parts: None,
+            predicate: None,
+            file_options,
+            scan_type: FileScan::Source { source },
+        }
+        .into())
+    }
+
     #[cfg(feature = "parquet")]
     #[allow(clippy::too_many_arguments)]
     pub fn scan_parquet<P: Into<Arc<[std::path::PathBuf]>>>(
@@ -83,6 +122,7 @@ impl DslBuilder {
         cloud_options: Option<CloudOptions>,
         use_statistics: bool,
         hive_options: HiveOptions,
+        allow_missing_files: bool,
     ) -> PolarsResult<Self> {
         let paths = paths.into();
 
@@ -90,9 +130,12 @@ impl DslBuilder {
             with_columns: None,
             cache,
             n_rows,
+            tail: None,
             rechunk,
             row_index,
             file_counter: Default::default(),
+            hive_pruned_files: 0,
+            allow_missing_files,
             hive_options,
         };
         Ok(DslPlan::Scan {
@@ -115,6 +158,7 @@ impl DslBuilder {
     }
 
     #[cfg(feature = "ipc")]
+    #[allow(clippy::too_many_arguments)]
     pub fn scan_ipc<P: Into<Arc<[std::path::PathBuf]>>>(
         paths: P,
         options: IpcScanOptions,
@@ -123,6 +167,7 @@ impl DslBuilder {
         row_index: Option<RowIndex>,
         rechunk: bool,
         cloud_options: Option<CloudOptions>,
+        allow_missing_files: bool,
     ) -> PolarsResult<Self> {
         let paths = paths.into();
 
@@ -134,6 +179,7 @@ impl DslBuilder {
                 with_columns: None,
                 cache,
                 n_rows,
+                tail: None,
                 rechunk,
                 row_index,
                 file_counter: Default::default(),
@@ -142,6 +188,8 @@ impl DslBuilder {
                     enabled: Some(false),
                     ..Default::default()
                 },
+                hive_pruned_files: 0,
+                allow_missing_files,
             },
             predicate: None,
             scan_type: FileScan::Ipc {
@@ -170,6 +218,7 @@ impl DslBuilder {
             with_columns: None,
             cache,
             n_rows: read_options_clone.n_rows,
+            tail: None,
             rechunk: read_options_clone.rechunk,
             row_index: read_options_clone.row_index,
             file_counter: Default::default(),
@@ -178,6 +227,8 @@ impl DslBuilder {
                 enabled: Some(false),
                 ..Default::default()
             },
+            hive_pruned_files: 0,
+            allow_missing_files: false,
         };
         Ok(DslPlan::Scan {
             paths,
@@ -293,6 +344,10 @@ impl DslBuilder {
             #[cfg(feature = "dynamic_group_by")]
             rolling: rolling_options,
             slice: None,
+            #[cfg(feature = "dynamic_group_by")]
+            resample: None,
+            #[cfg(feature = "grouping_sets")]
+            grouping_sets: None,
         };
 
         DslPlan::GroupBy {
@@ -347,12 +402,22 @@ impl DslBuilder {
         .into()
     }
 
+    #[cfg(feature = "pivot")]
+    pub fn pivot(self, args: PivotArgs) -> Self {
+        DslPlan::MapFunction {
+            input: Arc::new(self.0),
+            function: DslFunction::Pivot(args),
+        }
+        .into()
+    }
+
     pub fn row_index(self, name: &str, offset: Option<IdxSize>) -> Self {
         DslPlan::MapFunction {
             input: Arc::new(self.0),
             function: DslFunction::RowIndex {
                 name: ColumnName::from(name),
                 offset,
+                over: None,
             },
         }
         .into()
@@ -391,6 +456,16 @@ impl DslBuilder {
         }
         .into()
     }
+
+    pub fn set_operation(self, other: DslPlan, op: FrameSetOperation) -> Self {
+        DslPlan::SetOperation {
+            input_left: Arc::new(self.0),
+            input_right: Arc::new(other),
+            op,
+        }
+        .into()
+    }
+
     pub fn map_private(self, function: DslFunction) -> Self {
         DslPlan::MapFunction {
             input: Arc::new(self.0),
@@ -416,6 +491,8 @@ impl DslBuilder {
                 projection_pd: optimizations.projection_pushdown,
                 streamable: optimizations.streaming,
                 validate_output,
+                columns_used: None,
+                row_independent: false,
             }),
         }
         .into()
@@ -442,6 +519,8 @@ impl DslBuilder {
                 projection_pd: optimizations.projection_pushdown,
                 streamable: optimizations.streaming,
                 fmt_str: name,
+                columns_used: None,
+                row_independent: false,
             }),
         }
         .into()
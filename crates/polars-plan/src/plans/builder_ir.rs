@@ -220,6 +220,16 @@ impl<'a> IRBuilder<'a> {
         let mut schema =
             expr_irs_to_schema(&keys, &current_schema, Context::Default, self.expr_arena);
 
+        #[cfg(feature = "grouping_sets")]
+        if let Some(grouping_sets) = options.grouping_sets.as_ref() {
+            if let Some(name) = &grouping_sets.grouping_id {
+                schema.with_column(name.as_ref().into(), DataType::UInt64);
+            }
+            if let Some(name) = &grouping_sets.level {
+                schema.with_column(name.as_ref().into(), DataType::UInt32);
+            }
+        }
+
         #[cfg(feature = "dynamic_group_by")]
         {
             if let Some(options) = options.rolling.as_ref() {
@@ -314,6 +324,7 @@ impl<'a> IRBuilder<'a> {
             function: FunctionNode::RowIndex {
                 name,
                 offset,
+                over: None,
                 schema: Default::default(),
             },
         };
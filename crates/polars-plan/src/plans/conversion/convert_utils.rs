@@ -54,22 +54,17 @@ pub(super) fn convert_diagonal_concat(
     mut inputs: Vec<Node>,
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
-) -> Vec<Node> {
+    to_supertypes: bool,
+) -> PolarsResult<Vec<Node>> {
     let schemas = nodes_to_schemas(&inputs, lp_arena);
 
-    let upper_bound_width = schemas.iter().map(|sch| sch.len()).sum();
-
-    let mut total_schema = Schema::with_capacity(upper_bound_width);
-
-    for sch in schemas.iter() {
-        sch.iter().for_each(|(name, dtype)| {
-            if !total_schema.contains(name) {
-                total_schema.with_column(name.as_str().into(), dtype.clone());
-            }
-        });
-    }
+    let options = UnifyOptions {
+        to_supertypes,
+        allow_missing_columns: true,
+    };
+    let total_schema = unify_schemas(&schemas, options)?;
     if total_schema.is_empty() {
-        return inputs;
+        return Ok(inputs);
     }
 
     let mut has_empty = false;
@@ -97,7 +92,7 @@ pub(super) fn convert_diagonal_concat(
             .node();
     }
 
-    if has_empty {
+    Ok(if has_empty {
         inputs
             .into_iter()
             .zip(schemas)
@@ -105,7 +100,7 @@ pub(super) fn convert_diagonal_concat(
             .collect()
     } else {
         inputs
-    }
+    })
 }
 
 pub(super) fn h_concat_schema(
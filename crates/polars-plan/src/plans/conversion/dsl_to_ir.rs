@@ -90,6 +90,14 @@ pub fn to_alp_impl(
             mut scan_type,
             mut file_options,
         } => {
+            #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
+            let paths = if file_info.is_none() && hive_parts.is_none() {
+                scans::validate_paths(paths, &file_options, &scan_type)
+                    .map_err(|e| e.context(failed_here!(scan)))?
+            } else {
+                paths
+            };
+
             let mut file_info = if let Some(file_info) = file_info {
                 file_info
             } else {
@@ -132,7 +140,7 @@ pub fn to_alp_impl(
                             .map_err(|e| e.context(failed_here!(ndjson scan)))?
                     },
                     // FileInfo should be set.
-                    FileScan::Anonymous { .. } => unreachable!(),
+                    FileScan::Anonymous { .. } | FileScan::Source { .. } => unreachable!(),
                 }
             };
 
@@ -205,7 +213,13 @@ pub fn to_alp_impl(
                 .map_err(|e| e.context(failed_input!(vertical concat)))?;
 
             if args.diagonal {
-                inputs = convert_utils::convert_diagonal_concat(inputs, lp_arena, expr_arena);
+                inputs = convert_utils::convert_diagonal_concat(
+                    inputs,
+                    lp_arena,
+                    expr_arena,
+                    args.to_supertypes,
+                )
+                .map_err(|e| e.context(failed_input!(vertical concat)))?;
             }
 
             if args.to_supertypes {
@@ -230,6 +244,97 @@ pub fn to_alp_impl(
                 options,
             }
         },
+        DslPlan::SetOperation {
+            input_left,
+            input_right,
+            op,
+        } => {
+            let left = to_alp_impl(owned(input_left.clone()), expr_arena, lp_arena, convert)
+                .map_err(|e| e.context(failed_input_args!(op)))?;
+            let right = to_alp_impl(owned(input_right.clone()), expr_arena, lp_arena, convert)
+                .map_err(|e| e.context(failed_input_args!(op)))?;
+
+            let schema_left = lp_arena.get(left).schema(lp_arena).into_owned();
+            let schema_right = lp_arena.get(right).schema(lp_arena).into_owned();
+            polars_ensure!(
+                op.by_name() || schema_left.len() == schema_right.len(),
+                InvalidOperation: "'{}' requires an equal number of columns in both inputs; got {} and {}",
+                op, schema_left.len(), schema_right.len()
+            );
+
+            // Line up the right input with the left one, after which the operation is expressed
+            // with the existing join, union and distinct nodes.
+            let right_exprs = if op.by_name() {
+                schema_left.iter_names().map(|name| col(name)).collect()
+            } else {
+                schema_left
+                    .iter_names()
+                    .zip(schema_right.iter_names())
+                    .map(|(l, r)| col(r).alias(l))
+                    .collect()
+            };
+            let version = lp_arena.version();
+            let input_left = DslPlan::IR {
+                node: Some(left),
+                version,
+                dsl: input_left,
+            };
+            let input_right = DslPlan::Select {
+                expr: right_exprs,
+                input: Arc::new(DslPlan::IR {
+                    node: Some(right),
+                    version,
+                    dsl: input_right,
+                }),
+                options: Default::default(),
+            };
+
+            let lp = match op {
+                #[cfg(feature = "semi_anti_join")]
+                FrameSetOperation::Intersect { .. } | FrameSetOperation::Except { .. } => {
+                    let how = if matches!(op, FrameSetOperation::Intersect { .. }) {
+                        JoinType::Semi
+                    } else {
+                        JoinType::Anti
+                    };
+                    let on: Vec<_> = schema_left.iter_names().map(|name| col(name)).collect();
+                    DslPlan::Join {
+                        input_left: Arc::new(input_left),
+                        input_right: Arc::new(input_right),
+                        left_on: on.clone(),
+                        right_on: on,
+                        options: Arc::new(JoinOptions {
+                            args: JoinArgs {
+                                join_nulls: true,
+                                ..JoinArgs::new(how)
+                            },
+                            ..Default::default()
+                        }),
+                    }
+                },
+                FrameSetOperation::Union { .. } => DslPlan::Union {
+                    inputs: vec![input_left, input_right],
+                    args: UnionArgs {
+                        to_supertypes: true,
+                        ..Default::default()
+                    },
+                },
+            };
+            let lp = if op.is_distinct() {
+                DslPlan::Distinct {
+                    input: Arc::new(lp),
+                    options: DistinctOptions {
+                        subset: None,
+                        maintain_order: false,
+                        keep_strategy: UniqueKeepStrategy::Any,
+                        slice: None,
+                    },
+                }
+            } else {
+                lp
+            };
+            return to_alp_impl(lp, expr_arena, lp_arena, convert);
+        },
         DslPlan::Filter { input, predicate } => {
             let mut input = to_alp_impl(owned(input), expr_arena, lp_arena, convert)
                 .map_err(|e| e.context(failed_input!(filter)))?;
@@ -615,6 +720,30 @@ pub fn to_alp_impl(
                     };
                     return run_conversion(lp, lp_arena, expr_arena, convert, "stats");
                 },
+                #[cfg(feature = "pivot")]
+                DslFunction::Pivot(args) => {
+                    let (keys, aggs) = args
+                        .to_group_by(&input_schema)
+                        .map_err(|e| e.context(failed_here!(pivot)))?;
+                    let options = Arc::new(GroupbyOptions::default());
+                    let (keys, aggs, schema) =
+                        resolve_group_by(input, keys, aggs, &options, lp_arena, expr_arena)
+                            .map_err(|e| e.context(failed_here!(pivot)))?;
+
+                    convert.fill_scratch(&keys, expr_arena);
+                    convert.fill_scratch(&aggs, expr_arena);
+
+                    let lp = IR::GroupBy {
+                        input,
+                        keys,
+                        aggs,
+                        schema,
+                        apply: None,
+                        maintain_order: args.maintain_order,
+                        options,
+                    };
+                    return run_conversion(lp, lp_arena, expr_arena, convert, "pivot");
+                },
                 _ => {
                     let function = function.into_function_node(&input_schema)?;
                     IR::MapFunction { input, function }
@@ -762,11 +891,42 @@ fn resolve_group_by(
 ) -> PolarsResult<(Vec<ExprIR>, Vec<ExprIR>, SchemaRef)> {
     let current_schema = lp_arena.get(input).schema(lp_arena);
     let current_schema = current_schema.as_ref();
+    #[cfg(feature = "grouping_sets")]
+    let n_keys = keys.len();
     let mut keys = rewrite_projections(keys, current_schema, &[])?;
 
     // Initialize schema from keys
     let mut schema = expressions_to_schema(&keys, current_schema, Context::Default)?;
 
+    // The grouping sets refer to the keys by index, so the keys may not expand.
+    #[cfg(feature = "grouping_sets")]
+    if let Some(grouping_sets) = _options.grouping_sets.as_ref() {
+        polars_ensure!(
+            keys.len() == n_keys,
+            InvalidOperation: "every key of grouping sets must produce a single column"
+        );
+        polars_ensure!(
+            !grouping_sets.sets.is_empty(),
+            InvalidOperation: "grouping sets require at least one set"
+        );
+        polars_ensure!(
+            grouping_sets.sets.iter().flatten().all(|&i| i < keys.len()),
+            ComputeError: "grouping set refers to a key that does not exist"
+        );
+        if let Some(name) = &grouping_sets.grouping_id {
+            polars_ensure!(
+                keys.len() <= 64,
+                InvalidOperation: "the grouping id supports at most 64 keys, got {}", keys.len()
+            );
+            polars_ensure!(!schema.contains(name), duplicate = name);
+            schema.with_column(name.as_ref().into(), DataType::UInt64);
+        }
+        if let Some(name) = &grouping_sets.level {
+            polars_ensure!(!schema.contains(name), duplicate = name);
+            schema.with_column(name.as_ref().into(), DataType::UInt32);
+        }
+    }
+
     #[allow(unused_mut)]
     let mut pop_keys = false;
     // Add dynamic groupby index column(s)
@@ -805,6 +965,11 @@ fn resolve_group_by(
     // Make sure aggregation columns do not contain keys or index columns
     if schema.len() < (keys_index_len + aggs.len()) {
         let mut names = PlHashSet::with_capacity(schema.len());
+        #[cfg(feature = "grouping_sets")]
+        if let Some(grouping_sets) = _options.grouping_sets.as_ref() {
+            names.extend(grouping_sets.grouping_id.clone());
+            names.extend(grouping_sets.level.clone());
+        }
         for expr in aggs.iter().chain(keys.iter()) {
             let name = expr_output_name(expr)?;
             polars_ensure!(names.insert(name.clone()), duplicate = name)
@@ -227,6 +227,36 @@ fn to_aexpr_impl(expr: Expr, arena: &mut Arena<AExpr>, state: &mut ConversionSta
                     quantile: to_aexpr_impl_materialized_lit(owned(quantile), arena, state),
                     interpol,
                 },
+                AggExpr::ApproxQuantile {
+                    expr,
+                    quantile,
+                    accuracy,
+                } => IRAggExpr::ApproxQuantile {
+                    expr: to_aexpr_impl_materialized_lit(owned(expr), arena, state),
+                    quantile: to_aexpr_impl_materialized_lit(owned(quantile), arena, state),
+                    accuracy,
+                },
+                AggExpr::Quantiles { expr, options } => IRAggExpr::Quantiles {
+                    expr: to_aexpr_impl_materialized_lit(owned(expr), arena, state),
+                    options,
+                },
+                AggExpr::StrJoin { expr, options } => IRAggExpr::StrJoin {
+                    expr: to_aexpr_impl_materialized_lit(owned(expr), arena, state),
+                    options,
+                },
+                AggExpr::FirstBy { expr, by } => IRAggExpr::FirstBy {
+                    expr: to_aexpr_impl_materialized_lit(owned(expr), arena, state),
+                    by: to_aexpr_impl_materialized_lit(owned(by), arena, state),
+                },
+                AggExpr::LastBy { expr, by } => IRAggExpr::LastBy {
+                    expr: to_aexpr_impl_materialized_lit(owned(expr), arena, state),
+                    by: to_aexpr_impl_materialized_lit(owned(by), arena, state),
+                },
+                #[cfg(feature = "ffi_plugin")]
+                AggExpr::Plugin { expr, function } => IRAggExpr::Plugin {
+                    expr: to_aexpr_impl_materialized_lit(owned(expr), arena, state),
+                    function,
+                },
                 AggExpr::Sum(expr) => {
                     IRAggExpr::Sum(to_aexpr_impl_materialized_lit(owned(expr), arena, state))
                 },
@@ -332,11 +362,20 @@ fn to_aexpr_impl(expr: Expr, arena: &mut Arena<AExpr>, state: &mut ConversionSta
             partition_by,
             order_by,
             options,
-        } => AExpr::Window {
-            function: to_aexpr_impl(owned(function), arena, state),
-            partition_by: to_aexprs(partition_by, arena, state),
-            order_by: order_by.map(|(e, options)| (to_aexpr_impl(owned(e), arena, state), options)),
-            options,
+        } => {
+            #[cfg(feature = "fill_null_by")]
+            if order_by.is_none() {
+                if let Some(expr) = fill_null_over(&function, &partition_by, &options) {
+                    return to_aexpr_impl(expr, arena, state);
+                }
+            }
+            AExpr::Window {
+                function: to_aexpr_impl(owned(function), arena, state),
+                partition_by: to_aexprs(partition_by, arena, state),
+                order_by: order_by
+                    .map(|(e, options)| (to_aexpr_impl(owned(e), arena, state), options)),
+                options,
+            }
         },
         Expr::Slice {
             input,
@@ -374,3 +413,36 @@ fn to_aexpr_impl(expr: Expr, arena: &mut Arena<AExpr>, state: &mut ConversionSta
     };
     arena.add(v)
 }
+
+/// Rewrite a forward or backward fill over groups to a single fill of all groups, instead of
+/// filling every group separately.
+#[cfg(feature = "fill_null_by")]
+fn fill_null_over(function: &Expr, partition_by: &[Expr], options: &WindowType) -> Option<Expr> {
+    if !matches!(options, WindowType::Over(WindowMapping::GroupsToRows)) {
+        return None;
+    }
+    let Expr::Function {
+        input,
+        function,
+        options,
+    } = function
+    else {
+        return None;
+    };
+    let (strategy, max_gap) = match function {
+        FunctionExpr::FillNullWithStrategy(
+            strategy @ (FillNullStrategy::Forward(_) | FillNullStrategy::Backward(_)),
+        ) => (*strategy, None),
+        FunctionExpr::FillNullBy { strategy, max_gap } => (*strategy, *max_gap),
+        _ => return None,
+    };
+    // The inputs are evaluated on all rows at once, so they must not depend on the groups.
+    if !input.iter().all(|e| matches!(e, Expr::Column(_))) {
+        return None;
+    }
+    Some(Expr::Function {
+        input: input.iter().chain(partition_by).cloned().collect(),
+        function: FunctionExpr::FillNullBy { strategy, max_gap },
+        options: *options,
+    })
+}
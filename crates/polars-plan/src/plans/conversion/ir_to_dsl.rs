@@ -140,6 +140,63 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 }
                 .into()
             },
+            IRAggExpr::ApproxQuantile {
+                expr,
+                quantile,
+                accuracy,
+            } => {
+                let expr = node_to_expr(expr, expr_arena);
+                let quantile = node_to_expr(quantile, expr_arena);
+                AggExpr::ApproxQuantile {
+                    expr: Arc::new(expr),
+                    quantile: Arc::new(quantile),
+                    accuracy,
+                }
+                .into()
+            },
+            IRAggExpr::Quantiles { expr, options } => {
+                let expr = node_to_expr(expr, expr_arena);
+                AggExpr::Quantiles {
+                    expr: Arc::new(expr),
+                    options,
+                }
+                .into()
+            },
+            IRAggExpr::StrJoin { expr, options } => {
+                let expr = node_to_expr(expr, expr_arena);
+                AggExpr::StrJoin {
+                    expr: Arc::new(expr),
+                    options,
+                }
+                .into()
+            },
+            IRAggExpr::FirstBy { expr, by } => {
+                let expr = node_to_expr(expr, expr_arena);
+                let by = node_to_expr(by, expr_arena);
+                AggExpr::FirstBy {
+                    expr: Arc::new(expr),
+                    by: Arc::new(by),
+                }
+                .into()
+            },
+            IRAggExpr::LastBy { expr, by } => {
+                let expr = node_to_expr(expr, expr_arena);
+                let by = node_to_expr(by, expr_arena);
+                AggExpr::LastBy {
+                    expr: Arc::new(expr),
+                    by: Arc::new(by),
+                }
+                .into()
+            },
+            #[cfg(feature = "ffi_plugin")]
+            IRAggExpr::Plugin { expr, function } => {
+                let expr = node_to_expr(expr, expr_arena);
+                AggExpr::Plugin {
+                    expr: Arc::new(expr),
+                    function,
+                }
+                .into()
+            },
             IRAggExpr::Sum(expr) => {
                 let exp = node_to_expr(expr, expr_arena);
                 AggExpr::Sum(Arc::new(exp)).into()
@@ -16,6 +16,70 @@ fn get_path(paths: &[PathBuf]) -> PolarsResult<&PathBuf> {
         .ok_or_else(|| polars_err!(ComputeError: "expected at least 1 path"))
 }
 
+/// Checks that the files of a scan exist before their schema and metadata are read.
+///
+/// The files are checked in one batch, so that a scan over many cloud files doesn't find a
+/// missing file only when it is read. Missing files are an error, unless the scan allows them,
+/// in which case they are dropped from the scan with a warning.
+pub(super) fn validate_paths(
+    paths: Arc<[PathBuf]>,
+    file_options: &FileScanOptions,
+    scan_type: &FileScan,
+) -> PolarsResult<Arc<[PathBuf]>> {
+    // A single missing file is already reported by the reader that resolves the schema.
+    if paths.len() < 2 && !file_options.allow_missing_files {
+        return Ok(paths);
+    }
+    let missing = polars_io::utils::missing_paths(&paths, scan_type.cloud_options())?;
+    if missing.is_empty() {
+        return Ok(paths);
+    }
+
+    const MAX_LISTED: usize = 5;
+    let mut listed = missing
+        .iter()
+        .take(MAX_LISTED)
+        .map(|&i| paths[i].display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    if missing.len() > MAX_LISTED {
+        listed.push_str(", ...");
+    }
+
+    if !file_options.allow_missing_files {
+        return Err(PolarsError::IO {
+            error: Arc::new(std::io::ErrorKind::NotFound.into()),
+            msg: Some(
+                format!(
+                    "{} of the {} files of the scan don't exist: {}",
+                    missing.len(),
+                    paths.len(),
+                    listed
+                )
+                .into(),
+            ),
+        });
+    }
+    polars_ensure!(
+        missing.len() < paths.len(),
+        ComputeError: "none of the {} files of the scan exist: {}", paths.len(), listed
+    );
+    polars_warn!(
+        "skipping {} of the {} files of the scan that don't exist: {}",
+        missing.len(),
+        paths.len(),
+        listed
+    );
+
+    let mut missing = missing.into_iter().peekable();
+    Ok(paths
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| missing.next_if_eq(i).is_none())
+        .map(|(_, path)| path.clone())
+        .collect())
+}
+
 #[cfg(any(feature = "parquet", feature = "ipc"))]
 fn prepare_output_schema(mut schema: Schema, row_index: Option<&RowIndex>) -> SchemaRef {
     if let Some(rc) = row_index {
@@ -95,24 +159,27 @@ pub(super) fn ipc_file_info(
 ) -> PolarsResult<(FileInfo, arrow::io::ipc::read::FileMetadata)> {
     let path = get_path(paths)?;
 
-    let metadata = if is_cloud_url(path) {
+    let (metadata, num_rows) = if is_cloud_url(path) {
         #[cfg(not(feature = "cloud"))]
         panic!("One or more of the cloud storage features ('aws', 'gcp', ...) must be enabled.");
 
         #[cfg(feature = "cloud")]
         {
             let uri = path.to_string_lossy();
-            get_runtime().block_on(async {
+            let metadata = get_runtime().block_on(async {
                 polars_io::ipc::IpcReaderAsync::from_uri(&uri, cloud_options)
                     .await?
                     .metadata()
                     .await
-            })?
+            })?;
+            (metadata, None)
         }
     } else {
-        arrow::io::ipc::read::read_file_metadata(&mut std::io::BufReader::new(
-            polars_utils::open_file(path)?,
-        ))?
+        let mut reader = std::io::BufReader::new(polars_utils::open_file(path)?);
+        let metadata = arrow::io::ipc::read::read_file_metadata(&mut reader)?;
+        // Only reads the headers of the record batches.
+        let num_rows = arrow::io::ipc::read::get_row_count(&mut reader)? as usize;
+        (metadata, Some(num_rows))
     };
     let file_info = FileInfo::new(
         prepare_output_schema(
@@ -120,7 +187,7 @@ pub(super) fn ipc_file_info(
             file_options.row_index.as_ref(),
         ),
         Some(Either::Left(Arc::clone(&metadata.schema))),
-        (None, 0),
+        (num_rows, num_rows.unwrap_or(0)),
     );
 
     Ok((file_info, metadata))
@@ -225,9 +292,12 @@ pub(super) fn csv_file_info(
                     (true, _) => schema_b,
                     (_, true) => schema_a,
                     _ => {
-                        let mut s = Arc::unwrap_or_clone(schema_a);
-                        s.to_supertype(&schema_b)?;
-                        Arc::new(s)
+                        // The columns of the files are matched by position.
+                        polars_ensure!(
+                            schema_a.iter_names().eq(schema_b.iter_names()),
+                            ComputeError: "the files of a CSV scan must have the same columns in the same order"
+                        );
+                        Arc::new(unify_schemas(&[schema_a, schema_b], Default::default())?)
                     },
                 }
             };
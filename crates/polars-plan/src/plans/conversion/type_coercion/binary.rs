@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::arithmetic::integer_decimal_dtype;
 #[cfg(feature = "dtype-categorical")]
 use polars_utils::matches_any_order;
 
@@ -143,6 +145,95 @@ fn process_struct_numeric_arithmetic(
     }
 }
 
+#[cfg(feature = "dtype-array")]
+// The number is applied to every element of the arrays, so it is cast to the supertype of
+// itself and the leaf type of the arrays, and the arrays keep their shape.
+fn process_array_numeric_arithmetic(
+    type_left: DataType,
+    type_right: DataType,
+    node_left: Node,
+    node_right: Node,
+    op: Operator,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Option<AExpr>> {
+    let (leaf, number) = match (&type_left, &type_right) {
+        (DataType::Array(_, _), number) => (type_left.leaf_dtype(), number),
+        (number, DataType::Array(_, _)) => (type_right.leaf_dtype(), number),
+        _ => unreachable!(),
+    };
+    let st = polars_core::utils::try_get_supertype(leaf, number)?;
+    let mut cast = |node: Node, dtype: &DataType| {
+        let to = if dtype.is_array() {
+            dtype.cast_leaf(st.clone())
+        } else {
+            st.clone()
+        };
+        if &to == dtype {
+            node
+        } else {
+            expr_arena.add(AExpr::Cast {
+                expr: node,
+                data_type: to,
+                options: CastOptions::NonStrict,
+            })
+        }
+    };
+    let new_node_left = cast(node_left, &type_left);
+    let new_node_right = cast(node_right, &type_right);
+    if new_node_left == node_left && new_node_right == node_right {
+        return Ok(None);
+    }
+    Ok(Some(AExpr::BinaryExpr {
+        left: new_node_left,
+        op,
+        right: new_node_right,
+    }))
+}
+
+/// The decimal type an operand of decimal arithmetic is cast to, if it can take part in it.
+#[cfg(feature = "dtype-decimal")]
+fn decimal_operand_dtype(dtype: &DataType) -> Option<DataType> {
+    match dtype {
+        DataType::Decimal(_, _) => Some(dtype.clone()),
+        dt => integer_decimal_dtype(dt),
+    }
+}
+
+#[cfg(feature = "dtype-decimal")]
+// Decimals keep their own precision and scale, as both decide the type of the result.
+// Integers are cast to the decimal that holds all their values.
+fn process_decimal_arithmetic(
+    type_left: DataType,
+    type_right: DataType,
+    node_left: Node,
+    node_right: Node,
+    op: Operator,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Option<AExpr>> {
+    let left_dtype = decimal_operand_dtype(&type_left).unwrap();
+    let right_dtype = decimal_operand_dtype(&type_right).unwrap();
+    if left_dtype == type_left && right_dtype == type_right {
+        return Ok(None);
+    }
+
+    let mut cast = |node: Node, from: DataType, to: DataType| {
+        if from == to {
+            node
+        } else {
+            expr_arena.add(AExpr::Cast {
+                expr: node,
+                data_type: to,
+                options: CastOptions::NonStrict,
+            })
+        }
+    };
+    Ok(Some(AExpr::BinaryExpr {
+        left: cast(node_left, type_left, left_dtype),
+        op,
+        right: cast(node_right, type_right, right_dtype),
+    }))
+}
+
 #[cfg(any(
     feature = "dtype-date",
     feature = "dtype-datetime",
@@ -282,9 +373,32 @@ pub(super) fn process_binary(
                     type_left, type_right, node_left, node_right, op, expr_arena,
                 )
             },
+            #[cfg(feature = "dtype-array")]
+            (Array(_, _), a) | (a, Array(_, _)) if a.is_numeric() => {
+                return process_array_numeric_arithmetic(
+                    type_left, type_right, node_left, node_right, op, expr_arena,
+                )
+            },
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(_, _), _) | (_, Decimal(_, _))
+                if op != Operator::FloorDivide
+                    && decimal_operand_dtype(&type_left).is_some()
+                    && decimal_operand_dtype(&type_right).is_some() =>
+            {
+                return process_decimal_arithmetic(
+                    type_left, type_right, node_left, node_right, op, expr_arena,
+                )
+            },
             _ => {},
         }
     } else if compares_cat_to_string(&type_left, &type_right, op) {
+        // The strings are compared to the categories, in strict mode that has to be explicit.
+        let (string, category) = if type_left.is_categorical() || type_left.is_enum() {
+            ((node_right, &type_right), (node_left, &type_left))
+        } else {
+            ((node_left, &type_left), (node_right, &type_right))
+        };
+        check_strict_supertype(expr_arena, string, category, category.1)?;
         return Ok(None);
     }
 
@@ -295,6 +409,10 @@ pub(super) fn process_binary(
     if is_cat_str_binary(&type_left, &type_right) {
         st = String
     }
+    let left_operand = (node_left, &type_left);
+    let right_operand = (node_right, &type_right);
+    check_strict_supertype(expr_arena, left_operand, right_operand, &st)?;
+    check_strict_supertype(expr_arena, right_operand, left_operand, &st)?;
 
     // only cast if the type is not already the super type.
     // this can prevent an expensive flattening and subsequent aggregation
@@ -64,6 +64,31 @@ fn modify_supertype(
     st
 }
 
+/// In strict supertype mode an operand may not be cast implicitly to the supertype it shares
+/// with `other`. Literals are exempt, they take the type of the expression they're used with.
+fn check_strict_supertype(
+    expr_arena: &Arena<AExpr>,
+    (node, dtype): (Node, &DataType),
+    (other, other_dtype): (Node, &DataType),
+    supertype: &DataType,
+) -> PolarsResult<()> {
+    if !polars_core::config::strict_supertypes()
+        || dtype == supertype
+        || dtype.is_null()
+        || matches!(expr_arena.get(node), AExpr::Literal(_))
+    {
+        return Ok(());
+    }
+    let expr = node_to_expr(node, expr_arena);
+    let other = node_to_expr(other, expr_arena);
+    polars_bail!(
+        SchemaMismatch:
+        "strict supertypes: `{}` of type {} would be cast to {} to match `{}` of type {}; \
+        add an explicit cast, e.g. `{}.cast({})`",
+        expr, dtype, supertype, other, other_dtype, expr, supertype
+    )
+}
+
 fn get_input(lp_arena: &Arena<IR>, lp_node: Node) -> UnitVec<Node> {
     let plan = lp_arena.get(lp_node);
     let mut inputs: UnitVec<Node> = unitvec!();
@@ -150,6 +175,10 @@ impl OptimizationRule for TypeCoercionRule {
                 }
                 let st = unpack!(get_supertype(&type_true, &type_false));
                 let st = modify_supertype(st, truthy, falsy, &type_true, &type_false);
+                let true_operand = (truthy_node, &type_true);
+                let false_operand = (falsy_node, &type_false);
+                check_strict_supertype(expr_arena, true_operand, false_operand, &st)?;
+                check_strict_supertype(expr_arena, false_operand, true_operand, &st)?;
 
                 // only cast if the type is not already the super type.
                 // this can prevent an expensive flattening and subsequent aggregation
@@ -311,6 +340,10 @@ impl OptimizationRule for TypeCoercionRule {
                 let super_type = unpack!(get_supertype(&type_left, &type_fill_value));
                 let super_type =
                     modify_supertype(super_type, left, fill_value, &type_left, &type_fill_value);
+                let left_operand = (left_node, &type_left);
+                let fill_operand = (fill_value_node, &type_fill_value);
+                check_strict_supertype(expr_arena, left_operand, fill_operand, &super_type)?;
+                check_strict_supertype(expr_arena, fill_operand, left_operand, &super_type)?;
 
                 let new_node_left = if type_left != super_type {
                     expr_arena.add(AExpr::Cast {
@@ -401,9 +434,6 @@ impl OptimizationRule for TypeCoercionRule {
                     polars_bail!(InvalidOperation: "could not determine supertype of: {}", format_list!(dtypes));
                 }
 
-                let function = function.clone();
-                let input = input.clone();
-
                 match super_type {
                     DataType::Unknown(UnknownKind::Float) => super_type = DataType::Float64,
                     DataType::Unknown(UnknownKind::Int(v)) => {
@@ -412,6 +442,27 @@ impl OptimizationRule for TypeCoercionRule {
                     _ => {},
                 }
 
+                for (e, dtype) in input.iter().zip(&dtypes) {
+                    #[cfg(feature = "dtype-categorical")]
+                    if matches!(super_type, DataType::Categorical(_, _)) && dtype.is_string() {
+                        continue;
+                    }
+                    // Name an input that has another type than this one.
+                    let (other, other_dtype) = input
+                        .iter()
+                        .zip(&dtypes)
+                        .find(|(_, other_dtype)| *other_dtype != dtype)
+                        .unwrap();
+                    check_strict_supertype(
+                        expr_arena,
+                        (e.node(), dtype),
+                        (other.node(), other_dtype),
+                        &super_type,
+                    )?;
+                }
+
+                let function = function.clone();
+                let input = input.clone();
                 let input = input
                     .into_iter()
                     .zip(dtypes)
@@ -40,6 +40,8 @@ pub enum FileScan {
         options: Arc<AnonymousScanOptions>,
         function: Arc<dyn AnonymousScan>,
     },
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Source { source: Arc<dyn ScanSource> },
 }
 
 impl PartialEq for FileScan {
@@ -84,6 +86,7 @@ impl PartialEq for FileScan {
             ) => l == r && c_l == c_r,
             #[cfg(feature = "json")]
             (FileScan::NDJson { options: l }, FileScan::NDJson { options: r }) => l == r,
+            (FileScan::Source { source: l }, FileScan::Source { source: r }) => Arc::ptr_eq(l, r),
             _ => false,
         }
     }
@@ -124,11 +127,33 @@ impl Hash for FileScan {
             #[cfg(feature = "json")]
             FileScan::NDJson { options } => options.hash(state),
             FileScan::Anonymous { options, .. } => options.hash(state),
+            FileScan::Source { source } => (Arc::as_ptr(source) as *const () as usize).hash(state),
         }
     }
 }
 
 impl FileScan {
+    /// Name of the scan as shown in the query plan.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Source { source } => source.name(),
+            _ => self.into(),
+        }
+    }
+
+    /// The cloud options used to access the files of the scan.
+    pub fn cloud_options(&self) -> Option<&polars_io::cloud::CloudOptions> {
+        match self {
+            #[cfg(feature = "csv")]
+            Self::Csv { cloud_options, .. } => cloud_options.as_ref(),
+            #[cfg(feature = "parquet")]
+            Self::Parquet { cloud_options, .. } => cloud_options.as_ref(),
+            #[cfg(feature = "ipc")]
+            Self::Ipc { cloud_options, .. } => cloud_options.as_ref(),
+            _ => None,
+        }
+    }
+
     pub(crate) fn remove_metadata(&mut self) {
         match self {
             #[cfg(feature = "parquet")]
@@ -166,6 +191,7 @@ impl FileScan {
             Self::Parquet { .. } => true,
             #[cfg(feature = "json")]
             Self::NDJson { .. } => false,
+            Self::Source { .. } => true,
             #[allow(unreachable_patterns)]
             _ => false,
         }
@@ -26,6 +26,16 @@ impl fmt::Debug for Expr {
                         function, options.index_column, options.offset, options.period
                     )
                 },
+                WindowType::Frame(frame) => {
+                    if let Some((order_by, _)) = order_by {
+                        write!(f, "{function:?}.over(partition_by: {partition_by:?}, order_by: {order_by:?}, frame: {frame})")
+                    } else {
+                        write!(
+                            f,
+                            "{function:?}.over(partition_by: {partition_by:?}, frame: {frame})"
+                        )
+                    }
+                },
                 _ => {
                     if let Some((order_by, _)) = order_by {
                         write!(f, "{function:?}.over(partition_by: {partition_by:?}, order_by: {order_by:?})")
@@ -120,6 +130,15 @@ impl fmt::Debug for Expr {
                     Var(expr, _) => write!(f, "{expr:?}.var()"),
                     Std(expr, _) => write!(f, "{expr:?}.std()"),
                     Quantile { expr, .. } => write!(f, "{expr:?}.quantile()"),
+                    ApproxQuantile { expr, .. } => write!(f, "{expr:?}.approx_quantile()"),
+                    Quantiles { expr, .. } => write!(f, "{expr:?}.quantiles()"),
+                    StrJoin { expr, .. } => write!(f, "{expr:?}.str.join_agg()"),
+                    FirstBy { expr, by } => write!(f, "{expr:?}.first_by({by:?})"),
+                    LastBy { expr, by } => write!(f, "{expr:?}.last_by({by:?})"),
+                    #[cfg(feature = "ffi_plugin")]
+                    Plugin { expr, function } => {
+                        write!(f, "{expr:?}.{}:{}()", function.lib, function.symbol)
+                    },
                 }
             },
             Cast {
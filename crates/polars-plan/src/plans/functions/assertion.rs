@@ -0,0 +1,140 @@
+use super::*;
+
+/// A check on the data flowing through the plan. Assertions don't modify the
+/// [`DataFrame`]; they raise an error if the check does not hold.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Assertion {
+    /// The frame has at least one row.
+    NonEmpty,
+    /// The frame has exactly this number of rows.
+    RowCount(IdxSize),
+    /// No two rows have the same values in `subset`. If `subset` is `None`, all
+    /// columns are considered.
+    Unique { subset: Option<Arc<[Arc<str>]>> },
+    /// The non-null values in `column` are sorted.
+    Sorted { column: Arc<str>, descending: bool },
+}
+
+impl Assertion {
+    /// The columns this assertion reads. Returns `None` if it depends on all columns.
+    pub(super) fn columns(&self) -> Option<Cow<[Arc<str>]>> {
+        use Assertion::*;
+        match self {
+            NonEmpty | RowCount(_) => Some(Cow::Borrowed(&[])),
+            Unique { subset } => subset.as_deref().map(Cow::Borrowed),
+            Sorted { column, .. } => Some(Cow::Owned(vec![column.clone()])),
+        }
+    }
+
+    pub(super) fn check_schema(&self, schema: &Schema) -> PolarsResult<()> {
+        if let Some(columns) = self.columns() {
+            for name in columns.iter() {
+                schema.try_get(name)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn check(&self, df: &DataFrame) -> PolarsResult<()> {
+        use Assertion::*;
+        match self {
+            NonEmpty => {
+                polars_ensure!(
+                    df.height() > 0,
+                    ComputeError: "assertion failed: expected a non-empty frame, got 0 rows"
+                );
+            },
+            RowCount(expected) => {
+                let height = df.height();
+                polars_ensure!(
+                    height == *expected as usize,
+                    ComputeError: "assertion failed: expected {} rows, got {}", expected, height
+                );
+            },
+            Unique { subset } => check_unique(df, subset.as_deref())?,
+            Sorted { column, descending } => check_sorted(df, column, *descending)?,
+        }
+        Ok(())
+    }
+}
+
+fn check_unique(df: &DataFrame, subset: Option<&[Arc<str>]>) -> PolarsResult<()> {
+    let keys = match subset {
+        Some(subset) => df.select(subset.iter().map(|s| s.as_ref()))?,
+        None => df.clone(),
+    };
+    let duplicated = keys.is_duplicated()?;
+    let n_duplicated = duplicated.sum().unwrap_or(0);
+    if n_duplicated == 0 {
+        return Ok(());
+    }
+    let first = duplicated
+        .into_iter()
+        .position(|v| v == Some(true))
+        .unwrap();
+    let values = keys.get(first).unwrap();
+    polars_bail!(
+        ComputeError:
+        "assertion failed: expected unique rows on {:?}, found {} duplicated rows; first duplicate at row {} with key {:?}",
+        keys.get_column_names(), n_duplicated, first, values
+    )
+}
+
+fn check_sorted(df: &DataFrame, column: &str, descending: bool) -> PolarsResult<()> {
+    let s = df.column(column)?;
+    let (s, row_idx) = if s.null_count() > 0 {
+        let valid = s.is_not_null();
+        let row_idx = IdxCa::from_vec("", (0..s.len() as IdxSize).collect());
+        (s.filter(&valid)?, Some(row_idx.filter(&valid)?))
+    } else {
+        (s.clone(), None)
+    };
+    if s.len() < 2 {
+        return Ok(());
+    }
+    let prev = s.slice(0, s.len() - 1);
+    let next = s.slice(1, s.len() - 1);
+    let violations = if descending {
+        prev.lt(&next)?
+    } else {
+        prev.gt(&next)?
+    };
+    let Some(i) = violations.into_iter().position(|v| v == Some(true)) else {
+        return Ok(());
+    };
+    let row = match &row_idx {
+        Some(row_idx) => row_idx.get(i + 1).unwrap() as usize,
+        None => i + 1,
+    };
+    polars_bail!(
+        ComputeError:
+        "assertion failed: column '{}' is not sorted in {} order; value {} at row {} follows {}",
+        column,
+        if descending { "descending" } else { "ascending" },
+        s.get(i + 1)?,
+        row,
+        s.get(i)?
+    )
+}
+
+impl Display for Assertion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use Assertion::*;
+        match self {
+            NonEmpty => write!(f, "ASSERT NON EMPTY"),
+            RowCount(n) => write!(f, "ASSERT ROW COUNT == {n}"),
+            Unique { subset: None } => write!(f, "ASSERT UNIQUE"),
+            Unique {
+                subset: Some(subset),
+            } => {
+                write!(f, "ASSERT UNIQUE by:")?;
+                fmt_column_delimited(f, subset.as_ref(), "[", "]")
+            },
+            Sorted { column, descending } => {
+                let order = if *descending { "DESC" } else { "ASC" };
+                write!(f, "ASSERT SORTED {column} {order}")
+            },
+        }
+    }
+}
@@ -73,7 +73,7 @@ pub fn count_rows(paths: &Arc<[PathBuf]>, scan_type: &FileScan) -> PolarsResult<
             ),
             #[cfg(feature = "json")]
             FileScan::NDJson { options } => count_rows_ndjson(paths),
-            FileScan::Anonymous { .. } => {
+            FileScan::Anonymous { .. } | FileScan::Source { .. } => {
                 unreachable!()
             },
         };
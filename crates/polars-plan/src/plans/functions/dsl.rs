@@ -12,9 +12,13 @@ pub enum DslFunction {
     Unpivot {
         args: UnpivotArgs,
     },
+    #[cfg(feature = "pivot")]
+    Pivot(PivotArgs),
     RowIndex {
         name: Arc<str>,
         offset: Option<IdxSize>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        over: Option<Arc<[Arc<str>]>>,
     },
     Rename {
         existing: Arc<[SmartString]>,
@@ -83,11 +87,37 @@ impl DslFunction {
                 args: Arc::new(args),
                 schema: Default::default(),
             },
+            DslFunction::FunctionNode(FunctionNode::Assert { assertion }) => {
+                assertion.check_schema(input_schema)?;
+                FunctionNode::Assert { assertion }
+            },
+            DslFunction::FunctionNode(FunctionNode::Sample { args }) => {
+                for name in &args.by {
+                    input_schema.try_get(name)?;
+                }
+                if let Some(name) = &args.weights {
+                    let dtype = input_schema.try_get(name)?;
+                    polars_ensure!(
+                        dtype.is_numeric(),
+                        InvalidOperation: "sample weights in column '{}' must be numeric, got {}",
+                        name, dtype
+                    );
+                }
+                FunctionNode::Sample { args }
+            },
             DslFunction::FunctionNode(func) => func,
-            DslFunction::RowIndex { name, offset } => FunctionNode::RowIndex {
-                name,
-                offset,
-                schema: Default::default(),
+            DslFunction::RowIndex { name, offset, over } => {
+                if let Some(over) = &over {
+                    for name in over.iter() {
+                        polars_ensure!(input_schema.contains(name), ColumnNotFound: "{name}");
+                    }
+                }
+                FunctionNode::RowIndex {
+                    name,
+                    offset,
+                    over,
+                    schema: Default::default(),
+                }
             },
             DslFunction::Rename { existing, new } => {
                 let swapping = new.iter().any(|name| input_schema.get(name).is_some());
@@ -104,6 +134,8 @@ impl DslFunction {
                     schema: Default::default(),
                 }
             },
+            #[cfg(feature = "pivot")]
+            DslFunction::Pivot(_) => panic!("impl error"),
             DslFunction::Stats(_) | DslFunction::FillNan(_) | DslFunction::Drop(_) => {
                 // We should not reach this.
                 panic!("impl error")
@@ -126,6 +158,8 @@ impl Display for DslFunction {
             FunctionNode(inner) => write!(f, "{inner}"),
             Explode { .. } => write!(f, "EXPLODE"),
             Unpivot { .. } => write!(f, "UNPIVOT"),
+            #[cfg(feature = "pivot")]
+            Pivot(_) => write!(f, "PIVOT"),
             RowIndex { .. } => write!(f, "WITH ROW INDEX"),
             Stats(_) => write!(f, "STATS"),
             FillNan(_) => write!(f, "FILL NAN"),
@@ -1,7 +1,10 @@
+mod assertion;
 mod count;
 mod dsl;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
+#[cfg(feature = "pivot")]
+mod pivot;
 #[cfg(feature = "python")]
 mod python_udf;
 mod rename;
@@ -13,7 +16,10 @@ use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
+pub use assertion::Assertion;
 pub use dsl::*;
+#[cfg(feature = "pivot")]
+pub use pivot::PivotArgs;
 use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -38,6 +44,12 @@ pub enum FunctionNode {
         projection_pd: bool,
         streamable: bool,
         validate_output: bool,
+        /// The input columns read by the function, if declared.
+        #[cfg_attr(feature = "serde", serde(default))]
+        columns_used: Option<Arc<[ColumnName]>>,
+        /// Every row of `columns_used` maps to one output row, the output is added to the input.
+        #[cfg_attr(feature = "serde", serde(default))]
+        row_independent: bool,
     },
     #[cfg_attr(feature = "serde", serde(skip))]
     Opaque {
@@ -51,6 +63,10 @@ pub enum FunctionNode {
         // used for formatting
         #[cfg_attr(feature = "serde", serde(skip))]
         fmt_str: &'static str,
+        /// The input columns read by the function, if declared.
+        columns_used: Option<Arc<[ColumnName]>>,
+        /// Every row of `columns_used` maps to one output row, the output is added to the input.
+        row_independent: bool,
     },
     Count {
         paths: Arc<[PathBuf]>,
@@ -101,6 +117,17 @@ pub enum FunctionNode {
         #[cfg_attr(feature = "serde", serde(skip))]
         schema: CachedSchema,
         offset: Option<IdxSize>,
+        /// Count the rows within the groups of these columns.
+        #[cfg_attr(feature = "serde", serde(default))]
+        over: Option<Arc<[Arc<str>]>>,
+    },
+    /// Validate the data without modifying it.
+    Assert {
+        assertion: Assertion,
+    },
+    /// Sample rows, possibly per stratum or weighted.
+    Sample {
+        args: Arc<SampleArgs>,
     },
 }
 
@@ -126,7 +153,20 @@ impl PartialEq for FunctionNode {
             ) => existing_l == existing_r && new_l == new_r,
             (Explode { columns: l, .. }, Explode { columns: r, .. }) => l == r,
             (Unpivot { args: l, .. }, Unpivot { args: r, .. }) => l == r,
-            (RowIndex { name: l, .. }, RowIndex { name: r, .. }) => l == r,
+            (
+                RowIndex {
+                    name: name_l,
+                    over: over_l,
+                    ..
+                },
+                RowIndex {
+                    name: name_r,
+                    over: over_r,
+                    ..
+                },
+            ) => name_l == name_r && over_l == over_r,
+            (Assert { assertion: l }, Assert { assertion: r }) => l == r,
+            (Sample { args: l }, Sample { args: r }) => l == r,
             #[cfg(feature = "merge_sorted")]
             (MergeSorted { column: l }, MergeSorted { column: r }) => l == r,
             _ => false,
@@ -170,10 +210,14 @@ impl Hash for FunctionNode {
                 name,
                 schema: _,
                 offset,
+                over,
             } => {
                 name.hash(state);
                 offset.hash(state);
+                over.hash(state);
             },
+            FunctionNode::Assert { assertion } => assertion.hash(state),
+            FunctionNode::Sample { args } => args.hash(state),
         }
     }
 }
@@ -191,7 +235,8 @@ impl FunctionNode {
             Opaque { streamable, .. } => *streamable,
             #[cfg(feature = "python")]
             OpaquePython { streamable, .. } => *streamable,
-            RowIndex { .. } => false,
+            // Reservoir samples are taken by a sink of the streaming engine.
+            RowIndex { .. } | Assert { .. } | Sample { .. } => false,
         }
     }
 
@@ -202,6 +247,7 @@ impl FunctionNode {
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
             Explode { .. } | Unpivot { .. } => true,
+            Sample { args } => args.with_replacement,
             _ => false,
         }
     }
@@ -209,13 +255,23 @@ impl FunctionNode {
     pub(crate) fn allow_predicate_pd(&self) -> bool {
         use FunctionNode::*;
         match self {
-            Opaque { predicate_pd, .. } => *predicate_pd,
+            Opaque {
+                predicate_pd,
+                row_independent,
+                ..
+            } => *predicate_pd || *row_independent,
             #[cfg(feature = "python")]
-            OpaquePython { predicate_pd, .. } => *predicate_pd,
+            OpaquePython {
+                predicate_pd,
+                row_independent,
+                ..
+            } => *predicate_pd || *row_independent,
             Rechunk | Unnest { .. } | Rename { .. } | Explode { .. } | Unpivot { .. } => true,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
-            RowIndex { .. } | Count { .. } => false,
+            // Filtering before the assertion would change what is asserted.
+            // Filtering before sampling changes the population.
+            RowIndex { .. } | Count { .. } | Assert { .. } | Sample { .. } => false,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -234,11 +290,112 @@ impl FunctionNode {
             | Unpivot { .. } => true,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
-            RowIndex { .. } => true,
+            RowIndex { .. } | Sample { .. } => true,
+            Assert { assertion } => assertion.columns().is_some(),
             Pipeline { .. } => unimplemented!(),
         }
     }
 
+    /// The input columns an opaque function declared to read. Only these have to be
+    /// provided by its input, regardless of what is projected from its output.
+    pub(crate) fn columns_used(&self) -> Option<&[ColumnName]> {
+        use FunctionNode::*;
+        match self {
+            Opaque { columns_used, .. } => columns_used.as_deref(),
+            #[cfg(feature = "python")]
+            OpaquePython { columns_used, .. } => columns_used.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Whether an opaque function declared to be row-independent. It is then called on its
+    /// `columns_used` only and its output columns are added to its input, like `with_columns`.
+    pub(crate) fn is_row_independent(&self) -> bool {
+        use FunctionNode::*;
+        match self {
+            Opaque {
+                row_independent, ..
+            } => *row_independent,
+            #[cfg(feature = "python")]
+            OpaquePython {
+                row_independent, ..
+            } => *row_independent,
+            _ => false,
+        }
+    }
+
+    /// The schema of the output of a row-independent function, before it is added to its input.
+    fn row_independent_output_schema(
+        &self,
+        input_schema: &SchemaRef,
+    ) -> PolarsResult<Option<SchemaRef>> {
+        use FunctionNode::*;
+        match self {
+            Opaque {
+                schema: Some(schema_fn),
+                ..
+            } => {
+                let columns_used = self.columns_used().unwrap_or_default();
+                let used_schema = columns_used
+                    .iter()
+                    .map(|name| input_schema.try_get_field(name))
+                    .collect::<PolarsResult<Schema>>()?;
+                schema_fn.get_schema(&Arc::new(used_schema)).map(Some)
+            },
+            #[cfg(feature = "python")]
+            OpaquePython { schema, .. } => Ok(schema.clone()),
+            _ => Ok(None),
+        }
+    }
+
+    /// The columns written by a row-independent function. All other columns of its input are
+    /// passed around it unchanged. Without an output schema, it is assumed to overwrite the
+    /// columns it uses.
+    pub(crate) fn row_independent_outputs(
+        &self,
+        input_schema: &SchemaRef,
+    ) -> PolarsResult<Vec<ColumnName>> {
+        Ok(match self.row_independent_output_schema(input_schema)? {
+            Some(schema) => schema
+                .iter_names()
+                .map(|name| ColumnName::from(name.as_str()))
+                .collect(),
+            None => self.columns_used().unwrap_or_default().to_vec(),
+        })
+    }
+
+    /// Declare the input columns read by an opaque function, and whether it is row-independent.
+    pub fn set_columns_used(
+        &mut self,
+        columns: Arc<[ColumnName]>,
+        row_independent: bool,
+    ) -> PolarsResult<()> {
+        use FunctionNode::*;
+        match self {
+            Opaque {
+                columns_used,
+                row_independent: row_independent_,
+                ..
+            } => {
+                *columns_used = Some(columns);
+                *row_independent_ = row_independent;
+            },
+            #[cfg(feature = "python")]
+            OpaquePython {
+                columns_used,
+                row_independent: row_independent_,
+                ..
+            } => {
+                *columns_used = Some(columns);
+                *row_independent_ = row_independent;
+            },
+            _ => polars_bail!(
+                InvalidOperation: "only user defined functions can declare the columns they use, got '{}'", self
+            ),
+        }
+        Ok(())
+    }
+
     pub(crate) fn additional_projection_pd_columns(&self) -> Cow<[Arc<str>]> {
         use FunctionNode::*;
         match self {
@@ -246,12 +403,52 @@ impl FunctionNode {
             Explode { columns, .. } => Cow::Borrowed(columns.as_ref()),
             #[cfg(feature = "merge_sorted")]
             MergeSorted { column, .. } => Cow::Owned(vec![column.clone()]),
+            Assert { assertion } => assertion.columns().unwrap_or_default(),
+            RowIndex {
+                over: Some(over), ..
+            } => Cow::Borrowed(over.as_ref()),
+            Sample { args } => Cow::Owned(
+                args.by
+                    .iter()
+                    .chain(&args.weights)
+                    .map(|name| Arc::from(name.as_str()))
+                    .collect(),
+            ),
             _ => Cow::Borrowed(&[]),
         }
     }
 
+    fn evaluate_row_independent(&self, mut df: DataFrame) -> PolarsResult<DataFrame> {
+        use FunctionNode::*;
+        let columns_used = self.columns_used().unwrap_or_default();
+        let used = df.select(columns_used.iter().map(|name| name.as_ref()))?;
+        let out = match self {
+            Opaque { function, .. } => function.call_udf(used)?,
+            #[cfg(feature = "python")]
+            OpaquePython {
+                function,
+                validate_output,
+                schema,
+                ..
+            } => python_udf::call_python_udf(function, used, *validate_output, schema.as_deref())?,
+            _ => unreachable!(),
+        };
+        polars_ensure!(
+            out.height() == df.height(),
+            ComputeError: "row-independent function returned {} rows for {} input rows",
+            out.height(), df.height()
+        );
+        for s in out.get_columns() {
+            df.with_column(s.clone())?;
+        }
+        Ok(df)
+    }
+
     pub fn evaluate(&self, mut df: DataFrame) -> PolarsResult<DataFrame> {
         use FunctionNode::*;
+        if self.is_row_independent() {
+            return self.evaluate_row_independent(df);
+        }
         match self {
             Opaque { function, .. } => function.call_udf(df),
             #[cfg(feature = "python")]
@@ -299,7 +496,17 @@ impl FunctionNode {
                 let args = (**args).clone();
                 df.unpivot2(args)
             },
-            RowIndex { name, offset, .. } => df.with_row_index(name.as_ref(), *offset),
+            RowIndex {
+                name, offset, over, ..
+            } => match over {
+                None => df.with_row_index(name.as_ref(), *offset),
+                Some(over) => df.with_row_index_over(name.as_ref(), over.iter(), *offset),
+            },
+            Assert { assertion } => {
+                assertion.check(&df)?;
+                Ok(df)
+            },
+            Sample { args } => df.sample_by(args),
         }
     }
 
@@ -354,7 +561,22 @@ impl Display for FunctionNode {
             Rename { .. } => write!(f, "RENAME"),
             Explode { .. } => write!(f, "EXPLODE"),
             Unpivot { .. } => write!(f, "UNPIVOT"),
-            RowIndex { .. } => write!(f, "WITH ROW INDEX"),
+            RowIndex { over: None, .. } => write!(f, "WITH ROW INDEX"),
+            RowIndex {
+                over: Some(over), ..
+            } => {
+                write!(f, "WITH ROW INDEX over:")?;
+                fmt_column_delimited(f, over.as_ref(), "[", "]")
+            },
+            Assert { assertion } => write!(f, "{assertion}"),
+            Sample { args } => {
+                write!(f, "SAMPLE")?;
+                if !args.by.is_empty() {
+                    write!(f, " by:")?;
+                    fmt_column_delimited(f, &args.by, "[", "]")?;
+                }
+                Ok(())
+            },
         }
     }
 }
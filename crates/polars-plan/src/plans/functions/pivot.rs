@@ -0,0 +1,136 @@
+use super::*;
+
+/// Arguments of a lazy pivot.
+///
+/// The output columns of a pivot depend on the values of the `on` columns. To know the schema
+/// without running the query, those values must be given in `on_columns`. The pivot then
+/// becomes a group by on the `index` columns with a filtered aggregation for every output
+/// column, which the optimizers and the streaming engines handle like any other group by.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PivotArgs {
+    /// The columns whose values become the output column names.
+    pub on: Arc<[ColumnName]>,
+    /// The distinct values of `on` that become output columns, with a column for every column
+    /// in `on`. Every row is a group of output columns, in the order of the rows.
+    pub on_columns: Option<Arc<DataFrame>>,
+    /// The columns to group by. If `None`, all columns not in `on` and `values`.
+    pub index: Option<Arc<[ColumnName]>>,
+    /// The columns to aggregate. If `None`, all columns not in `on` and `index`.
+    pub values: Option<Arc<[ColumnName]>>,
+    /// The aggregation of the values of a cell, in which any column refers to the values.
+    pub agg: Expr,
+    pub maintain_order: bool,
+    /// Sort the output columns of every value column by name.
+    pub sort_columns: bool,
+    /// Separates the value column name from the `on` values if there are multiple values.
+    pub separator: Arc<str>,
+}
+
+impl PivotArgs {
+    /// Resolve the `index` columns and the aggregations that produce the pivoted columns.
+    pub(crate) fn to_group_by(
+        &self,
+        input_schema: &Schema,
+    ) -> PolarsResult<(Vec<Expr>, Vec<Expr>)> {
+        polars_ensure!(!self.on.is_empty(), ComputeError: "`on` cannot be zero length");
+        for name in self.on.iter() {
+            input_schema.try_get(name)?;
+        }
+        let Some(on_columns) = self.on_columns.as_deref() else {
+            polars_bail!(
+                ComputeError: "the values of the `on` columns must be known to pivot lazily"
+            )
+        };
+
+        let remaining = |other: &[ColumnName]| -> Arc<[ColumnName]> {
+            input_schema
+                .iter_names()
+                .filter(|name| {
+                    !self
+                        .on
+                        .iter()
+                        .chain(other)
+                        .any(|c| c.as_ref() == name.as_str())
+                })
+                .map(|name| ColumnName::from(name.as_str()))
+                .collect()
+        };
+        let (index, values) = match (&self.index, &self.values) {
+            (Some(index), Some(values)) => (index.clone(), values.clone()),
+            (Some(index), None) => (index.clone(), remaining(index)),
+            (None, Some(values)) => (remaining(values), values.clone()),
+            (None, None) => {
+                polars_bail!(InvalidOperation: "`index` and `values` cannot both be None in `pivot` operation")
+            },
+        };
+        polars_ensure!(!index.is_empty(), ComputeError: "index cannot be zero length");
+
+        // The values are compared with the `on` columns, so they must have the same type.
+        let on_values = self
+            .on
+            .iter()
+            .map(|name| {
+                on_columns
+                    .column(name)?
+                    .strict_cast(input_schema.try_get(name)?)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let headers = if let [s] = on_values.as_slice() {
+            s.cast(&DataType::String)?
+        } else {
+            #[cfg(feature = "dtype-struct")]
+            {
+                StructChunked::new("", &on_values)?
+                    .into_series()
+                    .cast(&DataType::String)?
+            }
+            #[cfg(not(feature = "dtype-struct"))]
+            {
+                polars_bail!(ComputeError: "activate 'dtype-struct' feature to pivot on multiple columns")
+            }
+        };
+        let headers = headers.str()?;
+        let mut order = (0..on_columns.height()).collect::<Vec<_>>();
+        if self.sort_columns {
+            order.sort_by_key(|&i| headers.get(i).unwrap_or("null"));
+        }
+
+        let mut aggs = Vec::with_capacity(order.len() * values.len());
+        for &i in &order {
+            let predicate = self
+                .on
+                .iter()
+                .zip(&on_values)
+                .map(|(name, s)| {
+                    let value = LiteralValue::try_from(s.get(i)?)?;
+                    Ok(col(name).eq_missing(Expr::Literal(value)))
+                })
+                .reduce(|l: PolarsResult<Expr>, r| Ok(l?.and(r?)))
+                .unwrap()?;
+            let header = headers.get(i).unwrap_or("null");
+
+            for value in values.iter() {
+                let cell = self.agg.clone().map_expr(|e| match e {
+                    Expr::Column(_) | Expr::Nth(_) => col(value).filter(predicate.clone()),
+                    Expr::Len => col(value).filter(predicate.clone()).len(),
+                    e => e,
+                });
+                let name = if values.len() > 1 {
+                    format!("{value}{}{header}", self.separator)
+                } else {
+                    header.to_string()
+                };
+                // Like the eager pivot, a cell without any rows is null.
+                aggs.push(
+                    when(predicate.clone().any(false))
+                        .then(cell)
+                        .otherwise(NULL.lit())
+                        .alias(&name),
+                );
+            }
+        }
+        let keys = index.iter().map(|name| col(name)).collect();
+        Ok((keys, aggs))
+    }
+}
@@ -24,6 +24,13 @@ impl FunctionNode {
         input_schema: &'a SchemaRef,
     ) -> PolarsResult<Cow<'a, SchemaRef>> {
         use FunctionNode::*;
+        if self.is_row_independent() {
+            let mut schema = (**input_schema).clone();
+            if let Some(output_schema) = self.row_independent_output_schema(input_schema)? {
+                schema.merge_from_ref(&output_schema);
+            }
+            return Ok(Cow::Owned(Arc::new(schema)));
+        }
         match self {
             Opaque { schema, .. } => match schema {
                 None => Ok(Cow::Borrowed(input_schema)),
@@ -99,6 +106,7 @@ impl FunctionNode {
             },
             Explode { schema, columns } => explode_schema(schema, input_schema, columns),
             Unpivot { schema, args } => unpivot_schema(args, schema, input_schema),
+            Assert { .. } | Sample { .. } => Ok(Cow::Borrowed(input_schema)),
         }
     }
 }
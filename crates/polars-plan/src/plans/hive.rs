@@ -1,6 +1,6 @@
 use std::path::{Path, PathBuf};
 
-use percent_encoding::percent_decode;
+use percent_encoding::{percent_decode, utf8_percent_encode, AsciiSet, CONTROLS};
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
 use polars_io::predicates::{BatchStats, ColumnStats};
@@ -59,6 +59,26 @@ impl HivePartitions {
     }
 }
 
+/// Materialize the partition values of all `hive_parts` into a [`DataFrame`] with a row per file.
+///
+/// The partition values are typed by the hive schema, so predicates that only refer to hive
+/// columns can be evaluated on this frame to determine which files have to be read.
+pub fn hive_partitions_to_frame(hive_parts: &[HivePartitions]) -> PolarsResult<DataFrame> {
+    let Some(first) = hive_parts.first() else {
+        return Ok(DataFrame::empty());
+    };
+
+    let mut columns = first.materialize_partition_columns();
+    for part in &hive_parts[1..] {
+        for (column, value) in columns.iter_mut().zip(part.materialize_partition_columns()) {
+            column.append(&value)?;
+        }
+    }
+    let mut df = DataFrame::new(columns)?;
+    df.as_single_chunk_par();
+    Ok(df)
+}
+
 /// # Safety
 /// `hive_start_idx <= [min path length]`
 pub fn hive_partitions_from_paths(
@@ -268,3 +288,31 @@ fn parse_hive_string(part: &'_ str) -> Option<(&'_ str, &'_ str)> {
 
     Some((name, value))
 }
+
+/// Characters that have to be escaped in a Hive partition value.
+const HIVE_VALUE_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b'/')
+    .add(b'\\')
+    .add(b'=')
+    .add(b'%')
+    .add(b'*')
+    .add(b'?')
+    .add(b':');
+
+/// Format a Hive partition directory name (e.g. "column=1.5") for a single partition value.
+///
+/// This is the inverse of the parsing done by [`hive_partitions_from_paths`]. Null values are
+/// written as `__HIVE_DEFAULT_PARTITION__`.
+pub fn format_hive_partition(name: &str, value: &AnyValue) -> String {
+    let value = match value {
+        AnyValue::Null => return format!("{name}=__HIVE_DEFAULT_PARTITION__"),
+        av => match av.get_str() {
+            Some(s) => s.to_string(),
+            None => av.to_string(),
+        },
+    };
+    format!(
+        "{name}={}",
+        utf8_percent_encode(&value, HIVE_VALUE_ENCODE_SET)
+    )
+}
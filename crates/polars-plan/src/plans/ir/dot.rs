@@ -1,6 +1,7 @@
 use std::fmt;
 use std::path::PathBuf;
 
+use polars_core::datatypes::PlHashMap;
 use polars_core::schema::Schema;
 
 use super::format::ExprIRSliceDisplay;
@@ -11,6 +12,7 @@ use crate::prelude::*;
 pub struct IRDotDisplay<'a> {
     is_streaming: bool,
     lp: IRPlanRef<'a>,
+    annotations: Option<&'a PlHashMap<Node, String>>,
 }
 
 const INDENT: &str = "  ";
@@ -34,12 +36,18 @@ impl fmt::Display for DotNode {
 fn write_label<'a, 'b>(
     f: &'b mut fmt::Formatter<'a>,
     id: DotNode,
+    annotation: Option<&str>,
     mut w: impl FnMut(&mut EscapeLabel<'a, 'b>) -> fmt::Result,
 ) -> fmt::Result {
+    use fmt::Write;
+
     write!(f, "{INDENT}{id}[label=\"")?;
 
     let mut escaped = EscapeLabel(f);
     w(&mut escaped)?;
+    if let Some(annotation) = annotation {
+        write!(escaped, "\n{annotation}")?;
+    }
     let EscapeLabel(f) = escaped;
 
     writeln!(f, "\"]")?;
@@ -56,6 +64,7 @@ impl<'a> IRDotDisplay<'a> {
         Self {
             is_streaming: false,
             lp,
+            annotations: None,
         }
     }
 
@@ -63,13 +72,25 @@ impl<'a> IRDotDisplay<'a> {
         Self {
             is_streaming: true,
             lp,
+            annotations: None,
         }
     }
 
+    /// Add a line to the label of the nodes in `annotations`.
+    ///
+    /// As with the text display, the annotations are ignored for plans of the streaming engine.
+    pub fn with_annotations(mut self, annotations: &'a PlHashMap<Node, String>) -> Self {
+        if !self.is_streaming {
+            self.annotations = Some(annotations);
+        }
+        self
+    }
+
     fn with_root(&self, root: Node) -> Self {
         Self {
             is_streaming: false,
             lp: self.lp.with_root(root),
+            annotations: self.annotations,
         }
     }
 
@@ -93,6 +114,10 @@ impl<'a> IRDotDisplay<'a> {
         use fmt::Write;
 
         let root = self.lp.root();
+        let annotation = self
+            .annotations
+            .and_then(|a| a.get(&self.lp.lp_top))
+            .map(|a| a.as_str());
 
         let mut parent = parent;
         if self.is_streaming {
@@ -101,7 +126,7 @@ impl<'a> IRDotDisplay<'a> {
 
             if let Some(parent) = parent {
                 writeln!(f, "{INDENT}{parent} -- {streaming_node}")?;
-                write_label(f, streaming_node, |f| f.write_str("STREAMING"))?;
+                write_label(f, streaming_node, None, |f| f.write_str("STREAMING"))?;
             }
 
             parent = Some(streaming_node);
@@ -126,14 +151,14 @@ impl<'a> IRDotDisplay<'a> {
                     self.with_root(*input)._format(f, Some(id), last)?;
                 }
 
-                write_label(f, id, |f| f.write_str("UNION"))?;
+                write_label(f, id, annotation, |f| f.write_str("UNION"))?;
             },
             HConcat { inputs, .. } => {
                 for input in inputs {
                     self.with_root(*input)._format(f, Some(id), last)?;
                 }
 
-                write_label(f, id, |f| f.write_str("HCONCAT"))?;
+                write_label(f, id, annotation, |f| f.write_str("HCONCAT"))?;
             },
             Cache {
                 input, cache_hits, ..
@@ -141,16 +166,18 @@ impl<'a> IRDotDisplay<'a> {
                 self.with_root(*input)._format(f, Some(id), last)?;
 
                 if *cache_hits == UNLIMITED_CACHE {
-                    write_label(f, id, |f| f.write_str("CACHE"))?;
+                    write_label(f, id, annotation, |f| f.write_str("CACHE"))?;
                 } else {
-                    write_label(f, id, |f| write!(f, "CACHE: {cache_hits} times"))?;
+                    write_label(f, id, annotation, |f| {
+                        write!(f, "CACHE: {cache_hits} times")
+                    })?;
                 };
             },
             Filter { predicate, input } => {
                 self.with_root(*input)._format(f, Some(id), last)?;
 
                 let pred = self.display_expr(predicate);
-                write_label(f, id, |f| write!(f, "FILTER BY {pred}"))?;
+                write_label(f, id, annotation, |f| write!(f, "FILTER BY {pred}"))?;
             },
             #[cfg(feature = "python")]
             PythonScan { predicate, options } => {
@@ -159,7 +186,7 @@ impl<'a> IRDotDisplay<'a> {
                 let total_columns = options.schema.len();
                 let predicate = OptionExprIRDisplay(predicate);
 
-                write_label(f, id, |f| {
+                write_label(f, id, annotation, |f| {
                     write!(
                         f,
                         "PYTHON SCAN\nπ {with_columns}/{total_columns};\nσ {predicate}"
@@ -173,14 +200,16 @@ impl<'a> IRDotDisplay<'a> {
                 ..
             } => {
                 self.with_root(*input)._format(f, Some(id), last)?;
-                write_label(f, id, |f| write!(f, "π {}/{}", expr.len(), schema.len()))?;
+                write_label(f, id, annotation, |f| {
+                    write!(f, "π {}/{}", expr.len(), schema.len())
+                })?;
             },
             Sort {
                 input, by_column, ..
             } => {
                 let by_column = self.display_exprs(by_column);
                 self.with_root(*input)._format(f, Some(id), last)?;
-                write_label(f, id, |f| write!(f, "SORT BY {by_column}"))?;
+                write_label(f, id, annotation, |f| write!(f, "SORT BY {by_column}"))?;
             },
             GroupBy {
                 input, keys, aggs, ..
@@ -188,25 +217,27 @@ impl<'a> IRDotDisplay<'a> {
                 let keys = self.display_exprs(keys);
                 let aggs = self.display_exprs(aggs);
                 self.with_root(*input)._format(f, Some(id), last)?;
-                write_label(f, id, |f| write!(f, "AGG {aggs}\nBY\n{keys}"))?;
+                write_label(f, id, annotation, |f| write!(f, "AGG {aggs}\nBY\n{keys}"))?;
             },
             HStack { input, exprs, .. } => {
                 let exprs = self.display_exprs(exprs);
                 self.with_root(*input)._format(f, Some(id), last)?;
-                write_label(f, id, |f| write!(f, "WITH COLUMNS {exprs}"))?;
+                write_label(f, id, annotation, |f| write!(f, "WITH COLUMNS {exprs}"))?;
             },
             Reduce { input, exprs, .. } => {
                 let exprs = self.display_exprs(exprs);
                 self.with_root(*input)._format(f, Some(id), last)?;
-                write_label(f, id, |f| write!(f, "REDUCE {exprs}"))?;
+                write_label(f, id, annotation, |f| write!(f, "REDUCE {exprs}"))?;
             },
             Slice { input, offset, len } => {
                 self.with_root(*input)._format(f, Some(id), last)?;
-                write_label(f, id, |f| write!(f, "SLICE offset: {offset}; len: {len}"))?;
+                write_label(f, id, annotation, |f| {
+                    write!(f, "SLICE offset: {offset}; len: {len}")
+                })?;
             },
             Distinct { input, options, .. } => {
                 self.with_root(*input)._format(f, Some(id), last)?;
-                write_label(f, id, |f| {
+                write_label(f, id, annotation, |f| {
                     f.write_str("DISTINCT")?;
 
                     if let Some(subset) = &options.subset {
@@ -238,7 +269,7 @@ impl<'a> IRDotDisplay<'a> {
                 let selection = OptionExprIRDisplay(selection);
                 let total_columns = schema.len();
 
-                write_label(f, id, |f| {
+                write_label(f, id, annotation, |f| {
                     write!(f, "TABLE\nπ {num_columns}/{total_columns};\nσ {selection}")
                 })?;
             },
@@ -251,14 +282,14 @@ impl<'a> IRDotDisplay<'a> {
                 file_options: options,
                 output_schema: _,
             } => {
-                let name: &str = scan_type.into();
+                let name = scan_type.name();
                 let path = PathsDisplay(paths.as_ref());
                 let with_columns = options.with_columns.as_ref().map(|cols| cols.as_ref());
                 let with_columns = NumColumns(with_columns);
                 let total_columns =
                     file_info.schema.len() - usize::from(options.row_index.is_some());
 
-                write_label(f, id, |f| {
+                write_label(f, id, annotation, |f| {
                     write!(f, "{name} SCAN {path}\nπ {with_columns}/{total_columns};",)?;
 
                     if let Some(predicate) = predicate.as_ref() {
@@ -286,7 +317,7 @@ impl<'a> IRDotDisplay<'a> {
                 let left_on = self.display_exprs(left_on);
                 let right_on = self.display_exprs(right_on);
 
-                write_label(f, id, |f| {
+                write_label(f, id, annotation, |f| {
                     write!(
                         f,
                         "JOIN {}\nleft: {left_on};\nright: {right_on}",
@@ -301,22 +332,25 @@ impl<'a> IRDotDisplay<'a> {
                     Self::new_streaming(streaming_lp)._format(f, Some(id), last)?;
                 } else {
                     self.with_root(*input)._format(f, Some(id), last)?;
-                    write_label(f, id, |f| write!(f, "{function}"))?;
+                    write_label(f, id, annotation, |f| write!(f, "{function}"))?;
                 }
             },
             ExtContext { input, .. } => {
                 self.with_root(*input)._format(f, Some(id), last)?;
-                write_label(f, id, |f| f.write_str("EXTERNAL_CONTEXT"))?;
+                write_label(f, id, annotation, |f| f.write_str("EXTERNAL_CONTEXT"))?;
             },
             Sink { input, payload, .. } => {
                 self.with_root(*input)._format(f, Some(id), last)?;
 
-                write_label(f, id, |f| {
+                write_label(f, id, annotation, |f| {
                     f.write_str(match payload {
                         SinkType::Memory => "SINK (MEMORY)",
                         SinkType::File { .. } => "SINK (FILE)",
                         #[cfg(feature = "cloud")]
                         SinkType::Cloud { .. } => "SINK (CLOUD)",
+                        SinkType::Partition { .. } => "SINK (PARTITION)",
+                        SinkType::Batches(_) => "SINK (BATCHES)",
+                        SinkType::Plugin(_) => "SINK (PLUGIN)",
                     })
                 })?;
             },
@@ -326,11 +360,11 @@ impl<'a> IRDotDisplay<'a> {
 
                 let columns = ColumnsDisplay(columns.as_ref());
                 self.with_root(*input)._format(f, Some(id), last)?;
-                write_label(f, id, |f| {
+                write_label(f, id, annotation, |f| {
                     write!(f, "simple π {num_columns}/{total_columns}\n[{columns}]")
                 })?;
             },
-            Invalid => write_label(f, id, |f| f.write_str("INVALID"))?,
+            Invalid => write_label(f, id, annotation, |f| f.write_str("INVALID"))?,
         }
 
         Ok(())
@@ -4,6 +4,7 @@ use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
 
 use polars_core::datatypes::AnyValue;
+use polars_core::datatypes::PlHashMap;
 use polars_core::schema::Schema;
 use polars_io::RowIndex;
 use recursive::recursive;
@@ -14,6 +15,7 @@ use crate::prelude::*;
 pub struct IRDisplay<'a> {
     is_streaming: bool,
     lp: IRPlanRef<'a>,
+    annotations: Option<&'a PlHashMap<Node, String>>,
 }
 
 #[derive(Clone, Copy)]
@@ -100,6 +102,7 @@ impl<'a> IRDisplay<'a> {
         Self {
             is_streaming: false,
             lp,
+            annotations: None,
         }
     }
 
@@ -107,9 +110,21 @@ impl<'a> IRDisplay<'a> {
         Self {
             is_streaming: true,
             lp,
+            annotations: None,
         }
     }
 
+    /// Append an annotation to the first line of the nodes in `annotations`.
+    ///
+    /// The annotations are ignored for plans of the streaming engine, as they display a copy of
+    /// the plan with nodes of its own.
+    pub fn with_annotations(mut self, annotations: &'a PlHashMap<Node, String>) -> Self {
+        if !self.is_streaming {
+            self.annotations = Some(annotations);
+        }
+        self
+    }
+
     fn root(&self) -> &IR {
         self.lp.root()
     }
@@ -118,6 +133,7 @@ impl<'a> IRDisplay<'a> {
         Self {
             is_streaming: false,
             lp: self.lp.with_root(root),
+            annotations: self.annotations,
         }
     }
 
@@ -136,8 +152,21 @@ impl<'a> IRDisplay<'a> {
         }
     }
 
-    #[recursive]
     fn _format(&self, f: &mut Formatter, indent: usize) -> fmt::Result {
+        let Some(annotation) = self.annotations.and_then(|a| a.get(&self.lp.lp_top)) else {
+            return self._format_node(f, indent);
+        };
+
+        // The inputs are written by the node itself, so we render it first to find the end of
+        // its first line.
+        let node = NodeDisplay(self, indent).to_string();
+        let start = usize::from(node.starts_with('\n'));
+        let end = node[start..].find('\n').map_or(node.len(), |i| start + i);
+        write!(f, "{}  [{annotation}]{}", &node[..end], &node[end..])
+    }
+
+    #[recursive]
+    fn _format_node(&self, f: &mut Formatter, indent: usize) -> fmt::Result {
         let indent = if self.is_streaming {
             writeln!(f, "{:indent$}STREAMING:", "")?;
             indent + 2
@@ -234,7 +263,7 @@ impl<'a> IRDisplay<'a> {
 
                 write_scan(
                     f,
-                    scan_type.into(),
+                    scan_type.name(),
                     paths,
                     indent,
                     n_columns,
@@ -298,12 +327,20 @@ impl<'a> IRDisplay<'a> {
                 self.with_root(*input)._format(f, sub_indent)
             },
             GroupBy {
-                input, keys, aggs, ..
+                input,
+                keys,
+                aggs,
+                options: _options,
+                ..
             } => {
                 let aggs = self.display_expr_slice(aggs);
                 let keys = self.display_expr_slice(keys);
 
                 write!(f, "{:indent$}AGGREGATE", "")?;
+                #[cfg(feature = "grouping_sets")]
+                if let Some(grouping_sets) = &_options.grouping_sets {
+                    write!(f, " GROUPING SETS {:?}", grouping_sets.sets)?;
+                }
                 write!(f, "\n{:indent$}\t{aggs} BY {keys} FROM", "")?;
                 self.with_root(*input)._format(f, sub_indent)
             },
@@ -366,6 +403,9 @@ impl<'a> IRDisplay<'a> {
                     SinkType::File { .. } => "SINK (file)",
                     #[cfg(feature = "cloud")]
                     SinkType::Cloud { .. } => "SINK (cloud)",
+                    SinkType::Partition { .. } => "SINK (partition)",
+                    SinkType::Batches(_) => "SINK (batches)",
+                    SinkType::Plugin(_) => "SINK (plugin)",
                 };
                 write!(f, "{:indent$}{name}", "")?;
                 self.with_root(*input)._format(f, sub_indent)
@@ -411,6 +451,15 @@ impl<'a> Display for IRDisplay<'a> {
     }
 }
 
+/// A single node of an [`IRDisplay`] without its annotation.
+struct NodeDisplay<'a, 'b>(&'b IRDisplay<'a>, usize);
+
+impl Display for NodeDisplay<'_, '_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0._format_node(f, self.1)
+    }
+}
+
 impl<'a, T: AsExpr> Display for ExprIRSliceDisplay<'a, T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         // Display items in slice delimited by a comma
@@ -468,6 +517,17 @@ impl<'a> Display for ExprIRDisplay<'a> {
                             options.index_column, options.offset, options.period
                         )
                     },
+                    WindowType::Frame(frame) => {
+                        if let Some((order_by, _)) = order_by {
+                            let order_by = self.with_root(order_by);
+                            write!(f, "{function}.over(partition_by: {partition_by}, order_by: {order_by}, frame: {frame})")
+                        } else {
+                            write!(
+                                f,
+                                "{function}.over(partition_by: {partition_by}, frame: {frame})"
+                            )
+                        }
+                    },
                     _ => {
                         if let Some((order_by, _)) = order_by {
                             let order_by = self.with_root(order_by);
@@ -580,6 +640,31 @@ impl<'a> Display for ExprIRDisplay<'a> {
                     Var(expr, _) => write!(f, "{}.var()", self.with_root(expr)),
                     Std(expr, _) => write!(f, "{}.std()", self.with_root(expr)),
                     Quantile { expr, .. } => write!(f, "{}.quantile()", self.with_root(expr)),
+                    ApproxQuantile { expr, .. } => {
+                        write!(f, "{}.approx_quantile()", self.with_root(expr))
+                    },
+                    Quantiles { expr, .. } => write!(f, "{}.quantiles()", self.with_root(expr)),
+                    StrJoin { expr, .. } => write!(f, "{}.str.join_agg()", self.with_root(expr)),
+                    FirstBy { expr, by } => write!(
+                        f,
+                        "{}.first_by({})",
+                        self.with_root(expr),
+                        self.with_root(by)
+                    ),
+                    LastBy { expr, by } => write!(
+                        f,
+                        "{}.last_by({})",
+                        self.with_root(expr),
+                        self.with_root(by)
+                    ),
+                    #[cfg(feature = "ffi_plugin")]
+                    Plugin { expr, function } => write!(
+                        f,
+                        "{}.{}:{}()",
+                        self.with_root(expr),
+                        function.lib,
+                        function.symbol
+                    ),
                 }
             },
             Cast {
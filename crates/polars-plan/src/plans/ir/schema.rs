@@ -41,6 +41,9 @@ impl IR {
                 SinkType::File { .. } => "sink (file)",
                 #[cfg(feature = "cloud")]
                 SinkType::Cloud { .. } => "sink (cloud)",
+                SinkType::Partition { .. } => "sink (partition)",
+                SinkType::Batches(_) => "sink (batches)",
+                SinkType::Plugin(_) => "sink (plugin)",
             },
             SimpleProjection { .. } => "simple_projection",
             Invalid => "invalid",
@@ -362,6 +362,9 @@ impl<'a> TreeFmtNode<'a> {
                                 SinkType::File { .. } => "SINK (file)",
                                 #[cfg(feature = "cloud")]
                                 SinkType::Cloud { .. } => "SINK (cloud)",
+                                SinkType::Partition { .. } => "SINK (partition)",
+                                SinkType::Batches(_) => "SINK (batches)",
+                                SinkType::Plugin(_) => "SINK (plugin)",
                             },
                         ),
                         vec![self.lp_node(None, *input)],
@@ -52,6 +52,16 @@ macro_rules! push_expr {
                     Implode(e) => $push($c, e),
                     Count(e, _) => $push($c, e),
                     Quantile { expr, .. } => $push($c, expr),
+                    ApproxQuantile { expr, .. } => $push($c, expr),
+                    Quantiles { expr, .. } => $push($c, expr),
+                    StrJoin { expr, .. } => $push($c, expr),
+                    FirstBy { expr, by } | LastBy { expr, by } => {
+                        $push($c, by);
+                        // latest, so that it is popped first
+                        $push($c, expr);
+                    },
+                    #[cfg(feature = "ffi_plugin")]
+                    Plugin { expr, .. } => $push($c, expr),
                     Sum(e) => $push($c, e),
                     AggGroups(e) => $push($c, e),
                     Std(e, _) => $push($c, e),
@@ -30,7 +30,12 @@ pub(crate) mod optimizer;
 pub(crate) mod options;
 #[cfg(feature = "python")]
 mod pyarrow;
+#[cfg(feature = "result_cache")]
+mod result_cache;
+pub(crate) mod scan_source;
 mod schema;
+#[cfg(feature = "serde")]
+mod serialize;
 pub mod visitor;
 
 pub use aexpr::*;
@@ -46,9 +51,14 @@ pub use ir::*;
 pub use iterator::*;
 pub use lit::*;
 pub use optimizer::*;
+#[cfg(feature = "result_cache")]
+pub use result_cache::*;
+pub use scan_source::*;
 pub use schema::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+pub use serialize::*;
 use strum_macros::IntoStaticStr;
 
 pub type ColumnName = Arc<str>;
@@ -153,6 +163,12 @@ pub enum DslPlan {
         inputs: Vec<DslPlan>,
         args: UnionArgs,
     },
+    /// Set operation (intersect, except, union) on the rows of two plans
+    SetOperation {
+        input_left: Arc<DslPlan>,
+        input_right: Arc<DslPlan>,
+        op: FrameSetOperation,
+    },
     /// Horizontal concatenation of multiple plans
     HConcat {
         inputs: Vec<DslPlan>,
@@ -199,6 +215,7 @@ impl Clone for DslPlan {
             Self::Slice { input, offset, len } => Self::Slice { input: input.clone(), offset: offset.clone(), len: len.clone() },
             Self::MapFunction { input, function } => Self::MapFunction { input: input.clone(), function: function.clone() },
             Self::Union { inputs, args} => Self::Union { inputs: inputs.clone(), args: args.clone() },
+            Self::SetOperation { input_left, input_right, op } => Self::SetOperation { input_left: input_left.clone(), input_right: input_right.clone(), op: *op },
             Self::HConcat { inputs, options } => Self::HConcat { inputs: inputs.clone(), options: options.clone() },
             Self::ExtContext { input, contexts, } => Self::ExtContext { input: input.clone(), contexts: contexts.clone() },
             Self::Sink { input, payload } => Self::Sink { input: input.clone(), payload: payload.clone() },
@@ -96,12 +96,18 @@ fn visit_logical_plan_for_scan_paths(
         },
         IR::Scan {
             scan_type, paths, ..
-        } if !matches!(scan_type, FileScan::Anonymous { .. }) => Some(CountStarExpr {
-            paths: paths.clone(),
-            scan_type: scan_type.clone(),
-            node,
-            alias: None,
-        }),
+        } if !matches!(
+            scan_type,
+            FileScan::Anonymous { .. } | FileScan::Source { .. }
+        ) =>
+        {
+            Some(CountStarExpr {
+                paths: paths.clone(),
+                scan_type: scan_type.clone(),
+                node,
+                alias: None,
+            })
+        },
         // A union can insert a simple projection to ensure all projections align.
         // We can ignore that if we are inside a count star.
         IR::SimpleProjection { input, .. } if inside_union => {
@@ -0,0 +1,166 @@
+use super::*;
+use crate::constants::QUANTILES_FUSED;
+
+/// Fuses the `quantile` aggregations of a group-by that take the same column and interpolation
+/// into a single `quantiles` aggregation, such that the values of every group are only
+/// collected and partially sorted once. The original outputs are taken from the resulting
+/// array in a projection on top of the group-by.
+pub(super) struct FuseQuantiles {}
+
+/// A `quantile` aggregation that can be fused: it takes a column with a numeric data type and
+/// a valid literal quantile.
+fn fusable_quantile(
+    node: Node,
+    expr_arena: &Arena<AExpr>,
+    input_schema: &Schema,
+) -> Option<(Node, ColumnName, QuantileInterpolOptions, f64)> {
+    let AExpr::Agg(IRAggExpr::Quantile {
+        expr,
+        quantile,
+        interpol,
+    }) = expr_arena.get(node)
+    else {
+        return None;
+    };
+    let AExpr::Column(name) = expr_arena.get(*expr) else {
+        return None;
+    };
+    if !input_schema.get(name)?.is_numeric() {
+        return None;
+    }
+    let AExpr::Literal(lv) = expr_arena.get(*quantile) else {
+        return None;
+    };
+    let q = lv.to_any_value()?.extract::<f64>()?;
+    // Quantiles outside of [0, 1] give nulls, but raise in `quantiles`.
+    (0.0..=1.0)
+        .contains(&q)
+        .then(|| (*expr, name.clone(), *interpol, q))
+}
+
+/// The `quantile` aggregations on one column.
+struct Fusion {
+    input: Node,
+    name: ColumnName,
+    interpol: QuantileInterpolOptions,
+    /// The positions of the aggregations in the group-by and their quantiles.
+    members: Vec<(usize, f64)>,
+}
+
+impl OptimizationRule for FuseQuantiles {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<IR> {
+        let IR::GroupBy {
+            input,
+            keys,
+            aggs,
+            schema,
+            apply: None,
+            maintain_order,
+            options,
+        } = lp_arena.get(node)
+        else {
+            return None;
+        };
+        let input_schema = lp_arena.get(*input).schema(lp_arena);
+
+        let mut fusions: Vec<Fusion> = vec![];
+        for (i, agg) in aggs.iter().enumerate() {
+            let Some((input, name, interpol, q)) =
+                fusable_quantile(agg.node(), expr_arena, &input_schema)
+            else {
+                continue;
+            };
+            match fusions
+                .iter_mut()
+                .find(|f| f.name == name && f.interpol == interpol)
+            {
+                Some(f) => f.members.push((i, q)),
+                None => fusions.push(Fusion {
+                    input,
+                    name,
+                    interpol,
+                    members: vec![(i, q)],
+                }),
+            }
+        }
+        fusions.retain(|f| f.members.len() > 1);
+        if fusions.is_empty() {
+            return None;
+        }
+
+        // Per fused output, the fused aggregation and the index of its quantile in it.
+        let mut fused_into = PlHashMap::new();
+        for (j, f) in fusions.iter().enumerate() {
+            for (k, (i, _)) in f.members.iter().enumerate() {
+                fused_into.insert(aggs[*i].output_name_arc().clone(), (j, k));
+            }
+        }
+        let tmp_name = |j: usize| ColumnName::from(format!("{QUANTILES_FUSED}{j}"));
+
+        // The other outputs keep their order, the fused aggregations come last.
+        let mut new_schema = schema
+            .iter()
+            .filter(|(name, _)| !fused_into.contains_key(name.as_str()))
+            .map(|(name, dtype)| Field::new(name, dtype.clone()))
+            .collect::<Schema>();
+        let mut new_aggs = aggs
+            .iter()
+            .filter(|agg| !fused_into.contains_key(agg.output_name()))
+            .cloned()
+            .collect::<Vec<_>>();
+        for (j, f) in fusions.iter().enumerate() {
+            let quantiles = f.members.iter().map(|(_, q)| *q).collect();
+            let agg = expr_arena.add(AExpr::Agg(IRAggExpr::Quantiles {
+                expr: f.input,
+                options: QuantilesOptions {
+                    quantiles,
+                    interpol: f.interpol,
+                },
+            }));
+            let field = expr_arena
+                .get(agg)
+                .to_field(&input_schema, Context::Aggregation, expr_arena)
+                .ok()?;
+            new_schema.with_column(tmp_name(j).as_ref().into(), field.dtype);
+            new_aggs.push(ExprIR::new(agg, OutputName::Alias(tmp_name(j))));
+        }
+
+        let group_by = IR::GroupBy {
+            input: *input,
+            keys: keys.clone(),
+            aggs: new_aggs,
+            schema: Arc::new(new_schema),
+            apply: None,
+            maintain_order: *maintain_order,
+            options: options.clone(),
+        };
+
+        let exprs = schema
+            .iter_names()
+            .map(|name| {
+                let Some(&(j, k)) = fused_into.get(name.as_str()) else {
+                    let node = expr_arena.add(AExpr::Column(name.as_str().into()));
+                    return ExprIR::from_node(node, expr_arena);
+                };
+                let name = ColumnName::from(name.as_str());
+                let e = col(&tmp_name(j)).arr().get(lit(k as IdxSize), false);
+                let node = to_aexpr(e, expr_arena);
+                ExprIR::new(node, OutputName::Alias(name))
+            })
+            .collect();
+
+        let schema = schema.clone();
+        let group_by = lp_arena.add(group_by);
+        Some(IR::Select {
+            input: group_by,
+            expr: exprs,
+            schema,
+            options: Default::default(),
+        })
+    }
+}
@@ -0,0 +1,522 @@
+//! Reorder chains of inner joins by their estimated cardinality.
+//!
+//! A chain is a left-deep tree of inner equi-joins on columns, e.g.
+//! `a.join(b, ..).join(c, ..).join(d, ..)`. The join keys of the chain are grouped into
+//! equivalence classes; two relations can be joined if they have a column in the same class.
+//! The relations are then joined in the order that keeps the intermediate results small,
+//! estimated from the row counts and column statistics of the scanned files. The chain is
+//! only reordered if all its relations are file scans with a known or estimated row
+//! count.
+//!
+//! Joining in another order changes which of the equal key columns is kept and the order
+//! of the columns, a projection on top of the new chain restores the original schema.
+#[cfg(feature = "parquet")]
+use either::Either;
+use polars_core::prelude::*;
+#[cfg(feature = "parquet")]
+use polars_io::parquet::metadata::{deserialize, FileMetaData};
+use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
+
+use super::*;
+
+/// Fraction of the rows that is estimated to pass a filter.
+const FILTER_SELECTIVITY: f64 = 0.3;
+
+/// Reorder all join chains in the plan under `root`.
+pub(super) fn reorder_joins(root: Node, lp_arena: &mut Arena<IR>, expr_arena: &mut Arena<AExpr>) {
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        match JoinChain::collect(node, lp_arena, expr_arena) {
+            Some(chain) => {
+                stack.extend_from_slice(&chain.relations);
+                chain.reorder(lp_arena, expr_arena);
+            },
+            None => lp_arena.get(node).copy_inputs(&mut stack),
+        }
+    }
+}
+
+struct JoinChain {
+    root: Node,
+    /// The inputs of the chain. `relations[0]` is the left input of the innermost join,
+    /// `relations[i + 1]` the right input of the `i`-th join from the inside.
+    relations: Vec<Node>,
+    /// The `(left_on, right_on)` columns of the joins, from the inside out.
+    keys: Vec<(Vec<ColumnName>, Vec<ColumnName>)>,
+    options: Arc<JoinOptions>,
+}
+
+impl JoinChain {
+    fn collect(root: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> Option<Self> {
+        let mut relations = vec![];
+        let mut keys = vec![];
+        let mut options = None;
+        let mut node = root;
+        while let IR::Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options: join_options,
+            ..
+        } = lp_arena.get(node)
+        {
+            let root_options = options.get_or_insert_with(|| join_options.clone());
+            if !is_reorderable(join_options, root_options) {
+                break;
+            }
+            let (Some(left_on), Some(right_on)) = (
+                key_columns(left_on, expr_arena),
+                key_columns(right_on, expr_arena),
+            ) else {
+                break;
+            };
+            keys.push((left_on, right_on));
+            relations.push(*input_right);
+            node = *input_left;
+        }
+        // A single join has nothing to reorder.
+        if keys.len() < 2 {
+            return None;
+        }
+        relations.push(node);
+        relations.reverse();
+        keys.reverse();
+
+        Some(Self {
+            root,
+            relations,
+            keys,
+            options: options.unwrap(),
+        })
+    }
+
+    fn reorder(&self, lp_arena: &mut Arena<IR>, expr_arena: &mut Arena<AExpr>) {
+        let Some(plan) = self.plan(lp_arena) else {
+            return;
+        };
+        let Some(order) = plan.best_order() else {
+            return;
+        };
+        let Some(survivors) = plan.survivors(&order) else {
+            return;
+        };
+
+        let join_options = Arc::new(JoinOptions {
+            allow_parallel: self.options.allow_parallel,
+            force_parallel: self.options.force_parallel,
+            args: JoinArgs {
+                join_nulls: self.options.args.join_nulls,
+                ..JoinArgs::new(JoinType::Inner)
+            },
+            ..Default::default()
+        });
+        let mut input = self.relations[order[0]];
+        let mut joined = vec![order[0]];
+        for &rel in &order[1..] {
+            let (left_on, right_on): (Vec<_>, Vec<_>) = plan.columns[rel]
+                .iter()
+                .filter(|(_, class)| joined.iter().any(|&r| plan.has_class(r, *class)))
+                .map(|(name, class)| {
+                    (
+                        column_expr(survivors[class].clone(), expr_arena),
+                        column_expr(name.clone(), expr_arena),
+                    )
+                })
+                .unzip();
+            input = IRBuilder::new(input, expr_arena, lp_arena)
+                .join(self.relations[rel], left_on, right_on, join_options.clone())
+                .node();
+            joined.push(rel);
+        }
+
+        let schema = lp_arena.get(self.root).schema(lp_arena).into_owned();
+        let expr = schema
+            .iter_names()
+            .map(|name| match plan.output_classes.get(name.as_str()) {
+                Some(class) if survivors[class].as_ref() != name.as_str() => {
+                    let node = expr_arena.add(AExpr::Column(survivors[class].clone()));
+                    ExprIR::new(node, OutputName::Alias(ColumnName::from(name.as_str())))
+                },
+                _ => column_expr(ColumnName::from(name.as_str()), expr_arena),
+            })
+            .collect();
+        lp_arena.replace(
+            self.root,
+            IR::Select {
+                input,
+                expr,
+                schema,
+                options: Default::default(),
+            },
+        );
+    }
+
+    /// Group the join keys into classes and estimate the size of the relations.
+    fn plan(&self, lp_arena: &Arena<IR>) -> Option<JoinPlan> {
+        let schemas = self
+            .relations
+            .iter()
+            .map(|node| lp_arena.get(*node).schema(lp_arena).into_owned())
+            .collect::<Vec<_>>();
+
+        // Find the relation of every left key by following the columns through the chain.
+        let mut classes = UnionFind::default();
+        let mut members: PlHashMap<(usize, ColumnName), usize> = PlHashMap::new();
+        let mut member = |rel: usize, name: &ColumnName, classes: &mut UnionFind| {
+            *members
+                .entry((rel, name.clone()))
+                .or_insert_with(|| classes.add())
+        };
+        let mut output: PlHashMap<ColumnName, usize> = schemas[0]
+            .iter_names()
+            .map(|name| (ColumnName::from(name.as_str()), 0))
+            .collect();
+        for (i, (left_on, right_on)) in self.keys.iter().enumerate() {
+            let rel = i + 1;
+            for (left, right) in left_on.iter().zip(right_on) {
+                let left = member(*output.get(left)?, left, &mut classes);
+                let right = member(rel, right, &mut classes);
+                classes.union(left, right);
+            }
+            for name in schemas[rel].iter_names() {
+                if right_on.iter().any(|key| key.as_ref() == name.as_str()) {
+                    continue;
+                }
+                // Duplicate names get a suffix, which depends on the join order.
+                if output
+                    .insert(ColumnName::from(name.as_str()), rel)
+                    .is_some()
+                {
+                    return None;
+                }
+            }
+        }
+        let root_schema = lp_arena.get(self.root).schema(lp_arena);
+        if root_schema.len() != output.len()
+            || !root_schema
+                .iter_names()
+                .all(|name| output.contains_key(name.as_str()))
+        {
+            return None;
+        }
+
+        let mut columns: Vec<Vec<(ColumnName, usize)>> = vec![vec![]; self.relations.len()];
+        let mut output_classes = PlHashMap::new();
+        for ((rel, name), member) in &members {
+            let class = classes.find(*member);
+            // A relation that has multiple columns in a class joins with itself.
+            if columns[*rel].iter().any(|(_, c)| *c == class) {
+                return None;
+            }
+            if output.get(name) == Some(rel) {
+                output_classes.insert(name.clone(), class);
+            }
+            columns[*rel].push((name.clone(), class));
+        }
+        for class_columns in &mut columns {
+            class_columns.sort_by_key(|(_, class)| *class);
+        }
+        // Keys of a class are replaced by each other, so they must have the same type.
+        {
+            let mut dtypes: PlHashMap<usize, &DataType> = PlHashMap::new();
+            for (rel, class_columns) in columns.iter().enumerate() {
+                for (name, class) in class_columns {
+                    let dtype = schemas[rel].get(name)?;
+                    if *dtypes.entry(*class).or_insert(dtype) != dtype {
+                        return None;
+                    }
+                }
+            }
+        }
+
+        let estimates = self
+            .relations
+            .iter()
+            .zip(&columns)
+            .map(|(node, class_columns)| {
+                let estimate = RelationEstimate::new(*node, lp_arena)?;
+                let distinct = class_columns
+                    .iter()
+                    .map(|(name, class)| (*class, estimate.distinct_count(name, lp_arena)))
+                    .collect::<PlHashMap<_, _>>();
+                Some((estimate.rows, distinct))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(JoinPlan {
+            columns,
+            output_classes,
+            schemas,
+            estimates,
+        })
+    }
+}
+
+/// Whether the join can be moved within the chain.
+fn is_reorderable(options: &JoinOptions, root_options: &JoinOptions) -> bool {
+    let args = &options.args;
+    matches!(args.how, JoinType::Inner)
+        && matches!(args.validation, JoinValidation::ManyToMany)
+        && args.slice.is_none()
+        && args.should_coalesce()
+        && args.join_nulls == root_options.args.join_nulls
+}
+
+/// The names of the join keys if they are all plain columns.
+fn key_columns(keys: &[ExprIR], expr_arena: &Arena<AExpr>) -> Option<Vec<ColumnName>> {
+    keys.iter()
+        .map(|key| match expr_arena.get(key.node()) {
+            AExpr::Column(name) if key.output_name() == name.as_ref() => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn column_expr(name: ColumnName, expr_arena: &mut Arena<AExpr>) -> ExprIR {
+    let node = expr_arena.add(AExpr::Column(name.clone()));
+    ExprIR::new(node, OutputName::ColumnLhs(name))
+}
+
+struct JoinPlan {
+    /// The key columns of every relation with their class, sorted by class.
+    columns: Vec<Vec<(ColumnName, usize)>>,
+    /// The class of the key columns that are in the output of the original chain.
+    output_classes: PlHashMap<ColumnName, usize>,
+    schemas: Vec<SchemaRef>,
+    /// The estimated number of rows and the distinct count of the key classes per relation.
+    estimates: Vec<(f64, PlHashMap<usize, f64>)>,
+}
+
+impl JoinPlan {
+    fn has_class(&self, rel: usize, class: usize) -> bool {
+        self.columns[rel].iter().any(|(_, c)| *c == class)
+    }
+
+    /// Returns the order if it is estimated to be cheaper than the original order.
+    fn best_order(&self) -> Option<Vec<usize>> {
+        let n = self.columns.len();
+        let original = (0..n).collect::<Vec<_>>();
+        let original_cost = self.cost(&original)?;
+
+        // Greedily add the relation that gives the smallest intermediate result.
+        let mut best: Option<(f64, Vec<usize>)> = None;
+        for start in 0..n {
+            let mut order = vec![start];
+            let mut cost = 0.0;
+            while order.len() < n {
+                let mut next: Option<(f64, usize)> = None;
+                for rel in (0..n).filter(|rel| !order.contains(rel)) {
+                    let mut candidate = order.clone();
+                    candidate.push(rel);
+                    if let Some(c) = self.cost(&candidate) {
+                        if next.map_or(true, |(best, _)| c < best) {
+                            next = Some((c, rel));
+                        }
+                    }
+                }
+                let Some((c, rel)) = next else {
+                    break;
+                };
+                cost = c;
+                order.push(rel);
+            }
+            if order.len() == n && best.as_ref().map_or(true, |(best, _)| cost < *best) {
+                best = Some((cost, order));
+            }
+        }
+        let (cost, order) = best?;
+        (cost < original_cost).then_some(order)
+    }
+
+    /// The sum of the estimated intermediate result sizes, `None` if the order needs a cross
+    /// join.
+    fn cost(&self, order: &[usize]) -> Option<f64> {
+        let (mut rows, mut distinct) = self.estimates[order[0]].clone();
+        let mut cost = 0.0;
+        for &rel in &order[1..] {
+            let (rel_rows, rel_distinct) = &self.estimates[rel];
+            let mut joined_rows = rows * rel_rows;
+            let mut connected = false;
+            for (class, n) in rel_distinct {
+                match distinct.get_mut(class) {
+                    Some(current) => {
+                        connected = true;
+                        joined_rows /= current.max(*n);
+                        *current = current.min(*n);
+                    },
+                    None => {
+                        distinct.insert(*class, *n);
+                    },
+                }
+            }
+            if !connected {
+                return None;
+            }
+            rows = joined_rows.max(1.0);
+            for n in distinct.values_mut() {
+                *n = n.min(rows);
+            }
+            cost += rows;
+        }
+        Some(cost)
+    }
+
+    /// The name of the key column that is kept for every class if the relations are joined in
+    /// `order`. Returns `None` if the output of that order would have duplicate names.
+    fn survivors(&self, order: &[usize]) -> Option<PlHashMap<usize, ColumnName>> {
+        let mut survivors = PlHashMap::new();
+        let mut names = PlHashSet::new();
+        for &rel in order {
+            for name in self.schemas[rel].iter_names() {
+                let class = self.columns[rel]
+                    .iter()
+                    .find(|(key, _)| key.as_ref() == name.as_str())
+                    .map(|(_, class)| *class);
+                if let Some(class) = class {
+                    if survivors.contains_key(&class) {
+                        continue;
+                    }
+                    survivors.insert(class, ColumnName::from(name.as_str()));
+                }
+                if !names.insert(name.clone()) {
+                    return None;
+                }
+            }
+        }
+        Some(survivors)
+    }
+}
+
+#[derive(Default)]
+struct UnionFind {
+    parents: Vec<usize>,
+}
+
+impl UnionFind {
+    fn add(&mut self) -> usize {
+        self.parents.push(self.parents.len());
+        self.parents.len() - 1
+    }
+
+    fn find(&self, mut i: usize) -> usize {
+        while self.parents[i] != i {
+            i = self.parents[i];
+        }
+        i
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        self.parents[b] = a;
+    }
+}
+
+struct RelationEstimate {
+    rows: f64,
+    scan: Node,
+}
+
+impl RelationEstimate {
+    /// Estimate the size of a file scan, possibly filtered and projected.
+    fn new(mut node: Node, lp_arena: &Arena<IR>) -> Option<Self> {
+        let mut selectivity = 1.0;
+        loop {
+            match lp_arena.get(node) {
+                IR::Filter { input, .. } => {
+                    selectivity *= FILTER_SELECTIVITY;
+                    node = *input;
+                },
+                IR::SimpleProjection { input, .. } => node = *input,
+                IR::Scan {
+                    paths,
+                    file_info,
+                    predicate,
+                    file_options,
+                    ..
+                } => {
+                    let mut rows = match file_info.row_estimation {
+                        // The row count is read from the first file.
+                        (Some(known), _) => known.saturating_mul(paths.len()),
+                        (None, 0 | usize::MAX) => return None,
+                        (None, estimated) => estimated,
+                    };
+                    if let Some(n_rows) = file_options.n_rows {
+                        rows = rows.min(n_rows);
+                    }
+                    if predicate.is_some() {
+                        selectivity *= FILTER_SELECTIVITY;
+                    }
+                    return Some(Self {
+                        rows: (rows as f64 * selectivity).max(1.0),
+                        scan: node,
+                    });
+                },
+                _ => return None,
+            }
+        }
+    }
+
+    /// Estimate the number of distinct values of a column. Without statistics every value is
+    /// assumed to be distinct.
+    #[allow(unused_variables)]
+    fn distinct_count(&self, name: &str, lp_arena: &Arena<IR>) -> f64 {
+        let IR::Scan {
+            file_info,
+            scan_type,
+            ..
+        } = lp_arena.get(self.scan)
+        else {
+            unreachable!()
+        };
+        let distinct = match (scan_type, &file_info.reader_schema) {
+            #[cfg(feature = "parquet")]
+            (
+                FileScan::Parquet {
+                    metadata: Some(metadata),
+                    ..
+                },
+                Some(Either::Left(schema)),
+            ) => parquet_distinct_count(metadata, schema, name),
+            _ => None,
+        };
+        distinct.unwrap_or(self.rows).clamp(1.0, self.rows)
+    }
+}
+
+/// The distinct count of the statistics, or else the range of the values of an integer column.
+#[cfg(feature = "parquet")]
+fn parquet_distinct_count(
+    metadata: &FileMetaData,
+    schema: &ArrowSchema,
+    name: &str,
+) -> Option<f64> {
+    let field = schema.fields.iter().find(|field| field.name == name)?;
+    let series = |array| Series::try_from(("", array)).ok();
+    let first_i64 = |s: Series| s.cast(&DataType::Int64).ok()?.i64().ok()?.get(0);
+
+    let mut distinct: Option<u64> = None;
+    // `None` once a row group has no bounds.
+    let mut range = Some((i64::MAX, i64::MIN));
+    for row_group in &metadata.row_groups {
+        let stats = deserialize(field, row_group).ok()?;
+        if let Some(n) = series(stats.distinct_count)
+            .and_then(|s| s.cast(&DataType::UInt64).ok()?.u64().ok()?.get(0))
+        {
+            distinct = Some(distinct.map_or(n, |d| d.max(n)));
+        }
+
+        let bounds = series(stats.min_value)
+            .zip(series(stats.max_value))
+            .filter(|(min, _)| min.dtype().is_integer())
+            .and_then(|(min, max)| first_i64(min).zip(first_i64(max)));
+        range = range
+            .zip(bounds)
+            .map(|((lo, hi), (min, max))| (lo.min(min), hi.max(max)));
+    }
+    match (distinct, range) {
+        (Some(n), _) => Some(n as f64),
+        (None, Some((min, max))) if min <= max => Some(max.abs_diff(min) as f64 + 1.0),
+        _ => None,
+    }
+}
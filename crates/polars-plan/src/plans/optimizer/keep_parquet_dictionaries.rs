@@ -0,0 +1,169 @@
+use arrow::datatypes::IntegerType;
+use either::Either;
+use polars_io::parquet::metadata::FileMetaData;
+
+use super::*;
+
+/// Keep the dictionary-encoded string columns of a Parquet scan as dictionaries up to a Parquet
+/// sink, for queries that only filter and select columns in between.
+///
+/// Those columns are read as categoricals, so that filters and gathers move the dictionary keys
+/// instead of the strings, and the sink writes the dictionaries as they are while declaring the
+/// columns as strings in the file. Columns that a predicate reads are decoded as usual.
+pub(super) fn keep_parquet_dictionaries(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    verbose: bool,
+) {
+    let IR::Sink { input, payload } = lp_arena.get(root) else {
+        return;
+    };
+    let file_type = match payload {
+        SinkType::File { file_type, .. } => file_type,
+        #[cfg(feature = "cloud")]
+        SinkType::Cloud { file_type, .. } => file_type,
+        _ => return,
+    };
+    if !matches!(file_type, FileType::Parquet(options) if !options.categoricals_as_strings) {
+        return;
+    }
+
+    // The nodes between the sink and the scan, and the columns that predicates read.
+    let mut chain = vec![];
+    let mut predicate_columns = PlHashSet::new();
+    let mut node = *input;
+    let scan = loop {
+        match lp_arena.get(node) {
+            IR::Filter { input, predicate } => {
+                predicate_columns.extend(aexpr_to_leaf_names_iter(predicate.node(), expr_arena));
+                chain.push(node);
+                node = *input;
+            },
+            IR::SimpleProjection { input, .. } => {
+                chain.push(node);
+                node = *input;
+            },
+            IR::Select { input, expr, .. } => {
+                let is_column = |e: &ExprIR| match expr_arena.get(e.node()) {
+                    AExpr::Column(name) => name.as_ref() == e.output_name(),
+                    _ => false,
+                };
+                if !expr.iter().all(is_column) {
+                    return;
+                }
+                chain.push(node);
+                node = *input;
+            },
+            IR::Scan {
+                paths,
+                file_info,
+                hive_parts: None,
+                predicate,
+                output_schema,
+                scan_type:
+                    FileScan::Parquet {
+                        metadata: Some(metadata),
+                        ..
+                    },
+                ..
+            } if paths.len() == 1 => {
+                if let Some(predicate) = predicate {
+                    predicate_columns
+                        .extend(aexpr_to_leaf_names_iter(predicate.node(), expr_arena));
+                }
+                break (file_info, output_schema, metadata);
+            },
+            _ => return,
+        }
+    };
+    let (file_info, output_schema, metadata) = scan;
+    let Some(Either::Left(reader_schema)) = &file_info.reader_schema else {
+        return;
+    };
+    // The sink writes all categoricals as strings, so there can't be others.
+    if file_info
+        .schema
+        .iter_dtypes()
+        .any(|dtype| dtype.is_categorical() || dtype.is_enum())
+    {
+        return;
+    }
+
+    let columns = output_schema
+        .as_ref()
+        .unwrap_or(&file_info.schema)
+        .iter()
+        .filter(|(name, dtype)| {
+            dtype.is_string()
+                && !predicate_columns.contains(name.as_str())
+                && is_dictionary_encoded(metadata, name)
+        })
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    if columns.is_empty() {
+        return;
+    }
+    if verbose {
+        eprintln!("parquet sink: keeping the dictionaries of the columns {columns:?}");
+    }
+
+    let categorical = DataType::Categorical(None, Default::default());
+    let to_categorical = |schema: &SchemaRef| {
+        let mut schema = schema.as_ref().clone();
+        for name in &columns {
+            schema.set_dtype(name, categorical.clone());
+        }
+        Arc::new(schema)
+    };
+    let mut reader_schema = reader_schema.as_ref().clone();
+    for field in reader_schema.fields.iter_mut() {
+        if columns.iter().any(|name| name.as_str() == field.name) {
+            let values = Box::new(field.data_type.clone());
+            field.data_type = ArrowDataType::Dictionary(IntegerType::UInt32, values, false);
+        }
+    }
+    let reader_schema = Arc::new(reader_schema);
+
+    if let IR::Scan {
+        file_info,
+        output_schema,
+        ..
+    } = lp_arena.get_mut(node)
+    {
+        file_info.schema = to_categorical(&file_info.schema);
+        file_info.reader_schema = Some(Either::Left(reader_schema));
+        *output_schema = output_schema.as_ref().map(to_categorical);
+    }
+    for node in chain {
+        match lp_arena.get_mut(node) {
+            IR::Select { schema, .. } => *schema = to_categorical(schema),
+            IR::SimpleProjection { columns, .. } => *columns = to_categorical(columns),
+            _ => {},
+        }
+    }
+    if let IR::Sink { payload, .. } = lp_arena.get_mut(root) {
+        match payload {
+            SinkType::File {
+                file_type: FileType::Parquet(options),
+                ..
+            } => options.categoricals_as_strings = true,
+            #[cfg(feature = "cloud")]
+            SinkType::Cloud {
+                file_type: FileType::Parquet(options),
+                ..
+            } => options.categoricals_as_strings = true,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Whether the column `name` is dictionary-encoded in every row group.
+fn is_dictionary_encoded(metadata: &FileMetaData, name: &str) -> bool {
+    !metadata.row_groups.is_empty()
+        && metadata.row_groups.iter().all(|rg| {
+            rg.columns().iter().any(|column| {
+                column.descriptor().path_in_schema == [name] && column.is_dictionary_encoded()
+            })
+        })
+}
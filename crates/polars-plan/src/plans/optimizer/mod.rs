@@ -12,9 +12,13 @@ mod count_star;
 #[cfg(feature = "cse")]
 mod cse;
 mod flatten_union;
+#[cfg(feature = "dtype-array")]
+mod fuse_quantiles;
 #[cfg(feature = "fused")]
 mod fused;
-mod join_utils;
+mod join_reorder;
+#[cfg(all(feature = "parquet", feature = "dtype-categorical"))]
+mod keep_parquet_dictionaries;
 mod predicate_pushdown;
 mod projection_pushdown;
 mod simplify_expr;
@@ -31,7 +35,7 @@ pub use predicate_pushdown::PredicatePushDown;
 pub use projection_pushdown::ProjectionPushDown;
 pub use simplify_expr::{SimplifyBooleanRule, SimplifyExprRule};
 use slice_pushdown_lp::SlicePushDown;
-pub use stack_opt::{OptimizationRule, StackOptimizer};
+pub use stack_opt::{OptimizationRule, SharedOptimizationRule, StackOptimizer};
 
 use self::flatten_union::FlattenUnionRule;
 pub use crate::frame::{AllowedOptimizations, OptState};
@@ -64,6 +68,7 @@ pub fn optimize(
     expr_arena: &mut Arena<AExpr>,
     scratch: &mut Vec<Node>,
     hive_partition_eval: HiveEval<'_>,
+    user_rules: &[SharedOptimizationRule],
 ) -> PolarsResult<Node> {
     #[allow(dead_code)]
     let verbose = verbose();
@@ -76,6 +81,7 @@ pub fn optimize(
     let slice_pushdown = opt_state.slice_pushdown;
     let streaming = opt_state.streaming;
     let fast_projection = opt_state.fast_projection;
+    let join_reorder = opt_state.join_reorder;
     // Don't run optimizations that don't make sense on a single node.
     // This keeps eager execution more snappy.
     let eager = opt_state.eager;
@@ -107,13 +113,15 @@ pub fn optimize(
 
     // Collect members for optimizations that need it.
     let mut members = MemberCollector::new();
-    if !eager && (comm_subexpr_elim || projection_pushdown) {
+    if !eager && (comm_subexpr_elim || projection_pushdown || join_reorder) {
         members.collect(lp_top, lp_arena, expr_arena)
     }
 
     if simplify_expr {
         #[cfg(feature = "fused")]
         rules.push(Box::new(fused::FusedArithmetic {}));
+        #[cfg(feature = "dtype-array")]
+        rules.push(Box::new(fuse_quantiles::FuseQuantiles {}));
     }
 
     #[cfg(feature = "cse")]
@@ -138,6 +146,11 @@ pub fn optimize(
     #[cfg(not(feature = "cse"))]
     let _cse_plan_changed = false;
 
+    // Runs before the pushdowns, so that they can push into the reordered joins.
+    if join_reorder && !eager && members.has_joins_or_unions {
+        join_reorder::reorder_joins(lp_top, lp_arena, expr_arena);
+    }
+
     // Should be run before predicate pushdown.
     if projection_pushdown {
         let mut projection_pushdown_opt = ProjectionPushDown::new();
@@ -191,6 +204,11 @@ pub fn optimize(
         rules.push(Box::new(FlattenUnionRule {}));
     }
 
+    // Rules registered by the user run after the built-in rules.
+    for rule in user_rules {
+        rules.push(Box::new(rule.clone()));
+    }
+
     lp_top = opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top)?;
 
     if members.has_joins_or_unions && members.has_cache && _cse_plan_changed {
@@ -217,6 +235,11 @@ pub fn optimize(
         })?;
     }
 
+    #[cfg(all(feature = "parquet", feature = "dtype-categorical"))]
+    if !eager {
+        keep_parquet_dictionaries::keep_parquet_dictionaries(lp_top, lp_arena, expr_arena, verbose);
+    }
+
     // During debug we check if the optimizations have not modified the final schema.
     #[cfg(debug_assertions)]
     {
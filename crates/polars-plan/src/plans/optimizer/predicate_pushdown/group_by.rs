@@ -22,6 +22,10 @@ pub(super) fn process_group_by(
     #[cfg(not(feature = "dynamic_group_by"))]
     let no_push = false;
 
+    // A key that is not in a grouping set is null in the rows of that set.
+    #[cfg(feature = "grouping_sets")]
+    let no_push = no_push || options.grouping_sets.is_some();
+
     // Don't pushdown predicates on these cases.
     if apply.is_some() || no_push || options.slice.is_some() {
         let lp = GroupBy {
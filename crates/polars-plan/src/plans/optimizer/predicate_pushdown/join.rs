@@ -1,5 +1,4 @@
 use super::*;
-use crate::plans::optimizer::join_utils::split_suffix;
 
 // Information concerning individual sides of a join.
 #[derive(PartialEq, Eq)]
@@ -33,7 +32,8 @@ fn should_block_join_specific(
         Function {
             function:
                 FunctionExpr::Boolean(BooleanFunction::IsUnique)
-                | FunctionExpr::Boolean(BooleanFunction::IsDuplicated),
+                | FunctionExpr::Boolean(BooleanFunction::IsDuplicated)
+                | FunctionExpr::Boolean(BooleanFunction::IsDuplicatedKeep { .. }),
             ..
         } => LeftRight(true, true),
         #[cfg(feature = "is_first_distinct")]
@@ -103,7 +103,7 @@ fn predicate_applies_to_both_tables(
     expr_arena: &Arena<AExpr>,
     schema_left: &Schema,
     schema_right: &Schema,
-    suffix: &str,
+    naming: &JoinNaming,
 ) -> bool {
     let mut left_used = false;
     let mut right_used = false;
@@ -112,8 +112,9 @@ fn predicate_applies_to_both_tables(
             left_used |= true;
         } else {
             right_used |= schema_right.contains(name.as_ref())
-                || name.ends_with(suffix)
-                    && schema_right.contains(split_suffix(name.as_ref(), suffix))
+                || naming
+                    .original(name.as_ref())
+                    .is_some_and(|name| schema_right.contains(name))
         }
     }
     left_used && right_used
@@ -158,7 +159,7 @@ pub(super) fn process_join(
                 expr_arena,
                 &schema_left,
                 &schema_right,
-                options.args.suffix(),
+                &options.args.naming(),
             )
         {
             local_predicates.push(predicate);
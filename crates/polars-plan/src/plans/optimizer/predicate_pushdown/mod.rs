@@ -2,15 +2,18 @@ mod group_by;
 mod join;
 mod keys;
 mod rename;
+mod row_index;
 mod utils;
 
 use polars_core::datatypes::PlHashMap;
 use polars_core::prelude::*;
 use recursive::recursive;
+use row_index::row_index_n_rows;
 use utils::*;
 
 use super::*;
 use crate::dsl::function_expr::FunctionExpr;
+use crate::plans::hive::hive_partitions_to_frame;
 use crate::prelude::optimizer::predicate_pushdown::group_by::process_group_by;
 use crate::prelude::optimizer::predicate_pushdown::join::process_join;
 use crate::prelude::optimizer::predicate_pushdown::rename::process_rename;
@@ -324,13 +327,25 @@ impl<'a> PredicatePushDown<'a> {
                 hive_parts: mut scan_hive_parts,
                 ref predicate,
                 mut scan_type,
-                file_options: options,
+                file_options: mut options,
                 output_schema,
             } => {
                 for e in acc_predicates.values() {
                     debug_assert_aexpr_allows_predicate_pushdown(e.node(), expr_arena);
                 }
 
+                // The rows after the end of a row range that is selected on the row index are not
+                // read. The predicate is kept, it also applies the start of the range.
+                if let Some(row_index) = &options.row_index {
+                    let n_rows = acc_predicates
+                        .values()
+                        .filter_map(|e| row_index_n_rows(e.node(), row_index, expr_arena))
+                        .min();
+                    if let Some(n_rows) = n_rows {
+                        options.n_rows = Some(options.n_rows.map_or(n_rows, |n| n.min(n_rows)));
+                    }
+                }
+
                 let local_predicates = match &scan_type {
                     #[cfg(feature = "parquet")]
                     FileScan::Parquet { .. } => vec![],
@@ -349,49 +364,88 @@ impl<'a> PredicatePushDown<'a> {
                         }
                     },
                 };
+                // Predicates that only refer to hive columns can be evaluated exactly on the
+                // partition values of every file.
+                let hive_predicate = scan_hive_parts.as_ref().and_then(|hive_parts| {
+                    let hive_schema = hive_parts.first()?.schema();
+                    let hive_only = acc_predicates
+                        .values()
+                        .filter(|e| {
+                            let mut names =
+                                aexpr_to_leaf_names_iter(e.node(), expr_arena).peekable();
+                            names.peek().is_some()
+                                && names.all(|name| hive_schema.contains(name.as_ref()))
+                        })
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    (!hive_only.is_empty())
+                        .then(|| combine_predicates(hive_only.into_iter(), expr_arena))
+                });
+
                 let predicate = predicate_at_scan(acc_predicates, predicate.clone(), expr_arena);
 
                 if let (Some(hive_parts), Some(predicate)) = (&scan_hive_parts, &predicate) {
-                    if let Some(io_expr) = self.hive_partition_eval.unwrap()(predicate, expr_arena)
-                    {
-                        if let Some(stats_evaluator) = io_expr.as_stats_evaluator() {
-                            let mut new_paths = Vec::with_capacity(paths.len());
-                            let mut new_hive_parts = Vec::with_capacity(paths.len());
-
-                            for i in 0..paths.len() {
-                                let path = &paths[i];
-                                let hive_parts = &hive_parts[i];
-
-                                if stats_evaluator.should_read(hive_parts.get_statistics())? {
-                                    new_paths.push(path.clone());
-                                    new_hive_parts.push(hive_parts.clone());
-                                }
-                            }
+                    let eval = self.hive_partition_eval.unwrap();
+                    let mask = match hive_predicate.as_ref().and_then(|e| eval(e, expr_arena)) {
+                        Some(io_expr) => {
+                            let df = hive_partitions_to_frame(hive_parts)?;
+                            let mask = io_expr.evaluate_io(&df)?;
+                            let mask = mask.bool()?;
+                            polars_ensure!(
+                                mask.len() == hive_parts.len(),
+                                ComputeError: "hive partition predicate did not produce a value per file"
+                            );
+                            Some(mask.iter().map(|v| v.unwrap_or(false)).collect::<Vec<_>>())
+                        },
+                        None => match eval(predicate, expr_arena) {
+                            Some(io_expr) => match io_expr.as_stats_evaluator() {
+                                Some(stats_evaluator) => Some(
+                                    hive_parts
+                                        .iter()
+                                        .map(|hp| stats_evaluator.should_read(hp.get_statistics()))
+                                        .collect::<PolarsResult<Vec<_>>>()?,
+                                ),
+                                None => None,
+                            },
+                            None => None,
+                        },
+                    };
 
-                            if paths.len() != new_paths.len() {
-                                if self.verbose {
-                                    eprintln!(
-                                        "hive partitioning: skipped {} files, first file : {}",
-                                        paths.len() - new_paths.len(),
-                                        paths[0].display()
-                                    )
-                                }
-                                scan_type.remove_metadata();
+                    if let Some(mask) = mask {
+                        let mut new_paths = Vec::with_capacity(paths.len());
+                        let mut new_hive_parts = Vec::with_capacity(paths.len());
+
+                        for i in 0..paths.len() {
+                            if mask[i] {
+                                new_paths.push(paths[i].clone());
+                                new_hive_parts.push(hive_parts[i].clone());
                             }
-                            if new_paths.is_empty() {
-                                let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
-                                let df = DataFrame::empty_with_schema(schema);
-
-                                return Ok(DataFrameScan {
-                                    df: Arc::new(df),
-                                    schema: schema.clone(),
-                                    output_schema: None,
-                                    filter: None,
-                                });
-                            } else {
-                                paths = Arc::from(new_paths);
-                                scan_hive_parts = Some(Arc::from(new_hive_parts));
+                        }
+
+                        if paths.len() != new_paths.len() {
+                            if self.verbose {
+                                eprintln!(
+                                    "hive partitioning: skipped {} files, first file : {}",
+                                    paths.len() - new_paths.len(),
+                                    paths[0].display()
+                                )
                             }
+                            scan_type.remove_metadata();
+                            options.hive_pruned_files += paths.len() - new_paths.len();
+                        }
+                        if new_paths.is_empty() {
+                            let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
+                            let df = DataFrame::empty_with_schema(schema);
+
+                            return Ok(DataFrameScan {
+                                df: Arc::new(df),
+                                schema: schema.clone(),
+                                output_schema: None,
+                                filter: None,
+                            });
+                        } else {
+                            paths = Arc::from(new_paths);
+                            scan_hive_parts = Some(Arc::from(new_hive_parts));
                         }
                     }
                 }
@@ -400,6 +454,7 @@ impl<'a> PredicatePushDown<'a> {
                     #[cfg(feature = "csv")]
                     FileScan::Csv { .. } => options.n_rows.is_none(),
                     FileScan::Anonymous { function, .. } => function.allows_predicate_pushdown(),
+                    FileScan::Source { source } => source.supports_predicate_pushdown(),
                     #[cfg(feature = "json")]
                     FileScan::NDJson { .. } => true,
                     #[allow(unreachable_patterns)]
@@ -479,9 +534,38 @@ impl<'a> PredicatePushDown<'a> {
                 options,
                 acc_predicates,
             ),
-            MapFunction { ref function, .. } => {
+            MapFunction {
+                input,
+                ref function,
+            } => {
                 if function.allow_predicate_pd() {
                     match function {
+                        _ if function.is_row_independent() => {
+                            // The rows of the columns passed around the function don't change,
+                            // so predicates on them can be applied below it.
+                            let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+                            let outputs = function.row_independent_outputs(&input_schema)?;
+                            let condition = |name: Arc<str>| outputs.contains(&name);
+                            let local_predicates = transfer_to_local_by_name(
+                                expr_arena,
+                                &mut acc_predicates,
+                                condition,
+                            );
+
+                            let lp = self.pushdown_and_continue(
+                                lp,
+                                acc_predicates,
+                                lp_arena,
+                                expr_arena,
+                                false,
+                            )?;
+                            Ok(self.optional_apply_predicate(
+                                lp,
+                                local_predicates,
+                                lp_arena,
+                                expr_arena,
+                            ))
+                        },
                         FunctionNode::Rename { existing, new, .. } => {
                             let local_predicates =
                                 process_rename(&mut acc_predicates, expr_arena, existing, new)?;
@@ -0,0 +1,77 @@
+use polars_io::RowIndex;
+
+use super::*;
+
+/// The number of rows a scan with `row_index` has to read, if `predicate` only keeps rows with
+/// a row index below a bound. This turns a filter on a row range into a slice of the scan.
+pub(super) fn row_index_n_rows(
+    predicate: Node,
+    row_index: &RowIndex,
+    expr_arena: &Arena<AExpr>,
+) -> Option<usize> {
+    let end = row_index_end(predicate, &row_index.name, expr_arena)?;
+    let n_rows = (end - row_index.offset as i128).clamp(0, usize::MAX as i128);
+    Some(n_rows as usize)
+}
+
+/// The exclusive upper bound of the row indices that pass `predicate`.
+fn row_index_end(node: Node, name: &str, expr_arena: &Arena<AExpr>) -> Option<i128> {
+    let is_row_index =
+        |node: Node| matches!(expr_arena.get(node), AExpr::Column(c) if c.as_ref() == name);
+    match expr_arena.get(node) {
+        AExpr::BinaryExpr { left, op, right } => match op {
+            Operator::And | Operator::LogicalAnd => {
+                let left = row_index_end(*left, name, expr_arena);
+                let right = row_index_end(*right, name, expr_arena);
+                match (left, right) {
+                    (Some(left), Some(right)) => Some(left.min(right)),
+                    (left, right) => left.or(right),
+                }
+            },
+            Operator::Or | Operator::LogicalOr => {
+                let left = row_index_end(*left, name, expr_arena)?;
+                let right = row_index_end(*right, name, expr_arena)?;
+                Some(left.max(right))
+            },
+            // `index < value` or `value > index`
+            Operator::Lt if is_row_index(*left) => integer_literal(*right, expr_arena),
+            Operator::Gt if is_row_index(*right) => integer_literal(*left, expr_arena),
+            Operator::LtEq | Operator::Eq if is_row_index(*left) => {
+                Some(integer_literal(*right, expr_arena)? + 1)
+            },
+            Operator::GtEq | Operator::Eq if is_row_index(*right) => {
+                Some(integer_literal(*left, expr_arena)? + 1)
+            },
+            _ => None,
+        },
+        #[cfg(feature = "is_between")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::Boolean(BooleanFunction::IsBetween { closed }),
+            ..
+        } if is_row_index(input[0].node()) => {
+            let upper = integer_literal(input[2].node(), expr_arena)?;
+            match closed {
+                ClosedInterval::Both | ClosedInterval::Right => Some(upper + 1),
+                ClosedInterval::None | ClosedInterval::Left => Some(upper),
+            }
+        },
+        _ => None,
+    }
+}
+
+fn integer_literal(node: Node, expr_arena: &Arena<AExpr>) -> Option<i128> {
+    match expr_arena.get(node) {
+        AExpr::Literal(lv) => {
+            let av = lv.to_any_value()?;
+            if av.dtype().is_integer() {
+                av.extract()
+            } else {
+                None
+            }
+        },
+        // Literals are cast to the type of the row index.
+        AExpr::Cast { expr, .. } => integer_literal(*expr, expr_arena),
+        _ => None,
+    }
+}
@@ -81,6 +81,57 @@ pub(super) fn process_functions(
                 expr_arena,
             )
         },
+        _ if function
+            .columns_used()
+            .is_some_and(|columns| !columns.is_empty()) =>
+        {
+            // The function declared what it reads, so the input only has to provide those
+            // columns. What is projected from the output is applied on top of the function.
+            let mut input_projections = vec![];
+            let mut input_names = PlHashSet::new();
+            for name in function.columns_used().unwrap() {
+                let node = expr_arena.add(AExpr::Column(name.clone()));
+                add_expr_to_accumulated(node, &mut input_projections, &mut input_names, expr_arena);
+            }
+            if function.is_row_independent() {
+                if acc_projections.is_empty() {
+                    // All columns are passed around the function.
+                    input_projections.clear();
+                    input_names.clear();
+                } else {
+                    // Projected columns that the function doesn't write are passed around it.
+                    let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+                    let outputs = function.row_independent_outputs(&input_schema)?;
+                    for node in &acc_projections {
+                        let name = column_node_to_name(*node, expr_arena);
+                        if !outputs.contains(&name) && input_schema.contains(&name) {
+                            add_expr_to_accumulated(
+                                node.0,
+                                &mut input_projections,
+                                &mut input_names,
+                                expr_arena,
+                            );
+                        }
+                    }
+                }
+            }
+            proj_pd.pushdown_and_assign(
+                input,
+                input_projections,
+                input_names,
+                projections_seen,
+                lp_arena,
+                expr_arena,
+            )?;
+
+            function.clear_cached_schema();
+            let lp = IR::MapFunction {
+                input,
+                function: function.clone(),
+            };
+            let builder = IRBuilder::from_lp(lp, expr_arena, lp_arena);
+            Ok(proj_pd.finish_node_simple_projection(&acc_projections, builder))
+        },
         _ => {
             if function.allow_projection_pd() && !acc_projections.is_empty() {
                 let original_acc_projection_len = acc_projections.len();
@@ -2,7 +2,6 @@
 use std::borrow::Cow;
 
 use super::*;
-use crate::prelude::optimizer::join_utils::split_suffix;
 
 fn add_keys_to_accumulated_state(
     expr: Node,
@@ -280,9 +279,12 @@ pub(super) fn process_join(
                 }
                 let key_name = e.output_name();
 
-                // If the name is in the lhs table, a suffix is added.
+                // If the name is in the lhs table, the column is renamed.
                 let key_name_after_join = if schema_left.contains(key_name) {
-                    Cow::Owned(_join_suffix_name(key_name, options.args.suffix()))
+                    match options.args.naming().rename(key_name) {
+                        Ok(name) => Cow::Owned(name),
+                        Err(_) => return true,
+                    }
                 } else {
                     Cow::Borrowed(key_name)
                 };
@@ -393,7 +395,7 @@ fn process_projection(
     join_schema: &Schema,
 ) {
     // Path for renamed columns due to the join. The column name of the left table
-    // stays as is, the column of the right will have the "_right" suffix.
+    // stays as is, the column of the right is renamed, by default with the "_right" suffix.
     // Thus joining two tables with both a foo column leads to ["foo", "foo_right"]
 
     // try to push down projection in either of two tables
@@ -416,12 +418,14 @@ fn process_projection(
         // Column name of the projection without any alias.
         let leaf_column_name = column_node_to_name(proj, expr_arena);
 
-        let suffix = options.args.suffix();
-        // If _right suffix exists we need to push a projection down without this
-        // suffix.
-        if leaf_column_name.ends_with(suffix) && join_schema.contains(leaf_column_name.as_ref()) {
+        let naming = options.args.naming();
+        // If the column was renamed by the join we need to push a projection down with its
+        // original name.
+        if let (Some(downwards_name), true) = (
+            naming.original(leaf_column_name.as_ref()),
+            join_schema.contains(leaf_column_name.as_ref()),
+        ) {
             // downwards name is the name without the _right i.e. "foo".
-            let downwards_name = split_suffix(leaf_column_name.as_ref(), suffix);
 
             let downwards_name_column = expr_arena.add(AExpr::Column(Arc::from(downwards_name)));
             // project downwards and locally immediately alias to prevent wrong projections
@@ -470,7 +474,7 @@ fn resolve_join_suffixes(
     expr_arena: &mut Arena<AExpr>,
     local_projection: &[ColumnNode],
 ) -> IR {
-    let suffix = options.args.suffix();
+    let naming = options.args.naming();
     let alp = IRBuilder::new(input_left, expr_arena, lp_arena)
         .join(input_right, left_on, right_on, options.clone())
         .build();
@@ -480,8 +484,8 @@ fn resolve_join_suffixes(
         .iter()
         .map(|proj| {
             let name = column_node_to_name(*proj, expr_arena);
-            if name.ends_with(suffix) && schema_after_join.get(&name).is_none() {
-                let downstream_name = &name.as_ref()[..name.len() - suffix.len()];
+            let original = naming.original(name.as_ref());
+            if let (Some(downstream_name), None) = (original, schema_after_join.get(&name)) {
                 let col = AExpr::Column(ColumnName::from(downstream_name));
                 let node = expr_arena.add(col);
                 ExprIR::new(node, OutputName::Alias(name))
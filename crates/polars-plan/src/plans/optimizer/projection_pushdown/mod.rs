@@ -405,6 +405,7 @@ impl ProjectionPushDown {
                     FileScan::Anonymous { ref function, .. } => {
                         function.allows_projection_pushdown()
                     },
+                    FileScan::Source { ref source } => source.supports_projection_pushdown(),
                     #[cfg(feature = "json")]
                     FileScan::NDJson { .. } => true,
                     #[cfg(feature = "ipc")]
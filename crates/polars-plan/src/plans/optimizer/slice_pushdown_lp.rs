@@ -185,6 +185,31 @@ impl SlicePushDown {
 
                 self.no_pushdown_finish_opt(lp, Some(state), lp_arena)
             },
+            // A tail only needs the last files of a Parquet scan. The slice is still applied
+            // on top of the scan, as the skipped files are only known at execution.
+            #[cfg(feature = "parquet")]
+            (Scan {
+                paths,
+                file_info,
+                hive_parts,
+                output_schema,
+                mut file_options,
+                predicate,
+                scan_type: scan_type @ FileScan::Parquet { .. },
+            }, Some(state)) if state.offset < 0 && predicate.is_none() && file_options.n_rows.is_none() => {
+                file_options.tail = Some(state.offset.unsigned_abs() as usize);
+                let lp = Scan {
+                    paths,
+                    file_info,
+                    hive_parts,
+                    output_schema,
+                    scan_type,
+                    file_options,
+                    predicate,
+                };
+
+                self.no_pushdown_finish_opt(lp, Some(state), lp_arena)
+            },
             // TODO! we currently skip slice pushdown if there is a predicate.
             (Scan {
                 paths,
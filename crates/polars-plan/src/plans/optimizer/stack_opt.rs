@@ -1,3 +1,5 @@
+use std::sync::{Arc, Mutex};
+
 use polars_core::prelude::PolarsResult;
 
 use crate::plans::aexpr::AExpr;
@@ -82,6 +84,12 @@ impl StackOptimizer {
     }
 }
 
+/// A rewrite of the [`IR`] and its [`AExpr`] expressions.
+///
+/// Rules are run by the [`StackOptimizer`], which visits every plan node and every non-leaf
+/// expression and keeps applying the rules until none of them returns a replacement.
+/// A rule must therefore reach a fixed point: it should return `None` once the node has been
+/// rewritten. Replacements must not change the schema of a plan node.
 pub trait OptimizationRule {
     ///  Optimize (subplan) in LogicalPlan
     ///
@@ -106,3 +114,47 @@ pub trait OptimizationRule {
         Ok(None)
     }
 }
+
+/// An [`OptimizationRule`] that can be shared between queries.
+///
+/// The rule is run under a lock, so queries that share it are optimized one at a time.
+#[derive(Clone)]
+pub struct SharedOptimizationRule(Arc<Mutex<Box<dyn OptimizationRule + Send>>>);
+
+impl SharedOptimizationRule {
+    pub fn new(rule: Box<dyn OptimizationRule + Send>) -> Self {
+        Self(Arc::new(Mutex::new(rule)))
+    }
+
+    /// Whether `self` and `other` are the same rule.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl OptimizationRule for SharedOptimizationRule {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<IR> {
+        self.0
+            .lock()
+            .unwrap()
+            .optimize_plan(lp_arena, expr_arena, node)
+    }
+
+    fn optimize_expr(
+        &mut self,
+        expr_arena: &mut Arena<AExpr>,
+        expr_node: Node,
+        lp_arena: &Arena<IR>,
+        lp_node: Node,
+    ) -> PolarsResult<Option<AExpr>> {
+        self.0
+            .lock()
+            .unwrap()
+            .optimize_expr(expr_arena, expr_node, lp_arena, lp_node)
+    }
+}
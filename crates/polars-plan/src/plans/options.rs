@@ -1,23 +1,34 @@
+use std::borrow::Cow;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
 #[cfg(feature = "json")]
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
+use std::sync::mpsc::SyncSender;
 
 use polars_core::prelude::*;
 use polars_core::utils::SuperTypeOptions;
+#[cfg(any(feature = "csv", feature = "json"))]
+use polars_io::compression::OutputCompression;
 #[cfg(feature = "csv")]
 use polars_io::csv::write::CsvWriterOptions;
+#[cfg(feature = "ipc_streaming")]
+use polars_io::ipc::IpcStreamWriterOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
 use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::write::ParquetWriteOptions;
+use polars_io::write_mode::WriteMode;
 use polars_io::{HiveOptions, RowIndex};
 #[cfg(feature = "dynamic_group_by")]
 use polars_time::{DynamicGroupOptions, RollingGroupOptions};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "dynamic_group_by")]
+use crate::plans::LiteralValue;
 #[cfg(feature = "python")]
 use crate::prelude::python_udf::PythonFunction;
 
@@ -28,12 +39,22 @@ pub type FileCount = u32;
 /// Generic options for all file types.
 pub struct FileScanOptions {
     pub n_rows: Option<usize>,
+    /// Only the last `tail` rows of the scan are needed. Sources that know the number of rows
+    /// of their files may skip the files before these rows. The slice itself is still applied
+    /// by the query.
+    pub tail: Option<usize>,
     pub with_columns: Option<Arc<[String]>>,
     pub cache: bool,
     pub row_index: Option<RowIndex>,
     pub rechunk: bool,
     pub file_counter: FileCount,
     pub hive_options: HiveOptions,
+    /// Skip the files that don't exist with a warning, instead of raising an error when the scan
+    /// is planned.
+    pub allow_missing_files: bool,
+    /// Number of files skipped by the optimizer based on their hive partition values.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub hive_pruned_files: usize,
 }
 
 #[derive(Clone, Debug, Copy, Default, Eq, PartialEq, Hash)]
@@ -63,6 +84,68 @@ pub struct GroupbyOptions {
     pub rolling: Option<RollingGroupOptions>,
     /// Take only a slice of the result
     pub slice: Option<(i64, usize)>,
+    /// Add the windows without data to the result of a dynamic group by and fill them.
+    #[cfg(feature = "dynamic_group_by")]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub resample: Option<ResampleFill>,
+    /// Aggregate over several sets of the keys at once.
+    #[cfg(feature = "grouping_sets")]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub grouping_sets: Option<GroupingSets>,
+}
+
+/// The sets of keys of a `GROUPING SETS` aggregation.
+///
+/// Every set aggregates the input by a subset of the keys of the group by. The keys
+/// that are not in a set are null in the rows of that set.
+#[cfg(feature = "grouping_sets")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GroupingSets {
+    /// Indices into the keys of the group by.
+    pub sets: Vec<Vec<usize>>,
+    /// Name of a `UInt64` column that identifies the set of every row. A bit is set for
+    /// every key that is not grouped, the first key being the most significant bit.
+    pub grouping_id: Option<Arc<str>>,
+    /// Name of a `UInt32` column with the number of keys in the set of every row, e.g. the
+    /// depth of the row in a hierarchy of keys.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub level: Option<Arc<str>>,
+}
+
+/// How the aggregated columns of the windows without data are filled after resampling.
+#[cfg(feature = "dynamic_group_by")]
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ResampleFill {
+    /// Leave them null.
+    Null,
+    /// Fill them with the previous window of the same group.
+    Forward,
+    /// Fill them with a value.
+    Value(LiteralValue),
+}
+
+#[cfg(feature = "dynamic_group_by")]
+impl Eq for ResampleFill {}
+
+/// The quantiles of the `quantiles` aggregation, which are computed together.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QuantilesOptions {
+    pub quantiles: Arc<[f64]>,
+    pub interpol: QuantileInterpolOptions,
+}
+
+impl Eq for QuantilesOptions {}
+
+impl Hash for QuantilesOptions {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for q in self.quantiles.iter() {
+            q.to_bits().hash(state)
+        }
+        self.interpol.hash(state)
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
@@ -240,6 +323,154 @@ pub enum SinkType {
         file_type: FileType,
         cloud_options: Option<polars_io::cloud::CloudOptions>,
     },
+    Partition {
+        path: Arc<PathBuf>,
+        file_type: FileType,
+        options: PartitionSinkOptions,
+    },
+    /// Hand the batches to a receiver as they are produced.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Batches(BatchSender),
+    /// Write the batches with a sink implemented outside of Polars.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Plugin(SinkPluginRef),
+}
+
+/// Sends the batches of a [`SinkType::Batches`] sink, in the order of the query output.
+#[derive(Clone)]
+pub struct BatchSender(Arc<SyncSender<DataFrame>>);
+
+impl BatchSender {
+    pub fn new(sender: SyncSender<DataFrame>) -> Self {
+        Self(Arc::new(sender))
+    }
+
+    /// Send a batch, returns `false` if the receiver has been dropped.
+    pub fn send(&self, df: DataFrame) -> bool {
+        self.0.send(df).is_ok()
+    }
+}
+
+impl Debug for BatchSender {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("BatchSender")
+    }
+}
+
+impl PartialEq for BatchSender {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for BatchSender {}
+
+impl Hash for BatchSender {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).hash(state)
+    }
+}
+
+/// A sink for the output of a streaming query that is implemented outside of Polars, e.g. to
+/// insert into a database or to write a custom file format.
+///
+/// [`SinkPlugin::open`] is called once before the first batch, and [`SinkPlugin::finalize`]
+/// once after the last batch if the query succeeded. Batches can be empty.
+pub trait SinkPlugin: Send + Sync {
+    /// The name of the sink in the query plan.
+    fn name(&self) -> &str;
+
+    /// Prepare for batches with `schema`.
+    fn open(&self, schema: &Schema) -> PolarsResult<()>;
+
+    fn write_batch(&self, df: &DataFrame) -> PolarsResult<()>;
+
+    /// Complete the output, after all batches were written.
+    fn finalize(&self) -> PolarsResult<()>;
+
+    /// Whether the batches can be written from multiple threads at once and in any order.
+    ///
+    /// By default, the batches are written one at a time and in the order of the query output,
+    /// from a dedicated thread.
+    fn parallel(&self) -> bool {
+        false
+    }
+}
+
+/// The [`SinkPlugin`] of a [`SinkType::Plugin`] sink.
+#[derive(Clone)]
+pub struct SinkPluginRef(pub Arc<dyn SinkPlugin>);
+
+impl Debug for SinkPluginRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SinkPlugin({})", self.0.name())
+    }
+}
+
+impl PartialEq for SinkPluginRef {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SinkPluginRef {}
+
+impl Hash for SinkPluginRef {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<()>().hash(state)
+    }
+}
+
+/// Options for sinking into a hive-style `key=value/` directory tree.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PartitionSinkOptions {
+    /// Columns that determine the partition directory of a row.
+    pub partition_by: Vec<String>,
+    /// Start a new file in a partition once the current file holds this many rows.
+    pub max_rows_per_file: Option<usize>,
+    /// Template for the file names within a partition directory.
+    ///
+    /// `{part}` is replaced by the file index within the partition and `{ext}` by the
+    /// extension of the file type. Defaults to `data-{part}.{ext}`.
+    pub file_name_template: Option<String>,
+}
+
+impl PartitionSinkOptions {
+    pub fn new<I, S>(partition_by: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            partition_by: partition_by
+                .into_iter()
+                .map(|s| s.as_ref().to_string())
+                .collect(),
+            max_rows_per_file: None,
+            file_name_template: None,
+        }
+    }
+
+    pub fn with_max_rows_per_file(mut self, max_rows_per_file: Option<usize>) -> Self {
+        self.max_rows_per_file = max_rows_per_file;
+        self
+    }
+
+    pub fn with_file_name_template(mut self, template: Option<String>) -> Self {
+        self.file_name_template = template;
+        self
+    }
+
+    /// Resolve the file name of the `part`-th file of a partition.
+    pub fn file_name(&self, part: usize, extension: &str) -> String {
+        match &self.file_name_template {
+            Some(template) => template
+                .replace("{part}", &format!("{part:04}"))
+                .replace("{ext}", extension),
+            None => format!("data-{part:04}.{extension}"),
+        }
+    }
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -256,12 +487,65 @@ pub enum FileType {
     Parquet(ParquetWriteOptions),
     #[cfg(feature = "ipc")]
     Ipc(IpcWriterOptions),
+    #[cfg(feature = "ipc_streaming")]
+    IpcStream(IpcStreamWriterOptions),
     #[cfg(feature = "csv")]
     Csv(CsvWriterOptions),
     #[cfg(feature = "json")]
     Json(JsonWriterOptions),
 }
 
+impl FileType {
+    /// The default file extension of this file type, followed by the extension of the
+    /// compression of CSV and NDJSON files.
+    pub fn extension(&self) -> Cow<'static, str> {
+        #[cfg(any(feature = "csv", feature = "json"))]
+        fn compressed(
+            extension: &'static str,
+            compression: Option<OutputCompression>,
+        ) -> Cow<'static, str> {
+            match compression {
+                Some(compression) => format!("{extension}.{}", compression.extension()).into(),
+                None => extension.into(),
+            }
+        }
+
+        match self {
+            #[cfg(feature = "parquet")]
+            Self::Parquet(_) => "parquet".into(),
+            #[cfg(feature = "ipc")]
+            Self::Ipc(_) => "ipc".into(),
+            #[cfg(feature = "ipc_streaming")]
+            Self::IpcStream(_) => "arrows".into(),
+            #[cfg(feature = "csv")]
+            Self::Csv(options) => compressed("csv", options.compression),
+            #[cfg(feature = "json")]
+            Self::Json(options) => compressed("jsonl", options.compression),
+            #[allow(unreachable_patterns)]
+            _ => unreachable!(),
+        }
+    }
+
+    /// What to do if the file that is written exists.
+    pub fn write_mode(&self) -> WriteMode {
+        match self {
+            #[cfg(feature = "parquet")]
+            Self::Parquet(options) => options.write_mode,
+            #[cfg(feature = "ipc")]
+            Self::Ipc(options) => options.write_mode,
+            // IPC streams have no write mode, they always replace the file.
+            #[cfg(feature = "ipc_streaming")]
+            Self::IpcStream(_) => WriteMode::Overwrite,
+            #[cfg(feature = "csv")]
+            Self::Csv(options) => options.write_mode,
+            #[cfg(feature = "json")]
+            Self::Json(options) => options.write_mode,
+            #[allow(unreachable_patterns)]
+            _ => unreachable!(),
+        }
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct ProjectionOptions {
@@ -330,6 +614,52 @@ impl From<UnionArgs> for UnionOptions {
     }
 }
 
+/// Set operation between two frames. The columns of both inputs are matched by position, or
+/// by name if `by_name` is set.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FrameSetOperation {
+    /// Distinct rows of the left input that occur in the right input.
+    #[cfg(feature = "semi_anti_join")]
+    Intersect { by_name: bool },
+    /// Distinct rows of the left input that don't occur in the right input.
+    #[cfg(feature = "semi_anti_join")]
+    Except { by_name: bool },
+    /// Rows of both inputs, deduplicated if `distinct` is set.
+    Union { by_name: bool, distinct: bool },
+}
+
+impl FrameSetOperation {
+    pub fn by_name(&self) -> bool {
+        match self {
+            #[cfg(feature = "semi_anti_join")]
+            Self::Intersect { by_name } | Self::Except { by_name } => *by_name,
+            Self::Union { by_name, .. } => *by_name,
+        }
+    }
+
+    pub fn is_distinct(&self) -> bool {
+        match self {
+            Self::Union { distinct, .. } => *distinct,
+            #[allow(unreachable_patterns)]
+            _ => true,
+        }
+    }
+}
+
+impl Display for FrameSetOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            #[cfg(feature = "semi_anti_join")]
+            Self::Intersect { .. } => "intersect",
+            #[cfg(feature = "semi_anti_join")]
+            Self::Except { .. } => "except",
+            Self::Union { .. } => "union",
+        };
+        write!(f, "{s}")
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg(feature = "json")]
@@ -0,0 +1,464 @@
+//! A process-wide cache of query results.
+//!
+//! When the cache is enabled, the result of a query is stored under its optimized plan. Later
+//! queries that contain the same plan, as a whole or as a part, read the stored result
+//! instead of computing it. Only plans that read local files and have no user-defined or
+//! random functions are cached. A stored result is dropped once one of the files it was
+//! computed from is modified.
+//!
+//! Results are kept in memory up to the memory budget. Beyond that, the least recently used
+//! results are spilled to disk in the IPC format if a spill directory is set, or dropped
+//! otherwise.
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use once_cell::sync::Lazy;
+use polars_core::prelude::*;
+use polars_io::utils::is_cloud_url;
+
+use crate::prelude::*;
+
+static RESULT_CACHE: Lazy<Mutex<Option<ResultCache>>> = Lazy::new(Default::default);
+
+#[derive(Clone, Debug)]
+pub struct ResultCacheConfig {
+    /// Maximum estimated size in bytes of the results that are kept in memory.
+    pub memory_budget: usize,
+    /// Directory that results are spilled to once the memory budget is exceeded. Spilling
+    /// requires the `ipc` feature.
+    pub spill_dir: Option<PathBuf>,
+    /// Maximum size in bytes of the spilled results.
+    pub disk_budget: usize,
+}
+
+impl Default for ResultCacheConfig {
+    fn default() -> Self {
+        Self {
+            memory_budget: 1 << 30,
+            spill_dir: None,
+            disk_budget: 0,
+        }
+    }
+}
+
+/// Enable the result cache, or change the configuration of the enabled cache.
+pub fn enable_result_cache(config: ResultCacheConfig) {
+    let mut cache = RESULT_CACHE.lock().unwrap();
+    match cache.as_mut() {
+        Some(cache) => {
+            cache.config = config;
+            cache.evict();
+        },
+        None => *cache = Some(ResultCache::new(config)),
+    }
+}
+
+/// Disable the result cache and drop all stored results.
+pub fn disable_result_cache() {
+    if let Some(mut cache) = RESULT_CACHE.lock().unwrap().take() {
+        cache.clear()
+    }
+}
+
+/// Drop all stored results, the cache stays enabled.
+pub fn clear_result_cache() {
+    if let Some(cache) = RESULT_CACHE.lock().unwrap().as_mut() {
+        cache.clear()
+    }
+}
+
+pub fn using_result_cache() -> bool {
+    RESULT_CACHE.lock().unwrap().is_some()
+}
+
+/// Replace the parts of the plan under `root` whose results are stored in the result cache
+/// by their results.
+///
+/// Returns the key to store the result of the query under, if the plan can be cached and
+/// its result isn't stored yet.
+pub fn use_cached_results(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<PendingResult> {
+    if !using_result_cache() {
+        return None;
+    }
+    let cacheable = cacheable_nodes(root, lp_arena, expr_arena);
+
+    let mut pending = None;
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if let Some(key) = cacheable
+            .contains(&node)
+            .then(|| PlanKey::new(node, lp_arena, expr_arena))
+            .flatten()
+        {
+            let schema = lp_arena.get(node).schema(lp_arena).into_owned();
+            let cached = RESULT_CACHE
+                .lock()
+                .unwrap()
+                .as_mut()
+                .and_then(|cache| cache.get(&key))
+                .filter(|df| df.schema() == *schema);
+            if let Some(df) = cached {
+                lp_arena.replace(
+                    node,
+                    IR::DataFrameScan {
+                        df: Arc::new(df),
+                        schema,
+                        output_schema: None,
+                        filter: None,
+                    },
+                );
+                continue;
+            }
+            if node == root {
+                pending = Some(PendingResult(key));
+            }
+        }
+        lp_arena.get(node).copy_inputs(&mut stack);
+    }
+    pending
+}
+
+/// The result of a query that can be stored in the result cache once the query has run.
+pub struct PendingResult(PlanKey);
+
+impl PendingResult {
+    pub fn store(self, df: &DataFrame) {
+        if let Some(cache) = RESULT_CACHE.lock().unwrap().as_mut() {
+            cache.insert(self.0, df.clone())
+        }
+    }
+}
+
+/// The nodes whose whole subtree can be cached.
+fn cacheable_nodes(root: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> PlHashSet<Node> {
+    // In reverse pre-order the inputs of a node come before the node.
+    let mut order = vec![];
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        order.push(node);
+        lp_arena.get(node).copy_inputs(&mut stack);
+    }
+
+    let mut cacheable = PlHashSet::new();
+    let mut inputs = vec![];
+    for node in order.into_iter().rev() {
+        let ir = lp_arena.get(node);
+        inputs.clear();
+        ir.copy_inputs(&mut inputs);
+        if is_cacheable(ir, expr_arena) && inputs.iter().all(|input| cacheable.contains(input)) {
+            cacheable.insert(node);
+        }
+    }
+    cacheable
+}
+
+fn local_paths(paths: &[PathBuf]) -> bool {
+    paths.iter().all(|path| !is_cloud_url(path))
+}
+
+fn is_cacheable(ir: &IR, expr_arena: &Arena<AExpr>) -> bool {
+    let cacheable = match ir {
+        IR::Scan {
+            paths, scan_type, ..
+        } => {
+            !matches!(
+                scan_type,
+                FileScan::Anonymous { .. } | FileScan::Source { .. }
+            ) && local_paths(paths)
+        },
+        IR::MapFunction { function, .. } => match function {
+            FunctionNode::Count { paths, .. } => local_paths(paths),
+            FunctionNode::Opaque { .. } | FunctionNode::Pipeline { .. } => false,
+            #[cfg(feature = "python")]
+            FunctionNode::OpaquePython { .. } => false,
+            _ => true,
+        },
+        IR::GroupBy { apply, .. } => apply.is_none(),
+        IR::Filter { .. }
+        | IR::Select { .. }
+        | IR::Reduce { .. }
+        | IR::HStack { .. }
+        | IR::SimpleProjection { .. }
+        | IR::Sort { .. }
+        | IR::Join { .. }
+        | IR::Distinct { .. }
+        | IR::Slice { .. }
+        | IR::Union { .. }
+        | IR::HConcat { .. } => true,
+        // In-memory data isn't identified by the plan, and the ids of caches differ per query.
+        _ => false,
+    };
+    cacheable
+        && ir.get_exprs().iter().all(|e| {
+            expr_arena
+                .iter(e.node())
+                .all(|(_, ae)| is_deterministic(ae))
+        })
+}
+
+fn is_deterministic(ae: &AExpr) -> bool {
+    match ae {
+        AExpr::AnonymousFunction { .. } => false,
+        #[cfg(feature = "random")]
+        AExpr::Function {
+            function: FunctionExpr::Random { .. },
+            ..
+        } => false,
+        #[cfg(feature = "ffi_plugin")]
+        AExpr::Function {
+            function: FunctionExpr::FfiPlugin { .. },
+            ..
+        } => false,
+        #[cfg(feature = "ffi_plugin")]
+        AExpr::Agg(IRAggExpr::Plugin { .. }) => false,
+        _ => true,
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+struct FileStamp {
+    path: PathBuf,
+    modified: SystemTime,
+    len: u64,
+}
+
+impl FileStamp {
+    fn new(path: &Path) -> Option<Self> {
+        let metadata = std::fs::metadata(path).ok()?;
+        Some(Self {
+            path: path.to_path_buf(),
+            modified: metadata.modified().ok()?,
+            len: metadata.len(),
+        })
+    }
+}
+
+/// Identifies the result of a plan.
+struct PlanKey {
+    hash: u64,
+    /// The serialized plan, to tell apart plans with the same hash.
+    plan: Vec<u8>,
+    /// The files that are read by the plan.
+    files: Vec<FileStamp>,
+}
+
+impl PlanKey {
+    fn new(node: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> Option<Self> {
+        let dsl = node_to_lp_cloned(node, expr_arena, lp_arena);
+        let mut plan = vec![];
+        ciborium::into_writer(&dsl, &mut plan).ok()?;
+        let mut hasher = DefaultHasher::new();
+        plan.hash(&mut hasher);
+
+        let mut files = vec![];
+        let mut stack = vec![node];
+        while let Some(node) = stack.pop() {
+            let ir = lp_arena.get(node);
+            match ir {
+                IR::Scan { paths, .. }
+                | IR::MapFunction {
+                    function: FunctionNode::Count { paths, .. },
+                    ..
+                } => {
+                    for path in paths.iter() {
+                        files.push(FileStamp::new(path)?);
+                    }
+                },
+                _ => {},
+            }
+            ir.copy_inputs(&mut stack);
+        }
+
+        Some(Self {
+            hash: hasher.finish(),
+            plan,
+            files,
+        })
+    }
+}
+
+enum StoredResult {
+    Memory(DataFrame),
+    #[cfg(feature = "ipc")]
+    Disk(PathBuf),
+}
+
+struct Entry {
+    plan: Vec<u8>,
+    files: Vec<FileStamp>,
+    result: StoredResult,
+    /// Estimated size in memory, or size of the file on disk.
+    size: usize,
+    last_used: u64,
+}
+
+struct ResultCache {
+    config: ResultCacheConfig,
+    entries: PlHashMap<u64, Entry>,
+    clock: u64,
+    memory_used: usize,
+    disk_used: usize,
+}
+
+impl ResultCache {
+    fn new(config: ResultCacheConfig) -> Self {
+        Self {
+            config,
+            entries: PlHashMap::new(),
+            clock: 0,
+            memory_used: 0,
+            disk_used: 0,
+        }
+    }
+
+    fn get(&mut self, key: &PlanKey) -> Option<DataFrame> {
+        let entry = self.entries.get_mut(&key.hash)?;
+        if entry.plan != key.plan {
+            return None;
+        }
+        if entry.files != key.files {
+            self.remove(key.hash);
+            return None;
+        }
+        self.clock += 1;
+        entry.last_used = self.clock;
+        match &entry.result {
+            StoredResult::Memory(df) => Some(df.clone()),
+            #[cfg(feature = "ipc")]
+            StoredResult::Disk(path) => {
+                let df = read_spilled(path).ok();
+                if df.is_none() {
+                    self.remove(key.hash);
+                }
+                df
+            },
+        }
+    }
+
+    fn insert(&mut self, key: PlanKey, df: DataFrame) {
+        self.remove(key.hash);
+        let size = df.estimated_size();
+        if size > self.config.memory_budget && self.config.spill_dir.is_none() {
+            return;
+        }
+        self.clock += 1;
+        self.memory_used += size;
+        self.entries.insert(
+            key.hash,
+            Entry {
+                plan: key.plan,
+                files: key.files,
+                result: StoredResult::Memory(df),
+                size,
+                last_used: self.clock,
+            },
+        );
+        self.evict();
+    }
+
+    /// Spill or drop the least recently used results until both budgets are met.
+    fn evict(&mut self) {
+        while self.memory_used > self.config.memory_budget {
+            let Some(hash) = self.least_recently_used(true) else {
+                break;
+            };
+            if !self.spill(hash) {
+                self.remove(hash);
+            }
+        }
+        while self.disk_used > self.config.disk_budget {
+            let Some(hash) = self.least_recently_used(false) else {
+                break;
+            };
+            self.remove(hash);
+        }
+    }
+
+    fn least_recently_used(&self, in_memory: bool) -> Option<u64> {
+        self.entries
+            .iter()
+            .filter(|(_, entry)| matches!(entry.result, StoredResult::Memory(_)) == in_memory)
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(hash, _)| *hash)
+    }
+
+    /// Move a result from memory to disk. Returns `false` if it can't be spilled.
+    #[cfg(feature = "ipc")]
+    fn spill(&mut self, hash: u64) -> bool {
+        let Some(dir) = &self.config.spill_dir else {
+            return false;
+        };
+        let entry = self.entries.get_mut(&hash).unwrap();
+        let StoredResult::Memory(df) = &entry.result else {
+            return false;
+        };
+        let path = dir.join(format!(
+            "polars-result-{}-{hash:016x}.arrow",
+            std::process::id()
+        ));
+        let Ok(size) = write_spilled(&path, df) else {
+            let _ = std::fs::remove_file(&path);
+            return false;
+        };
+        if size > self.config.disk_budget {
+            let _ = std::fs::remove_file(&path);
+            return false;
+        }
+        self.memory_used -= entry.size;
+        self.disk_used += size;
+        entry.size = size;
+        entry.result = StoredResult::Disk(path);
+        true
+    }
+
+    #[cfg(not(feature = "ipc"))]
+    fn spill(&mut self, _hash: u64) -> bool {
+        false
+    }
+
+    fn remove(&mut self, hash: u64) {
+        let Some(entry) = self.entries.remove(&hash) else {
+            return;
+        };
+        match entry.result {
+            StoredResult::Memory(_) => self.memory_used -= entry.size,
+            #[cfg(feature = "ipc")]
+            StoredResult::Disk(path) => {
+                self.disk_used -= entry.size;
+                let _ = std::fs::remove_file(path);
+            },
+        }
+    }
+
+    fn clear(&mut self) {
+        let hashes = self.entries.keys().copied().collect::<Vec<_>>();
+        for hash in hashes {
+            self.remove(hash)
+        }
+    }
+}
+
+#[cfg(feature = "ipc")]
+fn write_spilled(path: &Path, df: &DataFrame) -> PolarsResult<usize> {
+    use polars_io::ipc::IpcWriter;
+    use polars_io::SerWriter;
+
+    let file = std::fs::File::create(path)?;
+    IpcWriter::new(file).finish(&mut df.clone())?;
+    Ok(std::fs::metadata(path)?.len() as usize)
+}
+
+#[cfg(feature = "ipc")]
+fn read_spilled(path: &Path) -> PolarsResult<DataFrame> {
+    use polars_io::ipc::IpcReader;
+    use polars_io::SerReader;
+
+    let file = std::fs::File::open(path)?;
+    IpcReader::new(file).finish()
+}
@@ -0,0 +1,153 @@
+use std::fmt::{Debug, Formatter};
+
+use polars_core::prelude::*;
+use polars_io::predicates::PhysicalIoExpr;
+use polars_io::RowIndex;
+
+use crate::dsl::Expr;
+use crate::plans::{FileInfo, FileScanOptions};
+
+/// A stream of [`DataFrame`] batches produced by a [`ScanSource`].
+pub type ScanSourceStream = Box<dyn Iterator<Item = PolarsResult<DataFrame>> + Send>;
+
+/// A predicate pushed down into a [`ScanSource`].
+#[derive(Clone)]
+pub struct ScanPredicate {
+    /// The predicate as an expression, e.g. to translate it into a query on a remote source.
+    pub expr: Expr,
+    /// Evaluates the predicate on a batch or on batch statistics.
+    pub evaluator: Arc<dyn PhysicalIoExpr>,
+}
+
+impl ScanPredicate {
+    /// Filter `df` by the predicate.
+    pub fn apply(&self, df: &DataFrame) -> PolarsResult<DataFrame> {
+        let s = self.evaluator.evaluate_io(df)?;
+        let mask = s
+            .bool()
+            .map_err(|_| polars_err!(ComputeError: "filter predicate was not of type boolean"))?;
+        df.filter(mask)
+    }
+}
+
+/// The pushed-down parts of a query that a [`ScanSource`] is opened with.
+#[derive(Clone)]
+pub struct ScanSourceArgs {
+    /// Schema of the source, as returned by [`ScanSource::schema`].
+    pub schema: SchemaRef,
+    /// Columns to produce. `None` means all columns.
+    ///
+    /// Only set if [`ScanSource::supports_projection_pushdown`] is `true`.
+    pub with_columns: Option<Arc<[String]>>,
+    /// Schema of the batches after the projection, including the row index column.
+    pub output_schema: SchemaRef,
+    /// Only set if [`ScanSource::supports_predicate_pushdown`] is `true`. The source must
+    /// only produce rows for which the predicate holds.
+    pub predicate: Option<ScanPredicate>,
+    /// Maximum number of rows that will be used. This is a hint; the engine stops reading
+    /// once it has received this many rows.
+    pub n_rows: Option<usize>,
+    /// Only set if [`ScanSource::supports_row_index`] is `true`. The source must insert the
+    /// row index as the first column, counting rows before the predicate is applied.
+    pub row_index: Option<RowIndex>,
+}
+
+impl ScanSourceArgs {
+    /// Collect the arguments of a [`FileScan::Source`](crate::plans::FileScan::Source) scan.
+    pub fn new(
+        file_info: &FileInfo,
+        file_options: &FileScanOptions,
+        output_schema: Option<&SchemaRef>,
+        predicate: Option<ScanPredicate>,
+    ) -> Self {
+        let mut schema = file_info.schema.clone();
+        if let Some(row_index) = &file_options.row_index {
+            let mut without_row_index = (*schema).clone();
+            without_row_index.shift_remove(row_index.name.as_ref());
+            schema = Arc::new(without_row_index);
+        }
+        Self {
+            schema,
+            with_columns: file_options.with_columns.clone(),
+            output_schema: output_schema
+                .cloned()
+                .unwrap_or_else(|| file_info.schema.clone()),
+            predicate,
+            n_rows: file_options.n_rows,
+            row_index: file_options.row_index.clone(),
+        }
+    }
+}
+
+/// Open `source` and cut off its stream once `args.n_rows` rows have been produced.
+pub fn open_scan_source(
+    source: &dyn ScanSource,
+    args: ScanSourceArgs,
+) -> PolarsResult<ScanSourceStream> {
+    let n_rows = args.n_rows;
+    let stream = source.open(args)?;
+    let Some(mut remaining) = n_rows else {
+        return Ok(stream);
+    };
+    Ok(Box::new(stream.map_while(move |batch| {
+        if remaining == 0 {
+            return None;
+        }
+        Some(batch.map(|df| {
+            let df = df.head(Some(remaining));
+            remaining -= df.height();
+            df
+        }))
+    })))
+}
+
+/// A data source that can be registered as a scan in the query plan.
+///
+/// Implement this trait to build a connector for a format or system that Polars doesn't
+/// read natively. Projections, predicates and row indices are pushed into the scan if the
+/// matching `supports_*` hook returns `true`, otherwise the engine applies them on the
+/// batches the source produces. Row limits are always passed on. Sources are read batch by
+/// batch by both the in-memory and the streaming engine.
+pub trait ScanSource: Send + Sync {
+    /// Name shown in the query plan.
+    fn name(&self) -> &'static str;
+
+    /// Schema of the data produced when no projection is pushed down.
+    fn schema(&self) -> PolarsResult<SchemaRef>;
+
+    /// Open the source and return a stream of batches. Every batch must have the
+    /// `output_schema` of `args`.
+    fn open(&self, args: ScanSourceArgs) -> PolarsResult<ScanSourceStream>;
+
+    /// Whether the source can limit itself to the projected columns.
+    ///
+    /// Defaults to `false`
+    fn supports_projection_pushdown(&self) -> bool {
+        false
+    }
+
+    /// Whether the source can filter rows itself.
+    ///
+    /// Defaults to `false`
+    fn supports_predicate_pushdown(&self) -> bool {
+        false
+    }
+
+    /// Whether the source can insert a row index column.
+    ///
+    /// Defaults to `false`
+    fn supports_row_index(&self) -> bool {
+        false
+    }
+
+    /// Number of rows of the source, if it is cheap to compute.
+    fn row_count(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl Debug for dyn ScanSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
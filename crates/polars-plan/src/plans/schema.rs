@@ -4,7 +4,6 @@ use std::sync::Mutex;
 use arrow::datatypes::ArrowSchemaRef;
 use either::Either;
 use polars_core::prelude::*;
-use polars_utils::format_smartstring;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -251,6 +250,7 @@ pub(crate) fn det_join_schema(
                 new_schema.with_column(name.clone(), dtype.clone());
             }
             let should_coalesce = options.args.should_coalesce();
+            let naming = options.args.naming();
 
             // make sure that expression are assigned to the schema
             // an expression can have an alias, and change a dtype.
@@ -275,7 +275,7 @@ pub(crate) fn det_join_schema(
                     if should_coalesce && field_left.name != field_right.name {
                         if schema_left.contains(&field_right.name) {
                             new_schema.with_column(
-                                _join_suffix_name(&field_right.name, options.args.suffix()).into(),
+                                naming.rename(&field_right.name)?.into(),
                                 field_right.dtype,
                             );
                         } else {
@@ -311,8 +311,7 @@ pub(crate) fn det_join_schema(
                             }
                         }
 
-                        let new_name = format_smartstring!("{}{}", name, options.args.suffix());
-                        new_schema.with_column(new_name, dtype.clone());
+                        new_schema.with_column(naming.rename(name)?.into(), dtype.clone());
                     } else {
                         new_schema.with_column(name.clone(), dtype.clone());
                     }
@@ -0,0 +1,352 @@
+//! Versioned serialization of [`DslPlan`].
+//!
+//! A serialized plan is an envelope that holds the version of the plan format next to the
+//! plan itself. The version is read before the plan, so a plan that this version of Polars
+//! can't read is rejected with a clear error instead of an arbitrary deserialization error.
+//!
+//! Compatibility rules:
+//! - Changes that older readers can't understand, such as new variants or fields, bump the
+//!   minor version.
+//! - Changes that newer readers can't understand, such as removed or renamed variants or
+//!   fields, bump the major version.
+//!
+//! A reader accepts every plan with its own major version and a minor version that is not
+//! newer than its own.
+use std::fmt::{self, Formatter};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use serde::de::{self, DeserializeOwned, IgnoredAny, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::plans::DslPlan;
+
+/// The version of the plan format written by this version of Polars.
+///
+/// - 1.0: initial version.
+/// - 1.1: row index over groups.
+/// - 1.2: `fill_null_by`.
+/// - 1.3: `arg_min_horizontal` and `arg_max_horizontal`.
+/// - 1.4: histogram bin strategies, shared bins and `hist_2d`.
+/// - 1.5: `resample` on dynamic group by.
+/// - 1.6: set operations.
+/// - 1.7: `float_scientific_threshold` of CSV sinks.
+/// - 1.8: `compression` of CSV and NDJSON sinks.
+/// - 1.9: `ts.lttb` and `ts.min_max` downsampling.
+/// - 1.10: inequality joins.
+/// - 1.11: `is_monotonic_increasing`, `is_monotonic_decreasing` and `check_sorted`.
+/// - 1.12: `strict` of `str.json_decode`.
+/// - 1.13: grouping sets of a group by.
+/// - 1.14: `mode` of `round` and `ceil_div`.
+/// - 1.15: lazy `pivot`.
+/// - 1.16: window frames of `over`.
+/// - 1.17: `parallel` hint of expressions.
+/// - 1.18: `arr.explode_to_rows` and `arr.reshape`.
+/// - 1.19: calendar anchors of durations, e.g. business month ends.
+/// - 1.20: `approx_quantile` aggregation.
+/// - 1.21: sketch functions, e.g. `approx_n_unique_sketch` and `merge_sketches`.
+/// - 1.22: string similarities, e.g. `str.levenshtein` and `str.jaro_winkler`.
+/// - 1.23: `collation` of sort and rank options, collated `str.compare`, `str.min` and `str.max`.
+/// - 1.24: element-wise aggregations, e.g. `arr.sum_elementwise` and `list.mean_elementwise`.
+/// - 1.25: `keep` strategy of `is_duplicated`.
+/// - 1.26: `rescale` of decimals.
+/// - 1.27: `allow_missing_files` of file scans.
+/// - 1.28: `Json` data type and the `json` namespace.
+/// - 1.29: `is_valid_mask`, `with_validity` and `coalesce_validity`.
+/// - 1.30: the `str.join_agg` aggregation.
+/// - 1.31: plugin aggregations.
+/// - 1.32: `first_by` and `last_by`.
+/// - 1.33: the level column of grouping sets.
+/// - 1.34: registered Rust UDFs.
+/// - 1.35: `zip_lists`.
+/// - 1.36: `arr.dot`, `arr.l2_norm`, `arr.cosine_similarity` and `arr.matmul`.
+/// - 1.37: stratified and weighted sampling of lazy frames.
+/// - 1.38: `stats.ttest`, `stats.ks_test` and `stats.chi2` hypothesis tests.
+/// - 1.39: random values of the uniform, normal and Poisson distributions.
+/// - 1.40: the `stable` sort option.
+/// - 1.41: the `quantiles` aggregation.
+/// - 1.42: `naming` of joins.
+/// - 1.43: `or_else`.
+/// - 1.44: `categoricals_as_strings` of Parquet sinks.
+/// - 1.45: `uncompressed_columns` and `statistics` of IPC sinks.
+pub const PLAN_FORMAT_VERSION: PlanFormatVersion = PlanFormatVersion {
+    major: 1,
+    minor: 45,
+};
+
+const VERSION_KEY: &str = "polars_plan_format";
+const POLARS_VERSION_KEY: &str = "polars_version";
+const PLAN_KEY: &str = "plan";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PlanFormatVersion {
+    pub major: u16,
+    pub minor: u16,
+}
+
+impl PlanFormatVersion {
+    /// Whether a plan of this version can be read by this version of Polars.
+    pub fn is_supported(&self) -> bool {
+        self.major == PLAN_FORMAT_VERSION.major && self.minor <= PLAN_FORMAT_VERSION.minor
+    }
+}
+
+impl fmt::Display for PlanFormatVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PlanSerializationFormat {
+    /// CBOR
+    #[default]
+    Binary,
+    Json,
+}
+
+/// A plan, or something that holds plans, together with the version of the plan format.
+struct VersionedRef<'a, T>(&'a T);
+
+impl<T: Serialize> Serialize for VersionedRef<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // The version must come first, the reader checks it before reading the plan.
+        let mut map = serializer.serialize_map(Some(3))?;
+        map.serialize_entry(VERSION_KEY, &PLAN_FORMAT_VERSION)?;
+        map.serialize_entry(POLARS_VERSION_KEY, env!("CARGO_PKG_VERSION"))?;
+        map.serialize_entry(PLAN_KEY, self.0)?;
+        map.end()
+    }
+}
+
+struct Versioned<T>(T);
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Versioned<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct VersionedVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for VersionedVisitor<T> {
+            type Value = Versioned<T>;
+
+            fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                write!(f, "a versioned polars plan")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut version = None;
+                let mut plan = None;
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        VERSION_KEY => {
+                            let v: PlanFormatVersion = map.next_value()?;
+                            if !v.is_supported() {
+                                return Err(de::Error::custom(format!(
+                                    "cannot read plan of format version {v}; this version of \
+                                    Polars reads format versions {}.0 up to {PLAN_FORMAT_VERSION}",
+                                    PLAN_FORMAT_VERSION.major
+                                )));
+                            }
+                            version = Some(v);
+                        },
+                        PLAN_KEY => {
+                            if version.is_none() {
+                                return Err(de::Error::custom(format!(
+                                    "expected '{VERSION_KEY}' before '{PLAN_KEY}'"
+                                )));
+                            }
+                            plan = Some(map.next_value::<T>()?);
+                        },
+                        // Room for metadata, e.g. the Polars version that wrote the plan.
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        },
+                    }
+                }
+                if version.is_none() {
+                    return Err(de::Error::missing_field(VERSION_KEY));
+                }
+                plan.map(Versioned)
+                    .ok_or_else(|| de::Error::missing_field(PLAN_KEY))
+            }
+        }
+
+        deserializer.deserialize_map(VersionedVisitor(PhantomData))
+    }
+}
+
+impl DslPlan {
+    /// Serialize the plan together with the version of the plan format.
+    ///
+    /// The result can be read with [`DslPlan::deserialize_versioned`] by any version of
+    /// Polars that supports the plan format version. See [`PLAN_FORMAT_VERSION`].
+    pub fn serialize_versioned<W: Write>(
+        &self,
+        writer: W,
+        format: PlanSerializationFormat,
+    ) -> PolarsResult<()> {
+        write_versioned(self, writer, format)
+    }
+
+    /// Deserialize a plan written by [`DslPlan::serialize_versioned`].
+    pub fn deserialize_versioned<R: Read>(
+        reader: R,
+        format: PlanSerializationFormat,
+    ) -> PolarsResult<Self> {
+        read_versioned(reader, format)
+    }
+}
+
+fn write_versioned<T: Serialize, W: Write>(
+    value: &T,
+    writer: W,
+    format: PlanSerializationFormat,
+) -> PolarsResult<()> {
+    let value = VersionedRef(value);
+    match format {
+        PlanSerializationFormat::Binary => {
+            ciborium::into_writer(&value, writer).map_err(to_compute_err)
+        },
+        PlanSerializationFormat::Json => {
+            serde_json::to_writer(writer, &value).map_err(to_compute_err)
+        },
+    }
+}
+
+fn read_versioned<T: DeserializeOwned, R: Read>(
+    reader: R,
+    format: PlanSerializationFormat,
+) -> PolarsResult<T> {
+    let value: Versioned<T> = match format {
+        PlanSerializationFormat::Binary => ciborium::from_reader(reader).map_err(to_compute_err)?,
+        PlanSerializationFormat::Json => serde_json::from_reader(reader).map_err(to_compute_err)?,
+    };
+    Ok(value.0)
+}
+
+/// A step of a [`DslPipeline`].
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DslPipelineStep {
+    pub name: String,
+    /// The plan of the step, whose input is the placeholder of [`DslPipeline::placeholder`].
+    pub plan: DslPlan,
+    /// The schema the output of the step must have, if any.
+    pub expected_schema: Option<Schema>,
+}
+
+/// The plans of a sequence of named steps, as serialized by `Pipeline` in `polars-lazy`.
+///
+/// Every step is a plan over a placeholder for its input, which is the output of the previous
+/// step, or the input of the pipeline for the first step.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DslPipeline {
+    pub input_schema: Schema,
+    pub steps: Vec<DslPipelineStep>,
+}
+
+impl DslPipeline {
+    /// The placeholder for the input of a step with input schema `schema`: a scan of a
+    /// `DataFrame` without columns.
+    pub fn placeholder(schema: SchemaRef) -> DslPlan {
+        DslPlan::DataFrameScan {
+            df: Arc::new(DataFrame::empty()),
+            schema,
+            output_schema: None,
+            filter: None,
+        }
+    }
+
+    /// Whether `plan` is a placeholder created by [`DslPipeline::placeholder`]. A scan of an
+    /// actual `DataFrame` always has a column per field of its schema.
+    pub fn is_placeholder(plan: &DslPlan) -> bool {
+        matches!(
+            plan,
+            DslPlan::DataFrameScan { df, schema, .. } if df.width() != schema.len()
+        )
+    }
+
+    /// Serialize the pipeline together with the version of the plan format, like
+    /// [`DslPlan::serialize_versioned`].
+    pub fn serialize_versioned<W: Write>(
+        &self,
+        writer: W,
+        format: PlanSerializationFormat,
+    ) -> PolarsResult<()> {
+        write_versioned(self, writer, format)
+    }
+
+    /// Deserialize a pipeline written by [`DslPipeline::serialize_versioned`].
+    pub fn deserialize_versioned<R: Read>(
+        reader: R,
+        format: PlanSerializationFormat,
+    ) -> PolarsResult<Self> {
+        read_versioned(reader, format)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::dsl::{col, lit};
+    use crate::plans::DslBuilder;
+
+    fn plan() -> DslPlan {
+        let df = DataFrame::new(vec![Series::new("a", [1i64, 2, 3])]).unwrap();
+        DslBuilder::from_existing_df(df)
+            .filter(col("a").gt(lit(1)))
+            .build()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for format in [
+            PlanSerializationFormat::Binary,
+            PlanSerializationFormat::Json,
+        ] {
+            let mut buf = vec![];
+            plan().serialize_versioned(&mut buf, format).unwrap();
+            let out = DslPlan::deserialize_versioned(buf.as_slice(), format).unwrap();
+            assert!(matches!(out, DslPlan::Filter { .. }));
+        }
+    }
+
+    #[test]
+    fn test_version_check() {
+        let mut buf = vec![];
+        plan()
+            .serialize_versioned(&mut buf, PlanSerializationFormat::Json)
+            .unwrap();
+        let json = String::from_utf8(buf).unwrap();
+        let version = format!(
+            r#"{{"polars_plan_format":{{"major":{},"minor":{}}}"#,
+            PLAN_FORMAT_VERSION.major, PLAN_FORMAT_VERSION.minor
+        );
+        assert!(json.starts_with(&version));
+
+        let newer = format!(
+            "{}.{}",
+            PLAN_FORMAT_VERSION.major,
+            PLAN_FORMAT_VERSION.minor + 1
+        );
+        let json = json.replacen(
+            &format!(r#""minor":{}"#, PLAN_FORMAT_VERSION.minor),
+            &format!(r#""minor":{}"#, PLAN_FORMAT_VERSION.minor + 1),
+            1,
+        );
+        let err = DslPlan::deserialize_versioned(json.as_bytes(), PlanSerializationFormat::Json)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains(&newer));
+
+        // The plan of an unversioned payload is not read.
+        let err = DslPlan::deserialize_versioned(
+            r#"{"plan": null}"#.as_bytes(),
+            PlanSerializationFormat::Json,
+        )
+        .err()
+        .unwrap();
+        assert!(err.to_string().contains("polars_plan_format"));
+    }
+}
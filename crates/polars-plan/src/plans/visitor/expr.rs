@@ -68,6 +68,13 @@ impl TreeWalker for Expr {
                 Implode(x) => Implode(am(x, f)?),
                 Count(x, nulls) => Count(am(x, f)?, nulls),
                 Quantile { expr, quantile, interpol } => Quantile { expr: am(expr, &mut f)?, quantile: am(quantile, f)?, interpol },
+                ApproxQuantile { expr, quantile, accuracy } => ApproxQuantile { expr: am(expr, &mut f)?, quantile: am(quantile, f)?, accuracy },
+                Quantiles { expr, options } => Quantiles { expr: am(expr, f)?, options },
+                StrJoin { expr, options } => StrJoin { expr: am(expr, f)?, options },
+                FirstBy { expr, by } => FirstBy { expr: am(expr, &mut f)?, by: am(by, f)? },
+                LastBy { expr, by } => LastBy { expr: am(expr, &mut f)?, by: am(by, f)? },
+                #[cfg(feature = "ffi_plugin")]
+                Plugin { expr, function } => Plugin { expr: am(expr, f)?, function },
                 Sum(x) => Sum(am(x, f)?),
                 AggGroups(x) => AggGroups(am(x, f)?),
                 Std(x, ddf) => Std(am(x, f)?, ddf),
@@ -132,7 +139,7 @@ impl AexprNode {
     }
 
     #[cfg(feature = "cse")]
-    pub(crate) fn is_leaf(&self, arena: &Arena<AExpr>) -> bool {
+    pub fn is_leaf(&self, arena: &Arena<AExpr>) -> bool {
         matches!(self.to_aexpr(arena), AExpr::Column(_) | AExpr::Literal(_))
     }
 
@@ -90,8 +90,11 @@ impl TreeWalker for IRNode {
     }
 }
 
-#[cfg(feature = "cse")]
-pub(crate) fn with_ir_arena<F: FnOnce(&mut IRNodeArena) -> T, T>(
+/// Run `func` on the arenas combined into an [`IRNodeArena`], e.g. to rewrite an [`IRNode`]
+/// with a [`RewritingVisitor`] from within an [`OptimizationRule`].
+///
+/// [`OptimizationRule`]: crate::plans::OptimizationRule
+pub fn with_ir_arena<F: FnOnce(&mut IRNodeArena) -> T, T>(
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
     func: F,
@@ -99,8 +102,8 @@ pub(crate) fn with_ir_arena<F: FnOnce(&mut IRNodeArena) -> T, T>(
     try_with_ir_arena(lp_arena, expr_arena, |a| Ok(func(a))).unwrap()
 }
 
-#[cfg(feature = "cse")]
-pub(crate) fn try_with_ir_arena<F: FnOnce(&mut IRNodeArena) -> PolarsResult<T>, T>(
+/// Fallible version of [`with_ir_arena`].
+pub fn try_with_ir_arena<F: FnOnce(&mut IRNodeArena) -> PolarsResult<T>, T>(
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
     func: F,
@@ -5,10 +5,12 @@ mod expr;
 #[cfg(feature = "cse")]
 mod hash;
 mod lp;
+mod rewrite;
 mod visitors;
 
 pub use expr::*;
 pub use lp::*;
+pub use rewrite::*;
 pub use visitors::*;
 
 /// Controls how the [`TreeWalker`] recursion should proceed for [`TreeWalker::visit`].
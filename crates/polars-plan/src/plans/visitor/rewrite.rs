@@ -0,0 +1,208 @@
+//! Helpers to match and rewrite expressions in an [`Arena<AExpr>`], for authors of optimizer
+//! passes that would otherwise have to traverse the arena themselves.
+//!
+//! The `match_*` functions return `Option`s, so patterns compose with `?` and `and_then`:
+//!
+//! ```rust,ignore
+//! // Match `col(name) == <literal>`.
+//! let (left, op, right) = match_binary(node, arena)?;
+//! let name = match_column(left, arena).filter(|_| op == Operator::Eq)?;
+//! let value = match_literal(right, arena)?;
+//! ```
+//!
+//! The rewrites never modify the nodes of their input: rewritten nodes are added to the
+//! arena, as the nodes of an expression may be shared with other expressions.
+use super::*;
+use crate::prelude::*;
+
+/// Match a column and return its name.
+pub fn match_column(node: Node, arena: &Arena<AExpr>) -> Option<&ColumnName> {
+    match arena.get(node) {
+        AExpr::Column(name) => Some(name),
+        _ => None,
+    }
+}
+
+/// Match a literal and return its value.
+pub fn match_literal(node: Node, arena: &Arena<AExpr>) -> Option<&LiteralValue> {
+    match arena.get(node) {
+        AExpr::Literal(value) => Some(value),
+        _ => None,
+    }
+}
+
+/// Match a binary expression and return its left operand, operator and right operand.
+pub fn match_binary(node: Node, arena: &Arena<AExpr>) -> Option<(Node, Operator, Node)> {
+    match arena.get(node) {
+        AExpr::BinaryExpr { left, op, right } => Some((*left, *op, *right)),
+        _ => None,
+    }
+}
+
+/// Match a function expression and return its function and inputs.
+pub fn match_function(node: Node, arena: &Arena<AExpr>) -> Option<(&FunctionExpr, &[ExprIR])> {
+    match arena.get(node) {
+        AExpr::Function {
+            input, function, ..
+        } => Some((function, input.as_slice())),
+        _ => None,
+    }
+}
+
+struct ReplaceSubtree<F> {
+    replace: F,
+    replacement: Option<Node>,
+}
+
+impl<F> RewritingVisitor for ReplaceSubtree<F>
+where
+    F: FnMut(Node, &Arena<AExpr>) -> PolarsResult<Option<Node>>,
+{
+    type Node = AexprNode;
+    type Arena = Arena<AExpr>;
+
+    fn pre_visit(
+        &mut self,
+        node: &Self::Node,
+        arena: &mut Self::Arena,
+    ) -> PolarsResult<RewriteRecursion> {
+        self.replacement = (self.replace)(node.node(), arena)?;
+        Ok(match self.replacement {
+            Some(_) => RewriteRecursion::MutateAndStop,
+            None => RewriteRecursion::NoMutateAndContinue,
+        })
+    }
+
+    fn mutate(&mut self, _node: Self::Node, _arena: &mut Self::Arena) -> PolarsResult<Self::Node> {
+        Ok(AexprNode::new(self.replacement.take().unwrap()))
+    }
+}
+
+/// Replace the subtrees of the expression at `node` for which `replace` returns a new node.
+///
+/// The tree is visited top-down and the nodes below a replaced subtree aren't visited, so
+/// `replace` isn't applied to its own output.
+pub fn replace_subtree<F>(node: Node, arena: &mut Arena<AExpr>, replace: F) -> PolarsResult<Node>
+where
+    F: FnMut(Node, &Arena<AExpr>) -> PolarsResult<Option<Node>>,
+{
+    let mut rewriter = ReplaceSubtree {
+        replace,
+        replacement: None,
+    };
+    Ok(AexprNode::new(node).rewrite(&mut rewriter, arena)?.node())
+}
+
+struct BottomUpRewriter<F> {
+    rewrite: F,
+    changed: bool,
+}
+
+impl<F> RewritingVisitor for BottomUpRewriter<F>
+where
+    F: FnMut(Node, &mut Arena<AExpr>) -> PolarsResult<Option<AExpr>>,
+{
+    type Node = AexprNode;
+    type Arena = Arena<AExpr>;
+
+    fn mutate(
+        &mut self,
+        mut node: Self::Node,
+        arena: &mut Self::Arena,
+    ) -> PolarsResult<Self::Node> {
+        if let Some(ae) = (self.rewrite)(node.node(), arena)? {
+            node.assign(ae, arena);
+            self.changed = true;
+        }
+        Ok(node)
+    }
+}
+
+/// Rewrite the expression at `node` in topological order: `rewrite` is applied to the inputs
+/// of an expression before the expression itself, and replaces it if it returns an expression.
+///
+/// Returns the rewritten node and whether anything was rewritten.
+pub fn rewrite_bottom_up<F>(
+    node: Node,
+    arena: &mut Arena<AExpr>,
+    rewrite: F,
+) -> PolarsResult<(Node, bool)>
+where
+    F: FnMut(Node, &mut Arena<AExpr>) -> PolarsResult<Option<AExpr>>,
+{
+    let mut rewriter = BottomUpRewriter {
+        rewrite,
+        changed: false,
+    };
+    let node = AexprNode::new(node).rewrite(&mut rewriter, arena)?.node();
+    Ok((node, rewriter.changed))
+}
+
+/// Apply [`rewrite_bottom_up`] until `rewrite` doesn't change the expression anymore.
+///
+/// `rewrite` must reach a fixpoint, a rule that keeps returning expressions loops forever.
+pub fn rewrite_fixpoint<F>(
+    mut node: Node,
+    arena: &mut Arena<AExpr>,
+    rewrite: F,
+) -> PolarsResult<Node>
+where
+    F: FnMut(Node, &mut Arena<AExpr>) -> PolarsResult<Option<AExpr>>,
+{
+    let mut rewriter = BottomUpRewriter {
+        rewrite,
+        changed: true,
+    };
+    while rewriter.changed {
+        rewriter.changed = false;
+        node = AexprNode::new(node).rewrite(&mut rewriter, arena)?.node();
+    }
+    Ok(node)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// `x & true` -> `x`
+    fn simplify_and_true(node: Node, arena: &mut Arena<AExpr>) -> PolarsResult<Option<AExpr>> {
+        let out = match_binary(node, arena).and_then(|(left, op, right)| {
+            let value = match_literal(right, arena).filter(|_| op == Operator::And)?;
+            matches!(value, LiteralValue::Boolean(true)).then(|| arena.get(left).clone())
+        });
+        Ok(out)
+    }
+
+    #[test]
+    fn test_rewrite_fixpoint() -> PolarsResult<()> {
+        let mut arena = Arena::new();
+        let expr = col("a").and(lit(true)).and(lit(true)).or(col("b"));
+        let node = to_aexpr(expr.clone(), &mut arena);
+
+        let rewritten = rewrite_fixpoint(node, &mut arena, simplify_and_true)?;
+        assert_eq!(node_to_expr(rewritten, &arena), col("a").or(col("b")));
+        // The input isn't modified.
+        assert_eq!(node_to_expr(node, &arena), expr);
+
+        let (_, changed) = rewrite_bottom_up(rewritten, &mut arena, simplify_and_true)?;
+        assert!(!changed);
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_subtree() -> PolarsResult<()> {
+        let mut arena = Arena::new();
+        let node = to_aexpr(col("a").and(col("b")).or(col("a")), &mut arena);
+
+        let b = arena.add(AExpr::Column(ColumnName::from("b")));
+        let replaced = replace_subtree(node, &mut arena, |node, arena| {
+            let is_a = match_column(node, arena).is_some_and(|name| name.as_ref() == "a");
+            Ok(is_a.then_some(b))
+        })?;
+        assert_eq!(
+            node_to_expr(replaced, &arena),
+            col("b").and(col("b")).or(col("b"))
+        );
+        Ok(())
+    }
+}
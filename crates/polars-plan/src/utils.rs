@@ -2,10 +2,12 @@ use std::fmt::Formatter;
 use std::iter::FlatMap;
 
 use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
 use polars_utils::idx_vec::UnitVec;
 use smartstring::alias::String as SmartString;
 
 use crate::constants::{get_len_name, LEN};
+use crate::plans::visitor::{match_column, rewrite_bottom_up};
 use crate::prelude::*;
 
 /// Utility to write comma delimited strings
@@ -272,13 +274,12 @@ pub(crate) fn rename_matching_aexpr_leaf_names(
     let mut leaves = aexpr_to_column_nodes_iter(node, arena);
 
     if leaves.any(|node| matches!(arena.get(node.0), AExpr::Column(name) if &**name == current)) {
-        // we convert to expression as we cannot easily copy the aexpr.
-        let mut new_expr = node_to_expr(node, arena);
-        new_expr = new_expr.map_expr(|e| match e {
-            Expr::Column(name) if &*name == current => Expr::Column(ColumnName::from(new_name)),
-            e => e,
-        });
-        to_aexpr(new_expr, arena)
+        let (node, _) = rewrite_bottom_up(node, arena, |node, arena| {
+            let matches = match_column(node, arena).is_some_and(|name| &**name == current);
+            Ok(matches.then(|| AExpr::Column(ColumnName::from(new_name))))
+        })
+        .unwrap();
+        node
     } else {
         node
     }
@@ -395,3 +396,79 @@ pub fn merge_schemas(schemas: &[SchemaRef]) -> PolarsResult<Schema> {
 
     Ok(merged_schema)
 }
+
+/// Options for [`unify_schemas`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UnifyOptions {
+    /// Resolve the dtypes of a column to their supertype, e.g. `Int32` and `Int64` to `Int64`,
+    /// an integer and a float to the float and `Null` to any dtype. Otherwise, a column must
+    /// have the same dtype in every schema.
+    pub to_supertypes: bool,
+    /// Allow columns that are not in every schema.
+    pub allow_missing_columns: bool,
+}
+
+impl Default for UnifyOptions {
+    fn default() -> Self {
+        Self {
+            to_supertypes: true,
+            allow_missing_columns: false,
+        }
+    }
+}
+
+/// Unify multiple schemas into one that has a column for every name in the schemas.
+///
+/// Unlike [`merge_schemas`], a column may be in more than one schema, the columns are matched
+/// by name. They are in the order in which they are first seen.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # use polars_core::prelude::*;
+/// # use polars_plan::utils::{unify_schemas, UnifyOptions};
+/// let a = Schema::from_iter([Field::new("x", DataType::Int32)]);
+/// let b = Schema::from_iter([
+///     Field::new("x", DataType::Float64),
+///     Field::new("y", DataType::String),
+/// ]);
+/// let options = UnifyOptions {
+///     allow_missing_columns: true,
+///     ..Default::default()
+/// };
+/// let schema = unify_schemas(&[Arc::new(a), Arc::new(b)], options).unwrap();
+/// assert_eq!(schema.get("x"), Some(&DataType::Float64));
+/// assert_eq!(schema.get("y"), Some(&DataType::String));
+/// ```
+pub fn unify_schemas(schemas: &[SchemaRef], options: UnifyOptions) -> PolarsResult<Schema> {
+    let schema_size = schemas.iter().map(|schema| schema.len()).max().unwrap_or(0);
+    let mut unified = Schema::with_capacity(schema_size);
+
+    for schema in schemas {
+        for (name, dtype) in schema.iter() {
+            let dtype = match unified.get(name) {
+                Some(current) if current == dtype => continue,
+                Some(current) => {
+                    polars_ensure!(
+                        options.to_supertypes,
+                        SchemaMismatch: "column '{}' has dtype {} in one schema and {} in another",
+                        name, current, dtype
+                    );
+                    try_get_supertype(current, dtype)?
+                },
+                None => dtype.clone(),
+            };
+            unified.with_column(name.clone(), dtype);
+        }
+    }
+
+    if !options.allow_missing_columns {
+        for schema in schemas {
+            if let Some(name) = unified.iter_names().find(|name| !schema.contains(name)) {
+                polars_bail!(SchemaMismatch: "column '{}' is missing in some of the schemas", name);
+            }
+        }
+    }
+    Ok(unified)
+}
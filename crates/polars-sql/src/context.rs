@@ -9,11 +9,12 @@ use polars_ops::frame::JoinCoalesce;
 use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
 use sqlparser::ast::{
-    BinaryOperator, Distinct, ExcludeSelectItem, Expr as SQLExpr, FunctionArg, GroupByExpr, Ident,
-    JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset, OrderByExpr, Query,
-    RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier, Statement,
-    TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
-    WildcardAdditionalOptions,
+    BinaryOperator, Distinct, ExcludeSelectItem, Expr as SQLExpr, ExprWithAlias, FunctionArg,
+    GroupByExpr, Ident, JoinConstraint, JoinOperator, MergeAction, MergeClause, MergeClauseKind,
+    MergeInsertKind, NamedWindowDefinition, NamedWindowExpr, ObjectName, ObjectType, Offset,
+    OrderByExpr, PivotValueSource, Query, RenameSelectItem, Select, SelectItem, SetExpr,
+    SetOperator, SetQuantifier, Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator,
+    Value as SQLValue, Values, WildcardAdditionalOptions, WindowSpec,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
@@ -24,6 +25,10 @@ use crate::sql_expr::{
 };
 use crate::table_functions::PolarsTableFunctions;
 
+// Columns marking the target and source rows of a MERGE.
+const MERGE_TARGET_ROW: &str = "__POLARS_MERGE_TARGET_ROW";
+const MERGE_SOURCE_ROW: &str = "__POLARS_MERGE_SOURCE_ROW";
+
 #[derive(Clone)]
 pub struct TableInfo {
     pub(crate) frame: LazyFrame,
@@ -63,6 +68,8 @@ pub struct SQLContext {
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
     table_aliases: RefCell<PlHashMap<String, String>>,
     joined_aliases: RefCell<PlHashMap<String, PlHashMap<String, String>>>,
+    /// The windows defined in the `WINDOW` clause of the select that is being processed.
+    named_windows: PlHashMap<String, WindowSpec>,
 }
 
 impl Default for SQLContext {
@@ -73,6 +80,7 @@ impl Default for SQLContext {
             cte_map: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
+            named_windows: Default::default(),
             lp_arena: Default::default(),
             expr_arena: Default::default(),
         }
@@ -201,6 +209,7 @@ impl SQLContext {
             } => self.execute_drop_table(stmt)?,
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
+            stmt @ Statement::Merge { .. } => self.execute_merge(stmt)?,
             _ => polars_bail!(
                 SQLInterface: "statement type {:?} is not supported", ast,
             ),
@@ -363,42 +372,37 @@ impl SQLContext {
         quantifier: &SetQuantifier,
         query: &Query,
     ) -> PolarsResult<LazyFrame> {
-        let (join_type, op_name) = match *query.body {
+        let (intersect, op_name) = match *query.body {
             SetExpr::SetOperation {
                 op: SetOperator::Except,
                 ..
-            } => (JoinType::Anti, "EXCEPT"),
-            _ => (JoinType::Semi, "INTERSECT"),
+            } => (false, "EXCEPT"),
+            _ => (true, "INTERSECT"),
         };
         let mut lf = self.process_query(left, query)?;
         let mut rf = self.process_query(right, query)?;
-        let join = lf
-            .clone()
-            .join_builder()
-            .with(rf.clone())
-            .how(join_type)
-            .join_nulls(true);
-
-        let lf_schema = self.get_frame_schema(&mut lf)?;
-        let lf_cols: Vec<_> = lf_schema.iter_names().map(|nm| col(nm)).collect();
-        let joined_tbl = match quantifier {
-            SetQuantifier::ByName | SetQuantifier::AllByName => {
-                // note: 'BY NAME' is pending https://github.com/sqlparser-rs/sqlparser-rs/pull/1309
-                join.on(lf_cols).finish()
-            },
+
+        let by_name = match quantifier {
+            // note: 'BY NAME' is pending https://github.com/sqlparser-rs/sqlparser-rs/pull/1309
+            SetQuantifier::ByName | SetQuantifier::AllByName => true,
             SetQuantifier::Distinct | SetQuantifier::None => {
+                let lf_schema = self.get_frame_schema(&mut lf)?;
                 let rf_schema = self.get_frame_schema(&mut rf)?;
-                let rf_cols: Vec<_> = rf_schema.iter_names().map(|nm| col(nm)).collect();
-                if lf_cols.len() != rf_cols.len() {
+                if lf_schema.len() != rf_schema.len() {
                     polars_bail!(SQLInterface: "{} requires equal number of columns in each table (use '{} BY NAME' to combine mismatched tables)", op_name, op_name)
                 }
-                join.left_on(lf_cols).right_on(rf_cols).finish()
+                false
             },
             _ => {
                 polars_bail!(SQLInterface: "'{} {}' is not supported", op_name, quantifier.to_string())
             },
         };
-        Ok(joined_tbl.unique(None, UniqueKeepStrategy::Any))
+        let op = if intersect {
+            FrameSetOperation::Intersect { by_name }
+        } else {
+            FrameSetOperation::Except { by_name }
+        };
+        Ok(lf.set_operation(rf, op))
     }
 
     fn process_union(
@@ -531,6 +535,193 @@ impl SQLContext {
         }
     }
 
+    // MERGE INTO target USING source ON ... WHEN [NOT] MATCHED ...
+    fn execute_merge(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Merge {
+            table,
+            source,
+            on,
+            clauses,
+            ..
+        } = stmt
+        else {
+            unreachable!()
+        };
+        let TableFactor::Table { name, .. } = table else {
+            polars_bail!(SQLInterface: "MERGE target must be a table; found {}", table)
+        };
+        let tbl_name = name.0.first().unwrap().value.clone();
+        polars_ensure!(
+            self.table_map.contains_key(&tbl_name),
+            SQLInterface: "MERGE target '{}' is not a registered table", tbl_name
+        );
+        let (t_name, tgt) = self.get_table(table)?;
+        let (s_name, src) = self.get_table(source)?;
+        polars_ensure!(
+            t_name != s_name,
+            SQLInterface: "MERGE source and target must have distinct names; found '{}' for both", t_name
+        );
+
+        // Mark the rows of both sides to tell matched and unmatched rows apart after the join.
+        let mut tgt = tgt.with_column(lit(true).alias(MERGE_TARGET_ROW));
+        let mut src = src.with_column(lit(true).alias(MERGE_SOURCE_ROW));
+        let tgt_schema = self.get_frame_schema(&mut tgt)?;
+        let src_schema = self.get_frame_schema(&mut src)?;
+        let mut joined = self.process_join(
+            &TableInfo {
+                frame: tgt,
+                name: t_name.clone(),
+                schema: tgt_schema.clone(),
+            },
+            &TableInfo {
+                frame: src,
+                name: s_name.clone(),
+                schema: src_schema.clone(),
+            },
+            &JoinConstraint::On(on.as_ref().clone()),
+            JoinType::Full,
+        )?;
+        let joined_schema = self.get_frame_schema(&mut joined)?;
+        self.track_joined_aliases(&s_name, &tgt_schema, &src_schema, &joined_schema);
+
+        let is_target = col(MERGE_TARGET_ROW).is_not_null();
+        let is_source = col(MERGE_SOURCE_ROW).is_not_null();
+        let columns = tgt_schema
+            .iter()
+            .filter(|(name, _)| name.as_str() != MERGE_TARGET_ROW)
+            .map(|(name, dtype)| (name.to_string(), dtype.clone()))
+            .collect::<Vec<_>>();
+
+        // Every row is handled by the first clause that applies to it.
+        let mut handled = lit(false);
+        let mut deleted = lit(false);
+        let mut inserted = lit(false);
+        let mut updates = vec![];
+        for clause in clauses {
+            let mut cond = match clause.clause_kind {
+                MergeClauseKind::Matched => is_target.clone().and(is_source.clone()),
+                MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget => {
+                    is_target.clone().not()
+                },
+                MergeClauseKind::NotMatchedBySource => is_source.clone().not(),
+            };
+            if let Some(predicate) = &clause.predicate {
+                let predicate = parse_sql_expr(predicate, self, Some(&joined_schema))?;
+                cond = cond.and(predicate.fill_null(lit(false)));
+            }
+            let cond = cond.and(handled.clone().not());
+            handled = handled.or(cond.clone());
+
+            let values = self.merge_clause_values(clause, &columns, &s_name, &joined_schema)?;
+            match &clause.action {
+                MergeAction::Delete => deleted = deleted.or(cond),
+                MergeAction::Insert(_) => {
+                    inserted = inserted.or(cond.clone());
+                    updates.push((cond, values));
+                },
+                MergeAction::Update { .. } => updates.push((cond, values)),
+            }
+        }
+
+        // Unmatched source rows are only kept if they are inserted.
+        let keep = is_target.or(inserted).and(deleted.not());
+        let exprs = columns
+            .iter()
+            .map(|(name, dtype)| {
+                updates
+                    .iter()
+                    .rev()
+                    .fold(col(name), |otherwise, (cond, values)| {
+                        match values.get(name) {
+                            Some(value) => {
+                                when(cond.clone()).then(value.clone()).otherwise(otherwise)
+                            },
+                            None => otherwise,
+                        }
+                    })
+                    .cast(dtype.clone())
+                    .alias(name)
+            })
+            .collect::<Vec<_>>();
+        let merged = joined.filter(keep).select(exprs);
+        self.register(&tbl_name, merged.clone());
+        Ok(merged)
+    }
+
+    /// The new values of the target columns set by a MERGE clause.
+    fn merge_clause_values(
+        &mut self,
+        clause: &MergeClause,
+        columns: &[(String, DataType)],
+        s_name: &str,
+        joined_schema: &Schema,
+    ) -> PolarsResult<PlHashMap<String, Expr>> {
+        let check_column = |name: &str| {
+            polars_ensure!(
+                columns.iter().any(|(c, _)| c == name),
+                ColumnNotFound: "MERGE target has no column '{}'", name
+            );
+            Ok(name.to_string())
+        };
+        let mut values = PlHashMap::new();
+        match &clause.action {
+            MergeAction::Delete => {},
+            MergeAction::Update { assignments } => {
+                polars_ensure!(
+                    matches!(clause.clause_kind, MergeClauseKind::Matched),
+                    SQLSyntax: "UPDATE is only allowed in a WHEN MATCHED clause"
+                );
+                for assignment in assignments {
+                    let name = check_column(&assignment.id.last().unwrap().value)?;
+                    let value = parse_sql_expr(&assignment.value, self, Some(joined_schema))?;
+                    values.insert(name, value);
+                }
+            },
+            MergeAction::Insert(insert) => {
+                polars_ensure!(
+                    matches!(
+                        clause.clause_kind,
+                        MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget
+                    ),
+                    SQLSyntax: "INSERT is only allowed in a WHEN NOT MATCHED clause"
+                );
+                let names = if insert.columns.is_empty() {
+                    columns.iter().map(|(c, _)| c.clone()).collect()
+                } else {
+                    insert
+                        .columns
+                        .iter()
+                        .map(|c| check_column(&c.value))
+                        .collect::<PolarsResult<Vec<_>>>()?
+                };
+                match &insert.kind {
+                    MergeInsertKind::Values(Values { rows, .. }) => {
+                        polars_ensure!(
+                            rows.len() == 1 && rows[0].len() == names.len(),
+                            SQLSyntax: "MERGE INSERT requires a single row of {} values", names.len()
+                        );
+                        for (name, value) in names.into_iter().zip(&rows[0]) {
+                            let value = parse_sql_expr(value, self, Some(joined_schema))?;
+                            values.insert(name, value);
+                        }
+                    },
+                    // Insert the source columns of the same names.
+                    MergeInsertKind::Row => {
+                        for name in names {
+                            let src_name = self.resolve_name(s_name, &name);
+                            polars_ensure!(
+                                joined_schema.contains(&src_name),
+                                ColumnNotFound: "MERGE source has no column '{}'", name
+                            );
+                            values.insert(name, col(&src_name));
+                        }
+                    },
+                }
+            },
+        }
+        Ok(values)
+    }
+
     fn register_cte(&mut self, name: &str, lf: LazyFrame) {
         self.cte_map.borrow_mut().insert(name.to_owned(), lf);
     }
@@ -603,31 +794,80 @@ impl SQLContext {
 
                 // track join-aliased columns so we can resolve them later
                 let joined_schema = self.get_frame_schema(&mut lf)?;
-
-                self.joined_aliases.borrow_mut().insert(
-                    r_name.to_string(),
-                    right_schema
-                        .iter_names()
-                        .filter_map(|name| {
-                            // col exists in both tables and is aliased in the joined result
-                            let aliased_name = format!("{}:{}", name, r_name);
-                            if left_schema.contains(name)
-                                && joined_schema.contains(aliased_name.as_str())
-                            {
-                                Some((name.to_string(), aliased_name))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<PlHashMap<String, String>>(),
-                );
+                self.track_joined_aliases(&r_name, &left_schema, &right_schema, &joined_schema);
             }
         };
         Ok(lf)
     }
 
+    fn track_joined_aliases(
+        &self,
+        r_name: &str,
+        left_schema: &Schema,
+        right_schema: &Schema,
+        joined_schema: &Schema,
+    ) {
+        self.joined_aliases.borrow_mut().insert(
+            r_name.to_string(),
+            right_schema
+                .iter_names()
+                .filter_map(|name| {
+                    // col exists in both tables and is aliased in the joined result
+                    let aliased_name = format!("{}:{}", name, r_name);
+                    if left_schema.contains(name) && joined_schema.contains(aliased_name.as_str()) {
+                        Some((name.to_string(), aliased_name))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<PlHashMap<String, String>>(),
+        );
+    }
+
     /// Execute the 'SELECT' part of the query.
     fn execute_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
+        // Named windows are scoped to their select, subqueries have their own.
+        let named_windows = self.resolve_named_windows(&select_stmt.named_window)?;
+        let outer_windows = std::mem::replace(&mut self.named_windows, named_windows);
+        let lf = self.execute_select_with_windows(select_stmt, query);
+        self.named_windows = outer_windows;
+        lf
+    }
+
+    /// Resolve the windows of a `WINDOW` clause, which can refer to the windows before them.
+    fn resolve_named_windows(
+        &self,
+        named_windows: &[NamedWindowDefinition],
+    ) -> PolarsResult<PlHashMap<String, WindowSpec>> {
+        let mut resolved = PlHashMap::with_capacity(named_windows.len());
+        for NamedWindowDefinition(name, window) in named_windows {
+            let spec = match window {
+                NamedWindowExpr::NamedWindow(base) => get_named_window(&resolved, base)?,
+                NamedWindowExpr::WindowSpec(spec) => merge_window_spec(&resolved, spec)?,
+            };
+            if resolved.insert(name.value.clone(), spec).is_some() {
+                polars_bail!(SQLSyntax: "window '{}' is defined more than once", name.value)
+            }
+        }
+        Ok(resolved)
+    }
+
+    /// Look up a window defined in the `WINDOW` clause of the current select.
+    pub(crate) fn get_named_window(&self, name: &Ident) -> PolarsResult<WindowSpec> {
+        get_named_window(&self.named_windows, name)
+    }
+
+    /// Resolve the named window that a window specification refers to, if any, such as `w` in
+    /// `OVER (w ORDER BY x)`.
+    pub(crate) fn resolve_window_spec(&self, spec: &WindowSpec) -> PolarsResult<WindowSpec> {
+        merge_window_spec(&self.named_windows, spec)
+    }
+
+    fn execute_select_with_windows(
+        &mut self,
+        select_stmt: &Select,
+        query: &Query,
+    ) -> PolarsResult<LazyFrame> {
         let mut lf = if select_stmt.from.is_empty() {
             DataFrame::empty().lazy()
         } else {
@@ -1031,11 +1271,184 @@ impl SQLContext {
                     polars_bail!(SQLSyntax: "UNNEST table must have an alias");
                 }
             },
+            TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => {
+                let (tbl_name, lf) = self.get_table(table)?;
+                let lf = self.execute_pivot(
+                    lf,
+                    aggregate_functions,
+                    value_column,
+                    value_source,
+                    default_on_null.as_ref(),
+                )?;
+                self.alias_table_factor(tbl_name, lf, alias)
+            },
+            TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                alias,
+            } => {
+                let (tbl_name, lf) = self.get_table(table)?;
+                let lf = self.execute_unpivot(lf, value, name, columns)?;
+                self.alias_table_factor(tbl_name, lf, alias)
+            },
             // Support bare table, optionally with an alias, for now
             _ => polars_bail!(SQLInterface: "not yet implemented: {}", relation),
         }
     }
 
+    /// Register the result of a `PIVOT` or `UNPIVOT` under its alias, if any.
+    fn alias_table_factor(
+        &mut self,
+        tbl_name: String,
+        lf: LazyFrame,
+        alias: &Option<TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        match alias {
+            Some(alias) => {
+                let lf = self.rename_columns_from_table_alias(lf, alias)?;
+                self.table_map.insert(alias.name.value.clone(), lf.clone());
+                Ok((alias.name.value.clone(), lf))
+            },
+            None => Ok((tbl_name, lf)),
+        }
+    }
+
+    /// Execute `PIVOT (agg FOR column IN (values))` on a table.
+    ///
+    /// The values must be listed explicitly, so that the output columns are known without
+    /// running the query. The rows are grouped by all columns not used in the aggregate or as
+    /// the pivot column, and every value gets a column with the aggregate of its rows.
+    fn execute_pivot(
+        &mut self,
+        mut lf: LazyFrame,
+        aggregate_functions: &[ExprWithAlias],
+        value_column: &[Ident],
+        value_source: &PivotValueSource,
+        default_on_null: Option<&SQLExpr>,
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.get_frame_schema(&mut lf)?;
+        let [aggregate] = aggregate_functions else {
+            polars_bail!(SQLInterface: "PIVOT supports a single aggregate function; found {}", aggregate_functions.len())
+        };
+        let Some(on) = value_column.last() else {
+            polars_bail!(SQLSyntax: "PIVOT requires a column after FOR")
+        };
+        let PivotValueSource::List(pivot_values) = value_source else {
+            polars_bail!(SQLInterface: "PIVOT requires an explicit list of values after IN")
+        };
+
+        let agg = parse_sql_expr(&aggregate.expr, self, Some(&schema))?;
+        let mut agg_columns = expr_to_leaf_column_names(&agg);
+        agg_columns.sort_unstable();
+        agg_columns.dedup();
+        let values: Arc<[ColumnName]> = match agg_columns.as_slice() {
+            // COUNT(*) counts the rows of the pivot column.
+            [] => [ColumnName::from(on.value.as_str())].into(),
+            [value] => [value.clone()].into(),
+            _ => {
+                polars_bail!(SQLInterface: "the aggregate function of a PIVOT must refer to a single column; found {}", aggregate.expr)
+            },
+        };
+
+        let mut on_values = Vec::with_capacity(pivot_values.len());
+        for v in pivot_values {
+            let Expr::Literal(value) = parse_sql_expr(&v.expr, self, None)? else {
+                polars_bail!(SQLSyntax: "PIVOT values must be literals; found {}", v.expr)
+            };
+            let value = value
+                .to_any_value()
+                .ok_or_else(|| polars_err!(SQLInterface: "unsupported PIVOT value: {}", v.expr))?;
+            on_values.push(value.into_static()?);
+        }
+        let on_values = Series::from_any_values(&on.value, &on_values, false)?;
+        let headers = on_values.cast(&DataType::String)?;
+
+        let mut lf = lf.pivot(PivotArgs {
+            on: [ColumnName::from(on.value.as_str())].into(),
+            on_columns: Some(Arc::new(DataFrame::new(vec![on_values])?)),
+            index: None,
+            values: Some(values),
+            agg,
+            maintain_order: true,
+            sort_columns: false,
+            separator: "_".into(),
+        })?;
+
+        // The pivoted columns come after the index columns, in the order of the values.
+        let out_schema = self.get_frame_schema(&mut lf)?;
+        let pivoted = out_schema
+            .iter_names()
+            .skip(out_schema.len() - pivot_values.len())
+            .cloned()
+            .collect::<Vec<_>>();
+        let names = pivot_values
+            .iter()
+            .zip(headers.str()?)
+            .map(|(v, header)| {
+                let name = v
+                    .alias
+                    .as_ref()
+                    .map_or(header.unwrap_or("null"), |alias| alias.value.as_str());
+                match &aggregate.alias {
+                    Some(agg_alias) => format!("{}_{}", name, agg_alias.value),
+                    None => name.to_string(),
+                }
+            })
+            .collect::<Vec<_>>();
+        if let Some(default) = default_on_null {
+            let default = parse_sql_expr(default, self, None)?;
+            lf = lf.with_columns(
+                pivoted
+                    .iter()
+                    .map(|name| col(name).fill_null(default.clone()))
+                    .collect::<Vec<_>>(),
+            );
+        }
+        Ok(lf.rename(pivoted, names))
+    }
+
+    /// Execute `UNPIVOT (value FOR name IN (columns))` on a table. Like in other engines, the
+    /// rows with a null value are dropped.
+    fn execute_unpivot(
+        &mut self,
+        mut lf: LazyFrame,
+        value: &Ident,
+        name: &Ident,
+        columns: &[Ident],
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.get_frame_schema(&mut lf)?;
+        let on = columns
+            .iter()
+            .map(|c| {
+                schema.try_get(&c.value)?;
+                Ok(c.value.as_str().into())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let index = schema
+            .iter_names()
+            .filter(|name| !columns.iter().any(|c| c.value == name.as_str()))
+            .cloned()
+            .collect();
+        Ok(lf
+            .unpivot(UnpivotArgs {
+                on,
+                index,
+                variable_name: Some(name.value.as_str().into()),
+                value_name: Some(value.value.as_str().into()),
+                streamable: true,
+            })
+            .filter(col(&value.value).is_not_null()))
+    }
+
     fn execute_table_function(
         &mut self,
         name: &ObjectName,
@@ -1489,3 +1902,45 @@ fn process_join_constraint(
         polars_bail!(SQLInterface: "unsupported SQL join constraint:\n{:?}", constraint);
     }
 }
+
+fn get_named_window(
+    windows: &PlHashMap<String, WindowSpec>,
+    name: &Ident,
+) -> PolarsResult<WindowSpec> {
+    windows
+        .get(&name.value)
+        .cloned()
+        .ok_or_else(|| polars_err!(SQLInterface: "no window named '{}' is defined", name.value))
+}
+
+/// Resolve a window specification that refers to a named window, e.g. `OVER (w ORDER BY x)`.
+/// It copies the partitioning of the named window and can only add an ordering and a frame to
+/// it, not replace them.
+fn merge_window_spec(
+    windows: &PlHashMap<String, WindowSpec>,
+    spec: &WindowSpec,
+) -> PolarsResult<WindowSpec> {
+    let Some(name) = &spec.window_name else {
+        return Ok(spec.clone());
+    };
+    let mut merged = get_named_window(windows, name)?;
+    polars_ensure!(
+        spec.partition_by.is_empty(),
+        SQLSyntax: "cannot override the PARTITION BY of window '{}'", name.value
+    );
+    if !spec.order_by.is_empty() {
+        polars_ensure!(
+            merged.order_by.is_empty(),
+            SQLSyntax: "cannot override the ORDER BY of window '{}'", name.value
+        );
+        merged.order_by.clone_from(&spec.order_by);
+    }
+    if spec.window_frame.is_some() {
+        polars_ensure!(
+            merged.window_frame.is_none(),
+            SQLSyntax: "cannot override the frame of window '{}'", name.value
+        );
+        merged.window_frame.clone_from(&spec.window_frame);
+    }
+    Ok(merged)
+}
@@ -1,20 +1,31 @@
+use std::ops::Neg;
+
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
 use polars_core::export::regex;
-use polars_core::prelude::{polars_bail, polars_err, DataType, PolarsResult, Schema, TimeUnit};
+use polars_core::prelude::{
+    polars_bail, polars_ensure, polars_err, DataType, PolarsResult, Schema, TimeUnit,
+};
 use polars_lazy::dsl::Expr;
 #[cfg(feature = "list_eval")]
 use polars_lazy::dsl::ListNameSpaceExtension;
-use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, when};
+use polars_ops::series::RoundMode;
+use polars_plan::dsl::{
+    coalesce, concat_str, lag, lead, len, max_horizontal, min_horizontal, when,
+};
 use polars_plan::plans::{typed_lit, LiteralValue};
 use polars_plan::prelude::LiteralValue::Null;
-use polars_plan::prelude::{col, cols, lit, StrptimeOptions};
+use polars_plan::prelude::{col, cols, lit, StrptimeOptions, WindowFrame, WindowMapping};
+use polars_time::Duration;
 use sqlparser::ast::{
     DateTimeField, DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, Ident,
-    OrderByExpr, Value as SQLValue, WindowSpec, WindowType,
+    OrderByExpr, Value as SQLValue, WindowFrame as SQLWindowFrame, WindowFrameBound,
+    WindowFrameUnits, WindowSpec, WindowType,
 };
 
-use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
+use crate::sql_expr::{
+    adjust_one_indexed_param, parse_extract_date_part, parse_interval, parse_sql_expr,
+};
 use crate::SQLContext;
 
 pub(crate) struct SQLFunctionVisitor<'a> {
@@ -526,6 +537,24 @@ pub(crate) enum PolarsSQLFunctions {
     /// ```
     Variance,
 
+    // ----
+    // Window functions
+    // ----
+    /// SQL 'lag' function
+    /// Returns the value of the given number of rows (default 1) before the row, within its
+    /// partition, or the default (null if not given) if there is no such row.
+    /// ```sql
+    /// SELECT LAG(column_1, 1, 0) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    Lag,
+    /// SQL 'lead' function
+    /// Returns the value of the given number of rows (default 1) after the row, within its
+    /// partition, or the default (null if not given) if there is no such row.
+    /// ```sql
+    /// SELECT LEAD(column_1, 1, column_1) OVER (ORDER BY column_3) FROM df;
+    /// ```
+    Lead,
+
     // ----
     // Array functions
     // ----
@@ -663,7 +692,9 @@ impl PolarsSQLFunctions {
             "if",
             "ifnull",
             "initcap",
+            "lag",
             "last",
+            "lead",
             "least",
             "left",
             "length",
@@ -820,6 +851,12 @@ impl PolarsSQLFunctions {
             "sum" => Self::Sum,
             "var" | "variance" | "var_samp" => Self::Variance,
 
+            // ----
+            // Window functions
+            // ----
+            "lag" => Self::Lag,
+            "lead" => Self::Lead,
+
             // ----
             // Array functions
             // ----
@@ -890,7 +927,7 @@ impl SQLFunctionVisitor<'_> {
             Round => {
                 let args = extract_args(function)?;
                 match args.len() {
-                    1 => self.visit_unary(|e| e.round(0)),
+                    1 => self.visit_unary(|e| e.round(0, RoundMode::HalfAway)),
                     2 => self.try_visit_binary(|e, decimals| {
                         Ok(e.round(match decimals {
                             Expr::Literal(LiteralValue::Int(n)) => {
@@ -899,7 +936,7 @@ impl SQLFunctionVisitor<'_> {
                                 }
                             },
                             _ => polars_bail!(SQLSyntax: "invalid value for ROUND decimals ({})", args[1]),
-                        }))
+                        }, RoundMode::HalfAway))
                     }),
                     _ => polars_bail!(SQLSyntax: "ROUND expects 1-2 arguments (found {})", args.len()),
                 }
@@ -1245,6 +1282,12 @@ impl SQLFunctionVisitor<'_> {
             Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
             Variance => self.visit_unary(|e| e.var(1)),
 
+            // ----
+            // Window functions
+            // ----
+            Lag => self.visit_offset(lag),
+            Lead => self.visit_offset(lead),
+
             // ----
             // Array functions
             // ----
@@ -1401,14 +1444,11 @@ impl SQLFunctionVisitor<'_> {
         f: impl Fn(Expr) -> Expr,
         cumulative_f: impl Fn(Expr, bool) -> Expr,
     ) -> PolarsResult<Expr> {
-        match self.func.over.as_ref() {
-            Some(WindowType::WindowSpec(spec)) => {
-                self.apply_cumulative_window(f, cumulative_f, spec)
+        match self.window_spec(&self.func.over)? {
+            // A frame is applied by `visit_unary` instead.
+            Some(spec) if spec.window_frame.is_none() => {
+                self.apply_cumulative_window(f, cumulative_f, &spec)
             },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
             _ => self.visit_unary(f),
         }
     }
@@ -1560,23 +1600,20 @@ impl SQLFunctionVisitor<'_> {
 
     fn visit_count(&mut self) -> PolarsResult<Expr> {
         let (args, is_distinct) = extract_args_distinct(self.func)?;
-        match (is_distinct, args.as_slice()) {
+        let count = match (is_distinct, args.as_slice()) {
             // count(*), count()
-            (false, [FunctionArgExpr::Wildcard] | []) => Ok(len()),
+            (false, [FunctionArgExpr::Wildcard] | []) => len(),
             // count(column_name)
             (false, [FunctionArgExpr::Expr(sql_expr)]) => {
-                let expr = parse_sql_expr(sql_expr, self.ctx, self.active_schema)?;
-                let expr = self.apply_window_spec(expr, &self.func.over)?;
-                Ok(expr.count())
+                parse_sql_expr(sql_expr, self.ctx, self.active_schema)?.count()
             },
             // count(distinct column_name)
             (true, [FunctionArgExpr::Expr(sql_expr)]) => {
-                let expr = parse_sql_expr(sql_expr, self.ctx, self.active_schema)?;
-                let expr = self.apply_window_spec(expr, &self.func.over)?;
-                Ok(expr.n_unique())
+                parse_sql_expr(sql_expr, self.ctx, self.active_schema)?.n_unique()
             },
-            _ => self.not_supported_error(),
-        }
+            _ => return self.not_supported_error(),
+        };
+        self.apply_window_spec(count, &self.func.over)
     }
 
     fn apply_order_by(&mut self, expr: Expr, order_by: &[OrderByExpr]) -> PolarsResult<Expr> {
@@ -1601,43 +1638,161 @@ impl SQLFunctionVisitor<'_> {
         ))
     }
 
+    /// Resolve the window of a function, which is either specified inline or refers to a window
+    /// of the `WINDOW` clause.
+    fn window_spec(&self, window_type: &Option<WindowType>) -> PolarsResult<Option<WindowSpec>> {
+        match window_type {
+            Some(WindowType::WindowSpec(spec)) => self.ctx.resolve_window_spec(spec).map(Some),
+            Some(WindowType::NamedWindow(name)) => self.ctx.get_named_window(name).map(Some),
+            None => Ok(None),
+        }
+    }
+
     fn apply_window_spec(
         &mut self,
         expr: Expr,
         window_type: &Option<WindowType>,
     ) -> PolarsResult<Expr> {
-        Ok(match &window_type {
-            Some(WindowType::WindowSpec(window_spec)) => {
-                if window_spec.partition_by.is_empty() {
-                    let exprs = window_spec
-                        .order_by
-                        .iter()
-                        .map(|o| {
-                            let e = parse_sql_expr(&o.expr, self.ctx, self.active_schema)?;
-                            Ok(o.asc.map_or(e.clone(), |b| {
-                                e.sort(SortOptions::default().with_order_descending(!b))
-                            }))
-                        })
-                        .collect::<PolarsResult<Vec<_>>>()?;
-                    expr.over(exprs)
-                } else {
-                    // Process for simple window specification, partition by first
-                    let partition_by = window_spec
-                        .partition_by
-                        .iter()
-                        .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
-                        .collect::<PolarsResult<Vec<_>>>()?;
-                    expr.over(partition_by)
-                }
-            },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
-            None => expr,
+        let Some(window_spec) = self.window_spec(window_type)? else {
+            return Ok(expr);
+        };
+        if let Some(frame) = &window_spec.window_frame {
+            return self.apply_window_frame(expr, &window_spec, frame);
+        }
+        Ok(if window_spec.partition_by.is_empty() {
+            let exprs = window_spec
+                .order_by
+                .iter()
+                .map(|o| {
+                    let e = parse_sql_expr(&o.expr, self.ctx, self.active_schema)?;
+                    Ok(o.asc.map_or(e.clone(), |b| {
+                        e.sort(SortOptions::default().with_order_descending(!b))
+                    }))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            expr.over(exprs)
+        } else {
+            // Process for simple window specification, partition by first
+            let partition_by = window_spec
+                .partition_by
+                .iter()
+                .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            expr.over(partition_by)
         })
     }
 
+    /// Compute `expr` over a `ROWS` or `RANGE` frame of its partition, e.g.
+    /// `SUM(x) OVER (PARTITION BY g ORDER BY t ROWS BETWEEN 2 PRECEDING AND CURRENT ROW)`.
+    fn apply_window_frame(
+        &mut self,
+        expr: Expr,
+        window_spec: &WindowSpec,
+        frame: &SQLWindowFrame,
+    ) -> PolarsResult<Expr> {
+        let partition_by = window_spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let order_by = self.window_order_by(window_spec)?;
+
+        // Without an end bound the frame ends at the current row.
+        let start_bound = &frame.start_bound;
+        let end_bound = frame
+            .end_bound
+            .as_ref()
+            .unwrap_or(&WindowFrameBound::CurrentRow);
+        let window_frame = match frame.units {
+            WindowFrameUnits::Rows => WindowFrame::rows_between(
+                frame_offset(start_bound, true, 0, frame_rows)?,
+                frame_offset(end_bound, false, 0, frame_rows)?,
+            ),
+            WindowFrameUnits::Range => {
+                polars_ensure!(
+                    window_spec.order_by.len() == 1,
+                    SQLSyntax: "a RANGE frame needs an ORDER BY on a single column; found {}", window_spec
+                );
+                WindowFrame::range_between(
+                    frame_offset(start_bound, true, Duration::new(0), frame_range)?,
+                    frame_offset(end_bound, false, Duration::new(0), frame_range)?,
+                )
+            },
+            WindowFrameUnits::Groups => {
+                polars_bail!(SQLInterface: "GROUPS window frames are not currently supported; found {:?}", frame)
+            },
+        };
+        Ok(expr.over_frame(partition_by, order_by, window_frame))
+    }
+
+    /// The `ORDER BY` of a window, which must sort all columns in the same direction.
+    fn window_order_by(
+        &mut self,
+        window_spec: &WindowSpec,
+    ) -> PolarsResult<Option<(Vec<Expr>, SortOptions)>> {
+        let mut order_by = Vec::with_capacity(window_spec.order_by.len());
+        let mut sort_options = None;
+        for ob in &window_spec.order_by {
+            let descending = !ob.asc.unwrap_or(true);
+            let options = SortOptions::default()
+                .with_order_descending(descending)
+                .with_nulls_last(!ob.nulls_first.unwrap_or(descending));
+            if sort_options.is_some_and(|o| o != options) {
+                polars_bail!(SQLInterface: "the ORDER BY of a window frame must sort all columns in the same direction; found {}", window_spec)
+            }
+            sort_options = Some(options);
+            order_by.push(parse_sql_expr(&ob.expr, self.ctx, self.active_schema)?);
+        }
+        Ok(sort_options.map(|options| (order_by, options)))
+    }
+
+    /// `LAG` or `LEAD`, i.e. `f(expr [, offset [, default]])`, evaluated per partition in the
+    /// order of the window.
+    fn visit_offset(&mut self, f: fn(Expr, i64, Option<Expr>) -> Expr) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let (expr, n, default) = match args.as_slice() {
+            [FunctionArgExpr::Expr(e)] => (e, None, None),
+            [FunctionArgExpr::Expr(e), FunctionArgExpr::Expr(n)] => (e, Some(n), None),
+            [FunctionArgExpr::Expr(e), FunctionArgExpr::Expr(n), FunctionArgExpr::Expr(d)] => {
+                (e, Some(n), Some(d))
+            },
+            _ => return self.not_supported_error(),
+        };
+        let n = match n {
+            Some(n) => frame_rows(n).map_err(|_| {
+                polars_err!(
+                    SQLSyntax: "the offset of {} must be a non-negative integer; found {}",
+                    self.func.name, n
+                )
+            })?,
+            None => 1,
+        };
+        let expr = parse_sql_expr(expr, self.ctx, self.active_schema)?;
+        let default = default
+            .map(|d| parse_sql_expr(d, self.ctx, self.active_schema))
+            .transpose()?;
+        let expr = f(expr, n, default);
+
+        let Some(window_spec) = self.window_spec(&self.func.over)? else {
+            return Ok(expr);
+        };
+        polars_ensure!(
+            window_spec.window_frame.is_none(),
+            SQLSyntax: "{} does not take a window frame; found {}", self.func.name, window_spec
+        );
+        let mut partition_by = window_spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if partition_by.is_empty() {
+            // A single partition of all rows.
+            partition_by.push(lit(true));
+        }
+        let order_by = self.window_order_by(&window_spec)?;
+        Ok(expr.over_with_options(partition_by, order_by, WindowMapping::default()))
+    }
+
     fn not_supported_error(&self) -> PolarsResult<Expr> {
         polars_bail!(
             SQLInterface:
@@ -1647,6 +1802,47 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// The number of rows of a `ROWS` frame bound, e.g. the `2` of `2 PRECEDING`.
+fn frame_rows(expr: &SQLExpr) -> PolarsResult<i64> {
+    match expr {
+        SQLExpr::Value(SQLValue::Number(n, _)) => n.parse::<i64>().ok().filter(|n| *n >= 0),
+        _ => None,
+    }
+    .ok_or_else(
+        || polars_err!(SQLSyntax: "ROWS frame offsets must be non-negative integers; found {}", expr),
+    )
+}
+
+/// The offset of a `RANGE` frame bound, e.g. the `INTERVAL '1 day'` of
+/// `INTERVAL '1 day' PRECEDING`. Integers are offsets on an integer `ORDER BY` column.
+fn frame_range(expr: &SQLExpr) -> PolarsResult<Duration> {
+    match expr {
+        SQLExpr::Interval(interval) => parse_interval(interval),
+        _ => frame_rows(expr).map(Duration::new).map_err(|_| {
+            polars_err!(SQLSyntax: "RANGE frame offsets must be non-negative integers or intervals; found {}", expr)
+        }),
+    }
+}
+
+/// The offset of a frame bound from the current row, or `None` if it is unbounded.
+fn frame_offset<T: Neg<Output = T>>(
+    bound: &WindowFrameBound,
+    is_start: bool,
+    current_row: T,
+    offset: fn(&SQLExpr) -> PolarsResult<T>,
+) -> PolarsResult<Option<T>> {
+    Ok(match bound {
+        WindowFrameBound::CurrentRow => Some(current_row),
+        WindowFrameBound::Preceding(Some(e)) => Some(-offset(e)?),
+        WindowFrameBound::Following(Some(e)) => Some(offset(e)?),
+        WindowFrameBound::Preceding(None) if is_start => None,
+        WindowFrameBound::Following(None) if !is_start => None,
+        _ => {
+            polars_bail!(SQLSyntax: "invalid window frame {} bound: {}", if is_start { "start" } else { "end" }, bound)
+        },
+    })
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
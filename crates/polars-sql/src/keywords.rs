@@ -27,6 +27,7 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::CREATE,
         keywords::DATE,
         keywords::DATETIME,
+        keywords::DELETE,
         keywords::DESC,
         keywords::DISTINCT,
         keywords::DOUBLE,
@@ -40,12 +41,16 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::HAVING,
         keywords::IN,
         keywords::INNER,
+        keywords::INSERT,
         keywords::INT,
         keywords::INTERSECT,
         keywords::INTERVAL,
+        keywords::INTO,
         keywords::JOIN,
         keywords::LEFT,
         keywords::LIMIT,
+        keywords::MATCHED,
+        keywords::MERGE,
         keywords::NOT,
         keywords::NULL,
         keywords::OFFSET,
@@ -60,6 +65,7 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::RLIKE,
         keywords::SELECT,
         keywords::SEMI,
+        keywords::SET,
         keywords::SHOW,
         keywords::TABLE,
         keywords::TABLES,
@@ -67,7 +73,9 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::TIME,
         keywords::TRUNCATE,
         keywords::UNION,
+        keywords::UPDATE,
         keywords::USING,
+        keywords::VALUES,
         keywords::VARCHAR,
         keywords::WHEN,
         keywords::WHERE,
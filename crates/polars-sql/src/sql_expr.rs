@@ -455,27 +455,7 @@ impl SQLExprVisitor<'_> {
     }
 
     fn visit_interval(&self, interval: &Interval) -> PolarsResult<Expr> {
-        if interval.last_field.is_some()
-            || interval.leading_field.is_some()
-            || interval.leading_precision.is_some()
-            || interval.fractional_seconds_precision.is_some()
-        {
-            polars_bail!(SQLSyntax: "unsupported interval syntax ('{}')", interval)
-        }
-        let s = match &*interval.value {
-            SQLExpr::UnaryOp { .. } => {
-                polars_bail!(SQLSyntax: "unary ops are not valid on interval strings; found {}", interval.value)
-            },
-            SQLExpr::Value(SQLValue::SingleQuotedString(s)) => Some(s),
-            _ => None,
-        };
-        match s {
-            Some(s) if s.contains('-') => {
-                polars_bail!(SQLInterface: "minus signs are not yet supported in interval strings; found '{}'", s)
-            },
-            Some(s) => Ok(lit(Duration::parse_interval(s))),
-            None => polars_bail!(SQLSyntax: "invalid interval {:?}", interval),
-        }
+        parse_interval(interval).map(lit)
     }
 
     fn visit_like(
@@ -870,7 +850,7 @@ impl SQLExprVisitor<'_> {
 
         #[cfg(feature = "json")]
         if data_type == &SQLDataType::JSON {
-            return Ok(expr.str().json_decode(None, None));
+            return Ok(expr.str().json_decode(None, None, true));
         }
         let polars_type = map_sql_polars_datatype(data_type)?;
         Ok(match cast_kind {
@@ -1274,6 +1254,31 @@ pub(crate) fn parse_extract_date_part(expr: Expr, field: &DateTimeField) -> Pola
 
 /// Allow an expression that represents a 1-indexed parameter to
 /// be adjusted from 1-indexed (SQL) to 0-indexed (Rust/Polars)
+/// Parse an `INTERVAL '...'` literal.
+pub(crate) fn parse_interval(interval: &Interval) -> PolarsResult<Duration> {
+    if interval.last_field.is_some()
+        || interval.leading_field.is_some()
+        || interval.leading_precision.is_some()
+        || interval.fractional_seconds_precision.is_some()
+    {
+        polars_bail!(SQLSyntax: "unsupported interval syntax ('{}')", interval)
+    }
+    let s = match &*interval.value {
+        SQLExpr::UnaryOp { .. } => {
+            polars_bail!(SQLSyntax: "unary ops are not valid on interval strings; found {}", interval.value)
+        },
+        SQLExpr::Value(SQLValue::SingleQuotedString(s)) => Some(s),
+        _ => None,
+    };
+    match s {
+        Some(s) if s.contains('-') => {
+            polars_bail!(SQLInterface: "minus signs are not yet supported in interval strings; found '{}'", s)
+        },
+        Some(s) => Ok(Duration::parse_interval(s)),
+        None => polars_bail!(SQLSyntax: "invalid interval {:?}", interval),
+    }
+}
+
 pub(crate) fn adjust_one_indexed_param(idx: Expr, null_if_zero: bool) -> Expr {
     match idx {
         Expr::Literal(Null) => lit(Null),
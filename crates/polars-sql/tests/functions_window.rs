@@ -0,0 +1,126 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "Year" => [2018, 2018, 2019, 2019, 2020, 2020],
+      "Country" => ["US", "UK", "US", "UK", "US", "UK"],
+      "Sales" => [1000i64, 2000, 3000, 4000, 5000, 6000]
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+    ctx
+}
+
+#[test]
+fn test_window_frame_rows() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT
+          Country,
+          Year,
+          SUM(Sales) OVER (
+            PARTITION BY Country ORDER BY Year ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
+          ) AS s
+        FROM df
+        ORDER BY Country, Year
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "Country" => ["UK", "UK", "UK", "US", "US", "US"],
+        "Year" => [2018, 2019, 2020, 2018, 2019, 2020],
+        "s" => [2000i64, 6000, 10000, 1000, 4000, 8000],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected));
+}
+
+#[test]
+fn test_window_frame_range() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT
+          Year,
+          AVG(Sales) OVER (ORDER BY Year RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) AS avg
+        FROM df
+        ORDER BY Year
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "Year" => [2018, 2018, 2019, 2019, 2020, 2020],
+        "avg" => [1500.0, 1500.0, 2500.0, 2500.0, 4500.0, 4500.0],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected));
+}
+
+#[test]
+fn test_named_window() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT
+          Country,
+          Year,
+          SUM(Sales) OVER (w ROWS UNBOUNDED PRECEDING) AS running,
+          COUNT(*) OVER w AS n
+        FROM df
+        WINDOW w AS (PARTITION BY Country ORDER BY Year DESC)
+        ORDER BY Country, Year
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "Country" => ["UK", "UK", "UK", "US", "US", "US"],
+        "Year" => [2018, 2019, 2020, 2018, 2019, 2020],
+        "running" => [12000i64, 10000, 6000, 9000, 8000, 5000],
+        "n" => [3 as IdxSize, 3, 3, 3, 3, 3],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected));
+}
+
+#[test]
+fn test_lag_lead() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT
+          Country,
+          Year,
+          LAG(Sales) OVER (PARTITION BY Country ORDER BY Year) AS prev,
+          LEAD(Sales, 1, Sales) OVER (PARTITION BY Country ORDER BY Year) AS next,
+          LAG(Year, 2, 0) OVER (ORDER BY Year DESC, Country DESC) AS lag2
+        FROM df
+        ORDER BY Country, Year
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "Country" => ["UK", "UK", "UK", "US", "US", "US"],
+        "Year" => [2018, 2019, 2020, 2018, 2019, 2020],
+        "prev" => [None, Some(2000i64), Some(4000), None, Some(1000), Some(3000)],
+        "next" => [4000i64, 6000, 6000, 3000, 5000, 5000],
+        "lag2" => [2019, 2020, 0, 2019, 2020, 0],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected));
+}
+
+#[test]
+fn test_window_frame_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT SUM(Sales) OVER w FROM df",
+        "SELECT SUM(Sales) OVER (ORDER BY Year GROUPS 1 PRECEDING) FROM df",
+        "SELECT SUM(Sales) OVER (ORDER BY Year ROWS BETWEEN UNBOUNDED FOLLOWING AND CURRENT ROW) FROM df",
+        "SELECT SUM(Sales) OVER (ORDER BY Country, Year RANGE 1 PRECEDING) FROM df",
+        "SELECT SUM(Sales) OVER (w PARTITION BY Year) FROM df WINDOW w AS (PARTITION BY Country)",
+        "SELECT LAG(Sales, -1) OVER (ORDER BY Year) FROM df",
+        "SELECT LAG(Sales) OVER (ORDER BY Year ROWS 1 PRECEDING) FROM df",
+    ] {
+        assert!(
+            ctx.execute(sql).and_then(|lf| lf.collect()).is_err(),
+            "expected an error for: {sql}"
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let sales = df! {
+      "region" => ["east", "east", "west", "east"],
+      "quarter" => ["Q1", "Q2", "Q1", "Q1"],
+      "amount" => [10i64, 20, 30, 5]
+    }
+    .unwrap()
+    .lazy();
+    let wide = df! {
+      "region" => ["east", "west"],
+      "q1" => [Some(15i64), Some(30)],
+      "q2" => [Some(20i64), None]
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("sales", sales);
+    ctx.register("wide", wide);
+    ctx
+}
+
+#[test]
+fn test_pivot() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+      SELECT *
+      FROM sales
+      PIVOT (SUM(amount) FOR quarter IN ('Q1', 'Q2', 'Q3' AS q3)) AS p
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "region" => ["east", "west"],
+      "Q1" => [Some(15i64), Some(30)],
+      "Q2" => [Some(20i64), None],
+      "q3" => [None::<i64>, None],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected));
+
+    // The pivoted table can be referred to by its alias.
+    let sql = r#"
+      SELECT p.region, p.Q1 + p.Q2 AS total
+      FROM sales
+      PIVOT (COUNT(*) FOR quarter IN ('Q1', 'Q2')) AS p
+      ORDER BY region
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "region" => ["east", "west"],
+      "total" => [Some(3 as IdxSize), None],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected));
+}
+
+#[test]
+fn test_pivot_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT * FROM sales PIVOT (SUM(amount) FOR quarter IN (ANY)) AS p",
+        "SELECT * FROM sales PIVOT (SUM(amount), MAX(amount) FOR quarter IN ('Q1')) AS p",
+        "SELECT * FROM sales PIVOT (SUM(amount) FOR quarter IN (UPPER('q1'))) AS p",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "expected an error for: {sql}");
+    }
+}
+
+#[test]
+fn test_unpivot() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+      SELECT *
+      FROM wide
+      UNPIVOT (amount FOR quarter IN (q1, q2)) AS u
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "region" => ["east", "west", "east"],
+      "quarter" => ["q1", "q1", "q2"],
+      "amount" => [15i64, 30, 20],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected));
+}
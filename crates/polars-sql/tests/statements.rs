@@ -629,3 +629,48 @@ fn test_compound_invalid_3() {
     let sql = "SELECT * FROM df1 INNER JOIN df2 ON df1.a = df2.a AND b";
     ctx.execute(sql).unwrap().collect().unwrap();
 }
+
+#[test]
+fn test_merge_into() {
+    let tgt = df! {
+        "id" => [1i64, 2, 3],
+        "val" => ["a", "b", "c"],
+    }
+    .unwrap();
+    let src = df! {
+        "id" => [2i64, 3, 4],
+        "val" => ["B", "C", "D"],
+        "del" => [false, true, false],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("tgt", tgt.lazy());
+    ctx.register("src", src.lazy());
+
+    let sql = r#"
+        MERGE INTO tgt AS t USING src AS s ON t.id = s.id
+        WHEN MATCHED AND s.del THEN DELETE
+        WHEN MATCHED THEN UPDATE SET val = s.val
+        WHEN NOT MATCHED THEN INSERT (id, val) VALUES (s.id, s.val)
+    "#;
+    let expected = df! {
+        "id" => [1i64, 2, 4],
+        "val" => ["a", "B", "D"],
+    }
+    .unwrap();
+    let merged = ctx
+        .execute(sql)
+        .unwrap()
+        .sort(["id"], Default::default())
+        .collect()
+        .unwrap();
+    assert!(merged.equals(&expected));
+
+    // The target table is replaced by the merged one.
+    let actual = ctx
+        .execute("SELECT * FROM tgt ORDER BY id")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(actual.equals(&expected));
+}
@@ -3,11 +3,13 @@ use polars_core::POOL;
 use polars_error::PolarsResult;
 use polars_expr::state::ExecutionState;
 use polars_utils::aliases::{InitHashMaps, PlHashSet};
+use polars_utils::cancel::CancellationToken;
 use slotmap::{SecondaryMap, SparseSecondaryMap};
 
 use crate::async_executor;
 use crate::async_primitives::pipe::{pipe, Receiver, Sender};
 use crate::graph::{Graph, GraphNodeKey, LogicalPipeKey, PortState};
+use crate::metrics::GraphMetrics;
 use crate::morsel::Morsel;
 
 /// Finds all pipeline blockers in the graph, that is, those nodes which do not
@@ -83,6 +85,7 @@ fn run_subgraph(
     nodes: &PlHashSet<GraphNodeKey>,
     pipes: &[LogicalPipeKey],
     finalize_output: &mut SparseSecondaryMap<GraphNodeKey, DataFrame>,
+    mut metrics: Option<&mut GraphMetrics>,
 ) -> PolarsResult<()> {
     // Get the number of threads from the rayon thread-pool as that respects our config.
     let num_pipes = POOL.current_num_threads();
@@ -94,10 +97,25 @@ fn run_subgraph(
 
     // For morsel-driven parallelism we create N independent pipelines, where N is the number of threads.
     // The first step is to create N physical pipes for every logical pipe in the graph.
+    // When gathering metrics, every physical pipe is split in two with a task in between that
+    // measures the morsels passing through.
+    let mut relays = Vec::new();
     for pipe_key in pipes.iter().copied() {
-        let (senders, receivers): (Vec<Sender<Morsel>>, Vec<Receiver<Morsel>>) =
+        let (senders, mut receivers): (Vec<Sender<Morsel>>, Vec<Receiver<Morsel>>) =
             (0..num_pipes).map(|_| pipe()).unzip();
 
+        if let Some(metrics) = metrics.as_deref_mut() {
+            let pipe_metrics = metrics.pipe(pipe_key);
+            receivers = receivers
+                .into_iter()
+                .map(|recv| {
+                    let (send, relayed_recv) = pipe();
+                    relays.push((recv, send, pipe_metrics.clone()));
+                    relayed_recv
+                })
+                .collect();
+        }
+
         physical_senders.insert(pipe_key, senders);
         physical_receivers.insert(pipe_key, receivers);
     }
@@ -110,6 +128,17 @@ fn run_subgraph(
         let mut join_handles = Vec::new();
         let mut phys_recv = Vec::new();
         let mut phys_send = Vec::new();
+        for (mut recv, mut send, pipe_metrics) in relays {
+            join_handles.push(scope.spawn_task(true, async move {
+                while let Ok(morsel) = recv.recv().await {
+                    pipe_metrics.add(&morsel);
+                    if send.send(morsel).await.is_err() {
+                        break;
+                    }
+                }
+                PolarsResult::Ok(())
+            }));
+        }
         for (node_key, node) in graph.nodes.iter_mut() {
             // We can't directly loop over nodes because we need iter_mut to get
             // multiple mutable references without the compiler complaining about
@@ -142,13 +171,23 @@ fn run_subgraph(
 
             // Spawn a task per pipeline.
             for pipeline in 0..num_pipes {
-                join_handles.push(node.compute.spawn(
+                let handle = node.compute.spawn(
                     scope,
                     pipeline,
                     &mut phys_recv[num_inputs * pipeline..num_inputs * (pipeline + 1)],
                     &mut phys_send[num_outputs * pipeline..num_outputs * (pipeline + 1)],
                     &execution_state,
-                ));
+                );
+                if let Some(metrics) = metrics.as_deref_mut() {
+                    let finished = metrics.start_node(node_key);
+                    join_handles.push(scope.spawn_task(false, async move {
+                        let out = handle.await;
+                        finished();
+                        out
+                    }));
+                } else {
+                    join_handles.push(handle);
+                }
             }
         }
 
@@ -164,6 +203,9 @@ fn run_subgraph(
     // Finalize computation and get any in-memory results.
     for node_key in nodes.iter().copied() {
         if let Some(df) = graph.nodes[node_key].compute.finalize()? {
+            if let Some(metrics) = metrics.as_deref_mut() {
+                metrics.finalize_node(node_key, &df);
+            }
             finalize_output.insert(node_key, df);
         }
     }
@@ -173,9 +215,12 @@ fn run_subgraph(
 
 pub fn execute_graph(
     graph: &mut Graph,
+    mut metrics: Option<&mut GraphMetrics>,
 ) -> PolarsResult<SparseSecondaryMap<GraphNodeKey, DataFrame>> {
+    let token = CancellationToken::current().unwrap_or_default();
     let mut out = SparseSecondaryMap::new();
     loop {
+        token.check()?;
         // println!("updating state");
         graph.update_all_states();
         let (nodes, pipes) = find_runnable_subgraph(graph);
@@ -185,7 +230,7 @@ pub fn execute_graph(
         if nodes.is_empty() {
             break;
         }
-        run_subgraph(graph, &nodes, &pipes, &mut out)?;
+        run_subgraph(graph, &nodes, &pipes, &mut out, metrics.as_deref_mut())?;
     }
     Ok(out)
 }
@@ -5,10 +5,11 @@ mod async_executor;
 mod async_primitives;
 mod skeleton;
 
-pub use skeleton::run_query;
+pub use skeleton::{profile_query, run_query};
 
 mod execute;
 mod graph;
+mod metrics;
 mod morsel;
 mod nodes;
 mod physical_plan;
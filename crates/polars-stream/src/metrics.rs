@@ -0,0 +1,142 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use polars_core::prelude::*;
+use slotmap::SecondaryMap;
+
+use crate::graph::{Graph, GraphNodeKey, LogicalPipeKey};
+use crate::morsel::Morsel;
+
+/// The data that flowed through a logical pipe, summed over its physical pipes.
+#[derive(Default)]
+pub struct PipeMetrics {
+    morsels: AtomicU64,
+    rows: AtomicU64,
+    bytes: AtomicU64,
+}
+
+impl PipeMetrics {
+    pub fn add(&self, morsel: &Morsel) {
+        let df = morsel.df();
+        self.morsels.fetch_add(1, Ordering::Relaxed);
+        self.rows.fetch_add(df.height() as u64, Ordering::Relaxed);
+        self.bytes
+            .fetch_add(df.estimated_size() as u64, Ordering::Relaxed);
+    }
+}
+
+/// When a node first started and last finished running, in microseconds since the start of
+/// the query. A node can run in multiple phases of the query.
+#[derive(Default)]
+pub struct NodeMetrics {
+    start: Option<u64>,
+    end: Arc<AtomicU64>,
+    /// Rows of the in-memory result of a sink.
+    rows_finalized: u64,
+}
+
+/// Per-node metrics of a graph execution, see [`GraphMetrics::finish`].
+pub struct GraphMetrics {
+    query_start: Instant,
+    pipes: SecondaryMap<LogicalPipeKey, Arc<PipeMetrics>>,
+    nodes: SecondaryMap<GraphNodeKey, NodeMetrics>,
+}
+
+impl GraphMetrics {
+    pub fn new() -> Self {
+        Self {
+            query_start: Instant::now(),
+            pipes: SecondaryMap::new(),
+            nodes: SecondaryMap::new(),
+        }
+    }
+
+    fn elapsed(query_start: Instant) -> u64 {
+        query_start.elapsed().as_micros() as u64
+    }
+
+    pub fn pipe(&mut self, key: LogicalPipeKey) -> Arc<PipeMetrics> {
+        self.pipes.entry(key).unwrap().or_default().clone()
+    }
+
+    /// Mark that the tasks of `key` are started, returns a function that is to be called when
+    /// one of them finishes.
+    pub fn start_node(&mut self, key: GraphNodeKey) -> impl Fn() + Send + Sync + 'static {
+        let now = Self::elapsed(self.query_start);
+        let node = self.nodes.entry(key).unwrap().or_default();
+        node.start.get_or_insert(now);
+
+        let query_start = self.query_start;
+        let end = node.end.clone();
+        move || {
+            end.fetch_max(Self::elapsed(query_start), Ordering::Relaxed);
+        }
+    }
+
+    pub fn finalize_node(&mut self, key: GraphNodeKey, df: &DataFrame) {
+        self.nodes.entry(key).unwrap().or_default().rows_finalized += df.height() as u64;
+    }
+
+    /// A DataFrame with a row per node: its name, when it started and finished running, how
+    /// many rows and bytes it received and sent, its `id` and the `id` of the node it sends
+    /// its output to.
+    pub fn finish(self, graph: &Graph) -> PolarsResult<DataFrame> {
+        let sum = |pipes: &[LogicalPipeKey], f: fn(&PipeMetrics) -> &AtomicU64| {
+            pipes
+                .iter()
+                .filter_map(|key| self.pipes.get(*key))
+                .map(|pipe| f(pipe).load(Ordering::Relaxed))
+                .sum::<u64>()
+        };
+
+        let mut names = Vec::with_capacity(self.nodes.len());
+        let mut start = Vec::with_capacity(self.nodes.len());
+        let mut end = Vec::with_capacity(self.nodes.len());
+        let mut rows_in = Vec::with_capacity(self.nodes.len());
+        let mut rows_out = Vec::with_capacity(self.nodes.len());
+        let mut bytes_in = Vec::with_capacity(self.nodes.len());
+        let mut bytes_out = Vec::with_capacity(self.nodes.len());
+        let mut morsels_out = Vec::with_capacity(self.nodes.len());
+        let mut ids = Vec::with_capacity(self.nodes.len());
+        let mut parents = Vec::with_capacity(self.nodes.len());
+        let node_ids: SecondaryMap<GraphNodeKey, u32> = self
+            .nodes
+            .keys()
+            .enumerate()
+            .map(|(id, key)| (key, id as u32))
+            .collect();
+        for (key, metrics) in self.nodes.iter() {
+            let node = &graph.nodes[key];
+            names.push(node.compute.name());
+            start.push(metrics.start.unwrap_or_default());
+            end.push(metrics.end.load(Ordering::Relaxed));
+            rows_in.push(sum(&node.inputs, |p| &p.rows));
+            rows_out.push(sum(&node.outputs, |p| &p.rows) + metrics.rows_finalized);
+            bytes_in.push(sum(&node.inputs, |p| &p.bytes));
+            bytes_out.push(sum(&node.outputs, |p| &p.bytes));
+            morsels_out.push(sum(&node.outputs, |p| &p.morsels));
+            ids.push(node_ids[key]);
+            // A node that sends to multiple nodes is attributed to the first of them.
+            parents.push(
+                node.outputs
+                    .first()
+                    .and_then(|pipe| node_ids.get(graph.pipes[*pipe].receiver).copied()),
+            );
+        }
+
+        let df = DataFrame::new(vec![
+            Series::new("node", names),
+            Series::new("start", start),
+            Series::new("end", end),
+            Series::new("rows_in", rows_in),
+            Series::new("rows_out", rows_out),
+            Series::new("bytes_in", bytes_in),
+            Series::new("bytes_out", bytes_out),
+            Series::new("morsels_out", morsels_out),
+            Series::new("id", ids),
+            Series::new("parent", parents),
+        ])?;
+        df.sort(vec!["start"], SortMultipleOptions::default())
+    }
+}
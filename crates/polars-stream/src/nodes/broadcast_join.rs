@@ -0,0 +1,205 @@
+use std::sync::Arc;
+
+use polars_core::frame::DataFrame;
+use polars_core::schema::Schema;
+use polars_core::series::Series;
+use polars_error::PolarsResult;
+use polars_expr::prelude::PhysicalExpr;
+use polars_expr::state::ExecutionState;
+#[cfg(feature = "iejoin")]
+use polars_ops::frame::IEJoinTable;
+use polars_ops::frame::{JoinArgs, JoinType};
+
+use super::in_memory_sink::InMemorySinkNode;
+use super::{ComputeNode, PortState};
+use crate::async_executor::{JoinHandle, TaskScope};
+use crate::async_primitives::pipe::{Receiver, Sender};
+use crate::morsel::Morsel;
+
+/// The right side of the join, fully materialized.
+struct ProbeTable {
+    df: DataFrame,
+    keys: ProbeKeys,
+}
+
+enum ProbeKeys {
+    #[cfg(feature = "asof_join")]
+    AsOf(Vec<Series>),
+    #[cfg(feature = "iejoin")]
+    IEJoin(IEJoinTable),
+}
+
+impl ProbeTable {
+    fn new(df: DataFrame, keys: Vec<Series>, args: &JoinArgs) -> PolarsResult<Self> {
+        let keys = match &args.how {
+            #[cfg(feature = "asof_join")]
+            JoinType::AsOf(_) => ProbeKeys::AsOf(keys),
+            #[cfg(feature = "iejoin")]
+            JoinType::IEJoin(options) => ProbeKeys::IEJoin(IEJoinTable::new(&keys, *options)?),
+            _ => unreachable!(),
+        };
+        Ok(Self { df, keys })
+    }
+
+    fn join(
+        &self,
+        left: &DataFrame,
+        left_keys: Vec<Series>,
+        args: &JoinArgs,
+    ) -> PolarsResult<DataFrame> {
+        match &self.keys {
+            #[cfg(feature = "asof_join")]
+            ProbeKeys::AsOf(keys) => {
+                use polars_ops::frame::DataFrameJoinOps;
+                left._join_impl(&self.df, left_keys, keys.clone(), args.clone(), true, false)
+            },
+            #[cfg(feature = "iejoin")]
+            ProbeKeys::IEJoin(table) => {
+                table.join(left, &left_keys, &self.df, &args.naming(), None)
+            },
+        }
+    }
+}
+
+enum BroadcastJoinState {
+    /// Collecting the right side.
+    Build(InMemorySinkNode),
+    /// Streaming the left side, joining each morsel against the right side.
+    Probe(ProbeTable),
+    Done,
+}
+
+/// A join that materializes the right side and streams the left side.
+///
+/// Supports the joins in which every left row is matched by searching only in the right side,
+/// asof and inequality joins. A left morsel thus gives the same rows joined on its own as when
+/// joined as part of the whole left side, and the morsels can be joined independently and in
+/// parallel while keeping their order.
+pub struct BroadcastJoinNode {
+    state: BroadcastJoinState,
+    left_on: Vec<Arc<dyn PhysicalExpr>>,
+    right_on: Vec<Arc<dyn PhysicalExpr>>,
+    args: JoinArgs,
+}
+
+impl BroadcastJoinNode {
+    pub fn new(
+        right_schema: Arc<Schema>,
+        left_on: Vec<Arc<dyn PhysicalExpr>>,
+        right_on: Vec<Arc<dyn PhysicalExpr>>,
+        args: JoinArgs,
+    ) -> Self {
+        Self {
+            state: BroadcastJoinState::Build(InMemorySinkNode::new(right_schema)),
+            left_on,
+            right_on,
+            args,
+        }
+    }
+}
+
+impl ComputeNode for BroadcastJoinNode {
+    fn name(&self) -> &'static str {
+        "broadcast_join"
+    }
+
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        // If the output doesn't want any more data, we are always done.
+        if send[0] == PortState::Done {
+            self.state = BroadcastJoinState::Done;
+        }
+
+        match &mut self.state {
+            BroadcastJoinState::Build(sink) => {
+                sink.update_state(&mut recv[1..2], &mut []);
+                recv[0] = PortState::Blocked;
+                send[0] = PortState::Blocked;
+            },
+            BroadcastJoinState::Probe(_) => {
+                recv[1] = PortState::Done;
+                core::mem::swap(&mut recv[0], &mut send[0]);
+            },
+            BroadcastJoinState::Done => {
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, BroadcastJoinState::Build(_))
+    }
+
+    fn initialize(&mut self, num_pipelines: usize) {
+        if let BroadcastJoinState::Build(sink) = &mut self.state {
+            sink.initialize(num_pipelines);
+        }
+    }
+
+    fn spawn<'env, 's>(
+        &'env self,
+        scope: &'s TaskScope<'s, 'env>,
+        pipeline: usize,
+        recv: &mut [Option<Receiver<Morsel>>],
+        send: &mut [Option<Sender<Morsel>>],
+        state: &'s ExecutionState,
+    ) -> JoinHandle<PolarsResult<()>> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        let table = match &self.state {
+            BroadcastJoinState::Build(sink) => {
+                return sink.spawn(scope, pipeline, &mut recv[1..2], &mut [], state)
+            },
+            BroadcastJoinState::Probe(table) => table,
+            BroadcastJoinState::Done => unreachable!(),
+        };
+
+        let mut recv = recv[0].take().unwrap();
+        let mut send = send[0].take().unwrap();
+
+        scope.spawn_task(true, async move {
+            while let Ok(morsel) = recv.recv().await {
+                if morsel.df().is_empty() {
+                    continue;
+                }
+
+                let morsel = morsel.try_map(|df| {
+                    let left_keys = self
+                        .left_on
+                        .iter()
+                        .map(|e| e.evaluate(&df, state))
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    table.join(&df, left_keys, &self.args)
+                })?;
+
+                if send.send(morsel).await.is_err() {
+                    break;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Option<DataFrame>> {
+        match &mut self.state {
+            BroadcastJoinState::Build(sink) => {
+                let df = sink.finalize()?.unwrap();
+                let state = ExecutionState::new();
+                let keys = self
+                    .right_on
+                    .iter()
+                    .map(|e| e.evaluate(&df, &state))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                self.state = BroadcastJoinState::Probe(ProbeTable::new(df, keys, &self.args)?);
+            },
+            // The left side is streamed in a single pass.
+            BroadcastJoinState::Probe(_) => self.state = BroadcastJoinState::Done,
+            BroadcastJoinState::Done => {},
+        }
+        Ok(None)
+    }
+}
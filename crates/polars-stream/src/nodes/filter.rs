@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use parking_lot::Mutex;
 use polars_error::{polars_err, PolarsResult};
 use polars_expr::prelude::PhysicalExpr;
 use polars_expr::state::ExecutionState;
@@ -11,11 +12,16 @@ use crate::morsel::Morsel;
 
 pub struct FilterNode {
     predicate: Arc<dyn PhysicalExpr>,
+    // Held while evaluating if the predicate may not run in parallel.
+    sequential: Option<Mutex<()>>,
 }
 
 impl FilterNode {
-    pub fn new(predicate: Arc<dyn PhysicalExpr>) -> Self {
-        Self { predicate }
+    pub fn new(predicate: Arc<dyn PhysicalExpr>, sequential: bool) -> Self {
+        Self {
+            predicate,
+            sequential: sequential.then(|| Mutex::new(())),
+        }
     }
 }
 
@@ -44,7 +50,10 @@ impl ComputeNode for FilterNode {
         scope.spawn_task(true, async move {
             while let Ok(morsel) = recv.recv().await {
                 let morsel = morsel.try_map(|df| {
-                    let mask = self.predicate.evaluate(&df, state)?;
+                    let mask = {
+                        let _guard = self.sequential.as_ref().map(|lock| lock.lock());
+                        self.predicate.evaluate(&df, state)?
+                    };
                     let mask = mask.bool().map_err(|_| {
                         polars_err!(
                             ComputeError: "filter predicate must be of type `Boolean`, got `{}`", mask.dtype()
@@ -7,6 +7,8 @@ use crate::async_primitives::pipe::{Receiver, Sender};
 use crate::graph::PortState;
 use crate::morsel::Morsel;
 
+#[cfg(any(feature = "asof_join", feature = "iejoin"))]
+pub mod broadcast_join;
 pub mod filter;
 pub mod in_memory_map;
 pub mod in_memory_sink;
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use parking_lot::Mutex;
 use polars_core::frame::DataFrame;
 use polars_core::schema::Schema;
 use polars_core::series::Series;
@@ -16,6 +17,8 @@ pub struct SelectNode {
     selectors: Vec<Arc<dyn PhysicalExpr>>,
     schema: Arc<Schema>,
     extend_original: bool,
+    // Held while evaluating if a selector may not run in parallel.
+    sequential: Option<Mutex<()>>,
 }
 
 impl SelectNode {
@@ -23,11 +26,13 @@ impl SelectNode {
         selectors: Vec<Arc<dyn PhysicalExpr>>,
         schema: Arc<Schema>,
         extend_original: bool,
+        sequential: bool,
     ) -> Self {
         Self {
             selectors,
             schema,
             extend_original,
+            sequential: sequential.then(|| Mutex::new(())),
         }
     }
 }
@@ -58,11 +63,13 @@ impl ComputeNode for SelectNode {
             while let Ok(morsel) = recv.recv().await {
                 let morsel = morsel.try_map(|df| {
                     // Select columns.
+                    let guard = self.sequential.as_ref().map(|lock| lock.lock());
                     let mut selected: Vec<Series> = self
                         .selectors
                         .iter()
                         .map(|s| s.evaluate(&df, state))
                         .collect::<PolarsResult<_>>()?;
+                    drop(guard);
 
                     // Extend or create new dataframe.
                     let ret = if self.extend_original {
@@ -1,7 +1,17 @@
 use std::sync::Arc;
 
+#[cfg(feature = "iejoin")]
+use polars_core::chunked_array::cast::CastOptions;
+#[cfg(feature = "iejoin")]
+use polars_core::schema::Schema;
+#[cfg(feature = "iejoin")]
+use polars_core::utils::try_get_supertype;
 use polars_error::PolarsResult;
+#[cfg(any(feature = "asof_join", feature = "iejoin"))]
+use polars_ops::frame::JoinType;
 use polars_plan::plans::{AExpr, Context, IR};
+#[cfg(feature = "iejoin")]
+use polars_plan::prelude::expr_ir::{ExprIR, OutputName};
 use polars_plan::prelude::SinkType;
 use polars_utils::arena::{Arena, Node};
 use slotmap::SlotMap;
@@ -12,6 +22,51 @@ fn is_streamable(node: Node, arena: &Arena<AExpr>) -> bool {
     polars_plan::plans::is_streamable(node, arena, Context::Default)
 }
 
+/// Whether the join matches every left row by searching only in the right side, such that it
+/// can stream the left side.
+#[cfg(any(feature = "asof_join", feature = "iejoin"))]
+fn is_broadcast_join(how: &JoinType) -> bool {
+    match how {
+        #[cfg(feature = "asof_join")]
+        JoinType::AsOf(_) => true,
+        #[cfg(feature = "iejoin")]
+        JoinType::IEJoin(_) => true,
+        _ => false,
+    }
+}
+
+#[cfg(feature = "iejoin")]
+fn cast_to_supertypes(
+    left_on: &mut [ExprIR],
+    right_on: &mut [ExprIR],
+    left_schema: &Schema,
+    right_schema: &Schema,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    for (l, r) in left_on.iter_mut().zip(right_on) {
+        let left_dtype =
+            expr_arena
+                .get(l.node())
+                .get_type(left_schema, Context::Default, expr_arena)?;
+        let right_dtype =
+            expr_arena
+                .get(r.node())
+                .get_type(right_schema, Context::Default, expr_arena)?;
+        let dtype = try_get_supertype(&left_dtype, &right_dtype)?;
+        for (e, e_dtype) in [(l, left_dtype), (r, right_dtype)] {
+            if e_dtype != dtype {
+                let node = expr_arena.add(AExpr::Cast {
+                    expr: e.node(),
+                    data_type: dtype.clone(),
+                    options: CastOptions::NonStrict,
+                });
+                *e = ExprIR::new(node, OutputName::Alias(e.output_name().into()));
+            }
+        }
+    }
+    Ok(())
+}
+
 #[recursive::recursive]
 pub fn lower_ir(
     node: Node,
@@ -65,8 +120,13 @@ pub fn lower_ir(
 
         IR::Filter { input, predicate } if is_streamable(predicate.node(), expr_arena) => {
             let predicate = predicate.clone();
+            let input_schema = ir_arena.get(*input).schema(ir_arena).into_owned();
             let input = lower_ir(*input, ir_arena, expr_arena, phys_sm)?;
-            Ok(phys_sm.insert(PhysNode::Filter { input, predicate }))
+            Ok(phys_sm.insert(PhysNode::Filter {
+                input,
+                predicate,
+                input_schema,
+            }))
         },
 
         IR::DataFrameScan {
@@ -82,11 +142,13 @@ pub fn lower_ir(
             }
 
             let mut phys_node = phys_sm.insert(PhysNode::InMemorySource { df: df.clone() });
+            let mut schema = Arc::new(df.schema());
 
-            if let Some(schema) = output_schema {
+            if let Some(output_schema) = output_schema {
+                schema = output_schema.clone();
                 phys_node = phys_sm.insert(PhysNode::SimpleProjection {
                     input: phys_node,
-                    schema: schema.clone(),
+                    schema: output_schema.clone(),
                 })
             }
 
@@ -94,6 +156,7 @@ pub fn lower_ir(
                 phys_node = phys_sm.insert(PhysNode::Filter {
                     input: phys_node,
                     predicate,
+                    input_schema: schema,
                 })
             }
 
@@ -147,6 +210,61 @@ pub fn lower_ir(
             Ok(phys_sm.insert(phys_node))
         },
 
+        #[cfg(any(feature = "asof_join", feature = "iejoin"))]
+        IR::Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options,
+            ..
+        } if is_broadcast_join(&options.args.how)
+            && options.args.slice.is_none()
+            && left_on
+                .iter()
+                .chain(right_on)
+                .all(|e| is_streamable(e.node(), expr_arena)) =>
+        {
+            let (input_left, input_right) = (*input_left, *input_right);
+            let mut left_on = left_on.clone();
+            let mut right_on = right_on.clone();
+            let mut args = options.args.clone();
+            let left_schema = ir_arena.get(input_left).schema(ir_arena).into_owned();
+            let right_schema = ir_arena.get(input_right).schema(ir_arena).into_owned();
+            match &mut args.how {
+                #[cfg(feature = "asof_join")]
+                JoinType::AsOf(asof_options) => {
+                    let key_dtype = expr_arena.get(left_on[0].node()).get_type(
+                        &left_schema,
+                        Context::Default,
+                        expr_arena,
+                    )?;
+                    polars_mem_engine::resolve_asof_tolerance(asof_options, &key_dtype)?;
+                },
+                // The in-memory engine casts the keys of every join, here it is done once.
+                #[cfg(feature = "iejoin")]
+                JoinType::IEJoin(_) => cast_to_supertypes(
+                    &mut left_on,
+                    &mut right_on,
+                    &left_schema,
+                    &right_schema,
+                    expr_arena,
+                )?,
+                _ => unreachable!(),
+            }
+
+            let input_left = lower_ir(input_left, ir_arena, expr_arena, phys_sm)?;
+            let input_right = lower_ir(input_right, ir_arena, expr_arena, phys_sm)?;
+            Ok(phys_sm.insert(PhysNode::BroadcastJoin {
+                input_left,
+                input_right,
+                right_schema,
+                left_on,
+                right_on,
+                args,
+            }))
+        },
+
         _ => todo!(),
     }
 }
@@ -4,6 +4,8 @@ use polars_core::frame::DataFrame;
 use polars_core::prelude::SortMultipleOptions;
 use polars_core::schema::Schema;
 use polars_error::PolarsResult;
+#[cfg(any(feature = "asof_join", feature = "iejoin"))]
+use polars_ops::frame::JoinArgs;
 use polars_plan::plans::DataFrameUdf;
 use polars_plan::prelude::expr_ir::ExprIR;
 use polars_utils::arena::Node;
@@ -39,6 +41,7 @@ pub enum PhysNode {
     Filter {
         input: PhysNodeKey,
         predicate: ExprIR,
+        input_schema: Arc<Schema>,
     },
 
     SimpleProjection {
@@ -69,4 +72,17 @@ pub enum PhysNode {
         slice: Option<(i64, usize)>,
         sort_options: SortMultipleOptions,
     },
+
+    /// A join that materializes the right side and streams the left side, for asof and
+    /// inequality joins.
+    #[cfg(any(feature = "asof_join", feature = "iejoin"))]
+    BroadcastJoin {
+        input_left: PhysNodeKey,
+        input_right: PhysNodeKey,
+        right_schema: Arc<Schema>,
+        left_on: Vec<ExprIR>,
+        right_on: Vec<ExprIR>,
+        /// The `tolerance_str` of asof joins is already resolved to a `tolerance`.
+        args: JoinArgs,
+    },
 }
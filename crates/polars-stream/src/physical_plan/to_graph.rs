@@ -2,10 +2,15 @@ use std::sync::Arc;
 
 use parking_lot::Mutex;
 use polars_error::PolarsResult;
-use polars_expr::planner::{create_physical_expr, get_expr_depth_limit, ExpressionConversionState};
+use polars_expr::planner::{
+    create_physical_expr, create_physical_filter_expr, get_expr_depth_limit,
+    ExpressionConversionState,
+};
 use polars_expr::state::ExecutionState;
 use polars_mem_engine::create_physical_plan;
-use polars_plan::plans::{AExpr, Context, IR};
+use polars_plan::plans::{all_parallel_safe, is_parallel_safe, AExpr, Context, IR};
+#[cfg(any(feature = "asof_join", feature = "iejoin"))]
+use polars_plan::prelude::expr_ir::ExprIR;
 use polars_utils::arena::Arena;
 use recursive::recursive;
 use slotmap::{SecondaryMap, SlotMap};
@@ -61,17 +66,21 @@ fn to_graph_rec<'a>(
             [],
         ),
 
-        Filter { predicate, input } => {
-            let phys_predicate_expr = create_physical_expr(
+        Filter {
+            predicate,
+            input,
+            input_schema,
+        } => {
+            let phys_predicate_expr = create_physical_filter_expr(
                 predicate,
-                Context::Default,
                 ctx.expr_arena,
-                None,
+                Some(input_schema),
                 &mut ctx.expr_conversion_state,
             )?;
+            let sequential = !is_parallel_safe(predicate.node(), ctx.expr_arena);
             let input_key = to_graph_rec(*input, ctx)?;
             ctx.graph.add_node(
-                nodes::filter::FilterNode::new(phys_predicate_expr),
+                nodes::filter::FilterNode::new(phys_predicate_expr, sequential),
                 [input_key],
             )
         },
@@ -94,12 +103,14 @@ fn to_graph_rec<'a>(
                     )
                 })
                 .collect::<PolarsResult<_>>()?;
+            let sequential = !all_parallel_safe(selectors, ctx.expr_arena);
             let input_key = to_graph_rec(*input, ctx)?;
             ctx.graph.add_node(
                 nodes::select::SelectNode::new(
                     phys_selectors,
                     output_schema.clone(),
                     *extend_original,
+                    sequential,
                 ),
                 [input_key],
             )
@@ -174,6 +185,44 @@ fn to_graph_rec<'a>(
                 [input_key],
             )
         },
+
+        #[cfg(any(feature = "asof_join", feature = "iejoin"))]
+        BroadcastJoin {
+            input_left,
+            input_right,
+            right_schema,
+            left_on,
+            right_on,
+            args,
+        } => {
+            let mut create_exprs = |exprs: &[ExprIR]| {
+                exprs
+                    .iter()
+                    .map(|e| {
+                        create_physical_expr(
+                            e,
+                            Context::Default,
+                            ctx.expr_arena,
+                            None,
+                            &mut ctx.expr_conversion_state,
+                        )
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()
+            };
+            let phys_left_on = create_exprs(left_on)?;
+            let phys_right_on = create_exprs(right_on)?;
+            let left_key = to_graph_rec(*input_left, ctx)?;
+            let right_key = to_graph_rec(*input_right, ctx)?;
+            ctx.graph.add_node(
+                nodes::broadcast_join::BroadcastJoinNode::new(
+                    right_schema.clone(),
+                    phys_left_on,
+                    phys_right_on,
+                    args.clone(),
+                ),
+                [left_key, right_key],
+            )
+        },
     };
 
     ctx.phys_to_graph.insert(phys_node_key, graph_key);
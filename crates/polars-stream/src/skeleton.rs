@@ -22,6 +22,25 @@ pub fn run_query(
     let root = crate::physical_plan::lower_ir(node, &mut ir_arena, &mut expr_arena, &mut phys_sm)?;
     let (mut graph, phys_to_graph) =
         crate::physical_plan::physical_plan_to_graph(&phys_sm, &expr_arena)?;
-    let mut results = crate::execute::execute_graph(&mut graph)?;
+    let mut results = crate::execute::execute_graph(&mut graph, None)?;
     Ok(results.remove(phys_to_graph[root]).unwrap())
 }
+
+/// Runs the query like [`run_query`], additionally returning a DataFrame with per-node timings (in
+/// microseconds since the start of execution) and the number of rows and bytes each node received
+/// and produced.
+pub fn profile_query(
+    node: Node,
+    mut ir_arena: Arena<IR>,
+    mut expr_arena: Arena<AExpr>,
+) -> PolarsResult<(DataFrame, DataFrame)> {
+    let mut phys_sm = SlotMap::with_capacity_and_key(ir_arena.len());
+
+    let root = crate::physical_plan::lower_ir(node, &mut ir_arena, &mut expr_arena, &mut phys_sm)?;
+    let (mut graph, phys_to_graph) =
+        crate::physical_plan::physical_plan_to_graph(&phys_sm, &expr_arena)?;
+    let mut metrics = crate::metrics::GraphMetrics::new();
+    let mut results = crate::execute::execute_graph(&mut graph, Some(&mut metrics))?;
+    let profile = metrics.finish(&graph)?;
+    Ok((results.remove(phys_to_graph[root]).unwrap(), profile))
+}
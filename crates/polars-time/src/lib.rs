@@ -36,6 +36,6 @@ pub use offset_by::*;
 pub use round::*;
 pub use truncate::*;
 pub use upsample::*;
-pub use windows::duration::Duration;
+pub use windows::duration::{register_frequency, Anchor, Duration};
 pub use windows::group_by::ClosedWindow;
 pub use windows::window::Window;
@@ -7,15 +7,25 @@ use polars_utils::cache::FastFixedCache;
 use crate::prelude::*;
 
 pub trait PolarsTruncate {
-    fn truncate(&self, tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self>
+    /// Truncate to the buckets of `every`, shifted by `offset` in local time.
+    fn truncate(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        offset: Duration,
+    ) -> PolarsResult<Self>
     where
         Self: Sized;
 }
 
 impl PolarsTruncate for DatetimeChunked {
-    fn truncate(&self, tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self> {
+    fn truncate(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        offset: Duration,
+    ) -> PolarsResult<Self> {
         let time_zone = self.time_zone();
-        let offset = Duration::new(0);
 
         // Let's check if we can use a fastpath...
         if every.len() == 1 {
@@ -26,32 +36,37 @@ impl PolarsTruncate for DatetimeChunked {
                 }
                 if (time_zone.is_none() || time_zone.as_deref() == Some("UTC"))
                     && (every_parsed.months() == 0 && every_parsed.weeks() == 0)
+                    && offset.months() == 0
                 {
                     // ... yes we can! Weeks, months, and time zones require extra logic.
                     // But in this simple case, it's just simple integer arithmetic.
-                    let every = match self.time_unit() {
-                        TimeUnit::Milliseconds => every_parsed.duration_ms(),
-                        TimeUnit::Microseconds => every_parsed.duration_us(),
-                        TimeUnit::Nanoseconds => every_parsed.duration_ns(),
+                    let sign = if offset.negative() { -1 } else { 1 };
+                    let (every, offset) = match self.time_unit() {
+                        TimeUnit::Milliseconds => {
+                            (every_parsed.duration_ms(), offset.duration_ms())
+                        },
+                        TimeUnit::Microseconds => {
+                            (every_parsed.duration_us(), offset.duration_us())
+                        },
+                        TimeUnit::Nanoseconds => (every_parsed.duration_ns(), offset.duration_ns()),
                     };
+                    let offset = sign * offset;
                     return Ok(self
                         .apply_values(|t| {
+                            let t = t - offset;
                             let remainder = t % every;
-                            t - remainder + every * (remainder < 0) as i64
+                            t - remainder + every * (remainder < 0) as i64 + offset
                         })
                         .into_datetime(self.time_unit(), time_zone.clone()));
                 } else {
                     let w = Window::new(every_parsed, every_parsed, offset);
                     let out = match self.time_unit() {
-                        TimeUnit::Milliseconds => {
-                            self.try_apply_nonnull_values_generic(|t| w.truncate_ms(t, tz))
-                        },
-                        TimeUnit::Microseconds => {
-                            self.try_apply_nonnull_values_generic(|t| w.truncate_us(t, tz))
-                        },
-                        TimeUnit::Nanoseconds => {
-                            self.try_apply_nonnull_values_generic(|t| w.truncate_ns(t, tz))
-                        },
+                        TimeUnit::Milliseconds => self
+                            .try_apply_nonnull_values_generic(|t| w.truncate_with_offset_ms(t, tz)),
+                        TimeUnit::Microseconds => self
+                            .try_apply_nonnull_values_generic(|t| w.truncate_with_offset_us(t, tz)),
+                        TimeUnit::Nanoseconds => self
+                            .try_apply_nonnull_values_generic(|t| w.truncate_with_offset_ns(t, tz)),
                     };
                     return Ok(out?.into_datetime(self.time_unit(), self.time_zone().clone()));
                 }
@@ -65,9 +80,9 @@ impl PolarsTruncate for DatetimeChunked {
         let mut duration_cache = FastFixedCache::new((every.len() as f64).sqrt() as usize);
 
         let func = match self.time_unit() {
-            TimeUnit::Nanoseconds => Window::truncate_ns,
-            TimeUnit::Microseconds => Window::truncate_us,
-            TimeUnit::Milliseconds => Window::truncate_ms,
+            TimeUnit::Nanoseconds => Window::truncate_with_offset_ns,
+            TimeUnit::Microseconds => Window::truncate_with_offset_us,
+            TimeUnit::Milliseconds => Window::truncate_with_offset_ms,
         };
 
         let out = broadcast_try_binary_elementwise(self, every, |opt_timestamp, opt_every| match (
@@ -92,8 +107,12 @@ impl PolarsTruncate for DatetimeChunked {
 }
 
 impl PolarsTruncate for DateChunked {
-    fn truncate(&self, _tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self> {
-        let offset = Duration::new(0);
+    fn truncate(
+        &self,
+        _tz: Option<&Tz>,
+        every: &StringChunked,
+        offset: Duration,
+    ) -> PolarsResult<Self> {
         let out = match every.len() {
             1 => {
                 if let Some(every) = every.get(0) {
@@ -103,8 +122,10 @@ impl PolarsTruncate for DateChunked {
                     }
                     let w = Window::new(every, every, offset);
                     self.try_apply_nonnull_values_generic(|t| {
-                        Ok((w.truncate_ms(MILLISECONDS_IN_DAY * t as i64, None)?
-                            / MILLISECONDS_IN_DAY) as i32)
+                        Ok(
+                            (w.truncate_with_offset_ms(MILLISECONDS_IN_DAY * t as i64, None)?
+                                / MILLISECONDS_IN_DAY) as i32,
+                        )
                     })
                 } else {
                     Ok(Int32Chunked::full_null(self.name(), self.len()))
@@ -124,7 +145,7 @@ impl PolarsTruncate for DateChunked {
 
                         let w = Window::new(every, every, offset);
                         Ok(Some(
-                            (w.truncate_ms(MILLISECONDS_IN_DAY * t as i64, None)?
+                            (w.truncate_with_offset_ms(MILLISECONDS_IN_DAY * t as i64, None)?
                                 / MILLISECONDS_IN_DAY) as i32,
                         ))
                     },
@@ -1,6 +1,7 @@
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::ops::{Mul, Neg};
+use std::sync::RwLock;
 
 #[cfg(feature = "timezones")]
 use arrow::legacy::kernels::{Ambiguous, NonExistent};
@@ -9,12 +10,13 @@ use arrow::temporal_conversions::{
     timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime, MILLISECONDS,
     NANOSECONDS,
 };
-use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+use once_cell::sync::Lazy;
 use polars_core::datatypes::DataType;
 use polars_core::export::arrow::temporal_conversions::MICROSECONDS;
 use polars_core::prelude::{
     datetime_to_timestamp_ms, datetime_to_timestamp_ns, datetime_to_timestamp_us, polars_bail,
-    PolarsResult,
+    PlHashMap, PolarsResult,
 };
 use polars_error::polars_ensure;
 #[cfg(feature = "serde")]
@@ -27,6 +29,24 @@ use super::calendar::{
 use crate::utils::{localize_datetime_opt, try_localize_datetime, unlocalize_datetime};
 use crate::windows::calendar::{is_leap_year, DAYS_PER_MONTH};
 
+const WEEKDAYS: [&str; 7] = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+
+/// Frequencies registered with [`register_frequency`].
+static CUSTOM_FREQUENCIES: Lazy<RwLock<PlHashMap<String, Duration>>> = Lazy::new(Default::default);
+
+/// Where the boundaries of a calendar [`Duration`] are anchored.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Anchor {
+    /// Weeks start on Monday, months on their first day.
+    #[default]
+    Start,
+    /// Weeks start on the given weekday, counted from Monday (0) to Sunday (6).
+    Weekday(u8),
+    /// Months end on their last business day (Monday to Friday).
+    BusinessMonthEnd,
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Duration {
@@ -42,6 +62,9 @@ pub struct Duration {
     pub(crate) negative: bool,
     // indicates if an integer string was passed. e.g. "2i"
     pub parsed_int: bool,
+    // the calendar anchor of the boundaries, e.g. "1bme" or "1w_sun"
+    #[cfg_attr(feature = "serde", serde(default))]
+    anchor: Anchor,
 }
 
 impl PartialOrd<Self> for Duration {
@@ -67,6 +90,7 @@ impl Neg for Duration {
             nsecs: self.nsecs,
             negative: !self.negative,
             parsed_int: self.parsed_int,
+            anchor: self.anchor,
         }
     }
 }
@@ -80,10 +104,16 @@ impl Display for Duration {
             write!(f, "-")?
         }
         if self.months > 0 {
-            write!(f, "{}m", self.months)?
+            match self.anchor {
+                Anchor::BusinessMonthEnd => write!(f, "{}bme", self.months)?,
+                _ => write!(f, "{}m", self.months)?,
+            }
         }
         if self.weeks > 0 {
-            write!(f, "{}w", self.weeks)?
+            match self.anchor {
+                Anchor::Weekday(wd) => write!(f, "{}w_{}", self.weeks, WEEKDAYS[wd as usize])?,
+                _ => write!(f, "{}w", self.weeks)?,
+            }
         }
         if self.days > 0 {
             write!(f, "{}d", self.days)?
@@ -115,6 +145,7 @@ impl Duration {
             nsecs: fixed_slots.abs(),
             negative: fixed_slots < 0,
             parsed_int: true,
+            anchor: Anchor::Start,
         }
     }
 
@@ -150,6 +181,14 @@ impl Duration {
     /// Similarly for "calendar week", "calendar month", "calendar quarter",
     /// and "calendar year".
     ///
+    /// The following anchored frequencies can't be combined with other units:
+    ///
+    /// * `bme`: business month end, the last weekday of the month
+    /// * `qs`: quarter start (the same as `q`)
+    /// * `w_mon`, ..., `w_sun`: calendar week starting on the given weekday
+    ///
+    /// Frequencies registered with [`register_frequency`] are available as units too.
+    ///
     /// # Panics
     /// If the given str is invalid for any reason.
     pub fn parse(duration: &str) -> Self {
@@ -208,6 +247,8 @@ impl Duration {
         // reserve capacity for the longest valid unit ("microseconds")
         let mut unit = String::with_capacity(12);
         let mut parsed_int = false;
+        let mut anchor = Anchor::Start;
+        let mut n_units = 0;
 
         while let Some((i, mut ch)) = iter.next() {
             if !ch.is_ascii_digit() {
@@ -221,6 +262,7 @@ impl Duration {
                 loop {
                     match ch {
                         c if c.is_ascii_alphabetic() => unit.push(c),
+                        '_' if !as_interval => unit.push('_'),
                         ' ' | ',' if as_interval => {},
                         _ => break,
                     }
@@ -255,6 +297,15 @@ impl Duration {
                         nsecs += n;
                         parsed_int = true;
                     },
+                    "bme" if !as_interval => {
+                        months += n;
+                        anchor = Anchor::BusinessMonthEnd;
+                    },
+                    "qs" if !as_interval => months += n * 3,
+                    u if !as_interval && weekday_anchor(u).is_some() => {
+                        weeks += n;
+                        anchor = weekday_anchor(u).unwrap();
+                    },
                     _ if as_interval => match &*unit {
                         // interval-only (verbose/sql) matches
                         "nanosecond" | "nanoseconds" => nsecs += n,
@@ -273,10 +324,26 @@ impl Duration {
                             panic!("unit: '{unit}' not supported; available units include: {} (and their plurals)", valid_units)
                         },
                     },
-                    _ => {
-                        panic!("unit: '{unit}' not supported; available units are: 'y', 'mo', 'q', 'w', 'd', 'h', 'm', 's', 'ms', 'us', 'ns'")
+                    _ => match CUSTOM_FREQUENCIES.read().unwrap().get(&unit) {
+                        Some(custom) => {
+                            months += n * custom.months;
+                            weeks += n * custom.weeks;
+                            days += n * custom.days;
+                            nsecs += n * custom.nsecs;
+                            anchor = custom.anchor;
+                        },
+                        None => {
+                            panic!("unit: '{unit}' not supported; available units are: 'y', 'mo', 'q', 'w', 'd', 'h', 'm', 's', 'ms', 'us', 'ns', 'bme', 'qs', 'w_mon', ..., 'w_sun' and registered frequencies")
+                        },
                     },
                 }
+                n_units += 1;
+                if anchor != Anchor::Start && n_units > 1 {
+                    panic!(
+                        "anchored frequencies cannot be combined with other units in the {} string '{}'",
+                        parse_type, s
+                    )
+                }
                 unit.clear();
             }
         }
@@ -287,6 +354,7 @@ impl Duration {
             months: months.abs(),
             negative,
             parsed_int,
+            anchor,
         }
     }
 
@@ -353,6 +421,7 @@ impl Duration {
             nsecs,
             negative,
             parsed_int: false,
+            anchor: Anchor::Start,
         }
     }
 
@@ -366,6 +435,7 @@ impl Duration {
             nsecs: 0,
             negative,
             parsed_int: false,
+            anchor: Anchor::Start,
         }
     }
 
@@ -379,6 +449,7 @@ impl Duration {
             nsecs: 0,
             negative,
             parsed_int: false,
+            anchor: Anchor::Start,
         }
     }
 
@@ -392,6 +463,7 @@ impl Duration {
             nsecs: 0,
             negative,
             parsed_int: false,
+            anchor: Anchor::Start,
         }
     }
 
@@ -452,6 +524,11 @@ impl Duration {
         self.negative
     }
 
+    /// Returns where the boundaries of the duration are anchored.
+    pub fn anchor(&self) -> Anchor {
+        self.anchor
+    }
+
     /// Estimated duration of the window duration. Not a very good one if not a constant duration.
     #[doc(hidden)]
     pub const fn duration_ns(&self) -> i64 {
@@ -652,8 +729,13 @@ impl Duration {
         //   t - (t % (7 * self.weeks * daily_duration))
         // then the timestamp would get truncated to the previous Thursday,
         // because 1970-01-01 (timestamp 0) is a Thursday.
-        // So, we adjust by 4 days to get to Monday.
-        let mut remainder = (t - 4 * daily_duration) % (7 * self.weeks * daily_duration);
+        // So, we adjust by 4 days to get to Monday, and further to the anchored weekday.
+        let weekday = match self.anchor {
+            Anchor::Weekday(weekday) => weekday as i64,
+            _ => 0,
+        };
+        let shift = (4 + weekday) % 7;
+        let mut remainder = (t - shift * daily_duration) % (7 * self.weeks * daily_duration);
         if remainder < 0 {
             remainder += 7 * self.weeks * daily_duration
         }
@@ -758,6 +840,47 @@ impl Duration {
         }
     }
 
+    fn truncate_business_month_end<G, J>(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        timestamp_to_datetime: G,
+        datetime_to_timestamp: J,
+    ) -> PolarsResult<i64>
+    where
+        G: Fn(i64) -> NaiveDateTime,
+        J: Fn(NaiveDateTime) -> i64,
+    {
+        let original_dt_utc = timestamp_to_datetime(t);
+        let original_dt_local = match tz {
+            #[cfg(feature = "timezones")]
+            // for UTC, use fastpath below (same as naive)
+            Some(tz) if tz != &chrono_tz::UTC => unlocalize_datetime(original_dt_utc, tz),
+            _ => original_dt_utc,
+        };
+
+        // Find the latest business month end at or before the date...
+        let date = original_dt_local.date();
+        let mut total = date.year() as i64 * 12 + date.month0() as i64;
+        if date < business_month_end(total) {
+            total -= 1;
+        }
+        // ...and align it to a multiple of the number of months.
+        total -= total.rem_euclid(self.months);
+        let result_dt_local = business_month_end(total).and_hms_opt(0, 0, 0).unwrap();
+
+        match tz {
+            #[cfg(feature = "timezones")]
+            // for UTC, use fastpath below (same as naive)
+            Some(tz) if tz != &chrono_tz::UTC => {
+                let result_dt_utc =
+                    self.localize_result(original_dt_local, original_dt_utc, result_dt_local, tz)?;
+                Ok(datetime_to_timestamp(result_dt_utc))
+            },
+            _ => Ok(datetime_to_timestamp(result_dt_local)),
+        }
+    }
+
     #[inline]
     pub fn truncate_impl<F, G, J>(
         &self,
@@ -807,6 +930,9 @@ impl Duration {
                     duration,
                 )
             },
+            // truncate by business month ends
+            (_, 0, 0, 0) if self.anchor == Anchor::BusinessMonthEnd => self
+                .truncate_business_month_end(t, tz, timestamp_to_datetime, datetime_to_timestamp),
             // truncate by months
             (_, 0, 0, 0) => {
                 let duration = nsecs_to_unit(NS_DAY);
@@ -885,7 +1011,14 @@ impl Duration {
                 },
                 _ => timestamp_to_datetime(t),
             };
-            let dt = Self::add_month(ts, d.months, d.negative);
+            let dt = match d.anchor {
+                Anchor::BusinessMonthEnd => {
+                    let months = if d.negative { -d.months } else { d.months };
+                    let total = ts.year() as i64 * 12 + ts.month0() as i64 + months;
+                    business_month_end(total).and_time(ts.time())
+                },
+                _ => Self::add_month(ts, d.months, d.negative),
+            };
             new_t = match tz {
                 #[cfg(feature = "timezones")]
                 // for UTC, use fastpath below (same as naive)
@@ -1002,6 +1135,58 @@ impl Mul<i64> for Duration {
     }
 }
 
+fn weekday_anchor(unit: &str) -> Option<Anchor> {
+    let weekday = WEEKDAYS
+        .iter()
+        .position(|wd| unit.strip_prefix("w_") == Some(wd))?;
+    // Weeks start on Monday by default.
+    Some(match weekday {
+        0 => Anchor::Start,
+        _ => Anchor::Weekday(weekday as u8),
+    })
+}
+
+/// The last business day of the month, given as the number of months since January of year 0.
+fn business_month_end(total_months: i64) -> NaiveDate {
+    let year = total_months.div_euclid(12) as i32;
+    let month = total_months.rem_euclid(12) as u32 + 1;
+    let day = DAYS_PER_MONTH[is_leap_year(year) as usize][(month - 1) as usize] as u32;
+    let last_day = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+    let weekend_days = match last_day.weekday() {
+        Weekday::Sat => 1,
+        Weekday::Sun => 2,
+        _ => 0,
+    };
+    last_day - Days::new(weekend_days)
+}
+
+/// Register a custom frequency, which can then be used as a unit in duration strings.
+///
+/// For example, after registering `"fortnight"` as `"2w"`, `"3fortnight"` parses as six weeks.
+pub fn register_frequency(name: &str, every: &str) -> PolarsResult<()> {
+    polars_ensure!(
+        !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase() || c == '_'),
+        InvalidOperation: "frequency name must consist of lowercase letters and underscores, got '{}'", name
+    );
+    let builtin = [
+        "ns", "us", "ms", "s", "m", "h", "d", "w", "mo", "q", "y", "i", "bme", "qs",
+    ];
+    polars_ensure!(
+        !builtin.contains(&name) && weekday_anchor(name).is_none(),
+        InvalidOperation: "cannot register frequency '{}': it is a built-in unit", name
+    );
+    let every = Duration::parse(every);
+    polars_ensure!(
+        !every.negative && !every.is_zero() && !every.parsed_int,
+        InvalidOperation: "frequency '{}' must be a positive temporal duration, got {}", name, every
+    );
+    CUSTOM_FREQUENCIES
+        .write()
+        .unwrap()
+        .insert(name.to_string(), every);
+    Ok(())
+}
+
 fn new_datetime(
     year: i32,
     month: u32,
@@ -1097,6 +1282,36 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_anchored() {
+        let ts = |y, m, d| datetime_to_timestamp_ns(new_datetime(y, m, d, 12, 0, 0, 0).unwrap());
+        let date = |t| timestamp_ns_to_datetime(t).date();
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // 2024-03-31 is a Sunday, so the last business day of March is Friday the 29th.
+        let bme = Duration::parse("1bme");
+        assert_eq!(format!("{bme}"), "1bme");
+        let t = bme.truncate_ns(ts(2024, 3, 30), None).unwrap();
+        assert_eq!(date(t), ymd(2024, 3, 29));
+        let t = bme.truncate_ns(ts(2024, 3, 28), None).unwrap();
+        assert_eq!(date(t), ymd(2024, 2, 29));
+        assert_eq!(date(bme.add_ns(t, None).unwrap()), ymd(2024, 3, 29));
+        assert_eq!(date((-bme).add_ns(t, None).unwrap()), ymd(2024, 1, 31));
+
+        // 2024-03-28 is a Thursday.
+        let week = Duration::parse("1w_sun");
+        assert_eq!(format!("{week}"), "1w_sun");
+        let t = week.truncate_ns(ts(2024, 3, 28), None).unwrap();
+        assert_eq!(date(t), ymd(2024, 3, 24));
+        assert_eq!(Duration::parse("1w_mon"), Duration::parse("1w"));
+        assert_eq!(Duration::parse("2qs"), Duration::parse("6mo"));
+
+        register_frequency("fortnight", "2w").unwrap();
+        assert_eq!(Duration::parse("3fortnight"), Duration::parse("6w"));
+        assert!(register_frequency("bme", "1d").is_err());
+        assert!(register_frequency("Fortnight", "2w").is_err());
+    }
+
     #[test]
     fn test_display() {
         let duration = Duration::parse("1h");
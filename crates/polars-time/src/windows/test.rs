@@ -926,3 +926,38 @@ fn test_group_by_windows_offsets_3776() {
     );
     assert_eq!(groups, [[0, 1], [1, 1], [2, 1]]);
 }
+
+#[test]
+fn test_truncate_with_offset() {
+    let ts = |d: u32, h: u32, m: u32| {
+        NaiveDate::from_ymd_opt(2024, 3, d)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_millis()
+    };
+    let every = Duration::parse("15m");
+    let w = Window::new(every, every, Duration::parse("5m"));
+    assert_eq!(
+        w.truncate_with_offset_ms(ts(31, 0, 10), None).unwrap(),
+        ts(31, 0, 5)
+    );
+    assert_eq!(
+        w.truncate_with_offset_ms(ts(31, 0, 4), None).unwrap(),
+        ts(30, 23, 50)
+    );
+    assert_eq!(w.round_ms(ts(31, 0, 14), None).unwrap(), ts(31, 0, 20));
+
+    // Weeks starting on Sunday.
+    let every = Duration::parse("1w");
+    let w = Window::new(every, every, Duration::parse("6d"));
+    assert_eq!(
+        w.truncate_with_offset_ms(ts(28, 12, 0), None).unwrap(),
+        ts(24, 0, 0)
+    );
+    assert_eq!(
+        w.truncate_with_offset_ms(ts(31, 0, 0), None).unwrap(),
+        ts(31, 0, 0)
+    );
+}
@@ -75,24 +75,55 @@ impl Window {
         self.every.truncate_ms(t, tz)
     }
 
-    /// Round the given ns timestamp by the window boundary.
+    /// Apply `truncate` to `t` with the window boundaries shifted by the offset: the offset is
+    /// subtracted before and added back after, in local time.
+    fn with_offset(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        add: fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>,
+        truncate: impl Fn(i64) -> PolarsResult<i64>,
+    ) -> PolarsResult<i64> {
+        if self.offset.is_zero() {
+            return truncate(t);
+        }
+        let t = add(&-self.offset, t, tz)?;
+        add(&self.offset, truncate(t)?, tz)
+    }
+
+    /// Truncate the given ns timestamp by the window boundary shifted by the offset.
+    pub fn truncate_with_offset_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.with_offset(t, tz, Duration::add_ns, |t| self.truncate_ns(t, tz))
+    }
+
+    /// Truncate the given us timestamp by the window boundary shifted by the offset.
+    pub fn truncate_with_offset_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.with_offset(t, tz, Duration::add_us, |t| self.truncate_us(t, tz))
+    }
+
+    /// Truncate the given ms timestamp by the window boundary shifted by the offset.
+    pub fn truncate_with_offset_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        self.with_offset(t, tz, Duration::add_ms, |t| self.truncate_ms(t, tz))
+    }
+
+    /// Round the given ns timestamp by the window boundary shifted by the offset.
     pub fn round_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
         let t = t + self.every.duration_ns() / 2_i64;
-        self.truncate_ns(t, tz)
+        self.truncate_with_offset_ns(t, tz)
     }
 
-    /// Round the given us timestamp by the window boundary.
+    /// Round the given us timestamp by the window boundary shifted by the offset.
     pub fn round_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
         let t = t + self.every.duration_ns()
             / (2 * timeunit_scale(ArrowTimeUnit::Nanosecond, ArrowTimeUnit::Microsecond) as i64);
-        self.truncate_us(t, tz)
+        self.truncate_with_offset_us(t, tz)
     }
 
-    /// Round the given ms timestamp by the window boundary.
+    /// Round the given ms timestamp by the window boundary shifted by the offset.
     pub fn round_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
         let t = t + self.every.duration_ns()
             / (2 * timeunit_scale(ArrowTimeUnit::Nanosecond, ArrowTimeUnit::Millisecond) as i64);
-        self.truncate_ms(t, tz)
+        self.truncate_with_offset_ms(t, tz)
     }
 
     /// returns the bounds for the earliest window bounds
@@ -0,0 +1,72 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use polars_error::{polars_ensure, PolarsResult};
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// A handle to cooperatively cancel a running query.
+///
+/// Clones share the same state, so a clone can be handed to another thread which calls
+/// [`CancellationToken::cancel`] while the query is running. Long-running operators check the
+/// token at chunk boundaries and return a [`PolarsError::Cancelled`] once it is set.
+///
+/// [`PolarsError::Cancelled`]: polars_error::PolarsError::Cancelled
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation. Cancelling is idempotent and cannot be undone.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Returns an error if cancellation was requested.
+    pub fn check(&self) -> PolarsResult<()> {
+        polars_ensure!(!self.is_cancelled(), Cancelled: "query was cancelled");
+        Ok(())
+    }
+
+    /// Run `f` with this token installed as the [current](CancellationToken::current) token of
+    /// this thread. Queries started inside `f` pick the token up.
+    pub fn run<T>(&self, f: impl FnOnce() -> T) -> T {
+        struct Restore(Option<CancellationToken>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CancellationToken::set_current(self.0.take());
+            }
+        }
+
+        let _restore = Restore(Self::set_current(Some(self.clone())));
+        f()
+    }
+
+    /// The token installed on this thread, see [`CancellationToken::run`].
+    pub fn current() -> Option<Self> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Returns an error if the token installed on this thread was cancelled. Kernels call this
+    /// between their phases, as they have no access to the state of the query.
+    pub fn check_current() -> PolarsResult<()> {
+        Self::current().map_or(Ok(()), |token| token.check())
+    }
+
+    /// Replace the token installed on this thread, returning the previous one. Prefer
+    /// [`CancellationToken::run`], which restores the previous token when done.
+    pub fn set_current(token: Option<Self>) -> Option<Self> {
+        CURRENT.with(|current| std::mem::replace(&mut *current.borrow_mut(), token))
+    }
+}
@@ -94,6 +94,15 @@ impl DirtyHash for i128 {
     }
 }
 
+impl DirtyHash for u128 {
+    fn dirty_hash(&self) -> u64 {
+        // Both halves are multiplied, so that the top bits depend on all bits.
+        let lo = (*self as u64).wrapping_mul(RANDOM_ODD);
+        let hi = ((*self >> 64) as u64).wrapping_mul(RANDOM_ODD);
+        lo.wrapping_add(hi.wrapping_mul(RANDOM_ODD))
+    }
+}
+
 impl<'a> DirtyHash for BytesHash<'a> {
     fn dirty_hash(&self) -> u64 {
         self.hash
@@ -0,0 +1,390 @@
+//! A compressed set of row indices, in the layout of a roaring bitmap.
+//!
+//! The indices are partitioned by their high bits into chunks of 2^16 indices. A chunk with
+//! few indices stores them as a sorted array of their low 16 bits, a dense chunk as a bitmap
+//! of 2^16 bits. Both take at most 8 KiB per chunk, and set operations are done a chunk at a
+//! time, on words of 64 indices for the bitmaps.
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+
+use crate::IdxSize;
+
+/// The number of words of a bitmap chunk.
+const BITMAP_WORDS: usize = (1 << 16) / 64;
+/// A chunk with more indices than this is stored as a bitmap.
+const MAX_ARRAY_LEN: usize = 4096;
+
+#[derive(Clone, PartialEq, Eq)]
+enum Chunk {
+    Array(Vec<u16>),
+    Bitmap { words: Box<[u64]>, len: usize },
+}
+
+impl Chunk {
+    fn empty_bitmap() -> Box<[u64]> {
+        vec![0u64; BITMAP_WORDS].into_boxed_slice()
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Chunk::Array(values) => values.len(),
+            Chunk::Bitmap { len, .. } => *len,
+        }
+    }
+
+    fn contains(&self, low: u16) -> bool {
+        match self {
+            Chunk::Array(values) => values.binary_search(&low).is_ok(),
+            Chunk::Bitmap { words, .. } => words[low as usize / 64] & (1 << (low % 64)) != 0,
+        }
+    }
+
+    fn insert(&mut self, low: u16) -> bool {
+        match self {
+            Chunk::Array(values) => match values.binary_search(&low) {
+                Ok(_) => false,
+                Err(pos) => {
+                    values.insert(pos, low);
+                    if values.len() > MAX_ARRAY_LEN {
+                        *self = Self::from_words(Self::to_words(values));
+                    }
+                    true
+                },
+            },
+            Chunk::Bitmap { words, len } => {
+                let word = &mut words[low as usize / 64];
+                let bit = 1 << (low % 64);
+                let inserted = *word & bit == 0;
+                *word |= bit;
+                *len += inserted as usize;
+                inserted
+            },
+        }
+    }
+
+    fn to_words(values: &[u16]) -> Box<[u64]> {
+        let mut words = Self::empty_bitmap();
+        for &v in values {
+            words[v as usize / 64] |= 1 << (v % 64);
+        }
+        words
+    }
+
+    /// The chunk of the set bits of `words`, as an array if it is sparse enough.
+    fn from_words(words: Box<[u64]>) -> Self {
+        let len = words.iter().map(|w| w.count_ones() as usize).sum();
+        if len > MAX_ARRAY_LEN {
+            return Chunk::Bitmap { words, len };
+        }
+        let mut values = Vec::with_capacity(len);
+        for (i, &word) in words.iter().enumerate() {
+            let mut word = word;
+            while word != 0 {
+                values.push((i * 64) as u16 + word.trailing_zeros() as u16);
+                word &= word - 1;
+            }
+        }
+        Chunk::Array(values)
+    }
+
+    fn words(&self) -> Box<[u64]> {
+        match self {
+            Chunk::Array(values) => Self::to_words(values),
+            Chunk::Bitmap { words, .. } => words.clone(),
+        }
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = u16> + '_> {
+        match self {
+            Chunk::Array(values) => Box::new(values.iter().copied()),
+            Chunk::Bitmap { words, .. } => {
+                Box::new(words.iter().enumerate().flat_map(|(i, &w)| {
+                    let mut word = w;
+                    std::iter::from_fn(move || {
+                        (word != 0).then(|| {
+                            let bit = word.trailing_zeros() as u16;
+                            word &= word - 1;
+                            (i * 64) as u16 + bit
+                        })
+                    })
+                }))
+            },
+        }
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Chunk::Array(a), Chunk::Array(b)) if a.len() + b.len() <= MAX_ARRAY_LEN => {
+                let mut out = Vec::with_capacity(a.len() + b.len());
+                let (mut i, mut j) = (0, 0);
+                while i < a.len() && j < b.len() {
+                    match a[i].cmp(&b[j]) {
+                        Ordering::Less => {
+                            out.push(a[i]);
+                            i += 1;
+                        },
+                        Ordering::Greater => {
+                            out.push(b[j]);
+                            j += 1;
+                        },
+                        Ordering::Equal => {
+                            out.push(a[i]);
+                            i += 1;
+                            j += 1;
+                        },
+                    }
+                }
+                out.extend_from_slice(&a[i..]);
+                out.extend_from_slice(&b[j..]);
+                Chunk::Array(out)
+            },
+            _ => self.combine_words(other, |a, b| a | b),
+        }
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Chunk::Array(a), b) | (b, Chunk::Array(a)) => {
+                Chunk::Array(a.iter().copied().filter(|&v| b.contains(v)).collect())
+            },
+            _ => self.combine_words(other, |a, b| a & b),
+        }
+    }
+
+    fn difference(&self, other: &Self) -> Self {
+        match self {
+            Chunk::Array(a) => {
+                Chunk::Array(a.iter().copied().filter(|&v| !other.contains(v)).collect())
+            },
+            _ => self.combine_words(other, |a, b| a & !b),
+        }
+    }
+
+    fn combine_words(&self, other: &Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        let mut words = self.words();
+        let other = other.words();
+        for (a, b) in words.iter_mut().zip(other.iter()) {
+            *a = op(*a, *b);
+        }
+        Self::from_words(words)
+    }
+
+    fn size_in_bytes(&self) -> usize {
+        match self {
+            Chunk::Array(values) => values.capacity() * std::mem::size_of::<u16>(),
+            Chunk::Bitmap { .. } => BITMAP_WORDS * std::mem::size_of::<u64>(),
+        }
+    }
+}
+
+/// A set of row indices, e.g. the selection of a filter or the rows a join matched.
+///
+/// The indices are stored compressed, see the [module documentation](self), so that the union,
+/// intersection and difference of sets of millions of indices are cheap. This makes it
+/// suitable to combine many selections, e.g. the masks of row-level security policies, before
+/// the rows are gathered once.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct IndexSet {
+    /// The high bits of the indices of every chunk, ascending.
+    keys: Vec<IdxSize>,
+    chunks: Vec<Chunk>,
+}
+
+#[inline]
+fn split(idx: IdxSize) -> (IdxSize, u16) {
+    (idx >> 16, idx as u16)
+}
+
+#[inline]
+fn join(key: IdxSize, low: u16) -> IdxSize {
+    (key << 16) | low as IdxSize
+}
+
+impl IndexSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of indices in the set.
+    pub fn len(&self) -> usize {
+        self.chunks.iter().map(Chunk::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    pub fn contains(&self, idx: IdxSize) -> bool {
+        let (key, low) = split(idx);
+        match self.keys.binary_search(&key) {
+            Ok(i) => self.chunks[i].contains(low),
+            Err(_) => false,
+        }
+    }
+
+    /// Add `idx` to the set. Returns whether it wasn't in the set yet.
+    pub fn insert(&mut self, idx: IdxSize) -> bool {
+        let (key, low) = split(idx);
+        // Indices are mostly inserted in ascending order.
+        let i = match self.keys.last() {
+            Some(&last) if last == key => self.keys.len() - 1,
+            _ => match self.keys.binary_search(&key) {
+                Ok(i) => i,
+                Err(i) => {
+                    self.keys.insert(i, key);
+                    self.chunks.insert(i, Chunk::Array(vec![]));
+                    i
+                },
+            },
+        };
+        self.chunks[i].insert(low)
+    }
+
+    /// The smallest index in the set.
+    pub fn min(&self) -> Option<IdxSize> {
+        let low = self.chunks.first()?.iter().next()?;
+        Some(join(self.keys[0], low))
+    }
+
+    /// The largest index in the set.
+    pub fn max(&self) -> Option<IdxSize> {
+        let low = self.chunks.last()?.iter().last()?;
+        Some(join(*self.keys.last()?, low))
+    }
+
+    /// Iterate over the indices in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = IdxSize> + '_ {
+        self.keys
+            .iter()
+            .zip(&self.chunks)
+            .flat_map(|(&key, chunk)| chunk.iter().map(move |low| join(key, low)))
+    }
+
+    /// The indices in ascending order.
+    pub fn to_vec(&self) -> Vec<IdxSize> {
+        let mut out = Vec::with_capacity(self.len());
+        out.extend(self.iter());
+        out
+    }
+
+    /// The number of bytes the chunks of the set take.
+    pub fn size_in_bytes(&self) -> usize {
+        self.keys.capacity() * std::mem::size_of::<IdxSize>()
+            + self.chunks.iter().map(Chunk::size_in_bytes).sum::<usize>()
+    }
+
+    /// The indices that are in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        self.merge(other, true, true, Chunk::union)
+    }
+
+    /// The indices that are in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.merge(other, false, false, Chunk::intersection)
+    }
+
+    /// The indices that are in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.merge(other, true, false, Chunk::difference)
+    }
+
+    /// Combine the chunks with the same key with `op`, and keep the chunks that only are in
+    /// `self` or `other` if `keep_self` or `keep_other` is set.
+    fn merge(
+        &self,
+        other: &Self,
+        keep_self: bool,
+        keep_other: bool,
+        op: impl Fn(&Chunk, &Chunk) -> Chunk,
+    ) -> Self {
+        let mut out = Self::new();
+        let mut push = |key, chunk: Chunk| {
+            if chunk.len() > 0 {
+                out.keys.push(key);
+                out.chunks.push(chunk);
+            }
+        };
+
+        let (mut i, mut j) = (0, 0);
+        while i < self.keys.len() && j < other.keys.len() {
+            match self.keys[i].cmp(&other.keys[j]) {
+                Ordering::Less => {
+                    if keep_self {
+                        push(self.keys[i], self.chunks[i].clone());
+                    }
+                    i += 1;
+                },
+                Ordering::Greater => {
+                    if keep_other {
+                        push(other.keys[j], other.chunks[j].clone());
+                    }
+                    j += 1;
+                },
+                Ordering::Equal => {
+                    push(self.keys[i], op(&self.chunks[i], &other.chunks[j]));
+                    i += 1;
+                    j += 1;
+                },
+            }
+        }
+        if keep_self {
+            for i in i..self.keys.len() {
+                push(self.keys[i], self.chunks[i].clone());
+            }
+        }
+        if keep_other {
+            for j in j..other.keys.len() {
+                push(other.keys[j], other.chunks[j].clone());
+            }
+        }
+        out
+    }
+}
+
+impl FromIterator<IdxSize> for IndexSet {
+    fn from_iter<I: IntoIterator<Item = IdxSize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for idx in iter {
+            set.insert(idx);
+        }
+        set
+    }
+}
+
+impl Debug for IndexSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_index_set_chunks() {
+        // Dense enough that the first chunk becomes a bitmap.
+        let evens: IndexSet = (0..20_000).step_by(2).chain([1 << 20]).collect();
+        assert_eq!(evens.len(), 10_001);
+        assert!(matches!(evens.chunks[0], Chunk::Bitmap { .. }));
+        assert!(evens.contains(4) && !evens.contains(5) && evens.contains(1 << 20));
+        assert_eq!(evens.min(), Some(0));
+        assert_eq!(evens.max(), Some(1 << 20));
+
+        let threes: IndexSet = (0..20_000).step_by(3).collect();
+        let both = evens.intersection(&threes);
+        assert_eq!(both.to_vec(), (0..20_000).step_by(6).collect::<Vec<_>>());
+        assert!(matches!(both.chunks[0], Chunk::Array(_)));
+
+        let either = evens.union(&threes);
+        assert_eq!(either.len(), evens.len() + threes.len() - both.len());
+        assert!(either.to_vec().windows(2).all(|w| w[0] < w[1]));
+
+        let diff = evens.difference(&threes);
+        assert_eq!(diff.len(), evens.len() - both.len());
+        assert!(diff
+            .iter()
+            .all(|i| i % 2 == 0 && (i % 3 != 0 || i == 1 << 20)));
+        assert!(evens.difference(&evens).is_empty());
+    }
+}
@@ -4,6 +4,7 @@ pub mod arena;
 pub mod atomic;
 pub mod binary_search;
 pub mod cache;
+pub mod cancel;
 pub mod cell;
 pub mod clmul;
 pub mod contention_pool;
@@ -13,6 +14,7 @@ pub mod floor_divmod;
 pub mod functions;
 pub mod hashing;
 pub mod idx_vec;
+pub mod index_set;
 pub mod mem;
 pub mod min_max;
 pub mod priority;
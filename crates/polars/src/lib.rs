@@ -183,13 +183,20 @@
 //! * `sql` - Pass SQL queries to polars.
 //! * `streaming` - Be able to process datasets that are larger than RAM.
 //! * `random` - Generate arrays with randomly sampled values
+//! * `arbitrary` - Generate random [`DataFrame`]s for property-based tests with [proptest](https://docs.rs/proptest/)
 //! * `ndarray`- Convert from [`DataFrame`] to [ndarray](https://docs.rs/ndarray/)
+//! * `derive` - `#[derive(IntoDataFrame)]` to create a [`DataFrame`] from structs, enable `uuid` for
+//!    [Uuid](https://docs.rs/uuid/) fields.
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
 //! * `timezones` - Activate timezone support.
 //! * `strings` - Extra string utilities for [`StringChunked`] //!     - `string_pad` - `zfill`, `ljust`, `rjust`
 //!     - `string_to_integer` - `parse_int`
+//!     - `string_similarity` - Edit distances and similarities between strings
+//!     - `collation` - Locale-aware sorting, ranking and comparisons of strings
 //! * `object` - Support for generic ChunkedArrays called [`ObjectChunked<T>`] (generic over `T`).
 //!              These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
+//! * `mem_stats` - Count allocations with [`TrackingAllocator`](polars_core::mem::TrackingAllocator),
+//!                 e.g. to get the peak memory of the nodes of a profiled query.
 //! * Performance related:
 //!     - `nightly` - Several nightly only features such as SIMD and specialization.
 //!     - `performant` - more fast paths, slower compile times.
@@ -198,6 +205,7 @@
 //!                  Polars will be a bit slower with this feature activated as many data structures
 //!                  are less cache efficient.
 //!     - `cse` - Activate common subplan elimination optimization
+//!     - `result_cache` - Reuse the results of queries across `collect` calls
 //! * IO related:
 //!     - `serde` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!                 Can be used for JSON and more serde supported serialization formats.
@@ -206,6 +214,14 @@
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `orc` - Read Apache ORC format
+//!     - `excel` - Read Excel (xlsx) workbooks
+//!     - `clipboard` - Read and write CSV text on the clipboard of the desktop
+//!     - `flight` - Read from Arrow Flight and Flight SQL servers
+//!     - `kafka` - Read offset ranges of Apache Kafka topics
+//!     - `database` - Read query results from Postgres
+//!     - `iceberg` - Read Apache Iceberg tables
+//!     - `delta` - Read Delta Lake tables
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!                      Supported compressions:
 //!                         * zip
@@ -219,11 +235,13 @@
 //! * [`DataFrame`] operations:
 //!     - `dynamic_group_by` - Groupby based on a time window instead of predefined keys.
 //!                           Also activates rolling window group by operations.
+//!     - `grouping_sets` - Aggregate over several sets of keys at once, e.g. a ROLLUP or CUBE.
 //!     - `sort_multiple` - Allow sorting a [`DataFrame`] on multiple columns
 //!     - `rows` - Create [`DataFrame`] from rows and extract rows from [`DataFrame`]s.
 //!                And activates `pivot` and `transpose` operations
 //!     - `asof_join` - Join ASOF, to join on nearest keys instead of exact equality match.
 //!     - `cross_join` - Create the Cartesian product of two [`DataFrame`]s.
+//!     - `iejoin` - Join on inequality predicates between the keys, see `join_where`.
 //!     - `semi_anti_join` - SEMI and ANTI joins.
 //!     - `row_hash` - Utility to hash [`DataFrame`] rows to [`UInt64Chunked`]
 //!     - `diagonal_concat` - Concat diagonally thereby combining different schemas.
@@ -246,6 +264,9 @@
 //!     - `cum_agg` - [`cum_sum`], [`cum_min`], [`cum_max`] aggregation.
 //!     - `rolling_window` - rolling window functions, like [`rolling_mean`]
 //!     - `interpolate` [interpolate None values](polars_ops::series::interpolate())
+//!     - `downsample` - [LTTB](polars_ops::series::lttb()) and [min-max](polars_ops::series::min_max_downsample()) downsampling of time series.
+//!     - `hypothesis_tests` - [t-tests](polars_ops::series::ttest()), [Kolmogorov-Smirnov](polars_ops::series::ks_test()) and [chi-squared](polars_ops::series::chi2_test()) tests.
+//!     - `fill_null_by` - [Fill None values per group or within a maximum gap](polars_ops::series::fill_null_by())
 //!     - `extract_jsonpath` - [Run jsonpath queries on StringChunked](https://goessner.net/articles/JsonPath/)
 //!     - `list` - List utils.
 //!         - `list_gather` take sublist by multiple indices
@@ -270,6 +291,7 @@
 //!     - `trigonometry` - Trigonometric functions.
 //!     - `sign` - Compute the element-wise sign of a [`Series`].
 //!     - `propagate_nans` - NaN propagating min/max aggregations.
+//!     - `approx_quantile` - Approximate quantile aggregations with t-digest sketches.
 //!     - `extract_groups` - Extract multiple regex groups from strings.
 //!     - `cov` - Covariance and correlation functions.
 //!     - `find_many` - Find/replace multiple string patterns at once.
@@ -306,13 +328,16 @@
 //! | Int16                   | dtype-i16         |
 //! | UInt8                   | dtype-u8          |
 //! | UInt16                  | dtype-u16         |
+//! | Float16                 | dtype-f16         |
+//! | Json                    | dtype-json        |
 //! | Categorical             | dtype-categorical |
 //! | Struct                  | dtype-struct      |
+//! | Extension               | dtype-extension   |
 //!
 //!
 //! Or you can choose on of the preconfigured pre-sets.
 //!
-//! * `dtype-full` - all opt-in dtypes.
+//! * `dtype-full` - all opt-in dtypes, except for `dtype-extension`.
 //! * `dtype-slim` - slim preset of opt-in dtypes.
 //!
 //! ## Performance
@@ -33,3 +33,82 @@ fn split_at() {
     assert_eq!(rhs.value(1), "!!!");
     assert_eq!(rhs.value(2), "hello there");
 }
+
+#[test]
+fn view_comparisons() {
+    let values: [&[u8]; 9] = [
+        b"",
+        b"a",
+        b"a\0",
+        b"a\0\x01",
+        b"ab",
+        b"abcd",
+        b"abcdefghijklm",
+        b"abcdefghijklmn",
+        b"abce",
+    ];
+    let arr = BinaryViewArray::from_slice_values(values);
+    let buffers = arr.data_buffers().as_ref();
+    for (a, va) in arr.views().iter().zip(values) {
+        for (b, vb) in arr.views().iter().zip(values) {
+            unsafe {
+                assert_eq!(a.cmp_unchecked(b, buffers, buffers), va.cmp(vb));
+                assert_eq!(a.eq_unchecked(b, buffers, buffers), va == vb);
+            }
+        }
+    }
+}
+
+#[test]
+fn shrink_views() {
+    let values = (0..100)
+        .map(|i| format!("a long string that is not inlined {i}"))
+        .collect::<Vec<_>>();
+    let arr = Utf8ViewArray::from_slice_values(&values);
+
+    let sliced = arr.clone().sliced(10, 5);
+    let shrunk = sliced.clone().shrink_views();
+    assert!(shrunk.total_buffer_len() < sliced.total_buffer_len());
+    assert_eq!(
+        shrunk.values_iter().collect::<Vec<_>>(),
+        sliced.values_iter().collect::<Vec<_>>()
+    );
+
+    // All bytes are referenced, so the buffers are kept.
+    let shrunk = arr.clone().shrink_views();
+    assert_eq!(shrunk.total_buffer_len(), arr.total_buffer_len());
+}
+
+#[test]
+fn large_values() {
+    let large = vec![7u8; 300 * 1024];
+    let mut mutable = MutableBinaryViewArray::<[u8]>::new();
+    mutable.push_value(b"a value that is not inlined");
+    mutable.push_value(&large);
+    mutable.push_value(b"another value that is not inlined");
+    mutable.push_value(&large);
+    let arr: BinaryViewArray = mutable.into();
+
+    // Every large value has a buffer of its own.
+    let buffer_lens = arr
+        .data_buffers()
+        .iter()
+        .map(|b| b.len())
+        .collect::<Vec<_>>();
+    assert_eq!(buffer_lens, [27, large.len(), 33, large.len()]);
+    assert_eq!(arr.value(1), large.as_slice());
+    assert_eq!(arr.value(2), b"another value that is not inlined");
+
+    let sliced = arr.clone().sliced(1, 2);
+    let dropped = sliced.clone().drop_unused_buffers();
+    assert_eq!(dropped.data_buffers().len(), 2);
+    assert_eq!(dropped.total_buffer_len(), large.len() + 33);
+    assert_eq!(
+        dropped.values_iter().collect::<Vec<_>>(),
+        sliced.values_iter().collect::<Vec<_>>()
+    );
+
+    // All buffers are used, so nothing is dropped.
+    let dropped = arr.clone().drop_unused_buffers();
+    assert_eq!(dropped.data_buffers().len(), 4);
+}
@@ -2,7 +2,7 @@ use std::io::Cursor;
 use std::sync::Arc;
 
 use arrow::array::*;
-use arrow::datatypes::{ArrowSchema, ArrowSchemaRef, Field};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, ArrowSchemaRef, Field, Metadata};
 use arrow::io::ipc::read::{read_file_metadata, FileReader};
 use arrow::io::ipc::write::*;
 use arrow::io::ipc::IpcField;
@@ -16,7 +16,10 @@ pub(crate) fn write(
     compression: Option<Compression>,
 ) -> PolarsResult<Vec<u8>> {
     let result = vec![];
-    let options = WriteOptions { compression };
+    let options = WriteOptions {
+        compression,
+        ..Default::default()
+    };
     let mut writer = FileWriter::try_new(result, schema.clone(), ipc_fields.clone(), options)?;
     for batch in batches {
         writer.write(batch, ipc_fields.as_ref().map(|x| x.as_ref()))?;
@@ -58,7 +61,7 @@ fn write_boolean() -> PolarsResult<()> {
     let array = BooleanArray::from([Some(true), Some(false), None, Some(true)]).boxed();
     let schema = prep_schema(array.as_ref());
     let columns = RecordBatchT::try_new(vec![array])?;
-    round_trip(columns, schema, None, Some(Compression::ZSTD))
+    round_trip(columns, schema, None, Some(Compression::ZSTD(None)))
 }
 
 #[test]
@@ -68,7 +71,7 @@ fn write_sliced_utf8() -> PolarsResult<()> {
         .boxed();
     let schema = prep_schema(array.as_ref());
     let columns = RecordBatchT::try_new(vec![array])?;
-    round_trip(columns, schema, None, Some(Compression::ZSTD))
+    round_trip(columns, schema, None, Some(Compression::ZSTD(None)))
 }
 
 #[test]
@@ -76,5 +79,104 @@ fn write_binview() -> PolarsResult<()> {
     let array = Utf8ViewArray::from_slice([Some("foo"), Some("bar"), None, Some("hamlet")]).boxed();
     let schema = prep_schema(array.as_ref());
     let columns = RecordBatchT::try_new(vec![array])?;
-    round_trip(columns, schema, None, Some(Compression::ZSTD))
+    round_trip(columns, schema, None, Some(Compression::ZSTD(None)))
+}
+
+#[test]
+fn write_uncompressed_columns() -> PolarsResult<()> {
+    let arrays = vec![
+        Int64Array::from_iter_values(0..1000).boxed(),
+        Utf8ViewArray::from_slice([Some("foo"), None, Some("a longer string than 12 bytes")])
+            .boxed(),
+        BooleanArray::from([Some(true), Some(false), None]).boxed(),
+        BinaryArray::<i64>::from_slice([b"", b""]).boxed(),
+    ];
+    for uncompressed_column in 1..arrays.len() {
+        let array = arrays[uncompressed_column].clone();
+        let len = array.len();
+        let schema = Arc::new(ArrowSchema::from(vec![
+            Field::new("a", ArrowDataType::Int64, true),
+            Field::new("b", array.data_type().clone(), true),
+        ]));
+        let batch = RecordBatchT::try_new(vec![arrays[0].sliced(0, len), array])?;
+
+        let options = WriteOptions {
+            compression: Some(Compression::ZSTD(None)),
+            uncompressed_columns: vec![1],
+        };
+        let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+        writer.write(&batch, None)?;
+        writer.finish()?;
+
+        let mut reader = Cursor::new(writer.into_inner());
+        let metadata = read_file_metadata(&mut reader)?;
+        let read =
+            FileReader::new(reader, metadata, None, None).collect::<PolarsResult<Vec<_>>>()?;
+        assert_eq!(read, vec![batch]);
+    }
+    Ok(())
+}
+
+#[test]
+fn write_footer_custom_metadata() -> PolarsResult<()> {
+    let array = Int32Array::from_slice([1, 2, 3]).boxed();
+    let schema = prep_schema(array.as_ref());
+    let batch = RecordBatchT::try_new(vec![array])?;
+
+    let custom_metadata = Metadata::from([("key".to_string(), "value".to_string())]);
+    let mut writer = FileWriter::try_new(vec![], schema, None, Default::default())?;
+    writer.set_custom_metadata(custom_metadata.clone());
+    writer.write(&batch, None)?;
+    writer.finish()?;
+
+    let metadata = read_file_metadata(&mut Cursor::new(writer.into_inner()))?;
+    assert_eq!(metadata.custom_metadata, custom_metadata);
+    Ok(())
+}
+
+#[test]
+fn write_stream_dictionary_deltas() -> PolarsResult<()> {
+    use arrow::io::ipc::read::{read_stream_metadata, StreamReader};
+
+    let dict = |keys: &[u32], values: std::ops::Range<u32>| -> PolarsResult<Box<dyn Array>> {
+        let keys = PrimitiveArray::from_slice(keys);
+        let values = Utf8Array::<i64>::from_iter_values(values.map(|v| format!("value {v}")));
+        Ok(DictionaryArray::try_from_keys(keys, values.boxed())?.boxed())
+    };
+    let batches = [
+        dict(&[0, 1, 0], 0..1000)?,
+        dict(&[1000, 1], 0..1001)?,
+        dict(&[0], 5000..5001)?,
+    ]
+    .into_iter()
+    .map(|array| RecordBatchT::try_new(vec![array]))
+    .collect::<PolarsResult<Vec<_>>>()?;
+    let schema = prep_schema(batches[0].arrays()[0].as_ref());
+
+    let write = |emit_deltas: bool| -> PolarsResult<Vec<u8>> {
+        let options = WriteOptions {
+            compression: Some(Compression::LZ4(Some(4))),
+            ..Default::default()
+        };
+        let mut writer = StreamWriter::new(vec![], options).with_dictionary_deltas(emit_deltas);
+        writer.start(&schema, None)?;
+        for batch in &batches {
+            writer.write(batch, None)?;
+        }
+        writer.finish()?;
+        Ok(writer.into_inner())
+    };
+
+    let data = write(true)?;
+    // The second dictionary is sent as a delta with a single value, the third one replaces the
+    // dictionary.
+    assert!(data.len() < write(false)?.len());
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let read = StreamReader::new(reader, metadata, None)
+        .map(|state| state.map(|state| state.unwrap()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(read, batches);
+    Ok(())
 }
@@ -0,0 +1,64 @@
+use polars::export::chrono::NaiveDate;
+
+use super::*;
+
+#[derive(IntoDataFrame)]
+struct Location {
+    lat: f64,
+    lon: f64,
+}
+
+#[derive(IntoDataFrame)]
+struct Visit {
+    #[polars(rename = "visitor_id")]
+    visitor: u32,
+    day: NaiveDate,
+    note: Option<String>,
+    location: Option<Location>,
+}
+
+#[test]
+fn test_derive_into_df() -> PolarsResult<()> {
+    let day = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+    let visits = vec![
+        Visit {
+            visitor: 1,
+            day,
+            note: Some("first".into()),
+            location: Some(Location { lat: 1.5, lon: 2.5 }),
+        },
+        Visit {
+            visitor: 2,
+            day,
+            note: None,
+            location: None,
+        },
+    ];
+    let df = Visit::into_df(visits)?;
+
+    let location = DataType::Struct(vec![
+        Field::new("lat", DataType::Float64),
+        Field::new("lon", DataType::Float64),
+    ]);
+    let schema = Schema::from_iter([
+        Field::new("visitor_id", DataType::UInt32),
+        Field::new("day", DataType::Date),
+        Field::new("note", DataType::String),
+        Field::new("location", location),
+    ]);
+    assert_eq!(df.schema(), schema);
+    assert_eq!(Visit::schema(), schema);
+
+    let expected = df![
+        "visitor_id" => [1u32, 2],
+        "day" => [day, day],
+        "note" => [Some("first"), None],
+    ]?;
+    assert!(df
+        .select(["visitor_id", "day", "note"])?
+        .equals_missing(&expected));
+    let lat = df.column("location")?.struct_()?.field_by_name("lat")?;
+    assert_eq!(lat.f64()?.get(0), Some(1.5));
+    assert_eq!(df.column("location")?.null_count(), 1);
+    Ok(())
+}
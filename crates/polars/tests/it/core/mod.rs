@@ -1,4 +1,6 @@
 mod date_like;
+#[cfg(all(feature = "derive", feature = "dtype-struct", feature = "dtype-date"))]
+mod derive;
 mod group_by;
 mod joins;
 mod list;
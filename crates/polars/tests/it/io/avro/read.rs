@@ -352,3 +352,89 @@ fn test_list() -> PolarsResult<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+fn schema_map() -> (AvroSchema, ArrowSchema) {
+    let raw_schema = r#"
+    {
+        "type": "record",
+        "name": "test",
+        "fields": [
+            {"name": "m", "type": {
+                "type": "map",
+                "values": ["null", "long"]
+            }}
+        ]
+    }
+"#;
+
+    let entries = ArrowDataType::Struct(vec![
+        Field::new("key", ArrowDataType::Utf8, false),
+        Field::new("value", ArrowDataType::Int64, true),
+    ]);
+    let schema = ArrowSchema::from(vec![Field::new(
+        "m",
+        ArrowDataType::List(Box::new(Field::new("item", entries, false))),
+        false,
+    )]);
+
+    (AvroSchema::parse_str(raw_schema).unwrap(), schema)
+}
+
+fn data_map() -> RecordBatchT<Box<dyn Array>> {
+    let (_, schema) = schema_map();
+    let data_type = schema.fields[0].data_type().clone();
+    let ArrowDataType::List(inner) = &data_type else {
+        unreachable!()
+    };
+
+    let entries = StructArray::new(
+        inner.data_type().clone(),
+        vec![
+            Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+            Int64Array::from([Some(1), None]).boxed(),
+        ],
+        None,
+    );
+    let array = ListArray::<i32>::new(
+        data_type,
+        vec![0, 1, 1, 2].try_into().unwrap(),
+        entries.boxed(),
+        None,
+    );
+
+    RecordBatchT::try_new(vec![array.boxed()]).unwrap()
+}
+
+fn write_map(codec: Codec) -> Result<Vec<u8>, apache_avro::Error> {
+    let (avro, _) = schema_map();
+    let mut writer = Writer::with_codec(&avro, Vec::new(), codec);
+
+    let entries = [
+        vec![("a", Value::Union(1, Box::new(Value::Long(1))))],
+        vec![],
+        vec![("b", Value::Union(0, Box::new(Value::Null)))],
+    ];
+    for entries in entries {
+        let mut record = Record::new(writer.schema()).unwrap();
+        let map = entries
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        record.put("m", Value::Map(map));
+        writer.append(record)?;
+    }
+    Ok(writer.into_inner().unwrap())
+}
+
+#[test]
+fn test_map() -> PolarsResult<()> {
+    let avro = write_map(Codec::Null).unwrap();
+    let expected = data_map();
+    let (_, expected_schema) = schema_map();
+
+    let (result, schema) = read_avro(&avro, None)?;
+
+    assert_eq!(schema, expected_schema);
+    assert_eq!(result, expected);
+    Ok(())
+}
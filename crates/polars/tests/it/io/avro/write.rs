@@ -452,3 +452,31 @@ fn test_with_columns() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_write_multiple_chunks_and_small_ints() -> PolarsResult<()> {
+    let df = df!(
+        "u8" => &[1u8, 2],
+        "i16" => &[-1i16, 2],
+        "string" => &["a", "b"]
+    )?;
+    let mut df = df.vstack(&df)?;
+    assert_eq!(df.n_chunks(), 2);
+
+    let expected_df = df!(
+        "u8" => &[1i32, 2, 1, 2],
+        "i16" => &[-1i32, 2, -1, 2],
+        "string" => &["a", "b", "a", "b"]
+    )?;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+
+    AvroWriter::new(&mut buf).finish(&mut df)?;
+    buf.set_position(0);
+
+    let read_df = AvroReader::new(buf).finish()?;
+
+    assert!(expected_df.equals(&read_df));
+
+    Ok(())
+}
@@ -272,6 +272,83 @@ fn test_tab_sep() {
     assert_eq!(df.shape(), (8, 26))
 }
 
+#[test]
+fn test_separator_pattern() -> PolarsResult<()> {
+    let csv = "a\t|\tb\n1\t|\t\"x\t|\ty\"\n2\t|\tz\n";
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_separator_str("\t|\t"))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    let expected = df![
+        "a" => [1i64, 2],
+        "b" => ["x\t|\ty", "z"],
+    ]?;
+    assert!(df.equals(&expected));
+
+    let csv = "a | b|c\n1|  2 |3\n";
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options
+                .with_separator_pattern(Some(SeparatorPattern::Regex(Arc::from(r"\s*\|\s*"))))
+        })
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(df.get_column_names(), ["a", "b", "c"]);
+    assert_eq!(df.column("b")?.get(0)?, AnyValue::Int64(2));
+    Ok(())
+}
+
+#[test]
+fn test_on_error() -> PolarsResult<()> {
+    let csv = "a,b\n1,x\n2,y\nfoo,z\n4,w,extra\n5,v\n";
+    let read = |on_error, n_rows| {
+        CsvReadOptions::default()
+            .with_schema(Some(Arc::new(Schema::from_iter([
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::String),
+            ]))))
+            .with_n_rows(n_rows)
+            .with_on_error(on_error)
+            .into_reader_with_file_handle(Cursor::new(csv))
+            .finish_with_rejected()
+    };
+
+    assert!(read(CsvOnError::Raise, None).is_err());
+
+    let (df, rejected) = read(CsvOnError::Skip, None)?;
+    let expected = df![
+        "a" => [1i64, 2, 5],
+        "b" => ["x", "y", "v"],
+    ]?;
+    assert!(df.equals(&expected));
+    assert_eq!(rejected.height(), 0);
+
+    let (df, rejected) = read(CsvOnError::Collect, None)?;
+    assert!(df.equals(&expected));
+    assert_eq!(rejected.column("row")?.idx()?.to_vec(), [Some(2), Some(3)]);
+    assert_eq!(
+        rejected.column("line")?.str()?.to_vec(),
+        [Some("foo,z"), Some("4,w,extra")]
+    );
+    let error = rejected.column("error")?;
+    assert!(error
+        .str()?
+        .get(0)
+        .unwrap()
+        .starts_with("could not parse `foo`"));
+    assert!(error
+        .str()?
+        .get(1)
+        .unwrap()
+        .starts_with("found more fields"));
+
+    // Rows after the last row that is read are not rejected.
+    let (df, rejected) = read(CsvOnError::Collect, Some(2))?;
+    assert_eq!(df.height(), 2);
+    assert_eq!(rejected.height(), 0);
+    Ok(())
+}
+
 #[test]
 fn test_projection() -> PolarsResult<()> {
     let df = CsvReadOptions::default()
@@ -591,6 +668,43 @@ AUDCAD,1616455921,0.96212,0.95666,1
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "dtype-decimal")]
+fn test_decimal_overwrite() -> PolarsResult<()> {
+    let csv = "a;b
+12345678901234567,89;0,1
+-0,5;0,25
+;3
+";
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .with_schema_overwrite(Some(Arc::new(Schema::from_iter([
+            Field::new("a", DataType::Decimal(Some(20), Some(2))),
+            Field::new("b", DataType::Decimal(None, None)),
+        ]))))
+        .map_parse_options(|parse_options| {
+            parse_options.with_separator(b';').with_decimal_comma(true)
+        })
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    assert_eq!(
+        df.dtypes(),
+        &[
+            DataType::Decimal(Some(20), Some(2)),
+            DataType::Decimal(None, Some(2))
+        ]
+    );
+    let a = df.column("a")?.decimal()?;
+    assert_eq!(
+        Vec::from(a.physical()),
+        &[Some(1234567890123456789), Some(-50), None]
+    );
+    let b = df.column("b")?.decimal()?;
+    assert_eq!(Vec::from(b.physical()), &[Some(10), Some(25), Some(300)]);
+    Ok(())
+}
+
 #[test]
 fn test_skip_rows() -> PolarsResult<()> {
     let csv = r"#doc source pos typeindex type topic
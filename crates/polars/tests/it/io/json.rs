@@ -179,3 +179,76 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+fn write_json_to_string(
+    df: &mut DataFrame,
+    format: JsonFormat,
+    orient: JsonOrient,
+    temporal_format: JsonTemporalFormat,
+) -> PolarsResult<String> {
+    let mut buf = vec![];
+    JsonWriter::new(&mut buf)
+        .with_json_format(format)
+        .with_orient(orient)
+        .with_temporal_format(temporal_format)
+        .with_batch_size(NonZeroUsize::new(2).unwrap())
+        .finish(df)?;
+    Ok(String::from_utf8(buf).unwrap())
+}
+
+#[test]
+fn test_write_json_orient() -> PolarsResult<()> {
+    let mut df = df!(
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"],
+    )?;
+    let iso = JsonTemporalFormat::Iso;
+
+    assert_eq!(
+        write_json_to_string(&mut df, JsonFormat::Json, JsonOrient::Records, iso)?,
+        r#"[{"a":1,"b":"x"},{"a":2,"b":"y"},{"a":3,"b":"z"}]"#
+    );
+    assert_eq!(
+        write_json_to_string(&mut df, JsonFormat::Json, JsonOrient::Columns, iso)?,
+        r#"{"a":[1,2,3],"b":["x","y","z"]}"#
+    );
+    assert_eq!(
+        write_json_to_string(&mut df, JsonFormat::Json, JsonOrient::Values, iso)?,
+        r#"[[1,"x"],[2,"y"],[3,"z"]]"#
+    );
+    assert_eq!(
+        write_json_to_string(&mut df, JsonFormat::JsonLines, JsonOrient::Values, iso)?,
+        "[1,\"x\"]\n[2,\"y\"]\n[3,\"z\"]\n"
+    );
+    assert!(
+        write_json_to_string(&mut df, JsonFormat::JsonLines, JsonOrient::Columns, iso).is_err()
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-date")]
+fn test_write_json_temporal_format() -> PolarsResult<()> {
+    let mut df = df!("date" => [0i32, 1])?;
+    df.apply("date", |s| s.cast(&DataType::Date).unwrap())?;
+
+    assert_eq!(
+        write_json_to_string(
+            &mut df,
+            JsonFormat::Json,
+            JsonOrient::Records,
+            JsonTemporalFormat::Iso
+        )?,
+        r#"[{"date":"1970-01-01"},{"date":"1970-01-02"}]"#
+    );
+    assert_eq!(
+        write_json_to_string(
+            &mut df,
+            JsonFormat::Json,
+            JsonOrient::Records,
+            JsonTemporalFormat::Epoch
+        )?,
+        r#"[{"date":0},{"date":1}]"#
+    );
+    Ok(())
+}
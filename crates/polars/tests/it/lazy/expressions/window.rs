@@ -398,3 +398,87 @@ fn test_window_map_empty_df_3542() -> PolarsResult<()> {
     assert_eq!(out.height(), 0);
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "fill_null_by", feature = "temporal"))]
+fn test_fill_null_over() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "b", "a", "a", "b", "a"],
+        "t" => [0, 1, 2, 3, 4, 10],
+        "v" => [Some(1), Some(2), None, None, None, None],
+    ]?;
+
+    let forward = FillNullStrategy::Forward(Some(1));
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("v").fill_null_with_strategy(forward).over([col("g")]),
+            // Not rewritten to a single fill, so filled per group.
+            (col("v") + lit(0))
+                .fill_null_with_strategy(forward)
+                .over([col("g")])
+                .alias("v_groups"),
+            col("v")
+                .fill_null_by(
+                    col("t"),
+                    FillNullStrategy::Forward(None),
+                    Duration::parse("3i"),
+                )
+                .over([col("g")])
+                .alias("v_by"),
+        ])
+        .collect()?;
+
+    let expected = [Some(1), Some(2), Some(1), None, Some(2), None];
+    assert_eq!(Vec::from(out.column("v")?.i32()?), expected);
+    assert_eq!(Vec::from(out.column("v_groups")?.i32()?), expected);
+    assert_eq!(
+        Vec::from(out.column("v_by")?.i32()?),
+        &[Some(1), Some(2), Some(1), Some(1), Some(2), None]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dynamic_group_by", feature = "temporal"))]
+fn test_window_frame() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 2, 1, 1, 2],
+        "t" => [3, 1, 1, 2, 5],
+        "v" => [1, 2, 3, 4, 5]
+    ]?;
+
+    let order_by = Some(([col("t")], SortOptions::default()));
+    let out = df
+        .lazy()
+        .select([
+            col("v")
+                .sum()
+                .over_frame(
+                    [col("g")],
+                    order_by.clone(),
+                    WindowFrame::rows_between(Some(-1), Some(0)),
+                )
+                .alias("rows"),
+            col("v")
+                .sum()
+                .over_frame(
+                    [col("g")],
+                    order_by,
+                    WindowFrame::range_between(Some(Duration::parse("-1i")), None),
+                )
+                .alias("range"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("rows")?.i32()?),
+        &[Some(5), Some(2), Some(3), Some(7), Some(7)]
+    );
+    assert_eq!(
+        Vec::from(out.column("range")?.i32()?),
+        &[Some(5), Some(7), Some(8), Some(8), Some(5)]
+    );
+    Ok(())
+}
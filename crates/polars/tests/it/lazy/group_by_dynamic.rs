@@ -61,3 +61,39 @@ fn test_group_by_dynamic_week_bounds() -> PolarsResult<()> {
     assert_eq!(a.get(1)?, AnyValue::Int32(6));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dynamic_group_by")]
+fn test_resample() -> PolarsResult<()> {
+    let df = df![
+        "idx" => [0i64, 1, 6],
+        "a" => [1i32, 2, 3]
+    ]?;
+
+    let resample = |fill| {
+        df.clone().lazy().resample(
+            col("idx"),
+            [],
+            Duration::parse("2i"),
+            [col("a").sum()],
+            fill,
+        )
+    };
+
+    let out = resample(ResampleFill::Null).collect()?;
+    assert_eq!(
+        Vec::from(out.column("idx")?.i64()?),
+        &[Some(0), Some(2), Some(4), Some(6)]
+    );
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(3), None, None, Some(3)]
+    );
+
+    let out = resample(ResampleFill::Value(LiteralValue::Int32(0)))
+        .slice(1, 2)
+        .collect()?;
+    assert_eq!(Vec::from(out.column("idx")?.i64()?), &[Some(2), Some(4)]);
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(0), Some(0)]);
+    Ok(())
+}
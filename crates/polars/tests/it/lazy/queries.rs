@@ -272,3 +272,44 @@ fn test_group_by_on_lists() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_set_operations() -> PolarsResult<()> {
+    let left = df![
+        "a" => [Some(1), Some(2), Some(2), None],
+        "b" => ["x", "y", "y", "z"]
+    ]?
+    .lazy();
+    let right = df![
+        "c" => [Some(2), None, Some(3)],
+        "d" => ["y", "z", "w"]
+    ]?
+    .lazy();
+
+    let out = left
+        .clone()
+        .intersect(right.clone())
+        .sort(["a"], Default::default())
+        .collect()?;
+    let expected = df![
+        "a" => [None, Some(2)],
+        "b" => ["z", "y"]
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = left.clone().except(right.clone()).collect()?;
+    let expected = df![
+        "a" => [Some(1)],
+        "b" => ["x"]
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = left.clone().union(right.clone(), true).collect()?;
+    assert_eq!(out.get_column_names(), &["a", "b"]);
+    assert_eq!(out.height(), 4);
+    let out = left.union(right, false).collect()?;
+    assert_eq!(out.height(), 7);
+
+    Ok(())
+}
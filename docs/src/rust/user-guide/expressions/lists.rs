@@ -107,7 +107,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         )
         .cast(DataType::Float32)
         / col("*").count().cast(DataType::Float32))
-    .round(2);
+    .round(2, RoundMode::HalfAway);
 
     let out = weather_by_day
         .clone()
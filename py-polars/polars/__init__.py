@@ -16,11 +16,12 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
 
     __register_startup_deps()
 
-from polars import api, exceptions, plugins, selectors
+from polars import api, exceptions, plugins, random, selectors
 from polars._utils.polars_version import get_polars_version as _get_polars_version
 
 # TODO: remove need for importing wrap utils at top level
 from polars._utils.wrap import wrap_df, wrap_s  # noqa: F401
+from polars.cancellation import CancellationToken
 from polars.config import Config
 from polars.convert import (
     from_arrow,
@@ -46,12 +47,14 @@ from polars.datatypes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
     Int16,
     Int32,
     Int64,
+    Json,
     List,
     Null,
     Object,
@@ -76,6 +79,8 @@ from polars.functions import (
     arange,
     arctan2,
     arctan2d,
+    arg_max_horizontal,
+    arg_min_horizontal,
     arg_sort_by,
     arg_where,
     business_day_count,
@@ -110,12 +115,14 @@ from polars.functions import (
     from_epoch,
     groups,
     head,
+    hist_2d,
     implode,
     int_range,
     int_ranges,
     last,
     len,
     lit,
+    lookup,
     map_batches,
     map_groups,
     max,
@@ -130,9 +137,11 @@ from polars.functions import (
     ones,
     quantile,
     reduce,
+    register_frequency,
     repeat,
     rolling_corr,
     rolling_cov,
+    row_indices,
     select,
     set_random_seed,
     sql_expr,
@@ -147,6 +156,7 @@ from polars.functions import (
     var,
     when,
     zeros,
+    zip_lists,
 )
 from polars.io import (
     read_avro,
@@ -181,6 +191,12 @@ from polars.meta import (
     thread_pool_size,
     threadpool_size,
 )
+from polars.result_cache import (
+    clear_result_cache,
+    disable_result_cache,
+    enable_result_cache,
+    using_result_cache,
+)
 from polars.schema import Schema
 from polars.series import Series
 from polars.sql import SQLContext, sql
@@ -199,6 +215,7 @@ __all__ = [
     "api",
     "exceptions",
     "plugins",
+    "random",
     "selectors",
     # core classes
     "DataFrame",
@@ -219,12 +236,14 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
     "Int8",
     "Int16",
     "Int32",
     "Int64",
+    "Json",
     "List",
     "Null",
     "Object",
@@ -266,6 +285,13 @@ __all__ = [
     "disable_string_cache",
     "enable_string_cache",
     "using_string_cache",
+    # polars.result_cache
+    "clear_result_cache",
+    "disable_result_cache",
+    "enable_result_cache",
+    "using_result_cache",
+    # polars.cancellation
+    "CancellationToken",
     # polars.config
     "Config",
     # polars.functions.whenthen
@@ -290,6 +316,8 @@ __all__ = [
     "all_horizontal",
     "any",
     "any_horizontal",
+    "arg_max_horizontal",
+    "arg_min_horizontal",
     "cum_sum",
     "cum_sum_horizontal",
     "max",
@@ -330,11 +358,15 @@ __all__ = [
     "from_epoch",
     "groups",
     "head",
+    "hist_2d",
     "implode",
     "int_range",
     "int_ranges",
+    "lag",
     "last",
+    "lead",
     "lit",
+    "lookup",
     "map_batches",
     "map_groups",
     "mean",
@@ -345,14 +377,18 @@ __all__ = [
     "reduce",
     "rolling_corr",
     "rolling_cov",
+    "row_indices",
     "select",
     "std",
     "struct",
     "tail",
     "time",
     "var",
+    "zip_lists",
     # polars.functions.len
     "len",
+    # polars.functions.frequency
+    "register_frequency",
     # polars.functions.random
     "set_random_seed",
     # polars.convert
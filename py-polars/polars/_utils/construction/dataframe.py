@@ -1201,6 +1201,22 @@ def arrow_to_pydf(
     return pydf
 
 
+def arrow_capsule_to_pydf(
+    data: Any,
+    schema: SchemaDefinition | None = None,
+    *,
+    schema_overrides: SchemaDict | None = None,
+    strict: bool = True,
+) -> PyDataFrame:
+    """Construct a PyDataFrame from an object exporting Arrow PyCapsules."""
+    pydf = PyDataFrame.from_arrow_c_interface(data)
+    if schema is None and schema_overrides is None:
+        return pydf
+    return dataframe_to_pydf(
+        wrap_df(pydf), schema, schema_overrides=schema_overrides, strict=strict
+    )
+
+
 def numpy_to_pydf(
     data: np.ndarray[Any, Any],
     schema: SchemaDefinition | None = None,
@@ -34,6 +34,8 @@ from polars.datatypes import (
     Decimal,
     Duration,
     Enum,
+    Float16,
+    Json,
     List,
     Null,
     Object,
@@ -143,6 +145,8 @@ def sequence_to_pyseries(
             Boolean,
             Enum,
             Decimal,
+            Float16,
+            Json,
         ):
             if pyseries.dtype() != dtype:
                 pyseries = pyseries.cast(dtype, strict=strict, wrap_numerical=False)
@@ -426,6 +430,24 @@ def arrow_to_pyseries(
     )
 
 
+def arrow_capsule_to_pyseries(
+    name: str | None,
+    values: Any,
+    *,
+    dtype: PolarsDataType | None = None,
+    strict: bool = True,
+) -> PySeries:
+    """Construct a PySeries from an object exporting Arrow PyCapsules."""
+    pys = PySeries.from_arrow_c_interface(values)
+    if name is not None:
+        pys.rename(name)
+    return (
+        pys.cast(dtype, strict=strict, wrap_numerical=False)
+        if dtype is not None
+        else pys
+    )
+
+
 def numpy_to_pyseries(
     name: str,
     values: np.ndarray[Any, Any],
@@ -0,0 +1,64 @@
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING, Any
+
+from polars._utils.various import normalize_filepath
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from polars import DataFrame
+
+SPEEDSCOPE_SCHEMA = "https://www.speedscope.app/file-format-schema.json"
+
+
+def write_speedscope(profile: DataFrame, file: str | Path) -> None:
+    """
+    Write the profile of a query as a speedscope file.
+
+    Every node is nested in the node that consumes its output, so that the nodes are
+    shown as a flame graph. The nodes of the streaming engine run concurrently and
+    their timings overlap, while speedscope needs the nested nodes to run one after
+    the other. A node is therefore clamped to the time of the node it is nested in
+    and starts no earlier than the end of the node before it.
+    """
+    rows = profile.with_row_index("frame").to_dicts()
+    children: dict[int | None, list[dict[str, Any]]] = {}
+    for row in rows:
+        children.setdefault(row["parent"], []).append(row)
+
+    events: list[dict[str, Any]] = []
+
+    def add_events(nodes: list[dict[str, Any]], lower: int, upper: int) -> None:
+        cursor = lower
+        for node in sorted(nodes, key=lambda node: node["start"]):
+            start = min(max(node["start"], cursor), upper)
+            end = min(max(node["end"], start), upper)
+            events.append({"type": "O", "frame": node["frame"], "at": start})
+            if node["id"] is not None:
+                add_events(children.get(node["id"], []), start, end)
+            events.append({"type": "C", "frame": node["frame"], "at": end})
+            cursor = end
+
+    end_value = max((row["end"] for row in rows), default=0)
+    add_events(children.get(None, []), 0, end_value)
+
+    speedscope = {
+        "$schema": SPEEDSCOPE_SCHEMA,
+        "exporter": "polars",
+        "name": "polars query",
+        "shared": {"frames": [{"name": row["node"]} for row in rows]},
+        "profiles": [
+            {
+                "type": "evented",
+                "name": "polars query",
+                "unit": "microseconds",
+                "startValue": 0,
+                "endValue": end_value,
+                "events": events,
+            }
+        ],
+    }
+    with open(normalize_filepath(file), "w") as f:
+        json.dump(speedscope, f)
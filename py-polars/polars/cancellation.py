@@ -0,0 +1,71 @@
+from __future__ import annotations
+
+import contextlib
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import issue_unstable_warning
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars.polars import PyCancellationToken
+
+if TYPE_CHECKING:
+    from types import TracebackType
+
+__all__ = ["CancellationToken"]
+
+
+class CancellationToken:
+    """
+    Token to cancel running queries from another thread.
+
+    Queries that are collected or sunk while the token is entered as a context manager
+    check it as they run. Once :meth:`cancel` is called, the scans, joins, sorts and
+    group-bys of such a query stop at the next chunk boundary and the query raises a
+    :class:`polars.exceptions.CancelledError`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Notes
+    -----
+    The token only applies to queries started on the thread that entered it.
+    Cancelling cannot be undone; use a new token for the next query.
+
+    Examples
+    --------
+    >>> import threading
+    >>> token = pl.CancellationToken()  # doctest: +SKIP
+    >>> threading.Timer(10.0, token.cancel).start()  # doctest: +SKIP
+    >>> with token:  # doctest: +SKIP
+    ...     df = pl.scan_parquet("data/*.parquet").sort("a").collect()
+    polars.exceptions.CancelledError: query was cancelled
+    """
+
+    def __init__(self) -> None:
+        issue_unstable_warning("`CancellationToken` is considered unstable.")
+        self._token = PyCancellationToken()
+
+    def __repr__(self) -> str:
+        return f"{self.__class__.__name__}(cancelled={self.cancelled})"
+
+    def __enter__(self) -> CancellationToken:
+        self._token.enter()
+        return self
+
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_val: BaseException | None,
+        exc_tb: TracebackType | None,
+    ) -> None:
+        self._token.exit()
+
+    def cancel(self) -> None:
+        """Cancel the queries using this token at the earliest convenience."""
+        self._token.cancel()
+
+    @property
+    def cancelled(self) -> bool:
+        """Whether :meth:`cancel` was called."""
+        return self._token.is_cancelled()
@@ -12,7 +12,7 @@ if TYPE_CHECKING:
     import sys
     from types import TracebackType
 
-    from polars._typing import FloatFmt
+    from polars._typing import FloatFmt, RoundMode
 
     if sys.version_info >= (3, 10):
         from typing import TypeAlias
@@ -44,6 +44,8 @@ TableFormatNames: TypeAlias = Literal[
 _POLARS_CFG_ENV_VARS = {
     "POLARS_WARN_UNSTABLE",
     "POLARS_AUTO_STRUCTIFY",
+    "POLARS_DECIMAL_OVERFLOW",
+    "POLARS_DECIMAL_ROUNDING",
     "POLARS_FMT_MAX_COLS",
     "POLARS_FMT_MAX_ROWS",
     "POLARS_FMT_NUM_DECIMAL",
@@ -61,8 +63,11 @@ _POLARS_CFG_ENV_VARS = {
     "POLARS_FMT_TABLE_HIDE_DATAFRAME_SHAPE_INFORMATION",
     "POLARS_FMT_TABLE_INLINE_COLUMN_DATA_TYPE",
     "POLARS_FMT_TABLE_ROUNDED_CORNERS",
+    "POLARS_MAX_MEMORY",
     "POLARS_STREAMING_CHUNK_SIZE",
+    "POLARS_STRICT_SUPERTYPES",
     "POLARS_TABLE_WIDTH",
+    "POLARS_VALIDATE_SORTED",
     "POLARS_VERBOSE",
     "POLARS_MAX_EXPR_DEPTH",
 }
@@ -400,6 +405,79 @@ class Config(contextlib.ContextDecorator):
             os.environ["POLARS_AUTO_STRUCTIFY"] = str(int(active))
         return cls
 
+    @classmethod
+    def set_decimal_overflow(
+        cls, behavior: Literal["promote", "raise"] | None = "promote"
+    ) -> type[Config]:
+        """
+        Set how decimal arithmetic treats results that need more than 38 digits.
+
+        The precision and scale of a decimal result follow from its operands, e.g.
+        multiplying two `Decimal(20, 10)` values needs 41 digits of which 20 are
+        fractional. A decimal holds at most 38 digits.
+
+        Parameters
+        ----------
+        behavior : {'promote', 'raise'}
+            * "promote": give up fractional digits, keeping at least 6, to make room
+              for the integer part of the result.
+            * "raise": keep all fractional digits, leaving fewer digits for the
+              integer part.
+
+            In both cases values that don't fit the result type raise an error.
+
+        Examples
+        --------
+        >>> from decimal import Decimal as D
+        >>> df = pl.DataFrame({"x": [D("1.5")]}, schema={"x": pl.Decimal(20, 10)})
+        >>> with pl.Config(decimal_overflow="raise"):
+        ...     df.select(pl.col("x") * pl.col("x")).dtypes
+        [Decimal(precision=38, scale=20)]
+        >>> df.select(pl.col("x") * pl.col("x")).dtypes
+        [Decimal(precision=38, scale=17)]
+        """
+        if behavior is None:
+            os.environ.pop("POLARS_DECIMAL_OVERFLOW", None)
+        elif behavior not in {"promote", "raise"}:
+            msg = f"invalid decimal overflow behavior: {behavior!r}"
+            raise ValueError(msg)
+        else:
+            os.environ["POLARS_DECIMAL_OVERFLOW"] = behavior
+        return cls
+
+    @classmethod
+    def set_decimal_rounding(cls, mode: RoundMode | None = "half_away") -> type[Config]:
+        """
+        Set how decimal arithmetic rounds results to their scale.
+
+        Parameters
+        ----------
+        mode : {'half_away', 'half_even'}
+            How to round values that lie exactly halfway between two candidates:
+            away from zero, or to the even candidate.
+
+        Examples
+        --------
+        >>> from decimal import Decimal as D
+        >>> df = pl.DataFrame(
+        ...     {"x": [D("0.0001"), D("0.0003")], "y": [D("8"), D("8")]},
+        ...     schema={"x": pl.Decimal(5, 4), "y": pl.Decimal(1, 0)},
+        ... )
+        >>> df.select(pl.col("x") / pl.col("y")).to_series().to_list()
+        [Decimal('0.000013'), Decimal('0.000038')]
+        >>> with pl.Config(decimal_rounding="half_even"):
+        ...     df.select(pl.col("x") / pl.col("y")).to_series().to_list()
+        [Decimal('0.000012'), Decimal('0.000038')]
+        """
+        if mode is None:
+            os.environ.pop("POLARS_DECIMAL_ROUNDING", None)
+        elif mode not in {"half_away", "half_even"}:
+            msg = f"invalid rounding mode: {mode!r}"
+            raise ValueError(msg)
+        else:
+            os.environ["POLARS_DECIMAL_ROUNDING"] = mode
+        return cls
+
     @classmethod
     def set_decimal_separator(cls, separator: str | None = None) -> type[Config]:
         """
@@ -749,6 +827,71 @@ class Config(contextlib.ContextDecorator):
             os.environ["POLARS_STREAMING_CHUNK_SIZE"] = str(size)
         return cls
 
+    @classmethod
+    def set_streaming_max_memory(cls, size: int | None) -> type[Config]:
+        """
+        Set the memory budget of the out-of-core operators of the `streaming` engine.
+
+        By default, the group-by and sort operators only spill to disk when the
+        system runs low on free memory. With a budget they also spill once they
+        have taken this many bytes, so a query can be kept from using all memory.
+
+        Parameters
+        ----------
+        size
+            Number of bytes. Set to `None` to only go by the free system memory.
+
+        Examples
+        --------
+        >>> pl.Config.set_streaming_max_memory(8 * 1024**3)  # doctest: +SKIP
+        """
+        if size is None:
+            os.environ.pop("POLARS_MAX_MEMORY", None)
+        else:
+            if size < 1:
+                msg = "memory budget must be >= 1 byte"
+                raise ValueError(msg)
+
+            os.environ["POLARS_MAX_MEMORY"] = str(size)
+        return cls
+
+    @classmethod
+    def set_strict_supertypes(cls, active: bool | None = False) -> type[Config]:
+        """
+        Raise instead of implicitly casting columns to a common supertype.
+
+        Expressions that combine columns of different types, like `Int32 + Float64`
+        or comparing a `String` column to a `Categorical` one, cast their inputs to
+        a common supertype. When this is set, such queries raise a
+        :class:`SchemaError` while the query is planned. The error names both
+        expressions and the cast that is needed. Literals still take the type of
+        the column they are used with.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"qty": [1, 2], "price": [1.5, 2.5]},
+        ...     schema={"qty": pl.Int32, "price": pl.Float64},
+        ... )
+        >>> with pl.Config(strict_supertypes=True):
+        ...     out = df.select(pl.col("qty").cast(pl.Float64) * pl.col("price"))
+        >>> out
+        shape: (2, 1)
+        ┌─────┐
+        │ qty │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 1.5 │
+        │ 5.0 │
+        └─────┘
+        """
+        if active is None:
+            os.environ.pop("POLARS_STRICT_SUPERTYPES", None)
+        else:
+            os.environ["POLARS_STRICT_SUPERTYPES"] = str(int(active))
+        return cls
+
     @classmethod
     def set_tbl_cell_alignment(
         cls, format: Literal["LEFT", "CENTER", "RIGHT"] | None
@@ -1262,6 +1405,30 @@ class Config(contextlib.ContextDecorator):
         plr.set_trim_decimal_zeros(active)
         return cls
 
+    @classmethod
+    def set_validate_sorted(cls, active: bool | None = True) -> type[Config]:
+        """
+        Verify that data is sorted when it is flagged with `set_sorted`.
+
+        By default `set_sorted` trusts the caller, and a wrongly flagged column leads
+        to incorrect results. With this setting, every `set_sorted` checks the order
+        like `check_sorted` does and raises an error if the data is not sorted. This
+        costs a pass over the data, so it is meant for debugging.
+
+        Examples
+        --------
+        >>> with pl.Config(validate_sorted=True):
+        ...     pl.Series([3, 1, 2]).set_sorted()  # doctest: +SKIP
+        Traceback (most recent call last):
+        ...
+        polars.exceptions.ComputeError: series '' is not sorted in ascending order; value 1 at index 1 follows 3
+        """  # noqa: W505
+        if active is None:
+            os.environ.pop("POLARS_VALIDATE_SORTED", None)
+        else:
+            os.environ["POLARS_VALIDATE_SORTED"] = str(int(active))
+        return cls
+
     @classmethod
     def set_verbose(cls, active: bool | None = True) -> type[Config]:
         """
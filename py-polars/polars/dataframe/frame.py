@@ -37,6 +37,7 @@ from polars._typing import (
     TorchExportType,
 )
 from polars._utils.construction import (
+    arrow_capsule_to_pydf,
     arrow_to_pydf,
     dataframe_to_pydf,
     dict_to_pydf,
@@ -140,6 +141,7 @@ if TYPE_CHECKING:
         ConnectionOrCursor,
         CsvQuoteStyle,
         DbWriteEngine,
+        DuplicateKeepStrategy,
         FillNullStrategy,
         FrameInitTypes,
         IndexOrder,
@@ -148,6 +150,8 @@ if TYPE_CHECKING:
         IpcCompression,
         JoinStrategy,
         JoinValidation,
+        JsonOrient,
+        JsonTemporalFormat,
         Label,
         MultiColSelector,
         MultiIndexSelector,
@@ -168,6 +172,7 @@ if TYPE_CHECKING:
         StartBy,
         UniqueKeepStrategy,
         UnstackDirection,
+        WriteMode,
     )
     from polars._utils.various import NoDefault
     from polars.interchange.dataframe import PolarsDataFrame
@@ -191,6 +196,8 @@ class DataFrame:
     data : dict, Sequence, ndarray, Series, or pandas.DataFrame
         Two-dimensional data in various forms; dict input must contain Sequences,
         Generators, or a `range`. Sequence may contain Series or other Sequences.
+        Objects that export their data through the Arrow PyCapsule interface, such
+        as a pyarrow RecordBatchReader, are read without copying.
     schema : Sequence of str, (str,DataType) pairs, or a {str:DataType,} dict
         The schema of the resulting DataFrame. The schema may be declared in several
         ways:
@@ -380,6 +387,11 @@ class DataFrame:
                 data, schema=schema, schema_overrides=schema_overrides, strict=strict
             )
 
+        elif isinstance(data, pl.DataFrame):
+            self._df = dataframe_to_pydf(
+                data, schema=schema, schema_overrides=schema_overrides, strict=strict
+            )
+
         elif _check_for_numpy(data) and isinstance(data, np.ndarray):
             self._df = numpy_to_pydf(
                 data,
@@ -400,6 +412,11 @@ class DataFrame:
                 data, schema=schema, schema_overrides=schema_overrides, strict=strict
             )
 
+        elif hasattr(data, "__arrow_c_stream__") or hasattr(data, "__arrow_c_array__"):
+            self._df = arrow_capsule_to_pydf(
+                data, schema=schema, schema_overrides=schema_overrides, strict=strict
+            )
+
         elif not isinstance(data, Sized) and isinstance(data, (Generator, Iterable)):
             self._df = iterable_to_pydf(
                 data,
@@ -410,10 +427,6 @@ class DataFrame:
                 infer_schema_length=infer_schema_length,
             )
 
-        elif isinstance(data, pl.DataFrame):
-            self._df = dataframe_to_pydf(
-                data, schema=schema, schema_overrides=schema_overrides, strict=strict
-            )
         else:
             msg = (
                 f"DataFrame constructor called with unsupported type {type(data).__name__!r}"
@@ -953,6 +966,15 @@ class DataFrame:
 
         return PolarsDataFrame(self, allow_copy=allow_copy)
 
+    def __arrow_c_stream__(self, requested_schema: Any = None) -> Any:
+        """
+        Export the data through the Arrow PyCapsule interface, with a batch per chunk.
+
+        This lets libraries such as pyarrow and DuckDB read the frame without
+        copying it.
+        """
+        return self._df.to_arrow_stream(False).__arrow_c_stream__(requested_schema)
+
     def _comp(self, other: Any, op: ComparisonOperator) -> DataFrame:
         """Compare a DataFrame with another object."""
         if isinstance(other, DataFrame):
@@ -2374,6 +2396,46 @@ class DataFrame:
 
         return output.getvalue()
 
+    def to_string(self, formats: Mapping[str, Mapping[str, Any]] | None = None) -> str:
+        """
+        Render the DataFrame as a table, with optional per-column display options.
+
+        Columns without options follow the global :class:`Config`.
+
+        Parameters
+        ----------
+        formats
+            Mapping from column names to display options. The supported options are:
+
+            - float_precision: the number of decimals of floats.
+            - thousands_separator: the separator between groups of thousands.
+            - datetime_format: a `strftime` format of dates, datetimes and times.
+            - max_str_len: the maximum number of characters shown of a value.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"item": ["apple", "banana"], "price": [1234.5, 0.25]})
+        >>> print(
+        ...     df.to_string(
+        ...         {"price": {"float_precision": 2, "thousands_separator": ","}}
+        ...     )
+        ... )
+        shape: (2, 2)
+        ┌────────┬──────────┐
+        │ item   ┆ price    │
+        │ ---    ┆ ---      │
+        │ str    ┆ f64      │
+        ╞════════╪══════════╡
+        │ apple  ┆ 1,234.50 │
+        │ banana ┆ 0.25     │
+        └────────┴──────────┘
+        """
+        if formats is None:
+            return self._df.as_str()
+        return self._df.as_str_with_config(
+            [(name, dict(fmt)) for name, fmt in formats.items()]
+        )
+
     @overload
     def serialize(
         self, file: None = ..., *, format: Literal["binary"] = ...
@@ -2452,12 +2514,30 @@ class DataFrame:
         return serialize_polars_object(serializer, file, format)
 
     @overload
-    def write_json(self, file: None = ...) -> str: ...
+    def write_json(
+        self,
+        file: None = ...,
+        *,
+        orient: JsonOrient = ...,
+        temporal_format: JsonTemporalFormat = ...,
+    ) -> str: ...
 
     @overload
-    def write_json(self, file: IOBase | str | Path) -> None: ...
+    def write_json(
+        self,
+        file: IOBase | str | Path,
+        *,
+        orient: JsonOrient = ...,
+        temporal_format: JsonTemporalFormat = ...,
+    ) -> None: ...
 
-    def write_json(self, file: IOBase | str | Path | None = None) -> str | None:
+    def write_json(
+        self,
+        file: IOBase | str | Path | None = None,
+        *,
+        orient: JsonOrient = "records",
+        temporal_format: JsonTemporalFormat = "iso",
+    ) -> str | None:
         """
         Serialize to JSON representation.
 
@@ -2466,6 +2546,15 @@ class DataFrame:
         file
             File path or writable file-like object to which the result will be written.
             If set to `None` (default), the output is returned as a string instead.
+        orient : {'records', 'columns', 'values'}
+            Layout of the output.
+
+            - records: a list of row objects, `[{"foo":1,"bar":6},...]`.
+            - columns: an object of column lists, `{"foo":[1,...],"bar":[6,...]}`.
+            - values: a list of row lists, `[[1,6],...]`.
+        temporal_format : {'iso', 'epoch'}
+            Write temporal columns as ISO 8601 strings, or as their integer
+            representation (e.g. days since the epoch for dates).
 
         See Also
         --------
@@ -2485,7 +2574,7 @@ class DataFrame:
 
         def write_json_to_string() -> str:
             with BytesIO() as buf:
-                self._df.write_json(buf)
+                self._df.write_json(buf, orient, temporal_format)
                 json_bytes = buf.getvalue()
             return json_bytes.decode("utf8")
 
@@ -2497,19 +2586,26 @@ class DataFrame:
             return None
         elif isinstance(file, (str, Path)):
             file = normalize_filepath(file)
-            self._df.write_json(file)
+            self._df.write_json(file, orient, temporal_format)
             return None
         else:
-            self._df.write_json(file)
+            self._df.write_json(file, orient, temporal_format)
             return None
 
     @overload
     def write_ndjson(self, file: None = None) -> str: ...
 
     @overload
-    def write_ndjson(self, file: IOBase | str | Path) -> None: ...
+    def write_ndjson(
+        self, file: IOBase | str | Path, *, write_mode: WriteMode = ...
+    ) -> None: ...
 
-    def write_ndjson(self, file: IOBase | str | Path | None = None) -> str | None:
+    def write_ndjson(
+        self,
+        file: IOBase | str | Path | None = None,
+        *,
+        write_mode: WriteMode = "overwrite",
+    ) -> str | None:
         r"""
         Serialize to newline delimited JSON representation.
 
@@ -2518,6 +2614,13 @@ class DataFrame:
         file
             File path or writable file-like object to which the result will be written.
             If set to `None` (default), the output is returned as a string instead.
+        write_mode : {'overwrite', 'error_if_exists', 'append'}
+            What to do if the file at path `file` already exists: replace it, raise
+            a `FileExistsError` or add the rows after its rows. Other modes than
+            "overwrite" require `file` to be a path.
+
+            The data is written to a temporary file next to `file` that is moved into
+            place once it is complete, so readers never see a partially written file.
 
         Examples
         --------
@@ -2533,22 +2636,22 @@ class DataFrame:
 
         def write_ndjson_to_string() -> str:
             with BytesIO() as buf:
-                self._df.write_ndjson(buf)
+                self._df.write_ndjson(buf, "overwrite")
                 ndjson_bytes = buf.getvalue()
             return ndjson_bytes.decode("utf8")
 
+        if isinstance(file, (str, Path)):
+            file = normalize_filepath(file)
+        _check_write_mode(write_mode, file)
+
         if file is None:
             return write_ndjson_to_string()
         elif isinstance(file, StringIO):
             ndjson_str = write_ndjson_to_string()
             file.write(ndjson_str)
             return None
-        elif isinstance(file, (str, Path)):
-            file = normalize_filepath(file)
-            self._df.write_ndjson(file)
-            return None
         else:
-            self._df.write_ndjson(file)
+            self._df.write_ndjson(file, write_mode)
             return None
 
     @overload
@@ -2567,6 +2670,7 @@ class DataFrame:
         time_format: str | None = ...,
         float_scientific: bool | None = ...,
         float_precision: int | None = ...,
+        float_scientific_threshold: int | None = ...,
         null_value: str | None = ...,
         quote_style: CsvQuoteStyle | None = ...,
     ) -> str: ...
@@ -2587,8 +2691,10 @@ class DataFrame:
         time_format: str | None = ...,
         float_scientific: bool | None = ...,
         float_precision: int | None = ...,
+        float_scientific_threshold: int | None = ...,
         null_value: str | None = ...,
         quote_style: CsvQuoteStyle | None = ...,
+        write_mode: WriteMode = ...,
     ) -> None: ...
 
     def write_csv(
@@ -2606,8 +2712,10 @@ class DataFrame:
         time_format: str | None = None,
         float_scientific: bool | None = None,
         float_precision: int | None = None,
+        float_scientific_threshold: int | None = None,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        write_mode: WriteMode = "overwrite",
     ) -> str | None:
         """
         Write to comma-separated values (CSV) file.
@@ -2649,6 +2757,11 @@ class DataFrame:
         float_precision
             Number of decimal places to write, applied to both `Float32` and
             `Float64` datatypes.
+        float_scientific_threshold
+            When `float_scientific` is None, write floats with an absolute value of
+            at least `10**float_scientific_threshold`, or a non-zero absolute value
+            below `10**-float_scientific_threshold`, in scientific form and all other
+            floats in positional form.
         null_value
             A string representing null values (defaulting to the empty string).
         quote_style : {'necessary', 'always', 'non_numeric', 'never'}
@@ -2667,6 +2780,13 @@ class DataFrame:
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        write_mode : {'overwrite', 'error_if_exists', 'append'}
+            What to do if the file at path `file` already exists: replace it, raise
+            a `FileExistsError` or add the rows after its rows, without writing the
+            header again. Other modes than "overwrite" require `file` to be a path.
+
+            The data is written to a temporary file next to `file` that is moved into
+            place once it is complete, so readers never see a partially written file.
 
         Examples
         --------
@@ -2697,6 +2817,7 @@ class DataFrame:
             file = normalize_filepath(file)
         elif isinstance(file, TextIOWrapper):
             file = cast(TextIOWrapper, file.buffer)
+        _check_write_mode(write_mode, file)
 
         self._df.write_csv(
             file,
@@ -2711,8 +2832,10 @@ class DataFrame:
             time_format,
             float_scientific,
             float_precision,
+            float_scientific_threshold,
             null_value,
             quote_style,
+            write_mode,
         )
 
         if should_return_buffer:
@@ -3301,6 +3424,8 @@ class DataFrame:
         *,
         compression: IpcCompression = "uncompressed",
         future: bool | None = None,
+        uncompressed_columns: Sequence[str] | None = None,
+        statistics: bool = False,
     ) -> BytesIO: ...
 
     @overload
@@ -3310,6 +3435,9 @@ class DataFrame:
         *,
         compression: IpcCompression = "uncompressed",
         future: bool | None = None,
+        write_mode: WriteMode = "overwrite",
+        uncompressed_columns: Sequence[str] | None = None,
+        statistics: bool = False,
     ) -> None: ...
 
     def write_ipc(
@@ -3318,6 +3446,9 @@ class DataFrame:
         *,
         compression: IpcCompression = "uncompressed",
         future: bool | None = None,
+        write_mode: WriteMode = "overwrite",
+        uncompressed_columns: Sequence[str] | None = None,
+        statistics: bool = False,
     ) -> BytesIO | None:
         """
         Write to Arrow IPC binary stream or Feather file.
@@ -3338,6 +3469,21 @@ class DataFrame:
             .. warning::
                 This functionality is considered **unstable**. It may be changed
                 at any point without it being considered a breaking change.
+        write_mode : {'overwrite', 'error_if_exists', 'append'}
+            What to do if the file at path `file` already exists: replace it, raise
+            a `FileExistsError` or add the rows after its rows, which requires the
+            same schema. Other modes than "overwrite" require `file` to be a path.
+
+            The data is written to a temporary file next to `file` that is moved into
+            place once it is complete, so readers never see a partially written file.
+        uncompressed_columns
+            Columns that are written without compression, such as columns that don't
+            compress well. Only applies if `compression` is set.
+        statistics
+            Write the minimum, maximum and null count of the columns of every record
+            batch in the footer of the file. :func:`scan_ipc` uses them to skip the
+            record batches that a filter removes. When appending, statistics are only
+            written if the existing record batches have them.
 
         Examples
         --------
@@ -3368,8 +3514,16 @@ class DataFrame:
             )
         if future is None:
             future = True
+        _check_write_mode(write_mode, file)
 
-        self._df.write_ipc(file, compression, future)
+        self._df.write_ipc(
+            file,
+            compression,
+            future,
+            write_mode,
+            list(uncompressed_columns or []),
+            statistics,
+        )
         return file if return_bytes else None  # type: ignore[return-value]
 
     @overload
@@ -3461,6 +3615,7 @@ class DataFrame:
         data_page_size: int | None = None,
         use_pyarrow: bool = False,
         pyarrow_options: dict[str, Any] | None = None,
+        write_mode: WriteMode = "overwrite",
     ) -> None:
         """
         Write to Apache Parquet file.
@@ -3512,6 +3667,13 @@ class DataFrame:
             using `pyarrow.parquet.write_to_dataset`.
             The `partition_cols` parameter leads to write the dataset to a directory.
             Similar to Spark's partitioned datasets.
+        write_mode : {'overwrite', 'error_if_exists'}
+            What to do if the file at path `file` already exists: replace it or raise
+            a `FileExistsError`. Parquet files cannot be appended to. Other modes than
+            "overwrite" require `file` to be a path and `use_pyarrow=False`.
+
+            The data is written to a temporary file next to `file` that is moved into
+            place once it is complete, so readers never see a partially written file.
 
         Examples
         --------
@@ -3547,6 +3709,7 @@ class DataFrame:
                 file = normalize_filepath(file, check_not_directory=False)
             else:
                 file = normalize_filepath(file)
+        _check_write_mode(write_mode, None if use_pyarrow else file)
 
         if use_pyarrow:
             if statistics == "full" or isinstance(statistics, dict):
@@ -3616,6 +3779,7 @@ class DataFrame:
                 statistics,
                 row_group_size,
                 data_page_size,
+                write_mode,
             )
 
     def write_database(
@@ -4724,6 +4888,8 @@ class DataFrame:
         nulls_last: bool | Sequence[bool] = False,
         multithreaded: bool = True,
         maintain_order: bool = False,
+        collation: str | None = None,
+        stable: bool = True,
     ) -> DataFrame:
         """
         Sort the dataframe by the given columns.
@@ -4744,7 +4910,21 @@ class DataFrame:
         multithreaded
             Sort using multiple threads.
         maintain_order
-            Whether the order should be maintained if elements are equal.
+            Whether the order should be maintained if elements are equal. Sorting a
+            DataFrame is already stable unless `stable=False`.
+        collation
+            Sort string columns by the collation of this locale instead of by their
+            bytes, given as a BCP 47 language tag such as `"de-AT"`. The strength of the
+            collation is set with the `ks` keyword, e.g. `"de-AT-u-ks-level2"` ignores
+            case.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        stable
+            Whether rows with equal sort keys keep their order, which is the default.
+            Set to `False` to allow a faster unstable sort, which notably pays off when
+            sorting many rows by a single numeric column.
 
         Examples
         --------
@@ -4820,6 +5000,8 @@ class DataFrame:
                 nulls_last=nulls_last,
                 multithreaded=multithreaded,
                 maintain_order=maintain_order,
+                collation=collation,
+                stable=stable,
             )
             .collect(_eager=True)
         )
@@ -5474,7 +5656,13 @@ class DataFrame:
         """
         return function(self, *args, **kwargs)
 
-    def with_row_index(self, name: str = "index", offset: int = 0) -> DataFrame:
+    def with_row_index(
+        self,
+        name: str = "index",
+        offset: int = 0,
+        *,
+        over: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
+    ) -> DataFrame:
         """
         Add a row index as the first column in the DataFrame.
 
@@ -5484,6 +5672,9 @@ class DataFrame:
             Name of the index column.
         offset
             Start the index at this offset. Cannot be negative.
+        over
+            Count the rows within each group of these columns instead. This is
+            equivalent to `pl.int_range(pl.len()).over(over)`, but faster.
 
         Notes
         -----
@@ -5538,9 +5729,28 @@ class DataFrame:
         │ 1     ┆ 3   ┆ 4   │
         │ 2     ┆ 5   ┆ 6   │
         └───────┴─────┴─────┘
+
+        Count the rows within each group instead.
+
+        >>> df = pl.DataFrame({"g": ["x", "y", "x", "x"], "v": [1, 2, 3, 4]})
+        >>> df.with_row_index(over="g")
+        shape: (4, 3)
+        ┌───────┬─────┬─────┐
+        │ index ┆ g   ┆ v   │
+        │ ---   ┆ --- ┆ --- │
+        │ u32   ┆ str ┆ i64 │
+        ╞═══════╪═════╪═════╡
+        │ 0     ┆ x   ┆ 1   │
+        │ 0     ┆ y   ┆ 2   │
+        │ 1     ┆ x   ┆ 3   │
+        │ 2     ┆ x   ┆ 4   │
+        └───────┴─────┴─────┘
         """
+        over_columns = None if over is None else _expand_selectors(self, over)
         try:
-            return self._from_pydf(self._df.with_row_index(name, offset))
+            return self._from_pydf(
+                self._df.with_row_index(name, offset, over_columns)
+            )
         except OverflowError:
             issue = "negative" if offset < 0 else "greater than the maximum index value"
             msg = f"`offset` input for `with_row_index` cannot be {issue}, got {offset}"
@@ -5881,6 +6091,72 @@ class DataFrame:
             group_by=group_by,
         )
 
+    @unstable()
+    def group_by_hierarchy(
+        self,
+        by: IntoExpr | Iterable[IntoExpr],
+        *,
+        agg: IntoExpr | Iterable[IntoExpr],
+        level: str = "level",
+    ) -> DataFrame:
+        """
+        Aggregate by every level of the hierarchy of the keys, with subtotal rows.
+
+        For the keys `[region, country, city]` this gives the groups of
+        `(region, country, city)`, `(region, country)`, `(region)` and the grand
+        total. The keys below the level of a row are null.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        by
+            The keys, from the top of the hierarchy down.
+        agg
+            Aggregations to compute for every group.
+        level
+            Name of a `UInt32` column, added after the keys, with the number of keys
+            that are grouped in the row: `0` for the grand total up to the number of
+            keys for the finest groups.
+
+        See Also
+        --------
+        LazyFrame.group_by_rollup
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "region": ["eu", "eu", "eu", "us"],
+        ...         "country": ["nl", "nl", "de", "us"],
+        ...         "sales": [10, 20, 30, 40],
+        ...     }
+        ... )
+        >>> df.group_by_hierarchy(
+        ...     ["region", "country"], agg=pl.col("sales").sum()
+        ... ).sort("level", "region", "country")
+        shape: (6, 4)
+        ┌────────┬─────────┬───────┬───────┐
+        │ region ┆ country ┆ level ┆ sales │
+        │ ---    ┆ ---     ┆ ---   ┆ ---   │
+        │ str    ┆ str     ┆ u32   ┆ i64   │
+        ╞════════╪═════════╪═══════╪═══════╡
+        │ null   ┆ null    ┆ 0     ┆ 100   │
+        │ eu     ┆ null    ┆ 1     ┆ 60    │
+        │ us     ┆ null    ┆ 1     ┆ 40    │
+        │ eu     ┆ de      ┆ 2     ┆ 30    │
+        │ eu     ┆ nl      ┆ 2     ┆ 30    │
+        │ us     ┆ us      ┆ 2     ┆ 40    │
+        └────────┴─────────┴───────┴───────┘
+        """
+        return (
+            self.lazy()
+            .group_by_hierarchy(by, agg=agg, level=level)
+            .collect(_eager=True)
+        )
+
     @deprecate_renamed_parameter("by", "group_by", version="0.20.14")
     def group_by_dynamic(
         self,
@@ -6010,6 +6286,10 @@ class DataFrame:
            - 1mo   (1 calendar month)
            - 1q    (1 calendar quarter)
            - 1y    (1 calendar year)
+           - 1bme  (1 business month end)
+           - 1qs   (1 calendar quarter, same as 1q)
+           - 1w_sun (1 calendar week starting on Sunday, likewise for
+             w_mon, w_tue, ..., w_sat)
            - 1i    (1 index count)
 
            Or combine them:
@@ -6019,6 +6299,10 @@ class DataFrame:
            not be 24 hours, due to daylight savings). Similarly for "calendar week",
            "calendar month", "calendar quarter", and "calendar year".
 
+           The anchored frequencies `bme`, `qs` and `w_mon` to `w_sun` cannot be
+           combined with other units. Custom frequencies can be registered with
+           :func:`register_frequency`.
+
            In case of a group_by_dynamic on an integer column, the windows are defined by:
 
            - "1i"      # length 1
@@ -6223,6 +6507,10 @@ class DataFrame:
         - 1mo   (1 calendar month)
         - 1q    (1 calendar quarter)
         - 1y    (1 calendar year)
+        - 1bme  (1 business month end)
+        - 1qs   (1 calendar quarter, same as 1q)
+        - 1w_sun (1 calendar week starting on Sunday, likewise for
+          w_mon, w_tue, ..., w_sat)
         - 1i    (1 index count)
 
         Or combine them:
@@ -6234,6 +6522,10 @@ class DataFrame:
         not be 24 hours, due to daylight savings). Similarly for "calendar week",
         "calendar month", "calendar quarter", and "calendar year".
 
+        The anchored frequencies `bme`, `qs` and `w_mon` to `w_sun` cannot be
+        combined with other units. Custom frequencies can be registered with
+        :func:`register_frequency`.
+
         Parameters
         ----------
         time_column
@@ -6310,6 +6602,7 @@ class DataFrame:
         by: str | Sequence[str] | None = None,
         strategy: AsofJoinStrategy = "backward",
         suffix: str = "_right",
+        naming: str | None = None,
         tolerance: str | int | float | timedelta | None = None,
         allow_parallel: bool = True,
         force_parallel: bool = False,
@@ -6358,6 +6651,11 @@ class DataFrame:
             Join strategy.
         suffix
             Suffix to append to columns with a duplicate name.
+        naming
+            How to name the columns of `other` that have the name of a column of this
+            frame, instead of appending `suffix`. Either a template in which `{name}` is
+            replaced by the name of the column and `{side}` by `right`, e.g.
+            `"{side}_{name}"`, or `"raise"` to raise an error for these columns.
         tolerance
             Numeric tolerance. By setting this the join will only be done if the near
             keys are within this distance. If an asof join is done on columns of dtype
@@ -6613,6 +6911,7 @@ class DataFrame:
                 by=by,
                 strategy=strategy,
                 suffix=suffix,
+                naming=naming,
                 tolerance=tolerance,
                 allow_parallel=allow_parallel,
                 force_parallel=force_parallel,
@@ -6630,6 +6929,7 @@ class DataFrame:
         left_on: str | Expr | Sequence[str | Expr] | None = None,
         right_on: str | Expr | Sequence[str | Expr] | None = None,
         suffix: str = "_right",
+        naming: str | None = None,
         validate: JoinValidation = "m:m",
         join_nulls: bool = False,
         coalesce: bool | None = None,
@@ -6668,6 +6968,11 @@ class DataFrame:
             Name(s) of the right join column(s).
         suffix
             Suffix to append to columns with a duplicate name.
+        naming
+            How to name the columns of `other` that have the name of a column of this
+            frame, instead of appending `suffix`. Either a template in which `{name}` is
+            replaced by the name of the column and `{side}` by `right`, e.g.
+            `"{side}_{name}"`, or `"raise"` to raise an error for these columns.
         validate: {'m:m', 'm:1', '1:m', '1:1'}
             Checks if join is of specified type.
 
@@ -6792,6 +7097,7 @@ class DataFrame:
                 on=on,
                 how=how,
                 suffix=suffix,
+                naming=naming,
                 validate=validate,
                 join_nulls=join_nulls,
                 coalesce=coalesce,
@@ -6799,6 +7105,88 @@ class DataFrame:
             .collect(_eager=True)
         )
 
+    @unstable()
+    def join_where(
+        self,
+        other: DataFrame,
+        *predicates: Expr | Iterable[Expr],
+        suffix: str = "_right",
+        naming: str | None = None,
+    ) -> DataFrame:
+        """
+        Perform a join based on one or multiple (in)equality predicates.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        This performs an inner join, so only rows where all predicates are true
+        are included in the result, and a row from either frame may be included
+        multiple times in the result. See :meth:`LazyFrame.join_where` for how
+        the predicates are evaluated.
+
+        Parameters
+        ----------
+        other
+            DataFrame to join with.
+        *predicates
+            (In)Equality condition to join the two frames on. Columns of
+            `other` are referred to by their name, or by their name after the join
+            (see `suffix` and `naming`) if this frame has a column with the same name.
+        suffix
+            Suffix to append to columns with a duplicate name.
+        naming
+            How to name the columns of `other` that have the name of a column of this
+            frame, instead of appending `suffix`. Either a template in which `{name}` is
+            replaced by the name of the column and `{side}` by `right`, e.g.
+            `"{side}_{name}"`, or `"raise"` to raise an error for these columns.
+
+        Examples
+        --------
+        >>> east = pl.DataFrame(
+        ...     {
+        ...         "id": [100, 101, 102],
+        ...         "dur": [120, 140, 160],
+        ...         "rev": [12, 14, 16],
+        ...         "cores": [2, 8, 4],
+        ...     }
+        ... )
+        >>> west = pl.DataFrame(
+        ...     {
+        ...         "t_id": [404, 498, 676, 742],
+        ...         "time": [90, 130, 150, 170],
+        ...         "cost": [9, 13, 15, 16],
+        ...         "cores": [4, 2, 1, 4],
+        ...     }
+        ... )
+        >>> east.join_where(
+        ...     west,
+        ...     pl.col("dur") < pl.col("time"),
+        ...     pl.col("rev") < pl.col("cost"),
+        ... )
+        shape: (5, 8)
+        ┌─────┬─────┬─────┬───────┬──────┬──────┬──────┬─────────────┐
+        │ id  ┆ dur ┆ rev ┆ cores ┆ t_id ┆ time ┆ cost ┆ cores_right │
+        │ --- ┆ --- ┆ --- ┆ ---   ┆ ---  ┆ ---  ┆ ---  ┆ ---         │
+        │ i64 ┆ i64 ┆ i64 ┆ i64   ┆ i64  ┆ i64  ┆ i64  ┆ i64         │
+        ╞═════╪═════╪═════╪═══════╪══════╪══════╪══════╪═════════════╡
+        │ 100 ┆ 120 ┆ 12  ┆ 2     ┆ 498  ┆ 130  ┆ 13   ┆ 2           │
+        │ 100 ┆ 120 ┆ 12  ┆ 2     ┆ 676  ┆ 150  ┆ 15   ┆ 1           │
+        │ 100 ┆ 120 ┆ 12  ┆ 2     ┆ 742  ┆ 170  ┆ 16   ┆ 4           │
+        │ 101 ┆ 140 ┆ 14  ┆ 8     ┆ 676  ┆ 150  ┆ 15   ┆ 1           │
+        │ 101 ┆ 140 ┆ 14  ┆ 8     ┆ 742  ┆ 170  ┆ 16   ┆ 4           │
+        └─────┴─────┴─────┴───────┴──────┴──────┴──────┴─────────────┘
+        """
+        if not isinstance(other, DataFrame):
+            msg = f"expected `other` join table to be a DataFrame, got {type(other).__name__!r}"
+            raise TypeError(msg)
+
+        return (
+            self.lazy()
+            .join_where(other.lazy(), *predicates, suffix=suffix, naming=naming)
+            .collect(_eager=True)
+        )
+
     def map_rows(
         self,
         function: Callable[[tuple[Any, ...]], Any],
@@ -7694,8 +8082,8 @@ class DataFrame:
         """
         Create a spreadsheet-style pivot table as a DataFrame.
 
-        Only available in eager mode. See "Examples" section below for how to do a
-        "lazy pivot" if you know the unique column values in advance.
+        See :meth:`LazyFrame.pivot` for a lazy pivot, which can be optimized if you
+        know the unique column values in advance.
 
         Parameters
         ----------
@@ -7837,9 +8225,9 @@ class DataFrame:
         │ b    ┆ 0.964028 ┆ 0.999954 │
         └──────┴──────────┴──────────┘
 
-        Note that `pivot` is only available in eager mode. If you know the unique
-        column values in advance, you can use :meth:`polars.LazyFrame.group_by` to
-        get the same result as above in lazy mode:
+        If you know the unique column values in advance, you can also use
+        :meth:`polars.LazyFrame.group_by` to get the same result as above in lazy mode,
+        which is what :meth:`polars.LazyFrame.pivot` does:
 
         >>> index = pl.col("col1")
         >>> on = pl.col("col2")
@@ -8374,10 +8762,27 @@ class DataFrame:
         """
         return self.lazy().shift(n, fill_value=fill_value).collect(_eager=True)
 
-    def is_duplicated(self) -> Series:
+    def is_duplicated(
+        self,
+        subset: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
+        *,
+        keep: DuplicateKeepStrategy = "none",
+    ) -> Series:
         """
         Get a mask of all duplicated rows in this DataFrame.
 
+        Parameters
+        ----------
+        subset
+            Column name(s) or selector(s), to consider when identifying
+            duplicate rows. If set to `None` (default), use all columns.
+        keep : {'none', 'first', 'last'}
+            Which row of a group of duplicate rows is not marked as duplicated.
+
+            * 'none': Mark all duplicate rows.
+            * 'first': Do not mark the first occurrence of each row.
+            * 'last': Do not mark the last occurrence of each row.
+
         Examples
         --------
         >>> df = pl.DataFrame(
@@ -8408,8 +8813,83 @@ class DataFrame:
         │ 1   ┆ x   │
         │ 1   ┆ x   │
         └─────┴─────┘
+
+        Mark only the repeated occurrences of a subset of the columns:
+
+        >>> df.is_duplicated(subset="a", keep="first")
+        shape: (4,)
+        Series: '' [bool]
+        [
+                false
+                false
+                false
+                true
+        ]
+        """
+        if subset is not None:
+            subset = _expand_selectors(self, subset)
+        return wrap_s(self._df.is_duplicated(subset, keep))
+
+    @unstable()
+    def duplicates(
+        self,
+        subset: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
+    ) -> DataFrame:
         """
-        return wrap_s(self._df.is_duplicated())
+        Report the groups of duplicate rows in this DataFrame.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Every group of equal rows that occurs more than once is returned with the
+        number of rows in the group and the indices of its first and last row, in
+        order of the first row.
+
+        Parameters
+        ----------
+        subset
+            Column name(s) or selector(s), to consider when identifying
+            duplicate rows. If set to `None` (default), use all columns.
+
+        Returns
+        -------
+        DataFrame
+            The `subset` columns, followed by the columns `count`, `first_index` and
+            `last_index`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [1, 2, 1, 3, 1, 2],
+        ...         "b": ["x", "y", "x", "z", "w", "y"],
+        ...     }
+        ... )
+        >>> df.duplicates()
+        shape: (2, 5)
+        ┌─────┬─────┬───────┬─────────────┬────────────┐
+        │ a   ┆ b   ┆ count ┆ first_index ┆ last_index │
+        │ --- ┆ --- ┆ ---   ┆ ---         ┆ ---        │
+        │ i64 ┆ str ┆ u32   ┆ u32         ┆ u32        │
+        ╞═════╪═════╪═══════╪═════════════╪════════════╡
+        │ 1   ┆ x   ┆ 2     ┆ 0           ┆ 2          │
+        │ 2   ┆ y   ┆ 2     ┆ 1           ┆ 5          │
+        └─────┴─────┴───────┴─────────────┴────────────┘
+        >>> df.duplicates("a")
+        shape: (2, 4)
+        ┌─────┬───────┬─────────────┬────────────┐
+        │ a   ┆ count ┆ first_index ┆ last_index │
+        │ --- ┆ ---   ┆ ---         ┆ ---        │
+        │ i64 ┆ u32   ┆ u32         ┆ u32        │
+        ╞═════╪═══════╪═════════════╪════════════╡
+        │ 1   ┆ 3     ┆ 0           ┆ 4          │
+        │ 2   ┆ 2     ┆ 1           ┆ 5          │
+        └─────┴───────┴─────────────┴────────────┘
+        """
+        if subset is not None:
+            subset = _expand_selectors(self, subset)
+        return self._from_pydf(self._df.duplicates(subset))
 
     def is_unique(self) -> Series:
         """
@@ -8796,6 +9276,58 @@ class DataFrame:
         """
         return self.lazy().with_columns_seq(*exprs, **named_exprs).collect(_eager=True)
 
+    @unstable()
+    def set_where(
+        self,
+        mask: IntoExpr,
+        values: Mapping[str, IntoExpr] | None = None,
+        **named_values: IntoExpr,
+    ) -> DataFrame:
+        """
+        Set the values of columns in the rows where a mask is true.
+
+        The mask is evaluated once for all columns, which makes this cheaper than a
+        `when/then/otherwise` expression per column that repeats the mask.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        mask
+            Boolean expression that selects the rows to update. Rows where the mask is
+            false or null keep their values.
+        values
+            Mapping of the names of the columns to update to their new values.
+            Accepts expression input. Strings are parsed as column names, other
+            non-expression inputs are parsed as literals.
+        **named_values
+            Additional columns to update, specified as keyword arguments.
+
+        See Also
+        --------
+        LazyFrame.set_where
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+        >>> df.set_where(
+        ...     pl.col("a") >= 2, {"a": 0, "b": pl.col("b").str.to_uppercase()}
+        ... )
+        shape: (3, 2)
+        ┌─────┬─────┐
+        │ a   ┆ b   │
+        │ --- ┆ --- │
+        │ i64 ┆ str │
+        ╞═════╪═════╡
+        │ 1   ┆ x   │
+        │ 0   ┆ Y   │
+        │ 0   ┆ Z   │
+        └─────┴─────┘
+        """
+        return self.lazy().set_where(mask, values, **named_values).collect(_eager=True)
+
     @overload
     def n_chunks(self, strategy: Literal["first"] = ...) -> int: ...
 
@@ -9508,14 +10040,33 @@ class DataFrame:
         """
         return self.lazy().approx_n_unique().collect(_eager=True)
 
-    def rechunk(self) -> DataFrame:
+    def rechunk(self, rows_per_chunk: int | None = None) -> DataFrame:
         """
         Rechunk the data in this DataFrame to a contiguous allocation.
 
         This will make sure all subsequent operations have optimal and predictable
         performance.
+
+        Parameters
+        ----------
+        rows_per_chunk
+            Instead of a single chunk, split the data in chunks of exactly this many
+            rows, of which only the last may be shorter. The chunks of all columns are
+            aligned, so :meth:`to_arrow` and the Arrow C stream interface produce
+            record batches of this length, e.g. for a Flight server or a writer that
+            needs batches of a fixed size.
+
+        Examples
+        --------
+        >>> df1, df2 = pl.DataFrame({"a": [1, 2]}), pl.DataFrame({"a": [3, 4, 5]})
+        >>> df = pl.concat([df1, df2])
+        >>> df.n_chunks()
+        2
+        >>> table = df.rechunk(rows_per_chunk=2).to_arrow()
+        >>> [batch.num_rows for batch in table.to_batches()]
+        [2, 2, 1]
         """
-        return self._from_pydf(self._df.rechunk())
+        return self._from_pydf(self._df.rechunk(rows_per_chunk))
 
     def null_count(self) -> DataFrame:
         """
@@ -10833,6 +11384,13 @@ class DataFrame:
         )
 
 
+def _check_write_mode(write_mode: WriteMode, file: Any) -> None:
+    # only files that are written through a path can be checked and moved into place
+    if write_mode != "overwrite" and not isinstance(file, str):
+        msg = f"`write_mode={write_mode!r}` requires `file` to be a path"
+        raise TypeError(msg)
+
+
 def _prepare_other_arg(other: Any, length: int | None = None) -> Series:
     # if not a series create singleton series such that it will broadcast
     value = other
@@ -12,6 +12,7 @@ from polars.datatypes.classes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -19,6 +20,7 @@ from polars.datatypes.classes import (
     Int32,
     Int64,
     IntegerType,
+    Json,
     List,
     Null,
     Object,
@@ -68,6 +70,7 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
     "Int16",
@@ -75,6 +78,7 @@ __all__ = [
     "Int64",
     "Int8",
     "IntegerType",
+    "Json",
     "List",
     "Null",
     "Object",
@@ -241,6 +241,14 @@ class UInt64(UnsignedIntegerType):
     """64-bit unsigned integer type."""
 
 
+class Float16(FloatType):
+    """
+    16-bit floating point type.
+
+    Arithmetic and aggregations are computed in `Float32`.
+    """
+
+
 class Float32(FloatType):
     """32-bit floating point type."""
 
@@ -321,6 +329,16 @@ class Binary(DataType):
     """Binary type."""
 
 
+class Json(DataType):
+    """
+    Data type for JSON values.
+
+    Values are stored in a normalized form: object keys are sorted and whitespace
+    is dropped, so equal JSON values compare equal. Casting a `String` column to
+    `Json` parses it, text that is not valid JSON becomes null.
+    """
+
+
 class Date(TemporalType):
     """
     Data type representing a calendar date.
@@ -20,12 +20,14 @@ from polars.datatypes.classes import (
     Decimal,
     Duration,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
     Int16,
     Int32,
     Int64,
+    Json,
     List,
     Null,
     Object,
@@ -142,6 +144,7 @@ class _DataTypeMappings:
             UInt16: "u16",
             UInt32: "u32",
             UInt64: "u64",
+            Float16: "f32",  # scalars are passed as f32
             Float32: "f32",
             Float64: "f64",
             Decimal: "decimal",
@@ -164,6 +167,7 @@ class _DataTypeMappings:
         return {
             Float64: float,
             Float32: float,
+            Float16: float,
             Int64: int,
             Int32: int,
             Int16: int,
@@ -180,6 +184,7 @@ class _DataTypeMappings:
             Date: date,
             Time: time,
             Binary: bytes,
+            Json: str,
             List: list,
             Array: list,
             Null: None.__class__,
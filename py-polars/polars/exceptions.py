@@ -1,5 +1,6 @@
 try:
     from polars.polars import (
+        CancelledError,
         CategoricalRemappingWarning,
         ColumnNotFoundError,
         ComputeError,
@@ -26,6 +27,9 @@ except ImportError:
     class PolarsError(Exception):  # type: ignore[no-redef]
         """Base class for all Polars errors."""
 
+    class CancelledError(PolarsError):  # type: ignore[no-redef, misc]
+        """Exception raised when a query is cancelled through a `CancellationToken`."""
+
     class ColumnNotFoundError(PolarsError):  # type: ignore[no-redef, misc]
         """
         Exception raised when a specified column is not found.
@@ -196,6 +200,7 @@ class UnstableWarning(PolarsWarning):  # type: ignore[misc]
 __all__ = [
     # Errors
     "PolarsError",
+    "CancelledError",
     "ColumnNotFoundError",
     "ComputeError",
     "DuplicateError",
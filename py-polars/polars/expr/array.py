@@ -3,6 +3,7 @@ from __future__ import annotations
 from typing import TYPE_CHECKING, Callable, Sequence
 
 from polars._utils.parse import parse_into_expression
+from polars._utils.unstable import unstable
 from polars._utils.wrap import wrap_expr
 
 if TYPE_CHECKING:
@@ -158,6 +159,259 @@ class ExprArrayNameSpace:
         """
         return wrap_expr(self._pyexpr.arr_median())
 
+    @unstable()
+    def sum_elementwise(self) -> Expr:
+        """
+        Sum the arrays of all rows element-wise into a single array.
+
+        This is an aggregation: in a group by context it produces one array per
+        group. Null values are skipped; a position without any values sums to zero.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        See Also
+        --------
+        mean_elementwise
+        sum : Sum the values within every array.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"g": ["a", "a", "b"], "vec": [[1, 2], [3, 4], [5, 6]]},
+        ...     schema={"g": pl.String, "vec": pl.Array(pl.Int64, 2)},
+        ... )
+        >>> df.group_by("g", maintain_order=True).agg(
+        ...     pl.col("vec").arr.sum_elementwise()
+        ... )
+        shape: (2, 2)
+        ┌─────┬───────────────┐
+        │ g   ┆ vec           │
+        │ --- ┆ ---           │
+        │ str ┆ array[i64, 2] │
+        ╞═════╪═══════════════╡
+        │ a   ┆ [4, 6]        │
+        │ b   ┆ [5, 6]        │
+        └─────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_sum_elementwise())
+
+    @unstable()
+    def mean_elementwise(self) -> Expr:
+        """
+        Average the arrays of all rows element-wise into a single array.
+
+        This is an aggregation: in a group by context it produces one array per
+        group, e.g. the centroid of the embeddings of every group. Null values are
+        skipped; a position without any values is null.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        See Also
+        --------
+        sum_elementwise
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"g": ["a", "a", "b"], "vec": [[1, 2], [3, 4], [5, 6]]},
+        ...     schema={"g": pl.String, "vec": pl.Array(pl.Int64, 2)},
+        ... )
+        >>> df.group_by("g", maintain_order=True).agg(
+        ...     pl.col("vec").arr.mean_elementwise()
+        ... )
+        shape: (2, 2)
+        ┌─────┬───────────────┐
+        │ g   ┆ vec           │
+        │ --- ┆ ---           │
+        │ str ┆ array[f64, 2] │
+        ╞═════╪═══════════════╡
+        │ a   ┆ [2.0, 3.0]    │
+        │ b   ┆ [5.0, 6.0]    │
+        └─────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_mean_elementwise())
+
+    @unstable()
+    def dot(self, other: IntoExpr) -> Expr:
+        """
+        Compute the dot product of the arrays of every row with the arrays of `other`.
+
+        The arrays must be one-dimensional arrays of numbers of the same width. A
+        single array, e.g. a literal, is used for every row. A row with a null is
+        null. The result is :class:`Float32` if both arrays are :class:`Float32` and
+        :class:`Float64` otherwise.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Arrays to multiply with. Accepts expression input, strings are parsed as
+            column names.
+
+        See Also
+        --------
+        cosine_similarity
+        matmul
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1.0, 2.0], [3.0, 4.0]], "b": [[1.0, 1.0], [0.0, 2.0]]},
+        ...     schema={"a": pl.Array(pl.Float64, 2), "b": pl.Array(pl.Float64, 2)},
+        ... )
+        >>> df.with_columns(dot=pl.col("a").arr.dot("b"))
+        shape: (2, 3)
+        ┌───────────────┬───────────────┬─────┐
+        │ a             ┆ b             ┆ dot │
+        │ ---           ┆ ---           ┆ --- │
+        │ array[f64, 2] ┆ array[f64, 2] ┆ f64 │
+        ╞═══════════════╪═══════════════╪═════╡
+        │ [1.0, 2.0]    ┆ [1.0, 1.0]    ┆ 3.0 │
+        │ [3.0, 4.0]    ┆ [0.0, 2.0]    ┆ 8.0 │
+        └───────────────┴───────────────┴─────┘
+        """
+        other = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.arr_dot(other))
+
+    @unstable()
+    def l2_norm(self) -> Expr:
+        """
+        Compute the euclidean norm of every array.
+
+        The arrays must be one-dimensional arrays of numbers. An array with a null
+        is null.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[3.0, 4.0], [1.0, 0.0]]},
+        ...     schema={"a": pl.Array(pl.Float64, 2)},
+        ... )
+        >>> df.with_columns(norm=pl.col("a").arr.l2_norm())
+        shape: (2, 2)
+        ┌───────────────┬──────┐
+        │ a             ┆ norm │
+        │ ---           ┆ ---  │
+        │ array[f64, 2] ┆ f64  │
+        ╞═══════════════╪══════╡
+        │ [3.0, 4.0]    ┆ 5.0  │
+        │ [1.0, 0.0]    ┆ 1.0  │
+        └───────────────┴──────┘
+
+        Dividing by the norm scales the arrays to unit length.
+
+        >>> df.select(pl.col("a") / pl.col("a").arr.l2_norm())
+        shape: (2, 1)
+        ┌───────────────┐
+        │ a             │
+        │ ---           │
+        │ array[f64, 2] │
+        ╞═══════════════╡
+        │ [0.6, 0.8]    │
+        │ [1.0, 0.0]    │
+        └───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_l2_norm())
+
+    @unstable()
+    def cosine_similarity(self, other: IntoExpr) -> Expr:
+        """
+        Compute the cosine similarity of the arrays of every row with those of `other`.
+
+        The arrays must be one-dimensional arrays of numbers of the same width. A
+        single array, e.g. a literal, is used for every row. A row with a null is
+        null, and the similarity with an array of zeros is NaN.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Arrays to compare with. Accepts expression input, strings are parsed as
+            column names.
+
+        See Also
+        --------
+        dot
+
+        Examples
+        --------
+        Find the embeddings that are most similar to a query.
+
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1.0, 0.0], [1.0, 1.0], [0.0, 2.0]]},
+        ...     schema={"a": pl.Array(pl.Float64, 2)},
+        ... )
+        >>> query = pl.Series([[1.0, 0.0]], dtype=pl.Array(pl.Float64, 2))
+        >>> df.with_columns(similarity=pl.col("a").arr.cosine_similarity(pl.lit(query)))
+        shape: (3, 2)
+        ┌───────────────┬────────────┐
+        │ a             ┆ similarity │
+        │ ---           ┆ ---        │
+        │ array[f64, 2] ┆ f64        │
+        ╞═══════════════╪════════════╡
+        │ [1.0, 0.0]    ┆ 1.0        │
+        │ [1.0, 1.0]    ┆ 0.707107   │
+        │ [0.0, 2.0]    ┆ 0.0        │
+        └───────────────┴────────────┘
+        """
+        other = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.arr_cosine_similarity(other))
+
+    @unstable()
+    def matmul(self, other: IntoExpr) -> Expr:
+        """
+        Compute the matrix product of the arrays of every row with those of `other`.
+
+        Two-dimensional arrays are matrices and one-dimensional arrays are vectors,
+        like for :func:`numpy.matmul`. A single array, e.g. a literal, is used for
+        every row. Use :meth:`dot` for the product of two vectors.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Arrays to multiply with. Accepts expression input, strings are parsed as
+            column names.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"m": [[[1.0, 2.0], [3.0, 4.0]]], "v": [[1.0, 1.0]]},
+        ...     schema={
+        ...         "m": pl.Array(pl.Float64, (2, 2)),
+        ...         "v": pl.Array(pl.Float64, 2),
+        ...     },
+        ... )
+        >>> df.select(pl.col("m").arr.matmul("v"))
+        shape: (1, 1)
+        ┌───────────────┐
+        │ m             │
+        │ ---           │
+        │ array[f64, 2] │
+        ╞═══════════════╡
+        │ [3.0, 7.0]    │
+        └───────────────┘
+        """
+        other = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.arr_matmul(other))
+
     def unique(self, *, maintain_order: bool = False) -> Expr:
         """
         Get the unique/distinct values in the array.
@@ -606,6 +860,73 @@ class ExprArrayNameSpace:
         """
         return wrap_expr(self._pyexpr.explode())
 
+    def explode_to_rows(self) -> Expr:
+        """
+        Explode the outer dimension of the arrays into rows.
+
+        Contrary to :meth:`explode`, multi-dimensional arrays keep their remaining
+        dimensions as :class:`Array`. Every array produces exactly `width` rows, so
+        a null array results in `width` null rows.
+
+        Returns
+        -------
+        Expr
+            Expression with the data type of the array elements.
+
+        See Also
+        --------
+        Expr.arr.reshape
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]},
+        ...     schema={"a": pl.Array(pl.Int64, (2, 2))},
+        ... )
+        >>> df.select(pl.col("a").arr.explode_to_rows())
+        shape: (4, 1)
+        ┌───────────────┐
+        │ a             │
+        │ ---           │
+        │ array[i64, 2] │
+        ╞═══════════════╡
+        │ [1, 2]        │
+        │ [3, 4]        │
+        │ [5, 6]        │
+        │ [7, 8]        │
+        └───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_explode_to_rows())
+
+    def reshape(self, dimensions: tuple[int, ...]) -> Expr:
+        """
+        Reshape every array into the given dimensions, keeping the number of rows.
+
+        The resulting shape is known from the schema, so this also works lazily.
+
+        Parameters
+        ----------
+        dimensions
+            Tuple of the dimension sizes of every array. A single dimension may be
+            -1, it is inferred from the width of the arrays.
+
+        See Also
+        --------
+        Expr.reshape : Reshape a whole column.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12]]},
+        ...     schema={"a": pl.Array(pl.Int64, 6)},
+        ... )
+        >>> df.lazy().select(pl.col("a").arr.reshape((-1, 2))).collect_schema()
+        Schema({'a': Array(Int64, shape=(3, 2))})
+        >>> df.select(pl.col("a").arr.reshape((2, 3))).to_series().to_list()
+        [[[1, 2, 3], [4, 5, 6]], [[7, 8, 9], [10, 11, 12]]]
+        """
+        return wrap_expr(self._pyexpr.arr_reshape(dimensions))
+
     def contains(
         self, item: float | str | bool | int | date | datetime | time | IntoExprColumn
     ) -> Expr:
@@ -150,7 +150,12 @@ class ExprDateTimeNameSpace:
             )
         )
 
-    def truncate(self, every: str | dt.timedelta | Expr) -> Expr:
+    def truncate(
+        self,
+        every: str | dt.timedelta | Expr,
+        *,
+        offset: str | dt.timedelta | None = None,
+    ) -> Expr:
         """
         Divide the date/datetime range into buckets.
 
@@ -165,6 +170,11 @@ class ExprDateTimeNameSpace:
         ----------
         every
             Every interval start and period length
+        offset
+            Shift the buckets by this duration, in local time like the `offset` of
+            :meth:`DataFrame.group_by_dynamic`. For example, `every="15m"` and
+            `offset="5m"` give buckets starting at 5, 20, 35 and 50 minutes past the
+            hour.
 
         Notes
         -----
@@ -182,6 +192,10 @@ class ExprDateTimeNameSpace:
         - 1mo   (1 calendar month)
         - 1q    (1 calendar quarter)
         - 1y    (1 calendar year)
+        - 1bme  (1 business month end)
+        - 1qs   (1 calendar quarter, same as 1q)
+        - 1w_sun (1 calendar week starting on Sunday, likewise for
+          w_mon, w_tue, ..., w_sat)
 
         These strings can be combined:
 
@@ -192,6 +206,10 @@ class ExprDateTimeNameSpace:
         not be 24 hours, due to daylight savings). Similarly for "calendar week",
         "calendar month", "calendar quarter", and "calendar year".
 
+        The anchored frequencies `bme`, `qs` and `w_mon` to `w_sun` cannot be
+        combined with other units. Custom frequencies can be registered with
+        :func:`register_frequency`.
+
         Returns
         -------
         Expr
@@ -269,15 +287,45 @@ class ExprDateTimeNameSpace:
         │ 2001-01-01 00:50:00 ┆ 2001-01-01 00:30:00 │
         │ 2001-01-01 01:00:00 ┆ 2001-01-01 01:00:00 │
         └─────────────────────┴─────────────────────┘
+
+        Shift the buckets with `offset`, here to start 5 minutes past the quarter:
+
+        >>> df.select(
+        ...     "datetime",
+        ...     pl.col("datetime").dt.truncate("15m", offset="5m").alias("truncate"),
+        ... )
+        shape: (7, 2)
+        ┌─────────────────────┬─────────────────────┐
+        │ datetime            ┆ truncate            │
+        │ ---                 ┆ ---                 │
+        │ datetime[μs]        ┆ datetime[μs]        │
+        ╞═════════════════════╪═════════════════════╡
+        │ 2001-01-01 00:00:00 ┆ 2000-12-31 23:50:00 │
+        │ 2001-01-01 00:10:00 ┆ 2001-01-01 00:05:00 │
+        │ 2001-01-01 00:20:00 ┆ 2001-01-01 00:20:00 │
+        │ 2001-01-01 00:30:00 ┆ 2001-01-01 00:20:00 │
+        │ 2001-01-01 00:40:00 ┆ 2001-01-01 00:35:00 │
+        │ 2001-01-01 00:50:00 ┆ 2001-01-01 00:50:00 │
+        │ 2001-01-01 01:00:00 ┆ 2001-01-01 00:50:00 │
+        └─────────────────────┴─────────────────────┘
         """
         if not isinstance(every, pl.Expr):
             every = parse_as_duration_string(every)
 
         every = parse_into_expression(every, str_as_lit=True)
-        return wrap_expr(self._pyexpr.dt_truncate(every))
+        if offset is not None:
+            offset = parse_into_expression(
+                parse_as_duration_string(offset), str_as_lit=True
+            )
+        return wrap_expr(self._pyexpr.dt_truncate(every, offset))
 
     @unstable()
-    def round(self, every: str | dt.timedelta | IntoExprColumn) -> Expr:
+    def round(
+        self,
+        every: str | dt.timedelta | IntoExprColumn,
+        *,
+        offset: str | dt.timedelta | None = None,
+    ) -> Expr:
         """
         Divide the date/datetime range into buckets.
 
@@ -298,6 +346,8 @@ class ExprDateTimeNameSpace:
         ----------
         every
             Every interval start and period length
+        offset
+            Shift the buckets by this duration, see :meth:`truncate`.
 
         Returns
         -------
@@ -382,7 +432,11 @@ class ExprDateTimeNameSpace:
         if isinstance(every, dt.timedelta):
             every = parse_as_duration_string(every)
         every = parse_into_expression(every, str_as_lit=True)
-        return wrap_expr(self._pyexpr.dt_round(every))
+        if offset is not None:
+            offset = parse_into_expression(
+                parse_as_duration_string(offset), str_as_lit=True
+            )
+        return wrap_expr(self._pyexpr.dt_round(every, offset))
 
     def combine(self, time: dt.time | Expr, time_unit: TimeUnit = "us") -> Expr:
         """
@@ -16,6 +16,7 @@ from typing import (
     Collection,
     FrozenSet,
     Iterable,
+    Literal,
     Mapping,
     NoReturn,
     Sequence,
@@ -54,11 +55,15 @@ from polars.expr.array import ExprArrayNameSpace
 from polars.expr.binary import ExprBinaryNameSpace
 from polars.expr.categorical import ExprCatNameSpace
 from polars.expr.datetime import ExprDateTimeNameSpace
+from polars.expr.json import ExprJsonNameSpace
 from polars.expr.list import ExprListNameSpace
 from polars.expr.meta import ExprMetaNameSpace
 from polars.expr.name import ExprNameNameSpace
+from polars.expr.scale import ExprScaleNameSpace
+from polars.expr.stats import ExprStatsNameSpace
 from polars.expr.string import ExprStringNameSpace
 from polars.expr.struct import ExprStructNameSpace
+from polars.expr.ts import ExprTimeSeriesNameSpace
 from polars.meta import thread_pool_size
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -74,7 +79,9 @@ if TYPE_CHECKING:
     from polars import DataFrame, LazyFrame, Series
     from polars._typing import (
         ClosedInterval,
+        DuplicateKeepStrategy,
         FillNullStrategy,
+        HistBinStrategy,
         InterpolationMethod,
         IntoExpr,
         IntoExprColumn,
@@ -84,6 +91,7 @@ if TYPE_CHECKING:
         PolarsDataType,
         RankMethod,
         RollingInterpolationMethod,
+        RoundMode,
         SearchSortedSide,
         SerializationFormat,
         TemporalLiteral,
@@ -113,12 +121,16 @@ class Expr:
         "arr",
         "cat",
         "dt",
+        "json",
         "list",
         "meta",
         "name",
         "str",
         "bin",
+        "scale",
+        "stats",
         "struct",
+        "ts",
     }
 
     @classmethod
@@ -426,6 +438,170 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.to_physical())
 
+    @unstable()
+    def is_valid_mask(self) -> Expr:
+        """
+        Get the validity of the values as a Boolean mask.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The mask is `True` where a value is valid and `False` where it is null. It
+        shares the validity buffers of the data, so no copy is made. Unlike
+        :meth:`is_not_null`, the mask of a Struct column only looks at whether the
+        struct values are null, as structs have no validity of their own.
+
+        See Also
+        --------
+        with_validity
+        is_not_null
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, None, 3]})
+        >>> df.with_columns(valid=pl.col("a").is_valid_mask())
+        shape: (3, 2)
+        ┌──────┬───────┐
+        │ a    ┆ valid │
+        │ ---  ┆ ---   │
+        │ i64  ┆ bool  │
+        ╞══════╪═══════╡
+        │ 1    ┆ true  │
+        │ null ┆ false │
+        │ 3    ┆ true  │
+        └──────┴───────┘
+        """
+        return self._from_pyexpr(self._pyexpr.is_valid_mask())
+
+    @unstable()
+    def with_validity(self, mask: IntoExpr) -> Expr:
+        """
+        Replace the validity of the values by a Boolean mask.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The result is null where the mask is `False` or null, and valid where it is
+        `True`. The values themselves are not touched: a value that was null and is
+        made valid is whatever the data holds in that slot, which is usually zero or
+        empty but not guaranteed to be.
+
+        Parameters
+        ----------
+        mask
+            Boolean expression with the new validity. A mask of length 1 is
+            broadcast.
+
+        See Also
+        --------
+        is_valid_mask
+        coalesce_validity
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3], "keep": [True, False, True]})
+        >>> df.with_columns(pl.col("a").with_validity(pl.col("keep")))
+        shape: (3, 2)
+        ┌──────┬───────┐
+        │ a    ┆ keep  │
+        │ ---  ┆ ---   │
+        │ i64  ┆ bool  │
+        ╞══════╪═══════╡
+        │ 1    ┆ true  │
+        │ null ┆ false │
+        │ 3    ┆ true  │
+        └──────┴───────┘
+        """
+        mask = parse_into_expression(mask)
+        return self._from_pyexpr(self._pyexpr.with_validity(mask))
+
+    @unstable()
+    def coalesce_validity(self, other: IntoExpr) -> Expr:
+        """
+        Set the values to null where `other` is null.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The result is only valid where both this expression and `other` are valid.
+        `other` can be of any data type; only its validity is used.
+
+        Parameters
+        ----------
+        other
+            Expression whose nulls are carried over.
+
+        See Also
+        --------
+        with_validity
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, None, 3], "b": ["x", "y", None]})
+        >>> df.with_columns(pl.col("a").coalesce_validity(pl.col("b")))
+        shape: (3, 2)
+        ┌──────┬──────┐
+        │ a    ┆ b    │
+        │ ---  ┆ ---  │
+        │ i64  ┆ str  │
+        ╞══════╪══════╡
+        │ 1    ┆ x    │
+        │ null ┆ y    │
+        │ null ┆ null │
+        └──────┴──────┘
+        """
+        other = parse_into_expression(other)
+        return self._from_pyexpr(self._pyexpr.coalesce_validity(other))
+
+    @unstable()
+    def or_else(self, fallback: IntoExpr) -> Expr:
+        """
+        Evaluate a fallback expression where evaluating this expression fails.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Errors that occur while the data is processed, such as an overflow in a
+        strict cast or an invalid regex pattern taken from a column, are caught per
+        batch: the fallback replaces the result of the whole batch in which the
+        error occurred, while the other batches keep their results. In the in-memory
+        engine, the batch is the whole frame, and in a group by context all groups
+        fall back together. Errors in the query itself, such as a missing column,
+        are not caught.
+
+        The result has the supertype of this expression and the fallback, and the
+        name of this expression.
+
+        Parameters
+        ----------
+        fallback
+            Expression to evaluate instead. Errors of the fallback are raised.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 300]})
+        >>> df.with_columns(
+        ...     b=pl.col("a")
+        ...     .cast(pl.Int8)
+        ...     .or_else(pl.col("a").cast(pl.Int8, strict=False))
+        ... )
+        shape: (2, 2)
+        ┌─────┬──────┐
+        │ a   ┆ b    │
+        │ --- ┆ ---  │
+        │ i64 ┆ i8   │
+        ╞═════╪══════╡
+        │ 1   ┆ 1    │
+        │ 300 ┆ null │
+        └─────┴──────┘
+        """
+        fallback = parse_into_expression(fallback, str_as_lit=True)
+        return self._from_pyexpr(self._pyexpr.or_else(fallback))
+
     def any(self, *, ignore_nulls: bool = True) -> Expr:
         """
         Return whether any of the values in the column are `True`.
@@ -1610,14 +1786,26 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.ceil())
 
-    def round(self, decimals: int = 0) -> Expr:
+    def round(self, decimals: int = 0, mode: RoundMode = "half_away") -> Expr:
         """
         Round underlying floating point data by `decimals` digits.
 
+        Decimal columns are rounded as well, keeping their scale. Integer columns
+        are returned unchanged.
+
         Parameters
         ----------
         decimals
             Number of decimals to round by.
+        mode : {'half_away', 'half_even'}
+            How to round values that lie exactly halfway between two candidates.
+
+            - 'half_away': round away from zero, e.g. 2.5 becomes 3.
+            - 'half_even': round to the even candidate, e.g. 2.5 becomes 2.
+              This is also known as banker's rounding.
+
+            Whether a float is a tie is decided on its exact value, so 0.35 (which
+            is stored as slightly less than 0.35) rounds to 0.3 in both modes.
 
         Examples
         --------
@@ -1634,8 +1822,73 @@ class Expr:
         │ 1.0 │
         │ 1.2 │
         └─────┘
+
+        Round halfway values to the even neighbour.
+
+        >>> df = pl.DataFrame({"a": [0.5, 1.5, 2.5, -2.5]})
+        >>> df.with_columns(pl.col("a").round(mode="half_even").alias("half_even"))
+        shape: (4, 2)
+        ┌──────┬───────────┐
+        │ a    ┆ half_even │
+        │ ---  ┆ ---       │
+        │ f64  ┆ f64       │
+        ╞══════╪═══════════╡
+        │ 0.5  ┆ 0.0       │
+        │ 1.5  ┆ 2.0       │
+        │ 2.5  ┆ 2.0       │
+        │ -2.5 ┆ -2.0      │
+        └──────┴───────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.round(decimals, mode))
+
+    @unstable()
+    def rescale(self, scale: int, mode: RoundMode = "half_away") -> Expr:
         """
-        return self._from_pyexpr(self._pyexpr.round(decimals))
+        Change the scale of Decimal data, rounding the digits that are dropped.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The number of integer digits is kept, so the precision changes by the same
+        amount as the scale. Integer columns are converted to decimals first.
+
+        Parameters
+        ----------
+        scale
+            Number of digits after the decimal point.
+        mode : {'half_away', 'half_even'}
+            How to round values that lie exactly halfway between two candidates:
+            away from zero, or to the even candidate.
+
+        Raises
+        ------
+        ComputeError
+            If a rounded value no longer fits the precision.
+
+        Examples
+        --------
+        >>> from decimal import Decimal as D
+        >>> df = pl.DataFrame(
+        ...     {"a": [D("1.125"), D("2.135"), D("-0.005")]},
+        ...     schema={"a": pl.Decimal(10, 3)},
+        ... )
+        >>> df.with_columns(
+        ...     half_away=pl.col("a").rescale(2),
+        ...     half_even=pl.col("a").rescale(2, mode="half_even"),
+        ... )
+        shape: (3, 3)
+        ┌───────────────┬──────────────┬──────────────┐
+        │ a             ┆ half_away    ┆ half_even    │
+        │ ---           ┆ ---          ┆ ---          │
+        │ decimal[10,3] ┆ decimal[9,2] ┆ decimal[9,2] │
+        ╞═══════════════╪══════════════╪══════════════╡
+        │ 1.125         ┆ 1.13         ┆ 1.12         │
+        │ 2.135         ┆ 2.14         ┆ 2.14         │
+        │ -0.005        ┆ -0.01        ┆ 0.00         │
+        └───────────────┴──────────────┴──────────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.rescale(scale, mode))
 
     def round_sig_figs(self, digits: int) -> Expr:
         """
@@ -1766,7 +2019,13 @@ class Expr:
         dtype = parse_into_dtype(dtype)
         return self._from_pyexpr(self._pyexpr.cast(dtype, strict, wrap_numerical))
 
-    def sort(self, *, descending: bool = False, nulls_last: bool = False) -> Expr:
+    def sort(
+        self,
+        *,
+        descending: bool = False,
+        nulls_last: bool = False,
+        collation: str | None = None,
+    ) -> Expr:
         """
         Sort this column.
 
@@ -1779,6 +2038,15 @@ class Expr:
             Sort in descending order.
         nulls_last
             Place null values last.
+        collation
+            Sort strings by the collation of this locale instead of by their bytes,
+            given as a BCP 47 language tag such as `"de-AT"`. The strength of the
+            collation is set with the `ks` keyword, e.g. `"de-AT-u-ks-level2"` ignores
+            case.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Examples
         --------
@@ -1843,7 +2111,9 @@ class Expr:
         │ one   ┆ [1, 2, 98] │
         └───────┴────────────┘
         """
-        return self._from_pyexpr(self._pyexpr.sort_with(descending, nulls_last))
+        return self._from_pyexpr(
+            self._pyexpr.sort_with(descending, nulls_last, collation)
+        )
 
     def top_k(self, k: int | IntoExprColumn = 5) -> Expr:
         r"""
@@ -2189,7 +2459,9 @@ class Expr:
         reverse = extend_bool(reverse, len(by), "reverse", "by")
         return self._from_pyexpr(self._pyexpr.bottom_k_by(by, k=k, reverse=reverse))
 
-    def arg_sort(self, *, descending: bool = False, nulls_last: bool = False) -> Expr:
+    def arg_sort(
+        self, *, descending: bool = False, nulls_last: bool = False, stable: bool = True
+    ) -> Expr:
         """
         Get the index values that would sort this column.
 
@@ -2199,6 +2471,10 @@ class Expr:
             Sort in descending (descending) order.
         nulls_last
             Place null values last instead of first.
+        stable
+            Whether the indices of equal values stay in ascending order, which is the
+            default. Set to `False` to allow a faster unstable sort, which notably pays
+            off for many numeric values.
 
         Returns
         -------
@@ -2244,7 +2520,7 @@ class Expr:
         │ 3   │
         └─────┘
         """
-        return self._from_pyexpr(self._pyexpr.arg_sort(descending, nulls_last))
+        return self._from_pyexpr(self._pyexpr.arg_sort(descending, nulls_last, stable))
 
     def arg_max(self) -> Expr:
         """
@@ -2343,6 +2619,8 @@ class Expr:
         nulls_last: bool | Sequence[bool] = False,
         multithreaded: bool = True,
         maintain_order: bool = False,
+        collation: str | None = None,
+        stable: bool = True,
     ) -> Expr:
         """
         Sort this column by the ordering of other columns.
@@ -2366,7 +2644,19 @@ class Expr:
         multithreaded
             Sort using multiple threads.
         maintain_order
-            Whether the order should be maintained if elements are equal.
+            Whether the order should be maintained if elements are equal. Sorting is
+            already stable unless `stable=False`.
+        collation
+            Sort string columns by the collation of this locale instead of by their
+            bytes, given as a BCP 47 language tag such as `"sv"`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        stable
+            Whether values with equal sort keys keep their order, which is the default.
+            Set to `False` to allow a faster unstable sort, which notably pays off when
+            sorting many values by a single numeric column.
 
         Examples
         --------
@@ -2473,7 +2763,13 @@ class Expr:
         nulls_last = extend_bool(nulls_last, len(by), "nulls_last", "by")
         return self._from_pyexpr(
             self._pyexpr.sort_by(
-                by, descending, nulls_last, multithreaded, maintain_order
+                by,
+                descending,
+                nulls_last,
+                multithreaded,
+                maintain_order,
+                stable,
+                collation,
             )
         )
 
@@ -2761,6 +3057,76 @@ class Expr:
                 self._pyexpr.fill_null_with_strategy(strategy, limit)
             )
 
+    @unstable()
+    def fill_null_by(
+        self,
+        by: IntoExpr,
+        max_gap: str | timedelta,
+        *,
+        strategy: Literal["forward", "backward"] = "forward",
+        limit: int | None = None,
+    ) -> Expr:
+        """
+        Fill null values with the previous or next value within a maximum gap.
+
+        A null value is only filled if the value it is filled with is at most
+        `max_gap` away in the `by` column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        by
+            Column that `max_gap` is measured in, typically a temporal column.
+        max_gap
+            Maximum distance in the `by` column between a null value and the value
+            it is filled with, as a duration string such as `"1h"` or as a
+            timedelta. Use an integer duration such as `"3i"` for an integer
+            column.
+        strategy : {'forward', 'backward'}
+            Fill with the previous or with the next non-null value.
+        limit
+            Number of consecutive null values to fill.
+
+        Notes
+        -----
+        Combined with :meth:`over`, the null values of all groups are filled in a
+        single pass. This also holds for `fill_null(strategy=...).over(...)` with
+        the 'forward' and 'backward' strategies.
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "time": [
+        ...             datetime(2024, 1, 1, 9),
+        ...             datetime(2024, 1, 1, 10),
+        ...             datetime(2024, 1, 1, 14),
+        ...         ],
+        ...         "value": [1, None, None],
+        ...     }
+        ... )
+        >>> df.with_columns(pl.col("value").fill_null_by("time", "2h"))
+        shape: (3, 2)
+        ┌─────────────────────┬───────┐
+        │ time                ┆ value │
+        │ ---                 ┆ ---   │
+        │ datetime[μs]        ┆ i64   │
+        ╞═════════════════════╪═══════╡
+        │ 2024-01-01 09:00:00 ┆ 1     │
+        │ 2024-01-01 10:00:00 ┆ 1     │
+        │ 2024-01-01 14:00:00 ┆ null  │
+        └─────────────────────┴───────┘
+        """
+        by = parse_into_expression(by)
+        max_gap = parse_as_duration_string(max_gap)
+        return self._from_pyexpr(
+            self._pyexpr.fill_null_by(by, max_gap, strategy, limit)
+        )
+
     def fill_nan(self, value: int | float | Expr | None) -> Expr:
         """
         Fill floating point NaN value with a fill value.
@@ -3196,6 +3562,181 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.approx_n_unique())
 
+    @unstable()
+    def approx_n_unique_sketch(self) -> Expr:
+        """
+        Sketch the unique values into a single binary value.
+
+        The sketches of different frames or partitions can be stored, merged with
+        :meth:`merge_sketches` and turned into the approximate number of unique
+        values with :meth:`approx_n_unique_from_sketch`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        See Also
+        --------
+        approx_n_unique
+
+        Examples
+        --------
+        >>> day_1 = pl.DataFrame({"user": ["a", "b", "c"]})
+        >>> day_2 = pl.DataFrame({"user": ["c", "d"]})
+        >>> sketches = pl.concat(
+        ...     df.select(pl.col("user").approx_n_unique_sketch())
+        ...     for df in [day_1, day_2]
+        ... )
+        >>> sketches.select(
+        ...     pl.col("user").merge_sketches().approx_n_unique_from_sketch()
+        ... )
+        shape: (1, 1)
+        ┌──────┐
+        │ user │
+        │ ---  │
+        │ u32  │
+        ╞══════╡
+        │ 4    │
+        └──────┘
+        """
+        return self._from_pyexpr(self._pyexpr.approx_n_unique_sketch())
+
+    @unstable()
+    def approx_quantile_sketch(self, accuracy: int = 100) -> Expr:
+        """
+        Sketch the distribution of the values into a single binary value.
+
+        The sketches of different frames or partitions can be stored, merged with
+        :meth:`merge_sketches` and turned into approximate quantiles with
+        :meth:`approx_quantile_from_sketch`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        accuracy
+            Compression of the sketch, see :meth:`approx_quantile`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"day": [1, 1, 2, 2], "value": [1.0, 2.0, 3.0, 4.0]})
+        >>> sketches = df.group_by("day").agg(pl.col("value").approx_quantile_sketch())
+        >>> sketches.select(
+        ...     pl.col("value").merge_sketches().approx_quantile_from_sketch(0.5)
+        ... )
+        shape: (1, 1)
+        ┌───────┐
+        │ value │
+        │ ---   │
+        │ f64   │
+        ╞═══════╡
+        │ 2.5   │
+        └───────┘
+        """
+        return self._from_pyexpr(self._pyexpr.approx_quantile_sketch(accuracy))
+
+    @unstable()
+    def merge_sketches(self) -> Expr:
+        """
+        Merge binary sketches into a single sketch.
+
+        All sketches must be created by the same function, e.g.
+        :meth:`approx_n_unique_sketch`. Null values are ignored.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "month": [1, 1, 1, 2],
+        ...         "day": [1, 1, 2, 1],
+        ...         "user": ["a", "b", "a", "c"],
+        ...     }
+        ... )
+        >>> daily = df.group_by("month", "day").agg(
+        ...     sketch=pl.col("user").approx_n_unique_sketch()
+        ... )
+        >>> daily.group_by("month").agg(
+        ...     pl.col("sketch").merge_sketches().approx_n_unique_from_sketch()
+        ... ).sort("month")
+        shape: (2, 2)
+        ┌───────┬────────┐
+        │ month ┆ sketch │
+        │ ---   ┆ ---    │
+        │ i64   ┆ u32    │
+        ╞═══════╪════════╡
+        │ 1     ┆ 2      │
+        │ 2     ┆ 1      │
+        └───────┴────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.merge_sketches())
+
+    @unstable()
+    def approx_n_unique_from_sketch(self) -> Expr:
+        """
+        Estimate the number of unique values of every sketch.
+
+        The sketches are created by :meth:`approx_n_unique_sketch`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"n": [1, 1, 2]})
+        >>> df.select(
+        ...     pl.col("n").approx_n_unique_sketch().approx_n_unique_from_sketch()
+        ... )
+        shape: (1, 1)
+        ┌─────┐
+        │ n   │
+        │ --- │
+        │ u32 │
+        ╞═════╡
+        │ 2   │
+        └─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.approx_n_unique_from_sketch())
+
+    @unstable()
+    def approx_quantile_from_sketch(self, quantile: float) -> Expr:
+        """
+        Estimate the quantile of every sketch.
+
+        The sketches are created by :meth:`approx_quantile_sketch`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [0, 1, 2, 3, 4, 5]})
+        >>> df.select(
+        ...     pl.col("a").approx_quantile_sketch().approx_quantile_from_sketch(0.3)
+        ... )
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 1.5 │
+        └─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.approx_quantile_from_sketch(quantile))
+
     def null_count(self) -> Expr:
         """
         Count null values.
@@ -3355,7 +3896,112 @@ class Expr:
         │ 2   │
         └─────┘
         """
-        return self._from_pyexpr(self._pyexpr.last())
+        return self._from_pyexpr(self._pyexpr.last())
+
+    @unstable()
+    def first_by(self, by: IntoExpr) -> Expr:
+        """
+        Get the value in the row where `by` is smallest.
+
+        This gives the same value as :meth:`first` after sorting by `by`, but
+        without sorting the data. It can also run in the streaming engine.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        by
+            Column to order by. Accepts expression input. Strings are parsed as
+            column names.
+
+        Notes
+        -----
+        Rows where `by` is null are skipped. If there are several rows with the
+        smallest value, the first of these rows is taken.
+
+        See Also
+        --------
+        last_by
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "key": ["a", "a", "b"],
+        ...         "value": [1, 2, 3],
+        ...         "time": [2, 1, 3],
+        ...     }
+        ... )
+        >>> df.group_by("key", maintain_order=True).agg(
+        ...     pl.col("value").first_by("time")
+        ... )
+        shape: (2, 2)
+        ┌─────┬───────┐
+        │ key ┆ value │
+        │ --- ┆ ---   │
+        │ str ┆ i64   │
+        ╞═════╪═══════╡
+        │ a   ┆ 2     │
+        │ b   ┆ 3     │
+        └─────┴───────┘
+        """
+        by = parse_into_expression(by)
+        return self._from_pyexpr(self._pyexpr.first_by(by))
+
+    @unstable()
+    def last_by(self, by: IntoExpr) -> Expr:
+        """
+        Get the value in the row where `by` is largest.
+
+        This gives the same value as :meth:`last` after sorting by `by`, but
+        without sorting the data. This is useful to get the latest record per key.
+        It can also run in the streaming engine.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        by
+            Column to order by. Accepts expression input. Strings are parsed as
+            column names.
+
+        Notes
+        -----
+        Rows where `by` is null are skipped. If there are several rows with the
+        largest value, the last of these rows is taken.
+
+        See Also
+        --------
+        first_by
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "key": ["a", "a", "b"],
+        ...         "value": [1, 2, 3],
+        ...         "time": [2, 1, 3],
+        ...     }
+        ... )
+        >>> df.group_by("key", maintain_order=True).agg(
+        ...     pl.col("value").last_by("time")
+        ... )
+        shape: (2, 2)
+        ┌─────┬───────┐
+        │ key ┆ value │
+        │ --- ┆ ---   │
+        │ str ┆ i64   │
+        ╞═════╪═══════╡
+        │ a   ┆ 1     │
+        │ b   ┆ 3     │
+        └─────┴───────┘
+        """
+        by = parse_into_expression(by)
+        return self._from_pyexpr(self._pyexpr.last_by(by))
 
     def over(
         self,
@@ -3363,6 +4009,11 @@ class Expr:
         *more_exprs: IntoExpr,
         order_by: IntoExpr | Iterable[IntoExpr] | None = None,
         mapping_strategy: WindowMappingStrategy = "group_to_rows",
+        rows_between: tuple[int | None, int | None] | None = None,
+        range_between: tuple[
+            str | timedelta | int | None, str | timedelta | int | None
+        ]
+        | None = None,
     ) -> Expr:
         """
         Compute expressions over the given groups.
@@ -3398,6 +4049,28 @@ class Expr:
                 if the groups are not part of the window operation for the operation,
                 otherwise the result would not make sense. This operation changes the
                 number of rows.
+        rows_between
+            Compute the expression over a frame of rows around every row instead of
+            over the whole group, like a SQL `ROWS BETWEEN` frame. The frame is given
+            as `(start, end)` offsets in rows from the current row, where negative
+            offsets are before the current row and `None` is the start or end of the
+            group. For example, `(-2, 0)` is the two preceding rows and the current row.
+            The rows of a group are in the order of `order_by`. The expression is null
+            for a frame without any rows.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        range_between
+            Compute the expression over the rows whose `order_by` value is within
+            `(start, end)` of the value of the current row, like a SQL
+            `RANGE BETWEEN` frame. The offsets are durations such as `"-2d"`, or
+            integers if `order_by` is an integer column, and `None` is the start or end
+            of the group. Requires `order_by` to be a single column without nulls.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Examples
         --------
@@ -3499,10 +4172,69 @@ class Expr:
         │ b   ┆ 3   ┆ 1   │
         └─────┴─────┴─────┘
 
+        Compute the expression over a frame of rows around every row with
+        `rows_between`, or over the rows with an `order_by` value close to that of the
+        current row with `range_between`.
+
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "g": ["a", "a", "a", "b", "b"],
+        ...         "t": [1, 2, 4, 1, 2],
+        ...         "x": [1, 2, 3, 4, 5],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     rows=pl.col("x").sum().over("g", order_by="t", rows_between=(-1, 0)),
+        ...     range=pl.col("x").sum().over("g", order_by="t", range_between=(-1, 0)),
+        ... )
+        shape: (5, 5)
+        ┌─────┬─────┬─────┬──────┬───────┐
+        │ g   ┆ t   ┆ x   ┆ rows ┆ range │
+        │ --- ┆ --- ┆ --- ┆ ---  ┆ ---   │
+        │ str ┆ i64 ┆ i64 ┆ i64  ┆ i64   │
+        ╞═════╪═════╪═════╪══════╪═══════╡
+        │ a   ┆ 1   ┆ 1   ┆ 1    ┆ 1     │
+        │ a   ┆ 2   ┆ 2   ┆ 3    ┆ 3     │
+        │ a   ┆ 4   ┆ 3   ┆ 5    ┆ 3     │
+        │ b   ┆ 1   ┆ 4   ┆ 4    ┆ 4     │
+        │ b   ┆ 2   ┆ 5   ┆ 9    ┆ 9     │
+        └─────┴─────┴─────┴──────┴───────┘
         """
         partition_by = parse_into_list_of_expressions(partition_by, *more_exprs)
         if order_by is not None:
             order_by = parse_into_list_of_expressions(order_by)
+        if rows_between is not None and range_between is not None:
+            msg = "cannot set both `rows_between` and `range_between`"
+            raise ValueError(msg)
+        if (
+            rows_between is not None or range_between is not None
+        ) and mapping_strategy != "group_to_rows":
+            msg = "a window frame cannot be combined with a `mapping_strategy`"
+            raise ValueError(msg)
+
+        if rows_between is not None:
+            issue_unstable_warning("window frames in `over` are considered unstable.")
+            rows_start, rows_end = rows_between
+            return self._from_pyexpr(
+                self._pyexpr.over_rows_between(
+                    partition_by, order_by, rows_start, rows_end
+                )
+            )
+        if range_between is not None:
+            issue_unstable_warning("window frames in `over` are considered unstable.")
+            range_start, range_end = (
+                (
+                    f"{offset}i"
+                    if isinstance(offset, int)
+                    else parse_as_duration_string(offset)
+                )
+                for offset in range_between
+            )
+            return self._from_pyexpr(
+                self._pyexpr.over_range_between(
+                    partition_by, order_by, range_start, range_end
+                )
+            )
         return self._from_pyexpr(
             self._pyexpr.over(
                 partition_by,
@@ -3697,10 +4429,19 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.is_last_distinct())
 
-    def is_duplicated(self) -> Expr:
+    def is_duplicated(self, *, keep: DuplicateKeepStrategy = "none") -> Expr:
         """
         Return a boolean mask indicating duplicated values.
 
+        Parameters
+        ----------
+        keep : {'none', 'first', 'last'}
+            Which occurrence of a duplicated value is not marked as duplicated.
+
+            * 'none': Mark all occurrences.
+            * 'first': Do not mark the first occurrence of each value.
+            * 'last': Do not mark the last occurrence of each value.
+
         Returns
         -------
         Expr
@@ -3720,8 +4461,37 @@ class Expr:
         │ true  │
         │ false │
         └───────┘
+
+        Mark only the repeated occurrences of a value:
+
+        >>> df.select(pl.col("a").is_duplicated(keep="first"))
+        shape: (3, 1)
+        ┌───────┐
+        │ a     │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ false │
+        │ true  │
+        │ false │
+        └───────┘
+
+        To compare multiple columns, combine them into a struct:
+
+        >>> df = pl.DataFrame({"a": [1, 1, 1], "b": ["x", "y", "x"]})
+        >>> df.select(pl.struct("a", "b").is_duplicated(keep="last").alias("dup"))
+        shape: (3, 1)
+        ┌───────┐
+        │ dup   │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ true  │
+        │ false │
+        │ false │
+        └───────┘
         """
-        return self._from_pyexpr(self._pyexpr.is_duplicated())
+        return self._from_pyexpr(self._pyexpr.is_duplicated(keep))
 
     def peak_max(self) -> Expr:
         """
@@ -3836,6 +4606,82 @@ class Expr:
         quantile = parse_into_expression(quantile)
         return self._from_pyexpr(self._pyexpr.quantile(quantile, interpolation))
 
+    def quantiles(
+        self,
+        quantiles: Sequence[float],
+        interpolation: RollingInterpolationMethod = "nearest",
+    ) -> Expr:
+        """
+        Get several quantile values at once, as an array with a value per quantile.
+
+        The values are only sorted partially once for all quantiles, which is faster
+        than a :meth:`quantile` per quantile. Multiple :meth:`quantile` aggregations
+        of the same column in a `group_by` are also combined in this way.
+
+        Parameters
+        ----------
+        quantiles
+            Quantiles between 0.0 and 1.0.
+        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear'}
+            Interpolation method.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [0, 1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").quantiles([0.25, 0.5, 0.75], interpolation="linear"))
+        shape: (1, 1)
+        ┌───────────────────┐
+        │ a                 │
+        │ ---               │
+        │ array[f64, 3]     │
+        ╞═══════════════════╡
+        │ [1.25, 2.5, 3.75] │
+        └───────────────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.quantiles(quantiles, interpolation))
+
+    @unstable()
+    def approx_quantile(self, quantile: float | Expr, accuracy: int = 100) -> Expr:
+        """
+        Approximate the quantile value with a t-digest sketch.
+
+        Contrary to :meth:`quantile`, this does not sort the values, and it uses a
+        bounded amount of memory per group. It can also run in the streaming engine.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        accuracy
+            Compression of the sketch. Higher values give more accurate quantiles
+            at the cost of memory. The result is exact when there are no more values
+            than the sketch can hold separately.
+
+        Notes
+        -----
+        Null and NaN values are ignored. The values are interpolated linearly, as
+        with `interpolation="linear"` in :meth:`quantile`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [0, 1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").approx_quantile(0.3))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 1.5 │
+        └─────┘
+        """
+        quantile = parse_into_expression(quantile)
+        return self._from_pyexpr(self._pyexpr.approx_quantile(quantile, accuracy))
+
     @unstable()
     def cut(
         self,
@@ -5401,6 +6247,45 @@ class Expr:
         """
         return self.__floordiv__(other)
 
+    def ceildiv(self, other: Any) -> Expr:
+        """
+        Divide and round the quotient up to the next integer.
+
+        This is the counterpart of :meth:`floordiv`. Integer division by zero results
+        in null. Decimals are divided exactly.
+
+        Parameters
+        ----------
+        other
+            Numeric literal or expression value.
+
+        See Also
+        --------
+        floordiv
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"x": [-3, -2, 0, 5, 7]})
+        >>> df.with_columns(
+        ...     pl.col("x").floordiv(2).alias("x//2"),
+        ...     pl.col("x").ceildiv(2).alias("ceil(x/2)"),
+        ... )
+        shape: (5, 3)
+        ┌─────┬──────┬───────────┐
+        │ x   ┆ x//2 ┆ ceil(x/2) │
+        │ --- ┆ ---  ┆ ---       │
+        │ i64 ┆ i64  ┆ i64       │
+        ╞═════╪══════╪═══════════╡
+        │ -3  ┆ -2   ┆ -1        │
+        │ -2  ┆ -1   ┆ -1        │
+        │ 0   ┆ 0    ┆ 0         │
+        │ 5   ┆ 2    ┆ 3         │
+        │ 7   ┆ 3    ┆ 4         │
+        └─────┴──────┴───────────┘
+        """
+        other = parse_into_expression(other)
+        return self._from_pyexpr(self._pyexpr.ceil_div(other))
+
     def mod(self, other: Any) -> Expr:
         """
         Method equivalent of modulus operator `expr % other`.
@@ -8312,6 +9197,7 @@ class Expr:
         *,
         descending: bool = False,
         seed: int | None = None,
+        collation: str | None = None,
     ) -> Expr:
         """
         Assign ranks to data, dealing with ties appropriately.
@@ -8340,6 +9226,14 @@ class Expr:
             Rank in descending order.
         seed
             If `method="random"`, use this as seed.
+        collation
+            Rank strings by the collation of this locale instead of by their bytes,
+            given as a BCP 47 language tag. Strings that are equal under the
+            collation are ties.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Examples
         --------
@@ -8394,7 +9288,9 @@ class Expr:
         │ 2   ┆ 11  ┆ 2.0  │
         └─────┴─────┴──────┘
         """
-        return self._from_pyexpr(self._pyexpr.rank(method, descending, seed))
+        return self._from_pyexpr(
+            self._pyexpr.rank(method, descending, seed, collation)
+        )
 
     def diff(self, n: int = 1, null_behavior: NullBehavior = "ignore") -> Expr:
         """
@@ -9905,7 +10801,8 @@ class Expr:
         Warnings
         --------
         This can lead to incorrect results if the data is NOT sorted!!
-        Use with care!
+        Use with care! Use :meth:`check_sorted` to verify the order instead, or
+        :meth:`Config.set_validate_sorted` to verify it in every `set_sorted`.
 
         Examples
         --------
@@ -9922,6 +10819,147 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.set_sorted_flag(descending))
 
+    def check_sorted(self, *, descending: bool = False) -> Expr:
+        """
+        Verify that the expression is sorted and flag it as 'sorted'.
+
+        Like :meth:`set_sorted`, but raises an error if the values are not sorted
+        instead of producing incorrect results downstream. The non-null values must
+        be sorted and the nulls must all be at the start or all at the end.
+
+        Parameters
+        ----------
+        descending
+            Whether the `Series` order is descending.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"values": [1, 2, 3]})
+        >>> df.select(pl.col("values").check_sorted().max())
+        shape: (1, 1)
+        ┌────────┐
+        │ values │
+        │ ---    │
+        │ i64    │
+        ╞════════╡
+        │ 3      │
+        └────────┘
+        >>> df.select(pl.col("values").check_sorted(descending=True))
+        Traceback (most recent call last):
+        ...
+        polars.exceptions.ComputeError: series 'values' is not sorted in descending order; value 2 at index 1 follows 1
+        """  # noqa: W505
+        return self._from_pyexpr(self._pyexpr.check_sorted(descending))
+
+    @unstable()
+    def parallel(self, parallel: bool) -> Expr:  # noqa: FBT001
+        """
+        Hint whether this expression may be evaluated in parallel.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The hint is honored by both the in-memory and the streaming engine and
+        overrides the choice made by the context, e.g. :meth:`DataFrame.select_seq`.
+
+        Parameters
+        ----------
+        parallel
+            If `False`, the expression is never evaluated concurrently with itself
+            or with the other expressions of its context. Use this for expressions
+            that call plugins or functions that are not thread-safe. If `True`, the
+            expression may parallelize its own work, even in a sequential context.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3]})
+        >>> df.select(
+        ...     (pl.col("a") * 2).parallel(False).alias("double"),
+        ...     pl.col("a").sum(),
+        ... )
+        shape: (3, 2)
+        ┌────────┬─────┐
+        │ double ┆ a   │
+        │ ---    ┆ --- │
+        │ i64    ┆ i64 │
+        ╞════════╪═════╡
+        │ 2      ┆ 6   │
+        │ 4      ┆ 6   │
+        │ 6      ┆ 6   │
+        └────────┴─────┘
+        """
+        return self._from_pyexpr(self._pyexpr.parallel(parallel))
+
+    def is_monotonic_increasing(self, *, strict: bool = False) -> Expr:
+        """
+        Check whether the non-null values never decrease.
+
+        Null values are ignored. If the expression is flagged as sorted in ascending
+        order, the values are not checked unless `strict` is set.
+
+        Parameters
+        ----------
+        strict
+            Also require every value to be greater than the previous one.
+
+        See Also
+        --------
+        is_monotonic_decreasing
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 2, None, 5], "b": [5, 4, 3, 2, 1]})
+        >>> df.select(
+        ...     pl.col("a").is_monotonic_increasing(),
+        ...     pl.col("a").is_monotonic_increasing(strict=True).alias("a_strict"),
+        ...     pl.col("b").is_monotonic_increasing(),
+        ... )
+        shape: (1, 3)
+        ┌──────┬──────────┬───────┐
+        │ a    ┆ a_strict ┆ b     │
+        │ ---  ┆ ---      ┆ ---   │
+        │ bool ┆ bool     ┆ bool  │
+        ╞══════╪══════════╪═══════╡
+        │ true ┆ false    ┆ false │
+        └──────┴──────────┴───────┘
+        """
+        return self._from_pyexpr(self._pyexpr.is_monotonic_increasing(strict))
+
+    def is_monotonic_decreasing(self, *, strict: bool = False) -> Expr:
+        """
+        Check whether the non-null values never increase.
+
+        Null values are ignored. If the expression is flagged as sorted in descending
+        order, the values are not checked unless `strict` is set.
+
+        Parameters
+        ----------
+        strict
+            Also require every value to be less than the previous one.
+
+        See Also
+        --------
+        is_monotonic_increasing
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 2, None, 5], "b": [5, 4, 3, 2, 1]})
+        >>> df.select(
+        ...     pl.col("b").is_monotonic_decreasing(strict=True),
+        ...     pl.col("a").is_monotonic_decreasing(),
+        ... )
+        shape: (1, 2)
+        ┌──────┬───────┐
+        │ b    ┆ a     │
+        │ ---  ┆ ---   │
+        │ bool ┆ bool  │
+        ╞══════╪═══════╡
+        │ true ┆ false │
+        └──────┴───────┘
+        """
+        return self._from_pyexpr(self._pyexpr.is_monotonic_decreasing(strict))
+
     def shrink_dtype(self) -> Expr:
         """
         Shrink numeric columns to the minimal required datatype.
@@ -9962,6 +11000,8 @@ class Expr:
         bins: IntoExpr | None = None,
         *,
         bin_count: int | None = None,
+        bin_strategy: HistBinStrategy | None = None,
+        shared_bins: bool = False,
         include_category: bool = False,
         include_breakpoint: bool = False,
     ) -> Expr:
@@ -9980,6 +11020,16 @@ class Expr:
         bin_count
             If no bins provided, this will be used to determine
             the distance of the bins
+        bin_strategy : {'fd', 'sturges'}
+            If neither `bins` nor `bin_count` are provided, the rule that determines
+            the number of bins from the data.
+
+            - 'fd': Freedman-Diaconis, bins of width `2 * IQR / n^(1/3)`.
+            - 'sturges': `log2(n) + 1` bins.
+        shared_bins
+            In a group by context, derive the bins from the data of all groups, so
+            every group is binned the same way. By default every group gets its own
+            bins. Has no effect if `bins` are provided.
         include_breakpoint
             Include a column that indicates the upper breakpoint.
         include_category
@@ -10020,13 +11070,36 @@ class Expr:
         │ {3.0,"(2.0, 3.0]",2}  │
         │ {inf,"(3.0, inf]",2}  │
         └───────────────────────┘
+
+        Bin the values of every group the same way.
+
+        >>> df = pl.DataFrame({"g": [1, 1, 2, 2], "a": [1, 2, 3, 8]})
+        >>> df.group_by("g", maintain_order=True).agg(
+        ...     pl.col("a").hist(bin_count=3, shared_bins=True)
+        ... )
+        shape: (2, 2)
+        ┌─────┬──────────────┐
+        │ g   ┆ a            │
+        │ --- ┆ ---          │
+        │ i64 ┆ list[u32]    │
+        ╞═════╪══════════════╡
+        │ 1   ┆ [0, 2, 0, 0] │
+        │ 2   ┆ [0, 1, 0, 1] │
+        └─────┴──────────────┘
         """
         if bins is not None:
             if isinstance(bins, list):
                 bins = pl.Series(bins)
             bins = parse_into_expression(bins)
         return self._from_pyexpr(
-            self._pyexpr.hist(bins, bin_count, include_category, include_breakpoint)
+            self._pyexpr.hist(
+                bins,
+                bin_count,
+                bin_strategy,
+                shared_bins,
+                include_category,
+                include_breakpoint,
+            )
         )
 
     def replace(
@@ -10560,6 +11633,15 @@ class Expr:
         """
         return ExprArrayNameSpace(self)
 
+    @property
+    def json(self) -> ExprJsonNameSpace:
+        """
+        Create an object namespace of all json related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprJsonNameSpace(self)
+
     @property
     def meta(self) -> ExprMetaNameSpace:
         """
@@ -10578,6 +11660,15 @@ class Expr:
         """
         return ExprNameNameSpace(self)
 
+    @property
+    def scale(self) -> ExprScaleNameSpace:
+        """
+        Create an object namespace of all methods that scale numeric features.
+
+        See the individual method pages for full details.
+        """
+        return ExprScaleNameSpace(self)
+
     @property
     def str(self) -> ExprStringNameSpace:
         """
@@ -10635,6 +11726,24 @@ class Expr:
         """
         return ExprStructNameSpace(self)
 
+    @property
+    def ts(self) -> ExprTimeSeriesNameSpace:
+        """
+        Create an object namespace of all time series related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprTimeSeriesNameSpace(self)
+
+    @property
+    def stats(self) -> ExprStatsNameSpace:
+        """
+        Create an object namespace of all statistical hypothesis tests.
+
+        See the individual method pages for full details.
+        """
+        return ExprStatsNameSpace(self)
+
 
 def _prepare_alpha(
     com: float | int | None = None,
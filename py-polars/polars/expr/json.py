@@ -0,0 +1,97 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+
+
+class ExprJsonNameSpace:
+    """Namespace for json related expressions."""
+
+    _accessor = "json"
+
+    def __init__(self, expr: Expr):
+        self._pyexpr = expr._pyexpr
+
+    @unstable()
+    def path(self, path: str) -> Expr:
+        """
+        Extract the values at a JSON path.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        path
+            A path like `$.a.b[0]`. Keys can be given as `.key` or `["key"]`, array
+            elements as `[n]`, where a negative `n` counts from the end of the array.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Json`. Values that do not have the path
+            become null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": ['{"b": [1, 2]}', '{"b": [3]}', '{"c": 4}']},
+        ...     schema={"a": pl.Json},
+        ... )
+        >>> df.select(pl.col("a").json.path("$.b[-1]"))
+        shape: (3, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ json │
+        ╞══════╡
+        │ 2    │
+        │ 3    │
+        │ null │
+        └──────┘
+        """
+        return wrap_expr(self._pyexpr.json_path(path))
+
+    @unstable()
+    def get_type(self) -> Expr:
+        """
+        Get the JSON type of the values.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`, one of `"null"`, `"boolean"`,
+            `"integer"`, `"float"`, `"string"`, `"array"` or `"object"`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": ["1", "1.5", '"x"', "[1]", '{"b": null}', "null"]},
+        ...     schema={"a": pl.Json},
+        ... )
+        >>> df.select(pl.col("a").json.get_type())
+        shape: (6, 1)
+        ┌─────────┐
+        │ a       │
+        │ ---     │
+        │ str     │
+        ╞═════════╡
+        │ integer │
+        │ float   │
+        │ string  │
+        │ array   │
+        │ object  │
+        │ null    │
+        └─────────┘
+        """
+        return wrap_expr(self._pyexpr.json_get_type())
@@ -6,6 +6,7 @@ from typing import TYPE_CHECKING, Any, Callable, Sequence
 import polars._reexport as pl
 from polars import functions as F
 from polars._utils.parse import parse_into_expression
+from polars._utils.unstable import unstable
 from polars._utils.wrap import wrap_expr
 
 if TYPE_CHECKING:
@@ -345,6 +346,77 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_var(ddof))
 
+    @unstable()
+    def sum_elementwise(self) -> Expr:
+        """
+        Sum the lists of all rows element-wise into a single list.
+
+        This is an aggregation: in a group by context it produces one list per
+        group. All lists that are not null must have the same length. Null values
+        are skipped; a position without any values sums to zero.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        See Also
+        --------
+        mean_elementwise
+        sum : Sum the values within every list.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"g": ["a", "a", "b"], "vec": [[1, 2], [3, 4], [5, 6]]})
+        >>> df.group_by("g", maintain_order=True).agg(
+        ...     pl.col("vec").list.sum_elementwise()
+        ... )
+        shape: (2, 2)
+        ┌─────┬───────────┐
+        │ g   ┆ vec       │
+        │ --- ┆ ---       │
+        │ str ┆ list[i64] │
+        ╞═════╪═══════════╡
+        │ a   ┆ [4, 6]    │
+        │ b   ┆ [5, 6]    │
+        └─────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.list_sum_elementwise())
+
+    @unstable()
+    def mean_elementwise(self) -> Expr:
+        """
+        Average the lists of all rows element-wise into a single list.
+
+        This is an aggregation: in a group by context it produces one list per
+        group. All lists that are not null must have the same length. Null values
+        are skipped; a position without any values is null.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        See Also
+        --------
+        sum_elementwise
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"g": ["a", "a", "b"], "vec": [[1, 2], [3, 4], [5, 6]]})
+        >>> df.group_by("g", maintain_order=True).agg(
+        ...     pl.col("vec").list.mean_elementwise()
+        ... )
+        shape: (2, 2)
+        ┌─────┬────────────┐
+        │ g   ┆ vec        │
+        │ --- ┆ ---        │
+        │ str ┆ list[f64]  │
+        ╞═════╪════════════╡
+        │ a   ┆ [2.0, 3.0] │
+        │ b   ┆ [5.0, 6.0] │
+        └─────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.list_mean_elementwise())
+
     def sort(self, *, descending: bool = False, nulls_last: bool = False) -> Expr:
         """
         Sort the lists in this column.
@@ -1221,6 +1293,45 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_eval(expr._pyexpr, parallel))
 
+    def agg(self, expr: Expr) -> Expr:
+        """
+        Reduce the elements of every list to a single value with an expression.
+
+        Unlike :meth:`eval`, the expression isn't run for every list separately, but
+        once on the elements of all lists, with every list as a group. Reductions such
+        as `quantile`, `std` and `arg_max` thus run as fast as in a `group_by`.
+
+        Parameters
+        ----------
+        expr
+            Expression that reduces the elements to a single value. Select the
+            elements with `pl.element()`.
+
+        Notes
+        -----
+        The result of a null list is null. Use :meth:`eval` for expressions that
+        don't produce a single value per list.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 8, 3], [5, 4, 2], None]})
+        >>> df.with_columns(
+        ...     std=pl.col("a").list.agg(pl.element().std()),
+        ...     arg_max=pl.col("a").list.agg(pl.element().arg_max()),
+        ... )
+        shape: (3, 3)
+        ┌───────────┬──────────┬─────────┐
+        │ a         ┆ std      ┆ arg_max │
+        │ ---       ┆ ---      ┆ ---     │
+        │ list[i64] ┆ f64      ┆ u32     │
+        ╞═══════════╪══════════╪═════════╡
+        │ [1, 8, 3] ┆ 3.605551 ┆ 1       │
+        │ [5, 4, 2] ┆ 1.527525 ┆ 0       │
+        │ null      ┆ null     ┆ null    │
+        └───────────┴──────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.list_agg(expr._pyexpr))
+
     def set_union(self, other: IntoExpr) -> Expr:
         """
         Compute the SET UNION between the elements in this list and the elements of `other`.
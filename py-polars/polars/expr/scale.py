@@ -0,0 +1,373 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExpr
+
+
+def _check_quantiles(q_low: float, q_high: float) -> None:
+    if not 0.0 <= q_low < q_high <= 1.0:
+        msg = f"expected 0 <= `q_low` < `q_high` <= 1, got {q_low!r} and {q_high!r}"
+        raise ValueError(msg)
+
+
+def _check_range(range: tuple[float, float]) -> tuple[float, float]:
+    low, high = range
+    if not low < high:
+        msg = f"the lower bound of `range` must be below the upper bound, got {range!r}"
+        raise ValueError(msg)
+    return float(low), float(high)
+
+
+class ExprScaleNameSpace:
+    """
+    Namespace for expressions that scale numeric features.
+
+    Every scaling is a linear transformation `(x - center) / scale`. The methods
+    ending in `_params` compute the `center` and `scale` of a column without
+    applying them, so that they can be computed on one dataset (e.g. the training
+    data) and applied to another with :meth:`apply`.
+    """
+
+    _accessor = "scale"
+
+    def __init__(self, expr: Expr):
+        self._pyexpr = expr._pyexpr
+
+    @unstable()
+    def standard(self, *, ddof: int = 1) -> Expr:
+        """
+        Scale to zero mean and unit standard deviation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        ddof
+            "Delta Degrees of Freedom" of the standard deviation.
+
+        See Also
+        --------
+        standard_params
+
+        Notes
+        -----
+        A column with a standard deviation of zero is only centered.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").scale.standard())
+        shape: (5, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ f64       │
+        ╞═══════════╡
+        │ -1.264911 │
+        │ -0.632456 │
+        │ 0.0       │
+        │ 0.632456  │
+        │ 1.264911  │
+        └───────────┘
+        """
+        return wrap_expr(self._pyexpr.scale_standard(ddof))
+
+    @unstable()
+    def robust(self, q_low: float = 0.25, q_high: float = 0.75) -> Expr:
+        """
+        Scale by subtracting the median and dividing by an interquantile range.
+
+        As the median and quantiles are insensitive to outliers, so is the scaling.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        q_low
+            Quantile at the start of the range, between 0 and 1.
+        q_high
+            Quantile at the end of the range, between `q_low` and 1.
+
+        See Also
+        --------
+        robust_params
+
+        Notes
+        -----
+        The quantiles are computed with linear interpolation. A column with an
+        interquantile range of zero is only centered.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4, 10]})
+        >>> df.select(pl.col("a").scale.robust())
+        shape: (5, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ -1.0 │
+        │ -0.5 │
+        │ 0.0  │
+        │ 0.5  │
+        │ 3.5  │
+        └──────┘
+        """
+        _check_quantiles(q_low, q_high)
+        return wrap_expr(self._pyexpr.scale_robust(q_low, q_high))
+
+    @unstable()
+    def min_max(self, range: tuple[float, float] = (0.0, 1.0)) -> Expr:
+        """
+        Scale the minimum and maximum to the bounds of a range.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        range
+            The values that the minimum and maximum are scaled to.
+
+        See Also
+        --------
+        min_max_params
+
+        Notes
+        -----
+        All values of a constant column are scaled to the lower bound of the range.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").scale.min_max(range=(-1, 1)))
+        shape: (5, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ -1.0 │
+        │ -0.5 │
+        │ 0.0  │
+        │ 0.5  │
+        │ 1.0  │
+        └──────┘
+        """
+        return wrap_expr(self._pyexpr.scale_min_max(_check_range(range)))
+
+    @unstable()
+    def standard_params(self, *, ddof: int = 1) -> Expr:
+        """
+        Compute the parameters of :meth:`standard` without applying them.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        ddof
+            "Delta Degrees of Freedom" of the standard deviation.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with the fields `center` (the
+            mean) and `scale` (the standard deviation), to pass to :meth:`apply`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").scale.standard_params()).unnest("a")
+        shape: (1, 2)
+        ┌────────┬──────────┐
+        │ center ┆ scale    │
+        │ ---    ┆ ---      │
+        │ f64    ┆ f64      │
+        ╞════════╪══════════╡
+        │ 3.0    ┆ 1.581139 │
+        └────────┴──────────┘
+        """
+        return wrap_expr(self._pyexpr.scale_standard_params(ddof))
+
+    @unstable()
+    def robust_params(self, q_low: float = 0.25, q_high: float = 0.75) -> Expr:
+        """
+        Compute the parameters of :meth:`robust` without applying them.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        q_low
+            Quantile at the start of the range, between 0 and 1.
+        q_high
+            Quantile at the end of the range, between `q_low` and 1.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with the fields `center` (the
+            median) and `scale` (the interquantile range), to pass to :meth:`apply`.
+
+        Examples
+        --------
+        Compute the parameters on the training data and scale the test data with
+        them.
+
+        >>> train = pl.DataFrame({"a": [1, 2, 3, 4, 10], "b": [0, 5, 10, 15, 20]})
+        >>> params = train.select(pl.all().scale.robust_params())
+        >>> params
+        shape: (1, 2)
+        ┌───────────┬─────────────┐
+        │ a         ┆ b           │
+        │ ---       ┆ ---         │
+        │ struct[2] ┆ struct[2]   │
+        ╞═══════════╪═════════════╡
+        │ {3.0,2.0} ┆ {10.0,10.0} │
+        └───────────┴─────────────┘
+        >>> test = pl.DataFrame({"a": [0, 5], "b": [10, 30]})
+        >>> test.with_columns(pl.col(c).scale.apply(params[c]) for c in params.columns)
+        shape: (2, 2)
+        ┌──────┬─────┐
+        │ a    ┆ b   │
+        │ ---  ┆ --- │
+        │ f64  ┆ f64 │
+        ╞══════╪═════╡
+        │ -1.5 ┆ 0.0 │
+        │ 1.0  ┆ 2.0 │
+        └──────┴─────┘
+        """
+        _check_quantiles(q_low, q_high)
+        return wrap_expr(self._pyexpr.scale_robust_params(q_low, q_high))
+
+    @unstable()
+    def min_max_params(self, range: tuple[float, float] = (0.0, 1.0)) -> Expr:
+        """
+        Compute the parameters of :meth:`min_max` without applying them.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        range
+            The values that the minimum and maximum are scaled to.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with the fields `center` and
+            `scale`, to pass to :meth:`apply`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").scale.min_max_params()).unnest("a")
+        shape: (1, 2)
+        ┌────────┬───────┐
+        │ center ┆ scale │
+        │ ---    ┆ ---   │
+        │ f64    ┆ f64   │
+        ╞════════╪═══════╡
+        │ 1.0    ┆ 4.0   │
+        └────────┴───────┘
+        """
+        return wrap_expr(self._pyexpr.scale_min_max_params(_check_range(range)))
+
+    @unstable()
+    def apply(self, params: IntoExpr) -> Expr:
+        """
+        Scale with previously computed parameters.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        params
+            Struct with the fields `center` and `scale`, as computed by
+            :meth:`standard_params`, :meth:`robust_params` or
+            :meth:`min_max_params`. Accepts expression input; a Series is parsed
+            as a literal.
+
+        See Also
+        --------
+        inverse
+
+        Examples
+        --------
+        >>> train = pl.DataFrame({"a": [1, 2, 3, 4, 5]})
+        >>> params = train.select(pl.col("a").scale.min_max_params())["a"]
+        >>> pl.DataFrame({"a": [0, 3, 9]}).select(pl.col("a").scale.apply(params))
+        shape: (3, 1)
+        ┌───────┐
+        │ a     │
+        │ ---   │
+        │ f64   │
+        ╞═══════╡
+        │ -0.25 │
+        │ 0.5   │
+        │ 2.0   │
+        └───────┘
+        """
+        params = parse_into_expression(params)
+        return wrap_expr(self._pyexpr.scale_apply(params))
+
+    @unstable()
+    def inverse(self, params: IntoExpr) -> Expr:
+        """
+        Undo the scaling with previously computed parameters.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        params
+            Struct with the fields `center` and `scale` that the values were scaled
+            with. Accepts expression input; a Series is parsed as a literal.
+
+        See Also
+        --------
+        apply
+
+        Examples
+        --------
+        >>> train = pl.DataFrame({"a": [1, 2, 3, 4, 5]})
+        >>> params = train.select(pl.col("a").scale.min_max_params())["a"]
+        >>> pl.DataFrame({"a": [0.0, 0.5, 1.0]}).select(
+        ...     pl.col("a").scale.inverse(params)
+        ... )
+        shape: (3, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 1.0 │
+        │ 3.0 │
+        │ 5.0 │
+        └─────┘
+        """
+        params = parse_into_expression(params)
+        return wrap_expr(self._pyexpr.scale_inverse(params))
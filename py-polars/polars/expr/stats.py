@@ -0,0 +1,195 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExpr, TTestVariant
+
+
+class ExprStatsNameSpace:
+    """
+    Namespace for statistical hypothesis tests.
+
+    The tests are aggregations that return a struct with a `statistic` and a
+    `p_value` field, so that samples can be compared per group, e.g. the variants
+    of every experiment of an A/B test.
+    """
+
+    _accessor = "stats"
+
+    def __init__(self, expr: Expr):
+        self._pyexpr = expr._pyexpr
+
+    @unstable()
+    def ttest(self, other: IntoExpr, *, variant: TTestVariant = "welch") -> Expr:
+        """
+        Test whether the mean differs from the mean of another sample.
+
+        Computes the two-sided t-test of the null hypothesis that both samples have
+        the same mean.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            The other sample. Accepts expression input. Strings are parsed as column
+            names.
+        variant : {'welch', 'student', 'paired'}
+            The t-test to use.
+
+            * 'welch': Welch's t-test of independent samples, which doesn't assume
+              that the samples have the same variance.
+            * 'student': Student's t-test of independent samples with the same
+              variance.
+            * 'paired': The t-test of the differences between the values in the
+              same row, e.g. measurements before and after a treatment. The samples
+              must have the same length.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with the t-statistic in the
+            field `statistic` and the p-value in the field `p_value`.
+
+        Notes
+        -----
+        Null values are ignored; the paired test ignores the rows where either
+        sample is null. The statistic and p-value are NaN if a sample has fewer
+        than two values.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "experiment": ["x", "x", "x", "x", "y", "y", "y", "y"],
+        ...         "control": [1.0, 2.0, 3.0, 4.0, 2.0, 4.0, 6.0, 8.0],
+        ...         "treatment": [2.0, 3.0, 4.5, 5.5, 1.0, 3.5, 4.5, 6.0],
+        ...     }
+        ... )
+        >>> df.group_by("experiment", maintain_order=True).agg(
+        ...     pl.col("control").stats.ttest("treatment").alias("ttest")
+        ... ).unnest("ttest")
+        shape: (2, 3)
+        ┌────────────┬───────────┬──────────┐
+        │ experiment ┆ statistic ┆ p_value  │
+        │ ---        ┆ ---       ┆ ---      │
+        │ str        ┆ f64       ┆ f64      │
+        ╞════════════╪═══════════╪══════════╡
+        │ x          ┆ -1.237179 ┆ 0.263724 │
+        │ y          ┆ 0.750939  ┆ 0.482216 │
+        └────────────┴───────────┴──────────┘
+        """
+        other = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.stats_ttest(other, variant))
+
+    @unstable()
+    def ks_test(self, other: IntoExpr) -> Expr:
+        """
+        Test whether the values come from the same distribution as another sample.
+
+        Computes the two-sample Kolmogorov-Smirnov test of the null hypothesis that
+        both samples are drawn from the same continuous distribution.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            The other sample. Accepts expression input. Strings are parsed as column
+            names.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with the largest distance
+            between the empirical distribution functions of the samples in the field
+            `statistic` and the p-value in the field `p_value`.
+
+        Notes
+        -----
+        Null and NaN values are ignored. The p-value is computed with the
+        asymptotic distribution of the statistic, which is less accurate for small
+        samples than the exact distribution that e.g. SciPy uses for them.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "control": [1.0, 2.0, 3.0, 4.0, 2.0, 4.0, 6.0, 8.0],
+        ...         "treatment": [2.0, 3.0, 4.5, 5.5, 1.0, 3.5, 4.5, 6.0],
+        ...     }
+        ... )
+        >>> df.select(pl.col("control").stats.ks_test("treatment")).unnest("control")
+        shape: (1, 2)
+        ┌───────────┬──────────┐
+        │ statistic ┆ p_value  │
+        │ ---       ┆ ---      │
+        │ f64       ┆ f64      │
+        ╞═══════════╪══════════╡
+        │ 0.25      ┆ 0.928955 │
+        └───────────┴──────────┘
+        """
+        other = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.stats_ks_test(other))
+
+    @unstable()
+    def chi2(self, expected: IntoExpr) -> Expr:
+        """
+        Test whether the observed frequencies differ from the expected frequencies.
+
+        Computes Pearson's chi-squared test of the null hypothesis that the
+        observed frequencies of categories, in this column, follow the expected
+        frequencies, in the same rows of `expected`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        expected
+            The expected frequencies, which should sum to the same total as the
+            observed frequencies. Accepts expression input. Strings are parsed as
+            column names.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with the chi-squared statistic
+            in the field `statistic` and the p-value in the field `p_value`.
+
+        Notes
+        -----
+        The rows where either frequency is null are ignored. The test has one
+        degree of freedom less than the number of categories.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "observed": [16, 18, 16, 14, 12, 12],
+        ...         "expected": [16, 16, 16, 16, 16, 8],
+        ...     }
+        ... )
+        >>> df.select(pl.col("observed").stats.chi2("expected")).unnest("observed")
+        shape: (1, 2)
+        ┌───────────┬──────────┐
+        │ statistic ┆ p_value  │
+        │ ---       ┆ ---      │
+        │ f64       ┆ f64      │
+        ╞═══════════╪══════════╡
+        │ 3.5       ┆ 0.623388 │
+        └───────────┴──────────┘
+        """
+        expected = parse_into_expression(expected)
+        return wrap_expr(self._pyexpr.stats_chi2(expected))
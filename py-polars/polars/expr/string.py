@@ -1,7 +1,8 @@
 from __future__ import annotations
 
 import warnings
-from typing import TYPE_CHECKING
+from collections.abc import Mapping
+from typing import TYPE_CHECKING, Literal
 
 import polars._reexport as pl
 from polars import functions as F
@@ -13,7 +14,7 @@ from polars._utils.parse import parse_into_expression
 from polars._utils.unstable import unstable
 from polars._utils.various import find_stacklevel
 from polars._utils.wrap import wrap_expr
-from polars.datatypes import Date, Datetime, Time, parse_into_dtype
+from polars.datatypes import Date, Datetime, Struct, Time, parse_into_dtype
 from polars.datatypes.constants import N_INFER_DEFAULT
 from polars.exceptions import ChronoFormatWarning
 
@@ -25,6 +26,7 @@ if TYPE_CHECKING:
         IntoExprColumn,
         PolarsDataType,
         PolarsTemporalType,
+        SchemaDict,
         TimeUnit,
         TransferEncoding,
     )
@@ -1195,23 +1197,32 @@ class ExprStringNameSpace:
 
     def json_decode(
         self,
-        dtype: PolarsDataType | None = None,
+        dtype: PolarsDataType | SchemaDict | None = None,
         *,
         infer_schema_length: int | None = N_INFER_DEFAULT,
+        strict: bool = True,
     ) -> Expr:
         """
         Parse string values as JSON.
 
-        Throws an error if invalid JSON strings are encountered.
+        Throws an error if invalid JSON strings are encountered, unless `strict` is
+        set to `False`. Large columns are parsed in parallel.
 
         Parameters
         ----------
         dtype
-            The dtype to cast the extracted value to. If None, the dtype will be
+            The dtype to cast the extracted value to. A mapping of field names to
+            dtypes is taken as the schema of a struct. If None, the dtype will be
             inferred from the JSON value.
         infer_schema_length
             The maximum number of rows to scan for schema inference.
             If set to `None`, the full data may be scanned *(this is slow)*.
+            Not used if `dtype` is given.
+        strict
+            Raise an error if a string is not valid JSON, naming its row. If set to
+            `False`, such strings are parsed as null instead. The schema is then
+            inferred from the valid strings only, and the number of invalid strings
+            is logged in verbose mode.
 
         See Also
         --------
@@ -1235,10 +1246,31 @@ class ExprStringNameSpace:
         │ null                ┆ {null,null} │
         │ {"a":2, "b": false} ┆ {2,false}   │
         └─────────────────────┴─────────────┘
-        """
-        if dtype is not None:
+
+        Strings that are not valid JSON can be parsed as null instead.
+
+        >>> df = pl.DataFrame({"json": ['{"a":1}', '{"a":', '{"a":3}']})
+        >>> df.with_columns(
+        ...     decoded=pl.col("json").str.json_decode({"a": pl.Int64}, strict=False)
+        ... )
+        shape: (3, 2)
+        ┌─────────┬───────────┐
+        │ json    ┆ decoded   │
+        │ ---     ┆ ---       │
+        │ str     ┆ struct[1] │
+        ╞═════════╪═══════════╡
+        │ {"a":1} ┆ {1}       │
+        │ {"a":   ┆ {null}    │
+        │ {"a":3} ┆ {3}       │
+        └─────────┴───────────┘
+        """
+        if isinstance(dtype, Mapping):
+            dtype = Struct(dtype)
+        elif dtype is not None:
             dtype = parse_into_dtype(dtype)
-        return wrap_expr(self._pyexpr.str_json_decode(dtype, infer_schema_length))
+        return wrap_expr(
+            self._pyexpr.str_json_decode(dtype, infer_schema_length, strict)
+        )
 
     def json_path_match(self, json_path: IntoExprColumn) -> Expr:
         """
@@ -2587,6 +2619,298 @@ class ExprStringNameSpace:
             self._pyexpr.str_extract_many(patterns, ascii_case_insensitive, overlapping)
         )
 
+    @unstable()
+    def levenshtein(self, other: IntoExpr, *, normalized: bool = False) -> Expr:
+        """
+        Compute the Levenshtein distance to other strings.
+
+        The distance is the number of inserted, deleted and substituted characters
+        that turn one string into the other.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Strings to compare with. Accepts expression input. Strings are parsed
+            as literals.
+        normalized
+            Return the similarity `1 - distance / max(len(a), len(b))` in
+            `[0, 1]` instead of the distance, where `1` means the strings are
+            equal.
+
+        See Also
+        --------
+        damerau_levenshtein
+        hamming
+        jaro_winkler
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "flaw", "abc", None],
+        ...         "b": ["sitting", "lawn", "acb", "x"],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     dist=pl.col("a").str.levenshtein("b"),
+        ...     sim=pl.col("a").str.levenshtein("b", normalized=True),
+        ... )
+        shape: (4, 4)
+        ┌────────┬─────────┬──────┬──────────┐
+        │ a      ┆ b       ┆ dist ┆ sim      │
+        │ ---    ┆ ---     ┆ ---  ┆ ---      │
+        │ str    ┆ str     ┆ u32  ┆ f64      │
+        ╞════════╪═════════╪══════╪══════════╡
+        │ kitten ┆ sitting ┆ 3    ┆ 0.571429 │
+        │ flaw   ┆ lawn    ┆ 2    ┆ 0.5      │
+        │ abc    ┆ acb     ┆ 2    ┆ 0.333333 │
+        │ null   ┆ x       ┆ null ┆ null     │
+        └────────┴─────────┴──────┴──────────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_levenshtein(other, normalized))
+
+    @unstable()
+    def damerau_levenshtein(self, other: IntoExpr, *, normalized: bool = False) -> Expr:
+        """
+        Compute the Damerau-Levenshtein distance to other strings.
+
+        Like the Levenshtein distance, but swapping two characters counts as a
+        single edit.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Strings to compare with. Accepts expression input. Strings are parsed
+            as literals.
+        normalized
+            Return the similarity `1 - distance / max(len(a), len(b))` in
+            `[0, 1]` instead of the distance, where `1` means the strings are
+            equal.
+
+        See Also
+        --------
+        levenshtein
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": ["abc", "ca", "kitten"], "b": ["acb", "abc", "sitting"]}
+        ... )
+        >>> df.select(
+        ...     lev=pl.col("a").str.levenshtein("b"),
+        ...     dl=pl.col("a").str.damerau_levenshtein("b"),
+        ... )
+        shape: (3, 2)
+        ┌─────┬─────┐
+        │ lev ┆ dl  │
+        │ --- ┆ --- │
+        │ u32 ┆ u32 │
+        ╞═════╪═════╡
+        │ 2   ┆ 1   │
+        │ 3   ┆ 2   │
+        │ 3   ┆ 3   │
+        └─────┴─────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_damerau_levenshtein(other, normalized))
+
+    @unstable()
+    def hamming(self, other: IntoExpr, *, normalized: bool = False) -> Expr:
+        """
+        Compute the Hamming distance to other strings.
+
+        The distance is the number of positions at which the characters differ.
+        Strings of a different length have a null distance.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Strings to compare with. Accepts expression input. Strings are parsed
+            as literals.
+        normalized
+            Return the similarity `1 - distance / len(a)` in `[0, 1]` instead of
+            the distance, where `1` means the strings are equal.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["karolin", "kathrin", "abc"]})
+        >>> df.with_columns(dist=pl.col("a").str.hamming("kerstin"))
+        shape: (3, 2)
+        ┌─────────┬──────┐
+        │ a       ┆ dist │
+        │ ---     ┆ ---  │
+        │ str     ┆ u32  │
+        ╞═════════╪══════╡
+        │ karolin ┆ 3    │
+        │ kathrin ┆ 4    │
+        │ abc     ┆ null │
+        └─────────┴──────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_hamming(other, normalized))
+
+    @unstable()
+    def jaro_winkler(self, other: IntoExpr) -> Expr:
+        """
+        Compute the Jaro-Winkler similarity to other strings.
+
+        The similarity is in `[0, 1]`, where `1` means the strings are equal.
+        Strings that share a prefix of up to 4 characters score higher.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Strings to compare with. Accepts expression input. Strings are parsed
+            as literals.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": ["MARTHA", "DIXON", "abc"], "b": ["MARHTA", "DICKSONX", "xyz"]}
+        ... )
+        >>> df.with_columns(sim=pl.col("a").str.jaro_winkler("b"))
+        shape: (3, 3)
+        ┌────────┬──────────┬──────────┐
+        │ a      ┆ b        ┆ sim      │
+        │ ---    ┆ ---      ┆ ---      │
+        │ str    ┆ str      ┆ f64      │
+        ╞════════╪══════════╪══════════╡
+        │ MARTHA ┆ MARHTA   ┆ 0.961111 │
+        │ DIXON  ┆ DICKSONX ┆ 0.813333 │
+        │ abc    ┆ xyz      ┆ 0.0      │
+        └────────┴──────────┴──────────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_jaro_winkler(other))
+
+    @unstable()
+    def compare(self, other: IntoExpr, collation: str) -> Expr:
+        """
+        Compare to other strings under a locale-aware collation.
+
+        Returns `-1` if the string orders before the other string, `0` if they are
+        equal under the collation and `1` if it orders after it.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Strings to compare with. Accepts expression input. Strings are parsed
+            as literals.
+        collation
+            Locale of the collation, as a BCP 47 language tag such as `"de-AT"`. The
+            strength is set with the `ks` keyword, e.g. `"en-u-ks-level1"` ignores
+            both case and accents.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["résumé", "Resume", "zebra"]})
+        >>> df.with_columns(cmp=pl.col("a").str.compare("resume", "en-u-ks-level1"))
+        shape: (3, 2)
+        ┌────────┬─────┐
+        │ a      ┆ cmp │
+        │ ---    ┆ --- │
+        │ str    ┆ i8  │
+        ╞════════╪═════╡
+        │ résumé ┆ 0   │
+        │ Resume ┆ 0   │
+        │ zebra  ┆ 1   │
+        └────────┴─────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_compare(other, collation))
+
+    @unstable()
+    def min(self, collation: str) -> Expr:
+        """
+        Get the smallest string under a locale-aware collation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        collation
+            Locale of the collation, as a BCP 47 language tag such as `"de-AT"`. The
+            strength is set with the `ks` keyword, e.g. `"en-u-ks-level1"` ignores
+            both case and accents.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"fruit": ["Zitrone", "apfel", "Äpfel", "Banane"]})
+        >>> df.select(
+        ...     pl.col("fruit").str.min("de").alias("min"),
+        ...     pl.col("fruit").str.max("de").alias("max"),
+        ... )
+        shape: (1, 2)
+        ┌───────┬─────────┐
+        │ min   ┆ max     │
+        │ ---   ┆ ---     │
+        │ str   ┆ str     │
+        ╞═══════╪═════════╡
+        │ apfel ┆ Zitrone │
+        └───────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.str_min(collation))
+
+    @unstable()
+    def max(self, collation: str) -> Expr:
+        """
+        Get the largest string under a locale-aware collation.
+
+        Unlike :func:`Expr.max`, which compares the bytes of the strings, this orders
+        accented letters next to their base letter.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        collation
+            Locale of the collation, as a BCP 47 language tag such as `"de-AT"`. The
+            strength is set with the `ks` keyword, e.g. `"en-u-ks-level1"` ignores
+            both case and accents.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"fruit": ["Zitrone", "apfel", "Äpfel", "Banane"]})
+        >>> df.select(
+        ...     pl.col("fruit").max().alias("bytes"),
+        ...     pl.col("fruit").str.max("de").alias("de"),
+        ... )
+        shape: (1, 2)
+        ┌───────┬─────────┐
+        │ bytes ┆ de      │
+        │ ---   ┆ ---     │
+        │ str   ┆ str     │
+        ╞═══════╪═════════╡
+        │ Äpfel ┆ Zitrone │
+        └───────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.str_max(collation))
+
     def join(self, delimiter: str = "", *, ignore_nulls: bool = True) -> Expr:
         """
         Vertically concatenate the string values in the column to a single string value.
@@ -2629,6 +2953,75 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_join(delimiter, ignore_nulls=ignore_nulls))
 
+    @unstable()
+    def join_agg(
+        self,
+        separator: str = "",
+        *,
+        distinct: bool = True,
+        sort: Literal["ascending", "descending"] | None = "ascending",
+    ) -> Expr:
+        """
+        Join the string values of every group into a single string value.
+
+        Unlike :meth:`join`, this is an aggregation that can drop duplicates and
+        sort the values before they are joined, and it can run in the streaming
+        engine. Null values are ignored, so an empty group gives an empty string.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        separator
+            The separator to insert between consecutive string values.
+        distinct
+            Only keep the first occurrence of every string value.
+        sort : {'ascending', 'descending'} or None
+            Sort the values before they are joined. If `None`, the values are
+            joined in the order of the group, which is not guaranteed by the
+            streaming engine.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "group": ["a", "a", "a", "b", "b"],
+        ...         "tag": ["y", "x", "y", None, "z"],
+        ...     }
+        ... )
+        >>> df.group_by("group", maintain_order=True).agg(
+        ...     pl.col("tag").str.join_agg(","),
+        ...     all_tags=pl.col("tag").str.join_agg(",", distinct=False, sort=None),
+        ... )
+        shape: (2, 3)
+        ┌───────┬─────┬──────────┐
+        │ group ┆ tag ┆ all_tags │
+        │ ---   ┆ --- ┆ ---      │
+        │ str   ┆ str ┆ str      │
+        ╞═══════╪═════╪══════════╡
+        │ a     ┆ x,y ┆ y,x,y    │
+        │ b     ┆ z   ┆ z        │
+        └───────┴─────┴──────────┘
+        """
+        if sort not in ("ascending", "descending", None):
+            msg = f"`sort` must be 'ascending', 'descending' or None, got {sort!r}"
+            raise ValueError(msg)
+        return wrap_expr(
+            self._pyexpr.str_join_agg(
+                separator,
+                distinct,
+                sort is not None,
+                sort == "descending",
+            )
+        )
+
     def concat(
         self, delimiter: str | None = None, *, ignore_nulls: bool = True
     ) -> Expr:
@@ -0,0 +1,146 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExpr
+
+
+class ExprTimeSeriesNameSpace:
+    """Namespace for time series related expressions."""
+
+    _accessor = "ts"
+
+    def __init__(self, expr: Expr):
+        self._pyexpr = expr._pyexpr
+
+    @unstable()
+    def lttb(self, n_out: int, x: IntoExpr | None = None) -> Expr:
+        """
+        Select the points to keep when downsampling with LTTB.
+
+        The Largest-Triangle-Three-Buckets algorithm reduces the series to `n_out`
+        points that preserve its visual shape, which makes it suited to plot series
+        with many more points than there are pixels. The first and last point are
+        always kept; from each of `n_out - 2` buckets of consecutive points in
+        between, the point that forms the largest triangle with its neighbouring
+        buckets is kept.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        n_out
+            Number of points to keep; at least 3.
+        x
+            Column with the x coordinates of the points, e.g. the timestamps. The
+            points are expected to be sorted by it. If not given, the points are
+            taken to be evenly spaced.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean` that is `True` for the points
+            to keep. Null and NaN values are never kept. If there are no more than
+            `n_out` other points, all of them are kept.
+
+        See Also
+        --------
+        min_max
+
+        Notes
+        -----
+        Use the mask in :meth:`DataFrame.filter` to downsample the other columns
+        along. Combine it with :meth:`Expr.over` to downsample every series of a
+        frame separately, e.g.
+        `df.filter(pl.col("y").ts.lttb(1000, x="time").over("sensor"))`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "t": range(10),
+        ...         "y": [0, 2, 1, 7, 3, 0, -4, 1, 2, 0],
+        ...     }
+        ... )
+        >>> df.filter(pl.col("y").ts.lttb(4, x="t"))
+        shape: (4, 2)
+        ┌─────┬─────┐
+        │ t   ┆ y   │
+        │ --- ┆ --- │
+        │ i64 ┆ i64 │
+        ╞═════╪═════╡
+        │ 0   ┆ 0   │
+        │ 3   ┆ 7   │
+        │ 6   ┆ -4  │
+        │ 9   ┆ 0   │
+        └─────┴─────┘
+        """
+        if x is not None:
+            x = parse_into_expression(x)
+        return wrap_expr(self._pyexpr.ts_lttb(n_out, x))
+
+    @unstable()
+    def min_max(self, n_out: int) -> Expr:
+        """
+        Select the points to keep when downsampling by min-max decimation.
+
+        The series is split in `n_out // 2` buckets of consecutive points, and the
+        minimum and maximum of every bucket are kept. This keeps all the extremes
+        of the series, which makes it suited to plot noisy series or series with
+        outliers.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        n_out
+            Maximum number of points to keep; at least 2.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean` that is `True` for the points
+            to keep. Null and NaN values are never kept. If there are no more than
+            `n_out` other points, all of them are kept.
+
+        See Also
+        --------
+        lttb
+
+        Notes
+        -----
+        Combine it with :meth:`Expr.over` to downsample every series of a frame
+        separately.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "t": range(10),
+        ...         "y": [0, 2, 1, 7, 3, 0, -4, 1, 2, 0],
+        ...     }
+        ... )
+        >>> df.filter(pl.col("y").ts.min_max(4))
+        shape: (4, 2)
+        ┌─────┬─────┐
+        │ t   ┆ y   │
+        │ --- ┆ --- │
+        │ i64 ┆ i64 │
+        ╞═════╪═════╡
+        │ 0   ┆ 0   │
+        │ 3   ┆ 7   │
+        │ 6   ┆ -4  │
+        │ 8   ┆ 2   │
+        └─────┴─────┘
+        """
+        return wrap_expr(self._pyexpr.ts_min_max(n_out))
@@ -3,6 +3,8 @@ from polars.functions.aggregation import (
     all_horizontal,
     any,
     any_horizontal,
+    arg_max_horizontal,
+    arg_min_horizontal,
     cum_sum,
     cum_sum_horizontal,
     max,
@@ -19,6 +21,7 @@ from polars.functions.as_datatype import (
     duration,
     format,
     struct,
+    zip_lists,
 )
 from polars.functions.as_datatype import date_ as date
 from polars.functions.as_datatype import datetime_ as datetime
@@ -49,8 +52,12 @@ from polars.functions.lazy import (
     from_epoch,
     groups,
     head,
+    hist_2d,
     implode,
+    lag,
     last,
+    lead,
+    lookup,
     map_batches,
     map_groups,
     mean,
@@ -61,12 +68,14 @@ from polars.functions.lazy import (
     reduce,
     rolling_corr,
     rolling_cov,
+    row_indices,
     select,
     sql_expr,
     std,
     tail,
     var,
 )
+from polars.functions.frequency import register_frequency
 from polars.functions.len import len
 from polars.functions.lit import lit
 from polars.functions.random import set_random_seed
@@ -94,6 +103,8 @@ __all__ = [
     "sum",
     "all_horizontal",
     "any_horizontal",
+    "arg_max_horizontal",
+    "arg_min_horizontal",
     "cum_sum_horizontal",
     "max_horizontal",
     "min_horizontal",
@@ -142,11 +153,15 @@ __all__ = [
     "from_epoch",
     "groups",
     "head",
+    "hist_2d",
     "implode",
     "int_range",
     "int_ranges",
+    "lag",
     "last",
+    "lead",
     "lit",
+    "lookup",
     "map_batches",
     "map_groups",
     "mean",
@@ -156,8 +171,10 @@ __all__ = [
     "nth",
     "quantile",
     "reduce",
+    "register_frequency",
     "rolling_corr",
     "rolling_cov",
+    "row_indices",
     "select",
     "set_random_seed",
     "std",
@@ -165,6 +182,7 @@ __all__ = [
     "tail",
     "time",
     "var",
+    "zip_lists",
     # polars.functions.len
     "len",
     # polars.functions.whenthen
@@ -1,6 +1,8 @@
 from polars.functions.aggregation.horizontal import (
     all_horizontal,
     any_horizontal,
+    arg_max_horizontal,
+    arg_min_horizontal,
     cum_sum_horizontal,
     max_horizontal,
     mean_horizontal,
@@ -25,6 +27,8 @@ __all__ = [
     "sum",
     "all_horizontal",
     "any_horizontal",
+    "arg_max_horizontal",
+    "arg_min_horizontal",
     "cum_sum_horizontal",
     "max_horizontal",
     "mean_horizontal",
@@ -5,6 +5,7 @@ from typing import TYPE_CHECKING, Iterable
 
 import polars.functions as F
 from polars._utils.parse import parse_into_list_of_expressions
+from polars._utils.unstable import unstable
 from polars._utils.wrap import wrap_expr
 from polars.datatypes import UInt32
 
@@ -176,6 +177,104 @@ def min_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     return wrap_expr(plr.min_horizontal(pyexprs))
 
 
+@unstable()
+def arg_max_horizontal(
+    *exprs: IntoExpr | Iterable[IntoExpr], return_names: bool = False
+) -> Expr:
+    """
+    Get the index of the column that holds the maximum value horizontally.
+
+    Null values are ignored. If multiple columns hold the maximum value, the first
+    of them is returned. A row that only holds null values returns null.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    *exprs
+        Column(s) to use in the aggregation. Accepts expression input. Strings are
+        parsed as column names, other non-expression inputs are parsed as literals.
+    return_names
+        Return the name of the column instead of its index.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "a": [1, 8, 3],
+    ...         "b": [4, 5, None],
+    ...     }
+    ... )
+    >>> df.with_columns(
+    ...     idx=pl.arg_max_horizontal("a", "b"),
+    ...     name=pl.arg_max_horizontal("a", "b", return_names=True),
+    ... )
+    shape: (3, 4)
+    ┌─────┬──────┬─────┬──────┐
+    │ a   ┆ b    ┆ idx ┆ name │
+    │ --- ┆ ---  ┆ --- ┆ ---  │
+    │ i64 ┆ i64  ┆ u32 ┆ str  │
+    ╞═════╪══════╪═════╪══════╡
+    │ 1   ┆ 4    ┆ 1   ┆ b    │
+    │ 8   ┆ 5    ┆ 0   ┆ a    │
+    │ 3   ┆ null ┆ 0   ┆ a    │
+    └─────┴──────┴─────┴──────┘
+    """
+    pyexprs = parse_into_list_of_expressions(*exprs)
+    return wrap_expr(plr.arg_max_horizontal(pyexprs, return_names))
+
+
+@unstable()
+def arg_min_horizontal(
+    *exprs: IntoExpr | Iterable[IntoExpr], return_names: bool = False
+) -> Expr:
+    """
+    Get the index of the column that holds the minimum value horizontally.
+
+    Null values are ignored. If multiple columns hold the minimum value, the first
+    of them is returned. A row that only holds null values returns null.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    *exprs
+        Column(s) to use in the aggregation. Accepts expression input. Strings are
+        parsed as column names, other non-expression inputs are parsed as literals.
+    return_names
+        Return the name of the column instead of its index.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "a": [1, 8, 3],
+    ...         "b": [4, 5, None],
+    ...     }
+    ... )
+    >>> df.with_columns(
+    ...     idx=pl.arg_min_horizontal("a", "b"),
+    ...     name=pl.arg_min_horizontal("a", "b", return_names=True),
+    ... )
+    shape: (3, 4)
+    ┌─────┬──────┬─────┬──────┐
+    │ a   ┆ b    ┆ idx ┆ name │
+    │ --- ┆ ---  ┆ --- ┆ ---  │
+    │ i64 ┆ i64  ┆ u32 ┆ str  │
+    ╞═════╪══════╪═════╪══════╡
+    │ 1   ┆ 4    ┆ 0   ┆ a    │
+    │ 8   ┆ 5    ┆ 1   ┆ b    │
+    │ 3   ┆ null ┆ 0   ┆ a    │
+    └─────┴──────┴─────┴──────┘
+    """
+    pyexprs = parse_into_list_of_expressions(*exprs)
+    return wrap_expr(plr.arg_min_horizontal(pyexprs, return_names))
+
+
 def sum_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     """
     Sum all values horizontally across columns.
@@ -501,6 +501,45 @@ def concat_list(exprs: IntoExpr | Iterable[IntoExpr], *more_exprs: IntoExpr) ->
     return wrap_expr(plr.concat_list(exprs))
 
 
+def zip_lists(exprs: IntoExpr | Iterable[IntoExpr], *more_exprs: IntoExpr) -> Expr:
+    """
+    Zip list columns into a single column of lists of structs.
+
+    The fields of the structs are named after the inputs, so an expression can be
+    evaluated over the elements of all lists with :meth:`Expr.list.eval`, without
+    exploding the lists to rows. The lists of a row must have the same length and a
+    row is null if it is null in any of the inputs.
+
+    Parameters
+    ----------
+    exprs
+        List columns to zip. Accepts expression input. Strings are parsed as column
+        names.
+    *more_exprs
+        Additional list columns to zip, specified as positional arguments.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [[1, 2], [3]], "b": [[4, 5], [6]]})
+    >>> df.select(
+    ...     pl.zip_lists("a", "b")
+    ...     .list.eval(pl.element().struct.field("a") * pl.element().struct.field("b"))
+    ...     .alias("ab")
+    ... )
+    shape: (2, 1)
+    ┌───────────┐
+    │ ab        │
+    │ ---       │
+    │ list[i64] │
+    ╞═══════════╡
+    │ [4, 10]   │
+    │ [18]      │
+    └───────────┘
+    """
+    exprs = parse_into_list_of_expressions(exprs, *more_exprs)
+    return wrap_expr(plr.zip_lists(exprs))
+
+
 @overload
 def struct(
     *exprs: IntoExpr | Iterable[IntoExpr],
@@ -0,0 +1,54 @@
+from __future__ import annotations
+
+import contextlib
+from typing import TYPE_CHECKING
+
+from polars._utils.convert import parse_as_duration_string
+from polars._utils.unstable import unstable
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars.polars as plr
+
+if TYPE_CHECKING:
+    from datetime import timedelta
+
+
+@unstable()
+def register_frequency(name: str, every: str | timedelta) -> None:
+    """
+    Register a custom frequency for use in duration strings.
+
+    Once registered, `name` can be used as a unit wherever Polars accepts a
+    duration string, such as `every` in :meth:`DataFrame.group_by_dynamic`,
+    :meth:`Expr.dt.truncate` and :meth:`DataFrame.upsample`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    name
+        Name of the frequency, consisting of lowercase letters and underscores.
+        Built-in units such as `"mo"` or `"bme"` cannot be overridden.
+    every
+        The positive duration that a single unit of the frequency represents,
+        e.g. `"2w"` or `"1bme"`.
+
+    Examples
+    --------
+    >>> from datetime import date
+    >>> pl.register_frequency("fortnight", "2w")
+    >>> df = pl.DataFrame({"date": [date(2024, 1, 3), date(2024, 1, 17)]})
+    >>> df.select(pl.col("date").dt.truncate("1fortnight"))
+    shape: (2, 1)
+    ┌────────────┐
+    │ date       │
+    │ ---        │
+    │ date       │
+    ╞════════════╡
+    │ 2023-12-25 │
+    │ 2024-01-08 │
+    └────────────┘
+    """
+    plr.register_frequency(name, parse_as_duration_string(every))
@@ -26,6 +26,7 @@ if TYPE_CHECKING:
     from polars._typing import (
         CorrelationMethod,
         EpochTimeUnit,
+        HistBinStrategy,
         IntoExpr,
         PolarsDataType,
         RollingInterpolationMethod,
@@ -896,6 +897,76 @@ def cov(a: IntoExpr, b: IntoExpr, ddof: int = 1) -> Expr:
     return wrap_expr(plr.cov(a, b, ddof))
 
 
+@unstable()
+def hist_2d(
+    x: IntoExpr,
+    y: IntoExpr,
+    bins: IntoExpr | None = None,
+    *,
+    bin_count: int | None = None,
+    bin_strategy: HistBinStrategy | None = None,
+) -> Expr:
+    """
+    Bin the values of two columns into a grid of buckets and count their occurrences.
+
+    Both columns are binned as in :meth:`Expr.hist`. The result is a struct with a
+    row for every pair of buckets, holding the upper breakpoints of both buckets and
+    the number of rows in which both values fall in them. Rows in which either value
+    is null are not counted.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    x
+        Column name or Expression.
+    y
+        Column name or Expression.
+    bins
+        Discretizations to make for both columns.
+        If None given, we determine the boundaries based on the data.
+    bin_count
+        If no bins provided, this will be used to determine
+        the distance of the bins
+    bin_strategy : {'fd', 'sturges'}
+        If neither `bins` nor `bin_count` are provided, the rule that determines
+        the number of bins from the data.
+
+        - 'fd': Freedman-Diaconis, bins of width `2 * IQR / n^(1/3)`.
+        - 'sturges': `log2(n) + 1` bins.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"x": [1, 2, 2], "y": [1, 3, 3]})
+    >>> df.select(pl.hist_2d("x", "y", bins=[1, 2])).unnest("x")
+    shape: (9, 3)
+    ┌──────────────┬──────────────┬───────┐
+    │ x_breakpoint ┆ y_breakpoint ┆ count │
+    │ ---          ┆ ---          ┆ ---   │
+    │ f64          ┆ f64          ┆ u32   │
+    ╞══════════════╪══════════════╪═══════╡
+    │ 1.0          ┆ 1.0          ┆ 1     │
+    │ 1.0          ┆ 2.0          ┆ 0     │
+    │ 1.0          ┆ inf          ┆ 0     │
+    │ 2.0          ┆ 1.0          ┆ 0     │
+    │ 2.0          ┆ 2.0          ┆ 0     │
+    │ 2.0          ┆ inf          ┆ 2     │
+    │ inf          ┆ 1.0          ┆ 0     │
+    │ inf          ┆ 2.0          ┆ 0     │
+    │ inf          ┆ inf          ┆ 0     │
+    └──────────────┴──────────────┴───────┘
+    """
+    x = parse_into_expression(x)
+    y = parse_into_expression(y)
+    if bins is not None:
+        if isinstance(bins, list):
+            bins = pl.Series(bins)
+        bins = parse_into_expression(bins)
+    return wrap_expr(plr.hist_2d(x, y, bins, bin_count, bin_strategy))
+
+
 def map_batches(
     exprs: Sequence[str] | Sequence[Expr],
     function: Callable[[Sequence[Series]], Series],
@@ -1487,11 +1558,252 @@ def exclude(
     return F.col("*").exclude(columns, *more_columns)
 
 
-def groups(column: str) -> Expr:
-    """Syntactic sugar for `pl.col("foo").agg_groups()`."""
+def groups(column: str | None = None) -> Expr:
+    """
+    Syntactic sugar for `pl.col("foo").agg_groups()`.
+
+    Without a column, this is :func:`row_indices`.
+    """
+    if column is None:
+        return row_indices()
     return F.col(column).agg_groups()
 
 
+def row_indices() -> Expr:
+    """
+    Return the indices of the rows of every group as a list.
+
+    Should be used in aggregation context only. In :meth:`DataFrame.rolling` and
+    :meth:`DataFrame.group_by_dynamic` these are the rows of every window, which can
+    be used to gather the rows of a window later on without assigning the rows to the
+    windows again.
+
+    Returns
+    -------
+    Expr
+        Expression of data type `List(UInt32)`, named `row_indices`.
+
+    See Also
+    --------
+    Expr.agg_groups
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"t": [1, 2, 3, 5]})
+    >>> df.rolling("t", period="2i").agg(pl.row_indices())
+    shape: (4, 2)
+    ┌─────┬─────────────┐
+    │ t   ┆ row_indices │
+    │ --- ┆ ---         │
+    │ i64 ┆ list[u32]   │
+    ╞═════╪═════════════╡
+    │ 1   ┆ [0]         │
+    │ 2   ┆ [0, 1]      │
+    │ 3   ┆ [1, 2]      │
+    │ 5   ┆ [3]         │
+    └─────┴─────────────┘
+    """
+    return wrap_expr(plr.row_indices())
+
+
+def lookup(
+    other: DataFrame,
+    key: IntoExpr,
+    value: str,
+    default: IntoExpr | None = None,
+    *,
+    other_key: str | None = None,
+) -> Expr:
+    """
+    Look up the value of every key in a small dimension table.
+
+    The table is embedded in the expression as a hash map instead of being joined,
+    so the lookup works in every context, including :meth:`Expr.over` and
+    :meth:`Expr.list.eval`, and keeps the rows and their order as they are.
+
+    Parameters
+    ----------
+    other
+        The table to look the keys up in. It is materialized into the query plan, so
+        it should be small.
+    key
+        The keys to look up. Accepts expression input. Strings are parsed as column
+        names.
+    value
+        The name of the column of `other` that holds the values.
+    default
+        The value of the keys that aren't in the table. Accepts expression input.
+        Strings are parsed as literals. Defaults to null.
+    other_key
+        The name of the column of `other` that holds the keys. If set to None
+        (default), `key` is evaluated on `other` to get the keys.
+
+    Returns
+    -------
+    Expr
+        Expression of the data type of the `value` column, named after `key`.
+
+    Raises
+    ------
+    InvalidOperationError
+        If the keys of `other` aren't unique.
+
+    See Also
+    --------
+    Expr.replace_strict
+    DataFrame.join
+
+    Examples
+    --------
+    >>> countries = pl.DataFrame(
+    ...     {"code": ["NL", "BE", "FR"], "name": ["Netherlands", "Belgium", "France"]}
+    ... )
+    >>> df = pl.DataFrame({"code": ["FR", "NL", "DE", "FR"], "sales": [1, 2, 3, 4]})
+    >>> df.with_columns(
+    ...     pl.lookup(countries, "code", "name", default="unknown").alias("country")
+    ... )
+    shape: (4, 3)
+    ┌──────┬───────┬─────────────┐
+    │ code ┆ sales ┆ country     │
+    │ ---  ┆ ---   ┆ ---         │
+    │ str  ┆ i64   ┆ str         │
+    ╞══════╪═══════╪═════════════╡
+    │ FR   ┆ 1     ┆ France      │
+    │ NL   ┆ 2     ┆ Netherlands │
+    │ DE   ┆ 3     ┆ unknown     │
+    │ FR   ┆ 4     ┆ France      │
+    └──────┴───────┴─────────────┘
+
+    Look up keys from a column with a different name:
+
+    >>> df = pl.DataFrame({"origin": ["BE", "NL"]})
+    >>> df.select(pl.lookup(countries, "origin", "name", other_key="code"))
+    shape: (2, 1)
+    ┌─────────────┐
+    │ origin      │
+    │ ---         │
+    │ str         │
+    ╞═════════════╡
+    │ Belgium     │
+    │ Netherlands │
+    └─────────────┘
+    """
+    key = parse_into_expression(key)
+    if default is not None:
+        default = parse_into_expression(default, str_as_lit=True)
+    return wrap_expr(plr.lookup(other._df, key, value, default, other_key))
+
+
+def lag(expr: IntoExpr, n: int = 1, default: IntoExpr | None = None) -> Expr:
+    """
+    Get the value of the row `n` rows before every row, like SQL `LAG`.
+
+    Within :meth:`Expr.over`, the rows are those of the partition, in the order of
+    its `order_by`.
+
+    Parameters
+    ----------
+    expr
+        The values. Accepts expression input. Strings are parsed as column names.
+    n
+        The number of rows to look back.
+    default
+        The value of the first `n` rows, which have no row to look back to. Accepts
+        expression input, which is evaluated per row. Strings are parsed as
+        literals. Defaults to null.
+
+    Notes
+    -----
+    Unlike `fill_null`, the `default` is only taken for the rows without a row to
+    look back to; nulls in `expr` are kept.
+
+    See Also
+    --------
+    lead
+    Expr.shift
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "store": ["a", "b", "a", "b", "a"],
+    ...         "day": [1, 1, 2, 2, 3],
+    ...         "sales": [10, 1, None, 2, 30],
+    ...     }
+    ... )
+    >>> df.with_columns(
+    ...     prev=pl.lag("sales", default=0).over("store", order_by="day"),
+    ...     prev_or_own=pl.lag("sales", default=pl.col("sales")).over("store"),
+    ... )
+    shape: (5, 5)
+    ┌───────┬─────┬───────┬──────┬─────────────┐
+    │ store ┆ day ┆ sales ┆ prev ┆ prev_or_own │
+    │ ---   ┆ --- ┆ ---   ┆ ---  ┆ ---         │
+    │ str   ┆ i64 ┆ i64   ┆ i64  ┆ i64         │
+    ╞═══════╪═════╪═══════╪══════╪═════════════╡
+    │ a     ┆ 1   ┆ 10    ┆ 0    ┆ 10          │
+    │ b     ┆ 1   ┆ 1     ┆ 0    ┆ 1           │
+    │ a     ┆ 2   ┆ null  ┆ 10   ┆ 10          │
+    │ b     ┆ 2   ┆ 2     ┆ 1    ┆ 1           │
+    │ a     ┆ 3   ┆ 30    ┆ null ┆ null        │
+    └───────┴─────┴───────┴──────┴─────────────┘
+    """
+    return wrap_expr(parse_into_expression(expr)).shift(n, fill_value=default)
+
+
+def lead(expr: IntoExpr, n: int = 1, default: IntoExpr | None = None) -> Expr:
+    """
+    Get the value of the row `n` rows after every row, like SQL `LEAD`.
+
+    Within :meth:`Expr.over`, the rows are those of the partition, in the order of
+    its `order_by`.
+
+    Parameters
+    ----------
+    expr
+        The values. Accepts expression input. Strings are parsed as column names.
+    n
+        The number of rows to look ahead.
+    default
+        The value of the last `n` rows, which have no row to look ahead to. Accepts
+        expression input, which is evaluated per row. Strings are parsed as
+        literals. Defaults to null.
+
+    See Also
+    --------
+    lag
+    Expr.shift
+
+    Examples
+    --------
+    The change to the price of the next day, which is zero on the last day:
+
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "item": ["x", "x", "y", "x"],
+    ...         "day": [3, 1, 1, 2],
+    ...         "price": [12.0, 10.0, 5.0, 11.0],
+    ...     }
+    ... )
+    >>> next_price = pl.lead("price", default=pl.col("price"))
+    >>> df.with_columns(
+    ...     change=next_price.over("item", order_by="day") - pl.col("price")
+    ... )
+    shape: (4, 4)
+    ┌──────┬─────┬───────┬────────┐
+    │ item ┆ day ┆ price ┆ change │
+    │ ---  ┆ --- ┆ ---   ┆ ---    │
+    │ str  ┆ i64 ┆ f64   ┆ f64    │
+    ╞══════╪═════╪═══════╪════════╡
+    │ x    ┆ 3   ┆ 12.0  ┆ 0.0    │
+    │ x    ┆ 1   ┆ 10.0  ┆ 1.0    │
+    │ y    ┆ 1   ┆ 5.0   ┆ 0.0    │
+    │ x    ┆ 2   ┆ 11.0  ┆ 1.0    │
+    └──────┴─────┴───────┴────────┘
+    """
+    return lag(expr, -n, default)
+
+
 def quantile(
     column: str,
     quantile: float | Expr,
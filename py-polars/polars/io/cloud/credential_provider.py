@@ -0,0 +1,153 @@
+from __future__ import annotations
+
+import abc
+from typing import TYPE_CHECKING, Any, Callable, Dict, Optional, Tuple
+
+from polars.dependencies import import_optional
+
+if TYPE_CHECKING:
+    import sys
+
+    if sys.version_info >= (3, 10):
+        from typing import TypeAlias
+    else:
+        from typing_extensions import TypeAlias
+
+# The credentials and the expiry as a unix timestamp in seconds (`None` if they don't
+# expire).
+CredentialProviderFunctionReturn: TypeAlias = Tuple[Dict[str, str], Optional[int]]
+CredentialProviderFunction: TypeAlias = Callable[[], CredentialProviderFunctionReturn]
+
+
+class CredentialProvider(abc.ABC):
+    """
+    Base class for credential providers.
+
+    A credential provider is called whenever the cloud credentials are needed and the
+    previously returned credentials have (almost) expired. It returns a tuple of the
+    credentials and their expiry as a unix timestamp in seconds, or `None` if they
+    never expire.
+
+    The credentials hold either `aws_access_key_id`, `aws_secret_access_key` and
+    optionally `aws_session_token`, or a `bearer_token` for GCP and Azure.
+
+    Any callable with the same signature can be passed as `credential_provider`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+    """
+
+    @abc.abstractmethod
+    def __call__(self) -> CredentialProviderFunctionReturn:
+        """Fetch the credentials."""
+
+
+class CredentialProviderAWS(CredentialProvider):
+    """
+    AWS credential provider, using `boto3`.
+
+    Parameters
+    ----------
+    profile_name
+        Name of the AWS profile to use.
+    assume_role
+        Arguments passed to the STS `assume_role` call, e.g.
+        `{"RoleArn": "arn:aws:iam::123456789012:role/role", "RoleSessionName": "polars"}`.
+        The temporary credentials of the assumed role are refreshed when they expire.
+    """
+
+    def __init__(
+        self,
+        *,
+        profile_name: str | None = None,
+        assume_role: dict[str, Any] | None = None,
+    ) -> None:
+        self.profile_name = profile_name
+        self.assume_role = assume_role
+
+    def __call__(self) -> CredentialProviderFunctionReturn:
+        boto3 = import_optional("boto3")
+        session = boto3.Session(profile_name=self.profile_name)
+
+        if self.assume_role is not None:
+            response = session.client("sts").assume_role(**self.assume_role)
+            creds = response["Credentials"]
+            return {
+                "aws_access_key_id": creds["AccessKeyId"],
+                "aws_secret_access_key": creds["SecretAccessKey"],
+                "aws_session_token": creds["SessionToken"],
+            }, int(creds["Expiration"].timestamp())
+
+        creds = session.get_credentials()
+        if creds is None:
+            msg = "did not find AWS credentials"
+            raise ValueError(msg)
+        frozen = creds.get_frozen_credentials()
+        out = {
+            "aws_access_key_id": frozen.access_key,
+            "aws_secret_access_key": frozen.secret_key,
+        }
+        if frozen.token is not None:
+            out["aws_session_token"] = frozen.token
+        # Refreshable credentials (e.g. from an instance profile) expose their expiry.
+        expiry = getattr(creds, "_expiry_time", None)
+        return out, None if expiry is None else int(expiry.timestamp())
+
+
+class CredentialProviderGCP(CredentialProvider):
+    """
+    GCP credential provider, using `google.auth` application default credentials.
+
+    Parameters
+    ----------
+    scopes
+        OAuth scopes to request.
+    """
+
+    def __init__(
+        self,
+        *,
+        scopes: list[str] | None = None,
+    ) -> None:
+        self.scopes = scopes or [
+            "https://www.googleapis.com/auth/devstorage.read_write"
+        ]
+
+    def __call__(self) -> CredentialProviderFunctionReturn:
+        google_auth = import_optional("google.auth")
+        requests = import_optional("google.auth.transport.requests")
+
+        creds, _ = google_auth.default(scopes=self.scopes)
+        creds.refresh(requests.Request())
+        expiry = creds.expiry
+        return {"bearer_token": creds.token}, None if expiry is None else int(
+            expiry.timestamp()
+        )
+
+
+class CredentialProviderAzure(CredentialProvider):
+    """
+    Azure credential provider, using `azure.identity.DefaultAzureCredential`.
+
+    Parameters
+    ----------
+    scopes
+        OAuth scopes to request.
+    """
+
+    def __init__(
+        self,
+        *,
+        scopes: list[str] | None = None,
+    ) -> None:
+        self.scopes = scopes or ["https://storage.azure.com/.default"]
+        self._credential: Any = None
+
+    def __call__(self) -> CredentialProviderFunctionReturn:
+        if self._credential is None:
+            identity = import_optional("azure.identity")
+            self._credential = identity.DefaultAzureCredential()
+
+        token = self._credential.get_token(*self.scopes)
+        return {"bearer_token": token.token}, token.expires_on
@@ -26,6 +26,12 @@ def _check_arg_is_1byte(
             raise ValueError(msg)
 
 
+def _check_separator(separator: str) -> None:
+    if not separator:
+        msg = "separator cannot be empty"
+        raise ValueError(msg)
+
+
 def _update_columns(df: DataFrame, new_columns: Sequence[str]) -> DataFrame:
     if df.width > len(new_columns):
         cols = df.columns
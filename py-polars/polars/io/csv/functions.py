@@ -3,7 +3,16 @@ from __future__ import annotations
 import contextlib
 from io import BytesIO, StringIO
 from pathlib import Path
-from typing import IO, TYPE_CHECKING, Any, Callable, Mapping, Sequence
+from typing import (
+    IO,
+    TYPE_CHECKING,
+    Any,
+    Callable,
+    Literal,
+    Mapping,
+    Sequence,
+    overload,
+)
 
 import polars._reexport as pl
 from polars._utils.deprecation import deprecate_renamed_parameter
@@ -20,7 +29,11 @@ from polars.io._utils import (
     parse_row_index_args,
     prepare_file_arg,
 )
-from polars.io.csv._utils import _check_arg_is_1byte, _update_columns
+from polars.io.csv._utils import (
+    _check_arg_is_1byte,
+    _check_separator,
+    _update_columns,
+)
 from polars.io.csv.batched_reader import BatchedCsvReader
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -28,7 +41,90 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
 
 if TYPE_CHECKING:
     from polars import DataFrame, LazyFrame
-    from polars._typing import CsvEncoding, PolarsDataType, SchemaDict
+    from polars._typing import CsvEncoding, CsvOnError, PolarsDataType, SchemaDict
+    from polars.io.cloud.credential_provider import CredentialProviderFunction
+
+
+@overload
+def read_csv(
+    source: str | Path | IO[str] | IO[bytes] | bytes,
+    *,
+    has_header: bool = ...,
+    columns: Sequence[int] | Sequence[str] | None = ...,
+    new_columns: Sequence[str] | None = ...,
+    separator: str = ...,
+    comment_prefix: str | None = ...,
+    quote_char: str | None = ...,
+    skip_rows: int = ...,
+    schema: SchemaDict | None = ...,
+    schema_overrides: (
+        Mapping[str, PolarsDataType] | Sequence[PolarsDataType] | None
+    ) = ...,
+    null_values: str | Sequence[str] | dict[str, str] | None = ...,
+    missing_utf8_is_empty_string: bool = ...,
+    ignore_errors: bool = ...,
+    try_parse_dates: bool = ...,
+    n_threads: int | None = ...,
+    infer_schema_length: int | None = ...,
+    batch_size: int = ...,
+    n_rows: int | None = ...,
+    encoding: CsvEncoding | str = ...,
+    low_memory: bool = ...,
+    rechunk: bool = ...,
+    use_pyarrow: bool = ...,
+    storage_options: dict[str, Any] | None = ...,
+    skip_rows_after_header: int = ...,
+    row_index_name: str | None = ...,
+    row_index_offset: int = ...,
+    sample_size: int = ...,
+    eol_char: str = ...,
+    raise_if_empty: bool = ...,
+    truncate_ragged_lines: bool = ...,
+    decimal_comma: bool = ...,
+    glob: bool = ...,
+    on_error: Literal["raise", "skip"] = ...,
+) -> DataFrame: ...
+
+
+@overload
+def read_csv(
+    source: str | Path | IO[str] | IO[bytes] | bytes,
+    *,
+    has_header: bool = ...,
+    columns: Sequence[int] | Sequence[str] | None = ...,
+    new_columns: Sequence[str] | None = ...,
+    separator: str = ...,
+    comment_prefix: str | None = ...,
+    quote_char: str | None = ...,
+    skip_rows: int = ...,
+    schema: SchemaDict | None = ...,
+    schema_overrides: (
+        Mapping[str, PolarsDataType] | Sequence[PolarsDataType] | None
+    ) = ...,
+    null_values: str | Sequence[str] | dict[str, str] | None = ...,
+    missing_utf8_is_empty_string: bool = ...,
+    ignore_errors: bool = ...,
+    try_parse_dates: bool = ...,
+    n_threads: int | None = ...,
+    infer_schema_length: int | None = ...,
+    batch_size: int = ...,
+    n_rows: int | None = ...,
+    encoding: CsvEncoding | str = ...,
+    low_memory: bool = ...,
+    rechunk: bool = ...,
+    use_pyarrow: bool = ...,
+    storage_options: dict[str, Any] | None = ...,
+    skip_rows_after_header: int = ...,
+    row_index_name: str | None = ...,
+    row_index_offset: int = ...,
+    sample_size: int = ...,
+    eol_char: str = ...,
+    raise_if_empty: bool = ...,
+    truncate_ragged_lines: bool = ...,
+    decimal_comma: bool = ...,
+    glob: bool = ...,
+    on_error: Literal["collect"],
+) -> tuple[DataFrame, DataFrame]: ...
 
 
 @deprecate_renamed_parameter("dtypes", "schema_overrides", version="0.20.31")
@@ -70,7 +166,8 @@ def read_csv(
     truncate_ragged_lines: bool = False,
     decimal_comma: bool = False,
     glob: bool = True,
-) -> DataFrame:
+    on_error: CsvOnError = "raise",
+) -> DataFrame | tuple[DataFrame, DataFrame]:
     r"""
     Read a CSV file into a DataFrame.
 
@@ -93,7 +190,8 @@ def read_csv(
         list is shorter than the width of the DataFrame the remaining
         columns will have their original name.
     separator
-        Single byte character to use as separator in the file.
+        Character or string to use as separator in the file. Separators of more than
+        one byte, such as `"||"`, are supported, but are slower to parse.
     comment_prefix
         A string used to indicate the start of a comment line. Comment lines are skipped
         during parsing. Common examples of comment prefixes are `#` and `//`.
@@ -108,6 +206,8 @@ def read_csv(
         used to partially overwrite a schema.
     schema_overrides
         Overwrite dtypes for specific or all columns during schema inference.
+        Columns overridden as `Decimal` are parsed without a loss of precision;
+        pass the `Decimal` class itself to infer the scale from the data.
     null_values
         Values to interpret as null values. You can provide a:
 
@@ -190,10 +290,25 @@ def read_csv(
         Parse floats using a comma as the decimal separator instead of a period.
     glob
         Expand path given via globbing rules.
+    on_error : {'raise', 'skip', 'collect'}
+        What to do with rows that cannot be parsed, such as rows with a value that does
+        not parse as the dtype of its column or with more fields than the schema.
+
+        - 'raise': raise an error at the first row that cannot be parsed.
+        - 'skip': leave the rows out.
+        - 'collect': leave the rows out and return them in a second DataFrame, with
+          the columns `row` (the position of the row among the rows of the file after
+          the header and skipped rows), `line` (the raw line) and `error` (the reason
+          the row was rejected).
+
+        Values that cannot be parsed become null with `ignore_errors`, which takes
+        precedence. Only supported when the file is read by the polars reader.
 
     Returns
     -------
     DataFrame
+        Or a tuple of the DataFrame and the DataFrame of rejected rows if
+        `on_error="collect"`.
 
     See Also
     --------
@@ -234,8 +349,24 @@ def read_csv(
     │ 2   ┆ Bob     ┆ 1990-09-20 │
     │ 3   ┆ Charlie ┆ 2002-03-08 │
     └─────┴─────────┴────────────┘
+
+    Collect the rows that cannot be parsed instead of raising an error.
+
+    >>> data = BytesIO(b"ID,Name\n1,Alice\ntwo,Bob\n3,Charlie\n")
+    >>> df, rejected = pl.read_csv(
+    ...     data, schema_overrides={"ID": pl.Int64}, on_error="collect"
+    ... )
+    >>> rejected.select("row", "line")
+    shape: (1, 2)
+    ┌─────┬─────────┐
+    │ row ┆ line    │
+    │ --- ┆ ---     │
+    │ u32 ┆ str     │
+    ╞═════╪═════════╡
+    │ 1   ┆ two,Bob │
+    └─────┴─────────┘
     """
-    _check_arg_is_1byte("separator", separator, can_be_empty=False)
+    _check_separator(separator)
     _check_arg_is_1byte("quote_char", quote_char, can_be_empty=True)
     _check_arg_is_1byte("eol_char", eol_char, can_be_empty=False)
 
@@ -253,6 +384,8 @@ def read_csv(
 
     if (
         use_pyarrow
+        and len(separator) == 1
+        and on_error == "raise"
         and schema_overrides is None
         and n_rows is None
         and n_threads is None
@@ -415,7 +548,7 @@ def read_csv(
         raise_if_empty=raise_if_empty,
         storage_options=storage_options,
     ) as data:
-        df = _read_csv_impl(
+        df, rejected = _read_csv_impl(
             data,
             has_header=has_header,
             columns=columns if columns else projection,
@@ -445,10 +578,13 @@ def read_csv(
             truncate_ragged_lines=truncate_ragged_lines,
             decimal_comma=decimal_comma,
             glob=glob,
+            on_error=on_error,
         )
 
     if new_columns:
-        return _update_columns(df, new_columns)
+        df = _update_columns(df, new_columns)
+    if rejected is not None:
+        return df, rejected
     return df
 
 
@@ -483,7 +619,8 @@ def _read_csv_impl(
     truncate_ragged_lines: bool = False,
     decimal_comma: bool = False,
     glob: bool = True,
-) -> DataFrame:
+    on_error: CsvOnError = "raise",
+) -> tuple[DataFrame, DataFrame | None]:
     path: str | None
     if isinstance(source, (str, Path)):
         path = normalize_filepath(source, check_not_directory=False)
@@ -512,6 +649,9 @@ def _read_csv_impl(
     if isinstance(columns, str):
         columns = [columns]
     if isinstance(source, str) and is_glob_pattern(source):
+        if on_error != "raise":
+            msg = "cannot use `on_error` with glob patterns"
+            raise ValueError(msg)
         dtypes_dict = None
         if dtype_list is not None:
             dtypes_dict = dict(dtype_list)
@@ -549,9 +689,9 @@ def _read_csv_impl(
             glob=glob,
         )
         if columns is None:
-            return scan.collect()
+            return scan.collect(), None
         elif is_str_sequence(columns, allow_str=False):
-            return scan.select(columns).collect()
+            return scan.select(columns).collect(), None
         else:
             msg = (
                 "cannot use glob patterns and integer based projection as `columns` argument"
@@ -561,7 +701,7 @@ def _read_csv_impl(
 
     projection, columns = parse_columns_arg(columns)
 
-    pydf = PyDataFrame.read_csv(
+    pydf, pydf_rejected = PyDataFrame.read_csv(
         source,
         infer_schema_length,
         batch_size,
@@ -592,8 +732,10 @@ def _read_csv_impl(
         truncate_ragged_lines=truncate_ragged_lines,
         decimal_comma=decimal_comma,
         schema=schema,
+        on_error=on_error,
     )
-    return wrap_df(pydf)
+    rejected = wrap_df(pydf_rejected) if on_error == "collect" else None
+    return wrap_df(pydf), rejected
 
 
 @deprecate_renamed_parameter("dtypes", "schema_overrides", version="0.20.31")
@@ -658,7 +800,8 @@ def read_csv_batched(
         list is shorter than the width of the DataFrame the remaining
         columns will have their original name.
     separator
-        Single byte character to use as separator in the file.
+        Character or string to use as separator in the file. Separators of more than
+        one byte, such as `"||"`, are supported, but are slower to parse.
     comment_prefix
         A string used to indicate the start of a comment line. Comment lines are skipped
         during parsing. Common examples of comment prefixes are `#` and `//`.
@@ -934,6 +1077,7 @@ def scan_csv(
     decimal_comma: bool = False,
     glob: bool = True,
     storage_options: dict[str, Any] | None = None,
+    credential_provider: CredentialProviderFunction | None = None,
     retries: int = 0,
     file_cache_ttl: int | None = None,
 ) -> LazyFrame:
@@ -953,7 +1097,8 @@ def scan_csv(
         column names will be autogenerated in the following format: `column_x`, with
         `x` being an enumeration over every column in the dataset, starting at 1.
     separator
-        Single byte character to use as separator in the file.
+        Character or string to use as separator in the file. Separators of more than
+        one byte, such as `"||"`, are supported, but are slower to parse.
     comment_prefix
         A string used to indicate the start of a comment line. Comment lines are skipped
         during parsing. Common examples of comment prefixes are `#` and `//`.
@@ -970,7 +1115,9 @@ def scan_csv(
     schema_overrides
         Overwrite dtypes during inference; should be a {colname:dtype,} dict or,
         if providing a list of strings to `new_columns`, a list of dtypes of
-        the same length.
+        the same length. Columns overridden as `Decimal` are parsed without a loss
+        of precision; pass the `Decimal` class itself to infer the scale from the
+        data.
     null_values
         Values to interpret as null values. You can provide a:
 
@@ -1046,6 +1193,14 @@ def scan_csv(
 
         If `storage_options` is not provided, Polars will try to infer the information
         from environment variables.
+    credential_provider
+        Callable that returns cloud credentials and their expiry, called again when
+        the credentials expire. Takes precedence over credentials in
+        `storage_options`. See :class:`polars.io.cloud.CredentialProvider`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     retries
         Number of retries if accessing a cloud instance fails.
     file_cache_ttl
@@ -1136,7 +1291,7 @@ def scan_csv(
             else:
                 return new_columns  # type: ignore[return-value]
 
-    _check_arg_is_1byte("separator", separator, can_be_empty=False)
+    _check_separator(separator)
     _check_arg_is_1byte("quote_char", quote_char, can_be_empty=True)
 
     if isinstance(source, (str, Path)):
@@ -1176,6 +1331,7 @@ def scan_csv(
         glob=glob,
         retries=retries,
         storage_options=storage_options,
+        credential_provider=credential_provider,
         file_cache_ttl=file_cache_ttl,
     )
 
@@ -1210,6 +1366,7 @@ def _scan_csv_impl(
     decimal_comma: bool = False,
     glob: bool = True,
     storage_options: dict[str, Any] | None = None,
+    credential_provider: CredentialProviderFunction | None = None,
     retries: int = 0,
     file_cache_ttl: int | None = None,
 ) -> LazyFrame:
@@ -1262,6 +1419,7 @@ def _scan_csv_impl(
         glob=glob,
         retries=retries,
         cloud_options=storage_options,
+        credential_provider=credential_provider,
         file_cache_ttl=file_cache_ttl,
     )
     return wrap_ldf(pylf)
@@ -27,6 +27,7 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
 
 if TYPE_CHECKING:
     from polars import DataFrame, DataType, LazyFrame
+    from polars.io.cloud.credential_provider import CredentialProviderFunction
 
 
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
@@ -310,9 +311,11 @@ def scan_ipc(
     row_index_name: str | None = None,
     row_index_offset: int = 0,
     storage_options: dict[str, Any] | None = None,
+    credential_provider: CredentialProviderFunction | None = None,
     memory_map: bool = True,
     retries: int = 0,
     file_cache_ttl: int | None = None,
+    allow_missing_files: bool = False,
 ) -> LazyFrame:
     """
     Lazily read from an Arrow IPC (Feather v2) file or multiple files via glob patterns.
@@ -339,6 +342,14 @@ def scan_ipc(
         Extra options that make sense for `fsspec.open()` or a
         particular storage connection.
         e.g. host, port, username, password, etc.
+    credential_provider
+        Callable that returns cloud credentials and their expiry, called again when
+        the credentials expire. Takes precedence over credentials in
+        `storage_options`. See :class:`polars.io.cloud.CredentialProvider`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     memory_map
         Try to memory map the file. This can greatly improve performance on repeated
         queries as the OS may cache pages.
@@ -349,6 +360,10 @@ def scan_ipc(
         Amount of time to keep downloaded cloud files since their last access time,
         in seconds. Uses the `POLARS_FILE_CACHE_TTL` environment variable
         (which defaults to 1 hour) if not given.
+    allow_missing_files
+        Skip the files that don't exist with a warning, instead of raising an error.
+        The existence of all files is checked when the query is planned, with
+        concurrent requests for cloud storage.
 
     """
     if isinstance(source, (str, Path)):
@@ -380,7 +395,9 @@ def scan_ipc(
         parse_row_index_args(row_index_name, row_index_offset),
         memory_map=memory_map,
         cloud_options=storage_options,
+        credential_provider=credential_provider,
         retries=retries,
         file_cache_ttl=file_cache_ttl,
+        allow_missing_files=allow_missing_files,
     )
     return wrap_ldf(pylf)
@@ -49,7 +49,9 @@ def read_ndjson(
         of names given in the schema should match the underlying data dimensions.
     schema_overrides : dict, default None
         Support type specification or override of one or more columns; note that
-        any dtypes inferred from the schema param will be overridden.
+        any dtypes inferred from the schema param will be overridden. Columns
+        overridden as `Decimal` are parsed without a loss of precision; pass the
+        `Decimal` class itself to infer the scale from the data.
     ignore_errors
         Return `Null` if parsing fails because of schema mismatches.
 
@@ -28,6 +28,7 @@ with contextlib.suppress(ImportError):
 if TYPE_CHECKING:
     from polars import DataFrame, DataType, LazyFrame
     from polars._typing import ParallelStrategy, SchemaDict
+    from polars.io.cloud.credential_provider import CredentialProviderFunction
 
 
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
@@ -48,6 +49,7 @@ def read_parquet(
     rechunk: bool = False,
     low_memory: bool = False,
     storage_options: dict[str, Any] | None = None,
+    credential_provider: CredentialProviderFunction | None = None,
     retries: int = 0,
     use_pyarrow: bool = False,
     pyarrow_options: dict[str, Any] | None = None,
@@ -116,6 +118,13 @@ def read_parquet(
 
         If `storage_options` is not provided, Polars will try to infer the information
         from environment variables.
+    credential_provider
+        Callable that returns cloud credentials and their expiry, called again when
+        the credentials expire. Not supported with `use_pyarrow=True`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     retries
         Number of retries if accessing a cloud instance fails.
     use_pyarrow
@@ -190,6 +199,7 @@ def read_parquet(
         low_memory=low_memory,
         cache=False,
         storage_options=storage_options,
+        credential_provider=credential_provider,
         retries=retries,
         glob=glob,
     )
@@ -303,7 +313,9 @@ def scan_parquet(
     low_memory: bool = False,
     cache: bool = True,
     storage_options: dict[str, Any] | None = None,
+    credential_provider: CredentialProviderFunction | None = None,
     retries: int = 0,
+    allow_missing_files: bool = False,
 ) -> LazyFrame:
     """
     Lazily read from a local or cloud-hosted parquet file (or files).
@@ -362,8 +374,20 @@ def scan_parquet(
 
         If `storage_options` is not provided, Polars will try to infer the information
         from environment variables.
+    credential_provider
+        Callable that returns cloud credentials and their expiry, called again when
+        the credentials expire. Takes precedence over credentials in
+        `storage_options`. See :class:`polars.io.cloud.CredentialProvider`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     retries
         Number of retries if accessing a cloud instance fails.
+    allow_missing_files
+        Skip the files that don't exist with a warning, instead of raising an error.
+        The existence of all files is checked when the query is planned, with
+        concurrent requests for cloud storage.
 
     See Also
     --------
@@ -407,6 +431,7 @@ def scan_parquet(
         row_index_name=row_index_name,
         row_index_offset=row_index_offset,
         storage_options=storage_options,
+        credential_provider=credential_provider,
         low_memory=low_memory,
         use_statistics=use_statistics,
         hive_partitioning=hive_partitioning,
@@ -414,6 +439,7 @@ def scan_parquet(
         try_parse_hive_dates=try_parse_hive_dates,
         retries=retries,
         glob=glob,
+        allow_missing_files=allow_missing_files,
     )
 
 
@@ -427,6 +453,7 @@ def _scan_parquet_impl(
     row_index_name: str | None = None,
     row_index_offset: int = 0,
     storage_options: dict[str, object] | None = None,
+    credential_provider: CredentialProviderFunction | None = None,
     low_memory: bool = False,
     use_statistics: bool = True,
     hive_partitioning: bool | None = None,
@@ -434,6 +461,7 @@ def _scan_parquet_impl(
     hive_schema: SchemaDict | None = None,
     try_parse_hive_dates: bool = True,
     retries: int = 0,
+    allow_missing_files: bool = False,
 ) -> LazyFrame:
     if isinstance(source, list):
         sources = source
@@ -457,11 +485,13 @@ def _scan_parquet_impl(
         parse_row_index_args(row_index_name, row_index_offset),
         low_memory,
         cloud_options=storage_options,
+        credential_provider=credential_provider,
         use_statistics=use_statistics,
         hive_partitioning=hive_partitioning,
         hive_schema=hive_schema,
         try_parse_hive_dates=try_parse_hive_dates,
         retries=retries,
         glob=glob,
+        allow_missing_files=allow_missing_files,
     )
     return wrap_ldf(pylf)
@@ -0,0 +1,29 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    import pyarrow as pa
+
+    from polars.polars import PyArrowStream
+
+
+class ArrowStream:
+    """
+    The result of a query as a stream of Arrow record batches.
+
+    The batches are produced by the streaming engine as the stream is read, so the
+    result doesn't have to fit into memory. The stream can be read once, either by
+    a consumer of the Arrow PyCapsule interface (such as DuckDB or
+    `pyarrow.RecordBatchReader.from_stream`) or through :meth:`to_pyarrow`.
+    """
+
+    def __init__(self, stream: PyArrowStream) -> None:
+        self._inner = stream
+
+    def __arrow_c_stream__(self, requested_schema: Any = None) -> Any:
+        return self._inner.__arrow_c_stream__(requested_schema)
+
+    def to_pyarrow(self) -> pa.RecordBatchReader:
+        """Read the stream with a pyarrow `RecordBatchReader`."""
+        return self._inner.to_pyarrow()
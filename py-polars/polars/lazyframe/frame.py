@@ -34,6 +34,7 @@ from polars._utils.parse import (
     parse_into_expression,
     parse_into_list_of_expressions,
 )
+from polars._utils.profile import write_speedscope
 from polars._utils.serde import serialize_polars_object
 from polars._utils.slice import LazyPolarsSlice
 from polars._utils.unstable import issue_unstable_warning, unstable
@@ -79,6 +80,7 @@ from polars.datatypes.group import DataTypeGroup
 from polars.dependencies import import_optional, subprocess
 from polars.exceptions import PerformanceWarning
 from polars.lazyframe.group_by import LazyGroupBy
+from polars.lazyframe.arrow_stream import ArrowStream
 from polars.lazyframe.in_process import InProcessQuery
 from polars.schema import Schema
 from polars.selectors import _expand_selectors, by_dtype, expand_selector
@@ -104,10 +106,12 @@ if TYPE_CHECKING:
         FrameInitTypes,
         IntoExpr,
         IntoExprColumn,
+        IpcCompression,
         JoinStrategy,
         JoinValidation,
         Label,
         Orientation,
+        PivotAgg,
         PolarsDataType,
         RollingInterpolationMethod,
         SchemaDefinition,
@@ -115,6 +119,7 @@ if TYPE_CHECKING:
         SerializationFormat,
         StartBy,
         UniqueKeepStrategy,
+        WriteMode,
     )
     from polars.dependencies import numpy as np
 
@@ -1106,6 +1111,33 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         else:
             return self._ldf.describe_plan()
 
+    @unstable()
+    def explain_analyze(self, *, format: Literal["plain", "dot"] = "plain") -> str:
+        """
+        Run the query and show the optimized query plan with what each node did.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Every node of the plan is annotated with the number of rows it produced
+        and its wall time in microseconds, which includes the time spent in its
+        inputs. Filters also show their selectivity, the fraction of their input
+        rows they kept. Nodes that are executed as part of another node, such as
+        a filter that is pushed down into a scan, are not annotated.
+
+        Parameters
+        ----------
+        format : {'plain', 'dot'}
+            Show the plan as text, or in the dot language as in `show_graph`.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": range(10)})
+        >>> lf.filter(pl.col("a") > 5).sort("a").explain_analyze()  # doctest: +SKIP
+        """
+        return self._ldf.explain_analyze(format == "dot")
+
     def show_graph(
         self,
         *,
@@ -1160,6 +1192,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             Combine sequential independent calls to with_columns
         streaming
             Run parts of the query in a streaming fashion (this is in an alpha state)
+        speedscope
+            Write the profile to this path as a JSON file in the format of
+            `speedscope <https://www.speedscope.app>`_, which shows the nodes as a
+            flame graph.
 
         Examples
         --------
@@ -1266,6 +1302,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         nulls_last: bool | Sequence[bool] = False,
         maintain_order: bool = False,
         multithreaded: bool = True,
+        collation: str | None = None,
+        stable: bool = True,
     ) -> LazyFrame:
         """
         Sort the LazyFrame by the given columns.
@@ -1284,11 +1322,30 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             Place null values last; can specify a single boolean applying to all columns
             or a sequence of booleans for per-column control.
         maintain_order
-            Whether the order should be maintained if elements are equal.
-            Note that if `true` streaming is not possible and performance might be
-            worse since this requires a stable search.
+            Whether the order should be maintained if elements are equal, in every
+            engine. Note that if `true` streaming is not possible and performance might
+            be worse since this requires a stable search.
         multithreaded
             Sort using multiple threads.
+        collation
+            Sort string columns by the collation of this locale instead of by their
+            bytes, given as a BCP 47 language tag such as `"de-AT"`. The strength of the
+            collation is set with the `ks` keyword, e.g. `"de-AT-u-ks-level2"` ignores
+            case.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        stable
+            Whether rows with equal sort keys keep their order in the in-memory engine.
+            Set to `False` to allow a faster unstable sort, which notably pays off when
+            sorting many rows by a single numeric column.
+
+        Notes
+        -----
+        By default, the in-memory engine sorts stably: rows with equal sort keys keep
+        their order, whatever the number of sort columns. The streaming engine only
+        does so with `maintain_order=True`.
 
         Examples
         --------
@@ -1359,7 +1416,13 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         if isinstance(by, str) and not more_by:
             return self._from_pyldf(
                 self._ldf.sort(
-                    by, descending, nulls_last, maintain_order, multithreaded
+                    by,
+                    descending,
+                    nulls_last,
+                    maintain_order,
+                    stable,
+                    multithreaded,
+                    collation,
                 )
             )
 
@@ -1368,7 +1431,13 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         nulls_last = extend_bool(nulls_last, len(by), "nulls_last", "by")
         return self._from_pyldf(
             self._ldf.sort_by_exprs(
-                by, descending, nulls_last, maintain_order, multithreaded
+                by,
+                descending,
+                nulls_last,
+                maintain_order,
+                stable,
+                multithreaded,
+                collation,
             )
         )
 
@@ -1622,6 +1691,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         truncate_nodes: int = 0,
         figsize: tuple[int, int] = (18, 8),
         streaming: bool = False,
+        speedscope: str | Path | None = None,
+        **_kwargs: Any,
     ) -> tuple[DataFrame, DataFrame]:
         """
         Profile a LazyFrame.
@@ -1630,6 +1701,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         containing the materialized DataFrame and a DataFrame that
         contains profiling information of each node that is executed.
 
+        Every node of the optimized plan gets a row with when it started and ended,
+        including the time spent in its inputs, and the number of rows it received
+        and produced. The nodes form a tree: the `parent` column holds the `id` of the
+        node that consumes the output of a node. The first row is the optimization of
+        the query, which has no `id`.
+
         The units of the timings are microseconds.
 
         Parameters
@@ -1661,6 +1738,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             matplotlib figsize of the profiling plot
         streaming
             Run parts of the query in a streaming fashion (this is in an alpha state)
+        speedscope
+            Write the profile to this path as a JSON file in the format of
+            `speedscope <https://www.speedscope.app>`_, which shows the nodes as a
+            flame graph.
 
         Examples
         --------
@@ -1684,16 +1765,17 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
          │ b   ┆ 11  ┆ 10  │
          │ c   ┆ 6   ┆ 1   │
          └─────┴─────┴─────┘,
-         shape: (3, 3)
-         ┌─────────────────────────┬───────┬──────┐
-         │ node                    ┆ start ┆ end  │
-         │ ---                     ┆ ---   ┆ ---  │
-         │ str                     ┆ u64   ┆ u64  │
-         ╞═════════════════════════╪═══════╪══════╡
-         │ optimization            ┆ 0     ┆ 5    │
-         │ group_by_partitioned(a) ┆ 5     ┆ 470  │
-         │ sort(a)                 ┆ 475   ┆ 1964 │
-         └─────────────────────────┴───────┴──────┘)
+         shape: (4, 7)
+         ┌─────────────────────────┬───────┬──────┬─────────┬──────────┬──────┬────────┐
+         │ node                    ┆ start ┆ end  ┆ rows_in ┆ rows_out ┆ id   ┆ parent │
+         │ ---                     ┆ ---   ┆ ---  ┆ ---     ┆ ---      ┆ ---  ┆ ---    │
+         │ str                     ┆ u64   ┆ u64  ┆ u64     ┆ u64      ┆ u32  ┆ u32    │
+         ╞═════════════════════════╪═══════╪══════╪═════════╪══════════╪══════╪════════╡
+         │ optimization            ┆ 0     ┆ 5    ┆ null    ┆ null     ┆ null ┆ null   │
+         │ sort(a)                 ┆ 5     ┆ 1964 ┆ 3       ┆ 3        ┆ 0    ┆ null   │
+         │ group_by_partitioned(a) ┆ 7     ┆ 470  ┆ 6       ┆ 3        ┆ 1    ┆ 0      │
+         │ df                      ┆ 8     ┆ 10   ┆ 0       ┆ 6        ┆ 2    ┆ 1      │
+         └─────────────────────────┴───────┴──────┴─────────┴──────────┴──────┴────────┘)
         """
         if no_optimization:
             predicate_pushdown = False
@@ -1713,11 +1795,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             cluster_with_columns,
             streaming,
             _eager=False,
-            new_streaming=False,
+            new_streaming=_kwargs.get("new_streaming", False),
         )
         df, timings = ldf.profile()
         (df, timings) = wrap_df(df), wrap_df(timings)
 
+        if speedscope is not None:
+            write_speedscope(timings, speedscope)
+
         if show_plot:
             import_optional(
                 "matplotlib",
@@ -1727,7 +1812,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
             fig, ax = plt.subplots(1, figsize=figsize)
 
-            max_val = timings["end"][-1]
+            max_val = max(timings["end"])
             timings_ = timings.reverse()
 
             if max_val > 1e9:
@@ -1743,7 +1828,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                     F.col("node").str.slice(0, truncate_nodes) + "..."
                 )
 
-            max_in_unit = timings_["end"][0]
+            max_in_unit = max(timings_["end"])
             ax.barh(
                 timings_["node"],
                 width=timings_["end"] - timings_["start"],
@@ -1757,6 +1842,108 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         return df, timings
 
+    @unstable()
+    def collect_arrow_stream(
+        self,
+        *,
+        type_coercion: bool = True,
+        predicate_pushdown: bool = True,
+        projection_pushdown: bool = True,
+        simplify_expression: bool = True,
+        slice_pushdown: bool = True,
+        no_optimization: bool = False,
+        future: bool = False,
+    ) -> ArrowStream:
+        """
+        Execute the query with the streaming engine, as a stream of Arrow batches.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The batches are produced as the stream is read and the query waits while
+        they are not consumed, so the result doesn't have to fit into memory. The
+        returned object implements the Arrow PyCapsule interface, which lets DuckDB,
+        DataFusion and pyarrow read it directly.
+
+        This method raises an error if the query cannot be completely done in a
+        streaming fashion.
+
+        Parameters
+        ----------
+        type_coercion
+            Do type coercion optimization.
+        predicate_pushdown
+            Do predicate pushdown optimization.
+        projection_pushdown
+            Do projection pushdown optimization.
+        simplify_expression
+            Run simplify expressions optimization.
+        slice_pushdown
+            Slice pushdown optimization.
+        no_optimization
+            Turn off (certain) optimizations.
+        future
+            Setting this to `True` will write Polars' internal data structures that
+            might not be available by other Arrow implementations.
+
+        Returns
+        -------
+        ArrowStream
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> reader = lf.collect_arrow_stream().to_pyarrow()  # doctest: +SKIP
+        >>> for batch in reader:  # doctest: +SKIP
+        ...     print(batch.num_rows)
+        """
+        lf = self._set_sink_optimizations(
+            type_coercion=type_coercion,
+            predicate_pushdown=predicate_pushdown,
+            projection_pushdown=projection_pushdown,
+            simplify_expression=simplify_expression,
+            slice_pushdown=slice_pushdown,
+            no_optimization=no_optimization,
+        )
+        return ArrowStream(lf.collect_arrow_stream(future))
+
+    @unstable()
+    def collect_with_scan_stats(self) -> tuple[DataFrame, DataFrame]:
+        """
+        Collect the query and report what its file scans read.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Useful to verify that a partitioning strategy actually lets queries skip
+        data. Only Parquet scans are reported; scans of which every file is pruned
+        are not executed and don't show up.
+
+        Returns
+        -------
+        tuple of DataFrames
+            The query result, and a DataFrame with a row per scan containing:
+
+            - source: the first file of the scan.
+            - files_listed: the number of files before hive partition pruning.
+            - files_read: the number of files read.
+            - row_groups_read: the number of row groups read.
+            - row_groups_pruned: the number of row groups skipped by their
+              statistics.
+            - bytes_read: the compressed size of the column chunks read.
+            - predicate: the predicate used for pruning.
+
+        Examples
+        --------
+        >>> lf = pl.scan_parquet("data/**/*.parquet")  # doctest: +SKIP
+        >>> lf = lf.filter(pl.col("year") == 2024)  # doctest: +SKIP
+        >>> df, stats = lf.collect_with_scan_stats()  # doctest: +SKIP
+        """
+        df, stats = self._ldf.collect_with_scan_stats()
+        return wrap_df(df), wrap_df(stats)
+
     @overload
     def collect(
         self,
@@ -2156,6 +2343,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         row_group_size: int | None = None,
         data_pagesize_limit: int | None = None,
         maintain_order: bool = True,
+        write_mode: WriteMode = "overwrite",
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2215,6 +2403,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
+        write_mode : {'overwrite', 'error_if_exists'}
+            What to do if `path` already exists: replace it or raise a
+            `FileExistsError`. Parquet files cannot be appended to.
+
+            The data is written to a temporary file next to `path` that is moved into
+            place once it is complete, so readers never see a partially written file.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2271,6 +2465,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             row_group_size=row_group_size,
             data_pagesize_limit=data_pagesize_limit,
             maintain_order=maintain_order,
+            write_mode=write_mode,
         )
 
     @unstable()
@@ -2280,6 +2475,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         *,
         compression: str | None = "zstd",
         maintain_order: bool = True,
+        write_mode: WriteMode = "overwrite",
+        uncompressed_columns: Sequence[str] | None = None,
+        statistics: bool = False,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2306,6 +2504,23 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
+        write_mode : {'overwrite', 'error_if_exists', 'append'}
+            What to do if `path` already exists.
+
+            - overwrite: replace the file.
+            - error_if_exists: raise a `FileExistsError`.
+            - append: add the rows after the rows of the file, which must have the
+              same schema.
+
+            The data is written to a temporary file next to `path` that is moved into
+            place once it is complete, so readers never see a partially written file.
+        uncompressed_columns
+            Columns that are written without compression, such as columns that don't
+            compress well.
+        statistics
+            Write the minimum, maximum and null count of the columns of every record
+            batch in the footer of the file, which :func:`scan_ipc` uses to skip
+            record batches.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2341,6 +2556,82 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             path=path,
             compression=compression,
             maintain_order=maintain_order,
+            write_mode=write_mode,
+            uncompressed_columns=list(uncompressed_columns or []),
+            statistics=statistics,
+        )
+
+    @unstable()
+    def sink_ipc_stream(
+        self,
+        path: str | Path,
+        *,
+        compression: IpcCompression = "uncompressed",
+        compression_level: int | None = None,
+        maintain_order: bool = True,
+        type_coercion: bool = True,
+        predicate_pushdown: bool = True,
+        projection_pushdown: bool = True,
+        simplify_expression: bool = True,
+        slice_pushdown: bool = True,
+        no_optimization: bool = False,
+    ) -> None:
+        """
+        Evaluate the query in streaming mode and write to an Arrow IPC stream.
+
+        .. warning::
+            Streaming mode is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The batches are written to `path` while they are produced, so `path` can be a
+        named pipe or a device such as `/dev/stdout` that another process reads the
+        stream from. Categorical columns that gain new categories between batches
+        only send the new categories, as delta dictionary batches.
+
+        Parameters
+        ----------
+        path
+            File path to which the stream should be written.
+        compression : {'uncompressed', 'lz4', 'zstd'}
+            Compression of the buffers of the batches.
+        compression_level
+            The compression level: 0-12 for lz4 and 1-22 for zstd. Defaults to the
+            default level of the codec.
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will  be slightly faster.
+        type_coercion
+            Do type coercion optimization.
+        predicate_pushdown
+            Do predicate pushdown optimization.
+        projection_pushdown
+            Do projection pushdown optimization.
+        simplify_expression
+            Run simplify expressions optimization.
+        slice_pushdown
+            Slice pushdown optimization.
+        no_optimization
+            Turn off (certain) optimizations.
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> lf.sink_ipc_stream("/tmp/polars.fifo", compression="lz4")  # doctest: +SKIP
+        """
+        lf = self._set_sink_optimizations(
+            type_coercion=type_coercion,
+            predicate_pushdown=predicate_pushdown,
+            projection_pushdown=projection_pushdown,
+            simplify_expression=simplify_expression,
+            slice_pushdown=slice_pushdown,
+            no_optimization=no_optimization,
+        )
+
+        lf.sink_ipc_stream(
+            path=normalize_filepath(path),
+            compression=compression,
+            compression_level=compression_level,
+            maintain_order=maintain_order,
         )
 
     @unstable()
@@ -2359,9 +2650,13 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         time_format: str | None = None,
         float_scientific: bool | None = None,
         float_precision: int | None = None,
+        float_scientific_threshold: int | None = None,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: str = "infer",
+        compression_level: int | None = None,
         maintain_order: bool = True,
+        write_mode: WriteMode = "overwrite",
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2414,6 +2709,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         float_precision
             Number of decimal places to write, applied to both `Float32` and
             `Float64` datatypes.
+        float_scientific_threshold
+            When `float_scientific` is None, write floats with an absolute value of
+            at least `10**float_scientific_threshold`, or a non-zero absolute value
+            below `10**-float_scientific_threshold`, in scientific form and all other
+            floats in positional form.
         null_value
             A string representing null values (defaulting to the empty string).
         quote_style : {'necessary', 'always', 'non_numeric', 'never'}
@@ -2433,9 +2733,32 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        compression : {'infer', 'uncompressed', 'gzip', 'bgzip', 'zstd'}
+            Compression of the whole file. With "infer" the compression follows the
+            extension of `path`: `.gz` for gzip, `.bgz` for bgzip and `.zst` for
+            zstd; other files are not compressed.
+
+            - gzip: a single gzip stream.
+            - bgzip: blocked gzip (BGZF), a series of gzip blocks of at most 64 KiB.
+              Any gzip reader can decompress it and it can be read from a block
+              offset, e.g. by `tabix`.
+            - zstd: zstd frames compressed in parallel.
+        compression_level
+            The compression level: 0-9 for gzip and bgzip and up to 22 for zstd.
+            Defaults to the default level of the codec.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
+        write_mode : {'overwrite', 'error_if_exists', 'append'}
+            What to do if `path` already exists.
+
+            - overwrite: replace the file.
+            - error_if_exists: raise a `FileExistsError`.
+            - append: add the rows after the rows of the file, which must have the
+              same schema. The header is not written again.
+
+            The data is written to a temporary file next to `path` that is moved into
+            place once it is complete, so readers never see a partially written file.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2457,6 +2780,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         --------
         >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
         >>> lf.sink_csv("out.csv")  # doctest: +SKIP
+
+        Compress the output with zstd, inferred from the file extension.
+
+        >>> lf.sink_csv("out.csv.zst")  # doctest: +SKIP
         """
         from polars.io.csv._utils import _check_arg_is_1byte
 
@@ -2487,9 +2814,13 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             time_format=time_format,
             float_scientific=float_scientific,
             float_precision=float_precision,
+            float_scientific_threshold=float_scientific_threshold,
             null_value=null_value,
             quote_style=quote_style,
             maintain_order=maintain_order,
+            compression=compression,
+            compression_level=compression_level,
+            write_mode=write_mode,
         )
 
     @unstable()
@@ -2497,7 +2828,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path,
         *,
+        compression: str = "infer",
+        compression_level: int | None = None,
         maintain_order: bool = True,
+        write_mode: WriteMode = "overwrite",
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2518,9 +2852,32 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         ----------
         path
             File path to which the file should be written.
+        compression : {'infer', 'uncompressed', 'gzip', 'bgzip', 'zstd'}
+            Compression of the whole file. With "infer" the compression follows the
+            extension of `path`: `.gz` for gzip, `.bgz` for bgzip and `.zst` for
+            zstd; other files are not compressed.
+
+            - gzip: a single gzip stream.
+            - bgzip: blocked gzip (BGZF), a series of gzip blocks of at most 64 KiB.
+              Any gzip reader can decompress it and it can be read from a block
+              offset, e.g. by `tabix`.
+            - zstd: zstd frames compressed in parallel.
+        compression_level
+            The compression level: 0-9 for gzip and bgzip and up to 22 for zstd.
+            Defaults to the default level of the codec.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
+        write_mode : {'overwrite', 'error_if_exists', 'append'}
+            What to do if `path` already exists.
+
+            - overwrite: replace the file.
+            - error_if_exists: raise a `FileExistsError`.
+            - append: add the rows after the rows of the file, which must have the
+              same schema.
+
+            The data is written to a temporary file next to `path` that is moved into
+            place once it is complete, so readers never see a partially written file.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2552,7 +2909,13 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             no_optimization=no_optimization,
         )
 
-        return lf.sink_json(path=path, maintain_order=maintain_order)
+        return lf.sink_json(
+            path=path,
+            maintain_order=maintain_order,
+            compression=compression,
+            compression_level=compression_level,
+            write_mode=write_mode,
+        )
 
     def _set_sink_optimizations(
         self,
@@ -3626,6 +3989,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
            - 1mo   (1 calendar month)
            - 1q    (1 calendar quarter)
            - 1y    (1 calendar year)
+           - 1bme  (1 business month end)
+           - 1qs   (1 calendar quarter, same as 1q)
+           - 1w_sun (1 calendar week starting on Sunday, likewise for
+             w_mon, w_tue, ..., w_sat)
            - 1i    (1 index count)
 
            Or combine them:
@@ -3635,6 +4002,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
            not be 24 hours, due to daylight savings). Similarly for "calendar week",
            "calendar month", "calendar quarter", and "calendar year".
 
+           The anchored frequencies `bme`, `qs` and `w_mon` to `w_sun` cannot be
+           combined with other units. Custom frequencies can be registered with
+           :func:`register_frequency`.
+
            In case of a group_by_dynamic on an integer column, the windows are defined by:
 
            - "1i"      # length 1
@@ -3832,66 +4203,431 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         )
         return LazyGroupBy(lgb)
 
-    def join_asof(
+    @unstable()
+    def resample(
         self,
-        other: LazyFrame,
+        every: str | timedelta,
         *,
-        left_on: str | None | Expr = None,
-        right_on: str | None | Expr = None,
-        on: str | None | Expr = None,
-        by_left: str | Sequence[str] | None = None,
-        by_right: str | Sequence[str] | None = None,
-        by: str | Sequence[str] | None = None,
-        strategy: AsofJoinStrategy = "backward",
-        suffix: str = "_right",
-        tolerance: str | int | float | timedelta | None = None,
-        allow_parallel: bool = True,
-        force_parallel: bool = False,
-        coalesce: bool | None = None,
+        index_column: IntoExpr,
+        agg: IntoExpr | Iterable[IntoExpr],
+        group_by: IntoExpr | Iterable[IntoExpr] | None = None,
+        fill: Literal["null", "forward"] | Any = "null",
     ) -> LazyFrame:
         """
-        Perform an asof join.
+        Aggregate the data in regular time windows and add the windows without data.
 
-        This is similar to a left-join except that we match on nearest key rather than
-        equal keys.
+        This combines :meth:`group_by_dynamic` with windows of length `every` and
+        upsampling the result to a window every `every` in a single operation. Within
+        every group, all windows between the first and the last window with data are
+        present in the result.
 
-        Both DataFrames must be sorted by the join_asof key.
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
-        For each row in the left DataFrame:
+        Parameters
+        ----------
+        every
+            Interval of the windows. See :meth:`group_by_dynamic` for the string
+            language of the interval.
+        index_column
+            Column used to group based on the time window. Must be sorted in
+            ascending order within every group.
+        agg
+            Aggregations to compute for every window.
+        group_by
+            Also group by this column/these columns.
+        fill : {'null', 'forward'} or value
+            How the null values of the aggregated columns, including all values of the
+            windows without data, are filled.
 
-          - A "backward" search selects the last row in the right DataFrame whose
-            'on' key is less than or equal to the left's key.
+            - 'null': Leave them null.
+            - 'forward': Use the previous value of the same group.
+            - Any other value fills them with that value. Use :func:`lit` to fill
+              with the strings 'null' or 'forward'.
 
-          - A "forward" search selects the first row in the right DataFrame whose
-            'on' key is greater than or equal to the left's key.
+        See Also
+        --------
+        group_by_dynamic
+        DataFrame.upsample
 
-            A "nearest" search selects the last row in the right DataFrame whose value
-            is nearest to the left's key. String keys are not currently supported for a
-            nearest search.
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "time": [
+        ...             datetime(2021, 12, 16, 0, 0),
+        ...             datetime(2021, 12, 16, 0, 30),
+        ...             datetime(2021, 12, 16, 3, 0),
+        ...         ],
+        ...         "value": [1, 2, 3],
+        ...     }
+        ... )
+        >>> lf.resample(
+        ...     "1h", index_column="time", agg=pl.col("value").sum()
+        ... ).collect()
+        shape: (4, 2)
+        ┌─────────────────────┬───────┐
+        │ time                ┆ value │
+        │ ---                 ┆ ---   │
+        │ datetime[μs]        ┆ i64   │
+        ╞═════════════════════╪═══════╡
+        │ 2021-12-16 00:00:00 ┆ 3     │
+        │ 2021-12-16 01:00:00 ┆ null  │
+        │ 2021-12-16 02:00:00 ┆ null  │
+        │ 2021-12-16 03:00:00 ┆ 3     │
+        └─────────────────────┴───────┘
+
+        Fill the windows without data with a value.
+
+        >>> lf.resample(
+        ...     "1h", index_column="time", agg=pl.col("value").sum(), fill=0
+        ... ).collect()
+        shape: (4, 2)
+        ┌─────────────────────┬───────┐
+        │ time                ┆ value │
+        │ ---                 ┆ ---   │
+        │ datetime[μs]        ┆ i64   │
+        ╞═════════════════════╪═══════╡
+        │ 2021-12-16 00:00:00 ┆ 3     │
+        │ 2021-12-16 01:00:00 ┆ 0     │
+        │ 2021-12-16 02:00:00 ┆ 0     │
+        │ 2021-12-16 03:00:00 ┆ 3     │
+        └─────────────────────┴───────┘
+        """
+        index_column = parse_into_expression(index_column)
+        every = parse_as_duration_string(every)
+        pyexprs_by = (
+            parse_into_list_of_expressions(group_by) if group_by is not None else []
+        )
+        pyexprs_agg = parse_into_list_of_expressions(agg)
+        if isinstance(fill, str) and fill in ("null", "forward"):
+            ldf = self._ldf.resample(
+                index_column, every, pyexprs_by, pyexprs_agg, fill, None
+            )
+        else:
+            fill_value = parse_into_expression(fill, str_as_lit=True)
+            ldf = self._ldf.resample(
+                index_column, every, pyexprs_by, pyexprs_agg, "null", fill_value
+            )
+        return self._from_pyldf(ldf)
 
-        The default is "backward".
+    @unstable()
+    def group_by_grouping_sets(
+        self,
+        sets: Sequence[IntoExpr | Iterable[IntoExpr]],
+        *,
+        agg: IntoExpr | Iterable[IntoExpr],
+        grouping_id: str | None = None,
+    ) -> LazyFrame:
+        """
+        Aggregate by several sets of keys at once, as SQL's `GROUP BY GROUPING SETS`.
+
+        The result holds the groups of every set, in the order of `sets`. It has a
+        column for every distinct key of the sets; the keys that are not in a set are
+        null in the rows of that set.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
         Parameters
         ----------
-        other
-            Lazy DataFrame to join with.
-        left_on
-            Join column of the left DataFrame.
-        right_on
-            Join column of the right DataFrame.
-        on
-            Join column of both DataFrames. If set, `left_on` and `right_on` should be
-            None.
-        by
-            Join on these columns before doing asof join.
-        by_left
-            Join on these columns before doing asof join.
-        by_right
-            Join on these columns before doing asof join.
-        strategy : {'backward', 'forward', 'nearest'}
-            Join strategy.
+        sets
+            The sets of keys to group by. An empty set aggregates all rows.
+        agg
+            Aggregations to compute for every group.
+        grouping_id
+            Name of a `UInt64` column, added after the keys, that tells the sets
+            apart. It has a bit set for every key that is not in the set of the row,
+            the first key being the most significant bit.
+
+        See Also
+        --------
+        group_by_rollup
+        group_by_cube
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {"store": ["a", "a", "b"], "day": [1, 2, 1], "sales": [10, 20, 30]}
+        ... )
+        >>> lf.group_by_grouping_sets(
+        ...     [["store"], ["day"]], agg=pl.col("sales").sum()
+        ... ).sort("store", "day", nulls_last=True).collect()
+        shape: (4, 3)
+        ┌───────┬──────┬───────┐
+        │ store ┆ day  ┆ sales │
+        │ ---   ┆ ---  ┆ ---   │
+        │ str   ┆ i64  ┆ i64   │
+        ╞═══════╪══════╪═══════╡
+        │ a     ┆ null ┆ 30    │
+        │ b     ┆ null ┆ 30    │
+        │ null  ┆ 1    ┆ 40    │
+        │ null  ┆ 2    ┆ 20    │
+        └───────┴──────┴───────┘
+        """
+        pysets = [parse_into_list_of_expressions(s) for s in sets]
+        pyexprs_agg = parse_into_list_of_expressions(agg)
+        return self._from_pyldf(
+            self._ldf.group_by_grouping_sets(pysets, pyexprs_agg, grouping_id)
+        )
+
+    @unstable()
+    def group_by_rollup(
+        self,
+        by: IntoExpr | Iterable[IntoExpr],
+        *,
+        agg: IntoExpr | Iterable[IntoExpr],
+        grouping_id: str | None = None,
+    ) -> LazyFrame:
+        """
+        Aggregate by the hierarchy of the keys, as SQL's `GROUP BY ROLLUP`.
+
+        For the keys `[a, b, c]` this aggregates by the sets `(a, b, c)`, `(a, b)`,
+        `(a)` and `()`. See :meth:`group_by_grouping_sets`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        by
+            The keys, from the top of the hierarchy down.
+        agg
+            Aggregations to compute for every group.
+        grouping_id
+            Name of a `UInt64` column, added after the keys, that tells the sets
+            apart. It has a bit set for every key that is not in the set of the row,
+            the first key being the most significant bit.
+
+        See Also
+        --------
+        group_by_grouping_sets
+        group_by_cube
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {"store": ["a", "a", "b"], "day": [1, 2, 1], "sales": [10, 20, 30]}
+        ... )
+        >>> lf.group_by_rollup(
+        ...     ["store", "day"], agg=pl.col("sales").sum(), grouping_id="gid"
+        ... ).sort("gid", "store", "day").collect()
+        shape: (6, 4)
+        ┌───────┬──────┬─────┬───────┐
+        │ store ┆ day  ┆ gid ┆ sales │
+        │ ---   ┆ ---  ┆ --- ┆ ---   │
+        │ str   ┆ i64  ┆ u64 ┆ i64   │
+        ╞═══════╪══════╪═════╪═══════╡
+        │ a     ┆ 1    ┆ 0   ┆ 10    │
+        │ a     ┆ 2    ┆ 0   ┆ 20    │
+        │ b     ┆ 1    ┆ 0   ┆ 30    │
+        │ a     ┆ null ┆ 1   ┆ 30    │
+        │ b     ┆ null ┆ 1   ┆ 30    │
+        │ null  ┆ null ┆ 3   ┆ 60    │
+        └───────┴──────┴─────┴───────┘
+        """
+        pyexprs_by = parse_into_list_of_expressions(by)
+        pyexprs_agg = parse_into_list_of_expressions(agg)
+        return self._from_pyldf(
+            self._ldf.group_by_rollup(pyexprs_by, pyexprs_agg, grouping_id)
+        )
+
+    @unstable()
+    def group_by_hierarchy(
+        self,
+        by: IntoExpr | Iterable[IntoExpr],
+        *,
+        agg: IntoExpr | Iterable[IntoExpr],
+        level: str = "level",
+    ) -> LazyFrame:
+        """
+        Aggregate by every level of the hierarchy of the keys, with subtotal rows.
+
+        For the keys `[region, country, city]` this gives the groups of
+        `(region, country, city)`, `(region, country)`, `(region)` and the grand
+        total. The keys below the level of a row are null. The input is hashed only
+        once; the coarser levels are derived from the groups of the finest level.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        by
+            The keys, from the top of the hierarchy down.
+        agg
+            Aggregations to compute for every group.
+        level
+            Name of a `UInt32` column, added after the keys, with the number of keys
+            that are grouped in the row: `0` for the grand total up to the number of
+            keys for the finest groups.
+
+        See Also
+        --------
+        group_by_rollup
+
+        Examples
+        --------
+        >>> df = pl.LazyFrame(
+        ...     {
+        ...         "region": ["eu", "eu", "eu", "us"],
+        ...         "country": ["nl", "nl", "de", "us"],
+        ...         "sales": [10, 20, 30, 40],
+        ...     }
+        ... )
+        >>> df.group_by_hierarchy(
+        ...     ["region", "country"], agg=pl.col("sales").sum()
+        ... ).sort("level", "region", "country").collect()
+        shape: (6, 4)
+        ┌────────┬─────────┬───────┬───────┐
+        │ region ┆ country ┆ level ┆ sales │
+        │ ---    ┆ ---     ┆ ---   ┆ ---   │
+        │ str    ┆ str     ┆ u32   ┆ i64   │
+        ╞════════╪═════════╪═══════╪═══════╡
+        │ null   ┆ null    ┆ 0     ┆ 100   │
+        │ eu     ┆ null    ┆ 1     ┆ 60    │
+        │ us     ┆ null    ┆ 1     ┆ 40    │
+        │ eu     ┆ de      ┆ 2     ┆ 30    │
+        │ eu     ┆ nl      ┆ 2     ┆ 30    │
+        │ us     ┆ us      ┆ 2     ┆ 40    │
+        └────────┴─────────┴───────┴───────┘
+        """
+        pyexprs_by = parse_into_list_of_expressions(by)
+        pyexprs_agg = parse_into_list_of_expressions(agg)
+        return self._from_pyldf(
+            self._ldf.group_by_hierarchy(pyexprs_by, pyexprs_agg, level)
+        )
+
+    @unstable()
+    def group_by_cube(
+        self,
+        by: IntoExpr | Iterable[IntoExpr],
+        *,
+        agg: IntoExpr | Iterable[IntoExpr],
+        grouping_id: str | None = None,
+    ) -> LazyFrame:
+        """
+        Aggregate by every combination of the keys, as SQL's `GROUP BY CUBE`.
+
+        For the keys `[a, b]` this aggregates by the sets `(a, b)`, `(a)`, `(b)` and
+        `()`. See :meth:`group_by_grouping_sets`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        by
+            The keys to combine.
+        agg
+            Aggregations to compute for every group.
+        grouping_id
+            Name of a `UInt64` column, added after the keys, that tells the sets
+            apart. It has a bit set for every key that is not in the set of the row,
+            the first key being the most significant bit.
+
+        See Also
+        --------
+        group_by_grouping_sets
+        group_by_rollup
+
+        Examples
+        --------
+        Aggregate by `day` alone, which has the grouping id `0b10`.
+
+        >>> lf = pl.LazyFrame(
+        ...     {"store": ["a", "a", "b"], "day": [1, 2, 1], "sales": [10, 20, 30]}
+        ... )
+        >>> lf.group_by_cube(
+        ...     ["store", "day"], agg=pl.col("sales").sum(), grouping_id="gid"
+        ... ).filter(pl.col("gid") == 2).sort("day").collect()
+        shape: (2, 4)
+        ┌───────┬─────┬─────┬───────┐
+        │ store ┆ day ┆ gid ┆ sales │
+        │ ---   ┆ --- ┆ --- ┆ ---   │
+        │ str   ┆ i64 ┆ u64 ┆ i64   │
+        ╞═══════╪═════╪═════╪═══════╡
+        │ null  ┆ 1   ┆ 2   ┆ 40    │
+        │ null  ┆ 2   ┆ 2   ┆ 20    │
+        └───────┴─────┴─────┴───────┘
+        """
+        pyexprs_by = parse_into_list_of_expressions(by)
+        pyexprs_agg = parse_into_list_of_expressions(agg)
+        return self._from_pyldf(
+            self._ldf.group_by_cube(pyexprs_by, pyexprs_agg, grouping_id)
+        )
+
+    def join_asof(
+        self,
+        other: LazyFrame,
+        *,
+        left_on: str | None | Expr = None,
+        right_on: str | None | Expr = None,
+        on: str | None | Expr = None,
+        by_left: str | Sequence[str] | None = None,
+        by_right: str | Sequence[str] | None = None,
+        by: str | Sequence[str] | None = None,
+        strategy: AsofJoinStrategy = "backward",
+        suffix: str = "_right",
+        naming: str | None = None,
+        tolerance: str | int | float | timedelta | None = None,
+        allow_parallel: bool = True,
+        force_parallel: bool = False,
+        coalesce: bool | None = None,
+    ) -> LazyFrame:
+        """
+        Perform an asof join.
+
+        This is similar to a left-join except that we match on nearest key rather than
+        equal keys.
+
+        Both DataFrames must be sorted by the join_asof key.
+
+        For each row in the left DataFrame:
+
+          - A "backward" search selects the last row in the right DataFrame whose
+            'on' key is less than or equal to the left's key.
+
+          - A "forward" search selects the first row in the right DataFrame whose
+            'on' key is greater than or equal to the left's key.
+
+            A "nearest" search selects the last row in the right DataFrame whose value
+            is nearest to the left's key. String keys are not currently supported for a
+            nearest search.
+
+        The default is "backward".
+
+        Parameters
+        ----------
+        other
+            Lazy DataFrame to join with.
+        left_on
+            Join column of the left DataFrame.
+        right_on
+            Join column of the right DataFrame.
+        on
+            Join column of both DataFrames. If set, `left_on` and `right_on` should be
+            None.
+        by
+            Join on these columns before doing asof join.
+        by_left
+            Join on these columns before doing asof join.
+        by_right
+            Join on these columns before doing asof join.
+        strategy : {'backward', 'forward', 'nearest'}
+            Join strategy.
         suffix
             Suffix to append to columns with a duplicate name.
+        naming
+            How to name the columns of `other` that have the name of a column of this
+            frame, instead of appending `suffix`. Either a template in which `{name}` is
+            replaced by the name of the column and `{side}` by `right`, e.g.
+            `"{side}_{name}"`, or `"raise"` to raise an error for these columns.
         tolerance
             Numeric tolerance. By setting this the join will only be done if the near
             keys are within this distance. If an asof join is done on columns of dtype
@@ -4024,6 +4760,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 tolerance_num,
                 tolerance_str,
                 coalesce=coalesce,
+                naming=naming,
             )
         )
 
@@ -4036,6 +4773,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         left_on: str | Expr | Sequence[str | Expr] | None = None,
         right_on: str | Expr | Sequence[str | Expr] | None = None,
         suffix: str = "_right",
+        naming: str | None = None,
         validate: JoinValidation = "m:m",
         join_nulls: bool = False,
         coalesce: bool | None = None,
@@ -4077,6 +4815,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             Join column of the right DataFrame.
         suffix
             Suffix to append to columns with a duplicate name.
+        naming
+            How to name the columns of `other` that have the name of a column of this
+            frame, instead of appending `suffix`. Either a template in which `{name}` is
+            replaced by the name of the column and `{side}` by `right`, e.g.
+            `"{side}_{name}"`, or `"raise"` to raise an error for these columns.
         validate: {'m:m', 'm:1', '1:m', '1:1'}
             Checks if join is of specified type.
 
@@ -4215,6 +4958,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                     how,
                     suffix,
                     validate,
+                    naming=naming,
                 )
             )
 
@@ -4241,9 +4985,102 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 suffix,
                 validate,
                 coalesce,
+                naming=naming,
             )
         )
 
+    @unstable()
+    def join_where(
+        self,
+        other: LazyFrame,
+        *predicates: Expr | Iterable[Expr],
+        suffix: str = "_right",
+        naming: str | None = None,
+    ) -> LazyFrame:
+        """
+        Perform a join based on one or multiple (in)equality predicates.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        This performs an inner join, so only rows where all predicates are true
+        are included in the result, and a row from either frame may be included
+        multiple times in the result.
+
+        Up to two predicates of the form `pl.col(a) < pl.col(b)` (or with `<=`,
+        `>` or `>=`) that compare a column of this frame with a column of the
+        other frame are evaluated with an inequality join, which does not
+        materialize all pairs of rows. Any other predicate is applied as a
+        filter on the result of that join, or on the cross join if there are
+        no such predicates. Rows with a null key never match.
+
+        Parameters
+        ----------
+        other
+            LazyFrame to join with.
+        *predicates
+            (In)Equality condition to join the two frames on. Columns of
+            `other` are referred to by their name, or by their name after the join
+            (see `suffix` and `naming`) if this frame has a column with the same name.
+            When a column name occurs in both frames, the left column is used.
+        suffix
+            Suffix to append to columns with a duplicate name.
+        naming
+            How to name the columns of `other` that have the name of a column of this
+            frame, instead of appending `suffix`. Either a template in which `{name}` is
+            replaced by the name of the column and `{side}` by `right`, e.g.
+            `"{side}_{name}"`, or `"raise"` to raise an error for these columns.
+
+        Examples
+        --------
+        >>> east = pl.LazyFrame(
+        ...     {
+        ...         "id": [100, 101, 102],
+        ...         "dur": [120, 140, 160],
+        ...         "rev": [12, 14, 16],
+        ...         "cores": [2, 8, 4],
+        ...     }
+        ... )
+        >>> west = pl.LazyFrame(
+        ...     {
+        ...         "t_id": [404, 498, 676, 742],
+        ...         "time": [90, 130, 150, 170],
+        ...         "cost": [9, 13, 15, 16],
+        ...         "cores": [4, 2, 1, 4],
+        ...     }
+        ... )
+        >>> east.join_where(
+        ...     west,
+        ...     pl.col("dur") < pl.col("time"),
+        ...     pl.col("rev") < pl.col("cost"),
+        ... ).collect()
+        shape: (5, 8)
+        ┌─────┬─────┬─────┬───────┬──────┬──────┬──────┬─────────────┐
+        │ id  ┆ dur ┆ rev ┆ cores ┆ t_id ┆ time ┆ cost ┆ cores_right │
+        │ --- ┆ --- ┆ --- ┆ ---   ┆ ---  ┆ ---  ┆ ---  ┆ ---         │
+        │ i64 ┆ i64 ┆ i64 ┆ i64   ┆ i64  ┆ i64  ┆ i64  ┆ i64         │
+        ╞═════╪═════╪═════╪═══════╪══════╪══════╪══════╪═════════════╡
+        │ 100 ┆ 120 ┆ 12  ┆ 2     ┆ 498  ┆ 130  ┆ 13   ┆ 2           │
+        │ 100 ┆ 120 ┆ 12  ┆ 2     ┆ 676  ┆ 150  ┆ 15   ┆ 1           │
+        │ 100 ┆ 120 ┆ 12  ┆ 2     ┆ 742  ┆ 170  ┆ 16   ┆ 4           │
+        │ 101 ┆ 140 ┆ 14  ┆ 8     ┆ 676  ┆ 150  ┆ 15   ┆ 1           │
+        │ 101 ┆ 140 ┆ 14  ┆ 8     ┆ 742  ┆ 170  ┆ 16   ┆ 4           │
+        └─────┴─────┴─────┴───────┴──────┴──────┴──────┴─────────────┘
+        """
+        if not isinstance(other, LazyFrame):
+            msg = f"expected `other` join table to be a LazyFrame, not a {type(other).__name__!r}"
+            raise TypeError(msg)
+
+        pyexprs = parse_into_list_of_expressions(*predicates)
+        if not pyexprs:
+            msg = "`join_where` requires at least one predicate"
+            raise ValueError(msg)
+
+        return self._from_pyldf(
+            self._ldf.join_where(other._ldf, pyexprs, suffix, naming=naming)
+        )
+
     def with_columns(
         self,
         *exprs: IntoExpr | Iterable[IntoExpr],
@@ -4436,6 +5273,71 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         )
         return self._from_pyldf(self._ldf.with_columns_seq(pyexprs))
 
+    @unstable()
+    def set_where(
+        self,
+        mask: IntoExpr,
+        values: Mapping[str, IntoExpr] | None = None,
+        **named_values: IntoExpr,
+    ) -> LazyFrame:
+        """
+        Set the values of columns in the rows where a mask is true.
+
+        The mask is evaluated once for all columns, which makes this cheaper than a
+        `when/then/otherwise` expression per column that repeats the mask.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        mask
+            Boolean expression that selects the rows to update. Rows where the mask is
+            false or null keep their values.
+        values
+            Mapping of the names of the columns to update to their new values.
+            Accepts expression input. Strings are parsed as column names, other
+            non-expression inputs are parsed as literals.
+        **named_values
+            Additional columns to update, specified as keyword arguments.
+
+        Notes
+        -----
+        The mask and the new values are evaluated on the frame before the update, so
+        a value that refers to another updated column sees its original values.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "price": [10.0, -1.0, 12.5],
+        ...         "quantity": [3, 5, 0],
+        ...     }
+        ... )
+        >>> lf.set_where(
+        ...     pl.col("price") < 0, price=None, quantity=pl.col("quantity") * 0
+        ... ).collect()
+        shape: (3, 2)
+        ┌───────┬──────────┐
+        │ price ┆ quantity │
+        │ ---   ┆ ---      │
+        │ f64   ┆ i64      │
+        ╞═══════╪══════════╡
+        │ 10.0  ┆ 3        │
+        │ null  ┆ 0        │
+        │ 12.5  ┆ 0        │
+        └───────┴──────────┘
+        """
+        values = {**(values or {}), **named_values}
+        return self._from_pyldf(
+            self._ldf.set_where(
+                parse_into_expression(mask),
+                list(values),
+                [parse_into_expression(value) for value in values.values()],
+            )
+        )
+
     @deprecate_function(
         "Use `pl.concat(..., how='horizontal')` instead.", version="1.0.0"
     )
@@ -4911,6 +5813,128 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         """
         return self._from_pyldf(self._ldf.tail(n))
 
+    def head_tail(self, n: int = 5) -> DataFrame:
+        """
+        Collect the first and the last `n` rows.
+
+        If the query has at most `2 * n` rows, all rows are returned. The head and
+        the tail are collected as separate slices of the query, which are pushed
+        down to the scans where possible; Parquet scans only read the files that
+        contain the tail.
+
+        Parameters
+        ----------
+        n
+            Number of rows to return from both the start and the end.
+
+        See Also
+        --------
+        head, tail, glimpse
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "a": [1, 2, 3, 4, 5, 6],
+        ...         "b": [7, 8, 9, 10, 11, 12],
+        ...     }
+        ... )
+        >>> lf.head_tail(2)
+        shape: (4, 2)
+        ┌─────┬─────┐
+        │ a   ┆ b   │
+        │ --- ┆ --- │
+        │ i64 ┆ i64 │
+        ╞═════╪═════╡
+        │ 1   ┆ 7   │
+        │ 2   ┆ 8   │
+        │ 5   ┆ 11  │
+        │ 6   ┆ 12  │
+        └─────┴─────┘
+        """
+        return wrap_df(self._ldf.head_tail(n))
+
+    @overload
+    def glimpse(
+        self,
+        n: int = ...,
+        *,
+        max_items_per_column: int = ...,
+        max_colname_length: int = ...,
+        return_as_string: Literal[False] = ...,
+    ) -> None: ...
+
+    @overload
+    def glimpse(
+        self,
+        n: int = ...,
+        *,
+        max_items_per_column: int = ...,
+        max_colname_length: int = ...,
+        return_as_string: Literal[True],
+    ) -> str: ...
+
+    @overload
+    def glimpse(
+        self,
+        n: int = ...,
+        *,
+        max_items_per_column: int = ...,
+        max_colname_length: int = ...,
+        return_as_string: bool,
+    ) -> str | None: ...
+
+    def glimpse(
+        self,
+        n: int = 10,
+        *,
+        max_items_per_column: int = 10,
+        max_colname_length: int = 50,
+        return_as_string: bool = False,
+    ) -> str | None:
+        """
+        Return a dense preview of the first `n` rows of the query.
+
+        Every scan of the query reads at most `n` rows, so the data sources are not
+        read completely, even if the query contains filters or joins. As a result,
+        fewer than `n` rows may be shown although the query has more.
+
+        Parameters
+        ----------
+        n
+            Maximum number of rows to collect.
+        max_items_per_column
+            Maximum number of items to show per column.
+        max_colname_length
+            Maximum length of the displayed column names; values that exceed this
+            value are truncated with a trailing ellipsis.
+        return_as_string
+            If True, return the preview as a string instead of printing to stdout.
+
+        See Also
+        --------
+        head_tail, polars.DataFrame.glimpse
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "a": [1.0, 2.8, 3.0],
+        ...         "b": [4, 5, None],
+        ...     }
+        ... )
+        >>> lf.glimpse(2)
+        Rows: 2
+        Columns: 2
+        $ a <f64> 1.0, 2.8
+        $ b <i64> 4, 5
+        """
+        return wrap_df(self._ldf.glimpse(n)).glimpse(
+            max_items_per_column=max_items_per_column,
+            max_colname_length=max_colname_length,
+            return_as_string=return_as_string,
+        )
+
     def last(self) -> LazyFrame:
         """
         Get the last row of the DataFrame.
@@ -4991,7 +6015,13 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         """
         return self.select(F.all().approx_n_unique())
 
-    def with_row_index(self, name: str = "index", offset: int = 0) -> LazyFrame:
+    def with_row_index(
+        self,
+        name: str = "index",
+        offset: int = 0,
+        *,
+        over: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
+    ) -> LazyFrame:
         """
         Add a row index as the first column in the LazyFrame.
 
@@ -5001,6 +6031,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             Name of the index column.
         offset
             Start the index at this offset. Cannot be negative.
+        over
+            Count the rows within each group of these columns instead. This is
+            equivalent to `pl.int_range(pl.len()).over(over)`, but faster.
 
         Warnings
         --------
@@ -5060,9 +6093,26 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         │ 1     ┆ 3   ┆ 4   │
         │ 2     ┆ 5   ┆ 6   │
         └───────┴─────┴─────┘
+
+        Count the rows within each group instead.
+
+        >>> lf = pl.LazyFrame({"g": ["x", "y", "x", "x"], "v": [1, 2, 3, 4]})
+        >>> lf.with_row_index(over="g").collect()
+        shape: (4, 3)
+        ┌───────┬─────┬─────┐
+        │ index ┆ g   ┆ v   │
+        │ ---   ┆ --- ┆ --- │
+        │ u32   ┆ str ┆ i64 │
+        ╞═══════╪═════╪═════╡
+        │ 0     ┆ x   ┆ 1   │
+        │ 0     ┆ y   ┆ 2   │
+        │ 1     ┆ x   ┆ 3   │
+        │ 2     ┆ x   ┆ 4   │
+        └───────┴─────┴─────┘
         """
+        over_columns = None if over is None else _expand_selectors(self, over)
         try:
-            return self._from_pyldf(self._ldf.with_row_index(name, offset))
+            return self._from_pyldf(self._ldf.with_row_index(name, offset, over_columns))
         except OverflowError:
             issue = "negative" if offset < 0 else "greater than the maximum index value"
             msg = f"`offset` input for `with_row_index` cannot be {issue}, got {offset}"
@@ -5825,6 +6875,238 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             subset = _expand_selectors(self, subset)
         return self._from_pyldf(self._ldf.drop_nulls(subset))
 
+    @unstable()
+    def pivot(
+        self,
+        on: ColumnNameOrSelector | Sequence[ColumnNameOrSelector],
+        *,
+        on_columns: Sequence[Any] | pl.Series | pl.DataFrame | None = None,
+        index: ColumnNameOrSelector | Sequence[ColumnNameOrSelector] | None = None,
+        values: ColumnNameOrSelector | Sequence[ColumnNameOrSelector] | None = None,
+        aggregate_function: PivotAgg | Expr | None = None,
+        maintain_order: bool = True,
+        sort_columns: bool = False,
+        separator: str = "_",
+    ) -> LazyFrame:
+        """
+        Create a spreadsheet-style pivot table as a LazyFrame.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The output columns depend on the values of the `on` columns. If those values
+        are given in `on_columns`, the schema is known without looking at the data and
+        the pivot is optimized like a group by: predicates on the `index` columns and
+        unused columns are pushed down, and it can run in the streaming engine.
+        Otherwise the distinct values of `on` are collected first, which evaluates
+        the query an extra time.
+
+        Parameters
+        ----------
+        on
+            Name of the column(s) whose values will be used as the header of the output
+            LazyFrame.
+        on_columns
+            The values of `on` that become output columns, in the order of the output
+            columns. Values that don't occur in the data give columns of nulls, values
+            that are not listed are left out. For multiple `on` columns, pass a
+            DataFrame with a column for every `on` column, or a sequence of tuples.
+            If None, all distinct values of `on` in order of appearance.
+        index
+            One or multiple keys to group by. If None, all remaining columns not
+            specified on `on` and `values` will be used. At least one of `index` and
+            `values` must be specified.
+        values
+            The column(s) to aggregate. If None, all remaining columns not specified
+            on `on` and `index` will be used. At least one of `index` and `values`
+            must be specified.
+        aggregate_function
+            Choose from:
+
+            - None: take the first value of every cell. Unlike
+              :meth:`DataFrame.pivot`, this does not raise an error if a cell has
+              multiple values.
+            - A predefined aggregate function string, one of
+              {'min', 'max', 'first', 'last', 'sum', 'mean', 'median', 'len'}
+            - An expression to do the aggregation.
+        maintain_order
+            Keep the rows in the order of the first occurrence of their `index`.
+        sort_columns
+            Sort the transposed columns by name. Default is the order of `on_columns`.
+        separator
+            Used as separator/delimiter in generated column names in case of multiple
+            `values` columns.
+
+        See Also
+        --------
+        DataFrame.pivot
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "name": ["Cady", "Cady", "Karen", "Karen"],
+        ...         "subject": ["maths", "physics", "maths", "physics"],
+        ...         "test_1": [98, 99, 61, 58],
+        ...     }
+        ... )
+        >>> lf.pivot(
+        ...     "subject", on_columns=["maths", "physics"], index="name"
+        ... ).filter(pl.col("name") == "Karen").collect()
+        shape: (1, 3)
+        ┌───────┬───────┬─────────┐
+        │ name  ┆ maths ┆ physics │
+        │ ---   ┆ ---   ┆ ---     │
+        │ str   ┆ i64   ┆ i64     │
+        ╞═══════╪═══════╪═════════╡
+        │ Karen ┆ 61    ┆ 58      │
+        └───────┴───────┴─────────┘
+        """
+        on = _expand_selectors(self, on)
+        if values is not None:
+            values = _expand_selectors(self, values)
+        if index is not None:
+            index = _expand_selectors(self, index)
+
+        if on_columns is None or isinstance(on_columns, pl.DataFrame):
+            on_df = on_columns
+        elif isinstance(on_columns, pl.Series):
+            on_df = on_columns.alias(on[0]).to_frame()
+        elif len(on) == 1:
+            on_df = pl.DataFrame({on[0]: on_columns})
+        else:
+            on_df = pl.DataFrame(list(on_columns), schema=on, orient="row")
+
+        if aggregate_function is None:
+            aggregate_expr = F.element().first()
+        elif isinstance(aggregate_function, str):
+            if aggregate_function == "len":
+                aggregate_expr = F.len()
+            elif aggregate_function in (
+                "min",
+                "max",
+                "first",
+                "last",
+                "sum",
+                "mean",
+                "median",
+            ):
+                aggregate_expr = getattr(F.element(), aggregate_function)()
+            else:
+                msg = (
+                    "invalid input for `aggregate_function` argument: "
+                    f"{aggregate_function!r}"
+                )
+                raise ValueError(msg)
+        else:
+            aggregate_expr = aggregate_function
+
+        return self._from_pyldf(
+            self._ldf.pivot(
+                on,
+                None if on_df is None else on_df._df,
+                index,
+                values,
+                aggregate_expr._pyexpr,
+                maintain_order,
+                sort_columns,
+                separator,
+            )
+        )
+
+    @unstable()
+    def iterate(
+        self,
+        step: Callable[[LazyFrame], LazyFrame],
+        *,
+        until: IntoExpr | None = None,
+        max_iterations: int = 100,
+    ) -> LazyFrame:
+        """
+        Repeatedly apply a query to a state, starting with this LazyFrame.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The `step` function is called once, with a LazyFrame of the state, and must
+        return a query of the next state with the same schema. That query is
+        optimized once and run for every iteration, without collecting the result
+        between iterations. Its parts that don't depend on the state, such as the
+        edges of a graph, are only computed once.
+
+        Parameters
+        ----------
+        step
+            Function that takes a LazyFrame of the state and returns a LazyFrame of
+            the next state.
+        until
+            Expression evaluated on every new state that must produce a single boolean.
+            The iteration stops once it is true. If None, the iteration stops at a
+            fixed point: once a step returns the same rows, in the same order, as it
+            was given.
+        max_iterations
+            The maximum number of times `step` is applied. The last state is
+            returned if the iteration has not stopped by then.
+
+        Examples
+        --------
+        Compute the transitive closure of the edges of a graph.
+
+        >>> edges = pl.LazyFrame({"src": [1, 2, 3], "dst": [2, 3, 4]})
+        >>> def step(reach: pl.LazyFrame) -> pl.LazyFrame:
+        ...     longer = reach.join(edges, left_on="dst", right_on="src").select(
+        ...         "src", dst="dst_right"
+        ...     )
+        ...     return pl.concat([reach, longer]).unique().sort("src", "dst")
+        >>> edges.iterate(step).collect()
+        shape: (6, 2)
+        ┌─────┬─────┐
+        │ src ┆ dst │
+        │ --- ┆ --- │
+        │ i64 ┆ i64 │
+        ╞═════╪═════╡
+        │ 1   ┆ 2   │
+        │ 1   ┆ 3   │
+        │ 1   ┆ 4   │
+        │ 2   ┆ 3   │
+        │ 2   ┆ 4   │
+        │ 3   ┆ 4   │
+        └─────┴─────┘
+
+        Double the values until they are all at least 100.
+
+        >>> lf = pl.LazyFrame({"x": [1, 3]})
+        >>> lf.iterate(
+        ...     lambda lf: lf.select(pl.col("x") * 2),
+        ...     until=pl.col("x").min() >= 100,
+        ... ).collect()
+        shape: (2, 1)
+        ┌─────┐
+        │ x   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 128 │
+        │ 384 │
+        └─────┘
+        """
+        if max_iterations < 0:
+            msg = f"`max_iterations` must be non-negative, got {max_iterations}"
+            raise ValueError(msg)
+
+        def _step(ldf: PyLazyFrame) -> PyLazyFrame:
+            return step(self._from_pyldf(ldf))._ldf
+
+        return self._from_pyldf(
+            self._ldf.iterate(
+                _step,
+                None if until is None else parse_into_expression(until),
+                max_iterations,
+            )
+        )
+
     def unpivot(
         self,
         on: ColumnNameOrSelector | Sequence[ColumnNameOrSelector] | None = None,
@@ -5909,6 +7191,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         schema: None | SchemaDict = None,
         validate_output_schema: bool = True,
         streamable: bool = False,
+        columns_used: Sequence[str] | None = None,
+        row_independent: bool = False,
     ) -> LazyFrame:
         """
         Apply a custom function.
@@ -5940,6 +7224,18 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             streaming engine. That means that the function must produce the same result
             when it is executed in batches or when it is be executed on the full
             dataset.
+        columns_used
+            The input columns read by the function. If given, all other columns are
+            pruned before the function, even when `projection_pushdown` is not allowed.
+            The function only receives these columns, so columns it passes through
+            must be included as well.
+        row_independent
+            Whether the function returns one row for every row of `columns_used`,
+            independent of the other rows. The columns it returns are then added to
+            the input, replacing the columns with the same name, and `schema` is the
+            schema of what the function returns. All other columns are passed around
+            the function, and filters on them are applied before it. Requires
+            `columns_used`.
 
         Warnings
         --------
@@ -5979,6 +7275,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         │ -2      ┆ 199998 │
         └─────────┴────────┘
         """
+        if row_independent and columns_used is None:
+            msg = "`row_independent` requires `columns_used`"
+            raise ValueError(msg)
         if no_optimizations:
             predicate_pushdown = False
             projection_pushdown = False
@@ -5993,6 +7292,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 streamable=streamable,
                 schema=schema,
                 validate_output=validate_output_schema,
+                columns_used=columns_used,
+                row_independent=row_independent,
             )
         )
 
@@ -6175,6 +7476,263 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             raise TypeError(msg)
         return self.with_columns(F.col(column).set_sorted(descending=descending))
 
+    @unstable()
+    def assert_non_empty(self) -> LazyFrame:
+        """
+        Raise an error during execution if the LazyFrame has no rows.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The assertion is checked when the query is executed; the data is passed
+        through unchanged.
+
+        Raises
+        ------
+        ComputeError
+            If the data has no rows.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3]})
+        >>> lf.filter(pl.col("a") > 1).assert_non_empty().collect()
+        shape: (2, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 2   │
+        │ 3   │
+        └─────┘
+        """
+        return self._from_pyldf(self._ldf.assert_non_empty())
+
+    @unstable()
+    def assert_row_count(self, n: int) -> LazyFrame:
+        """
+        Raise an error during execution if the LazyFrame does not have exactly `n` rows.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The assertion is checked when the query is executed; the data is passed
+        through unchanged.
+
+        Parameters
+        ----------
+        n
+            The expected number of rows.
+
+        Raises
+        ------
+        ComputeError
+            If the number of rows differs from `n`.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3]})
+        >>> lf.assert_row_count(3).select(pl.col("a").sum()).collect()
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 6   │
+        └─────┘
+        """
+        return self._from_pyldf(self._ldf.assert_row_count(n))
+
+    @unstable()
+    def assert_unique(
+        self,
+        subset: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
+    ) -> LazyFrame:
+        """
+        Raise an error during execution if the LazyFrame contains duplicate rows.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The assertion is checked when the query is executed; the data is passed
+        through unchanged.
+
+        Parameters
+        ----------
+        subset
+            Column name(s) or selector(s) that together form the key that must be
+            unique. If set to `None` (default), all columns are used.
+
+        Raises
+        ------
+        ComputeError
+            If two rows have the same key. The error reports the number of
+            duplicated rows and the first duplicated key.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"id": [1, 2, 3], "group": ["x", "x", "y"]})
+        >>> lf.assert_unique("id").group_by("group").len().sort("group").collect()
+        shape: (2, 2)
+        ┌───────┬─────┐
+        │ group ┆ len │
+        │ ---   ┆ --- │
+        │ str   ┆ u32 │
+        ╞═══════╪═════╡
+        │ x     ┆ 2   │
+        │ y     ┆ 1   │
+        └───────┴─────┘
+        """
+        if subset is not None:
+            subset = _expand_selectors(self, subset)
+        return self._from_pyldf(self._ldf.assert_unique(subset))
+
+    @unstable()
+    def assert_sorted(self, column: str, *, descending: bool = False) -> LazyFrame:
+        """
+        Raise an error during execution if a column is not sorted.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The assertion is checked when the query is executed; the data is passed
+        through unchanged. Null values are ignored.
+
+        Parameters
+        ----------
+        column
+            Name of the column that must be sorted.
+        descending
+            Whether the column must be sorted in descending order.
+
+        Raises
+        ------
+        ComputeError
+            If the column is not sorted. The error reports the first row that is
+            out of order.
+
+        See Also
+        --------
+        set_sorted
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"ts": [1, 2, 5], "value": [10, 20, 30]})
+        >>> lf.assert_sorted("ts").collect()
+        shape: (3, 2)
+        ┌─────┬───────┐
+        │ ts  ┆ value │
+        │ --- ┆ ---   │
+        │ i64 ┆ i64   │
+        ╞═════╪═══════╡
+        │ 1   ┆ 10    │
+        │ 2   ┆ 20    │
+        │ 5   ┆ 30    │
+        └─────┴───────┘
+        """
+        return self._from_pyldf(self._ldf.assert_sorted(column, descending))
+
+    @unstable()
+    def sample(
+        self,
+        n: int | None = None,
+        *,
+        fraction: float | None = None,
+        by: str | Sequence[str] | None = None,
+        weights: str | None = None,
+        with_replacement: bool = False,
+        shuffle: bool = False,
+        seed: int | None = None,
+    ) -> LazyFrame:
+        """
+        Sample rows from this LazyFrame, optionally per group or weighted.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A sample of `n` rows without replacement runs in the streaming engine as a
+        reservoir sample, which only keeps `n` rows per group in memory. This makes
+        it possible to sample scans that are larger than memory.
+
+        Parameters
+        ----------
+        n
+            Number of rows to return, from every group if `by` is given. Cannot be
+            used with `fraction`. Defaults to 1 if `fraction` is None.
+        fraction
+            Fraction of rows to return, of every group if `by` is given. Cannot be
+            used with `n`.
+        by
+            Column(s) whose groups are sampled separately, so that every group is
+            represented in the sample (stratified sampling).
+        weights
+            Column of non-negative weights. Rows are sampled proportionally to their
+            weight and rows with a weight of zero or null are never sampled.
+        with_replacement
+            Allow rows to be sampled more than once.
+        shuffle
+            Return the sampled rows in random order. By default they are returned in
+            the order of the input.
+        seed
+            Seed for the random number generator. If set to None (default), a
+            random seed is generated every time the query is executed.
+
+        Raises
+        ------
+        ShapeError
+            If a group has fewer rows with a positive weight than the sample size and
+            `with_replacement=False`.
+
+        See Also
+        --------
+        DataFrame.sample
+
+        Examples
+        --------
+        Take a balanced sample of two rows of every label:
+
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "label": ["a", "a", "a", "b", "b", "b"],
+        ...         "x": [1, 2, 3, 4, 5, 6],
+        ...         "weight": [0.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+        ...     }
+        ... )
+        >>> lf.sample(2, by="label", weights="weight", seed=0).collect()
+        ... # doctest: +IGNORE_RESULT
+        shape: (4, 3)
+        ┌───────┬─────┬────────┐
+        │ label ┆ x   ┆ weight │
+        │ ---   ┆ --- ┆ ---    │
+        │ str   ┆ i64 ┆ f64    │
+        ╞═══════╪═════╪════════╡
+        │ a     ┆ 2   ┆ 1.0    │
+        │ a     ┆ 3   ┆ 1.0    │
+        │ b     ┆ 4   ┆ 1.0    │
+        │ b     ┆ 6   ┆ 1.0    │
+        └───────┴─────┴────────┘
+        """
+        if n is not None and fraction is not None:
+            msg = "cannot specify both `n` and `fraction`"
+            raise ValueError(msg)
+        if n is None and fraction is None:
+            n = 1
+        if by is None:
+            by = []
+        elif isinstance(by, str):
+            by = [by]
+        return self._from_pyldf(
+            self._ldf.sample(
+                n, fraction, list(by), weights, with_replacement, shuffle, seed
+            )
+        )
+
     @unstable()
     def update(
         self,
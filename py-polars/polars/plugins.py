@@ -5,6 +5,7 @@ from pathlib import Path
 from typing import TYPE_CHECKING, Any, Iterable
 
 from polars._utils.parse import parse_into_list_of_expressions
+from polars._utils.unstable import unstable
 from polars._utils.wrap import wrap_expr
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -14,7 +15,7 @@ if TYPE_CHECKING:
     from polars import Expr
     from polars._typing import IntoExpr
 
-__all__ = ["register_plugin_function"]
+__all__ = ["register_plugin_aggregation", "register_plugin_function"]
 
 
 def register_plugin_function(
@@ -99,6 +100,74 @@ def register_plugin_function(
     )
 
 
+@unstable()
+def register_plugin_aggregation(
+    *,
+    plugin_path: Path | str,
+    function_name: str,
+    args: IntoExpr | Iterable[IntoExpr],
+    kwargs: dict[str, Any] | None = None,
+) -> Expr:
+    """
+    Register a user-defined aggregation of a plugin.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Unlike a plugin function, the aggregation never sees all values of a group at
+    once. The plugin keeps a state per group, to which the values are added in
+    parts, and states that are built in parallel are merged. This allows the
+    aggregation to run in the streaming engine.
+
+    The plugin must export these functions, next to the
+    `_polars_plugin_field_{function_name}` function that determines the output
+    field:
+
+    - `_polars_plugin_agg_init_{function_name}` creates an empty state.
+    - `_polars_plugin_agg_update_{function_name}` adds values to a state.
+    - `_polars_plugin_agg_merge_{function_name}` adds another state to a state.
+    - `_polars_plugin_agg_finalize_{function_name}` writes the result, a single
+      value.
+    - `_polars_plugin_agg_drop_{function_name}` frees a state.
+
+    Parameters
+    ----------
+    plugin_path
+        Path to the plugin package. Accepts either the file path to the dynamic library
+        file or the path to the directory containing it.
+    function_name
+        The name of the aggregation, without the prefixes of the exported functions.
+    args
+        The inputs of the aggregation. These are passed to the `update` function of
+        the plugin, and have to be expressions (or be convertible to expressions).
+    kwargs
+        Non-expression arguments, passed to the `init` function of the plugin. These
+        must be JSON serializable.
+
+    Returns
+    -------
+    Expr
+
+    Warnings
+    --------
+    This is highly unsafe as this will call the C functions loaded from
+    `plugin_path`.
+    """
+    pyexprs = parse_into_list_of_expressions(args)
+    serialized_kwargs = _serialize_kwargs(kwargs)
+    plugin_path = _resolve_plugin_path(plugin_path)
+
+    return wrap_expr(
+        plr.register_plugin_aggregation(
+            plugin_path=str(plugin_path),
+            function_name=function_name,
+            args=pyexprs,
+            kwargs=serialized_kwargs,
+        )
+    )
+
+
 def _serialize_kwargs(kwargs: dict[str, Any] | None) -> bytes:
     """Serialize the function's keyword arguments."""
     if not kwargs:
@@ -0,0 +1,189 @@
+"""
+Expressions that draw random values from probability distributions.
+
+The value of every row only depends on the seed and the position of the row, so a
+query with a seed gives the same values however the rows are split over threads or
+morsels. Without a seed, a seed is drawn from the global random number generator,
+which can be seeded with :func:`polars.set_random_seed`.
+"""
+
+from __future__ import annotations
+
+import contextlib
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars.polars as plr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExpr
+
+__all__ = ["normal", "poisson", "uniform"]
+
+
+@unstable()
+def uniform(
+    low: IntoExpr = 0.0, high: IntoExpr = 1.0, *, seed: int | None = None
+) -> Expr:
+    """
+    Draw a value for every row from the uniform distribution.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    low
+        The lower bound, which is included. Accepts expression input. Strings are
+        parsed as column names.
+    high
+        The upper bound, which is excluded. Accepts expression input. Strings are
+        parsed as column names.
+    seed
+        The seed of the random values. If set to None (default), a seed is drawn
+        from the global random number generator.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`Float64`.
+
+    Notes
+    -----
+    The rows where a bound is null are null. Within `group_by` and `over`, every
+    group draws the same values for a given seed.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"high": [1.0, 10.0, 100.0]})
+    >>> df.with_columns(
+    ...     pl.random.uniform(high="high", seed=1).alias("value")
+    ... )  # doctest: +IGNORE_RESULT
+    shape: (3, 2)
+    ┌───────┬───────────┐
+    │ high  ┆ value     │
+    │ ---   ┆ ---       │
+    │ f64   ┆ f64       │
+    ╞═══════╪═══════════╡
+    │ 1.0   ┆ 0.412096  │
+    │ 10.0  ┆ 7.382914  │
+    │ 100.0 ┆ 25.916071 │
+    └───────┴───────────┘
+    """
+    low = parse_into_expression(low)
+    high = parse_into_expression(high)
+    return wrap_expr(plr.rand_uniform(low, high, seed))
+
+
+@unstable()
+def normal(
+    mean: IntoExpr = 0.0, std: IntoExpr = 1.0, *, seed: int | None = None
+) -> Expr:
+    """
+    Draw a value for every row from the normal distribution.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    mean
+        The mean of the distribution. Accepts expression input. Strings are parsed
+        as column names.
+    std
+        The standard deviation of the distribution, which must not be negative.
+        Accepts expression input. Strings are parsed as column names.
+    seed
+        The seed of the random values. If set to None (default), a seed is drawn
+        from the global random number generator.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`Float64`.
+
+    Notes
+    -----
+    The rows where a parameter is null are null. Within `group_by` and `over`,
+    every group draws the same values for a given seed.
+
+    Examples
+    --------
+    Add noise to a column:
+
+    >>> df = pl.DataFrame({"x": [1.0, 2.0, 3.0]})
+    >>> df.with_columns(
+    ...     (pl.col("x") + pl.random.normal(std=0.1, seed=1)).alias("noisy")
+    ... )  # doctest: +IGNORE_RESULT
+    shape: (3, 2)
+    ┌─────┬──────────┐
+    │ x   ┆ noisy    │
+    │ --- ┆ ---      │
+    │ f64 ┆ f64      │
+    ╞═════╪══════════╡
+    │ 1.0 ┆ 1.046373 │
+    │ 2.0 ┆ 1.893105 │
+    │ 3.0 ┆ 3.152967 │
+    └─────┴──────────┘
+    """
+    mean = parse_into_expression(mean)
+    std = parse_into_expression(std)
+    return wrap_expr(plr.rand_normal(mean, std, seed))
+
+
+@unstable()
+def poisson(lam: IntoExpr = 1.0, *, seed: int | None = None) -> Expr:
+    """
+    Draw a value for every row from the Poisson distribution.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    lam
+        The expected number of events, which must be finite and not negative.
+        Accepts expression input. Strings are parsed as column names.
+    seed
+        The seed of the random values. If set to None (default), a seed is drawn
+        from the global random number generator.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`Int64`.
+
+    Notes
+    -----
+    The rows where `lam` is null are null. Within `group_by` and `over`, every
+    group draws the same values for a given seed.
+
+    Examples
+    --------
+    Simulate the number of arrivals of every hour:
+
+    >>> df = pl.DataFrame({"hour": [8, 9, 10], "rate": [2.5, 10.0, 4.0]})
+    >>> df.with_columns(
+    ...     pl.random.poisson("rate", seed=1).alias("arrivals")
+    ... )  # doctest: +IGNORE_RESULT
+    shape: (3, 3)
+    ┌──────┬──────┬──────────┐
+    │ hour ┆ rate ┆ arrivals │
+    │ ---  ┆ ---  ┆ ---      │
+    │ i64  ┆ f64  ┆ i64      │
+    ╞══════╪══════╪══════════╡
+    │ 8    ┆ 2.5  ┆ 3        │
+    │ 9    ┆ 10.0 ┆ 12       │
+    │ 10   ┆ 4.0  ┆ 2        │
+    └──────┴──────┴──────────┘
+    """
+    lam = parse_into_expression(lam)
+    return wrap_expr(plr.rand_poisson(lam, seed))
@@ -0,0 +1,109 @@
+from __future__ import annotations
+
+import contextlib
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars._utils.various import normalize_filepath
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars.polars as plr
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+__all__ = [
+    "clear_result_cache",
+    "disable_result_cache",
+    "enable_result_cache",
+    "using_result_cache",
+]
+
+
+@unstable()
+def enable_result_cache(
+    memory_budget: int = 1 << 30,
+    *,
+    spill_dir: str | Path | None = None,
+    disk_budget: int = 0,
+) -> None:
+    """
+    Enable the global result cache.
+
+    While the result cache is enabled, the results of :meth:`LazyFrame.collect` are
+    kept, keyed by the optimized query plan. When a later query contains a part of the
+    plan that was collected before, that part is read from the cache instead of being
+    computed again.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    memory_budget
+        Maximum estimated size in bytes of the results kept in memory. Once exceeded,
+        the least recently used results are spilled to `spill_dir` or dropped.
+    spill_dir
+        Directory that results are written to when they no longer fit in memory.
+        If not set, those results are dropped.
+    disk_budget
+        Maximum size in bytes of the results written to `spill_dir`.
+
+    See Also
+    --------
+    disable_result_cache : Function to disable the result cache.
+    clear_result_cache : Function to clear the result cache.
+
+    Notes
+    -----
+    Only queries that read local files or in-memory data and that don't contain
+    user-defined or random functions are cached. A result is invalidated when one
+    of the files it was read from is modified.
+
+    Calling this function while the result cache is enabled updates its budgets
+    and keeps the cached results that fit.
+
+    Examples
+    --------
+    >>> pl.enable_result_cache(memory_budget=100_000_000)  # doctest: +SKIP
+    >>> lf = pl.scan_csv("data.csv").filter(pl.col("a") > 1)  # doctest: +SKIP
+    >>> df = lf.collect()  # doctest: +SKIP
+
+    The filtered data is read from the cache.
+
+    >>> df = lf.with_columns(b=pl.col("a") * 2).collect()  # doctest: +SKIP
+    >>> pl.disable_result_cache()  # doctest: +SKIP
+    """
+    if spill_dir is not None:
+        spill_dir = normalize_filepath(spill_dir, check_not_directory=False)
+    plr.enable_result_cache(memory_budget, spill_dir, disk_budget)
+
+
+def disable_result_cache() -> None:
+    """
+    Disable and clear the global result cache.
+
+    See Also
+    --------
+    enable_result_cache : Function to enable the result cache.
+    """
+    plr.disable_result_cache()
+
+
+def clear_result_cache() -> None:
+    """
+    Remove all results from the global result cache.
+
+    The result cache stays enabled if it was enabled.
+
+    See Also
+    --------
+    disable_result_cache : Function to disable and clear the result cache.
+    """
+    plr.clear_result_cache()
+
+
+def using_result_cache() -> bool:
+    """Check whether the global result cache is enabled."""
+    return plr.using_result_cache()
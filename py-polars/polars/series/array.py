@@ -3,6 +3,7 @@ from __future__ import annotations
 from typing import TYPE_CHECKING, Callable, Sequence
 
 from polars import functions as F
+from polars._utils.unstable import unstable
 from polars._utils.wrap import wrap_s
 from polars.series.utils import expr_dispatch
 
@@ -119,6 +120,150 @@ class ArrayNameSpace:
         ]
         """
 
+    @unstable()
+    def sum_elementwise(self) -> Series:
+        """
+        Sum the arrays of all rows element-wise into a single array.
+
+        Null values are skipped; a position without any values sums to zero.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2], [3, None]], dtype=pl.Array(pl.Int64, 2))
+        >>> s.arr.sum_elementwise()
+        shape: (1,)
+        Series: 'a' [array[i64, 2]]
+        [
+            [4, 2]
+        ]
+        """
+
+    @unstable()
+    def mean_elementwise(self) -> Series:
+        """
+        Average the arrays of all rows element-wise into a single array.
+
+        Null values are skipped; a position without any values is null.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2], [3, None]], dtype=pl.Array(pl.Int64, 2))
+        >>> s.arr.mean_elementwise()
+        shape: (1,)
+        Series: 'a' [array[f64, 2]]
+        [
+            [2.0, 2.0]
+        ]
+        """
+
+    @unstable()
+    def dot(self, other: IntoExpr) -> Series:
+        """
+        Compute the dot product of every array with the arrays of `other`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Arrays to multiply with, a single array is used for every row.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1.0, 2.0], [3.0, 4.0]], dtype=pl.Array(pl.Float64, 2))
+        >>> s.arr.dot(pl.Series([[1.0, 1.0]], dtype=pl.Array(pl.Float64, 2)))
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            3.0
+            7.0
+        ]
+        """
+
+    @unstable()
+    def l2_norm(self) -> Series:
+        """
+        Compute the euclidean norm of every array.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3.0, 4.0], [1.0, 0.0]], dtype=pl.Array(pl.Float64, 2))
+        >>> s.arr.l2_norm()
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            5.0
+            1.0
+        ]
+        """
+
+    @unstable()
+    def cosine_similarity(self, other: IntoExpr) -> Series:
+        """
+        Compute the cosine similarity of every array with the arrays of `other`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Arrays to compare with, a single array is used for every row.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1.0, 0.0], [0.0, 2.0]], dtype=pl.Array(pl.Float64, 2))
+        >>> s.arr.cosine_similarity(s)
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            1.0
+            1.0
+        ]
+        """
+
+    @unstable()
+    def matmul(self, other: IntoExpr) -> Series:
+        """
+        Compute the matrix product of every array with the arrays of `other`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Arrays to multiply with, a single array is used for every row.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "m", [[[1.0, 2.0], [3.0, 4.0]]], dtype=pl.Array(pl.Float64, (2, 2))
+        ... )
+        >>> s.arr.matmul(pl.Series([[1.0, 1.0]], dtype=pl.Array(pl.Float64, 2)))
+        shape: (1,)
+        Series: 'm' [array[f64, 2]]
+        [
+            [3.0, 7.0]
+        ]
+        """
+
     def unique(self, *, maintain_order: bool = False) -> Series:
         """
         Get the unique/distinct values in the array.
@@ -475,6 +620,59 @@ class ArrayNameSpace:
         ]
         """
 
+    def explode_to_rows(self) -> Series:
+        """
+        Explode the outer dimension of the arrays into rows.
+
+        Contrary to :meth:`explode`, multi-dimensional arrays keep their remaining
+        dimensions as :class:`Array`. Every array produces exactly `width` rows, so
+        a null array results in `width` null rows.
+
+        Returns
+        -------
+        Series
+            Series with the data type of the array elements.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a", [[[1, 2], [3, 4]], None], dtype=pl.Array(pl.Int64, (2, 2))
+        ... )
+        >>> s.arr.explode_to_rows()
+        shape: (4,)
+        Series: 'a' [array[i64, 2]]
+        [
+            [1, 2]
+            [3, 4]
+            null
+            null
+        ]
+        """
+
+    def reshape(self, dimensions: tuple[int, ...]) -> Series:
+        """
+        Reshape every array into the given dimensions, keeping the number of rows.
+
+        Parameters
+        ----------
+        dimensions
+            Tuple of the dimension sizes of every array. A single dimension may be
+            -1, it is inferred from the width of the arrays.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a", [[1, 2, 3, 4], [5, 6, 7, 8]], dtype=pl.Array(pl.Int64, 4)
+        ... )
+        >>> s.arr.reshape((2, -1))
+        shape: (2,)
+        Series: 'a' [array[i64, (2, 2)]]
+        [
+            [[1, 2], [3, 4]]
+            [[5, 6], [7, 8]]
+        ]
+        """
+
     def contains(
         self, item: float | str | bool | int | date | datetime | time | IntoExprColumn
     ) -> Series:
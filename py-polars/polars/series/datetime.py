@@ -1642,7 +1642,12 @@ class DateTimeNameSpace:
         ]
         """
 
-    def truncate(self, every: str | dt.timedelta | Expr) -> Series:
+    def truncate(
+        self,
+        every: str | dt.timedelta | Expr,
+        *,
+        offset: str | dt.timedelta | None = None,
+    ) -> Series:
         """
         Divide the date/ datetime range into buckets.
 
@@ -1657,6 +1662,11 @@ class DateTimeNameSpace:
         ----------
         every
             Every interval start and period length
+        offset
+            Shift the buckets by this duration, in local time like the `offset` of
+            :meth:`DataFrame.group_by_dynamic`. For example, `every="15m"` and
+            `offset="5m"` give buckets starting at 5, 20, 35 and 50 minutes past the
+            hour.
 
         Notes
         -----
@@ -1674,6 +1684,10 @@ class DateTimeNameSpace:
         - 1mo   (1 calendar month)
         - 1q    (1 calendar quarter)
         - 1y    (1 calendar year)
+        - 1bme  (1 business month end)
+        - 1qs   (1 calendar quarter, same as 1q)
+        - 1w_sun (1 calendar week starting on Sunday, likewise for
+          w_mon, w_tue, ..., w_sat)
 
         These strings can be combined:
 
@@ -1683,6 +1697,10 @@ class DateTimeNameSpace:
         not be 24 hours, due to daylight savings). Similarly for "calendar week",
         "calendar month", "calendar quarter", and "calendar year".
 
+        The anchored frequencies `bme`, `qs` and `w_mon` to `w_sun` cannot be
+        combined with other units. Custom frequencies can be registered with
+        :func:`register_frequency`.
+
         Returns
         -------
         Series
@@ -1756,7 +1774,12 @@ class DateTimeNameSpace:
         """
 
     @unstable()
-    def round(self, every: str | dt.timedelta | IntoExprColumn) -> Series:
+    def round(
+        self,
+        every: str | dt.timedelta | IntoExprColumn,
+        *,
+        offset: str | dt.timedelta | None = None,
+    ) -> Series:
         """
         Divide the date/ datetime range into buckets.
 
@@ -1777,6 +1800,8 @@ class DateTimeNameSpace:
         ----------
         every
             Every interval start and period length
+        offset
+            Shift the buckets by this duration, see :meth:`truncate`.
 
         Returns
         -------
@@ -0,0 +1,69 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars.polars import PySeries
+
+
+@expr_dispatch
+class JsonNameSpace:
+    """Series.json namespace."""
+
+    _accessor = "json"
+
+    def __init__(self, series: Series):
+        self._s: PySeries = series._s
+
+    @unstable()
+    def path(self, path: str) -> Series:
+        """
+        Extract the values at a JSON path.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        path
+            A path like `$.a.b[0]`. Keys can be given as `.key` or `["key"]`, array
+            elements as `[n]`, where a negative `n` counts from the end of the array.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ['{"b": {"c": 1}}', '{"b": 2}'], dtype=pl.Json)
+        >>> s.json.path("b.c")
+        shape: (2,)
+        Series: 'a' [json]
+        [
+            "1"
+            null
+        ]
+        """
+
+    @unstable()
+    def get_type(self) -> Series:
+        """
+        Get the JSON type of the values.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["true", "[]", None], dtype=pl.Json)
+        >>> s.json.get_type()
+        shape: (3,)
+        Series: 'a' [str]
+        [
+            "boolean"
+            "array"
+            null
+        ]
+        """
@@ -3,6 +3,7 @@ from __future__ import annotations
 from typing import TYPE_CHECKING, Any, Callable, Sequence
 
 from polars import functions as F
+from polars._utils.unstable import unstable
 from polars._utils.wrap import wrap_s
 from polars.series.utils import expr_dispatch
 
@@ -277,6 +278,52 @@ class ListNameSpace:
         ]
         """
 
+    @unstable()
+    def sum_elementwise(self) -> Series:
+        """
+        Sum the lists of all rows element-wise into a single list.
+
+        All lists that are not null must have the same length. Null values are
+        skipped; a position without any values sums to zero.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2], [3, None]])
+        >>> s.list.sum_elementwise()
+        shape: (1,)
+        Series: 'a' [list[i64]]
+        [
+            [4, 2]
+        ]
+        """
+
+    @unstable()
+    def mean_elementwise(self) -> Series:
+        """
+        Average the lists of all rows element-wise into a single list.
+
+        All lists that are not null must have the same length. Null values are
+        skipped; a position without any values is null.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2], [3, None]])
+        >>> s.list.mean_elementwise()
+        shape: (1,)
+        Series: 'a' [list[f64]]
+        [
+            [2.0, 2.0]
+        ]
+        """
+
     def sort(
         self,
         *,
@@ -953,6 +1000,32 @@ class ListNameSpace:
         ]
         """
 
+    def agg(self, expr: Expr) -> Series:
+        """
+        Reduce the elements of every list to a single value with an expression.
+
+        Unlike :meth:`eval`, the expression isn't run for every list separately, but
+        once on the elements of all lists, with every list as a group.
+
+        Parameters
+        ----------
+        expr
+            Expression that reduces the elements to a single value. Select the
+            elements with `pl.element()`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 4], [8, 5], [3, 2]])
+        >>> s.list.agg(pl.element().median())
+        shape: (3,)
+        Series: 'a' [f64]
+        [
+            2.5
+            6.5
+            2.5
+        ]
+        """
+
     def set_union(self, other: Series) -> Series:
         """
         Compute the SET UNION between the elements in this list and the elements of `other`.
@@ -0,0 +1,223 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars._typing import IntoExpr
+    from polars.polars import PySeries
+
+
+@expr_dispatch
+class ScaleNameSpace:
+    """Namespace for methods that scale numeric features."""
+
+    _accessor = "scale"
+
+    def __init__(self, series: Series):
+        self._s: PySeries = series._s
+
+    @unstable()
+    def standard(self, *, ddof: int = 1) -> Series:
+        """
+        Scale to zero mean and unit standard deviation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        ddof
+            "Delta Degrees of Freedom" of the standard deviation.
+
+        Examples
+        --------
+        >>> pl.Series("a", [1, 3, 5]).scale.standard()
+        shape: (3,)
+        Series: 'a' [f64]
+        [
+            -1.0
+            0.0
+            1.0
+        ]
+        """
+
+    @unstable()
+    def robust(self, q_low: float = 0.25, q_high: float = 0.75) -> Series:
+        """
+        Scale by subtracting the median and dividing by an interquantile range.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        q_low
+            Quantile at the start of the range, between 0 and 1.
+        q_high
+            Quantile at the end of the range, between `q_low` and 1.
+
+        Examples
+        --------
+        >>> pl.Series("a", [1, 2, 3, 4, 10]).scale.robust()
+        shape: (5,)
+        Series: 'a' [f64]
+        [
+            -1.0
+            -0.5
+            0.0
+            0.5
+            3.5
+        ]
+        """
+
+    @unstable()
+    def min_max(self, range: tuple[float, float] = (0.0, 1.0)) -> Series:
+        """
+        Scale the minimum and maximum to the bounds of a range.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        range
+            The values that the minimum and maximum are scaled to.
+
+        Examples
+        --------
+        >>> pl.Series("a", [1, 2, 5]).scale.min_max()
+        shape: (3,)
+        Series: 'a' [f64]
+        [
+            0.0
+            0.25
+            1.0
+        ]
+        """
+
+    @unstable()
+    def standard_params(self, *, ddof: int = 1) -> Series:
+        """
+        Compute the parameters of :meth:`standard` without applying them.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        ddof
+            "Delta Degrees of Freedom" of the standard deviation.
+
+        Returns
+        -------
+        Series
+            Series of length 1 and data type :class:`Struct` with the fields
+            `center` and `scale`, to pass to :meth:`apply`.
+        """
+
+    @unstable()
+    def robust_params(self, q_low: float = 0.25, q_high: float = 0.75) -> Series:
+        """
+        Compute the parameters of :meth:`robust` without applying them.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        q_low
+            Quantile at the start of the range, between 0 and 1.
+        q_high
+            Quantile at the end of the range, between `q_low` and 1.
+
+        Returns
+        -------
+        Series
+            Series of length 1 and data type :class:`Struct` with the fields
+            `center` and `scale`, to pass to :meth:`apply`.
+        """
+
+    @unstable()
+    def min_max_params(self, range: tuple[float, float] = (0.0, 1.0)) -> Series:
+        """
+        Compute the parameters of :meth:`min_max` without applying them.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        range
+            The values that the minimum and maximum are scaled to.
+
+        Returns
+        -------
+        Series
+            Series of length 1 and data type :class:`Struct` with the fields
+            `center` and `scale`, to pass to :meth:`apply`.
+        """
+
+    @unstable()
+    def apply(self, params: IntoExpr) -> Series:
+        """
+        Scale with previously computed parameters.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        params
+            Struct with the fields `center` and `scale`, as computed by
+            :meth:`standard_params`, :meth:`robust_params` or
+            :meth:`min_max_params`.
+
+        Examples
+        --------
+        >>> params = pl.Series("a", [1, 2, 5]).scale.min_max_params()
+        >>> pl.Series("a", [3, 9]).scale.apply(params)
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            0.5
+            2.0
+        ]
+        """
+
+    @unstable()
+    def inverse(self, params: IntoExpr) -> Series:
+        """
+        Undo the scaling with previously computed parameters.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        params
+            Struct with the fields `center` and `scale` that the values were scaled
+            with.
+
+        Examples
+        --------
+        >>> params = pl.Series("a", [1, 2, 5]).scale.min_max_params()
+        >>> pl.Series("a", [0.5, 2.0]).scale.inverse(params)
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            3.0
+            9.0
+        ]
+        """
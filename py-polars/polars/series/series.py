@@ -25,6 +25,7 @@ from typing import (
 import polars._reexport as pl
 from polars import functions as F
 from polars._utils.construction import (
+    arrow_capsule_to_pyseries,
     arrow_to_pyseries,
     dataframe_to_pyseries,
     iterable_to_pyseries,
@@ -102,9 +103,12 @@ from polars.series.array import ArrayNameSpace
 from polars.series.binary import BinaryNameSpace
 from polars.series.categorical import CatNameSpace
 from polars.series.datetime import DateTimeNameSpace
+from polars.series.json import JsonNameSpace
 from polars.series.list import ListNameSpace
+from polars.series.scale import ScaleNameSpace
 from polars.series.string import StringNameSpace
 from polars.series.struct import StructNameSpace
+from polars.series.ts import TimeSeriesNameSpace
 from polars.series.utils import expr_dispatch, get_ffi_func
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -123,7 +127,9 @@ if TYPE_CHECKING:
         BufferInfo,
         ClosedInterval,
         ComparisonOperator,
+        DuplicateKeepStrategy,
         FillNullStrategy,
+        HistBinStrategy,
         InterpolationMethod,
         IntoExpr,
         IntoExprColumn,
@@ -135,6 +141,7 @@ if TYPE_CHECKING:
         PythonLiteral,
         RankMethod,
         RollingInterpolationMethod,
+        RoundMode,
         SearchSortedSide,
         SeriesBuffers,
         SingleIndexSelector,
@@ -175,7 +182,8 @@ class Series:
         When not specified, name is set to an empty string.
     values : ArrayLike, default None
         One-dimensional data in various forms. Supported are: Sequence, Series,
-        pyarrow Array, and numpy ndarray.
+        pyarrow Array, numpy ndarray, and objects that export their data through
+        the Arrow PyCapsule interface.
     dtype : DataType, default None
         Data type of the resulting Series. If set to `None` (default), the data type is
         inferred from the `values` input. The strategy for data type inference depends
@@ -247,10 +255,13 @@ class Series:
         "arr",
         "cat",
         "dt",
+        "json",
         "list",
         "str",
         "bin",
+        "scale",
         "struct",
+        "ts",
         "plot",
     }
 
@@ -340,6 +351,13 @@ class Series:
                 original_name, values, dtype=dtype, strict=strict
             )
 
+        elif hasattr(values, "__arrow_c_stream__") or hasattr(
+            values, "__arrow_c_array__"
+        ):
+            self._s = arrow_capsule_to_pyseries(
+                original_name, values, dtype=dtype, strict=strict
+            )
+
         else:
             msg = (
                 f"Series constructor called with unsupported type {type(values).__name__!r}"
@@ -1391,6 +1409,28 @@ class Series:
             )
             raise NotImplementedError(msg)
 
+    def __arrow_c_schema__(self) -> Any:
+        """Export the data type through the Arrow PyCapsule interface."""
+        return self._s.__arrow_c_schema__()
+
+    def __arrow_c_array__(self, requested_schema: Any = None) -> Any:
+        """
+        Export the data through the Arrow PyCapsule interface, as a single array.
+
+        A Series with multiple chunks is rechunked first, use `__arrow_c_stream__` to
+        export the chunks as they are.
+        """
+        return self._s.__arrow_c_array__(requested_schema)
+
+    def __arrow_c_stream__(self, requested_schema: Any = None) -> Any:
+        """
+        Export the data through the Arrow PyCapsule interface, with an array per chunk.
+
+        This lets libraries such as pyarrow and nanoarrow read the Series without
+        copying it.
+        """
+        return self._s.__arrow_c_stream__(requested_schema)
+
     def _repr_html_(self) -> str:
         """Format output data in HTML for display in Jupyter Notebooks."""
         return self.to_frame()._repr_html_(_from_series=True)
@@ -2334,6 +2374,7 @@ class Series:
         bins: list[float] | None = None,
         *,
         bin_count: int | None = None,
+        bin_strategy: HistBinStrategy | None = None,
         include_category: bool = True,
         include_breakpoint: bool = True,
     ) -> DataFrame:
@@ -2352,6 +2393,12 @@ class Series:
         bin_count
             If no bins provided, this will be used to determine
             the distance of the bins
+        bin_strategy : {'fd', 'sturges'}
+            If neither `bins` nor `bin_count` are provided, the rule that determines
+            the number of bins from the data.
+
+            - 'fd': Freedman-Diaconis, bins of width `2 * IQR / n^(1/3)`.
+            - 'sturges': `log2(n) + 1` bins.
         include_breakpoint
             Include a column that indicates the upper breakpoint.
         include_category
@@ -2384,6 +2431,7 @@ class Series:
                 F.col(self.name).hist(
                     bins=bins,
                     bin_count=bin_count,
+                    bin_strategy=bin_strategy,
                     include_category=include_category,
                     include_breakpoint=include_breakpoint,
                 )
@@ -3096,6 +3144,7 @@ class Series:
         nulls_last: bool = False,
         multithreaded: bool = True,
         in_place: bool = False,
+        collation: str | None = None,
     ) -> Self:
         """
         Sort this Series.
@@ -3110,6 +3159,13 @@ class Series:
             Sort using multiple threads.
         in_place
             Sort in-place.
+        collation
+            Sort strings by the collation of this locale instead of by their bytes,
+            given as a BCP 47 language tag such as `"de-AT"`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Examples
         --------
@@ -3134,11 +3190,11 @@ class Series:
         ]
         """
         if in_place:
-            self._s = self._s.sort(descending, nulls_last, multithreaded)
+            self._s = self._s.sort(descending, nulls_last, multithreaded, collation)
             return self
         else:
             return self._from_pyseries(
-                self._s.sort(descending, nulls_last, multithreaded)
+                self._s.sort(descending, nulls_last, multithreaded, collation)
             )
 
     def top_k(self, k: int = 5) -> Series:
@@ -3210,7 +3266,9 @@ class Series:
         ]
         """
 
-    def arg_sort(self, *, descending: bool = False, nulls_last: bool = False) -> Series:
+    def arg_sort(
+        self, *, descending: bool = False, nulls_last: bool = False, stable: bool = True
+    ) -> Series:
         """
         Get the index values that would sort this Series.
 
@@ -3220,6 +3278,10 @@ class Series:
             Sort in descending order.
         nulls_last
             Place null values last instead of first.
+        stable
+            Whether the indices of equal values stay in ascending order, which is the
+            default. Set to `False` to allow a faster unstable sort, which notably pays
+            off for many numeric values.
 
         See Also
         --------
@@ -3800,10 +3862,19 @@ class Series:
         ]
         """
 
-    def is_duplicated(self) -> Series:
+    def is_duplicated(self, *, keep: DuplicateKeepStrategy = "none") -> Series:
         """
         Get mask of all duplicated values.
 
+        Parameters
+        ----------
+        keep : {'none', 'first', 'last'}
+            Which occurrence of a duplicated value is not marked as duplicated.
+
+            * 'none': Mark all occurrences.
+            * 'first': Do not mark the first occurrence of each value.
+            * 'last': Do not mark the last occurrence of each value.
+
         Returns
         -------
         Series
@@ -3821,6 +3892,15 @@ class Series:
                 true
                 false
         ]
+        >>> s.is_duplicated(keep="first")
+        shape: (4,)
+        Series: 'a' [bool]
+        [
+                false
+                false
+                true
+                false
+        ]
         """
 
     def explode(self) -> Series:
@@ -3980,6 +4060,94 @@ class Series:
         ]
         """
 
+    @unstable()
+    def is_valid_mask(self) -> Series:
+        """
+        Get the validity of the values as a Boolean mask.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The mask is `True` where a value is valid and `False` where it is null. It
+        shares the validity buffers of the data, so no copy is made.
+
+        See Also
+        --------
+        with_validity
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, None, 3])
+        >>> s.is_valid_mask()
+        shape: (3,)
+        Series: 'a' [bool]
+        [
+            true
+            false
+            true
+        ]
+        """
+
+    @unstable()
+    def with_validity(self, mask: Series | bool) -> Series:
+        """
+        Replace the validity of the values by a Boolean mask.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The result is null where the mask is `False` or null, and valid where it is
+        `True`. The values themselves are not touched: a value that was null and is
+        made valid is whatever the data holds in that slot, which is usually zero or
+        empty but not guaranteed to be.
+
+        Parameters
+        ----------
+        mask
+            Boolean Series with the new validity. A mask of length 1 is broadcast.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, 3])
+        >>> s.with_validity(pl.Series([True, False, None]))
+        shape: (3,)
+        Series: 'a' [i64]
+        [
+            1
+            null
+            null
+        ]
+        """
+
+    @unstable()
+    def coalesce_validity(self, other: Series) -> Series:
+        """
+        Set the values to null where `other` is null.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Series of any data type whose nulls are carried over.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, None, 3])
+        >>> s.coalesce_validity(pl.Series(["x", "y", None]))
+        shape: (3,)
+        Series: 'a' [i64]
+        [
+            1
+            null
+            null
+        ]
+        """
+
     def to_list(self) -> list[Any]:
         """
         Convert this Series to a Python list.
@@ -4828,7 +4996,7 @@ class Series:
         ]
         """
 
-    def round(self, decimals: int = 0) -> Series:
+    def round(self, decimals: int = 0, mode: RoundMode = "half_away") -> Series:
         """
         Round underlying floating point data by `decimals` digits.
 
@@ -4843,11 +5011,80 @@ class Series:
                 2.57
                 3.9
         ]
+        >>> pl.Series([0.5, 1.5, 2.5]).round(mode="half_even")
+        shape: (3,)
+        Series: '' [f64]
+        [
+                0.0
+                2.0
+                2.0
+        ]
 
         Parameters
         ----------
         decimals
             number of decimals to round by.
+        mode : {'half_away', 'half_even'}
+            How to round values that lie exactly halfway between two candidates:
+            away from zero, or to the even candidate.
+        """
+
+    @unstable()
+    def rescale(self, scale: int, mode: RoundMode = "half_away") -> Series:
+        """
+        Change the scale of Decimal data, rounding the digits that are dropped.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The number of integer digits is kept, so the precision changes by the same
+        amount as the scale. Integer data is converted to decimals first.
+
+        Parameters
+        ----------
+        scale
+            Number of digits after the decimal point.
+        mode : {'half_away', 'half_even'}
+            How to round values that lie exactly halfway between two candidates:
+            away from zero, or to the even candidate.
+
+        Examples
+        --------
+        >>> from decimal import Decimal as D
+        >>> s = pl.Series([D("1.125"), D("2.135")], dtype=pl.Decimal(10, 3))
+        >>> s.rescale(2, mode="half_even")
+        shape: (2,)
+        Series: '' [decimal[9,2]]
+        [
+            1.12
+            2.14
+        ]
+        """
+
+    def ceildiv(self, other: Any) -> Series:
+        """
+        Divide and round the quotient up to the next integer.
+
+        Integer division by zero results in null.
+
+        Parameters
+        ----------
+        other
+            Numeric literal or series to divide by.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [-3, 0, 5, 7])
+        >>> s.ceildiv(2)
+        shape: (4,)
+        Series: 'a' [i64]
+        [
+                -1
+                0
+                3
+                4
+        ]
         """
 
     def round_sig_figs(self, digits: int) -> Series:
@@ -6134,6 +6371,7 @@ class Series:
         *,
         descending: bool = False,
         seed: int | None = None,
+        collation: str | None = None,
     ) -> Series:
         """
         Assign ranks to data, dealing with ties appropriately.
@@ -6162,6 +6400,13 @@ class Series:
             Rank in descending order.
         seed
             If `method="random"`, use this as seed.
+        collation
+            Rank strings by the collation of this locale instead of by their bytes,
+            given as a BCP 47 language tag.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Examples
         --------
@@ -7163,7 +7408,8 @@ class Series:
         Warnings
         --------
         This can lead to incorrect results if this `Series` is not sorted!!
-        Use with care!
+        Use with care! Use :meth:`check_sorted` to verify the order instead, or
+        :meth:`Config.set_validate_sorted` to verify it in every `set_sorted`.
 
         Examples
         --------
@@ -7173,6 +7419,73 @@ class Series:
         """
         return self._from_pyseries(self._s.set_sorted_flag(descending))
 
+    def check_sorted(self, *, descending: bool = False) -> Self:
+        """
+        Verify that the Series is sorted and flag it as 'sorted'.
+
+        Like :meth:`set_sorted`, but raises an error if the values are not sorted
+        instead of producing incorrect results downstream. The non-null values must
+        be sorted and the nulls must all be at the start or all at the end.
+
+        Parameters
+        ----------
+        descending
+            If the `Series` order is descending.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [None, 1, 2, 3])
+        >>> s.check_sorted().flags
+        {'SORTED_ASC': True, 'SORTED_DESC': False}
+        >>> pl.Series("a", [1, 3, 2]).check_sorted()
+        Traceback (most recent call last):
+        ...
+        polars.exceptions.ComputeError: series 'a' is not sorted in ascending order; value 2 at index 2 follows 3
+        """  # noqa: W505
+        return self._from_pyseries(self._s.check_sorted(descending))
+
+    def is_monotonic_increasing(self, *, strict: bool = False) -> bool:
+        """
+        Check whether the non-null values never decrease.
+
+        Null values are ignored. If the Series is flagged as sorted in ascending
+        order, the values are not checked unless `strict` is set.
+
+        Parameters
+        ----------
+        strict
+            Also require every value to be greater than the previous one.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, 2, None, 5])
+        >>> s.is_monotonic_increasing()
+        True
+        >>> s.is_monotonic_increasing(strict=True)
+        False
+        """
+        return self._s.is_monotonic(False, strict)
+
+    def is_monotonic_decreasing(self, *, strict: bool = False) -> bool:
+        """
+        Check whether the non-null values never increase.
+
+        Null values are ignored. If the Series is flagged as sorted in descending
+        order, the values are not checked unless `strict` is set.
+
+        Parameters
+        ----------
+        strict
+            Also require every value to be less than the previous one.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [5, 4, None, 1])
+        >>> s.is_monotonic_decreasing(strict=True)
+        True
+        """
+        return self._s.is_monotonic(True, strict)
+
     def new_from_index(self, index: int, length: int) -> Self:
         """
         Create a new Series filled with values from the given index.
@@ -7294,6 +7607,16 @@ class Series:
         """Create an object namespace of all array related methods."""
         return ArrayNameSpace(self)
 
+    @property
+    def json(self) -> JsonNameSpace:
+        """Create an object namespace of all json related methods."""
+        return JsonNameSpace(self)
+
+    @property
+    def scale(self) -> ScaleNameSpace:
+        """Create an object namespace of all methods that scale numeric features."""
+        return ScaleNameSpace(self)
+
     @property
     def str(self) -> StringNameSpace:
         """Create an object namespace of all string related methods."""
@@ -7304,6 +7627,11 @@ class Series:
         """Create an object namespace of all struct related methods."""
         return StructNameSpace(self)
 
+    @property
+    def ts(self) -> TimeSeriesNameSpace:
+        """Create an object namespace of all time series related methods."""
+        return TimeSeriesNameSpace(self)
+
     @property
     @unstable()
     def plot(self) -> hvPlotTabularPolars:
@@ -15,6 +15,7 @@ if TYPE_CHECKING:
         IntoExprColumn,
         PolarsDataType,
         PolarsTemporalType,
+        SchemaDict,
         TimeUnit,
         TransferEncoding,
     )
@@ -637,23 +638,32 @@ class StringNameSpace:
 
     def json_decode(
         self,
-        dtype: PolarsDataType | None = None,
+        dtype: PolarsDataType | SchemaDict | None = None,
         *,
         infer_schema_length: int | None = N_INFER_DEFAULT,
+        strict: bool = True,
     ) -> Series:
         """
         Parse string values as JSON.
 
-        Throws an error if invalid JSON strings are encountered.
+        Throws an error if invalid JSON strings are encountered, unless `strict` is
+        set to `False`. Large columns are parsed in parallel.
 
         Parameters
         ----------
         dtype
-            The dtype to cast the extracted value to. If None, the dtype will be
+            The dtype to cast the extracted value to. A mapping of field names to
+            dtypes is taken as the schema of a struct. If None, the dtype will be
             inferred from the JSON value.
         infer_schema_length
             The maximum number of rows to scan for schema inference.
             If set to `None`, the full data may be scanned *(this is slow)*.
+            Not used if `dtype` is given.
+        strict
+            Raise an error if a string is not valid JSON, naming its row. If set to
+            `False`, such strings are parsed as null instead. The schema is then
+            inferred from the valid strings only, and the number of invalid strings
+            is logged in verbose mode.
 
         See Also
         --------
@@ -1916,6 +1926,227 @@ class StringNameSpace:
 
         """
 
+    @unstable()
+    def levenshtein(self, other: IntoExpr, *, normalized: bool = False) -> Series:
+        """
+        Compute the Levenshtein distance to other strings.
+
+        The distance is the number of inserted, deleted and substituted characters
+        that turn one string into the other.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Strings to compare with.
+        normalized
+            Return the similarity `1 - distance / max(len(a), len(b))` in
+            `[0, 1]` instead of the distance, where `1` means the strings are
+            equal.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["kitten", "sitting", None])
+        >>> s.str.levenshtein("sitting")
+        shape: (3,)
+        Series: 'a' [u32]
+        [
+            3
+            0
+            null
+        ]
+        """
+
+    @unstable()
+    def damerau_levenshtein(
+        self, other: IntoExpr, *, normalized: bool = False
+    ) -> Series:
+        """
+        Compute the Damerau-Levenshtein distance to other strings.
+
+        Like the Levenshtein distance, but swapping two characters counts as a
+        single edit.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Strings to compare with.
+        normalized
+            Return the similarity `1 - distance / max(len(a), len(b))` in
+            `[0, 1]` instead of the distance, where `1` means the strings are
+            equal.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["acb", "bac", "cab"])
+        >>> s.str.damerau_levenshtein("abc")
+        shape: (3,)
+        Series: 'a' [u32]
+        [
+            1
+            1
+            2
+        ]
+        """
+
+    @unstable()
+    def hamming(self, other: IntoExpr, *, normalized: bool = False) -> Series:
+        """
+        Compute the Hamming distance to other strings.
+
+        The distance is the number of positions at which the characters differ.
+        Strings of a different length have a null distance.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Strings to compare with.
+        normalized
+            Return the similarity `1 - distance / len(a)` in `[0, 1]` instead of
+            the distance, where `1` means the strings are equal.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["karolin", "kathrin", "abc"])
+        >>> s.str.hamming("kerstin", normalized=True)
+        shape: (3,)
+        Series: 'a' [f64]
+        [
+            0.571429
+            0.428571
+            null
+        ]
+        """
+
+    @unstable()
+    def jaro_winkler(self, other: IntoExpr) -> Series:
+        """
+        Compute the Jaro-Winkler similarity to other strings.
+
+        The similarity is in `[0, 1]`, where `1` means the strings are equal.
+        Strings that share a prefix of up to 4 characters score higher.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Strings to compare with.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["MARTHA", "MARHTA", "xyz"])
+        >>> s.str.jaro_winkler("MARTHA")
+        shape: (3,)
+        Series: 'a' [f64]
+        [
+            1.0
+            0.961111
+            0.0
+        ]
+        """
+
+    @unstable()
+    def compare(self, other: IntoExpr, collation: str) -> Series:
+        """
+        Compare to other strings under a locale-aware collation.
+
+        Returns `-1` if the string orders before the other string, `0` if they are
+        equal under the collation and `1` if it orders after it.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Strings to compare with.
+        collation
+            Locale of the collation, as a BCP 47 language tag such as `"de-AT"`. The
+            strength is set with the `ks` keyword, e.g. `"en-u-ks-level1"` ignores
+            both case and accents.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["résumé", "Resume", "zebra"])
+        >>> s.str.compare("resume", "en-u-ks-level1")
+        shape: (3,)
+        Series: 'a' [i8]
+        [
+            0
+            0
+            1
+        ]
+        """
+
+    @unstable()
+    def min(self, collation: str) -> Series:
+        """
+        Get the smallest string under a locale-aware collation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        collation
+            Locale of the collation, as a BCP 47 language tag such as `"de-AT"`. The
+            strength is set with the `ks` keyword, e.g. `"en-u-ks-level1"` ignores
+            both case and accents.
+
+        Examples
+        --------
+        >>> s = pl.Series("fruit", ["Zitrone", "Äpfel", "apfel", "Banane"])
+        >>> s.str.min("de")
+        shape: (1,)
+        Series: 'fruit' [str]
+        [
+            "apfel"
+        ]
+        """
+
+    @unstable()
+    def max(self, collation: str) -> Series:
+        """
+        Get the largest string under a locale-aware collation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        collation
+            Locale of the collation, as a BCP 47 language tag such as `"de-AT"`. The
+            strength is set with the `ks` keyword, e.g. `"en-u-ks-level1"` ignores
+            both case and accents.
+
+        Examples
+        --------
+        >>> s = pl.Series("fruit", ["Zitrone", "Äpfel", "apfel", "Banane"])
+        >>> s.str.max("de")
+        shape: (1,)
+        Series: 'fruit' [str]
+        [
+            "Zitrone"
+        ]
+        """
+
     def join(self, delimiter: str = "", *, ignore_nulls: bool = True) -> Series:
         """
         Vertically concatenate the string values in the column to a single string value.
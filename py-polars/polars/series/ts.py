@@ -0,0 +1,107 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars._typing import IntoExpr
+    from polars.polars import PySeries
+
+
+@expr_dispatch
+class TimeSeriesNameSpace:
+    """Namespace for time series related methods."""
+
+    _accessor = "ts"
+
+    def __init__(self, series: Series):
+        self._s: PySeries = series._s
+
+    @unstable()
+    def lttb(self, n_out: int, x: IntoExpr | None = None) -> Series:
+        """
+        Select the points to keep when downsampling with LTTB.
+
+        The Largest-Triangle-Three-Buckets algorithm reduces the series to `n_out`
+        points that preserve its visual shape.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        n_out
+            Number of points to keep; at least 3.
+        x
+            The x coordinates of the points, e.g. the timestamps. The points are
+            expected to be sorted by it. If not given, the points are taken to be
+            evenly spaced.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean` that is `True` for the points to
+            keep.
+
+        See Also
+        --------
+        min_max
+
+        Examples
+        --------
+        >>> s = pl.Series("y", [0, 2, 1, 7, 3, 0, -4, 1, 2, 0])
+        >>> s.filter(s.ts.lttb(4))
+        shape: (4,)
+        Series: 'y' [i64]
+        [
+            0
+            7
+            -4
+            0
+        ]
+        """
+
+    @unstable()
+    def min_max(self, n_out: int) -> Series:
+        """
+        Select the points to keep when downsampling by min-max decimation.
+
+        The series is split in `n_out // 2` buckets of consecutive points, and the
+        minimum and maximum of every bucket are kept.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        n_out
+            Maximum number of points to keep; at least 2.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean` that is `True` for the points to
+            keep.
+
+        See Also
+        --------
+        lttb
+
+        Examples
+        --------
+        >>> s = pl.Series("y", [0, 2, 1, 7, 3, 0, -4, 1, 2, 0])
+        >>> s.filter(s.ts.min_max(4))
+        shape: (4,)
+        Series: 'y' [i64]
+        [
+            0
+            7
+            -4
+            2
+        ]
+        """
@@ -112,7 +112,7 @@ impl PyBatchedCsv {
             .with_raise_if_empty(raise_if_empty)
             .with_parse_options(
                 CsvParseOptions::default()
-                    .with_separator(separator.as_bytes()[0])
+                    .with_separator_str(separator)
                     .with_encoding(encoding.0)
                     .with_missing_is_null(!missing_utf8_is_empty_string)
                     .with_comment_prefix(comment_prefix)
@@ -3,6 +3,8 @@ pub(crate) mod chunked_array;
 mod datetime;
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
+#[cfg(any(feature = "csv", feature = "json"))]
+use std::path::Path;
 
 #[cfg(feature = "object")]
 use polars::chunked_array::object::PolarsObjectSafe;
@@ -12,7 +14,12 @@ use polars::frame::NullStrategy;
 use polars::io::avro::AvroCompression;
 #[cfg(feature = "cloud")]
 use polars::io::cloud::CloudOptions;
+#[cfg(feature = "cloud")]
+use polars::io::cloud::{FetchedCredential, ObjectStoreCredential, PlCredentialProvider};
+#[cfg(any(feature = "csv", feature = "json"))]
+use polars::io::compression::OutputCompression;
 use polars::series::ops::NullBehavior;
+use polars_core::fmt::ColumnFmt;
 use polars_core::utils::arrow::array::Array;
 use polars_core::utils::arrow::types::NativeType;
 use polars_core::utils::materialize_dyn_int;
@@ -204,6 +211,10 @@ impl ToPyObject for Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "UInt64")).unwrap();
                 class.call0().unwrap().into()
             },
+            DataType::Float16 => {
+                let class = pl.getattr(intern!(py, "Float16")).unwrap();
+                class.call0().unwrap().into()
+            },
             DataType::Float32 => {
                 let class = pl.getattr(intern!(py, "Float32")).unwrap();
                 class.call0().unwrap().into()
@@ -229,6 +240,10 @@ impl ToPyObject for Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "Binary")).unwrap();
                 class.call0().unwrap().into()
             },
+            DataType::Json => {
+                let class = pl.getattr(intern!(py, "Json")).unwrap();
+                class.call0().unwrap().into()
+            },
             DataType::Array(inner, size) => {
                 let class = pl.getattr(intern!(py, "Array")).unwrap();
                 let inner = Wrap(*inner.clone()).to_object(py);
@@ -340,11 +355,13 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "UInt16" => DataType::UInt16,
                     "UInt32" => DataType::UInt32,
                     "UInt64" => DataType::UInt64,
+                    "Float16" => DataType::Float16,
                     "Float32" => DataType::Float32,
                     "Float64" => DataType::Float64,
                     "Boolean" => DataType::Boolean,
                     "String" => DataType::String,
                     "Binary" => DataType::Binary,
+                    "Json" => DataType::Json,
                     "Categorical" => DataType::Categorical(None, Default::default()),
                     "Enum" => DataType::Enum(None, Default::default()),
                     "Date" => DataType::Date,
@@ -374,11 +391,13 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
             "UInt16" => DataType::UInt16,
             "UInt32" => DataType::UInt32,
             "UInt64" => DataType::UInt64,
+            "Float16" => DataType::Float16,
             "Float32" => DataType::Float32,
             "Float64" => DataType::Float64,
             "Boolean" => DataType::Boolean,
             "String" => DataType::String,
             "Binary" => DataType::Binary,
+            "Json" => DataType::Json,
             "Categorical" => {
                 let ordering = ob.getattr(intern!(py, "ordering")).unwrap();
                 let ordering = ordering.extract::<Wrap<CategoricalOrdering>>()?.0;
@@ -753,6 +772,23 @@ impl<'py> FromPyObject<'py> for Wrap<CsvEncoding> {
     }
 }
 
+#[cfg(feature = "csv")]
+impl<'py> FromPyObject<'py> for Wrap<CsvOnError> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "raise" => CsvOnError::Raise,
+            "skip" => CsvOnError::Skip,
+            "collect" => CsvOnError::Collect,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`on_error` must be one of {{'raise', 'skip', 'collect'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "ipc")]
 impl<'py> FromPyObject<'py> for Wrap<Option<IpcCompression>> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
@@ -936,6 +972,28 @@ impl<'py> FromPyObject<'py> for Wrap<RankMethod> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<Collation> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let collation = Collation::new(&ob.extract::<PyBackedStr>()?).map_err(PyPolarsErr::from)?;
+        Ok(Wrap(collation))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<HistBinStrategy> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "fd" => HistBinStrategy::FreedmanDiaconis,
+            "sturges" => HistBinStrategy::Sturges,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`bin_strategy` must be one of {{'fd', 'sturges'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<Roll> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -952,6 +1010,21 @@ impl<'py> FromPyObject<'py> for Wrap<Roll> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<RoundMode> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "half_away" => RoundMode::HalfAway,
+            "half_even" => RoundMode::HalfEven,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`mode` must be one of {{'half_away', 'half_even'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<TimeUnit> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1035,6 +1108,23 @@ impl<'py> FromPyObject<'py> for Wrap<ClosedInterval> {
     }
 }
 
+#[cfg(feature = "hypothesis_tests")]
+impl<'py> FromPyObject<'py> for Wrap<TTestVariant> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "welch" => TTestVariant::Welch,
+            "student" => TTestVariant::Student,
+            "paired" => TTestVariant::Paired,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`variant` must be one of {{'welch', 'student', 'paired'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<WindowMapping> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1068,6 +1158,43 @@ impl<'py> FromPyObject<'py> for Wrap<JoinValidation> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<JoinNaming> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "raise" => JoinNaming::Error,
+            v if v.contains("{name}") => JoinNaming::template(v).map_err(PyPolarsErr::from)?,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`naming` must be a template containing '{{name}}' or 'raise', got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<ColumnFmt> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let dict = ob.downcast::<PyDict>()?;
+        let mut fmt = ColumnFmt::default();
+        for (key, value) in dict.iter() {
+            let key = key.extract::<PyBackedStr>()?;
+            match &*key {
+                "float_precision" => fmt.float_precision = value.extract()?,
+                "thousands_separator" => fmt.thousands_separator = value.extract()?,
+                "datetime_format" => fmt.datetime_format = value.extract()?,
+                "max_str_len" => fmt.max_str_len = value.extract()?,
+                v => {
+                    return Err(PyValueError::new_err(format!(
+                        "format option must be one of {{'float_precision', 'thousands_separator', 'datetime_format', 'max_str_len'}}, got {v}",
+                    )))
+                },
+            }
+        }
+        Ok(Wrap(fmt))
+    }
+}
+
 #[cfg(feature = "csv")]
 impl<'py> FromPyObject<'py> for Wrap<QuoteStyle> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
@@ -1086,12 +1213,107 @@ impl<'py> FromPyObject<'py> for Wrap<QuoteStyle> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<WriteMode> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "overwrite" => WriteMode::Overwrite,
+            "error_if_exists" => WriteMode::ErrorIfExists,
+            "append" => WriteMode::Append,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                "`write_mode` must be one of {{'overwrite', 'error_if_exists', 'append'}}, got {v}",
+            )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'py> FromPyObject<'py> for Wrap<JsonOrient> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "records" => JsonOrient::Records,
+            "columns" => JsonOrient::Columns,
+            "values" => JsonOrient::Values,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`orient` must be one of {{'records', 'columns', 'values'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'py> FromPyObject<'py> for Wrap<JsonTemporalFormat> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "iso" => JsonTemporalFormat::Iso,
+            "epoch" => JsonTemporalFormat::Epoch,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`temporal_format` must be one of {{'iso', 'epoch'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "cloud")]
 pub(crate) fn parse_cloud_options(uri: &str, kv: Vec<(String, String)>) -> PyResult<CloudOptions> {
     let out = CloudOptions::from_untyped_config(uri, kv).map_err(PyPolarsErr::from)?;
     Ok(out)
 }
 
+/// Wrap a Python callable that returns `(credentials: dict[str, str], expiry: int | None)`.
+///
+/// The credentials hold either `aws_access_key_id`, `aws_secret_access_key` and optionally
+/// `aws_session_token`, or a `bearer_token` for GCP and Azure.
+#[cfg(feature = "cloud")]
+pub(crate) fn python_credential_provider(func: PyObject) -> PlCredentialProvider {
+    PlCredentialProvider::from_func(move || {
+        Python::with_gil(|py| {
+            let out = func.call0(py).map_err(
+                |e| polars_err!(ComputeError: "credential provider raised an exception: {}", e),
+            )?;
+            let (credentials, expiry) = out
+                .extract::<(Bound<'_, PyDict>, Option<u64>)>(py)
+                .map_err(|_| {
+                    polars_err!(ComputeError: "credential provider must return a tuple of (dict, int | None)")
+                })?;
+
+            let get = |key: &str| -> PolarsResult<Option<String>> {
+                credentials
+                    .get_item(key)
+                    .and_then(|value| value.map(|v| v.extract::<String>()).transpose())
+                    .map_err(|e| polars_err!(ComputeError: "invalid credential '{}': {}", key, e))
+            };
+
+            let credential = if let Some(bearer) = get("bearer_token")? {
+                ObjectStoreCredential::Bearer(bearer)
+            } else if let (Some(key_id), Some(secret_key)) =
+                (get("aws_access_key_id")?, get("aws_secret_access_key")?)
+            {
+                ObjectStoreCredential::Aws {
+                    key_id,
+                    secret_key,
+                    session_token: get("aws_session_token")?,
+                }
+            } else {
+                polars_bail!(
+                    ComputeError: "credential provider must return either 'bearer_token' or \
+                    'aws_access_key_id' and 'aws_secret_access_key'"
+                )
+            };
+
+            Ok(FetchedCredential { credential, expiry })
+        })
+    })
+}
+
 #[cfg(feature = "list_sets")]
 impl<'py> FromPyObject<'py> for Wrap<SetOperation> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
@@ -1131,6 +1353,45 @@ pub(crate) fn parse_fill_null_strategy(
     Ok(parsed)
 }
 
+#[cfg(any(feature = "csv", feature = "json"))]
+pub(crate) fn parse_output_compression(
+    path: &Path,
+    compression: &str,
+    compression_level: Option<i32>,
+) -> PyResult<Option<OutputCompression>> {
+    let gzip_level = || {
+        compression_level
+            .map(|lvl| {
+                u32::try_from(lvl).map_err(|_| {
+                    PyValueError::new_err(format!(
+                        "gzip compression level must be in 0..=9, got {lvl}"
+                    ))
+                })
+            })
+            .transpose()
+    };
+    let parsed = match compression {
+        "infer" => match OutputCompression::from_path(path) {
+            Some(OutputCompression::Gzip(_)) => Some(OutputCompression::Gzip(gzip_level()?)),
+            Some(OutputCompression::BlockGzip(_)) => {
+                Some(OutputCompression::BlockGzip(gzip_level()?))
+            },
+            Some(OutputCompression::Zstd(_)) => Some(OutputCompression::Zstd(compression_level)),
+            None => None,
+        },
+        "uncompressed" => None,
+        "gzip" => Some(OutputCompression::Gzip(gzip_level()?)),
+        "bgzip" => Some(OutputCompression::BlockGzip(gzip_level()?)),
+        "zstd" => Some(OutputCompression::Zstd(compression_level)),
+        e => {
+            return Err(PyValueError::new_err(format!(
+                "`compression` must be one of {{'infer', 'uncompressed', 'gzip', 'bgzip', 'zstd'}}, got {e}",
+            )))
+        },
+    };
+    Ok(parsed)
+}
+
 #[cfg(feature = "parquet")]
 pub(crate) fn parse_parquet_compression(
     compression: &str,
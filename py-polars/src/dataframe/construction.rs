@@ -52,6 +52,15 @@ impl PyDataFrame {
         let df = interop::arrow::to_rust::to_rust_df(&rb)?;
         Ok(Self::from(df))
     }
+
+    /// Import an object that implements the Arrow PyCapsule interface, reading its record
+    /// batches without pyarrow.
+    #[staticmethod]
+    pub fn from_arrow_c_interface(ob: &Bound<PyAny>) -> PyResult<Self> {
+        let (field, batches) = interop::arrow::capsule::import(ob)?;
+        let df = interop::arrow::capsule::to_rust_df(&field, batches)?;
+        Ok(Self::from(df))
+    }
 }
 
 fn finish_from_rows(
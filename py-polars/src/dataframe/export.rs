@@ -7,6 +7,7 @@ use pyo3::types::{PyList, PyTuple};
 use super::*;
 use crate::conversion::{ObjectValue, Wrap};
 use crate::interop;
+use crate::interop::arrow::stream::PyArrowStream;
 
 #[pymethods]
 impl PyDataFrame {
@@ -78,6 +79,10 @@ impl PyDataFrame {
         })
     }
 
+    pub fn to_arrow_stream(&self, future: bool) -> PyArrowStream {
+        self.df.clone().into_arrow_stream(future).into()
+    }
+
     /// Create a `Vec` of PyArrow RecordBatch instances.
     ///
     /// Note this will give bad results for columns with dtype `pl.Object`,
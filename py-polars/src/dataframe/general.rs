@@ -1,5 +1,6 @@
 use either::Either;
 use polars::prelude::*;
+use polars_core::fmt::{ColumnFmt, FmtConfig};
 use polars_core::frame::*;
 #[cfg(feature = "pivot")]
 use polars_lazy::frame::pivot::{pivot, pivot_stable};
@@ -117,10 +118,21 @@ impl PyDataFrame {
         Ok(df.into())
     }
 
-    pub fn rechunk(&self, py: Python) -> Self {
+    #[pyo3(signature = (rows_per_chunk=None))]
+    pub fn rechunk(&self, py: Python, rows_per_chunk: Option<usize>) -> PyResult<Self> {
         let mut df = self.df.clone();
-        py.allow_threads(|| df.as_single_chunk_par());
-        df.into()
+        match rows_per_chunk {
+            Some(n) => {
+                let df = py
+                    .allow_threads(|| df.rechunk_by_rows(n))
+                    .map_err(PyPolarsErr::from)?;
+                Ok(df.into())
+            },
+            None => {
+                py.allow_threads(|| df.as_single_chunk_par());
+                Ok(df.into())
+            },
+        }
     }
 
     /// Format `DataFrame` as String
@@ -128,6 +140,16 @@ impl PyDataFrame {
         format!("{:?}", self.df)
     }
 
+    /// Format `DataFrame` as String with per-column display options
+    pub fn as_str_with_config(&self, formats: Vec<(String, Wrap<ColumnFmt>)>) -> String {
+        let config = formats
+            .into_iter()
+            .fold(FmtConfig::new(), |config, (name, fmt)| {
+                config.with_column(name, fmt.0)
+            });
+        self.df.to_string_with_config(&config)
+    }
+
     pub fn get_columns(&self) -> Vec<PySeries> {
         let cols = self.df.get_columns().to_vec();
         cols.to_pyseries()
@@ -299,11 +321,27 @@ impl PyDataFrame {
         Ok(mask.into_series().into())
     }
 
-    pub fn is_duplicated(&self) -> PyResult<PySeries> {
-        let mask = self.df.is_duplicated().map_err(PyPolarsErr::from)?;
+    pub fn is_duplicated(
+        &self,
+        subset: Option<Vec<String>>,
+        keep: Wrap<UniqueKeepStrategy>,
+    ) -> PyResult<PySeries> {
+        let mask = match (&subset, keep.0) {
+            (None, UniqueKeepStrategy::None) => self.df.is_duplicated(),
+            (subset, keep) => self.df.is_duplicated_by(subset.as_deref(), keep),
+        }
+        .map_err(PyPolarsErr::from)?;
         Ok(mask.into_series().into())
     }
 
+    pub fn duplicates(&self, subset: Option<Vec<String>>) -> PyResult<Self> {
+        let df = self
+            .df
+            .duplicates(subset.as_deref())
+            .map_err(PyPolarsErr::from)?;
+        Ok(df.into())
+    }
+
     pub fn equals(&self, other: &PyDataFrame, null_equal: bool) -> bool {
         if null_equal {
             self.df.equals_missing(&other.df)
@@ -312,11 +350,18 @@ impl PyDataFrame {
         }
     }
 
-    pub fn with_row_index(&self, name: &str, offset: Option<IdxSize>) -> PyResult<Self> {
-        let df = self
-            .df
-            .with_row_index(name, offset)
-            .map_err(PyPolarsErr::from)?;
+    #[pyo3(signature = (name, offset, over=None))]
+    pub fn with_row_index(
+        &self,
+        name: &str,
+        offset: Option<IdxSize>,
+        over: Option<Vec<String>>,
+    ) -> PyResult<Self> {
+        let df = match over {
+            Some(over) if !over.is_empty() => self.df.with_row_index_over(name, over, offset),
+            _ => self.df.with_row_index(name, offset),
+        }
+        .map_err(PyPolarsErr::from)?;
         Ok(df.into())
     }
 
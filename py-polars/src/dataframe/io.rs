@@ -1,9 +1,11 @@
-use std::io::BufWriter;
+use std::io::{BufWriter, Write};
 use std::num::NonZeroUsize;
+use std::path::Path;
 
 #[cfg(feature = "avro")]
 use polars::io::avro::AvroCompression;
-use polars::io::mmap::try_create_file;
+use polars::io::mmap::ensure_not_memory_mapped;
+use polars::io::write_mode::AtomicFileWrite;
 use polars::io::RowIndex;
 #[cfg(feature = "parquet")]
 use polars_parquet::arrow::write::StatisticsOptions;
@@ -28,7 +30,8 @@ impl PyDataFrame {
     skip_rows, projection, separator, rechunk, columns, encoding, n_threads, path,
     overwrite_dtype, overwrite_dtype_slice, low_memory, comment_prefix, quote_char,
     null_values, missing_utf8_is_empty_string, try_parse_dates, skip_rows_after_header,
-    row_index, sample_size, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, schema)
+    row_index, sample_size, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, schema,
+    on_error)
 )]
     pub fn read_csv(
         py: Python,
@@ -62,7 +65,8 @@ impl PyDataFrame {
         truncate_ragged_lines: bool,
         decimal_comma: bool,
         schema: Option<Wrap<Schema>>,
-    ) -> PyResult<Self> {
+        on_error: Wrap<CsvOnError>,
+    ) -> PyResult<(Self, Self)> {
         let null_values = null_values.map(|w| w.0);
         let eol_char = eol_char.as_bytes()[0];
         let row_index = row_index.map(|(name, offset)| RowIndex {
@@ -90,7 +94,7 @@ impl PyDataFrame {
 
         py_f = read_if_bytesio(py_f);
         let mmap_bytes_r = get_mmap_bytes_reader(&py_f)?;
-        let df = py.allow_threads(move || {
+        let (df, rejected) = py.allow_threads(move || {
             CsvReadOptions::default()
                 .with_path(path)
                 .with_infer_schema_length(infer_schema_length)
@@ -98,6 +102,7 @@ impl PyDataFrame {
                 .with_n_rows(n_rows)
                 .with_skip_rows(skip_rows)
                 .with_ignore_errors(ignore_errors)
+                .with_on_error(on_error.0)
                 .with_projection(projection.map(Arc::new))
                 .with_rechunk(rechunk)
                 .with_chunk_size(chunk_size)
@@ -113,7 +118,7 @@ impl PyDataFrame {
                 .with_raise_if_empty(raise_if_empty)
                 .with_parse_options(
                     CsvParseOptions::default()
-                        .with_separator(separator.as_bytes()[0])
+                        .with_separator_str(separator)
                         .with_encoding(encoding.0)
                         .with_missing_is_null(!missing_utf8_is_empty_string)
                         .with_comment_prefix(comment_prefix)
@@ -125,10 +130,10 @@ impl PyDataFrame {
                         .with_decimal_comma(decimal_comma),
                 )
                 .into_reader_with_file_handle(mmap_bytes_r)
-                .finish()
+                .finish_with_rejected()
                 .map_err(PyPolarsErr::from)
         })?;
-        Ok(df.into())
+        Ok((df.into(), rejected.into()))
     }
 
     #[staticmethod]
@@ -350,18 +355,23 @@ impl PyDataFrame {
         time_format: Option<String>,
         float_scientific: Option<bool>,
         float_precision: Option<usize>,
+        float_scientific_threshold: Option<u32>,
         null_value: Option<String>,
         quote_style: Option<Wrap<QuoteStyle>>,
+        write_mode: Wrap<WriteMode>,
     ) -> PyResult<()> {
         let null = null_value.unwrap_or_default();
 
         if let Ok(s) = py_f.extract::<PyBackedStr>(py) {
-            let f = std::fs::File::create(&*s)?;
+            let (atomic_write, f) =
+                AtomicFileWrite::create(Path::new(&*s), write_mode.0).map_err(PyPolarsErr::from)?;
+            // An existing file that is appended to already starts with the header.
+            let appending = atomic_write.is_appending();
             py.allow_threads(|| {
                 // No need for a buffered writer, because the csv writer does internal buffering.
                 CsvWriter::new(f)
-                    .include_bom(include_bom)
-                    .include_header(include_header)
+                    .include_bom(include_bom && !appending)
+                    .include_header(include_header && !appending)
                     .with_separator(separator)
                     .with_line_terminator(line_terminator)
                     .with_quote_char(quote_char)
@@ -371,11 +381,13 @@ impl PyDataFrame {
                     .with_time_format(time_format)
                     .with_float_scientific(float_scientific)
                     .with_float_precision(float_precision)
+                    .with_float_scientific_threshold(float_scientific_threshold)
                     .with_null_value(null)
                     .with_quote_style(quote_style.map(|wrap| wrap.0).unwrap_or_default())
-                    .finish(&mut self.df)
-                    .map_err(PyPolarsErr::from)
-            })?;
+                    .finish(&mut self.df)?;
+                atomic_write.commit()
+            })
+            .map_err(PyPolarsErr::from)?;
         } else {
             let mut buf = get_file_like(py_f, true)?;
             CsvWriter::new(&mut buf)
@@ -390,6 +402,7 @@ impl PyDataFrame {
                 .with_time_format(time_format)
                 .with_float_scientific(float_scientific)
                 .with_float_precision(float_precision)
+                .with_float_scientific_threshold(float_scientific_threshold)
                 .with_null_value(null)
                 .with_quote_style(quote_style.map(|wrap| wrap.0).unwrap_or_default())
                 .finish(&mut self.df)
@@ -400,7 +413,7 @@ impl PyDataFrame {
     }
 
     #[cfg(feature = "parquet")]
-    #[pyo3(signature = (py_f, compression, compression_level, statistics, row_group_size, data_page_size))]
+    #[pyo3(signature = (py_f, compression, compression_level, statistics, row_group_size, data_page_size, write_mode))]
     pub fn write_parquet(
         &mut self,
         py: Python,
@@ -410,20 +423,27 @@ impl PyDataFrame {
         statistics: Wrap<StatisticsOptions>,
         row_group_size: Option<usize>,
         data_page_size: Option<usize>,
+        write_mode: Wrap<WriteMode>,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
 
         if let Ok(s) = py_f.extract::<PyBackedStr>(py) {
-            let f = std::fs::File::create(&*s)?;
+            write_mode
+                .0
+                .check_append_supported("parquet")
+                .map_err(PyPolarsErr::from)?;
+            let (atomic_write, f) =
+                AtomicFileWrite::create(Path::new(&*s), write_mode.0).map_err(PyPolarsErr::from)?;
             py.allow_threads(|| {
                 ParquetWriter::new(f)
                     .with_compression(compression)
                     .with_statistics(statistics.0)
                     .with_row_group_size(row_group_size)
                     .with_data_page_size(data_page_size)
-                    .finish(&mut self.df)
-                    .map_err(PyPolarsErr::from)
-            })?;
+                    .finish(&mut self.df)?;
+                atomic_write.commit()
+            })
+            .map_err(PyPolarsErr::from)?;
         } else {
             let buf = get_file_like(py_f, true)?;
             ParquetWriter::new(buf)
@@ -439,18 +459,44 @@ impl PyDataFrame {
     }
 
     #[cfg(feature = "json")]
-    pub fn write_json(&mut self, py_f: PyObject) -> PyResult<()> {
+    #[pyo3(signature = (py_f, orient, temporal_format))]
+    pub fn write_json(
+        &mut self,
+        py_f: PyObject,
+        orient: Wrap<JsonOrient>,
+        temporal_format: Wrap<JsonTemporalFormat>,
+    ) -> PyResult<()> {
         let file = BufWriter::new(get_file_like(py_f, true)?);
 
         JsonWriter::new(file)
             .with_json_format(JsonFormat::Json)
+            .with_orient(orient.0)
+            .with_temporal_format(temporal_format.0)
             .finish(&mut self.df)
             .map_err(PyPolarsErr::from)?;
         Ok(())
     }
 
     #[cfg(feature = "json")]
-    pub fn write_ndjson(&mut self, py_f: PyObject) -> PyResult<()> {
+    pub fn write_ndjson(
+        &mut self,
+        py: Python,
+        py_f: PyObject,
+        write_mode: Wrap<WriteMode>,
+    ) -> PyResult<()> {
+        if let Ok(s) = py_f.extract::<PyBackedStr>(py) {
+            let (atomic_write, f) =
+                AtomicFileWrite::create(Path::new(&*s), write_mode.0).map_err(PyPolarsErr::from)?;
+            let mut file = BufWriter::new(f);
+            JsonWriter::new(&mut file)
+                .with_json_format(JsonFormat::JsonLines)
+                .finish(&mut self.df)
+                .map_err(PyPolarsErr::from)?;
+            file.flush()?;
+            atomic_write.commit().map_err(PyPolarsErr::from)?;
+            return Ok(());
+        }
+
         let file = BufWriter::new(get_file_like(py_f, true)?);
 
         JsonWriter::new(file)
@@ -468,23 +514,39 @@ impl PyDataFrame {
         py_f: PyObject,
         compression: Wrap<Option<IpcCompression>>,
         future: bool,
+        write_mode: Wrap<WriteMode>,
+        uncompressed_columns: Vec<String>,
+        statistics: bool,
     ) -> PyResult<()> {
         if let Ok(s) = py_f.extract::<PyBackedStr>(py) {
-            let s: &str = s.as_ref();
-            let path = std::path::Path::new(s);
-            let f = try_create_file(path).map_err(PyPolarsErr::from)?;
+            let path = Path::new(&*s);
+            ensure_not_memory_mapped(path).map_err(PyPolarsErr::from)?;
+            let (atomic_write, f) =
+                AtomicFileWrite::create(path, write_mode.0).map_err(PyPolarsErr::from)?;
             py.allow_threads(|| {
-                IpcWriter::new(f)
+                let mut writer = IpcWriter::new(f)
                     .with_compression(compression.0)
-                    .with_pl_flavor(future)
-                    .finish(&mut self.df)
-                    .map_err(PyPolarsErr::from)
-            })?;
+                    .with_uncompressed_columns(uncompressed_columns)
+                    .with_statistics(statistics)
+                    .with_pl_flavor(future);
+                if atomic_write.is_appending() {
+                    let mut writer = writer.batched_append(&self.df.schema())?;
+                    self.df.align_chunks();
+                    writer.write_batch(&self.df)?;
+                    writer.finish()?;
+                } else {
+                    writer.finish(&mut self.df)?;
+                }
+                atomic_write.commit()
+            })
+            .map_err(PyPolarsErr::from)?;
         } else {
             let mut buf = get_file_like(py_f, true)?;
 
             IpcWriter::new(&mut buf)
                 .with_compression(compression.0)
+                .with_uncompressed_columns(uncompressed_columns)
+                .with_statistics(statistics)
                 .with_pl_flavor(future)
                 .finish(&mut self.df)
                 .map_err(PyPolarsErr::from)?;
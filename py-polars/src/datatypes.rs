@@ -34,6 +34,8 @@ pub(crate) enum PyDataType {
     Decimal(Option<usize>, usize),
     Array(usize),
     Enum(Utf8ViewArray),
+    Float16,
+    Json,
 }
 
 impl From<&DataType> for PyDataType {
@@ -48,12 +50,14 @@ impl From<&DataType> for PyDataType {
             DataType::UInt16 => UInt16,
             DataType::UInt32 => UInt32,
             DataType::UInt64 => UInt64,
+            DataType::Float16 => Float16,
             DataType::Float32 => Float32,
             DataType::Float64 => Float64,
             DataType::Decimal(p, s) => Decimal(*p, s.expect("unexpected null decimal scale")),
             DataType::Boolean => Bool,
             DataType::String => String,
             DataType::Binary => Binary,
+            DataType::Json => Json,
             DataType::Array(_, width) => Array(*width),
             DataType::List(_) => List,
             DataType::Date => Date,
@@ -107,6 +111,8 @@ impl From<PyDataType> for DataType {
             PyDataType::Struct => Struct(vec![]),
             PyDataType::Decimal(p, s) => Decimal(p, Some(s)),
             PyDataType::Array(width) => Array(DataType::Null.into(), width),
+            PyDataType::Float16 => Float16,
+            PyDataType::Json => Json,
         }
     }
 }
@@ -5,6 +5,7 @@ use pyo3::exceptions::{PyException, PyWarning};
 
 // Errors
 create_exception!(polars.exceptions, PolarsError, PyException);
+create_exception!(polars.exceptions, CancelledError, PolarsError);
 create_exception!(polars.exceptions, ColumnNotFoundError, PolarsError);
 create_exception!(polars.exceptions, ComputeError, PolarsError);
 create_exception!(polars.exceptions, DuplicateError, PolarsError);
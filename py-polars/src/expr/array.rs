@@ -126,4 +126,40 @@ impl PyExpr {
     fn arr_shift(&self, n: PyExpr) -> Self {
         self.inner.clone().arr().shift(n.inner).into()
     }
+
+    fn arr_explode_to_rows(&self) -> Self {
+        self.inner.clone().arr().explode_to_rows().into()
+    }
+
+    fn arr_reshape(&self, dimensions: Vec<i64>) -> Self {
+        self.inner.clone().arr().reshape(&dimensions).into()
+    }
+
+    fn arr_sum_elementwise(&self) -> Self {
+        self.inner.clone().arr().sum_elementwise().into()
+    }
+
+    fn arr_mean_elementwise(&self) -> Self {
+        self.inner.clone().arr().mean_elementwise().into()
+    }
+
+    fn arr_dot(&self, other: PyExpr) -> Self {
+        self.inner.clone().arr().dot(other.inner).into()
+    }
+
+    fn arr_l2_norm(&self) -> Self {
+        self.inner.clone().arr().l2_norm().into()
+    }
+
+    fn arr_cosine_similarity(&self, other: PyExpr) -> Self {
+        self.inner
+            .clone()
+            .arr()
+            .cosine_similarity(other.inner)
+            .into()
+    }
+
+    fn arr_matmul(&self, other: PyExpr) -> Self {
+        self.inner.clone().arr().matmul(other.inner).into()
+    }
 }
@@ -69,8 +69,14 @@ impl PyExpr {
             .into()
     }
 
-    fn dt_truncate(&self, every: Self) -> Self {
-        self.inner.clone().dt().truncate(every.inner).into()
+    #[pyo3(signature = (every, offset=None))]
+    fn dt_truncate(&self, every: Self, offset: Option<Self>) -> Self {
+        let dt = self.inner.clone().dt();
+        match offset {
+            Some(offset) => dt.truncate_with_offset(every.inner, offset.inner),
+            None => dt.truncate(every.inner),
+        }
+        .into()
     }
 
     fn dt_month_start(&self) -> Self {
@@ -90,8 +96,14 @@ impl PyExpr {
         self.inner.clone().dt().dst_offset().into()
     }
 
-    fn dt_round(&self, every: Self) -> Self {
-        self.inner.clone().dt().round(every.inner).into()
+    #[pyo3(signature = (every, offset=None))]
+    fn dt_round(&self, every: Self, offset: Option<Self>) -> Self {
+        let dt = self.inner.clone().dt();
+        match offset {
+            Some(offset) => dt.round_with_offset(every.inner, offset.inner),
+            None => dt.round(every.inner),
+        }
+        .into()
     }
 
     fn dt_combine(&self, time: Self, time_unit: Wrap<TimeUnit>) -> Self {
@@ -146,6 +146,12 @@ impl PyExpr {
     fn last(&self) -> Self {
         self.inner.clone().last().into()
     }
+    fn first_by(&self, by: Self) -> Self {
+        self.inner.clone().first_by(by.inner).into()
+    }
+    fn last_by(&self, by: Self) -> Self {
+        self.inner.clone().last_by(by.inner).into()
+    }
     fn implode(&self) -> Self {
         self.inner.clone().implode().into()
     }
@@ -156,6 +162,20 @@ impl PyExpr {
             .into()
     }
 
+    fn quantiles(&self, quantiles: Vec<f64>, interpolation: Wrap<QuantileInterpolOptions>) -> Self {
+        self.inner
+            .clone()
+            .quantiles(&quantiles, interpolation.0)
+            .into()
+    }
+
+    fn approx_quantile(&self, quantile: Self, accuracy: u32) -> Self {
+        self.inner
+            .clone()
+            .approx_quantile(quantile.inner, accuracy)
+            .into()
+    }
+
     #[pyo3(signature = (breaks, labels, left_closed, include_breaks))]
     #[cfg(feature = "cutqcut")]
     fn cut(
@@ -251,7 +271,12 @@ impl PyExpr {
         let expr = self.inner.clone().cast_with_options(dt, options);
         expr.into()
     }
-    fn sort_with(&self, descending: bool, nulls_last: bool) -> Self {
+    fn sort_with(
+        &self,
+        descending: bool,
+        nulls_last: bool,
+        collation: Option<Wrap<Collation>>,
+    ) -> Self {
         self.inner
             .clone()
             .sort(SortOptions {
@@ -259,11 +284,13 @@ impl PyExpr {
                 nulls_last,
                 multithreaded: true,
                 maintain_order: false,
+                stable: true,
+                collation: collation.map(|c| c.0),
             })
             .into()
     }
 
-    fn arg_sort(&self, descending: bool, nulls_last: bool) -> Self {
+    fn arg_sort(&self, descending: bool, nulls_last: bool, stable: bool) -> Self {
         self.inner
             .clone()
             .arg_sort(SortOptions {
@@ -271,6 +298,8 @@ impl PyExpr {
                 nulls_last,
                 multithreaded: true,
                 maintain_order: false,
+                stable,
+                collation: None,
             })
             .into()
     }
@@ -337,6 +366,8 @@ impl PyExpr {
         nulls_last: Vec<bool>,
         multithreaded: bool,
         maintain_order: bool,
+        stable: bool,
+        collation: Option<Wrap<Collation>>,
     ) -> Self {
         let by = by.into_iter().map(|e| e.inner).collect::<Vec<_>>();
         self.inner
@@ -348,6 +379,8 @@ impl PyExpr {
                     nulls_last,
                     multithreaded,
                     maintain_order,
+                    stable,
+                    collation: collation.map(|c| c.0),
                 },
             )
             .into()
@@ -379,6 +412,21 @@ impl PyExpr {
         Ok(self.inner.clone().fill_null_with_strategy(strategy).into())
     }
 
+    fn fill_null_by(
+        &self,
+        by: Self,
+        max_gap: &str,
+        strategy: &str,
+        limit: FillNullLimit,
+    ) -> PyResult<Self> {
+        let strategy = parse_fill_null_strategy(strategy, limit)?;
+        Ok(self
+            .inner
+            .clone()
+            .fill_null_by(by.inner, strategy, Duration::parse(max_gap))
+            .into())
+    }
+
     fn fill_nan(&self, expr: Self) -> Self {
         self.inner.clone().fill_nan(expr.inner).into()
     }
@@ -422,6 +470,29 @@ impl PyExpr {
         self.inner.clone().approx_n_unique().into()
     }
 
+    fn approx_n_unique_sketch(&self) -> Self {
+        self.inner.clone().approx_n_unique_sketch().into()
+    }
+
+    fn approx_quantile_sketch(&self, accuracy: u32) -> Self {
+        self.inner.clone().approx_quantile_sketch(accuracy).into()
+    }
+
+    fn merge_sketches(&self) -> Self {
+        self.inner.clone().merge_sketches().into()
+    }
+
+    fn approx_n_unique_from_sketch(&self) -> Self {
+        self.inner.clone().approx_n_unique_from_sketch().into()
+    }
+
+    fn approx_quantile_from_sketch(&self, quantile: f64) -> Self {
+        self.inner
+            .clone()
+            .approx_quantile_from_sketch(quantile)
+            .into()
+    }
+
     fn is_first_distinct(&self) -> Self {
         self.inner.clone().is_first_distinct().into()
     }
@@ -461,8 +532,12 @@ impl PyExpr {
             .into()
     }
 
-    fn round(&self, decimals: u32) -> Self {
-        self.inner.clone().round(decimals).into()
+    fn round(&self, decimals: u32, mode: Wrap<RoundMode>) -> Self {
+        self.inner.clone().round(decimals, mode.0).into()
+    }
+
+    fn rescale(&self, scale: usize, mode: Wrap<RoundMode>) -> Self {
+        self.inner.clone().rescale(scale, mode.0).into()
     }
 
     fn round_sig_figs(&self, digits: i32) -> Self {
@@ -477,6 +552,10 @@ impl PyExpr {
         self.inner.clone().ceil().into()
     }
 
+    fn ceil_div(&self, other: Self) -> Self {
+        self.inner.clone().ceil_div(other.inner).into()
+    }
+
     fn clip(&self, min: Option<Self>, max: Option<Self>) -> Self {
         let expr = self.inner.clone();
         let out = match (min, max) {
@@ -577,8 +656,8 @@ impl PyExpr {
         self.inner.clone().sign().into()
     }
 
-    fn is_duplicated(&self) -> Self {
-        self.inner.clone().is_duplicated().into()
+    fn is_duplicated(&self, keep: Wrap<UniqueKeepStrategy>) -> Self {
+        self.inner.clone().is_duplicated_keep(keep.0).into()
     }
 
     #[pyo3(signature = (partition_by, order_by, order_by_descending, order_by_nulls_last, mapping_strategy))]
@@ -613,6 +692,39 @@ impl PyExpr {
             .into()
     }
 
+    #[pyo3(signature = (partition_by, order_by, rows_start, rows_end))]
+    fn over_rows_between(
+        &self,
+        partition_by: Vec<Self>,
+        order_by: Option<Vec<Self>>,
+        rows_start: Option<i64>,
+        rows_end: Option<i64>,
+    ) -> Self {
+        self.over_frame(
+            partition_by,
+            order_by,
+            WindowFrame::rows_between(rows_start, rows_end),
+        )
+    }
+
+    #[pyo3(signature = (partition_by, order_by, range_start, range_end))]
+    fn over_range_between(
+        &self,
+        partition_by: Vec<Self>,
+        order_by: Option<Vec<Self>>,
+        range_start: Option<&str>,
+        range_end: Option<&str>,
+    ) -> Self {
+        self.over_frame(
+            partition_by,
+            order_by,
+            WindowFrame::range_between(
+                range_start.map(Duration::parse),
+                range_end.map(Duration::parse),
+            ),
+        )
+    }
+
     fn rolling(
         &self,
         index_column: &str,
@@ -746,10 +858,17 @@ impl PyExpr {
         self.inner.clone().upper_bound().into()
     }
 
-    fn rank(&self, method: Wrap<RankMethod>, descending: bool, seed: Option<u64>) -> Self {
+    fn rank(
+        &self,
+        method: Wrap<RankMethod>,
+        descending: bool,
+        seed: Option<u64>,
+        collation: Option<Wrap<Collation>>,
+    ) -> Self {
         let options = RankOptions {
             method: method.0,
             descending,
+            collation: collation.map(|c| c.0),
         };
         self.inner.clone().rank(options, seed).into()
     }
@@ -778,6 +897,22 @@ impl PyExpr {
         self.inner.clone().to_physical().into()
     }
 
+    fn is_valid_mask(&self) -> Self {
+        self.inner.clone().is_valid_mask().into()
+    }
+
+    fn with_validity(&self, mask: Self) -> Self {
+        self.inner.clone().with_validity(mask.inner).into()
+    }
+
+    fn coalesce_validity(&self, other: Self) -> Self {
+        self.inner.clone().coalesce_validity(other.inner).into()
+    }
+
+    fn or_else(&self, fallback: Self) -> Self {
+        self.inner.clone().or_else(fallback.inner).into()
+    }
+
     #[pyo3(signature = (seed))]
     fn shuffle(&self, seed: Option<u64>) -> Self {
         self.inner.clone().shuffle(seed).into()
@@ -897,6 +1032,18 @@ impl PyExpr {
         };
         self.inner.clone().set_sorted_flag(is_sorted).into()
     }
+    fn check_sorted(&self, descending: bool) -> Self {
+        self.inner.clone().check_sorted(descending).into()
+    }
+    fn parallel(&self, parallel: bool) -> Self {
+        self.inner.clone().parallel(parallel).into()
+    }
+    fn is_monotonic_increasing(&self, strict: bool) -> Self {
+        self.inner.clone().is_monotonic_increasing(strict).into()
+    }
+    fn is_monotonic_decreasing(&self, strict: bool) -> Self {
+        self.inner.clone().is_monotonic_decreasing(strict).into()
+    }
 
     fn replace(&self, old: PyExpr, new: PyExpr) -> Self {
         self.inner.clone().replace(old.inner, new.inner).into()
@@ -926,13 +1073,47 @@ impl PyExpr {
         &self,
         bins: Option<PyExpr>,
         bin_count: Option<usize>,
+        bin_strategy: Option<Wrap<HistBinStrategy>>,
+        shared_bins: bool,
         include_category: bool,
         include_breakpoint: bool,
     ) -> Self {
         let bins = bins.map(|e| e.inner);
         self.inner
             .clone()
-            .hist(bins, bin_count, include_category, include_breakpoint)
+            .hist(
+                bins,
+                bin_count,
+                bin_strategy.map(|s| s.0),
+                shared_bins,
+                include_category,
+                include_breakpoint,
+            )
+            .into()
+    }
+}
+
+impl PyExpr {
+    fn over_frame(
+        &self,
+        partition_by: Vec<Self>,
+        order_by: Option<Vec<Self>>,
+        frame: WindowFrame,
+    ) -> Self {
+        let partition_by = partition_by
+            .into_iter()
+            .map(|e| e.inner)
+            .collect::<Vec<Expr>>();
+        let order_by = order_by.map(|order_by| {
+            (
+                order_by.into_iter().map(|e| e.inner).collect::<Vec<Expr>>(),
+                SortOptions::default(),
+            )
+        });
+
+        self.inner
+            .clone()
+            .over_frame(partition_by, order_by, frame)
             .into()
     }
 }
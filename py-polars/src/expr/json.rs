@@ -0,0 +1,14 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn json_path(&self, path: &str) -> Self {
+        self.inner.clone().json().path(path).into()
+    }
+
+    fn json_get_type(&self) -> Self {
+        self.inner.clone().json().get_type().into()
+    }
+}
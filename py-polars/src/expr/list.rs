@@ -42,6 +42,10 @@ impl PyExpr {
         Ok(self.inner.clone().list().diff(n, null_behavior.0).into())
     }
 
+    fn list_agg(&self, expr: PyExpr) -> Self {
+        self.inner.clone().list().agg(expr.inner).into()
+    }
+
     fn list_eval(&self, expr: PyExpr, parallel: bool) -> Self {
         self.inner.clone().list().eval(expr.inner, parallel).into()
     }
@@ -203,6 +207,14 @@ impl PyExpr {
         self.inner.clone().list().to_array(width).into()
     }
 
+    fn list_sum_elementwise(&self) -> Self {
+        self.inner.clone().list().sum_elementwise().into()
+    }
+
+    fn list_mean_elementwise(&self) -> Self {
+        self.inner.clone().list().mean_elementwise().into()
+    }
+
     #[pyo3(signature = (width_strat, name_gen, upper_bound))]
     fn list_to_struct(
         &self,
@@ -3,14 +3,20 @@ mod binary;
 mod categorical;
 mod datetime;
 mod general;
+mod json;
 mod list;
 #[cfg(feature = "meta")]
 mod meta;
 mod name;
 mod rolling;
+mod scale;
 mod serde;
+#[cfg(feature = "hypothesis_tests")]
+mod stats;
 mod string;
 mod r#struct;
+#[cfg(feature = "downsample")]
+mod ts;
 
 use polars::lazy::dsl::Expr;
 use pyo3::prelude::*;
@@ -0,0 +1,42 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn scale_standard(&self, ddof: u8) -> Self {
+        self.inner.clone().scale().standard(ddof).into()
+    }
+
+    fn scale_robust(&self, q_low: f64, q_high: f64) -> Self {
+        self.inner.clone().scale().robust(q_low, q_high).into()
+    }
+
+    fn scale_min_max(&self, range: (f64, f64)) -> Self {
+        self.inner.clone().scale().min_max(range).into()
+    }
+
+    fn scale_standard_params(&self, ddof: u8) -> Self {
+        self.inner.clone().scale().standard_params(ddof).into()
+    }
+
+    fn scale_robust_params(&self, q_low: f64, q_high: f64) -> Self {
+        self.inner
+            .clone()
+            .scale()
+            .robust_params(q_low, q_high)
+            .into()
+    }
+
+    fn scale_min_max_params(&self, range: (f64, f64)) -> Self {
+        self.inner.clone().scale().min_max_params(range).into()
+    }
+
+    fn scale_apply(&self, params: Self) -> Self {
+        self.inner.clone().scale().apply(params.inner).into()
+    }
+
+    fn scale_inverse(&self, params: Self) -> Self {
+        self.inner.clone().scale().inverse(params.inner).into()
+    }
+}
@@ -0,0 +1,24 @@
+use polars::prelude::TTestVariant;
+use pyo3::prelude::*;
+
+use crate::conversion::Wrap;
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn stats_ttest(&self, other: Self, variant: Wrap<TTestVariant>) -> Self {
+        self.inner
+            .clone()
+            .stats()
+            .ttest(other.inner, variant.0)
+            .into()
+    }
+
+    fn stats_ks_test(&self, other: Self) -> Self {
+        self.inner.clone().stats().ks_test(other.inner).into()
+    }
+
+    fn stats_chi2(&self, expected: Self) -> Self {
+        self.inner.clone().stats().chi2(expected.inner).into()
+    }
+}
@@ -15,6 +15,25 @@ impl PyExpr {
             .into()
     }
 
+    fn str_join_agg(
+        &self,
+        separator: String,
+        distinct: bool,
+        sort: bool,
+        descending: bool,
+    ) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .join_agg(StrJoinAggOptions {
+                separator,
+                distinct,
+                sort,
+                descending,
+            })
+            .into()
+    }
+
     #[pyo3(signature = (format, strict, exact, cache))]
     fn str_to_date(&self, format: Option<String>, strict: bool, exact: bool, cache: bool) -> Self {
         let options = StrptimeOptions {
@@ -153,6 +172,50 @@ impl PyExpr {
         self.inner.clone().str().reverse().into()
     }
 
+    fn str_levenshtein(&self, other: Self, normalized: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .levenshtein(other.inner, normalized)
+            .into()
+    }
+
+    fn str_damerau_levenshtein(&self, other: Self, normalized: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .damerau_levenshtein(other.inner, normalized)
+            .into()
+    }
+
+    fn str_hamming(&self, other: Self, normalized: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .hamming(other.inner, normalized)
+            .into()
+    }
+
+    fn str_jaro_winkler(&self, other: Self) -> Self {
+        self.inner.clone().str().jaro_winkler(other.inner).into()
+    }
+
+    fn str_compare(&self, other: Self, collation: Wrap<Collation>) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .compare(other.inner, collation.0)
+            .into()
+    }
+
+    fn str_min(&self, collation: Wrap<Collation>) -> Self {
+        self.inner.clone().str().min(collation.0).into()
+    }
+
+    fn str_max(&self, collation: Wrap<Collation>) -> Self {
+        self.inner.clone().str().max(collation.0).into()
+    }
+
     fn str_pad_start(&self, length: usize, fill_char: char) -> Self {
         self.inner.clone().str().pad_start(length, fill_char).into()
     }
@@ -223,12 +286,13 @@ impl PyExpr {
         &self,
         dtype: Option<Wrap<DataType>>,
         infer_schema_len: Option<usize>,
+        strict: bool,
     ) -> Self {
         let dtype = dtype.map(|wrap| wrap.0);
         self.inner
             .clone()
             .str()
-            .json_decode(dtype, infer_schema_len)
+            .json_decode(dtype, infer_schema_len, strict)
             .into()
     }
 
@@ -0,0 +1,19 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    #[pyo3(signature = (n_out, x))]
+    fn ts_lttb(&self, n_out: usize, x: Option<Self>) -> Self {
+        self.inner
+            .clone()
+            .ts()
+            .lttb(n_out, x.map(|x| x.inner))
+            .into()
+    }
+
+    fn ts_min_max(&self, n_out: usize) -> Self {
+        self.inner.clone().ts().min_max(n_out).into()
+    }
+}
@@ -33,6 +33,20 @@ pub fn min_horizontal(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
     Ok(e.into())
 }
 
+#[pyfunction]
+pub fn arg_max_horizontal(exprs: Vec<PyExpr>, return_names: bool) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e = dsl::arg_max_horizontal(exprs, return_names).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
+
+#[pyfunction]
+pub fn arg_min_horizontal(exprs: Vec<PyExpr>, return_names: bool) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e = dsl::arg_min_horizontal(exprs, return_names).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
+
 #[pyfunction]
 pub fn sum_horizontal(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
     let exprs = exprs.to_exprs();
@@ -15,24 +15,27 @@ pub fn concat_df(dfs: &Bound<'_, PyAny>, py: Python) -> PyResult<PyDataFrame> {
     let first = iter.next().unwrap()?;
 
     let first_rdf = get_df(&first)?;
-    let identity_df = first_rdf.clear();
 
-    let mut rdfs: Vec<PolarsResult<DataFrame>> = vec![Ok(first_rdf)];
+    let mut rdfs: Vec<DataFrame> = vec![first_rdf];
 
     for item in iter {
         let rdf = get_df(&item?)?;
-        rdfs.push(Ok(rdf));
+        rdfs.push(rdf);
     }
 
-    let identity = || Ok(identity_df.clone());
-
     let df = py
         .allow_threads(|| {
+            // Share a single dictionary between the categoricals up front, so that the
+            // parallel vstacks don't re-encode them pairwise.
+            polars_core::utils::unify_categorical_dictionaries(&mut rdfs);
+            let identity_df = rdfs[0].clear();
+            let identity = || Ok(identity_df.clone());
+
             polars_core::POOL.install(|| {
                 rdfs.into_par_iter()
                     .fold(identity, |acc: PolarsResult<DataFrame>, df| {
                         let mut acc = acc?;
-                        acc.vstack_mut(&df?)?;
+                        acc.vstack_mut(&df)?;
                         Ok(acc)
                     })
                     .reduce(identity, |acc, df| {
@@ -75,6 +75,8 @@ pub fn arg_sort_by(
             nulls_last,
             multithreaded,
             maintain_order,
+            stable: true,
+            collation: None,
         },
     )
     .into()
@@ -200,6 +202,13 @@ pub fn concat_list(s: Vec<PyExpr>) -> PyResult<PyExpr> {
     Ok(expr.into())
 }
 
+#[pyfunction]
+pub fn zip_lists(s: Vec<PyExpr>) -> PyResult<PyExpr> {
+    let s = s.into_iter().map(|e| e.inner).collect::<Vec<_>>();
+    let expr = dsl::zip_lists(s).map_err(PyPolarsErr::from)?;
+    Ok(expr.into())
+}
+
 #[pyfunction]
 pub fn concat_str(s: Vec<PyExpr>, separator: &str, ignore_nulls: bool) -> PyExpr {
     let s = s.into_iter().map(|e| e.inner).collect::<Vec<_>>();
@@ -211,11 +220,49 @@ pub fn len() -> PyExpr {
     dsl::len().into()
 }
 
+#[pyfunction]
+pub fn row_indices() -> PyExpr {
+    dsl::row_indices().into()
+}
+
+#[pyfunction]
+#[pyo3(signature = (other, key, value, default, other_key))]
+pub fn lookup(
+    other: PyDataFrame,
+    key: PyExpr,
+    value: &str,
+    default: Option<PyExpr>,
+    other_key: Option<&str>,
+) -> PyResult<PyExpr> {
+    let default = default.map(|e| e.inner);
+    let expr =
+        dsl::lookup(&other.df, key.inner, value, default, other_key).map_err(PyPolarsErr::from)?;
+    Ok(expr.into())
+}
+
 #[pyfunction]
 pub fn cov(a: PyExpr, b: PyExpr, ddof: u8) -> PyExpr {
     dsl::cov(a.inner, b.inner, ddof).into()
 }
 
+#[pyfunction]
+pub fn hist_2d(
+    x: PyExpr,
+    y: PyExpr,
+    bins: Option<PyExpr>,
+    bin_count: Option<usize>,
+    bin_strategy: Option<Wrap<HistBinStrategy>>,
+) -> PyExpr {
+    dsl::hist_2d(
+        x.inner,
+        y.inner,
+        bins.map(|e| e.inner),
+        bin_count,
+        bin_strategy.map(|s| s.0),
+    )
+    .into()
+}
+
 #[pyfunction]
 #[cfg(feature = "trigonometry")]
 pub fn arctan2(y: PyExpr, x: PyExpr) -> PyExpr {
@@ -1,9 +1,12 @@
 use std::sync::Arc;
 
 use polars_plan::prelude::*;
+#[cfg(feature = "ffi_plugin")]
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 use crate::conversion::Wrap;
+use crate::error::PyPolarsErr;
 use crate::expr::ToExprs;
 use crate::prelude::DataType;
 use crate::PyExpr;
@@ -14,6 +17,12 @@ pub fn dtype_str_repr(dtype: Wrap<DataType>) -> PyResult<String> {
     Ok(dtype.to_string())
 }
 
+#[pyfunction]
+pub fn register_frequency(name: &str, every: &str) -> PyResult<()> {
+    polars_time::register_frequency(name, every).map_err(PyPolarsErr::from)?;
+    Ok(())
+}
+
 #[cfg(feature = "ffi_plugin")]
 #[pyfunction]
 pub fn register_plugin_function(
@@ -59,3 +68,33 @@ pub fn register_plugin_function(
     }
     .into())
 }
+
+#[cfg(feature = "ffi_plugin")]
+#[pyfunction]
+pub fn register_plugin_aggregation(
+    plugin_path: &str,
+    function_name: &str,
+    args: Vec<PyExpr>,
+    kwargs: Vec<u8>,
+) -> PyResult<PyExpr> {
+    let mut input = args.to_exprs();
+    let packed = input.len() > 1;
+    let expr = if packed {
+        as_struct(input)
+    } else {
+        input.pop().ok_or_else(|| {
+            PyValueError::new_err("a plugin aggregation requires at least one input")
+        })?
+    };
+
+    Ok(Expr::Agg(AggExpr::Plugin {
+        expr: Arc::new(expr),
+        function: PluginAggregation {
+            lib: Arc::from(plugin_path),
+            symbol: Arc::from(function_name),
+            kwargs: Arc::from(kwargs),
+            packed,
+        },
+    })
+    .into())
+}
@@ -7,6 +7,7 @@ mod meta;
 mod misc;
 mod random;
 mod range;
+mod result_cache;
 mod string_cache;
 mod whenthen;
 
@@ -19,5 +20,6 @@ pub use meta::*;
 pub use misc::*;
 pub use random::*;
 pub use range::*;
+pub use result_cache::*;
 pub use string_cache::*;
 pub use whenthen::*;
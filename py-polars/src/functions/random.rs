@@ -1,7 +1,25 @@
+use polars::lazy::dsl;
 use pyo3::prelude::*;
 
+use crate::PyExpr;
+
 #[pyfunction]
 pub fn set_random_seed(seed: u64) -> PyResult<()> {
     polars_core::random::set_global_random_seed(seed);
     Ok(())
 }
+
+#[pyfunction]
+pub fn rand_uniform(low: PyExpr, high: PyExpr, seed: Option<u64>) -> PyExpr {
+    dsl::rand_uniform(low.inner, high.inner, seed).into()
+}
+
+#[pyfunction]
+pub fn rand_normal(mean: PyExpr, std: PyExpr, seed: Option<u64>) -> PyExpr {
+    dsl::rand_normal(mean.inner, std.inner, seed).into()
+}
+
+#[pyfunction]
+pub fn rand_poisson(lambda: PyExpr, seed: Option<u64>) -> PyExpr {
+    dsl::rand_poisson(lambda.inner, seed).into()
+}
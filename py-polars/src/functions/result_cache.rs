@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+use polars::prelude::ResultCacheConfig;
+use pyo3::prelude::*;
+
+#[pyfunction]
+#[pyo3(signature = (memory_budget, spill_dir, disk_budget))]
+pub fn enable_result_cache(memory_budget: usize, spill_dir: Option<PathBuf>, disk_budget: usize) {
+    polars::prelude::enable_result_cache(ResultCacheConfig {
+        memory_budget,
+        spill_dir,
+        disk_budget,
+    })
+}
+
+#[pyfunction]
+pub fn disable_result_cache() {
+    polars::prelude::disable_result_cache()
+}
+
+#[pyfunction]
+pub fn clear_result_cache() {
+    polars::prelude::clear_result_cache()
+}
+
+#[pyfunction]
+pub fn using_result_cache() -> bool {
+    polars::prelude::using_result_cache()
+}
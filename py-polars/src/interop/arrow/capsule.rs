@@ -0,0 +1,175 @@
+//! The [Arrow PyCapsule interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html),
+//! which passes the structs of the Arrow C data and stream interfaces between Python libraries in
+//! named capsules.
+use std::ffi::{c_void, CString};
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::utils::arrow::array::{new_empty_array, StructArray};
+use polars_core::utils::arrow::ffi;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyCapsule, PyTuple};
+
+use crate::error::PyPolarsErr;
+
+/// The structs are handed to the capsules as is, consumers read them through the capsule pointer.
+#[repr(transparent)]
+struct CapsuleSchema(ffi::ArrowSchema);
+
+#[repr(transparent)]
+struct CapsuleStream(ffi::ArrowArrayStream);
+
+// SAFETY: a capsule is consumed by one reader at a time, as the C interfaces require.
+unsafe impl Send for CapsuleSchema {}
+unsafe impl Send for CapsuleStream {}
+
+pub(crate) fn field_to_capsule<'py>(
+    py: Python<'py>,
+    field: &ArrowField,
+) -> PyResult<Bound<'py, PyCapsule>> {
+    let schema = CapsuleSchema(ffi::export_field_to_c(field));
+    PyCapsule::new_bound(py, schema, Some(CString::new("arrow_schema").unwrap()))
+}
+
+/// The `(schema, array)` pair of capsules that `__arrow_c_array__` returns.
+pub(crate) fn array_to_capsules<'py>(
+    py: Python<'py>,
+    field: &ArrowField,
+    array: ArrayRef,
+) -> PyResult<Bound<'py, PyTuple>> {
+    let schema = field_to_capsule(py, field)?;
+    let array = PyCapsule::new_bound(
+        py,
+        ffi::export_array_to_c(array),
+        Some(CString::new("arrow_array").unwrap()),
+    )?;
+    Ok(PyTuple::new_bound(
+        py,
+        [schema.into_any(), array.into_any()],
+    ))
+}
+
+pub(crate) fn stream_to_capsule(
+    py: Python<'_>,
+    stream: ffi::ArrowArrayStream,
+) -> PyResult<Bound<'_, PyCapsule>> {
+    PyCapsule::new_bound(
+        py,
+        CapsuleStream(stream),
+        Some(CString::new("arrow_array_stream").unwrap()),
+    )
+}
+
+/// The pointer of a capsule, checking its name so that no other struct is read through it.
+fn capsule_pointer(capsule: &Bound<'_, PyAny>, name: &str) -> PyResult<*mut c_void> {
+    let capsule = capsule.downcast::<PyCapsule>()?;
+    if capsule.name()?.and_then(|n| n.to_str().ok()) != Some(name) {
+        return Err(PyValueError::new_err(format!(
+            "expected a PyCapsule named {name:?}"
+        )));
+    }
+    Ok(capsule.pointer())
+}
+
+fn field_from_capsule(capsule: &Bound<'_, PyAny>) -> PyResult<ArrowField> {
+    let schema = capsule_pointer(capsule, "arrow_schema")? as *const ffi::ArrowSchema;
+    // SAFETY: the capsule holds an Arrow C schema, which it keeps owning.
+    let field = unsafe { ffi::import_field_from_c(&*schema) }.map_err(PyPolarsErr::from)?;
+    Ok(field)
+}
+
+fn array_from_capsules(
+    schema: &Bound<'_, PyAny>,
+    array: &Bound<'_, PyAny>,
+) -> PyResult<(ArrowField, ArrayRef)> {
+    let field = field_from_capsule(schema)?;
+    let array = capsule_pointer(array, "arrow_array")? as *mut ffi::ArrowArray;
+    // SAFETY: the capsule holds an Arrow C array. It is moved out, leaving a released array
+    // behind for the capsule to drop.
+    let array = unsafe { std::ptr::replace(array, ffi::ArrowArray::empty()) };
+    let array = unsafe { ffi::import_array_from_c(array, field.data_type.clone()) }
+        .map_err(PyPolarsErr::from)?;
+    Ok((field, array))
+}
+
+fn stream_from_capsule(capsule: &Bound<'_, PyAny>) -> PyResult<ffi::ArrowArrayStream> {
+    let stream = capsule_pointer(capsule, "arrow_array_stream")? as *mut ffi::ArrowArrayStream;
+    // SAFETY: the capsule holds an Arrow C stream, which is moved out like an array.
+    Ok(unsafe { std::ptr::replace(stream, ffi::ArrowArrayStream::empty()) })
+}
+
+/// Import the array of an object that implements `__arrow_c_array__`.
+pub(crate) fn import_array(ob: &Bound<'_, PyAny>) -> PyResult<(ArrowField, Vec<ArrayRef>)> {
+    let (schema, array) = ob
+        .call_method0("__arrow_c_array__")?
+        .extract::<(Bound<PyAny>, Bound<PyAny>)>()?;
+    let (field, array) = array_from_capsules(&schema, &array)?;
+    Ok((field, vec![array]))
+}
+
+/// Read all arrays of an object that implements `__arrow_c_stream__`.
+pub(crate) fn import_stream(ob: &Bound<'_, PyAny>) -> PyResult<(ArrowField, Vec<ArrayRef>)> {
+    let capsule = ob.call_method0("__arrow_c_stream__")?;
+    let stream = Box::new(stream_from_capsule(&capsule)?);
+    let mut reader =
+        unsafe { ffi::ArrowArrayStreamReader::try_new(stream) }.map_err(PyPolarsErr::from)?;
+
+    let mut arrays = vec![];
+    // SAFETY: the producer of the stream fulfills the C stream interface.
+    while let Some(array) = unsafe { reader.next() } {
+        arrays.push(array.map_err(PyPolarsErr::from)?);
+    }
+    Ok((reader.field().clone(), arrays))
+}
+
+/// Import an object that implements `__arrow_c_stream__` or `__arrow_c_array__`, preferring the
+/// stream as it can hold more than one chunk.
+pub(crate) fn import(ob: &Bound<'_, PyAny>) -> PyResult<(ArrowField, Vec<ArrayRef>)> {
+    if ob.hasattr("__arrow_c_stream__")? {
+        import_stream(ob)
+    } else if ob.hasattr("__arrow_c_array__")? {
+        import_array(ob)
+    } else {
+        Err(PyTypeError::new_err(format!(
+            "object of type {} does not implement the Arrow PyCapsule interface",
+            ob.get_type().name()?
+        )))
+    }
+}
+
+pub(crate) fn to_rust_series(field: &ArrowField, mut chunks: Vec<ArrayRef>) -> PyResult<Series> {
+    if chunks.is_empty() {
+        chunks.push(new_empty_array(field.data_type.clone()));
+    }
+    let s = Series::try_from((field, chunks)).map_err(PyPolarsErr::from)?;
+    Ok(s)
+}
+
+/// Build a [`DataFrame`] from record batches, which are passed as struct arrays of the columns.
+pub(crate) fn to_rust_df(field: &ArrowField, mut batches: Vec<ArrayRef>) -> PyResult<DataFrame> {
+    let ArrowDataType::Struct(fields) = field.data_type.to_logical_type() else {
+        return Err(PyTypeError::new_err(format!(
+            "expected Arrow record batches (struct arrays) to create a DataFrame, got {:?}",
+            field.data_type
+        )));
+    };
+    if batches.is_empty() {
+        batches.push(new_empty_array(field.data_type.clone()));
+    }
+
+    let dfs = batches
+        .iter()
+        .map(|batch| {
+            let batch = batch.as_any().downcast_ref::<StructArray>().unwrap();
+            let columns = fields
+                .iter()
+                .zip(batch.values())
+                .map(|(field, values)| Series::try_from((field, values.clone())))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            DataFrame::new(columns)
+        })
+        .collect::<PolarsResult<Vec<_>>>()
+        .map_err(PyPolarsErr::from)?;
+    Ok(accumulate_dataframes_vertical_unchecked(dfs))
+}
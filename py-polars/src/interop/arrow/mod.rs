@@ -1,2 +1,4 @@
+pub mod capsule;
+pub mod stream;
 pub mod to_py;
 pub mod to_rust;
@@ -0,0 +1,56 @@
+use polars_core::utils::arrow::ffi::ArrowArrayStream;
+use pyo3::exceptions::PyValueError;
+use pyo3::ffi::Py_uintptr_t;
+use pyo3::prelude::*;
+use pyo3::types::PyCapsule;
+
+use super::capsule::stream_to_capsule;
+
+/// A stream of record batches that can be consumed once, through the
+/// [Arrow PyCapsule interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html)
+/// or as a pyarrow `RecordBatchReader`.
+#[pyclass(unsendable)]
+pub struct PyArrowStream {
+    stream: Option<ArrowArrayStream>,
+}
+
+impl From<ArrowArrayStream> for PyArrowStream {
+    fn from(stream: ArrowArrayStream) -> Self {
+        Self {
+            stream: Some(stream),
+        }
+    }
+}
+
+impl PyArrowStream {
+    fn take(&mut self) -> PyResult<ArrowArrayStream> {
+        self.stream
+            .take()
+            .ok_or_else(|| PyValueError::new_err("the Arrow stream was already consumed"))
+    }
+}
+
+#[pymethods]
+impl PyArrowStream {
+    // The requested schema may be ignored by the producer.
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_stream__<'py>(
+        &mut self,
+        py: Python<'py>,
+        requested_schema: Option<PyObject>,
+    ) -> PyResult<Bound<'py, PyCapsule>> {
+        let _ = requested_schema;
+        stream_to_capsule(py, self.take()?)
+    }
+
+    fn to_pyarrow(&mut self, py: Python) -> PyResult<PyObject> {
+        let stream = Box::new(self.take()?);
+        let stream_ptr: *const ArrowArrayStream = &*stream;
+        // pyarrow moves the stream out, leaving a released stream behind that is dropped here.
+        let reader = py
+            .import_bound("pyarrow")?
+            .getattr("RecordBatchReader")?
+            .call_method1("_import_from_c", (stream_ptr as Py_uintptr_t,))?;
+        Ok(reader.to_object(py))
+    }
+}
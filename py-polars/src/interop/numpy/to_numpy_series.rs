@@ -194,6 +194,10 @@ fn series_to_numpy_with_copy(py: Python, s: &Series, writable: bool) -> PyObject
         UInt16 => numeric_series_to_numpy::<UInt16Type, f32>(py, s),
         UInt32 => numeric_series_to_numpy::<UInt32Type, f64>(py, s),
         UInt64 => numeric_series_to_numpy::<UInt64Type, f64>(py, s),
+        Float16 => {
+            let s = s.cast(&Float32).unwrap();
+            numeric_series_to_numpy::<Float32Type, f32>(py, &s)
+        },
         Float32 => numeric_series_to_numpy::<Float32Type, f32>(py, s),
         Float64 => numeric_series_to_numpy::<Float64Type, f64>(py, s),
         Boolean => boolean_series_to_numpy(py, s),
@@ -241,6 +245,11 @@ fn series_to_numpy_with_copy(py: Python, s: &Series, writable: bool) -> PyObject
             let values = ca.iter().map(|s| s.into_py(py));
             PyArray1::from_iter_bound(py, values).into_py(py)
         },
+        Json => {
+            let ca = s.json().unwrap().to_text();
+            let values = ca.iter().map(|s| s.into_py(py));
+            PyArray1::from_iter_bound(py, values).into_py(py)
+        },
         Categorical(_, _) | Enum(_, _) => {
             let ca = s.categorical().unwrap();
             let values = ca.iter_str().map(|s| s.into_py(py));
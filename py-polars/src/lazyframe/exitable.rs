@@ -34,3 +34,39 @@ impl PyInProcessQuery {
         Ok(out.into())
     }
 }
+
+#[pyclass]
+#[derive(Clone, Default)]
+pub struct PyCancellationToken {
+    token: CancellationToken,
+    // The tokens that were installed on the thread before each (nested) `enter`.
+    previous: Vec<Option<CancellationToken>>,
+}
+
+#[pymethods]
+impl PyCancellationToken {
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.token.cancel()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Make queries started on this thread use this token, until `exit` is called.
+    pub fn enter(&mut self) {
+        self.previous
+            .push(CancellationToken::set_current(Some(self.token.clone())));
+    }
+
+    pub fn exit(&mut self) {
+        if let Some(previous) = self.previous.pop() {
+            CancellationToken::set_current(previous);
+        }
+    }
+}
@@ -6,7 +6,7 @@ use std::num::NonZeroUsize;
 use std::path::PathBuf;
 mod serde;
 
-pub use exitable::PyInProcessQuery;
+pub use exitable::{PyCancellationToken, PyInProcessQuery};
 use polars::io::cloud::CloudOptions;
 use polars::io::{HiveOptions, RowIndex};
 use polars::time::*;
@@ -19,8 +19,12 @@ use pyo3::pybacked::PyBackedStr;
 use pyo3::types::{PyDict, PyList};
 pub(crate) use visit::PyExprIR;
 
+#[cfg(all(feature = "streaming", any(feature = "csv", feature = "json")))]
+use crate::conversion::parse_output_compression;
 use crate::error::PyPolarsErr;
 use crate::expr::ToExprs;
+#[cfg(feature = "streaming")]
+use crate::interop::arrow::stream::PyArrowStream;
 use crate::interop::arrow::to_rust::pyarrow_schema_to_rust;
 use crate::lazyframe::visit::NodeTraverser;
 use crate::prelude::*;
@@ -90,7 +94,7 @@ impl PyLazyFrame {
         low_memory, comment_prefix, quote_char, null_values, missing_utf8_is_empty_string,
         infer_schema_length, with_schema_modify, rechunk, skip_rows_after_header,
         encoding, row_index, try_parse_dates, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, glob, schema,
-        cloud_options, retries, file_cache_ttl
+        cloud_options, credential_provider, retries, file_cache_ttl
     )
     )]
     fn new_from_csv(
@@ -122,12 +126,12 @@ impl PyLazyFrame {
         glob: bool,
         schema: Option<Wrap<Schema>>,
         cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
         retries: usize,
         file_cache_ttl: Option<u64>,
     ) -> PyResult<Self> {
         let null_values = null_values.map(|w| w.0);
         let quote_char = quote_char.map(|s| s.as_bytes()[0]);
-        let separator = separator.as_bytes()[0];
         let eol_char = eol_char.as_bytes()[0];
         let row_index = row_index.map(|(name, offset)| RowIndex {
             name: Arc::from(name.as_str()),
@@ -167,6 +171,9 @@ impl PyLazyFrame {
                 cloud_options.file_cache_ttl = file_cache_ttl;
             }
 
+            let cloud_options = cloud_options
+                .with_credential_provider(credential_provider.map(python_credential_provider));
+
             Some(cloud_options)
         };
 
@@ -178,7 +185,7 @@ impl PyLazyFrame {
 
         let mut r = r
             .with_infer_schema_length(infer_schema_length)
-            .with_separator(separator)
+            .with_separator_str(separator)
             .with_has_header(has_header)
             .with_ignore_errors(ignore_errors)
             .with_skip_rows(skip_rows)
@@ -232,7 +239,7 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[staticmethod]
     #[pyo3(signature = (path, paths, n_rows, cache, parallel, rechunk, row_index,
-        low_memory, cloud_options, use_statistics, hive_partitioning, hive_schema, try_parse_hive_dates, retries, glob)
+        low_memory, cloud_options, credential_provider, use_statistics, hive_partitioning, hive_schema, try_parse_hive_dates, retries, glob, allow_missing_files)
     )]
     fn new_from_parquet(
         path: Option<PathBuf>,
@@ -244,12 +251,14 @@ impl PyLazyFrame {
         row_index: Option<(String, IdxSize)>,
         low_memory: bool,
         cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
         use_statistics: bool,
         hive_partitioning: Option<bool>,
         hive_schema: Option<Wrap<Schema>>,
         try_parse_hive_dates: bool,
         retries: usize,
         glob: bool,
+        allow_missing_files: bool,
     ) -> PyResult<Self> {
         let parallel = parallel.0;
         let hive_schema = hive_schema.map(|s| Arc::new(s.0));
@@ -275,6 +284,15 @@ impl PyLazyFrame {
                         options
                     });
         }
+        if let Some(credential_provider) = credential_provider {
+            cloud_options = Some(
+                cloud_options
+                    .unwrap_or_default()
+                    .with_credential_provider(Some(python_credential_provider(
+                        credential_provider,
+                    ))),
+            );
+        }
         let row_index = row_index.map(|(name, offset)| RowIndex {
             name: Arc::from(name.as_str()),
             offset,
@@ -297,6 +315,7 @@ impl PyLazyFrame {
             use_statistics,
             hive_options,
             glob,
+            allow_missing_files,
         };
 
         let lf = if path.is_some() {
@@ -310,7 +329,7 @@ impl PyLazyFrame {
 
     #[cfg(feature = "ipc")]
     #[staticmethod]
-    #[pyo3(signature = (path, paths, n_rows, cache, rechunk, row_index, memory_map, cloud_options, retries, file_cache_ttl))]
+    #[pyo3(signature = (path, paths, n_rows, cache, rechunk, row_index, memory_map, cloud_options, credential_provider, retries, file_cache_ttl, allow_missing_files))]
     fn new_from_ipc(
         path: Option<PathBuf>,
         paths: Vec<PathBuf>,
@@ -320,8 +339,10 @@ impl PyLazyFrame {
         row_index: Option<(String, IdxSize)>,
         memory_map: bool,
         cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
         retries: usize,
         file_cache_ttl: Option<u64>,
+        allow_missing_files: bool,
     ) -> PyResult<Self> {
         let row_index = row_index.map(|(name, offset)| RowIndex {
             name: Arc::from(name.as_str()),
@@ -354,6 +375,9 @@ impl PyLazyFrame {
                 cloud_options.file_cache_ttl = file_cache_ttl;
             }
 
+            let cloud_options = cloud_options
+                .with_credential_provider(credential_provider.map(python_credential_provider));
+
             Some(cloud_options)
         };
 
@@ -365,6 +389,7 @@ impl PyLazyFrame {
             memory_map,
             #[cfg(feature = "cloud")]
             cloud_options,
+            allow_missing_files,
         };
 
         let lf = if let Some(path) = &path {
@@ -475,7 +500,9 @@ impl PyLazyFrame {
         descending: bool,
         nulls_last: bool,
         maintain_order: bool,
+        stable: bool,
         multithreaded: bool,
+        collation: Option<Wrap<Collation>>,
     ) -> Self {
         let ldf = self.ldf.clone();
         ldf.sort(
@@ -485,6 +512,8 @@ impl PyLazyFrame {
                 nulls_last: vec![nulls_last],
                 multithreaded,
                 maintain_order,
+                stable,
+                collation: collation.map(|c| c.0),
             },
         )
         .into()
@@ -496,7 +525,9 @@ impl PyLazyFrame {
         descending: Vec<bool>,
         nulls_last: Vec<bool>,
         maintain_order: bool,
+        stable: bool,
         multithreaded: bool,
+        collation: Option<Wrap<Collation>>,
     ) -> Self {
         let ldf = self.ldf.clone();
         let exprs = by.to_exprs();
@@ -507,6 +538,8 @@ impl PyLazyFrame {
                 nulls_last,
                 maintain_order,
                 multithreaded,
+                stable,
+                collation: collation.map(|c| c.0),
             },
         )
         .into()
@@ -549,6 +582,31 @@ impl PyLazyFrame {
         Ok((df.into(), time_df.into()))
     }
 
+    fn explain_analyze(&self, py: Python, dot: bool) -> PyResult<String> {
+        let out = py.allow_threads(|| {
+            let ldf = self.ldf.clone();
+            ldf.explain_analyze(dot).map_err(PyPolarsErr::from)
+        })?;
+        Ok(out)
+    }
+
+    fn collect_with_scan_stats(&self, py: Python) -> PyResult<(PyDataFrame, PyDataFrame)> {
+        let (df, stats_df) = py.allow_threads(|| {
+            let ldf = self.ldf.clone();
+            ldf.collect_with_scan_stats().map_err(PyPolarsErr::from)
+        })?;
+        Ok((df.into(), stats_df.into()))
+    }
+
+    #[cfg(feature = "streaming")]
+    fn collect_arrow_stream(&self, py: Python, future: bool) -> PyResult<PyArrowStream> {
+        let stream = py.allow_threads(|| {
+            let ldf = self.ldf.clone();
+            ldf.collect_arrow_stream(future).map_err(PyPolarsErr::from)
+        })?;
+        Ok(stream.into())
+    }
+
     fn collect(&self, py: Python, lamdba_post_opt: Option<PyObject>) -> PyResult<PyDataFrame> {
         // if we don't allow threads and we have udfs trying to acquire the gil from different
         // threads we deadlock.
@@ -614,7 +672,7 @@ impl PyLazyFrame {
     }
 
     #[cfg(all(feature = "streaming", feature = "parquet"))]
-    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_pagesize_limit, maintain_order))]
+    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_pagesize_limit, maintain_order, write_mode))]
     fn sink_parquet(
         &self,
         py: Python,
@@ -625,6 +683,7 @@ impl PyLazyFrame {
         row_group_size: Option<usize>,
         data_pagesize_limit: Option<usize>,
         maintain_order: bool,
+        write_mode: Wrap<WriteMode>,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
 
@@ -634,6 +693,8 @@ impl PyLazyFrame {
             row_group_size,
             data_pagesize_limit,
             maintain_order,
+            write_mode: write_mode.0,
+            categoricals_as_strings: false,
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -646,17 +707,23 @@ impl PyLazyFrame {
     }
 
     #[cfg(all(feature = "streaming", feature = "ipc"))]
-    #[pyo3(signature = (path, compression, maintain_order))]
+    #[pyo3(signature = (path, compression, maintain_order, write_mode, uncompressed_columns, statistics))]
     fn sink_ipc(
         &self,
         py: Python,
         path: PathBuf,
         compression: Option<Wrap<IpcCompression>>,
         maintain_order: bool,
+        write_mode: Wrap<WriteMode>,
+        uncompressed_columns: Vec<String>,
+        statistics: bool,
     ) -> PyResult<()> {
         let options = IpcWriterOptions {
             compression: compression.map(|c| c.0),
             maintain_order,
+            write_mode: write_mode.0,
+            uncompressed_columns,
+            statistics,
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -668,8 +735,34 @@ impl PyLazyFrame {
         Ok(())
     }
 
+    #[cfg(all(feature = "streaming", feature = "ipc_streaming"))]
+    #[pyo3(signature = (path, compression, compression_level, maintain_order))]
+    fn sink_ipc_stream(
+        &self,
+        py: Python,
+        path: PathBuf,
+        compression: Wrap<Option<IpcCompression>>,
+        compression_level: Option<i32>,
+        maintain_order: bool,
+    ) -> PyResult<()> {
+        let options = IpcStreamWriterOptions {
+            compression: compression.0,
+            compression_level,
+            maintain_order,
+        };
+
+        // if we don't allow threads and we have udfs trying to acquire the gil from different
+        // threads we deadlock.
+        py.allow_threads(|| {
+            let ldf = self.ldf.clone();
+            ldf.sink_ipc_stream(path, options)
+                .map_err(PyPolarsErr::from)
+        })?;
+        Ok(())
+    }
+
     #[cfg(all(feature = "streaming", feature = "csv"))]
-    #[pyo3(signature = (path, include_bom, include_header, separator, line_terminator, quote_char, batch_size, datetime_format, date_format, time_format, float_scientific, float_precision, null_value, quote_style, maintain_order))]
+    #[pyo3(signature = (path, include_bom, include_header, separator, line_terminator, quote_char, batch_size, datetime_format, date_format, time_format, float_scientific, float_precision, float_scientific_threshold, null_value, quote_style, maintain_order, compression, compression_level, write_mode))]
     fn sink_csv(
         &self,
         py: Python,
@@ -685,10 +778,15 @@ impl PyLazyFrame {
         time_format: Option<String>,
         float_scientific: Option<bool>,
         float_precision: Option<usize>,
+        float_scientific_threshold: Option<u32>,
         null_value: Option<String>,
         quote_style: Option<Wrap<QuoteStyle>>,
         maintain_order: bool,
+        compression: &str,
+        compression_level: Option<i32>,
+        write_mode: Wrap<WriteMode>,
     ) -> PyResult<()> {
+        let compression = parse_output_compression(&path, compression, compression_level)?;
         let quote_style = quote_style.map_or(QuoteStyle::default(), |wrap| wrap.0);
         let null_value = null_value.unwrap_or(SerializeOptions::default().null);
 
@@ -698,6 +796,7 @@ impl PyLazyFrame {
             datetime_format,
             float_scientific,
             float_precision,
+            float_scientific_threshold,
             separator,
             quote_char,
             null: null_value,
@@ -711,6 +810,8 @@ impl PyLazyFrame {
             maintain_order,
             batch_size,
             serialize_options,
+            compression,
+            write_mode: write_mode.0,
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -724,9 +825,21 @@ impl PyLazyFrame {
 
     #[allow(clippy::too_many_arguments)]
     #[cfg(all(feature = "streaming", feature = "json"))]
-    #[pyo3(signature = (path, maintain_order))]
-    fn sink_json(&self, py: Python, path: PathBuf, maintain_order: bool) -> PyResult<()> {
-        let options = JsonWriterOptions { maintain_order };
+    #[pyo3(signature = (path, maintain_order, compression, compression_level, write_mode))]
+    fn sink_json(
+        &self,
+        py: Python,
+        path: PathBuf,
+        maintain_order: bool,
+        compression: &str,
+        compression_level: Option<i32>,
+        write_mode: Wrap<WriteMode>,
+    ) -> PyResult<()> {
+        let options = JsonWriterOptions {
+            maintain_order,
+            compression: parse_output_compression(&path, compression, compression_level)?,
+            write_mode: write_mode.0,
+        };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
         // threads we deadlock.
@@ -743,6 +856,18 @@ impl PyLazyFrame {
         Ok(df.into())
     }
 
+    fn glimpse(&self, py: Python, n: IdxSize) -> PyResult<PyDataFrame> {
+        let ldf = self.ldf.clone();
+        let df = py.allow_threads(|| ldf.glimpse(n).map_err(PyPolarsErr::from))?;
+        Ok(df.into())
+    }
+
+    fn head_tail(&self, py: Python, n: IdxSize) -> PyResult<PyDataFrame> {
+        let ldf = self.ldf.clone();
+        let df = py.allow_threads(|| ldf.head_tail(n).map_err(PyPolarsErr::from))?;
+        Ok(df.into())
+    }
+
     fn filter(&mut self, predicate: PyExpr) -> Self {
         let ldf = self.ldf.clone();
         ldf.filter(predicate.inner).into()
@@ -836,13 +961,94 @@ impl PyLazyFrame {
         PyLazyGroupBy { lgb: Some(lazy_gb) }
     }
 
+    #[pyo3(signature = (index_column, every, group_by, aggs, fill_strategy, fill_value))]
+    fn resample(
+        &self,
+        index_column: PyExpr,
+        every: &str,
+        group_by: Vec<PyExpr>,
+        aggs: Vec<PyExpr>,
+        fill_strategy: &str,
+        fill_value: Option<PyExpr>,
+    ) -> PyResult<Self> {
+        let fill = match (fill_value, fill_strategy) {
+            (Some(value), _) => match value.inner {
+                Expr::Literal(value) => ResampleFill::Value(value),
+                _ => return Err(PyValueError::new_err("`fill` must be a literal value")),
+            },
+            (None, "null") => ResampleFill::Null,
+            (None, "forward") => ResampleFill::Forward,
+            (None, v) => {
+                return Err(PyValueError::new_err(format!(
+                    "`fill` must be one of {{'null', 'forward'}} or a value, got {v}",
+                )))
+            },
+        };
+        let ldf = self.ldf.clone();
+        Ok(ldf
+            .resample(
+                index_column.inner,
+                group_by.to_exprs(),
+                Duration::parse(every),
+                aggs.to_exprs(),
+                fill,
+            )
+            .into())
+    }
+
+    #[cfg(feature = "grouping_sets")]
+    #[pyo3(signature = (sets, aggs, grouping_id))]
+    fn group_by_grouping_sets(
+        &self,
+        sets: Vec<Vec<PyExpr>>,
+        aggs: Vec<PyExpr>,
+        grouping_id: Option<&str>,
+    ) -> Self {
+        let sets = sets
+            .into_iter()
+            .map(|set| set.to_exprs())
+            .collect::<Vec<_>>();
+        let ldf = self.ldf.clone();
+        ldf.group_by_grouping_sets(&sets, aggs.to_exprs(), grouping_id)
+            .into()
+    }
+
+    #[cfg(feature = "grouping_sets")]
+    #[pyo3(signature = (by, aggs, grouping_id))]
+    fn group_by_rollup(
+        &self,
+        by: Vec<PyExpr>,
+        aggs: Vec<PyExpr>,
+        grouping_id: Option<&str>,
+    ) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.group_by_rollup(by.to_exprs(), aggs.to_exprs(), grouping_id)
+            .into()
+    }
+
+    #[cfg(feature = "grouping_sets")]
+    #[pyo3(signature = (by, aggs, level))]
+    fn group_by_hierarchy(&self, by: Vec<PyExpr>, aggs: Vec<PyExpr>, level: &str) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.group_by_hierarchy(by.to_exprs(), aggs.to_exprs(), level)
+            .into()
+    }
+
+    #[cfg(feature = "grouping_sets")]
+    #[pyo3(signature = (by, aggs, grouping_id))]
+    fn group_by_cube(&self, by: Vec<PyExpr>, aggs: Vec<PyExpr>, grouping_id: Option<&str>) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.group_by_cube(by.to_exprs(), aggs.to_exprs(), grouping_id)
+            .into()
+    }
+
     fn with_context(&self, contexts: Vec<Self>) -> Self {
         let contexts = contexts.into_iter().map(|ldf| ldf.ldf).collect::<Vec<_>>();
         self.ldf.clone().with_context(contexts).into()
     }
 
     #[cfg(feature = "asof_join")]
-    #[pyo3(signature = (other, left_on, right_on, left_by, right_by, allow_parallel, force_parallel, suffix, strategy, tolerance, tolerance_str, coalesce))]
+    #[pyo3(signature = (other, left_on, right_on, left_by, right_by, allow_parallel, force_parallel, suffix, strategy, tolerance, tolerance_str, coalesce, naming=None))]
     fn join_asof(
         &self,
         other: Self,
@@ -857,6 +1063,7 @@ impl PyLazyFrame {
         tolerance: Option<Wrap<AnyValue<'_>>>,
         tolerance_str: Option<String>,
         coalesce: Option<bool>,
+        naming: Option<Wrap<JoinNaming>>,
     ) -> PyResult<Self> {
         let coalesce = match coalesce {
             None => JoinCoalesce::JoinSpecific,
@@ -867,7 +1074,7 @@ impl PyLazyFrame {
         let other = other.ldf;
         let left_on = left_on.inner;
         let right_on = right_on.inner;
-        Ok(ldf
+        let builder = ldf
             .join_builder()
             .with(other)
             .left_on([left_on])
@@ -882,11 +1089,15 @@ impl PyLazyFrame {
                 tolerance: tolerance.map(|t| t.0.into_static().unwrap()),
                 tolerance_str: tolerance_str.map(|s| s.into()),
             }))
-            .suffix(suffix)
-            .finish()
-            .into())
+            .suffix(suffix);
+        let builder = match naming {
+            Some(naming) => builder.naming(naming.0),
+            None => builder,
+        };
+        Ok(builder.finish().into())
     }
 
+    #[pyo3(signature = (other, left_on, right_on, allow_parallel, force_parallel, join_nulls, how, suffix, validate, coalesce=None, naming=None))]
     fn join(
         &self,
         other: Self,
@@ -899,6 +1110,7 @@ impl PyLazyFrame {
         suffix: String,
         validate: Wrap<JoinValidation>,
         coalesce: Option<bool>,
+        naming: Option<Wrap<JoinNaming>>,
     ) -> PyResult<Self> {
         let coalesce = match coalesce {
             None => JoinCoalesce::JoinSpecific,
@@ -916,7 +1128,7 @@ impl PyLazyFrame {
             .map(|pyexpr| pyexpr.inner)
             .collect::<Vec<_>>();
 
-        Ok(ldf
+        let builder = ldf
             .join_builder()
             .with(other)
             .left_on(left_on)
@@ -927,9 +1139,34 @@ impl PyLazyFrame {
             .how(how.0)
             .coalesce(coalesce)
             .validate(validate.0)
-            .suffix(suffix)
-            .finish()
-            .into())
+            .suffix(suffix);
+        let builder = match naming {
+            Some(naming) => builder.naming(naming.0),
+            None => builder,
+        };
+        Ok(builder.finish().into())
+    }
+
+    #[cfg(feature = "iejoin")]
+    #[pyo3(signature = (other, predicates, suffix, naming=None))]
+    fn join_where(
+        &self,
+        other: Self,
+        predicates: Vec<PyExpr>,
+        suffix: String,
+        naming: Option<Wrap<JoinNaming>>,
+    ) -> PyResult<Self> {
+        let ldf = self.ldf.clone();
+        let other = other.ldf;
+        let predicates = predicates.into_iter().map(|e| e.inner).collect();
+
+        let builder = ldf.join_builder().with(other).suffix(suffix);
+        let builder = match naming {
+            Some(naming) => builder.naming(naming.0),
+            None => builder,
+        };
+        let lf = builder.join_where(predicates).map_err(PyPolarsErr::from)?;
+        Ok(lf.into())
     }
 
     fn with_column(&mut self, expr: PyExpr) -> Self {
@@ -947,6 +1184,12 @@ impl PyLazyFrame {
         ldf.with_columns_seq(exprs.to_exprs()).into()
     }
 
+    fn set_where(&mut self, mask: PyExpr, names: Vec<String>, values: Vec<PyExpr>) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.set_where(mask.inner, names.into_iter().zip(values.to_exprs()))
+            .into()
+    }
+
     fn rename(&mut self, existing: Vec<String>, new: Vec<String>) -> Self {
         let ldf = self.ldf.clone();
         ldf.rename(existing, new).into()
@@ -1082,12 +1325,85 @@ impl PyLazyFrame {
         ldf.unpivot(args).into()
     }
 
-    fn with_row_index(&self, name: &str, offset: Option<IdxSize>) -> Self {
+    #[cfg(feature = "pivot")]
+    #[pyo3(signature = (on, on_columns, index, values, aggregate_expr, maintain_order, sort_columns, separator))]
+    #[allow(clippy::too_many_arguments)]
+    fn pivot(
+        &self,
+        py: Python,
+        on: Vec<String>,
+        on_columns: Option<PyDataFrame>,
+        index: Option<Vec<String>>,
+        values: Option<Vec<String>>,
+        aggregate_expr: PyExpr,
+        maintain_order: bool,
+        sort_columns: bool,
+        separator: &str,
+    ) -> PyResult<Self> {
+        let to_names = |names: Vec<String>| names.into_iter().map(Arc::from).collect();
+        let args = PivotArgs {
+            on: to_names(on),
+            on_columns: on_columns.map(|df| Arc::new(df.df)),
+            index: index.map(to_names),
+            values: values.map(to_names),
+            agg: aggregate_expr.inner,
+            maintain_order,
+            sort_columns,
+            separator: Arc::from(separator),
+        };
+
+        // Without `on_columns` the distinct values of `on` are collected first.
         let ldf = self.ldf.clone();
-        ldf.with_row_index(name, offset).into()
+        let out = py
+            .allow_threads(|| ldf.pivot(args))
+            .map_err(PyPolarsErr::from)?;
+        Ok(out.into())
+    }
+
+    #[pyo3(signature = (step, until, max_iterations))]
+    fn iterate(
+        &self,
+        py: Python,
+        step: PyObject,
+        until: Option<PyExpr>,
+        max_iterations: usize,
+    ) -> PyResult<Self> {
+        // Keep the exception raised by `step` to raise it as is.
+        let mut py_err = None;
+        let ldf = self.ldf.clone().iterate(
+            |lf| {
+                step.call1(py, (PyLazyFrame::from(lf),))
+                    .and_then(|out| out.extract::<PyLazyFrame>(py))
+                    .map(|out| out.ldf)
+                    .map_err(|err| {
+                        py_err = Some(err);
+                        polars_err!(ComputeError: "the step of `iterate` failed")
+                    })
+            },
+            until.map(|e| e.inner),
+            max_iterations,
+        );
+        match (ldf, py_err) {
+            (_, Some(err)) => Err(err),
+            (ldf, None) => Ok(ldf.map_err(PyPolarsErr::from)?.into()),
+        }
+    }
+
+    #[pyo3(signature = (name, offset, over=None))]
+    fn with_row_index(
+        &self,
+        name: &str,
+        offset: Option<IdxSize>,
+        over: Option<Vec<String>>,
+    ) -> Self {
+        let ldf = self.ldf.clone();
+        match over {
+            Some(over) => ldf.with_row_index_over(name, over, offset).into(),
+            None => ldf.with_row_index(name, offset).into(),
+        }
     }
 
-    #[pyo3(signature = (lambda, predicate_pushdown, projection_pushdown, slice_pushdown, streamable, schema, validate_output))]
+    #[pyo3(signature = (lambda, predicate_pushdown, projection_pushdown, slice_pushdown, streamable, schema, validate_output, columns_used=None, row_independent=false))]
     fn map_batches(
         &self,
         lambda: PyObject,
@@ -1097,7 +1413,9 @@ impl PyLazyFrame {
         streamable: bool,
         schema: Option<Wrap<Schema>>,
         validate_output: bool,
-    ) -> Self {
+        columns_used: Option<Vec<String>>,
+        row_independent: bool,
+    ) -> PyResult<Self> {
         let opt = AllowedOptimizations {
             predicate_pushdown,
             projection_pushdown,
@@ -1106,15 +1424,20 @@ impl PyLazyFrame {
             ..Default::default()
         };
 
-        self.ldf
-            .clone()
-            .map_python(
-                lambda.into(),
-                opt,
-                schema.map(|s| Arc::new(s.0)),
-                validate_output,
-            )
-            .into()
+        let ldf = self.ldf.clone().map_python(
+            lambda.into(),
+            opt,
+            schema.map(|s| Arc::new(s.0)),
+            validate_output,
+        );
+        let ldf = match columns_used {
+            Some(columns) if row_independent => ldf
+                .with_row_independent_columns(&columns)
+                .map_err(PyPolarsErr::from)?,
+            Some(columns) => ldf.with_columns_used(&columns).map_err(PyPolarsErr::from)?,
+            None => ldf,
+        };
+        Ok(ldf.into())
     }
 
     fn drop(&self, columns: Vec<String>, strict: bool) -> Self {
@@ -1171,4 +1494,52 @@ impl PyLazyFrame {
             .map_err(PyPolarsErr::from)?;
         Ok(out.into())
     }
+
+    fn assert_non_empty(&self) -> Self {
+        self.ldf.clone().assert_non_empty().into()
+    }
+
+    fn assert_row_count(&self, n: IdxSize) -> Self {
+        self.ldf.clone().assert_row_count(n).into()
+    }
+
+    #[pyo3(signature = (subset=None))]
+    fn assert_unique(&self, subset: Option<Vec<String>>) -> Self {
+        self.ldf.clone().assert_unique(subset).into()
+    }
+
+    fn assert_sorted(&self, column: &str, descending: bool) -> Self {
+        self.ldf.clone().assert_sorted(column, descending).into()
+    }
+
+    #[pyo3(signature = (n, fraction, by, weights, with_replacement, shuffle, seed))]
+    fn sample(
+        &self,
+        n: Option<usize>,
+        fraction: Option<f64>,
+        by: Vec<String>,
+        weights: Option<String>,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
+        let size = match (n, fraction) {
+            (Some(n), None) => SampleSize::N(n),
+            (None, Some(fraction)) => SampleSize::Fraction(fraction),
+            _ => {
+                return Err(PyValueError::new_err(
+                    "exactly one of `n` and `fraction` must be given",
+                ))
+            },
+        };
+        let args = SampleArgs {
+            size,
+            by: by.into_iter().map(|s| s.into()).collect(),
+            weights: weights.map(|s| s.into()),
+            with_replacement,
+            shuffle,
+            seed,
+        };
+        Ok(self.ldf.clone().sample(args).into())
+    }
 }
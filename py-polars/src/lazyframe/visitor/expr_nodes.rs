@@ -1,7 +1,7 @@
 use polars::datatypes::TimeUnit;
 use polars_core::prelude::{NonExistent, QuantileInterpolOptions};
 use polars_core::series::IsSorted;
-use polars_ops::prelude::ClosedInterval;
+use polars_ops::prelude::{ClosedInterval, RoundMode};
 use polars_plan::dsl::function_expr::rolling::RollingFunction;
 use polars_plan::dsl::function_expr::rolling_by::RollingFunctionBy;
 use polars_plan::dsl::function_expr::trigonometry::TrigonometricFunction;
@@ -255,6 +255,19 @@ impl IntoPy<PyObject> for Wrap<TimeUnit> {
     }
 }
 
+impl IntoPy<PyObject> for Wrap<QuantileInterpolOptions> {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self.0 {
+            QuantileInterpolOptions::Nearest => "nearest",
+            QuantileInterpolOptions::Lower => "lower",
+            QuantileInterpolOptions::Higher => "higher",
+            QuantileInterpolOptions::Midpoint => "midpoint",
+            QuantileInterpolOptions::Linear => "linear",
+        }
+        .into_py(py)
+    }
+}
+
 #[pyclass]
 pub struct BinaryExpr {
     #[pyo3(get)]
@@ -683,14 +696,50 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
             } => Agg {
                 name: "quantile".to_object(py),
                 arguments: vec![expr.0, quantile.0],
-                options: match interpol {
-                    QuantileInterpolOptions::Nearest => "nearest",
-                    QuantileInterpolOptions::Lower => "lower",
-                    QuantileInterpolOptions::Higher => "higher",
-                    QuantileInterpolOptions::Midpoint => "midpoint",
-                    QuantileInterpolOptions::Linear => "linear",
-                }
-                .to_object(py),
+                options: Wrap(*interpol).into_py(py),
+            },
+            IRAggExpr::ApproxQuantile {
+                expr,
+                quantile,
+                accuracy,
+            } => Agg {
+                name: "approx_quantile".to_object(py),
+                arguments: vec![expr.0, quantile.0],
+                options: accuracy.to_object(py),
+            },
+            IRAggExpr::Quantiles { expr, options } => Agg {
+                name: "quantiles".to_object(py),
+                arguments: vec![expr.0],
+                options: (
+                    options.quantiles.to_vec(),
+                    Wrap(options.interpol).into_py(py),
+                )
+                    .to_object(py),
+            },
+            IRAggExpr::StrJoin { expr, options } => Agg {
+                name: "str_join_agg".to_object(py),
+                arguments: vec![expr.0],
+                options: (
+                    options.separator.as_str(),
+                    options.distinct,
+                    options.sort,
+                    options.descending,
+                )
+                    .to_object(py),
+            },
+            IRAggExpr::FirstBy { expr, by } => Agg {
+                name: "first_by".to_object(py),
+                arguments: vec![expr.0, by.0],
+                options: py.None(),
+            },
+            IRAggExpr::LastBy { expr, by } => Agg {
+                name: "last_by".to_object(py),
+                arguments: vec![expr.0, by.0],
+                options: py.None(),
+            },
+            #[cfg(feature = "ffi_plugin")]
+            IRAggExpr::Plugin { .. } => {
+                return Err(PyNotImplementedError::new_err("plugin aggregation"))
             },
             IRAggExpr::Sum(n) => Agg {
                 name: "sum".to_object(py),
@@ -749,6 +798,15 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Categorical(_) => {
                     return Err(PyNotImplementedError::new_err("categorical expr"))
                 },
+                FunctionExpr::Downsample(_) => {
+                    return Err(PyNotImplementedError::new_err("downsample expr"))
+                },
+                FunctionExpr::HypothesisTest(_) => {
+                    return Err(PyNotImplementedError::new_err("hypothesis test expr"))
+                },
+                FunctionExpr::JsonExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("json expr"))
+                },
                 FunctionExpr::ListExpr(_) => {
                     return Err(PyNotImplementedError::new_err("list expr"))
                 },
@@ -810,7 +868,13 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::JsonDecode {
                         dtype: _,
                         infer_schema_len,
-                    } => (PyStringFunction::JsonDecode.into_py(py), infer_schema_len).to_object(py),
+                        strict,
+                    } => (
+                        PyStringFunction::JsonDecode.into_py(py),
+                        infer_schema_len,
+                        strict,
+                    )
+                        .to_object(py),
                     StringFunction::JsonPathMatch => {
                         (PyStringFunction::JsonPathMatch.into_py(py),).to_object(py)
                     },
@@ -898,6 +962,17 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::ExtractMany { .. } => {
                         return Err(PyNotImplementedError::new_err("extract_many"))
                     },
+                    StringFunction::Levenshtein { .. }
+                    | StringFunction::DamerauLevenshtein { .. }
+                    | StringFunction::Hamming { .. }
+                    | StringFunction::JaroWinkler => {
+                        return Err(PyNotImplementedError::new_err("string similarity"))
+                    },
+                    StringFunction::CollatedCompare(_)
+                    | StringFunction::CollatedMin(_)
+                    | StringFunction::CollatedMax(_) => {
+                        return Err(PyNotImplementedError::new_err("collated string function"))
+                    },
                 },
                 FunctionExpr::StructExpr(_) => {
                     return Err(PyNotImplementedError::new_err("struct expr"))
@@ -1018,6 +1093,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                         (PyBooleanFunction::IsBetween, Wrap(*closed)).into_py(py)
                     },
                     BooleanFunction::IsIn => (PyBooleanFunction::IsIn,).into_py(py),
+                    BooleanFunction::IsMonotonic { .. } => {
+                        return Err(PyNotImplementedError::new_err("is monotonic"))
+                    },
                     BooleanFunction::AllHorizontal => {
                         (PyBooleanFunction::AllHorizontal,).into_py(py)
                     },
@@ -1028,6 +1106,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 },
                 FunctionExpr::Abs => ("abs",).to_object(py),
                 FunctionExpr::Hist { .. } => return Err(PyNotImplementedError::new_err("hist")),
+                FunctionExpr::Hist2d { .. } => {
+                    return Err(PyNotImplementedError::new_err("hist_2d"))
+                },
                 FunctionExpr::NullCount => ("null_count",).to_object(py),
                 FunctionExpr::Pow(f) => match f {
                     PowFunction::Generic => ("pow",).to_object(py),
@@ -1154,6 +1235,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::ApproxNUnique => {
                     return Err(PyNotImplementedError::new_err("approx nunique"))
                 },
+                FunctionExpr::Sketch(_) => return Err(PyNotImplementedError::new_err("sketch")),
                 FunctionExpr::Coalesce => ("coalesce",).to_object(py),
                 FunctionExpr::ShrinkType => {
                     return Err(PyNotImplementedError::new_err("shrink type"))
@@ -1176,10 +1258,28 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Log1p => return Err(PyNotImplementedError::new_err("log1p")),
                 FunctionExpr::Exp => return Err(PyNotImplementedError::new_err("exp")),
                 FunctionExpr::Unique(maintain_order) => ("unique", maintain_order).to_object(py),
-                FunctionExpr::Round { decimals } => ("round", decimals).to_object(py),
+                FunctionExpr::Round { decimals, mode } => (
+                    "round",
+                    decimals,
+                    match mode {
+                        RoundMode::HalfAway => "half_away",
+                        RoundMode::HalfEven => "half_even",
+                    },
+                )
+                    .to_object(py),
                 FunctionExpr::RoundSF { digits } => ("round_sig_figs", digits).to_object(py),
                 FunctionExpr::Floor => ("floor",).to_object(py),
                 FunctionExpr::Ceil => ("ceil",).to_object(py),
+                FunctionExpr::CeilDiv => ("ceil_div",).to_object(py),
+                FunctionExpr::Rescale { scale, mode } => (
+                    "rescale",
+                    scale,
+                    match mode {
+                        RoundMode::HalfAway => "half_away",
+                        RoundMode::HalfEven => "half_even",
+                    },
+                )
+                    .to_object(py),
                 FunctionExpr::UpperBound => ("upper_bound",).to_object(py),
                 FunctionExpr::LowerBound => ("lower_bound",).to_object(py),
                 FunctionExpr::Fused(_) => return Err(PyNotImplementedError::new_err("fused")),
@@ -1198,6 +1298,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::ToPhysical => {
                     return Err(PyNotImplementedError::new_err("to physical"))
                 },
+                FunctionExpr::IsValidMask => ("is_valid_mask",).to_object(py),
+                FunctionExpr::WithValidity => ("with_validity",).to_object(py),
+                FunctionExpr::CoalesceValidity => ("coalesce_validity",).to_object(py),
                 FunctionExpr::Random { .. } => {
                     return Err(PyNotImplementedError::new_err("random"))
                 },
@@ -1210,6 +1313,11 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     },
                 )
                     .to_object(py),
+                FunctionExpr::Parallel(parallel) => ("parallel", *parallel).to_object(py),
+                FunctionExpr::OrElse => ("or_else",).to_object(py),
+                FunctionExpr::CheckSorted { descending } => {
+                    ("checksorted", *descending).to_object(py)
+                },
                 FunctionExpr::FfiPlugin { .. } => {
                     return Err(PyNotImplementedError::new_err("ffi plugin"))
                 },
@@ -1231,6 +1339,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::MinHorizontal => {
                     return Err(PyNotImplementedError::new_err("min horizontal"))
                 },
+                FunctionExpr::ArgMaxHorizontal { .. } => {
+                    return Err(PyNotImplementedError::new_err("arg max horizontal"))
+                },
+                FunctionExpr::ArgMinHorizontal { .. } => {
+                    return Err(PyNotImplementedError::new_err("arg min horizontal"))
+                },
                 FunctionExpr::EwmMean { options: _ } => {
                     return Err(PyNotImplementedError::new_err("ewm mean"))
                 },
@@ -1248,6 +1362,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::FillNullWithStrategy(_) => {
                     return Err(PyNotImplementedError::new_err("fill null with strategy"))
                 },
+                FunctionExpr::FillNullBy { .. } => {
+                    return Err(PyNotImplementedError::new_err("fill null by"))
+                },
                 FunctionExpr::GatherEvery { n, offset } => {
                     ("gather_every", offset, n).to_object(py)
                 },
@@ -1290,6 +1407,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     inner: options.clone(),
                 }
                 .into_py(py),
+                WindowType::Frame(_) => return Err(PyNotImplementedError::new_err("window frame")),
             };
             Window {
                 function,
@@ -348,6 +348,9 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                 FileScan::Anonymous { .. } => {
                     return Err(PyNotImplementedError::new_err("anonymous scan"))
                 },
+                FileScan::Source { .. } => {
+                    return Err(PyNotImplementedError::new_err("scan source"))
+                },
             },
         }
         .into_py(py),
@@ -430,7 +433,13 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
             })?,
             maintain_order: *maintain_order,
             // TODO: dynamic options
-            options: PyGroupbyOptions::new(options.as_ref().clone()).into_py(py),
+            options: {
+                #[cfg(feature = "grouping_sets")]
+                if options.grouping_sets.is_some() {
+                    return Err(PyNotImplementedError::new_err("grouping sets"));
+                }
+                PyGroupbyOptions::new(options.as_ref().clone()).into_py(py)
+            },
         }
         .into_py(py),
         IR::Join {
@@ -451,6 +460,9 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     JoinType::Inner => "inner",
                     JoinType::Full => "full",
                     JoinType::AsOf(_) => return Err(PyNotImplementedError::new_err("asof join")),
+                    JoinType::IEJoin(_) => {
+                        return Err(PyNotImplementedError::new_err("inequality join"))
+                    },
                     JoinType::Cross => "cross",
                     JoinType::Semi => "leftsemi",
                     JoinType::Anti => "leftanti",
@@ -512,6 +524,8 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     projection_pd: _,
                     streamable: _,
                     validate_output: _,
+                    columns_used: _,
+                    row_independent: _,
                 } => return Err(PyNotImplementedError::new_err("opaque python mapfunction")),
                 FunctionNode::Opaque {
                     function: _,
@@ -520,6 +534,8 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     projection_pd: _,
                     streamable: _,
                     fmt_str: _,
+                    columns_used: _,
+                    row_independent: _,
                 } => return Err(PyNotImplementedError::new_err("opaque rust mapfunction")),
                 FunctionNode::Pipeline {
                     function: _,
@@ -568,12 +584,19 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     name,
                     schema: _,
                     offset,
+                    over: None,
                 } => ("row_index", name.to_string(), offset.unwrap_or(0)).to_object(py),
+                FunctionNode::RowIndex { over: Some(_), .. } => {
+                    return Err(PyNotImplementedError::new_err("row index over groups"))
+                },
                 FunctionNode::Count {
                     paths: _,
                     scan_type: _,
                     alias: _,
                 } => return Err(PyNotImplementedError::new_err("function count")),
+                FunctionNode::Assert { assertion: _ } => {
+                    return Err(PyNotImplementedError::new_err("assert mapfunction"))
+                },
             },
         }
         .into_py(py),
@@ -48,7 +48,8 @@ use crate::conversion::Wrap;
 use crate::dataframe::PyDataFrame;
 use crate::expr::PyExpr;
 use crate::functions::PyStringCacheHolder;
-use crate::lazyframe::{PyInProcessQuery, PyLazyFrame};
+use crate::interop::arrow::stream::PyArrowStream;
+use crate::lazyframe::{PyCancellationToken, PyInProcessQuery, PyLazyFrame};
 use crate::lazygroupby::PyLazyGroupBy;
 use crate::series::PySeries;
 #[cfg(feature = "sql")]
@@ -118,6 +119,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PyDataFrame>().unwrap();
     m.add_class::<PyLazyFrame>().unwrap();
     m.add_class::<PyInProcessQuery>().unwrap();
+    m.add_class::<PyCancellationToken>().unwrap();
+    m.add_class::<PyArrowStream>().unwrap();
     m.add_class::<PyLazyGroupBy>().unwrap();
     m.add_class::<PyExpr>().unwrap();
     m.add_class::<PyStringCacheHolder>().unwrap();
@@ -174,6 +177,10 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::min_horizontal))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::arg_max_horizontal))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::arg_min_horizontal))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::sum_horizontal))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::mean_horizontal))
@@ -201,8 +208,14 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::concat_str))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::zip_lists))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::len)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::row_indices))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::lookup)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::cov)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::hist_2d)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::cum_fold))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::cum_reduce))
@@ -270,6 +283,14 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::using_string_cache))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::enable_result_cache))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::disable_result_cache))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::clear_result_cache))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::using_result_cache))
+        .unwrap();
 
     // Numeric formatting
     m.add_wrapped(wrap_pyfunction!(functions::get_thousands_separator))
@@ -296,6 +317,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // Functions - misc
     m.add_wrapped(wrap_pyfunction!(functions::dtype_str_repr))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::register_frequency))
+        .unwrap();
     #[cfg(feature = "object")]
     m.add_wrapped(wrap_pyfunction!(on_startup::__register_startup_deps))
         .unwrap();
@@ -303,6 +326,12 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // Functions - random
     m.add_wrapped(wrap_pyfunction!(functions::set_random_seed))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::rand_uniform))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::rand_normal))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::rand_poisson))
+        .unwrap();
 
     // Exceptions - Errors
     m.add(
@@ -310,6 +339,11 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         py.get_type_bound::<exceptions::PolarsError>(),
     )
     .unwrap();
+    m.add(
+        "CancelledError",
+        py.get_type_bound::<exceptions::CancelledError>(),
+    )
+    .unwrap();
     m.add(
         "ColumnNotFoundError",
         py.get_type_bound::<exceptions::ColumnNotFoundError>(),
@@ -410,6 +444,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // Plugins
     m.add_wrapped(wrap_pyfunction!(functions::register_plugin_function))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::register_plugin_aggregation))
+        .unwrap();
 
     Ok(())
 }
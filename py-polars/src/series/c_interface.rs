@@ -1,7 +1,9 @@
 use polars::export::arrow;
 use pyo3::ffi::Py_uintptr_t;
+use pyo3::types::{PyCapsule, PyTuple};
 
 use super::*;
+use crate::interop::arrow::capsule;
 
 // Import arrow data directly without requiring pyarrow (used in pyo3-polars)
 #[pymethods]
@@ -29,4 +31,45 @@ impl PySeries {
         let s = Series::try_from((name, chunks)).map_err(PyPolarsErr::from)?;
         Ok(s.into())
     }
+
+    /// Import an object that implements the Arrow PyCapsule interface.
+    #[staticmethod]
+    fn from_arrow_c_interface(ob: &Bound<PyAny>) -> PyResult<Self> {
+        let (field, chunks) = capsule::import(ob)?;
+        Ok(capsule::to_rust_series(&field, chunks)?.into())
+    }
+}
+
+// Export through the Arrow PyCapsule interface.
+#[pymethods]
+impl PySeries {
+    fn __arrow_c_schema__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyCapsule>> {
+        capsule::field_to_capsule(py, &self.series.field().to_arrow(false))
+    }
+
+    // The requested schema may be ignored by the producer.
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_array__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<PyObject>,
+    ) -> PyResult<Bound<'py, PyTuple>> {
+        let _ = requested_schema;
+        let s = self.series.rechunk();
+        capsule::array_to_capsules(py, &s.field().to_arrow(false), s.to_arrow(0, false))
+    }
+
+    /// Export the chunks as they are, without rechunking.
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_stream__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<PyObject>,
+    ) -> PyResult<Bound<'py, PyCapsule>> {
+        let _ = requested_schema;
+        let s = self.series.clone();
+        let field = s.field().to_arrow(false);
+        let chunks = (0..s.n_chunks()).map(move |i| Ok(s.to_arrow(i, false)));
+        capsule::stream_to_capsule(py, arrow::ffi::export_iterator(Box::new(chunks), field))
+    }
 }
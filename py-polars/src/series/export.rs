@@ -24,6 +24,10 @@ impl PySeries {
                     DataType::Int16 => PyList::new_bound(py, series.i16().unwrap()),
                     DataType::Int32 => PyList::new_bound(py, series.i32().unwrap()),
                     DataType::Int64 => PyList::new_bound(py, series.i64().unwrap()),
+                    DataType::Float16 => {
+                        let s = series.cast(&DataType::Float32).unwrap();
+                        PyList::new_bound(py, s.f32().unwrap())
+                    },
                     DataType::Float32 => PyList::new_bound(py, series.f32().unwrap()),
                     DataType::Float64 => PyList::new_bound(py, series.f64().unwrap()),
                     DataType::Categorical(_, _) | DataType::Enum(_, _) => {
@@ -105,6 +109,10 @@ impl PySeries {
                         let ca = series.binary().unwrap();
                         return Wrap(ca).to_object(py);
                     },
+                    DataType::Json => {
+                        let ca = series.json().unwrap().to_text();
+                        return Wrap(&ca).to_object(py);
+                    },
                     DataType::Null => {
                         let null: Option<u8> = None;
                         let n = series.len();
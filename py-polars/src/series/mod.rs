@@ -249,14 +249,32 @@ impl PySeries {
         Wrap(self.series.dtype().clone()).to_object(py)
     }
 
-    fn set_sorted_flag(&self, descending: bool) -> Self {
+    fn set_sorted_flag(&self, descending: bool) -> PyResult<Self> {
+        if polars_core::config::validate_sorted() {
+            self.series
+                .check_sorted(descending)
+                .map_err(PyPolarsErr::from)?;
+        }
         let mut out = self.series.clone();
         if descending {
             out.set_sorted_flag(IsSorted::Descending);
         } else {
             out.set_sorted_flag(IsSorted::Ascending)
         }
-        out.into()
+        Ok(out.into())
+    }
+
+    fn check_sorted(&self, descending: bool) -> PyResult<Self> {
+        self.series
+            .check_sorted(descending)
+            .map_err(PyPolarsErr::from)?;
+        let mut out = self.series.clone();
+        if descending {
+            out.set_sorted_flag(IsSorted::Descending);
+        } else {
+            out.set_sorted_flag(IsSorted::Ascending)
+        }
+        Ok(out.into())
     }
 
     fn n_chunks(&self) -> usize {
@@ -295,14 +313,21 @@ impl PySeries {
         }
     }
 
-    fn sort(&mut self, descending: bool, nulls_last: bool, multithreaded: bool) -> PyResult<Self> {
+    fn sort(
+        &mut self,
+        descending: bool,
+        nulls_last: bool,
+        multithreaded: bool,
+        collation: Option<Wrap<Collation>>,
+    ) -> PyResult<Self> {
         Ok(self
             .series
             .sort(
                 SortOptions::default()
                     .with_order_descending(descending)
                     .with_nulls_last(nulls_last)
-                    .with_multithreaded(multithreaded),
+                    .with_multithreaded(multithreaded)
+                    .with_collation(collation.map(|c| c.0)),
             )
             .map_err(PyPolarsErr::from)?
             .into())
@@ -741,10 +766,19 @@ impl PySeries {
             nulls_last,
             multithreaded: true,
             maintain_order: false,
+            stable: true,
+            collation: None,
         };
         Ok(self.series.is_sorted(options).map_err(PyPolarsErr::from)?)
     }
 
+    fn is_monotonic(&self, descending: bool, strict: bool) -> PyResult<bool> {
+        Ok(self
+            .series
+            .is_monotonic(descending, strict)
+            .map_err(PyPolarsErr::from)?)
+    }
+
     fn clear(&self) -> Self {
         self.series.clear().into()
     }
@@ -0,0 +1,45 @@
+"""Benchmark tests for decimal kernels, with Float64 as the baseline."""
+
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+
+pytestmark = pytest.mark.benchmark()
+
+
+@pytest.fixture(scope="module")
+def ledger() -> pl.DataFrame:
+    n = 1_000_000
+    return pl.DataFrame(
+        {
+            "account": pl.int_range(n, eager=True) % 1_000,
+            "debit": (pl.int_range(n, eager=True) * 7919 % 1_000_000) / 100,
+            "credit": (pl.int_range(n, eager=True) * 104_729 % 1_000_000) / 100,
+        }
+    )
+
+
+@pytest.fixture(params=[pl.Float64, pl.Decimal(18, 2)], ids=["f64", "decimal"])
+def typed_ledger(request: pytest.FixtureRequest, ledger: pl.DataFrame) -> pl.DataFrame:
+    return ledger.with_columns(pl.col("debit", "credit").cast(request.param))
+
+
+def test_decimal_add_sub(typed_ledger: pl.DataFrame) -> None:
+    typed_ledger.select(
+        balance=pl.col("debit") - pl.col("credit"),
+        total=pl.col("debit") + pl.col("credit"),
+    )
+
+
+def test_decimal_compare_min_max(typed_ledger: pl.DataFrame) -> None:
+    typed_ledger.select(
+        (pl.col("debit") > pl.col("credit")).sum(),
+        pl.col("debit").min(),
+        pl.col("credit").max(),
+    )
+
+
+def test_decimal_group_by_sum(typed_ledger: pl.DataFrame) -> None:
+    typed_ledger.group_by("account").agg(pl.col("debit", "credit").sum())
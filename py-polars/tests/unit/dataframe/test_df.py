@@ -539,6 +539,22 @@ def test_multiple_columns_drop() -> None:
     assert out.columns == ["a"]
 
 
+def test_rechunk_rows_per_chunk() -> None:
+    df1 = pl.DataFrame({"a": [1, 2, 3], "b": ["x", None, "z"]})
+    df2 = pl.DataFrame({"a": [4, 5, 6, 7], "b": ["w", "x", "y", "z"]})
+    df = pl.concat([df1, df2, df1])
+
+    out = df.rechunk(rows_per_chunk=4)
+    assert_frame_equal(out, df)
+    assert out.n_chunks("all") == [3, 3]
+    assert [b.num_rows for b in out.to_arrow().to_batches()] == [4, 4, 2]
+
+    assert df.rechunk(rows_per_chunk=100).n_chunks() == 1
+    assert df.clear().rechunk(rows_per_chunk=2).height == 0
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        df.rechunk(rows_per_chunk=0)
+
+
 def test_concat() -> None:
     df1 = pl.DataFrame({"a": [2, 1, 3], "b": [1, 2, 3], "c": [1, 2, 3]})
     df2 = pl.concat([df1, df1], rechunk=True)
@@ -1626,6 +1642,29 @@ def test_with_row_index_bad_offset_lazy() -> None:
         lf.with_row_index(offset=2**32)
 
 
+def test_with_row_index_over() -> None:
+    df = pl.DataFrame({"g": ["x", "y", "x", "x", "y"], "v": [1, 2, 3, 4, 5]})
+    expected = df.select(
+        pl.int_range(1, pl.len() + 1, dtype=pl.get_index_type()).over("g").alias("i"),
+        pl.all(),
+    )
+
+    assert_frame_equal(df.with_row_index("i", offset=1, over="g"), expected)
+    assert_frame_equal(
+        df.lazy().with_row_index("i", offset=1, over=cs.string()).collect(), expected
+    )
+    assert_frame_equal(
+        df.lazy().with_row_index("i", over=["g"]).filter(pl.col("v") > 2).collect(),
+        pl.DataFrame(
+            {"i": [1, 2, 1], "g": ["x", "x", "y"], "v": [3, 4, 5]},
+            schema_overrides={"i": pl.get_index_type()},
+        ),
+    )
+
+    with pytest.raises(pl.exceptions.ColumnNotFoundError):
+        df.lazy().with_row_index(over="h").collect()
+
+
 def test_with_row_count_deprecated() -> None:
     df = pl.DataFrame({"a": [1, 1, 3], "b": [1.0, 2.0, 2.0]})
 
@@ -1,15 +1,41 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+from datetime import date
 
+import pytest
 from hypothesis import given
 
+import polars as pl
 from polars.testing.parametric import dataframes
 
-if TYPE_CHECKING:
-    import polars as pl
-
 
 @given(df=dataframes())
 def test_repr(df: pl.DataFrame) -> None:
     assert isinstance(repr(df), str)
+
+
+def test_to_string_formats() -> None:
+    df = pl.DataFrame(
+        {
+            "price": [1234.5678],
+            "count": [1000000],
+            "day": [date(2024, 3, 1)],
+            "text": ["abcdefghij"],
+        }
+    )
+    out = df.to_string(
+        {
+            "price": {"float_precision": 2},
+            "count": {"thousands_separator": "."},
+            "day": {"datetime_format": "%d/%m/%Y"},
+            "text": {"max_str_len": 4},
+        }
+    )
+    assert "1234.57" in out
+    assert "1.000.000" in out
+    assert "01/03/2024" in out
+    assert "abcd…" in out
+    assert df.to_string() == str(df)
+
+    with pytest.raises(ValueError, match="format option must be one of"):
+        df.to_string({"price": {"precision": 2}})
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from datetime import datetime
+from datetime import date, datetime
 from typing import TYPE_CHECKING
 
 import pytest
@@ -216,3 +216,20 @@ def test_upsample_index_invalid(
             every="1h",
             maintain_order=maintain_order,
         )
+
+
+def test_upsample_business_month_end() -> None:
+    df = pl.DataFrame({"date": [date(2024, 1, 31), date(2024, 4, 30)], "value": [1, 2]})
+    result = df.upsample("date", every="1bme")
+    expected = pl.DataFrame(
+        {
+            "date": [
+                date(2024, 1, 31),
+                date(2024, 2, 29),
+                date(2024, 3, 29),
+                date(2024, 4, 30),
+            ],
+            "value": [1, None, None, 2],
+        }
+    )
+    assert_frame_equal(result, expected)
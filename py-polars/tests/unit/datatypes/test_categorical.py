@@ -751,6 +751,20 @@ def test_categorical_vstack_with_local_different_rev_map() -> None:
     assert df3.get_column("a").cast(pl.UInt32).to_list() == [0, 1, 2, 3, 4, 5]
 
 
+def test_categorical_concat_local_unified_rev_map() -> None:
+    dfs = [
+        pl.DataFrame({"a": pl.Series(values, dtype=pl.Categorical)})
+        for values in (["x", None, "y"], ["z", "x"], ["y", "w"])
+    ]
+    # The dictionaries are merged once up front, without remapping warnings.
+    eager = pl.concat(dfs, rechunk=False)
+    lazy = pl.concat([df.lazy() for df in dfs]).collect()
+    for out in (eager, lazy):
+        assert out["a"].to_list() == ["x", None, "y", "z", "x", "y", "w"]
+        assert out["a"].cat.get_categories().to_list() == ["x", "y", "z", "w"]
+        assert out["a"].to_physical().to_list() == [0, None, 1, 2, 0, 1, 3]
+
+
 def test_shift_over_13041() -> None:
     df = pl.DataFrame(
         {
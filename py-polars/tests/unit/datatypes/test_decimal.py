@@ -256,15 +256,19 @@ def test_decimal_arithmetic() -> None:
         pl.Decimal(precision=None, scale=2),
         pl.Decimal(precision=None, scale=6),
         pl.Decimal(precision=None, scale=2),
-        pl.Decimal(precision=None, scale=14),
+        pl.Decimal(precision=38, scale=18),
     ]
 
     assert out.to_dict(as_series=False) == {
         "out1": [D("2.0100"), D("102.9190"), D("3921.3921")],
         "out2": [D("20.20"), D("20.29"), D("139.22")],
-        "out3": [D("0.004975"), D("0.991167"), D("2.550624")],
+        "out3": [D("0.004975"), D("0.991168"), D("2.550625")],
         "out4": [D("-20.00"), D("-0.09"), D("60.80")],
-        "out5": [D("0.00497512437810"), D("0.99116781157998"), D("2.55062484060188")],
+        "out5": [
+            D("0.004975124378109453"),
+            D("0.991167811579980373"),
+            D("2.550624840601887274"),
+        ],
     }
 
 
@@ -288,15 +292,112 @@ def test_decimal_series_value_arithmetic() -> None:
     assert out1.to_list() == [D("10.1"), D("20.1"), D("110.01")]
     assert out2.to_list() == [D("10.1"), D("20.1"), D("110.01")]
     assert out3.to_list() == [D("10.1001"), D("20.1001"), D("110.0101")]
-    assert out4.to_list() == [
-        D("0.066666"),
-        D("6.733333"),
-        D("66.673333"),
-    ]  # TODO: do we want floor instead of round?
-    assert out5.to_list() == [D("0.066666"), D("6.733333"), D("66.673333")]
+    assert out4.to_list() == [D("0.066667"), D("6.733333"), D("66.673333")]
+    assert out5.to_list() == [D("0.066667"), D("6.733333"), D("66.673333")]
     assert out6.to_list() == [D("-4.9"), D("5.1"), D("95.01")]
 
 
+def test_decimal_arithmetic_precision() -> None:
+    lf = pl.LazyFrame(
+        {
+            "a": [D("1.25"), D("-3.10"), D("100.00")],
+            "b": [D("0.5"), D("3"), D("-7.1234")],
+            "i": [2, 3, 4],
+        },
+        schema={"a": pl.Decimal(5, 2), "b": pl.Decimal(10, 4), "i": pl.Int32},
+    )
+    q = lf.select(
+        add=pl.col("a") + pl.col("b"),
+        sub=pl.col("a") - pl.col("b"),
+        mul=pl.col("a") * pl.col("b"),
+        div=pl.col("a") / pl.col("b"),
+        rem=pl.col("a") % pl.col("b"),
+        mul_int=pl.col("a") * pl.col("i"),
+        mul_lit=pl.col("a") * 2,
+    )
+    expected_schema = pl.Schema(
+        {
+            "add": pl.Decimal(11, 4),
+            "sub": pl.Decimal(11, 4),
+            "mul": pl.Decimal(16, 6),
+            "div": pl.Decimal(20, 13),
+            "rem": pl.Decimal(7, 4),
+            "mul_int": pl.Decimal(16, 2),
+            "mul_lit": pl.Decimal(7, 2),
+        }
+    )
+    assert q.collect_schema() == expected_schema
+    out = q.collect()
+    assert out.schema == expected_schema
+    assert out.to_dict(as_series=False) == {
+        "add": [D("1.75"), D("-0.1"), D("92.8766")],
+        "sub": [D("0.75"), D("-6.1"), D("107.1234")],
+        "mul": [D("0.625"), D("-9.3"), D("-712.34")],
+        "div": [D("2.5"), D("-1.0333333333333"), D("-14.0382401662128")],
+        "rem": [D("0.25"), D("-0.1"), D("0.2724")],
+        "mul_int": [D("2.5"), D("-9.3"), D("400")],
+        "mul_lit": [D("2.5"), D("-6.2"), D("200")],
+    }
+
+
+def test_decimal_div_by_zero() -> None:
+    s = pl.Series([D("1.5"), D("2.5")], dtype=pl.Decimal(5, 1))
+    zero = pl.Series([D("0"), D("2")], dtype=pl.Decimal(5, 1))
+    assert (s / zero).to_list() == [None, D("1.25")]
+    assert (s % zero).to_list() == [None, D("0.5")]
+
+
+def test_decimal_arithmetic_overflow() -> None:
+    s = pl.Series([D(10**37)], dtype=pl.Decimal(38, 0))
+    with pytest.raises(pl.exceptions.ComputeError, match="decimal overflow"):
+        s * s
+
+
+def test_decimal_overflow_config() -> None:
+    lf = pl.LazyFrame({"x": [D("1.5")]}, schema={"x": pl.Decimal(20, 10)})
+    q = lf.select(pl.col("x") * pl.col("x"))
+
+    assert q.collect().dtypes == [pl.Decimal(38, 17)]
+    with pl.Config(decimal_overflow="raise"):
+        assert q.collect_schema().dtypes() == [pl.Decimal(38, 20)]
+        assert q.collect().to_series().to_list() == [D("2.25")]
+
+
+def test_decimal_rounding_config() -> None:
+    df = pl.DataFrame(
+        {"x": [D("0.0001"), D("-0.0001"), D("0.0003")], "y": [D("8")] * 3},
+        schema={"x": pl.Decimal(5, 4), "y": pl.Decimal(1, 0)},
+    )
+    q = df.select(pl.col("x") / pl.col("y"))
+    assert q.to_series().to_list() == [D("0.000013"), D("-0.000013"), D("0.000038")]
+    with pl.Config(decimal_rounding="half_even"):
+        out = df.select(pl.col("x") / pl.col("y")).to_series()
+        assert out.to_list() == [D("0.000012"), D("-0.000012"), D("0.000038")]
+
+
+def test_decimal_rescale() -> None:
+    s = pl.Series([D("1.125"), D("-2.135"), None], dtype=pl.Decimal(10, 3))
+
+    out = s.rescale(2)
+    assert out.dtype == pl.Decimal(9, 2)
+    assert out.to_list() == [D("1.13"), D("-2.14"), None]
+    assert s.rescale(2, mode="half_even").to_list() == [D("1.12"), D("-2.14"), None]
+
+    out = s.rescale(5)
+    assert out.dtype == pl.Decimal(12, 5)
+    assert out.to_list() == [D("1.125"), D("-2.135"), None]
+
+    out = pl.Series([1, -2], dtype=pl.Int8).rescale(2)
+    assert out.dtype == pl.Decimal(5, 2)
+    assert out.to_list() == [D("1"), D("-2")]
+
+    lf = pl.LazyFrame({"a": s})
+    assert lf.select(pl.col("a").rescale(1)).collect_schema()["a"] == pl.Decimal(8, 1)
+
+    with pytest.raises(pl.exceptions.ComputeError, match="decimal overflow"):
+        pl.Series([D("9.995")], dtype=pl.Decimal(4, 3)).rescale(2)
+
+
 def test_decimal_aggregations() -> None:
     df = pl.DataFrame(
         {
@@ -472,3 +573,28 @@ def test_decimal_supertype() -> None:
         pl.col("column_0").cast(pl.Decimal(scale=6)) * 1
     )
     assert q.collect().dtypes[0].is_decimal()
+
+
+def test_decimal_sum_overflow() -> None:
+    big = D(10**37 * 6)
+    df = pl.DataFrame(
+        {"g": [1, 1, 2, 2, 2, 2], "x": [big, big, big, big, -big, -big]},
+        schema_overrides={"x": pl.Decimal(38, 0)},
+    )
+
+    with pytest.raises(pl.exceptions.ComputeError, match="decimal overflow"):
+        df.group_by("g").agg(pl.col("x").sum())
+    with pytest.raises(pl.exceptions.ComputeError, match="decimal overflow"):
+        df.select(pl.col("x").sum())
+
+    # Only the total has to fit, not the partial sums.
+    out = df.filter(pl.col("g") == 2).select(pl.col("x").sum())
+    assert out.item() == D(0)
+
+    # The fast add kernel agrees with the exact one.
+    s = pl.Series([D("1.25"), None, D("-3.50")], dtype=pl.Decimal(10, 2))
+    assert (s + s).to_list() == [D("2.50"), None, D("-7.00")]
+    assert (s - D("0.25")).to_list() == [D("1.00"), None, D("-3.75")]
+    assert (s > D("0")).to_list() == [True, None, False]
+    assert s.min() == D("-3.50")
+    assert s.max() == D("1.25")
@@ -1,3 +1,5 @@
+import io
+
 import pytest
 
 import polars as pl
@@ -296,3 +298,31 @@ def test_first_last_distinct() -> None:
     assert_series_equal(
         pl.Series("x", [False, True, False, True, True, True]), s.is_last_distinct()
     )
+
+
+def test_float16() -> None:
+    s = pl.Series("a", [1.5, None, -0.25, 1e6]).cast(pl.Float16)
+    assert s.dtype == pl.Float16
+    assert s.to_list() == [1.5, None, -0.25, float("inf")]
+
+    assert (s > 0).to_list() == [True, None, False, True]
+    assert s.sort().to_list() == [None, -0.25, 1.5, float("inf")]
+    assert s.fill_null(strategy="forward").to_list() == [1.5, 1.5, -0.25, float("inf")]
+    assert s.fill_null(strategy="max").dtype == pl.Float16
+
+    # arithmetic is done in Float32
+    out = s.head(3) * 2
+    assert out.dtype == pl.Float32
+    assert out.to_list() == [3.0, None, -0.5]
+    assert pl.select(pl.lit(s) / 2).to_series().dtype == pl.Float32
+
+
+@pytest.mark.parametrize("format", ["parquet", "ipc"])
+def test_float16_roundtrip(format: str) -> None:
+    df = pl.DataFrame({"a": [0.5, None, -2.0]}, schema={"a": pl.Float16})
+    f = io.BytesIO()
+    getattr(df, f"write_{format}")(f)
+    f.seek(0)
+    out = getattr(pl, f"read_{format}")(f)
+    assert out.schema == df.schema
+    assert out["a"].to_list() == [0.5, None, -2.0]
@@ -0,0 +1,83 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_json_from_string() -> None:
+    s = pl.Series("a", ['{"b": 1,  "a": [1, 2.5]}', "[true]", None])
+    out = s.cast(pl.Json)
+    assert out.dtype == pl.Json
+    assert out.to_list() == ['{"a":[1,2.5],"b":1}', "[true]", None]
+    assert_series_equal(out.cast(pl.String), pl.Series("a", out.to_list()))
+
+    s = pl.Series("a", ['{"a": 1}', "not json"])
+    assert s.cast(pl.Json, strict=False).to_list() == ['{"a":1}', None]
+    with pytest.raises(InvalidOperationError):
+        s.cast(pl.Json)
+
+
+def test_json_series_constructor() -> None:
+    s = pl.Series("a", ["1", '{"x": "y"}'], dtype=pl.Json)
+    assert s.dtype == pl.Json
+    assert s.to_list() == ["1", '{"x":"y"}']
+
+
+def test_json_equality_is_normalized() -> None:
+    df = pl.DataFrame(
+        {"a": ['{"x": 1, "y": 2}', '{"y":2,"x":1}', "[1]"], "b": [1, 2, 3]},
+        schema_overrides={"a": pl.Json},
+    )
+    out = df.group_by("a", maintain_order=True).agg(pl.col("b").sum())
+    assert out["b"].to_list() == [3, 3]
+    assert df["a"].n_unique() == 2
+
+
+def test_json_path() -> None:
+    s = pl.Series(
+        "a",
+        ['{"a": {"b": [10, 20, 30]}, "c d": "x"}', '{"a": 1}', None],
+        dtype=pl.Json,
+    )
+    assert s.json.path("$.a.b[0]").to_list() == ["10", None, None]
+    assert s.json.path("$.a.b[-1]").to_list() == ["30", None, None]
+    assert s.json.path('$["c d"]').to_list() == ['"x"', None, None]
+    assert s.json.path("a").json.get_type().to_list() == ["object", "integer", None]
+
+    out = pl.DataFrame({"a": s}).select(pl.col("a").json.path("a.b[1]"))
+    assert out.schema == pl.Schema({"a": pl.Json})
+
+    with pytest.raises(pl.exceptions.ComputeError, match="invalid json path"):
+        s.json.path("$.a[x]")
+
+
+def test_json_get_type() -> None:
+    s = pl.Series(
+        "a", ["null", "true", "1", "1.5", '"s"', "[]", "{}", None], dtype=pl.Json
+    )
+    expected = ["null", "boolean", "integer", "float", "string", "array", "object"]
+    assert s.json.get_type().to_list() == [*expected, None]
+
+
+def test_json_decode_cast() -> None:
+    s = pl.Series("a", ['{"a": [1, 2], "b": "x"}', '{"a": null}'], dtype=pl.Json)
+    dtype = pl.Struct({"a": pl.List(pl.Int32), "b": pl.String})
+    out = s.cast(dtype)
+    assert out.dtype == dtype
+    assert out.to_list() == [{"a": [1, 2], "b": "x"}, {"a": None, "b": None}]
+
+    back = out.cast(pl.Json)
+    assert back.to_list() == ['{"a":[1,2],"b":"x"}', '{"a":null,"b":null}']
+
+
+def test_json_encode_cast() -> None:
+    df = pl.DataFrame({"a": [1, None], "b": [[1.5], []], "c": ["x", "y"]})
+    out = df.select(pl.struct(pl.all()).cast(pl.Json).alias("j"))
+    expected = pl.DataFrame(
+        {"j": ['{"a":1,"b":[1.5],"c":"x"}', '{"a":null,"b":[],"c":"y"}']},
+        schema={"j": pl.Json},
+    )
+    assert_frame_equal(out, expected)
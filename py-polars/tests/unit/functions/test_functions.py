@@ -6,7 +6,7 @@ import numpy as np
 import pytest
 
 import polars as pl
-from polars.exceptions import DuplicateError, InvalidOperationError
+from polars.exceptions import DuplicateError, InvalidOperationError, SchemaError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
@@ -102,6 +102,26 @@ def test_concat_diagonal_relaxed_with_empty_frame() -> None:
     assert_frame_equal(out, expected)
 
 
+def test_concat_diagonal_relaxed_supertypes() -> None:
+    a = pl.LazyFrame({"x": [1], "y": [None]}, schema={"x": pl.Int32, "y": pl.Null})
+    b = pl.LazyFrame({"x": [2], "z": [1.5]}, schema={"x": pl.Int64, "z": pl.Float32})
+    c = pl.LazyFrame({"x": [0.5], "y": ["s"]})
+
+    out = pl.concat([a, b, c], how="diagonal_relaxed")
+    assert out.collect_schema() == pl.Schema(
+        {"x": pl.Float64, "y": pl.String, "z": pl.Float32}
+    )
+    expected = pl.DataFrame(
+        {"x": [1.0, 2.0, 0.5], "y": [None, None, "s"], "z": [None, 1.5, None]},
+        schema={"x": pl.Float64, "y": pl.String, "z": pl.Float32},
+    )
+    assert_frame_equal(out.collect(), expected)
+
+    # Without relaxing, the dtypes of a column must be equal.
+    with pytest.raises(SchemaError, match="column 'x' has dtype i32"):
+        pl.concat([a, b], how="diagonal").collect()
+
+
 @pytest.mark.parametrize("lazy", [False, True])
 def test_concat_horizontal(lazy: bool) -> None:
     a = pl.DataFrame({"a": ["a", "b"], "b": [1, 2]})
@@ -523,3 +543,40 @@ def test_head_tail(fruits_cars: pl.DataFrame) -> None:
     res_expr = fruits_cars.select(pl.tail("A", 2))
     expected = pl.Series("A", [4, 5])
     assert_series_equal(res_expr.to_series(), expected)
+
+
+def test_lookup() -> None:
+    dim = pl.DataFrame({"id": [1, 2, 3], "label": ["a", "b", "c"]})
+    df = pl.DataFrame({"id": [3, None, 1, 4], "g": [1, 1, 2, 2]})
+
+    result = df.select(pl.lookup(dim, "id", "label"))
+    expected = pl.DataFrame({"id": ["c", None, "a", None]})
+    assert_frame_equal(result, expected)
+
+    result = df.select(pl.lookup(dim, "id", "label", default="?"))
+    expected = pl.DataFrame({"id": ["c", "?", "a", "?"]})
+    assert_frame_equal(result, expected)
+
+    # Works in window and list contexts, unlike a join.
+    result = df.select(
+        pl.lookup(dim, "id", "label").first().over("g").alias("first"),
+        pl.concat_list("id").list.eval(
+            pl.lookup(dim, pl.element(), "label", other_key="id")
+        ),
+    )
+    assert result["first"].to_list() == ["c", "c", "a", "a"]
+    assert result["id"].to_list() == [["c"], [None], ["a"], [None]]
+
+
+def test_lookup_other_key() -> None:
+    dim = pl.DataFrame({"code": ["x", "y"], "value": [1.5, 2.5]})
+    df = pl.DataFrame({"key": ["y", "x", "z"]})
+
+    result = df.select(pl.lookup(dim, "key", "value", other_key="code"))
+    assert_series_equal(result.to_series(), pl.Series("key", [2.5, 1.5, None]))
+
+
+def test_lookup_duplicate_keys() -> None:
+    dim = pl.DataFrame({"id": [1, 1], "label": ["a", "b"]})
+    with pytest.raises(InvalidOperationError, match="must be unique"):
+        pl.lookup(dim, "id", "label")
@@ -0,0 +1,69 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_random_uniform() -> None:
+    df = pl.DataFrame({"low": [0.0, 10.0, None], "high": [1.0, 20.0, 1.0]})
+    out = df.select(pl.random.uniform("low", "high", seed=0)).to_series()
+
+    assert out.dtype == pl.Float64
+    assert out.null_count() == 1
+    assert 0.0 <= out[0] < 1.0
+    assert 10.0 <= out[1] < 20.0
+
+
+def test_random_deterministic_with_seed() -> None:
+    df = pl.DataFrame({"x": range(100)})
+    expr = pl.random.normal(seed=42).alias("value")
+
+    a = df.select(expr)
+    assert_frame_equal(a, df.select(expr))
+    assert_frame_equal(a, df.lazy().select(expr).collect(streaming=True))
+    # A row's value doesn't depend on the number of rows.
+    assert_frame_equal(a.head(10), df.head(10).select(expr))
+
+    other = df.select(pl.random.normal(seed=43).alias("value"))
+    assert not a.equals(other)
+
+
+def test_random_global_seed() -> None:
+    df = pl.DataFrame({"x": range(10)})
+    expr = pl.random.uniform().alias("value")
+
+    pl.set_random_seed(0)
+    a = df.select(expr)
+    pl.set_random_seed(0)
+    assert_frame_equal(a, df.select(expr))
+
+
+def test_random_poisson() -> None:
+    df = pl.DataFrame({"lam": [0.0, 5.0, None]})
+    out = df.select(pl.random.poisson("lam", seed=1)).to_series()
+
+    assert out.dtype == pl.Int64
+    assert out[0] == 0
+    assert out[1] >= 0
+    assert out[2] is None
+
+
+def test_random_group_by() -> None:
+    df = pl.DataFrame({"g": ["a", "a", "a", "b", "b"]})
+    out = df.select(pl.random.uniform(seed=3).over("g")).to_series()
+
+    # Every group draws the same stream for a seed.
+    assert_series_equal(out[:2], out[3:], check_names=False)
+
+
+def test_random_invalid_params() -> None:
+    df = pl.DataFrame({"x": [1, 2]})
+    with pytest.raises(ComputeError, match="must not exceed"):
+        df.select(pl.random.uniform(1.0, 0.0, seed=0))
+    with pytest.raises(ComputeError, match="non-negative"):
+        df.select(pl.random.normal(std=-1.0, seed=0))
+    with pytest.raises(ComputeError, match="non-negative and finite"):
+        df.select(pl.random.poisson(float("inf"), seed=0))
@@ -1037,3 +1037,54 @@ def test_from_pandas_nan_to_null_16453(monkeypatch: pytest.MonkeyPatch) -> None:
         {"a": [None, 1.0, 2], "b": [1.0, 2.0, 3.0], "c": [4.0, 5.0, 6.0]}
     )
     assert_frame_equal(result, expected)
+
+
+def test_arrow_c_stream() -> None:
+    df = pl.concat(
+        [pl.DataFrame({"a": [1, 2], "b": ["x", None]})] * 2, rechunk=False
+    )
+    reader = pa.RecordBatchReader.from_stream(df)
+    assert [batch.num_rows for batch in reader] == [2, 2]
+    assert pa.RecordBatchReader.from_stream(df).read_all() == df.to_arrow()
+
+
+class _PyCapsuleWrapper:
+    """Exposes only the Arrow PyCapsule interface of the wrapped object."""
+
+    def __init__(self, obj: Any, *, stream: bool = True) -> None:
+        self.obj = obj
+        if stream:
+            self.__arrow_c_stream__ = obj.__arrow_c_stream__
+        else:
+            self.__arrow_c_array__ = obj.__arrow_c_array__
+
+
+def test_series_arrow_c_interface() -> None:
+    s = pl.concat([pl.Series("a", [1, None]), pl.Series("a", [3])], rechunk=False)
+    assert pa.array(s) == pa.array([1, None, 3], type=pa.int64())
+
+    out = pl.Series(_PyCapsuleWrapper(s))
+    assert_series_equal(out, s)
+    assert out.n_chunks() == 2
+
+    out = pl.Series("b", _PyCapsuleWrapper(s, stream=False), dtype=pl.Float64)
+    assert_series_equal(out, pl.Series("b", [1.0, None, 3.0]))
+    assert out.n_chunks() == 1
+
+
+def test_dataframe_from_arrow_c_stream() -> None:
+    df = pl.concat(
+        [pl.DataFrame({"a": [1, 2], "b": ["x", None]})] * 2, rechunk=False
+    )
+    assert_frame_equal(pl.DataFrame(_PyCapsuleWrapper(df)), df)
+
+    out = pl.DataFrame(
+        _PyCapsuleWrapper(df.clear()), schema_overrides={"a": pl.Int8}
+    )
+    assert out.schema == pl.Schema({"a": pl.Int8, "b": pl.String})
+
+    result = pl.LazyFrame({"a": [1, 2]}).collect_arrow_stream()
+    assert_frame_equal(pl.DataFrame(result), pl.DataFrame({"a": [1, 2]}))
+
+    with pytest.raises(TypeError, match="record batches"):
+        pl.DataFrame(_PyCapsuleWrapper(pl.Series([1])))
@@ -102,3 +102,23 @@ def test_lazy_count_s3(s3: str) -> None:
     assert "FAST COUNT(*)" in lf.explain()
     expected = pl.DataFrame({"len": [54]}, schema={"len": pl.UInt32})
     assert_frame_equal(lf.collect(), expected)
+
+
+def test_scan_s3_credential_provider(s3: str) -> None:
+    calls = 0
+
+    def credential_provider() -> tuple[dict[str, str], int | None]:
+        nonlocal calls
+        calls += 1
+        return {
+            "aws_access_key_id": "accesskey",
+            "aws_secret_access_key": "secretkey",
+        }, None
+
+    lf = pl.scan_parquet(
+        "s3://bucket/foods1.parquet",
+        storage_options={"endpoint_url": s3},
+        credential_provider=credential_provider,
+    )
+    assert lf.collect().shape == (27, 4)
+    assert calls > 0
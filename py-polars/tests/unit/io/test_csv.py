@@ -1125,6 +1125,45 @@ def test_csv_whitespace_separator_at_end_do_not_skip() -> None:
     assert result.to_dict(as_series=False) == expected
 
 
+def test_csv_multi_byte_separator(tmp_path: Path) -> None:
+    csv = 'a||b||c\n1||"x||y"||2.5\n3||||4.0\n'
+    expected = pl.DataFrame(
+        {"a": [1, 3], "b": ["x||y", None], "c": [2.5, 4.0]},
+    )
+    assert_frame_equal(pl.read_csv(csv.encode(), separator="||"), expected)
+
+    path = tmp_path / "multi.csv"
+    path.write_text(csv)
+    assert_frame_equal(pl.scan_csv(path, separator="||").collect(), expected)
+
+    with pytest.raises(ValueError, match="separator cannot be empty"):
+        pl.read_csv(csv.encode(), separator="")
+
+
+def test_csv_on_error() -> None:
+    csv = b"a,b\n1,x\nfoo,y\n3,z,extra\n4,w\n"
+    schema = {"a": pl.Int64, "b": pl.String}
+    expected = pl.DataFrame({"a": [1, 4], "b": ["x", "w"]})
+
+    with pytest.raises(ComputeError):
+        pl.read_csv(csv, schema=schema)
+
+    df = pl.read_csv(csv, schema=schema, on_error="skip")
+    assert_frame_equal(df, expected)
+
+    df, rejected = pl.read_csv(csv, schema=schema, on_error="collect")
+    assert_frame_equal(df, expected)
+    assert rejected["row"].to_list() == [1, 2]
+    assert rejected["line"].to_list() == ["foo,y", "3,z,extra"]
+    assert rejected["error"].str.starts_with("could not parse").to_list() == [
+        True,
+        False,
+    ]
+
+    with pytest.raises(ValueError, match="`on_error` must be one of"):
+        pl.read_csv(csv, on_error="ignore")  # type: ignore[call-overload]
+
+
 def test_csv_multiple_null_values() -> None:
     df = pl.DataFrame(
         {
@@ -1394,6 +1433,36 @@ def test_float_scientific() -> None:
     )
 
 
+@pytest.mark.parametrize("dtype", [pl.Float32, pl.Float64])
+def test_float_scientific_threshold(dtype: pl.Float32 | pl.Float64) -> None:
+    df = pl.Series("x", [0.00001, 0.5, 0.0, 12345.0, -2000.0], dtype=dtype).to_frame()
+
+    assert (
+        df.write_csv(float_scientific_threshold=3)
+        == "x\n1e-5\n0.5\n0\n1.2345e4\n-2e3\n"
+    )
+    assert (
+        df.write_csv(float_scientific_threshold=3, float_precision=2)
+        == "x\n1.00e-5\n0.50\n0.00\n1.23e4\n-2.00e3\n"
+    )
+    # An explicit notation takes precedence over the threshold.
+    assert df.write_csv(
+        float_scientific_threshold=3, float_scientific=False
+    ) == df.write_csv(float_scientific=False)
+
+
+def test_write_csv_many_batches() -> None:
+    df = pl.DataFrame(
+        {
+            "a": range(10_000),
+            "b": [f"s{i}" for i in range(10_000)],
+            "c": [i / 3 for i in range(10_000)],
+        }
+    )
+    csv = df.write_csv(batch_size=7)
+    assert_frame_equal(pl.read_csv(io.StringIO(csv)), df)
+
+
 def test_skip_rows_different_field_len() -> None:
     csv = io.StringIO(
         textwrap.dedent(
@@ -2157,6 +2226,39 @@ def test_csv_float_decimal() -> None:
         pl.read_csv(floats, decimal_comma=True)
 
 
+def test_csv_decimal(tmp_path: Path) -> None:
+    csv = b"a;b\n12345678901234567,89;0,1\n-0,5;0,25\n;3\n"
+    schema_overrides = {"a": pl.Decimal(20, 2), "b": pl.Decimal}
+    expected = pl.DataFrame(
+        {
+            "a": [D("12345678901234567.89"), D("-0.50"), None],
+            "b": [D("0.10"), D("0.25"), D("3.00")],
+        },
+        schema={"a": pl.Decimal(20, 2), "b": pl.Decimal(None, 2)},
+    )
+
+    df = pl.read_csv(
+        csv, separator=";", decimal_comma=True, schema_overrides=schema_overrides
+    )
+    assert_frame_equal(df, expected)
+
+    tmp_path.mkdir(exist_ok=True)
+    path = tmp_path / "decimal.csv"
+    path.write_bytes(csv)
+    lf = pl.scan_csv(
+        path, separator=";", decimal_comma=True, schema_overrides=schema_overrides
+    )
+    assert lf.collect_schema() == expected.schema
+    assert_frame_equal(lf.collect(), expected)
+
+    # values that do not fit the precision
+    csv = b"a\n1.5\n123.25\n"
+    with pytest.raises(ComputeError, match=r"could not parse `123.25`"):
+        pl.read_csv(csv, schema_overrides={"a": pl.Decimal(3, 2)})
+    df = pl.read_csv(csv, schema_overrides={"a": pl.Decimal(3, 2)}, ignore_errors=True)
+    assert df["a"].to_list() == [D("1.50"), None]
+
+
 def test_fsspec_not_available(monkeypatch: pytest.MonkeyPatch) -> None:
     monkeypatch.setattr("polars.io._utils._FSSPEC_AVAILABLE", False)
     with pytest.raises(
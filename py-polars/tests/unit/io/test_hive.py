@@ -1,13 +1,15 @@
+from __future__ import annotations
+
 import os
 import sys
 import urllib.parse
 import warnings
 from collections import OrderedDict
-from datetime import datetime
+from datetime import date, datetime
 from functools import partial
 from multiprocessing import get_context
 from pathlib import Path
-from typing import Any, Callable
+from typing import TYPE_CHECKING, Any, Callable
 
 import pyarrow.parquet as pq
 import pytest
@@ -16,6 +18,9 @@ import polars as pl
 from polars.exceptions import SchemaFieldNotFoundError
 from polars.testing import assert_frame_equal, assert_series_equal
 
+if TYPE_CHECKING:
+    from polars._typing import PolarsDataType
+
 
 def impl_test_hive_partitioned_predicate_pushdown(
     io_files_path: Path,
@@ -175,6 +180,72 @@ def test_hive_partitioned_predicate_pushdown_skips_correct_number_of_files(
     assert result.to_dict(as_series=False) == expected
 
 
+def write_single_column_partitions(root: Path, values: list[Any]) -> None:
+    for i, value in enumerate(values):
+        part = str(value).lower() if isinstance(value, bool) else value
+        path = root / f"p={part}" / "data.parquet"
+        path.parent.mkdir(parents=True)
+        pl.DataFrame({"x": [i]}).write_parquet(path)
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize(
+    ("values", "dtype", "predicate", "n_skipped"),
+    [
+        ([1, 2, 3, 4], pl.Int64, pl.col("p").is_in([1, 4]), 2),
+        ([1, 2, 3, 4], pl.Int64, pl.col("p").is_between(2, 3), 2),
+        ([1, 2, 3, 4], pl.Int64, (pl.col("p") > 1) & (pl.col("p") <= 2), 3),
+        (
+            [date(2024, 1, 1), date(2024, 2, 1), date(2024, 3, 1)],
+            pl.Date,
+            pl.col("p").is_in([date(2024, 2, 1)]),
+            2,
+        ),
+        (
+            [date(2024, 1, 1), date(2024, 2, 1), date(2024, 3, 1)],
+            pl.Date,
+            pl.col("p") >= date(2024, 2, 1),
+            1,
+        ),
+        ([False, True], pl.Boolean, pl.col("p"), 1),
+        ([False, True], pl.Boolean, pl.col("p").is_in([False]), 1),
+    ],
+)
+def test_hive_partition_pruning_typed_predicates(
+    tmp_path: Path,
+    monkeypatch: Any,
+    capfd: Any,
+    values: list[Any],
+    dtype: PolarsDataType,
+    predicate: pl.Expr,
+    n_skipped: int,
+) -> None:
+    write_single_column_partitions(tmp_path, values)
+    lf = pl.scan_parquet(tmp_path, hive_schema={"p": dtype})
+    expected = lf.collect().filter(predicate)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    capfd.readouterr()
+    assert_frame_equal(lf.filter(predicate).collect(), expected)
+    assert f"hive partitioning: skipped {n_skipped} files" in capfd.readouterr().err
+
+
+@pytest.mark.write_disk()
+def test_hive_partition_pruning_mixed_predicate(
+    tmp_path: Path, monkeypatch: Any, capfd: Any
+) -> None:
+    write_single_column_partitions(tmp_path, [1, 2, 3, 4])
+    lf = pl.scan_parquet(tmp_path, hive_schema={"p": pl.Int64})
+    # Every file may have rows with `x > 2`, so no file can be skipped.
+    predicate = (pl.col("p") == 1) | (pl.col("x") > 2)
+    expected = lf.collect().filter(predicate)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    capfd.readouterr()
+    assert_frame_equal(lf.filter(predicate).collect(), expected)
+    assert "hive partitioning: skipped" not in capfd.readouterr().err
+
+
 @pytest.mark.xdist_group("streaming")
 @pytest.mark.write_disk()
 def test_hive_partitioned_slice_pushdown(io_files_path: Path, tmp_path: Path) -> None:
@@ -8,7 +8,7 @@ import pandas as pd
 import pytest
 
 import polars as pl
-from polars.exceptions import ComputeError
+from polars.exceptions import ColumnNotFoundError, ComputeError
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
@@ -352,3 +352,26 @@ def test_ipc_raise_on_writing_mmap(tmp_path: Path) -> None:
         ComputeError, match="cannot write to file: already memory mapped"
     ):
         df.write_ipc(p)
+
+
+@pytest.mark.parametrize("compression", ["lz4", "zstd"])
+def test_ipc_uncompressed_columns(compression: IpcCompression) -> None:
+    df = pl.DataFrame(
+        {
+            "a": list(range(1000)),
+            "b": [f"value {i}" if i % 3 else None for i in range(1000)],
+            "c": [i % 2 == 0 for i in range(1000)],
+        }
+    )
+    uncompressed = df.write_ipc(
+        None, compression=compression, uncompressed_columns=["b", "c"]
+    )
+    compressed = df.write_ipc(None, compression=compression)
+    assert len(uncompressed.getvalue()) > len(compressed.getvalue())
+
+    assert_frame_equal(pl.read_ipc(uncompressed), df)
+    uncompressed.seek(0)
+    assert_frame_equal(pl.read_ipc(uncompressed, use_pyarrow=True), df)
+
+    with pytest.raises(ColumnNotFoundError):
+        df.write_ipc(None, compression=compression, uncompressed_columns=["d"])
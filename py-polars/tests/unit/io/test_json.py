@@ -4,6 +4,7 @@ import io
 import json
 import typing
 from collections import OrderedDict
+from datetime import date
 from decimal import Decimal as D
 from io import BytesIO
 from typing import TYPE_CHECKING
@@ -30,6 +31,21 @@ def test_write_json() -> None:
     assert_frame_equal(result, df)
 
 
+def test_write_json_orient() -> None:
+    df = pl.DataFrame({"a": [1, 2], "b": ["x", None]})
+    assert df.write_json(orient="columns") == '{"a":[1,2],"b":["x",null]}'
+    assert df.write_json(orient="values") == '[[1,"x"],[2,null]]'
+
+    with pytest.raises(ValueError, match="`orient` must be one of"):
+        df.write_json(orient="index")  # type: ignore[arg-type]
+
+
+def test_write_json_temporal_format() -> None:
+    df = pl.DataFrame({"d": [date(1970, 1, 2)]})
+    assert df.write_json() == '[{"d":"1970-01-02"}]'
+    assert df.write_json(temporal_format="epoch") == '[{"d":1}]'
+
+
 def test_write_json_categoricals() -> None:
     data = {"column": ["test1", "test2", "test3", "test4"]}
     df = pl.DataFrame(data).with_columns(pl.col("column").cast(pl.Categorical))
@@ -149,6 +165,32 @@ def test_read_ndjson_empty_array() -> None:
     ) == {"foo": [{"bar": []}]}
 
 
+def test_read_ndjson_decimal() -> None:
+    ndjson = (
+        '{"a": "12345678901234567.89", "b": 0.1}\n'
+        '{"a": 1, "b": 2.25}\n'
+        '{"a": null, "b": "3"}\n'
+        '{"b": -1}\n'
+    )
+    df = pl.read_ndjson(
+        io.StringIO(ndjson),
+        schema_overrides={"a": pl.Decimal(20, 2), "b": pl.Decimal},
+    )
+    expected = pl.DataFrame(
+        {
+            "a": [D("12345678901234567.89"), D("1.00"), None, None],
+            "b": [D("0.10"), D("2.25"), D("3.00"), D("-1.00")],
+        },
+        schema={"a": pl.Decimal(20, 2), "b": pl.Decimal(None, 2)},
+    )
+    assert_frame_equal(df, expected)
+
+    with pytest.raises(pl.ComputeError, match="cannot parse"):
+        pl.read_ndjson(
+            io.StringIO('{"a": "x"}\n'), schema_overrides={"a": pl.Decimal(10, 2)}
+        )
+
+
 def test_ndjson_nested_null() -> None:
     json_payload = """{"foo":{"bar":[{}]}}"""
     df = pl.read_ndjson(io.StringIO(json_payload))
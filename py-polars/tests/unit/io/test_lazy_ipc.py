@@ -110,3 +110,40 @@ def test_scan_ipc_local_with_async(
 
     captured = capfd.readouterr().err
     assert "ASYNC READING FORCED" in captured
+
+
+@pytest.mark.write_disk()
+def test_scan_ipc_statistics(monkeypatch: Any, capfd: Any, tmp_path: Path) -> None:
+    path = tmp_path / "data.ipc"
+    df = pl.concat(
+        [
+            pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", None]}),
+            pl.DataFrame({"a": [4, 5, 6], "b": ["x", "y", "z"]}),
+            pl.DataFrame({"a": [7, 8, 9], "b": ["w", "w", None]}),
+        ],
+        rechunk=False,
+    )
+    df.write_ipc(path, statistics=True)
+    assert_frame_equal(pl.read_ipc(path, use_pyarrow=True), df)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    for memory_map in [True, False]:
+        lf = pl.scan_ipc(path, memory_map=memory_map)
+        out = lf.filter(pl.col("a") > 4).collect()
+        assert_frame_equal(out, df.filter(pl.col("a") > 4))
+        assert "skipping 1 of 3 record batches" in capfd.readouterr().err
+
+        out = lf.filter(pl.col("b") == "z").collect()
+        assert_frame_equal(out, df.filter(pl.col("b") == "z"))
+        assert "skipping 2 of 3 record batches" in capfd.readouterr().err
+
+    # Appended record batches get statistics as well.
+    df.write_ipc(path, statistics=True, write_mode="append")
+    out = pl.scan_ipc(path).filter(pl.col("a") < 2).collect()
+    assert out["a"].to_list() == [1, 1]
+    assert "skipping 4 of 6 record batches" in capfd.readouterr().err
+
+    # A row index counts the rows of all record batches.
+    out = pl.scan_ipc(path, row_index_name="i").filter(pl.col("a") > 8).collect()
+    assert out["i"].to_list() == [8, 17]
+    assert "record batches" not in capfd.readouterr().err
@@ -437,3 +437,42 @@ def test_scan_deadlock_rayon_spawn_from_async_15172(
     t.join(5)
 
     assert results[0].equals(df)
+
+
+def test_scan_parquet_tail_multiple_files(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": range(10)})
+    for i in range(3):
+        df.slice(i * 4, 4).write_parquet(tmp_path / f"{i}.parquet")
+
+    lf = pl.scan_parquet(tmp_path / "*.parquet").with_row_index(offset=1)
+    expected = df.with_row_index(offset=1)
+    assert_frame_equal(lf.tail(3).collect(), expected.tail(3))
+    assert_frame_equal(lf.tail(5).collect(), expected.tail(5))
+    assert_frame_equal(
+        lf.head_tail(2), pl.concat([expected.head(2), expected.tail(2)])
+    )
+
+
+@pytest.mark.write_disk()
+def test_collect_with_scan_stats(tmp_path: Path) -> None:
+    df = pl.DataFrame({"idx": pl.arange(0, 100, eager=True)})
+    for part in range(2):
+        (tmp_path / f"part={part}").mkdir()
+        df.slice(part * 50, 50).write_parquet(
+            tmp_path / f"part={part}" / "data.parquet", row_group_size=25
+        )
+
+    q = pl.scan_parquet(tmp_path / "**/*.parquet", hive_partitioning=True).filter(
+        (pl.col("part") == 1) & (pl.col("idx") < 75)
+    )
+    result, stats = q.collect_with_scan_stats()
+
+    assert result["idx"].to_list() == list(range(50, 75))
+    assert stats.height == 1
+    row = stats.row(0, named=True)
+    assert row["files_listed"] == 2
+    assert row["files_read"] == 1
+    assert row["row_groups_read"] == 1
+    assert row["row_groups_pruned"] == 1
+    assert row["bytes_read"] > 0
+    assert row["predicate"] is not None
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import copy
 import sys
+from io import BytesIO
 from pathlib import Path
 from typing import Any, Callable, cast
 
@@ -122,3 +123,35 @@ def test_unit_io_subdir_has_no_init() -> None:
     assert not (
         io_dir / "__init__.py"
     ).exists(), "Found undesirable '__init__.py' in the 'unit.io' tests subdirectory"
+
+
+@pytest.mark.write_disk()
+def test_write_mode(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": [1, 2], "b": ["x", "y"]})
+    expected = pl.concat([df, df])
+
+    path = tmp_path / "out.csv"
+    df.write_csv(path)
+    df.write_csv(path, write_mode="append")
+    assert_frame_equal(pl.read_csv(path), expected)
+
+    path = tmp_path / "out.ndjson"
+    df.write_ndjson(path, write_mode="append")
+    df.write_ndjson(path, write_mode="append")
+    assert_frame_equal(pl.read_ndjson(path), expected)
+
+    path = tmp_path / "out.arrow"
+    df.write_ipc(path, write_mode="error_if_exists")
+    df.write_ipc(path, write_mode="append")
+    assert_frame_equal(pl.read_ipc(path, memory_map=False), expected)
+
+    path = tmp_path / "out.parquet"
+    df.write_parquet(path, write_mode="error_if_exists")
+    with pytest.raises(FileExistsError):
+        df.write_parquet(path, write_mode="error_if_exists")
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        df.write_parquet(path, write_mode="append")
+    assert_frame_equal(pl.read_parquet(path), df)
+
+    with pytest.raises(TypeError, match="requires `file` to be a path"):
+        df.write_csv(BytesIO(), write_mode="append")
@@ -796,6 +796,26 @@ def test_parquet_string_rle_encoding() -> None:
     )
 
 
+def test_parquet_large_binary_delta_length_encoding() -> None:
+    blobs = [bytes([i]) * 300_000 for i in range(5)]
+    df = pl.DataFrame(
+        {"blob": [*blobs, None, blobs[0]], "text": ["x" * 5_000] * 6 + [None]}
+    )
+    f = io.BytesIO()
+    df.write_parquet(f)
+    f.seek(0)
+
+    row_group = pq.read_metadata(f).row_group(0)
+    for i in range(2):
+        assert "DELTA_LENGTH_BYTE_ARRAY" in row_group.column(i).encodings
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    # Gathers of large values drop the buffers of the other values.
+    out = df.gather([4, 0, 0]).filter(pl.col("blob").is_not_null())
+    assert out["blob"].to_list() == [blobs[4], blobs[0], blobs[0]]
+
+
 def test_sliced_dict_with_nulls_14904() -> None:
     df = (
         pl.DataFrame({"x": [None, None]})
@@ -1230,3 +1250,26 @@ def test_parquet_record_batches_pyarrow_fixed_size_list_16614(tmp_path: Path) ->
 
     assert b["x"].shape[0] == n
     assert_frame_equal(b, x)
+
+
+@pytest.mark.parametrize("descending", [False, True])
+def test_parquet_sorting_columns_set_sorted(descending: bool) -> None:
+    values = list(range(10))
+    if descending:
+        values.reverse()
+    table = pa.table({"a": values, "b": [1, 0] * 5})
+
+    f = io.BytesIO()
+    pq.write_table(
+        table,
+        f,
+        row_group_size=4,
+        sorting_columns=[pq.SortingColumn(0, descending=descending)],
+    )
+
+    f.seek(0)
+    df = pl.read_parquet(f)
+    assert df["a"].to_list() == values
+    assert df.flags["a"]["SORTED_DESC" if descending else "SORTED_ASC"]
+    assert not df.flags["b"]["SORTED_ASC"]
+    assert not df.flags["b"]["SORTED_DESC"]
@@ -489,3 +489,35 @@ def test_scan_glob_excludes_directories(tmp_path: Path) -> None:
         pl.scan_parquet(tmp_path / "**/*").collect(), pl.concat(3 * [df])
     )
     assert_frame_equal(pl.scan_parquet(tmp_path / "*").collect(), df)
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize(
+    ("scan_func", "write_func"),
+    [
+        (pl.scan_parquet, pl.DataFrame.write_parquet),
+        (pl.scan_ipc, pl.DataFrame.write_ipc),
+    ],
+)
+def test_scan_missing_files(
+    tmp_path: Path,
+    scan_func: Callable[..., pl.LazyFrame],
+    write_func: Callable[[pl.DataFrame, Path], None],
+) -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    write_func(df, tmp_path / "0.bin")
+    write_func(df, tmp_path / "2.bin")
+    paths = [tmp_path / f"{i}.bin" for i in range(3)]
+
+    # The files are validated when the query is planned, before anything is read.
+    lf = scan_func(paths)
+    with pytest.raises(FileNotFoundError, match="1 of the 3 files"):
+        lf.collect_schema()
+
+    lf = scan_func(paths, allow_missing_files=True)
+    with pytest.warns(UserWarning, match="skipping 1 of the 3 files"):
+        out = lf.collect()
+    assert_frame_equal(out, pl.concat([df, df]))
+
+    with pytest.raises(pl.exceptions.ComputeError, match="none of the 1 files"):
+        scan_func([paths[1]], allow_missing_files=True).collect()
@@ -0,0 +1,74 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import polars as pl
+from polars.exceptions import CancelledError
+from polars.testing import assert_frame_equal
+
+
+def test_cancelled_token_raises() -> None:
+    lf = pl.LazyFrame({"a": [3, 1, 2]}).sort("a")
+    token = pl.CancellationToken()
+    token.cancel()
+    assert token.cancelled
+
+    with token, pytest.raises(CancelledError, match="query was cancelled"):
+        lf.collect()
+
+    # The token no longer applies once the context is exited.
+    assert_frame_equal(lf.collect(), pl.DataFrame({"a": [1, 2, 3]}))
+
+
+def test_cancel_during_query() -> None:
+    token = pl.CancellationToken()
+
+    def cancel(df: pl.DataFrame) -> pl.DataFrame:
+        token.cancel()
+        return df
+
+    lf = (
+        pl.LazyFrame({"a": [1, 2, 1], "b": [1, 2, 3]})
+        .map_batches(cancel)
+        .group_by("a")
+        .agg(pl.col("b").sum())
+    )
+    with token, pytest.raises(CancelledError):
+        lf.collect()
+
+
+def test_cancel_sink(tmp_path: Path) -> None:
+    token = pl.CancellationToken()
+    token.cancel()
+
+    with token, pytest.raises(CancelledError):
+        pl.LazyFrame({"a": [1, 2, 3]}).sink_parquet(tmp_path / "out.parquet")
+
+
+def test_cancel_running_sink(tmp_path: Path) -> None:
+    token = pl.CancellationToken()
+
+    def cancel(df: pl.DataFrame) -> pl.DataFrame:
+        token.cancel()
+        return df
+
+    path = tmp_path / "out.parquet"
+    lf = pl.LazyFrame({"a": [1, 2, 3]}).map_batches(cancel, streamable=True)
+    with token, pytest.raises(CancelledError):
+        lf.sink_parquet(path)
+
+    # The cancelled sink doesn't commit the file.
+    assert not path.exists()
+
+
+def test_uncancelled_token() -> None:
+    token = pl.CancellationToken()
+    lf = pl.LazyFrame({"a": [1, 2]}).join(pl.LazyFrame({"a": [2, 3]}), on="a")
+
+    with token:
+        out = lf.collect()
+
+    assert not token.cancelled
+    assert_frame_equal(out, pl.DataFrame({"a": [2]}))
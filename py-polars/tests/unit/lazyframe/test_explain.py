@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import re
+
 import pytest
 
 import polars as pl
@@ -50,3 +52,20 @@ def test_lf_explain_tree_format_deprecated() -> None:
 
     with pytest.deprecated_call():
         lf.explain(tree_format=True)
+
+
+def test_lf_explain_analyze() -> None:
+    lf = pl.LazyFrame({"a": range(10)}).filter(pl.col("a") >= 6).sort("a")
+
+    result = re.sub(r"time: \d+µs", "time: _", lf.explain_analyze())
+
+    expected = """\
+SORT BY [col("a")]  [rows: 4, time: _]
+  DF ["a"]; PROJECT */1 COLUMNS; SELECTION: [(col("a")) >= (6)]  \
+[rows: 4, selectivity: 0.40, time: _]\
+"""
+    assert result == expected
+
+    dot = lf.explain_analyze(format="dot")
+    assert dot.startswith("graph  polars_query {")
+    assert "selectivity: 0.40" in dot
@@ -0,0 +1,74 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import SchemaError
+from polars.testing import assert_frame_equal
+
+
+def test_iterate_until() -> None:
+    lf = pl.LazyFrame({"x": [1, 3]})
+    out = lf.iterate(
+        lambda lf: lf.select(pl.col("x") * 2), until=pl.col("x").min() >= 100
+    )
+    assert_frame_equal(out.collect(), pl.DataFrame({"x": [128, 384]}))
+
+    # The last state is returned once the iterations run out.
+    out = lf.iterate(
+        lambda lf: lf.select(pl.col("x") * 2), until=pl.lit(False), max_iterations=3
+    )
+    assert_frame_equal(out.collect(), pl.DataFrame({"x": [8, 24]}))
+
+    out = lf.iterate(lambda lf: lf.select(pl.col("x") * 2), max_iterations=0)
+    assert_frame_equal(out.collect(), lf.collect())
+
+
+def test_iterate_fixed_point() -> None:
+    # Propagate the smallest label through the edges of a graph.
+    edges = pl.LazyFrame({"src": [1, 2, 4, 5], "dst": [2, 3, 5, 6]})
+    nodes = pl.LazyFrame({"node": [1, 2, 3, 4, 5, 6]})
+
+    def step(labels: pl.LazyFrame) -> pl.LazyFrame:
+        forward = labels.join(edges, left_on="node", right_on="src").select(
+            node="dst", label="label"
+        )
+        backward = labels.join(edges, left_on="node", right_on="dst").select(
+            node="src", label="label"
+        )
+        return (
+            pl.concat([labels, forward, backward])
+            .group_by("node")
+            .agg(pl.col("label").min())
+            .sort("node")
+        )
+
+    out = nodes.with_columns(label=pl.col("node")).iterate(step).collect()
+    expected = pl.DataFrame(
+        {"node": [1, 2, 3, 4, 5, 6], "label": [1, 1, 1, 4, 4, 4]}
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_iterate_is_lazy() -> None:
+    lf = pl.LazyFrame({"x": [1, 2, 3]})
+    out = lf.iterate(lambda lf: lf.with_columns(pl.col("x") + 1), max_iterations=2)
+    assert_frame_equal(
+        out.filter(pl.col("x") > 3).collect(), pl.DataFrame({"x": [4, 5]})
+    )
+
+
+def test_iterate_errors() -> None:
+    lf = pl.LazyFrame({"x": [1, 2, 3]})
+    with pytest.raises(SchemaError):
+        lf.iterate(lambda lf: lf.select(pl.col("x").cast(pl.Float64)))
+
+    def fail(lf: pl.LazyFrame) -> pl.LazyFrame:
+        msg = "step failed"
+        raise RuntimeError(msg)
+
+    with pytest.raises(RuntimeError, match="step failed"):
+        lf.iterate(fail)
+
+    with pytest.raises(pl.exceptions.ComputeError, match="single value"):
+        lf.iterate(lambda lf: lf, until=pl.col("x") > 1).collect()
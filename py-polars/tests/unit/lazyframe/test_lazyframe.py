@@ -866,6 +866,19 @@ def test_tail(fruits_cars: pl.DataFrame) -> None:
     assert_frame_equal(fruits_cars.lazy().tail(2).collect(), fruits_cars[3:, :])
 
 
+def test_head_tail(fruits_cars: pl.DataFrame) -> None:
+    result = fruits_cars.lazy().head_tail(2)
+    expected = pl.concat([fruits_cars[:2, :], fruits_cars[3:, :]])
+    assert_frame_equal(result, expected)
+    assert_frame_equal(fruits_cars.lazy().head_tail(3), fruits_cars)
+
+
+def test_glimpse(fruits_cars: pl.DataFrame) -> None:
+    result = fruits_cars.lazy().glimpse(3, return_as_string=True)
+    assert result.startswith("Rows: 3\nColumns: 4\n")
+    assert "$ A      <i64> 1, 2, 3\n" in result
+
+
 def test_last(fruits_cars: pl.DataFrame) -> None:
     result = fruits_cars.lazy().last().collect()
     expected = fruits_cars[(len(fruits_cars) - 1) :, :]
@@ -1396,3 +1409,37 @@ def test_lf_properties() -> None:
         assert lf.dtypes == [pl.Int64, pl.Float64, pl.String]
     with pytest.warns(PerformanceWarning):
         assert lf.width == 3
+
+
+def test_assertions() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 2, 3], "b": [4, None, 3, 1]})
+
+    result = (
+        lf.assert_non_empty()
+        .assert_row_count(4)
+        .assert_unique(["a", "b"])
+        .assert_sorted("a")
+        .assert_sorted("b", descending=True)
+        .collect()
+    )
+    assert_frame_equal(result, lf.collect())
+
+    with pytest.raises(pl.ComputeError, match="found 2 duplicated rows"):
+        lf.assert_unique("a").collect()
+    with pytest.raises(pl.ComputeError, match="value 3 at row 2 follows 4"):
+        lf.assert_sorted("b").collect()
+    with pytest.raises(pl.ComputeError, match="expected 3 rows, got 4"):
+        lf.assert_row_count(3).collect()
+    with pytest.raises(pl.ComputeError, match="non-empty"):
+        lf.filter(pl.col("a") > 5).assert_non_empty().collect()
+    with pytest.raises(pl.ColumnNotFoundError):
+        lf.assert_sorted("c").collect()
+
+
+def test_assertions_block_predicate_pushdown() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 2]})
+    q = lf.assert_unique("a").filter(pl.col("a") == 1)
+    plan = q.explain()
+    assert plan.index("FILTER") < plan.index("ASSERT UNIQUE")
+    with pytest.raises(pl.ComputeError):
+        q.collect()
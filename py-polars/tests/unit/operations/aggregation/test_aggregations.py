@@ -113,6 +113,160 @@ def test_quantile() -> None:
     assert s.quantile(0.5, "higher") == 2
 
 
+def test_approx_quantile() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "b", "a", "b"],
+            "x": [1.0, 10.0, None, 20.0, 3.0, float("nan")],
+        }
+    )
+    # Small inputs are kept exactly, so the results match a linear interpolation.
+    assert df.select(pl.col("x").approx_quantile(0.5)).item() == 6.5
+    out = df.group_by("g", maintain_order=True).agg(pl.col("x").approx_quantile(0.25))
+    assert out.to_dict(as_series=False) == {"g": ["a", "b"], "x": [1.5, 12.5]}
+    out = df.select(pl.col("x").approx_quantile(0.5).over("g"))
+    assert out["x"].to_list() == [2.0, 15.0, 2.0, 15.0, 2.0, 15.0]
+
+    with pytest.raises(pl.exceptions.ComputeError, match="between 0.0 and 1.0"):
+        df.select(pl.col("x").approx_quantile(1.5))
+
+
+def test_quantiles() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "b", "a", "b"],
+            "x": [1, 10, None, 20, 3, 30],
+        }
+    )
+    out = df.select(pl.col("x").quantiles([0.0, 0.5, 1.0], "linear"))
+    assert out.schema == {"x": pl.Array(pl.Float64, 3)}
+    assert out["x"].to_list() == [[1.0, 10.0, 30.0]]
+
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("x").quantiles([0.75, 0.25], "lower")
+    )
+    assert out.to_dict(as_series=False) == {
+        "g": ["a", "b"],
+        "x": [[1.0, 1.0], [20.0, 10.0]],
+    }
+
+    with pytest.raises(pl.exceptions.ComputeError, match="between 0.0 and 1.0"):
+        df.select(pl.col("x").quantiles([0.5, 1.5]))
+
+
+def test_quantile_aggs_fused() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 2, 1, 2, 1, 1],
+            "x": [4.0, 1.0, None, 3.0, 2.0, 8.0],
+        }
+    )
+    q = df.lazy().group_by("g", maintain_order=True).agg(
+        pl.col("x").quantile(0.1, "linear").alias("p10"),
+        pl.col("x").max(),
+        pl.col("x").quantile(0.9, "linear").alias("p90"),
+    )
+    assert "quantiles" in q.explain()
+    out = q.collect()
+    assert out.columns == ["g", "p10", "x", "p90"]
+    assert_frame_equal(out, q.collect(simplify_expression=False))
+
+
+def test_first_by_last_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "b", "a", "c"],
+            "x": [1, 2, 3, 4, 5, 6],
+            "t": [
+                date(2024, 1, 2),
+                None,
+                date(2024, 1, 1),
+                date(2024, 1, 3),
+                None,
+                None,
+            ],
+        }
+    )
+    out = df.group_by("g", maintain_order=True).agg(
+        first=pl.col("x").first_by("t"),
+        last=pl.col("x").last_by("t"),
+        sorted_first=pl.col("x").sort_by("t", nulls_last=True).first(),
+        sorted_last=pl.col("x").sort_by("t").last(),
+    )
+    assert out.to_dict(as_series=False) == {
+        "g": ["a", "b", "c"],
+        "first": [3, 4, None],
+        "last": [1, 4, None],
+        "sorted_first": [3, 4, 6],
+        "sorted_last": [1, 4, 6],
+    }
+    assert df.select(pl.col("x").last_by("t")).item() == 4
+
+    # Ties are broken as with a stable sort.
+    df = pl.DataFrame({"x": [1, 2, 3, 4], "t": [0, 1, 0, 1]})
+    out = df.select(pl.col("x").first_by("t"), y=pl.col("x").last_by("t"))
+    assert out.row(0) == (1, 4)
+
+    with pytest.raises(InvalidOperationError, match="cannot order by dtype"):
+        df.select(pl.col("x").first_by(pl.concat_list("t")))
+
+
+def test_approx_quantile_large() -> None:
+    n = 100_000
+    df = pl.DataFrame({"g": pl.arange(0, n, eager=True) % 4, "x": np.random.rand(n)})
+    out = (
+        df.group_by("g")
+        .agg(
+            approx=pl.col("x").approx_quantile(0.9),
+            exact=pl.col("x").quantile(0.9, interpolation="linear"),
+        )
+        .select((pl.col("approx") - pl.col("exact")).abs().max())
+    )
+    assert out.item() < 0.01
+
+
+def test_sketches_merge() -> None:
+    df = pl.DataFrame(
+        {
+            "day": [1, 1, 1, 2, 2, 3],
+            "x": [1, 2, 3, 3, 4, None],
+        }
+    )
+    daily = df.group_by("day", maintain_order=True).agg(
+        n_unique=pl.col("x").approx_n_unique_sketch(),
+        quantile=pl.col("x").approx_quantile_sketch(),
+    )
+    assert daily.schema["n_unique"] == pl.Binary
+    assert daily.schema["quantile"] == pl.Binary
+
+    out = daily.select(
+        pl.col("n_unique").merge_sketches().approx_n_unique_from_sketch(),
+        pl.col("quantile").merge_sketches().approx_quantile_from_sketch(0.5),
+    )
+    # The null of day 3 counts as a unique value, but not for the quantile.
+    assert out.row(0) == (5, 3.0)
+
+    per_day = daily.select(
+        pl.col("n_unique").approx_n_unique_from_sketch(),
+        pl.col("quantile").approx_quantile_from_sketch(1.0),
+    )
+    assert per_day.rows() == [(3, 3.0), (2, 4.0), (1, None)]
+
+
+def test_sketches_invalid() -> None:
+    df = pl.DataFrame({"x": [1, 2]})
+    sketches = pl.concat(
+        [
+            df.select(pl.col("x").approx_n_unique_sketch()),
+            df.select(pl.col("x").approx_quantile_sketch()),
+        ]
+    )
+    with pytest.raises(pl.exceptions.ComputeError, match="cannot merge"):
+        sketches.select(pl.col("x").merge_sketches())
+    with pytest.raises(pl.exceptions.ComputeError, match="invalid sketch"):
+        pl.select(pl.lit(b"\x01\x01").approx_n_unique_from_sketch())
+
+
 @pytest.mark.slow()
 @pytest.mark.parametrize("tp", [int, float])
 @pytest.mark.parametrize("n", [1, 2, 10, 100])
@@ -1,6 +1,8 @@
 from __future__ import annotations
 
 import datetime
+import functools
+import operator
 from collections import OrderedDict
 from typing import TYPE_CHECKING, Any
 
@@ -123,6 +125,98 @@ def test_nested_min_max() -> None:
     assert_frame_equal(result, expected)
 
 
+def test_arg_max_min_horizontal() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, None, 3, None],
+            "b": [4.0, 2.0, 3.0, None],
+            "c": [4, 5, 0, None],
+        }
+    )
+    result = df.select(
+        arg_max=pl.arg_max_horizontal(pl.all()),
+        arg_min=pl.arg_min_horizontal(pl.all()),
+        max_name=pl.arg_max_horizontal(pl.all(), return_names=True),
+    )
+    expected = pl.DataFrame(
+        {
+            "arg_max": [1, 2, 0, None],
+            "arg_min": [0, 1, 2, None],
+            "max_name": ["b", "c", "a", None],
+        },
+        schema_overrides={"arg_max": pl.UInt32, "arg_min": pl.UInt32},
+    )
+    assert_frame_equal(result, expected)
+
+    df = pl.DataFrame({"a": ["x", "z", None], "b": ["y", "y", "w"]})
+    result = df.select(pl.arg_max_horizontal("a", "b").alias("idx")).to_series()
+    assert_series_equal(result, pl.Series("idx", [1, 0, 1], dtype=pl.UInt32))
+
+
+def test_arg_max_min_horizontal_many_columns() -> None:
+    n_columns = 200
+    df = pl.DataFrame({f"c{i}": [i, -i, i % 7] for i in range(n_columns)})
+    result = df.select(
+        max=pl.arg_max_horizontal(pl.all(), return_names=True),
+        min=pl.arg_min_horizontal(pl.all(), return_names=True),
+    )
+    expected = pl.DataFrame(
+        {"max": [f"c{n_columns - 1}", "c0", "c6"], "min": ["c0", "c199", "c0"]}
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.select(max=pl.max_horizontal(pl.all()), min=pl.min_horizontal(pl.all()))
+    expected = pl.DataFrame(
+        {"max": [n_columns - 1, 0, 6], "min": [0, 1 - n_columns, 0]}
+    )
+    assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize("first", [[True] * 36 + [None] * 4, [None] * 40])
+def test_all_any_horizontal_many_columns(first: list[bool | None]) -> None:
+    n_columns = 200
+
+    def value(r: int, i: int) -> bool | None:
+        v = (r * 7 + i * 3) % 11
+        return None if v == 0 else v > 5
+
+    data = {f"c{i}": [value(r, i) for r in range(40)] for i in range(n_columns)}
+    data["c0"] = first
+    df = pl.DataFrame(data, schema={name: pl.Boolean for name in data})
+
+    exprs = [pl.col(name) for name in data]
+    negated = [~e for e in exprs]
+    result = df.select(
+        any=pl.any_horizontal(exprs),
+        all=pl.all_horizontal(exprs),
+        not_any=pl.any_horizontal(negated),
+    )
+    expected = df.select(
+        any=functools.reduce(operator.or_, exprs),
+        all=functools.reduce(operator.and_, exprs),
+        not_any=functools.reduce(operator.or_, negated),
+    )
+    assert "horizontal" in df.lazy().select(pl.any_horizontal(exprs)).explain()
+    assert_frame_equal(result, expected)
+
+
+def test_coalesce_skips_decided_rows() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, None, 4, 5, 6, 7, 8],
+            "b": ["x", "y", "3", "z", "x", "y", "z", "x"],
+        }
+    )
+    # The cast would fail on the rows where `a` is not null.
+    result = df.select(pl.coalesce("a", pl.col("b").cast(pl.Int64), 0))
+    expected = pl.Series("a", [1, 2, 3, 4, 5, 6, 7, 8])
+    assert_series_equal(result.to_series(), expected)
+
+    result = df.select(pl.coalesce(pl.lit(None, pl.Int64), "a", 0))
+    expected = pl.Series("literal", [1, 2, 0, 4, 5, 6, 7, 8])
+    assert_series_equal(result.to_series(), expected)
+
+
 def test_empty_inputs_raise() -> None:
     with pytest.raises(
         ComputeError,
@@ -1,6 +1,7 @@
 import operator
 from collections import OrderedDict
 from datetime import date, datetime, timedelta
+from decimal import Decimal as D
 from typing import Any
 
 import numpy as np
@@ -136,6 +137,56 @@ def test_floor_division_float_int_consistency() -> None:
     )
 
 
+@pytest.mark.parametrize("dtype", INTEGER_DTYPES)
+def test_ceildiv_integer(dtype: pl.PolarsDataType) -> None:
+    df = pl.DataFrame(
+        {"a": [7, 6, 0, 1, None, 5], "b": [2, 3, 4, 0, 1, None]},
+        schema={"a": dtype, "b": dtype},
+    )
+    out = df.select(pl.col("a").ceildiv(pl.col("b")))
+    assert out.schema == pl.Schema({"a": dtype})
+    assert out["a"].to_list() == [4, 2, 0, None, None, None]
+    assert df["a"].ceildiv(2).to_list() == [4, 3, 0, 1, None, 3]
+
+
+def test_ceildiv_signed() -> None:
+    s = pl.Series([-7, -6, 7, 6])
+    assert s.ceildiv(2).to_list() == [-3, -3, 4, 3]
+    assert s.ceildiv(-2).to_list() == [4, 3, -3, -3]
+    assert (s // -2).to_list() == [3, 3, -4, -3]
+
+
+def test_ceildiv_float() -> None:
+    s = pl.Series([7.0, -7.0, 6.0])
+    assert s.ceildiv(2).to_list() == [4.0, -3.0, 3.0]
+    assert s.cast(pl.Float32).ceildiv(2.0).dtype == pl.Float32
+
+
+def test_floordiv_ceildiv_decimal() -> None:
+    dtype = pl.Decimal(10, 2)
+    df = pl.DataFrame(
+        {
+            "a": [D("7.5"), D("-7.5"), D("6.00"), None],
+            "b": [D("2"), D("2"), D("1.5"), D("1")],
+        },
+        schema={"a": dtype, "b": dtype},
+    )
+    out = df.select(
+        floor=pl.col("a") // pl.col("b"),
+        ceil=pl.col("a").ceildiv(pl.col("b")),
+    )
+    assert out.schema == pl.Schema({"floor": dtype, "ceil": dtype})
+    assert out.to_dict(as_series=False) == {
+        "floor": [D("3"), D("-4"), D("4"), None],
+        "ceil": [D("4"), D("-3"), D("4"), None],
+    }
+
+
+def test_ceildiv_unsupported() -> None:
+    with pytest.raises(InvalidOperationError, match="ceil_div not supported"):
+        pl.Series(["a"]).ceildiv(pl.Series(["b"]))
+
+
 def test_series_expr_arithm() -> None:
     s = pl.Series([1, 2, 3])
     assert (s + pl.col("a")).meta == pl.lit(s) + pl.col("a")
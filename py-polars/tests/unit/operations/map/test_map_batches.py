@@ -117,3 +117,46 @@ def test_lazy_map_schema() -> None:
     assert df.lazy().map_batches(
         custom2, validate_output_schema=False
     ).collect().to_dict(as_series=False) == {"a": ["1", "2", "3"], "b": ["a", "b", "c"]}
+
+
+def test_lazy_map_columns_used() -> None:
+    lf = pl.LazyFrame({"a": [1, 2], "b": [3, 4], "c": [5, 6]})
+
+    def udf(df: pl.DataFrame) -> pl.DataFrame:
+        assert df.columns == ["a", "b"]
+        return df.with_columns(pl.col("a") * 2)
+
+    q = lf.map_batches(
+        udf, projection_pushdown=False, columns_used=["a", "b"]
+    ).select("a")
+    assert "PROJECT 2/3 COLUMNS" in q.explain()
+    assert_frame_equal(q.collect(), pl.DataFrame({"a": [2, 4]}))
+
+
+def test_lazy_map_row_independent() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3], "b": [4, 5, 6], "c": [7, 8, 9]})
+
+    def udf(df: pl.DataFrame) -> pl.DataFrame:
+        assert df.columns == ["a"]
+        return df.select(d=pl.col("a") * 10)
+
+    q = (
+        lf.map_batches(
+            udf,
+            predicate_pushdown=False,
+            projection_pushdown=False,
+            schema={"d": pl.Int64},
+            columns_used=["a"],
+            row_independent=True,
+        )
+        .filter(pl.col("c") > 7, pl.col("d") < 30)
+        .select("c", "d")
+    )
+    plan = q.explain()
+    assert "PROJECT 2/3 COLUMNS" in plan
+    assert "SELECTION" in plan.splitlines()[-1]
+    assert q.collect_schema() == {"c": pl.Int64, "d": pl.Int64}
+    assert_frame_equal(q.collect(), pl.DataFrame({"c": [8], "d": [20]}))
+
+    with pytest.raises(ValueError, match="requires `columns_used`"):
+        lf.map_batches(udf, row_independent=True)
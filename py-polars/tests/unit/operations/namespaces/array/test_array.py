@@ -6,7 +6,7 @@ from typing import Any
 import pytest
 
 import polars as pl
-from polars.exceptions import ComputeError
+from polars.exceptions import ComputeError, InvalidOperationError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 
@@ -393,6 +393,55 @@ def test_array_count_matches(
     assert out.to_dict(as_series=False) == {"count_matches": expected}
 
 
+def test_array_explode_to_rows() -> None:
+    s = pl.Series(
+        "a",
+        [[[1, 2], [3, None]], None, [[5, 6], None]],
+        dtype=pl.Array(pl.Int64, (2, 2)),
+    )
+    out = s.arr.explode_to_rows()
+    expected = pl.Series(
+        "a", [[1, 2], [3, None], None, None, [5, 6], None], dtype=pl.Array(pl.Int64, 2)
+    )
+    assert_series_equal(out, expected)
+    assert_series_equal(
+        out.arr.explode_to_rows().head(4), pl.Series("a", [1, 2, 3, None])
+    )
+
+    # Sliced input.
+    assert_series_equal(s.slice(2).arr.explode_to_rows(), expected.slice(4))
+
+    lf = s.to_frame().lazy().select(pl.col("a").arr.explode_to_rows())
+    assert lf.collect_schema() == {"a": pl.Array(pl.Int64, 2)}
+
+
+def test_array_reshape() -> None:
+    s = pl.Series(
+        "a",
+        [[1, 2, 3, 4, 5, 6], None, [7, 8, 9, 10, 11, 12]],
+        dtype=pl.Array(pl.Int64, 6),
+    )
+    out = s.arr.reshape((3, -1))
+    assert out.dtype == pl.Array(pl.Int64, (3, 2))
+    assert out.to_list() == [
+        [[1, 2], [3, 4], [5, 6]],
+        None,
+        [[7, 8], [9, 10], [11, 12]],
+    ]
+    assert_series_equal(out.arr.reshape((-1,)), s)
+
+    lf = s.to_frame().lazy().select(pl.col("a").arr.reshape((-1, 3)).arr.reshape((6,)))
+    assert lf.collect_schema() == {"a": pl.Array(pl.Int64, 6)}
+    assert_series_equal(lf.collect().to_series(), s)
+
+    with pytest.raises(InvalidOperationError, match="cannot reshape arrays of size 6"):
+        s.arr.reshape((4, -1))
+    with pytest.raises(InvalidOperationError, match="one unknown dimension"):
+        s.arr.reshape((-1, -1))
+    with pytest.raises(InvalidOperationError, match="null sub-arrays"):
+        pl.Series([[[1], None]], dtype=pl.Array(pl.Int64, (2, 1))).arr.reshape((2,))
+
+
 def test_array_to_struct() -> None:
     df = pl.DataFrame(
         {"a": [[1, 2, 3], [4, 5, None]]}, schema={"a": pl.Array(pl.Int8, 3)}
@@ -449,3 +498,113 @@ def test_array_n_unique() -> None:
         {"n_unique": [2, 1, 1, None]}, schema={"n_unique": pl.UInt32}
     )
     assert_frame_equal(out, expected)
+
+
+def test_array_elementwise_agg() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "b", "c"],
+            "vec": [[1, 2], [3, None], [5, 6], None, None],
+        },
+        schema={"g": pl.String, "vec": pl.Array(pl.Int32, 2)},
+    )
+
+    out = df.group_by("g", maintain_order=True).agg(
+        sum=pl.col("vec").arr.sum_elementwise(),
+        mean=pl.col("vec").arr.mean_elementwise(),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": ["a", "b", "c"],
+            "sum": [[6, 8], [3, 0], None],
+            "mean": [[3.0, 4.0], [3.0, None], None],
+        },
+        schema={
+            "g": pl.String,
+            "sum": pl.Array(pl.Int32, 2),
+            "mean": pl.Array(pl.Float64, 2),
+        },
+    )
+    assert_frame_equal(out, expected)
+    assert out.schema == df.lazy().group_by("g").agg(
+        sum=pl.col("vec").arr.sum_elementwise(),
+        mean=pl.col("vec").arr.mean_elementwise(),
+    ).collect_schema()
+
+    s = pl.Series("a", [[True, False], [True, True]], dtype=pl.Array(pl.Boolean, 2))
+    assert s.arr.sum_elementwise().to_list() == [[2, 1]]
+
+    with pytest.raises(InvalidOperationError):
+        pl.Series([["x"]], dtype=pl.Array(pl.String, 1)).arr.sum_elementwise()
+
+
+def test_array_linalg() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[3.0, 4.0], [1.0, 0.0], None, [0.0, 0.0]],
+            "b": [[1.0, 2.0], [2.0, 5.0], [1.0, 1.0], [1.0, 1.0]],
+        },
+        schema={"a": pl.Array(pl.Float32, 2), "b": pl.Array(pl.Float64, 2)},
+    )
+    query = pl.lit(pl.Series([[1.0, 0.0]], dtype=pl.Array(pl.Float32, 2)))
+
+    out = df.select(
+        dot=pl.col("a").arr.dot("b"),
+        norm=pl.col("a").arr.l2_norm(),
+        similarity=pl.col("a").arr.cosine_similarity(query),
+    )
+    expected = pl.DataFrame(
+        {
+            "dot": [11.0, 2.0, None, 0.0],
+            "norm": [5.0, 1.0, None, 0.0],
+            "similarity": [0.6, 1.0, None, float("nan")],
+        },
+        schema={"dot": pl.Float64, "norm": pl.Float32, "similarity": pl.Float32},
+    )
+    assert_frame_equal(out, expected)
+    assert out.schema == df.lazy().select(
+        dot=pl.col("a").arr.dot("b"),
+        norm=pl.col("a").arr.l2_norm(),
+        similarity=pl.col("a").arr.cosine_similarity(query),
+    ).collect_schema()
+
+    with pytest.raises(pl.exceptions.ShapeError):
+        pl.Series([[1.0]], dtype=pl.Array(pl.Float64, 1)).arr.dot(df["b"])
+
+
+def test_array_matmul() -> None:
+    m = pl.Series("m", [[[1, 2], [3, 4]], None], dtype=pl.Array(pl.Int64, (2, 2)))
+    v = pl.Series("v", [[1.0, 1.0]], dtype=pl.Array(pl.Float64, 2))
+    assert m.arr.matmul(v).to_list() == [[3.0, 7.0], None]
+    assert v.arr.matmul(m).to_list() == [[4.0, 6.0], None]
+    assert m.arr.matmul(m).to_list() == [[[7.0, 10.0], [15.0, 22.0]], None]
+    assert m.arr.matmul(m).dtype == pl.Array(pl.Float64, (2, 2))
+
+    with pytest.raises(InvalidOperationError, match="arr.dot"):
+        v.arr.matmul(v)
+
+
+def test_array_numeric_arithmetic() -> None:
+    df = pl.DataFrame(
+        {"a": [[3.0, 4.0], [1.0, 0.0], None], "b": [2, 1, 1]},
+        schema={"a": pl.Array(pl.Float64, 2), "b": pl.Int64},
+    )
+    out = df.select(
+        scaled=pl.col("a") * 2,
+        shifted=1 - pl.col("a"),
+        per_row=pl.col("a") / pl.col("b"),
+        unit=pl.col("a") / pl.col("a").arr.l2_norm(),
+    )
+    expected = pl.DataFrame(
+        {
+            "scaled": [[6.0, 8.0], [2.0, 0.0], None],
+            "shifted": [[-2.0, -3.0], [0.0, 1.0], None],
+            "per_row": [[1.5, 2.0], [1.0, 0.0], None],
+            "unit": [[0.6, 0.8], [1.0, 0.0], None],
+        },
+        schema={
+            name: pl.Array(pl.Float64, 2)
+            for name in ["scaled", "shifted", "per_row", "unit"]
+        },
+    )
+    assert_frame_equal(out, expected)
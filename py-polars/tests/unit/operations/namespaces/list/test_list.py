@@ -8,8 +8,10 @@ import pytest
 import polars as pl
 from polars.exceptions import (
     ComputeError,
+    InvalidOperationError,
     OutOfBoundsError,
     SchemaError,
+    ShapeError,
     StructFieldNotFoundError,
 )
 from polars.testing import assert_frame_equal, assert_series_equal
@@ -434,6 +436,43 @@ def test_list_eval_type_coercion() -> None:
     ).to_dict(as_series=False) == {"col_last": [[3]]}
 
 
+def test_list_agg() -> None:
+    df = pl.DataFrame({"a": [[1, 2, 3, 4], [10, 2, 1], [], None, [5, 7]]})
+    out = df.select(
+        median=pl.col("a").list.agg(pl.element().median()),
+        arg_max=pl.col("a").list.agg(pl.element().arg_max()),
+        sum=pl.col("a").list.agg(pl.element().sum()),
+    )
+    expected = pl.DataFrame(
+        {
+            "median": [2.5, 2.0, None, None, 6.0],
+            "arg_max": pl.Series([3, 0, None, None, 1], dtype=pl.UInt32),
+            "sum": [10, 13, 0, None, 12],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    # The lists don't start at the first value.
+    out = df.slice(1).select(pl.col("a").list.agg(pl.element().max()))
+    assert out.to_series().to_list() == [10, None, None, 7]
+
+
+def test_list_agg_matches_eval() -> None:
+    s = pl.Series("a", [[1.0, 4.0, 2.0], [3.0], [8.0, 5.0, 6.0, 1.0]])
+    expr = pl.element().quantile(0.3, "linear")
+    assert_series_equal(
+        s.list.agg(expr), s.list.eval(expr).list.first(), check_names=False
+    )
+
+
+def test_list_agg_not_scalar() -> None:
+    df = pl.DataFrame({"a": [[1, 2], [3]]})
+    with pytest.raises(InvalidOperationError, match="single value per list"):
+        df.select(pl.col("a").list.agg(pl.element().rank()))
+    with pytest.raises(ComputeError, match="named columns are not allowed"):
+        df.select(pl.col("a").list.agg(pl.col("a").max()))
+
+
 def test_list_slice() -> None:
     df = pl.DataFrame(
         {
@@ -917,3 +956,58 @@ def test_list_eval_type_cast_11188() -> None:
     assert df.select(
         pl.col("a").list.eval(pl.element().cast(pl.String)).alias("a_str")
     ).schema == {"a_str": pl.List(pl.String)}
+
+
+def test_list_elementwise_agg() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 2, 2],
+            "vec": [[1.0, 2.0, 3.0], [3.0, 4.0, None], None, [0.5, 1.0, 1.5]],
+        }
+    )
+
+    out = df.group_by("g", maintain_order=True).agg(
+        sum=pl.col("vec").list.sum_elementwise(),
+        mean=pl.col("vec").list.mean_elementwise(),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2],
+            "sum": [[4.0, 6.0, 3.0], [0.5, 1.0, 1.5]],
+            "mean": [[2.0, 3.0, 3.0], [0.5, 1.0, 1.5]],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    with pytest.raises(ShapeError, match="equal length"):
+        pl.Series([[1, 2], [3]]).list.sum_elementwise()
+
+
+def test_zip_lists() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2], None, [3], []],
+            "b": [[4, 5], [6, 7], [8], []],
+            "c": ["x", "y", "z", "w"],
+        }
+    )
+
+    out = df.select(pl.zip_lists("a", "b"))
+    expected = pl.Series(
+        "a",
+        [[{"a": 1, "b": 4}, {"a": 2, "b": 5}], None, [{"a": 3, "b": 8}], []],
+        dtype=pl.List(pl.Struct({"a": pl.Int64, "b": pl.Int64})),
+    )
+    assert_series_equal(out.to_series(), expected)
+
+    out = df.select(
+        pl.zip_lists("a", "b").list.eval(
+            pl.element().struct.field("a") * pl.element().struct.field("b")
+        )
+    )
+    assert out.to_series().to_list() == [[4, 10], None, [24], []]
+
+    with pytest.raises(ShapeError, match="lengths 2 and 1 in row 0"):
+        pl.DataFrame({"a": [[1, 2]], "b": [[3]]}).select(pl.zip_lists("a", "b"))
+    with pytest.raises(InvalidOperationError, match="expects lists"):
+        df.select(pl.zip_lists("a", "c"))
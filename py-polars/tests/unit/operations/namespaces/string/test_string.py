@@ -689,6 +689,52 @@ def test_json_decode_primitive_to_list_11053() -> None:
     assert_frame_equal(output, expected)
 
 
+def test_json_decode_schema_mapping() -> None:
+    s = pl.Series(['{"a": 1, "b": true}', None, '{"a": 2}'])
+    out = s.str.json_decode({"a": pl.Int32, "b": pl.Boolean})
+    expected = pl.Series(
+        [{"a": 1, "b": True}, None, {"a": 2, "b": None}],
+        dtype=pl.Struct({"a": pl.Int32, "b": pl.Boolean}),
+    )
+    assert_series_equal(out, expected)
+
+
+def test_json_decode_invalid_rows() -> None:
+    s = pl.Series("json", ['{"a": 1}', '{"a": ', None, "{}", "nope"])
+
+    with pytest.raises(ComputeError, match="row 1 is not valid JSON"):
+        s.str.json_decode({"a": pl.Int64})
+
+    expected = pl.Series(
+        "json",
+        [{"a": 1}, None, None, {"a": None}, None],
+        dtype=pl.Struct({"a": pl.Int64}),
+    )
+    assert_series_equal(s.str.json_decode(strict=False), expected)
+    out = s.to_frame().select(
+        pl.col("json").str.json_decode({"a": pl.Int64}, strict=False)
+    )
+    assert_series_equal(out.to_series(), expected)
+
+
+def test_json_decode_parallel() -> None:
+    n = 100_000
+    s = pl.Series("json", [f'{{"a": {i}, "b": "{i}"}}' for i in range(n)])
+    dtype = pl.Struct({"a": pl.Int64, "b": pl.String})
+    out = s.str.json_decode(dtype)
+    assert out.struct.field("a").to_list() == list(range(n))
+    assert out.struct.field("b").to_list() == [str(i) for i in range(n)]
+
+    # The reported row is relative to the whole column, not to a part of it.
+    s = s.scatter(76_543, "{")
+    with pytest.raises(ComputeError, match="row 76543 is not valid JSON"):
+        s.str.json_decode(dtype)
+    out = s.str.json_decode(dtype, strict=False)
+    assert out.null_count() == 1
+    assert out[76_543] is None
+    assert out.struct.field("a")[76_544] == 76_544
+
+
 def test_jsonpath_single() -> None:
     s = pl.Series(['{"a":"1"}', None, '{"a":2}', '{"a":2.1}', '{"a":true}'])
     expected = pl.Series(["1", None, "2", "2.1", "true"])
@@ -776,6 +822,38 @@ def test_str_join_returns_scalar() -> None:
     assert grouped.dtype == pl.String
 
 
+def test_str_join_agg() -> None:
+    df = pl.DataFrame(
+        {
+            "id": [1, 1, 1, 2, 2, 3],
+            "val": ["b", "a", "b", None, "c", None],
+        }
+    )
+    result = df.group_by("id", maintain_order=True).agg(
+        default=pl.col("val").str.join_agg(","),
+        desc=pl.col("val").str.join_agg(sort="descending"),
+        all=pl.col("val").str.join_agg("-", distinct=False, sort=None),
+        unsorted=pl.col("val").str.join_agg("-", sort=None),
+    )
+    expected = pl.DataFrame(
+        {
+            "id": [1, 2, 3],
+            "default": ["a,b", "c", ""],
+            "desc": ["ba", "c", ""],
+            "all": ["b-a-b", "c", ""],
+            "unsorted": ["b-a", "c", ""],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    # Outside of a group by the whole column is a single group.
+    assert df.select(pl.col("val").str.join_agg()).item() == "abc"
+    assert df.select(pl.col("id").str.join_agg("+")).item() == "1+2+3"
+
+    with pytest.raises(ValueError, match="`sort` must be"):
+        pl.col("val").str.join_agg(sort="asc")  # type: ignore[arg-type]
+
+
 def test_contains() -> None:
     # test strict/non strict
     s_txt = pl.Series(["123", "456", "789"])
@@ -1521,3 +1599,57 @@ def test_extract_many() -> None:
     assert df.select(pl.col("values").str.extract_many("patterns")).to_dict(
         as_series=False
     ) == {"values": [["disco"], ["rhap", "ody"]]}
+
+
+def test_string_similarity() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["kitten", "abc", "", None, "héllo"],
+            "b": ["sitting", "acb", "", "x", "hello"],
+        }
+    )
+    result = df.select(
+        lev=pl.col("a").str.levenshtein("b"),
+        dl=pl.col("a").str.damerau_levenshtein("b"),
+        ham=pl.col("a").str.hamming("b"),
+        lev_sim=pl.col("a").str.levenshtein("b", normalized=True),
+    )
+    expected = pl.DataFrame(
+        {
+            "lev": [3, 2, 0, None, 1],
+            "dl": [3, 1, 0, None, 1],
+            "ham": [None, 2, 0, None, 1],
+            "lev_sim": [1 - 3 / 7, 1 - 2 / 3, 1.0, None, 0.8],
+        },
+        schema_overrides={"lev": pl.UInt32, "dl": pl.UInt32, "ham": pl.UInt32},
+    )
+    assert_frame_equal(result, expected)
+
+    s = pl.Series("a", ["MARTHA", "DIXON", None])
+    result_s = s.str.jaro_winkler("MARHTA")
+    assert result_s.dtype == pl.Float64
+    assert result_s[0] == pytest.approx(0.961111, abs=1e-6)
+    assert result_s[2] is None
+
+    with pytest.raises(pl.exceptions.ShapeError):
+        pl.Series(["a", "b"]).str.levenshtein(pl.Series(["a", "b", "c"]))
+
+
+def test_collated_string_functions() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2],
+            "a": ["Zitrone", "apfel", "Äpfel", "résumé", None],
+        }
+    )
+    result = df.group_by("g", maintain_order=True).agg(
+        min=pl.col("a").str.min("de"),
+        max=pl.col("a").str.max("de"),
+    )
+    expected = pl.DataFrame(
+        {"g": [1, 2], "min": ["apfel", "résumé"], "max": ["Zitrone", "résumé"]}
+    )
+    assert_frame_equal(result, expected)
+
+    result_s = df["a"].str.compare("Resume", "en-u-ks-level1")
+    assert_series_equal(result_s, pl.Series("a", [1, -1, -1, 0, None], dtype=pl.Int8))
@@ -8,7 +8,8 @@ import pytest
 from hypothesis import given
 
 import polars as pl
-from polars.testing import assert_series_equal
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
     from polars._typing import TimeUnit
@@ -92,3 +93,67 @@ def test_truncate_datetime_w_expression(time_unit: TimeUnit) -> None:
     result = df.select(pl.col("a").dt.truncate(pl.col("b")))["a"]
     assert result[0] == datetime(2020, 1, 1)
     assert result[1] == datetime(2020, 1, 3)
+
+
+def test_truncate_anchored_frequencies() -> None:
+    # 2024-03-31 is a Sunday, so the last business day of March is the 29th.
+    s = pl.Series([date(2024, 3, 28), date(2024, 3, 29), date(2024, 3, 31)])
+    assert s.dt.truncate("1bme").to_list() == [
+        date(2024, 2, 29),
+        date(2024, 3, 29),
+        date(2024, 3, 29),
+    ]
+    assert s.dt.truncate("1w_sun").to_list() == [
+        date(2024, 3, 24),
+        date(2024, 3, 24),
+        date(2024, 3, 31),
+    ]
+    assert s.dt.truncate("1qs").to_list() == [date(2024, 1, 1)] * 3
+
+
+def test_truncate_registered_frequency() -> None:
+    pl.register_frequency("fortnight", "2w")
+    s = pl.Series([datetime(2024, 1, 3, 12), datetime(2024, 1, 17)])
+    assert s.dt.truncate("1fortnight").to_list() == [
+        datetime(2023, 12, 25),
+        datetime(2024, 1, 8),
+    ]
+
+    with pytest.raises(InvalidOperationError, match="built-in unit"):
+        pl.register_frequency("mo", "30d")
+
+
+@pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
+@pytest.mark.parametrize("time_zone", [None, "Europe/Amsterdam"])
+def test_truncate_round_offset(time_unit: TimeUnit, time_zone: str | None) -> None:
+    s = pl.Series(
+        [datetime(2024, 3, 31, 0, 10), datetime(2024, 3, 31, 0, 14)],
+        dtype=pl.Datetime(time_unit, time_zone),
+    )
+    result = s.dt.truncate("15m", offset="5m").dt.replace_time_zone(None)
+    assert result.to_list() == [datetime(2024, 3, 31, 0, 5)] * 2
+    result = s.dt.round("15m", offset="5m").dt.replace_time_zone(None)
+    assert result.to_list() == [
+        datetime(2024, 3, 31, 0, 5),
+        datetime(2024, 3, 31, 0, 20),
+    ]
+
+
+def test_truncate_offset_weeks() -> None:
+    s = pl.Series([date(2024, 3, 28), date(2024, 3, 29), date(2024, 3, 31)])
+    assert_series_equal(s.dt.truncate("1w", offset="6d"), s.dt.truncate("1w_sun"))
+
+
+def test_truncate_offset_matches_group_by_dynamic() -> None:
+    df = pl.DataFrame(
+        {
+            "t": pl.datetime_range(
+                datetime(2024, 1, 1), datetime(2024, 1, 1, 2), "7m", eager=True
+            )
+        }
+    )
+    result = df.group_by_dynamic("t", every="15m", offset="5m").agg(pl.len())
+    expected = df.group_by(
+        pl.col("t").dt.truncate("15m", offset="5m"), maintain_order=True
+    ).agg(pl.len())
+    assert_frame_equal(result, expected)
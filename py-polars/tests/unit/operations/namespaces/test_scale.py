@@ -0,0 +1,80 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_scale_standard() -> None:
+    s = pl.Series("a", [1, 2, 3, 4, 5])
+    expected = (s - s.mean()) / s.std()
+    assert_series_equal(s.scale.standard(), expected)
+
+    expected = (s - s.mean()) / s.std(ddof=0)
+    assert_series_equal(s.scale.standard(ddof=0), expected)
+
+
+def test_scale_robust() -> None:
+    s = pl.Series("a", [1, 2, 3, 4, 10, None])
+    expected = pl.Series("a", [-1.0, -0.5, 0.0, 0.5, 3.5, None])
+    assert_series_equal(s.scale.robust(), expected)
+
+    assert_series_equal(s.scale.robust(0.0, 1.0), (s - 3) / 9)
+
+
+def test_scale_min_max() -> None:
+    s = pl.Series("a", [1, 2, 5])
+    assert_series_equal(s.scale.min_max(), pl.Series("a", [0.0, 0.25, 1.0]))
+    assert_series_equal(s.scale.min_max((-1, 1)), pl.Series("a", [-1.0, -0.5, 1.0]))
+
+
+def test_scale_constant() -> None:
+    s = pl.Series("a", [2, 2, 2])
+    assert_series_equal(s.scale.standard(), pl.Series("a", [0.0, 0.0, 0.0]))
+    assert_series_equal(s.scale.robust(), pl.Series("a", [0.0, 0.0, 0.0]))
+    assert_series_equal(s.scale.min_max((1, 2)), pl.Series("a", [1.0, 1.0, 1.0]))
+
+
+def test_scale_params_apply() -> None:
+    train = pl.DataFrame({"a": [1, 2, 3, 4, 10], "b": [0.0, 5.0, 10.0, 15.0, 20.0]})
+    test = pl.DataFrame({"a": [0, 5], "b": [10.0, 30.0]})
+
+    robust = train.select(pl.all().scale.robust_params())
+    assert robust.schema == {
+        "a": pl.Struct({"center": pl.Float64, "scale": pl.Float64}),
+        "b": pl.Struct({"center": pl.Float64, "scale": pl.Float64}),
+    }
+    assert robust.unnest("a").row(0) == (3.0, 2.0)
+
+    scaled = test.with_columns(pl.col(c).scale.apply(robust[c]) for c in robust.columns)
+    assert_frame_equal(scaled, pl.DataFrame({"a": [-1.5, 1.0], "b": [0.0, 2.0]}))
+
+    # Applying the parameters to the data they were fitted on equals scaling it.
+    for method in ("standard", "robust", "min_max"):
+        params = train.select(getattr(pl.all().scale, f"{method}_params")())
+        out = train.select(pl.col(c).scale.apply(params[c]) for c in train.columns)
+        assert_frame_equal(out, train.select(getattr(pl.all().scale, method)()))
+
+    restored = scaled.with_columns(
+        pl.col(c).scale.inverse(robust[c]) for c in robust.columns
+    )
+    assert_frame_equal(restored, test, check_dtypes=False)
+
+
+def test_scale_over() -> None:
+    df = pl.DataFrame({"g": [1, 1, 2, 2], "x": [1, 3, 10, 30]})
+    out = df.select(pl.col("x").scale.min_max().over("g"))
+    assert out["x"].to_list() == [0.0, 1.0, 0.0, 1.0]
+
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("x").scale.min_max_params()
+    )
+    assert out.unnest("x").rows() == [(1, 1.0, 2.0), (2, 10.0, 20.0)]
+
+
+def test_scale_invalid() -> None:
+    with pytest.raises(ValueError, match="q_low"):
+        pl.col("a").scale.robust(0.75, 0.25)
+    with pytest.raises(ValueError, match="range"):
+        pl.col("a").scale.min_max((1, 1))
@@ -0,0 +1,100 @@
+from __future__ import annotations
+
+import math
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError, ShapeError
+from polars.testing import assert_frame_equal
+
+
+def test_ttest_per_group() -> None:
+    df = pl.DataFrame(
+        {
+            "experiment": ["x", "x", "x", "x", "y", "y", "y", "y"],
+            "control": [1.0, 2.0, 3.0, 4.0, 2.0, 4.0, 6.0, 8.0],
+            "treatment": [2.0, 3.0, 4.5, 5.5, 1.0, 3.5, 4.5, 6.0],
+        }
+    )
+    out = df.group_by("experiment", maintain_order=True).agg(
+        welch=pl.col("control").stats.ttest("treatment"),
+        student=pl.col("control").stats.ttest("treatment", variant="student"),
+        paired=pl.col("control").stats.ttest("treatment", variant="paired"),
+    )
+    # `scipy.stats.ttest_ind` and `scipy.stats.ttest_rel`.
+    expected = pl.DataFrame(
+        {
+            "statistic": [-1.2371791482634837, 0.7509392614826382],
+            "p_value": [0.26372373364265833, 0.48221575492477653],
+        }
+    )
+    assert out.schema["welch"] == pl.Struct(
+        {"statistic": pl.Float64, "p_value": pl.Float64}
+    )
+    assert_frame_equal(out.select("welch").unnest("welch"), expected)
+    assert out.select("student").unnest("student")["p_value"].to_list() == (
+        pytest.approx([0.2622418540459055, 0.4810926010109279])
+    )
+    assert out.select("paired").unnest("paired").row(0) == pytest.approx(
+        (-8.660254037844387, 0.003239037076544418)
+    )
+
+
+def test_ttest_nulls() -> None:
+    df = pl.DataFrame(
+        {"a": [1.0, None, 2.0, 3.0, 4.0], "b": [2.0, 5.0, 3.0, None, 5.0]}
+    )
+    out = df.select(pl.col("a").stats.ttest("b", variant="paired")).unnest("a")
+    # The differences of the rows without nulls are all -1.
+    assert out["statistic"].item() == -math.inf
+    assert out["p_value"].item() == 0.0
+
+    out = df.select(pl.col("a").stats.ttest(pl.lit(1.0))).unnest("a")
+    assert math.isnan(out["statistic"].item())
+
+
+def test_ttest_paired_length_mismatch() -> None:
+    df = pl.DataFrame({"a": [1.0, 2.0, 3.0]})
+    with pytest.raises(ShapeError, match="same length"):
+        df.select(pl.col("a").stats.ttest(pl.col("a").head(2), variant="paired"))
+
+
+def test_ks_test() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1.0, 2.0, 3.0, 4.0, None],
+            "b": [3.0, 4.0, 5.0, 6.0, float("nan")],
+        }
+    )
+    out = df.select(pl.col("a").stats.ks_test("b")).unnest("a")
+    assert out.row(0) == pytest.approx((0.5, 0.5344157192165071))
+
+    out = df.select(pl.col("a").stats.ks_test("a")).unnest("a")
+    assert out.row(0) == (0.0, 1.0)
+
+
+def test_chi2() -> None:
+    df = pl.DataFrame(
+        {
+            "group": ["a"] * 6 + ["b"] * 4,
+            "observed": [16, 18, 16, 14, 12, 12, 18, 22, 30, 30],
+            "expected": [16, 16, 16, 16, 16, 8, 25, 25, 25, 25],
+        }
+    )
+    out = (
+        df.group_by("group", maintain_order=True)
+        .agg(pl.col("observed").stats.chi2("expected"))
+        .unnest("observed")
+    )
+    # `scipy.stats.chisquare`.
+    assert out["statistic"].to_list() == pytest.approx([3.5, 4.32])
+    assert out["p_value"].to_list() == pytest.approx(
+        [0.6233876277495822, 0.22891886433610487]
+    )
+
+
+def test_stats_non_numeric() -> None:
+    df = pl.DataFrame({"a": ["x", "y"], "b": [1, 2]})
+    with pytest.raises(InvalidOperationError, match="numeric"):
+        df.select(pl.col("a").stats.ks_test("b"))
@@ -0,0 +1,83 @@
+from __future__ import annotations
+
+from datetime import datetime, timedelta
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+VALUES = [0, 2, 1, 7, 3, 0, -4, 1, 2, 0]
+
+
+def test_lttb() -> None:
+    s = pl.Series("y", VALUES)
+    mask = s.ts.lttb(4)
+    assert mask.dtype == pl.Boolean
+    assert mask.arg_true().to_list() == [0, 3, 6, 9]
+
+    assert s.ts.lttb(5).arg_true().to_list() == [0, 1, 3, 6, 9]
+    # All points are kept if there are no more than `n_out`.
+    assert s.ts.lttb(10).all()
+
+
+def test_lttb_x() -> None:
+    start = datetime(2024, 1, 1)
+    df = pl.DataFrame(
+        {
+            "time": [start] + [start + timedelta(hours=h) for h in range(10, 19)],
+            "y": VALUES,
+        }
+    )
+    out = df.with_row_index().filter(pl.col("y").ts.lttb(5, x="time"))
+    assert out["index"].to_list() == [0, 2, 3, 6, 9]
+
+
+def test_lttb_nulls() -> None:
+    s = pl.Series("y", [0, None, 2, 1, 7, 3, 0, -4, 1, 2, float("nan"), 0])
+    assert s.ts.lttb(4).arg_true().to_list() == [0, 4, 7, 11]
+
+
+def test_min_max() -> None:
+    s = pl.Series("y", VALUES)
+    assert s.ts.min_max(4).arg_true().to_list() == [0, 3, 6, 8]
+    # An odd `n_out` is rounded down to full buckets.
+    assert s.ts.min_max(5).arg_true().to_list() == [0, 3, 6, 8]
+    assert s.ts.min_max(10).all()
+
+
+def test_downsample_over() -> None:
+    df = pl.DataFrame(
+        {
+            "id": ["a"] * 10 + ["b"] * 12,
+            "y": VALUES + [5, 4, 3, 2, 1, 0, 1, 2, 3, 4, 5, 6],
+        }
+    ).with_row_index()
+
+    out = df.filter(pl.col("y").ts.lttb(4).over("id"))
+    expected = df[[0, 3, 6, 9, 10, 15, 16, 21]]
+    assert_frame_equal(out, expected)
+
+    out = df.filter(pl.col("y").ts.min_max(4).over("id"))
+    expected = df[[0, 3, 6, 8, 10, 15, 16, 21]]
+    assert_frame_equal(out, expected)
+
+
+def test_downsample_lazy() -> None:
+    lf = pl.LazyFrame({"y": VALUES})
+    out = lf.select(pl.col("y").ts.lttb(4))
+    assert out.collect_schema() == {"y": pl.Boolean}
+    expected = pl.Series("y", [False] * 10)
+    expected[[0, 3, 6, 9]] = True
+    assert_series_equal(out.collect().to_series(), expected)
+
+
+def test_downsample_invalid() -> None:
+    s = pl.Series("y", VALUES)
+    with pytest.raises(InvalidOperationError, match="at least 3"):
+        s.ts.lttb(2)
+    with pytest.raises(InvalidOperationError, match="at least 2"):
+        s.ts.min_max(1)
+    with pytest.raises(InvalidOperationError, match="numeric or temporal"):
+        pl.Series(["a", "b"]).ts.min_max(2)
@@ -1,5 +1,7 @@
+from datetime import date
+
 import polars as pl
-from polars.testing import assert_series_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 def test_fill_null_minimal_upcast_4056() -> None:
@@ -35,3 +37,53 @@ def test_fill_null_f32_with_lit() -> None:
     # ensure the literal integer does not upcast the f32 to an f64
     df = pl.DataFrame({"a": [1.1, 1.2]}, schema=[("a", pl.Float32)])
     assert df.fill_null(value=0).dtypes == [pl.Float32]
+
+
+def test_fill_null_over_groups() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "a", "b", "a"],
+            "v": [1, 2, None, None, None, None],
+        }
+    )
+    # `pl.col("v") + 0` is filled per group, `pl.col("v")` in a single pass.
+    out = df.select(
+        pl.col("v").fill_null(strategy="forward", limit=1).over("g"),
+        (pl.col("v") + 0).fill_null(strategy="forward", limit=1).over("g").alias("w"),
+        pl.col("v").fill_null(strategy="backward").over("g").alias("b"),
+    )
+    assert out["v"].to_list() == [1, 2, 1, None, 2, None]
+    assert_series_equal(out["w"], out["v"], check_names=False)
+    assert out["b"].to_list() == [1, 2, None, None, None, None]
+
+
+def test_fill_null_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2],
+            "date": [
+                date(2024, 1, 1),
+                date(2024, 1, 2),
+                date(2024, 1, 5),
+                date(2024, 1, 2),
+                date(2024, 1, 3),
+            ],
+            "v": [1.0, None, None, None, 5.0],
+        }
+    )
+    out = df.select(
+        pl.col("v").fill_null_by("date", "2d"),
+        pl.col("v").fill_null_by("date", "2d", strategy="backward").alias("b"),
+        pl.col("v").fill_null_by("date", "2d").over("g").alias("g"),
+    )
+    expected = pl.DataFrame(
+        {
+            "v": [1.0, 1.0, None, 1.0, 5.0],
+            "b": [1.0, 5.0, 5.0, 5.0, 5.0],
+            "g": [1.0, 1.0, None, None, 5.0],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.select(pl.col("v").fill_null_by("g", "0i"))
+    assert out["v"].to_list() == [1.0, 1.0, 1.0, None, 5.0]
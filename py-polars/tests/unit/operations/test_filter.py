@@ -285,3 +285,52 @@ def test_filter_group_aware_17030() -> None:
         (group_count > 2) & (group_cum_count > 1) & (group_cum_count < group_count)
     )
     assert df.filter(filter_expr)["foo"].to_list() == ["1", "2"]
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming", "new-streaming"])
+def test_filter_adaptive_conjunct_order(
+    engine: str, monkeypatch: pytest.MonkeyPatch, capfd: pytest.CaptureFixture[str]
+) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    n = 200_000
+    df = pl.DataFrame(
+        {
+            "a": np.arange(n),
+            "b": np.arange(n) % 7,
+            "s": ["x", "yz", None, "xyz"] * (n // 4),
+        }
+    )
+    # The string conjunct is the most expensive one, the comparison of `b` removes the
+    # most rows.
+    predicate = (
+        pl.col("s").str.contains("y|z")
+        & (pl.col("a") >= 0)
+        & (pl.col("b") == 3)
+        & pl.col("s").is_not_null()
+    )
+    expected = df.filter(df.select(predicate).to_series())
+
+    q = df.lazy().filter(predicate)
+    if engine == "in-memory":
+        out = q.collect()
+    elif engine == "streaming":
+        out = q.collect(streaming=True)
+    else:
+        out = q.collect(new_streaming=True)  # type: ignore[call-arg]
+    assert_frame_equal(out, expected)
+    err = capfd.readouterr().err
+    assert "adaptive filter: evaluating the conjuncts in the order" in err
+
+
+def test_filter_adaptive_conjunct_order_nulls() -> None:
+    n = 100_000
+    df = pl.DataFrame(
+        {
+            "a": pl.Series([1, None, 3, 4] * (n // 4)),
+            "b": pl.Series([True, False, None, True] * (n // 4)),
+        }
+    )
+    predicate = (pl.col("a") > 2) & pl.col("b") & (pl.col("a") < 10)
+    out = df.lazy().filter(predicate).collect()
+    assert_frame_equal(out, df.filter(df.select(predicate).to_series()))
+    assert out.height == n // 4
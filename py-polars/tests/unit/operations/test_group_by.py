@@ -1139,3 +1139,32 @@ def test_grouped_slice_literals() -> None:
         ),  # slices a list of 1 element, so remains the same element
         x2=pl.lit(pl.Series([1, 2])).slice(-1, 1),
     ).to_dict(as_series=False) == {"literal": [True], "x": [[1, 2]], "x2": [2]}
+
+
+def test_group_by_sorted_keys_reductions() -> None:
+    nan = float("nan")
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 2, 2, 2, 3, 4],
+            "i": pl.Series([2**31 - 1, 1, 5, 6, 7, 8, 9], dtype=pl.Int32),
+            "f": [nan, 1.0, 3.0, nan, 2.0, 4.0, nan],
+        }
+    )
+    aggs = [
+        pl.col("i").sum().alias("i_sum"),
+        pl.col("i").mean().alias("i_mean"),
+        pl.col("f").min().alias("f_min"),
+        pl.col("f").max().alias("f_max"),
+        pl.col("f").mean().alias("f_mean"),
+    ]
+
+    # Sorted keys are grouped into slices, which are reduced with the segmented kernels.
+    result = df.set_sorted("g").group_by("g", maintain_order=True).agg(aggs)
+    expected = df.group_by("g", maintain_order=True).agg(aggs)
+    assert_frame_equal(result, expected)
+
+    assert result["i_sum"].to_list() == [-(2**31), 18, 8, 9]
+    assert result["i_mean"].to_list() == [2**30, 6.0, 8.0, 9.0]
+    assert result["f_min"].to_list()[:3] == [1.0, 2.0, 4.0]
+    assert result["f_max"].to_list()[:3] == [1.0, 3.0, 4.0]
+    assert result["f_min"].is_nan().to_list() == [False, False, False, True]
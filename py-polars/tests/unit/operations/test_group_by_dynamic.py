@@ -1043,3 +1043,57 @@ def test_group_by_dynamic_exclude_index_from_expansion_17075() -> None:
         "n": [0, 2, 4, 6],
         "m": [0, 2, 4, 6],
     }
+
+
+def test_resample() -> None:
+    lf = pl.LazyFrame(
+        {
+            "g": ["a", "a", "b", "b"],
+            "idx": [0, 3, 0, 1],
+            "value": [1, 2, 3, 4],
+        }
+    )
+
+    result = lf.resample(
+        "1i",
+        index_column="idx",
+        group_by="g",
+        agg=pl.col("value").sum(),
+        fill="forward",
+    ).collect()
+    expected = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "a", "b", "b"],
+            "idx": [0, 1, 2, 3, 0, 1],
+            "value": [1, 1, 1, 2, 3, 4],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    result = lf.resample(
+        "1i", index_column="idx", group_by="g", agg=pl.col("value").sum(), fill=0
+    ).collect()
+    assert result["value"].to_list() == [1, 0, 0, 2, 3, 4]
+
+
+def test_group_by_dynamic_business_month_end() -> None:
+    df = pl.DataFrame(
+        {
+            "date": [
+                date(2024, 1, 30),
+                date(2024, 1, 31),
+                date(2024, 2, 15),
+                date(2024, 3, 29),
+                date(2024, 3, 30),
+            ],
+            "value": [1, 2, 3, 4, 5],
+        }
+    )
+    result = df.group_by_dynamic("date", every="1bme").agg(pl.col("value").sum())
+    expected = pl.DataFrame(
+        {
+            "date": [date(2023, 12, 29), date(2024, 1, 31), date(2024, 3, 29)],
+            "value": [1, 5, 9],
+        }
+    )
+    assert_frame_equal(result, expected)
@@ -0,0 +1,180 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import (
+    ColumnNotFoundError,
+    DuplicateError,
+    InvalidOperationError,
+)
+from polars.testing import assert_frame_equal
+
+
+@pytest.fixture
+def lf() -> pl.LazyFrame:
+    return pl.LazyFrame(
+        {
+            "store": ["a", "a", "b", "b", "c", None],
+            "day": [1, 2, 1, 1, 2, 2],
+            "kind": ["x", "y", "x", "y", "x", "x"],
+            "sales": [10, 20, 30, 40, 50, 60],
+        }
+    )
+
+
+def reference_grouping_sets(
+    lf: pl.LazyFrame,
+    keys: list[str],
+    sets: list[list[str]],
+    agg: pl.Expr | list[pl.Expr],
+) -> pl.DataFrame:
+    schema = lf.collect_schema()
+    parts = []
+    for grouped in sets:
+        part = lf.group_by(grouped).agg(agg) if grouped else lf.select(agg)
+        gid = sum(
+            1 << (len(keys) - 1 - i) for i, k in enumerate(keys) if k not in grouped
+        )
+        part = part.select(
+            *(
+                pl.col(k) if k in grouped else pl.lit(None, dtype=schema[k]).alias(k)
+                for k in keys
+            ),
+            pl.lit(gid, dtype=pl.UInt64).alias("gid"),
+            pl.exclude(keys),
+        )
+        parts.append(part)
+    return pl.concat(parts).collect()
+
+
+def test_grouping_sets(lf: pl.LazyFrame) -> None:
+    sets = [["store", "day"], ["kind"], []]
+    agg = pl.col("sales").sum()
+    out = lf.group_by_grouping_sets(sets, agg=agg, grouping_id="gid").collect()
+    expected = reference_grouping_sets(lf, ["store", "day", "kind"], sets, agg)
+    assert_frame_equal(out, expected, check_row_order=False)
+
+
+def test_grouping_sets_rollup(lf: pl.LazyFrame) -> None:
+    agg = [pl.col("sales").sum(), pl.len()]
+    out = lf.group_by_rollup(["store", "day"], agg=agg, grouping_id="gid").collect()
+    sets = [["store", "day"], ["store"], []]
+    expected = reference_grouping_sets(lf, ["store", "day"], sets, agg)
+    assert_frame_equal(out, expected, check_row_order=False)
+
+
+def test_grouping_sets_cube(lf: pl.LazyFrame) -> None:
+    agg = pl.col("sales").mean()
+    out = lf.group_by_cube(["store", "kind"], agg=agg, grouping_id="gid").collect()
+    sets = [["store", "kind"], ["store"], ["kind"], []]
+    expected = reference_grouping_sets(lf, ["store", "kind"], sets, agg)
+    assert_frame_equal(out, expected, check_row_order=False)
+
+    # The sets come in order of their grouping id.
+    assert out["gid"].is_sorted()
+
+
+def test_grouping_sets_without_grouping_id(lf: pl.LazyFrame) -> None:
+    out = lf.group_by_rollup("store", agg=pl.col("sales").sum())
+    assert out.collect_schema() == pl.Schema({"store": pl.String, "sales": pl.Int64})
+    assert out.collect().sort("store", "sales", nulls_last=True).to_dict(
+        as_series=False
+    ) == {
+        "store": ["a", "b", "c", None, None],
+        "sales": [30, 70, 50, 60, 210],
+    }
+
+
+def test_grouping_sets_schema(lf: pl.LazyFrame) -> None:
+    q = lf.group_by_cube(["store", "day"], agg=pl.col("sales").max(), grouping_id="g")
+    assert q.collect_schema() == pl.Schema(
+        {"store": pl.String, "day": pl.Int64, "g": pl.UInt64, "sales": pl.Int64}
+    )
+    assert q.collect().schema == q.collect_schema()
+
+
+def test_grouping_sets_empty_input() -> None:
+    lf = pl.LazyFrame({"a": [], "b": []}, schema={"a": pl.Int64, "b": pl.Int64})
+    out = lf.group_by_rollup("a", agg=pl.col("b").sum(), grouping_id="gid").collect()
+    # Only the empty set has a group without any rows.
+    expected = pl.DataFrame(
+        {"a": [None], "gid": [1], "b": [0]},
+        schema={"a": pl.Int64, "gid": pl.UInt64, "b": pl.Int64},
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_grouping_sets_no_predicate_pushdown(lf: pl.LazyFrame) -> None:
+    q = lf.group_by_rollup("store", agg=pl.col("sales").sum()).filter(
+        pl.col("store").is_null()
+    )
+    assert q.collect()["sales"].sort().to_list() == [60, 210]
+
+
+def test_grouping_sets_projection_and_slice(lf: pl.LazyFrame) -> None:
+    q = lf.group_by_rollup(
+        ["store", "day"],
+        agg=[pl.col("sales").sum(), pl.col("kind").n_unique()],
+        grouping_id="gid",
+    )
+    assert q.select("sales").collect()["sales"].sum() == 3 * 210
+    assert q.select("gid").collect()["gid"].n_unique() == 3
+
+    # The empty set comes last.
+    expected = pl.DataFrame(
+        {"store": [None], "day": [None], "gid": [3], "sales": [210], "kind": [2]},
+        schema=q.collect_schema(),
+    )
+    assert_frame_equal(q.tail(1).collect(), expected)
+
+
+def test_grouping_sets_errors(lf: pl.LazyFrame) -> None:
+    agg = pl.col("sales").sum()
+    with pytest.raises(InvalidOperationError, match="at least one set"):
+        lf.group_by_grouping_sets([], agg=agg).collect()
+    with pytest.raises(InvalidOperationError, match="single column"):
+        lf.group_by_grouping_sets([[pl.all()]], agg=agg).collect()
+    with pytest.raises(DuplicateError):
+        lf.group_by_rollup("store", agg=agg, grouping_id="sales").collect()
+    with pytest.raises(DuplicateError):
+        lf.group_by_rollup("store", agg=agg, grouping_id="store").collect()
+    with pytest.raises(ColumnNotFoundError):
+        lf.group_by_rollup("store", agg=pl.col("nope").sum()).collect()
+
+
+def test_grouping_sets_explain(lf: pl.LazyFrame) -> None:
+    plan = lf.group_by_rollup(["store", "day"], agg=pl.col("sales").sum()).explain()
+    assert "GROUPING SETS [[0, 1], [0], []]" in plan
+
+
+def test_group_by_hierarchy(lf: pl.LazyFrame) -> None:
+    agg = [pl.col("sales").sum(), pl.col("sales").first().alias("first"), pl.len()]
+    out = lf.group_by_hierarchy(["store", "day", "kind"], agg=agg).collect()
+    expected = lf.group_by_rollup(
+        ["store", "day", "kind"], agg=agg, grouping_id="level"
+    ).with_columns(
+        pl.col("level").replace_strict({0: 3, 1: 2, 3: 1, 7: 0}, return_dtype=pl.UInt32)
+    )
+    assert_frame_equal(out, expected.collect(), check_row_order=False)
+
+    # The groups of every level keep the order of the rows.
+    subtotals = out.filter(pl.col("level") == 1).sort("store", nulls_last=True)
+    assert subtotals["first"].to_list() == [10, 30, 50, 60]
+
+
+def test_group_by_hierarchy_eager(lf: pl.LazyFrame) -> None:
+    df = lf.collect()
+    agg = pl.col("sales").sum()
+    out = df.group_by_hierarchy(["store", "day"], agg=agg, level="lvl")
+    assert out.schema == pl.Schema(
+        {"store": pl.String, "day": pl.Int64, "lvl": pl.UInt32, "sales": pl.Int64}
+    )
+    assert out.group_by("lvl").agg(pl.col("sales").sum()).sort("lvl").to_dict(
+        as_series=False
+    ) == {"lvl": [0, 1, 2], "sales": [210, 210, 210]}
+
+
+def test_group_by_hierarchy_duplicate_level(lf: pl.LazyFrame) -> None:
+    with pytest.raises(DuplicateError):
+        lf.group_by_hierarchy("store", agg=pl.len(), level="store").collect()
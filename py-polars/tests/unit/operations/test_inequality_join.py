@@ -0,0 +1,190 @@
+from __future__ import annotations
+
+from typing import Any, Callable
+
+import numpy as np
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+OPERATORS: list[Callable[[pl.Expr, pl.Expr], pl.Expr]] = [
+    lambda a, b: a < b,
+    lambda a, b: a <= b,
+    lambda a, b: a > b,
+    lambda a, b: a >= b,
+]
+
+
+def reference_join_where(
+    left: pl.DataFrame, right: pl.DataFrame, *predicates: pl.Expr
+) -> pl.DataFrame:
+    return left.join(right, how="cross").filter(*predicates)
+
+
+@pytest.fixture
+def frames() -> tuple[pl.DataFrame, pl.DataFrame]:
+    rng = np.random.default_rng(0)
+
+    def values(n: int) -> list[Any]:
+        out: list[Any] = rng.integers(0, 10, n).tolist()
+        for i in rng.choice(n, n // 10, replace=False):
+            out[i] = None
+        return out
+
+    left = pl.DataFrame({"id": range(50), "a": values(50), "b": values(50)})
+    right = pl.DataFrame({"id": range(40), "x": values(40), "y": values(40)})
+    return left, right
+
+
+@pytest.mark.parametrize("op1", OPERATORS)
+def test_join_where_single_inequality(
+    frames: tuple[pl.DataFrame, pl.DataFrame],
+    op1: Callable[[pl.Expr, pl.Expr], pl.Expr],
+) -> None:
+    left, right = frames
+    predicate = op1(pl.col("a"), pl.col("x"))
+    assert_frame_equal(
+        left.join_where(right, predicate),
+        reference_join_where(left, right, predicate),
+        check_row_order=False,
+    )
+
+
+@pytest.mark.parametrize("op1", OPERATORS)
+@pytest.mark.parametrize("op2", OPERATORS)
+def test_join_where_two_inequalities(
+    frames: tuple[pl.DataFrame, pl.DataFrame],
+    op1: Callable[[pl.Expr, pl.Expr], pl.Expr],
+    op2: Callable[[pl.Expr, pl.Expr], pl.Expr],
+) -> None:
+    left, right = frames
+    predicates = [op1(pl.col("a"), pl.col("x")), op2(pl.col("b"), pl.col("y"))]
+    assert_frame_equal(
+        left.join_where(right, *predicates),
+        reference_join_where(left, right, *predicates),
+        check_row_order=False,
+    )
+
+
+def test_join_where_right_column_first(
+    frames: tuple[pl.DataFrame, pl.DataFrame],
+) -> None:
+    left, right = frames
+    result = left.join_where(
+        right, pl.col("x") > pl.col("a"), pl.col("b") >= pl.col("y")
+    )
+    expected = reference_join_where(
+        left, right, pl.col("a") < pl.col("x"), pl.col("b") >= pl.col("y")
+    )
+    assert_frame_equal(result, expected, check_row_order=False)
+
+
+def test_join_where_suffixed_column() -> None:
+    left = pl.DataFrame({"a": [1, 2, 3], "b": [10, 20, 30]})
+    right = pl.DataFrame({"a": [2, 3], "c": ["x", "y"]})
+
+    result = left.join_where(right, pl.col("a") < pl.col("a_other"), suffix="_other")
+    expected = pl.DataFrame(
+        {
+            "a": [1, 1, 2],
+            "b": [10, 10, 20],
+            "a_other": [2, 3, 3],
+            "c": ["x", "y", "y"],
+        }
+    )
+    assert_frame_equal(result, expected, check_row_order=False)
+
+
+def test_join_where_remaining_predicates(
+    frames: tuple[pl.DataFrame, pl.DataFrame],
+) -> None:
+    left, right = frames
+    predicates = [
+        pl.col("a") <= pl.col("x"),
+        pl.col("b") > pl.col("y"),
+        pl.col("a") < pl.col("y"),
+        pl.col("id") != pl.col("id_right"),
+    ]
+    assert_frame_equal(
+        left.join_where(right, *predicates),
+        reference_join_where(left, right, *predicates),
+        check_row_order=False,
+    )
+
+    # Conjunctions are split, so this is the same join.
+    combined = predicates[0] & predicates[1] & predicates[2] & predicates[3]
+    assert_frame_equal(
+        left.join_where(right, combined),
+        reference_join_where(left, right, *predicates),
+        check_row_order=False,
+    )
+
+
+def test_join_where_without_inequality(
+    frames: tuple[pl.DataFrame, pl.DataFrame],
+) -> None:
+    left, right = frames
+    predicate = (pl.col("a") + pl.col("x")) == 7
+    assert_frame_equal(
+        left.join_where(right, predicate),
+        reference_join_where(left, right, predicate),
+        check_row_order=False,
+    )
+
+
+def test_join_where_supertype() -> None:
+    left = pl.DataFrame({"a": [1, 2, 3]}, schema={"a": pl.Int32})
+    right = pl.DataFrame({"x": [1.5, 2.5]})
+
+    result = left.join_where(right, pl.col("a") > pl.col("x"))
+    expected = pl.DataFrame(
+        {"a": [2, 3, 3], "x": [1.5, 1.5, 2.5]},
+        schema={"a": pl.Int32, "x": pl.Float64},
+    )
+    assert_frame_equal(result, expected, check_row_order=False)
+
+
+def test_join_where_temporal() -> None:
+    df = pl.DataFrame(
+        {
+            "id": [1, 2, 3],
+            "start": pl.date_range(
+                pl.date(2024, 1, 1), pl.date(2024, 1, 3), eager=True
+            ),
+        }
+    )
+    events = pl.DataFrame(
+        {"at": pl.date_range(pl.date(2024, 1, 1), pl.date(2024, 1, 4), eager=True)}
+    )
+    predicates = [
+        pl.col("start") <= pl.col("at"),
+        pl.col("start") > pl.col("at") - pl.duration(days=2),
+    ]
+    assert_frame_equal(
+        df.join_where(events, *predicates),
+        reference_join_where(df, events, *predicates),
+        check_row_order=False,
+    )
+
+
+def test_join_where_lazy_slice(frames: tuple[pl.DataFrame, pl.DataFrame]) -> None:
+    left, right = frames
+    predicate = pl.col("a") < pl.col("x")
+    result = left.lazy().join_where(right.lazy(), predicate).head(10).collect()
+    assert result.height == 10
+    expected = reference_join_where(left, right, predicate)
+    assert result.join(
+        expected, on=result.columns, how="anti", join_nulls=True
+    ).is_empty()
+
+
+def test_join_where_invalid() -> None:
+    df = pl.DataFrame({"a": [1]})
+    with pytest.raises(TypeError, match="expected `other` join table"):
+        df.join_where(
+            df.lazy(),  # type: ignore[arg-type]
+            pl.col("a") < pl.col("a_right"),
+        )
+    with pytest.raises(ValueError, match="at least one predicate"):
+        df.join_where(df)
@@ -407,3 +407,120 @@ def test_sorted_flag_group_by_dynamic() -> None:
 
 def test_is_sorted_rle_id() -> None:
     assert pl.Series([12, 3345, 12, 3, 4, 4, 1, 12]).rle_id().flags["SORTED_ASC"]
+
+
+@pytest.mark.parametrize(
+    ("values", "increasing", "strictly_increasing", "decreasing"),
+    [
+        ([], True, True, True),
+        ([None, None], True, True, True),
+        ([1, 2, 2, 5], True, False, False),
+        ([1, None, 2, None, 3], True, True, False),
+        ([3, 2, None, 2, 1], False, False, True),
+        ([1, 3, 2], False, False, False),
+    ],
+)
+def test_is_monotonic(
+    values: list[Any],
+    increasing: bool,
+    strictly_increasing: bool,
+    decreasing: bool,
+) -> None:
+    s = pl.Series("a", values, dtype=pl.Int64)
+    assert s.is_monotonic_increasing() is increasing
+    assert s.is_monotonic_increasing(strict=True) is strictly_increasing
+    assert s.is_monotonic_decreasing() is decreasing
+
+    out = s.to_frame().select(
+        pl.col("a").is_monotonic_increasing().alias("inc"),
+        pl.col("a").is_monotonic_increasing(strict=True).alias("strict_inc"),
+        pl.col("a").is_monotonic_decreasing().alias("dec"),
+    )
+    assert out.row(0) == (increasing, strictly_increasing, decreasing)
+
+
+def test_is_monotonic_large() -> None:
+    # Spans several threads, so out of order values at the part boundaries count.
+    s = pl.Series("a", np.arange(1_000_000))
+    assert s.is_monotonic_increasing(strict=True)
+    assert not s.is_monotonic_decreasing()
+    for i in [1, 250_000, 500_000, 999_999]:
+        t = s.clone().scatter(i, s[i - 1])
+        assert t.is_monotonic_increasing()
+        assert not t.is_monotonic_increasing(strict=True)
+        t = s.clone().scatter(i, s[i - 1] - 1)
+        assert not t.is_monotonic_increasing()
+
+
+def test_is_monotonic_uses_sorted_flag() -> None:
+    # The flag is trusted for non-strict checks only.
+    s = pl.Series("a", [1, 3, 2]).set_sorted()
+    assert s.is_monotonic_increasing()
+    assert not s.is_monotonic_increasing(strict=True)
+
+
+def test_is_monotonic_group_by() -> None:
+    df = pl.DataFrame({"g": [1, 1, 2, 2, 2], "a": [1, 2, 3, 1, 2]})
+    out = (
+        df.group_by("g", maintain_order=True)
+        .agg(pl.col("a").is_monotonic_increasing())
+        .to_dict(as_series=False)
+    )
+    assert out == {"g": [1, 2], "a": [True, False]}
+
+
+def test_is_monotonic_other_dtypes() -> None:
+    assert pl.Series(["a", "b", "b"]).is_monotonic_increasing()
+    assert pl.Series([date(2020, 1, 2), date(2020, 1, 1)]).is_monotonic_decreasing()
+    s = pl.Series([{"x": 1, "y": 2}, {"x": 1, "y": 3}, {"x": 2, "y": 0}])
+    assert s.is_monotonic_increasing(strict=True)
+    assert not s.is_monotonic_decreasing()
+
+
+@pytest.mark.parametrize(
+    ("values", "descending"),
+    [
+        ([1, 2, 2, 3], False),
+        ([None, 1, 2], False),
+        ([1, 2, None, None], False),
+        ([3, 1, None], True),
+        ([None, None], False),
+    ],
+)
+def test_check_sorted(values: list[Any], descending: bool) -> None:
+    s = pl.Series("a", values, dtype=pl.Int64)
+    out = s.check_sorted(descending=descending)
+    assert_series_equal(out, s)
+    assert out.flags["SORTED_DESC" if descending else "SORTED_ASC"]
+
+    out = s.to_frame().select(pl.col("a").check_sorted(descending=descending))
+    assert out["a"].flags["SORTED_DESC" if descending else "SORTED_ASC"]
+
+
+def test_check_sorted_raises() -> None:
+    s = pl.Series("a", [1, 3, 2])
+    with pytest.raises(pl.ComputeError, match="value 2 at index 2 follows 3"):
+        s.check_sorted()
+    with pytest.raises(pl.ComputeError, match="not sorted in descending order"):
+        s.to_frame().select(pl.col("a").check_sorted(descending=True))
+    with pytest.raises(pl.ComputeError, match="nulls are not all at the start"):
+        pl.Series("a", [1, None, 2]).check_sorted()
+
+    # The index refers to the original series, including leading nulls.
+    with pytest.raises(pl.ComputeError, match="value 0 at index 3 follows 5"):
+        pl.Series("a", [None, None, 5, 0]).check_sorted()
+
+
+def test_set_sorted_validate() -> None:
+    s = pl.Series("a", [3, 1, 2])
+    df = s.to_frame()
+    assert s.set_sorted().flags["SORTED_ASC"]
+
+    with pl.Config(validate_sorted=True):
+        with pytest.raises(pl.ComputeError, match="not sorted in ascending order"):
+            s.set_sorted()
+        with pytest.raises(pl.ComputeError, match="not sorted in ascending order"):
+            df.select(pl.col("a").set_sorted())
+        with pytest.raises(pl.ComputeError, match="not sorted in ascending order"):
+            df.set_sorted("a")
+        assert s.sort().set_sorted().flags["SORTED_ASC"]
@@ -110,6 +110,39 @@ def test_sorted_merge_joins(reverse: bool) -> None:
             assert_frame_equal(out_hash_join, out_sorted_merge_join)
 
 
+@pytest.mark.parametrize("nulls_last", [False, True])
+def test_sorted_merge_joins_with_nulls(nulls_last: bool) -> None:
+    df_a = pl.DataFrame({"a": [None, 1, 2, 2, 4, None]}).with_row_index("row_a")
+    df_b = pl.DataFrame({"a": [None, 2, 3, 4, 4]}).with_row_index("row_b")
+    df_a = df_a.sort("a", nulls_last=nulls_last, maintain_order=True)
+    df_b = df_b.sort("a", nulls_last=nulls_last, maintain_order=True)
+    assert df_a.flags["a"]["SORTED_ASC"]
+    assert df_b.flags["a"]["SORTED_ASC"]
+
+    out = df_a.join(df_b, on="a", how="inner")
+    assert out.sort("row_a", "row_b").select("row_a", "row_b").rows() == [
+        (2, 1),
+        (3, 1),
+        (4, 3),
+        (4, 4),
+    ]
+
+    # A left join keeps the order of the left rows, including those with a null key.
+    out = df_a.join(df_b, on="a", how="left")
+    assert out["row_a"].to_list() == [
+        r for r in df_a["row_a"] for _ in range(2 if r == 4 else 1)
+    ]
+    assert out.sort("row_a", "row_b").select("row_a", "row_b").rows() == [
+        (0, None),
+        (1, None),
+        (2, 1),
+        (3, 1),
+        (4, 3),
+        (4, 4),
+        (5, None),
+    ]
+
+
 def test_join_negative_integers() -> None:
     expected = {"a": [-6, -1, 0], "b": [-6, -1, 0]}
 
@@ -911,6 +944,37 @@ def test_cross_join_slice_pushdown() -> None:
     assert_frame_equal(result, expected)
 
 
+@pytest.mark.parametrize("how", ["inner", "left", "full", "cross"])
+def test_join_naming(how: JoinStrategy) -> None:
+    left = pl.DataFrame({"id": [1, 2], "a": [1, 2]})
+    right = pl.DataFrame({"id": [1, 2], "a": [3, 4]})
+    on = None if how == "cross" else "id"
+
+    out = left.join(right, on=on, how=how, naming="{side}_{name}")
+    assert "right_a" in out.columns
+    lazy = left.lazy().join(right.lazy(), on=on, how=how, naming="{side}_{name}")
+    assert out.columns == lazy.collect_schema().names()
+
+    with pytest.raises(DuplicateError):
+        left.join(right, on=on, how=how, naming="raise")
+
+
+def test_join_naming_asof_and_where() -> None:
+    left = pl.DataFrame({"t": [1, 5], "a": [1, 2]}).set_sorted("t")
+    right = pl.DataFrame({"t": [2, 4], "a": [3, 4]}).set_sorted("t")
+
+    out = left.join_asof(right, on="t", naming="{name}_r")
+    assert out.columns == ["t", "a", "a_r"]
+    out = left.join_where(right, pl.col("t") < pl.col("t_r"), naming="{name}_r")
+    assert out.columns == ["t", "a", "t_r", "a_r"]
+    assert out["t"].to_list() == [1, 1]
+
+    with pytest.raises(ValueError, match="must be a template"):
+        left.join(right, on="t", naming="_r")
+    with pytest.raises(InvalidOperationError, match="must contain"):
+        left.join(right, on="t", naming="{name}_{name}")
+
+
 @pytest.mark.parametrize("how", ["left", "inner"])
 def test_join_coalesce(how: JoinStrategy) -> None:
     a = pl.LazyFrame({"a": [1, 2], "b": [1, 2]})
@@ -994,3 +1058,28 @@ def test_join_empty_literal_17027() -> None:
         .height
         == 1
     )
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "full", "semi", "anti"])
+@pytest.mark.parametrize("join_nulls", [False, True])
+def test_join_dictionary_encoded_string_keys(
+    how: JoinStrategy, join_nulls: bool, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    # Enough low cardinality keys for the keys to be dictionary encoded.
+    keys = [None if i % 97 == 0 else f"key_{i % 50}" for i in range(20_000)]
+    left = pl.DataFrame({"a": keys, "b": range(20_000)})
+    right = pl.concat(
+        [
+            pl.DataFrame({"a": [f"key_{i}" for i in range(0, 100, 2)], "c": 1}),
+            pl.DataFrame({"a": [None, "key_1", "other"], "c": 2}),
+        ],
+        rechunk=False,
+    )
+
+    def join() -> pl.DataFrame:
+        out = left.join(right, on="a", how=how, join_nulls=join_nulls)
+        return out.sort(pl.all(), nulls_last=True)
+
+    result = join()
+    monkeypatch.setenv("POLARS_NO_JOIN_DICT_ENCODING", "1")
+    assert_frame_equal(result, join())
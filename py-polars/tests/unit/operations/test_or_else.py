@@ -0,0 +1,63 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ColumnNotFoundError, InvalidOperationError
+from polars.testing import assert_frame_equal
+
+
+def test_or_else() -> None:
+    df = pl.DataFrame({"a": [1, 300]})
+
+    out = df.select(
+        pl.col("a").cast(pl.Int8).or_else(pl.col("a").cast(pl.Int8, strict=False))
+    )
+    assert_frame_equal(out, pl.DataFrame({"a": [1, None]}, schema={"a": pl.Int8}))
+
+    # The fallback is not evaluated if the primary expression succeeds.
+    out = df.select(pl.col("a").cast(pl.Int16).or_else(pl.col("a").cast(pl.Int8)))
+    assert_frame_equal(out, pl.DataFrame({"a": [1, 300]}, schema={"a": pl.Int16}))
+
+
+def test_or_else_regex_from_column() -> None:
+    df = pl.DataFrame({"s": ["a(b", "xyz"], "pattern": ["(", "y"]})
+    out = df.select(
+        pl.col("s")
+        .str.contains(pl.col("pattern"))
+        .or_else(pl.col("s").str.contains(pl.col("pattern"), literal=True))
+    )
+    assert_frame_equal(out, pl.DataFrame({"s": [True, True]}))
+
+
+def test_or_else_supertype() -> None:
+    df = pl.DataFrame({"a": [1, 300]})
+    out = df.select(pl.col("a").cast(pl.Int8).or_else(pl.lit(None)))
+    assert_frame_equal(out, pl.DataFrame({"a": [None, None]}, schema={"a": pl.Int8}))
+
+    out = df.select(pl.col("a").cast(pl.Int8).or_else(pl.col("a").cast(pl.Float32)))
+    assert out.schema == pl.Schema({"a": pl.Float32})
+    assert out["a"].to_list() == [1.0, 300.0]
+
+
+def test_or_else_group_by() -> None:
+    df = pl.DataFrame({"g": [1, 1, 2], "a": [1, 300, 2]})
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("a")
+        .cast(pl.Int8)
+        .sum()
+        .or_else(pl.col("a").cast(pl.Int8, strict=False).sum())
+    )
+    assert out["a"].to_list() == [1, 2]
+
+
+def test_or_else_errors() -> None:
+    df = pl.DataFrame({"a": [1, 300]})
+
+    # Errors of the fallback are raised.
+    with pytest.raises(InvalidOperationError):
+        df.select(pl.col("a").cast(pl.Int8).or_else(pl.col("a").cast(pl.UInt8)))
+
+    # Errors in the query are not caught.
+    with pytest.raises(ColumnNotFoundError):
+        df.select(pl.col("b").or_else(pl.col("a")))
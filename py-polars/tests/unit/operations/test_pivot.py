@@ -7,7 +7,7 @@ import pytest
 
 import polars as pl
 import polars.selectors as cs
-from polars.exceptions import ComputeError, DuplicateError
+from polars.exceptions import ComputeError, DuplicateError, InvalidOperationError
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
@@ -531,3 +531,125 @@ def test_pivot_invalid() -> None:
         match="`index` and `values` cannot both be None in `pivot` operation",
     ):
         pl.DataFrame({"a": [1, 2], "b": [2, 3], "c": [3, 4]}).pivot("a")
+
+
+
+@pytest.fixture
+def pivot_df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "ix": [1, 1, 2, 2, 1, 3],
+            "col": ["b", "a", "b", "a", "b", "c"],
+            "foo": [0, 1, 2, 2, 7, 1],
+            "bar": [0.5, 2.0, 0.0, None, 9.0, 4.0],
+        }
+    )
+
+
+@pytest.mark.parametrize(
+    "agg", ["min", "max", "first", "last", "sum", "mean", "median", "len"]
+)
+def test_lazy_pivot_matches_eager(pivot_df: pl.DataFrame, agg: PivotAgg) -> None:
+    for values in ["foo", ["foo", "bar"]]:
+        expected = pivot_df.pivot(
+            "col", index="ix", values=values, aggregate_function=agg
+        )
+        result = pivot_df.lazy().pivot(
+            "col", index="ix", values=values, aggregate_function=agg
+        )
+        assert_frame_equal(result.collect(), expected)
+
+
+def test_lazy_pivot_expr_and_sort_columns(pivot_df: pl.DataFrame) -> None:
+    kwargs: dict[str, Any] = {
+        "index": "ix",
+        "aggregate_function": pl.element().tanh().mean(),
+        "sort_columns": True,
+        "separator": "/",
+    }
+    expected = pivot_df.pivot("col", **kwargs)
+    result = pivot_df.lazy().pivot("col", **kwargs).collect()
+    assert result.columns == [
+        "ix",
+        *("foo/a", "foo/b", "foo/c"),
+        *("bar/a", "bar/b", "bar/c"),
+    ]
+    assert_frame_equal(result, expected)
+
+
+def test_lazy_pivot_null_header() -> None:
+    df = pl.DataFrame({"ix": [1, 1, 2], "col": ["a", None, None], "foo": [1, 2, 3]})
+    expected = df.pivot("col", index="ix", values="foo")
+    result = df.lazy().pivot("col", index="ix", values="foo").collect()
+    assert result.columns == ["ix", "a", "null"]
+    assert_frame_equal(result, expected)
+
+
+def test_lazy_pivot_multiple_on(pivot_df: pl.DataFrame) -> None:
+    df = pivot_df.with_columns(pl.col("foo") % 2)
+    expected = df.pivot(["col", "foo"], index="ix", values="bar")
+    result = df.lazy().pivot(["col", "foo"], index="ix", values="bar").collect()
+    assert_frame_equal(result, expected)
+
+    # The headers are ("b", 0), ("a", 1), ("a", 0), ("b", 1) and ("c", 1).
+    on_columns = [("b", 1), ("a", 0)]
+    result = (
+        df.lazy()
+        .pivot(["col", "foo"], on_columns=on_columns, index="ix", values="bar")
+        .collect()
+    )
+    assert_frame_equal(result, expected.select("ix", *expected.columns[4:2:-1]))
+
+
+def test_lazy_pivot_on_columns(pivot_df: pl.DataFrame) -> None:
+    q = pivot_df.lazy().pivot(
+        "col",
+        on_columns=["b", "z", "a"],
+        index="ix",
+        values="foo",
+        aggregate_function="sum",
+    )
+    # The schema is known without evaluating the query.
+    assert q.collect_schema() == pl.Schema(
+        {"ix": pl.Int64, "b": pl.Int64, "z": pl.Int64, "a": pl.Int64}
+    )
+    expected = pl.DataFrame(
+        {"ix": [1, 2, 3], "b": [7, 2, None], "z": [None] * 3, "a": [1, 2, None]},
+        schema_overrides={"z": pl.Int64},
+    )
+    assert_frame_equal(q.collect(), expected)
+
+    on_columns = pl.Series(["a", "b"]).cast(pl.Categorical)
+    q = pivot_df.lazy().pivot("col", on_columns=on_columns, values="foo")
+    assert q.collect().columns == ["ix", "bar", "a", "b"]
+
+
+def test_lazy_pivot_pushdown(pivot_df: pl.DataFrame) -> None:
+    q = (
+        pivot_df.lazy()
+        .pivot("col", on_columns=["a", "b"], index="ix", values="foo")
+        .filter(pl.col("ix") == 2)
+        .select("ix", "a")
+    )
+    plan = q.explain()
+    assert "FILTER" not in plan
+    assert "SELECTION: None" not in plan
+    assert "PROJECT 3/4 COLUMNS" in plan
+    assert q.collect().to_dict(as_series=False) == {"ix": [2], "a": [2]}
+
+
+def test_lazy_pivot_without_aggregate_takes_first(pivot_df: pl.DataFrame) -> None:
+    result = pivot_df.lazy().pivot("col", index="ix", values="foo").collect()
+    assert result.row(0) == (1, 0, 1, None)
+
+
+def test_lazy_pivot_errors(pivot_df: pl.DataFrame) -> None:
+    lf = pivot_df.lazy()
+    with pytest.raises(ValueError, match="invalid input for `aggregate_function`"):
+        lf.pivot("col", index="ix", aggregate_function="mode")  # type: ignore[arg-type]
+    with pytest.raises(InvalidOperationError, match="cannot both be None"):
+        lf.pivot("col", on_columns=["a"]).collect()
+    with pytest.raises(InvalidOperationError, match="conversion"):
+        lf.pivot("ix", on_columns=["x"], index="col", values="foo").collect()
+    with pytest.raises(DuplicateError):
+        lf.pivot("col", on_columns=["ix"], index="ix", values="foo").collect()
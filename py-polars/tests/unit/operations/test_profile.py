@@ -1,5 +1,13 @@
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING
+
 import polars as pl
 
+if TYPE_CHECKING:
+    from pathlib import Path
+
 
 def test_profile_columns() -> None:
     ldf = pl.LazyFrame({"a": [1, 2, 3], "b": [1.0, 2.0, 3.0]})
@@ -7,16 +15,25 @@ def test_profile_columns() -> None:
     # profile lazyframe operation/plan
     lazy = ldf.group_by("a").agg(pl.implode("b"))
     profiling_info = lazy.profile()
-    # ┌──────────────┬───────┬─────┐
-    # │ node         ┆ start ┆ end │
-    # │ ---          ┆ ---   ┆ --- │
-    # │ str          ┆ u64   ┆ u64 │
-    # ╞══════════════╪═══════╪═════╡
-    # │ optimization ┆ 0     ┆ 69  │
-    # │ group_by(a)  ┆ 69    ┆ 342 │
-    # └──────────────┴───────┴─────┘
+    # ┌──────────────┬───────┬─────┬─────────┬──────────┬──────┬────────┐
+    # │ node         ┆ start ┆ end ┆ rows_in ┆ rows_out ┆ id   ┆ parent │
+    # │ ---          ┆ ---   ┆ --- ┆ ---     ┆ ---      ┆ ---  ┆ ---    │
+    # │ str          ┆ u64   ┆ u64 ┆ u64     ┆ u64      ┆ u32  ┆ u32    │
+    # ╞══════════════╪═══════╪═════╪═════════╪══════════╪══════╪════════╡
+    # │ optimization ┆ 0     ┆ 69  ┆ null    ┆ null     ┆ null ┆ null   │
+    # │ group_by(a)  ┆ 69    ┆ 342 ┆ 3       ┆ 3        ┆ 0    ┆ null   │
+    # │ df           ┆ 70    ┆ 71  ┆ 0       ┆ 3        ┆ 1    ┆ 0      │
+    # └──────────────┴───────┴─────┴─────────┴──────────┴──────┴────────┘
     assert len(profiling_info) == 2
-    assert profiling_info[1].columns == ["node", "start", "end"]
+    assert profiling_info[1].columns == [
+        "node",
+        "start",
+        "end",
+        "rows_in",
+        "rows_out",
+        "id",
+        "parent",
+    ]
 
 
 def test_profile_with_cse() -> None:
@@ -29,4 +46,98 @@ def test_profile_with_cse() -> None:
         pl.when(x.is_null())
         .then(None)
         .otherwise(pl.when(y == 0).then(None).otherwise(x + y))
-    ).profile(comm_subexpr_elim=True)[1].shape == (2, 3)
+    ).profile(comm_subexpr_elim=True)[1].shape == (3, 7)
+
+
+def test_profile_new_streaming() -> None:
+    lf = pl.LazyFrame({"a": range(10)}).filter(pl.col("a") >= 5)
+
+    df, profile = lf.profile(new_streaming=True)  # type: ignore[call-arg]
+    assert df["a"].to_list() == [5, 6, 7, 8, 9]
+    assert profile.columns == [
+        "node",
+        "start",
+        "end",
+        "rows_in",
+        "rows_out",
+        "bytes_in",
+        "bytes_out",
+        "morsels_out",
+        "id",
+        "parent",
+    ]
+
+    rows = {
+        node: (rows_in, rows_out)
+        for node, rows_in, rows_out in profile.select(
+            "node", "rows_in", "rows_out"
+        ).iter_rows()
+    }
+    assert rows == {
+        "in_memory_source": (0, 10),
+        "filter": (10, 5),
+        "in_memory_sink": (5, 5),
+    }
+
+    ids = dict(profile.select("node", "id").iter_rows())
+    parents = dict(profile.select("node", "parent").iter_rows())
+    assert parents == {
+        "in_memory_source": ids["filter"],
+        "filter": ids["in_memory_sink"],
+        "in_memory_sink": None,
+    }
+
+
+def test_profile_tree() -> None:
+    lf = (
+        pl.LazyFrame({"a": [1, 2, 3, 4], "b": [1, 1, 2, 2]})
+        .group_by("b")
+        .agg(pl.col("a").sum())
+        .sort("b")
+    )
+    df, profile = lf.profile()
+    assert df.to_dict(as_series=False) == {"b": [1, 2], "a": [3, 7]}
+
+    assert profile["node"][0] == "optimization"
+    nodes = {
+        row["id"]: row for row in profile.filter(pl.col("id").is_not_null()).to_dicts()
+    }
+    assert len(nodes) == 3
+
+    # sort <- group by <- scan
+    (sort,) = (node for node in nodes.values() if node["parent"] is None)
+    (group_by,) = (node for node in nodes.values() if node["parent"] == sort["id"])
+    (scan,) = (node for node in nodes.values() if node["parent"] == group_by["id"])
+    assert (sort["rows_in"], sort["rows_out"]) == (2, 2)
+    assert (group_by["rows_in"], group_by["rows_out"]) == (4, 2)
+    assert (scan["rows_in"], scan["rows_out"]) == (0, 4)
+
+    # The time of a node includes the time of its inputs.
+    for child, parent in [(group_by, sort), (scan, group_by)]:
+        assert parent["start"] <= child["start"] <= child["end"] <= parent["end"]
+
+
+def test_profile_speedscope(tmp_path: Path) -> None:
+    path = tmp_path / "profile.json"
+    lf = pl.LazyFrame({"a": [1, 2, 3]}).with_columns(pl.col("a") * 2).sort("a")
+    _, profile = lf.profile(speedscope=path)
+
+    with path.open() as f:
+        speedscope = json.load(f)
+    assert [frame["name"] for frame in speedscope["shared"]["frames"]] == profile[
+        "node"
+    ].to_list()
+
+    # The events open and close the frames like a stack, in order of time.
+    (events,) = (p["events"] for p in speedscope["profiles"])
+    stack: list[int] = []
+    at = 0
+    for event in events:
+        assert event["at"] >= at
+        at = event["at"]
+        if event["type"] == "O":
+            stack.append(event["frame"])
+        else:
+            assert stack.pop() == event["frame"]
+    assert stack == []
+    assert len(events) == 2 * profile.height
@@ -167,3 +167,50 @@ def test_sample_16232() -> None:
     assert df.select(pl.col("b").list.sample(n=pl.col("a"), seed=0)).to_dict(
         as_series=False
     ) == {"b": [[], [], [1]]}
+
+
+@pytest.mark.parametrize("streaming", [False, True])
+def test_sample_lazy_stratified(streaming: bool) -> None:
+    lf = pl.LazyFrame(
+        {
+            "g": [1, 1, 1, 1, 2, 2, 2, 2, 2, 2],
+            "w": [0.0, 0.0, 1.0, 1.0, None, 1.0, 1.0, 1.0, 1.0, 1.0],
+            "x": range(10),
+        }
+    )
+
+    out = lf.sample(2, by="g", seed=0).collect(streaming=streaming)
+    assert out["g"].to_list() == [1, 1, 2, 2]
+    assert out["x"].is_sorted()
+
+    # Rows with a weight of zero or null are never sampled.
+    out = lf.sample(2, by="g", weights="w", seed=0).collect(streaming=streaming)
+    assert out.filter(pl.col("g") == 1)["x"].to_list() == [2, 3]
+    assert 4 not in out["x"]
+
+    out = lf.sample(fraction=0.5, by="g", seed=0).collect(streaming=streaming)
+    assert out["g"].to_list() == [1, 1, 2, 2, 2]
+
+    out = lf.sample(3, with_replacement=True, seed=0).collect(streaming=streaming)
+    assert out.height == 3
+
+    # The same seed gives the same sample.
+    assert_frame_equal(
+        lf.sample(3, shuffle=True, seed=1).collect(streaming=streaming),
+        lf.sample(3, shuffle=True, seed=1).collect(streaming=streaming),
+    )
+
+    with pytest.raises(ShapeError):
+        lf.sample(3, by="g", weights="w").collect(streaming=streaming)
+
+
+def test_sample_lazy_invalid() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+    with pytest.raises(ValueError, match="cannot specify both"):
+        lf.sample(1, fraction=0.5)
+    with pytest.raises(pl.exceptions.ColumnNotFoundError):
+        lf.sample(1, by="c").collect()
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        lf.sample(1, weights="b").collect()
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        lf.with_columns(w=pl.lit(-1)).sample(1, weights="w").collect()
@@ -533,3 +533,33 @@ def test_rolling_by_ordering() -> None:
         ],
         "sum val": [2, 2, 1, 1, 2, 2, 1],
     }
+
+
+def test_rolling_row_indices() -> None:
+    df = pl.DataFrame({"t": [1, 2, 3, 5], "v": [10, 20, 30, 40]})
+
+    out = df.rolling("t", period="2i").agg(pl.row_indices(), pl.col("v").sum())
+    expected = pl.DataFrame(
+        {
+            "t": [1, 2, 3, 5],
+            "row_indices": [[0], [0, 1], [1, 2], [3]],
+            "v": [10, 30, 50, 40],
+        },
+        schema_overrides={"row_indices": pl.List(pl.UInt32)},
+    )
+    assert_frame_equal(out, expected)
+    assert_frame_equal(df.rolling("t", period="2i").agg(pl.groups()), out[:, :2])
+
+    # The rows of a window can be gathered again later on.
+    window = df.get_column("v").gather(out.get_column("row_indices")[2])
+    assert window.to_list() == [20, 30]
+
+    out = (
+        df.group_by_dynamic("t", every="2i")
+        .agg(pl.row_indices())
+        .get_column("row_indices")
+    )
+    assert out.to_list() == [[0], [1, 2], [3]]
+
+    with pytest.raises(InvalidOperationError, match="aggregation context"):
+        df.select(pl.row_indices())
@@ -1,3 +1,10 @@
+from __future__ import annotations
+
+import threading
+import time
+
+import pytest
+
 import polars as pl
 from polars.testing import assert_frame_equal
 
@@ -71,3 +78,44 @@ def test_select_named_inputs_reserved() -> None:
     result = pl.select(inputs=1.0, structify=pl.lit("x"))
     expected = pl.DataFrame({"inputs": [1.0], "structify": ["x"]})
     assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize("streaming", [False, True])
+def test_select_parallel_false(streaming: bool) -> None:
+    lock = threading.Lock()
+    active = 0
+    max_active = 0
+
+    def not_thread_safe(s: pl.Series) -> pl.Series:
+        nonlocal active, max_active
+        with lock:
+            active += 1
+            max_active = max(max_active, active)
+        # Releases the GIL, so other threads could enter.
+        time.sleep(0.01)
+        with lock:
+            active -= 1
+        return s * 2
+
+    lf = pl.LazyFrame({"a": [1, 2, 3], "b": [4, 5, 6]})
+    exprs = [
+        pl.col(c).map_batches(not_thread_safe).parallel(False) for c in ("a", "b")
+    ]
+    result = lf.select(*exprs, c=pl.col("a").sum()).collect(streaming=streaming)
+    expected = pl.DataFrame({"a": [2, 4, 6], "b": [8, 10, 12], "c": [6, 6, 6]})
+    assert_frame_equal(result, expected)
+    assert max_active == 1
+
+    result = lf.with_columns(exprs).collect(streaming=streaming)
+    assert_frame_equal(result, expected.drop("c"))
+    assert max_active == 1
+
+
+def test_select_seq_parallel_true() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    result = df.select_seq(
+        (pl.col("a") + pl.col("a").max()).parallel(True),
+        b=pl.col("a").parallel(False),
+    )
+    expected = pl.DataFrame({"a": [4, 5, 6], "b": [1, 2, 3]})
+    assert_frame_equal(result, expected)
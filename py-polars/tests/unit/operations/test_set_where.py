@@ -0,0 +1,41 @@
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+@pytest.mark.filterwarnings("ignore::polars.exceptions.UnstableWarning")
+def test_set_where() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, 3, None],
+            "b": [4.0, 5.0, None, 7.0],
+            "c": ["x", "y", "z", "w"],
+        }
+    )
+    # The mask and the values see the frame before the update, a null in the mask
+    # keeps the row.
+    out = df.set_where(
+        pl.col("a") > 1, {"a": pl.col("a") * 10}, b=pl.col("a").cast(pl.Float64)
+    )
+    expected = pl.DataFrame(
+        {
+            "a": [1, 20, 30, None],
+            "b": [4.0, 2.0, 3.0, 7.0],
+            "c": ["x", "y", "z", "w"],
+        }
+    )
+    assert_frame_equal(out, expected)
+    assert_frame_equal(
+        df.lazy()
+        .set_where(pl.col("a") > 1, {"a": pl.col("a") * 10}, b=pl.col("a"))
+        .collect(),
+        expected,
+    )
+
+    # A single column, and no columns.
+    assert_frame_equal(
+        df.set_where(pl.col("c") == "x", c=pl.lit("v")),
+        df.with_columns(pl.col("c").replace("x", "v")),
+    )
+    assert_frame_equal(df.set_where(pl.col("a") > 1), df)
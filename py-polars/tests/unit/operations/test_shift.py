@@ -116,3 +116,47 @@ def test_shift_fill_value_group_logicals() -> None:
     result = df.select(pl.col("d").shift(fill_value=pl.col("d").max(), n=-1).over("s"))
 
     assert result.dtypes == [pl.Date]
+
+
+def test_shift_fill_value_per_row() -> None:
+    df = pl.DataFrame({"a": [1, None, 3, 4], "b": [10, 20, 30, 40]})
+    result = df.select(
+        lag=pl.col("a").shift(1, fill_value=pl.col("b")),
+        lead=pl.col("a").shift(-2, fill_value=pl.col("b") * 2),
+        all=pl.col("a").shift(10, fill_value=pl.col("b")),
+    )
+    expected = pl.DataFrame(
+        {
+            "lag": [10, 1, None, 3],
+            "lead": [3, 4, 60, 80],
+            "all": [10, 20, 30, 40],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_lag_lead() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "a", "b"],
+            "t": [3, 2, 1, 2, 1],
+            "x": [30.0, 20.0, 10.0, None, 10.0],
+        }
+    )
+    result = df.select(
+        pl.lag("x").over("g", order_by="t").alias("lag"),
+        pl.lag("x", 2, default=-1).over("g", order_by="t").alias("lag2"),
+        pl.lead("x", default=pl.col("t")).over("g", order_by="t").alias("lead"),
+    )
+    expected = pl.DataFrame(
+        {
+            "lag": [None, 10.0, None, 10.0, None],
+            "lag2": [10.0, -1.0, -1.0, -1.0, -1.0],
+            "lead": [3.0, 2.0, None, 30.0, 20.0],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.group_by("g", maintain_order=True).agg(pl.lead("x", 1, pl.col("t")))
+    expected = pl.DataFrame({"g": ["a", "b"], "x": [[10.0, None, 2.0], [10.0, 1.0]]})
+    assert_frame_equal(result, expected)
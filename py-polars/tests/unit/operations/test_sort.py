@@ -436,6 +436,7 @@ def test_merge_sorted() -> None:
     )
     out = df_a.merge_sorted(df_b, key="range")
     assert out["range"].is_sorted()
+    assert out.flags["range"]["SORTED_ASC"]
     assert out.to_dict(as_series=False) == {
         "index": [0, 0, 1, 2, 10, 3, 4, 20, 5, 6, 30, 7, 8, 40, 9, 10, 50, 11],
         "range": [
@@ -824,3 +825,115 @@ def test_sort_by_unequal_lengths_7207() -> None:
     df = pl.DataFrame({"a": [0, 1, 1, 0], "b": [3, 2, 3, 2]})
     with pytest.raises(pl.exceptions.ComputeError):
         df.select(pl.col.a.sort_by(["a", 1]))
+
+
+def test_sort_collation() -> None:
+    s = pl.Series("a", ["zebra", "Äpfel", None, "apfel", "Banane"])
+    assert s.sort(collation="de").to_list() == [
+        None,
+        "apfel",
+        "Äpfel",
+        "Banane",
+        "zebra",
+    ]
+    assert s.sort(descending=True, nulls_last=True, collation="de").to_list() == [
+        "zebra",
+        "Banane",
+        "Äpfel",
+        "apfel",
+        None,
+    ]
+    # Swedish orders "ö" after "z", German next to "o".
+    s = pl.Series("a", ["ö", "z", "a"])
+    assert s.sort(collation="sv").to_list() == ["a", "z", "ö"]
+    assert s.sort(collation="de").to_list() == ["a", "ö", "z"]
+
+    df = pl.DataFrame({"a": ["b", "B", "a", "b"], "b": [2, 1, 3, 0]})
+    expected = pl.DataFrame({"a": ["a", "b", "B", "b"], "b": [3, 0, 1, 2]})
+    result = df.sort("a", "b", collation="en-u-ks-level2")
+    assert_frame_equal(result, expected)
+    result = df.lazy().sort("a", "b", collation="en-u-ks-level2").collect()
+    assert_frame_equal(result, expected)
+    result = df.select(pl.col("b").sort_by("a", "b", collation="en-u-ks-level2"))
+    assert_frame_equal(result, expected.select("b"))
+
+    result_s = df["a"].rank("dense", collation="en-u-ks-level2")
+    assert_series_equal(result_s, pl.Series("a", [2, 2, 1, 2], dtype=pl.UInt32))
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        s.sort(collation="not a locale")
+
+
+@pytest.mark.parametrize("by", [["key"], ["key", "other"]])
+def test_sort_stable_by_default(by: list[str]) -> None:
+    n = 100_000
+    df = pl.DataFrame(
+        {
+            "key": pl.int_range(n, eager=True) % 7,
+            "other": pl.repeat(1, n, eager=True),
+        }
+    ).with_row_index()
+
+    for descending in [False, True]:
+        result = df.sort(by, descending=descending)
+        expected = df.sort([*by, "index"], descending=[descending] * len(by) + [False])
+        assert_frame_equal(result, expected)
+
+        # The slice is pushed down into the sort.
+        result = df.lazy().sort(by, descending=descending).head(10).collect()
+        assert_frame_equal(result, expected.head(10))
+
+
+def test_sort_unstable() -> None:
+    n = 100_000
+    df = pl.DataFrame({"key": (pl.int_range(n, eager=True) * 7919) % 1000})
+    df = df.with_row_index()
+
+    result = df.sort("key", stable=False)
+    assert result["key"].is_sorted()
+    assert_frame_equal(result.sort("index"), df)
+
+    idx = df["key"].arg_sort(descending=True, stable=False)
+    assert df["key"].gather(idx).is_sorted(descending=True)
+    assert idx.sort().to_list() == list(range(n))
+
+    # Stability is still guaranteed with `maintain_order`.
+    result = df.sort("key", stable=False, maintain_order=True)
+    assert_frame_equal(result, df.sort("key", "index"))
+
+
+def test_arg_sort_stable() -> None:
+    s = pl.Series([2, 1, 2, 1, None, 1])
+    assert s.arg_sort().to_list() == [4, 1, 3, 5, 0, 2]
+    assert s.arg_sort(descending=True, nulls_last=True).to_list() == [0, 2, 1, 3, 5, 4]
+
+
+def test_sort_by_nulls_last_per_key_in_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 1, 2, 2],
+            "a": [None, 1, 1, None, 2, None],
+            "b": [1, None, 2, 2, None, 1],
+            "v": [0, 1, 2, 3, 4, 5],
+        }
+    )
+
+    result = df.group_by("g", maintain_order=True).agg(
+        pl.col("v").sort_by("a", "b", nulls_last=[True, False]).alias("both"),
+        pl.col("v").sort_by("a", "b", nulls_last=True).alias("all"),
+        pl.col("v").reverse().sort_by(pl.col("a").reverse(), nulls_last=True),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2],
+            "both": [[1, 2, 0, 3], [4, 5]],
+            "all": [[2, 1, 0, 3], [4, 5]],
+            "v": [[2, 1, 3, 0], [4, 5]],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    assert_frame_equal(
+        df.sort("a", "b", nulls_last=[True, False]).select("v"),
+        pl.DataFrame({"v": [1, 2, 4, 0, 5, 3]}),
+    )
@@ -75,6 +75,60 @@ def test_hist_rand(n: int) -> None:
         assert ((a <= upper) & (a > lower)).sum() == count[i]
 
 
+def test_hist_bin_strategy() -> None:
+    s = pl.Series("a", range(16))
+    out = s.hist(bin_strategy="sturges", include_category=False)
+    # 5 bins after the unbounded lower bin
+    assert out.height == 6
+    assert out["count"].sum() == 16
+
+    out = s.hist(bin_strategy="fd", include_category=False)
+    assert out["count"].sum() == 16
+
+    with pytest.raises(ValueError, match="bin_strategy"):
+        s.hist(bin_strategy="auto")  # type: ignore[arg-type]
+
+
+def test_hist_shared_bins() -> None:
+    df = pl.DataFrame({"g": [1, 1, 2, 2], "a": [1, 2, 3, 8]})
+
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("a").hist(bin_count=3, shared_bins=True, include_breakpoint=True)
+    )
+    bins = [[row["breakpoint"] for row in group] for group in out["a"].to_list()]
+    assert bins == [[0.0, 3.0, 6.0, float("inf")]] * 2
+    assert out["a"].list.eval(pl.element().struct.field("count")).to_list() == [
+        [0, 2, 0, 0],
+        [0, 1, 0, 1],
+    ]
+
+    # without shared bins every group is binned over its own range
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("a").hist(bin_count=3, include_breakpoint=True)
+    )
+    bins = [[row["breakpoint"] for row in group] for group in out["a"].to_list()]
+    assert bins[0] != bins[1]
+
+
+def test_hist_2d() -> None:
+    df = pl.DataFrame({"x": [1, 2, None, 2], "y": [1.0, 3.0, 3.0, 3.0]})
+    fields = {
+        "x_breakpoint": pl.Float64,
+        "y_breakpoint": pl.Float64,
+        "count": pl.UInt32,
+    }
+    out = df.select(pl.hist_2d("x", "y", bins=[1, 2])).unnest("x")
+    assert out.schema == pl.Schema(fields)
+    assert out["count"].to_list() == [1, 0, 0, 0, 0, 2, 0, 0, 0]
+
+    out = (
+        df.with_columns(g=pl.Series([1, 1, 2, 2]))
+        .group_by("g", maintain_order=True)
+        .agg(pl.hist_2d("x", "y", bin_count=2))
+    )
+    assert out["x"].dtype == pl.List(pl.Struct(fields))
+
+
 def test_median_quantile_duration() -> None:
     df = pl.DataFrame({"A": [timedelta(days=0), timedelta(days=1)]})
 
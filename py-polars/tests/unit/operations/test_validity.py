@@ -0,0 +1,87 @@
+from __future__ import annotations
+
+import pytest
+from hypothesis import given
+
+import polars as pl
+from polars.exceptions import InvalidOperationError, ShapeError
+from polars.testing import assert_frame_equal, assert_series_equal
+from polars.testing.parametric import series
+
+
+@given(s=series(excluded_dtypes=[pl.Struct]))
+def test_is_valid_mask_parametric(s: pl.Series) -> None:
+    assert_series_equal(s.is_valid_mask(), s.is_not_null())
+
+
+def test_is_valid_mask_null_dtype() -> None:
+    s = pl.Series("a", [None, None])
+    assert_series_equal(s.is_valid_mask(), pl.Series("a", [False, False]))
+
+
+def test_with_validity() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, 3, 4],
+            "s": ["x", "y", "z", "w"],
+            "mask": [True, False, None, True],
+        }
+    )
+    result = df.select(
+        pl.col("a", "s").with_validity(pl.col("mask")),
+        b=pl.col("a").with_validity(False),
+    )
+    expected = pl.DataFrame(
+        {
+            "a": [1, None, None, 4],
+            "s": ["x", None, None, "w"],
+            "b": [None, None, None, None],
+        },
+        schema={"a": pl.Int64, "s": pl.String, "b": pl.Int64},
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_with_validity_roundtrip() -> None:
+    s = pl.Series("a", [1, None, 3, None], dtype=pl.Datetime("ms"))
+    mask = s.is_valid_mask()
+    assert_series_equal(s.with_validity(True).with_validity(mask), s)
+
+
+def test_with_validity_chunked() -> None:
+    s = pl.concat([pl.Series("a", [1, 2]), pl.Series("a", [3])], rechunk=False)
+    mask = pl.Series([False, True, False])
+    assert_series_equal(s.with_validity(mask), pl.Series("a", [None, 2, None]))
+
+
+def test_with_validity_errors() -> None:
+    s = pl.Series("a", [1, 2, 3])
+    with pytest.raises(ShapeError):
+        s.with_validity(pl.Series([True, False]))
+    with pytest.raises(InvalidOperationError):
+        pl.Series([{"x": 1}]).with_validity(pl.Series([True]))
+
+
+def test_coalesce_validity() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, None, 3, 4],
+            "b": ["x", "y", None, "z"],
+            "c": [{"x": 1}, {"x": 2}, None, {"x": None}],
+        }
+    )
+    result = df.select(
+        pl.col("a", "c").coalesce_validity(pl.col("b")),
+        d=pl.col("b").coalesce_validity(pl.col("a")),
+        e=pl.col("a").coalesce_validity(pl.lit(None)),
+    )
+    expected = pl.DataFrame(
+        {
+            "a": [1, None, None, 4],
+            "c": [{"x": 1}, {"x": 2}, {"x": None}, {"x": None}],
+            "d": ["x", None, None, "z"],
+            "e": [None, None, None, None],
+        },
+        schema_overrides={"e": pl.Int64},
+    )
+    assert_frame_equal(result, expected)
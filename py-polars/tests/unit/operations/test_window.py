@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+from datetime import date, timedelta
+
 import numpy as np
 import pytest
 
@@ -511,3 +513,85 @@ def test_window_17308() -> None:
     assert df.select(pl.col("A").sum(), pl.col("B").sum().over("grp")).to_dict(
         as_series=False
     ) == {"A": [3, 3], "B": [3, 4]}
+
+
+def test_over_rows_between() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "b", "a", "a"],
+            "t": [3, 1, 1, 2, 2, 4],
+            "x": [1, 2, 3, 4, None, 6],
+        }
+    )
+    out = df.select(
+        sum=pl.col("x").sum().over("g", order_by="t", rows_between=(-1, 0)),
+        first=pl.col("x").first().over("g", order_by="t", rows_between=(None, 0)),
+        next_max=pl.col("x").max().over("g", order_by="t", rows_between=(1, None)),
+        len=pl.len().over("g", rows_between=(-1, 1)),
+    )
+    expected = pl.DataFrame(
+        {
+            # The rows of "a" ordered by "t" are at 2, 4, 0 and 5.
+            "sum": [1, 2, 3, 6, 3, 7],
+            "first": [3, 2, 3, 2, 3, 3],
+            "next_max": [6, 4, 6, None, 6, None],
+            "len": pl.Series([2, 2, 3, 2, 3, 2], dtype=pl.UInt32),
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_over_range_between() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "a", "b"],
+            "date": [
+                date(2024, 1, 1),
+                date(2024, 1, 2),
+                date(2024, 1, 5),
+                date(2024, 1, 1),
+                date(2024, 1, 4),
+                date(2024, 1, 3),
+            ],
+            "x": [1, 2, 3, 4, 5, 6],
+        }
+    )
+    out = df.select(
+        pl.col("x")
+        .sum()
+        .over("g", order_by="date", range_between=("-2d", "0d"))
+        .alias("past"),
+        pl.col("x")
+        .sum()
+        .over("g", order_by="date", range_between=(timedelta(days=1), None))
+        .alias("future"),
+        pl.col("x")
+        .sum()
+        .over("g", order_by=pl.col("date").dt.day(), range_between=(-1, 1))
+        .alias("int"),
+    )
+    expected = pl.DataFrame(
+        {
+            "past": [1, 3, 8, 4, 7, 10],
+            "future": [10, 8, None, 6, 3, None],
+            "int": [3, 3, 8, 4, 8, 6],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_over_frame_errors() -> None:
+    df = pl.DataFrame({"g": [1, 1], "t": [1, None], "x": [1, 2]})
+
+    with pytest.raises(ValueError, match="cannot set both"):
+        pl.col("x").sum().over("g", rows_between=(-1, 0), range_between=(-1, 0))
+    with pytest.raises(ValueError, match="mapping_strategy"):
+        pl.col("x").sum().over("g", rows_between=(-1, 0), mapping_strategy="join")
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="start"):
+        df.select(pl.col("x").sum().over("g", rows_between=(1, 0)))
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="order_by"):
+        df.select(pl.col("x").sum().over("g", range_between=(-1, 0)))
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="nulls"):
+        df.select(pl.col("x").sum().over("g", order_by="t", range_between=(-1, 0)))
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="integer offsets"):
+        df.select(pl.col("x").sum().over("g", order_by="x", range_between=("-1d", 0)))
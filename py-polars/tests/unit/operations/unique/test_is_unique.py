@@ -1,5 +1,5 @@
 import polars as pl
-from polars.testing import assert_series_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 def test_is_unique_series() -> None:
@@ -85,3 +85,70 @@ def test_is_duplicated_null() -> None:
     s = pl.Series([None, None, None])
     expected = pl.Series([True, True, True], dtype=pl.Boolean)
     assert_series_equal(s.is_duplicated(), expected)
+
+
+def test_is_duplicated_keep() -> None:
+    s = pl.Series("a", [1, 2, 1, 3, 1, None, None])
+    assert s.is_duplicated(keep="first").to_list() == [
+        False,
+        False,
+        True,
+        False,
+        True,
+        False,
+        True,
+    ]
+    assert s.is_duplicated(keep="last").to_list() == [
+        True,
+        False,
+        True,
+        False,
+        False,
+        True,
+        False,
+    ]
+    assert_series_equal(s.is_duplicated(keep="none"), s.is_duplicated())
+
+    df = pl.DataFrame({"a": ["x", "y", "x"], "b": [1, 2, 3]})
+    result = df.select(pl.struct("a").is_duplicated(keep="first"))
+    assert result.to_series().to_list() == [False, False, True]
+
+
+def test_is_duplicated_df_subset_keep() -> None:
+    df = pl.DataFrame({"foo": [1, 2, 2, 1], "bar": [6, 7, 7, 8]})
+    assert_series_equal(
+        df.is_duplicated(keep="first"), pl.Series("", [False, False, True, False])
+    )
+    assert_series_equal(
+        df.is_duplicated("foo", keep="last"), pl.Series("", [True, True, False, False])
+    )
+    assert_series_equal(
+        df.is_duplicated(["foo"]), pl.Series("", [True, True, True, True])
+    )
+
+
+def test_duplicates() -> None:
+    df = pl.DataFrame({"a": [1, 2, 1, 3, 1, 2], "b": ["x", "y", "x", "z", "w", "y"]})
+
+    expected = pl.DataFrame(
+        {
+            "a": [1, 2],
+            "b": ["x", "y"],
+            "count": [2, 2],
+            "first_index": [0, 1],
+            "last_index": [2, 5],
+        },
+        schema_overrides={
+            "count": pl.get_index_type(),
+            "first_index": pl.get_index_type(),
+            "last_index": pl.get_index_type(),
+        },
+    )
+    assert_frame_equal(df.duplicates(), expected)
+
+    result = df.duplicates("a")
+    assert result.columns == ["a", "count", "first_index", "last_index"]
+    assert result.rows() == [(1, 3, 0, 4), (2, 2, 1, 5)]
+
+    assert df.duplicates("b").rows() == [("x", 2, 0, 2), ("y", 2, 1, 5)]
+    assert df.head(0).duplicates().height == 0
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import math
+from decimal import Decimal as D
 from datetime import date, datetime, time, timedelta
 from typing import TYPE_CHECKING, Any, Iterator, cast
 
@@ -921,6 +922,41 @@ def test_round() -> None:
     assert b.to_list() == [1.0, 2.0]
 
 
+@pytest.mark.parametrize("dtype", [pl.Float32, pl.Float64])
+def test_round_mode(dtype: PolarsDataType) -> None:
+    s = pl.Series([0.5, 1.5, 2.5, -0.5, -2.5, 0.4], dtype=dtype)
+    assert s.round(mode="half_away").to_list() == [1, 2, 3, -1, -3, 0]
+    assert s.round(mode="half_even").to_list() == [0, 2, 2, 0, -2, 0]
+
+    # 0.125 is a tie, 0.35 is stored slightly below its decimal value.
+    s = pl.Series([0.125, 0.35, 2.675])
+    assert s.round(2, mode="half_away").to_list() == [0.13, 0.35, 2.67]
+    assert s.round(2, mode="half_even").to_list() == [0.12, 0.35, 2.67]
+    assert s.round(1, mode="half_away").to_list() == [0.1, 0.3, 2.7]
+
+    with pytest.raises(ValueError, match="`mode` must be one of"):
+        s.round(mode="half_up")  # type: ignore[arg-type]
+
+
+def test_round_decimal() -> None:
+    s = pl.Series([D("1.25"), D("-1.25"), D("1.35"), D("2.999")], pl.Decimal(10, 3))
+    out = s.round(1)
+    assert out.dtype == pl.Decimal(10, 3)
+    assert out.to_list() == [
+        D("1.300"),
+        D("-1.300"),
+        D("1.400"),
+        D("3.000"),
+    ]
+    assert s.round(1, mode="half_even").to_list() == [
+        D("1.200"),
+        D("-1.200"),
+        D("1.400"),
+        D("3.000"),
+    ]
+    assert_series_equal(s.round(5), s)
+
+
 @pytest.mark.parametrize(
     ("series", "digits", "expected_result"),
     [
@@ -941,6 +977,24 @@ def test_round() -> None:
         pytest.param(
             pl.Series([0.0]), 2, pl.Series([0.0]), id="0 should remain the same"
         ),
+        pytest.param(
+            pl.Series([120, 127, -128], dtype=pl.Int8),
+            2,
+            pl.Series([120, None, None], dtype=pl.Int8),
+            id="i8 overflow becomes null",
+        ),
+        pytest.param(
+            pl.Series([D("123.45"), D("-0.0151")], dtype=pl.Decimal(10, 4)),
+            2,
+            pl.Series([D("120"), D("-0.015")], dtype=pl.Decimal(10, 4)),
+            id="decimal",
+        ),
+        pytest.param(
+            pl.Series([0.125, 1234.5, 9.99]),
+            2,
+            pl.Series([0.13, 1200.0, 10.0]),
+            id="ties and large values",
+        ),
     ],
 )
 def test_round_sig_figs(
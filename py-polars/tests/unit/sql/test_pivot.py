@@ -0,0 +1,72 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import SQLInterfaceError
+from polars.testing import assert_frame_equal
+
+
+@pytest.fixture()
+def df_sales() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "region": ["east", "east", "west", "east", "west"],
+            "quarter": ["Q1", "Q2", "Q1", "Q1", "Q3"],
+            "amount": [10, 20, 30, 5, 1],
+        }
+    )
+
+
+def test_pivot(df_sales: pl.DataFrame) -> None:
+    res = df_sales.sql(
+        """
+        SELECT * FROM self
+        PIVOT (SUM(amount) AS total FOR quarter IN ('Q1', 'Q2' AS second))
+        """
+    )
+    expected = pl.DataFrame(
+        {
+            "region": ["east", "west"],
+            "Q1_total": [15, 30],
+            "second_total": [20, None],
+        }
+    )
+    assert_frame_equal(res, expected)
+
+
+def test_pivot_lazy_schema(df_sales: pl.DataFrame) -> None:
+    # The values are listed, so the schema is known without running the query.
+    lf = df_sales.lazy().sql(
+        """
+        SELECT * FROM self
+        PIVOT (
+          MAX(amount) FOR quarter IN ('Q1', 'Q2', 'Q3') DEFAULT ON NULL (0)
+        )
+        """
+    )
+    assert lf.collect_schema().names() == ["region", "Q1", "Q2", "Q3"]
+    assert lf.collect().rows() == [("east", 10, 20, 0), ("west", 30, 0, 1)]
+
+
+def test_pivot_requires_values(df_sales: pl.DataFrame) -> None:
+    with pytest.raises(SQLInterfaceError, match="explicit list of values"):
+        df_sales.sql("SELECT * FROM self PIVOT (SUM(amount) FOR quarter IN (ANY))")
+
+
+def test_unpivot() -> None:
+    df = pl.DataFrame(
+        {
+            "region": ["east", "west"],
+            "q1": [15, 30],
+            "q2": [20, None],
+        }
+    )
+    res = df.sql(
+        """
+        SELECT * FROM self
+        UNPIVOT (amount FOR quarter IN (q1, q2))
+        ORDER BY region, quarter
+        """
+    )
+    assert res.rows() == [("east", "q1", 15), ("east", "q2", 20), ("west", "q1", 30)]
@@ -81,3 +81,40 @@ def test_truncate_table(truncate_sql: str, test_frame: pl.LazyFrame) -> None:
 
         res = ctx.execute("SELECT * FROM frame")
         assert_frame_equal(res, expected)
+
+
+def test_merge_into() -> None:
+    target = pl.LazyFrame({"id": [1, 2, 3], "qty": [10, 20, 30]})
+    source = pl.LazyFrame({"id": [3, 4], "qty": [5, 40]})
+
+    with pl.SQLContext(target=target, source=source, eager=True) as ctx:
+        res = ctx.execute(
+            """
+            MERGE INTO target USING source ON target.id = source.id
+            WHEN MATCHED AND source.qty < 10
+              THEN UPDATE SET qty = target.qty + source.qty
+            WHEN NOT MATCHED THEN INSERT VALUES (source.id, source.qty * 2)
+            """
+        )
+        expected = pl.DataFrame({"id": [1, 2, 3, 4], "qty": [10, 20, 35, 80]})
+        assert_frame_equal(res.sort("id"), expected)
+        assert_frame_equal(ctx.execute("SELECT * FROM target ORDER BY id"), expected)
+
+
+def test_merge_into_errors() -> None:
+    lf = pl.LazyFrame({"id": [1]})
+    with pl.SQLContext(target=lf, source=lf) as ctx:
+        with pytest.raises(SQLInterfaceError, match="equi-join constraints"):
+            ctx.execute(
+                """
+                MERGE INTO target USING source ON target.id < source.id
+                WHEN MATCHED THEN DELETE
+                """
+            )
+        with pytest.raises(pl.exceptions.ColumnNotFoundError, match="no column 'x'"):
+            ctx.execute(
+                """
+                MERGE INTO target USING source ON target.id = source.id
+                WHEN MATCHED THEN UPDATE SET x = 1
+                """
+            )
@@ -0,0 +1,133 @@
+from __future__ import annotations
+
+from datetime import date
+
+import pytest
+
+import polars as pl
+from polars.exceptions import SQLInterfaceError, SQLSyntaxError
+
+
+@pytest.fixture()
+def df_sales() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "store": ["a", "a", "a", "b", "b", "b"],
+            "day": [
+                date(2024, 1, 1),
+                date(2024, 1, 2),
+                date(2024, 1, 4),
+                date(2024, 1, 1),
+                date(2024, 1, 3),
+                date(2024, 1, 4),
+            ],
+            "sales": [10, 20, 30, 1, 2, 3],
+        }
+    )
+
+
+def test_window_frame_rows(df_sales: pl.DataFrame) -> None:
+    res = df_sales.sql(
+        """
+        SELECT
+          store,
+          SUM(sales) OVER (
+            PARTITION BY store ORDER BY day
+            ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING
+          ) AS centered,
+          MAX(sales) OVER (
+            PARTITION BY store ORDER BY day DESC
+            ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING
+          ) AS max_before
+        FROM self
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "store": ["a", "a", "a", "b", "b", "b"],
+        "centered": [30, 60, 50, 3, 6, 5],
+        "max_before": [10, 20, 30, 1, 2, 3],
+    }
+
+
+def test_window_frame_range(df_sales: pl.DataFrame) -> None:
+    res = df_sales.sql(
+        """
+        SELECT
+          store,
+          day,
+          SUM(sales) OVER (
+            PARTITION BY store ORDER BY day
+            RANGE BETWEEN INTERVAL '1 day' PRECEDING AND CURRENT ROW
+          ) AS two_days
+        FROM self
+        """
+    )
+    assert res["two_days"].to_list() == [10, 30, 30, 1, 2, 5]
+
+
+def test_named_window(df_sales: pl.DataFrame) -> None:
+    res = df_sales.sql(
+        """
+        SELECT
+          store,
+          SUM(sales) OVER (w ROWS UNBOUNDED PRECEDING) AS running,
+          AVG(sales) OVER ws AS mean
+        FROM self
+        WINDOW w AS (PARTITION BY store), ws AS (w ORDER BY day)
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "store": ["a", "a", "a", "b", "b", "b"],
+        "running": [10, 30, 60, 1, 3, 6],
+        "mean": [20.0, 20.0, 20.0, 2.0, 2.0, 2.0],
+    }
+
+
+def test_window_frame_errors(df_sales: pl.DataFrame) -> None:
+    with pytest.raises(SQLInterfaceError, match="no window named 'w'"):
+        df_sales.sql("SELECT SUM(sales) OVER w FROM self")
+
+    with pytest.raises(SQLInterfaceError, match="GROUPS window frames"):
+        df_sales.sql(
+            "SELECT SUM(sales) OVER (ORDER BY day GROUPS 1 PRECEDING) FROM self"
+        )
+
+    with pytest.raises(SQLSyntaxError, match="invalid window frame start bound"):
+        df_sales.sql(
+            """
+            SELECT SUM(sales) OVER (
+              ORDER BY day ROWS BETWEEN UNBOUNDED FOLLOWING AND CURRENT ROW
+            ) FROM self
+            """
+        )
+
+    with pytest.raises(SQLSyntaxError, match="cannot override the PARTITION BY"):
+        df_sales.sql(
+            """
+            SELECT SUM(sales) OVER (w PARTITION BY day) FROM self
+            WINDOW w AS (PARTITION BY store)
+            """
+        )
+
+
+def test_lag_lead(df_sales: pl.DataFrame) -> None:
+    res = df_sales.sql(
+        """
+        SELECT
+          store,
+          LAG(sales) OVER (PARTITION BY store ORDER BY day DESC) AS next_day,
+          LEAD(sales, 2, -1) OVER (ORDER BY day, store) AS lead2,
+          sales - LAG(sales, 1, sales) OVER w AS change
+        FROM self
+        WINDOW w AS (PARTITION BY store ORDER BY day)
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "store": ["a", "a", "a", "b", "b", "b"],
+        "next_day": [20, 30, None, 2, 3, None],
+        "lead2": [20, 30, -1, 2, 3, -1],
+        "change": [0, 10, 10, 0, 1, 1],
+    }
+
+    with pytest.raises(SQLSyntaxError, match="offset of LAG must be a non-negative"):
+        df_sales.sql("SELECT LAG(sales, 'x') OVER (ORDER BY day) FROM self")
@@ -292,6 +292,33 @@ def test_streaming_group_by_ooc_q3(
     assert_frame_equal(result, expected)
 
 
+@pytest.mark.write_disk()
+def test_streaming_group_by_max_memory(
+    random_integers: pl.Series,
+    tmp_path: Path,
+    monkeypatch: Any,
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_MAX_MEMORY", "1")
+
+    lf = random_integers.to_frame().lazy()
+    result = (
+        lf.group_by("a")
+        .agg(pl.len(), pl.col("a").sum().alias("a_sum"))
+        .sort("a")
+        .collect(streaming=True)
+    )
+
+    expected = (
+        random_integers.to_frame()
+        .group_by("a")
+        .agg(pl.len(), pl.col("a").sum().alias("a_sum"))
+        .sort("a")
+    )
+    assert_frame_equal(result, expected)
+
+
 def test_streaming_group_by_struct_key() -> None:
     df = pl.DataFrame(
         {"A": [1, 2, 3, 2], "B": ["google", "ms", "apple", "ms"], "C": [2, 3, 4, 3]}
@@ -519,3 +546,83 @@ def test_streaming_group_by_boolean_mean_15610(
     )
 
     assert_frame_equal(out, expect)
+
+
+def test_streaming_group_by_approx_quantile() -> None:
+    n = 100_000
+    df = pl.DataFrame({"g": pl.arange(0, n, eager=True) % 3, "x": np.random.rand(n)})
+    q = (
+        df.lazy()
+        .group_by("g")
+        .agg(pl.col("x").approx_quantile(0.5, accuracy=200))
+        .sort("g")
+    )
+    assert "STREAMING" in q.explain(streaming=True)
+
+    expected = df.group_by("g").agg(pl.col("x").median()).sort("g")
+    assert_frame_equal(q.collect(streaming=True), expected, atol=0.01)
+    assert_frame_equal(q.collect(), expected, atol=0.01)
+
+
+def test_streaming_group_by_first_by_last_by() -> None:
+    n = 30_000
+    df = pl.DataFrame(
+        {
+            "g": pl.arange(0, n, eager=True) % 3,
+            "x": pl.arange(0, n, eager=True),
+            "t": (pl.arange(0, n, eager=True) * 7919) % n,
+        }
+    )
+    q = (
+        df.lazy()
+        .group_by("g")
+        .agg(
+            first=pl.col("x").first_by("t"),
+            last=pl.col("x").last_by("t"),
+        )
+        .sort("g")
+    )
+    assert "STREAMING" in q.explain(streaming=True)
+
+    expected = (
+        df.sort("t")
+        .group_by("g")
+        .agg(first=pl.col("x").first(), last=pl.col("x").last())
+        .sort("g")
+    )
+    assert_frame_equal(q.collect(streaming=True), expected)
+    assert_frame_equal(q.collect(), expected)
+
+
+def test_streaming_group_by_str_join_agg() -> None:
+    n = 30_000
+    df = pl.DataFrame(
+        {
+            "g": pl.arange(0, n, eager=True) % 3,
+            "s": (pl.arange(0, n, eager=True) % 5).cast(pl.String),
+        }
+    )
+    q = (
+        df.lazy()
+        .group_by("g")
+        .agg(
+            pl.col("s").str.join_agg(","),
+            desc=pl.col("s").str.join_agg(sort="descending"),
+            all=pl.col("s").str.join_agg(distinct=False),
+        )
+        .with_columns(pl.col("all").str.len_bytes())
+        .sort("g")
+    )
+    assert "STREAMING" in q.explain(streaming=True)
+
+    expected = pl.DataFrame(
+        {
+            "g": [0, 1, 2],
+            "s": ["0,1,2,3,4"] * 3,
+            "desc": ["43210"] * 3,
+            "all": [10_000] * 3,
+        },
+        schema_overrides={"all": pl.UInt32},
+    )
+    assert_frame_equal(q.collect(streaming=True), expected)
+    assert_frame_equal(q.collect(), expected)
@@ -1,8 +1,12 @@
 from __future__ import annotations
 
+import gzip
+import io
 from typing import TYPE_CHECKING, Any
 from unittest.mock import patch
 
+import pyarrow as pa
+import pyarrow.parquet as pq
 import pytest
 
 import polars as pl
@@ -89,6 +93,42 @@ def test_sink_parquet_10115(tmp_path: Path) -> None:
     }
 
 
+@pytest.mark.write_disk()
+def test_sink_parquet_keep_dictionaries(
+    monkeypatch: Any, capfd: Any, tmp_path: Path
+) -> None:
+    in_path = tmp_path / "in.parquet"
+    out_path = tmp_path / "out.parquet"
+    df = pl.DataFrame(
+        {
+            "a": ["x", "y", None, "z"] * 250,
+            "b": ["p", "q"] * 500,
+            "c": range(1000),
+        }
+    )
+    df.write_parquet(in_path, row_group_size=300)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    (
+        pl.scan_parquet(in_path)
+        .filter(pl.col("c") % 3 == 0, pl.col("b") == "q")
+        .select("a", "c")
+        .sink_parquet(out_path)
+    )
+    # Columns that a predicate reads are decoded.
+    captured = capfd.readouterr().err
+    assert "parquet sink: keeping the dictionaries of the columns [\"a\"]" in captured
+
+    result = pl.read_parquet(out_path)
+    expected = df.filter(pl.col("c") % 3 == 0, pl.col("b") == "q").select("a", "c")
+    assert_frame_equal(result, expected)
+
+    # Written as strings, with dictionary-encoded pages.
+    column = pq.read_metadata(out_path).row_group(0).column(0)
+    assert column.path_in_schema == "a"
+    assert "RLE_DICTIONARY" in column.encodings
+
+
 @pytest.mark.write_disk()
 def test_sink_ipc(io_files_path: Path, tmp_path: Path) -> None:
     tmp_path.mkdir(exist_ok=True)
@@ -150,9 +190,12 @@ def test_sink_csv_with_options() -> None:
             time_format="%H",
             float_scientific=True,
             float_precision=42,
+            float_scientific_threshold=5,
             null_value="BOOM",
             quote_style="always",
             maintain_order=False,
+            compression="zstd",
+            compression_level=5,
         )
 
         ldf.optimization_toggle().sink_csv.assert_called_with(
@@ -168,9 +211,12 @@ def test_sink_csv_with_options() -> None:
             time_format="%H",
             float_scientific=True,
             float_precision=42,
+            float_scientific_threshold=5,
             null_value="BOOM",
             quote_style="always",
             maintain_order=False,
+            compression="zstd",
+            compression_level=5,
         )
 
 
@@ -242,6 +288,115 @@ def test_sink_ndjson_should_write_same_data(
     assert_frame_equal(df, expected)
 
 
+@pytest.mark.write_disk()
+@pytest.mark.parametrize(
+    ("file_name", "compression", "magic"),
+    [
+        ("out.csv.gz", "infer", b"\x1f\x8b"),
+        ("out.csv.zst", "infer", b"\x28\xb5\x2f\xfd"),
+        ("out.csv", "gzip", b"\x1f\x8b"),
+        ("out.csv", "bgzip", b"\x1f\x8b\x08\x04"),
+        ("out.csv", "zstd", b"\x28\xb5\x2f\xfd"),
+        ("out.csv.gz", "uncompressed", b"a,b\n"),
+    ],
+)
+def test_sink_csv_compressed(
+    file_name: str, compression: str, magic: bytes, tmp_path: Path
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    path = tmp_path / file_name
+
+    df = pl.DataFrame({"a": range(100_000), "b": ["x", "y"] * 50_000})
+    df.lazy().sink_csv(path, compression=compression)
+
+    assert path.read_bytes().startswith(magic)
+    assert_frame_equal(pl.read_csv(path), df)
+
+
+@pytest.mark.write_disk()
+def test_sink_csv_bgzip_blocks(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    path = tmp_path / "out.csv.bgz"
+
+    df = pl.DataFrame({"a": range(100_000)})
+    df.lazy().sink_csv(path, compression_level=1)
+    data = path.read_bytes()
+
+    # A block starts with a gzip header with a `BC` extra field holding its size.
+    offset = 0
+    n_blocks = 0
+    while offset < len(data):
+        assert data[offset : offset + 4] == b"\x1f\x8b\x08\x04"
+        assert data[offset + 12 : offset + 14] == b"BC"
+        offset += int.from_bytes(data[offset + 16 : offset + 18], "little") + 1
+        n_blocks += 1
+
+    assert offset == len(data)
+    assert n_blocks > 2
+    assert gzip.decompress(data) == df.write_csv().encode()
+
+
+@pytest.mark.write_disk()
+def test_sink_ndjson_compressed(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    path = tmp_path / "out.ndjson.gz"
+
+    df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", None, "z"]})
+    df.lazy().sink_ndjson(path)
+
+    result = pl.read_ndjson(io.BytesIO(gzip.decompress(path.read_bytes())))
+    assert_frame_equal(result, df)
+
+
+def test_sink_compression_invalid() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+    with pytest.raises(ValueError, match="`compression` must be one of"):
+        lf.sink_csv("out.csv", compression="lz4")
+    with pytest.raises(ValueError, match="gzip compression level"):
+        lf.sink_ndjson("out.ndjson", compression="gzip", compression_level=-1)
+
+
+@pytest.mark.write_disk()
+def test_sink_write_mode(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    lf = pl.LazyFrame({"a": [1, 2], "b": ["x", "y"]})
+    expected = pl.concat([lf, lf]).collect()
+
+    path = tmp_path / "out.csv"
+    lf.sink_csv(path, write_mode="error_if_exists")
+    with pytest.raises(FileExistsError):
+        lf.sink_csv(path, write_mode="error_if_exists")
+    lf.sink_csv(path, write_mode="append")
+    assert_frame_equal(pl.read_csv(path), expected)
+
+    path = tmp_path / "out.ndjson"
+    lf.sink_ndjson(path, write_mode="append")
+    lf.sink_ndjson(path, write_mode="append")
+    assert_frame_equal(pl.read_ndjson(path), expected)
+
+    path = tmp_path / "out.arrow"
+    lf.sink_ipc(path)
+    lf.sink_ipc(path, write_mode="append")
+    assert_frame_equal(pl.read_ipc(path, memory_map=False), expected)
+    with pytest.raises(pl.exceptions.SchemaError):
+        lf.select("a").sink_ipc(path, write_mode="append")
+
+    path = tmp_path / "out.parquet"
+    lf.sink_parquet(path)
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        lf.sink_parquet(path, write_mode="append")
+    lf.sink_parquet(path)
+    assert_frame_equal(pl.read_parquet(path), lf.collect())
+
+    # failed and successful writes leave no temporary files behind
+    assert sorted(p.name for p in tmp_path.iterdir()) == [
+        "out.arrow",
+        "out.csv",
+        "out.ndjson",
+        "out.parquet",
+    ]
+
+
 @pytest.mark.write_disk()
 def test_parquet_eq_statistics(monkeypatch: Any, capfd: Any, tmp_path: Path) -> None:
     tmp_path.mkdir(exist_ok=True)
@@ -290,3 +445,36 @@ def test_streaming_empty_parquet_16523(tmp_path: Path) -> None:
     q = pl.scan_parquet(file_path)
     q2 = pl.LazyFrame({"a": [1]}, schema={"a": pl.Int32})
     assert q.join(q2, on="a").collect(streaming=True).shape == (0, 1)
+
+
+def test_collect_arrow_stream() -> None:
+    lf = pl.LazyFrame({"a": range(10), "b": [str(i) for i in range(10)]})
+    lf = lf.filter(pl.col("a") > 2)
+    expected = lf.collect()
+
+    reader = lf.collect_arrow_stream().to_pyarrow()
+    assert_frame_equal(pl.DataFrame(reader.read_all()), expected)
+
+    stream = lf.collect_arrow_stream()
+    table = pa.RecordBatchReader.from_stream(stream).read_all()
+    assert_frame_equal(pl.DataFrame(table), expected)
+    with pytest.raises(ValueError, match="already consumed"):
+        stream.to_pyarrow()
+
+
+@pytest.mark.write_disk()
+def test_sink_ipc_stream(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    path = tmp_path / "out.arrows"
+
+    df = pl.DataFrame(
+        {"a": range(100), "b": ["x", "y", "z", "x"] * 25},
+        schema_overrides={"b": pl.Categorical},
+    )
+    df.lazy().sink_ipc_stream(path, compression="zstd", compression_level=5)
+    assert_frame_equal(pl.read_ipc_stream(path), df, categorical_as_str=True)
+    table = pa.ipc.open_stream(path).read_all()
+    assert_frame_equal(pl.DataFrame(table), df, categorical_as_str=True)
+
+    with pytest.raises(pl.exceptions.ComputeError, match="compression range"):
+        df.lazy().sink_ipc_stream(path, compression="lz4", compression_level=20)
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from datetime import datetime
+from datetime import datetime, timedelta
 from typing import TYPE_CHECKING, Literal
 
 import numpy as np
@@ -13,7 +13,7 @@ from polars.testing import assert_frame_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
-    from polars._typing import JoinStrategy
+    from polars._typing import AsofJoinStrategy, JoinStrategy
 
 pytestmark = pytest.mark.xdist_group("streaming")
 
@@ -293,3 +293,64 @@ def test_streaming_outer_join_partial_flush(tmp_path: Path) -> None:
         ],
         "value": [0, 1, 2, 3, 4, 5],
     }
+
+
+@pytest.mark.parametrize("strategy", ["backward", "forward", "nearest"])
+def test_new_streaming_join_asof(strategy: AsofJoinStrategy) -> None:
+    n = 200_000
+    start = datetime(2024, 1, 1)
+    trades = pl.LazyFrame(
+        {
+            "time": pl.datetime_range(
+                start, start + timedelta(seconds=n - 1), "1s", eager=True
+            ),
+            "ticker": np.random.choice(["A", "B", "C"], n),
+            "size": np.arange(n),
+        }
+    )
+    quotes = pl.LazyFrame(
+        {
+            "time": pl.datetime_range(
+                start, start + timedelta(seconds=n - 1), "7s", eager=True
+            ),
+            "ticker": np.random.choice(["A", "B"], n // 7 + 1),
+            "bid": np.arange(n // 7 + 1, dtype=np.float64),
+        }
+    )
+
+    q = trades.join_asof(
+        quotes, on="time", by="ticker", strategy=strategy, tolerance="20s"
+    ).with_columns(pl.col("bid") * 2)
+
+    expected = q.collect()
+    assert expected["bid"].null_count() > 0
+    out = q.collect(new_streaming=True)  # type: ignore[call-arg]
+    assert_frame_equal(out, expected)
+
+
+def test_new_streaming_join_where() -> None:
+    np.random.seed(0)
+    left = pl.LazyFrame(
+        {
+            "id": np.arange(20_000),
+            "a": np.random.randint(0, 1_000, 20_000),
+            "b": np.random.randint(0, 1_000, 20_000),
+        }
+    )
+    right = pl.LazyFrame(
+        {
+            "a": np.random.randint(0, 1_000, 200).astype(np.float32),
+            "y": np.random.randint(0, 1_000, 200),
+        }
+    )
+
+    q = left.join_where(
+        right,
+        pl.col("a") >= pl.col("a_right"),
+        pl.col("b") < pl.col("y"),
+    ).with_columns(pl.col("y") * 2)
+
+    expected = q.collect()
+    assert expected.height > 0
+    out = q.collect(new_streaming=True)  # type: ignore[call-arg]
+    assert_frame_equal(out, expected)
@@ -92,6 +92,79 @@ def test_ooc_sort(tmp_path: Path, monkeypatch: Any) -> None:
         assert_series_equal(out, s.sort(descending=descending))
 
 
+@pytest.mark.write_disk()
+def test_streaming_sort_max_memory(
+    tmp_path: Path, monkeypatch: Any, capfd: Any
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    s = pl.arange(0, 10_000, eager=True).rename("idx")
+    lf = s.shuffle(seed=1).to_frame().lazy().sort("idx")
+
+    with pl.Config(streaming_max_memory=1):
+        out = lf.collect(streaming=True).to_series()
+
+    assert_series_equal(out, s)
+    assert "OOC sort started" in capfd.readouterr().err
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("descending", [False, True])
+@pytest.mark.parametrize("nulls_last", [False, True])
+def test_streaming_sort_merge_runs(
+    tmp_path: Path, monkeypatch: Any, capfd: Any, descending: bool, nulls_last: bool
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+    monkeypatch.setenv("POLARS_OOC_SORT_RUN_SIZE", "20000")
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    np.random.seed(0)
+    df = pl.DataFrame({"a": np.random.randint(0, 1_000, 100_000)}).with_columns(
+        pl.when(pl.col("a") >= 10).then(pl.col("a"))
+    )
+
+    out = (
+        df.lazy()
+        .sort("a", descending=descending, nulls_last=nulls_last)
+        .collect(streaming=True)
+    )
+
+    assert_frame_equal(out, df.sort("a", descending=descending, nulls_last=nulls_last))
+    assert "sorted runs" in capfd.readouterr().err
+
+
+@pytest.mark.write_disk()
+def test_streaming_sort_merge_runs_multiple_columns_slice(
+    tmp_path: Path, monkeypatch: Any
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+    monkeypatch.setenv("POLARS_OOC_SORT_RUN_SIZE", "20000")
+
+    np.random.seed(0)
+    df = pl.DataFrame(
+        {
+            "a": np.random.randint(0, 100, 50_000),
+            "b": pl.Series(np.random.randint(0, 100, 50_000)).cast(pl.String),
+        }
+    )
+
+    out = (
+        df.lazy()
+        .sort(["a", "b"], descending=[True, False])
+        .slice(1_000, 5_000)
+        .collect(streaming=True)
+    )
+
+    expected = df.sort(["a", "b"], descending=[True, False]).slice(1_000, 5_000)
+    assert_frame_equal(out, expected)
+
+
 @pytest.mark.debug()
 @pytest.mark.write_disk()
 @pytest.mark.parametrize("spill_source", [True, False])
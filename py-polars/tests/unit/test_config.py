@@ -742,6 +742,16 @@ def test_set_streaming_chunk_size() -> None:
         cfg.set_streaming_chunk_size(0)
 
 
+def test_set_streaming_max_memory() -> None:
+    with pl.Config(streaming_max_memory=1024) as cfg:
+        assert os.environ.get("POLARS_MAX_MEMORY") == "1024"
+        cfg.set_streaming_max_memory(None)
+        assert "POLARS_MAX_MEMORY" not in os.environ
+
+    with pytest.raises(ValueError), pl.Config() as cfg:
+        cfg.set_streaming_max_memory(0)
+
+
 def test_set_fmt_str_lengths_invalid_length() -> None:
     with pl.Config() as cfg:
         with pytest.raises(ValueError):
@@ -769,6 +779,8 @@ def test_warn_unstable(recwarn: pytest.WarningsRecorder) -> None:
     ("environment_variable", "config_setting", "value", "expected"),
     [
         ("POLARS_AUTO_STRUCTIFY", "set_auto_structify", True, "1"),
+        ("POLARS_DECIMAL_OVERFLOW", "set_decimal_overflow", "raise", "raise"),
+        ("POLARS_DECIMAL_ROUNDING", "set_decimal_rounding", "half_even", "half_even"),
         ("POLARS_FMT_MAX_COLS", "set_tbl_cols", 12, "12"),
         ("POLARS_FMT_MAX_ROWS", "set_tbl_rows", 3, "3"),
         ("POLARS_FMT_STR_LEN", "set_fmt_str_lengths", 42, "42"),
@@ -823,6 +835,7 @@ def test_warn_unstable(recwarn: pytest.WarningsRecorder) -> None:
             "1",
         ),
         ("POLARS_STREAMING_CHUNK_SIZE", "set_streaming_chunk_size", 100, "100"),
+        ("POLARS_STRICT_SUPERTYPES", "set_strict_supertypes", True, "1"),
         ("POLARS_TABLE_WIDTH", "set_tbl_width_chars", 80, "80"),
         ("POLARS_VERBOSE", "set_verbose", True, "1"),
         ("POLARS_WARN_UNSTABLE", "warn_unstable", True, "1"),
@@ -838,3 +851,36 @@ def test_unset_config_env_vars(
 
     with pl.Config(**{config_setting: None}):  # type: ignore[arg-type]
         assert environment_variable not in os.environ
+
+
+def test_strict_supertypes() -> None:
+    df = pl.DataFrame(
+        {
+            "i": [1, 2],
+            "f": [1.5, 2.5],
+            "s": ["a", "b"],
+            "c": ["a", "c"],
+        },
+        schema={"i": pl.Int32, "f": pl.Float64, "s": pl.String, "c": pl.Categorical},
+    )
+    # Without the setting the columns are cast silently.
+    assert df.select(pl.col("i") + pl.col("f")).dtypes == [pl.Float64]
+
+    with pl.Config(strict_supertypes=True):
+        with pytest.raises(pl.exceptions.SchemaError, match=r'col\("i"\).cast\(f64\)'):
+            df.select(pl.col("i") + pl.col("f"))
+        with pytest.raises(pl.exceptions.SchemaError, match="cast"):
+            df.select(pl.col("s") == pl.col("c"))
+        with pytest.raises(pl.exceptions.SchemaError, match="cast"):
+            df.select(pl.when(pl.col("f") > 2).then(pl.col("i")).otherwise(pl.col("f")))
+        with pytest.raises(pl.exceptions.SchemaError, match="cast"):
+            df.select(pl.coalesce("i", "f"))
+
+        # Explicit casts and literals are fine.
+        out = df.select(
+            pl.col("i").cast(pl.Float64) + pl.col("f"),
+            (pl.col("i") + 1).alias("plus_one"),
+            (pl.col("f") * 2).alias("double"),
+            pl.col("c") == "a",
+        )
+        assert out.dtypes == [pl.Float64, pl.Int32, pl.Float64, pl.Boolean]
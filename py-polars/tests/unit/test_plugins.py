@@ -11,6 +11,7 @@ from polars.plugins import (
     _is_dynamic_lib,
     _resolve_plugin_path,
     _serialize_kwargs,
+    register_plugin_aggregation,
     register_plugin_function,
 )
 
@@ -29,6 +30,21 @@ def test_register_plugin_function_invalid_plugin_path(tmp_path: Path) -> None:
         pl.select(expr)
 
 
+@pytest.mark.write_disk()
+def test_register_plugin_aggregation_invalid_plugin_path(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    plugin_path = tmp_path / "lib.so"
+    plugin_path.touch()
+
+    expr = register_plugin_aggregation(
+        plugin_path=plugin_path, function_name="hello", args=["a", "b"]
+    )
+    lf = pl.LazyFrame({"g": [1, 1], "a": [1, 2], "b": [3, 4]})
+
+    with pytest.raises(ComputeError, match="error loading dynamic library"):
+        lf.group_by("g").agg(expr).collect()
+
+
 @pytest.mark.parametrize(
     ("input", "expected"),
     [
@@ -0,0 +1,46 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Iterator
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+@pytest.fixture(autouse=True)
+def _disable_result_cache() -> Iterator[None]:
+    """Fixture to make sure the result cache is disabled before and after each test."""
+    pl.disable_result_cache()
+    yield
+    pl.disable_result_cache()
+
+
+def test_result_cache_enable_disable() -> None:
+    assert not pl.using_result_cache()
+    pl.enable_result_cache()
+    assert pl.using_result_cache()
+    pl.clear_result_cache()
+    assert pl.using_result_cache()
+    pl.disable_result_cache()
+    assert not pl.using_result_cache()
+
+
+@pytest.mark.write_disk()
+def test_result_cache_file_modified(tmp_path: Path) -> None:
+    path = tmp_path / "data.csv"
+    pl.DataFrame({"a": [1, 2, 3]}).write_csv(path)
+
+    pl.enable_result_cache()
+    lf = pl.scan_csv(path).filter(pl.col("a") > 1)
+    assert lf.collect().height == 2
+    assert_frame_equal(
+        lf.with_columns(b=pl.col("a") * 2).collect(),
+        pl.DataFrame({"a": [2, 3], "b": [4, 6]}),
+    )
+
+    pl.DataFrame({"a": [1, 2, 3, 4]}).write_csv(path)
+    assert lf.collect().height == 3